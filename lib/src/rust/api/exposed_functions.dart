@@ -4,15 +4,879 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../third_party/holloway_core/api/functions/anchors.dart';
+import '../third_party/holloway_core/api/functions/ansi.dart';
+import '../third_party/holloway_core/api/functions/batch.dart';
+import '../third_party/holloway_core/api/functions/bidi.dart';
+import '../third_party/holloway_core/api/functions/bookmark_export.dart';
+import '../third_party/holloway_core/api/functions/bookmark_import.dart';
+import '../third_party/holloway_core/api/functions/clock_skew.dart';
+import '../third_party/holloway_core/api/functions/config.dart';
+import '../third_party/holloway_core/api/functions/content_router.dart';
+import '../third_party/holloway_core/api/functions/crawler.dart';
+import '../third_party/holloway_core/api/functions/data_usage.dart';
+import '../third_party/holloway_core/api/functions/downloads.dart';
+import '../third_party/holloway_core/api/functions/explore.dart';
+import '../third_party/holloway_core/api/functions/feed_discovery.dart';
+import '../third_party/holloway_core/api/functions/file_type.dart';
+import '../third_party/holloway_core/api/functions/gempub.dart';
+import '../third_party/holloway_core/api/functions/highlighting.dart';
+import '../third_party/holloway_core/api/functions/homograph.dart';
+import '../third_party/holloway_core/api/functions/host_migration.dart';
+import '../third_party/holloway_core/api/functions/identities.dart';
+import '../third_party/holloway_core/api/functions/identity_transfer.dart';
+import '../third_party/holloway_core/api/functions/inline_images.dart';
+import '../third_party/holloway_core/api/functions/known_hosts_transfer.dart';
+import '../third_party/holloway_core/api/functions/links.dart';
+import '../third_party/holloway_core/api/functions/logging.dart';
+import '../third_party/holloway_core/api/functions/menu_pages.dart';
+import '../third_party/holloway_core/api/functions/metasearch.dart';
+import '../third_party/holloway_core/api/functions/mirror.dart';
+import '../third_party/holloway_core/api/functions/page_diff.dart';
+import '../third_party/holloway_core/api/functions/page_summary.dart';
+import '../third_party/holloway_core/api/functions/peek.dart';
+import '../third_party/holloway_core/api/functions/reading_stats.dart';
+import '../third_party/holloway_core/api/functions/reflow.dart';
+import '../third_party/holloway_core/api/functions/request_trace.dart';
+import '../third_party/holloway_core/api/functions/scheduler.dart';
+import '../third_party/holloway_core/api/functions/search.dart';
+import '../third_party/holloway_core/api/functions/shutdown.dart';
+import '../third_party/holloway_core/api/functions/site_map.dart';
+import '../third_party/holloway_core/api/functions/streaming.dart';
+import '../third_party/holloway_core/api/functions/suggestions.dart';
+import '../third_party/holloway_core/api/functions/text_width.dart';
+import '../third_party/holloway_core/api/functions/top_sites.dart';
+import '../third_party/holloway_core/api/models/certificate_error.dart';
+import '../third_party/holloway_core/api/models/error_info.dart';
+import '../third_party/holloway_core/api/models/fetch_options.dart';
+import '../third_party/holloway_core/api/models/fetch_response.dart';
+import '../third_party/holloway_core/api/models/navigation_event.dart';
+import '../third_party/holloway_core/api/models/navigation_result.dart';
+import '../third_party/holloway_core/api/models/server_failure.dart';
+import '../third_party/holloway_core/api/models/stream_event.dart';
+import '../third_party/holloway_core/api/models/tls_failure.dart';
+import '../third_party/holloway_core/api/parsers/gophermap.dart';
+import '../third_party/holloway_core/api/protocols/cso.dart';
+import '../third_party/holloway_core/api/protocols/registry.dart';
+import '../third_party/holloway_core/api/storage/archive.dart';
+import '../third_party/holloway_core/api/storage/blocklist.dart';
+import '../third_party/holloway_core/api/storage/bookmarks.dart';
+import '../third_party/holloway_core/api/storage/cache.dart';
+import '../third_party/holloway_core/api/storage/clear.dart';
+import '../third_party/holloway_core/api/storage/data_usage.dart';
+import '../third_party/holloway_core/api/storage/downloads.dart';
+import '../third_party/holloway_core/api/storage/feeds.dart';
+import '../third_party/holloway_core/api/storage/gemini_proxies.dart';
+import '../third_party/holloway_core/api/storage/history.dart';
+import '../third_party/holloway_core/api/storage/host_settings.dart';
+import '../third_party/holloway_core/api/storage/host_stats.dart';
+import '../third_party/holloway_core/api/storage/identities.dart';
+import '../third_party/holloway_core/api/storage/identity_scopes.dart';
+import '../third_party/holloway_core/api/storage/input_answers.dart';
+import '../third_party/holloway_core/api/storage/known_hosts.dart';
+import '../third_party/holloway_core/api/storage/page_index.dart';
+import '../third_party/holloway_core/api/storage/plan_watches.dart';
+import '../third_party/holloway_core/api/storage/ports.dart';
+import '../third_party/holloway_core/api/storage/read_later.dart';
+import '../third_party/holloway_core/api/storage/sessions.dart';
+import '../third_party/holloway_core/api/storage/trusted_cas.dart';
+import '../third_party/holloway_core/api/storage/watches.dart';
+import '../third_party/holloway_core/api/transport/limits.dart';
+import '../third_party/holloway_core/api/transport/proxy.dart';
+import '../third_party/holloway_core/api/transport/rate_limit.dart';
+import 'mirrors.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-String greet({required String name}) =>
-    RustLib.instance.api.crateApiExposedFunctionsGreet(name: name);
 
-/// Navigate to a Gemini, Gopher, or Finger URL and return the plaintext content
-Future<String> navigate({required String url}) =>
-    RustLib.instance.api.crateApiExposedFunctionsNavigate(url: url);
+            
 
-/// Get the default start page URL
-String getStartPage() =>
-    RustLib.instance.api.crateApiExposedFunctionsGetStartPage();
+            String  greet({required String name }) => RustLib.instance.api.crateApiExposedFunctionsGreet(name: name);
+
+/// Navigate to a URL and return the content with its final URL, protocol, status, MIME type, charset, duration, and certificate summary;
+/// a fresh cached copy is served unless `force_refresh` is set, e.g. by the reload button
+Future<NavigationResult>  navigate({required String url , required bool forceRefresh }) => RustLib.instance.api.crateApiExposedFunctionsNavigate(url: url, forceRefresh: forceRefresh);
+
+/// Navigate like `navigate` with per-request options, e.g. connect and read timeouts and a deadline for the whole request
+Future<NavigationResult>  navigateWithOptions({required String url , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsNavigateWithOptions(url: url, options: options);
+
+/// Reserve a request id for `navigate_cancellable`, so the navigation can be stopped while it runs
+BigInt  reserveRequestId() => RustLib.instance.api.crateApiExposedFunctionsReserveRequestId();
+
+/// Navigate like `navigate` under a request id from `reserve_request_id`
+Future<NavigationResult>  navigateCancellable({required String url , required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsNavigateCancellable(url: url, requestId: requestId);
+
+/// Stop a navigation or download by its request id (reserved, or from `begin_fetch`), closing its connection at once; returns whether it was still running
+bool  cancelRequest({required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsCancelRequest(requestId: requestId);
+
+/// Navigate to a URL and return the content along with page metadata such as discovered feeds
+Future<FetchResponse>  fetch({required String url }) => RustLib.instance.api.crateApiExposedFunctionsFetch(url: url);
+
+/// Learn a URL's status, MIME type, and (for small bodies) size without downloading it, e.g. for link previews or choosing between opening and saving
+Future<PeekResult>  peek({required String url }) => RustLib.instance.api.crateApiExposedFunctionsPeek(url: url);
+
+/// Summarize a page for a share sheet or bookmark preview: its first heading, first paragraph, and the capsule's favicon, from the cache when fresh
+Future<PageSummary>  pageSummary({required String url }) => RustLib.instance.api.crateApiExposedFunctionsPageSummary(url: url);
+
+/// Start streaming a Gemini URL line by line (for endless endpoints such as chat logs, or long gemtext documents, whose lines arrive parsed so the first screenful renders early) and return its handle
+Future<BigInt>  openStream({required String url , required StreamOptions options }) => RustLib.instance.api.crateApiExposedFunctionsOpenStream(url: url, options: options);
+
+/// Wait for the next header, line, idle, or end event of a stream
+Future<StreamEvent>  nextStreamEvent({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsNextStreamEvent(handle: handle);
+
+/// Stop a stream, returning whether it was still running
+bool  cancelStream({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCancelStream(handle: handle);
+
+/// Start navigating a list of URLs with at most max_concurrent at a time, e.g. to check which bookmarks still answer, and return its handle
+BigInt  navigateMany({required List<String> urls , required FetchOptions options , required int maxConcurrent }) => RustLib.instance.api.crateApiExposedFunctionsNavigateMany(urls: urls, options: options, maxConcurrent: maxConcurrent);
+
+/// Wait for the next URL of a batch to finish; None once all of them have been reported
+Future<BatchItem?>  nextBatchResult({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsNextBatchResult(handle: handle);
+
+/// Stop a batch, cancelling its running navigations; returns whether it was still running
+bool  cancelBatch({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCancelBatch(handle: handle);
+
+/// Navigate with per-request options, e.g. `private` for incognito tabs that must leave no history
+Future<FetchResponse>  fetchWithOptions({required String url , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsFetchWithOptions(url: url, options: options);
+
+/// Start a navigation in the background, returning its request id; use `next_fetch_update`
+/// to show the header and progress before the body is complete, then `finish_fetch`
+Future<BigInt>  beginFetch({required String url , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsBeginFetch(url: url, options: options);
+
+/// Wait for the next header, progress, or completion event of a background navigation; None after completion
+Future<NavigationEvent?>  nextFetchUpdate({required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsNextFetchUpdate(requestId: requestId);
+
+/// Wait for a background navigation to complete and return its response
+Future<FetchResponse>  finishFetch({required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsFinishFetch(requestId: requestId);
+
+/// Subscribe to navigation events (started, resolving, connecting, TLS handshake, header, progress, finished, failed) of every fetch and stream
+BigInt  subscribeEvents() => RustLib.instance.api.crateApiExposedFunctionsSubscribeEvents();
+
+/// Subscribe to the navigation events of one request, e.g. one reserved with `reserve_request_id`, for a progress indicator; ends after it finishes or fails
+BigInt  subscribeRequestEvents({required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsSubscribeRequestEvents(requestId: requestId);
+
+/// Wait for the next navigation event of a subscription
+Future<NavigationEvent>  nextNavigationEvent({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsNextNavigationEvent(subscription: subscription);
+
+/// The ordered steps (cache, connection, TLS, response, parsing) of a recent request by its request id, for bug reports; None once it is no longer kept
+List<TraceEvent>?  getTrace({required BigInt requestId }) => RustLib.instance.api.crateApiExposedFunctionsGetTrace(requestId: requestId);
+
+/// End a navigation event subscription, returning whether it existed
+bool  unsubscribeEvents({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsUnsubscribeEvents(subscription: subscription);
+
+/// Subscribe to log records (requests, TLS, redirects, failures) for a debug console
+BigInt  subscribeLogs() => RustLib.instance.api.crateApiExposedFunctionsSubscribeLogs();
+
+/// Wait for the next log record of a subscription
+Future<LogRecord>  nextLogRecord({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsNextLogRecord(subscription: subscription);
+
+/// End a log subscription, returning whether it existed
+bool  unsubscribeLogs({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsUnsubscribeLogs(subscription: subscription);
+
+/// The most recent log records of at least a level still kept in memory, oldest first
+List<LogRecord>  recentLogs({required LogLevel minLevel , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsRecentLogs(minLevel: minLevel, limit: limit);
+
+/// The least severe level that is logged; Info by default, Debug adds every step of every request
+LogLevel  getLogLevel() => RustLib.instance.api.crateApiExposedFunctionsGetLogLevel();
+
+/// Set the least severe level that is logged
+void  setLogLevel({required LogLevel level }) => RustLib.instance.api.crateApiExposedFunctionsSetLogLevel(level: level);
+
+/// Also write log records to rotating files in a directory, or stop with None
+void  setLogDirectory({String? dir }) => RustLib.instance.api.crateApiExposedFunctionsSetLogDirectory(dir: dir);
+
+/// Paths of the log files, oldest first, for attaching to a bug report
+List<String>  logFiles() => RustLib.instance.api.crateApiExposedFunctionsLogFiles();
+
+/// The stable code (e.g. GEM_TIMEOUT / 704) and recovery hints of an error a navigation failed with
+ErrorInfo  navigateErrorInfo({required NavigateError error }) => RustLib.instance.api.crateApiExposedFunctionsNavigateErrorInfo(error: error);
+
+/// The recovery action a Gemini status asks for: input (1x), retry (4x), or an identity (6x)
+RecoveryAction?  suggestedActionForStatus({required int status }) => RustLib.instance.api.crateApiExposedFunctionsSuggestedActionForStatus(status: status);
+
+/// List the supported protocols with their default ports and capabilities
+List<ProtocolInfo>  getProtocols() => RustLib.instance.api.crateApiExposedFunctionsGetProtocols();
+
+/// Parse a gemtext body and wrap its text, quote, and list lines for reader mode
+List<ReflowedLine>  reflowGemtext({required String body , required int maxCols }) => RustLib.instance.api.crateApiExposedFunctionsReflowGemtext(body: body, maxCols: maxCols);
+
+/// Rewrap plain text laid out for an 80-column terminal (Gopher text, finger output) to max_cols, keeping ASCII art, tables, and short lines as they are
+String  reflowText({required String text , required int maxCols }) => RustLib.instance.api.crateApiExposedFunctionsReflowText(text: text, maxCols: maxCols);
+
+/// How to present content of a MIME type from a URL (the same decision as `FetchResponse.renderer`), e.g. before following a link
+Renderer  routeContent({required String url , required String mime }) => RustLib.instance.api.crateApiExposedFunctionsRouteContent(url: url, mime: mime);
+
+/// Parse a Gopher menu into items with their kind, icon, and whether they can be opened
+List<GopherItem>  parseGopherMenu({required String body }) => RustLib.instance.api.crateApiExposedFunctionsParseGopherMenu(body: body);
+
+/// Every link of a gemtext page or Gopher menu, resolved against its URL and classified, for prefetching, "open all links", and link hints
+List<PageLink>  extractLinks({required String url , required String body , required String mime }) => RustLib.instance.api.crateApiExposedFunctionsExtractLinks(url: url, body: body, mime: mime);
+
+/// Resolve a link target against the URL of its page (RFC 3986), keeping the item type layout of Gopher URLs
+String  resolveLink({required String baseUrl , required String href }) => RustLib.instance.api.crateApiExposedFunctionsResolveLink(baseUrl: baseUrl, href: href);
+
+/// Look up entries in the CSO phone book of a gopher type-2 item URL, returning them as field records
+Future<List<CsoRecord>>  csoQuery({required String url , required String query }) => RustLib.instance.api.crateApiExposedFunctionsCsoQuery(url: url, query: query);
+
+/// Render a gemtext page as standalone HTML with its links made absolute, for sharing or archiving it outside the app
+String  renderToHtml({required String gemtext , required String baseUrl }) => RustLib.instance.api.crateApiExposedFunctionsRenderToHtml(gemtext: gemtext, baseUrl: baseUrl);
+
+/// Render a gemtext page, or a Gopher menu when the URL or MIME type says so, as CommonMark with its links made absolute, for Markdown-based note tools
+String  renderToMarkdown({required String body , required String baseUrl , required String mime }) => RustLib.instance.api.crateApiExposedFunctionsRenderToMarkdown(body: body, baseUrl: baseUrl, mime: mime);
+
+/// Render a Gopher menu as gemtext: information lines as text and items as links to their gopher:// URLs, for showing or exporting menus like gemtext pages
+String  gophermapToGemtext({required String body }) => RustLib.instance.api.crateApiExposedFunctionsGophermapToGemtext(body: body);
+
+/// Keep a Gopher menu on the Rust side for paged access, returning a handle for `get_menu_page`;
+/// use this instead of `parse_gopher_menu` for menus with thousands of items
+BigInt  openMenu({required String body }) => RustLib.instance.api.crateApiExposedFunctionsOpenMenu(body: body);
+
+/// Up to `count` items of a retained Gopher menu starting at `offset`, with the menu's total item count
+MenuPage  getMenuPage({required BigInt handle , required int offset , required int count }) => RustLib.instance.api.crateApiExposedFunctionsGetMenuPage(handle: handle, offset: offset, count: count);
+
+/// Release a menu retained by `open_menu`
+bool  closeMenu({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCloseMenu(handle: handle);
+
+/// Parse a gemtext body and keep it on the Rust side, returning a handle for `get_lines`;
+/// lets a virtualized list view fetch only visible lines of very large documents
+BigInt  openDocument({required String body }) => RustLib.instance.api.crateApiExposedFunctionsOpenDocument(body: body);
+
+/// Number of lines in a document retained by `open_document`
+int  getLineCount({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsGetLineCount(handle: handle);
+
+/// Up to `count` lines of a retained document starting at `start`
+List<GemtextLine>  getLines({required BigInt handle , required int start , required int count }) => RustLib.instance.api.crateApiExposedFunctionsGetLines(handle: handle, start: start, count: count);
+
+/// Release a document retained by `open_document`
+bool  closeDocument({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCloseDocument(handle: handle);
+
+/// The headings of a document retained by `open_document` as an outline (level, text, line index, nesting) for a jump-to-section sidebar
+List<OutlineEntry>  documentOutline({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsDocumentOutline(handle: handle);
+
+/// The headings of a gemtext body as an outline, like `document_outline`
+List<OutlineEntry>  gemtextOutline({required String body }) => RustLib.instance.api.crateApiExposedFunctionsGemtextOutline(body: body);
+
+/// Word count, estimated reading time, and link and heading counts of a document retained by `open_document`
+ReadingStats  documentStats({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsDocumentStats(handle: handle);
+
+/// Reading statistics of a gemtext body, like `document_stats`
+ReadingStats  gemtextStats({required String body }) => RustLib.instance.api.crateApiExposedFunctionsGemtextStats(body: body);
+
+/// Fetch the same-host images a document retained by `open_document` links to, keyed by link index, for an inline-images mode; each is capped at `max_bytes` (1 MiB by default)
+Future<InlineImages>  fetchDocumentImages({required BigInt handle , required String pageUrl , int? maxBytes }) => RustLib.instance.api.crateApiExposedFunctionsFetchDocumentImages(handle: handle, pageUrl: pageUrl, maxBytes: maxBytes);
+
+/// Download a Gempub e-book (.gpub) and open it, returning its handle, metadata and chapters in reading order
+Future<GempubBook>  openGempub({required String url }) => RustLib.instance.api.crateApiExposedFunctionsOpenGempub(url: url);
+
+/// Open a Gempub e-book from the bytes of a .gpub file, e.g. one already downloaded
+GempubBook  openGempubData({required List<int> data }) => RustLib.instance.api.crateApiExposedFunctionsOpenGempubData(data: data);
+
+/// A chapter of a book opened by `open_gempub`, with the indexes of the previous and next chapters
+GempubPage  gempubChapter({required BigInt handle , required int chapter }) => RustLib.instance.api.crateApiExposedFunctionsGempubChapter(handle: handle, chapter: chapter);
+
+/// The file of a book opened by `open_gempub` that a link on the page at `from_path` leads to, such as another chapter or an image
+GempubPage  gempubFile({required BigInt handle , String? fromPath , required String link }) => RustLib.instance.api.crateApiExposedFunctionsGempubFile(handle: handle, fromPath: fromPath, link: link);
+
+/// Release a book opened by `open_gempub`
+bool  closeGempub({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCloseGempub(handle: handle);
+
+/// Measure the display width of each preformatted block in a gemtext body
+List<PreformattedBlock>  measurePreformatted({required String body }) => RustLib.instance.api.crateApiExposedFunctionsMeasurePreformatted(body: body);
+
+/// Token spans of each preformatted block of a gemtext body whose alt text names a known language
+List<HighlightedBlock>  highlightPreformatted({required String body }) => RustLib.instance.api.crateApiExposedFunctionsHighlightPreformatted(body: body);
+
+/// Token spans of each line of code in a language given by name or extension; None if it has no grammar
+List<HighlightedLine>?  highlightCode({required String code , required String language }) => RustLib.instance.api.crateApiExposedFunctionsHighlightCode(code: code, language: language);
+
+/// Names of the languages preformatted blocks can be highlighted in
+List<String>  highlightLanguages() => RustLib.instance.api.crateApiExposedFunctionsHighlightLanguages();
+
+/// Remove ANSI escape sequences (terminal colors and cursor commands) from a Gopher or Finger body
+String  stripAnsiEscapes({required String body }) => RustLib.instance.api.crateApiExposedFunctionsStripAnsiEscapes(body: body);
+
+/// Remove ANSI escape sequences from a body, returning the colors and attributes they set as styled spans
+StyledText  styleAnsiText({required String body }) => RustLib.instance.api.crateApiExposedFunctionsStyleAnsiText(body: body);
+
+/// Compute paragraph direction and bidi level runs for the right-to-left lines of a gemtext body
+List<LineDirection>  analyzeTextDirection({required String body }) => RustLib.instance.api.crateApiExposedFunctionsAnalyzeTextDirection(body: body);
+
+/// Recognize a file type (MIME and extension) from the leading bytes of its content
+FileType?  detectFileType({required List<int> content }) => RustLib.instance.api.crateApiExposedFunctionsDetectFileType(content: content);
+
+/// How to show the host of a URL in the address bar, flagging likely spoofed (homograph) hosts
+DisplayHost  displayHost({required String url }) => RustLib.instance.api.crateApiExposedFunctionsDisplayHost(url: url);
+
+/// A URL with its host in Unicode for display, or in punycode when the host may be spoofed
+String  displayUrl({required String url }) => RustLib.instance.api.crateApiExposedFunctionsDisplayUrl(url: url);
+
+/// Suggest a safe download file name for a URL and MIME type, numbered if it already exists in dest_dir
+String  suggestDownloadPath({required String url , required String mime , required String destDir }) => RustLib.instance.api.crateApiExposedFunctionsSuggestDownloadPath(url: url, mime: mime, destDir: destDir);
+
+/// The decompression ratio and size caps protecting against decompression bombs
+DecompressionLimits  getDecompressionLimits() => RustLib.instance.api.crateApiExposedFunctionsGetDecompressionLimits();
+
+/// Change the decompression ratio and size caps, e.g. lower them on devices short of memory
+void  setDecompressionLimits({required DecompressionLimits limits }) => RustLib.instance.api.crateApiExposedFunctionsSetDecompressionLimits(limits: limits);
+
+/// How many TLS handshakes background fetches such as prefetches may run at once
+int  getMaxConcurrentHandshakes() => RustLib.instance.api.crateApiExposedFunctionsGetMaxConcurrentHandshakes();
+
+/// Cap the TLS handshakes of background fetches, e.g. lower it on slow phones; fetches the user asked for are never held back
+void  setMaxConcurrentHandshakes({required int max }) => RustLib.instance.api.crateApiExposedFunctionsSetMaxConcurrentHandshakes(max: max);
+
+/// How many connections background fetches may keep open to one host and how long they wait between them
+RateLimits  getRateLimits() => RustLib.instance.api.crateApiExposedFunctionsGetRateLimits();
+
+/// Limit how hard prefetches and feed refreshes hit one host; fetches the user asked for are never held back but count against the host
+void  setRateLimits({required RateLimits limits }) => RustLib.instance.api.crateApiExposedFunctionsSetRateLimits(limits: limits);
+
+/// Tunnel all connections through a WebSocket gateway (required on the web), or pass None for direct sockets
+void  setGateway({String? urlTemplate }) => RustLib.instance.api.crateApiExposedFunctionsSetGateway(urlTemplate: urlTemplate);
+
+/// The active proxy settings
+ProxySettings  getProxySettings() => RustLib.instance.api.crateApiExposedFunctionsGetProxySettings();
+
+/// Validate, save to the profile, and activate proxy settings (direct, one SOCKS5 proxy, or per-host rules)
+void  setProxySettings({required ProxySettings settings }) => RustLib.instance.api.crateApiExposedFunctionsSetProxySettings(settings: settings);
+
+/// Probe a SOCKS5 proxy: connect, authenticate, and open a connection through it
+Future<ProxyProbe>  testProxy({required Socks5Proxy proxy }) => RustLib.instance.api.crateApiExposedFunctionsTestProxy(proxy: proxy);
+
+/// Open the profile database stored in the given app data directory
+void  openProfile({required String dir }) => RustLib.instance.api.crateApiExposedFunctionsOpenProfile(dir: dir);
+
+/// Fold the profile database's write-ahead log into the database file, e.g. when the app moves to the background
+void  checkpointProfile() => RustLib.instance.api.crateApiExposedFunctionsCheckpointProfile();
+
+/// Stop background work (streams, pending fetches, refresh and expiry loops) and close the profile database; call from the app's paused/detached lifecycle hooks and `open_profile` again on resume
+ShutdownSummary  shutdown() => RustLib.instance.api.crateApiExposedFunctionsShutdown();
+
+/// Block a host ("spam.example"), a domain with its subdomains ("*.example.org"), or a URL prefix
+BlockRule  addBlockRule({required String pattern }) => RustLib.instance.api.crateApiExposedFunctionsAddBlockRule(pattern: pattern);
+
+/// Remove a blocklist pattern, returning whether it was present
+bool  removeBlockRule({required String pattern }) => RustLib.instance.api.crateApiExposedFunctionsRemoveBlockRule(pattern: pattern);
+
+/// List all blocklist rules
+List<BlockRule>  listBlockRules() => RustLib.instance.api.crateApiExposedFunctionsListBlockRules();
+
+/// The most recent history entries, newest first
+List<HistoryEntry>  getRecentHistory({required int limit }) => RustLib.instance.api.crateApiExposedFunctionsGetRecentHistory(limit: limit);
+
+/// Visited pages with their visit counts, most recently visited first, `limit` at a time
+/// starting after `offset` pages
+List<HistoryResult>  getHistoryPage({required int offset , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsGetHistoryPage(offset: offset, limit: limit);
+
+/// Record a visit to a page the UI loaded without `navigate`
+void  addHistoryVisit({required String url , String? title }) => RustLib.instance.api.crateApiExposedFunctionsAddHistoryVisit(url: url, title: title);
+
+/// Delete one history entry, returning whether it existed
+bool  deleteHistoryEntry({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsDeleteHistoryEntry(id: id);
+
+/// Delete every visit to a page from history, returning how many were removed
+int  deleteHistoryUrl({required String url }) => RustLib.instance.api.crateApiExposedFunctionsDeleteHistoryUrl(url: url);
+
+/// Search history by title or URL within a time range, with one aggregated result per page
+List<HistoryResult>  searchHistory({required String query , required HistoryMatch mode , required HistoryRange range , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsSearchHistory(query: query, mode: mode, range: range, limit: limit);
+
+/// Address bar completions for typed text from history and bookmarks, ranked with titles and an inline completion for URL prefix matches
+List<Suggestion>  suggest({required String prefix , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsSuggest(prefix: prefix, limit: limit);
+
+/// Search the text of cached pages, best matches first, with a snippet around the matches of each
+List<LocalSearchResult>  searchLocal({required String query , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsSearchLocal(query: query, limit: limit);
+
+/// Crawl the capsule a URL belongs to (same host, under the URL's directory) through its internal links, up to `max_pages` pages, adding them to the local search index within robots.txt and the host rate limits
+Future<CrawlResult>  indexCapsule({required String url , required int maxPages }) => RustLib.instance.api.crateApiExposedFunctionsIndexCapsule(url: url, maxPages: maxPages);
+
+/// Stop running capsule crawls before their next page
+void  cancelCapsuleIndexing() => RustLib.instance.api.crateApiExposedFunctionsCancelCapsuleIndexing();
+
+/// Walk a gopher hole's menus from a URL up to `depth` levels (at most 8) within the host rate limits, returning its menus and documents as a flattened tree for a site map sidebar
+Future<SiteMap>  mapSite({required String url , required int depth }) => RustLib.instance.api.crateApiExposedFunctionsMapSite(url: url, depth: depth);
+
+/// Search the locally indexed pages of the capsule a URL belongs to, like `search_local`
+List<LocalSearchResult>  searchCapsule({required String url , required String query , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsSearchCapsule(url: url, query: query, limit: limit);
+
+/// Start saving a Gemini capsule's pages up to `depth` links deep, with the files they link to, under dest_dir with links rewritten to relative paths; resumes a stopped mirror of the same dest_dir and returns a handle
+BigInt  mirrorCapsule({required String url , required String destDir , required int depth }) => RustLib.instance.api.crateApiExposedFunctionsMirrorCapsule(url: url, destDir: destDir, depth: depth);
+
+/// Wait for the next file of a mirror to be saved or fail; None once the mirror is finished
+Future<MirrorProgress?>  nextMirrorProgress({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsNextMirrorProgress(handle: handle);
+
+/// Stop a mirror after its current file; mirroring to the same dest_dir again resumes it
+bool  cancelMirror({required BigInt handle }) => RustLib.instance.api.crateApiExposedFunctionsCancelMirror(handle: handle);
+
+/// The most frequently and recently visited pages, for the new-tab speed dial
+List<TopSite>  getTopSites({required int limit }) => RustLib.instance.api.crateApiExposedFunctionsGetTopSites(limit: limit);
+
+/// The most frequently and recently visited hosts, each linked to its root page
+List<TopSite>  getTopHosts({required int limit }) => RustLib.instance.api.crateApiExposedFunctionsGetTopHosts(limit: limit);
+
+/// Fetch and cache the /favicon.txt emoji of the capsule serving a page (not cached for private requests)
+Future<String?>  refreshFavicon({required String pageUrl , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsRefreshFavicon(pageUrl: pageUrl, options: options);
+
+/// List cached responses with their size, type, and staleness, optionally for one host only
+List<CacheEntry>  listCacheEntries({String? host }) => RustLib.instance.api.crateApiExposedFunctionsListCacheEntries(host: host);
+
+/// Remove one page from the cache, returning whether it was cached
+bool  evictCacheEntry({required String url }) => RustLib.instance.api.crateApiExposedFunctionsEvictCacheEntry(url: url);
+
+/// The cache policy (cacheability, TTL, query handling) of each registered scheme
+List<CachePolicy>  getCachePolicies() => RustLib.instance.api.crateApiExposedFunctionsGetCachePolicies();
+
+/// Override the cache policy of one scheme
+void  setCachePolicy({required CachePolicy policy }) => RustLib.instance.api.crateApiExposedFunctionsSetCachePolicy(policy: policy);
+
+/// Restore the built-in cache policy of a scheme
+void  resetCachePolicy({required String scheme }) => RustLib.instance.api.crateApiExposedFunctionsResetCachePolicy(scheme: scheme);
+
+/// Turn a protocol on or off; fetching a URL of a disabled scheme fails with a SchemeDisabled error
+void  setProtocolEnabled({required String scheme , required bool enabled }) => RustLib.instance.api.crateApiExposedFunctionsSetProtocolEnabled(scheme: scheme, enabled: enabled);
+
+/// Use a port for URLs of a scheme that omit one, or only for one host when host is given
+void  setPortOverride({required String scheme , String? host , required int port }) => RustLib.instance.api.crateApiExposedFunctionsSetPortOverride(scheme: scheme, host: host, port: port);
+
+/// Remove a scheme-wide (host None) or per-host port override, returning whether it existed
+bool  removePortOverride({required String scheme , String? host }) => RustLib.instance.api.crateApiExposedFunctionsRemovePortOverride(scheme: scheme, host: host);
+
+/// List the configured port overrides
+List<PortOverride>  listPortOverrides() => RustLib.instance.api.crateApiExposedFunctionsListPortOverrides();
+
+/// Send every URL of a scheme (e.g. http) as a full URL to a Gemini proxy, replacing the scheme's
+/// handler if it has one
+void  setGeminiProxy({required String scheme , required String host , required int port }) => RustLib.instance.api.crateApiExposedFunctionsSetGeminiProxy(scheme: scheme, host: host, port: port);
+
+/// Stop sending a scheme to its Gemini proxy or Gopher gateway, returning whether it had one
+bool  removeGeminiProxy({required String scheme }) => RustLib.instance.api.crateApiExposedFunctionsRemoveGeminiProxy(scheme: scheme);
+
+/// Send every URL of a scheme (e.g. https) to a Gopher gateway, asking for `selector` (item type and selector, e.g. "0/fetch?{url}") with the URL in place of {url} or appended
+void  setGopherGateway({required String scheme , required String host , required int port , required String selector }) => RustLib.instance.api.crateApiExposedFunctionsSetGopherGateway(scheme: scheme, host: host, port: port, selector: selector);
+
+/// List the configured Gemini proxies and Gopher gateways
+List<SchemeProxy>  listGeminiProxies() => RustLib.instance.api.crateApiExposedFunctionsListGeminiProxies();
+
+/// Overrides of a host (certificate policy, identity, timeouts, redirect policy, maximum body
+/// size, charset), if it has any
+HostSettings?  getHostSettings({required String host }) => RustLib.instance.api.crateApiExposedFunctionsGetHostSettings(host: host);
+
+/// Store the overrides of a host, replacing any it had
+void  setHostSettings({required HostSettings settings }) => RustLib.instance.api.crateApiExposedFunctionsSetHostSettings(settings: settings);
+
+/// Drop the overrides of a host, returning whether it had any
+bool  removeHostSettings({required String host }) => RustLib.instance.api.crateApiExposedFunctionsRemoveHostSettings(host: host);
+
+/// List every host with overrides
+List<HostSettings>  listHostSettings() => RustLib.instance.api.crateApiExposedFunctionsListHostSettings();
+
+/// Turn offline browsing on or off; while on, navigation serves the newest cached copy of a page,
+/// marked stale when past its expiry, and fails for pages never cached
+void  setOfflineMode({required bool enabled }) => RustLib.instance.api.crateApiExposedFunctionsSetOfflineMode(enabled: enabled);
+
+/// Whether offline browsing is on
+bool  isOfflineMode() => RustLib.instance.api.crateApiExposedFunctionsIsOfflineMode();
+
+/// Clear browsing data recorded since the given Unix time (None for everything);
+/// an empty category list clears the defaults, which exclude identities
+List<ClearedData>  clearData({required List<DataCategory> categories , PlatformInt64? since }) => RustLib.instance.api.crateApiExposedFunctionsClearData(categories: categories, since: since);
+
+/// Save a URL of any protocol to dest_path, streaming it to disk instead of memory, and return its size, MIME type, and SHA-256
+Future<DownloadResult>  download({required String url , required String destPath , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsDownload(url: url, destPath: destPath, options: options);
+
+/// The checksum file (file.sha256 or a SHA256SUMS list) a page links alongside a file it links, to pass to download_verified
+String?  checksumLink({required String pageUrl , required String mime , required String body , required String fileUrl }) => RustLib.instance.api.crateApiExposedFunctionsChecksumLink(pageUrl: pageUrl, mime: mime, body: body, fileUrl: fileUrl);
+
+/// Download a URL like download, then check the file against a checksum file, reporting whether it was verified in the result and the download history
+Future<DownloadResult>  downloadVerified({required String url , required String destPath , String? checksumUrl , required FetchOptions options }) => RustLib.instance.api.crateApiExposedFunctionsDownloadVerified(url: url, destPath: destPath, checksumUrl: checksumUrl, options: options);
+
+/// Record a finished download in the profile's download history, returning its id
+PlatformInt64  recordDownload({required String url , required String path , required BigInt size , required String mime , required DownloadStatus status , String? error }) => RustLib.instance.api.crateApiExposedFunctionsRecordDownload(url: url, path: path, size: size, mime: mime, status: status, error: error);
+
+/// Per-host request counts, traffic, average latency, and error rate, ranked by the chosen statistic
+List<HostStats>  getHostStats({required HostStatsOrder order , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsGetHostStats(order: order, limit: limit);
+
+/// Tell the core which network the device is on (Wi-Fi, cellular, ...) so traffic is attributed to it
+void  setNetworkType({required NetworkType network }) => RustLib.instance.api.crateApiExposedFunctionsSetNetworkType(network: network);
+
+/// Bytes sent and received per day and network type for the days in the given Unix time range
+List<DailyUsage>  getDataUsage({required PlatformInt64 since , required PlatformInt64 until }) => RustLib.instance.api.crateApiExposedFunctionsGetDataUsage(since: since, until: until);
+
+/// Set (or remove with None) a soft cap on cellular traffic over the last 30 days; prefetching stops once it is exceeded
+void  setDataCap({BigInt? limitBytes }) => RustLib.instance.api.crateApiExposedFunctionsSetDataCap(limitBytes: limitBytes);
+
+/// The cellular soft cap and how much of it has been used
+DataCapStatus  getDataCapStatus() => RustLib.instance.api.crateApiExposedFunctionsGetDataCapStatus();
+
+/// Whether background and speculative fetches should run right now
+bool  prefetchAllowed() => RustLib.instance.api.crateApiExposedFunctionsPrefetchAllowed();
+
+/// Whether lite mode is on
+bool  getLiteMode() => RustLib.instance.api.crateApiExposedFunctionsGetLiteMode();
+
+/// Turn lite mode on or off; in lite mode links are not prefetched and inline images are not fetched
+void  setLiteMode({required bool enabled }) => RustLib.instance.api.crateApiExposedFunctionsSetLiteMode(enabled: enabled);
+
+/// The limit on bytes received per second over all connections, None when unlimited
+BigInt?  getBandwidthLimit() => RustLib.instance.api.crateApiExposedFunctionsGetBandwidthLimit();
+
+/// Limit how fast all connections together receive (at least 1024 bytes per second), or pass None for no limit; applies to connections opened afterwards
+void  setBandwidthLimit({BigInt? bytesPerSec }) => RustLib.instance.api.crateApiExposedFunctionsSetBandwidthLimit(bytesPerSec: bytesPerSec);
+
+/// Fetch and cache the links of the current page at low priority so following one is instant, returning how many were fetched; a later call drops this one's queued fetches
+Future<int>  prefetch({required List<String> urls }) => RustLib.instance.api.crateApiExposedFunctionsPrefetch(urls: urls);
+
+/// Connect to a Gemini link's server and complete the TLS handshake ahead of time, e.g. on hover or long-press, so following the link within ten seconds skips DNS, TCP, and TLS; other schemes are ignored
+Future<void>  preconnect({required String url }) => RustLib.instance.api.crateApiExposedFunctionsPreconnect(url: url);
+
+/// Drop the link prefetches not yet started, e.g. when the user leaves the page
+void  cancelPrefetch() => RustLib.instance.api.crateApiExposedFunctionsCancelPrefetch();
+
+/// Every server whose certificate is pinned, with its fingerprint and when it was first and last seen
+List<KnownHost>  listKnownHosts() => RustLib.instance.api.crateApiExposedFunctionsListKnownHosts();
+
+/// Export the trusted server certificates as Holloway's known_hosts file, Amfora's tofu.toml or Lagrange's trusted.2.txt
+String  exportKnownHosts({required KnownHostsFormat format }) => RustLib.instance.api.crateApiExposedFunctionsExportKnownHosts(format: format);
+
+/// Import trusted server certificates from a known_hosts file, keeping hosts that are already pinned
+KnownHostsImport  importKnownHosts({required KnownHostsFormat format , required String data }) => RustLib.instance.api.crateApiExposedFunctionsImportKnownHosts(format: format, data: data);
+
+/// Forget the pinned certificate of a server so its next certificate is trusted (the exception for TLS_FPR_MISMATCH)
+bool  forgetKnownHost({required String host , required int port }) => RustLib.instance.api.crateApiExposedFunctionsForgetKnownHost(host: host, port: port);
+
+/// Trust the certificate a host presented instead of its pinned one (a "Certificate changed" error), e.g. a renewal; returns how many of its ports were re-pinned
+int  acceptNewCertificate({required String host }) => RustLib.instance.api.crateApiExposedFunctionsAcceptNewCertificate(host: host);
+
+/// End the private session once the last private tab closes, forgetting the certificates its navigations pinned in memory; returns how many were pinned
+int  endPrivateSession() => RustLib.instance.api.crateApiExposedFunctionsEndPrivateSession();
+
+/// Why strict validation refused a host's certificate (a "Certificate rejected by the trust store" error): expired, self-signed, for another host, or an incomplete chain
+TlsFailure?  getCertificateFailure({required String host }) => RustLib.instance.api.crateApiExposedFunctionsGetCertificateFailure(host: host);
+
+/// Add the CA certificates of a PEM file to the user trust store that hosts with the strict TLS policy are verified against
+List<TrustedCa>  importCaCertificates({required String pem }) => RustLib.instance.api.crateApiExposedFunctionsImportCaCertificates(pem: pem);
+
+/// Every certificate authority in the user trust store
+List<TrustedCa>  listTrustedCas() => RustLib.instance.api.crateApiExposedFunctionsListTrustedCas();
+
+/// Remove a certificate authority from the user trust store by its fingerprint, returning whether it was there
+bool  removeTrustedCa({required String fingerprint }) => RustLib.instance.api.crateApiExposedFunctionsRemoveTrustedCa(fingerprint: fingerprint);
+
+/// How many seconds outside its validity period a server certificate may appear before it is refused
+BigInt  getClockSkewTolerance() => RustLib.instance.api.crateApiExposedFunctionsGetClockSkewTolerance();
+
+/// Change the clock skew tolerance for devices with unreliable clocks; 0 refuses every outdated certificate
+void  setClockSkewTolerance({required BigInt secs }) => RustLib.instance.api.crateApiExposedFunctionsSetClockSkewTolerance(secs: secs);
+
+/// How far the device clock appears to be off, inferred from the certificates of the servers visited
+ClockOffset  getClockOffset() => RustLib.instance.api.crateApiExposedFunctionsGetClockOffset();
+
+/// Pick a random page for "I'm feeling lucky" from weighted sources (directory pages and bookmarks), using cached directories when offline
+Future<RandomDestination>  randomDestination({required List<ExploreSource> sources }) => RustLib.instance.api.crateApiExposedFunctionsRandomDestination(sources: sources);
+
+/// Bookmark a URL; if an equivalent URL is already bookmarked, that bookmark is returned marked as a duplicate instead
+AddedBookmark  addBookmark({required String url , String? title }) => RustLib.instance.api.crateApiExposedFunctionsAddBookmark(url: url, title: title);
+
+/// The existing bookmark of a URL differing only in trailing slash, default port, or percent-encoding
+Bookmark?  findDuplicateBookmark({required String url }) => RustLib.instance.api.crateApiExposedFunctionsFindDuplicateBookmark(url: url);
+
+/// Change the title of a bookmark, e.g. when merging a duplicate into it
+bool  setBookmarkTitle({required PlatformInt64 id , String? title }) => RustLib.instance.api.crateApiExposedFunctionsSetBookmarkTitle(id: id, title: title);
+
+/// Canonical form of an address as typed (scheme guessed, host lowercased, default port and fragment dropped, dot segments resolved), as history and the cache key pages
+String  normalizeUrl({required String input }) => RustLib.instance.api.crateApiExposedFunctionsNormalizeUrl(input: input);
+
+/// Index of the open tab already showing the same page as url, so the UI can switch to it instead of opening another
+int?  findDuplicateTab({required String url , required List<String> openTabs }) => RustLib.instance.api.crateApiExposedFunctionsFindDuplicateTab(url: url, openTabs: openTabs);
+
+/// Save the open tabs with their back/forward stacks and scroll positions under a name, replacing an earlier save; private tabs are left out. Save under "current" as tabs change to resume after a restart or crash
+void  saveSession({required Session session }) => RustLib.instance.api.crateApiExposedFunctionsSaveSession(session: session);
+
+/// A saved session, e.g. "current" on startup to reopen the tabs the app had
+Session?  loadSession({required String name }) => RustLib.instance.api.crateApiExposedFunctionsLoadSession(name: name);
+
+/// Record the scroll position of a saved tab's current page without saving the whole session, returning whether the tab exists
+bool  setSessionScrollOffset({required String name , required int tab , required double scrollOffset }) => RustLib.instance.api.crateApiExposedFunctionsSetSessionScrollOffset(name: name, tab: tab, scrollOffset: scrollOffset);
+
+/// Every saved session with its tab count, most recently saved first
+List<SessionSummary>  listSessions() => RustLib.instance.api.crateApiExposedFunctionsListSessions();
+
+/// Delete a saved session, returning whether it existed
+bool  deleteSession({required String name }) => RustLib.instance.api.crateApiExposedFunctionsDeleteSession(name: name);
+
+/// Every bookmark, oldest first
+List<Bookmark>  listBookmarks() => RustLib.instance.api.crateApiExposedFunctionsListBookmarks();
+
+/// Delete a bookmark, returning whether it existed
+bool  removeBookmark({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsRemoveBookmark(id: id);
+
+/// Serialize every bookmark as XBEL, a gemtext page, or a Netscape HTML bookmarks file
+String  exportBookmarks({required BookmarkFormat format }) => RustLib.instance.api.crateApiExposedFunctionsExportBookmarks(format: format);
+
+/// Write every bookmark to a file in one of the export formats, returning how many were written
+int  exportBookmarksToFile({required BookmarkFormat format , required String path }) => RustLib.instance.api.crateApiExposedFunctionsExportBookmarksToFile(format: format, path: path);
+
+/// Import bookmarks from Amfora's bookmarks.xml or Lagrange's bookmarks.ini, keeping URLs that are already bookmarked
+BookmarksImport  importBookmarks({required BookmarkSource source , required String data }) => RustLib.instance.api.crateApiExposedFunctionsImportBookmarks(source: source, data: data);
+
+/// Subscribe to an Atom, RSS, or gemfeed URL, e.g. one of a page's discovered feeds; an existing subscription of the URL is returned as is
+Feed  subscribeFeed({required String url , String? title , required FeedKind kind }) => RustLib.instance.api.crateApiExposedFunctionsSubscribeFeed(url: url, title: title, kind: kind);
+
+/// Drop a feed subscription and its entries, returning whether it existed
+bool  unsubscribeFeed({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsUnsubscribeFeed(id: id);
+
+/// Every feed subscription with its unread count, by title
+List<Feed>  listFeeds() => RustLib.instance.api.crateApiExposedFunctionsListFeeds();
+
+/// Fetch one feed and store its new entries, returning how many there were
+Future<int>  refreshFeed({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsRefreshFeed(id: id);
+
+/// Fetch every feed, returning how many new entries they had; failures are kept on each feed as last_error
+Future<int>  refreshAllFeeds() => RustLib.instance.api.crateApiExposedFunctionsRefreshAllFeeds();
+
+/// A page of the merged timeline of every feed, or of one, newest first, for the Feeds tab
+List<FeedEntry>  listFeedEntries({PlatformInt64? feedId , required bool unreadOnly , required int offset , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsListFeedEntries(feedId: feedId, unreadOnly: unreadOnly, offset: offset, limit: limit);
+
+/// Mark a feed entry read or unread, returning whether it exists
+bool  setFeedEntryRead({required PlatformInt64 id , required bool read }) => RustLib.instance.api.crateApiExposedFunctionsSetFeedEntryRead(id: id, read: read);
+
+/// Mark every entry of a feed, or of all feeds when feed_id is None, read
+int  markAllFeedEntriesRead({PlatformInt64? feedId }) => RustLib.instance.api.crateApiExposedFunctionsMarkAllFeedEntriesRead(feedId: feedId);
+
+/// Number of unread feed entries, for the Feeds tab badge
+int  unreadFeedCount() => RustLib.instance.api.crateApiExposedFunctionsUnreadFeedCount();
+
+/// Watch a page for changes, e.g. a capsule without a feed; returns the existing watch of the same page
+WatchedPage  watchPage({required String url , String? title }) => RustLib.instance.api.crateApiExposedFunctionsWatchPage(url: url, title: title);
+
+/// Stop watching a page, returning whether it was watched
+bool  unwatchPage({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsUnwatchPage(id: id);
+
+/// Every watched page, changed ones first
+List<WatchedPage>  listWatchedPages() => RustLib.instance.api.crateApiExposedFunctionsListWatchedPages();
+
+/// Refetch watched pages not checked in the last 15 minutes and return those changed since they were last visited
+Future<List<WatchedPage>>  checkForUpdates() => RustLib.instance.api.crateApiExposedFunctionsCheckForUpdates();
+
+/// Watch a finger address, `user@host` or a finger:// URL, for changes to its .plan and .project; returns the existing watch of the same address
+WatchedPlan  watchPlan({required String address }) => RustLib.instance.api.crateApiExposedFunctionsWatchPlan(address: address);
+
+/// Stop watching a finger address, returning whether it was watched
+bool  unwatchPlan({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsUnwatchPlan(id: id);
+
+/// Every watched finger address with its last answer, updated ones first
+List<WatchedPlan>  listWatchedPlans() => RustLib.instance.api.crateApiExposedFunctionsListWatchedPlans();
+
+/// Mark the .plan update of a watched finger address as read
+void  markPlanRead({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsMarkPlanRead(id: id);
+
+/// Finger watched addresses not checked in the last 15 minutes and return those whose plans changed since they were last read
+Future<List<WatchedPlan>>  checkPlans() => RustLib.instance.api.crateApiExposedFunctionsCheckPlans();
+
+/// Refetch a page and return the lines added and removed since its cached copy
+Future<PageDiff>  diffWithCache({required String url }) => RustLib.instance.api.crateApiExposedFunctionsDiffWithCache(url: url);
+
+/// Bookmarked hosts whose pages permanently redirect (31) to another host, for offering a migration
+List<HostMigration>  suggestedHostMigrations() => RustLib.instance.api.crateApiExposedFunctionsSuggestedHostMigrations();
+
+/// Rewrite the bookmarks on a host that moved to its new host
+HostMigrationResult  migrateHost({required String scheme , required String fromHost , required String toHost }) => RustLib.instance.api.crateApiExposedFunctionsMigrateHost(scheme: scheme, fromHost: fromHost, toHost: toHost);
+
+/// Stop suggesting a migration away from a host
+bool  dismissHostMigration({required String scheme , required String fromHost }) => RustLib.instance.api.crateApiExposedFunctionsDismissHostMigration(scheme: scheme, fromHost: fromHost);
+
+/// Refresh the cached copies of bookmarked pages older than max_age_secs now, returning how many were refetched
+Future<int>  refreshBookmarks({required int maxAgeSecs }) => RustLib.instance.api.crateApiExposedFunctionsRefreshBookmarks(maxAgeSecs: maxAgeSecs);
+
+/// Refresh bookmarked pages in the background every interval_secs while on Wi-Fi or Ethernet, for offline reading
+void  startBookmarkRefresh({required int intervalSecs , required int maxAgeSecs }) => RustLib.instance.api.crateApiExposedFunctionsStartBookmarkRefresh(intervalSecs: intervalSecs, maxAgeSecs: maxAgeSecs);
+
+/// Stop the background bookmark refresh
+void  stopBookmarkRefresh() => RustLib.instance.api.crateApiExposedFunctionsStopBookmarkRefresh();
+
+/// The schedule of every background task (feed refreshes, page and plan watches, pinned certificate expiry scans)
+List<TaskSchedule>  getSchedules() => RustLib.instance.api.crateApiExposedFunctionsGetSchedules();
+
+/// Change a background task's interval, jitter, and network and battery constraints
+void  setSchedule({required TaskSchedule schedule }) => RustLib.instance.api.crateApiExposedFunctionsSetSchedule(schedule: schedule);
+
+/// Tell the core whether the device is charging, on battery, or low on battery, for the scheduler's constraints
+void  setPowerState({required PowerState state }) => RustLib.instance.api.crateApiExposedFunctionsSetPowerState(state: state);
+
+/// Run the background tasks that are due and allowed now, e.g. from the platform's background fetch, and return what happened to them
+Future<List<SchedulerEvent>>  schedulerTick() => RustLib.instance.api.crateApiExposedFunctionsSchedulerTick();
+
+/// Tick the scheduler every tick_secs on a background thread while the app runs
+void  startScheduler({required int tickSecs }) => RustLib.instance.api.crateApiExposedFunctionsStartScheduler(tickSecs: tickSecs);
+
+/// Stop the scheduler's background thread
+void  stopScheduler() => RustLib.instance.api.crateApiExposedFunctionsStopScheduler();
+
+/// Subscribe to the events of background tasks: started, completed with what they found, skipped, or failed
+BigInt  subscribeSchedulerEvents() => RustLib.instance.api.crateApiExposedFunctionsSubscribeSchedulerEvents();
+
+/// Wait for the next background task event of a subscription
+Future<SchedulerEvent>  nextSchedulerEvent({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsNextSchedulerEvent(subscription: subscription);
+
+/// End a background task event subscription, returning whether it existed
+bool  unsubscribeSchedulerEvents({required BigInt subscription }) => RustLib.instance.api.crateApiExposedFunctionsUnsubscribeSchedulerEvents(subscription: subscription);
+
+/// Recorded downloads, newest first; pass a status to show only completed or failed ones
+List<DownloadRecord>  listDownloads({DownloadStatus? status , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsListDownloads(status: status, limit: limit);
+
+/// Remove one entry from the download history without deleting the file
+bool  deleteDownload({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsDeleteDownload(id: id);
+
+/// Keep a permanent copy of a page (the on-screen copy when it is cached) with its metadata and fetch time
+Future<ArchivedPage>  archivePage({required String url }) => RustLib.instance.api.crateApiExposedFunctionsArchivePage(url: url);
+
+/// Archived page snapshots, newest first; pass a URL to list only the snapshots of that page
+List<ArchivedPage>  listArchived({String? url , required int limit }) => RustLib.instance.api.crateApiExposedFunctionsListArchived(url: url, limit: limit);
+
+/// Reopen an archived snapshot as a response served from the cache
+FetchResponse  openArchived({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsOpenArchived(id: id);
+
+/// Delete an archived snapshot
+bool  deleteArchived({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsDeleteArchived(id: id);
+
+/// Save a page to read later and fetch a copy of it right away for reading offline; a failed fetch leaves it queued with the error
+Future<ReadLaterItem>  saveForLater({required String url , String? title }) => RustLib.instance.api.crateApiExposedFunctionsSaveForLater(url: url, title: title);
+
+/// Fetch the read-later pages that have no copy yet as background requests; returns how many were saved
+Future<int>  cacheReadLater() => RustLib.instance.api.crateApiExposedFunctionsCacheReadLater();
+
+/// The read-later queue with titles and whether each page is cached, unread first and newest first
+List<ReadLaterItem>  listReadLater({required bool includeRead }) => RustLib.instance.api.crateApiExposedFunctionsListReadLater(includeRead: includeRead);
+
+/// Open the saved copy of a read-later page as a response served from the cache
+FetchResponse  openReadLater({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsOpenReadLater(id: id);
+
+/// Mark a read-later page read, or unread again
+bool  markReadLater({required PlatformInt64 id , required bool read }) => RustLib.instance.api.crateApiExposedFunctionsMarkReadLater(id: id, read: read);
+
+/// Remove a page and its saved copy from the read-later queue
+bool  removeReadLater({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsRemoveReadLater(id: id);
+
+/// List the stored client certificate identities
+List<Identity>  listIdentities() => RustLib.instance.api.crateApiExposedFunctionsListIdentities();
+
+/// Create an identity with a new key pair and a self-signed certificate for common_name, valid for valid_days; returns its id
+String  createIdentity({required String commonName , required int validDays }) => RustLib.instance.api.crateApiExposedFunctionsCreateIdentity(commonName: commonName, validDays: validDays);
+
+/// Issue a fresh certificate for an identity, keeping its key unless rotate_key is set
+Identity  renewIdentity({required String id , required bool rotateKey , int? validDays }) => RustLib.instance.api.crateApiExposedFunctionsRenewIdentity(id: id, rotateKey: rotateKey, validDays: validDays);
+
+/// Import a client certificate and PKCS#8 key from PEM; the key may also follow the certificate in certificate_pem
+Identity  importIdentityPem({String? name , required String certificatePem , String? privateKeyPem }) => RustLib.instance.api.crateApiExposedFunctionsImportIdentityPem(name: name, certificatePem: certificatePem, privateKeyPem: privateKeyPem);
+
+/// Import a client certificate from a password-protected .p12/.pfx archive
+Identity  importIdentityPkcs12({String? name , required List<int> data , required String password }) => RustLib.instance.api.crateApiExposedFunctionsImportIdentityPkcs12(name: name, data: data, password: password);
+
+/// Import every identity of Lagrange's idents directory, skipping those already imported
+IdentitiesImport  importLagrangeIdentities({required String dir }) => RustLib.instance.api.crateApiExposedFunctionsImportLagrangeIdentities(dir: dir);
+
+/// Export an identity as PEM (certificate followed by its unencrypted key)
+String  exportIdentityPem({required String id }) => RustLib.instance.api.crateApiExposedFunctionsExportIdentityPem(id: id);
+
+/// Export an identity as a password-protected PKCS#12 archive
+Uint8List  exportIdentityPkcs12({required String id , required String password }) => RustLib.instance.api.crateApiExposedFunctionsExportIdentityPkcs12(id: id, password: password);
+
+/// Present an identity on every request to url and the URLs under it until it is deactivated, as when a page asks for a certificate
+IdentityScope  activateIdentity({required String identityId , required String url }) => RustLib.instance.api.crateApiExposedFunctionsActivateIdentity(identityId: identityId, url: url);
+
+/// Stop presenting the identity activated for exactly this URL; returns whether one was
+bool  deactivateIdentity({required String url }) => RustLib.instance.api.crateApiExposedFunctionsDeactivateIdentity(url: url);
+
+/// Every URL prefix an identity is activated for
+List<IdentityScope>  listIdentityScopes() => RustLib.instance.api.crateApiExposedFunctionsListIdentityScopes();
+
+/// The activation a URL falls under, e.g. to show which identity the current page is seen with
+IdentityScope?  activeIdentity({required String url }) => RustLib.instance.api.crateApiExposedFunctionsActiveIdentity(url: url);
+
+/// Identities whose certificates expire within the given number of days
+List<ExpiryReminder>  expiringIdentities({required int withinDays }) => RustLib.instance.api.crateApiExposedFunctionsExpiringIdentities(withinDays: withinDays);
+
+/// Scan identities for upcoming expiry now and then periodically (0 scans once); call after open_profile
+void  startExpiryChecks({required int withinDays , required int intervalSecs }) => RustLib.instance.api.crateApiExposedFunctionsStartExpiryChecks(withinDays: withinDays, intervalSecs: intervalSecs);
+
+/// Stop the periodic identity expiry scans
+void  stopExpiryChecks() => RustLib.instance.api.crateApiExposedFunctionsStopExpiryChecks();
+
+/// Wait for the next identity expiry reminder from the periodic check
+Future<ExpiryReminder>  nextExpiryReminder() => RustLib.instance.api.crateApiExposedFunctionsNextExpiryReminder();
+
+/// A new random storage key for sealing identity private keys; keep it in the platform's secure storage (Android Keystore, iOS Keychain, Secret Service)
+Uint8List  generateStorageKey() => RustLib.instance.api.crateApiExposedFunctionsGenerateStorageKey();
+
+/// Seal the profile's identity private keys at rest with the storage key from secure storage; call after open_profile. Returns how many plain keys were sealed now
+int  unlockKeyStorage({required List<int> storageKey }) => RustLib.instance.api.crateApiExposedFunctionsUnlockKeyStorage(storageKey: storageKey);
+
+/// Unlock key storage with the profile's storage key from the platform keyring (Keychain, Windows Credential Manager, or the Secret Service), generating and saving one the first time; needs the `keyring` feature. Returns how many plain keys were sealed now
+int  unlockKeyStorageFromKeyring({required String profile }) => RustLib.instance.api.crateApiExposedFunctionsUnlockKeyStorageFromKeyring(profile: profile);
+
+/// Forget the storage key; sealed identities cannot be used until key storage is unlocked again
+void  lockKeyStorage() => RustLib.instance.api.crateApiExposedFunctionsLockKeyStorage();
+
+/// Whether a storage key is set for the open profile
+bool  isKeyStorageUnlocked() => RustLib.instance.api.crateApiExposedFunctionsIsKeyStorageUnlocked();
+
+/// Upload data to a titan:// (or gemini://) URL with its mime and token parameters, using the host's stored token and identity unless others are given
+Future<FetchResponse>  titanUpload({required String url , required List<int> data , String? mime , String? token , String? identityId }) => RustLib.instance.api.crateApiExposedFunctionsTitanUpload(url: url, data: data, mime: mime, token: token, identityId: identityId);
+
+/// Store the Titan token of a host so uploads to it need not ask for it again
+void  setTitanToken({required String host , required String token }) => RustLib.instance.api.crateApiExposedFunctionsSetTitanToken(host: host, token: token);
+
+/// Forget the stored Titan token of a host
+bool  removeTitanToken({required String host }) => RustLib.instance.api.crateApiExposedFunctionsRemoveTitanToken(host: host);
+
+/// List the hosts with a stored Titan token, without the tokens
+List<String>  titanTokenHosts() => RustLib.instance.api.crateApiExposedFunctionsTitanTokenHosts();
+
+/// Get the browser-wide configuration: default scheme, start page, search provider, timeouts, TLS policy and proxy
+Config  getConfig() => RustLib.instance.api.crateApiExposedFunctionsGetConfig();
+
+/// Validate, save and apply a whole configuration; returns it as stored
+Config  setConfig({required Config config }) => RustLib.instance.api.crateApiExposedFunctionsSetConfig(config: config);
+
+/// Get the start page URL: the user's choice (possibly about:home) or the default
+String  getStartPage() => RustLib.instance.api.crateApiExposedFunctionsGetStartPage();
+
+/// Change the start page (a URL or about:home), or restore the default with None; returns the new start page
+String  setStartPage({String? url }) => RustLib.instance.api.crateApiExposedFunctionsSetStartPage(url: url);
+
+/// Navigate to the results of a query at the configured search provider
+Future<NavigationResult>  search({required String query }) => RustLib.instance.api.crateApiExposedFunctionsSearch(query: query);
+
+/// Query every offered search engine at once and return their hits merged, deduplicated by URL and ranked
+Future<MetasearchResult>  searchAll({required String query }) => RustLib.instance.api.crateApiExposedFunctionsSearchAll(query: query);
+
+/// The search engines offered in settings, the default first
+List<SearchProvider>  searchProviders() => RustLib.instance.api.crateApiExposedFunctionsSearchProviders();
+
+/// Get the search URL template, with %s where the query goes
+String  getSearchProvider() => RustLib.instance.api.crateApiExposedFunctionsGetSearchProvider();
+
+/// Change the search URL template (it must contain %s), or restore the default with None; returns the new template
+String  setSearchProvider({String? urlTemplate }) => RustLib.instance.api.crateApiExposedFunctionsSetSearchProvider(urlTemplate: urlTemplate);
+
+/// Whether a Gemini address that fails to load is searched for instead of returning its error (off by default)
+bool  getSearchOnFailure() => RustLib.instance.api.crateApiExposedFunctionsGetSearchOnFailure();
+
+/// Turn searching for Gemini addresses that fail to load on or off; input that is not an address is always searched for
+void  setSearchOnFailure({required bool enabled }) => RustLib.instance.api.crateApiExposedFunctionsSetSearchOnFailure(enabled: enabled);
+
+/// Whether answers to Gemini input prompts are saved to be offered again (off by default)
+bool  getRememberInputAnswers() => RustLib.instance.api.crateApiExposedFunctionsGetRememberInputAnswers();
+
+/// Turn saving answers to input prompts on, or off forgetting those saved; answers to sensitive (status 11) prompts are never saved
+void  setRememberInputAnswers({required bool enabled }) => RustLib.instance.api.crateApiExposedFunctionsSetRememberInputAnswers(enabled: enabled);
+
+/// Answers given before to the prompt a URL asks, most recently given first, to offer as autofill
+List<SavedAnswer>  getSavedAnswers({required String url , required String prompt }) => RustLib.instance.api.crateApiExposedFunctionsGetSavedAnswers(url: url, prompt: prompt);
+
+/// Every saved answer to an input prompt, by URL and prompt
+List<SavedAnswer>  listSavedAnswers() => RustLib.instance.api.crateApiExposedFunctionsListSavedAnswers();
+
+/// Forget one saved answer, returning whether it was saved
+bool  deleteSavedAnswer({required PlatformInt64 id }) => RustLib.instance.api.crateApiExposedFunctionsDeleteSavedAnswer(id: id);
+
+            
+            
\ No newline at end of file
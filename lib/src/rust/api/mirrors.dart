@@ -0,0 +1,141 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../third_party/holloway_core/api/models/certificate_error.dart';
+import '../third_party/holloway_core/api/models/error_info.dart';
+import '../third_party/holloway_core/api/models/server_failure.dart';
+import '../third_party/holloway_core/api/transport/proxy.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'mirrors.freezed.dart';
+
+            
+
+            
+
+            @freezed
+                sealed class AnsiColor with _$AnsiColor  {
+                    const AnsiColor._();
+
+                     const factory AnsiColor.indexed(  int field0,) = AnsiColor_Indexed;
+ const factory AnsiColor.rgb({   required int r ,  required int g ,  required int b , }) = AnsiColor_Rgb;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class GemtextLine with _$GemtextLine  {
+                    const GemtextLine._();
+
+                     const factory GemtextLine.text(  String field0,) = GemtextLine_Text;
+ const factory GemtextLine.link({   required String url ,  String? label , }) = GemtextLine_Link;
+ const factory GemtextLine.heading({   required int level ,  required String text , }) = GemtextLine_Heading;
+ const factory GemtextLine.listItem(  String field0,) = GemtextLine_ListItem;
+ const factory GemtextLine.quote(  String field0,) = GemtextLine_Quote;
+ const factory GemtextLine.preformatToggle({   String? alt , }) = GemtextLine_PreformatToggle;
+ const factory GemtextLine.preformatted(  String field0,) = GemtextLine_Preformatted;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class NavigateError with _$NavigateError implements FrbException {
+                    const NavigateError._();
+
+                     /// The input is not a usable URL
+const factory NavigateError.invalidUrl(  String field0,) = NavigateError_InvalidUrl;
+ /// No protocol handler serves the scheme
+const factory NavigateError.unsupportedScheme(  String field0,) = NavigateError_UnsupportedScheme;
+ /// Refused locally: the user turned the protocol off
+const factory NavigateError.schemeDisabled(  String field0,) = NavigateError_SchemeDisabled;
+ /// Refused locally: the URL matches a blocklist rule
+const factory NavigateError.blocked(  String field0,) = NavigateError_Blocked;
+ /// Refused locally: the host's robots.txt disallows a fetch the user did not ask for
+const factory NavigateError.disallowed(  String field0,) = NavigateError_Disallowed;
+ /// Offline mode is on and the page has no cached copy
+const factory NavigateError.offline(  String field0,) = NavigateError_Offline;
+ const factory NavigateError.dnsFailure(  String field0,) = NavigateError_DnsFailure;
+ const factory NavigateError.connectTimeout(  String field0,) = NavigateError_ConnectTimeout;
+ /// The host refused the connection
+const factory NavigateError.connectionRefused(  String field0,) = NavigateError_ConnectionRefused;
+ /// The host could not be reached
+const factory NavigateError.connectionFailed(  String field0,) = NavigateError_ConnectionFailed;
+ /// The connection broke while sending the request or reading the response
+const factory NavigateError.connectionLost(  String field0,) = NavigateError_ConnectionLost;
+ /// The server stopped sending for longer than the read timeout, or the request outlasted
+/// its deadline
+const factory NavigateError.readTimeout(  String field0,) = NavigateError_ReadTimeout;
+ /// A Gemini server stopped sending for longer than the read timeout
+const factory NavigateError.geminiTimeout(  String field0,) = NavigateError_GeminiTimeout;
+ /// The SOCKS5 proxy could not be reached
+const factory NavigateError.proxyUnreachable(  String field0,) = NavigateError_ProxyUnreachable;
+ /// The SOCKS5 proxy refused the credentials, or needs some
+const factory NavigateError.proxyAuthFailed(  String field0,) = NavigateError_ProxyAuthFailed;
+ /// The SOCKS5 proxy failed to connect to the host, or does not speak SOCKS5
+const factory NavigateError.proxyError(  String field0,) = NavigateError_ProxyError;
+ /// The WebSocket gateway failed
+const factory NavigateError.gatewayError(  String field0,) = NavigateError_GatewayError;
+ /// The TLS handshake failed
+const factory NavigateError.tlsError(  String field0,) = NavigateError_TlsError;
+ /// The server's certificate changed from the pinned one
+const factory NavigateError.fingerprintMismatch(  String field0,) = NavigateError_FingerprintMismatch;
+ /// The server's certificate is expired or not yet valid, past the clock skew tolerance
+const factory NavigateError.certificateDate(  String field0,) = NavigateError_CertificateDate;
+ /// Strict validation refused the server's certificate; `certificate_failure` tells why
+const factory NavigateError.certificateRejected(  String field0,) = NavigateError_CertificateRejected;
+ /// The server answered something that is not valid for its protocol
+const factory NavigateError.badResponse(  String field0,) = NavigateError_BadResponse;
+ /// The response outgrew the body limit or the decompression limits
+const factory NavigateError.tooLarge(  String field0,) = NavigateError_TooLarge;
+ /// Something the request named does not exist, e.g. a local file or a newsgroup
+const factory NavigateError.notFound(  String field0,) = NavigateError_NotFound;
+ /// The request cannot be sent as it is, e.g. a Finger query spanning lines
+const factory NavigateError.invalidInput(  String field0,) = NavigateError_InvalidInput;
+ /// The Gopher item type names no document to fetch, e.g. a telnet session or an info line
+const factory NavigateError.gopherBadType(  String field0,) = NavigateError_GopherBadType;
+ /// The Gopher selector spans lines, which would send the server more than one request
+const factory NavigateError.gopherBadSelector(  String field0,) = NavigateError_GopherBadSelector;
+ /// The profile database failed
+const factory NavigateError.storageError(  String field0,) = NavigateError_StorageError;
+ /// The identity to present could not be used, e.g. while the key store is locked
+const factory NavigateError.identityError(  String field0,) = NavigateError_IdentityError;
+ /// The caller cancelled the navigation
+const factory NavigateError.cancelled(  String field0,) = NavigateError_Cancelled;
+ /// Gemini 3x: the page moved
+const factory NavigateError.redirected({   required String target ,  required bool permanent , }) = NavigateError_Redirected;
+ /// Gemini 1x: the server asks for input; sensitive input (11) should be masked
+const factory NavigateError.inputRequired({   required String prompt ,  required bool sensitive , }) = NavigateError_InputRequired;
+ /// Gemini 6x: a client certificate is needed or was refused
+const factory NavigateError.certificateRequired(  CertificateError field0,) = NavigateError_CertificateRequired;
+ /// Gemini 4x and 5x: the server could not serve the page, for now or for good
+const factory NavigateError.serverError(  ServerFailure field0,) = NavigateError_ServerError;
+ const factory NavigateError.other(  String field0,) = NavigateError_Other;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class ProxySettings with _$ProxySettings  {
+                    const ProxySettings._();
+
+                     /// Connect directly
+const factory ProxySettings.direct() = ProxySettings_Direct;
+ /// Send every connection through one proxy
+const factory ProxySettings.socks5({   required Socks5Proxy proxy , }) = ProxySettings_Socks5;
+ /// Use the first matching rule, then the fallback (direct when None)
+const factory ProxySettings.perRule({   required List<ProxyRule> rules ,  Socks5Proxy? fallback , }) = ProxySettings_PerRule;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -4,479 +4,12623 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/exposed_functions.dart';
-import 'api/functions/navigate_internal.dart';
-import 'api/protocols/finger.dart';
-import 'api/protocols/gemini.dart';
-import 'api/protocols/gopher.dart';
+import 'api/mirrors.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'third_party/holloway_core/api/functions/anchors.dart';
+import 'third_party/holloway_core/api/functions/ansi.dart';
+import 'third_party/holloway_core/api/functions/batch.dart';
+import 'third_party/holloway_core/api/functions/bidi.dart';
+import 'third_party/holloway_core/api/functions/bookmark_export.dart';
+import 'third_party/holloway_core/api/functions/bookmark_import.dart';
+import 'third_party/holloway_core/api/functions/clock_skew.dart';
+import 'third_party/holloway_core/api/functions/config.dart';
+import 'third_party/holloway_core/api/functions/content_router.dart';
+import 'third_party/holloway_core/api/functions/crawler.dart';
+import 'third_party/holloway_core/api/functions/data_usage.dart';
+import 'third_party/holloway_core/api/functions/downloads.dart';
+import 'third_party/holloway_core/api/functions/explore.dart';
+import 'third_party/holloway_core/api/functions/feed_discovery.dart';
+import 'third_party/holloway_core/api/functions/file_type.dart';
+import 'third_party/holloway_core/api/functions/gempub.dart';
+import 'third_party/holloway_core/api/functions/highlighting.dart';
+import 'third_party/holloway_core/api/functions/homograph.dart';
+import 'third_party/holloway_core/api/functions/host_migration.dart';
+import 'third_party/holloway_core/api/functions/identities.dart';
+import 'third_party/holloway_core/api/functions/identity_transfer.dart';
+import 'third_party/holloway_core/api/functions/inline_images.dart';
+import 'third_party/holloway_core/api/functions/known_hosts_transfer.dart';
+import 'third_party/holloway_core/api/functions/links.dart';
+import 'third_party/holloway_core/api/functions/logging.dart';
+import 'third_party/holloway_core/api/functions/menu_pages.dart';
+import 'third_party/holloway_core/api/functions/metasearch.dart';
+import 'third_party/holloway_core/api/functions/mirror.dart';
+import 'third_party/holloway_core/api/functions/page_diff.dart';
+import 'third_party/holloway_core/api/functions/page_summary.dart';
+import 'third_party/holloway_core/api/functions/peek.dart';
+import 'third_party/holloway_core/api/functions/reading_stats.dart';
+import 'third_party/holloway_core/api/functions/reflow.dart';
+import 'third_party/holloway_core/api/functions/request_trace.dart';
+import 'third_party/holloway_core/api/functions/scheduler.dart';
+import 'third_party/holloway_core/api/functions/search.dart';
+import 'third_party/holloway_core/api/functions/shutdown.dart';
+import 'third_party/holloway_core/api/functions/site_map.dart';
+import 'third_party/holloway_core/api/functions/streaming.dart';
+import 'third_party/holloway_core/api/functions/suggestions.dart';
+import 'third_party/holloway_core/api/functions/text_width.dart';
+import 'third_party/holloway_core/api/functions/top_sites.dart';
+import 'third_party/holloway_core/api/models/certificate_error.dart';
+import 'third_party/holloway_core/api/models/error_info.dart';
+import 'third_party/holloway_core/api/models/fetch_options.dart';
+import 'third_party/holloway_core/api/models/fetch_response.dart';
+import 'third_party/holloway_core/api/models/navigation_event.dart';
+import 'third_party/holloway_core/api/models/navigation_result.dart';
+import 'third_party/holloway_core/api/models/server_failure.dart';
+import 'third_party/holloway_core/api/models/stream_event.dart';
+import 'third_party/holloway_core/api/models/tls_failure.dart';
+import 'third_party/holloway_core/api/parsers/gophermap.dart';
+import 'third_party/holloway_core/api/protocols/cso.dart';
+import 'third_party/holloway_core/api/protocols/registry.dart';
+import 'third_party/holloway_core/api/storage/archive.dart';
+import 'third_party/holloway_core/api/storage/blocklist.dart';
+import 'third_party/holloway_core/api/storage/bookmarks.dart';
+import 'third_party/holloway_core/api/storage/cache.dart';
+import 'third_party/holloway_core/api/storage/clear.dart';
+import 'third_party/holloway_core/api/storage/data_usage.dart';
+import 'third_party/holloway_core/api/storage/downloads.dart';
+import 'third_party/holloway_core/api/storage/feeds.dart';
+import 'third_party/holloway_core/api/storage/gemini_proxies.dart';
+import 'third_party/holloway_core/api/storage/history.dart';
+import 'third_party/holloway_core/api/storage/host_settings.dart';
+import 'third_party/holloway_core/api/storage/host_stats.dart';
+import 'third_party/holloway_core/api/storage/identities.dart';
+import 'third_party/holloway_core/api/storage/identity_scopes.dart';
+import 'third_party/holloway_core/api/storage/input_answers.dart';
+import 'third_party/holloway_core/api/storage/known_hosts.dart';
+import 'third_party/holloway_core/api/storage/page_index.dart';
+import 'third_party/holloway_core/api/storage/plan_watches.dart';
+import 'third_party/holloway_core/api/storage/ports.dart';
+import 'third_party/holloway_core/api/storage/read_later.dart';
+import 'third_party/holloway_core/api/storage/sessions.dart';
+import 'third_party/holloway_core/api/storage/trusted_cas.dart';
+import 'third_party/holloway_core/api/storage/watches.dart';
+import 'third_party/holloway_core/api/transport/limits.dart';
+import 'third_party/holloway_core/api/transport/proxy.dart';
+import 'third_party/holloway_core/api/transport/rate_limit.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiExposedFunctionsInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 568361207;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_holloway',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<String> crateApiProtocolsFingerConnectAndFetchFinger({
-    required String host,
-    required int port,
-    required String username,
-  });
-
-  Future<String> crateApiProtocolsGeminiConnectAndFetchGemini({
-    required String host,
-    required int port,
-    required String url,
-  });
-
-  Future<String> crateApiProtocolsGopherConnectAndFetchGopher({
-    required String host,
-    required int port,
-    required String path,
-  });
-
-  String crateApiExposedFunctionsGetStartPage();
-
-  String crateApiExposedFunctionsGreet({required String name});
-
-  Future<void> crateApiExposedFunctionsInitApp();
-
-  Future<String> crateApiExposedFunctionsNavigate({required String url});
-
-  Future<String> crateApiFunctionsNavigateInternalNavigateInternal({
-    required String url,
-  });
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<String> crateApiProtocolsFingerConnectAndFetchFinger({
-    required String host,
-    required int port,
-    required String username,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(host, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_String(username, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiExposedFunctionsInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -1059131202;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_holloway',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  int crateApiExposedFunctionsAcceptNewCertificate({required String host });
+
+IdentityScope crateApiExposedFunctionsActivateIdentity({required String identityId , required String url });
+
+IdentityScope? crateApiExposedFunctionsActiveIdentity({required String url });
+
+BlockRule crateApiExposedFunctionsAddBlockRule({required String pattern });
+
+AddedBookmark crateApiExposedFunctionsAddBookmark({required String url , String? title });
+
+void crateApiExposedFunctionsAddHistoryVisit({required String url , String? title });
+
+List<LineDirection> crateApiExposedFunctionsAnalyzeTextDirection({required String body });
+
+Future<ArchivedPage> crateApiExposedFunctionsArchivePage({required String url });
+
+Future<BigInt> crateApiExposedFunctionsBeginFetch({required String url , required FetchOptions options });
+
+Future<int> crateApiExposedFunctionsCacheReadLater();
+
+bool crateApiExposedFunctionsCancelBatch({required BigInt handle });
+
+void crateApiExposedFunctionsCancelCapsuleIndexing();
+
+bool crateApiExposedFunctionsCancelMirror({required BigInt handle });
+
+void crateApiExposedFunctionsCancelPrefetch();
+
+bool crateApiExposedFunctionsCancelRequest({required BigInt requestId });
+
+bool crateApiExposedFunctionsCancelStream({required BigInt handle });
+
+Future<List<WatchedPage>> crateApiExposedFunctionsCheckForUpdates();
+
+Future<List<WatchedPlan>> crateApiExposedFunctionsCheckPlans();
+
+void crateApiExposedFunctionsCheckpointProfile();
+
+String? crateApiExposedFunctionsChecksumLink({required String pageUrl , required String mime , required String body , required String fileUrl });
+
+List<ClearedData> crateApiExposedFunctionsClearData({required List<DataCategory> categories , PlatformInt64? since });
+
+bool crateApiExposedFunctionsCloseDocument({required BigInt handle });
+
+bool crateApiExposedFunctionsCloseGempub({required BigInt handle });
+
+bool crateApiExposedFunctionsCloseMenu({required BigInt handle });
+
+String crateApiExposedFunctionsCreateIdentity({required String commonName , required int validDays });
+
+Future<List<CsoRecord>> crateApiExposedFunctionsCsoQuery({required String url , required String query });
+
+bool crateApiExposedFunctionsDeactivateIdentity({required String url });
+
+bool crateApiExposedFunctionsDeleteArchived({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsDeleteDownload({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsDeleteHistoryEntry({required PlatformInt64 id });
+
+int crateApiExposedFunctionsDeleteHistoryUrl({required String url });
+
+bool crateApiExposedFunctionsDeleteSavedAnswer({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsDeleteSession({required String name });
+
+FileType? crateApiExposedFunctionsDetectFileType({required List<int> content });
+
+Future<PageDiff> crateApiExposedFunctionsDiffWithCache({required String url });
+
+bool crateApiExposedFunctionsDismissHostMigration({required String scheme , required String fromHost });
+
+DisplayHost crateApiExposedFunctionsDisplayHost({required String url });
+
+String crateApiExposedFunctionsDisplayUrl({required String url });
+
+List<OutlineEntry> crateApiExposedFunctionsDocumentOutline({required BigInt handle });
+
+ReadingStats crateApiExposedFunctionsDocumentStats({required BigInt handle });
+
+Future<DownloadResult> crateApiExposedFunctionsDownload({required String url , required String destPath , required FetchOptions options });
+
+Future<DownloadResult> crateApiExposedFunctionsDownloadVerified({required String url , required String destPath , String? checksumUrl , required FetchOptions options });
+
+int crateApiExposedFunctionsEndPrivateSession();
+
+bool crateApiExposedFunctionsEvictCacheEntry({required String url });
+
+List<ExpiryReminder> crateApiExposedFunctionsExpiringIdentities({required int withinDays });
+
+String crateApiExposedFunctionsExportBookmarks({required BookmarkFormat format });
+
+int crateApiExposedFunctionsExportBookmarksToFile({required BookmarkFormat format , required String path });
+
+String crateApiExposedFunctionsExportIdentityPem({required String id });
+
+Uint8List crateApiExposedFunctionsExportIdentityPkcs12({required String id , required String password });
+
+String crateApiExposedFunctionsExportKnownHosts({required KnownHostsFormat format });
+
+List<PageLink> crateApiExposedFunctionsExtractLinks({required String url , required String body , required String mime });
+
+Future<FetchResponse> crateApiExposedFunctionsFetch({required String url });
+
+Future<InlineImages> crateApiExposedFunctionsFetchDocumentImages({required BigInt handle , required String pageUrl , int? maxBytes });
+
+Future<FetchResponse> crateApiExposedFunctionsFetchWithOptions({required String url , required FetchOptions options });
+
+Bookmark? crateApiExposedFunctionsFindDuplicateBookmark({required String url });
+
+int? crateApiExposedFunctionsFindDuplicateTab({required String url , required List<String> openTabs });
+
+Future<FetchResponse> crateApiExposedFunctionsFinishFetch({required BigInt requestId });
+
+bool crateApiExposedFunctionsForgetKnownHost({required String host , required int port });
+
+GempubPage crateApiExposedFunctionsGempubChapter({required BigInt handle , required int chapter });
+
+GempubPage crateApiExposedFunctionsGempubFile({required BigInt handle , String? fromPath , required String link });
+
+List<OutlineEntry> crateApiExposedFunctionsGemtextOutline({required String body });
+
+ReadingStats crateApiExposedFunctionsGemtextStats({required String body });
+
+Uint8List crateApiExposedFunctionsGenerateStorageKey();
+
+BigInt? crateApiExposedFunctionsGetBandwidthLimit();
+
+List<CachePolicy> crateApiExposedFunctionsGetCachePolicies();
+
+TlsFailure? crateApiExposedFunctionsGetCertificateFailure({required String host });
+
+ClockOffset crateApiExposedFunctionsGetClockOffset();
+
+BigInt crateApiExposedFunctionsGetClockSkewTolerance();
+
+Config crateApiExposedFunctionsGetConfig();
+
+DataCapStatus crateApiExposedFunctionsGetDataCapStatus();
+
+List<DailyUsage> crateApiExposedFunctionsGetDataUsage({required PlatformInt64 since , required PlatformInt64 until });
+
+DecompressionLimits crateApiExposedFunctionsGetDecompressionLimits();
+
+List<HistoryResult> crateApiExposedFunctionsGetHistoryPage({required int offset , required int limit });
+
+HostSettings? crateApiExposedFunctionsGetHostSettings({required String host });
+
+List<HostStats> crateApiExposedFunctionsGetHostStats({required HostStatsOrder order , required int limit });
+
+int crateApiExposedFunctionsGetLineCount({required BigInt handle });
+
+List<GemtextLine> crateApiExposedFunctionsGetLines({required BigInt handle , required int start , required int count });
+
+bool crateApiExposedFunctionsGetLiteMode();
+
+LogLevel crateApiExposedFunctionsGetLogLevel();
+
+int crateApiExposedFunctionsGetMaxConcurrentHandshakes();
+
+MenuPage crateApiExposedFunctionsGetMenuPage({required BigInt handle , required int offset , required int count });
+
+List<ProtocolInfo> crateApiExposedFunctionsGetProtocols();
+
+ProxySettings crateApiExposedFunctionsGetProxySettings();
+
+RateLimits crateApiExposedFunctionsGetRateLimits();
+
+List<HistoryEntry> crateApiExposedFunctionsGetRecentHistory({required int limit });
+
+bool crateApiExposedFunctionsGetRememberInputAnswers();
+
+List<SavedAnswer> crateApiExposedFunctionsGetSavedAnswers({required String url , required String prompt });
+
+List<TaskSchedule> crateApiExposedFunctionsGetSchedules();
+
+bool crateApiExposedFunctionsGetSearchOnFailure();
+
+String crateApiExposedFunctionsGetSearchProvider();
+
+String crateApiExposedFunctionsGetStartPage();
+
+List<TopSite> crateApiExposedFunctionsGetTopHosts({required int limit });
+
+List<TopSite> crateApiExposedFunctionsGetTopSites({required int limit });
+
+List<TraceEvent>? crateApiExposedFunctionsGetTrace({required BigInt requestId });
+
+String crateApiExposedFunctionsGophermapToGemtext({required String body });
+
+String crateApiExposedFunctionsGreet({required String name });
+
+List<HighlightedLine>? crateApiExposedFunctionsHighlightCode({required String code , required String language });
+
+List<String> crateApiExposedFunctionsHighlightLanguages();
+
+List<HighlightedBlock> crateApiExposedFunctionsHighlightPreformatted({required String body });
+
+BookmarksImport crateApiExposedFunctionsImportBookmarks({required BookmarkSource source , required String data });
+
+List<TrustedCa> crateApiExposedFunctionsImportCaCertificates({required String pem });
+
+Identity crateApiExposedFunctionsImportIdentityPem({String? name , required String certificatePem , String? privateKeyPem });
+
+Identity crateApiExposedFunctionsImportIdentityPkcs12({String? name , required List<int> data , required String password });
+
+KnownHostsImport crateApiExposedFunctionsImportKnownHosts({required KnownHostsFormat format , required String data });
+
+IdentitiesImport crateApiExposedFunctionsImportLagrangeIdentities({required String dir });
+
+Future<CrawlResult> crateApiExposedFunctionsIndexCapsule({required String url , required int maxPages });
+
+Future<void> crateApiExposedFunctionsInitApp();
+
+bool crateApiExposedFunctionsIsKeyStorageUnlocked();
+
+bool crateApiExposedFunctionsIsOfflineMode();
+
+List<ArchivedPage> crateApiExposedFunctionsListArchived({String? url , required int limit });
+
+List<BlockRule> crateApiExposedFunctionsListBlockRules();
+
+List<Bookmark> crateApiExposedFunctionsListBookmarks();
+
+List<CacheEntry> crateApiExposedFunctionsListCacheEntries({String? host });
+
+List<DownloadRecord> crateApiExposedFunctionsListDownloads({DownloadStatus? status , required int limit });
+
+List<FeedEntry> crateApiExposedFunctionsListFeedEntries({PlatformInt64? feedId , required bool unreadOnly , required int offset , required int limit });
+
+List<Feed> crateApiExposedFunctionsListFeeds();
+
+List<SchemeProxy> crateApiExposedFunctionsListGeminiProxies();
+
+List<HostSettings> crateApiExposedFunctionsListHostSettings();
+
+List<Identity> crateApiExposedFunctionsListIdentities();
+
+List<IdentityScope> crateApiExposedFunctionsListIdentityScopes();
+
+List<KnownHost> crateApiExposedFunctionsListKnownHosts();
+
+List<PortOverride> crateApiExposedFunctionsListPortOverrides();
+
+List<ReadLaterItem> crateApiExposedFunctionsListReadLater({required bool includeRead });
+
+List<SavedAnswer> crateApiExposedFunctionsListSavedAnswers();
+
+List<SessionSummary> crateApiExposedFunctionsListSessions();
+
+List<TrustedCa> crateApiExposedFunctionsListTrustedCas();
+
+List<WatchedPage> crateApiExposedFunctionsListWatchedPages();
+
+List<WatchedPlan> crateApiExposedFunctionsListWatchedPlans();
+
+Session? crateApiExposedFunctionsLoadSession({required String name });
+
+void crateApiExposedFunctionsLockKeyStorage();
+
+List<String> crateApiExposedFunctionsLogFiles();
+
+Future<SiteMap> crateApiExposedFunctionsMapSite({required String url , required int depth });
+
+int crateApiExposedFunctionsMarkAllFeedEntriesRead({PlatformInt64? feedId });
+
+void crateApiExposedFunctionsMarkPlanRead({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsMarkReadLater({required PlatformInt64 id , required bool read });
+
+List<PreformattedBlock> crateApiExposedFunctionsMeasurePreformatted({required String body });
+
+HostMigrationResult crateApiExposedFunctionsMigrateHost({required String scheme , required String fromHost , required String toHost });
+
+BigInt crateApiExposedFunctionsMirrorCapsule({required String url , required String destDir , required int depth });
+
+Future<NavigationResult> crateApiExposedFunctionsNavigate({required String url , required bool forceRefresh });
+
+Future<NavigationResult> crateApiExposedFunctionsNavigateCancellable({required String url , required BigInt requestId });
+
+ErrorInfo crateApiExposedFunctionsNavigateErrorInfo({required NavigateError error });
+
+BigInt crateApiExposedFunctionsNavigateMany({required List<String> urls , required FetchOptions options , required int maxConcurrent });
+
+Future<NavigationResult> crateApiExposedFunctionsNavigateWithOptions({required String url , required FetchOptions options });
+
+Future<BatchItem?> crateApiExposedFunctionsNextBatchResult({required BigInt handle });
+
+Future<ExpiryReminder> crateApiExposedFunctionsNextExpiryReminder();
+
+Future<NavigationEvent?> crateApiExposedFunctionsNextFetchUpdate({required BigInt requestId });
+
+Future<LogRecord> crateApiExposedFunctionsNextLogRecord({required BigInt subscription });
+
+Future<MirrorProgress?> crateApiExposedFunctionsNextMirrorProgress({required BigInt handle });
+
+Future<NavigationEvent> crateApiExposedFunctionsNextNavigationEvent({required BigInt subscription });
+
+Future<SchedulerEvent> crateApiExposedFunctionsNextSchedulerEvent({required BigInt subscription });
+
+Future<StreamEvent> crateApiExposedFunctionsNextStreamEvent({required BigInt handle });
+
+String crateApiExposedFunctionsNormalizeUrl({required String input });
+
+FetchResponse crateApiExposedFunctionsOpenArchived({required PlatformInt64 id });
+
+BigInt crateApiExposedFunctionsOpenDocument({required String body });
+
+Future<GempubBook> crateApiExposedFunctionsOpenGempub({required String url });
+
+GempubBook crateApiExposedFunctionsOpenGempubData({required List<int> data });
+
+BigInt crateApiExposedFunctionsOpenMenu({required String body });
+
+void crateApiExposedFunctionsOpenProfile({required String dir });
+
+FetchResponse crateApiExposedFunctionsOpenReadLater({required PlatformInt64 id });
+
+Future<BigInt> crateApiExposedFunctionsOpenStream({required String url , required StreamOptions options });
+
+Future<PageSummary> crateApiExposedFunctionsPageSummary({required String url });
+
+List<GopherItem> crateApiExposedFunctionsParseGopherMenu({required String body });
+
+Future<PeekResult> crateApiExposedFunctionsPeek({required String url });
+
+Future<void> crateApiExposedFunctionsPreconnect({required String url });
+
+Future<int> crateApiExposedFunctionsPrefetch({required List<String> urls });
+
+bool crateApiExposedFunctionsPrefetchAllowed();
+
+Future<RandomDestination> crateApiExposedFunctionsRandomDestination({required List<ExploreSource> sources });
+
+List<LogRecord> crateApiExposedFunctionsRecentLogs({required LogLevel minLevel , required int limit });
+
+PlatformInt64 crateApiExposedFunctionsRecordDownload({required String url , required String path , required BigInt size , required String mime , required DownloadStatus status , String? error });
+
+List<ReflowedLine> crateApiExposedFunctionsReflowGemtext({required String body , required int maxCols });
+
+String crateApiExposedFunctionsReflowText({required String text , required int maxCols });
+
+Future<int> crateApiExposedFunctionsRefreshAllFeeds();
+
+Future<int> crateApiExposedFunctionsRefreshBookmarks({required int maxAgeSecs });
+
+Future<String?> crateApiExposedFunctionsRefreshFavicon({required String pageUrl , required FetchOptions options });
+
+Future<int> crateApiExposedFunctionsRefreshFeed({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsRemoveBlockRule({required String pattern });
+
+bool crateApiExposedFunctionsRemoveBookmark({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsRemoveGeminiProxy({required String scheme });
+
+bool crateApiExposedFunctionsRemoveHostSettings({required String host });
+
+bool crateApiExposedFunctionsRemovePortOverride({required String scheme , String? host });
+
+bool crateApiExposedFunctionsRemoveReadLater({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsRemoveTitanToken({required String host });
+
+bool crateApiExposedFunctionsRemoveTrustedCa({required String fingerprint });
+
+String crateApiExposedFunctionsRenderToHtml({required String gemtext , required String baseUrl });
+
+String crateApiExposedFunctionsRenderToMarkdown({required String body , required String baseUrl , required String mime });
+
+Identity crateApiExposedFunctionsRenewIdentity({required String id , required bool rotateKey , int? validDays });
+
+Future<RequestTimings> hollowayCoreApiModelsNavigationResultRequestTimingsDefault();
+
+BigInt crateApiExposedFunctionsReserveRequestId();
+
+void crateApiExposedFunctionsResetCachePolicy({required String scheme });
+
+String crateApiExposedFunctionsResolveLink({required String baseUrl , required String href });
+
+Renderer crateApiExposedFunctionsRouteContent({required String url , required String mime });
+
+Future<ReadLaterItem> crateApiExposedFunctionsSaveForLater({required String url , String? title });
+
+void crateApiExposedFunctionsSaveSession({required Session session });
+
+Future<List<SchedulerEvent>> crateApiExposedFunctionsSchedulerTick();
+
+Future<NavigationResult> crateApiExposedFunctionsSearch({required String query });
+
+Future<MetasearchResult> crateApiExposedFunctionsSearchAll({required String query });
+
+List<LocalSearchResult> crateApiExposedFunctionsSearchCapsule({required String url , required String query , required int limit });
+
+List<HistoryResult> crateApiExposedFunctionsSearchHistory({required String query , required HistoryMatch mode , required HistoryRange range , required int limit });
+
+List<LocalSearchResult> crateApiExposedFunctionsSearchLocal({required String query , required int limit });
+
+List<SearchProvider> crateApiExposedFunctionsSearchProviders();
+
+void crateApiExposedFunctionsSetBandwidthLimit({BigInt? bytesPerSec });
+
+bool crateApiExposedFunctionsSetBookmarkTitle({required PlatformInt64 id , String? title });
+
+void crateApiExposedFunctionsSetCachePolicy({required CachePolicy policy });
+
+void crateApiExposedFunctionsSetClockSkewTolerance({required BigInt secs });
+
+Config crateApiExposedFunctionsSetConfig({required Config config });
+
+void crateApiExposedFunctionsSetDataCap({BigInt? limitBytes });
+
+void crateApiExposedFunctionsSetDecompressionLimits({required DecompressionLimits limits });
+
+bool crateApiExposedFunctionsSetFeedEntryRead({required PlatformInt64 id , required bool read });
+
+void crateApiExposedFunctionsSetGateway({String? urlTemplate });
+
+void crateApiExposedFunctionsSetGeminiProxy({required String scheme , required String host , required int port });
+
+void crateApiExposedFunctionsSetGopherGateway({required String scheme , required String host , required int port , required String selector });
+
+void crateApiExposedFunctionsSetHostSettings({required HostSettings settings });
+
+void crateApiExposedFunctionsSetLiteMode({required bool enabled });
+
+void crateApiExposedFunctionsSetLogDirectory({String? dir });
+
+void crateApiExposedFunctionsSetLogLevel({required LogLevel level });
+
+void crateApiExposedFunctionsSetMaxConcurrentHandshakes({required int max });
+
+void crateApiExposedFunctionsSetNetworkType({required NetworkType network });
+
+void crateApiExposedFunctionsSetOfflineMode({required bool enabled });
+
+void crateApiExposedFunctionsSetPortOverride({required String scheme , String? host , required int port });
+
+void crateApiExposedFunctionsSetPowerState({required PowerState state });
+
+void crateApiExposedFunctionsSetProtocolEnabled({required String scheme , required bool enabled });
+
+void crateApiExposedFunctionsSetProxySettings({required ProxySettings settings });
+
+void crateApiExposedFunctionsSetRateLimits({required RateLimits limits });
+
+void crateApiExposedFunctionsSetRememberInputAnswers({required bool enabled });
+
+void crateApiExposedFunctionsSetSchedule({required TaskSchedule schedule });
+
+void crateApiExposedFunctionsSetSearchOnFailure({required bool enabled });
+
+String crateApiExposedFunctionsSetSearchProvider({String? urlTemplate });
+
+bool crateApiExposedFunctionsSetSessionScrollOffset({required String name , required int tab , required double scrollOffset });
+
+String crateApiExposedFunctionsSetStartPage({String? url });
+
+void crateApiExposedFunctionsSetTitanToken({required String host , required String token });
+
+ShutdownSummary crateApiExposedFunctionsShutdown();
+
+void crateApiExposedFunctionsStartBookmarkRefresh({required int intervalSecs , required int maxAgeSecs });
+
+void crateApiExposedFunctionsStartExpiryChecks({required int withinDays , required int intervalSecs });
+
+void crateApiExposedFunctionsStartScheduler({required int tickSecs });
+
+void crateApiExposedFunctionsStopBookmarkRefresh();
+
+void crateApiExposedFunctionsStopExpiryChecks();
+
+void crateApiExposedFunctionsStopScheduler();
+
+String crateApiExposedFunctionsStripAnsiEscapes({required String body });
+
+StyledText crateApiExposedFunctionsStyleAnsiText({required String body });
+
+BigInt crateApiExposedFunctionsSubscribeEvents();
+
+Feed crateApiExposedFunctionsSubscribeFeed({required String url , String? title , required FeedKind kind });
+
+BigInt crateApiExposedFunctionsSubscribeLogs();
+
+BigInt crateApiExposedFunctionsSubscribeRequestEvents({required BigInt requestId });
+
+BigInt crateApiExposedFunctionsSubscribeSchedulerEvents();
+
+List<Suggestion> crateApiExposedFunctionsSuggest({required String prefix , required int limit });
+
+String crateApiExposedFunctionsSuggestDownloadPath({required String url , required String mime , required String destDir });
+
+RecoveryAction? crateApiExposedFunctionsSuggestedActionForStatus({required int status });
+
+List<HostMigration> crateApiExposedFunctionsSuggestedHostMigrations();
+
+Future<ProxyProbe> crateApiExposedFunctionsTestProxy({required Socks5Proxy proxy });
+
+List<String> crateApiExposedFunctionsTitanTokenHosts();
+
+Future<FetchResponse> crateApiExposedFunctionsTitanUpload({required String url , required List<int> data , String? mime , String? token , String? identityId });
+
+int crateApiExposedFunctionsUnlockKeyStorage({required List<int> storageKey });
+
+int crateApiExposedFunctionsUnlockKeyStorageFromKeyring({required String profile });
+
+int crateApiExposedFunctionsUnreadFeedCount();
+
+bool crateApiExposedFunctionsUnsubscribeEvents({required BigInt subscription });
+
+bool crateApiExposedFunctionsUnsubscribeFeed({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsUnsubscribeLogs({required BigInt subscription });
+
+bool crateApiExposedFunctionsUnsubscribeSchedulerEvents({required BigInt subscription });
+
+bool crateApiExposedFunctionsUnwatchPage({required PlatformInt64 id });
+
+bool crateApiExposedFunctionsUnwatchPlan({required PlatformInt64 id });
+
+WatchedPage crateApiExposedFunctionsWatchPage({required String url , String? title });
+
+WatchedPlan crateApiExposedFunctionsWatchPlan({required String address });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override int crateApiExposedFunctionsAcceptNewCertificate({required String host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsAcceptNewCertificateConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsAcceptNewCertificateConstMeta => const TaskConstMeta(
+            debugName: "accept_new_certificate",
+            argNames: ["host"],
+        );
+        
+
+@override IdentityScope crateApiExposedFunctionsActivateIdentity({required String identityId , required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(identityId, serializer);
+sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_identity_scope,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsActivateIdentityConstMeta,
+            argValues: [identityId, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsActivateIdentityConstMeta => const TaskConstMeta(
+            debugName: "activate_identity",
+            argNames: ["identityId", "url"],
+        );
+        
+
+@override IdentityScope? crateApiExposedFunctionsActiveIdentity({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_identity_scope,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsActiveIdentityConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsActiveIdentityConstMeta => const TaskConstMeta(
+            debugName: "active_identity",
+            argNames: ["url"],
+        );
+        
+
+@override BlockRule crateApiExposedFunctionsAddBlockRule({required String pattern })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pattern, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_block_rule,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsAddBlockRuleConstMeta,
+            argValues: [pattern],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsAddBlockRuleConstMeta => const TaskConstMeta(
+            debugName: "add_block_rule",
+            argNames: ["pattern"],
+        );
+        
+
+@override AddedBookmark crateApiExposedFunctionsAddBookmark({required String url , String? title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_opt_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_added_bookmark,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsAddBookmarkConstMeta,
+            argValues: [url, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsAddBookmarkConstMeta => const TaskConstMeta(
+            debugName: "add_bookmark",
+            argNames: ["url", "title"],
+        );
+        
+
+@override void crateApiExposedFunctionsAddHistoryVisit({required String url , String? title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_opt_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsAddHistoryVisitConstMeta,
+            argValues: [url, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsAddHistoryVisitConstMeta => const TaskConstMeta(
+            debugName: "add_history_visit",
+            argNames: ["url", "title"],
+        );
+        
+
+@override List<LineDirection> crateApiExposedFunctionsAnalyzeTextDirection({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_line_direction,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsAnalyzeTextDirectionConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsAnalyzeTextDirectionConstMeta => const TaskConstMeta(
+            debugName: "analyze_text_direction",
+            argNames: ["body"],
+        );
+        
+
+@override Future<ArchivedPage> crateApiExposedFunctionsArchivePage({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_archived_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsArchivePageConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsArchivePageConstMeta => const TaskConstMeta(
+            debugName: "archive_page",
+            argNames: ["url"],
+        );
+        
+
+@override Future<BigInt> crateApiExposedFunctionsBeginFetch({required String url , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsBeginFetchConstMeta,
+            argValues: [url, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsBeginFetchConstMeta => const TaskConstMeta(
+            debugName: "begin_fetch",
+            argNames: ["url", "options"],
+        );
+        
+
+@override Future<int> crateApiExposedFunctionsCacheReadLater()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCacheReadLaterConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCacheReadLaterConstMeta => const TaskConstMeta(
+            debugName: "cache_read_later",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsCancelBatch({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelBatchConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelBatchConstMeta => const TaskConstMeta(
+            debugName: "cancel_batch",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiExposedFunctionsCancelCapsuleIndexing()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelCapsuleIndexingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelCapsuleIndexingConstMeta => const TaskConstMeta(
+            debugName: "cancel_capsule_indexing",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsCancelMirror({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelMirrorConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelMirrorConstMeta => const TaskConstMeta(
+            debugName: "cancel_mirror",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiExposedFunctionsCancelPrefetch()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelPrefetchConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelPrefetchConstMeta => const TaskConstMeta(
+            debugName: "cancel_prefetch",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsCancelRequest({required BigInt requestId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(requestId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelRequestConstMeta,
+            argValues: [requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelRequestConstMeta => const TaskConstMeta(
+            debugName: "cancel_request",
+            argNames: ["requestId"],
+        );
+        
+
+@override bool crateApiExposedFunctionsCancelStream({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCancelStreamConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCancelStreamConstMeta => const TaskConstMeta(
+            debugName: "cancel_stream",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<List<WatchedPage>> crateApiExposedFunctionsCheckForUpdates()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_watched_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCheckForUpdatesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCheckForUpdatesConstMeta => const TaskConstMeta(
+            debugName: "check_for_updates",
+            argNames: [],
+        );
+        
+
+@override Future<List<WatchedPlan>> crateApiExposedFunctionsCheckPlans()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_watched_plan,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCheckPlansConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCheckPlansConstMeta => const TaskConstMeta(
+            debugName: "check_plans",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsCheckpointProfile()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCheckpointProfileConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCheckpointProfileConstMeta => const TaskConstMeta(
+            debugName: "checkpoint_profile",
+            argNames: [],
+        );
+        
+
+@override String? crateApiExposedFunctionsChecksumLink({required String pageUrl , required String mime , required String body , required String fileUrl })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pageUrl, serializer);
+sse_encode_String(mime, serializer);
+sse_encode_String(body, serializer);
+sse_encode_String(fileUrl, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsChecksumLinkConstMeta,
+            argValues: [pageUrl, mime, body, fileUrl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsChecksumLinkConstMeta => const TaskConstMeta(
+            debugName: "checksum_link",
+            argNames: ["pageUrl", "mime", "body", "fileUrl"],
+        );
+        
+
+@override List<ClearedData> crateApiExposedFunctionsClearData({required List<DataCategory> categories , PlatformInt64? since })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_data_category(categories, serializer);
+sse_encode_opt_box_autoadd_i_64(since, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cleared_data,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsClearDataConstMeta,
+            argValues: [categories, since],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsClearDataConstMeta => const TaskConstMeta(
+            debugName: "clear_data",
+            argNames: ["categories", "since"],
+        );
+        
+
+@override bool crateApiExposedFunctionsCloseDocument({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCloseDocumentConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCloseDocumentConstMeta => const TaskConstMeta(
+            debugName: "close_document",
+            argNames: ["handle"],
+        );
+        
+
+@override bool crateApiExposedFunctionsCloseGempub({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCloseGempubConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCloseGempubConstMeta => const TaskConstMeta(
+            debugName: "close_gempub",
+            argNames: ["handle"],
+        );
+        
+
+@override bool crateApiExposedFunctionsCloseMenu({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCloseMenuConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCloseMenuConstMeta => const TaskConstMeta(
+            debugName: "close_menu",
+            argNames: ["handle"],
+        );
+        
+
+@override String crateApiExposedFunctionsCreateIdentity({required String commonName , required int validDays })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(commonName, serializer);
+sse_encode_u_32(validDays, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCreateIdentityConstMeta,
+            argValues: [commonName, validDays],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCreateIdentityConstMeta => const TaskConstMeta(
+            debugName: "create_identity",
+            argNames: ["commonName", "validDays"],
+        );
+        
+
+@override Future<List<CsoRecord>> crateApiExposedFunctionsCsoQuery({required String url , required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cso_record,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsCsoQueryConstMeta,
+            argValues: [url, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsCsoQueryConstMeta => const TaskConstMeta(
+            debugName: "cso_query",
+            argNames: ["url", "query"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeactivateIdentity({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeactivateIdentityConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeactivateIdentityConstMeta => const TaskConstMeta(
+            debugName: "deactivate_identity",
+            argNames: ["url"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeleteArchived({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteArchivedConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteArchivedConstMeta => const TaskConstMeta(
+            debugName: "delete_archived",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeleteDownload({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteDownloadConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteDownloadConstMeta => const TaskConstMeta(
+            debugName: "delete_download",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeleteHistoryEntry({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteHistoryEntryConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteHistoryEntryConstMeta => const TaskConstMeta(
+            debugName: "delete_history_entry",
+            argNames: ["id"],
+        );
+        
+
+@override int crateApiExposedFunctionsDeleteHistoryUrl({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteHistoryUrlConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteHistoryUrlConstMeta => const TaskConstMeta(
+            debugName: "delete_history_url",
+            argNames: ["url"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeleteSavedAnswer({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteSavedAnswerConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteSavedAnswerConstMeta => const TaskConstMeta(
+            debugName: "delete_saved_answer",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDeleteSession({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDeleteSessionConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDeleteSessionConstMeta => const TaskConstMeta(
+            debugName: "delete_session",
+            argNames: ["name"],
+        );
+        
+
+@override FileType? crateApiExposedFunctionsDetectFileType({required List<int> content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(content, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDetectFileTypeConstMeta,
+            argValues: [content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDetectFileTypeConstMeta => const TaskConstMeta(
+            debugName: "detect_file_type",
+            argNames: ["content"],
+        );
+        
+
+@override Future<PageDiff> crateApiExposedFunctionsDiffWithCache({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_page_diff,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDiffWithCacheConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDiffWithCacheConstMeta => const TaskConstMeta(
+            debugName: "diff_with_cache",
+            argNames: ["url"],
+        );
+        
+
+@override bool crateApiExposedFunctionsDismissHostMigration({required String scheme , required String fromHost })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_String(fromHost, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDismissHostMigrationConstMeta,
+            argValues: [scheme, fromHost],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDismissHostMigrationConstMeta => const TaskConstMeta(
+            debugName: "dismiss_host_migration",
+            argNames: ["scheme", "fromHost"],
+        );
+        
+
+@override DisplayHost crateApiExposedFunctionsDisplayHost({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_display_host,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDisplayHostConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDisplayHostConstMeta => const TaskConstMeta(
+            debugName: "display_host",
+            argNames: ["url"],
+        );
+        
+
+@override String crateApiExposedFunctionsDisplayUrl({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDisplayUrlConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDisplayUrlConstMeta => const TaskConstMeta(
+            debugName: "display_url",
+            argNames: ["url"],
+        );
+        
+
+@override List<OutlineEntry> crateApiExposedFunctionsDocumentOutline({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_outline_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDocumentOutlineConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDocumentOutlineConstMeta => const TaskConstMeta(
+            debugName: "document_outline",
+            argNames: ["handle"],
+        );
+        
+
+@override ReadingStats crateApiExposedFunctionsDocumentStats({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reading_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDocumentStatsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDocumentStatsConstMeta => const TaskConstMeta(
+            debugName: "document_stats",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<DownloadResult> crateApiExposedFunctionsDownload({required String url , required String destPath , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(destPath, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_download_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDownloadConstMeta,
+            argValues: [url, destPath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDownloadConstMeta => const TaskConstMeta(
+            debugName: "download",
+            argNames: ["url", "destPath", "options"],
+        );
+        
+
+@override Future<DownloadResult> crateApiExposedFunctionsDownloadVerified({required String url , required String destPath , String? checksumUrl , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(destPath, serializer);
+sse_encode_opt_String(checksumUrl, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_download_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsDownloadVerifiedConstMeta,
+            argValues: [url, destPath, checksumUrl, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsDownloadVerifiedConstMeta => const TaskConstMeta(
+            debugName: "download_verified",
+            argNames: ["url", "destPath", "checksumUrl", "options"],
+        );
+        
+
+@override int crateApiExposedFunctionsEndPrivateSession()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsEndPrivateSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsEndPrivateSessionConstMeta => const TaskConstMeta(
+            debugName: "end_private_session",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsEvictCacheEntry({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsEvictCacheEntryConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsEvictCacheEntryConstMeta => const TaskConstMeta(
+            debugName: "evict_cache_entry",
+            argNames: ["url"],
+        );
+        
+
+@override List<ExpiryReminder> crateApiExposedFunctionsExpiringIdentities({required int withinDays })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(withinDays, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_expiry_reminder,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExpiringIdentitiesConstMeta,
+            argValues: [withinDays],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExpiringIdentitiesConstMeta => const TaskConstMeta(
+            debugName: "expiring_identities",
+            argNames: ["withinDays"],
+        );
+        
+
+@override String crateApiExposedFunctionsExportBookmarks({required BookmarkFormat format })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bookmark_format(format, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExportBookmarksConstMeta,
+            argValues: [format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExportBookmarksConstMeta => const TaskConstMeta(
+            debugName: "export_bookmarks",
+            argNames: ["format"],
+        );
+        
+
+@override int crateApiExposedFunctionsExportBookmarksToFile({required BookmarkFormat format , required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bookmark_format(format, serializer);
+sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExportBookmarksToFileConstMeta,
+            argValues: [format, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExportBookmarksToFileConstMeta => const TaskConstMeta(
+            debugName: "export_bookmarks_to_file",
+            argNames: ["format", "path"],
+        );
+        
+
+@override String crateApiExposedFunctionsExportIdentityPem({required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExportIdentityPemConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExportIdentityPemConstMeta => const TaskConstMeta(
+            debugName: "export_identity_pem",
+            argNames: ["id"],
+        );
+        
+
+@override Uint8List crateApiExposedFunctionsExportIdentityPkcs12({required String id , required String password })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(password, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExportIdentityPkcs12ConstMeta,
+            argValues: [id, password],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExportIdentityPkcs12ConstMeta => const TaskConstMeta(
+            debugName: "export_identity_pkcs12",
+            argNames: ["id", "password"],
+        );
+        
+
+@override String crateApiExposedFunctionsExportKnownHosts({required KnownHostsFormat format })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_known_hosts_format(format, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExportKnownHostsConstMeta,
+            argValues: [format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExportKnownHostsConstMeta => const TaskConstMeta(
+            debugName: "export_known_hosts",
+            argNames: ["format"],
+        );
+        
+
+@override List<PageLink> crateApiExposedFunctionsExtractLinks({required String url , required String body , required String mime })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(body, serializer);
+sse_encode_String(mime, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_page_link,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsExtractLinksConstMeta,
+            argValues: [url, body, mime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsExtractLinksConstMeta => const TaskConstMeta(
+            debugName: "extract_links",
+            argNames: ["url", "body", "mime"],
+        );
+        
+
+@override Future<FetchResponse> crateApiExposedFunctionsFetch({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFetchConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFetchConstMeta => const TaskConstMeta(
+            debugName: "fetch",
+            argNames: ["url"],
+        );
+        
+
+@override Future<InlineImages> crateApiExposedFunctionsFetchDocumentImages({required BigInt handle , required String pageUrl , int? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(pageUrl, serializer);
+sse_encode_opt_box_autoadd_u_32(maxBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_inline_images,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFetchDocumentImagesConstMeta,
+            argValues: [handle, pageUrl, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFetchDocumentImagesConstMeta => const TaskConstMeta(
+            debugName: "fetch_document_images",
+            argNames: ["handle", "pageUrl", "maxBytes"],
+        );
+        
+
+@override Future<FetchResponse> crateApiExposedFunctionsFetchWithOptions({required String url , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFetchWithOptionsConstMeta,
+            argValues: [url, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFetchWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "fetch_with_options",
+            argNames: ["url", "options"],
+        );
+        
+
+@override Bookmark? crateApiExposedFunctionsFindDuplicateBookmark({required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bookmark,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFindDuplicateBookmarkConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFindDuplicateBookmarkConstMeta => const TaskConstMeta(
+            debugName: "find_duplicate_bookmark",
+            argNames: ["url"],
+        );
+        
+
+@override int? crateApiExposedFunctionsFindDuplicateTab({required String url , required List<String> openTabs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_list_String(openTabs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFindDuplicateTabConstMeta,
+            argValues: [url, openTabs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFindDuplicateTabConstMeta => const TaskConstMeta(
+            debugName: "find_duplicate_tab",
+            argNames: ["url", "openTabs"],
+        );
+        
+
+@override Future<FetchResponse> crateApiExposedFunctionsFinishFetch({required BigInt requestId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(requestId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsFinishFetchConstMeta,
+            argValues: [requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsFinishFetchConstMeta => const TaskConstMeta(
+            debugName: "finish_fetch",
+            argNames: ["requestId"],
+        );
+        
+
+@override bool crateApiExposedFunctionsForgetKnownHost({required String host , required int port })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+sse_encode_u_16(port, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsForgetKnownHostConstMeta,
+            argValues: [host, port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsForgetKnownHostConstMeta => const TaskConstMeta(
+            debugName: "forget_known_host",
+            argNames: ["host", "port"],
+        );
+        
+
+@override GempubPage crateApiExposedFunctionsGempubChapter({required BigInt handle , required int chapter })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_u_32(chapter, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gempub_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGempubChapterConstMeta,
+            argValues: [handle, chapter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGempubChapterConstMeta => const TaskConstMeta(
+            debugName: "gempub_chapter",
+            argNames: ["handle", "chapter"],
+        );
+        
+
+@override GempubPage crateApiExposedFunctionsGempubFile({required BigInt handle , String? fromPath , required String link })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_opt_String(fromPath, serializer);
+sse_encode_String(link, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gempub_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGempubFileConstMeta,
+            argValues: [handle, fromPath, link],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGempubFileConstMeta => const TaskConstMeta(
+            debugName: "gempub_file",
+            argNames: ["handle", "fromPath", "link"],
+        );
+        
+
+@override List<OutlineEntry> crateApiExposedFunctionsGemtextOutline({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_outline_entry,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGemtextOutlineConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGemtextOutlineConstMeta => const TaskConstMeta(
+            debugName: "gemtext_outline",
+            argNames: ["body"],
+        );
+        
+
+@override ReadingStats crateApiExposedFunctionsGemtextStats({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reading_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGemtextStatsConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGemtextStatsConstMeta => const TaskConstMeta(
+            debugName: "gemtext_stats",
+            argNames: ["body"],
+        );
+        
+
+@override Uint8List crateApiExposedFunctionsGenerateStorageKey()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGenerateStorageKeyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGenerateStorageKeyConstMeta => const TaskConstMeta(
+            debugName: "generate_storage_key",
+            argNames: [],
+        );
+        
+
+@override BigInt? crateApiExposedFunctionsGetBandwidthLimit()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetBandwidthLimitConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetBandwidthLimitConstMeta => const TaskConstMeta(
+            debugName: "get_bandwidth_limit",
+            argNames: [],
+        );
+        
+
+@override List<CachePolicy> crateApiExposedFunctionsGetCachePolicies()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cache_policy,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetCachePoliciesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetCachePoliciesConstMeta => const TaskConstMeta(
+            debugName: "get_cache_policies",
+            argNames: [],
+        );
+        
+
+@override TlsFailure? crateApiExposedFunctionsGetCertificateFailure({required String host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_tls_failure,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetCertificateFailureConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetCertificateFailureConstMeta => const TaskConstMeta(
+            debugName: "get_certificate_failure",
+            argNames: ["host"],
+        );
+        
+
+@override ClockOffset crateApiExposedFunctionsGetClockOffset()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_clock_offset,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetClockOffsetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetClockOffsetConstMeta => const TaskConstMeta(
+            debugName: "get_clock_offset",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsGetClockSkewTolerance()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetClockSkewToleranceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetClockSkewToleranceConstMeta => const TaskConstMeta(
+            debugName: "get_clock_skew_tolerance",
+            argNames: [],
+        );
+        
+
+@override Config crateApiExposedFunctionsGetConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetConfigConstMeta => const TaskConstMeta(
+            debugName: "get_config",
+            argNames: [],
+        );
+        
+
+@override DataCapStatus crateApiExposedFunctionsGetDataCapStatus()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_data_cap_status,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetDataCapStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetDataCapStatusConstMeta => const TaskConstMeta(
+            debugName: "get_data_cap_status",
+            argNames: [],
+        );
+        
+
+@override List<DailyUsage> crateApiExposedFunctionsGetDataUsage({required PlatformInt64 since , required PlatformInt64 until })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(since, serializer);
+sse_encode_i_64(until, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_daily_usage,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetDataUsageConstMeta,
+            argValues: [since, until],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetDataUsageConstMeta => const TaskConstMeta(
+            debugName: "get_data_usage",
+            argNames: ["since", "until"],
+        );
+        
+
+@override DecompressionLimits crateApiExposedFunctionsGetDecompressionLimits()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_decompression_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetDecompressionLimitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetDecompressionLimitsConstMeta => const TaskConstMeta(
+            debugName: "get_decompression_limits",
+            argNames: [],
+        );
+        
+
+@override List<HistoryResult> crateApiExposedFunctionsGetHistoryPage({required int offset , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(offset, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetHistoryPageConstMeta,
+            argValues: [offset, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetHistoryPageConstMeta => const TaskConstMeta(
+            debugName: "get_history_page",
+            argNames: ["offset", "limit"],
+        );
+        
+
+@override HostSettings? crateApiExposedFunctionsGetHostSettings({required String host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_host_settings,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetHostSettingsConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetHostSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_host_settings",
+            argNames: ["host"],
+        );
+        
+
+@override List<HostStats> crateApiExposedFunctionsGetHostStats({required HostStatsOrder order , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_host_stats_order(order, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_host_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetHostStatsConstMeta,
+            argValues: [order, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetHostStatsConstMeta => const TaskConstMeta(
+            debugName: "get_host_stats",
+            argNames: ["order", "limit"],
+        );
+        
+
+@override int crateApiExposedFunctionsGetLineCount({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetLineCountConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetLineCountConstMeta => const TaskConstMeta(
+            debugName: "get_line_count",
+            argNames: ["handle"],
+        );
+        
+
+@override List<GemtextLine> crateApiExposedFunctionsGetLines({required BigInt handle , required int start , required int count })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_u_32(start, serializer);
+sse_encode_u_32(count, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_gemtext_line,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetLinesConstMeta,
+            argValues: [handle, start, count],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetLinesConstMeta => const TaskConstMeta(
+            debugName: "get_lines",
+            argNames: ["handle", "start", "count"],
+        );
+        
+
+@override bool crateApiExposedFunctionsGetLiteMode()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetLiteModeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetLiteModeConstMeta => const TaskConstMeta(
+            debugName: "get_lite_mode",
+            argNames: [],
+        );
+        
+
+@override LogLevel crateApiExposedFunctionsGetLogLevel()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_log_level,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetLogLevelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "get_log_level",
+            argNames: [],
+        );
+        
+
+@override int crateApiExposedFunctionsGetMaxConcurrentHandshakes()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetMaxConcurrentHandshakesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetMaxConcurrentHandshakesConstMeta => const TaskConstMeta(
+            debugName: "get_max_concurrent_handshakes",
+            argNames: [],
+        );
+        
+
+@override MenuPage crateApiExposedFunctionsGetMenuPage({required BigInt handle , required int offset , required int count })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_u_32(offset, serializer);
+sse_encode_u_32(count, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_menu_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetMenuPageConstMeta,
+            argValues: [handle, offset, count],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetMenuPageConstMeta => const TaskConstMeta(
+            debugName: "get_menu_page",
+            argNames: ["handle", "offset", "count"],
+        );
+        
+
+@override List<ProtocolInfo> crateApiExposedFunctionsGetProtocols()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_protocol_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetProtocolsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetProtocolsConstMeta => const TaskConstMeta(
+            debugName: "get_protocols",
+            argNames: [],
+        );
+        
+
+@override ProxySettings crateApiExposedFunctionsGetProxySettings()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_proxy_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetProxySettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetProxySettingsConstMeta => const TaskConstMeta(
+            debugName: "get_proxy_settings",
+            argNames: [],
+        );
+        
+
+@override RateLimits crateApiExposedFunctionsGetRateLimits()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rate_limits,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetRateLimitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetRateLimitsConstMeta => const TaskConstMeta(
+            debugName: "get_rate_limits",
+            argNames: [],
+        );
+        
+
+@override List<HistoryEntry> crateApiExposedFunctionsGetRecentHistory({required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetRecentHistoryConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetRecentHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_recent_history",
+            argNames: ["limit"],
+        );
+        
+
+@override bool crateApiExposedFunctionsGetRememberInputAnswers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetRememberInputAnswersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetRememberInputAnswersConstMeta => const TaskConstMeta(
+            debugName: "get_remember_input_answers",
+            argNames: [],
+        );
+        
+
+@override List<SavedAnswer> crateApiExposedFunctionsGetSavedAnswers({required String url , required String prompt })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(prompt, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_saved_answer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetSavedAnswersConstMeta,
+            argValues: [url, prompt],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetSavedAnswersConstMeta => const TaskConstMeta(
+            debugName: "get_saved_answers",
+            argNames: ["url", "prompt"],
+        );
+        
+
+@override List<TaskSchedule> crateApiExposedFunctionsGetSchedules()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_task_schedule,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetSchedulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetSchedulesConstMeta => const TaskConstMeta(
+            debugName: "get_schedules",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsGetSearchOnFailure()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetSearchOnFailureConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetSearchOnFailureConstMeta => const TaskConstMeta(
+            debugName: "get_search_on_failure",
+            argNames: [],
+        );
+        
+
+@override String crateApiExposedFunctionsGetSearchProvider()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetSearchProviderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetSearchProviderConstMeta => const TaskConstMeta(
+            debugName: "get_search_provider",
+            argNames: [],
+        );
+        
+
+@override String crateApiExposedFunctionsGetStartPage()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetStartPageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetStartPageConstMeta => const TaskConstMeta(
+            debugName: "get_start_page",
+            argNames: [],
+        );
+        
+
+@override List<TopSite> crateApiExposedFunctionsGetTopHosts({required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_top_site,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetTopHostsConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetTopHostsConstMeta => const TaskConstMeta(
+            debugName: "get_top_hosts",
+            argNames: ["limit"],
+        );
+        
+
+@override List<TopSite> crateApiExposedFunctionsGetTopSites({required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_top_site,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetTopSitesConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetTopSitesConstMeta => const TaskConstMeta(
+            debugName: "get_top_sites",
+            argNames: ["limit"],
+        );
+        
+
+@override List<TraceEvent>? crateApiExposedFunctionsGetTrace({required BigInt requestId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(requestId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_trace_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGetTraceConstMeta,
+            argValues: [requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGetTraceConstMeta => const TaskConstMeta(
+            debugName: "get_trace",
+            argNames: ["requestId"],
+        );
+        
+
+@override String crateApiExposedFunctionsGophermapToGemtext({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGophermapToGemtextConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGophermapToGemtextConstMeta => const TaskConstMeta(
+            debugName: "gophermap_to_gemtext",
+            argNames: ["body"],
+        );
+        
+
+@override String crateApiExposedFunctionsGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override List<HighlightedLine>? crateApiExposedFunctionsHighlightCode({required String code , required String language })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(code, serializer);
+sse_encode_String(language, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_highlighted_line,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsHighlightCodeConstMeta,
+            argValues: [code, language],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsHighlightCodeConstMeta => const TaskConstMeta(
+            debugName: "highlight_code",
+            argNames: ["code", "language"],
+        );
+        
+
+@override List<String> crateApiExposedFunctionsHighlightLanguages()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsHighlightLanguagesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsHighlightLanguagesConstMeta => const TaskConstMeta(
+            debugName: "highlight_languages",
+            argNames: [],
+        );
+        
+
+@override List<HighlightedBlock> crateApiExposedFunctionsHighlightPreformatted({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_highlighted_block,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsHighlightPreformattedConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsHighlightPreformattedConstMeta => const TaskConstMeta(
+            debugName: "highlight_preformatted",
+            argNames: ["body"],
+        );
+        
+
+@override BookmarksImport crateApiExposedFunctionsImportBookmarks({required BookmarkSource source , required String data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bookmark_source(source, serializer);
+sse_encode_String(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bookmarks_import,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportBookmarksConstMeta,
+            argValues: [source, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportBookmarksConstMeta => const TaskConstMeta(
+            debugName: "import_bookmarks",
+            argNames: ["source", "data"],
+        );
+        
+
+@override List<TrustedCa> crateApiExposedFunctionsImportCaCertificates({required String pem })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pem, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_ca,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportCaCertificatesConstMeta,
+            argValues: [pem],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportCaCertificatesConstMeta => const TaskConstMeta(
+            debugName: "import_ca_certificates",
+            argNames: ["pem"],
+        );
+        
+
+@override Identity crateApiExposedFunctionsImportIdentityPem({String? name , required String certificatePem , String? privateKeyPem })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(name, serializer);
+sse_encode_String(certificatePem, serializer);
+sse_encode_opt_String(privateKeyPem, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_identity,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportIdentityPemConstMeta,
+            argValues: [name, certificatePem, privateKeyPem],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportIdentityPemConstMeta => const TaskConstMeta(
+            debugName: "import_identity_pem",
+            argNames: ["name", "certificatePem", "privateKeyPem"],
+        );
+        
+
+@override Identity crateApiExposedFunctionsImportIdentityPkcs12({String? name , required List<int> data , required String password })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(name, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_String(password, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_identity,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportIdentityPkcs12ConstMeta,
+            argValues: [name, data, password],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportIdentityPkcs12ConstMeta => const TaskConstMeta(
+            debugName: "import_identity_pkcs12",
+            argNames: ["name", "data", "password"],
+        );
+        
+
+@override KnownHostsImport crateApiExposedFunctionsImportKnownHosts({required KnownHostsFormat format , required String data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_known_hosts_format(format, serializer);
+sse_encode_String(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_known_hosts_import,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportKnownHostsConstMeta,
+            argValues: [format, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportKnownHostsConstMeta => const TaskConstMeta(
+            debugName: "import_known_hosts",
+            argNames: ["format", "data"],
+        );
+        
+
+@override IdentitiesImport crateApiExposedFunctionsImportLagrangeIdentities({required String dir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_identities_import,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsImportLagrangeIdentitiesConstMeta,
+            argValues: [dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsImportLagrangeIdentitiesConstMeta => const TaskConstMeta(
+            debugName: "import_lagrange_identities",
+            argNames: ["dir"],
+        );
+        
+
+@override Future<CrawlResult> crateApiExposedFunctionsIndexCapsule({required String url , required int maxPages })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_32(maxPages, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crawl_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsIndexCapsuleConstMeta,
+            argValues: [url, maxPages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsIndexCapsuleConstMeta => const TaskConstMeta(
+            debugName: "index_capsule",
+            argNames: ["url", "maxPages"],
+        );
+        
+
+@override Future<void> crateApiExposedFunctionsInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsIsKeyStorageUnlocked()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsIsKeyStorageUnlockedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsIsKeyStorageUnlockedConstMeta => const TaskConstMeta(
+            debugName: "is_key_storage_unlocked",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsIsOfflineMode()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsIsOfflineModeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsIsOfflineModeConstMeta => const TaskConstMeta(
+            debugName: "is_offline_mode",
+            argNames: [],
+        );
+        
+
+@override List<ArchivedPage> crateApiExposedFunctionsListArchived({String? url , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(url, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_archived_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListArchivedConstMeta,
+            argValues: [url, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListArchivedConstMeta => const TaskConstMeta(
+            debugName: "list_archived",
+            argNames: ["url", "limit"],
+        );
+        
+
+@override List<BlockRule> crateApiExposedFunctionsListBlockRules()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_block_rule,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListBlockRulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListBlockRulesConstMeta => const TaskConstMeta(
+            debugName: "list_block_rules",
+            argNames: [],
+        );
+        
+
+@override List<Bookmark> crateApiExposedFunctionsListBookmarks()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_bookmark,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListBookmarksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListBookmarksConstMeta => const TaskConstMeta(
+            debugName: "list_bookmarks",
+            argNames: [],
+        );
+        
+
+@override List<CacheEntry> crateApiExposedFunctionsListCacheEntries({String? host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cache_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListCacheEntriesConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListCacheEntriesConstMeta => const TaskConstMeta(
+            debugName: "list_cache_entries",
+            argNames: ["host"],
+        );
+        
+
+@override List<DownloadRecord> crateApiExposedFunctionsListDownloads({DownloadStatus? status , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_download_status(status, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_download_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListDownloadsConstMeta,
+            argValues: [status, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListDownloadsConstMeta => const TaskConstMeta(
+            debugName: "list_downloads",
+            argNames: ["status", "limit"],
+        );
+        
+
+@override List<FeedEntry> crateApiExposedFunctionsListFeedEntries({PlatformInt64? feedId , required bool unreadOnly , required int offset , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_i_64(feedId, serializer);
+sse_encode_bool(unreadOnly, serializer);
+sse_encode_u_32(offset, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_feed_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListFeedEntriesConstMeta,
+            argValues: [feedId, unreadOnly, offset, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListFeedEntriesConstMeta => const TaskConstMeta(
+            debugName: "list_feed_entries",
+            argNames: ["feedId", "unreadOnly", "offset", "limit"],
+        );
+        
+
+@override List<Feed> crateApiExposedFunctionsListFeeds()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_feed,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListFeedsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListFeedsConstMeta => const TaskConstMeta(
+            debugName: "list_feeds",
+            argNames: [],
+        );
+        
+
+@override List<SchemeProxy> crateApiExposedFunctionsListGeminiProxies()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_scheme_proxy,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListGeminiProxiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListGeminiProxiesConstMeta => const TaskConstMeta(
+            debugName: "list_gemini_proxies",
+            argNames: [],
+        );
+        
+
+@override List<HostSettings> crateApiExposedFunctionsListHostSettings()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_host_settings,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListHostSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListHostSettingsConstMeta => const TaskConstMeta(
+            debugName: "list_host_settings",
+            argNames: [],
+        );
+        
+
+@override List<Identity> crateApiExposedFunctionsListIdentities()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_identity,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListIdentitiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListIdentitiesConstMeta => const TaskConstMeta(
+            debugName: "list_identities",
+            argNames: [],
+        );
+        
+
+@override List<IdentityScope> crateApiExposedFunctionsListIdentityScopes()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_identity_scope,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListIdentityScopesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListIdentityScopesConstMeta => const TaskConstMeta(
+            debugName: "list_identity_scopes",
+            argNames: [],
+        );
+        
+
+@override List<KnownHost> crateApiExposedFunctionsListKnownHosts()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_known_host,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListKnownHostsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListKnownHostsConstMeta => const TaskConstMeta(
+            debugName: "list_known_hosts",
+            argNames: [],
+        );
+        
+
+@override List<PortOverride> crateApiExposedFunctionsListPortOverrides()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_port_override,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListPortOverridesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListPortOverridesConstMeta => const TaskConstMeta(
+            debugName: "list_port_overrides",
+            argNames: [],
+        );
+        
+
+@override List<ReadLaterItem> crateApiExposedFunctionsListReadLater({required bool includeRead })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(includeRead, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_read_later_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListReadLaterConstMeta,
+            argValues: [includeRead],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListReadLaterConstMeta => const TaskConstMeta(
+            debugName: "list_read_later",
+            argNames: ["includeRead"],
+        );
+        
+
+@override List<SavedAnswer> crateApiExposedFunctionsListSavedAnswers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_saved_answer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListSavedAnswersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListSavedAnswersConstMeta => const TaskConstMeta(
+            debugName: "list_saved_answers",
+            argNames: [],
+        );
+        
+
+@override List<SessionSummary> crateApiExposedFunctionsListSessions()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_session_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListSessionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListSessionsConstMeta => const TaskConstMeta(
+            debugName: "list_sessions",
+            argNames: [],
+        );
+        
+
+@override List<TrustedCa> crateApiExposedFunctionsListTrustedCas()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_trusted_ca,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListTrustedCasConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListTrustedCasConstMeta => const TaskConstMeta(
+            debugName: "list_trusted_cas",
+            argNames: [],
+        );
+        
+
+@override List<WatchedPage> crateApiExposedFunctionsListWatchedPages()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_watched_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListWatchedPagesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListWatchedPagesConstMeta => const TaskConstMeta(
+            debugName: "list_watched_pages",
+            argNames: [],
+        );
+        
+
+@override List<WatchedPlan> crateApiExposedFunctionsListWatchedPlans()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_watched_plan,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsListWatchedPlansConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsListWatchedPlansConstMeta => const TaskConstMeta(
+            debugName: "list_watched_plans",
+            argNames: [],
+        );
+        
+
+@override Session? crateApiExposedFunctionsLoadSession({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_session,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsLoadSessionConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsLoadSessionConstMeta => const TaskConstMeta(
+            debugName: "load_session",
+            argNames: ["name"],
+        );
+        
+
+@override void crateApiExposedFunctionsLockKeyStorage()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsLockKeyStorageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsLockKeyStorageConstMeta => const TaskConstMeta(
+            debugName: "lock_key_storage",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiExposedFunctionsLogFiles()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsLogFilesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsLogFilesConstMeta => const TaskConstMeta(
+            debugName: "log_files",
+            argNames: [],
+        );
+        
+
+@override Future<SiteMap> crateApiExposedFunctionsMapSite({required String url , required int depth })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_32(depth, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_site_map,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMapSiteConstMeta,
+            argValues: [url, depth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMapSiteConstMeta => const TaskConstMeta(
+            debugName: "map_site",
+            argNames: ["url", "depth"],
+        );
+        
+
+@override int crateApiExposedFunctionsMarkAllFeedEntriesRead({PlatformInt64? feedId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_i_64(feedId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMarkAllFeedEntriesReadConstMeta,
+            argValues: [feedId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMarkAllFeedEntriesReadConstMeta => const TaskConstMeta(
+            debugName: "mark_all_feed_entries_read",
+            argNames: ["feedId"],
+        );
+        
+
+@override void crateApiExposedFunctionsMarkPlanRead({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMarkPlanReadConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMarkPlanReadConstMeta => const TaskConstMeta(
+            debugName: "mark_plan_read",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsMarkReadLater({required PlatformInt64 id , required bool read })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+sse_encode_bool(read, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMarkReadLaterConstMeta,
+            argValues: [id, read],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMarkReadLaterConstMeta => const TaskConstMeta(
+            debugName: "mark_read_later",
+            argNames: ["id", "read"],
+        );
+        
+
+@override List<PreformattedBlock> crateApiExposedFunctionsMeasurePreformatted({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_preformatted_block,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMeasurePreformattedConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMeasurePreformattedConstMeta => const TaskConstMeta(
+            debugName: "measure_preformatted",
+            argNames: ["body"],
+        );
+        
+
+@override HostMigrationResult crateApiExposedFunctionsMigrateHost({required String scheme , required String fromHost , required String toHost })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_String(fromHost, serializer);
+sse_encode_String(toHost, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_host_migration_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMigrateHostConstMeta,
+            argValues: [scheme, fromHost, toHost],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMigrateHostConstMeta => const TaskConstMeta(
+            debugName: "migrate_host",
+            argNames: ["scheme", "fromHost", "toHost"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsMirrorCapsule({required String url , required String destDir , required int depth })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(destDir, serializer);
+sse_encode_u_32(depth, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsMirrorCapsuleConstMeta,
+            argValues: [url, destDir, depth],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsMirrorCapsuleConstMeta => const TaskConstMeta(
+            debugName: "mirror_capsule",
+            argNames: ["url", "destDir", "depth"],
+        );
+        
+
+@override Future<NavigationResult> crateApiExposedFunctionsNavigate({required String url , required bool forceRefresh })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_bool(forceRefresh, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_navigation_result,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNavigateConstMeta,
+            argValues: [url, forceRefresh],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNavigateConstMeta => const TaskConstMeta(
+            debugName: "navigate",
+            argNames: ["url", "forceRefresh"],
+        );
+        
+
+@override Future<NavigationResult> crateApiExposedFunctionsNavigateCancellable({required String url , required BigInt requestId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_64(requestId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_navigation_result,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNavigateCancellableConstMeta,
+            argValues: [url, requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNavigateCancellableConstMeta => const TaskConstMeta(
+            debugName: "navigate_cancellable",
+            argNames: ["url", "requestId"],
+        );
+        
+
+@override ErrorInfo crateApiExposedFunctionsNavigateErrorInfo({required NavigateError error })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_navigate_error(error, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_error_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNavigateErrorInfoConstMeta,
+            argValues: [error],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNavigateErrorInfoConstMeta => const TaskConstMeta(
+            debugName: "navigate_error_info",
+            argNames: ["error"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsNavigateMany({required List<String> urls , required FetchOptions options , required int maxConcurrent })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(urls, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+sse_encode_u_32(maxConcurrent, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNavigateManyConstMeta,
+            argValues: [urls, options, maxConcurrent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNavigateManyConstMeta => const TaskConstMeta(
+            debugName: "navigate_many",
+            argNames: ["urls", "options", "maxConcurrent"],
+        );
+        
+
+@override Future<NavigationResult> crateApiExposedFunctionsNavigateWithOptions({required String url , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_navigation_result,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNavigateWithOptionsConstMeta,
+            argValues: [url, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNavigateWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "navigate_with_options",
+            argNames: ["url", "options"],
+        );
+        
+
+@override Future<BatchItem?> crateApiExposedFunctionsNextBatchResult({required BigInt handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_batch_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextBatchResultConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextBatchResultConstMeta => const TaskConstMeta(
+            debugName: "next_batch_result",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<ExpiryReminder> crateApiExposedFunctionsNextExpiryReminder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_expiry_reminder,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextExpiryReminderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextExpiryReminderConstMeta => const TaskConstMeta(
+            debugName: "next_expiry_reminder",
+            argNames: [],
+        );
+        
+
+@override Future<NavigationEvent?> crateApiExposedFunctionsNextFetchUpdate({required BigInt requestId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(requestId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_navigation_event,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextFetchUpdateConstMeta,
+            argValues: [requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextFetchUpdateConstMeta => const TaskConstMeta(
+            debugName: "next_fetch_update",
+            argNames: ["requestId"],
+        );
+        
+
+@override Future<LogRecord> crateApiExposedFunctionsNextLogRecord({required BigInt subscription })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_log_record,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextLogRecordConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextLogRecordConstMeta => const TaskConstMeta(
+            debugName: "next_log_record",
+            argNames: ["subscription"],
+        );
+        
+
+@override Future<MirrorProgress?> crateApiExposedFunctionsNextMirrorProgress({required BigInt handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_mirror_progress,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextMirrorProgressConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextMirrorProgressConstMeta => const TaskConstMeta(
+            debugName: "next_mirror_progress",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<NavigationEvent> crateApiExposedFunctionsNextNavigationEvent({required BigInt subscription })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_navigation_event,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextNavigationEventConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextNavigationEventConstMeta => const TaskConstMeta(
+            debugName: "next_navigation_event",
+            argNames: ["subscription"],
+        );
+        
+
+@override Future<SchedulerEvent> crateApiExposedFunctionsNextSchedulerEvent({required BigInt subscription })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scheduler_event,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextSchedulerEventConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextSchedulerEventConstMeta => const TaskConstMeta(
+            debugName: "next_scheduler_event",
+            argNames: ["subscription"],
+        );
+        
+
+@override Future<StreamEvent> crateApiExposedFunctionsNextStreamEvent({required BigInt handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stream_event,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNextStreamEventConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNextStreamEventConstMeta => const TaskConstMeta(
+            debugName: "next_stream_event",
+            argNames: ["handle"],
+        );
+        
+
+@override String crateApiExposedFunctionsNormalizeUrl({required String input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(input, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsNormalizeUrlConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsNormalizeUrlConstMeta => const TaskConstMeta(
+            debugName: "normalize_url",
+            argNames: ["input"],
+        );
+        
+
+@override FetchResponse crateApiExposedFunctionsOpenArchived({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenArchivedConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenArchivedConstMeta => const TaskConstMeta(
+            debugName: "open_archived",
+            argNames: ["id"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsOpenDocument({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenDocumentConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenDocumentConstMeta => const TaskConstMeta(
+            debugName: "open_document",
+            argNames: ["body"],
+        );
+        
+
+@override Future<GempubBook> crateApiExposedFunctionsOpenGempub({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gempub_book,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenGempubConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenGempubConstMeta => const TaskConstMeta(
+            debugName: "open_gempub",
+            argNames: ["url"],
+        );
+        
+
+@override GempubBook crateApiExposedFunctionsOpenGempubData({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gempub_book,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenGempubDataConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenGempubDataConstMeta => const TaskConstMeta(
+            debugName: "open_gempub_data",
+            argNames: ["data"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsOpenMenu({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenMenuConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenMenuConstMeta => const TaskConstMeta(
+            debugName: "open_menu",
+            argNames: ["body"],
+        );
+        
+
+@override void crateApiExposedFunctionsOpenProfile({required String dir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenProfileConstMeta,
+            argValues: [dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenProfileConstMeta => const TaskConstMeta(
+            debugName: "open_profile",
+            argNames: ["dir"],
+        );
+        
+
+@override FetchResponse crateApiExposedFunctionsOpenReadLater({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenReadLaterConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenReadLaterConstMeta => const TaskConstMeta(
+            debugName: "open_read_later",
+            argNames: ["id"],
+        );
+        
+
+@override Future<BigInt> crateApiExposedFunctionsOpenStream({required String url , required StreamOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_box_autoadd_stream_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsOpenStreamConstMeta,
+            argValues: [url, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsOpenStreamConstMeta => const TaskConstMeta(
+            debugName: "open_stream",
+            argNames: ["url", "options"],
+        );
+        
+
+@override Future<PageSummary> crateApiExposedFunctionsPageSummary({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_page_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsPageSummaryConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsPageSummaryConstMeta => const TaskConstMeta(
+            debugName: "page_summary",
+            argNames: ["url"],
+        );
+        
+
+@override List<GopherItem> crateApiExposedFunctionsParseGopherMenu({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_gopher_item,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsParseGopherMenuConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsParseGopherMenuConstMeta => const TaskConstMeta(
+            debugName: "parse_gopher_menu",
+            argNames: ["body"],
+        );
+        
+
+@override Future<PeekResult> crateApiExposedFunctionsPeek({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_peek_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsPeekConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsPeekConstMeta => const TaskConstMeta(
+            debugName: "peek",
+            argNames: ["url"],
+        );
+        
+
+@override Future<void> crateApiExposedFunctionsPreconnect({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsPreconnectConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsPreconnectConstMeta => const TaskConstMeta(
+            debugName: "preconnect",
+            argNames: ["url"],
+        );
+        
+
+@override Future<int> crateApiExposedFunctionsPrefetch({required List<String> urls })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(urls, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsPrefetchConstMeta,
+            argValues: [urls],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsPrefetchConstMeta => const TaskConstMeta(
+            debugName: "prefetch",
+            argNames: ["urls"],
+        );
+        
+
+@override bool crateApiExposedFunctionsPrefetchAllowed()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsPrefetchAllowedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsPrefetchAllowedConstMeta => const TaskConstMeta(
+            debugName: "prefetch_allowed",
+            argNames: [],
+        );
+        
+
+@override Future<RandomDestination> crateApiExposedFunctionsRandomDestination({required List<ExploreSource> sources })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_explore_source(sources, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_random_destination,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRandomDestinationConstMeta,
+            argValues: [sources],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRandomDestinationConstMeta => const TaskConstMeta(
+            debugName: "random_destination",
+            argNames: ["sources"],
+        );
+        
+
+@override List<LogRecord> crateApiExposedFunctionsRecentLogs({required LogLevel minLevel , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_log_level(minLevel, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_log_record,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRecentLogsConstMeta,
+            argValues: [minLevel, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRecentLogsConstMeta => const TaskConstMeta(
+            debugName: "recent_logs",
+            argNames: ["minLevel", "limit"],
+        );
+        
+
+@override PlatformInt64 crateApiExposedFunctionsRecordDownload({required String url , required String path , required BigInt size , required String mime , required DownloadStatus status , String? error })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(path, serializer);
+sse_encode_u_64(size, serializer);
+sse_encode_String(mime, serializer);
+sse_encode_download_status(status, serializer);
+sse_encode_opt_String(error, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRecordDownloadConstMeta,
+            argValues: [url, path, size, mime, status, error],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRecordDownloadConstMeta => const TaskConstMeta(
+            debugName: "record_download",
+            argNames: ["url", "path", "size", "mime", "status", "error"],
+        );
+        
+
+@override List<ReflowedLine> crateApiExposedFunctionsReflowGemtext({required String body , required int maxCols })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+sse_encode_u_32(maxCols, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_reflowed_line,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsReflowGemtextConstMeta,
+            argValues: [body, maxCols],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsReflowGemtextConstMeta => const TaskConstMeta(
+            debugName: "reflow_gemtext",
+            argNames: ["body", "maxCols"],
+        );
+        
+
+@override String crateApiExposedFunctionsReflowText({required String text , required int maxCols })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_u_32(maxCols, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsReflowTextConstMeta,
+            argValues: [text, maxCols],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsReflowTextConstMeta => const TaskConstMeta(
+            debugName: "reflow_text",
+            argNames: ["text", "maxCols"],
+        );
+        
+
+@override Future<int> crateApiExposedFunctionsRefreshAllFeeds()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRefreshAllFeedsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRefreshAllFeedsConstMeta => const TaskConstMeta(
+            debugName: "refresh_all_feeds",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiExposedFunctionsRefreshBookmarks({required int maxAgeSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(maxAgeSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRefreshBookmarksConstMeta,
+            argValues: [maxAgeSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRefreshBookmarksConstMeta => const TaskConstMeta(
+            debugName: "refresh_bookmarks",
+            argNames: ["maxAgeSecs"],
+        );
+        
+
+@override Future<String?> crateApiExposedFunctionsRefreshFavicon({required String pageUrl , required FetchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pageUrl, serializer);
+sse_encode_box_autoadd_fetch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRefreshFaviconConstMeta,
+            argValues: [pageUrl, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRefreshFaviconConstMeta => const TaskConstMeta(
+            debugName: "refresh_favicon",
+            argNames: ["pageUrl", "options"],
+        );
+        
+
+@override Future<int> crateApiExposedFunctionsRefreshFeed({required PlatformInt64 id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRefreshFeedConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRefreshFeedConstMeta => const TaskConstMeta(
+            debugName: "refresh_feed",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveBlockRule({required String pattern })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(pattern, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveBlockRuleConstMeta,
+            argValues: [pattern],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveBlockRuleConstMeta => const TaskConstMeta(
+            debugName: "remove_block_rule",
+            argNames: ["pattern"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveBookmark({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveBookmarkConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveBookmarkConstMeta => const TaskConstMeta(
+            debugName: "remove_bookmark",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveGeminiProxy({required String scheme })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveGeminiProxyConstMeta,
+            argValues: [scheme],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveGeminiProxyConstMeta => const TaskConstMeta(
+            debugName: "remove_gemini_proxy",
+            argNames: ["scheme"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveHostSettings({required String host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveHostSettingsConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveHostSettingsConstMeta => const TaskConstMeta(
+            debugName: "remove_host_settings",
+            argNames: ["host"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemovePortOverride({required String scheme , String? host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_opt_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemovePortOverrideConstMeta,
+            argValues: [scheme, host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemovePortOverrideConstMeta => const TaskConstMeta(
+            debugName: "remove_port_override",
+            argNames: ["scheme", "host"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveReadLater({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveReadLaterConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveReadLaterConstMeta => const TaskConstMeta(
+            debugName: "remove_read_later",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveTitanToken({required String host })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveTitanTokenConstMeta,
+            argValues: [host],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveTitanTokenConstMeta => const TaskConstMeta(
+            debugName: "remove_titan_token",
+            argNames: ["host"],
+        );
+        
+
+@override bool crateApiExposedFunctionsRemoveTrustedCa({required String fingerprint })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(fingerprint, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRemoveTrustedCaConstMeta,
+            argValues: [fingerprint],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRemoveTrustedCaConstMeta => const TaskConstMeta(
+            debugName: "remove_trusted_ca",
+            argNames: ["fingerprint"],
+        );
+        
+
+@override String crateApiExposedFunctionsRenderToHtml({required String gemtext , required String baseUrl })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gemtext, serializer);
+sse_encode_String(baseUrl, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRenderToHtmlConstMeta,
+            argValues: [gemtext, baseUrl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRenderToHtmlConstMeta => const TaskConstMeta(
+            debugName: "render_to_html",
+            argNames: ["gemtext", "baseUrl"],
+        );
+        
+
+@override String crateApiExposedFunctionsRenderToMarkdown({required String body , required String baseUrl , required String mime })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+sse_encode_String(baseUrl, serializer);
+sse_encode_String(mime, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRenderToMarkdownConstMeta,
+            argValues: [body, baseUrl, mime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRenderToMarkdownConstMeta => const TaskConstMeta(
+            debugName: "render_to_markdown",
+            argNames: ["body", "baseUrl", "mime"],
+        );
+        
+
+@override Identity crateApiExposedFunctionsRenewIdentity({required String id , required bool rotateKey , int? validDays })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_bool(rotateKey, serializer);
+sse_encode_opt_box_autoadd_u_32(validDays, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_identity,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRenewIdentityConstMeta,
+            argValues: [id, rotateKey, validDays],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRenewIdentityConstMeta => const TaskConstMeta(
+            debugName: "renew_identity",
+            argNames: ["id", "rotateKey", "validDays"],
+        );
+        
+
+@override Future<RequestTimings> hollowayCoreApiModelsNavigationResultRequestTimingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_request_timings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kHollowayCoreApiModelsNavigationResultRequestTimingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kHollowayCoreApiModelsNavigationResultRequestTimingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "request_timings_default",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsReserveRequestId()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsReserveRequestIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsReserveRequestIdConstMeta => const TaskConstMeta(
+            debugName: "reserve_request_id",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsResetCachePolicy({required String scheme })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsResetCachePolicyConstMeta,
+            argValues: [scheme],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsResetCachePolicyConstMeta => const TaskConstMeta(
+            debugName: "reset_cache_policy",
+            argNames: ["scheme"],
+        );
+        
+
+@override String crateApiExposedFunctionsResolveLink({required String baseUrl , required String href })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(baseUrl, serializer);
+sse_encode_String(href, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsResolveLinkConstMeta,
+            argValues: [baseUrl, href],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsResolveLinkConstMeta => const TaskConstMeta(
+            debugName: "resolve_link",
+            argNames: ["baseUrl", "href"],
+        );
+        
+
+@override Renderer crateApiExposedFunctionsRouteContent({required String url , required String mime })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(mime, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_renderer,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsRouteContentConstMeta,
+            argValues: [url, mime],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsRouteContentConstMeta => const TaskConstMeta(
+            debugName: "route_content",
+            argNames: ["url", "mime"],
+        );
+        
+
+@override Future<ReadLaterItem> crateApiExposedFunctionsSaveForLater({required String url , String? title })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_opt_String(title, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_read_later_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSaveForLaterConstMeta,
+            argValues: [url, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSaveForLaterConstMeta => const TaskConstMeta(
+            debugName: "save_for_later",
+            argNames: ["url", "title"],
+        );
+        
+
+@override void crateApiExposedFunctionsSaveSession({required Session session })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_session(session, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSaveSessionConstMeta,
+            argValues: [session],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSaveSessionConstMeta => const TaskConstMeta(
+            debugName: "save_session",
+            argNames: ["session"],
+        );
+        
+
+@override Future<List<SchedulerEvent>> crateApiExposedFunctionsSchedulerTick()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_scheduler_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSchedulerTickConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSchedulerTickConstMeta => const TaskConstMeta(
+            debugName: "scheduler_tick",
+            argNames: [],
+        );
+        
+
+@override Future<NavigationResult> crateApiExposedFunctionsSearch({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_navigation_result,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchConstMeta => const TaskConstMeta(
+            debugName: "search",
+            argNames: ["query"],
+        );
+        
+
+@override Future<MetasearchResult> crateApiExposedFunctionsSearchAll({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_metasearch_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchAllConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchAllConstMeta => const TaskConstMeta(
+            debugName: "search_all",
+            argNames: ["query"],
+        );
+        
+
+@override List<LocalSearchResult> crateApiExposedFunctionsSearchCapsule({required String url , required String query , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_local_search_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchCapsuleConstMeta,
+            argValues: [url, query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchCapsuleConstMeta => const TaskConstMeta(
+            debugName: "search_capsule",
+            argNames: ["url", "query", "limit"],
+        );
+        
+
+@override List<HistoryResult> crateApiExposedFunctionsSearchHistory({required String query , required HistoryMatch mode , required HistoryRange range , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_history_match(mode, serializer);
+sse_encode_box_autoadd_history_range(range, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchHistoryConstMeta,
+            argValues: [query, mode, range, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchHistoryConstMeta => const TaskConstMeta(
+            debugName: "search_history",
+            argNames: ["query", "mode", "range", "limit"],
+        );
+        
+
+@override List<LocalSearchResult> crateApiExposedFunctionsSearchLocal({required String query , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_local_search_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchLocalConstMeta,
+            argValues: [query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchLocalConstMeta => const TaskConstMeta(
+            debugName: "search_local",
+            argNames: ["query", "limit"],
+        );
+        
+
+@override List<SearchProvider> crateApiExposedFunctionsSearchProviders()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_search_provider,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSearchProvidersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSearchProvidersConstMeta => const TaskConstMeta(
+            debugName: "search_providers",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsSetBandwidthLimit({BigInt? bytesPerSec })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_64(bytesPerSec, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetBandwidthLimitConstMeta,
+            argValues: [bytesPerSec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetBandwidthLimitConstMeta => const TaskConstMeta(
+            debugName: "set_bandwidth_limit",
+            argNames: ["bytesPerSec"],
+        );
+        
+
+@override bool crateApiExposedFunctionsSetBookmarkTitle({required PlatformInt64 id , String? title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+sse_encode_opt_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetBookmarkTitleConstMeta,
+            argValues: [id, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetBookmarkTitleConstMeta => const TaskConstMeta(
+            debugName: "set_bookmark_title",
+            argNames: ["id", "title"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetCachePolicy({required CachePolicy policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_cache_policy(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetCachePolicyConstMeta,
+            argValues: [policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetCachePolicyConstMeta => const TaskConstMeta(
+            debugName: "set_cache_policy",
+            argNames: ["policy"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetClockSkewTolerance({required BigInt secs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(secs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetClockSkewToleranceConstMeta,
+            argValues: [secs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetClockSkewToleranceConstMeta => const TaskConstMeta(
+            debugName: "set_clock_skew_tolerance",
+            argNames: ["secs"],
+        );
+        
+
+@override Config crateApiExposedFunctionsSetConfig({required Config config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_config,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetConfigConstMeta => const TaskConstMeta(
+            debugName: "set_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetDataCap({BigInt? limitBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_u_64(limitBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetDataCapConstMeta,
+            argValues: [limitBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetDataCapConstMeta => const TaskConstMeta(
+            debugName: "set_data_cap",
+            argNames: ["limitBytes"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetDecompressionLimits({required DecompressionLimits limits })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_decompression_limits(limits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetDecompressionLimitsConstMeta,
+            argValues: [limits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetDecompressionLimitsConstMeta => const TaskConstMeta(
+            debugName: "set_decompression_limits",
+            argNames: ["limits"],
+        );
+        
+
+@override bool crateApiExposedFunctionsSetFeedEntryRead({required PlatformInt64 id , required bool read })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+sse_encode_bool(read, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetFeedEntryReadConstMeta,
+            argValues: [id, read],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetFeedEntryReadConstMeta => const TaskConstMeta(
+            debugName: "set_feed_entry_read",
+            argNames: ["id", "read"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetGateway({String? urlTemplate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(urlTemplate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetGatewayConstMeta,
+            argValues: [urlTemplate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetGatewayConstMeta => const TaskConstMeta(
+            debugName: "set_gateway",
+            argNames: ["urlTemplate"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetGeminiProxy({required String scheme , required String host , required int port })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_String(host, serializer);
+sse_encode_u_16(port, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetGeminiProxyConstMeta,
+            argValues: [scheme, host, port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetGeminiProxyConstMeta => const TaskConstMeta(
+            debugName: "set_gemini_proxy",
+            argNames: ["scheme", "host", "port"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetGopherGateway({required String scheme , required String host , required int port , required String selector })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_String(host, serializer);
+sse_encode_u_16(port, serializer);
+sse_encode_String(selector, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetGopherGatewayConstMeta,
+            argValues: [scheme, host, port, selector],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetGopherGatewayConstMeta => const TaskConstMeta(
+            debugName: "set_gopher_gateway",
+            argNames: ["scheme", "host", "port", "selector"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetHostSettings({required HostSettings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_host_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetHostSettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetHostSettingsConstMeta => const TaskConstMeta(
+            debugName: "set_host_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetLiteMode({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetLiteModeConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetLiteModeConstMeta => const TaskConstMeta(
+            debugName: "set_lite_mode",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetLogDirectory({String? dir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(dir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetLogDirectoryConstMeta,
+            argValues: [dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetLogDirectoryConstMeta => const TaskConstMeta(
+            debugName: "set_log_directory",
+            argNames: ["dir"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetLogLevel({required LogLevel level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetLogLevelConstMeta,
+            argValues: [level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "set_log_level",
+            argNames: ["level"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetMaxConcurrentHandshakes({required int max })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(max, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetMaxConcurrentHandshakesConstMeta,
+            argValues: [max],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetMaxConcurrentHandshakesConstMeta => const TaskConstMeta(
+            debugName: "set_max_concurrent_handshakes",
+            argNames: ["max"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetNetworkType({required NetworkType network })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_network_type(network, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetNetworkTypeConstMeta,
+            argValues: [network],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetNetworkTypeConstMeta => const TaskConstMeta(
+            debugName: "set_network_type",
+            argNames: ["network"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetOfflineMode({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetOfflineModeConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetOfflineModeConstMeta => const TaskConstMeta(
+            debugName: "set_offline_mode",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetPortOverride({required String scheme , String? host , required int port })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_opt_String(host, serializer);
+sse_encode_u_16(port, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetPortOverrideConstMeta,
+            argValues: [scheme, host, port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetPortOverrideConstMeta => const TaskConstMeta(
+            debugName: "set_port_override",
+            argNames: ["scheme", "host", "port"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetPowerState({required PowerState state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_power_state(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetPowerStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetPowerStateConstMeta => const TaskConstMeta(
+            debugName: "set_power_state",
+            argNames: ["state"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetProtocolEnabled({required String scheme , required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(scheme, serializer);
+sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetProtocolEnabledConstMeta,
+            argValues: [scheme, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetProtocolEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_protocol_enabled",
+            argNames: ["scheme", "enabled"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetProxySettings({required ProxySettings settings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_proxy_settings(settings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetProxySettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetProxySettingsConstMeta => const TaskConstMeta(
+            debugName: "set_proxy_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetRateLimits({required RateLimits limits })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_rate_limits(limits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetRateLimitsConstMeta,
+            argValues: [limits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetRateLimitsConstMeta => const TaskConstMeta(
+            debugName: "set_rate_limits",
+            argNames: ["limits"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetRememberInputAnswers({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetRememberInputAnswersConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetRememberInputAnswersConstMeta => const TaskConstMeta(
+            debugName: "set_remember_input_answers",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetSchedule({required TaskSchedule schedule })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_task_schedule(schedule, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetScheduleConstMeta,
+            argValues: [schedule],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetScheduleConstMeta => const TaskConstMeta(
+            debugName: "set_schedule",
+            argNames: ["schedule"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetSearchOnFailure({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetSearchOnFailureConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetSearchOnFailureConstMeta => const TaskConstMeta(
+            debugName: "set_search_on_failure",
+            argNames: ["enabled"],
+        );
+        
+
+@override String crateApiExposedFunctionsSetSearchProvider({String? urlTemplate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(urlTemplate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProtocolsFingerConnectAndFetchFingerConstMeta,
-        argValues: [host, port, username],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProtocolsFingerConnectAndFetchFingerConstMeta =>
-      const TaskConstMeta(
-        debugName: "connect_and_fetch_finger",
-        argNames: ["host", "port", "username"],
-      );
-
-  @override
-  Future<String> crateApiProtocolsGeminiConnectAndFetchGemini({
-    required String host,
-    required int port,
-    required String url,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(host, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_String(url, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetSearchProviderConstMeta,
+            argValues: [urlTemplate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetSearchProviderConstMeta => const TaskConstMeta(
+            debugName: "set_search_provider",
+            argNames: ["urlTemplate"],
+        );
+        
+
+@override bool crateApiExposedFunctionsSetSessionScrollOffset({required String name , required int tab , required double scrollOffset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_u_32(tab, serializer);
+sse_encode_f_64(scrollOffset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetSessionScrollOffsetConstMeta,
+            argValues: [name, tab, scrollOffset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetSessionScrollOffsetConstMeta => const TaskConstMeta(
+            debugName: "set_session_scroll_offset",
+            argNames: ["name", "tab", "scrollOffset"],
+        );
+        
+
+@override String crateApiExposedFunctionsSetStartPage({String? url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProtocolsGeminiConnectAndFetchGeminiConstMeta,
-        argValues: [host, port, url],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProtocolsGeminiConnectAndFetchGeminiConstMeta =>
-      const TaskConstMeta(
-        debugName: "connect_and_fetch_gemini",
-        argNames: ["host", "port", "url"],
-      );
-
-  @override
-  Future<String> crateApiProtocolsGopherConnectAndFetchGopher({
-    required String host,
-    required int port,
-    required String path,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(host, serializer);
-          sse_encode_u_16(port, serializer);
-          sse_encode_String(path, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetStartPageConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetStartPageConstMeta => const TaskConstMeta(
+            debugName: "set_start_page",
+            argNames: ["url"],
+        );
+        
+
+@override void crateApiExposedFunctionsSetTitanToken({required String host , required String token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+sse_encode_String(token, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSetTitanTokenConstMeta,
+            argValues: [host, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSetTitanTokenConstMeta => const TaskConstMeta(
+            debugName: "set_titan_token",
+            argNames: ["host", "token"],
+        );
+        
+
+@override ShutdownSummary crateApiExposedFunctionsShutdown()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_shutdown_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsShutdownConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsShutdownConstMeta => const TaskConstMeta(
+            debugName: "shutdown",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsStartBookmarkRefresh({required int intervalSecs , required int maxAgeSecs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(intervalSecs, serializer);
+sse_encode_u_32(maxAgeSecs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStartBookmarkRefreshConstMeta,
+            argValues: [intervalSecs, maxAgeSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStartBookmarkRefreshConstMeta => const TaskConstMeta(
+            debugName: "start_bookmark_refresh",
+            argNames: ["intervalSecs", "maxAgeSecs"],
+        );
+        
+
+@override void crateApiExposedFunctionsStartExpiryChecks({required int withinDays , required int intervalSecs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(withinDays, serializer);
+sse_encode_u_32(intervalSecs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStartExpiryChecksConstMeta,
+            argValues: [withinDays, intervalSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStartExpiryChecksConstMeta => const TaskConstMeta(
+            debugName: "start_expiry_checks",
+            argNames: ["withinDays", "intervalSecs"],
+        );
+        
+
+@override void crateApiExposedFunctionsStartScheduler({required int tickSecs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(tickSecs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStartSchedulerConstMeta,
+            argValues: [tickSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStartSchedulerConstMeta => const TaskConstMeta(
+            debugName: "start_scheduler",
+            argNames: ["tickSecs"],
+        );
+        
+
+@override void crateApiExposedFunctionsStopBookmarkRefresh()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStopBookmarkRefreshConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStopBookmarkRefreshConstMeta => const TaskConstMeta(
+            debugName: "stop_bookmark_refresh",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsStopExpiryChecks()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStopExpiryChecksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStopExpiryChecksConstMeta => const TaskConstMeta(
+            debugName: "stop_expiry_checks",
+            argNames: [],
+        );
+        
+
+@override void crateApiExposedFunctionsStopScheduler()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStopSchedulerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStopSchedulerConstMeta => const TaskConstMeta(
+            debugName: "stop_scheduler",
+            argNames: [],
+        );
+        
+
+@override String crateApiExposedFunctionsStripAnsiEscapes({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStripAnsiEscapesConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStripAnsiEscapesConstMeta => const TaskConstMeta(
+            debugName: "strip_ansi_escapes",
+            argNames: ["body"],
+        );
+        
+
+@override StyledText crateApiExposedFunctionsStyleAnsiText({required String body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_styled_text,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsStyleAnsiTextConstMeta,
+            argValues: [body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsStyleAnsiTextConstMeta => const TaskConstMeta(
+            debugName: "style_ansi_text",
+            argNames: ["body"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsSubscribeEvents()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSubscribeEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSubscribeEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_events",
+            argNames: [],
+        );
+        
+
+@override Feed crateApiExposedFunctionsSubscribeFeed({required String url , String? title , required FeedKind kind })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_opt_String(title, serializer);
+sse_encode_feed_kind(kind, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_feed,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSubscribeFeedConstMeta,
+            argValues: [url, title, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSubscribeFeedConstMeta => const TaskConstMeta(
+            debugName: "subscribe_feed",
+            argNames: ["url", "title", "kind"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsSubscribeLogs()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSubscribeLogsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSubscribeLogsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_logs",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsSubscribeRequestEvents({required BigInt requestId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(requestId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSubscribeRequestEventsConstMeta,
+            argValues: [requestId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSubscribeRequestEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_request_events",
+            argNames: ["requestId"],
+        );
+        
+
+@override BigInt crateApiExposedFunctionsSubscribeSchedulerEvents()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSubscribeSchedulerEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSubscribeSchedulerEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_scheduler_events",
+            argNames: [],
+        );
+        
+
+@override List<Suggestion> crateApiExposedFunctionsSuggest({required String prefix , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+sse_encode_u_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_suggestion,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiProtocolsGopherConnectAndFetchGopherConstMeta,
-        argValues: [host, port, path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProtocolsGopherConnectAndFetchGopherConstMeta =>
-      const TaskConstMeta(
-        debugName: "connect_and_fetch_gopher",
-        argNames: ["host", "port", "path"],
-      );
-
-  @override
-  String crateApiExposedFunctionsGetStartPage() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSuggestConstMeta,
+            argValues: [prefix, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSuggestConstMeta => const TaskConstMeta(
+            debugName: "suggest",
+            argNames: ["prefix", "limit"],
+        );
+        
+
+@override String crateApiExposedFunctionsSuggestDownloadPath({required String url , required String mime , required String destDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_String(mime, serializer);
+sse_encode_String(destDir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiExposedFunctionsGetStartPageConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExposedFunctionsGetStartPageConstMeta =>
-      const TaskConstMeta(debugName: "get_start_page", argNames: []);
-
-  @override
-  String crateApiExposedFunctionsGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSuggestDownloadPathConstMeta,
+            argValues: [url, mime, destDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSuggestDownloadPathConstMeta => const TaskConstMeta(
+            debugName: "suggest_download_path",
+            argNames: ["url", "mime", "destDir"],
+        );
+        
+
+@override RecoveryAction? crateApiExposedFunctionsSuggestedActionForStatus({required int status })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(status, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_recovery_action,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSuggestedActionForStatusConstMeta,
+            argValues: [status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSuggestedActionForStatusConstMeta => const TaskConstMeta(
+            debugName: "suggested_action_for_status",
+            argNames: ["status"],
+        );
+        
+
+@override List<HostMigration> crateApiExposedFunctionsSuggestedHostMigrations()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_host_migration,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsSuggestedHostMigrationsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsSuggestedHostMigrationsConstMeta => const TaskConstMeta(
+            debugName: "suggested_host_migrations",
+            argNames: [],
+        );
+        
+
+@override Future<ProxyProbe> crateApiExposedFunctionsTestProxy({required Socks5Proxy proxy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_socks_5_proxy(proxy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_proxy_probe,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsTestProxyConstMeta,
+            argValues: [proxy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsTestProxyConstMeta => const TaskConstMeta(
+            debugName: "test_proxy",
+            argNames: ["proxy"],
+        );
+        
+
+@override List<String> crateApiExposedFunctionsTitanTokenHosts()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsTitanTokenHostsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsTitanTokenHostsConstMeta => const TaskConstMeta(
+            debugName: "titan_token_hosts",
+            argNames: [],
+        );
+        
+
+@override Future<FetchResponse> crateApiExposedFunctionsTitanUpload({required String url , required List<int> data , String? mime , String? token , String? identityId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_opt_String(mime, serializer);
+sse_encode_opt_String(token, serializer);
+sse_encode_opt_String(identityId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_response,
+          decodeErrorData: sse_decode_navigate_error,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsTitanUploadConstMeta,
+            argValues: [url, data, mime, token, identityId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsTitanUploadConstMeta => const TaskConstMeta(
+            debugName: "titan_upload",
+            argNames: ["url", "data", "mime", "token", "identityId"],
+        );
+        
+
+@override int crateApiExposedFunctionsUnlockKeyStorage({required List<int> storageKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(storageKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnlockKeyStorageConstMeta,
+            argValues: [storageKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnlockKeyStorageConstMeta => const TaskConstMeta(
+            debugName: "unlock_key_storage",
+            argNames: ["storageKey"],
+        );
+        
+
+@override int crateApiExposedFunctionsUnlockKeyStorageFromKeyring({required String profile })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(profile, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnlockKeyStorageFromKeyringConstMeta,
+            argValues: [profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnlockKeyStorageFromKeyringConstMeta => const TaskConstMeta(
+            debugName: "unlock_key_storage_from_keyring",
+            argNames: ["profile"],
+        );
+        
+
+@override int crateApiExposedFunctionsUnreadFeedCount()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnreadFeedCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnreadFeedCountConstMeta => const TaskConstMeta(
+            debugName: "unread_feed_count",
+            argNames: [],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnsubscribeEvents({required BigInt subscription })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnsubscribeEventsConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnsubscribeEventsConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_events",
+            argNames: ["subscription"],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnsubscribeFeed({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnsubscribeFeedConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnsubscribeFeedConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_feed",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnsubscribeLogs({required BigInt subscription })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnsubscribeLogsConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnsubscribeLogsConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_logs",
+            argNames: ["subscription"],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnsubscribeSchedulerEvents({required BigInt subscription })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(subscription, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiExposedFunctionsGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExposedFunctionsGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiExposedFunctionsInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiExposedFunctionsInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExposedFunctionsInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<String> crateApiExposedFunctionsNavigate({required String url}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnsubscribeSchedulerEventsConstMeta,
+            argValues: [subscription],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnsubscribeSchedulerEventsConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_scheduler_events",
+            argNames: ["subscription"],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnwatchPage({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiExposedFunctionsNavigateConstMeta,
-        argValues: [url],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExposedFunctionsNavigateConstMeta =>
-      const TaskConstMeta(debugName: "navigate", argNames: ["url"]);
-
-  @override
-  Future<String> crateApiFunctionsNavigateInternalNavigateInternal({
-    required String url,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnwatchPageConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnwatchPageConstMeta => const TaskConstMeta(
+            debugName: "unwatch_page",
+            argNames: ["id"],
+        );
+        
+
+@override bool crateApiExposedFunctionsUnwatchPlan({required PlatformInt64 id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsUnwatchPlanConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsUnwatchPlanConstMeta => const TaskConstMeta(
+            debugName: "unwatch_plan",
+            argNames: ["id"],
+        );
+        
+
+@override WatchedPage crateApiExposedFunctionsWatchPage({required String url , String? title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_opt_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_watched_page,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFunctionsNavigateInternalNavigateInternalConstMeta,
-        argValues: [url],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFunctionsNavigateInternalNavigateInternalConstMeta =>
-      const TaskConstMeta(debugName: "navigate_internal", argNames: ["url"]);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsWatchPageConstMeta,
+            argValues: [url, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsWatchPageConstMeta => const TaskConstMeta(
+            debugName: "watch_page",
+            argNames: ["url", "title"],
+        );
+        
+
+@override WatchedPlan crateApiExposedFunctionsWatchPlan({required String address })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(address, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_watched_plan,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExposedFunctionsWatchPlanConstMeta,
+            argValues: [address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExposedFunctionsWatchPlanConstMeta => const TaskConstMeta(
+            debugName: "watch_plan",
+            argNames: ["address"],
+        );
+        
+
+
+
+                  @protected String dco_decode_Char(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return String.fromCharCode(raw); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AddedBookmark dco_decode_added_bookmark(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AddedBookmark(bookmark: dco_decode_bookmark(arr[0]),
+duplicate: dco_decode_bool(arr[1]),); }
+
+@protected AnsiColor dco_decode_ansi_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AnsiColor_Indexed(dco_decode_u_8(raw[1]),);
+case 1: return AnsiColor_Rgb(r: dco_decode_u_8(raw[1]),g: dco_decode_u_8(raw[2]),b: dco_decode_u_8(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected AnsiStyle dco_decode_ansi_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AnsiStyle(foreground: dco_decode_opt_box_autoadd_ansi_color(arr[0]),
+background: dco_decode_opt_box_autoadd_ansi_color(arr[1]),
+bold: dco_decode_bool(arr[2]),
+dim: dco_decode_bool(arr[3]),
+italic: dco_decode_bool(arr[4]),
+underline: dco_decode_bool(arr[5]),
+inverse: dco_decode_bool(arr[6]),); }
+
+@protected ArchivedPage dco_decode_archived_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ArchivedPage(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+status: dco_decode_opt_box_autoadd_u_8(arr[2]),
+mime: dco_decode_String(arr[3]),
+title: dco_decode_opt_String(arr[4]),
+size: dco_decode_u_64(arr[5]),
+bodyHash: dco_decode_String(arr[6]),
+certificateFingerprint: dco_decode_opt_String(arr[7]),
+fetchedAt: dco_decode_i_64(arr[8]),
+archivedAt: dco_decode_i_64(arr[9]),); }
+
+@protected BatchItem dco_decode_batch_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return BatchItem(index: dco_decode_u_32(arr[0]),
+url: dco_decode_String(arr[1]),
+requestId: dco_decode_u_64(arr[2]),
+result: dco_decode_opt_box_autoadd_navigation_result(arr[3]),
+error: dco_decode_opt_box_autoadd_error_info(arr[4]),); }
+
+@protected BlockRule dco_decode_block_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BlockRule(pattern: dco_decode_String(arr[0]),
+kind: dco_decode_block_rule_kind(arr[1]),
+createdAt: dco_decode_i_64(arr[2]),); }
+
+@protected BlockRuleKind dco_decode_block_rule_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BlockRuleKind.values[raw as int]; }
+
+@protected Bookmark dco_decode_bookmark(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return Bookmark(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+createdAt: dco_decode_i_64(arr[3]),
+favicon: dco_decode_opt_String(arr[4]),); }
+
+@protected BookmarkFormat dco_decode_bookmark_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BookmarkFormat.values[raw as int]; }
+
+@protected BookmarkSource dco_decode_bookmark_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BookmarkSource.values[raw as int]; }
+
+@protected BookmarksImport dco_decode_bookmarks_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BookmarksImport(imported: dco_decode_u_32(arr[0]),
+skipped: dco_decode_u_32(arr[1]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AnsiColor dco_decode_box_autoadd_ansi_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ansi_color(raw); }
+
+@protected BatchItem dco_decode_box_autoadd_batch_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_batch_item(raw); }
+
+@protected Bookmark dco_decode_box_autoadd_bookmark(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bookmark(raw); }
+
+@protected CachePolicy dco_decode_box_autoadd_cache_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cache_policy(raw); }
+
+@protected CertificateError dco_decode_box_autoadd_certificate_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_certificate_error(raw); }
+
+@protected CertificateSummary dco_decode_box_autoadd_certificate_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_certificate_summary(raw); }
+
+@protected Config dco_decode_box_autoadd_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_config(raw); }
+
+@protected DecompressionLimits dco_decode_box_autoadd_decompression_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_decompression_limits(raw); }
+
+@protected DownloadStatus dco_decode_box_autoadd_download_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_download_status(raw); }
+
+@protected ErrorInfo dco_decode_box_autoadd_error_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_error_info(raw); }
+
+@protected FetchOptions dco_decode_box_autoadd_fetch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fetch_options(raw); }
+
+@protected FileType dco_decode_box_autoadd_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_file_type(raw); }
+
+@protected GemtextLine dco_decode_box_autoadd_gemtext_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_gemtext_line(raw); }
+
+@protected HistoryRange dco_decode_box_autoadd_history_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_history_range(raw); }
+
+@protected HostSettings dco_decode_box_autoadd_host_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_host_settings(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected IdentityScope dco_decode_box_autoadd_identity_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_identity_scope(raw); }
+
+@protected MirrorProgress dco_decode_box_autoadd_mirror_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_mirror_progress(raw); }
+
+@protected NavigateError dco_decode_box_autoadd_navigate_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_navigate_error(raw); }
+
+@protected NavigationEvent dco_decode_box_autoadd_navigation_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_navigation_event(raw); }
+
+@protected NavigationResult dco_decode_box_autoadd_navigation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_navigation_result(raw); }
+
+@protected ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_proxy_settings(raw); }
+
+@protected RateLimits dco_decode_box_autoadd_rate_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rate_limits(raw); }
+
+@protected RecoveryAction dco_decode_box_autoadd_recovery_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_recovery_action(raw); }
+
+@protected RedirectPolicy dco_decode_box_autoadd_redirect_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_redirect_policy(raw); }
+
+@protected Renderer dco_decode_box_autoadd_renderer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_renderer(raw); }
+
+@protected ServerFailure dco_decode_box_autoadd_server_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_server_failure(raw); }
+
+@protected Session dco_decode_box_autoadd_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_session(raw); }
+
+@protected Socks5Proxy dco_decode_box_autoadd_socks_5_proxy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_socks_5_proxy(raw); }
+
+@protected StreamOptions dco_decode_box_autoadd_stream_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stream_options(raw); }
+
+@protected TaskSchedule dco_decode_box_autoadd_task_schedule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_task_schedule(raw); }
+
+@protected TlsFailure dco_decode_box_autoadd_tls_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tls_failure(raw); }
+
+@protected TlsPolicy dco_decode_box_autoadd_tls_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tls_policy(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected CacheEntry dco_decode_cache_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CacheEntry(url: dco_decode_String(arr[0]),
+host: dco_decode_String(arr[1]),
+mime: dco_decode_String(arr[2]),
+size: dco_decode_u_64(arr[3]),
+fetchedAt: dco_decode_i_64(arr[4]),
+expiresAt: dco_decode_i_64(arr[5]),
+stale: dco_decode_bool(arr[6]),); }
+
+@protected CachePolicy dco_decode_cache_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CachePolicy(scheme: dco_decode_String(arr[0]),
+cacheable: dco_decode_bool(arr[1]),
+ttlSecs: dco_decode_u_32(arr[2]),
+cacheQueries: dco_decode_bool(arr[3]),); }
+
+@protected CertificateError dco_decode_certificate_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CertificateError(kind: dco_decode_certificate_error_kind(arr[0]),
+identityId: dco_decode_opt_String(arr[1]),
+message: dco_decode_String(arr[2]),
+suggestedAction: dco_decode_recovery_action(arr[3]),); }
+
+@protected CertificateErrorKind dco_decode_certificate_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CertificateErrorKind.values[raw as int]; }
+
+@protected CertificateSummary dco_decode_certificate_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return CertificateSummary(fingerprint: dco_decode_String(arr[0]),
+subject: dco_decode_String(arr[1]),
+issuer: dco_decode_String(arr[2]),
+notBefore: dco_decode_i_64(arr[3]),
+notAfter: dco_decode_i_64(arr[4]),
+firstSeen: dco_decode_i_64(arr[5]),
+pinStatus: dco_decode_pin_status(arr[6]),
+expiry: dco_decode_pin_expiry(arr[7]),
+tlsVersion: dco_decode_opt_String(arr[8]),
+cipherSuite: dco_decode_opt_String(arr[9]),); }
+
+@protected ChecksumStatus dco_decode_checksum_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChecksumStatus.values[raw as int]; }
+
+@protected ClearedData dco_decode_cleared_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ClearedData(category: dco_decode_data_category(arr[0]),
+removed: dco_decode_u_32(arr[1]),); }
+
+@protected ClockOffset dco_decode_clock_offset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ClockOffset(offsetSecs: dco_decode_opt_box_autoadd_i_64(arr[0]),
+hosts: dco_decode_u_32(arr[1]),
+consistent: dco_decode_bool(arr[2]),); }
+
+@protected Config dco_decode_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return Config(defaultScheme: dco_decode_String(arr[0]),
+guessSchemes: dco_decode_bool(arr[1]),
+startPage: dco_decode_String(arr[2]),
+searchProvider: dco_decode_String(arr[3]),
+connectTimeoutSecs: dco_decode_u_32(arr[4]),
+readTimeoutSecs: dco_decode_opt_box_autoadd_u_32(arr[5]),
+tlsPolicy: dco_decode_tls_policy(arr[6]),
+proxy: dco_decode_proxy_settings(arr[7]),); }
+
+@protected CrawlResult dco_decode_crawl_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CrawlResult(capsule: dco_decode_String(arr[0]),
+indexed: dco_decode_u_32(arr[1]),
+failed: dco_decode_u_32(arr[2]),
+disallowed: dco_decode_u_32(arr[3]),
+remaining: dco_decode_u_32(arr[4]),
+cancelled: dco_decode_bool(arr[5]),); }
+
+@protected CsoField dco_decode_cso_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CsoField(name: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected CsoRecord dco_decode_cso_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CsoRecord(index: dco_decode_u_32(arr[0]),
+fields: dco_decode_list_cso_field(arr[1]),); }
+
+@protected DailyUsage dco_decode_daily_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DailyUsage(dayStart: dco_decode_i_64(arr[0]),
+network: dco_decode_network_type(arr[1]),
+bytesSent: dco_decode_u_64(arr[2]),
+bytesReceived: dco_decode_u_64(arr[3]),); }
+
+@protected DataCapStatus dco_decode_data_cap_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DataCapStatus(limitBytes: dco_decode_opt_box_autoadd_u_64(arr[0]),
+usedBytes: dco_decode_u_64(arr[1]),
+exceeded: dco_decode_bool(arr[2]),); }
+
+@protected DataCategory dco_decode_data_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DataCategory.values[raw as int]; }
+
+@protected DecompressionLimits dco_decode_decompression_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DecompressionLimits(maxRatio: dco_decode_u_32(arr[0]),
+maxBytes: dco_decode_u_64(arr[1]),); }
+
+@protected DiffLine dco_decode_diff_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DiffLine(line: dco_decode_u_32(arr[0]),
+text: dco_decode_String(arr[1]),); }
+
+@protected DisplayHost dco_decode_display_host(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DisplayHost(host: dco_decode_String(arr[0]),
+spoofingWarning: dco_decode_bool(arr[1]),); }
+
+@protected DownloadRecord dco_decode_download_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return DownloadRecord(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+path: dco_decode_String(arr[2]),
+size: dco_decode_u_64(arr[3]),
+mime: dco_decode_String(arr[4]),
+status: dco_decode_download_status(arr[5]),
+error: dco_decode_opt_String(arr[6]),
+finishedAt: dco_decode_i_64(arr[7]),
+checksumUrl: dco_decode_opt_String(arr[8]),
+checksum: dco_decode_checksum_status(arr[9]),); }
+
+@protected DownloadResult dco_decode_download_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DownloadResult(path: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),
+mime: dco_decode_String(arr[2]),
+sha256: dco_decode_String(arr[3]),
+checksum: dco_decode_checksum_status(arr[4]),); }
+
+@protected DownloadStatus dco_decode_download_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DownloadStatus.values[raw as int]; }
+
+@protected ErrorCode dco_decode_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ErrorCode.values[raw as int]; }
+
+@protected ErrorInfo dco_decode_error_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ErrorInfo(code: dco_decode_error_code(arr[0]),
+name: dco_decode_String(arr[1]),
+number: dco_decode_u_32(arr[2]),
+message: dco_decode_String(arr[3]),
+retryable: dco_decode_bool(arr[4]),
+userActionable: dco_decode_bool(arr[5]),
+suggestedAction: dco_decode_opt_box_autoadd_recovery_action(arr[6]),); }
+
+@protected ExpiryReminder dco_decode_expiry_reminder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ExpiryReminder(identity: dco_decode_identity(arr[0]),
+daysLeft: dco_decode_i_64(arr[1]),
+expired: dco_decode_bool(arr[2]),); }
+
+@protected ExploreSource dco_decode_explore_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ExploreSource(directoryUrl: dco_decode_opt_String(arr[0]),
+weight: dco_decode_f_64(arr[1]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected Feed dco_decode_feed(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return Feed(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+kind: dco_decode_feed_kind(arr[3]),
+subscribedAt: dco_decode_i_64(arr[4]),
+refreshedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+lastError: dco_decode_opt_String(arr[6]),
+unread: dco_decode_u_32(arr[7]),); }
+
+@protected FeedCandidate dco_decode_feed_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FeedCandidate(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+kind: dco_decode_feed_kind(arr[2]),); }
+
+@protected FeedEntry dco_decode_feed_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return FeedEntry(id: dco_decode_i_64(arr[0]),
+feedId: dco_decode_i_64(arr[1]),
+feedTitle: dco_decode_opt_String(arr[2]),
+url: dco_decode_String(arr[3]),
+title: dco_decode_String(arr[4]),
+publishedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+seenAt: dco_decode_i_64(arr[6]),
+read: dco_decode_bool(arr[7]),); }
+
+@protected FeedKind dco_decode_feed_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FeedKind.values[raw as int]; }
+
+@protected FetchOptions dco_decode_fetch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return FetchOptions(private: dco_decode_bool(arr[0]),
+useCache: dco_decode_bool(arr[1]),
+revalidate: dco_decode_bool(arr[2]),
+probeProtocols: dco_decode_bool(arr[3]),
+background: dco_decode_bool(arr[4]),
+identityId: dco_decode_opt_String(arr[5]),
+connectTimeoutSecs: dco_decode_opt_box_autoadd_u_32(arr[6]),
+readTimeoutSecs: dco_decode_opt_box_autoadd_u_32(arr[7]),
+deadlineSecs: dco_decode_opt_box_autoadd_u_32(arr[8]),
+maxBodyBytes: dco_decode_opt_box_autoadd_u_64(arr[9]),); }
+
+@protected FetchResponse dco_decode_fetch_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 27) throw Exception('unexpected arr length: expect 27 but see ${arr.length}');
+                return FetchResponse(url: dco_decode_String(arr[0]),
+redirects: dco_decode_list_String(arr[1]),
+status: dco_decode_opt_box_autoadd_u_8(arr[2]),
+meta: dco_decode_opt_String(arr[3]),
+mime: dco_decode_String(arr[4]),
+renderer: dco_decode_renderer(arr[5]),
+body: dco_decode_String(arr[6]),
+bodyHash: dco_decode_String(arr[7]),
+unchanged: dco_decode_bool(arr[8]),
+truncated: dco_decode_bool(arr[9]),
+stale: dco_decode_bool(arr[10]),
+title: dco_decode_opt_String(arr[11]),
+feeds: dco_decode_list_feed_candidate(arr[12]),
+favicon: dco_decode_opt_String(arr[13]),
+fragment: dco_decode_opt_String(arr[14]),
+fragmentLine: dco_decode_opt_box_autoadd_u_32(arr[15]),
+cachedAt: dco_decode_opt_box_autoadd_i_64(arr[16]),
+requestId: dco_decode_u_64(arr[17]),
+displayHost: dco_decode_String(arr[18]),
+spoofingWarning: dco_decode_bool(arr[19]),
+clockSkewWarning: dco_decode_bool(arr[20]),
+probedScheme: dco_decode_opt_String(arr[21]),
+certificateError: dco_decode_opt_box_autoadd_certificate_error(arr[22]),
+serverFailure: dco_decode_opt_box_autoadd_server_failure(arr[23]),
+certificate: dco_decode_opt_box_autoadd_certificate_summary(arr[24]),
+durationMs: dco_decode_u_64(arr[25]),
+timings: dco_decode_request_timings(arr[26]),); }
+
+@protected FileType dco_decode_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FileType(mime: dco_decode_String(arr[0]),
+extension_: dco_decode_String(arr[1]),); }
+
+@protected GempubBook dco_decode_gempub_book(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GempubBook(handle: dco_decode_u_64(arr[0]),
+metadata: dco_decode_gempub_metadata(arr[1]),
+indexPath: dco_decode_opt_String(arr[2]),
+chapters: dco_decode_list_gempub_chapter(arr[3]),); }
+
+@protected GempubChapter dco_decode_gempub_chapter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return GempubChapter(title: dco_decode_String(arr[0]),
+path: dco_decode_String(arr[1]),); }
+
+@protected GempubMetadata dco_decode_gempub_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return GempubMetadata(title: dco_decode_String(arr[0]),
+author: dco_decode_opt_String(arr[1]),
+language: dco_decode_opt_String(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+published: dco_decode_opt_String(arr[4]),
+version: dco_decode_opt_String(arr[5]),
+cover: dco_decode_opt_String(arr[6]),); }
+
+@protected GempubPage dco_decode_gempub_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return GempubPage(path: dco_decode_String(arr[0]),
+mime: dco_decode_String(arr[1]),
+body: dco_decode_String(arr[2]),
+data: dco_decode_list_prim_u_8_strict(arr[3]),
+chapter: dco_decode_opt_box_autoadd_u_32(arr[4]),
+previousChapter: dco_decode_opt_box_autoadd_u_32(arr[5]),
+nextChapter: dco_decode_opt_box_autoadd_u_32(arr[6]),); }
+
+@protected GemtextLine dco_decode_gemtext_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return GemtextLine_Text(dco_decode_String(raw[1]),);
+case 1: return GemtextLine_Link(url: dco_decode_String(raw[1]),label: dco_decode_opt_String(raw[2]),);
+case 2: return GemtextLine_Heading(level: dco_decode_u_8(raw[1]),text: dco_decode_String(raw[2]),);
+case 3: return GemtextLine_ListItem(dco_decode_String(raw[1]),);
+case 4: return GemtextLine_Quote(dco_decode_String(raw[1]),);
+case 5: return GemtextLine_PreformatToggle(alt: dco_decode_opt_String(raw[1]),);
+case 6: return GemtextLine_Preformatted(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected GopherItem dco_decode_gopher_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return GopherItem(itemType: dco_decode_Char(arr[0]),
+display: dco_decode_String(arr[1]),
+selector: dco_decode_String(arr[2]),
+host: dco_decode_String(arr[3]),
+port: dco_decode_u_16(arr[4]),
+kind: dco_decode_String(arr[5]),
+icon: dco_decode_String(arr[6]),
+fetchable: dco_decode_bool(arr[7]),); }
+
+@protected HeadingAnchor dco_decode_heading_anchor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HeadingAnchor(slug: dco_decode_String(arr[0]),
+text: dco_decode_String(arr[1]),
+level: dco_decode_u_8(arr[2]),
+lineIndex: dco_decode_u_32(arr[3]),); }
+
+@protected HighlightedBlock dco_decode_highlighted_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HighlightedBlock(startIndex: dco_decode_u_32(arr[0]),
+language: dco_decode_String(arr[1]),
+lines: dco_decode_list_highlighted_line(arr[2]),); }
+
+@protected HighlightedLine dco_decode_highlighted_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return HighlightedLine(spans: dco_decode_list_token_span(arr[0]),); }
+
+@protected HistoryEntry dco_decode_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HistoryEntry(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+visitedAt: dco_decode_i_64(arr[3]),); }
+
+@protected HistoryMatch dco_decode_history_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HistoryMatch.values[raw as int]; }
+
+@protected HistoryRange dco_decode_history_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HistoryRange(since: dco_decode_opt_box_autoadd_i_64(arr[0]),
+until: dco_decode_opt_box_autoadd_i_64(arr[1]),); }
+
+@protected HistoryResult dco_decode_history_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HistoryResult(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+visitCount: dco_decode_u_32(arr[2]),
+lastVisited: dco_decode_i_64(arr[3]),); }
+
+@protected HostMigration dco_decode_host_migration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return HostMigration(scheme: dco_decode_String(arr[0]),
+fromHost: dco_decode_String(arr[1]),
+toHost: dco_decode_String(arr[2]),
+movedPages: dco_decode_u_32(arr[3]),
+bookmarks: dco_decode_u_32(arr[4]),); }
+
+@protected HostMigrationResult dco_decode_host_migration_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return HostMigrationResult(bookmarks: dco_decode_u_32(arr[0]),); }
+
+@protected HostSettings dco_decode_host_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return HostSettings(host: dco_decode_String(arr[0]),
+tlsPolicy: dco_decode_opt_box_autoadd_tls_policy(arr[1]),
+identityId: dco_decode_opt_String(arr[2]),
+connectTimeoutSecs: dco_decode_opt_box_autoadd_u_32(arr[3]),
+readTimeoutSecs: dco_decode_opt_box_autoadd_u_32(arr[4]),
+redirectPolicy: dco_decode_opt_box_autoadd_redirect_policy(arr[5]),
+maxBodyBytes: dco_decode_opt_box_autoadd_u_64(arr[6]),
+charset: dco_decode_opt_String(arr[7]),); }
+
+@protected HostStats dco_decode_host_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return HostStats(host: dco_decode_String(arr[0]),
+requests: dco_decode_u_32(arr[1]),
+errors: dco_decode_u_32(arr[2]),
+bytesSent: dco_decode_u_64(arr[3]),
+bytesReceived: dco_decode_u_64(arr[4]),
+averageLatencyMs: dco_decode_u_32(arr[5]),
+errorRate: dco_decode_f_64(arr[6]),
+firstRequestAt: dco_decode_i_64(arr[7]),
+lastRequestAt: dco_decode_i_64(arr[8]),); }
+
+@protected HostStatsOrder dco_decode_host_stats_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HostStatsOrder.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IdentitiesImport dco_decode_identities_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IdentitiesImport(imported: dco_decode_u_32(arr[0]),
+skipped: dco_decode_u_32(arr[1]),); }
+
+@protected Identity dco_decode_identity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return Identity(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+commonName: dco_decode_String(arr[2]),
+fingerprint: dco_decode_String(arr[3]),
+notBefore: dco_decode_i_64(arr[4]),
+notAfter: dco_decode_i_64(arr[5]),
+createdAt: dco_decode_i_64(arr[6]),); }
+
+@protected IdentityScope dco_decode_identity_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return IdentityScope(prefix: dco_decode_String(arr[0]),
+identityId: dco_decode_String(arr[1]),
+activatedAt: dco_decode_i_64(arr[2]),); }
+
+@protected InlineImage dco_decode_inline_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return InlineImage(linkIndex: dco_decode_u_32(arr[0]),
+lineIndex: dco_decode_u_32(arr[1]),
+url: dco_decode_String(arr[2]),
+mime: dco_decode_String(arr[3]),
+data: dco_decode_list_prim_u_8_strict(arr[4]),); }
+
+@protected InlineImageFailure dco_decode_inline_image_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return InlineImageFailure(linkIndex: dco_decode_u_32(arr[0]),
+lineIndex: dco_decode_u_32(arr[1]),
+url: dco_decode_String(arr[2]),
+error: dco_decode_String(arr[3]),); }
+
+@protected InlineImages dco_decode_inline_images(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return InlineImages(images: dco_decode_list_inline_image(arr[0]),
+failed: dco_decode_list_inline_image_failure(arr[1]),); }
+
+@protected KnownHost dco_decode_known_host(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return KnownHost(host: dco_decode_String(arr[0]),
+port: dco_decode_u_16(arr[1]),
+fingerprint: dco_decode_String(arr[2]),
+expiresAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+firstSeen: dco_decode_i_64(arr[4]),
+lastSeen: dco_decode_i_64(arr[5]),); }
+
+@protected KnownHostsFormat dco_decode_known_hosts_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KnownHostsFormat.values[raw as int]; }
+
+@protected KnownHostsImport dco_decode_known_hosts_import(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return KnownHostsImport(imported: dco_decode_u_32(arr[0]),
+skipped: dco_decode_u_32(arr[1]),); }
+
+@protected LevelRun dco_decode_level_run(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LevelRun(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),
+level: dco_decode_u_8(arr[2]),
+direction: dco_decode_text_direction(arr[3]),); }
+
+@protected LineDirection dco_decode_line_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LineDirection(lineIndex: dco_decode_u_32(arr[0]),
+direction: dco_decode_text_direction(arr[1]),
+runs: dco_decode_list_level_run(arr[2]),); }
+
+@protected LinkKind dco_decode_link_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LinkKind.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ArchivedPage> dco_decode_list_archived_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_archived_page).toList(); }
+
+@protected List<BlockRule> dco_decode_list_block_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_block_rule).toList(); }
+
+@protected List<Bookmark> dco_decode_list_bookmark(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_bookmark).toList(); }
+
+@protected List<CacheEntry> dco_decode_list_cache_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cache_entry).toList(); }
+
+@protected List<CachePolicy> dco_decode_list_cache_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cache_policy).toList(); }
+
+@protected List<ClearedData> dco_decode_list_cleared_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cleared_data).toList(); }
+
+@protected List<CsoField> dco_decode_list_cso_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cso_field).toList(); }
+
+@protected List<CsoRecord> dco_decode_list_cso_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cso_record).toList(); }
+
+@protected List<DailyUsage> dco_decode_list_daily_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_daily_usage).toList(); }
+
+@protected List<DataCategory> dco_decode_list_data_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_data_category).toList(); }
+
+@protected List<DiffLine> dco_decode_list_diff_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_diff_line).toList(); }
+
+@protected List<DownloadRecord> dco_decode_list_download_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_download_record).toList(); }
+
+@protected List<ExpiryReminder> dco_decode_list_expiry_reminder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_expiry_reminder).toList(); }
+
+@protected List<ExploreSource> dco_decode_list_explore_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_explore_source).toList(); }
+
+@protected List<Feed> dco_decode_list_feed(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feed).toList(); }
+
+@protected List<FeedCandidate> dco_decode_list_feed_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feed_candidate).toList(); }
+
+@protected List<FeedEntry> dco_decode_list_feed_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_feed_entry).toList(); }
+
+@protected List<GempubChapter> dco_decode_list_gempub_chapter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_gempub_chapter).toList(); }
+
+@protected List<GemtextLine> dco_decode_list_gemtext_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_gemtext_line).toList(); }
+
+@protected List<GopherItem> dco_decode_list_gopher_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_gopher_item).toList(); }
+
+@protected List<HighlightedBlock> dco_decode_list_highlighted_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_highlighted_block).toList(); }
+
+@protected List<HighlightedLine> dco_decode_list_highlighted_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_highlighted_line).toList(); }
+
+@protected List<HistoryEntry> dco_decode_list_history_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_history_entry).toList(); }
+
+@protected List<HistoryResult> dco_decode_list_history_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_history_result).toList(); }
+
+@protected List<HostMigration> dco_decode_list_host_migration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_host_migration).toList(); }
+
+@protected List<HostSettings> dco_decode_list_host_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_host_settings).toList(); }
+
+@protected List<HostStats> dco_decode_list_host_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_host_stats).toList(); }
+
+@protected List<Identity> dco_decode_list_identity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_identity).toList(); }
+
+@protected List<IdentityScope> dco_decode_list_identity_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_identity_scope).toList(); }
+
+@protected List<InlineImage> dco_decode_list_inline_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_inline_image).toList(); }
+
+@protected List<InlineImageFailure> dco_decode_list_inline_image_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_inline_image_failure).toList(); }
+
+@protected List<KnownHost> dco_decode_list_known_host(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_known_host).toList(); }
+
+@protected List<LevelRun> dco_decode_list_level_run(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_level_run).toList(); }
+
+@protected List<LineDirection> dco_decode_list_line_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_line_direction).toList(); }
+
+@protected List<LocalSearchResult> dco_decode_list_local_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_local_search_result).toList(); }
+
+@protected List<LogRecord> dco_decode_list_log_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_log_record).toList(); }
+
+@protected List<OutlineEntry> dco_decode_list_outline_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_outline_entry).toList(); }
+
+@protected List<PageLink> dco_decode_list_page_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_page_link).toList(); }
+
+@protected List<PortOverride> dco_decode_list_port_override(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_port_override).toList(); }
+
+@protected List<PreformattedBlock> dco_decode_list_preformatted_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_preformatted_block).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ProtocolInfo> dco_decode_list_protocol_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_protocol_info).toList(); }
+
+@protected List<ProviderFailure> dco_decode_list_provider_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_provider_failure).toList(); }
+
+@protected List<ProxyRule> dco_decode_list_proxy_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_proxy_rule).toList(); }
+
+@protected List<ReadLaterItem> dco_decode_list_read_later_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_read_later_item).toList(); }
+
+@protected List<ReflowedLine> dco_decode_list_reflowed_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_reflowed_line).toList(); }
+
+@protected List<SavedAnswer> dco_decode_list_saved_answer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_saved_answer).toList(); }
+
+@protected List<SchedulerEvent> dco_decode_list_scheduler_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_scheduler_event).toList(); }
+
+@protected List<SchemeProxy> dco_decode_list_scheme_proxy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_scheme_proxy).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<SearchProvider> dco_decode_list_search_provider(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_provider).toList(); }
+
+@protected List<SessionEntry> dco_decode_list_session_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_entry).toList(); }
+
+@protected List<SessionSummary> dco_decode_list_session_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_summary).toList(); }
+
+@protected List<SessionTab> dco_decode_list_session_tab(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_tab).toList(); }
+
+@protected List<SiteNode> dco_decode_list_site_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_site_node).toList(); }
+
+@protected List<SnippetPart> dco_decode_list_snippet_part(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snippet_part).toList(); }
+
+@protected List<StyledSpan> dco_decode_list_styled_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_styled_span).toList(); }
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_suggestion).toList(); }
+
+@protected List<TaskSchedule> dco_decode_list_task_schedule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_task_schedule).toList(); }
+
+@protected List<TokenSpan> dco_decode_list_token_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_token_span).toList(); }
+
+@protected List<TopSite> dco_decode_list_top_site(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_top_site).toList(); }
+
+@protected List<TraceEvent> dco_decode_list_trace_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_trace_event).toList(); }
+
+@protected List<TrustedCa> dco_decode_list_trusted_ca(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_trusted_ca).toList(); }
+
+@protected List<WatchedPage> dco_decode_list_watched_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_watched_page).toList(); }
+
+@protected List<WatchedPlan> dco_decode_list_watched_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_watched_plan).toList(); }
+
+@protected LocalSearchResult dco_decode_local_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LocalSearchResult(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+snippet: dco_decode_list_snippet_part(arr[2]),
+fetchedAt: dco_decode_i_64(arr[3]),
+lastVisitedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LogRecord dco_decode_log_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LogRecord(timestampMs: dco_decode_i_64(arr[0]),
+level: dco_decode_log_level(arr[1]),
+target: dco_decode_String(arr[2]),
+message: dco_decode_String(arr[3]),
+requestId: dco_decode_opt_box_autoadd_u_64(arr[4]),); }
+
+@protected MenuPage dco_decode_menu_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MenuPage(offset: dco_decode_u_32(arr[0]),
+items: dco_decode_list_gopher_item(arr[1]),
+total: dco_decode_u_32(arr[2]),); }
+
+@protected MetasearchResult dco_decode_metasearch_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MetasearchResult(hits: dco_decode_list_search_hit(arr[0]),
+failed: dco_decode_list_provider_failure(arr[1]),); }
+
+@protected MirrorProgress dco_decode_mirror_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MirrorProgress(url: dco_decode_String(arr[0]),
+path: dco_decode_opt_String(arr[1]),
+error: dco_decode_opt_String(arr[2]),
+saved: dco_decode_u_32(arr[3]),
+failed: dco_decode_u_32(arr[4]),
+remaining: dco_decode_u_32(arr[5]),); }
+
+@protected NavigateError dco_decode_navigate_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return NavigateError_InvalidUrl(dco_decode_String(raw[1]),);
+case 1: return NavigateError_UnsupportedScheme(dco_decode_String(raw[1]),);
+case 2: return NavigateError_SchemeDisabled(dco_decode_String(raw[1]),);
+case 3: return NavigateError_Blocked(dco_decode_String(raw[1]),);
+case 4: return NavigateError_Disallowed(dco_decode_String(raw[1]),);
+case 5: return NavigateError_Offline(dco_decode_String(raw[1]),);
+case 6: return NavigateError_DnsFailure(dco_decode_String(raw[1]),);
+case 7: return NavigateError_ConnectTimeout(dco_decode_String(raw[1]),);
+case 8: return NavigateError_ConnectionRefused(dco_decode_String(raw[1]),);
+case 9: return NavigateError_ConnectionFailed(dco_decode_String(raw[1]),);
+case 10: return NavigateError_ConnectionLost(dco_decode_String(raw[1]),);
+case 11: return NavigateError_ReadTimeout(dco_decode_String(raw[1]),);
+case 12: return NavigateError_GeminiTimeout(dco_decode_String(raw[1]),);
+case 13: return NavigateError_ProxyUnreachable(dco_decode_String(raw[1]),);
+case 14: return NavigateError_ProxyAuthFailed(dco_decode_String(raw[1]),);
+case 15: return NavigateError_ProxyError(dco_decode_String(raw[1]),);
+case 16: return NavigateError_GatewayError(dco_decode_String(raw[1]),);
+case 17: return NavigateError_TlsError(dco_decode_String(raw[1]),);
+case 18: return NavigateError_FingerprintMismatch(dco_decode_String(raw[1]),);
+case 19: return NavigateError_CertificateDate(dco_decode_String(raw[1]),);
+case 20: return NavigateError_CertificateRejected(dco_decode_String(raw[1]),);
+case 21: return NavigateError_BadResponse(dco_decode_String(raw[1]),);
+case 22: return NavigateError_TooLarge(dco_decode_String(raw[1]),);
+case 23: return NavigateError_NotFound(dco_decode_String(raw[1]),);
+case 24: return NavigateError_InvalidInput(dco_decode_String(raw[1]),);
+case 25: return NavigateError_GopherBadType(dco_decode_String(raw[1]),);
+case 26: return NavigateError_GopherBadSelector(dco_decode_String(raw[1]),);
+case 27: return NavigateError_StorageError(dco_decode_String(raw[1]),);
+case 28: return NavigateError_IdentityError(dco_decode_String(raw[1]),);
+case 29: return NavigateError_Cancelled(dco_decode_String(raw[1]),);
+case 30: return NavigateError_Redirected(target: dco_decode_String(raw[1]),permanent: dco_decode_bool(raw[2]),);
+case 31: return NavigateError_InputRequired(prompt: dco_decode_String(raw[1]),sensitive: dco_decode_bool(raw[2]),);
+case 32: return NavigateError_CertificateRequired(dco_decode_box_autoadd_certificate_error(raw[1]),);
+case 33: return NavigateError_ServerError(dco_decode_box_autoadd_server_failure(raw[1]),);
+case 34: return NavigateError_Other(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected NavigationEvent dco_decode_navigation_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return NavigationEvent(requestId: dco_decode_u_64(arr[0]),
+kind: dco_decode_navigation_event_kind(arr[1]),
+url: dco_decode_String(arr[2]),
+status: dco_decode_opt_box_autoadd_u_8(arr[3]),
+meta: dco_decode_opt_String(arr[4]),
+bytes: dco_decode_u_64(arr[5]),
+message: dco_decode_opt_String(arr[6]),); }
+
+@protected NavigationEventKind dco_decode_navigation_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NavigationEventKind.values[raw as int]; }
+
+@protected NavigationResult dco_decode_navigation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 18) throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+                return NavigationResult(url: dco_decode_String(arr[0]),
+redirects: dco_decode_list_String(arr[1]),
+protocol: dco_decode_String(arr[2]),
+status: dco_decode_opt_box_autoadd_u_8(arr[3]),
+mime: dco_decode_String(arr[4]),
+charset: dco_decode_opt_String(arr[5]),
+renderer: dco_decode_renderer(arr[6]),
+title: dco_decode_opt_String(arr[7]),
+favicon: dco_decode_opt_String(arr[8]),
+body: dco_decode_String(arr[9]),
+truncated: dco_decode_bool(arr[10]),
+cachedAt: dco_decode_opt_box_autoadd_i_64(arr[11]),
+stale: dco_decode_bool(arr[12]),
+durationMs: dco_decode_u_64(arr[13]),
+timings: dco_decode_request_timings(arr[14]),
+certificate: dco_decode_opt_box_autoadd_certificate_summary(arr[15]),
+serverFailure: dco_decode_opt_box_autoadd_server_failure(arr[16]),
+requestId: dco_decode_u_64(arr[17]),); }
+
+@protected NetworkType dco_decode_network_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkType.values[raw as int]; }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AnsiColor? dco_decode_opt_box_autoadd_ansi_color(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ansi_color(raw); }
+
+@protected BatchItem? dco_decode_opt_box_autoadd_batch_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_batch_item(raw); }
+
+@protected Bookmark? dco_decode_opt_box_autoadd_bookmark(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bookmark(raw); }
+
+@protected CertificateError? dco_decode_opt_box_autoadd_certificate_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_certificate_error(raw); }
+
+@protected CertificateSummary? dco_decode_opt_box_autoadd_certificate_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_certificate_summary(raw); }
+
+@protected DownloadStatus? dco_decode_opt_box_autoadd_download_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_download_status(raw); }
+
+@protected ErrorInfo? dco_decode_opt_box_autoadd_error_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_error_info(raw); }
+
+@protected FileType? dco_decode_opt_box_autoadd_file_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_file_type(raw); }
+
+@protected GemtextLine? dco_decode_opt_box_autoadd_gemtext_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_gemtext_line(raw); }
+
+@protected HostSettings? dco_decode_opt_box_autoadd_host_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_host_settings(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected IdentityScope? dco_decode_opt_box_autoadd_identity_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_identity_scope(raw); }
+
+@protected MirrorProgress? dco_decode_opt_box_autoadd_mirror_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_mirror_progress(raw); }
+
+@protected NavigationEvent? dco_decode_opt_box_autoadd_navigation_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_navigation_event(raw); }
+
+@protected NavigationResult? dco_decode_opt_box_autoadd_navigation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_navigation_result(raw); }
+
+@protected RecoveryAction? dco_decode_opt_box_autoadd_recovery_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_recovery_action(raw); }
+
+@protected RedirectPolicy? dco_decode_opt_box_autoadd_redirect_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_redirect_policy(raw); }
+
+@protected Renderer? dco_decode_opt_box_autoadd_renderer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_renderer(raw); }
+
+@protected ServerFailure? dco_decode_opt_box_autoadd_server_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_server_failure(raw); }
+
+@protected Session? dco_decode_opt_box_autoadd_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_session(raw); }
+
+@protected Socks5Proxy? dco_decode_opt_box_autoadd_socks_5_proxy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_socks_5_proxy(raw); }
+
+@protected TlsFailure? dco_decode_opt_box_autoadd_tls_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_tls_failure(raw); }
+
+@protected TlsPolicy? dco_decode_opt_box_autoadd_tls_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_tls_policy(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected List<HighlightedLine>? dco_decode_opt_list_highlighted_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_highlighted_line(raw); }
+
+@protected List<TraceEvent>? dco_decode_opt_list_trace_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_trace_event(raw); }
+
+@protected OutlineEntry dco_decode_outline_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return OutlineEntry(anchor: dco_decode_heading_anchor(arr[0]),
+depth: dco_decode_u_8(arr[1]),
+parent: dco_decode_opt_box_autoadd_u_32(arr[2]),); }
+
+@protected PageDiff dco_decode_page_diff(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PageDiff(url: dco_decode_String(arr[0]),
+cachedAt: dco_decode_i_64(arr[1]),
+added: dco_decode_list_diff_line(arr[2]),
+removed: dco_decode_list_diff_line(arr[3]),); }
+
+@protected PageLink dco_decode_page_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PageLink(url: dco_decode_String(arr[0]),
+label: dco_decode_opt_String(arr[1]),
+kind: dco_decode_link_kind(arr[2]),); }
+
+@protected PageSummary dco_decode_page_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PageSummary(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+favicon: dco_decode_opt_String(arr[3]),
+cachedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected PeekResult dco_decode_peek_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PeekResult(url: dco_decode_String(arr[0]),
+status: dco_decode_opt_box_autoadd_u_8(arr[1]),
+meta: dco_decode_opt_String(arr[2]),
+mime: dco_decode_opt_String(arr[3]),
+renderer: dco_decode_opt_box_autoadd_renderer(arr[4]),
+size: dco_decode_opt_box_autoadd_u_64(arr[5]),); }
+
+@protected PinExpiry dco_decode_pin_expiry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PinExpiry.values[raw as int]; }
+
+@protected PinStatus dco_decode_pin_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PinStatus.values[raw as int]; }
+
+@protected PortOverride dco_decode_port_override(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PortOverride(scheme: dco_decode_String(arr[0]),
+host: dco_decode_opt_String(arr[1]),
+port: dco_decode_u_16(arr[2]),); }
+
+@protected PowerState dco_decode_power_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PowerState.values[raw as int]; }
+
+@protected PreformattedBlock dco_decode_preformatted_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PreformattedBlock(startIndex: dco_decode_u_32(arr[0]),
+lineCount: dco_decode_u_32(arr[1]),
+alt: dco_decode_opt_String(arr[2]),
+maxWidth: dco_decode_u_32(arr[3]),
+hasWideChars: dco_decode_bool(arr[4]),); }
+
+@protected ProtocolCapabilities dco_decode_protocol_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ProtocolCapabilities(userInput: dco_decode_bool(arr[0]),
+redirects: dco_decode_bool(arr[1]),
+clientCertificates: dco_decode_bool(arr[2]),
+menus: dco_decode_bool(arr[3]),); }
+
+@protected ProtocolInfo dco_decode_protocol_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ProtocolInfo(scheme: dco_decode_String(arr[0]),
+defaultPort: dco_decode_u_16(arr[1]),
+requiresTls: dco_decode_bool(arr[2]),
+capabilities: dco_decode_protocol_capabilities(arr[3]),
+enabled: dco_decode_bool(arr[4]),); }
+
+@protected ProviderFailure dco_decode_provider_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProviderFailure(name: dco_decode_String(arr[0]),
+error: dco_decode_String(arr[1]),); }
+
+@protected ProxyProbe dco_decode_proxy_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProxyProbe(handshakeMs: dco_decode_u_32(arr[0]),
+connectMs: dco_decode_u_32(arr[1]),); }
+
+@protected ProxyProtocol dco_decode_proxy_protocol(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProxyProtocol.values[raw as int]; }
+
+@protected ProxyRule dco_decode_proxy_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProxyRule(pattern: dco_decode_String(arr[0]),
+proxy: dco_decode_opt_box_autoadd_socks_5_proxy(arr[1]),); }
+
+@protected ProxySettings dco_decode_proxy_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ProxySettings_Direct();
+case 1: return ProxySettings_Socks5(proxy: dco_decode_box_autoadd_socks_5_proxy(raw[1]),);
+case 2: return ProxySettings_PerRule(rules: dco_decode_list_proxy_rule(raw[1]),fallback: dco_decode_opt_box_autoadd_socks_5_proxy(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RandomDestination dco_decode_random_destination(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RandomDestination(url: dco_decode_String(arr[0]),
+label: dco_decode_opt_String(arr[1]),
+directoryUrl: dco_decode_opt_String(arr[2]),); }
+
+@protected RateLimits dco_decode_rate_limits(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RateLimits(maxConnections: dco_decode_u_32(arr[0]),
+minIntervalMs: dco_decode_u_64(arr[1]),); }
+
+@protected ReadLaterItem dco_decode_read_later_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ReadLaterItem(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+addedAt: dco_decode_i_64(arr[3]),
+readAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+cached: dco_decode_bool(arr[5]),
+fetchedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+size: dco_decode_u_64(arr[7]),
+lastError: dco_decode_opt_String(arr[8]),); }
+
+@protected ReadingStats dco_decode_reading_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ReadingStats(words: dco_decode_u_32(arr[0]),
+preformattedWords: dco_decode_u_32(arr[1]),
+readingMinutes: dco_decode_u_32(arr[2]),
+links: dco_decode_u_32(arr[3]),
+headings: dco_decode_u_32(arr[4]),); }
+
+@protected RecoveryAction dco_decode_recovery_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecoveryAction.values[raw as int]; }
+
+@protected RedirectPolicy dco_decode_redirect_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RedirectPolicy.values[raw as int]; }
+
+@protected ReflowedLine dco_decode_reflowed_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReflowedLine(sourceIndex: dco_decode_u_32(arr[0]),
+line: dco_decode_gemtext_line(arr[1]),
+continuation: dco_decode_bool(arr[2]),); }
+
+@protected Renderer dco_decode_renderer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Renderer.values[raw as int]; }
+
+@protected RequestTimings dco_decode_request_timings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RequestTimings(dnsMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+connectMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+tlsMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+firstByteMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+transferMs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+bytesSent: dco_decode_u_64(arr[5]),
+bytesReceived: dco_decode_u_64(arr[6]),); }
+
+@protected SavedAnswer dco_decode_saved_answer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SavedAnswer(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+prompt: dco_decode_String(arr[2]),
+answer: dco_decode_String(arr[3]),
+answeredAt: dco_decode_i_64(arr[4]),
+uses: dco_decode_u_32(arr[5]),); }
+
+@protected ScheduledTask dco_decode_scheduled_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScheduledTask.values[raw as int]; }
+
+@protected SchedulerEvent dco_decode_scheduler_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SchedulerEvent(task: dco_decode_scheduled_task(arr[0]),
+kind: dco_decode_scheduler_event_kind(arr[1]),
+timestamp: dco_decode_i_64(arr[2]),
+count: dco_decode_u_32(arr[3]),
+items: dco_decode_list_String(arr[4]),
+message: dco_decode_opt_String(arr[5]),
+nextRunAt: dco_decode_opt_box_autoadd_i_64(arr[6]),); }
+
+@protected SchedulerEventKind dco_decode_scheduler_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SchedulerEventKind.values[raw as int]; }
+
+@protected SchemeProxy dco_decode_scheme_proxy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SchemeProxy(scheme: dco_decode_String(arr[0]),
+protocol: dco_decode_proxy_protocol(arr[1]),
+host: dco_decode_String(arr[2]),
+port: dco_decode_u_16(arr[3]),
+selector: dco_decode_String(arr[4]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchHit(url: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+snippet: dco_decode_opt_String(arr[2]),
+providers: dco_decode_list_String(arr[3]),
+score: dco_decode_f_64(arr[4]),); }
+
+@protected SearchProvider dco_decode_search_provider(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SearchProvider(name: dco_decode_String(arr[0]),
+urlTemplate: dco_decode_String(arr[1]),); }
+
+@protected ServerFailure dco_decode_server_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ServerFailure(status: dco_decode_u_8(arr[0]),
+kind: dco_decode_server_failure_kind(arr[1]),
+message: dco_decode_String(arr[2]),
+temporary: dco_decode_bool(arr[3]),
+retryAfterSecs: dco_decode_opt_box_autoadd_u_32(arr[4]),
+suggestedAction: dco_decode_opt_box_autoadd_recovery_action(arr[5]),); }
+
+@protected ServerFailureKind dco_decode_server_failure_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ServerFailureKind.values[raw as int]; }
+
+@protected Session dco_decode_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Session(name: dco_decode_String(arr[0]),
+tabs: dco_decode_list_session_tab(arr[1]),
+activeTab: dco_decode_u_32(arr[2]),
+savedAt: dco_decode_i_64(arr[3]),); }
+
+@protected SessionEntry dco_decode_session_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SessionEntry(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+scrollOffset: dco_decode_f_64(arr[2]),); }
+
+@protected SessionSummary dco_decode_session_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SessionSummary(name: dco_decode_String(arr[0]),
+tabCount: dco_decode_u_32(arr[1]),
+savedAt: dco_decode_i_64(arr[2]),); }
+
+@protected SessionTab dco_decode_session_tab(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SessionTab(entries: dco_decode_list_session_entry(arr[0]),
+currentEntry: dco_decode_u_32(arr[1]),
+private: dco_decode_bool(arr[2]),); }
+
+@protected ShutdownSummary dco_decode_shutdown_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ShutdownSummary(streamsCancelled: dco_decode_u_32(arr[0]),
+fetchesAborted: dco_decode_u_32(arr[1]),
+requestsCancelled: dco_decode_u_32(arr[2]),
+mirrorsCancelled: dco_decode_u_32(arr[3]),); }
+
+@protected SiteMap dco_decode_site_map(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SiteMap(root: dco_decode_String(arr[0]),
+nodes: dco_decode_list_site_node(arr[1]),
+truncated: dco_decode_bool(arr[2]),); }
+
+@protected SiteNode dco_decode_site_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return SiteNode(url: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+kind: dco_decode_link_kind(arr[2]),
+icon: dco_decode_String(arr[3]),
+menu: dco_decode_bool(arr[4]),
+depth: dco_decode_u_32(arr[5]),
+parent: dco_decode_opt_box_autoadd_u_32(arr[6]),
+expanded: dco_decode_bool(arr[7]),
+error: dco_decode_opt_String(arr[8]),); }
+
+@protected SnippetPart dco_decode_snippet_part(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SnippetPart(text: dco_decode_String(arr[0]),
+matched: dco_decode_bool(arr[1]),); }
+
+@protected Socks5Proxy dco_decode_socks_5_proxy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Socks5Proxy(host: dco_decode_String(arr[0]),
+port: dco_decode_u_16(arr[1]),
+username: dco_decode_opt_String(arr[2]),
+password: dco_decode_opt_String(arr[3]),); }
+
+@protected StreamEvent dco_decode_stream_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return StreamEvent(kind: dco_decode_stream_event_kind(arr[0]),
+status: dco_decode_opt_box_autoadd_u_8(arr[1]),
+meta: dco_decode_opt_String(arr[2]),
+text: dco_decode_opt_String(arr[3]),
+element: dco_decode_opt_box_autoadd_gemtext_line(arr[4]),
+bytesReceived: dco_decode_u_64(arr[5]),); }
+
+@protected StreamEventKind dco_decode_stream_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StreamEventKind.values[raw as int]; }
+
+@protected StreamOptions dco_decode_stream_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return StreamOptions(idleIntervalSecs: dco_decode_u_32(arr[0]),
+maxIdleSecs: dco_decode_opt_box_autoadd_u_32(arr[1]),); }
+
+@protected StyledSpan dco_decode_styled_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return StyledSpan(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),
+style: dco_decode_ansi_style(arr[2]),); }
+
+@protected StyledText dco_decode_styled_text(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return StyledText(text: dco_decode_String(arr[0]),
+spans: dco_decode_list_styled_span(arr[1]),); }
+
+@protected Suggestion dco_decode_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return Suggestion(url: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+bookmarked: dco_decode_bool(arr[2]),
+visitCount: dco_decode_u_32(arr[3]),
+lastVisited: dco_decode_opt_box_autoadd_i_64(arr[4]),
+matched: dco_decode_suggestion_match(arr[5]),
+completion: dco_decode_opt_String(arr[6]),); }
+
+@protected SuggestionMatch dco_decode_suggestion_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SuggestionMatch.values[raw as int]; }
+
+@protected TaskSchedule dco_decode_task_schedule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TaskSchedule(task: dco_decode_scheduled_task(arr[0]),
+enabled: dco_decode_bool(arr[1]),
+intervalSecs: dco_decode_u_32(arr[2]),
+jitterSecs: dco_decode_u_32(arr[3]),
+unmeteredOnly: dco_decode_bool(arr[4]),
+chargingOnly: dco_decode_bool(arr[5]),); }
+
+@protected TextDirection dco_decode_text_direction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TextDirection.values[raw as int]; }
+
+@protected TlsFailure dco_decode_tls_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return TlsFailure(kind: dco_decode_tls_failure_kind(arr[0]),
+host: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),
+names: dco_decode_list_String(arr[3]),
+subject: dco_decode_String(arr[4]),
+issuer: dco_decode_String(arr[5]),
+notBefore: dco_decode_i_64(arr[6]),
+notAfter: dco_decode_i_64(arr[7]),
+intermediates: dco_decode_u_32(arr[8]),); }
+
+@protected TlsFailureKind dco_decode_tls_failure_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TlsFailureKind.values[raw as int]; }
+
+@protected TlsPolicy dco_decode_tls_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TlsPolicy.values[raw as int]; }
+
+@protected TokenKind dco_decode_token_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TokenKind.values[raw as int]; }
+
+@protected TokenSpan dco_decode_token_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TokenSpan(text: dco_decode_String(arr[0]),
+kind: dco_decode_token_kind(arr[1]),); }
+
+@protected TopSite dco_decode_top_site(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return TopSite(url: dco_decode_String(arr[0]),
+host: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+favicon: dco_decode_opt_String(arr[3]),
+visitCount: dco_decode_u_32(arr[4]),
+lastVisited: dco_decode_i_64(arr[5]),
+score: dco_decode_f_64(arr[6]),); }
+
+@protected TraceEvent dco_decode_trace_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TraceEvent(stage: dco_decode_trace_stage(arr[0]),
+message: dco_decode_String(arr[1]),
+elapsedMs: dco_decode_u_64(arr[2]),); }
+
+@protected TraceStage dco_decode_trace_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TraceStage.values[raw as int]; }
+
+@protected TrustedCa dco_decode_trusted_ca(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return TrustedCa(fingerprint: dco_decode_String(arr[0]),
+subject: dco_decode_String(arr[1]),
+notAfter: dco_decode_i_64(arr[2]),
+addedAt: dco_decode_i_64(arr[3]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected WatchedPage dco_decode_watched_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return WatchedPage(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+addedAt: dco_decode_i_64(arr[3]),
+seenAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+checkedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+changedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+lastError: dco_decode_opt_String(arr[7]),); }
+
+@protected WatchedPlan dco_decode_watched_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return WatchedPlan(id: dco_decode_i_64(arr[0]),
+url: dco_decode_String(arr[1]),
+addedAt: dco_decode_i_64(arr[2]),
+plan: dco_decode_opt_String(arr[3]),
+checkedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+updatedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+lastError: dco_decode_opt_String(arr[6]),); }
+
+@protected String sse_decode_Char(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return inner; }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AddedBookmark sse_decode_added_bookmark(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bookmark = sse_decode_bookmark(deserializer);
+var var_duplicate = sse_decode_bool(deserializer);
+return AddedBookmark(bookmark: var_bookmark, duplicate: var_duplicate); }
+
+@protected AnsiColor sse_decode_ansi_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_u_8(deserializer);
+return AnsiColor_Indexed(var_field0);case 1: var var_r = sse_decode_u_8(deserializer);
+var var_g = sse_decode_u_8(deserializer);
+var var_b = sse_decode_u_8(deserializer);
+return AnsiColor_Rgb(r: var_r, g: var_g, b: var_b); default: throw UnimplementedError(''); }
+             }
+
+@protected AnsiStyle sse_decode_ansi_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_foreground = sse_decode_opt_box_autoadd_ansi_color(deserializer);
+var var_background = sse_decode_opt_box_autoadd_ansi_color(deserializer);
+var var_bold = sse_decode_bool(deserializer);
+var var_dim = sse_decode_bool(deserializer);
+var var_italic = sse_decode_bool(deserializer);
+var var_underline = sse_decode_bool(deserializer);
+var var_inverse = sse_decode_bool(deserializer);
+return AnsiStyle(foreground: var_foreground, background: var_background, bold: var_bold, dim: var_dim, italic: var_italic, underline: var_underline, inverse: var_inverse); }
+
+@protected ArchivedPage sse_decode_archived_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_bodyHash = sse_decode_String(deserializer);
+var var_certificateFingerprint = sse_decode_opt_String(deserializer);
+var var_fetchedAt = sse_decode_i_64(deserializer);
+var var_archivedAt = sse_decode_i_64(deserializer);
+return ArchivedPage(id: var_id, url: var_url, status: var_status, mime: var_mime, title: var_title, size: var_size, bodyHash: var_bodyHash, certificateFingerprint: var_certificateFingerprint, fetchedAt: var_fetchedAt, archivedAt: var_archivedAt); }
+
+@protected BatchItem sse_decode_batch_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_requestId = sse_decode_u_64(deserializer);
+var var_result = sse_decode_opt_box_autoadd_navigation_result(deserializer);
+var var_error = sse_decode_opt_box_autoadd_error_info(deserializer);
+return BatchItem(index: var_index, url: var_url, requestId: var_requestId, result: var_result, error: var_error); }
+
+@protected BlockRule sse_decode_block_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pattern = sse_decode_String(deserializer);
+var var_kind = sse_decode_block_rule_kind(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return BlockRule(pattern: var_pattern, kind: var_kind, createdAt: var_createdAt); }
+
+@protected BlockRuleKind sse_decode_block_rule_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BlockRuleKind.values[inner]; }
+
+@protected Bookmark sse_decode_bookmark(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_favicon = sse_decode_opt_String(deserializer);
+return Bookmark(id: var_id, url: var_url, title: var_title, createdAt: var_createdAt, favicon: var_favicon); }
+
+@protected BookmarkFormat sse_decode_bookmark_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BookmarkFormat.values[inner]; }
+
+@protected BookmarkSource sse_decode_bookmark_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BookmarkSource.values[inner]; }
+
+@protected BookmarksImport sse_decode_bookmarks_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_imported = sse_decode_u_32(deserializer);
+var var_skipped = sse_decode_u_32(deserializer);
+return BookmarksImport(imported: var_imported, skipped: var_skipped); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AnsiColor sse_decode_box_autoadd_ansi_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ansi_color(deserializer)); }
+
+@protected BatchItem sse_decode_box_autoadd_batch_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_batch_item(deserializer)); }
+
+@protected Bookmark sse_decode_box_autoadd_bookmark(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bookmark(deserializer)); }
+
+@protected CachePolicy sse_decode_box_autoadd_cache_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cache_policy(deserializer)); }
+
+@protected CertificateError sse_decode_box_autoadd_certificate_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_certificate_error(deserializer)); }
+
+@protected CertificateSummary sse_decode_box_autoadd_certificate_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_certificate_summary(deserializer)); }
+
+@protected Config sse_decode_box_autoadd_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_config(deserializer)); }
+
+@protected DecompressionLimits sse_decode_box_autoadd_decompression_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_decompression_limits(deserializer)); }
+
+@protected DownloadStatus sse_decode_box_autoadd_download_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_download_status(deserializer)); }
+
+@protected ErrorInfo sse_decode_box_autoadd_error_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_error_info(deserializer)); }
+
+@protected FetchOptions sse_decode_box_autoadd_fetch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fetch_options(deserializer)); }
+
+@protected FileType sse_decode_box_autoadd_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_file_type(deserializer)); }
+
+@protected GemtextLine sse_decode_box_autoadd_gemtext_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_gemtext_line(deserializer)); }
+
+@protected HistoryRange sse_decode_box_autoadd_history_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_history_range(deserializer)); }
+
+@protected HostSettings sse_decode_box_autoadd_host_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_host_settings(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected IdentityScope sse_decode_box_autoadd_identity_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_identity_scope(deserializer)); }
+
+@protected MirrorProgress sse_decode_box_autoadd_mirror_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_mirror_progress(deserializer)); }
+
+@protected NavigateError sse_decode_box_autoadd_navigate_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_navigate_error(deserializer)); }
+
+@protected NavigationEvent sse_decode_box_autoadd_navigation_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_navigation_event(deserializer)); }
+
+@protected NavigationResult sse_decode_box_autoadd_navigation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_navigation_result(deserializer)); }
+
+@protected ProxySettings sse_decode_box_autoadd_proxy_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_proxy_settings(deserializer)); }
+
+@protected RateLimits sse_decode_box_autoadd_rate_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rate_limits(deserializer)); }
+
+@protected RecoveryAction sse_decode_box_autoadd_recovery_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_recovery_action(deserializer)); }
+
+@protected RedirectPolicy sse_decode_box_autoadd_redirect_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_redirect_policy(deserializer)); }
+
+@protected Renderer sse_decode_box_autoadd_renderer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_renderer(deserializer)); }
+
+@protected ServerFailure sse_decode_box_autoadd_server_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_server_failure(deserializer)); }
+
+@protected Session sse_decode_box_autoadd_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_session(deserializer)); }
+
+@protected Socks5Proxy sse_decode_box_autoadd_socks_5_proxy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_socks_5_proxy(deserializer)); }
+
+@protected StreamOptions sse_decode_box_autoadd_stream_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stream_options(deserializer)); }
+
+@protected TaskSchedule sse_decode_box_autoadd_task_schedule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_task_schedule(deserializer)); }
+
+@protected TlsFailure sse_decode_box_autoadd_tls_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tls_failure(deserializer)); }
+
+@protected TlsPolicy sse_decode_box_autoadd_tls_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tls_policy(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected CacheEntry sse_decode_cache_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_fetchedAt = sse_decode_i_64(deserializer);
+var var_expiresAt = sse_decode_i_64(deserializer);
+var var_stale = sse_decode_bool(deserializer);
+return CacheEntry(url: var_url, host: var_host, mime: var_mime, size: var_size, fetchedAt: var_fetchedAt, expiresAt: var_expiresAt, stale: var_stale); }
+
+@protected CachePolicy sse_decode_cache_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scheme = sse_decode_String(deserializer);
+var var_cacheable = sse_decode_bool(deserializer);
+var var_ttlSecs = sse_decode_u_32(deserializer);
+var var_cacheQueries = sse_decode_bool(deserializer);
+return CachePolicy(scheme: var_scheme, cacheable: var_cacheable, ttlSecs: var_ttlSecs, cacheQueries: var_cacheQueries); }
+
+@protected CertificateError sse_decode_certificate_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_certificate_error_kind(deserializer);
+var var_identityId = sse_decode_opt_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_suggestedAction = sse_decode_recovery_action(deserializer);
+return CertificateError(kind: var_kind, identityId: var_identityId, message: var_message, suggestedAction: var_suggestedAction); }
+
+@protected CertificateErrorKind sse_decode_certificate_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CertificateErrorKind.values[inner]; }
+
+@protected CertificateSummary sse_decode_certificate_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fingerprint = sse_decode_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_issuer = sse_decode_String(deserializer);
+var var_notBefore = sse_decode_i_64(deserializer);
+var var_notAfter = sse_decode_i_64(deserializer);
+var var_firstSeen = sse_decode_i_64(deserializer);
+var var_pinStatus = sse_decode_pin_status(deserializer);
+var var_expiry = sse_decode_pin_expiry(deserializer);
+var var_tlsVersion = sse_decode_opt_String(deserializer);
+var var_cipherSuite = sse_decode_opt_String(deserializer);
+return CertificateSummary(fingerprint: var_fingerprint, subject: var_subject, issuer: var_issuer, notBefore: var_notBefore, notAfter: var_notAfter, firstSeen: var_firstSeen, pinStatus: var_pinStatus, expiry: var_expiry, tlsVersion: var_tlsVersion, cipherSuite: var_cipherSuite); }
+
+@protected ChecksumStatus sse_decode_checksum_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChecksumStatus.values[inner]; }
+
+@protected ClearedData sse_decode_cleared_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_category = sse_decode_data_category(deserializer);
+var var_removed = sse_decode_u_32(deserializer);
+return ClearedData(category: var_category, removed: var_removed); }
+
+@protected ClockOffset sse_decode_clock_offset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_offsetSecs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_hosts = sse_decode_u_32(deserializer);
+var var_consistent = sse_decode_bool(deserializer);
+return ClockOffset(offsetSecs: var_offsetSecs, hosts: var_hosts, consistent: var_consistent); }
+
+@protected Config sse_decode_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_defaultScheme = sse_decode_String(deserializer);
+var var_guessSchemes = sse_decode_bool(deserializer);
+var var_startPage = sse_decode_String(deserializer);
+var var_searchProvider = sse_decode_String(deserializer);
+var var_connectTimeoutSecs = sse_decode_u_32(deserializer);
+var var_readTimeoutSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_tlsPolicy = sse_decode_tls_policy(deserializer);
+var var_proxy = sse_decode_proxy_settings(deserializer);
+return Config(defaultScheme: var_defaultScheme, guessSchemes: var_guessSchemes, startPage: var_startPage, searchProvider: var_searchProvider, connectTimeoutSecs: var_connectTimeoutSecs, readTimeoutSecs: var_readTimeoutSecs, tlsPolicy: var_tlsPolicy, proxy: var_proxy); }
+
+@protected CrawlResult sse_decode_crawl_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_capsule = sse_decode_String(deserializer);
+var var_indexed = sse_decode_u_32(deserializer);
+var var_failed = sse_decode_u_32(deserializer);
+var var_disallowed = sse_decode_u_32(deserializer);
+var var_remaining = sse_decode_u_32(deserializer);
+var var_cancelled = sse_decode_bool(deserializer);
+return CrawlResult(capsule: var_capsule, indexed: var_indexed, failed: var_failed, disallowed: var_disallowed, remaining: var_remaining, cancelled: var_cancelled); }
+
+@protected CsoField sse_decode_cso_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return CsoField(name: var_name, value: var_value); }
+
+@protected CsoRecord sse_decode_cso_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_fields = sse_decode_list_cso_field(deserializer);
+return CsoRecord(index: var_index, fields: var_fields); }
+
+@protected DailyUsage sse_decode_daily_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dayStart = sse_decode_i_64(deserializer);
+var var_network = sse_decode_network_type(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+return DailyUsage(dayStart: var_dayStart, network: var_network, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived); }
+
+@protected DataCapStatus sse_decode_data_cap_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_limitBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_usedBytes = sse_decode_u_64(deserializer);
+var var_exceeded = sse_decode_bool(deserializer);
+return DataCapStatus(limitBytes: var_limitBytes, usedBytes: var_usedBytes, exceeded: var_exceeded); }
+
+@protected DataCategory sse_decode_data_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DataCategory.values[inner]; }
+
+@protected DecompressionLimits sse_decode_decompression_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxRatio = sse_decode_u_32(deserializer);
+var var_maxBytes = sse_decode_u_64(deserializer);
+return DecompressionLimits(maxRatio: var_maxRatio, maxBytes: var_maxBytes); }
+
+@protected DiffLine sse_decode_diff_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_line = sse_decode_u_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+return DiffLine(line: var_line, text: var_text); }
+
+@protected DisplayHost sse_decode_display_host(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_spoofingWarning = sse_decode_bool(deserializer);
+return DisplayHost(host: var_host, spoofingWarning: var_spoofingWarning); }
+
+@protected DownloadRecord sse_decode_download_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_status = sse_decode_download_status(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_finishedAt = sse_decode_i_64(deserializer);
+var var_checksumUrl = sse_decode_opt_String(deserializer);
+var var_checksum = sse_decode_checksum_status(deserializer);
+return DownloadRecord(id: var_id, url: var_url, path: var_path, size: var_size, mime: var_mime, status: var_status, error: var_error, finishedAt: var_finishedAt, checksumUrl: var_checksumUrl, checksum: var_checksum); }
+
+@protected DownloadResult sse_decode_download_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_sha256 = sse_decode_String(deserializer);
+var var_checksum = sse_decode_checksum_status(deserializer);
+return DownloadResult(path: var_path, size: var_size, mime: var_mime, sha256: var_sha256, checksum: var_checksum); }
+
+@protected DownloadStatus sse_decode_download_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DownloadStatus.values[inner]; }
+
+@protected ErrorCode sse_decode_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ErrorCode.values[inner]; }
+
+@protected ErrorInfo sse_decode_error_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_error_code(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_number = sse_decode_u_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+var var_userActionable = sse_decode_bool(deserializer);
+var var_suggestedAction = sse_decode_opt_box_autoadd_recovery_action(deserializer);
+return ErrorInfo(code: var_code, name: var_name, number: var_number, message: var_message, retryable: var_retryable, userActionable: var_userActionable, suggestedAction: var_suggestedAction); }
+
+@protected ExpiryReminder sse_decode_expiry_reminder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_identity = sse_decode_identity(deserializer);
+var var_daysLeft = sse_decode_i_64(deserializer);
+var var_expired = sse_decode_bool(deserializer);
+return ExpiryReminder(identity: var_identity, daysLeft: var_daysLeft, expired: var_expired); }
+
+@protected ExploreSource sse_decode_explore_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_directoryUrl = sse_decode_opt_String(deserializer);
+var var_weight = sse_decode_f_64(deserializer);
+return ExploreSource(directoryUrl: var_directoryUrl, weight: var_weight); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected Feed sse_decode_feed(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_kind = sse_decode_feed_kind(deserializer);
+var var_subscribedAt = sse_decode_i_64(deserializer);
+var var_refreshedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+var var_unread = sse_decode_u_32(deserializer);
+return Feed(id: var_id, url: var_url, title: var_title, kind: var_kind, subscribedAt: var_subscribedAt, refreshedAt: var_refreshedAt, lastError: var_lastError, unread: var_unread); }
+
+@protected FeedCandidate sse_decode_feed_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_kind = sse_decode_feed_kind(deserializer);
+return FeedCandidate(url: var_url, title: var_title, kind: var_kind); }
+
+@protected FeedEntry sse_decode_feed_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_feedId = sse_decode_i_64(deserializer);
+var var_feedTitle = sse_decode_opt_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_publishedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_seenAt = sse_decode_i_64(deserializer);
+var var_read = sse_decode_bool(deserializer);
+return FeedEntry(id: var_id, feedId: var_feedId, feedTitle: var_feedTitle, url: var_url, title: var_title, publishedAt: var_publishedAt, seenAt: var_seenAt, read: var_read); }
+
+@protected FeedKind sse_decode_feed_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FeedKind.values[inner]; }
+
+@protected FetchOptions sse_decode_fetch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_private = sse_decode_bool(deserializer);
+var var_useCache = sse_decode_bool(deserializer);
+var var_revalidate = sse_decode_bool(deserializer);
+var var_probeProtocols = sse_decode_bool(deserializer);
+var var_background = sse_decode_bool(deserializer);
+var var_identityId = sse_decode_opt_String(deserializer);
+var var_connectTimeoutSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_readTimeoutSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_deadlineSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_maxBodyBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+return FetchOptions(private: var_private, useCache: var_useCache, revalidate: var_revalidate, probeProtocols: var_probeProtocols, background: var_background, identityId: var_identityId, connectTimeoutSecs: var_connectTimeoutSecs, readTimeoutSecs: var_readTimeoutSecs, deadlineSecs: var_deadlineSecs, maxBodyBytes: var_maxBodyBytes); }
+
+@protected FetchResponse sse_decode_fetch_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_redirects = sse_decode_list_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_meta = sse_decode_opt_String(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_renderer = sse_decode_renderer(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_bodyHash = sse_decode_String(deserializer);
+var var_unchanged = sse_decode_bool(deserializer);
+var var_truncated = sse_decode_bool(deserializer);
+var var_stale = sse_decode_bool(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_feeds = sse_decode_list_feed_candidate(deserializer);
+var var_favicon = sse_decode_opt_String(deserializer);
+var var_fragment = sse_decode_opt_String(deserializer);
+var var_fragmentLine = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_cachedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_requestId = sse_decode_u_64(deserializer);
+var var_displayHost = sse_decode_String(deserializer);
+var var_spoofingWarning = sse_decode_bool(deserializer);
+var var_clockSkewWarning = sse_decode_bool(deserializer);
+var var_probedScheme = sse_decode_opt_String(deserializer);
+var var_certificateError = sse_decode_opt_box_autoadd_certificate_error(deserializer);
+var var_serverFailure = sse_decode_opt_box_autoadd_server_failure(deserializer);
+var var_certificate = sse_decode_opt_box_autoadd_certificate_summary(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_timings = sse_decode_request_timings(deserializer);
+return FetchResponse(url: var_url, redirects: var_redirects, status: var_status, meta: var_meta, mime: var_mime, renderer: var_renderer, body: var_body, bodyHash: var_bodyHash, unchanged: var_unchanged, truncated: var_truncated, stale: var_stale, title: var_title, feeds: var_feeds, favicon: var_favicon, fragment: var_fragment, fragmentLine: var_fragmentLine, cachedAt: var_cachedAt, requestId: var_requestId, displayHost: var_displayHost, spoofingWarning: var_spoofingWarning, clockSkewWarning: var_clockSkewWarning, probedScheme: var_probedScheme, certificateError: var_certificateError, serverFailure: var_serverFailure, certificate: var_certificate, durationMs: var_durationMs, timings: var_timings); }
+
+@protected FileType sse_decode_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mime = sse_decode_String(deserializer);
+var var_extension_ = sse_decode_String(deserializer);
+return FileType(mime: var_mime, extension_: var_extension_); }
+
+@protected GempubBook sse_decode_gempub_book(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_handle = sse_decode_u_64(deserializer);
+var var_metadata = sse_decode_gempub_metadata(deserializer);
+var var_indexPath = sse_decode_opt_String(deserializer);
+var var_chapters = sse_decode_list_gempub_chapter(deserializer);
+return GempubBook(handle: var_handle, metadata: var_metadata, indexPath: var_indexPath, chapters: var_chapters); }
+
+@protected GempubChapter sse_decode_gempub_chapter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+return GempubChapter(title: var_title, path: var_path); }
+
+@protected GempubMetadata sse_decode_gempub_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_published = sse_decode_opt_String(deserializer);
+var var_version = sse_decode_opt_String(deserializer);
+var var_cover = sse_decode_opt_String(deserializer);
+return GempubMetadata(title: var_title, author: var_author, language: var_language, description: var_description, published: var_published, version: var_version, cover: var_cover); }
+
+@protected GempubPage sse_decode_gempub_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_chapter = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_previousChapter = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_nextChapter = sse_decode_opt_box_autoadd_u_32(deserializer);
+return GempubPage(path: var_path, mime: var_mime, body: var_body, data: var_data, chapter: var_chapter, previousChapter: var_previousChapter, nextChapter: var_nextChapter); }
+
+@protected GemtextLine sse_decode_gemtext_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return GemtextLine_Text(var_field0);case 1: var var_url = sse_decode_String(deserializer);
+var var_label = sse_decode_opt_String(deserializer);
+return GemtextLine_Link(url: var_url, label: var_label);case 2: var var_level = sse_decode_u_8(deserializer);
+var var_text = sse_decode_String(deserializer);
+return GemtextLine_Heading(level: var_level, text: var_text);case 3: var var_field0 = sse_decode_String(deserializer);
+return GemtextLine_ListItem(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return GemtextLine_Quote(var_field0);case 5: var var_alt = sse_decode_opt_String(deserializer);
+return GemtextLine_PreformatToggle(alt: var_alt);case 6: var var_field0 = sse_decode_String(deserializer);
+return GemtextLine_Preformatted(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected GopherItem sse_decode_gopher_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_itemType = sse_decode_Char(deserializer);
+var var_display = sse_decode_String(deserializer);
+var var_selector = sse_decode_String(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_icon = sse_decode_String(deserializer);
+var var_fetchable = sse_decode_bool(deserializer);
+return GopherItem(itemType: var_itemType, display: var_display, selector: var_selector, host: var_host, port: var_port, kind: var_kind, icon: var_icon, fetchable: var_fetchable); }
+
+@protected HeadingAnchor sse_decode_heading_anchor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_slug = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_level = sse_decode_u_8(deserializer);
+var var_lineIndex = sse_decode_u_32(deserializer);
+return HeadingAnchor(slug: var_slug, text: var_text, level: var_level, lineIndex: var_lineIndex); }
+
+@protected HighlightedBlock sse_decode_highlighted_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startIndex = sse_decode_u_32(deserializer);
+var var_language = sse_decode_String(deserializer);
+var var_lines = sse_decode_list_highlighted_line(deserializer);
+return HighlightedBlock(startIndex: var_startIndex, language: var_language, lines: var_lines); }
+
+@protected HighlightedLine sse_decode_highlighted_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_spans = sse_decode_list_token_span(deserializer);
+return HighlightedLine(spans: var_spans); }
+
+@protected HistoryEntry sse_decode_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_visitedAt = sse_decode_i_64(deserializer);
+return HistoryEntry(id: var_id, url: var_url, title: var_title, visitedAt: var_visitedAt); }
+
+@protected HistoryMatch sse_decode_history_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HistoryMatch.values[inner]; }
+
+@protected HistoryRange sse_decode_history_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_since = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_until = sse_decode_opt_box_autoadd_i_64(deserializer);
+return HistoryRange(since: var_since, until: var_until); }
+
+@protected HistoryResult sse_decode_history_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_visitCount = sse_decode_u_32(deserializer);
+var var_lastVisited = sse_decode_i_64(deserializer);
+return HistoryResult(url: var_url, title: var_title, visitCount: var_visitCount, lastVisited: var_lastVisited); }
+
+@protected HostMigration sse_decode_host_migration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scheme = sse_decode_String(deserializer);
+var var_fromHost = sse_decode_String(deserializer);
+var var_toHost = sse_decode_String(deserializer);
+var var_movedPages = sse_decode_u_32(deserializer);
+var var_bookmarks = sse_decode_u_32(deserializer);
+return HostMigration(scheme: var_scheme, fromHost: var_fromHost, toHost: var_toHost, movedPages: var_movedPages, bookmarks: var_bookmarks); }
+
+@protected HostMigrationResult sse_decode_host_migration_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bookmarks = sse_decode_u_32(deserializer);
+return HostMigrationResult(bookmarks: var_bookmarks); }
+
+@protected HostSettings sse_decode_host_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_tlsPolicy = sse_decode_opt_box_autoadd_tls_policy(deserializer);
+var var_identityId = sse_decode_opt_String(deserializer);
+var var_connectTimeoutSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_readTimeoutSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_redirectPolicy = sse_decode_opt_box_autoadd_redirect_policy(deserializer);
+var var_maxBodyBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_charset = sse_decode_opt_String(deserializer);
+return HostSettings(host: var_host, tlsPolicy: var_tlsPolicy, identityId: var_identityId, connectTimeoutSecs: var_connectTimeoutSecs, readTimeoutSecs: var_readTimeoutSecs, redirectPolicy: var_redirectPolicy, maxBodyBytes: var_maxBodyBytes, charset: var_charset); }
+
+@protected HostStats sse_decode_host_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_requests = sse_decode_u_32(deserializer);
+var var_errors = sse_decode_u_32(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_averageLatencyMs = sse_decode_u_32(deserializer);
+var var_errorRate = sse_decode_f_64(deserializer);
+var var_firstRequestAt = sse_decode_i_64(deserializer);
+var var_lastRequestAt = sse_decode_i_64(deserializer);
+return HostStats(host: var_host, requests: var_requests, errors: var_errors, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, averageLatencyMs: var_averageLatencyMs, errorRate: var_errorRate, firstRequestAt: var_firstRequestAt, lastRequestAt: var_lastRequestAt); }
+
+@protected HostStatsOrder sse_decode_host_stats_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HostStatsOrder.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IdentitiesImport sse_decode_identities_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_imported = sse_decode_u_32(deserializer);
+var var_skipped = sse_decode_u_32(deserializer);
+return IdentitiesImport(imported: var_imported, skipped: var_skipped); }
+
+@protected Identity sse_decode_identity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_commonName = sse_decode_String(deserializer);
+var var_fingerprint = sse_decode_String(deserializer);
+var var_notBefore = sse_decode_i_64(deserializer);
+var var_notAfter = sse_decode_i_64(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return Identity(id: var_id, name: var_name, commonName: var_commonName, fingerprint: var_fingerprint, notBefore: var_notBefore, notAfter: var_notAfter, createdAt: var_createdAt); }
+
+@protected IdentityScope sse_decode_identity_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_prefix = sse_decode_String(deserializer);
+var var_identityId = sse_decode_String(deserializer);
+var var_activatedAt = sse_decode_i_64(deserializer);
+return IdentityScope(prefix: var_prefix, identityId: var_identityId, activatedAt: var_activatedAt); }
+
+@protected InlineImage sse_decode_inline_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_linkIndex = sse_decode_u_32(deserializer);
+var var_lineIndex = sse_decode_u_32(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return InlineImage(linkIndex: var_linkIndex, lineIndex: var_lineIndex, url: var_url, mime: var_mime, data: var_data); }
+
+@protected InlineImageFailure sse_decode_inline_image_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_linkIndex = sse_decode_u_32(deserializer);
+var var_lineIndex = sse_decode_u_32(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+return InlineImageFailure(linkIndex: var_linkIndex, lineIndex: var_lineIndex, url: var_url, error: var_error); }
+
+@protected InlineImages sse_decode_inline_images(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_images = sse_decode_list_inline_image(deserializer);
+var var_failed = sse_decode_list_inline_image_failure(deserializer);
+return InlineImages(images: var_images, failed: var_failed); }
+
+@protected KnownHost sse_decode_known_host(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_fingerprint = sse_decode_String(deserializer);
+var var_expiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_firstSeen = sse_decode_i_64(deserializer);
+var var_lastSeen = sse_decode_i_64(deserializer);
+return KnownHost(host: var_host, port: var_port, fingerprint: var_fingerprint, expiresAt: var_expiresAt, firstSeen: var_firstSeen, lastSeen: var_lastSeen); }
+
+@protected KnownHostsFormat sse_decode_known_hosts_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KnownHostsFormat.values[inner]; }
+
+@protected KnownHostsImport sse_decode_known_hosts_import(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_imported = sse_decode_u_32(deserializer);
+var var_skipped = sse_decode_u_32(deserializer);
+return KnownHostsImport(imported: var_imported, skipped: var_skipped); }
+
+@protected LevelRun sse_decode_level_run(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_level = sse_decode_u_8(deserializer);
+var var_direction = sse_decode_text_direction(deserializer);
+return LevelRun(start: var_start, end: var_end, level: var_level, direction: var_direction); }
+
+@protected LineDirection sse_decode_line_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lineIndex = sse_decode_u_32(deserializer);
+var var_direction = sse_decode_text_direction(deserializer);
+var var_runs = sse_decode_list_level_run(deserializer);
+return LineDirection(lineIndex: var_lineIndex, direction: var_direction, runs: var_runs); }
+
+@protected LinkKind sse_decode_link_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LinkKind.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ArchivedPage> sse_decode_list_archived_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ArchivedPage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_archived_page(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BlockRule> sse_decode_list_block_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BlockRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_block_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Bookmark> sse_decode_list_bookmark(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Bookmark>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_bookmark(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CacheEntry> sse_decode_list_cache_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CacheEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cache_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CachePolicy> sse_decode_list_cache_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CachePolicy>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cache_policy(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ClearedData> sse_decode_list_cleared_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ClearedData>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cleared_data(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CsoField> sse_decode_list_cso_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CsoField>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cso_field(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CsoRecord> sse_decode_list_cso_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CsoRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cso_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DailyUsage> sse_decode_list_daily_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DailyUsage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_daily_usage(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DataCategory> sse_decode_list_data_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DataCategory>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_data_category(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiffLine> sse_decode_list_diff_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiffLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_diff_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DownloadRecord> sse_decode_list_download_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DownloadRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_download_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExpiryReminder> sse_decode_list_expiry_reminder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExpiryReminder>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_expiry_reminder(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ExploreSource> sse_decode_list_explore_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ExploreSource>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_explore_source(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Feed> sse_decode_list_feed(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Feed>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feed(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FeedCandidate> sse_decode_list_feed_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FeedCandidate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feed_candidate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FeedEntry> sse_decode_list_feed_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FeedEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_feed_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GempubChapter> sse_decode_list_gempub_chapter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GempubChapter>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_gempub_chapter(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GemtextLine> sse_decode_list_gemtext_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GemtextLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_gemtext_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GopherItem> sse_decode_list_gopher_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GopherItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_gopher_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HighlightedBlock> sse_decode_list_highlighted_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HighlightedBlock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_highlighted_block(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HighlightedLine> sse_decode_list_highlighted_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HighlightedLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_highlighted_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistoryEntry> sse_decode_list_history_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistoryEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_history_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistoryResult> sse_decode_list_history_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistoryResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_history_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HostMigration> sse_decode_list_host_migration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HostMigration>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_host_migration(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HostSettings> sse_decode_list_host_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HostSettings>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_host_settings(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HostStats> sse_decode_list_host_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HostStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_host_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Identity> sse_decode_list_identity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Identity>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_identity(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IdentityScope> sse_decode_list_identity_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IdentityScope>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_identity_scope(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InlineImage> sse_decode_list_inline_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InlineImage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_inline_image(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InlineImageFailure> sse_decode_list_inline_image_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InlineImageFailure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_inline_image_failure(deserializer)); }
+        return ans_;
+         }
+
+@protected List<KnownHost> sse_decode_list_known_host(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <KnownHost>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_known_host(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LevelRun> sse_decode_list_level_run(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LevelRun>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_level_run(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LineDirection> sse_decode_list_line_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LineDirection>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_line_direction(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LocalSearchResult> sse_decode_list_local_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LocalSearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_local_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LogRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_log_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OutlineEntry> sse_decode_list_outline_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OutlineEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_outline_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PageLink> sse_decode_list_page_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PageLink>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_page_link(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PortOverride> sse_decode_list_port_override(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PortOverride>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_port_override(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PreformattedBlock> sse_decode_list_preformatted_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PreformattedBlock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_preformatted_block(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ProtocolInfo> sse_decode_list_protocol_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProtocolInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_protocol_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProviderFailure> sse_decode_list_provider_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProviderFailure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_provider_failure(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProxyRule> sse_decode_list_proxy_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProxyRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_proxy_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReadLaterItem> sse_decode_list_read_later_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReadLaterItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_read_later_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReflowedLine> sse_decode_list_reflowed_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReflowedLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_reflowed_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SavedAnswer> sse_decode_list_saved_answer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SavedAnswer>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_saved_answer(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SchedulerEvent> sse_decode_list_scheduler_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SchedulerEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_scheduler_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SchemeProxy> sse_decode_list_scheme_proxy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SchemeProxy>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_scheme_proxy(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchProvider> sse_decode_list_search_provider(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchProvider>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_provider(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionEntry> sse_decode_list_session_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionSummary> sse_decode_list_session_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionTab> sse_decode_list_session_tab(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionTab>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_tab(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SiteNode> sse_decode_list_site_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SiteNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_site_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SnippetPart> sse_decode_list_snippet_part(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SnippetPart>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snippet_part(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StyledSpan> sse_decode_list_styled_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StyledSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_styled_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Suggestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_suggestion(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TaskSchedule> sse_decode_list_task_schedule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TaskSchedule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_task_schedule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TokenSpan> sse_decode_list_token_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TokenSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_token_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TopSite> sse_decode_list_top_site(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TopSite>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_top_site(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TraceEvent> sse_decode_list_trace_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TraceEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_trace_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TrustedCa> sse_decode_list_trusted_ca(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TrustedCa>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_trusted_ca(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WatchedPage> sse_decode_list_watched_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WatchedPage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_watched_page(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WatchedPlan> sse_decode_list_watched_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WatchedPlan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_watched_plan(deserializer)); }
+        return ans_;
+         }
+
+@protected LocalSearchResult sse_decode_local_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_snippet = sse_decode_list_snippet_part(deserializer);
+var var_fetchedAt = sse_decode_i_64(deserializer);
+var var_lastVisitedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return LocalSearchResult(url: var_url, title: var_title, snippet: var_snippet, fetchedAt: var_fetchedAt, lastVisitedAt: var_lastVisitedAt); }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_i_64(deserializer);
+var var_level = sse_decode_log_level(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_requestId = sse_decode_opt_box_autoadd_u_64(deserializer);
+return LogRecord(timestampMs: var_timestampMs, level: var_level, target: var_target, message: var_message, requestId: var_requestId); }
+
+@protected MenuPage sse_decode_menu_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_offset = sse_decode_u_32(deserializer);
+var var_items = sse_decode_list_gopher_item(deserializer);
+var var_total = sse_decode_u_32(deserializer);
+return MenuPage(offset: var_offset, items: var_items, total: var_total); }
+
+@protected MetasearchResult sse_decode_metasearch_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_failed = sse_decode_list_provider_failure(deserializer);
+return MetasearchResult(hits: var_hits, failed: var_failed); }
+
+@protected MirrorProgress sse_decode_mirror_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_path = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_saved = sse_decode_u_32(deserializer);
+var var_failed = sse_decode_u_32(deserializer);
+var var_remaining = sse_decode_u_32(deserializer);
+return MirrorProgress(url: var_url, path: var_path, error: var_error, saved: var_saved, failed: var_failed, remaining: var_remaining); }
+
+@protected NavigateError sse_decode_navigate_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_InvalidUrl(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_UnsupportedScheme(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_SchemeDisabled(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_Blocked(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_Disallowed(var_field0);case 5: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_Offline(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_DnsFailure(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ConnectTimeout(var_field0);case 8: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ConnectionRefused(var_field0);case 9: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ConnectionFailed(var_field0);case 10: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ConnectionLost(var_field0);case 11: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ReadTimeout(var_field0);case 12: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_GeminiTimeout(var_field0);case 13: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ProxyUnreachable(var_field0);case 14: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ProxyAuthFailed(var_field0);case 15: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_ProxyError(var_field0);case 16: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_GatewayError(var_field0);case 17: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_TlsError(var_field0);case 18: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_FingerprintMismatch(var_field0);case 19: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_CertificateDate(var_field0);case 20: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_CertificateRejected(var_field0);case 21: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_BadResponse(var_field0);case 22: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_TooLarge(var_field0);case 23: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_NotFound(var_field0);case 24: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_InvalidInput(var_field0);case 25: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_GopherBadType(var_field0);case 26: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_GopherBadSelector(var_field0);case 27: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_StorageError(var_field0);case 28: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_IdentityError(var_field0);case 29: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_Cancelled(var_field0);case 30: var var_target = sse_decode_String(deserializer);
+var var_permanent = sse_decode_bool(deserializer);
+return NavigateError_Redirected(target: var_target, permanent: var_permanent);case 31: var var_prompt = sse_decode_String(deserializer);
+var var_sensitive = sse_decode_bool(deserializer);
+return NavigateError_InputRequired(prompt: var_prompt, sensitive: var_sensitive);case 32: var var_field0 = sse_decode_box_autoadd_certificate_error(deserializer);
+return NavigateError_CertificateRequired(var_field0);case 33: var var_field0 = sse_decode_box_autoadd_server_failure(deserializer);
+return NavigateError_ServerError(var_field0);case 34: var var_field0 = sse_decode_String(deserializer);
+return NavigateError_Other(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected NavigationEvent sse_decode_navigation_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requestId = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_navigation_event_kind(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_meta = sse_decode_opt_String(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+var var_message = sse_decode_opt_String(deserializer);
+return NavigationEvent(requestId: var_requestId, kind: var_kind, url: var_url, status: var_status, meta: var_meta, bytes: var_bytes, message: var_message); }
+
+@protected NavigationEventKind sse_decode_navigation_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NavigationEventKind.values[inner]; }
+
+@protected NavigationResult sse_decode_navigation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_redirects = sse_decode_list_String(deserializer);
+var var_protocol = sse_decode_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_mime = sse_decode_String(deserializer);
+var var_charset = sse_decode_opt_String(deserializer);
+var var_renderer = sse_decode_renderer(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_favicon = sse_decode_opt_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_truncated = sse_decode_bool(deserializer);
+var var_cachedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_stale = sse_decode_bool(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_timings = sse_decode_request_timings(deserializer);
+var var_certificate = sse_decode_opt_box_autoadd_certificate_summary(deserializer);
+var var_serverFailure = sse_decode_opt_box_autoadd_server_failure(deserializer);
+var var_requestId = sse_decode_u_64(deserializer);
+return NavigationResult(url: var_url, redirects: var_redirects, protocol: var_protocol, status: var_status, mime: var_mime, charset: var_charset, renderer: var_renderer, title: var_title, favicon: var_favicon, body: var_body, truncated: var_truncated, cachedAt: var_cachedAt, stale: var_stale, durationMs: var_durationMs, timings: var_timings, certificate: var_certificate, serverFailure: var_serverFailure, requestId: var_requestId); }
+
+@protected NetworkType sse_decode_network_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NetworkType.values[inner]; }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AnsiColor? sse_decode_opt_box_autoadd_ansi_color(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ansi_color(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BatchItem? sse_decode_opt_box_autoadd_batch_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_batch_item(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Bookmark? sse_decode_opt_box_autoadd_bookmark(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bookmark(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CertificateError? sse_decode_opt_box_autoadd_certificate_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_certificate_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CertificateSummary? sse_decode_opt_box_autoadd_certificate_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_certificate_summary(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DownloadStatus? sse_decode_opt_box_autoadd_download_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_download_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ErrorInfo? sse_decode_opt_box_autoadd_error_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_error_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FileType? sse_decode_opt_box_autoadd_file_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_file_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GemtextLine? sse_decode_opt_box_autoadd_gemtext_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_gemtext_line(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HostSettings? sse_decode_opt_box_autoadd_host_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_host_settings(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IdentityScope? sse_decode_opt_box_autoadd_identity_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_identity_scope(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MirrorProgress? sse_decode_opt_box_autoadd_mirror_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_mirror_progress(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NavigationEvent? sse_decode_opt_box_autoadd_navigation_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_navigation_event(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NavigationResult? sse_decode_opt_box_autoadd_navigation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_navigation_result(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RecoveryAction? sse_decode_opt_box_autoadd_recovery_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_recovery_action(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RedirectPolicy? sse_decode_opt_box_autoadd_redirect_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_redirect_policy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Renderer? sse_decode_opt_box_autoadd_renderer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_renderer(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ServerFailure? sse_decode_opt_box_autoadd_server_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_server_failure(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Session? sse_decode_opt_box_autoadd_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_session(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Socks5Proxy? sse_decode_opt_box_autoadd_socks_5_proxy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_socks_5_proxy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TlsFailure? sse_decode_opt_box_autoadd_tls_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_tls_failure(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TlsPolicy? sse_decode_opt_box_autoadd_tls_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_tls_policy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<HighlightedLine>? sse_decode_opt_list_highlighted_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_highlighted_line(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<TraceEvent>? sse_decode_opt_list_trace_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_trace_event(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OutlineEntry sse_decode_outline_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_anchor = sse_decode_heading_anchor(deserializer);
+var var_depth = sse_decode_u_8(deserializer);
+var var_parent = sse_decode_opt_box_autoadd_u_32(deserializer);
+return OutlineEntry(anchor: var_anchor, depth: var_depth, parent: var_parent); }
+
+@protected PageDiff sse_decode_page_diff(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_cachedAt = sse_decode_i_64(deserializer);
+var var_added = sse_decode_list_diff_line(deserializer);
+var var_removed = sse_decode_list_diff_line(deserializer);
+return PageDiff(url: var_url, cachedAt: var_cachedAt, added: var_added, removed: var_removed); }
+
+@protected PageLink sse_decode_page_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_label = sse_decode_opt_String(deserializer);
+var var_kind = sse_decode_link_kind(deserializer);
+return PageLink(url: var_url, label: var_label, kind: var_kind); }
+
+@protected PageSummary sse_decode_page_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_favicon = sse_decode_opt_String(deserializer);
+var var_cachedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return PageSummary(url: var_url, title: var_title, description: var_description, favicon: var_favicon, cachedAt: var_cachedAt); }
+
+@protected PeekResult sse_decode_peek_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_meta = sse_decode_opt_String(deserializer);
+var var_mime = sse_decode_opt_String(deserializer);
+var var_renderer = sse_decode_opt_box_autoadd_renderer(deserializer);
+var var_size = sse_decode_opt_box_autoadd_u_64(deserializer);
+return PeekResult(url: var_url, status: var_status, meta: var_meta, mime: var_mime, renderer: var_renderer, size: var_size); }
+
+@protected PinExpiry sse_decode_pin_expiry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PinExpiry.values[inner]; }
+
+@protected PinStatus sse_decode_pin_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PinStatus.values[inner]; }
+
+@protected PortOverride sse_decode_port_override(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scheme = sse_decode_String(deserializer);
+var var_host = sse_decode_opt_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+return PortOverride(scheme: var_scheme, host: var_host, port: var_port); }
+
+@protected PowerState sse_decode_power_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PowerState.values[inner]; }
+
+@protected PreformattedBlock sse_decode_preformatted_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startIndex = sse_decode_u_32(deserializer);
+var var_lineCount = sse_decode_u_32(deserializer);
+var var_alt = sse_decode_opt_String(deserializer);
+var var_maxWidth = sse_decode_u_32(deserializer);
+var var_hasWideChars = sse_decode_bool(deserializer);
+return PreformattedBlock(startIndex: var_startIndex, lineCount: var_lineCount, alt: var_alt, maxWidth: var_maxWidth, hasWideChars: var_hasWideChars); }
+
+@protected ProtocolCapabilities sse_decode_protocol_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userInput = sse_decode_bool(deserializer);
+var var_redirects = sse_decode_bool(deserializer);
+var var_clientCertificates = sse_decode_bool(deserializer);
+var var_menus = sse_decode_bool(deserializer);
+return ProtocolCapabilities(userInput: var_userInput, redirects: var_redirects, clientCertificates: var_clientCertificates, menus: var_menus); }
+
+@protected ProtocolInfo sse_decode_protocol_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scheme = sse_decode_String(deserializer);
+var var_defaultPort = sse_decode_u_16(deserializer);
+var var_requiresTls = sse_decode_bool(deserializer);
+var var_capabilities = sse_decode_protocol_capabilities(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+return ProtocolInfo(scheme: var_scheme, defaultPort: var_defaultPort, requiresTls: var_requiresTls, capabilities: var_capabilities, enabled: var_enabled); }
+
+@protected ProviderFailure sse_decode_provider_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+return ProviderFailure(name: var_name, error: var_error); }
+
+@protected ProxyProbe sse_decode_proxy_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_handshakeMs = sse_decode_u_32(deserializer);
+var var_connectMs = sse_decode_u_32(deserializer);
+return ProxyProbe(handshakeMs: var_handshakeMs, connectMs: var_connectMs); }
+
+@protected ProxyProtocol sse_decode_proxy_protocol(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProxyProtocol.values[inner]; }
+
+@protected ProxyRule sse_decode_proxy_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pattern = sse_decode_String(deserializer);
+var var_proxy = sse_decode_opt_box_autoadd_socks_5_proxy(deserializer);
+return ProxyRule(pattern: var_pattern, proxy: var_proxy); }
+
+@protected ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ProxySettings_Direct();case 1: var var_proxy = sse_decode_box_autoadd_socks_5_proxy(deserializer);
+return ProxySettings_Socks5(proxy: var_proxy);case 2: var var_rules = sse_decode_list_proxy_rule(deserializer);
+var var_fallback = sse_decode_opt_box_autoadd_socks_5_proxy(deserializer);
+return ProxySettings_PerRule(rules: var_rules, fallback: var_fallback); default: throw UnimplementedError(''); }
+             }
+
+@protected RandomDestination sse_decode_random_destination(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_label = sse_decode_opt_String(deserializer);
+var var_directoryUrl = sse_decode_opt_String(deserializer);
+return RandomDestination(url: var_url, label: var_label, directoryUrl: var_directoryUrl); }
+
+@protected RateLimits sse_decode_rate_limits(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxConnections = sse_decode_u_32(deserializer);
+var var_minIntervalMs = sse_decode_u_64(deserializer);
+return RateLimits(maxConnections: var_maxConnections, minIntervalMs: var_minIntervalMs); }
+
+@protected ReadLaterItem sse_decode_read_later_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_addedAt = sse_decode_i_64(deserializer);
+var var_readAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_cached = sse_decode_bool(deserializer);
+var var_fetchedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return ReadLaterItem(id: var_id, url: var_url, title: var_title, addedAt: var_addedAt, readAt: var_readAt, cached: var_cached, fetchedAt: var_fetchedAt, size: var_size, lastError: var_lastError); }
+
+@protected ReadingStats sse_decode_reading_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_words = sse_decode_u_32(deserializer);
+var var_preformattedWords = sse_decode_u_32(deserializer);
+var var_readingMinutes = sse_decode_u_32(deserializer);
+var var_links = sse_decode_u_32(deserializer);
+var var_headings = sse_decode_u_32(deserializer);
+return ReadingStats(words: var_words, preformattedWords: var_preformattedWords, readingMinutes: var_readingMinutes, links: var_links, headings: var_headings); }
+
+@protected RecoveryAction sse_decode_recovery_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RecoveryAction.values[inner]; }
+
+@protected RedirectPolicy sse_decode_redirect_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RedirectPolicy.values[inner]; }
+
+@protected ReflowedLine sse_decode_reflowed_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sourceIndex = sse_decode_u_32(deserializer);
+var var_line = sse_decode_gemtext_line(deserializer);
+var var_continuation = sse_decode_bool(deserializer);
+return ReflowedLine(sourceIndex: var_sourceIndex, line: var_line, continuation: var_continuation); }
+
+@protected Renderer sse_decode_renderer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Renderer.values[inner]; }
+
+@protected RequestTimings sse_decode_request_timings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dnsMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_connectMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_tlsMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_firstByteMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_transferMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+return RequestTimings(dnsMs: var_dnsMs, connectMs: var_connectMs, tlsMs: var_tlsMs, firstByteMs: var_firstByteMs, transferMs: var_transferMs, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived); }
+
+@protected SavedAnswer sse_decode_saved_answer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_prompt = sse_decode_String(deserializer);
+var var_answer = sse_decode_String(deserializer);
+var var_answeredAt = sse_decode_i_64(deserializer);
+var var_uses = sse_decode_u_32(deserializer);
+return SavedAnswer(id: var_id, url: var_url, prompt: var_prompt, answer: var_answer, answeredAt: var_answeredAt, uses: var_uses); }
+
+@protected ScheduledTask sse_decode_scheduled_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ScheduledTask.values[inner]; }
+
+@protected SchedulerEvent sse_decode_scheduler_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_task = sse_decode_scheduled_task(deserializer);
+var var_kind = sse_decode_scheduler_event_kind(deserializer);
+var var_timestamp = sse_decode_i_64(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+var var_items = sse_decode_list_String(deserializer);
+var var_message = sse_decode_opt_String(deserializer);
+var var_nextRunAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return SchedulerEvent(task: var_task, kind: var_kind, timestamp: var_timestamp, count: var_count, items: var_items, message: var_message, nextRunAt: var_nextRunAt); }
+
+@protected SchedulerEventKind sse_decode_scheduler_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SchedulerEventKind.values[inner]; }
+
+@protected SchemeProxy sse_decode_scheme_proxy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scheme = sse_decode_String(deserializer);
+var var_protocol = sse_decode_proxy_protocol(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_selector = sse_decode_String(deserializer);
+return SchemeProxy(scheme: var_scheme, protocol: var_protocol, host: var_host, port: var_port, selector: var_selector); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_snippet = sse_decode_opt_String(deserializer);
+var var_providers = sse_decode_list_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+return SearchHit(url: var_url, title: var_title, snippet: var_snippet, providers: var_providers, score: var_score); }
+
+@protected SearchProvider sse_decode_search_provider(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_urlTemplate = sse_decode_String(deserializer);
+return SearchProvider(name: var_name, urlTemplate: var_urlTemplate); }
+
+@protected ServerFailure sse_decode_server_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_u_8(deserializer);
+var var_kind = sse_decode_server_failure_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_temporary = sse_decode_bool(deserializer);
+var var_retryAfterSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_suggestedAction = sse_decode_opt_box_autoadd_recovery_action(deserializer);
+return ServerFailure(status: var_status, kind: var_kind, message: var_message, temporary: var_temporary, retryAfterSecs: var_retryAfterSecs, suggestedAction: var_suggestedAction); }
+
+@protected ServerFailureKind sse_decode_server_failure_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ServerFailureKind.values[inner]; }
+
+@protected Session sse_decode_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_tabs = sse_decode_list_session_tab(deserializer);
+var var_activeTab = sse_decode_u_32(deserializer);
+var var_savedAt = sse_decode_i_64(deserializer);
+return Session(name: var_name, tabs: var_tabs, activeTab: var_activeTab, savedAt: var_savedAt); }
+
+@protected SessionEntry sse_decode_session_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_scrollOffset = sse_decode_f_64(deserializer);
+return SessionEntry(url: var_url, title: var_title, scrollOffset: var_scrollOffset); }
+
+@protected SessionSummary sse_decode_session_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_tabCount = sse_decode_u_32(deserializer);
+var var_savedAt = sse_decode_i_64(deserializer);
+return SessionSummary(name: var_name, tabCount: var_tabCount, savedAt: var_savedAt); }
+
+@protected SessionTab sse_decode_session_tab(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entries = sse_decode_list_session_entry(deserializer);
+var var_currentEntry = sse_decode_u_32(deserializer);
+var var_private = sse_decode_bool(deserializer);
+return SessionTab(entries: var_entries, currentEntry: var_currentEntry, private: var_private); }
+
+@protected ShutdownSummary sse_decode_shutdown_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_streamsCancelled = sse_decode_u_32(deserializer);
+var var_fetchesAborted = sse_decode_u_32(deserializer);
+var var_requestsCancelled = sse_decode_u_32(deserializer);
+var var_mirrorsCancelled = sse_decode_u_32(deserializer);
+return ShutdownSummary(streamsCancelled: var_streamsCancelled, fetchesAborted: var_fetchesAborted, requestsCancelled: var_requestsCancelled, mirrorsCancelled: var_mirrorsCancelled); }
+
+@protected SiteMap sse_decode_site_map(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_root = sse_decode_String(deserializer);
+var var_nodes = sse_decode_list_site_node(deserializer);
+var var_truncated = sse_decode_bool(deserializer);
+return SiteMap(root: var_root, nodes: var_nodes, truncated: var_truncated); }
+
+@protected SiteNode sse_decode_site_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_kind = sse_decode_link_kind(deserializer);
+var var_icon = sse_decode_String(deserializer);
+var var_menu = sse_decode_bool(deserializer);
+var var_depth = sse_decode_u_32(deserializer);
+var var_parent = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_expanded = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return SiteNode(url: var_url, title: var_title, kind: var_kind, icon: var_icon, menu: var_menu, depth: var_depth, parent: var_parent, expanded: var_expanded, error: var_error); }
+
+@protected SnippetPart sse_decode_snippet_part(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_matched = sse_decode_bool(deserializer);
+return SnippetPart(text: var_text, matched: var_matched); }
+
+@protected Socks5Proxy sse_decode_socks_5_proxy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_username = sse_decode_opt_String(deserializer);
+var var_password = sse_decode_opt_String(deserializer);
+return Socks5Proxy(host: var_host, port: var_port, username: var_username, password: var_password); }
+
+@protected StreamEvent sse_decode_stream_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_stream_event_kind(deserializer);
+var var_status = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_meta = sse_decode_opt_String(deserializer);
+var var_text = sse_decode_opt_String(deserializer);
+var var_element = sse_decode_opt_box_autoadd_gemtext_line(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+return StreamEvent(kind: var_kind, status: var_status, meta: var_meta, text: var_text, element: var_element, bytesReceived: var_bytesReceived); }
+
+@protected StreamEventKind sse_decode_stream_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StreamEventKind.values[inner]; }
+
+@protected StreamOptions sse_decode_stream_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_idleIntervalSecs = sse_decode_u_32(deserializer);
+var var_maxIdleSecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+return StreamOptions(idleIntervalSecs: var_idleIntervalSecs, maxIdleSecs: var_maxIdleSecs); }
+
+@protected StyledSpan sse_decode_styled_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_style = sse_decode_ansi_style(deserializer);
+return StyledSpan(start: var_start, end: var_end, style: var_style); }
+
+@protected StyledText sse_decode_styled_text(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_spans = sse_decode_list_styled_span(deserializer);
+return StyledText(text: var_text, spans: var_spans); }
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_bookmarked = sse_decode_bool(deserializer);
+var var_visitCount = sse_decode_u_32(deserializer);
+var var_lastVisited = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_matched = sse_decode_suggestion_match(deserializer);
+var var_completion = sse_decode_opt_String(deserializer);
+return Suggestion(url: var_url, title: var_title, bookmarked: var_bookmarked, visitCount: var_visitCount, lastVisited: var_lastVisited, matched: var_matched, completion: var_completion); }
+
+@protected SuggestionMatch sse_decode_suggestion_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SuggestionMatch.values[inner]; }
+
+@protected TaskSchedule sse_decode_task_schedule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_task = sse_decode_scheduled_task(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+var var_intervalSecs = sse_decode_u_32(deserializer);
+var var_jitterSecs = sse_decode_u_32(deserializer);
+var var_unmeteredOnly = sse_decode_bool(deserializer);
+var var_chargingOnly = sse_decode_bool(deserializer);
+return TaskSchedule(task: var_task, enabled: var_enabled, intervalSecs: var_intervalSecs, jitterSecs: var_jitterSecs, unmeteredOnly: var_unmeteredOnly, chargingOnly: var_chargingOnly); }
+
+@protected TextDirection sse_decode_text_direction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TextDirection.values[inner]; }
+
+@protected TlsFailure sse_decode_tls_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_tls_failure_kind(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_names = sse_decode_list_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_issuer = sse_decode_String(deserializer);
+var var_notBefore = sse_decode_i_64(deserializer);
+var var_notAfter = sse_decode_i_64(deserializer);
+var var_intermediates = sse_decode_u_32(deserializer);
+return TlsFailure(kind: var_kind, host: var_host, message: var_message, names: var_names, subject: var_subject, issuer: var_issuer, notBefore: var_notBefore, notAfter: var_notAfter, intermediates: var_intermediates); }
+
+@protected TlsFailureKind sse_decode_tls_failure_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TlsFailureKind.values[inner]; }
+
+@protected TlsPolicy sse_decode_tls_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TlsPolicy.values[inner]; }
+
+@protected TokenKind sse_decode_token_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TokenKind.values[inner]; }
+
+@protected TokenSpan sse_decode_token_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_kind = sse_decode_token_kind(deserializer);
+return TokenSpan(text: var_text, kind: var_kind); }
+
+@protected TopSite sse_decode_top_site(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_favicon = sse_decode_opt_String(deserializer);
+var var_visitCount = sse_decode_u_32(deserializer);
+var var_lastVisited = sse_decode_i_64(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+return TopSite(url: var_url, host: var_host, title: var_title, favicon: var_favicon, visitCount: var_visitCount, lastVisited: var_lastVisited, score: var_score); }
+
+@protected TraceEvent sse_decode_trace_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stage = sse_decode_trace_stage(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+return TraceEvent(stage: var_stage, message: var_message, elapsedMs: var_elapsedMs); }
+
+@protected TraceStage sse_decode_trace_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TraceStage.values[inner]; }
+
+@protected TrustedCa sse_decode_trusted_ca(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fingerprint = sse_decode_String(deserializer);
+var var_subject = sse_decode_String(deserializer);
+var var_notAfter = sse_decode_i_64(deserializer);
+var var_addedAt = sse_decode_i_64(deserializer);
+return TrustedCa(fingerprint: var_fingerprint, subject: var_subject, notAfter: var_notAfter, addedAt: var_addedAt); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected WatchedPage sse_decode_watched_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_addedAt = sse_decode_i_64(deserializer);
+var var_seenAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_checkedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_changedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return WatchedPage(id: var_id, url: var_url, title: var_title, addedAt: var_addedAt, seenAt: var_seenAt, checkedAt: var_checkedAt, changedAt: var_changedAt, lastError: var_lastError); }
+
+@protected WatchedPlan sse_decode_watched_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_addedAt = sse_decode_i_64(deserializer);
+var var_plan = sse_decode_opt_String(deserializer);
+var var_checkedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_updatedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return WatchedPlan(id: var_id, url: var_url, addedAt: var_addedAt, plan: var_plan, checkedAt: var_checkedAt, updatedAt: var_updatedAt, lastError: var_lastError); }
+
+@protected void sse_encode_Char(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self, serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_added_bookmark(AddedBookmark self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bookmark(self.bookmark, serializer);
+sse_encode_bool(self.duplicate, serializer);
+ }
+
+@protected void sse_encode_ansi_color(AnsiColor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AnsiColor_Indexed(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_u_8(field0, serializer);
+case AnsiColor_Rgb(r: final r,g: final g,b: final b): sse_encode_i_32(1, serializer); sse_encode_u_8(r, serializer);
+sse_encode_u_8(g, serializer);
+sse_encode_u_8(b, serializer);
+  } }
+
+@protected void sse_encode_ansi_style(AnsiStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_ansi_color(self.foreground, serializer);
+sse_encode_opt_box_autoadd_ansi_color(self.background, serializer);
+sse_encode_bool(self.bold, serializer);
+sse_encode_bool(self.dim, serializer);
+sse_encode_bool(self.italic, serializer);
+sse_encode_bool(self.underline, serializer);
+sse_encode_bool(self.inverse, serializer);
+ }
+
+@protected void sse_encode_archived_page(ArchivedPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.bodyHash, serializer);
+sse_encode_opt_String(self.certificateFingerprint, serializer);
+sse_encode_i_64(self.fetchedAt, serializer);
+sse_encode_i_64(self.archivedAt, serializer);
+ }
+
+@protected void sse_encode_batch_item(BatchItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_u_64(self.requestId, serializer);
+sse_encode_opt_box_autoadd_navigation_result(self.result, serializer);
+sse_encode_opt_box_autoadd_error_info(self.error, serializer);
+ }
+
+@protected void sse_encode_block_rule(BlockRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.pattern, serializer);
+sse_encode_block_rule_kind(self.kind, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_block_rule_kind(BlockRuleKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bookmark(Bookmark self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_opt_String(self.favicon, serializer);
+ }
+
+@protected void sse_encode_bookmark_format(BookmarkFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bookmark_source(BookmarkSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bookmarks_import(BookmarksImport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.imported, serializer);
+sse_encode_u_32(self.skipped, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_ansi_color(AnsiColor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ansi_color(self, serializer); }
+
+@protected void sse_encode_box_autoadd_batch_item(BatchItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_batch_item(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bookmark(Bookmark self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bookmark(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cache_policy(CachePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cache_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_certificate_error(CertificateError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_certificate_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_certificate_summary(CertificateSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_certificate_summary(self, serializer); }
+
+@protected void sse_encode_box_autoadd_config(Config self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_decompression_limits(DecompressionLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_decompression_limits(self, serializer); }
+
+@protected void sse_encode_box_autoadd_download_status(DownloadStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_download_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_error_info(ErrorInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_error_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fetch_options(FetchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fetch_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_file_type(FileType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_gemtext_line(GemtextLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_gemtext_line(self, serializer); }
+
+@protected void sse_encode_box_autoadd_history_range(HistoryRange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_history_range(self, serializer); }
+
+@protected void sse_encode_box_autoadd_host_settings(HostSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_host_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_identity_scope(IdentityScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_identity_scope(self, serializer); }
+
+@protected void sse_encode_box_autoadd_mirror_progress(MirrorProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mirror_progress(self, serializer); }
+
+@protected void sse_encode_box_autoadd_navigate_error(NavigateError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_navigate_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_navigation_event(NavigationEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_navigation_event(self, serializer); }
+
+@protected void sse_encode_box_autoadd_navigation_result(NavigationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_navigation_result(self, serializer); }
+
+@protected void sse_encode_box_autoadd_proxy_settings(ProxySettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_proxy_settings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rate_limits(RateLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rate_limits(self, serializer); }
+
+@protected void sse_encode_box_autoadd_recovery_action(RecoveryAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_recovery_action(self, serializer); }
+
+@protected void sse_encode_box_autoadd_redirect_policy(RedirectPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_redirect_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_renderer(Renderer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_renderer(self, serializer); }
+
+@protected void sse_encode_box_autoadd_server_failure(ServerFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_server_failure(self, serializer); }
+
+@protected void sse_encode_box_autoadd_session(Session self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_socks_5_proxy(Socks5Proxy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_socks_5_proxy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stream_options(StreamOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stream_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_task_schedule(TaskSchedule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_task_schedule(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tls_failure(TlsFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tls_failure(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tls_policy(TlsPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tls_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_cache_entry(CacheEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_i_64(self.fetchedAt, serializer);
+sse_encode_i_64(self.expiresAt, serializer);
+sse_encode_bool(self.stale, serializer);
+ }
+
+@protected void sse_encode_cache_policy(CachePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.scheme, serializer);
+sse_encode_bool(self.cacheable, serializer);
+sse_encode_u_32(self.ttlSecs, serializer);
+sse_encode_bool(self.cacheQueries, serializer);
+ }
+
+@protected void sse_encode_certificate_error(CertificateError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_certificate_error_kind(self.kind, serializer);
+sse_encode_opt_String(self.identityId, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_recovery_action(self.suggestedAction, serializer);
+ }
+
+@protected void sse_encode_certificate_error_kind(CertificateErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_certificate_summary(CertificateSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fingerprint, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.issuer, serializer);
+sse_encode_i_64(self.notBefore, serializer);
+sse_encode_i_64(self.notAfter, serializer);
+sse_encode_i_64(self.firstSeen, serializer);
+sse_encode_pin_status(self.pinStatus, serializer);
+sse_encode_pin_expiry(self.expiry, serializer);
+sse_encode_opt_String(self.tlsVersion, serializer);
+sse_encode_opt_String(self.cipherSuite, serializer);
+ }
+
+@protected void sse_encode_checksum_status(ChecksumStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_cleared_data(ClearedData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_data_category(self.category, serializer);
+sse_encode_u_32(self.removed, serializer);
+ }
+
+@protected void sse_encode_clock_offset(ClockOffset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_64(self.offsetSecs, serializer);
+sse_encode_u_32(self.hosts, serializer);
+sse_encode_bool(self.consistent, serializer);
+ }
+
+@protected void sse_encode_config(Config self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.defaultScheme, serializer);
+sse_encode_bool(self.guessSchemes, serializer);
+sse_encode_String(self.startPage, serializer);
+sse_encode_String(self.searchProvider, serializer);
+sse_encode_u_32(self.connectTimeoutSecs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.readTimeoutSecs, serializer);
+sse_encode_tls_policy(self.tlsPolicy, serializer);
+sse_encode_proxy_settings(self.proxy, serializer);
+ }
+
+@protected void sse_encode_crawl_result(CrawlResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.capsule, serializer);
+sse_encode_u_32(self.indexed, serializer);
+sse_encode_u_32(self.failed, serializer);
+sse_encode_u_32(self.disallowed, serializer);
+sse_encode_u_32(self.remaining, serializer);
+sse_encode_bool(self.cancelled, serializer);
+ }
+
+@protected void sse_encode_cso_field(CsoField self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_cso_record(CsoRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_list_cso_field(self.fields, serializer);
+ }
+
+@protected void sse_encode_daily_usage(DailyUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.dayStart, serializer);
+sse_encode_network_type(self.network, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+ }
+
+@protected void sse_encode_data_cap_status(DataCapStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.limitBytes, serializer);
+sse_encode_u_64(self.usedBytes, serializer);
+sse_encode_bool(self.exceeded, serializer);
+ }
+
+@protected void sse_encode_data_category(DataCategory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_decompression_limits(DecompressionLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxRatio, serializer);
+sse_encode_u_64(self.maxBytes, serializer);
+ }
+
+@protected void sse_encode_diff_line(DiffLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.line, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_display_host(DisplayHost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_bool(self.spoofingWarning, serializer);
+ }
+
+@protected void sse_encode_download_record(DownloadRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_download_status(self.status, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_i_64(self.finishedAt, serializer);
+sse_encode_opt_String(self.checksumUrl, serializer);
+sse_encode_checksum_status(self.checksum, serializer);
+ }
+
+@protected void sse_encode_download_result(DownloadResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_String(self.sha256, serializer);
+sse_encode_checksum_status(self.checksum, serializer);
+ }
+
+@protected void sse_encode_download_status(DownloadStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_error_code(ErrorCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_error_info(ErrorInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_error_code(self.code, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.number, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_bool(self.retryable, serializer);
+sse_encode_bool(self.userActionable, serializer);
+sse_encode_opt_box_autoadd_recovery_action(self.suggestedAction, serializer);
+ }
+
+@protected void sse_encode_expiry_reminder(ExpiryReminder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_identity(self.identity, serializer);
+sse_encode_i_64(self.daysLeft, serializer);
+sse_encode_bool(self.expired, serializer);
+ }
+
+@protected void sse_encode_explore_source(ExploreSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.directoryUrl, serializer);
+sse_encode_f_64(self.weight, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_feed(Feed self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_feed_kind(self.kind, serializer);
+sse_encode_i_64(self.subscribedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.refreshedAt, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+sse_encode_u_32(self.unread, serializer);
+ }
+
+@protected void sse_encode_feed_candidate(FeedCandidate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_feed_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_feed_entry(FeedEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_i_64(self.feedId, serializer);
+sse_encode_opt_String(self.feedTitle, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_opt_box_autoadd_i_64(self.publishedAt, serializer);
+sse_encode_i_64(self.seenAt, serializer);
+sse_encode_bool(self.read, serializer);
+ }
+
+@protected void sse_encode_feed_kind(FeedKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_fetch_options(FetchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.private, serializer);
+sse_encode_bool(self.useCache, serializer);
+sse_encode_bool(self.revalidate, serializer);
+sse_encode_bool(self.probeProtocols, serializer);
+sse_encode_bool(self.background, serializer);
+sse_encode_opt_String(self.identityId, serializer);
+sse_encode_opt_box_autoadd_u_32(self.connectTimeoutSecs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.readTimeoutSecs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.deadlineSecs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxBodyBytes, serializer);
+ }
+
+@protected void sse_encode_fetch_response(FetchResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_list_String(self.redirects, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_opt_String(self.meta, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_renderer(self.renderer, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_String(self.bodyHash, serializer);
+sse_encode_bool(self.unchanged, serializer);
+sse_encode_bool(self.truncated, serializer);
+sse_encode_bool(self.stale, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_list_feed_candidate(self.feeds, serializer);
+sse_encode_opt_String(self.favicon, serializer);
+sse_encode_opt_String(self.fragment, serializer);
+sse_encode_opt_box_autoadd_u_32(self.fragmentLine, serializer);
+sse_encode_opt_box_autoadd_i_64(self.cachedAt, serializer);
+sse_encode_u_64(self.requestId, serializer);
+sse_encode_String(self.displayHost, serializer);
+sse_encode_bool(self.spoofingWarning, serializer);
+sse_encode_bool(self.clockSkewWarning, serializer);
+sse_encode_opt_String(self.probedScheme, serializer);
+sse_encode_opt_box_autoadd_certificate_error(self.certificateError, serializer);
+sse_encode_opt_box_autoadd_server_failure(self.serverFailure, serializer);
+sse_encode_opt_box_autoadd_certificate_summary(self.certificate, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_request_timings(self.timings, serializer);
+ }
+
+@protected void sse_encode_file_type(FileType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.mime, serializer);
+sse_encode_String(self.extension_, serializer);
+ }
+
+@protected void sse_encode_gempub_book(GempubBook self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.handle, serializer);
+sse_encode_gempub_metadata(self.metadata, serializer);
+sse_encode_opt_String(self.indexPath, serializer);
+sse_encode_list_gempub_chapter(self.chapters, serializer);
+ }
+
+@protected void sse_encode_gempub_chapter(GempubChapter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.path, serializer);
+ }
+
+@protected void sse_encode_gempub_metadata(GempubMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_String(self.published, serializer);
+sse_encode_opt_String(self.version, serializer);
+sse_encode_opt_String(self.cover, serializer);
+ }
+
+@protected void sse_encode_gempub_page(GempubPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_opt_box_autoadd_u_32(self.chapter, serializer);
+sse_encode_opt_box_autoadd_u_32(self.previousChapter, serializer);
+sse_encode_opt_box_autoadd_u_32(self.nextChapter, serializer);
+ }
+
+@protected void sse_encode_gemtext_line(GemtextLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case GemtextLine_Text(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case GemtextLine_Link(url: final url,label: final label): sse_encode_i_32(1, serializer); sse_encode_String(url, serializer);
+sse_encode_opt_String(label, serializer);
+case GemtextLine_Heading(level: final level,text: final text): sse_encode_i_32(2, serializer); sse_encode_u_8(level, serializer);
+sse_encode_String(text, serializer);
+case GemtextLine_ListItem(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case GemtextLine_Quote(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case GemtextLine_PreformatToggle(alt: final alt): sse_encode_i_32(5, serializer); sse_encode_opt_String(alt, serializer);
+case GemtextLine_Preformatted(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_gopher_item(GopherItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Char(self.itemType, serializer);
+sse_encode_String(self.display, serializer);
+sse_encode_String(self.selector, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.icon, serializer);
+sse_encode_bool(self.fetchable, serializer);
+ }
+
+@protected void sse_encode_heading_anchor(HeadingAnchor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_u_8(self.level, serializer);
+sse_encode_u_32(self.lineIndex, serializer);
+ }
+
+@protected void sse_encode_highlighted_block(HighlightedBlock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.startIndex, serializer);
+sse_encode_String(self.language, serializer);
+sse_encode_list_highlighted_line(self.lines, serializer);
+ }
+
+@protected void sse_encode_highlighted_line(HighlightedLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_token_span(self.spans, serializer);
+ }
+
+@protected void sse_encode_history_entry(HistoryEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_i_64(self.visitedAt, serializer);
+ }
+
+@protected void sse_encode_history_match(HistoryMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_history_range(HistoryRange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_64(self.since, serializer);
+sse_encode_opt_box_autoadd_i_64(self.until, serializer);
+ }
+
+@protected void sse_encode_history_result(HistoryResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_u_32(self.visitCount, serializer);
+sse_encode_i_64(self.lastVisited, serializer);
+ }
+
+@protected void sse_encode_host_migration(HostMigration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.scheme, serializer);
+sse_encode_String(self.fromHost, serializer);
+sse_encode_String(self.toHost, serializer);
+sse_encode_u_32(self.movedPages, serializer);
+sse_encode_u_32(self.bookmarks, serializer);
+ }
+
+@protected void sse_encode_host_migration_result(HostMigrationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.bookmarks, serializer);
+ }
+
+@protected void sse_encode_host_settings(HostSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_opt_box_autoadd_tls_policy(self.tlsPolicy, serializer);
+sse_encode_opt_String(self.identityId, serializer);
+sse_encode_opt_box_autoadd_u_32(self.connectTimeoutSecs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.readTimeoutSecs, serializer);
+sse_encode_opt_box_autoadd_redirect_policy(self.redirectPolicy, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxBodyBytes, serializer);
+sse_encode_opt_String(self.charset, serializer);
+ }
+
+@protected void sse_encode_host_stats(HostStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_u_32(self.requests, serializer);
+sse_encode_u_32(self.errors, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_u_32(self.averageLatencyMs, serializer);
+sse_encode_f_64(self.errorRate, serializer);
+sse_encode_i_64(self.firstRequestAt, serializer);
+sse_encode_i_64(self.lastRequestAt, serializer);
+ }
+
+@protected void sse_encode_host_stats_order(HostStatsOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_identities_import(IdentitiesImport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.imported, serializer);
+sse_encode_u_32(self.skipped, serializer);
+ }
+
+@protected void sse_encode_identity(Identity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.commonName, serializer);
+sse_encode_String(self.fingerprint, serializer);
+sse_encode_i_64(self.notBefore, serializer);
+sse_encode_i_64(self.notAfter, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_identity_scope(IdentityScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.prefix, serializer);
+sse_encode_String(self.identityId, serializer);
+sse_encode_i_64(self.activatedAt, serializer);
+ }
+
+@protected void sse_encode_inline_image(InlineImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.linkIndex, serializer);
+sse_encode_u_32(self.lineIndex, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+ }
+
+@protected void sse_encode_inline_image_failure(InlineImageFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.linkIndex, serializer);
+sse_encode_u_32(self.lineIndex, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_inline_images(InlineImages self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_inline_image(self.images, serializer);
+sse_encode_list_inline_image_failure(self.failed, serializer);
+ }
+
+@protected void sse_encode_known_host(KnownHost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_String(self.fingerprint, serializer);
+sse_encode_opt_box_autoadd_i_64(self.expiresAt, serializer);
+sse_encode_i_64(self.firstSeen, serializer);
+sse_encode_i_64(self.lastSeen, serializer);
+ }
+
+@protected void sse_encode_known_hosts_format(KnownHostsFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_known_hosts_import(KnownHostsImport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.imported, serializer);
+sse_encode_u_32(self.skipped, serializer);
+ }
+
+@protected void sse_encode_level_run(LevelRun self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_u_8(self.level, serializer);
+sse_encode_text_direction(self.direction, serializer);
+ }
+
+@protected void sse_encode_line_direction(LineDirection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.lineIndex, serializer);
+sse_encode_text_direction(self.direction, serializer);
+sse_encode_list_level_run(self.runs, serializer);
+ }
+
+@protected void sse_encode_link_kind(LinkKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_archived_page(List<ArchivedPage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_archived_page(item, serializer); } }
+
+@protected void sse_encode_list_block_rule(List<BlockRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_block_rule(item, serializer); } }
+
+@protected void sse_encode_list_bookmark(List<Bookmark> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_bookmark(item, serializer); } }
+
+@protected void sse_encode_list_cache_entry(List<CacheEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cache_entry(item, serializer); } }
+
+@protected void sse_encode_list_cache_policy(List<CachePolicy> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cache_policy(item, serializer); } }
+
+@protected void sse_encode_list_cleared_data(List<ClearedData> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cleared_data(item, serializer); } }
+
+@protected void sse_encode_list_cso_field(List<CsoField> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cso_field(item, serializer); } }
+
+@protected void sse_encode_list_cso_record(List<CsoRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cso_record(item, serializer); } }
+
+@protected void sse_encode_list_daily_usage(List<DailyUsage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_daily_usage(item, serializer); } }
+
+@protected void sse_encode_list_data_category(List<DataCategory> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_data_category(item, serializer); } }
+
+@protected void sse_encode_list_diff_line(List<DiffLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_diff_line(item, serializer); } }
+
+@protected void sse_encode_list_download_record(List<DownloadRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_download_record(item, serializer); } }
+
+@protected void sse_encode_list_expiry_reminder(List<ExpiryReminder> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_expiry_reminder(item, serializer); } }
+
+@protected void sse_encode_list_explore_source(List<ExploreSource> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_explore_source(item, serializer); } }
+
+@protected void sse_encode_list_feed(List<Feed> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feed(item, serializer); } }
+
+@protected void sse_encode_list_feed_candidate(List<FeedCandidate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feed_candidate(item, serializer); } }
+
+@protected void sse_encode_list_feed_entry(List<FeedEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_feed_entry(item, serializer); } }
+
+@protected void sse_encode_list_gempub_chapter(List<GempubChapter> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_gempub_chapter(item, serializer); } }
+
+@protected void sse_encode_list_gemtext_line(List<GemtextLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_gemtext_line(item, serializer); } }
+
+@protected void sse_encode_list_gopher_item(List<GopherItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_gopher_item(item, serializer); } }
+
+@protected void sse_encode_list_highlighted_block(List<HighlightedBlock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_highlighted_block(item, serializer); } }
+
+@protected void sse_encode_list_highlighted_line(List<HighlightedLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_highlighted_line(item, serializer); } }
+
+@protected void sse_encode_list_history_entry(List<HistoryEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_history_entry(item, serializer); } }
+
+@protected void sse_encode_list_history_result(List<HistoryResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_history_result(item, serializer); } }
+
+@protected void sse_encode_list_host_migration(List<HostMigration> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_host_migration(item, serializer); } }
+
+@protected void sse_encode_list_host_settings(List<HostSettings> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_host_settings(item, serializer); } }
+
+@protected void sse_encode_list_host_stats(List<HostStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_host_stats(item, serializer); } }
+
+@protected void sse_encode_list_identity(List<Identity> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_identity(item, serializer); } }
+
+@protected void sse_encode_list_identity_scope(List<IdentityScope> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_identity_scope(item, serializer); } }
+
+@protected void sse_encode_list_inline_image(List<InlineImage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_inline_image(item, serializer); } }
+
+@protected void sse_encode_list_inline_image_failure(List<InlineImageFailure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_inline_image_failure(item, serializer); } }
+
+@protected void sse_encode_list_known_host(List<KnownHost> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_known_host(item, serializer); } }
+
+@protected void sse_encode_list_level_run(List<LevelRun> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_level_run(item, serializer); } }
+
+@protected void sse_encode_list_line_direction(List<LineDirection> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_line_direction(item, serializer); } }
+
+@protected void sse_encode_list_local_search_result(List<LocalSearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_local_search_result(item, serializer); } }
+
+@protected void sse_encode_list_log_record(List<LogRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_log_record(item, serializer); } }
+
+@protected void sse_encode_list_outline_entry(List<OutlineEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_outline_entry(item, serializer); } }
+
+@protected void sse_encode_list_page_link(List<PageLink> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_page_link(item, serializer); } }
+
+@protected void sse_encode_list_port_override(List<PortOverride> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_port_override(item, serializer); } }
+
+@protected void sse_encode_list_preformatted_block(List<PreformattedBlock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_preformatted_block(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_protocol_info(List<ProtocolInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_protocol_info(item, serializer); } }
+
+@protected void sse_encode_list_provider_failure(List<ProviderFailure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_provider_failure(item, serializer); } }
+
+@protected void sse_encode_list_proxy_rule(List<ProxyRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_proxy_rule(item, serializer); } }
+
+@protected void sse_encode_list_read_later_item(List<ReadLaterItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_read_later_item(item, serializer); } }
+
+@protected void sse_encode_list_reflowed_line(List<ReflowedLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_reflowed_line(item, serializer); } }
+
+@protected void sse_encode_list_saved_answer(List<SavedAnswer> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_saved_answer(item, serializer); } }
+
+@protected void sse_encode_list_scheduler_event(List<SchedulerEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_scheduler_event(item, serializer); } }
+
+@protected void sse_encode_list_scheme_proxy(List<SchemeProxy> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_scheme_proxy(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_search_provider(List<SearchProvider> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_provider(item, serializer); } }
+
+@protected void sse_encode_list_session_entry(List<SessionEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_entry(item, serializer); } }
+
+@protected void sse_encode_list_session_summary(List<SessionSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_summary(item, serializer); } }
+
+@protected void sse_encode_list_session_tab(List<SessionTab> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_tab(item, serializer); } }
+
+@protected void sse_encode_list_site_node(List<SiteNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_site_node(item, serializer); } }
+
+@protected void sse_encode_list_snippet_part(List<SnippetPart> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snippet_part(item, serializer); } }
+
+@protected void sse_encode_list_styled_span(List<StyledSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_styled_span(item, serializer); } }
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_suggestion(item, serializer); } }
+
+@protected void sse_encode_list_task_schedule(List<TaskSchedule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_task_schedule(item, serializer); } }
+
+@protected void sse_encode_list_token_span(List<TokenSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_token_span(item, serializer); } }
+
+@protected void sse_encode_list_top_site(List<TopSite> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_top_site(item, serializer); } }
+
+@protected void sse_encode_list_trace_event(List<TraceEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_trace_event(item, serializer); } }
+
+@protected void sse_encode_list_trusted_ca(List<TrustedCa> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_trusted_ca(item, serializer); } }
+
+@protected void sse_encode_list_watched_page(List<WatchedPage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_watched_page(item, serializer); } }
+
+@protected void sse_encode_list_watched_plan(List<WatchedPlan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_watched_plan(item, serializer); } }
+
+@protected void sse_encode_local_search_result(LocalSearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_list_snippet_part(self.snippet, serializer);
+sse_encode_i_64(self.fetchedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastVisitedAt, serializer);
+ }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.timestampMs, serializer);
+sse_encode_log_level(self.level, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_box_autoadd_u_64(self.requestId, serializer);
+ }
+
+@protected void sse_encode_menu_page(MenuPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.offset, serializer);
+sse_encode_list_gopher_item(self.items, serializer);
+sse_encode_u_32(self.total, serializer);
+ }
+
+@protected void sse_encode_metasearch_result(MetasearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_list_provider_failure(self.failed, serializer);
+ }
+
+@protected void sse_encode_mirror_progress(MirrorProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.path, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_u_32(self.saved, serializer);
+sse_encode_u_32(self.failed, serializer);
+sse_encode_u_32(self.remaining, serializer);
+ }
+
+@protected void sse_encode_navigate_error(NavigateError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case NavigateError_InvalidUrl(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case NavigateError_UnsupportedScheme(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case NavigateError_SchemeDisabled(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case NavigateError_Blocked(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case NavigateError_Disallowed(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case NavigateError_Offline(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+case NavigateError_DnsFailure(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ConnectTimeout(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ConnectionRefused(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ConnectionFailed(field0: final field0): sse_encode_i_32(9, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ConnectionLost(field0: final field0): sse_encode_i_32(10, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ReadTimeout(field0: final field0): sse_encode_i_32(11, serializer); sse_encode_String(field0, serializer);
+case NavigateError_GeminiTimeout(field0: final field0): sse_encode_i_32(12, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ProxyUnreachable(field0: final field0): sse_encode_i_32(13, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ProxyAuthFailed(field0: final field0): sse_encode_i_32(14, serializer); sse_encode_String(field0, serializer);
+case NavigateError_ProxyError(field0: final field0): sse_encode_i_32(15, serializer); sse_encode_String(field0, serializer);
+case NavigateError_GatewayError(field0: final field0): sse_encode_i_32(16, serializer); sse_encode_String(field0, serializer);
+case NavigateError_TlsError(field0: final field0): sse_encode_i_32(17, serializer); sse_encode_String(field0, serializer);
+case NavigateError_FingerprintMismatch(field0: final field0): sse_encode_i_32(18, serializer); sse_encode_String(field0, serializer);
+case NavigateError_CertificateDate(field0: final field0): sse_encode_i_32(19, serializer); sse_encode_String(field0, serializer);
+case NavigateError_CertificateRejected(field0: final field0): sse_encode_i_32(20, serializer); sse_encode_String(field0, serializer);
+case NavigateError_BadResponse(field0: final field0): sse_encode_i_32(21, serializer); sse_encode_String(field0, serializer);
+case NavigateError_TooLarge(field0: final field0): sse_encode_i_32(22, serializer); sse_encode_String(field0, serializer);
+case NavigateError_NotFound(field0: final field0): sse_encode_i_32(23, serializer); sse_encode_String(field0, serializer);
+case NavigateError_InvalidInput(field0: final field0): sse_encode_i_32(24, serializer); sse_encode_String(field0, serializer);
+case NavigateError_GopherBadType(field0: final field0): sse_encode_i_32(25, serializer); sse_encode_String(field0, serializer);
+case NavigateError_GopherBadSelector(field0: final field0): sse_encode_i_32(26, serializer); sse_encode_String(field0, serializer);
+case NavigateError_StorageError(field0: final field0): sse_encode_i_32(27, serializer); sse_encode_String(field0, serializer);
+case NavigateError_IdentityError(field0: final field0): sse_encode_i_32(28, serializer); sse_encode_String(field0, serializer);
+case NavigateError_Cancelled(field0: final field0): sse_encode_i_32(29, serializer); sse_encode_String(field0, serializer);
+case NavigateError_Redirected(target: final target,permanent: final permanent): sse_encode_i_32(30, serializer); sse_encode_String(target, serializer);
+sse_encode_bool(permanent, serializer);
+case NavigateError_InputRequired(prompt: final prompt,sensitive: final sensitive): sse_encode_i_32(31, serializer); sse_encode_String(prompt, serializer);
+sse_encode_bool(sensitive, serializer);
+case NavigateError_CertificateRequired(field0: final field0): sse_encode_i_32(32, serializer); sse_encode_box_autoadd_certificate_error(field0, serializer);
+case NavigateError_ServerError(field0: final field0): sse_encode_i_32(33, serializer); sse_encode_box_autoadd_server_failure(field0, serializer);
+case NavigateError_Other(field0: final field0): sse_encode_i_32(34, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_navigation_event(NavigationEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.requestId, serializer);
+sse_encode_navigation_event_kind(self.kind, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_opt_String(self.meta, serializer);
+sse_encode_u_64(self.bytes, serializer);
+sse_encode_opt_String(self.message, serializer);
+ }
+
+@protected void sse_encode_navigation_event_kind(NavigationEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_navigation_result(NavigationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_list_String(self.redirects, serializer);
+sse_encode_String(self.protocol, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_String(self.mime, serializer);
+sse_encode_opt_String(self.charset, serializer);
+sse_encode_renderer(self.renderer, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.favicon, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_bool(self.truncated, serializer);
+sse_encode_opt_box_autoadd_i_64(self.cachedAt, serializer);
+sse_encode_bool(self.stale, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_request_timings(self.timings, serializer);
+sse_encode_opt_box_autoadd_certificate_summary(self.certificate, serializer);
+sse_encode_opt_box_autoadd_server_failure(self.serverFailure, serializer);
+sse_encode_u_64(self.requestId, serializer);
+ }
+
+@protected void sse_encode_network_type(NetworkType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ansi_color(AnsiColor? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ansi_color(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_batch_item(BatchItem? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_batch_item(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bookmark(Bookmark? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bookmark(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_certificate_error(CertificateError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_certificate_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_certificate_summary(CertificateSummary? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_certificate_summary(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_download_status(DownloadStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_download_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_error_info(ErrorInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_error_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_file_type(FileType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_file_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_gemtext_line(GemtextLine? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_gemtext_line(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_host_settings(HostSettings? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_host_settings(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_identity_scope(IdentityScope? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_identity_scope(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_mirror_progress(MirrorProgress? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_mirror_progress(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_navigation_event(NavigationEvent? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_navigation_event(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_navigation_result(NavigationResult? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_navigation_result(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_recovery_action(RecoveryAction? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_recovery_action(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_redirect_policy(RedirectPolicy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_redirect_policy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_renderer(Renderer? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_renderer(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_server_failure(ServerFailure? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_server_failure(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_session(Session? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_session(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_socks_5_proxy(Socks5Proxy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_socks_5_proxy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_tls_failure(TlsFailure? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_tls_failure(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_tls_policy(TlsPolicy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_tls_policy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_highlighted_line(List<HighlightedLine>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_highlighted_line(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_trace_event(List<TraceEvent>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_trace_event(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_outline_entry(OutlineEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_heading_anchor(self.anchor, serializer);
+sse_encode_u_8(self.depth, serializer);
+sse_encode_opt_box_autoadd_u_32(self.parent, serializer);
+ }
+
+@protected void sse_encode_page_diff(PageDiff self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_i_64(self.cachedAt, serializer);
+sse_encode_list_diff_line(self.added, serializer);
+sse_encode_list_diff_line(self.removed, serializer);
+ }
+
+@protected void sse_encode_page_link(PageLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.label, serializer);
+sse_encode_link_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_page_summary(PageSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_String(self.favicon, serializer);
+sse_encode_opt_box_autoadd_i_64(self.cachedAt, serializer);
+ }
+
+@protected void sse_encode_peek_result(PeekResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_opt_String(self.meta, serializer);
+sse_encode_opt_String(self.mime, serializer);
+sse_encode_opt_box_autoadd_renderer(self.renderer, serializer);
+sse_encode_opt_box_autoadd_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_pin_expiry(PinExpiry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pin_status(PinStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_port_override(PortOverride self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.scheme, serializer);
+sse_encode_opt_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+ }
+
+@protected void sse_encode_power_state(PowerState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_preformatted_block(PreformattedBlock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.startIndex, serializer);
+sse_encode_u_32(self.lineCount, serializer);
+sse_encode_opt_String(self.alt, serializer);
+sse_encode_u_32(self.maxWidth, serializer);
+sse_encode_bool(self.hasWideChars, serializer);
+ }
+
+@protected void sse_encode_protocol_capabilities(ProtocolCapabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.userInput, serializer);
+sse_encode_bool(self.redirects, serializer);
+sse_encode_bool(self.clientCertificates, serializer);
+sse_encode_bool(self.menus, serializer);
+ }
+
+@protected void sse_encode_protocol_info(ProtocolInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.scheme, serializer);
+sse_encode_u_16(self.defaultPort, serializer);
+sse_encode_bool(self.requiresTls, serializer);
+sse_encode_protocol_capabilities(self.capabilities, serializer);
+sse_encode_bool(self.enabled, serializer);
+ }
+
+@protected void sse_encode_provider_failure(ProviderFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_proxy_probe(ProxyProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.handshakeMs, serializer);
+sse_encode_u_32(self.connectMs, serializer);
+ }
+
+@protected void sse_encode_proxy_protocol(ProxyProtocol self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_proxy_rule(ProxyRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.pattern, serializer);
+sse_encode_opt_box_autoadd_socks_5_proxy(self.proxy, serializer);
+ }
+
+@protected void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ProxySettings_Direct(): sse_encode_i_32(0, serializer); case ProxySettings_Socks5(proxy: final proxy): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_socks_5_proxy(proxy, serializer);
+case ProxySettings_PerRule(rules: final rules,fallback: final fallback): sse_encode_i_32(2, serializer); sse_encode_list_proxy_rule(rules, serializer);
+sse_encode_opt_box_autoadd_socks_5_proxy(fallback, serializer);
+  } }
+
+@protected void sse_encode_random_destination(RandomDestination self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.label, serializer);
+sse_encode_opt_String(self.directoryUrl, serializer);
+ }
+
+@protected void sse_encode_rate_limits(RateLimits self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxConnections, serializer);
+sse_encode_u_64(self.minIntervalMs, serializer);
+ }
+
+@protected void sse_encode_read_later_item(ReadLaterItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_i_64(self.addedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.readAt, serializer);
+sse_encode_bool(self.cached, serializer);
+sse_encode_opt_box_autoadd_i_64(self.fetchedAt, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_reading_stats(ReadingStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.words, serializer);
+sse_encode_u_32(self.preformattedWords, serializer);
+sse_encode_u_32(self.readingMinutes, serializer);
+sse_encode_u_32(self.links, serializer);
+sse_encode_u_32(self.headings, serializer);
+ }
+
+@protected void sse_encode_recovery_action(RecoveryAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_redirect_policy(RedirectPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_reflowed_line(ReflowedLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.sourceIndex, serializer);
+sse_encode_gemtext_line(self.line, serializer);
+sse_encode_bool(self.continuation, serializer);
+ }
+
+@protected void sse_encode_renderer(Renderer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_request_timings(RequestTimings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.dnsMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.connectMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.tlsMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.firstByteMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.transferMs, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+ }
+
+@protected void sse_encode_saved_answer(SavedAnswer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.prompt, serializer);
+sse_encode_String(self.answer, serializer);
+sse_encode_i_64(self.answeredAt, serializer);
+sse_encode_u_32(self.uses, serializer);
+ }
+
+@protected void sse_encode_scheduled_task(ScheduledTask self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_scheduler_event(SchedulerEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scheduled_task(self.task, serializer);
+sse_encode_scheduler_event_kind(self.kind, serializer);
+sse_encode_i_64(self.timestamp, serializer);
+sse_encode_u_32(self.count, serializer);
+sse_encode_list_String(self.items, serializer);
+sse_encode_opt_String(self.message, serializer);
+sse_encode_opt_box_autoadd_i_64(self.nextRunAt, serializer);
+ }
+
+@protected void sse_encode_scheduler_event_kind(SchedulerEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_scheme_proxy(SchemeProxy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.scheme, serializer);
+sse_encode_proxy_protocol(self.protocol, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_String(self.selector, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_opt_String(self.snippet, serializer);
+sse_encode_list_String(self.providers, serializer);
+sse_encode_f_64(self.score, serializer);
+ }
+
+@protected void sse_encode_search_provider(SearchProvider self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.urlTemplate, serializer);
+ }
+
+@protected void sse_encode_server_failure(ServerFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.status, serializer);
+sse_encode_server_failure_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_bool(self.temporary, serializer);
+sse_encode_opt_box_autoadd_u_32(self.retryAfterSecs, serializer);
+sse_encode_opt_box_autoadd_recovery_action(self.suggestedAction, serializer);
+ }
+
+@protected void sse_encode_server_failure_kind(ServerFailureKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_session(Session self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_list_session_tab(self.tabs, serializer);
+sse_encode_u_32(self.activeTab, serializer);
+sse_encode_i_64(self.savedAt, serializer);
+ }
+
+@protected void sse_encode_session_entry(SessionEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_f_64(self.scrollOffset, serializer);
+ }
+
+@protected void sse_encode_session_summary(SessionSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.tabCount, serializer);
+sse_encode_i_64(self.savedAt, serializer);
+ }
+
+@protected void sse_encode_session_tab(SessionTab self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_session_entry(self.entries, serializer);
+sse_encode_u_32(self.currentEntry, serializer);
+sse_encode_bool(self.private, serializer);
+ }
+
+@protected void sse_encode_shutdown_summary(ShutdownSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.streamsCancelled, serializer);
+sse_encode_u_32(self.fetchesAborted, serializer);
+sse_encode_u_32(self.requestsCancelled, serializer);
+sse_encode_u_32(self.mirrorsCancelled, serializer);
+ }
+
+@protected void sse_encode_site_map(SiteMap self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.root, serializer);
+sse_encode_list_site_node(self.nodes, serializer);
+sse_encode_bool(self.truncated, serializer);
+ }
+
+@protected void sse_encode_site_node(SiteNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_link_kind(self.kind, serializer);
+sse_encode_String(self.icon, serializer);
+sse_encode_bool(self.menu, serializer);
+sse_encode_u_32(self.depth, serializer);
+sse_encode_opt_box_autoadd_u_32(self.parent, serializer);
+sse_encode_bool(self.expanded, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_snippet_part(SnippetPart self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_bool(self.matched, serializer);
+ }
+
+@protected void sse_encode_socks_5_proxy(Socks5Proxy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.username, serializer);
+sse_encode_opt_String(self.password, serializer);
+ }
+
+@protected void sse_encode_stream_event(StreamEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stream_event_kind(self.kind, serializer);
+sse_encode_opt_box_autoadd_u_8(self.status, serializer);
+sse_encode_opt_String(self.meta, serializer);
+sse_encode_opt_String(self.text, serializer);
+sse_encode_opt_box_autoadd_gemtext_line(self.element, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+ }
+
+@protected void sse_encode_stream_event_kind(StreamEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_stream_options(StreamOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.idleIntervalSecs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxIdleSecs, serializer);
+ }
+
+@protected void sse_encode_styled_span(StyledSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_ansi_style(self.style, serializer);
+ }
+
+@protected void sse_encode_styled_text(StyledText self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_list_styled_span(self.spans, serializer);
+ }
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_bool(self.bookmarked, serializer);
+sse_encode_u_32(self.visitCount, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastVisited, serializer);
+sse_encode_suggestion_match(self.matched, serializer);
+sse_encode_opt_String(self.completion, serializer);
+ }
+
+@protected void sse_encode_suggestion_match(SuggestionMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_task_schedule(TaskSchedule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scheduled_task(self.task, serializer);
+sse_encode_bool(self.enabled, serializer);
+sse_encode_u_32(self.intervalSecs, serializer);
+sse_encode_u_32(self.jitterSecs, serializer);
+sse_encode_bool(self.unmeteredOnly, serializer);
+sse_encode_bool(self.chargingOnly, serializer);
+ }
+
+@protected void sse_encode_text_direction(TextDirection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_tls_failure(TlsFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tls_failure_kind(self.kind, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_list_String(self.names, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_String(self.issuer, serializer);
+sse_encode_i_64(self.notBefore, serializer);
+sse_encode_i_64(self.notAfter, serializer);
+sse_encode_u_32(self.intermediates, serializer);
+ }
+
+@protected void sse_encode_tls_failure_kind(TlsFailureKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_tls_policy(TlsPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_token_kind(TokenKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_token_span(TokenSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_token_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_top_site(TopSite self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.favicon, serializer);
+sse_encode_u_32(self.visitCount, serializer);
+sse_encode_i_64(self.lastVisited, serializer);
+sse_encode_f_64(self.score, serializer);
+ }
+
+@protected void sse_encode_trace_event(TraceEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_trace_stage(self.stage, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+ }
+
+@protected void sse_encode_trace_stage(TraceStage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_trusted_ca(TrustedCa self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fingerprint, serializer);
+sse_encode_String(self.subject, serializer);
+sse_encode_i_64(self.notAfter, serializer);
+sse_encode_i_64(self.addedAt, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_watched_page(WatchedPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_i_64(self.addedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.seenAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.checkedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.changedAt, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_watched_plan(WatchedPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_i_64(self.addedAt, serializer);
+sse_encode_opt_String(self.plan, serializer);
+sse_encode_opt_box_autoadd_i_64(self.checkedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.updatedAt, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+                }
+                
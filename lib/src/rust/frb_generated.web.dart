@@ -3,97 +3,1869 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/exposed_functions.dart';
-import 'api/functions/navigate_internal.dart';
-import 'api/protocols/finger.dart';
-import 'api/protocols/gemini.dart';
-import 'api/protocols/gopher.dart';
+import 'api/mirrors.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'third_party/holloway_core/api/functions/anchors.dart';
+import 'third_party/holloway_core/api/functions/ansi.dart';
+import 'third_party/holloway_core/api/functions/batch.dart';
+import 'third_party/holloway_core/api/functions/bidi.dart';
+import 'third_party/holloway_core/api/functions/bookmark_export.dart';
+import 'third_party/holloway_core/api/functions/bookmark_import.dart';
+import 'third_party/holloway_core/api/functions/clock_skew.dart';
+import 'third_party/holloway_core/api/functions/config.dart';
+import 'third_party/holloway_core/api/functions/content_router.dart';
+import 'third_party/holloway_core/api/functions/crawler.dart';
+import 'third_party/holloway_core/api/functions/data_usage.dart';
+import 'third_party/holloway_core/api/functions/downloads.dart';
+import 'third_party/holloway_core/api/functions/explore.dart';
+import 'third_party/holloway_core/api/functions/feed_discovery.dart';
+import 'third_party/holloway_core/api/functions/file_type.dart';
+import 'third_party/holloway_core/api/functions/gempub.dart';
+import 'third_party/holloway_core/api/functions/highlighting.dart';
+import 'third_party/holloway_core/api/functions/homograph.dart';
+import 'third_party/holloway_core/api/functions/host_migration.dart';
+import 'third_party/holloway_core/api/functions/identities.dart';
+import 'third_party/holloway_core/api/functions/identity_transfer.dart';
+import 'third_party/holloway_core/api/functions/inline_images.dart';
+import 'third_party/holloway_core/api/functions/known_hosts_transfer.dart';
+import 'third_party/holloway_core/api/functions/links.dart';
+import 'third_party/holloway_core/api/functions/logging.dart';
+import 'third_party/holloway_core/api/functions/menu_pages.dart';
+import 'third_party/holloway_core/api/functions/metasearch.dart';
+import 'third_party/holloway_core/api/functions/mirror.dart';
+import 'third_party/holloway_core/api/functions/page_diff.dart';
+import 'third_party/holloway_core/api/functions/page_summary.dart';
+import 'third_party/holloway_core/api/functions/peek.dart';
+import 'third_party/holloway_core/api/functions/reading_stats.dart';
+import 'third_party/holloway_core/api/functions/reflow.dart';
+import 'third_party/holloway_core/api/functions/request_trace.dart';
+import 'third_party/holloway_core/api/functions/scheduler.dart';
+import 'third_party/holloway_core/api/functions/search.dart';
+import 'third_party/holloway_core/api/functions/shutdown.dart';
+import 'third_party/holloway_core/api/functions/site_map.dart';
+import 'third_party/holloway_core/api/functions/streaming.dart';
+import 'third_party/holloway_core/api/functions/suggestions.dart';
+import 'third_party/holloway_core/api/functions/text_width.dart';
+import 'third_party/holloway_core/api/functions/top_sites.dart';
+import 'third_party/holloway_core/api/models/certificate_error.dart';
+import 'third_party/holloway_core/api/models/error_info.dart';
+import 'third_party/holloway_core/api/models/fetch_options.dart';
+import 'third_party/holloway_core/api/models/fetch_response.dart';
+import 'third_party/holloway_core/api/models/navigation_event.dart';
+import 'third_party/holloway_core/api/models/navigation_result.dart';
+import 'third_party/holloway_core/api/models/server_failure.dart';
+import 'third_party/holloway_core/api/models/stream_event.dart';
+import 'third_party/holloway_core/api/models/tls_failure.dart';
+import 'third_party/holloway_core/api/parsers/gophermap.dart';
+import 'third_party/holloway_core/api/protocols/cso.dart';
+import 'third_party/holloway_core/api/protocols/registry.dart';
+import 'third_party/holloway_core/api/storage/archive.dart';
+import 'third_party/holloway_core/api/storage/blocklist.dart';
+import 'third_party/holloway_core/api/storage/bookmarks.dart';
+import 'third_party/holloway_core/api/storage/cache.dart';
+import 'third_party/holloway_core/api/storage/clear.dart';
+import 'third_party/holloway_core/api/storage/data_usage.dart';
+import 'third_party/holloway_core/api/storage/downloads.dart';
+import 'third_party/holloway_core/api/storage/feeds.dart';
+import 'third_party/holloway_core/api/storage/gemini_proxies.dart';
+import 'third_party/holloway_core/api/storage/history.dart';
+import 'third_party/holloway_core/api/storage/host_settings.dart';
+import 'third_party/holloway_core/api/storage/host_stats.dart';
+import 'third_party/holloway_core/api/storage/identities.dart';
+import 'third_party/holloway_core/api/storage/identity_scopes.dart';
+import 'third_party/holloway_core/api/storage/input_answers.dart';
+import 'third_party/holloway_core/api/storage/known_hosts.dart';
+import 'third_party/holloway_core/api/storage/page_index.dart';
+import 'third_party/holloway_core/api/storage/plan_watches.dart';
+import 'third_party/holloway_core/api/storage/ports.dart';
+import 'third_party/holloway_core/api/storage/read_later.dart';
+import 'third_party/holloway_core/api/storage/sessions.dart';
+import 'third_party/holloway_core/api/storage/trusted_cas.dart';
+import 'third_party/holloway_core/api/storage/watches.dart';
+import 'third_party/holloway_core/api/transport/limits.dart';
+import 'third_party/holloway_core/api/transport/proxy.dart';
+import 'third_party/holloway_core/api/transport/rate_limit.dart';
+
+
+
+
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected String dco_decode_Char(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AddedBookmark dco_decode_added_bookmark(dynamic raw);
+
+@protected AnsiColor dco_decode_ansi_color(dynamic raw);
+
+@protected AnsiStyle dco_decode_ansi_style(dynamic raw);
+
+@protected ArchivedPage dco_decode_archived_page(dynamic raw);
+
+@protected BatchItem dco_decode_batch_item(dynamic raw);
+
+@protected BlockRule dco_decode_block_rule(dynamic raw);
+
+@protected BlockRuleKind dco_decode_block_rule_kind(dynamic raw);
+
+@protected Bookmark dco_decode_bookmark(dynamic raw);
+
+@protected BookmarkFormat dco_decode_bookmark_format(dynamic raw);
+
+@protected BookmarkSource dco_decode_bookmark_source(dynamic raw);
+
+@protected BookmarksImport dco_decode_bookmarks_import(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected AnsiColor dco_decode_box_autoadd_ansi_color(dynamic raw);
+
+@protected BatchItem dco_decode_box_autoadd_batch_item(dynamic raw);
+
+@protected Bookmark dco_decode_box_autoadd_bookmark(dynamic raw);
+
+@protected CachePolicy dco_decode_box_autoadd_cache_policy(dynamic raw);
+
+@protected CertificateError dco_decode_box_autoadd_certificate_error(dynamic raw);
+
+@protected CertificateSummary dco_decode_box_autoadd_certificate_summary(dynamic raw);
+
+@protected Config dco_decode_box_autoadd_config(dynamic raw);
+
+@protected DecompressionLimits dco_decode_box_autoadd_decompression_limits(dynamic raw);
+
+@protected DownloadStatus dco_decode_box_autoadd_download_status(dynamic raw);
+
+@protected ErrorInfo dco_decode_box_autoadd_error_info(dynamic raw);
+
+@protected FetchOptions dco_decode_box_autoadd_fetch_options(dynamic raw);
+
+@protected FileType dco_decode_box_autoadd_file_type(dynamic raw);
+
+@protected GemtextLine dco_decode_box_autoadd_gemtext_line(dynamic raw);
+
+@protected HistoryRange dco_decode_box_autoadd_history_range(dynamic raw);
+
+@protected HostSettings dco_decode_box_autoadd_host_settings(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected IdentityScope dco_decode_box_autoadd_identity_scope(dynamic raw);
+
+@protected MirrorProgress dco_decode_box_autoadd_mirror_progress(dynamic raw);
+
+@protected NavigateError dco_decode_box_autoadd_navigate_error(dynamic raw);
+
+@protected NavigationEvent dco_decode_box_autoadd_navigation_event(dynamic raw);
+
+@protected NavigationResult dco_decode_box_autoadd_navigation_result(dynamic raw);
+
+@protected ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw);
+
+@protected RateLimits dco_decode_box_autoadd_rate_limits(dynamic raw);
+
+@protected RecoveryAction dco_decode_box_autoadd_recovery_action(dynamic raw);
+
+@protected RedirectPolicy dco_decode_box_autoadd_redirect_policy(dynamic raw);
+
+@protected Renderer dco_decode_box_autoadd_renderer(dynamic raw);
+
+@protected ServerFailure dco_decode_box_autoadd_server_failure(dynamic raw);
+
+@protected Session dco_decode_box_autoadd_session(dynamic raw);
+
+@protected Socks5Proxy dco_decode_box_autoadd_socks_5_proxy(dynamic raw);
+
+@protected StreamOptions dco_decode_box_autoadd_stream_options(dynamic raw);
+
+@protected TaskSchedule dco_decode_box_autoadd_task_schedule(dynamic raw);
+
+@protected TlsFailure dco_decode_box_autoadd_tls_failure(dynamic raw);
+
+@protected TlsPolicy dco_decode_box_autoadd_tls_policy(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
+
+@protected CacheEntry dco_decode_cache_entry(dynamic raw);
+
+@protected CachePolicy dco_decode_cache_policy(dynamic raw);
+
+@protected CertificateError dco_decode_certificate_error(dynamic raw);
+
+@protected CertificateErrorKind dco_decode_certificate_error_kind(dynamic raw);
+
+@protected CertificateSummary dco_decode_certificate_summary(dynamic raw);
+
+@protected ChecksumStatus dco_decode_checksum_status(dynamic raw);
+
+@protected ClearedData dco_decode_cleared_data(dynamic raw);
+
+@protected ClockOffset dco_decode_clock_offset(dynamic raw);
+
+@protected Config dco_decode_config(dynamic raw);
+
+@protected CrawlResult dco_decode_crawl_result(dynamic raw);
+
+@protected CsoField dco_decode_cso_field(dynamic raw);
+
+@protected CsoRecord dco_decode_cso_record(dynamic raw);
+
+@protected DailyUsage dco_decode_daily_usage(dynamic raw);
+
+@protected DataCapStatus dco_decode_data_cap_status(dynamic raw);
+
+@protected DataCategory dco_decode_data_category(dynamic raw);
+
+@protected DecompressionLimits dco_decode_decompression_limits(dynamic raw);
+
+@protected DiffLine dco_decode_diff_line(dynamic raw);
+
+@protected DisplayHost dco_decode_display_host(dynamic raw);
+
+@protected DownloadRecord dco_decode_download_record(dynamic raw);
+
+@protected DownloadResult dco_decode_download_result(dynamic raw);
+
+@protected DownloadStatus dco_decode_download_status(dynamic raw);
+
+@protected ErrorCode dco_decode_error_code(dynamic raw);
+
+@protected ErrorInfo dco_decode_error_info(dynamic raw);
+
+@protected ExpiryReminder dco_decode_expiry_reminder(dynamic raw);
+
+@protected ExploreSource dco_decode_explore_source(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected Feed dco_decode_feed(dynamic raw);
+
+@protected FeedCandidate dco_decode_feed_candidate(dynamic raw);
+
+@protected FeedEntry dco_decode_feed_entry(dynamic raw);
+
+@protected FeedKind dco_decode_feed_kind(dynamic raw);
+
+@protected FetchOptions dco_decode_fetch_options(dynamic raw);
+
+@protected FetchResponse dco_decode_fetch_response(dynamic raw);
+
+@protected FileType dco_decode_file_type(dynamic raw);
+
+@protected GempubBook dco_decode_gempub_book(dynamic raw);
+
+@protected GempubChapter dco_decode_gempub_chapter(dynamic raw);
+
+@protected GempubMetadata dco_decode_gempub_metadata(dynamic raw);
+
+@protected GempubPage dco_decode_gempub_page(dynamic raw);
+
+@protected GemtextLine dco_decode_gemtext_line(dynamic raw);
+
+@protected GopherItem dco_decode_gopher_item(dynamic raw);
+
+@protected HeadingAnchor dco_decode_heading_anchor(dynamic raw);
+
+@protected HighlightedBlock dco_decode_highlighted_block(dynamic raw);
+
+@protected HighlightedLine dco_decode_highlighted_line(dynamic raw);
+
+@protected HistoryEntry dco_decode_history_entry(dynamic raw);
+
+@protected HistoryMatch dco_decode_history_match(dynamic raw);
+
+@protected HistoryRange dco_decode_history_range(dynamic raw);
+
+@protected HistoryResult dco_decode_history_result(dynamic raw);
+
+@protected HostMigration dco_decode_host_migration(dynamic raw);
+
+@protected HostMigrationResult dco_decode_host_migration_result(dynamic raw);
+
+@protected HostSettings dco_decode_host_settings(dynamic raw);
+
+@protected HostStats dco_decode_host_stats(dynamic raw);
+
+@protected HostStatsOrder dco_decode_host_stats_order(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected IdentitiesImport dco_decode_identities_import(dynamic raw);
+
+@protected Identity dco_decode_identity(dynamic raw);
+
+@protected IdentityScope dco_decode_identity_scope(dynamic raw);
+
+@protected InlineImage dco_decode_inline_image(dynamic raw);
+
+@protected InlineImageFailure dco_decode_inline_image_failure(dynamic raw);
+
+@protected InlineImages dco_decode_inline_images(dynamic raw);
+
+@protected KnownHost dco_decode_known_host(dynamic raw);
+
+@protected KnownHostsFormat dco_decode_known_hosts_format(dynamic raw);
+
+@protected KnownHostsImport dco_decode_known_hosts_import(dynamic raw);
+
+@protected LevelRun dco_decode_level_run(dynamic raw);
+
+@protected LineDirection dco_decode_line_direction(dynamic raw);
+
+@protected LinkKind dco_decode_link_kind(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<ArchivedPage> dco_decode_list_archived_page(dynamic raw);
+
+@protected List<BlockRule> dco_decode_list_block_rule(dynamic raw);
+
+@protected List<Bookmark> dco_decode_list_bookmark(dynamic raw);
+
+@protected List<CacheEntry> dco_decode_list_cache_entry(dynamic raw);
+
+@protected List<CachePolicy> dco_decode_list_cache_policy(dynamic raw);
+
+@protected List<ClearedData> dco_decode_list_cleared_data(dynamic raw);
+
+@protected List<CsoField> dco_decode_list_cso_field(dynamic raw);
+
+@protected List<CsoRecord> dco_decode_list_cso_record(dynamic raw);
+
+@protected List<DailyUsage> dco_decode_list_daily_usage(dynamic raw);
+
+@protected List<DataCategory> dco_decode_list_data_category(dynamic raw);
+
+@protected List<DiffLine> dco_decode_list_diff_line(dynamic raw);
+
+@protected List<DownloadRecord> dco_decode_list_download_record(dynamic raw);
+
+@protected List<ExpiryReminder> dco_decode_list_expiry_reminder(dynamic raw);
+
+@protected List<ExploreSource> dco_decode_list_explore_source(dynamic raw);
+
+@protected List<Feed> dco_decode_list_feed(dynamic raw);
+
+@protected List<FeedCandidate> dco_decode_list_feed_candidate(dynamic raw);
+
+@protected List<FeedEntry> dco_decode_list_feed_entry(dynamic raw);
+
+@protected List<GempubChapter> dco_decode_list_gempub_chapter(dynamic raw);
+
+@protected List<GemtextLine> dco_decode_list_gemtext_line(dynamic raw);
+
+@protected List<GopherItem> dco_decode_list_gopher_item(dynamic raw);
+
+@protected List<HighlightedBlock> dco_decode_list_highlighted_block(dynamic raw);
+
+@protected List<HighlightedLine> dco_decode_list_highlighted_line(dynamic raw);
+
+@protected List<HistoryEntry> dco_decode_list_history_entry(dynamic raw);
+
+@protected List<HistoryResult> dco_decode_list_history_result(dynamic raw);
+
+@protected List<HostMigration> dco_decode_list_host_migration(dynamic raw);
+
+@protected List<HostSettings> dco_decode_list_host_settings(dynamic raw);
+
+@protected List<HostStats> dco_decode_list_host_stats(dynamic raw);
+
+@protected List<Identity> dco_decode_list_identity(dynamic raw);
+
+@protected List<IdentityScope> dco_decode_list_identity_scope(dynamic raw);
+
+@protected List<InlineImage> dco_decode_list_inline_image(dynamic raw);
+
+@protected List<InlineImageFailure> dco_decode_list_inline_image_failure(dynamic raw);
+
+@protected List<KnownHost> dco_decode_list_known_host(dynamic raw);
+
+@protected List<LevelRun> dco_decode_list_level_run(dynamic raw);
+
+@protected List<LineDirection> dco_decode_list_line_direction(dynamic raw);
+
+@protected List<LocalSearchResult> dco_decode_list_local_search_result(dynamic raw);
+
+@protected List<LogRecord> dco_decode_list_log_record(dynamic raw);
+
+@protected List<OutlineEntry> dco_decode_list_outline_entry(dynamic raw);
+
+@protected List<PageLink> dco_decode_list_page_link(dynamic raw);
+
+@protected List<PortOverride> dco_decode_list_port_override(dynamic raw);
+
+@protected List<PreformattedBlock> dco_decode_list_preformatted_block(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<ProtocolInfo> dco_decode_list_protocol_info(dynamic raw);
+
+@protected List<ProviderFailure> dco_decode_list_provider_failure(dynamic raw);
+
+@protected List<ProxyRule> dco_decode_list_proxy_rule(dynamic raw);
+
+@protected List<ReadLaterItem> dco_decode_list_read_later_item(dynamic raw);
+
+@protected List<ReflowedLine> dco_decode_list_reflowed_line(dynamic raw);
+
+@protected List<SavedAnswer> dco_decode_list_saved_answer(dynamic raw);
+
+@protected List<SchedulerEvent> dco_decode_list_scheduler_event(dynamic raw);
+
+@protected List<SchemeProxy> dco_decode_list_scheme_proxy(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<SearchProvider> dco_decode_list_search_provider(dynamic raw);
+
+@protected List<SessionEntry> dco_decode_list_session_entry(dynamic raw);
+
+@protected List<SessionSummary> dco_decode_list_session_summary(dynamic raw);
+
+@protected List<SessionTab> dco_decode_list_session_tab(dynamic raw);
+
+@protected List<SiteNode> dco_decode_list_site_node(dynamic raw);
+
+@protected List<SnippetPart> dco_decode_list_snippet_part(dynamic raw);
+
+@protected List<StyledSpan> dco_decode_list_styled_span(dynamic raw);
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw);
+
+@protected List<TaskSchedule> dco_decode_list_task_schedule(dynamic raw);
+
+@protected List<TokenSpan> dco_decode_list_token_span(dynamic raw);
+
+@protected List<TopSite> dco_decode_list_top_site(dynamic raw);
+
+@protected List<TraceEvent> dco_decode_list_trace_event(dynamic raw);
+
+@protected List<TrustedCa> dco_decode_list_trusted_ca(dynamic raw);
+
+@protected List<WatchedPage> dco_decode_list_watched_page(dynamic raw);
+
+@protected List<WatchedPlan> dco_decode_list_watched_plan(dynamic raw);
+
+@protected LocalSearchResult dco_decode_local_search_result(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected LogRecord dco_decode_log_record(dynamic raw);
+
+@protected MenuPage dco_decode_menu_page(dynamic raw);
+
+@protected MetasearchResult dco_decode_metasearch_result(dynamic raw);
+
+@protected MirrorProgress dco_decode_mirror_progress(dynamic raw);
+
+@protected NavigateError dco_decode_navigate_error(dynamic raw);
+
+@protected NavigationEvent dco_decode_navigation_event(dynamic raw);
+
+@protected NavigationEventKind dco_decode_navigation_event_kind(dynamic raw);
+
+@protected NavigationResult dco_decode_navigation_result(dynamic raw);
+
+@protected NetworkType dco_decode_network_type(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected AnsiColor? dco_decode_opt_box_autoadd_ansi_color(dynamic raw);
+
+@protected BatchItem? dco_decode_opt_box_autoadd_batch_item(dynamic raw);
+
+@protected Bookmark? dco_decode_opt_box_autoadd_bookmark(dynamic raw);
+
+@protected CertificateError? dco_decode_opt_box_autoadd_certificate_error(dynamic raw);
+
+@protected CertificateSummary? dco_decode_opt_box_autoadd_certificate_summary(dynamic raw);
+
+@protected DownloadStatus? dco_decode_opt_box_autoadd_download_status(dynamic raw);
+
+@protected ErrorInfo? dco_decode_opt_box_autoadd_error_info(dynamic raw);
+
+@protected FileType? dco_decode_opt_box_autoadd_file_type(dynamic raw);
+
+@protected GemtextLine? dco_decode_opt_box_autoadd_gemtext_line(dynamic raw);
+
+@protected HostSettings? dco_decode_opt_box_autoadd_host_settings(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected IdentityScope? dco_decode_opt_box_autoadd_identity_scope(dynamic raw);
+
+@protected MirrorProgress? dco_decode_opt_box_autoadd_mirror_progress(dynamic raw);
+
+@protected NavigationEvent? dco_decode_opt_box_autoadd_navigation_event(dynamic raw);
+
+@protected NavigationResult? dco_decode_opt_box_autoadd_navigation_result(dynamic raw);
+
+@protected RecoveryAction? dco_decode_opt_box_autoadd_recovery_action(dynamic raw);
+
+@protected RedirectPolicy? dco_decode_opt_box_autoadd_redirect_policy(dynamic raw);
+
+@protected Renderer? dco_decode_opt_box_autoadd_renderer(dynamic raw);
+
+@protected ServerFailure? dco_decode_opt_box_autoadd_server_failure(dynamic raw);
+
+@protected Session? dco_decode_opt_box_autoadd_session(dynamic raw);
+
+@protected Socks5Proxy? dco_decode_opt_box_autoadd_socks_5_proxy(dynamic raw);
+
+@protected TlsFailure? dco_decode_opt_box_autoadd_tls_failure(dynamic raw);
+
+@protected TlsPolicy? dco_decode_opt_box_autoadd_tls_policy(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+@protected List<HighlightedLine>? dco_decode_opt_list_highlighted_line(dynamic raw);
+
+@protected List<TraceEvent>? dco_decode_opt_list_trace_event(dynamic raw);
+
+@protected OutlineEntry dco_decode_outline_entry(dynamic raw);
+
+@protected PageDiff dco_decode_page_diff(dynamic raw);
+
+@protected PageLink dco_decode_page_link(dynamic raw);
+
+@protected PageSummary dco_decode_page_summary(dynamic raw);
+
+@protected PeekResult dco_decode_peek_result(dynamic raw);
+
+@protected PinExpiry dco_decode_pin_expiry(dynamic raw);
+
+@protected PinStatus dco_decode_pin_status(dynamic raw);
+
+@protected PortOverride dco_decode_port_override(dynamic raw);
+
+@protected PowerState dco_decode_power_state(dynamic raw);
+
+@protected PreformattedBlock dco_decode_preformatted_block(dynamic raw);
+
+@protected ProtocolCapabilities dco_decode_protocol_capabilities(dynamic raw);
+
+@protected ProtocolInfo dco_decode_protocol_info(dynamic raw);
+
+@protected ProviderFailure dco_decode_provider_failure(dynamic raw);
+
+@protected ProxyProbe dco_decode_proxy_probe(dynamic raw);
+
+@protected ProxyProtocol dco_decode_proxy_protocol(dynamic raw);
+
+@protected ProxyRule dco_decode_proxy_rule(dynamic raw);
+
+@protected ProxySettings dco_decode_proxy_settings(dynamic raw);
+
+@protected RandomDestination dco_decode_random_destination(dynamic raw);
+
+@protected RateLimits dco_decode_rate_limits(dynamic raw);
+
+@protected ReadLaterItem dco_decode_read_later_item(dynamic raw);
+
+@protected ReadingStats dco_decode_reading_stats(dynamic raw);
+
+@protected RecoveryAction dco_decode_recovery_action(dynamic raw);
+
+@protected RedirectPolicy dco_decode_redirect_policy(dynamic raw);
+
+@protected ReflowedLine dco_decode_reflowed_line(dynamic raw);
+
+@protected Renderer dco_decode_renderer(dynamic raw);
+
+@protected RequestTimings dco_decode_request_timings(dynamic raw);
+
+@protected SavedAnswer dco_decode_saved_answer(dynamic raw);
+
+@protected ScheduledTask dco_decode_scheduled_task(dynamic raw);
+
+@protected SchedulerEvent dco_decode_scheduler_event(dynamic raw);
+
+@protected SchedulerEventKind dco_decode_scheduler_event_kind(dynamic raw);
+
+@protected SchemeProxy dco_decode_scheme_proxy(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchProvider dco_decode_search_provider(dynamic raw);
+
+@protected ServerFailure dco_decode_server_failure(dynamic raw);
+
+@protected ServerFailureKind dco_decode_server_failure_kind(dynamic raw);
+
+@protected Session dco_decode_session(dynamic raw);
+
+@protected SessionEntry dco_decode_session_entry(dynamic raw);
+
+@protected SessionSummary dco_decode_session_summary(dynamic raw);
+
+@protected SessionTab dco_decode_session_tab(dynamic raw);
+
+@protected ShutdownSummary dco_decode_shutdown_summary(dynamic raw);
+
+@protected SiteMap dco_decode_site_map(dynamic raw);
+
+@protected SiteNode dco_decode_site_node(dynamic raw);
+
+@protected SnippetPart dco_decode_snippet_part(dynamic raw);
+
+@protected Socks5Proxy dco_decode_socks_5_proxy(dynamic raw);
+
+@protected StreamEvent dco_decode_stream_event(dynamic raw);
+
+@protected StreamEventKind dco_decode_stream_event_kind(dynamic raw);
+
+@protected StreamOptions dco_decode_stream_options(dynamic raw);
+
+@protected StyledSpan dco_decode_styled_span(dynamic raw);
+
+@protected StyledText dco_decode_styled_text(dynamic raw);
+
+@protected Suggestion dco_decode_suggestion(dynamic raw);
+
+@protected SuggestionMatch dco_decode_suggestion_match(dynamic raw);
+
+@protected TaskSchedule dco_decode_task_schedule(dynamic raw);
+
+@protected TextDirection dco_decode_text_direction(dynamic raw);
+
+@protected TlsFailure dco_decode_tls_failure(dynamic raw);
+
+@protected TlsFailureKind dco_decode_tls_failure_kind(dynamic raw);
+
+@protected TlsPolicy dco_decode_tls_policy(dynamic raw);
+
+@protected TokenKind dco_decode_token_kind(dynamic raw);
+
+@protected TokenSpan dco_decode_token_span(dynamic raw);
+
+@protected TopSite dco_decode_top_site(dynamic raw);
+
+@protected TraceEvent dco_decode_trace_event(dynamic raw);
+
+@protected TraceStage dco_decode_trace_stage(dynamic raw);
+
+@protected TrustedCa dco_decode_trusted_ca(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected WatchedPage dco_decode_watched_page(dynamic raw);
+
+@protected WatchedPlan dco_decode_watched_plan(dynamic raw);
+
+@protected String sse_decode_Char(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AddedBookmark sse_decode_added_bookmark(SseDeserializer deserializer);
+
+@protected AnsiColor sse_decode_ansi_color(SseDeserializer deserializer);
+
+@protected AnsiStyle sse_decode_ansi_style(SseDeserializer deserializer);
+
+@protected ArchivedPage sse_decode_archived_page(SseDeserializer deserializer);
+
+@protected BatchItem sse_decode_batch_item(SseDeserializer deserializer);
+
+@protected BlockRule sse_decode_block_rule(SseDeserializer deserializer);
+
+@protected BlockRuleKind sse_decode_block_rule_kind(SseDeserializer deserializer);
+
+@protected Bookmark sse_decode_bookmark(SseDeserializer deserializer);
+
+@protected BookmarkFormat sse_decode_bookmark_format(SseDeserializer deserializer);
+
+@protected BookmarkSource sse_decode_bookmark_source(SseDeserializer deserializer);
+
+@protected BookmarksImport sse_decode_bookmarks_import(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AnsiColor sse_decode_box_autoadd_ansi_color(SseDeserializer deserializer);
+
+@protected BatchItem sse_decode_box_autoadd_batch_item(SseDeserializer deserializer);
+
+@protected Bookmark sse_decode_box_autoadd_bookmark(SseDeserializer deserializer);
+
+@protected CachePolicy sse_decode_box_autoadd_cache_policy(SseDeserializer deserializer);
+
+@protected CertificateError sse_decode_box_autoadd_certificate_error(SseDeserializer deserializer);
+
+@protected CertificateSummary sse_decode_box_autoadd_certificate_summary(SseDeserializer deserializer);
+
+@protected Config sse_decode_box_autoadd_config(SseDeserializer deserializer);
+
+@protected DecompressionLimits sse_decode_box_autoadd_decompression_limits(SseDeserializer deserializer);
+
+@protected DownloadStatus sse_decode_box_autoadd_download_status(SseDeserializer deserializer);
+
+@protected ErrorInfo sse_decode_box_autoadd_error_info(SseDeserializer deserializer);
+
+@protected FetchOptions sse_decode_box_autoadd_fetch_options(SseDeserializer deserializer);
+
+@protected FileType sse_decode_box_autoadd_file_type(SseDeserializer deserializer);
+
+@protected GemtextLine sse_decode_box_autoadd_gemtext_line(SseDeserializer deserializer);
+
+@protected HistoryRange sse_decode_box_autoadd_history_range(SseDeserializer deserializer);
+
+@protected HostSettings sse_decode_box_autoadd_host_settings(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IdentityScope sse_decode_box_autoadd_identity_scope(SseDeserializer deserializer);
+
+@protected MirrorProgress sse_decode_box_autoadd_mirror_progress(SseDeserializer deserializer);
+
+@protected NavigateError sse_decode_box_autoadd_navigate_error(SseDeserializer deserializer);
+
+@protected NavigationEvent sse_decode_box_autoadd_navigation_event(SseDeserializer deserializer);
+
+@protected NavigationResult sse_decode_box_autoadd_navigation_result(SseDeserializer deserializer);
+
+@protected ProxySettings sse_decode_box_autoadd_proxy_settings(SseDeserializer deserializer);
+
+@protected RateLimits sse_decode_box_autoadd_rate_limits(SseDeserializer deserializer);
+
+@protected RecoveryAction sse_decode_box_autoadd_recovery_action(SseDeserializer deserializer);
+
+@protected RedirectPolicy sse_decode_box_autoadd_redirect_policy(SseDeserializer deserializer);
+
+@protected Renderer sse_decode_box_autoadd_renderer(SseDeserializer deserializer);
+
+@protected ServerFailure sse_decode_box_autoadd_server_failure(SseDeserializer deserializer);
+
+@protected Session sse_decode_box_autoadd_session(SseDeserializer deserializer);
+
+@protected Socks5Proxy sse_decode_box_autoadd_socks_5_proxy(SseDeserializer deserializer);
+
+@protected StreamOptions sse_decode_box_autoadd_stream_options(SseDeserializer deserializer);
+
+@protected TaskSchedule sse_decode_box_autoadd_task_schedule(SseDeserializer deserializer);
+
+@protected TlsFailure sse_decode_box_autoadd_tls_failure(SseDeserializer deserializer);
+
+@protected TlsPolicy sse_decode_box_autoadd_tls_policy(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected CacheEntry sse_decode_cache_entry(SseDeserializer deserializer);
+
+@protected CachePolicy sse_decode_cache_policy(SseDeserializer deserializer);
+
+@protected CertificateError sse_decode_certificate_error(SseDeserializer deserializer);
+
+@protected CertificateErrorKind sse_decode_certificate_error_kind(SseDeserializer deserializer);
+
+@protected CertificateSummary sse_decode_certificate_summary(SseDeserializer deserializer);
+
+@protected ChecksumStatus sse_decode_checksum_status(SseDeserializer deserializer);
+
+@protected ClearedData sse_decode_cleared_data(SseDeserializer deserializer);
+
+@protected ClockOffset sse_decode_clock_offset(SseDeserializer deserializer);
+
+@protected Config sse_decode_config(SseDeserializer deserializer);
+
+@protected CrawlResult sse_decode_crawl_result(SseDeserializer deserializer);
+
+@protected CsoField sse_decode_cso_field(SseDeserializer deserializer);
+
+@protected CsoRecord sse_decode_cso_record(SseDeserializer deserializer);
+
+@protected DailyUsage sse_decode_daily_usage(SseDeserializer deserializer);
+
+@protected DataCapStatus sse_decode_data_cap_status(SseDeserializer deserializer);
+
+@protected DataCategory sse_decode_data_category(SseDeserializer deserializer);
+
+@protected DecompressionLimits sse_decode_decompression_limits(SseDeserializer deserializer);
+
+@protected DiffLine sse_decode_diff_line(SseDeserializer deserializer);
+
+@protected DisplayHost sse_decode_display_host(SseDeserializer deserializer);
+
+@protected DownloadRecord sse_decode_download_record(SseDeserializer deserializer);
+
+@protected DownloadResult sse_decode_download_result(SseDeserializer deserializer);
+
+@protected DownloadStatus sse_decode_download_status(SseDeserializer deserializer);
+
+@protected ErrorCode sse_decode_error_code(SseDeserializer deserializer);
+
+@protected ErrorInfo sse_decode_error_info(SseDeserializer deserializer);
+
+@protected ExpiryReminder sse_decode_expiry_reminder(SseDeserializer deserializer);
+
+@protected ExploreSource sse_decode_explore_source(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected Feed sse_decode_feed(SseDeserializer deserializer);
+
+@protected FeedCandidate sse_decode_feed_candidate(SseDeserializer deserializer);
+
+@protected FeedEntry sse_decode_feed_entry(SseDeserializer deserializer);
+
+@protected FeedKind sse_decode_feed_kind(SseDeserializer deserializer);
+
+@protected FetchOptions sse_decode_fetch_options(SseDeserializer deserializer);
+
+@protected FetchResponse sse_decode_fetch_response(SseDeserializer deserializer);
+
+@protected FileType sse_decode_file_type(SseDeserializer deserializer);
+
+@protected GempubBook sse_decode_gempub_book(SseDeserializer deserializer);
+
+@protected GempubChapter sse_decode_gempub_chapter(SseDeserializer deserializer);
+
+@protected GempubMetadata sse_decode_gempub_metadata(SseDeserializer deserializer);
+
+@protected GempubPage sse_decode_gempub_page(SseDeserializer deserializer);
+
+@protected GemtextLine sse_decode_gemtext_line(SseDeserializer deserializer);
+
+@protected GopherItem sse_decode_gopher_item(SseDeserializer deserializer);
+
+@protected HeadingAnchor sse_decode_heading_anchor(SseDeserializer deserializer);
+
+@protected HighlightedBlock sse_decode_highlighted_block(SseDeserializer deserializer);
+
+@protected HighlightedLine sse_decode_highlighted_line(SseDeserializer deserializer);
+
+@protected HistoryEntry sse_decode_history_entry(SseDeserializer deserializer);
+
+@protected HistoryMatch sse_decode_history_match(SseDeserializer deserializer);
+
+@protected HistoryRange sse_decode_history_range(SseDeserializer deserializer);
+
+@protected HistoryResult sse_decode_history_result(SseDeserializer deserializer);
+
+@protected HostMigration sse_decode_host_migration(SseDeserializer deserializer);
+
+@protected HostMigrationResult sse_decode_host_migration_result(SseDeserializer deserializer);
+
+@protected HostSettings sse_decode_host_settings(SseDeserializer deserializer);
+
+@protected HostStats sse_decode_host_stats(SseDeserializer deserializer);
+
+@protected HostStatsOrder sse_decode_host_stats_order(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IdentitiesImport sse_decode_identities_import(SseDeserializer deserializer);
+
+@protected Identity sse_decode_identity(SseDeserializer deserializer);
+
+@protected IdentityScope sse_decode_identity_scope(SseDeserializer deserializer);
+
+@protected InlineImage sse_decode_inline_image(SseDeserializer deserializer);
+
+@protected InlineImageFailure sse_decode_inline_image_failure(SseDeserializer deserializer);
+
+@protected InlineImages sse_decode_inline_images(SseDeserializer deserializer);
+
+@protected KnownHost sse_decode_known_host(SseDeserializer deserializer);
+
+@protected KnownHostsFormat sse_decode_known_hosts_format(SseDeserializer deserializer);
+
+@protected KnownHostsImport sse_decode_known_hosts_import(SseDeserializer deserializer);
+
+@protected LevelRun sse_decode_level_run(SseDeserializer deserializer);
+
+@protected LineDirection sse_decode_line_direction(SseDeserializer deserializer);
+
+@protected LinkKind sse_decode_link_kind(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<ArchivedPage> sse_decode_list_archived_page(SseDeserializer deserializer);
+
+@protected List<BlockRule> sse_decode_list_block_rule(SseDeserializer deserializer);
+
+@protected List<Bookmark> sse_decode_list_bookmark(SseDeserializer deserializer);
+
+@protected List<CacheEntry> sse_decode_list_cache_entry(SseDeserializer deserializer);
+
+@protected List<CachePolicy> sse_decode_list_cache_policy(SseDeserializer deserializer);
+
+@protected List<ClearedData> sse_decode_list_cleared_data(SseDeserializer deserializer);
+
+@protected List<CsoField> sse_decode_list_cso_field(SseDeserializer deserializer);
+
+@protected List<CsoRecord> sse_decode_list_cso_record(SseDeserializer deserializer);
+
+@protected List<DailyUsage> sse_decode_list_daily_usage(SseDeserializer deserializer);
+
+@protected List<DataCategory> sse_decode_list_data_category(SseDeserializer deserializer);
+
+@protected List<DiffLine> sse_decode_list_diff_line(SseDeserializer deserializer);
+
+@protected List<DownloadRecord> sse_decode_list_download_record(SseDeserializer deserializer);
+
+@protected List<ExpiryReminder> sse_decode_list_expiry_reminder(SseDeserializer deserializer);
+
+@protected List<ExploreSource> sse_decode_list_explore_source(SseDeserializer deserializer);
+
+@protected List<Feed> sse_decode_list_feed(SseDeserializer deserializer);
+
+@protected List<FeedCandidate> sse_decode_list_feed_candidate(SseDeserializer deserializer);
+
+@protected List<FeedEntry> sse_decode_list_feed_entry(SseDeserializer deserializer);
+
+@protected List<GempubChapter> sse_decode_list_gempub_chapter(SseDeserializer deserializer);
+
+@protected List<GemtextLine> sse_decode_list_gemtext_line(SseDeserializer deserializer);
+
+@protected List<GopherItem> sse_decode_list_gopher_item(SseDeserializer deserializer);
+
+@protected List<HighlightedBlock> sse_decode_list_highlighted_block(SseDeserializer deserializer);
+
+@protected List<HighlightedLine> sse_decode_list_highlighted_line(SseDeserializer deserializer);
+
+@protected List<HistoryEntry> sse_decode_list_history_entry(SseDeserializer deserializer);
+
+@protected List<HistoryResult> sse_decode_list_history_result(SseDeserializer deserializer);
+
+@protected List<HostMigration> sse_decode_list_host_migration(SseDeserializer deserializer);
+
+@protected List<HostSettings> sse_decode_list_host_settings(SseDeserializer deserializer);
+
+@protected List<HostStats> sse_decode_list_host_stats(SseDeserializer deserializer);
+
+@protected List<Identity> sse_decode_list_identity(SseDeserializer deserializer);
+
+@protected List<IdentityScope> sse_decode_list_identity_scope(SseDeserializer deserializer);
+
+@protected List<InlineImage> sse_decode_list_inline_image(SseDeserializer deserializer);
+
+@protected List<InlineImageFailure> sse_decode_list_inline_image_failure(SseDeserializer deserializer);
+
+@protected List<KnownHost> sse_decode_list_known_host(SseDeserializer deserializer);
+
+@protected List<LevelRun> sse_decode_list_level_run(SseDeserializer deserializer);
+
+@protected List<LineDirection> sse_decode_list_line_direction(SseDeserializer deserializer);
+
+@protected List<LocalSearchResult> sse_decode_list_local_search_result(SseDeserializer deserializer);
+
+@protected List<LogRecord> sse_decode_list_log_record(SseDeserializer deserializer);
+
+@protected List<OutlineEntry> sse_decode_list_outline_entry(SseDeserializer deserializer);
+
+@protected List<PageLink> sse_decode_list_page_link(SseDeserializer deserializer);
+
+@protected List<PortOverride> sse_decode_list_port_override(SseDeserializer deserializer);
+
+@protected List<PreformattedBlock> sse_decode_list_preformatted_block(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<ProtocolInfo> sse_decode_list_protocol_info(SseDeserializer deserializer);
+
+@protected List<ProviderFailure> sse_decode_list_provider_failure(SseDeserializer deserializer);
+
+@protected List<ProxyRule> sse_decode_list_proxy_rule(SseDeserializer deserializer);
+
+@protected List<ReadLaterItem> sse_decode_list_read_later_item(SseDeserializer deserializer);
+
+@protected List<ReflowedLine> sse_decode_list_reflowed_line(SseDeserializer deserializer);
+
+@protected List<SavedAnswer> sse_decode_list_saved_answer(SseDeserializer deserializer);
+
+@protected List<SchedulerEvent> sse_decode_list_scheduler_event(SseDeserializer deserializer);
+
+@protected List<SchemeProxy> sse_decode_list_scheme_proxy(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SearchProvider> sse_decode_list_search_provider(SseDeserializer deserializer);
+
+@protected List<SessionEntry> sse_decode_list_session_entry(SseDeserializer deserializer);
+
+@protected List<SessionSummary> sse_decode_list_session_summary(SseDeserializer deserializer);
+
+@protected List<SessionTab> sse_decode_list_session_tab(SseDeserializer deserializer);
+
+@protected List<SiteNode> sse_decode_list_site_node(SseDeserializer deserializer);
+
+@protected List<SnippetPart> sse_decode_list_snippet_part(SseDeserializer deserializer);
+
+@protected List<StyledSpan> sse_decode_list_styled_span(SseDeserializer deserializer);
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer);
+
+@protected List<TaskSchedule> sse_decode_list_task_schedule(SseDeserializer deserializer);
+
+@protected List<TokenSpan> sse_decode_list_token_span(SseDeserializer deserializer);
+
+@protected List<TopSite> sse_decode_list_top_site(SseDeserializer deserializer);
+
+@protected List<TraceEvent> sse_decode_list_trace_event(SseDeserializer deserializer);
+
+@protected List<TrustedCa> sse_decode_list_trusted_ca(SseDeserializer deserializer);
+
+@protected List<WatchedPage> sse_decode_list_watched_page(SseDeserializer deserializer);
+
+@protected List<WatchedPlan> sse_decode_list_watched_plan(SseDeserializer deserializer);
+
+@protected LocalSearchResult sse_decode_local_search_result(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+@protected MenuPage sse_decode_menu_page(SseDeserializer deserializer);
+
+@protected MetasearchResult sse_decode_metasearch_result(SseDeserializer deserializer);
+
+@protected MirrorProgress sse_decode_mirror_progress(SseDeserializer deserializer);
+
+@protected NavigateError sse_decode_navigate_error(SseDeserializer deserializer);
+
+@protected NavigationEvent sse_decode_navigation_event(SseDeserializer deserializer);
+
+@protected NavigationEventKind sse_decode_navigation_event_kind(SseDeserializer deserializer);
+
+@protected NavigationResult sse_decode_navigation_result(SseDeserializer deserializer);
+
+@protected NetworkType sse_decode_network_type(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AnsiColor? sse_decode_opt_box_autoadd_ansi_color(SseDeserializer deserializer);
+
+@protected BatchItem? sse_decode_opt_box_autoadd_batch_item(SseDeserializer deserializer);
+
+@protected Bookmark? sse_decode_opt_box_autoadd_bookmark(SseDeserializer deserializer);
+
+@protected CertificateError? sse_decode_opt_box_autoadd_certificate_error(SseDeserializer deserializer);
+
+@protected CertificateSummary? sse_decode_opt_box_autoadd_certificate_summary(SseDeserializer deserializer);
+
+@protected DownloadStatus? sse_decode_opt_box_autoadd_download_status(SseDeserializer deserializer);
+
+@protected ErrorInfo? sse_decode_opt_box_autoadd_error_info(SseDeserializer deserializer);
+
+@protected FileType? sse_decode_opt_box_autoadd_file_type(SseDeserializer deserializer);
+
+@protected GemtextLine? sse_decode_opt_box_autoadd_gemtext_line(SseDeserializer deserializer);
+
+@protected HostSettings? sse_decode_opt_box_autoadd_host_settings(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IdentityScope? sse_decode_opt_box_autoadd_identity_scope(SseDeserializer deserializer);
+
+@protected MirrorProgress? sse_decode_opt_box_autoadd_mirror_progress(SseDeserializer deserializer);
+
+@protected NavigationEvent? sse_decode_opt_box_autoadd_navigation_event(SseDeserializer deserializer);
+
+@protected NavigationResult? sse_decode_opt_box_autoadd_navigation_result(SseDeserializer deserializer);
+
+@protected RecoveryAction? sse_decode_opt_box_autoadd_recovery_action(SseDeserializer deserializer);
+
+@protected RedirectPolicy? sse_decode_opt_box_autoadd_redirect_policy(SseDeserializer deserializer);
+
+@protected Renderer? sse_decode_opt_box_autoadd_renderer(SseDeserializer deserializer);
+
+@protected ServerFailure? sse_decode_opt_box_autoadd_server_failure(SseDeserializer deserializer);
+
+@protected Session? sse_decode_opt_box_autoadd_session(SseDeserializer deserializer);
+
+@protected Socks5Proxy? sse_decode_opt_box_autoadd_socks_5_proxy(SseDeserializer deserializer);
+
+@protected TlsFailure? sse_decode_opt_box_autoadd_tls_failure(SseDeserializer deserializer);
+
+@protected TlsPolicy? sse_decode_opt_box_autoadd_tls_policy(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected List<HighlightedLine>? sse_decode_opt_list_highlighted_line(SseDeserializer deserializer);
+
+@protected List<TraceEvent>? sse_decode_opt_list_trace_event(SseDeserializer deserializer);
+
+@protected OutlineEntry sse_decode_outline_entry(SseDeserializer deserializer);
+
+@protected PageDiff sse_decode_page_diff(SseDeserializer deserializer);
+
+@protected PageLink sse_decode_page_link(SseDeserializer deserializer);
+
+@protected PageSummary sse_decode_page_summary(SseDeserializer deserializer);
+
+@protected PeekResult sse_decode_peek_result(SseDeserializer deserializer);
+
+@protected PinExpiry sse_decode_pin_expiry(SseDeserializer deserializer);
+
+@protected PinStatus sse_decode_pin_status(SseDeserializer deserializer);
+
+@protected PortOverride sse_decode_port_override(SseDeserializer deserializer);
+
+@protected PowerState sse_decode_power_state(SseDeserializer deserializer);
+
+@protected PreformattedBlock sse_decode_preformatted_block(SseDeserializer deserializer);
+
+@protected ProtocolCapabilities sse_decode_protocol_capabilities(SseDeserializer deserializer);
+
+@protected ProtocolInfo sse_decode_protocol_info(SseDeserializer deserializer);
+
+@protected ProviderFailure sse_decode_provider_failure(SseDeserializer deserializer);
+
+@protected ProxyProbe sse_decode_proxy_probe(SseDeserializer deserializer);
+
+@protected ProxyProtocol sse_decode_proxy_protocol(SseDeserializer deserializer);
+
+@protected ProxyRule sse_decode_proxy_rule(SseDeserializer deserializer);
+
+@protected ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer);
+
+@protected RandomDestination sse_decode_random_destination(SseDeserializer deserializer);
+
+@protected RateLimits sse_decode_rate_limits(SseDeserializer deserializer);
+
+@protected ReadLaterItem sse_decode_read_later_item(SseDeserializer deserializer);
+
+@protected ReadingStats sse_decode_reading_stats(SseDeserializer deserializer);
+
+@protected RecoveryAction sse_decode_recovery_action(SseDeserializer deserializer);
+
+@protected RedirectPolicy sse_decode_redirect_policy(SseDeserializer deserializer);
+
+@protected ReflowedLine sse_decode_reflowed_line(SseDeserializer deserializer);
+
+@protected Renderer sse_decode_renderer(SseDeserializer deserializer);
+
+@protected RequestTimings sse_decode_request_timings(SseDeserializer deserializer);
+
+@protected SavedAnswer sse_decode_saved_answer(SseDeserializer deserializer);
+
+@protected ScheduledTask sse_decode_scheduled_task(SseDeserializer deserializer);
+
+@protected SchedulerEvent sse_decode_scheduler_event(SseDeserializer deserializer);
+
+@protected SchedulerEventKind sse_decode_scheduler_event_kind(SseDeserializer deserializer);
+
+@protected SchemeProxy sse_decode_scheme_proxy(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchProvider sse_decode_search_provider(SseDeserializer deserializer);
+
+@protected ServerFailure sse_decode_server_failure(SseDeserializer deserializer);
+
+@protected ServerFailureKind sse_decode_server_failure_kind(SseDeserializer deserializer);
+
+@protected Session sse_decode_session(SseDeserializer deserializer);
+
+@protected SessionEntry sse_decode_session_entry(SseDeserializer deserializer);
+
+@protected SessionSummary sse_decode_session_summary(SseDeserializer deserializer);
+
+@protected SessionTab sse_decode_session_tab(SseDeserializer deserializer);
+
+@protected ShutdownSummary sse_decode_shutdown_summary(SseDeserializer deserializer);
+
+@protected SiteMap sse_decode_site_map(SseDeserializer deserializer);
+
+@protected SiteNode sse_decode_site_node(SseDeserializer deserializer);
+
+@protected SnippetPart sse_decode_snippet_part(SseDeserializer deserializer);
+
+@protected Socks5Proxy sse_decode_socks_5_proxy(SseDeserializer deserializer);
+
+@protected StreamEvent sse_decode_stream_event(SseDeserializer deserializer);
+
+@protected StreamEventKind sse_decode_stream_event_kind(SseDeserializer deserializer);
+
+@protected StreamOptions sse_decode_stream_options(SseDeserializer deserializer);
+
+@protected StyledSpan sse_decode_styled_span(SseDeserializer deserializer);
+
+@protected StyledText sse_decode_styled_text(SseDeserializer deserializer);
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer);
+
+@protected SuggestionMatch sse_decode_suggestion_match(SseDeserializer deserializer);
+
+@protected TaskSchedule sse_decode_task_schedule(SseDeserializer deserializer);
+
+@protected TextDirection sse_decode_text_direction(SseDeserializer deserializer);
+
+@protected TlsFailure sse_decode_tls_failure(SseDeserializer deserializer);
+
+@protected TlsFailureKind sse_decode_tls_failure_kind(SseDeserializer deserializer);
+
+@protected TlsPolicy sse_decode_tls_policy(SseDeserializer deserializer);
+
+@protected TokenKind sse_decode_token_kind(SseDeserializer deserializer);
+
+@protected TokenSpan sse_decode_token_span(SseDeserializer deserializer);
+
+@protected TopSite sse_decode_top_site(SseDeserializer deserializer);
+
+@protected TraceEvent sse_decode_trace_event(SseDeserializer deserializer);
+
+@protected TraceStage sse_decode_trace_stage(SseDeserializer deserializer);
+
+@protected TrustedCa sse_decode_trusted_ca(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected WatchedPage sse_decode_watched_page(SseDeserializer deserializer);
+
+@protected WatchedPlan sse_decode_watched_plan(SseDeserializer deserializer);
+
+@protected void sse_encode_Char(String self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_added_bookmark(AddedBookmark self, SseSerializer serializer);
+
+@protected void sse_encode_ansi_color(AnsiColor self, SseSerializer serializer);
+
+@protected void sse_encode_ansi_style(AnsiStyle self, SseSerializer serializer);
+
+@protected void sse_encode_archived_page(ArchivedPage self, SseSerializer serializer);
+
+@protected void sse_encode_batch_item(BatchItem self, SseSerializer serializer);
+
+@protected void sse_encode_block_rule(BlockRule self, SseSerializer serializer);
+
+@protected void sse_encode_block_rule_kind(BlockRuleKind self, SseSerializer serializer);
+
+@protected void sse_encode_bookmark(Bookmark self, SseSerializer serializer);
+
+@protected void sse_encode_bookmark_format(BookmarkFormat self, SseSerializer serializer);
+
+@protected void sse_encode_bookmark_source(BookmarkSource self, SseSerializer serializer);
+
+@protected void sse_encode_bookmarks_import(BookmarksImport self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ansi_color(AnsiColor self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_batch_item(BatchItem self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bookmark(Bookmark self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cache_policy(CachePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_certificate_error(CertificateError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_certificate_summary(CertificateSummary self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_config(Config self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_decompression_limits(DecompressionLimits self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_download_status(DownloadStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_error_info(ErrorInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fetch_options(FetchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_file_type(FileType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_gemtext_line(GemtextLine self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_history_range(HistoryRange self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_host_settings(HostSettings self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_identity_scope(IdentityScope self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mirror_progress(MirrorProgress self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_navigate_error(NavigateError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_navigation_event(NavigationEvent self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_navigation_result(NavigationResult self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_proxy_settings(ProxySettings self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rate_limits(RateLimits self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_recovery_action(RecoveryAction self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_redirect_policy(RedirectPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_renderer(Renderer self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_server_failure(ServerFailure self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_session(Session self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_socks_5_proxy(Socks5Proxy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stream_options(StreamOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_task_schedule(TaskSchedule self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tls_failure(TlsFailure self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tls_policy(TlsPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_cache_entry(CacheEntry self, SseSerializer serializer);
+
+@protected void sse_encode_cache_policy(CachePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_certificate_error(CertificateError self, SseSerializer serializer);
+
+@protected void sse_encode_certificate_error_kind(CertificateErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_certificate_summary(CertificateSummary self, SseSerializer serializer);
+
+@protected void sse_encode_checksum_status(ChecksumStatus self, SseSerializer serializer);
+
+@protected void sse_encode_cleared_data(ClearedData self, SseSerializer serializer);
+
+@protected void sse_encode_clock_offset(ClockOffset self, SseSerializer serializer);
+
+@protected void sse_encode_config(Config self, SseSerializer serializer);
+
+@protected void sse_encode_crawl_result(CrawlResult self, SseSerializer serializer);
+
+@protected void sse_encode_cso_field(CsoField self, SseSerializer serializer);
+
+@protected void sse_encode_cso_record(CsoRecord self, SseSerializer serializer);
+
+@protected void sse_encode_daily_usage(DailyUsage self, SseSerializer serializer);
+
+@protected void sse_encode_data_cap_status(DataCapStatus self, SseSerializer serializer);
+
+@protected void sse_encode_data_category(DataCategory self, SseSerializer serializer);
+
+@protected void sse_encode_decompression_limits(DecompressionLimits self, SseSerializer serializer);
+
+@protected void sse_encode_diff_line(DiffLine self, SseSerializer serializer);
+
+@protected void sse_encode_display_host(DisplayHost self, SseSerializer serializer);
+
+@protected void sse_encode_download_record(DownloadRecord self, SseSerializer serializer);
+
+@protected void sse_encode_download_result(DownloadResult self, SseSerializer serializer);
+
+@protected void sse_encode_download_status(DownloadStatus self, SseSerializer serializer);
+
+@protected void sse_encode_error_code(ErrorCode self, SseSerializer serializer);
+
+@protected void sse_encode_error_info(ErrorInfo self, SseSerializer serializer);
+
+@protected void sse_encode_expiry_reminder(ExpiryReminder self, SseSerializer serializer);
+
+@protected void sse_encode_explore_source(ExploreSource self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_feed(Feed self, SseSerializer serializer);
+
+@protected void sse_encode_feed_candidate(FeedCandidate self, SseSerializer serializer);
+
+@protected void sse_encode_feed_entry(FeedEntry self, SseSerializer serializer);
+
+@protected void sse_encode_feed_kind(FeedKind self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_options(FetchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_response(FetchResponse self, SseSerializer serializer);
+
+@protected void sse_encode_file_type(FileType self, SseSerializer serializer);
+
+@protected void sse_encode_gempub_book(GempubBook self, SseSerializer serializer);
+
+@protected void sse_encode_gempub_chapter(GempubChapter self, SseSerializer serializer);
+
+@protected void sse_encode_gempub_metadata(GempubMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_gempub_page(GempubPage self, SseSerializer serializer);
+
+@protected void sse_encode_gemtext_line(GemtextLine self, SseSerializer serializer);
+
+@protected void sse_encode_gopher_item(GopherItem self, SseSerializer serializer);
+
+@protected void sse_encode_heading_anchor(HeadingAnchor self, SseSerializer serializer);
+
+@protected void sse_encode_highlighted_block(HighlightedBlock self, SseSerializer serializer);
+
+@protected void sse_encode_highlighted_line(HighlightedLine self, SseSerializer serializer);
+
+@protected void sse_encode_history_entry(HistoryEntry self, SseSerializer serializer);
+
+@protected void sse_encode_history_match(HistoryMatch self, SseSerializer serializer);
+
+@protected void sse_encode_history_range(HistoryRange self, SseSerializer serializer);
+
+@protected void sse_encode_history_result(HistoryResult self, SseSerializer serializer);
+
+@protected void sse_encode_host_migration(HostMigration self, SseSerializer serializer);
+
+@protected void sse_encode_host_migration_result(HostMigrationResult self, SseSerializer serializer);
+
+@protected void sse_encode_host_settings(HostSettings self, SseSerializer serializer);
+
+@protected void sse_encode_host_stats(HostStats self, SseSerializer serializer);
+
+@protected void sse_encode_host_stats_order(HostStatsOrder self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_identities_import(IdentitiesImport self, SseSerializer serializer);
+
+@protected void sse_encode_identity(Identity self, SseSerializer serializer);
+
+@protected void sse_encode_identity_scope(IdentityScope self, SseSerializer serializer);
+
+@protected void sse_encode_inline_image(InlineImage self, SseSerializer serializer);
+
+@protected void sse_encode_inline_image_failure(InlineImageFailure self, SseSerializer serializer);
+
+@protected void sse_encode_inline_images(InlineImages self, SseSerializer serializer);
+
+@protected void sse_encode_known_host(KnownHost self, SseSerializer serializer);
+
+@protected void sse_encode_known_hosts_format(KnownHostsFormat self, SseSerializer serializer);
+
+@protected void sse_encode_known_hosts_import(KnownHostsImport self, SseSerializer serializer);
+
+@protected void sse_encode_level_run(LevelRun self, SseSerializer serializer);
+
+@protected void sse_encode_line_direction(LineDirection self, SseSerializer serializer);
+
+@protected void sse_encode_link_kind(LinkKind self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_archived_page(List<ArchivedPage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_block_rule(List<BlockRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_bookmark(List<Bookmark> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cache_entry(List<CacheEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cache_policy(List<CachePolicy> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cleared_data(List<ClearedData> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cso_field(List<CsoField> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cso_record(List<CsoRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_daily_usage(List<DailyUsage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_data_category(List<DataCategory> self, SseSerializer serializer);
+
+@protected void sse_encode_list_diff_line(List<DiffLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_download_record(List<DownloadRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_expiry_reminder(List<ExpiryReminder> self, SseSerializer serializer);
+
+@protected void sse_encode_list_explore_source(List<ExploreSource> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feed(List<Feed> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feed_candidate(List<FeedCandidate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_feed_entry(List<FeedEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_gempub_chapter(List<GempubChapter> self, SseSerializer serializer);
+
+@protected void sse_encode_list_gemtext_line(List<GemtextLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_gopher_item(List<GopherItem> self, SseSerializer serializer);
+
+@protected void sse_encode_list_highlighted_block(List<HighlightedBlock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_highlighted_line(List<HighlightedLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_history_entry(List<HistoryEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_history_result(List<HistoryResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_host_migration(List<HostMigration> self, SseSerializer serializer);
+
+@protected void sse_encode_list_host_settings(List<HostSettings> self, SseSerializer serializer);
+
+@protected void sse_encode_list_host_stats(List<HostStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_identity(List<Identity> self, SseSerializer serializer);
+
+@protected void sse_encode_list_identity_scope(List<IdentityScope> self, SseSerializer serializer);
+
+@protected void sse_encode_list_inline_image(List<InlineImage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_inline_image_failure(List<InlineImageFailure> self, SseSerializer serializer);
+
+@protected void sse_encode_list_known_host(List<KnownHost> self, SseSerializer serializer);
+
+@protected void sse_encode_list_level_run(List<LevelRun> self, SseSerializer serializer);
+
+@protected void sse_encode_list_line_direction(List<LineDirection> self, SseSerializer serializer);
+
+@protected void sse_encode_list_local_search_result(List<LocalSearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_log_record(List<LogRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_outline_entry(List<OutlineEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_page_link(List<PageLink> self, SseSerializer serializer);
+
+@protected void sse_encode_list_port_override(List<PortOverride> self, SseSerializer serializer);
+
+@protected void sse_encode_list_preformatted_block(List<PreformattedBlock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_protocol_info(List<ProtocolInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_provider_failure(List<ProviderFailure> self, SseSerializer serializer);
+
+@protected void sse_encode_list_proxy_rule(List<ProxyRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_read_later_item(List<ReadLaterItem> self, SseSerializer serializer);
+
+@protected void sse_encode_list_reflowed_line(List<ReflowedLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_saved_answer(List<SavedAnswer> self, SseSerializer serializer);
+
+@protected void sse_encode_list_scheduler_event(List<SchedulerEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_scheme_proxy(List<SchemeProxy> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_provider(List<SearchProvider> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_entry(List<SessionEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_summary(List<SessionSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_tab(List<SessionTab> self, SseSerializer serializer);
+
+@protected void sse_encode_list_site_node(List<SiteNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snippet_part(List<SnippetPart> self, SseSerializer serializer);
+
+@protected void sse_encode_list_styled_span(List<StyledSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_task_schedule(List<TaskSchedule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_token_span(List<TokenSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_top_site(List<TopSite> self, SseSerializer serializer);
+
+@protected void sse_encode_list_trace_event(List<TraceEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_trusted_ca(List<TrustedCa> self, SseSerializer serializer);
+
+@protected void sse_encode_list_watched_page(List<WatchedPage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_watched_plan(List<WatchedPlan> self, SseSerializer serializer);
+
+@protected void sse_encode_local_search_result(LocalSearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer);
+
+@protected void sse_encode_menu_page(MenuPage self, SseSerializer serializer);
+
+@protected void sse_encode_metasearch_result(MetasearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_mirror_progress(MirrorProgress self, SseSerializer serializer);
+
+@protected void sse_encode_navigate_error(NavigateError self, SseSerializer serializer);
+
+@protected void sse_encode_navigation_event(NavigationEvent self, SseSerializer serializer);
+
+@protected void sse_encode_navigation_event_kind(NavigationEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_navigation_result(NavigationResult self, SseSerializer serializer);
+
+@protected void sse_encode_network_type(NetworkType self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ansi_color(AnsiColor? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_batch_item(BatchItem? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bookmark(Bookmark? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_certificate_error(CertificateError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_certificate_summary(CertificateSummary? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_download_status(DownloadStatus? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_error_info(ErrorInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_file_type(FileType? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_gemtext_line(GemtextLine? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_host_settings(HostSettings? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_identity_scope(IdentityScope? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_mirror_progress(MirrorProgress? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_navigation_event(NavigationEvent? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_navigation_result(NavigationResult? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_recovery_action(RecoveryAction? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_redirect_policy(RedirectPolicy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_renderer(Renderer? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_server_failure(ServerFailure? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_session(Session? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_socks_5_proxy(Socks5Proxy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tls_failure(TlsFailure? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_tls_policy(TlsPolicy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_highlighted_line(List<HighlightedLine>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_trace_event(List<TraceEvent>? self, SseSerializer serializer);
+
+@protected void sse_encode_outline_entry(OutlineEntry self, SseSerializer serializer);
+
+@protected void sse_encode_page_diff(PageDiff self, SseSerializer serializer);
+
+@protected void sse_encode_page_link(PageLink self, SseSerializer serializer);
+
+@protected void sse_encode_page_summary(PageSummary self, SseSerializer serializer);
+
+@protected void sse_encode_peek_result(PeekResult self, SseSerializer serializer);
+
+@protected void sse_encode_pin_expiry(PinExpiry self, SseSerializer serializer);
+
+@protected void sse_encode_pin_status(PinStatus self, SseSerializer serializer);
+
+@protected void sse_encode_port_override(PortOverride self, SseSerializer serializer);
+
+@protected void sse_encode_power_state(PowerState self, SseSerializer serializer);
+
+@protected void sse_encode_preformatted_block(PreformattedBlock self, SseSerializer serializer);
+
+@protected void sse_encode_protocol_capabilities(ProtocolCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_protocol_info(ProtocolInfo self, SseSerializer serializer);
+
+@protected void sse_encode_provider_failure(ProviderFailure self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_probe(ProxyProbe self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_protocol(ProxyProtocol self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_rule(ProxyRule self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer);
+
+@protected void sse_encode_random_destination(RandomDestination self, SseSerializer serializer);
+
+@protected void sse_encode_rate_limits(RateLimits self, SseSerializer serializer);
+
+@protected void sse_encode_read_later_item(ReadLaterItem self, SseSerializer serializer);
+
+@protected void sse_encode_reading_stats(ReadingStats self, SseSerializer serializer);
+
+@protected void sse_encode_recovery_action(RecoveryAction self, SseSerializer serializer);
+
+@protected void sse_encode_redirect_policy(RedirectPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_reflowed_line(ReflowedLine self, SseSerializer serializer);
+
+@protected void sse_encode_renderer(Renderer self, SseSerializer serializer);
+
+@protected void sse_encode_request_timings(RequestTimings self, SseSerializer serializer);
+
+@protected void sse_encode_saved_answer(SavedAnswer self, SseSerializer serializer);
+
+@protected void sse_encode_scheduled_task(ScheduledTask self, SseSerializer serializer);
+
+@protected void sse_encode_scheduler_event(SchedulerEvent self, SseSerializer serializer);
+
+@protected void sse_encode_scheduler_event_kind(SchedulerEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_scheme_proxy(SchemeProxy self, SseSerializer serializer);
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_search_provider(SearchProvider self, SseSerializer serializer);
+
+@protected void sse_encode_server_failure(ServerFailure self, SseSerializer serializer);
+
+@protected void sse_encode_server_failure_kind(ServerFailureKind self, SseSerializer serializer);
+
+@protected void sse_encode_session(Session self, SseSerializer serializer);
+
+@protected void sse_encode_session_entry(SessionEntry self, SseSerializer serializer);
+
+@protected void sse_encode_session_summary(SessionSummary self, SseSerializer serializer);
+
+@protected void sse_encode_session_tab(SessionTab self, SseSerializer serializer);
+
+@protected void sse_encode_shutdown_summary(ShutdownSummary self, SseSerializer serializer);
+
+@protected void sse_encode_site_map(SiteMap self, SseSerializer serializer);
+
+@protected void sse_encode_site_node(SiteNode self, SseSerializer serializer);
+
+@protected void sse_encode_snippet_part(SnippetPart self, SseSerializer serializer);
+
+@protected void sse_encode_socks_5_proxy(Socks5Proxy self, SseSerializer serializer);
+
+@protected void sse_encode_stream_event(StreamEvent self, SseSerializer serializer);
+
+@protected void sse_encode_stream_event_kind(StreamEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_stream_options(StreamOptions self, SseSerializer serializer);
+
+@protected void sse_encode_styled_span(StyledSpan self, SseSerializer serializer);
+
+@protected void sse_encode_styled_text(StyledText self, SseSerializer serializer);
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer);
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+@protected void sse_encode_suggestion_match(SuggestionMatch self, SseSerializer serializer);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected void sse_encode_task_schedule(TaskSchedule self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_text_direction(TextDirection self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_16(dynamic raw);
+@protected void sse_encode_tls_failure(TlsFailure self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_tls_failure_kind(TlsFailureKind self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_tls_policy(TlsPolicy self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_token_kind(TokenKind self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_token_span(TokenSpan self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+@protected void sse_encode_top_site(TopSite self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_trace_event(TraceEvent self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_trace_stage(TraceStage self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_trusted_ca(TrustedCa self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_watched_page(WatchedPage self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_watched_plan(WatchedPlan self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
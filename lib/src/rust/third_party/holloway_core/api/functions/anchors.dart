@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A heading that can be targeted by a #fragment
+class HeadingAnchor  {
+                final String slug;
+final String text;
+final int level;
+final int lineIndex;
+
+                const HeadingAnchor({required this.slug ,required this.text ,required this.level ,required this.lineIndex ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => slug.hashCode^text.hashCode^level.hashCode^lineIndex.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HeadingAnchor &&
+                runtimeType == other.runtimeType
+                && slug == other.slug&& text == other.text&& level == other.level&& lineIndex == other.lineIndex;
+        
+            }
+
+/// A heading placed in the outline of its document
+class OutlineEntry  {
+                final HeadingAnchor anchor;
+/// Nesting in the outline, 0 for headings under no other; a document starting at `##` has
+/// those at 0 too
+final int depth;
+/// Index in the outline of the nearest preceding heading of a higher level
+final int? parent;
+
+                const OutlineEntry({required this.anchor ,required this.depth ,this.parent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => anchor.hashCode^depth.hashCode^parent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutlineEntry &&
+                runtimeType == other.runtimeType
+                && anchor == other.anchor&& depth == other.depth&& parent == other.parent;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,99 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../api/mirrors.dart';
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Text attributes set by SGR ("select graphic rendition") sequences
+class AnsiStyle  {
+                final AnsiColor? foreground;
+final AnsiColor? background;
+final bool bold;
+final bool dim;
+final bool italic;
+final bool underline;
+/// Swap foreground and background
+final bool inverse;
+
+                const AnsiStyle({this.foreground ,this.background ,required this.bold ,required this.dim ,required this.italic ,required this.underline ,required this.inverse ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => foreground.hashCode^background.hashCode^bold.hashCode^dim.hashCode^italic.hashCode^underline.hashCode^inverse.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AnsiStyle &&
+                runtimeType == other.runtimeType
+                && foreground == other.foreground&& background == other.background&& bold == other.bold&& dim == other.dim&& italic == other.italic&& underline == other.underline&& inverse == other.inverse;
+        
+            }
+
+/// A stretch of text drawn with one non-default style
+class StyledSpan  {
+                /// Start offset in UTF-16 code units of the stripped text, matching Dart string indices
+final int start;
+/// End offset (exclusive) in UTF-16 code units
+final int end;
+final AnsiStyle style;
+
+                const StyledSpan({required this.start ,required this.end ,required this.style ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^style.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StyledSpan &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& style == other.style;
+        
+            }
+
+/// Text with its escape sequences removed and the styles they set
+class StyledText  {
+                final String text;
+/// Only styled stretches are listed; text outside every span uses the default style
+final List<StyledSpan> spans;
+
+                const StyledText({required this.text ,required this.spans ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^spans.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StyledText &&
+                runtimeType == other.runtimeType
+                && text == other.text&& spans == other.spans;
+        
+            }
+            
\ No newline at end of file
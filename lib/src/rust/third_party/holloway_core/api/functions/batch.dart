@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../models/error_info.dart';
+import '../models/navigation_result.dart';
+import '../models/server_failure.dart';
+import 'content_router.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Outcome of one URL of a batch
+class BatchItem  {
+                /// Position of the URL in the list passed to `navigate_many`
+final int index;
+final String url;
+/// Request id of its navigation, for matching it with navigation events and traces
+final BigInt requestId;
+/// Set when the URL answered, whatever its status
+final NavigationResult? result;
+/// Set when it could not be fetched
+final ErrorInfo? error;
+
+                const BatchItem({required this.index ,required this.url ,required this.requestId ,this.result ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^url.hashCode^requestId.hashCode^result.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchItem &&
+                runtimeType == other.runtimeType
+                && index == other.index&& url == other.url&& requestId == other.requestId&& result == other.result&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A maximal stretch of a line sharing one embedding level, in logical order
+class LevelRun  {
+                /// Start offset in UTF-16 code units, matching Dart string indices
+final int start;
+/// End offset (exclusive) in UTF-16 code units
+final int end;
+final int level;
+final TextDirection direction;
+
+                const LevelRun({required this.start ,required this.end ,required this.level ,required this.direction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^level.hashCode^direction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LevelRun &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& level == other.level&& direction == other.direction;
+        
+            }
+
+/// Bidi analysis of a single document line
+class LineDirection  {
+                final int lineIndex;
+/// Paragraph direction from the first strong character (rule P2/P3)
+final TextDirection direction;
+final List<LevelRun> runs;
+
+                const LineDirection({required this.lineIndex ,required this.direction ,required this.runs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => lineIndex.hashCode^direction.hashCode^runs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LineDirection &&
+                runtimeType == other.runtimeType
+                && lineIndex == other.lineIndex&& direction == other.direction&& runs == other.runs;
+        
+            }
+
+/// Base direction of a paragraph or run
+enum TextDirection {
+                    ltr,
+rtl,
+                    ;
+                    
+                }
+            
\ No newline at end of file
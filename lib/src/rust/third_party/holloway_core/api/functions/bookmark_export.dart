@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Bookmark file formats other browsers read
+enum BookmarkFormat {
+                    /// XML Bookmark Exchange Language, with each bookmark's creation time as `added`
+xbel,
+/// A gemtext page of `=> URL title` links, e.g. for publishing on a capsule
+gemtext,
+/// The Netscape bookmark file desktop web browsers import and export
+html,
+                    ;
+                    
+                }
+            
\ No newline at end of file
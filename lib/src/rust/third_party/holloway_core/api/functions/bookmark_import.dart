@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Bookmark files of other Gemini clients
+enum BookmarkSource {
+                    /// Amfora's bookmarks.xml, an XBEL file; also reads XBEL written by other browsers
+amfora,
+/// Lagrange's bookmarks.ini: one `[n]` section per bookmark with quoted `url`, `title`,
+/// and `tags` values and a `created` time in seconds; folders have no URL and are flattened
+lagrange,
+                    ;
+                    
+                }
+
+/// How many bookmarks an import added and how many it passed over
+class BookmarksImport  {
+                final int imported;
+/// URLs already bookmarked here, possibly spelled differently, and unreadable entries
+final int skipped;
+
+                const BookmarksImport({required this.imported ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => imported.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BookmarksImport &&
+                runtimeType == other.runtimeType
+                && imported == other.imported&& skipped == other.skipped;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// The device clock offset inferred from the certificates of the servers visited
+class ClockOffset  {
+                /// Seconds the device clock appears to be behind true time; negative when it runs ahead.
+/// None until enough hosts were seen
+final PlatformInt64? offsetSecs;
+/// Hosts whose certificates the estimate is based on
+final int hosts;
+/// Whether every certificate seen was valid under the estimated offset; false when some
+/// server's certificate is simply outdated and the estimate is a median instead
+final bool consistent;
+
+                const ClockOffset({this.offsetSecs ,required this.hosts ,required this.consistent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => offsetSecs.hashCode^hosts.hashCode^consistent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClockOffset &&
+                runtimeType == other.runtimeType
+                && offsetSecs == other.offsetSecs&& hosts == other.hosts&& consistent == other.consistent;
+        
+            }
+            
\ No newline at end of file
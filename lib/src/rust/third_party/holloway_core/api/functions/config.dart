@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../api/mirrors.dart';
+import '../../../../frb_generated.dart';
+import '../storage/host_settings.dart';
+import '../transport/proxy.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Browser-wide settings, each applying wherever a host or a request sets nothing of its own
+class Config  {
+                /// Scheme of an address typed without one that neither its port, a `user@`, its path, its
+/// host name, nor an earlier visit decides
+final String defaultScheme;
+/// Guess the scheme of addresses typed without one from their form, e.g. finger for
+/// `user@host`; off, they all get `default_scheme`
+final bool guessSchemes;
+final String startPage;
+/// Search URL template, with `%s` where the query goes
+final String searchProvider;
+final int connectTimeoutSecs;
+/// Longest wait for data, or None to wait as long as the server keeps the connection open
+final int? readTimeoutSecs;
+final TlsPolicy tlsPolicy;
+final ProxySettings proxy;
+
+                const Config({required this.defaultScheme ,required this.guessSchemes ,required this.startPage ,required this.searchProvider ,required this.connectTimeoutSecs ,this.readTimeoutSecs ,required this.tlsPolicy ,required this.proxy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => defaultScheme.hashCode^guessSchemes.hashCode^startPage.hashCode^searchProvider.hashCode^connectTimeoutSecs.hashCode^readTimeoutSecs.hashCode^tlsPolicy.hashCode^proxy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Config &&
+                runtimeType == other.runtimeType
+                && defaultScheme == other.defaultScheme&& guessSchemes == other.guessSchemes&& startPage == other.startPage&& searchProvider == other.searchProvider&& connectTimeoutSecs == other.connectTimeoutSecs&& readTimeoutSecs == other.readTimeoutSecs&& tlsPolicy == other.tlsPolicy&& proxy == other.proxy;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How the UI should present a response
+enum Renderer {
+                    /// A text/gemini document, parsed into lines
+gemtext,
+/// A Gopher menu of typed items
+gopherMenu,
+/// Text shown as is, monospaced where the protocol expects it
+plainText,
+markdown,
+/// An image shown inline
+image,
+/// Content Holloway cannot show, offered for saving instead
+download,
+/// Something for another application: a scheme no handler serves, a telnet session, or a
+/// web link from a Gopher menu
+external_,
+                    ;
+                    
+                }
+            
\ No newline at end of file
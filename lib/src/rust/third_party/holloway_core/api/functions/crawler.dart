@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What a crawl of a capsule did
+class CrawlResult  {
+                /// The URL prefix the crawl kept to and `search_capsule` searches under
+final String capsule;
+/// Pages fetched and added to the local index, or found there already
+final int indexed;
+/// Pages whose fetch failed or that answered with an error status
+final int failed;
+/// Pages the capsule's robots.txt keeps crawlers out of
+final int disallowed;
+/// Pages found but left unvisited once `max_pages` were visited
+final int remaining;
+/// Set when `cancel_capsule_indexing` stopped the crawl
+final bool cancelled;
+
+                const CrawlResult({required this.capsule ,required this.indexed ,required this.failed ,required this.disallowed ,required this.remaining ,required this.cancelled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => capsule.hashCode^indexed.hashCode^failed.hashCode^disallowed.hashCode^remaining.hashCode^cancelled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CrawlResult &&
+                runtimeType == other.runtimeType
+                && capsule == other.capsule&& indexed == other.indexed&& failed == other.failed&& disallowed == other.disallowed&& remaining == other.remaining&& cancelled == other.cancelled;
+        
+            }
+            
\ No newline at end of file
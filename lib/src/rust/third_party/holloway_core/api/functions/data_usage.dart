@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How much of the cellular soft cap has been used
+class DataCapStatus  {
+                /// None when no cap is set
+final BigInt? limitBytes;
+/// Cellular bytes over the last DATA_CAP_PERIOD_DAYS days
+final BigInt usedBytes;
+final bool exceeded;
+
+                const DataCapStatus({this.limitBytes ,required this.usedBytes ,required this.exceeded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => limitBytes.hashCode^usedBytes.hashCode^exceeded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DataCapStatus &&
+                runtimeType == other.runtimeType
+                && limitBytes == other.limitBytes&& usedBytes == other.usedBytes&& exceeded == other.exceeded;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../storage/downloads.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A file saved by `download`
+class DownloadResult  {
+                final String path;
+/// Bytes written
+final BigInt size;
+final String mime;
+/// SHA-256 of the file as lowercase hex
+final String sha256;
+/// How the file compared with the checksum it was downloaded with
+final ChecksumStatus checksum;
+
+                const DownloadResult({required this.path ,required this.size ,required this.mime ,required this.sha256 ,required this.checksum ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^size.hashCode^mime.hashCode^sha256.hashCode^checksum.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DownloadResult &&
+                runtimeType == other.runtimeType
+                && path == other.path&& size == other.size&& mime == other.mime&& sha256 == other.sha256&& checksum == other.checksum;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Where the "explore the smolnet" button may take the user
+class ExploreSource  {
+                /// Directory or aggregator page whose links to other hosts are candidates; None picks among
+/// the bookmarks
+final String? directoryUrl;
+/// Relative chance of picking from this source; sources without candidates are skipped
+final double weight;
+
+                const ExploreSource({this.directoryUrl ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => directoryUrl.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExploreSource &&
+                runtimeType == other.runtimeType
+                && directoryUrl == other.directoryUrl&& weight == other.weight;
+        
+            }
+
+/// A randomly picked page
+class RandomDestination  {
+                final String url;
+/// Link label or bookmark title
+final String? label;
+/// Directory the page was listed in, or None when it is a bookmark
+final String? directoryUrl;
+
+                const RandomDestination({required this.url ,this.label ,this.directoryUrl ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^label.hashCode^directoryUrl.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RandomDestination &&
+                runtimeType == other.runtimeType
+                && url == other.url&& label == other.label&& directoryUrl == other.directoryUrl;
+        
+            }
+            
\ No newline at end of file
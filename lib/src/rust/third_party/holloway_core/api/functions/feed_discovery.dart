@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A link on a page that looks like something the user could subscribe to
+class FeedCandidate  {
+                final String url;
+final String? title;
+final FeedKind kind;
+
+                const FeedCandidate({required this.url ,this.title ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FeedCandidate &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& kind == other.kind;
+        
+            }
+
+/// The flavour of a discovered feed
+enum FeedKind {
+                    atom,
+rss,
+/// A gemtext page following the Gemini subscription (gmisub) convention
+gemfeed,
+/// An XML feed whose flavour is only known once it is fetched
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A file type recognized from its content or MIME type
+class FileType  {
+                final String mime;
+/// Suggested file name extension, without the dot
+final String extension_;
+
+                const FileType({required this.mime ,required this.extension_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mime.hashCode^extension_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileType &&
+                runtimeType == other.runtimeType
+                && mime == other.mime&& extension_ == other.extension_;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,135 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An unpacked Gempub kept open for reading
+class GempubBook  {
+                final BigInt handle;
+final GempubMetadata metadata;
+/// Path of the index page inside the archive, if the book has one
+final String? indexPath;
+final List<GempubChapter> chapters;
+
+                const GempubBook({required this.handle ,required this.metadata ,this.indexPath ,required this.chapters ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => handle.hashCode^metadata.hashCode^indexPath.hashCode^chapters.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GempubBook &&
+                runtimeType == other.runtimeType
+                && handle == other.handle&& metadata == other.metadata&& indexPath == other.indexPath&& chapters == other.chapters;
+        
+            }
+
+/// A chapter in reading order
+class GempubChapter  {
+                /// The index link's label, else the chapter's first heading, else its path
+final String title;
+/// Path of the chapter's gemtext file inside the archive
+final String path;
+
+                const GempubChapter({required this.title ,required this.path ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^path.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GempubChapter &&
+                runtimeType == other.runtimeType
+                && title == other.title&& path == other.path;
+        
+            }
+
+/// The descriptive fields of a Gempub's metadata.txt
+class GempubMetadata  {
+                /// The `title` field, else the first heading of the index, else "Untitled"
+final String title;
+final String? author;
+final String? language;
+final String? description;
+/// Publication date as written, e.g. "2021-03-01"
+final String? published;
+final String? version;
+/// Path of the cover image inside the archive
+final String? cover;
+
+                const GempubMetadata({required this.title ,this.author ,this.language ,this.description ,this.published ,this.version ,this.cover ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^author.hashCode^language.hashCode^description.hashCode^published.hashCode^version.hashCode^cover.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GempubMetadata &&
+                runtimeType == other.runtimeType
+                && title == other.title&& author == other.author&& language == other.language&& description == other.description&& published == other.published&& version == other.version&& cover == other.cover;
+        
+            }
+
+/// A file of an open book with its place among the chapters
+class GempubPage  {
+                /// Path inside the archive, to resolve the page's links against
+final String path;
+final String mime;
+/// Text of text files (chapters, the index); empty for other files
+final String body;
+/// Bytes of other files, such as images; empty for text files
+final Uint8List data;
+/// Position of the page among the chapters, if it is one
+final int? chapter;
+final int? previousChapter;
+final int? nextChapter;
+
+                const GempubPage({required this.path ,required this.mime ,required this.body ,required this.data ,this.chapter ,this.previousChapter ,this.nextChapter ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^mime.hashCode^body.hashCode^data.hashCode^chapter.hashCode^previousChapter.hashCode^nextChapter.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GempubPage &&
+                runtimeType == other.runtimeType
+                && path == other.path&& mime == other.mime&& body == other.body&& data == other.data&& chapter == other.chapter&& previousChapter == other.previousChapter&& nextChapter == other.nextChapter;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A preformatted block whose alt text names a recognized language, split into token spans
+class HighlightedBlock  {
+                /// Index of the opening ``` line in the document
+final int startIndex;
+/// Name of the grammar used, e.g. "Rust"
+final String language;
+/// Each line of the block in order
+final List<HighlightedLine> lines;
+
+                const HighlightedBlock({required this.startIndex ,required this.language ,required this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startIndex.hashCode^language.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HighlightedBlock &&
+                runtimeType == other.runtimeType
+                && startIndex == other.startIndex&& language == other.language&& lines == other.lines;
+        
+            }
+
+/// The token spans of one line of code; joining them gives the line back
+class HighlightedLine  {
+                final List<TokenSpan> spans;
+
+                const HighlightedLine({required this.spans ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => spans.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HighlightedLine &&
+                runtimeType == other.runtimeType
+                && spans == other.spans;
+        
+            }
+
+/// What a span of code is, for the UI to color with its own light or dark palette
+enum TokenKind {
+                    plain,
+comment,
+string,
+number,
+/// Language constants such as `true` and `None`
+constant,
+keyword,
+operator_,
+type,
+function,
+variable,
+/// Markup tag names, e.g. `div` in HTML
+tag,
+attribute,
+punctuation,
+                    ;
+                    
+                }
+
+/// A run of a line's text of one kind
+class TokenSpan  {
+                final String text;
+final TokenKind kind;
+
+                const TokenSpan({required this.text ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenSpan &&
+                runtimeType == other.runtimeType
+                && text == other.text&& kind == other.kind;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How a host should be shown to the user
+class DisplayHost  {
+                /// Unicode form of the host, or its punycode (xn--) form when it may be spoofed
+final String host;
+/// Set when the host mixes scripts or looks like a different host the user has visited
+final bool spoofingWarning;
+
+                const DisplayHost({required this.host ,required this.spoofingWarning ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^spoofingWarning.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DisplayHost &&
+                runtimeType == other.runtimeType
+                && host == other.host&& spoofingWarning == other.spoofingWarning;
+        
+            }
+            
\ No newline at end of file
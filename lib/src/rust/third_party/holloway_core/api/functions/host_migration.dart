@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A bookmarked host whose pages permanently redirect to another host
+class HostMigration  {
+                final String scheme;
+final String fromHost;
+final String toHost;
+/// Distinct pages seen redirecting from `from_host` to `to_host`
+final int movedPages;
+/// Bookmarks that `migrate_host` would rewrite
+final int bookmarks;
+
+                const HostMigration({required this.scheme ,required this.fromHost ,required this.toHost ,required this.movedPages ,required this.bookmarks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scheme.hashCode^fromHost.hashCode^toHost.hashCode^movedPages.hashCode^bookmarks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HostMigration &&
+                runtimeType == other.runtimeType
+                && scheme == other.scheme&& fromHost == other.fromHost&& toHost == other.toHost&& movedPages == other.movedPages&& bookmarks == other.bookmarks;
+        
+            }
+
+/// What `migrate_host` rewrote
+class HostMigrationResult  {
+                /// Bookmarks moved to the new host, including ones merged into an existing bookmark there
+final int bookmarks;
+
+                const HostMigrationResult({required this.bookmarks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bookmarks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HostMigrationResult &&
+                runtimeType == other.runtimeType
+                && bookmarks == other.bookmarks;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../storage/identities.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An identity whose certificate expires soon or already has
+class ExpiryReminder  {
+                final Identity identity;
+/// Whole days until expiry; negative once expired
+final PlatformInt64 daysLeft;
+final bool expired;
+
+                const ExpiryReminder({required this.identity ,required this.daysLeft ,required this.expired ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => identity.hashCode^daysLeft.hashCode^expired.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExpiryReminder &&
+                runtimeType == other.runtimeType
+                && identity == other.identity&& daysLeft == other.daysLeft&& expired == other.expired;
+        
+            }
+            
\ No newline at end of file
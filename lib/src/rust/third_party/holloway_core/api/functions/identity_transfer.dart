@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How many identities an import added and how many it passed over
+class IdentitiesImport  {
+                final int imported;
+/// Identities already imported, and certificates without a readable key
+final int skipped;
+
+                const IdentitiesImport({required this.imported ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => imported.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IdentitiesImport &&
+                runtimeType == other.runtimeType
+                && imported == other.imported&& skipped == other.skipped;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An image link of a document with the image it leads to
+class InlineImage  {
+                /// Position of the link among the document's links, counting from 0
+final int linkIndex;
+/// Index of the link's line in the document
+final int lineIndex;
+final String url;
+final String mime;
+final Uint8List data;
+
+                const InlineImage({required this.linkIndex ,required this.lineIndex ,required this.url ,required this.mime ,required this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => linkIndex.hashCode^lineIndex.hashCode^url.hashCode^mime.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InlineImage &&
+                runtimeType == other.runtimeType
+                && linkIndex == other.linkIndex&& lineIndex == other.lineIndex&& url == other.url&& mime == other.mime&& data == other.data;
+        
+            }
+
+/// An image link that was not fetched and why, e.g. the image was over the size limit
+class InlineImageFailure  {
+                final int linkIndex;
+final int lineIndex;
+final String url;
+final String error;
+
+                const InlineImageFailure({required this.linkIndex ,required this.lineIndex ,required this.url ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => linkIndex.hashCode^lineIndex.hashCode^url.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InlineImageFailure &&
+                runtimeType == other.runtimeType
+                && linkIndex == other.linkIndex&& lineIndex == other.lineIndex&& url == other.url&& error == other.error;
+        
+            }
+
+/// The images of a document's image links, in document order
+class InlineImages  {
+                final List<InlineImage> images;
+final List<InlineImageFailure> failed;
+
+                const InlineImages({required this.images ,required this.failed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => images.hashCode^failed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InlineImages &&
+                runtimeType == other.runtimeType
+                && images == other.images&& failed == other.failed;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// known_hosts file formats; all of them pin the SHA-256 of the public key
+enum KnownHostsFormat {
+                    /// Holloway's own file, which keeps every detail of a pin: one
+/// `host port hex expiry first-seen last-seen` line per host, times in Unix seconds and `-`
+/// for a certificate without an expiry
+holloway,
+/// Amfora's tofu.toml: `"host" = "HEX"` and `"host/expiry" = <RFC 3339 time>`, with the dots
+/// of the host written as slashes and non-default ports appended as `:port`
+amfora,
+/// Lagrange's trusted.2.txt: one `host;port expiry-unix-seconds hex` line per host
+lagrange,
+                    ;
+                    
+                }
+
+/// How many pins an import added and how many it passed over
+class KnownHostsImport  {
+                final int imported;
+/// Hosts already pinned here (whose existing trust decision wins) and unreadable entries
+final int skipped;
+
+                const KnownHostsImport({required this.imported ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => imported.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KnownHostsImport &&
+                runtimeType == other.runtimeType
+                && imported == other.imported&& skipped == other.skipped;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What following a link leads to
+enum LinkKind {
+                    /// A page or menu the browser opens itself
+page,
+/// A Gopher search or phone book, which needs a query before it can be fetched
+search,
+/// An image, sound, video, or other file that is downloaded rather than shown as a page
+download,
+/// A link handed to another app, e.g. a web page or a telnet session
+external_,
+                    ;
+                    
+                }
+
+/// A link on a page, resolved against the page URL
+class PageLink  {
+                final String url;
+final String? label;
+final LinkKind kind;
+
+                const PageLink({required this.url ,this.label ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^label.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PageLink &&
+                runtimeType == other.runtimeType
+                && url == other.url&& label == other.label&& kind == other.kind;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How severe a log record is
+enum LogLevel {
+                    /// Each step of a request, as recorded in its trace
+debug,
+/// Requests finishing, settings changing
+info,
+/// Failures of a request or of a server, e.g. a refused connection or a changed certificate
+warn,
+/// Failures of the browser itself, e.g. of the profile database
+error,
+                    ;
+                    
+                }
+
+/// One entry of the log
+class LogRecord  {
+                /// Milliseconds since the Unix epoch
+final PlatformInt64 timestampMs;
+final LogLevel level;
+/// Part of the browser that logged it, e.g. "gemini", "tls", or "navigation"
+final String target;
+final String message;
+/// Request id of the navigation it was logged during, for matching it with its trace
+final BigInt? requestId;
+
+                const LogRecord({required this.timestampMs ,required this.level ,required this.target ,required this.message ,this.requestId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^level.hashCode^target.hashCode^message.hashCode^requestId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogRecord &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& level == other.level&& target == other.target&& message == other.message&& requestId == other.requestId;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../parsers/gophermap.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A window of a retained Gopher menu
+class MenuPage  {
+                final int offset;
+final List<GopherItem> items;
+/// Items in the whole menu
+final int total;
+
+                const MenuPage({required this.offset ,required this.items ,required this.total ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => offset.hashCode^items.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MenuPage &&
+                runtimeType == other.runtimeType
+                && offset == other.offset&& items == other.items&& total == other.total;
+        
+            }
+            
\ No newline at end of file
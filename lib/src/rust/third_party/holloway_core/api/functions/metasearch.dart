@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Merged hits of every engine that answered, best first
+class MetasearchResult  {
+                final List<SearchHit> hits;
+/// Engines that failed or answered with something other than a result page
+final List<ProviderFailure> failed;
+
+                const MetasearchResult({required this.hits ,required this.failed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^failed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MetasearchResult &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& failed == other.failed;
+        
+            }
+
+/// An engine left out of a metasearch and why
+class ProviderFailure  {
+                final String name;
+final String error;
+
+                const ProviderFailure({required this.name ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProviderFailure &&
+                runtimeType == other.runtimeType
+                && name == other.name&& error == other.error;
+        
+            }
+
+/// One result of a metasearch, merged across the engines that returned it
+class SearchHit  {
+                final String url;
+/// The link label the first engine gave it, or its URL
+final String title;
+/// First line of text an engine printed under the link, e.g. an excerpt or the page size
+final String? snippet;
+/// Names of the engines that returned it, in the order of the provider list
+final List<String> providers;
+/// Sum over those engines of 1 / (60 + rank); higher is better
+final double score;
+
+                const SearchHit({required this.url ,required this.title ,this.snippet ,required this.providers ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^snippet.hashCode^providers.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& snippet == other.snippet&& providers == other.providers&& score == other.score;
+        
+            }
+            
\ No newline at end of file
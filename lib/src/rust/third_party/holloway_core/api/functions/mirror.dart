@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What happened to one URL of a mirror
+class MirrorProgress  {
+                final String url;
+/// Where it was saved, when it was
+final String? path;
+/// Why it was not saved
+final String? error;
+/// Files saved so far, counting those of the run that was resumed
+final int saved;
+final int failed;
+/// URLs found and not yet fetched
+final int remaining;
+
+                const MirrorProgress({required this.url ,this.path ,this.error ,required this.saved ,required this.failed ,required this.remaining ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^path.hashCode^error.hashCode^saved.hashCode^failed.hashCode^remaining.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MirrorProgress &&
+                runtimeType == other.runtimeType
+                && url == other.url&& path == other.path&& error == other.error&& saved == other.saved&& failed == other.failed&& remaining == other.remaining;
+        
+            }
+            
\ No newline at end of file
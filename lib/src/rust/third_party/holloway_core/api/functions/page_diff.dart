@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A line that was added to or removed from a page
+class DiffLine  {
+                /// 1-based line number, in the fresh version for added lines and in the cached version for
+/// removed ones
+final int line;
+final String text;
+
+                const DiffLine({required this.line ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => line.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiffLine &&
+                runtimeType == other.runtimeType
+                && line == other.line&& text == other.text;
+        
+            }
+
+/// How a page changed between its cached copy and a fresh fetch
+class PageDiff  {
+                final String url;
+/// When the cached copy compared against was fetched, in seconds since the Unix epoch
+final PlatformInt64 cachedAt;
+/// Lines of the fresh version not in the cached one, in page order
+final List<DiffLine> added;
+/// Lines of the cached version not in the fresh one, in page order
+final List<DiffLine> removed;
+
+                const PageDiff({required this.url ,required this.cachedAt ,required this.added ,required this.removed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^cachedAt.hashCode^added.hashCode^removed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PageDiff &&
+                runtimeType == other.runtimeType
+                && url == other.url&& cachedAt == other.cachedAt&& added == other.added&& removed == other.removed;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What a page is about in a few words, for a share sheet or a bookmark preview
+class PageSummary  {
+                /// The URL the page was found at, after redirects
+final String url;
+/// The first heading, or None for a page without one
+final String? title;
+/// The first paragraph of text, cut to about 200 characters
+final String? description;
+/// The capsule's /favicon.txt emoji, when it has one
+final String? favicon;
+/// When the summarized copy was fetched, if it came from the cache
+final PlatformInt64? cachedAt;
+
+                const PageSummary({required this.url ,this.title ,this.description ,this.favicon ,this.cachedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^description.hashCode^favicon.hashCode^cachedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PageSummary &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& description == other.description&& favicon == other.favicon&& cachedAt == other.cachedAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'content_router.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What a URL answers, learned without downloading its body
+class PeekResult  {
+                final String url;
+/// Status code of the header, for protocols that have one (Gemini)
+final int? status;
+final String? meta;
+/// MIME type of the body, from the Gemini header or guessed by the protocol handler; None
+/// when the answer has no body, e.g. a redirect or an error
+final String? mime;
+/// How the body would be presented, e.g. Download for a file to save
+final Renderer? renderer;
+/// Body size in bytes, known when the whole body of a protocol without a header fit in the
+/// first 4 KiB; never known for Gemini, whose header does not give it
+final BigInt? size;
+
+                const PeekResult({required this.url ,this.status ,this.meta ,this.mime ,this.renderer ,this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^status.hashCode^meta.hashCode^mime.hashCode^renderer.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PeekResult &&
+                runtimeType == other.runtimeType
+                && url == other.url&& status == other.status&& meta == other.meta&& mime == other.mime&& renderer == other.renderer&& size == other.size;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Size of a document as a reader sees it, for showing next to its title
+class ReadingStats  {
+                /// Words of text, headings, list items, quotes and link labels; preformatted blocks are
+/// counted apart, since code and art are not read like prose
+final int words;
+final int preformattedWords;
+/// Estimated minutes to read `words`, rounded up; 0 only for a document without words
+final int readingMinutes;
+final int links;
+final int headings;
+
+                const ReadingStats({required this.words ,required this.preformattedWords ,required this.readingMinutes ,required this.links ,required this.headings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => words.hashCode^preformattedWords.hashCode^readingMinutes.hashCode^links.hashCode^headings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReadingStats &&
+                runtimeType == other.runtimeType
+                && words == other.words&& preformattedWords == other.preformattedWords&& readingMinutes == other.readingMinutes&& links == other.links&& headings == other.headings;
+        
+            }
+            
\ No newline at end of file
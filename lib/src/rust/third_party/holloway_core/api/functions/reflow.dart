@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../api/mirrors.dart';
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One display line produced by reflowing a gemtext document
+class ReflowedLine  {
+                /// Index of the document line this display line came from
+final int sourceIndex;
+final GemtextLine line;
+/// True for the second and later display lines of a wrapped source line
+final bool continuation;
+
+                const ReflowedLine({required this.sourceIndex ,required this.line ,required this.continuation ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sourceIndex.hashCode^line.hashCode^continuation.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReflowedLine &&
+                runtimeType == other.runtimeType
+                && sourceIndex == other.sourceIndex&& line == other.line&& continuation == other.continuation;
+        
+            }
+            
\ No newline at end of file
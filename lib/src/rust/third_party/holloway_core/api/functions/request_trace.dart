@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One step of a request, for bug reports
+class TraceEvent  {
+                final TraceStage stage;
+final String message;
+/// Milliseconds since the navigation started
+final BigInt elapsedMs;
+
+                const TraceEvent({required this.stage ,required this.message ,required this.elapsedMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stage.hashCode^message.hashCode^elapsedMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TraceEvent &&
+                runtimeType == other.runtimeType
+                && stage == other.stage&& message == other.message&& elapsedMs == other.elapsedMs;
+        
+            }
+
+/// Part of the pipeline an event comes from
+enum TraceStage {
+                    /// Resolving the input, checking blocklists and toggles, and choosing a handler
+navigation,
+cache,
+/// Opening the connection, directly or through a proxy or gateway
+transport,
+tls,
+/// The server's answer arriving
+response,
+/// Analysis of the body: title, feeds, fragment
+parser,
+                    ;
+                    
+                }
+            
\ No newline at end of file
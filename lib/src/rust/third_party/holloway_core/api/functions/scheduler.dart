@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// The device's power source, as last reported by the app
+enum PowerState {
+                    charging,
+battery,
+/// On battery and low on it: no background work runs
+lowBattery,
+unknown,
+                    ;
+                    
+                }
+
+/// Background work the scheduler runs
+enum ScheduledTask {
+                    /// Refresh every feed subscription
+feedRefresh,
+/// Check the watched pages for changes
+pageWatches,
+/// Finger the watched addresses for .plan updates
+planWatches,
+/// Find pinned certificates that expired or expire within 30 days
+pinExpiry,
+                    ;
+                    
+                }
+
+/// One step of a scheduled task, published on the scheduler's event stream
+class SchedulerEvent  {
+                final ScheduledTask task;
+final SchedulerEventKind kind;
+/// Seconds since the Unix epoch
+final PlatformInt64 timestamp;
+/// What a completed task found: new feed entries, changed pages, updated plans, or expiring
+/// pins
+final int count;
+/// The URLs of the changed pages or updated plans, or the host:port of the expiring pins
+final List<String> items;
+/// Why the task was skipped or failed
+final String? message;
+/// When the task is due next, for Completed and Failed events
+final PlatformInt64? nextRunAt;
+
+                const SchedulerEvent({required this.task ,required this.kind ,required this.timestamp ,required this.count ,required this.items ,this.message ,this.nextRunAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => task.hashCode^kind.hashCode^timestamp.hashCode^count.hashCode^items.hashCode^message.hashCode^nextRunAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchedulerEvent &&
+                runtimeType == other.runtimeType
+                && task == other.task&& kind == other.kind&& timestamp == other.timestamp&& count == other.count&& items == other.items&& message == other.message&& nextRunAt == other.nextRunAt;
+        
+            }
+
+/// What happened to a task
+enum SchedulerEventKind {
+                    started,
+completed,
+/// The task was due, but a constraint held it back; it runs once the constraint is met.
+/// Published once until the reason changes or the task runs.
+skipped,
+failed,
+                    ;
+                    
+                }
+
+/// When and under which conditions a task runs
+class TaskSchedule  {
+                final ScheduledTask task;
+final bool enabled;
+/// Seconds between two runs, at least 60
+final int intervalSecs;
+/// Up to this many seconds are added at random to each interval, so many devices, or many
+/// tasks, do not all contact the same servers at once
+final int jitterSecs;
+/// Run only on Wi-Fi or Ethernet
+final bool unmeteredOnly;
+/// Run only while the device is charging
+final bool chargingOnly;
+
+                const TaskSchedule({required this.task ,required this.enabled ,required this.intervalSecs ,required this.jitterSecs ,required this.unmeteredOnly ,required this.chargingOnly ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => task.hashCode^enabled.hashCode^intervalSecs.hashCode^jitterSecs.hashCode^unmeteredOnly.hashCode^chargingOnly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TaskSchedule &&
+                runtimeType == other.runtimeType
+                && task == other.task&& enabled == other.enabled&& intervalSecs == other.intervalSecs&& jitterSecs == other.jitterSecs&& unmeteredOnly == other.unmeteredOnly&& chargingOnly == other.chargingOnly;
+        
+            }
+            
\ No newline at end of file
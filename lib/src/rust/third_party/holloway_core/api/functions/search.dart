@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A search engine and how to ask it
+class SearchProvider  {
+                final String name;
+/// URL with `%s` where the query goes
+final String urlTemplate;
+
+                const SearchProvider({required this.name ,required this.urlTemplate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^urlTemplate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchProvider &&
+                runtimeType == other.runtimeType
+                && name == other.name&& urlTemplate == other.urlTemplate;
+        
+            }
+            
\ No newline at end of file
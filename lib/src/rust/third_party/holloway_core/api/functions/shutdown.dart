@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// What `shutdown` stopped
+class ShutdownSummary  {
+                final int streamsCancelled;
+final int fetchesAborted;
+/// Navigations whose sockets were shut down, including ones awaited by the caller
+final int requestsCancelled;
+/// Capsule mirrors stopped after their current file; mirroring again resumes them
+final int mirrorsCancelled;
+
+                const ShutdownSummary({required this.streamsCancelled ,required this.fetchesAborted ,required this.requestsCancelled ,required this.mirrorsCancelled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => streamsCancelled.hashCode^fetchesAborted.hashCode^requestsCancelled.hashCode^mirrorsCancelled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShutdownSummary &&
+                runtimeType == other.runtimeType
+                && streamsCancelled == other.streamsCancelled&& fetchesAborted == other.fetchesAborted&& requestsCancelled == other.requestsCancelled&& mirrorsCancelled == other.mirrorsCancelled;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'links.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// The menus and documents of a gopher hole, as a tree flattened in menu order: each node is
+/// followed by the items of its menu, so a sidebar shows them indented by depth
+class SiteMap  {
+                /// The hole's root, e.g. gopher://sdf.org/
+final String root;
+final List<SiteNode> nodes;
+/// Set when menus were left unfetched once 300 were
+final bool truncated;
+
+                const SiteMap({required this.root ,required this.nodes ,required this.truncated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => root.hashCode^nodes.hashCode^truncated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SiteMap &&
+                runtimeType == other.runtimeType
+                && root == other.root&& nodes == other.nodes&& truncated == other.truncated;
+        
+            }
+
+/// An item of a site map
+class SiteNode  {
+                final String url;
+/// The item's text in the menu that lists it, or the URL for the start menu
+final String title;
+final LinkKind kind;
+/// Icon the UI shows next to the item, e.g. "text", "folder", or "search"
+final String icon;
+/// Whether the item is a menu, whose items may follow it
+final bool menu;
+/// Menus from the start menu to this item; 0 for the start menu
+final int depth;
+/// Index in `SiteMap::nodes` of the menu listing the item; None for the start menu
+final int? parent;
+/// Whether the items of a menu are listed after it; not for menus past the depth, off the
+/// hole, listed under another menu already, left over at the menu limit, or failing
+final bool expanded;
+/// Why fetching a menu failed
+final String? error;
+
+                const SiteNode({required this.url ,required this.title ,required this.kind ,required this.icon ,required this.menu ,required this.depth ,this.parent ,required this.expanded ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^kind.hashCode^icon.hashCode^menu.hashCode^depth.hashCode^parent.hashCode^expanded.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SiteNode &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& kind == other.kind&& icon == other.icon&& menu == other.menu&& depth == other.depth&& parent == other.parent&& expanded == other.expanded&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How a stream behaves while the server is quiet
+class StreamOptions  {
+                /// Seconds without data before an Idle event is delivered
+final int idleIntervalSecs;
+/// Seconds without data before giving up, or None to keep reading until cancelled
+final int? maxIdleSecs;
+
+                const StreamOptions({required this.idleIntervalSecs ,this.maxIdleSecs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => idleIntervalSecs.hashCode^maxIdleSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StreamOptions &&
+                runtimeType == other.runtimeType
+                && idleIntervalSecs == other.idleIntervalSecs&& maxIdleSecs == other.maxIdleSecs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An address bar completion from history or bookmarks
+class Suggestion  {
+                final String url;
+final String? title;
+final bool bookmarked;
+/// Visits in history, 0 for a bookmark never visited
+final int visitCount;
+final PlatformInt64? lastVisited;
+final SuggestionMatch matched;
+/// For a URL prefix match, the rest of the URL after the typed text, to fill in inline
+final String? completion;
+
+                const Suggestion({required this.url ,this.title ,required this.bookmarked ,required this.visitCount ,this.lastVisited ,required this.matched ,this.completion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^bookmarked.hashCode^visitCount.hashCode^lastVisited.hashCode^matched.hashCode^completion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Suggestion &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& bookmarked == other.bookmarked&& visitCount == other.visitCount&& lastVisited == other.lastVisited&& matched == other.matched&& completion == other.completion;
+        
+            }
+
+/// How well a suggestion matches what was typed, best last
+enum SuggestionMatch {
+                    /// The typed text appears somewhere in the URL or title
+substring,
+/// A word of the title or a label of the host starts with the typed text
+wordPrefix,
+/// The URL without its scheme starts with the typed text, or the whole URL does when the
+/// typed text includes a scheme
+urlPrefix,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Measurements of one preformatted block in a gemtext document
+class PreformattedBlock  {
+                /// Index of the opening ``` line in the document
+final int startIndex;
+final int lineCount;
+final String? alt;
+/// Widest line in terminal columns
+final int maxWidth;
+/// Whether any line contains wide (East Asian or emoji) characters
+final bool hasWideChars;
+
+                const PreformattedBlock({required this.startIndex ,required this.lineCount ,this.alt ,required this.maxWidth ,required this.hasWideChars ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startIndex.hashCode^lineCount.hashCode^alt.hashCode^maxWidth.hashCode^hasWideChars.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreformattedBlock &&
+                runtimeType == other.runtimeType
+                && startIndex == other.startIndex&& lineCount == other.lineCount&& alt == other.alt&& maxWidth == other.maxWidth&& hasWideChars == other.hasWideChars;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A frequently and recently visited page or host for the new-tab speed dial
+class TopSite  {
+                final String url;
+final String host;
+final String? title;
+final String? favicon;
+final int visitCount;
+final PlatformInt64 lastVisited;
+/// Frecency: each visit weighs 1, halving every two weeks
+final double score;
+
+                const TopSite({required this.url ,required this.host ,this.title ,this.favicon ,required this.visitCount ,required this.lastVisited ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^host.hashCode^title.hashCode^favicon.hashCode^visitCount.hashCode^lastVisited.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TopSite &&
+                runtimeType == other.runtimeType
+                && url == other.url&& host == other.host&& title == other.title&& favicon == other.favicon&& visitCount == other.visitCount&& lastVisited == other.lastVisited&& score == other.score;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'error_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A Gemini 6x response together with the identity that was presented, so the UI can offer
+/// switching identities for 61 and renewing the presented one for 62
+class CertificateError  {
+                final CertificateErrorKind kind;
+/// Identity presented with the request, or None when the request carried no certificate
+final String? identityId;
+/// The server's explanation from the meta line
+final String message;
+final RecoveryAction suggestedAction;
+
+                const CertificateError({required this.kind ,this.identityId ,required this.message ,required this.suggestedAction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^identityId.hashCode^message.hashCode^suggestedAction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CertificateError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& identityId == other.identityId&& message == other.message&& suggestedAction == other.suggestedAction;
+        
+            }
+
+/// Which client certificate problem a Gemini 6x response reports
+enum CertificateErrorKind {
+                    /// 60 and unassigned 6x codes: the page needs a client certificate
+required_,
+/// 61: the certificate is valid but not allowed to see the page
+notAuthorized,
+/// 62: the certificate itself was rejected, e.g. because it expired
+notValid,
+                    ;
+                    
+                }
+            
\ No newline at end of file
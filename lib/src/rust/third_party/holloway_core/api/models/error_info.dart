@@ -0,0 +1,126 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Stable identifier of a failure, independent of the English error text; each `NavigateError`
+/// variant has its own
+enum ErrorCode {
+                    urlInvalid,
+urlUnsupportedScheme,
+urlSchemeDisabled,
+urlBlocked,
+/// The host's robots.txt disallows a fetch the user did not ask for
+urlDisallowed,
+netDnsFailed,
+/// Connecting took longer than the connect timeout
+netTimeout,
+netRefused,
+netConnectFailed,
+netConnectionLost,
+/// Offline mode is on and the page has no cached copy
+netOffline,
+/// The server stopped sending for longer than the read timeout, or the request outlasted
+/// its deadline
+netReadTimeout,
+proxyUnreachable,
+proxyAuthFailed,
+proxyFailed,
+gatewayFailed,
+/// A response outgrew the body limit or the decompression limits
+contentTooLarge,
+tlsHandshakeFailed,
+tlsFingerprintMismatch,
+/// The server certificate is expired or not yet valid by more than the clock skew tolerance
+tlsCertificateDate,
+/// Strict validation refused the server certificate, e.g. self-signed or for another host
+tlsCertificateInvalid,
+storeFailed,
+/// The identity to present could not be used, e.g. while the key store is locked
+identityFailed,
+notFound,
+inputInvalid,
+/// The caller cancelled the request
+cancelled,
+/// The server answered something that is not valid for its protocol
+responseInvalid,
+/// Gemini 1x
+gemInputRequired,
+/// Gemini 3x
+gemRedirect,
+/// Gemini 4x and 5x
+gemServerError,
+/// Gemini 6x
+gemCertificateRequired,
+/// A Gemini server stopped sending for longer than the read timeout
+gemTimeout,
+/// The Gopher item type names no document to fetch, e.g. a telnet session or an info line
+gopherBadType,
+/// The Gopher selector would span lines
+gopherBadSelector,
+unknown,
+                    ;
+                    
+                }
+
+/// An error message with its stable code, for localized messages and recovery actions
+class ErrorInfo  {
+                final ErrorCode code;
+/// `code` as its stable string, e.g. "NET_TIMEOUT"
+final String name;
+/// `code` as its stable number, e.g. 201
+final int number;
+/// The original English message
+final String message;
+final bool retryable;
+final bool userActionable;
+final RecoveryAction? suggestedAction;
+
+                const ErrorInfo({required this.code ,required this.name ,required this.number ,required this.message ,required this.retryable ,required this.userActionable ,this.suggestedAction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^name.hashCode^number.hashCode^message.hashCode^retryable.hashCode^userActionable.hashCode^suggestedAction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ErrorInfo &&
+                runtimeType == other.runtimeType
+                && code == other.code&& name == other.name&& number == other.number&& message == other.message&& retryable == other.retryable&& userActionable == other.userActionable&& suggestedAction == other.suggestedAction;
+        
+            }
+
+/// What the UI can offer the user after a failure
+enum RecoveryAction {
+                    /// Try the same request again
+retry,
+/// Make an exception for what refused the URL: a blocklist rule or a changed certificate
+addException,
+/// Turn the disabled protocol back on
+enableProtocol,
+/// Ask the user for the input a Gemini 1x response requested
+provideInput,
+/// Let the user present a client certificate for a Gemini 6x response, or switch to another
+chooseIdentity,
+/// Issue a new certificate for the identity the server found invalid, e.g. because it expired
+renewIdentity,
+/// Open the proxy settings
+checkProxy,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Per-request switches for the navigation pipeline
+class FetchOptions  {
+                /// Private (incognito) request: nothing about it is written to the profile database, so
+/// certificates are checked against the TOFU pins without pinning new ones, and no
+/// identity is presented but the one named in `identity_id`.
+/// Tabs opened in private mode pass this on every navigation.
+final bool private;
+/// Serve a fresh cached copy when there is one, e.g. for back and forward navigation
+final bool useCache;
+/// The caller already shows this page (pull-to-refresh): when the refetched body matches
+/// the cached copy, return it with `unchanged` set instead of sending the body again
+final bool revalidate;
+/// When a bare host typed without a scheme refuses Gemini connections, try Gopher and
+/// then Finger on it before falling back to search
+final bool probeProtocols;
+/// Requested by a background task rather than the user, e.g. a cache refresh: the response
+/// is cached but no visit is added to history
+final bool background;
+/// Identity (client certificate) to present, for protocols that support them
+final String? identityId;
+/// Seconds to wait for a connection, or None for the default of 10
+final int? connectTimeoutSecs;
+/// Seconds to wait for data once connected, or None to wait as long as the server keeps
+/// the connection open
+final int? readTimeoutSecs;
+/// Seconds the whole request may take, redirects and protocol probes included
+final int? deadlineSecs;
+/// Bytes of body the response may have before the request fails, or None for no limit
+final BigInt? maxBodyBytes;
+
+                const FetchOptions({required this.private ,required this.useCache ,required this.revalidate ,required this.probeProtocols ,required this.background ,this.identityId ,this.connectTimeoutSecs ,this.readTimeoutSecs ,this.deadlineSecs ,this.maxBodyBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => private.hashCode^useCache.hashCode^revalidate.hashCode^probeProtocols.hashCode^background.hashCode^identityId.hashCode^connectTimeoutSecs.hashCode^readTimeoutSecs.hashCode^deadlineSecs.hashCode^maxBodyBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FetchOptions &&
+                runtimeType == other.runtimeType
+                && private == other.private&& useCache == other.useCache&& revalidate == other.revalidate&& probeProtocols == other.probeProtocols&& background == other.background&& identityId == other.identityId&& connectTimeoutSecs == other.connectTimeoutSecs&& readTimeoutSecs == other.readTimeoutSecs&& deadlineSecs == other.deadlineSecs&& maxBodyBytes == other.maxBodyBytes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../functions/content_router.dart';
+import '../functions/feed_discovery.dart';
+import 'certificate_error.dart';
+import 'error_info.dart';
+import 'navigation_result.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'server_failure.dart';
+
+
+            
+
+            
+
+            /// A fetched page together with the metadata the UI needs to render it
+class FetchResponse  {
+                final String url;
+/// URLs that answered with a redirect on the way to `url`, in the order they were
+/// followed and starting with the requested one; empty when no redirect was followed
+final List<String> redirects;
+/// Protocol status code, for protocols that have one (Gemini)
+final int? status;
+/// Raw meta/header text accompanying the status
+final String? meta;
+final String mime;
+/// How the UI should present the body, decided from the scheme, MIME type, and item type
+final Renderer renderer;
+final String body;
+/// SHA-256 of the body as lowercase hex, for detecting unchanged pages
+final String bodyHash;
+/// Set on revalidation when the body matched the cached copy; `body` is then left empty
+final bool unchanged;
+/// Set when the server kept the connection open and the body is what arrived before it
+/// went quiet or the read deadline passed, so it may be incomplete
+final bool truncated;
+/// Served from the cache after its expiry, e.g. while offline; `cached_at` tells how old
+final bool stale;
+/// Page title taken from the first gemtext heading
+final String? title;
+final List<FeedCandidate> feeds;
+/// Cached /favicon.txt emoji of the capsule, for its tab and bookmarks
+final String? favicon;
+/// The #fragment of the requested URL, which is never sent to the server
+final String? fragment;
+/// Document line the fragment resolved to, for the UI to scroll to
+final int? fragmentLine;
+/// When the response was fetched, if it was served from the cache
+final PlatformInt64? cachedAt;
+/// Request id of the navigation events published for this fetch
+final BigInt requestId;
+/// Host to show in the address bar, in punycode when it may impersonate another host
+final String displayHost;
+/// Set when the host mixes scripts or looks like a host the user has visited before
+final bool spoofingWarning;
+/// Set when the server certificate appeared expired or not yet valid and was accepted only
+/// because a wrong device clock may explain it
+final bool clockSkewWarning;
+/// Scheme that answered when a bare host was probed after Gemini could not connect
+final String? probedScheme;
+/// Set for Gemini 6x responses: which certificate problem it was and what was presented
+final CertificateError? certificateError;
+/// Set for Gemini 4x and 5x responses, whose body is then a generated error page
+final ServerFailure? serverFailure;
+/// The server certificate the response came over, when it was fetched over TLS
+final CertificateSummary? certificate;
+/// Milliseconds from the start of the navigation until the response was complete
+final BigInt durationMs;
+/// How `duration_ms` divides into resolving, connecting, waiting, and transferring
+final RequestTimings timings;
+
+                const FetchResponse({required this.url ,required this.redirects ,this.status ,this.meta ,required this.mime ,required this.renderer ,required this.body ,required this.bodyHash ,required this.unchanged ,required this.truncated ,required this.stale ,this.title ,required this.feeds ,this.favicon ,this.fragment ,this.fragmentLine ,this.cachedAt ,required this.requestId ,required this.displayHost ,required this.spoofingWarning ,required this.clockSkewWarning ,this.probedScheme ,this.certificateError ,this.serverFailure ,this.certificate ,required this.durationMs ,required this.timings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^redirects.hashCode^status.hashCode^meta.hashCode^mime.hashCode^renderer.hashCode^body.hashCode^bodyHash.hashCode^unchanged.hashCode^truncated.hashCode^stale.hashCode^title.hashCode^feeds.hashCode^favicon.hashCode^fragment.hashCode^fragmentLine.hashCode^cachedAt.hashCode^requestId.hashCode^displayHost.hashCode^spoofingWarning.hashCode^clockSkewWarning.hashCode^probedScheme.hashCode^certificateError.hashCode^serverFailure.hashCode^certificate.hashCode^durationMs.hashCode^timings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FetchResponse &&
+                runtimeType == other.runtimeType
+                && url == other.url&& redirects == other.redirects&& status == other.status&& meta == other.meta&& mime == other.mime&& renderer == other.renderer&& body == other.body&& bodyHash == other.bodyHash&& unchanged == other.unchanged&& truncated == other.truncated&& stale == other.stale&& title == other.title&& feeds == other.feeds&& favicon == other.favicon&& fragment == other.fragment&& fragmentLine == other.fragmentLine&& cachedAt == other.cachedAt&& requestId == other.requestId&& displayHost == other.displayHost&& spoofingWarning == other.spoofingWarning&& clockSkewWarning == other.clockSkewWarning&& probedScheme == other.probedScheme&& certificateError == other.certificateError&& serverFailure == other.serverFailure&& certificate == other.certificate&& durationMs == other.durationMs&& timings == other.timings;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One event published on the navigation event bus
+class NavigationEvent  {
+                /// Identifies the request across all of its events
+final BigInt requestId;
+final NavigationEventKind kind;
+final String url;
+final int? status;
+final String? meta;
+/// Body bytes received so far
+final BigInt bytes;
+final String? message;
+
+                const NavigationEvent({required this.requestId ,required this.kind ,required this.url ,this.status ,this.meta ,required this.bytes ,this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => requestId.hashCode^kind.hashCode^url.hashCode^status.hashCode^meta.hashCode^bytes.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NavigationEvent &&
+                runtimeType == other.runtimeType
+                && requestId == other.requestId&& kind == other.kind&& url == other.url&& status == other.status&& meta == other.meta&& bytes == other.bytes&& message == other.message;
+        
+            }
+
+/// Stage of a navigation reported on the event bus
+enum NavigationEventKind {
+                    /// The request was accepted and is about to be resolved
+navigationStarted,
+/// The host name of a new connection is being looked up
+resolving,
+/// A connection is being opened; `message` is the address when the host was looked up
+connecting,
+/// The connection is open and its TLS handshake started
+tlsHandshake,
+/// A redirect was followed automatically; `url` is the new target
+redirectFollowed,
+/// The response header arrived; `status` and `meta` are set
+headerReceived,
+/// More of the body arrived; `bytes` is the running total
+bodyProgress,
+/// The response is complete
+navigationFinished,
+/// The request stopped with an error; `message` is set
+navigationFailed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,183 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../functions/content_router.dart';
+import 'error_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'server_failure.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+
+            
+
+            /// The server certificate a navigation was made over, as it was checked against the pin
+class CertificateSummary  {
+                /// SHA-256 of the certificate's public key as lowercase hex, as pinned in known_hosts
+final String fingerprint;
+final String subject;
+final String issuer;
+final PlatformInt64 notBefore;
+final PlatformInt64 notAfter;
+/// When a certificate was first pinned for the host
+final PlatformInt64 firstSeen;
+final PinStatus pinStatus;
+final PinExpiry expiry;
+/// TLS version the connection negotiated, e.g. "TLS 1.3"
+final String? tlsVersion;
+/// Cipher suite the connection negotiated, by its IANA name
+final String? cipherSuite;
+
+                const CertificateSummary({required this.fingerprint ,required this.subject ,required this.issuer ,required this.notBefore ,required this.notAfter ,required this.firstSeen ,required this.pinStatus ,required this.expiry ,this.tlsVersion ,this.cipherSuite ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fingerprint.hashCode^subject.hashCode^issuer.hashCode^notBefore.hashCode^notAfter.hashCode^firstSeen.hashCode^pinStatus.hashCode^expiry.hashCode^tlsVersion.hashCode^cipherSuite.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CertificateSummary &&
+                runtimeType == other.runtimeType
+                && fingerprint == other.fingerprint&& subject == other.subject&& issuer == other.issuer&& notBefore == other.notBefore&& notAfter == other.notAfter&& firstSeen == other.firstSeen&& pinStatus == other.pinStatus&& expiry == other.expiry&& tlsVersion == other.tlsVersion&& cipherSuite == other.cipherSuite;
+        
+            }
+
+/// A finished navigation with the metadata history, the address bar, and the renderer share
+class NavigationResult  {
+                /// The URL that answered, after any redirects; the base for relative links
+final String url;
+/// URLs that answered with a redirect on the way to `url`, in the order they were
+/// followed and starting with the requested one; empty when no redirect was followed
+final List<String> redirects;
+/// Scheme of the final URL
+final String protocol;
+/// Protocol status code, for protocols that have one (Gemini)
+final int? status;
+final String mime;
+/// Character set from the MIME type, or the protocol's default when it names none
+final String? charset;
+final Renderer renderer;
+final String? title;
+/// Emoji from the capsule's /favicon.txt, for the tab; None until it has been fetched
+final String? favicon;
+final String body;
+/// Set when the server kept the connection open and the body may be incomplete
+final bool truncated;
+/// When the body was fetched, if it was served from the cache
+final PlatformInt64? cachedAt;
+/// Served from the cache after its expiry, e.g. while offline or the host was unreachable
+final bool stale;
+/// Milliseconds from the start of the navigation until the response was complete
+final BigInt durationMs;
+/// How `duration_ms` divides into resolving, connecting, waiting, and transferring
+final RequestTimings timings;
+/// Set when the response came over TLS from the network rather than from the cache
+final CertificateSummary? certificate;
+/// Set for Gemini 4x and 5x responses, whose body is then a generated error page with retry
+/// and cached copy links
+final ServerFailure? serverFailure;
+/// Request id of the navigation events and trace of this navigation
+final BigInt requestId;
+
+                const NavigationResult({required this.url ,required this.redirects ,required this.protocol ,this.status ,required this.mime ,this.charset ,required this.renderer ,this.title ,this.favicon ,required this.body ,required this.truncated ,this.cachedAt ,required this.stale ,required this.durationMs ,required this.timings ,this.certificate ,this.serverFailure ,required this.requestId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^redirects.hashCode^protocol.hashCode^status.hashCode^mime.hashCode^charset.hashCode^renderer.hashCode^title.hashCode^favicon.hashCode^body.hashCode^truncated.hashCode^cachedAt.hashCode^stale.hashCode^durationMs.hashCode^timings.hashCode^certificate.hashCode^serverFailure.hashCode^requestId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NavigationResult &&
+                runtimeType == other.runtimeType
+                && url == other.url&& redirects == other.redirects&& protocol == other.protocol&& status == other.status&& mime == other.mime&& charset == other.charset&& renderer == other.renderer&& title == other.title&& favicon == other.favicon&& body == other.body&& truncated == other.truncated&& cachedAt == other.cachedAt&& stale == other.stale&& durationMs == other.durationMs&& timings == other.timings&& certificate == other.certificate&& serverFailure == other.serverFailure&& requestId == other.requestId;
+        
+            }
+
+/// How near a server certificate is to the end of its validity. A capsule usually replaces its
+/// certificate around then, and the replacement is refused while the pin is still valid until
+/// the user accepts it with `accept_new_certificate`.
+enum PinExpiry {
+                    valid,
+/// Expires within 30 days
+expiringSoon,
+/// Past its expiry, accepted because a wrong device clock may explain it or the host's
+/// settings skip verification
+expired,
+                    ;
+                    
+                }
+
+/// How a server certificate compared with the one pinned for its host
+enum PinStatus {
+                    /// No certificate was pinned for the host, and this one now is
+firstUse,
+/// The certificate is the pinned one
+matched,
+/// The certificate differs from the pinned one and replaced it, because the pin had expired
+/// or the host's settings accept changed certificates
+replaced,
+/// The host's settings skip verification, so the certificate was not checked against a pin
+unverified,
+/// The host's settings are strict, and a certificate authority in the user trust store
+/// issued the certificate, which is not pinned
+caVerified,
+                    ;
+                    
+                }
+
+/// Where the time of a navigation went, measured on the connection that answered; phases the
+/// connection did not go through, e.g. resolving a host a proxy resolved, are None, and a
+/// response from the cache has none at all
+class RequestTimings  {
+                /// Resolving the host name to an address
+final BigInt? dnsMs;
+/// Opening the TCP connection, or the connection through the proxy or gateway
+final BigInt? connectMs;
+final BigInt? tlsMs;
+/// From the connection being ready until the first byte of the response arrived
+final BigInt? firstByteMs;
+/// From the first byte until the response was complete
+final BigInt? transferMs;
+/// Bytes of the request line sent to the server
+final BigInt bytesSent;
+/// Bytes of the header and body received from the server
+final BigInt bytesReceived;
+
+                const RequestTimings({this.dnsMs ,this.connectMs ,this.tlsMs ,this.firstByteMs ,this.transferMs ,required this.bytesSent ,required this.bytesReceived ,});
+
+                static Future<RequestTimings>  default_()=>RustLib.instance.api.hollowayCoreApiModelsNavigationResultRequestTimingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => dnsMs.hashCode^connectMs.hashCode^tlsMs.hashCode^firstByteMs.hashCode^transferMs.hashCode^bytesSent.hashCode^bytesReceived.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequestTimings &&
+                runtimeType == other.runtimeType
+                && dnsMs == other.dnsMs&& connectMs == other.connectMs&& tlsMs == other.tlsMs&& firstByteMs == other.firstByteMs&& transferMs == other.transferMs&& bytesSent == other.bytesSent&& bytesReceived == other.bytesReceived;
+        
+            }
+            
\ No newline at end of file
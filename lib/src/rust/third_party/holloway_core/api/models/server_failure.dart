@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'error_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A Gemini 4x or 5x response, so the UI can tell a page that may come back from one that
+/// will not, and when it is worth trying again
+class ServerFailure  {
+                final int status;
+final ServerFailureKind kind;
+/// The server's explanation from the meta line
+final String message;
+/// 4x: the same request may succeed later
+final bool temporary;
+/// Seconds the server asked to wait before trying again (44, slow down)
+final int? retryAfterSecs;
+final RecoveryAction? suggestedAction;
+
+                const ServerFailure({required this.status ,required this.kind ,required this.message ,required this.temporary ,this.retryAfterSecs ,this.suggestedAction ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => status.hashCode^kind.hashCode^message.hashCode^temporary.hashCode^retryAfterSecs.hashCode^suggestedAction.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServerFailure &&
+                runtimeType == other.runtimeType
+                && status == other.status&& kind == other.kind&& message == other.message&& temporary == other.temporary&& retryAfterSecs == other.retryAfterSecs&& suggestedAction == other.suggestedAction;
+        
+            }
+
+/// Which failure a Gemini 4x or 5x response reports
+enum ServerFailureKind {
+                    /// 40 and unassigned 4x codes
+temporaryFailure,
+/// 41: the server is down for maintenance or overloaded
+serverUnavailable,
+/// 42: the program generating the page failed
+cgiError,
+/// 43: the server could not complete a request it proxies
+proxyError,
+/// 44: the client asks too often and should wait the seconds in the meta line
+slowDown,
+/// 50 and unassigned 5x codes
+permanentFailure,
+/// 51
+notFound,
+/// 52: the page was removed for good
+gone,
+/// 53: the server does not serve the requested host or protocol
+proxyRequestRefused,
+/// 59: the server could not parse the request
+badRequest,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../api/mirrors.dart';
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One event delivered by a streaming fetch
+class StreamEvent  {
+                final StreamEventKind kind;
+final int? status;
+final String? meta;
+final String? text;
+/// The line parsed as gemtext, so a long document renders while it is still downloading
+final GemtextLine? element;
+/// Total bytes read from the server so far
+final BigInt bytesReceived;
+
+                const StreamEvent({required this.kind ,this.status ,this.meta ,this.text ,this.element ,required this.bytesReceived ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^status.hashCode^meta.hashCode^text.hashCode^element.hashCode^bytesReceived.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StreamEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& status == other.status&& meta == other.meta&& text == other.text&& element == other.element&& bytesReceived == other.bytesReceived;
+        
+            }
+
+/// What a stream event carries
+enum StreamEventKind {
+                    /// The response header arrived; `status` and `meta` are set
+header,
+/// A complete body line arrived; `text` is set, and `element` for a text/gemini body
+line,
+/// Nothing arrived for the idle interval but the connection is still open
+idle,
+/// The server closed the connection or the stream was cancelled
+finished,
+/// The stream stopped because of an error; `text` is set
+failed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A server certificate refused by strict validation, with what the certificate says, so the
+/// security warning can explain the problem rather than report a failed connection
+class TlsFailure  {
+                final TlsFailureKind kind;
+final String host;
+/// The problem in plain words, e.g. "the certificate is for example.org, not example.com"
+final String message;
+/// DNS names and IP addresses the certificate is valid for
+final List<String> names;
+final String subject;
+final String issuer;
+/// Start of the validity period, in seconds since the Unix epoch
+final PlatformInt64 notBefore;
+/// End of the validity period, in seconds since the Unix epoch
+final PlatformInt64 notAfter;
+/// Certificates the server sent after its own
+final int intermediates;
+
+                const TlsFailure({required this.kind ,required this.host ,required this.message ,required this.names ,required this.subject ,required this.issuer ,required this.notBefore ,required this.notAfter ,required this.intermediates ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^host.hashCode^message.hashCode^names.hashCode^subject.hashCode^issuer.hashCode^notBefore.hashCode^notAfter.hashCode^intermediates.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TlsFailure &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& host == other.host&& message == other.message&& names == other.names&& subject == other.subject&& issuer == other.issuer&& notBefore == other.notBefore&& notAfter == other.notAfter&& intermediates == other.intermediates;
+        
+            }
+
+/// Why a server certificate failed validation against the user trust store
+enum TlsFailureKind {
+                    /// The certificate's validity period has ended
+expired,
+/// The certificate's validity period has not started
+notYetValid,
+/// The certificate signed itself and is not a trusted CA
+selfSigned,
+/// None of the certificate's names (its subject alternative names) is the host's
+nameMismatch,
+/// The server sent no chain up to a self-signed root, so the certificate cannot be traced to
+/// a trusted CA; usually an intermediate certificate missing from the server's configuration
+incompleteChain,
+/// The chain ends at a root CA that is not in the user trust store
+untrustedIssuer,
+/// The issuer revoked the certificate
+revoked,
+/// A bad signature, an unsupported algorithm, an unreadable certificate, and the like
+other,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A single item of a Gopher menu (RFC 1436)
+class GopherItem  {
+                final String itemType;
+final String display;
+final String selector;
+final String host;
+final int port;
+/// Human-readable item kind, e.g. "Text file" or "Directory"
+final String kind;
+/// Icon the UI shows next to the item, e.g. "text", "folder", or "search"
+final String icon;
+/// Whether Holloway can open or download the item itself; informational lines, errors,
+/// telnet sessions, and web links are not. Phone books, like searches, need a query
+final bool fetchable;
+
+                const GopherItem({required this.itemType ,required this.display ,required this.selector ,required this.host ,required this.port ,required this.kind ,required this.icon ,required this.fetchable ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => itemType.hashCode^display.hashCode^selector.hashCode^host.hashCode^port.hashCode^kind.hashCode^icon.hashCode^fetchable.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GopherItem &&
+                runtimeType == other.runtimeType
+                && itemType == other.itemType&& display == other.display&& selector == other.selector&& host == other.host&& port == other.port&& kind == other.kind&& icon == other.icon&& fetchable == other.fetchable;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A field of a phone book entry, e.g. "name" or "email"
+class CsoField  {
+                final String name;
+/// The value, with continuation lines joined by newlines
+final String value;
+
+                const CsoField({required this.name ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CsoField &&
+                runtimeType == other.runtimeType
+                && name == other.name&& value == other.value;
+        
+            }
+
+/// One entry a CSO phone book returned
+class CsoRecord  {
+                /// Position of the entry among the matches, starting at 1
+final int index;
+final List<CsoField> fields;
+
+                const CsoRecord({required this.index ,required this.fields ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^fields.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CsoRecord &&
+                runtimeType == other.runtimeType
+                && index == other.index&& fields == other.fields;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Optional features a protocol supports, so the UI can adapt its controls
+class ProtocolCapabilities  {
+                /// The server can ask the user for input (Gemini status 1x, Gopher search items)
+final bool userInput;
+/// The server can redirect to another URL
+final bool redirects;
+/// Identities (client certificates) can be presented
+final bool clientCertificates;
+/// Responses are menus of typed items rather than documents
+final bool menus;
+
+                const ProtocolCapabilities({required this.userInput ,required this.redirects ,required this.clientCertificates ,required this.menus ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userInput.hashCode^redirects.hashCode^clientCertificates.hashCode^menus.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProtocolCapabilities &&
+                runtimeType == other.runtimeType
+                && userInput == other.userInput&& redirects == other.redirects&& clientCertificates == other.clientCertificates&& menus == other.menus;
+        
+            }
+
+/// Summary of a registered protocol exposed to the UI
+class ProtocolInfo  {
+                final String scheme;
+final int defaultPort;
+final bool requiresTls;
+final ProtocolCapabilities capabilities;
+/// False when the user has turned the protocol off
+final bool enabled;
+
+                const ProtocolInfo({required this.scheme ,required this.defaultPort ,required this.requiresTls ,required this.capabilities ,required this.enabled ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scheme.hashCode^defaultPort.hashCode^requiresTls.hashCode^capabilities.hashCode^enabled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProtocolInfo &&
+                runtimeType == other.runtimeType
+                && scheme == other.scheme&& defaultPort == other.defaultPort&& requiresTls == other.requiresTls&& capabilities == other.capabilities&& enabled == other.enabled;
+        
+            }
+            
\ No newline at end of file
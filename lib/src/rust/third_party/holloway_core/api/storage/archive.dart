@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Summary of an archived snapshot, without its body
+class ArchivedPage  {
+                final PlatformInt64 id;
+final String url;
+/// Protocol status code the page was served with, for protocols that have one (Gemini)
+final int? status;
+final String mime;
+final String? title;
+/// Body size in bytes
+final BigInt size;
+/// SHA-256 of the body as lowercase hex, e.g. to tell whether two snapshots differ
+final String bodyHash;
+/// Fingerprint of the server certificate the page came over, when it was fetched over TLS
+final String? certificateFingerprint;
+/// When the page was fetched from the server, seconds since the Unix epoch
+final PlatformInt64 fetchedAt;
+final PlatformInt64 archivedAt;
+
+                const ArchivedPage({required this.id ,required this.url ,this.status ,required this.mime ,this.title ,required this.size ,required this.bodyHash ,this.certificateFingerprint ,required this.fetchedAt ,required this.archivedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^status.hashCode^mime.hashCode^title.hashCode^size.hashCode^bodyHash.hashCode^certificateFingerprint.hashCode^fetchedAt.hashCode^archivedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ArchivedPage &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& status == other.status&& mime == other.mime&& title == other.title&& size == other.size&& bodyHash == other.bodyHash&& certificateFingerprint == other.certificateFingerprint&& fetchedAt == other.fetchedAt&& archivedAt == other.archivedAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A single user-defined blocklist entry
+class BlockRule  {
+                final String pattern;
+final BlockRuleKind kind;
+final PlatformInt64 createdAt;
+
+                const BlockRule({required this.pattern ,required this.kind ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pattern.hashCode^kind.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BlockRule &&
+                runtimeType == other.runtimeType
+                && pattern == other.pattern&& kind == other.kind&& createdAt == other.createdAt;
+        
+            }
+
+/// How a blocklist pattern is matched against a URL
+enum BlockRuleKind {
+                    /// Matches one host exactly, e.g. "spam.example"
+exactHost,
+/// Matches a domain and all of its subdomains, written "*.example.org"
+subdomains,
+/// Matches every URL starting with the pattern, e.g. "gemini://host/path/"
+urlPrefix,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Result of `add_bookmark`
+class AddedBookmark  {
+                final Bookmark bookmark;
+/// Set when the URL was already bookmarked, possibly spelled differently; `bookmark` is then
+/// the existing entry, left unchanged for the UI to merge into
+final bool duplicate;
+
+                const AddedBookmark({required this.bookmark ,required this.duplicate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bookmark.hashCode^duplicate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AddedBookmark &&
+                runtimeType == other.runtimeType
+                && bookmark == other.bookmark&& duplicate == other.duplicate;
+        
+            }
+
+/// A page the user bookmarked
+class Bookmark  {
+                final PlatformInt64 id;
+final String url;
+final String? title;
+/// Seconds since the Unix epoch
+final PlatformInt64 createdAt;
+/// Cached /favicon.txt emoji of the bookmarked capsule
+final String? favicon;
+
+                const Bookmark({required this.id ,required this.url ,this.title ,required this.createdAt ,this.favicon ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^title.hashCode^createdAt.hashCode^favicon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Bookmark &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& title == other.title&& createdAt == other.createdAt&& favicon == other.favicon;
+        
+            }
+            
\ No newline at end of file
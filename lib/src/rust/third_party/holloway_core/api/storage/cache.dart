@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Summary of one cached response, without its body
+class CacheEntry  {
+                final String url;
+final String host;
+final String mime;
+/// Body size in bytes
+final BigInt size;
+/// Seconds since the Unix epoch
+final PlatformInt64 fetchedAt;
+final PlatformInt64 expiresAt;
+/// Past its expiry, so navigation fetches the page again instead of serving it
+final bool stale;
+
+                const CacheEntry({required this.url ,required this.host ,required this.mime ,required this.size ,required this.fetchedAt ,required this.expiresAt ,required this.stale ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^host.hashCode^mime.hashCode^size.hashCode^fetchedAt.hashCode^expiresAt.hashCode^stale.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CacheEntry &&
+                runtimeType == other.runtimeType
+                && url == other.url&& host == other.host&& mime == other.mime&& size == other.size&& fetchedAt == other.fetchedAt&& expiresAt == other.expiresAt&& stale == other.stale;
+        
+            }
+
+/// Whether and for how long responses of one scheme are cached
+class CachePolicy  {
+                final String scheme;
+final bool cacheable;
+final int ttlSecs;
+/// Also cache URLs with a query string, which usually name search results or input replies
+final bool cacheQueries;
+
+                const CachePolicy({required this.scheme ,required this.cacheable ,required this.ttlSecs ,required this.cacheQueries ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scheme.hashCode^cacheable.hashCode^ttlSecs.hashCode^cacheQueries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CachePolicy &&
+                runtimeType == other.runtimeType
+                && scheme == other.scheme&& cacheable == other.cacheable&& ttlSecs == other.ttlSecs&& cacheQueries == other.cacheQueries;
+        
+            }
+            
\ No newline at end of file
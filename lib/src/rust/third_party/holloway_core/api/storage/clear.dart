@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How much was removed from one category
+class ClearedData  {
+                final DataCategory category;
+final int removed;
+
+                const ClearedData({required this.category ,required this.removed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => category.hashCode^removed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClearedData &&
+                runtimeType == other.runtimeType
+                && category == other.category&& removed == other.removed;
+        
+            }
+
+/// A kind of locally stored browsing data that can be cleared
+enum DataCategory {
+                    history,
+/// Cached page responses
+cache,
+/// Daily traffic totals per network type
+dataUsage,
+/// The download history; downloaded files stay on disk
+downloads,
+/// Cached /favicon.txt emoji of visited capsules
+favicons,
+/// Per-host request counts, traffic, and latency
+hostStats,
+/// Answers given to input prompts, such as search terms, which live on in the query of the
+/// answered URL: the visits to and cached copies of URLs with a query, and saved answers
+inputAnswers,
+/// Identities activated for URL prefixes, the Gemini counterpart of cookies; the identities
+/// themselves are kept
+identityActivations,
+/// Client certificates; losing them can lock the user out of capsule accounts
+identities,
+/// Trusted server certificates; without them the next certificate of every server is trusted blindly
+knownHosts,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Traffic of one day on one network type
+class DailyUsage  {
+                /// Start of the UTC day, in seconds since the Unix epoch
+final PlatformInt64 dayStart;
+final NetworkType network;
+final BigInt bytesSent;
+final BigInt bytesReceived;
+
+                const DailyUsage({required this.dayStart ,required this.network ,required this.bytesSent ,required this.bytesReceived ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dayStart.hashCode^network.hashCode^bytesSent.hashCode^bytesReceived.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DailyUsage &&
+                runtimeType == other.runtimeType
+                && dayStart == other.dayStart&& network == other.network&& bytesSent == other.bytesSent&& bytesReceived == other.bytesReceived;
+        
+            }
+
+/// Kind of connection the device is on, as reported by the app
+enum NetworkType {
+                    wifi,
+/// Mobile data, usually metered
+cellular,
+ethernet,
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
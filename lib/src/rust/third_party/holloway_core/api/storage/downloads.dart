@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Whether a download matched the checksum published alongside it
+enum ChecksumStatus {
+                    /// No checksum was given to check against
+unchecked,
+/// The file's SHA-256 is the one the checksum file gives
+verified,
+/// The file's SHA-256 differs: it was corrupted or tampered with
+mismatch,
+/// The checksum file could not be fetched or gave no hash for the file
+unavailable,
+                    ;
+                    
+                }
+
+/// A finished download, kept so the downloads screen survives restarts
+class DownloadRecord  {
+                final PlatformInt64 id;
+final String url;
+/// Where the file was (or would have been) written
+final String path;
+/// Bytes written to disk
+final BigInt size;
+final String mime;
+final DownloadStatus status;
+/// Why a failed download stopped
+final String? error;
+/// Seconds since the Unix epoch
+final PlatformInt64 finishedAt;
+/// The checksum file the download was checked against
+final String? checksumUrl;
+final ChecksumStatus checksum;
+
+                const DownloadRecord({required this.id ,required this.url ,required this.path ,required this.size ,required this.mime ,required this.status ,this.error ,required this.finishedAt ,this.checksumUrl ,required this.checksum ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^path.hashCode^size.hashCode^mime.hashCode^status.hashCode^error.hashCode^finishedAt.hashCode^checksumUrl.hashCode^checksum.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DownloadRecord &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& path == other.path&& size == other.size&& mime == other.mime&& status == other.status&& error == other.error&& finishedAt == other.finishedAt&& checksumUrl == other.checksumUrl&& checksum == other.checksum;
+        
+            }
+
+/// How a download ended
+enum DownloadStatus {
+                    completed,
+failed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
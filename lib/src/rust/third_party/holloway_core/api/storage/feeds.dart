@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import '../functions/feed_discovery.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A feed the user subscribed to
+class Feed  {
+                final PlatformInt64 id;
+final String url;
+/// Title the user gave or, once fetched, the feed's own
+final String? title;
+/// Flavour of the feed, Unknown until it is first fetched
+final FeedKind kind;
+/// Seconds since the Unix epoch
+final PlatformInt64 subscribedAt;
+/// When the feed was last fetched, successfully or not
+final PlatformInt64? refreshedAt;
+/// Why the last fetch failed, cleared by a successful one
+final String? lastError;
+final int unread;
+
+                const Feed({required this.id ,required this.url ,this.title ,required this.kind ,required this.subscribedAt ,this.refreshedAt ,this.lastError ,required this.unread ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^title.hashCode^kind.hashCode^subscribedAt.hashCode^refreshedAt.hashCode^lastError.hashCode^unread.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Feed &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& title == other.title&& kind == other.kind&& subscribedAt == other.subscribedAt&& refreshedAt == other.refreshedAt&& lastError == other.lastError&& unread == other.unread;
+        
+            }
+
+/// An entry of the merged timeline of every subscription
+class FeedEntry  {
+                final PlatformInt64 id;
+final PlatformInt64 feedId;
+final String? feedTitle;
+final String url;
+final String title;
+/// When the feed dates the entry, in seconds since the Unix epoch
+final PlatformInt64? publishedAt;
+/// When the entry first appeared in a refresh
+final PlatformInt64 seenAt;
+final bool read;
+
+                const FeedEntry({required this.id ,required this.feedId ,this.feedTitle ,required this.url ,required this.title ,this.publishedAt ,required this.seenAt ,required this.read ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^feedId.hashCode^feedTitle.hashCode^url.hashCode^title.hashCode^publishedAt.hashCode^seenAt.hashCode^read.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FeedEntry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& feedId == other.feedId&& feedTitle == other.feedTitle&& url == other.url&& title == other.title&& publishedAt == other.publishedAt&& seenAt == other.seenAt&& read == other.read;
+        
+            }
+            
\ No newline at end of file
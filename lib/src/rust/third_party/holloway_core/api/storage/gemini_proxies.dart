@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Protocol spoken to the upstream server of a proxied scheme
+enum ProxyProtocol {
+                    /// A Gemini proxy, sent the full URL as an ordinary Gemini request
+gemini,
+/// A Gopher gateway, sent a selector containing the URL
+gopher,
+                    ;
+                    
+                }
+
+/// A server that fetches URLs of another scheme (e.g. http) on the browser's behalf
+class SchemeProxy  {
+                final String scheme;
+final ProxyProtocol protocol;
+final String host;
+final int port;
+/// For a Gopher gateway, the item type and selector requested, as in the path of a gopher://
+/// URL: `{url}` is replaced by the URL, which is appended when there is no placeholder.
+/// Empty for a Gemini proxy.
+final String selector;
+
+                const SchemeProxy({required this.scheme ,required this.protocol ,required this.host ,required this.port ,required this.selector ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scheme.hashCode^protocol.hashCode^host.hashCode^port.hashCode^selector.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchemeProxy &&
+                runtimeType == other.runtimeType
+                && scheme == other.scheme&& protocol == other.protocol&& host == other.host&& port == other.port&& selector == other.selector;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// One visit to a page
+class HistoryEntry  {
+                final PlatformInt64 id;
+final String url;
+final String? title;
+/// Seconds since the Unix epoch
+final PlatformInt64 visitedAt;
+
+                const HistoryEntry({required this.id ,required this.url ,this.title ,required this.visitedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^title.hashCode^visitedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryEntry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& title == other.title&& visitedAt == other.visitedAt;
+        
+            }
+
+/// How a history search query is matched against titles and URLs
+enum HistoryMatch {
+                    /// The query appears anywhere in the title or URL
+substring,
+/// The title, the URL, or the URL without its scheme starts with the query
+prefix,
+                    ;
+                    
+                }
+
+/// Visit time window of a history search, in seconds since the Unix epoch; open ends are None
+class HistoryRange  {
+                final PlatformInt64? since;
+final PlatformInt64? until;
+
+                const HistoryRange({this.since ,this.until ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => since.hashCode^until.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryRange &&
+                runtimeType == other.runtimeType
+                && since == other.since&& until == other.until;
+        
+            }
+
+/// All visits to one URL, aggregated
+class HistoryResult  {
+                final String url;
+/// Most recent title the page was seen with
+final String? title;
+final int visitCount;
+final PlatformInt64 lastVisited;
+
+                const HistoryResult({required this.url ,this.title ,required this.visitCount ,required this.lastVisited ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^visitCount.hashCode^lastVisited.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryResult &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& visitCount == other.visitCount&& lastVisited == other.lastVisited;
+        
+            }
+            
\ No newline at end of file
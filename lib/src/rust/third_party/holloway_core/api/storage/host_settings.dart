@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Overrides of navigation behaviour for one host; a None field keeps the default
+class HostSettings  {
+                final String host;
+final TlsPolicy? tlsPolicy;
+/// Identity (client certificate) presented to the host when the navigation names none
+final String? identityId;
+final int? connectTimeoutSecs;
+final int? readTimeoutSecs;
+final RedirectPolicy? redirectPolicy;
+/// Largest body accepted from the host before the navigation fails
+final BigInt? maxBodyBytes;
+/// Character set reported for the host's text responses instead of the one they declare;
+/// Gopher and Finger text, which declares none, is also decoded with it rather than guessed.
+/// Any label of the Encoding Standard, ASCII, or IBM437 (CP437); others are refused.
+final String? charset;
+
+                const HostSettings({required this.host ,this.tlsPolicy ,this.identityId ,this.connectTimeoutSecs ,this.readTimeoutSecs ,this.redirectPolicy ,this.maxBodyBytes ,this.charset ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^tlsPolicy.hashCode^identityId.hashCode^connectTimeoutSecs.hashCode^readTimeoutSecs.hashCode^redirectPolicy.hashCode^maxBodyBytes.hashCode^charset.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HostSettings &&
+                runtimeType == other.runtimeType
+                && host == other.host&& tlsPolicy == other.tlsPolicy&& identityId == other.identityId&& connectTimeoutSecs == other.connectTimeoutSecs&& readTimeoutSecs == other.readTimeoutSecs&& redirectPolicy == other.redirectPolicy&& maxBodyBytes == other.maxBodyBytes&& charset == other.charset;
+        
+            }
+
+/// What to do when a host answers with a redirect; navigation follows the redirects it allows
+/// and returns the others for the UI to ask about
+enum RedirectPolicy {
+                    follow,
+/// Ask before following a redirect to another host
+askCrossHost,
+/// Always ask before following
+ask,
+                    ;
+                    
+                }
+
+/// How a host's server certificates are checked
+enum TlsPolicy {
+                    /// Pin the first certificate and refuse a different one until the pin expires
+trustOnFirstUse,
+/// Pin the first certificate and silently replace the pin when the host presents another,
+/// for hosts that renew their self-signed certificates often
+acceptChanged,
+/// Neither pin certificates nor check their validity dates
+unverified,
+/// Require a certificate for the host issued by a certificate authority in the user trust
+/// store, instead of pinning the first one
+strict,
+                    ;
+                    
+                }
+            
\ No newline at end of file
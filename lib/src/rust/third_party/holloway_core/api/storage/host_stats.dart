@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Totals of all network requests made to one host
+class HostStats  {
+                final String host;
+final int requests;
+/// Requests that failed to produce a response
+final int errors;
+final BigInt bytesSent;
+final BigInt bytesReceived;
+final int averageLatencyMs;
+/// Share of requests that failed, from 0 to 1
+final double errorRate;
+final PlatformInt64 firstRequestAt;
+final PlatformInt64 lastRequestAt;
+
+                const HostStats({required this.host ,required this.requests ,required this.errors ,required this.bytesSent ,required this.bytesReceived ,required this.averageLatencyMs ,required this.errorRate ,required this.firstRequestAt ,required this.lastRequestAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^requests.hashCode^errors.hashCode^bytesSent.hashCode^bytesReceived.hashCode^averageLatencyMs.hashCode^errorRate.hashCode^firstRequestAt.hashCode^lastRequestAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HostStats &&
+                runtimeType == other.runtimeType
+                && host == other.host&& requests == other.requests&& errors == other.errors&& bytesSent == other.bytesSent&& bytesReceived == other.bytesReceived&& averageLatencyMs == other.averageLatencyMs&& errorRate == other.errorRate&& firstRequestAt == other.firstRequestAt&& lastRequestAt == other.lastRequestAt;
+        
+            }
+
+/// Which statistic ranks hosts first
+enum HostStatsOrder {
+                    requests,
+bytesReceived,
+averageLatency,
+errorRate,
+lastRequest,
+                    ;
+                    
+                }
+            
\ No newline at end of file
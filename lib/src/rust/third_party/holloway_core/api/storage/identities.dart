@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A client certificate the user can present to capsules, without its key material
+class Identity  {
+                final String id;
+final String name;
+final String commonName;
+/// SHA-256 fingerprint of the DER certificate, as lowercase hex
+final String fingerprint;
+/// Validity window as seconds since the Unix epoch
+final PlatformInt64 notBefore;
+final PlatformInt64 notAfter;
+final PlatformInt64 createdAt;
+
+                const Identity({required this.id ,required this.name ,required this.commonName ,required this.fingerprint ,required this.notBefore ,required this.notAfter ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^commonName.hashCode^fingerprint.hashCode^notBefore.hashCode^notAfter.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Identity &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& commonName == other.commonName&& fingerprint == other.fingerprint&& notBefore == other.notBefore&& notAfter == other.notAfter&& createdAt == other.createdAt;
+        
+            }
+            
\ No newline at end of file
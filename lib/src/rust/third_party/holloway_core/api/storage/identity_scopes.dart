@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An identity activated for every URL under a prefix, such as gemini://host/app/
+class IdentityScope  {
+                final String prefix;
+final String identityId;
+final PlatformInt64 activatedAt;
+
+                const IdentityScope({required this.prefix ,required this.identityId ,required this.activatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => prefix.hashCode^identityId.hashCode^activatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IdentityScope &&
+                runtimeType == other.runtimeType
+                && prefix == other.prefix&& identityId == other.identityId&& activatedAt == other.activatedAt;
+        
+            }
+            
\ No newline at end of file
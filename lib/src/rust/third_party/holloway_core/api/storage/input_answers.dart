@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// An answer given to a Gemini input prompt (status 10), kept to be offered again
+class SavedAnswer  {
+                final PlatformInt64 id;
+/// The canonical URL that asked, without a query
+final String url;
+/// The prompt the server sent with status 10
+final String prompt;
+final String answer;
+/// When the answer was last given, in seconds since the Unix epoch
+final PlatformInt64 answeredAt;
+/// How many times the answer was given
+final int uses;
+
+                const SavedAnswer({required this.id ,required this.url ,required this.prompt ,required this.answer ,required this.answeredAt ,required this.uses ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^prompt.hashCode^answer.hashCode^answeredAt.hashCode^uses.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SavedAnswer &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& prompt == other.prompt&& answer == other.answer&& answeredAt == other.answeredAt&& uses == other.uses;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A server whose certificate was trusted on first use
+class KnownHost  {
+                final String host;
+final int port;
+/// SHA-256 of the certificate's public key as lowercase hex
+final String fingerprint;
+/// When the pinned certificate expires; after that a new certificate replaces the pin
+final PlatformInt64? expiresAt;
+final PlatformInt64 firstSeen;
+final PlatformInt64 lastSeen;
+
+                const KnownHost({required this.host ,required this.port ,required this.fingerprint ,this.expiresAt ,required this.firstSeen ,required this.lastSeen ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^port.hashCode^fingerprint.hashCode^expiresAt.hashCode^firstSeen.hashCode^lastSeen.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KnownHost &&
+                runtimeType == other.runtimeType
+                && host == other.host&& port == other.port&& fingerprint == other.fingerprint&& expiresAt == other.expiresAt&& firstSeen == other.firstSeen&& lastSeen == other.lastSeen;
+        
+            }
+            
\ No newline at end of file
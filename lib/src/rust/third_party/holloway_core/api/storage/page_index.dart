@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A cached page matching a local search
+class LocalSearchResult  {
+                final String url;
+final String? title;
+/// The best-matching passage of the page, elided with "…" where it was cut
+final List<SnippetPart> snippet;
+/// When the cached copy was fetched, in seconds since the Unix epoch
+final PlatformInt64 fetchedAt;
+/// Latest visit to the page, if it is in the history
+final PlatformInt64? lastVisitedAt;
+
+                const LocalSearchResult({required this.url ,this.title ,required this.snippet ,required this.fetchedAt ,this.lastVisitedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^snippet.hashCode^fetchedAt.hashCode^lastVisitedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LocalSearchResult &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& snippet == other.snippet&& fetchedAt == other.fetchedAt&& lastVisitedAt == other.lastVisitedAt;
+        
+            }
+
+/// A piece of a search result snippet
+class SnippetPart  {
+                final String text;
+/// Whether the text matched the query, for the UI to highlight
+final bool matched;
+
+                const SnippetPart({required this.text ,required this.matched ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^matched.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnippetPart &&
+                runtimeType == other.runtimeType
+                && text == other.text&& matched == other.matched;
+        
+            }
+            
\ No newline at end of file
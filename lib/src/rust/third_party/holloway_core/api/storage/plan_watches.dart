@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A finger address watched for changes to its .plan and .project, a lightweight status feed
+class WatchedPlan  {
+                final PlatformInt64 id;
+/// The canonical finger:// URL, e.g. finger://alice@example.org
+final String url;
+/// Seconds since the Unix epoch
+final PlatformInt64 addedAt;
+/// The finger answer of the last successful check, with the user's .plan and .project;
+/// None until the first check
+final String? plan;
+/// When the last check was made, successfully or not
+final PlatformInt64? checkedAt;
+/// When a check first found the answer different from the one read; None once it is read
+final PlatformInt64? updatedAt;
+/// Why the last check failed, cleared by a successful one
+final String? lastError;
+
+                const WatchedPlan({required this.id ,required this.url ,required this.addedAt ,this.plan ,this.checkedAt ,this.updatedAt ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^addedAt.hashCode^plan.hashCode^checkedAt.hashCode^updatedAt.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WatchedPlan &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& addedAt == other.addedAt&& plan == other.plan&& checkedAt == other.checkedAt&& updatedAt == other.updatedAt&& lastError == other.lastError;
+        
+            }
+            
\ No newline at end of file
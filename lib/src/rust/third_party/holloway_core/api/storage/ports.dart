@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A port used instead of the protocol default when a URL omits one
+class PortOverride  {
+                final String scheme;
+/// Only URLs of this host use the port; None applies it to the whole scheme
+final String? host;
+final int port;
+
+                const PortOverride({required this.scheme ,this.host ,required this.port ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scheme.hashCode^host.hashCode^port.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PortOverride &&
+                runtimeType == other.runtimeType
+                && scheme == other.scheme&& host == other.host&& port == other.port;
+        
+            }
+            
\ No newline at end of file
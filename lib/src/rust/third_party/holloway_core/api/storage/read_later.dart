@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A page in the read-later queue, without its body
+class ReadLaterItem  {
+                final PlatformInt64 id;
+/// The page's canonical URL
+final String url;
+/// Title of the saved copy, or the one given when the page was saved
+final String? title;
+/// Seconds since the Unix epoch
+final PlatformInt64 addedAt;
+/// When the item was marked read; None while it is unread
+final PlatformInt64? readAt;
+/// Whether a copy of the page is saved for reading offline
+final bool cached;
+/// When the saved copy was fetched
+final PlatformInt64? fetchedAt;
+/// Body size of the saved copy in bytes
+final BigInt size;
+/// Why the last fetch failed, cleared by a successful one
+final String? lastError;
+
+                const ReadLaterItem({required this.id ,required this.url ,this.title ,required this.addedAt ,this.readAt ,required this.cached ,this.fetchedAt ,required this.size ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^title.hashCode^addedAt.hashCode^readAt.hashCode^cached.hashCode^fetchedAt.hashCode^size.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReadLaterItem &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& title == other.title&& addedAt == other.addedAt&& readAt == other.readAt&& cached == other.cached&& fetchedAt == other.fetchedAt&& size == other.size&& lastError == other.lastError;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// The open tabs of a window
+class Session  {
+                final String name;
+final List<SessionTab> tabs;
+/// Index of the selected tab in `tabs`
+final int activeTab;
+/// Seconds since the Unix epoch; set when the session is saved
+final PlatformInt64 savedAt;
+
+                const Session({required this.name ,required this.tabs ,required this.activeTab ,required this.savedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^tabs.hashCode^activeTab.hashCode^savedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Session &&
+                runtimeType == other.runtimeType
+                && name == other.name&& tabs == other.tabs&& activeTab == other.activeTab&& savedAt == other.savedAt;
+        
+            }
+
+/// One page of a tab's navigation stack
+class SessionEntry  {
+                final String url;
+final String? title;
+/// How far the page was scrolled, in logical pixels
+final double scrollOffset;
+
+                const SessionEntry({required this.url ,this.title ,required this.scrollOffset ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^title.hashCode^scrollOffset.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionEntry &&
+                runtimeType == other.runtimeType
+                && url == other.url&& title == other.title&& scrollOffset == other.scrollOffset;
+        
+            }
+
+/// A saved session without its tabs
+class SessionSummary  {
+                final String name;
+final int tabCount;
+final PlatformInt64 savedAt;
+
+                const SessionSummary({required this.name ,required this.tabCount ,required this.savedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^tabCount.hashCode^savedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionSummary &&
+                runtimeType == other.runtimeType
+                && name == other.name&& tabCount == other.tabCount&& savedAt == other.savedAt;
+        
+            }
+
+/// An open tab and its back/forward stack
+class SessionTab  {
+                /// Pages from the oldest back entry to the newest forward entry
+final List<SessionEntry> entries;
+/// Index of the page the tab shows in `entries`
+final int currentEntry;
+/// Private (incognito) tab: never written to the profile database, so it is not restored
+final bool private;
+
+                const SessionTab({required this.entries ,required this.currentEntry ,required this.private ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entries.hashCode^currentEntry.hashCode^private.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionTab &&
+                runtimeType == other.runtimeType
+                && entries == other.entries&& currentEntry == other.currentEntry&& private == other.private;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A certificate authority in the user trust store, which vouches for the certificates of
+/// hosts whose TLS policy is strict
+class TrustedCa  {
+                /// SHA-256 of the DER certificate as lowercase hex
+final String fingerprint;
+final String subject;
+final PlatformInt64 notAfter;
+final PlatformInt64 addedAt;
+
+                const TrustedCa({required this.fingerprint ,required this.subject ,required this.notAfter ,required this.addedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fingerprint.hashCode^subject.hashCode^notAfter.hashCode^addedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TrustedCa &&
+                runtimeType == other.runtimeType
+                && fingerprint == other.fingerprint&& subject == other.subject&& notAfter == other.notAfter&& addedAt == other.addedAt;
+        
+            }
+            
\ No newline at end of file
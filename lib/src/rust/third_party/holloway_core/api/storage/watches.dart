@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// A page watched for changes, for capsules that publish no feed
+class WatchedPage  {
+                final PlatformInt64 id;
+/// The page's canonical URL
+final String url;
+final String? title;
+/// Seconds since the Unix epoch
+final PlatformInt64 addedAt;
+/// When the user last visited the page, or when it was first checked if never
+final PlatformInt64? seenAt;
+/// When the page was last fetched to check it, successfully or not
+final PlatformInt64? checkedAt;
+/// When a check first found the page different from the visited version; None while it is
+/// unchanged
+final PlatformInt64? changedAt;
+/// Why the last check failed, cleared by a successful one
+final String? lastError;
+
+                const WatchedPage({required this.id ,required this.url ,this.title ,required this.addedAt ,this.seenAt ,this.checkedAt ,this.changedAt ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^title.hashCode^addedAt.hashCode^seenAt.hashCode^checkedAt.hashCode^changedAt.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WatchedPage &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& title == other.title&& addedAt == other.addedAt&& seenAt == other.seenAt&& checkedAt == other.checkedAt&& changedAt == other.changedAt&& lastError == other.lastError;
+        
+            }
+            
\ No newline at end of file
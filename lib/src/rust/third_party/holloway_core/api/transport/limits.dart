@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How far decoded content may outgrow what arrived over the wire
+class DecompressionLimits  {
+                /// Decoded bytes allowed per received byte
+final int maxRatio;
+/// Decoded bytes allowed in total, however well the input compresses
+final BigInt maxBytes;
+
+                const DecompressionLimits({required this.maxRatio ,required this.maxBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxRatio.hashCode^maxBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DecompressionLimits &&
+                runtimeType == other.runtimeType
+                && maxRatio == other.maxRatio&& maxBytes == other.maxBytes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,93 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// Timings of a successful test_proxy probe
+class ProxyProbe  {
+                /// Time to connect to the proxy and authenticate
+final int handshakeMs;
+/// Time to open a connection to the probe host through the proxy
+final int connectMs;
+
+                const ProxyProbe({required this.handshakeMs ,required this.connectMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => handshakeMs.hashCode^connectMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProxyProbe &&
+                runtimeType == other.runtimeType
+                && handshakeMs == other.handshakeMs&& connectMs == other.connectMs;
+        
+            }
+
+/// Route hosts matching a pattern ("host.example" or "*.onion") through a proxy, or directly when
+/// proxy is None
+class ProxyRule  {
+                final String pattern;
+final Socks5Proxy? proxy;
+
+                const ProxyRule({required this.pattern ,this.proxy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pattern.hashCode^proxy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProxyRule &&
+                runtimeType == other.runtimeType
+                && pattern == other.pattern&& proxy == other.proxy;
+        
+            }
+
+/// A SOCKS5 proxy server, e.g. Tor at 127.0.0.1:9050
+class Socks5Proxy  {
+                final String host;
+final int port;
+final String? username;
+final String? password;
+
+                const Socks5Proxy({required this.host ,required this.port ,this.username ,this.password ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^port.hashCode^username.hashCode^password.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Socks5Proxy &&
+                runtimeType == other.runtimeType
+                && host == other.host&& port == other.port&& username == other.username&& password == other.password;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            /// How hard background requests may hit one host
+class RateLimits  {
+                /// Connections open to one host at once, counting those of requests the user is waiting for
+final int maxConnections;
+/// Milliseconds between the start of one connection to a host and the next
+final BigInt minIntervalMs;
+
+                const RateLimits({required this.maxConnections ,required this.minIntervalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxConnections.hashCode^minIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RateLimits &&
+                runtimeType == other.runtimeType
+                && maxConnections == other.maxConnections&& minIntervalMs == other.minIntervalMs;
+        
+            }
+            
\ No newline at end of file
@@ -2,6 +2,7 @@ import 'package:flutter/material.dart';
 import 'dart:math';
 import 'models/tab_info.dart';
 import 'rust/api/exposed_functions.dart';
+import 'rust/third_party/holloway_core/api/models/navigation_result.dart';
 import 'content/content_renderer.dart';
 
 // manages the state of all browser tabs
@@ -56,19 +57,19 @@ class TabProvider with ChangeNotifier {
     final newTab = TabInfo(
       id: _nextTabId++,
       title: 'New Tab',
-      content: FutureBuilder<String>(
-        future: navigate(url: getStartPage()),
+      content: FutureBuilder<NavigationResult>(
+        future: navigate(url: getStartPage(), forceRefresh: false),
         builder: (context, snapshot) {
           if (snapshot.hasData) {
             // Schedule title update for next frame to avoid build phase issues
             WidgetsBinding.instance.addPostFrameCallback((_) {
-              _updateTabTitle(_tabs.length - 1, snapshot.data!);
+              _updateTabTitle(_tabs.length - 1, snapshot.data!.body);
             });
             // Use the new ContentRenderer
             return ContentRenderer(
-              content: snapshot.data!,
-              baseUrl: getStartPage(),
-              protocol: _getProtocolFromUrl(getStartPage()),
+              content: snapshot.data!.body,
+              baseUrl: snapshot.data!.url,
+              protocol: snapshot.data!.protocol,
               onNavigate: navigateToUrl,
             );
           } else if (snapshot.hasError) {
@@ -148,19 +149,19 @@ class TabProvider with ChangeNotifier {
         notifyListeners();
       }
       
-      tab.content = FutureBuilder<String>(
-        future: navigate(url: processedUrl),
+      tab.content = FutureBuilder<NavigationResult>(
+        future: navigate(url: processedUrl, forceRefresh: false),
         builder: (context, snapshot) {
           if (snapshot.hasData) {
             // Schedule title update for next frame to avoid build phase issues
             WidgetsBinding.instance.addPostFrameCallback((_) {
-              _updateTabTitle(_activeTabIndex, snapshot.data!);
+              _updateTabTitle(_activeTabIndex, snapshot.data!.body);
             });
             // Use the new ContentRenderer
             return ContentRenderer(
-              content: snapshot.data!,
-              baseUrl: processedUrl,
-              protocol: _getProtocolFromUrl(processedUrl),
+              content: snapshot.data!.body,
+              baseUrl: snapshot.data!.url,
+              protocol: snapshot.data!.protocol,
               onNavigate: navigateToUrl,
             );
           } else if (snapshot.hasError) {
@@ -220,15 +221,4 @@ class TabProvider with ChangeNotifier {
       }
     }
   }
-
-  // Helper method to determine protocol from URL
-  String _getProtocolFromUrl(String url) {
-    if (url.startsWith('gemini://')) return 'gemini';
-    if (url.startsWith('gopher://')) return 'gopher';
-    if (url.startsWith('finger://')) return 'finger';
-    if (url.startsWith('http://')) return 'http';
-    if (url.startsWith('https://')) return 'https';
-    // Default to gemini for URLs without scheme
-    return 'gemini';
-  }
 }
@@ -0,0 +1,176 @@
+//! Command-line front-end for reproducing fetch problems without the Flutter app
+//!
+//! Usage:
+//!   holloway-cli fetch [--raw] [--no-body] [--private] [--probe] <url>
+//!   holloway-cli stream [--idle <secs>] <url>
+//!   holloway-cli protocols
+
+use std::process::ExitCode;
+use std::time::Instant;
+
+use holloway_core::api::functions::navigate_internal::fetch_with_options;
+use holloway_core::api::functions::streaming::{self, StreamOptions};
+use holloway_core::api::models::fetch_options::FetchOptions;
+use holloway_core::api::models::stream_event::StreamEventKind;
+use holloway_core::api::protocols::registry::registered_protocols;
+
+const USAGE: &str = "usage:
+  holloway-cli fetch [--raw] [--no-body] [--private] [--probe] <url>
+                                         fetch a URL and print its headers, timing, and body
+                                         (--raw: the response exactly as sent, header included)
+  holloway-cli stream [--idle <secs>] <url>
+                                         print a Gemini stream's lines as they arrive
+  holloway-cli protocols                 list the registered protocols";
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("fetch") => fetch(&args[1..]).await,
+        Some("stream") => stream(&args[1..]).await,
+        Some("protocols") => {
+            for protocol in registered_protocols() {
+                println!(
+                    "{:<8} port {:<5} tls={:<5} enabled={:<5} {:?}",
+                    protocol.scheme,
+                    protocol.default_port,
+                    protocol.requires_tls,
+                    protocol.enabled,
+                    protocol.capabilities
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+async fn fetch(args: &[String]) -> ExitCode {
+    let print_body = !args.iter().any(|a| a == "--no-body");
+    let options = FetchOptions {
+        private: args.iter().any(|a| a == "--private"),
+        probe_protocols: args.iter().any(|a| a == "--probe"),
+        ..FetchOptions::default()
+    };
+    let url = match args.iter().find(|a| !a.starts_with("--")) {
+        // view-source: hands back the response bytes instead of the rendered page
+        Some(url) if args.iter().any(|a| a == "--raw") => format!("view-source:{}", url),
+        Some(url) => url.clone(),
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let started = Instant::now();
+    let result = fetch_with_options(url, &options).await;
+    let elapsed = started.elapsed();
+
+    // Diagnostics go to stderr so the body can be piped on its own
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("error:    {}", e);
+            eprintln!("time:     {} ms", elapsed.as_millis());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("url:      {}", response.url);
+    if let Some(scheme) = &response.probed_scheme {
+        eprintln!("probed:   {} answered instead of gemini", scheme);
+    }
+    if let Some(status) = response.status {
+        eprintln!(
+            "header:   {} {}",
+            status,
+            response.meta.as_deref().unwrap_or("")
+        );
+    }
+    eprintln!("mime:     {}", response.mime);
+    if let Some(title) = &response.title {
+        eprintln!("title:    {}", title);
+    }
+    eprintln!("size:     {} bytes", response.body.len());
+    eprintln!("time:     {} ms", elapsed.as_millis());
+    if let Some(fragment) = &response.fragment {
+        match response.fragment_line {
+            Some(line) => eprintln!("fragment: #{} -> line {}", fragment, line),
+            None => eprintln!("fragment: #{} (no matching heading)", fragment),
+        }
+    }
+    for feed in &response.feeds {
+        eprintln!("feed:     {:?} {}", feed.kind, feed.url);
+    }
+
+    if print_body {
+        eprintln!();
+        print!("{}", response.body);
+    }
+    ExitCode::SUCCESS
+}
+
+async fn stream(args: &[String]) -> ExitCode {
+    let mut options = StreamOptions::default();
+    let mut url = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--idle" => match args.next().and_then(|secs| secs.parse().ok()) {
+                Some(secs) => options.idle_interval_secs = secs,
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::from(2);
+                }
+            },
+            _ => url = Some(arg.clone()),
+        }
+    }
+    let Some(url) = url else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+
+    let handle = match streaming::open_stream(&url, options).await {
+        Ok(handle) => handle,
+        Err(e) => {
+            eprintln!("error:    {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let started = Instant::now();
+    loop {
+        let event = match streaming::next_event(handle).await {
+            Ok(event) => event,
+            Err(e) => {
+                eprintln!("error:    {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        match event.kind {
+            StreamEventKind::Header => eprintln!(
+                "header:   {} {}",
+                event.status.unwrap_or_default(),
+                event.meta.as_deref().unwrap_or("")
+            ),
+            StreamEventKind::Line => println!("{}", event.text.unwrap_or_default()),
+            StreamEventKind::Idle => eprintln!(
+                "idle:     {} bytes after {} s",
+                event.bytes_received,
+                started.elapsed().as_secs()
+            ),
+            StreamEventKind::Finished => {
+                eprintln!("finished: {} bytes", event.bytes_received);
+                return ExitCode::SUCCESS;
+            }
+            StreamEventKind::Failed => {
+                eprintln!("error:    {}", event.text.unwrap_or_default());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+}
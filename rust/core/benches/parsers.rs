@@ -0,0 +1,155 @@
+//! Benchmarks for the document parsers and the cheap stages of the fetch pipeline.
+//!
+//! Inputs are built by repeating the sample documents in `benches/corpus` until they
+//! reach the size of a large real-world page. Run with `cargo bench -p holloway-core`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+use holloway_core::api::functions::feed_discovery::discover_feeds;
+use holloway_core::api::functions::navigate_internal::parse_input_url;
+use holloway_core::api::functions::reflow::reflow;
+use holloway_core::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use holloway_core::api::parsers::gophermap::parse_gophermap;
+use holloway_core::api::storage::blocklist;
+
+const GEMTEXT_SAMPLE: &str = include_str!("corpus/capsule.gmi");
+const GOPHERMAP_SAMPLE: &str = include_str!("corpus/menu.gophermap");
+
+/// Number of sample copies in each generated corpus
+const CORPUS_SIZES: [usize; 3] = [1, 100, 1000];
+
+fn gemtext_corpus(copies: usize) -> String {
+    GEMTEXT_SAMPLE.repeat(copies)
+}
+
+fn gophermap_corpus(copies: usize) -> String {
+    let mut corpus = GOPHERMAP_SAMPLE.repeat(copies);
+    corpus.push_str(".\r\n");
+    corpus
+}
+
+fn bench_gemtext(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_gemtext");
+    for copies in CORPUS_SIZES {
+        let corpus = gemtext_corpus(copies);
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(copies), &corpus, |b, corpus| {
+            b.iter(|| parse_gemtext(black_box(corpus)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_gophermap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_gophermap");
+    for copies in CORPUS_SIZES {
+        let corpus = gophermap_corpus(copies);
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(copies), &corpus, |b, corpus| {
+            b.iter(|| parse_gophermap(black_box(corpus)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_reflow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reflow");
+    for copies in CORPUS_SIZES {
+        let document = parse_gemtext(&gemtext_corpus(copies));
+        group.throughput(Throughput::Elements(document.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(copies),
+            &document,
+            |b, document| b.iter(|| reflow(black_box(document), 80)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_feed_discovery(c: &mut Criterion) {
+    let mut group = c.benchmark_group("discover_feeds");
+    for copies in CORPUS_SIZES {
+        let corpus = gemtext_corpus(copies);
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(copies), &corpus, |b, corpus| {
+            b.iter(|| {
+                discover_feeds(
+                    "gemini://station.example/",
+                    "text/gemini",
+                    black_box(corpus),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Every link target in the gemtext sample, as typed or followed by the user
+fn link_targets() -> Vec<String> {
+    parse_gemtext(GEMTEXT_SAMPLE)
+        .into_iter()
+        .filter_map(|line| match line {
+            GemtextLine::Link { url, .. } => Some(url),
+            _ => None,
+        })
+        .chain(
+            [
+                "station.example/log/",
+                "gemini://STATION.example:1965/a/../b/",
+            ]
+            .map(String::from),
+        )
+        .collect()
+}
+
+fn bench_url_normalization(c: &mut Criterion) {
+    let targets = link_targets();
+    let base = url::Url::parse("gemini://station.example/log/index.gmi").unwrap();
+    let mut group = c.benchmark_group("url_normalization");
+    group.throughput(Throughput::Elements(targets.len() as u64));
+    group.bench_function("parse_input_url", |b| {
+        b.iter(|| {
+            for target in &targets {
+                black_box(parse_input_url(black_box(target)));
+            }
+        })
+    });
+    group.bench_function("resolve_relative", |b| {
+        b.iter(|| {
+            for target in &targets {
+                let _ = black_box(base.join(black_box(target)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_blocklist_lookup(c: &mut Criterion) {
+    // The in-memory profile database is used since no profile is opened
+    for i in 0..500 {
+        blocklist::add_rule(&format!("spam{}.example", i)).unwrap();
+        blocklist::add_rule(&format!("*.tracker{}.example", i)).unwrap();
+    }
+    let allowed = url::Url::parse("gemini://station.example/log/").unwrap();
+    let blocked = url::Url::parse("gemini://ads.tracker499.example/").unwrap();
+    let mut group = c.benchmark_group("blocklist_lookup");
+    group.bench_function("allowed", |b| {
+        b.iter(|| blocklist::check_url(black_box(&allowed)))
+    });
+    group.bench_function("blocked", |b| {
+        b.iter(|| blocklist::check_url(black_box(&blocked)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_gemtext,
+    bench_gophermap,
+    bench_reflow,
+    bench_feed_discovery,
+    bench_url_normalization,
+    bench_blocklist_lookup
+);
+criterion_main!(benches);
@@ -0,0 +1,98 @@
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// A heading that can be targeted by a #fragment
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadingAnchor {
+    pub slug: String,
+    pub text: String,
+    pub level: u8,
+    pub line_index: u32,
+}
+
+/// Turn heading text into a fragment identifier: "Hello, World!" becomes "hello-world"
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.extend(ch.to_lowercase());
+        } else if ch.is_whitespace() || ch == '-' || ch == '_' {
+            pending_dash = true;
+        }
+    }
+    slug
+}
+
+/// Collect an anchor for every heading, suffixing repeated slugs with -1, -2, ...
+pub fn heading_anchors(document: &[GemtextLine]) -> Vec<HeadingAnchor> {
+    let mut anchors: Vec<HeadingAnchor> = Vec::new();
+    for (index, line) in document.iter().enumerate() {
+        if let GemtextLine::Heading { level, text } = line {
+            let base = slugify(text);
+            let mut slug = base.clone();
+            let mut n = 0;
+            while anchors.iter().any(|a| a.slug == slug) {
+                n += 1;
+                slug = format!("{}-{}", base, n);
+            }
+            anchors.push(HeadingAnchor {
+                slug,
+                text: text.clone(),
+                level: *level,
+                line_index: index as u32,
+            });
+        }
+    }
+    anchors
+}
+
+/// A heading placed in the outline of its document
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry {
+    pub anchor: HeadingAnchor,
+    /// Nesting in the outline, 0 for headings under no other; a document starting at `##` has
+    /// those at 0 too
+    pub depth: u8,
+    /// Index in the outline of the nearest preceding heading of a higher level
+    pub parent: Option<u32>,
+}
+
+/// The heading hierarchy of a document in document order, for an outline sidebar; each entry's
+/// anchor gives the line to jump to
+pub fn outline(document: &[GemtextLine]) -> Vec<OutlineEntry> {
+    let mut entries: Vec<OutlineEntry> = Vec::new();
+    // Indexes of the headings enclosing the current one, outermost first
+    let mut open: Vec<usize> = Vec::new();
+    for anchor in heading_anchors(document) {
+        while open
+            .last()
+            .is_some_and(|&index| entries[index].anchor.level >= anchor.level)
+        {
+            open.pop();
+        }
+        entries.push(OutlineEntry {
+            anchor,
+            depth: open.len() as u8,
+            parent: open.last().map(|&index| index as u32),
+        });
+        open.push(entries.len() - 1);
+    }
+    entries
+}
+
+/// Find the line a fragment points at, matching slugs first and raw heading text second
+pub fn resolve_fragment(document: &[GemtextLine], fragment: &str) -> Option<u32> {
+    let fragment = percent_encoding::percent_decode_str(fragment).decode_utf8_lossy();
+    let anchors = heading_anchors(document);
+    let wanted = slugify(&fragment);
+
+    anchors
+        .iter()
+        .find(|a| a.slug == fragment || (!wanted.is_empty() && a.slug == wanted))
+        .or_else(|| anchors.iter().find(|a| a.text == fragment))
+        .map(|a| a.line_index)
+}
@@ -0,0 +1,192 @@
+/// Escape character that starts every ANSI sequence
+const ESC: char = '\u{1b}';
+/// Single-character form of "ESC [" (C1 control sequence introducer)
+const CSI: char = '\u{9b}';
+/// Bell, which also terminates operating system commands (window titles)
+const BEL: char = '\u{7}';
+
+/// A terminal color: an entry of the 256-color palette (0-7 standard, 8-15 bright) or 24-bit RGB
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    Indexed(u8),
+    Rgb { r: u8, g: u8, b: u8 },
+}
+
+/// Text attributes set by SGR ("select graphic rendition") sequences
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnsiStyle {
+    pub foreground: Option<AnsiColor>,
+    pub background: Option<AnsiColor>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    /// Swap foreground and background
+    pub inverse: bool,
+}
+
+/// A stretch of text drawn with one non-default style
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledSpan {
+    /// Start offset in UTF-16 code units of the stripped text, matching Dart string indices
+    pub start: u32,
+    /// End offset (exclusive) in UTF-16 code units
+    pub end: u32,
+    pub style: AnsiStyle,
+}
+
+/// Text with its escape sequences removed and the styles they set
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyledText {
+    pub text: String,
+    /// Only styled stretches are listed; text outside every span uses the default style
+    pub spans: Vec<StyledSpan>,
+}
+
+/// Whether the text contains any escape sequence
+pub fn contains_ansi(text: &str) -> bool {
+    text.contains([ESC, CSI])
+}
+
+/// Remove every escape sequence, keeping only the text
+pub fn strip_ansi(text: &str) -> String {
+    if !contains_ansi(text) {
+        return text.to_string();
+    }
+    convert(text, false).text
+}
+
+/// Remove every escape sequence, turning color and attribute changes into styled spans;
+/// cursor movement, screen clearing, and other terminal commands are dropped
+pub fn parse_ansi(text: &str) -> StyledText {
+    convert(text, true)
+}
+
+fn convert(text: &str, styled: bool) -> StyledText {
+    let mut output = String::with_capacity(text.len());
+    let mut spans: Vec<StyledSpan> = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut offset = 0u32;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let parameters = match ch {
+            ESC => match chars.next() {
+                Some('[') => Some(control_sequence(&mut chars)),
+                Some(']') => {
+                    operating_system_command(&mut chars);
+                    None
+                }
+                // Character set selection and similar take one more character
+                Some('(' | ')' | '*' | '+' | '#' | '%') => {
+                    chars.next();
+                    None
+                }
+                // Every other escape is a two-character sequence
+                _ => None,
+            },
+            CSI => Some(control_sequence(&mut chars)),
+            _ => {
+                output.push(ch);
+                let next = offset + ch.len_utf16() as u32;
+                if styled && style != AnsiStyle::default() {
+                    match spans.last_mut() {
+                        Some(span) if span.end == offset && span.style == style => span.end = next,
+                        _ => spans.push(StyledSpan {
+                            start: offset,
+                            end: next,
+                            style,
+                        }),
+                    }
+                }
+                offset = next;
+                continue;
+            }
+        };
+        if let Some((parameters, 'm')) = parameters {
+            apply_sgr(&mut style, &parameters);
+        }
+    }
+
+    StyledText {
+        text: output,
+        spans,
+    }
+}
+
+/// Consume a control sequence after its introducer, returning its parameters and final character
+fn control_sequence(chars: &mut impl Iterator<Item = char>) -> (String, char) {
+    let mut parameters = String::new();
+    for ch in chars {
+        // Final bytes are 0x40-0x7E; parameter and intermediate bytes come before
+        if ('\u{40}'..='\u{7e}').contains(&ch) {
+            return (parameters, ch);
+        }
+        parameters.push(ch);
+    }
+    (parameters, '\0')
+}
+
+/// Consume an operating system command up to its BEL or "ESC \" terminator
+fn operating_system_command(chars: &mut std::iter::Peekable<impl Iterator<Item = char>>) {
+    while let Some(ch) = chars.next() {
+        if ch == BEL {
+            return;
+        }
+        if ch == ESC {
+            if chars.peek() == Some(&'\\') {
+                chars.next();
+            }
+            return;
+        }
+    }
+}
+
+/// Update the current style from the parameters of an SGR sequence
+fn apply_sgr(style: &mut AnsiStyle, parameters: &str) {
+    // An empty parameter means 0; colons are the ITU form of the extended color separators
+    let mut codes = parameters
+        .split([';', ':'])
+        .map(|code| code.parse::<u32>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = AnsiStyle::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            7 => style.inverse = true,
+            22 => {
+                style.bold = false;
+                style.dim = false;
+            }
+            23 => style.italic = false,
+            24 => style.underline = false,
+            27 => style.inverse = false,
+            30..=37 => style.foreground = Some(AnsiColor::Indexed((code - 30) as u8)),
+            38 => style.foreground = extended_color(&mut codes),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(AnsiColor::Indexed((code - 40) as u8)),
+            48 => style.background = extended_color(&mut codes),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(AnsiColor::Indexed((code - 90 + 8) as u8)),
+            100..=107 => style.background = Some(AnsiColor::Indexed((code - 100 + 8) as u8)),
+            // Blinking, fonts, and the rest have no counterpart in the UI
+            _ => {}
+        }
+    }
+}
+
+/// Read the rest of a 38/48 color: "5;n" for the palette or "2;r;g;b" for RGB
+fn extended_color(codes: &mut impl Iterator<Item = u32>) -> Option<AnsiColor> {
+    let channel = |code: Option<u32>| code.map_or(0, |code| code.min(255) as u8);
+    match codes.next() {
+        Some(5) => Some(AnsiColor::Indexed(channel(codes.next()))),
+        Some(2) => Some(AnsiColor::Rgb {
+            r: channel(codes.next()),
+            g: channel(codes.next()),
+            b: channel(codes.next()),
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,33 @@
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::archive::{self, ArchivedPage};
+use crate::api::storage::now;
+
+/// Keep a permanent copy of a page with its status, MIME type, title, and fetch time. A fresh
+/// cached copy is archived as it is, so the snapshot matches the page on screen; otherwise the
+/// page is fetched, without adding a visit to history. Prompts, redirects, and errors are not
+/// pages and cannot be archived.
+pub async fn archive_page(url: &str) -> Result<ArchivedPage, String> {
+    let options = FetchOptions {
+        private: true,
+        use_cache: true,
+        ..FetchOptions::default()
+    };
+    let response = fetch_with_options(url.to_string(), &options).await?;
+    if !response.is_success() {
+        return Err(format!(
+            "Cannot archive {}: the server answered {} {}",
+            url,
+            response.status.unwrap_or_default(),
+            response.meta.as_deref().unwrap_or_default()
+        ));
+    }
+    archive::store(&response, response.cached_at.unwrap_or_else(now))
+}
+
+/// Reopen an archived snapshot as a response served from the cache, with `cached_at` set to
+/// when it was fetched
+pub fn open_archived(id: i64) -> Result<FetchResponse, String> {
+    archive::load(id)?.ok_or_else(|| format!("Unknown archived page {}", id))
+}
@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::api::functions::cancellation;
+use crate::api::functions::navigate_internal::fetch_tracked;
+use crate::api::functions::navigation_events::{next_request_id, NavigationTracker};
+use crate::api::models::error_info::ErrorInfo;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::navigation_result::NavigationResult;
+
+/// Most fetches one batch may run at once
+const MAX_CONCURRENT: u32 = 16;
+
+/// Outcome of one URL of a batch
+#[derive(Debug, Clone)]
+pub struct BatchItem {
+    /// Position of the URL in the list passed to `navigate_many`
+    pub index: u32,
+    pub url: String,
+    /// Request id of its navigation, for matching it with navigation events and traces
+    pub request_id: u64,
+    /// Set when the URL answered, whatever its status
+    pub result: Option<NavigationResult>,
+    /// Set when it could not be fetched
+    pub error: Option<ErrorInfo>,
+}
+
+/// Requests of a batch not yet finished; once `cancelled` no more are started
+#[derive(Debug, Default)]
+struct Running {
+    cancelled: bool,
+    request_ids: HashSet<u64>,
+}
+
+/// The caller's end of a running batch
+struct ActiveBatch {
+    running: Arc<Mutex<Running>>,
+    results: tokio::sync::Mutex<mpsc::UnboundedReceiver<BatchItem>>,
+}
+
+static BATCHES: LazyLock<Mutex<HashMap<u64, Arc<ActiveBatch>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Start fetching a list of URLs, at most `max_concurrent` at a time, returning a handle for
+/// `next_result`. Each URL is navigated like `fetch_with_options` with the same options, so
+/// e.g. `background` keeps a bookmark check out of history.
+pub fn navigate_many(
+    urls: Vec<String>,
+    options: FetchOptions,
+    max_concurrent: u32,
+) -> Result<u64, String> {
+    if max_concurrent == 0 || max_concurrent > MAX_CONCURRENT {
+        return Err(format!(
+            "Concurrent fetches must be between 1 and {}",
+            MAX_CONCURRENT
+        ));
+    }
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start batch: {}", e))?;
+    let running = Arc::new(Mutex::new(Running::default()));
+    let (sender, results) = mpsc::unbounded_channel();
+    let worker_running = running.clone();
+    std::thread::Builder::new()
+        .name("batch fetch".to_string())
+        .spawn(move || {
+            runtime.block_on(fetch_all(
+                urls,
+                options,
+                max_concurrent,
+                worker_running,
+                sender,
+            ))
+        })
+        .map_err(|e| format!("Failed to start batch: {}", e))?;
+
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let batch = ActiveBatch {
+        running,
+        results: tokio::sync::Mutex::new(results),
+    };
+    BATCHES.lock().unwrap().insert(handle, Arc::new(batch));
+    Ok(handle)
+}
+
+/// Fetch the URLs of a batch, sending each outcome as it completes
+async fn fetch_all(
+    urls: Vec<String>,
+    options: FetchOptions,
+    max_concurrent: u32,
+    running: Arc<Mutex<Running>>,
+    results: mpsc::UnboundedSender<BatchItem>,
+) {
+    let options = Arc::new(options);
+    let slots = Arc::new(Semaphore::new(max_concurrent as usize));
+    let mut fetches = JoinSet::new();
+    for (index, url) in urls.into_iter().enumerate() {
+        let Ok(slot) = slots.clone().acquire_owned().await else {
+            break;
+        };
+        let request_id = next_request_id();
+        {
+            let mut running = running.lock().unwrap();
+            if running.cancelled {
+                break;
+            }
+            running.request_ids.insert(request_id);
+        }
+        let options = options.clone();
+        let running = running.clone();
+        let results = results.clone();
+        fetches.spawn(async move {
+            let tracker = NavigationTracker::start_with_id(&url, request_id);
+            let fetched = fetch_tracked(url.clone(), &options, tracker).await;
+            running.lock().unwrap().request_ids.remove(&request_id);
+            drop(slot);
+            let (result, error) = match fetched {
+                Ok(response) => (Some(NavigationResult::from(response)), None),
+                Err(e) => (None, Some(e.info())),
+            };
+            // Sending only fails once the batch was cancelled
+            let _ = results.send(BatchItem {
+                index: index as u32,
+                url,
+                request_id,
+                result,
+                error,
+            });
+        });
+    }
+    while fetches.join_next().await.is_some() {}
+}
+
+/// Wait for the next URL of a batch to finish, in the order they complete; None once every URL
+/// has been reported, which releases the handle
+pub async fn next_result(handle: u64) -> Result<Option<BatchItem>, String> {
+    let batch = BATCHES
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown batch {}", handle))?;
+    let item = batch.results.lock().await.recv().await;
+    if item.is_none() {
+        BATCHES.lock().unwrap().remove(&handle);
+    }
+    Ok(item)
+}
+
+/// Stop a batch, returning whether it was running: URLs not yet started are dropped and running
+/// navigations are cancelled
+pub fn cancel_batch(handle: u64) -> bool {
+    match BATCHES.lock().unwrap().remove(&handle) {
+        Some(batch) => {
+            cancel(&batch);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Cancel every running batch, returning how many were cancelled
+pub(crate) fn cancel_all() -> usize {
+    let batches: Vec<_> = BATCHES.lock().unwrap().drain().collect();
+    for (_, batch) in &batches {
+        cancel(batch);
+    }
+    batches.len()
+}
+
+fn cancel(batch: &ActiveBatch) {
+    let mut running = batch.running.lock().unwrap();
+    running.cancelled = true;
+    for request_id in running.request_ids.drain() {
+        cancellation::cancel_request(request_id);
+    }
+}
@@ -0,0 +1,94 @@
+use unicode_bidi::ParagraphBidiInfo;
+
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// Base direction of a paragraph or run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
+
+/// A maximal stretch of a line sharing one embedding level, in logical order
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelRun {
+    /// Start offset in UTF-16 code units, matching Dart string indices
+    pub start: u32,
+    /// End offset (exclusive) in UTF-16 code units
+    pub end: u32,
+    pub level: u8,
+    pub direction: TextDirection,
+}
+
+/// Bidi analysis of a single document line
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineDirection {
+    pub line_index: u32,
+    /// Paragraph direction from the first strong character (rule P2/P3)
+    pub direction: TextDirection,
+    pub runs: Vec<LevelRun>,
+}
+
+/// Run the Unicode bidi algorithm over a document's text lines
+///
+/// Only lines containing right-to-left text are returned; every other line is plain LTR.
+/// Preformatted lines are skipped since they must be displayed exactly as sent.
+pub fn analyze_directions(document: &[GemtextLine]) -> Vec<LineDirection> {
+    document
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let text = match line {
+                GemtextLine::Text(text)
+                | GemtextLine::ListItem(text)
+                | GemtextLine::Quote(text)
+                | GemtextLine::Heading { text, .. } => text.as_str(),
+                GemtextLine::Link { url, label } => label.as_deref().unwrap_or(url),
+                GemtextLine::PreformatToggle { .. } | GemtextLine::Preformatted(_) => return None,
+            };
+            analyze_text(text).map(|(direction, runs)| LineDirection {
+                line_index: index as u32,
+                direction,
+                runs,
+            })
+        })
+        .collect()
+}
+
+/// Paragraph direction and level runs of one line, or None when it is purely LTR
+pub fn analyze_text(text: &str) -> Option<(TextDirection, Vec<LevelRun>)> {
+    let info = ParagraphBidiInfo::new(text, None);
+    if !info.has_rtl() {
+        return None;
+    }
+
+    let direction = if info.paragraph_level.is_rtl() {
+        TextDirection::Rtl
+    } else {
+        TextDirection::Ltr
+    };
+
+    // `levels` holds one entry per byte; fold them into runs over UTF-16 offsets
+    let mut runs: Vec<LevelRun> = Vec::new();
+    let mut offset = 0u32;
+    for (byte_index, ch) in text.char_indices() {
+        let level = info.levels[byte_index];
+        let next = offset + ch.len_utf16() as u32;
+        match runs.last_mut() {
+            Some(run) if run.level == level.number() => run.end = next,
+            _ => runs.push(LevelRun {
+                start: offset,
+                end: next,
+                level: level.number(),
+                direction: if level.is_rtl() {
+                    TextDirection::Rtl
+                } else {
+                    TextDirection::Ltr
+                },
+            }),
+        }
+        offset = next;
+    }
+
+    Some((direction, runs))
+}
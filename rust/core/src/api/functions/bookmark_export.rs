@@ -0,0 +1,120 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::api::storage::bookmarks::{self, Bookmark};
+
+/// Bookmark file formats other browsers read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkFormat {
+    /// XML Bookmark Exchange Language, with each bookmark's creation time as `added`
+    Xbel,
+    /// A gemtext page of `=> URL title` links, e.g. for publishing on a capsule
+    Gemtext,
+    /// The Netscape bookmark file desktop web browsers import and export
+    Html,
+}
+
+/// Write every bookmark, oldest first, in another browser's format
+pub fn export_bookmarks(format: BookmarkFormat) -> Result<String, String> {
+    Ok(serialize(format, &bookmarks::list_bookmarks()?))
+}
+
+/// Write every bookmark to a file, replacing it, and return how many were written
+pub fn export_bookmarks_to_file(format: BookmarkFormat, path: &str) -> Result<u32, String> {
+    let bookmarks = bookmarks::list_bookmarks()?;
+    std::fs::write(path, serialize(format, &bookmarks))
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok(bookmarks.len() as u32)
+}
+
+fn serialize(format: BookmarkFormat, bookmarks: &[Bookmark]) -> String {
+    match format {
+        BookmarkFormat::Xbel => export_xbel(bookmarks),
+        BookmarkFormat::Gemtext => export_gemtext(bookmarks),
+        BookmarkFormat::Html => export_html(bookmarks),
+    }
+}
+
+fn export_xbel(bookmarks: &[Bookmark]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE xbel PUBLIC \"+//IDN python.org//DTD XML Bookmark Exchange Language 1.0//EN//XML\" \
+         \"http://pyxml.sourceforge.net/topics/dtds/xbel.dtd\">\n\
+         <xbel version=\"1.0\">\n",
+    );
+    for bookmark in bookmarks {
+        let added = OffsetDateTime::from_unix_timestamp(bookmark.created_at)
+            .ok()
+            .and_then(|added| added.format(&Rfc3339).ok())
+            .map(|added| format!(" added=\"{}\"", added))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "  <bookmark href=\"{}\"{}>\n    <title>{}</title>\n  </bookmark>\n",
+            escape(&bookmark.url),
+            added,
+            escape(title(bookmark))
+        ));
+    }
+    out.push_str("</xbel>\n");
+    out
+}
+
+fn export_gemtext(bookmarks: &[Bookmark]) -> String {
+    let mut out = String::from("# Bookmarks\n\n");
+    for bookmark in bookmarks {
+        match bookmark.title.as_deref().map(str::trim) {
+            // A title cannot span lines in a link line
+            Some(title) if !title.is_empty() => out.push_str(&format!(
+                "=> {} {}\n",
+                bookmark.url,
+                title.replace(['\r', '\n'], " ")
+            )),
+            _ => out.push_str(&format!("=> {}\n", bookmark.url)),
+        }
+    }
+    out
+}
+
+fn export_html(bookmarks: &[Bookmark]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n\
+         <META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">\n\
+         <TITLE>Bookmarks</TITLE>\n\
+         <H1>Bookmarks</H1>\n\
+         <DL><p>\n",
+    );
+    for bookmark in bookmarks {
+        out.push_str(&format!(
+            "    <DT><A HREF=\"{}\" ADD_DATE=\"{}\">{}</A>\n",
+            escape(&bookmark.url),
+            bookmark.created_at,
+            escape(title(bookmark))
+        ));
+    }
+    out.push_str("</DL><p>\n");
+    out
+}
+
+/// The title of a bookmark, or its URL when it has none
+fn title(bookmark: &Bookmark) -> &str {
+    match bookmark.title.as_deref().map(str::trim) {
+        Some(title) if !title.is_empty() => title,
+        _ => &bookmark.url,
+    }
+}
+
+/// Escape text for XML and HTML element content and quoted attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,125 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::api::parsers::feed::{attribute, child_text, elements};
+use crate::api::storage::{bookmarks, now};
+
+/// Bookmark files of other Gemini clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkSource {
+    /// Amfora's bookmarks.xml, an XBEL file; also reads XBEL written by other browsers
+    Amfora,
+    /// Lagrange's bookmarks.ini: one `[n]` section per bookmark with quoted `url`, `title`,
+    /// and `tags` values and a `created` time in seconds; folders have no URL and are flattened
+    Lagrange,
+}
+
+/// How many bookmarks an import added and how many it passed over
+#[derive(Debug, Clone, Default)]
+pub struct BookmarksImport {
+    pub imported: u32,
+    /// URLs already bookmarked here, possibly spelled differently, and unreadable entries
+    pub skipped: u32,
+}
+
+/// One bookmark read from another client's file
+struct ImportedBookmark {
+    url: String,
+    title: Option<String>,
+    created_at: Option<i64>,
+}
+
+/// Add the bookmarks of another client's file, keeping bookmarks that already exist
+pub fn import_bookmarks(source: BookmarkSource, data: &str) -> Result<BookmarksImport, String> {
+    let entries = match source {
+        BookmarkSource::Amfora => parse_xbel(data),
+        BookmarkSource::Lagrange => parse_lagrange(data),
+    };
+    let mut result = BookmarksImport::default();
+    for entry in entries {
+        let Ok(url) = Url::parse(entry.url.trim()) else {
+            result.skipped += 1;
+            continue;
+        };
+        let title = entry
+            .title
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty());
+        if bookmarks::import(&url, title, entry.created_at.unwrap_or_else(now))? {
+            result.imported += 1;
+        } else {
+            result.skipped += 1;
+        }
+    }
+    Ok(result)
+}
+
+fn parse_xbel(data: &str) -> Vec<ImportedBookmark> {
+    elements(data, "bookmark")
+        .into_iter()
+        .map(|(tag, inner)| ImportedBookmark {
+            url: attribute(tag, "href").unwrap_or_default(),
+            title: child_text(inner, "title"),
+            created_at: attribute(tag, "added")
+                .and_then(|added| OffsetDateTime::parse(&added, &Rfc3339).ok())
+                .map(OffsetDateTime::unix_timestamp),
+        })
+        .collect()
+}
+
+fn parse_lagrange(data: &str) -> Vec<ImportedBookmark> {
+    let mut entries = Vec::new();
+    let mut current: Option<ImportedBookmark> = None;
+    for line in data.lines().map(str::trim) {
+        if line.starts_with('[') && line.ends_with(']') {
+            entries.extend(current.take());
+            current = Some(ImportedBookmark {
+                url: String::new(),
+                title: None,
+                created_at: None,
+            });
+            continue;
+        }
+        let (Some(entry), Some((key, value))) = (current.as_mut(), line.split_once('=')) else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "url" => entry.url = unquote(value),
+            "title" => entry.title = Some(unquote(value)),
+            "created" => entry.created_at = value.parse().ok(),
+            _ => {}
+        }
+    }
+    entries.extend(current);
+    // Folders and other sections without a URL are not bookmarks
+    entries.retain(|entry| !entry.url.is_empty());
+    entries
+}
+
+/// A double-quoted value of Lagrange's config files with its backslash escapes resolved
+fn unquote(value: &str) -> String {
+    let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    else {
+        return value.to_string();
+    };
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('t') => unquoted.push('\t'),
+            Some(escaped) => unquoted.push(escaped),
+            None => unquoted.push('\\'),
+        }
+    }
+    unquoted
+}
@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::data_usage::network_type;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::data_usage::NetworkType;
+use crate::api::storage::{bookmarks, cache, now};
+
+/// Bumped by every `start_bookmark_refresh` and `stop_bookmark_refresh` call so older refresh
+/// loops stop
+static REFRESH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Whether the device is on an unmetered network (Wi-Fi or Ethernet)
+fn unmetered() -> bool {
+    matches!(network_type(), NetworkType::Wifi | NetworkType::Ethernet)
+}
+
+/// Refetch every cacheable bookmarked page whose cached copy is missing or older than
+/// `max_age_secs`, returning how many were refreshed; the fetches are not added to history
+pub async fn refresh_bookmarks(max_age_secs: u32) -> Result<u32, String> {
+    let options = FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    };
+    let mut refreshed = 0;
+    for bookmark in bookmarks::list_bookmarks()? {
+        let Ok(url) = Url::parse(&bookmark.url) else {
+            continue;
+        };
+        let policy = cache::policy_for(url.scheme())?;
+        if !policy.allows(&url) {
+            continue;
+        }
+        let fetched_at = cache::fetched_at(canonicalize(&url).as_str())?;
+        if fetched_at.is_some_and(|fetched_at| now() - fetched_at < max_age_secs as i64) {
+            continue;
+        }
+        // One unreachable capsule must not stop the others from refreshing
+        if fetch_with_options(bookmark.url, &options).await.is_ok() {
+            refreshed += 1;
+        }
+    }
+    Ok(refreshed)
+}
+
+/// Refresh bookmarked pages every `interval_secs` while the device is on Wi-Fi or Ethernet,
+/// so offline browsing has recent copies. Off until called; call after `open_profile`.
+pub fn start_bookmark_refresh(interval_secs: u32, max_age_secs: u32) -> Result<(), String> {
+    let generation = REFRESH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start bookmark refresh: {}", e))?;
+
+    std::thread::Builder::new()
+        .name("bookmark refresh".to_string())
+        .spawn(move || loop {
+            std::thread::sleep(Duration::from_secs(interval_secs.max(1) as u64));
+            if REFRESH_GENERATION.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            if unmetered() {
+                let _ = runtime.block_on(refresh_bookmarks(max_age_secs));
+            }
+        })
+        .map_err(|e| format!("Failed to start bookmark refresh: {}", e))?;
+    Ok(())
+}
+
+/// Stop the background refresh started by `start_bookmark_refresh`
+pub fn stop_bookmark_refresh() {
+    REFRESH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::task::{Context, Poll};
+use std::thread::Thread;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::Notify;
+
+use crate::api::functions::navigation_events::{self, current_cancel_token};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::transport::Connection;
+
+/// Error returned by a navigation that was cancelled
+pub const CANCELLED_ERROR: &str = "Request cancelled";
+
+/// Lets a navigation be stopped from outside: reads waiting on its connections fail as soon as
+/// it is cancelled, instead of waiting for the server
+#[derive(Debug, Default)]
+pub(crate) struct CancelToken {
+    cancelled: AtomicBool,
+    /// Set when the request was cancelled because its deadline passed
+    deadline_exceeded: AtomicBool,
+    /// Wakes whatever waits for the cancellation
+    notify: Notify,
+}
+
+impl CancelToken {
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn deadline_exceeded(&self) -> bool {
+        self.deadline_exceeded.load(Ordering::Relaxed)
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.notify.notify_waiters();
+    }
+
+    /// Wait until the request is cancelled
+    async fn wait(&self) {
+        // Registered before the flag is looked at, so a cancellation in between still wakes it
+        let notified = self.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+        if !self.is_cancelled() {
+            notified.await;
+        }
+    }
+}
+
+/// Tokens of reserved and running requests by request id
+static TOKENS: LazyLock<Mutex<HashMap<u64, Arc<CancelToken>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Allocate a request id before starting a navigation with it, so the caller can cancel the
+/// navigation while it waits for the result
+pub fn reserve_request_id() -> u64 {
+    let request_id = navigation_events::next_request_id();
+    TOKENS
+        .lock()
+        .unwrap()
+        .insert(request_id, Arc::new(CancelToken::default()));
+    request_id
+}
+
+/// Whether a request id was reserved and its navigation has not finished
+pub(crate) fn is_reserved(request_id: u64) -> bool {
+    TOKENS.lock().unwrap().contains_key(&request_id)
+}
+
+/// The token of a request, created when the request id was not reserved
+pub(crate) fn token(request_id: u64) -> Arc<CancelToken> {
+    TOKENS
+        .lock()
+        .unwrap()
+        .entry(request_id)
+        .or_default()
+        .clone()
+}
+
+/// Forget the token of a finished request
+pub(crate) fn release(request_id: u64) {
+    TOKENS.lock().unwrap().remove(&request_id);
+}
+
+/// Stop a reserved or running request, returning whether it was known; the navigation fails
+/// with `CANCELLED_ERROR` as soon as it next waits on the network
+pub fn cancel_request(request_id: u64) -> bool {
+    let token = TOKENS.lock().unwrap().get(&request_id).cloned();
+    match token {
+        Some(token) => {
+            token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Cancel every reserved and running request, returning how many were cancelled
+pub(crate) fn cancel_all() -> usize {
+    let tokens: Vec<_> = TOKENS.lock().unwrap().drain().collect();
+    for (_, token) in &tokens {
+        token.cancel();
+    }
+    tokens.len()
+}
+
+/// Wait until the navigation running on this task is cancelled; never returns for a task
+/// running none
+pub(crate) async fn cancelled() {
+    match current_cancel_token() {
+        Some(token) => token.wait().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Let the navigation running on this task close a connection when it is cancelled: reads and
+/// writes waiting on it then fail with `CANCELLED_ERROR`, as do any made afterwards
+pub(crate) fn watched(connection: Box<dyn Connection>) -> Box<dyn Connection> {
+    match current_cancel_token() {
+        Some(token) => Box::new(WatchedConnection {
+            inner: connection,
+            cancelled: Box::pin(async move { token.wait().await }),
+        }),
+        None => connection,
+    }
+}
+
+/// A connection that fails once its navigation is cancelled
+struct WatchedConnection {
+    inner: Box<dyn Connection>,
+    cancelled: Pin<Box<dyn Future<Output = ()> + Send>>,
+}
+
+impl std::fmt::Debug for WatchedConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchedConnection")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WatchedConnection {
+    fn check(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        match self.cancelled.as_mut().poll(cx) {
+            Poll::Ready(()) => Err(io::Error::other(cancelled_error())),
+            Poll::Pending => Ok(()),
+        }
+    }
+}
+
+impl AsyncRead for WatchedConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.check(cx)?;
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for WatchedConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.check(cx)?;
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.check(cx)?;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// Fail if the navigation running on this task was cancelled, before starting more work for it
+pub(crate) fn check_cancelled() -> Result<(), NavigateError> {
+    match current_cancel_token() {
+        Some(token) if token.is_cancelled() => Err(cancelled_error()),
+        _ => Ok(()),
+    }
+}
+
+/// The error a cancelled navigation fails with
+pub(crate) fn cancelled_error() -> NavigateError {
+    NavigateError::Cancelled(CANCELLED_ERROR.to_string())
+}
+
+/// Cancels a request once its deadline passes, unless dropped first
+pub(crate) struct DeadlineGuard {
+    done: Arc<AtomicBool>,
+    watchdog: Thread,
+}
+
+/// Cancel a request if it is still running after `after`; None if no watchdog thread could be
+/// started, leaving the request bounded only by its read timeouts
+pub(crate) fn arm_deadline(token: Arc<CancelToken>, after: Duration) -> Option<DeadlineGuard> {
+    let deadline = Instant::now() + after;
+    let done = Arc::new(AtomicBool::new(false));
+    let watchdog_done = done.clone();
+    let watchdog = std::thread::Builder::new()
+        .name("request deadline".to_string())
+        .spawn(move || loop {
+            if watchdog_done.load(Ordering::Relaxed) {
+                return;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                token.deadline_exceeded.store(true, Ordering::Relaxed);
+                token.cancel();
+                return;
+            }
+            // Woken early when the request completes
+            std::thread::park_timeout(deadline - now);
+        })
+        .ok()?;
+    Some(DeadlineGuard {
+        done,
+        watchdog: watchdog.thread().clone(),
+    })
+}
+
+impl Drop for DeadlineGuard {
+    fn drop(&mut self) {
+        self.done.store(true, Ordering::Relaxed);
+        self.watchdog.unpark();
+    }
+}
@@ -0,0 +1,130 @@
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::protocols::registry;
+use crate::api::storage::ports::resolve_port;
+
+/// Canonical form of a URL, so that addresses naming the same resource compare equal:
+/// the host is converted to punycode and lowercased, the default port (including a configured override) and any fragment
+/// are dropped, and an empty path becomes "/"
+pub fn canonicalize(url: &Url) -> Url {
+    let mut canonical = url.clone();
+    canonical.set_fragment(None);
+    to_ascii_host(&mut canonical);
+
+    // Hosts of non-special schemes such as gemini:// keep their case when parsed
+    if let Some(host) = canonical.host_str() {
+        let lowercase = host.to_ascii_lowercase();
+        if lowercase != host {
+            let _ = canonical.set_host(Some(&lowercase));
+        }
+    }
+
+    if let (Some(port), Some(handler)) =
+        (canonical.port(), registry::handler_for(canonical.scheme()))
+    {
+        let _ = canonical.set_port(None);
+        if port != resolve_port(&canonical, handler.default_port()) {
+            let _ = canonical.set_port(Some(port));
+        }
+    }
+
+    if canonical.has_host() && canonical.path().is_empty() {
+        canonical.set_path("/");
+    }
+    canonical
+}
+
+/// Replace an internationalized host with its punycode (xn--) form, which is what DNS, TLS
+/// server names, and request lines carry. Special schemes such as https:// are converted when
+/// parsed, but non-special ones such as gemini:// keep the host as percent-encoded UTF-8. A host
+/// that is not a valid domain name is left as it is, for the request to fail on.
+pub(crate) fn to_ascii_host(url: &mut Url) {
+    let Some(host) = url.host_str() else {
+        return;
+    };
+    let Ok(decoded) = percent_decode_str(host).decode_utf8() else {
+        return;
+    };
+    if decoded.is_ascii() {
+        return;
+    }
+    if let Ok(ascii) = idna::domain_to_ascii(&decoded) {
+        let _ = url.set_host(Some(&ascii));
+    }
+}
+
+/// Canonicalize a URL string, leaving it unchanged if it does not parse
+pub fn canonicalize_str(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(parsed) => canonicalize(&parsed).to_string(),
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Canonical form of an address as typed, the scheme guessed as navigation guesses it, so the
+/// address bar, history, and cache agree on one spelling of a page; dot segments are resolved
+/// by parsing. Input that is not an address, e.g. a search query, is an error.
+pub fn normalize_url(input: &str) -> Result<String, String> {
+    let url = parse_input_url(input).ok_or_else(|| format!("Invalid URL: {}", input.trim()))?;
+    Ok(canonicalize(&url).to_string())
+}
+
+/// Looser form of `canonicalize` for spotting duplicates: additionally ignores a trailing slash
+/// and differences in percent-encoding. Too loose for cache keys, since "/dir" and "/dir/" may be
+/// different pages, but right for telling the user they already have something open or saved
+pub fn equivalence_key(url: &Url) -> String {
+    let mut key = canonicalize(url);
+    let path = normalize_percent_encoding(key.path());
+    let trimmed = match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    };
+    let trimmed = trimmed.to_string();
+    key.set_path(&trimmed);
+    if let Some(query) = key.query().map(normalize_percent_encoding) {
+        key.set_query(Some(&query));
+    }
+    key.to_string()
+}
+
+/// Decode escapes of unreserved characters, which mean the same encoded or not, and uppercase
+/// the hex digits of the rest
+fn normalize_percent_encoding(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut output = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) => {
+                output.push(byte as char);
+                i += 3;
+            }
+            Some(byte) => {
+                output.push_str(&format!("%{:02X}", byte));
+                i += 3;
+            }
+            None => {
+                let ch = text[i..].chars().next().unwrap_or_default();
+                output.push(ch);
+                i += ch.len_utf8().max(1);
+            }
+        }
+    }
+    output
+}
+
+/// Index of the first open tab showing the same page as `url`, so the UI can switch to it
+/// instead of opening another
+pub fn duplicate_tab(url: &str, open_tabs: &[String]) -> Option<u32> {
+    let key = equivalence_key(&Url::parse(url).ok()?);
+    open_tabs
+        .iter()
+        .position(|tab| Url::parse(tab).is_ok_and(|tab| equivalence_key(&tab) == key))
+        .map(|index| index as u32)
+}
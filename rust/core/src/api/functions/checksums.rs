@@ -0,0 +1,129 @@
+use url::Url;
+
+use crate::api::functions::links::extract_links;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::downloads::ChecksumStatus;
+
+/// Suffixes of a checksum file published next to the file it checks, e.g. file.tar.gz.sha256
+const CHECKSUM_SUFFIXES: &[&str] = &[".sha256", ".sha256sum", ".sha256.txt"];
+
+/// Names of a checksum list covering every file of its directory, compared case-insensitively
+const CHECKSUM_LISTS: &[&str] = &["sha256sums", "sha256sums.txt"];
+
+/// Largest checksum file fetched; a list of a few thousand files fits
+const MAX_CHECKSUM_BYTES: u64 = 256 * 1024;
+
+/// The checksum a page links alongside a file it links: `file.tar.gz.sha256` (or `.sha256sum`)
+/// for `file.tar.gz`, or else a SHA256SUMS list in the same directory. Works for gemtext pages
+/// and Gopher menus, whose item types may differ between the file and its checksum.
+pub fn checksum_link(page_url: &str, mime: &str, body: &str, file_url: &str) -> Option<String> {
+    let file = Url::parse(file_url).ok()?;
+    let path = file_path(&file)?;
+    let (file_dir, file_name) = path.rsplit_once('/')?;
+    let links: Vec<Url> = extract_links(page_url, mime, body)
+        .into_iter()
+        .filter_map(|link| Url::parse(&link.url).ok())
+        .filter(|url| url.scheme() == file.scheme() && url.host_str() == file.host_str())
+        .filter(|url| url.port_or_known_default() == file.port_or_known_default())
+        .collect();
+    let in_directory = |url: &Url, wanted: &dyn Fn(&str) -> bool| {
+        file_path(url)
+            .and_then(|path| {
+                let (dir, name) = path.rsplit_once('/')?;
+                Some(dir == file_dir && wanted(name))
+            })
+            .unwrap_or(false)
+    };
+    let own = |name: &str| {
+        CHECKSUM_SUFFIXES
+            .iter()
+            .any(|suffix| name.strip_suffix(suffix) == Some(file_name))
+    };
+    let list = |name: &str| {
+        CHECKSUM_LISTS
+            .iter()
+            .any(|list| name.eq_ignore_ascii_case(list))
+    };
+    links
+        .iter()
+        .find(|url| in_directory(url, &own))
+        .or_else(|| links.iter().find(|url| in_directory(url, &list)))
+        .map(Url::to_string)
+}
+
+/// The SHA-256 a checksum file gives for a file name, as lowercase hex: the line naming the
+/// file in a `sha256sum` listing, or the only hash of a file holding just one
+pub(crate) fn expected_sha256(checksums: &str, file_name: &str) -> Option<String> {
+    let mut hashes = checksums.lines().filter_map(|line| {
+        let mut fields = line.split_whitespace();
+        let hash = fields.next().filter(|hash| is_sha256(hash))?;
+        // sha256sum marks files read in binary mode with a leading '*'
+        let name = fields.next().map(|name| name.trim_start_matches('*'));
+        Some((hash.to_ascii_lowercase(), name))
+    });
+    let first = hashes.next()?;
+    if first.1.is_none_or(|name| names_file(name, file_name)) {
+        return Some(first.0);
+    }
+    hashes
+        .find(|(_, name)| name.is_some_and(|name| names_file(name, file_name)))
+        .map(|(hash, _)| hash)
+}
+
+/// Fetch a checksum file with the options of the download it checks, and check the downloaded
+/// file's SHA-256 against the hash it gives for the file's name
+pub(crate) async fn verify(
+    checksum_url: &str,
+    file_url: &str,
+    sha256: &str,
+    options: &FetchOptions,
+) -> ChecksumStatus {
+    let Some(file_name) = Url::parse(file_url)
+        .ok()
+        .and_then(|url| Some(file_path(&url)?.rsplit_once('/')?.1.to_string()))
+    else {
+        return ChecksumStatus::Unavailable;
+    };
+    // Fetched fresh, and in the background so it adds no visit to history (keeping to robots.txt
+    // and the rate limits); unless the download is private, its certificate is checked against
+    // the pins like any other
+    let options = FetchOptions {
+        use_cache: false,
+        background: true,
+        max_body_bytes: Some(MAX_CHECKSUM_BYTES),
+        ..options.clone()
+    };
+    let checksums = match fetch_with_options(checksum_url.to_string(), &options).await {
+        Ok(response) if response.is_success() => response.body,
+        _ => return ChecksumStatus::Unavailable,
+    };
+    match expected_sha256(&checksums, &file_name) {
+        Some(expected) if expected.eq_ignore_ascii_case(sha256) => ChecksumStatus::Verified,
+        Some(_) => ChecksumStatus::Mismatch,
+        None => ChecksumStatus::Unavailable,
+    }
+}
+
+/// The percent-decoded path of a URL, without the item type of a Gopher selector
+fn file_path(url: &Url) -> Option<String> {
+    let path = percent_encoding::percent_decode_str(url.path())
+        .decode_utf8_lossy()
+        .to_string();
+    if url.scheme() != "gopher" {
+        return Some(path);
+    }
+    let selector = path.strip_prefix('/')?;
+    let mut chars = selector.chars();
+    chars.next()?;
+    Some(chars.as_str().to_string())
+}
+
+/// Whether a name in a checksum list is the file's, also when listed with its directory
+fn names_file(name: &str, file_name: &str) -> bool {
+    name.rsplit('/').next() == Some(file_name)
+}
+
+fn is_sha256(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, RwLock};
+
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::storage::now;
+
+/// Prefix of the error returned when a certificate is outside its validity period by more than
+/// the clock skew tolerance
+pub const CERTIFICATE_DATE_ERROR_PREFIX: &str = "Certificate not valid at this time";
+
+/// Hosts whose certificate validity is remembered for inferring the device clock offset
+const MAX_OBSERVATIONS: usize = 64;
+
+/// Hosts needed before an offset is inferred, so one misconfigured server cannot set it
+const MIN_HOSTS: usize = 2;
+
+/// How far outside its validity period a certificate may appear before it is refused; two days
+/// either way covers time zone mistakes and a phone that lost its clock over a weekend
+const DEFAULT_TOLERANCE_SECS: u64 = 2 * 24 * 60 * 60;
+
+static TOLERANCE_SECS: RwLock<u64> = RwLock::new(DEFAULT_TOLERANCE_SECS);
+
+/// Validity periods seen per host and port, as offsets from the device clock when they were seen
+static OBSERVATIONS: LazyLock<Mutex<HashMap<(String, u16), Observation>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The range of device clock offsets under which a host's certificate was valid
+#[derive(Debug, Clone, Copy)]
+struct Observation {
+    /// Earliest offset (true time minus device time) at which the certificate was valid
+    earliest: i64,
+    /// Latest such offset
+    latest: i64,
+    seen_at: i64,
+}
+
+/// The device clock offset inferred from the certificates of the servers visited
+#[derive(Debug, Clone)]
+pub struct ClockOffset {
+    /// Seconds the device clock appears to be behind true time; negative when it runs ahead.
+    /// None until enough hosts were seen
+    pub offset_secs: Option<i64>,
+    /// Hosts whose certificates the estimate is based on
+    pub hosts: u32,
+    /// Whether every certificate seen was valid under the estimated offset; false when some
+    /// server's certificate is simply outdated and the estimate is a median instead
+    pub consistent: bool,
+}
+
+/// How far outside its validity period a certificate may appear before it is refused
+pub fn clock_skew_tolerance() -> u64 {
+    *TOLERANCE_SECS.read().unwrap()
+}
+
+/// Change the clock skew tolerance; 0 refuses every certificate outside its validity period
+pub fn set_clock_skew_tolerance(secs: u64) {
+    *TOLERANCE_SECS.write().unwrap() = secs;
+}
+
+/// Check a server certificate's validity period against the device clock, allowing for the
+/// clock skew tolerance; returns how many seconds it was outside the period (positive when it
+/// appears expired, negative when not yet valid) if it was only accepted thanks to the tolerance
+pub(crate) fn check_validity(
+    host: &str,
+    port: u16,
+    der: &[u8],
+) -> Result<Option<i64>, NavigateError> {
+    let (_, certificate) = X509Certificate::from_der(der).map_err(|e| {
+        NavigateError::TlsError(format!(
+            "TLS connection failed: unreadable server certificate: {}",
+            e
+        ))
+    })?;
+    let not_before = certificate.validity().not_before.timestamp();
+    let not_after = certificate.validity().not_after.timestamp();
+    let now = now();
+    observe(host, port, not_before - now, not_after - now, now);
+
+    let skew = if now < not_before {
+        now - not_before
+    } else if now > not_after {
+        now - not_after
+    } else {
+        return Ok(None);
+    };
+    if skew.unsigned_abs() > clock_skew_tolerance() {
+        let problem = if skew < 0 { "not yet valid" } else { "expired" };
+        return Err(NavigateError::CertificateDate(format!(
+            "{}: the certificate of {}:{} is {} ({} seconds off); check the device clock",
+            CERTIFICATE_DATE_ERROR_PREFIX,
+            host,
+            port,
+            problem,
+            skew.unsigned_abs()
+        )));
+    }
+    Ok(Some(skew))
+}
+
+/// Remember the offsets under which a host's certificate is valid, forgetting the host seen
+/// longest ago once the table is full
+fn observe(host: &str, port: u16, earliest: i64, latest: i64, seen_at: i64) {
+    let mut observations = OBSERVATIONS.lock().unwrap();
+    let key = (host.to_ascii_lowercase(), port);
+    if !observations.contains_key(&key) && observations.len() >= MAX_OBSERVATIONS {
+        if let Some(oldest) = observations
+            .iter()
+            .min_by_key(|(_, observation)| observation.seen_at)
+            .map(|(key, _)| key.clone())
+        {
+            observations.remove(&oldest);
+        }
+    }
+    observations.insert(
+        key,
+        Observation {
+            earliest,
+            latest,
+            seen_at,
+        },
+    );
+}
+
+/// Infer the device clock offset: the smallest correction under which every certificate seen
+/// is valid, or the median of each host's smallest correction when no single one fits all
+pub fn clock_offset() -> ClockOffset {
+    let observations: Vec<Observation> = OBSERVATIONS.lock().unwrap().values().copied().collect();
+    let hosts = observations.len() as u32;
+    if observations.len() < MIN_HOSTS {
+        return ClockOffset {
+            offset_secs: None,
+            hosts,
+            consistent: true,
+        };
+    }
+
+    let earliest = observations.iter().map(|o| o.earliest).max().unwrap_or(0);
+    let latest = observations.iter().map(|o| o.latest).min().unwrap_or(0);
+    if earliest <= latest {
+        return ClockOffset {
+            offset_secs: Some(0.clamp(earliest, latest)),
+            hosts,
+            consistent: true,
+        };
+    }
+
+    let mut corrections: Vec<i64> = observations
+        .iter()
+        .map(|o| 0.clamp(o.earliest, o.latest.max(o.earliest)))
+        .collect();
+    corrections.sort_unstable();
+    ClockOffset {
+        offset_secs: Some(corrections[corrections.len() / 2]),
+        hosts,
+        consistent: false,
+    }
+}
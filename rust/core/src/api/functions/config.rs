@@ -0,0 +1,148 @@
+use std::sync::{LazyLock, RwLock};
+
+use crate::api::functions::{search, start_page};
+use crate::api::protocols::registry;
+use crate::api::storage::host_settings::TlsPolicy;
+use crate::api::storage::{proxy as proxy_store, settings};
+use crate::api::transport::proxy::{self, ProxySettings};
+use crate::api::transport::CONNECT_TIMEOUT;
+
+/// Settings key of the scheme of bare host names
+const DEFAULT_SCHEME_KEY: &str = "default_scheme";
+
+/// Settings key of whether schemes are guessed, stored as "0" when they are not
+const GUESS_SCHEMES_KEY: &str = "guess_schemes";
+
+/// Settings key of the connect timeout of hosts without their own
+const CONNECT_TIMEOUT_KEY: &str = "connect_timeout_secs";
+
+/// Settings key of the read timeout of hosts without their own
+const READ_TIMEOUT_KEY: &str = "read_timeout_secs";
+
+/// Settings key of the certificate policy of hosts without their own
+const TLS_POLICY_KEY: &str = "tls_policy";
+
+/// Scheme of bare host names until the user picks another
+pub const DEFAULT_SCHEME: &str = "gemini";
+
+/// Browser-wide settings, each applying wherever a host or a request sets nothing of its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Scheme of an address typed without one that neither its port, a `user@`, its path, its
+    /// host name, nor an earlier visit decides
+    pub default_scheme: String,
+    /// Guess the scheme of addresses typed without one from their form, e.g. finger for
+    /// `user@host`; off, they all get `default_scheme`
+    pub guess_schemes: bool,
+    pub start_page: String,
+    /// Search URL template, with `%s` where the query goes
+    pub search_provider: String,
+    pub connect_timeout_secs: u32,
+    /// Longest wait for data, or None to wait as long as the server keeps the connection open
+    pub read_timeout_secs: Option<u32>,
+    pub tls_policy: TlsPolicy,
+    pub proxy: ProxySettings,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            default_scheme: DEFAULT_SCHEME.to_string(),
+            guess_schemes: true,
+            start_page: start_page::DEFAULT_START_PAGE.to_string(),
+            search_provider: search::DEFAULT_SEARCH_PROVIDER.to_string(),
+            connect_timeout_secs: CONNECT_TIMEOUT.as_secs() as u32,
+            read_timeout_secs: None,
+            tls_policy: TlsPolicy::TrustOnFirstUse,
+            proxy: ProxySettings::Direct,
+        }
+    }
+}
+
+/// The settings of the open profile, read on every navigation and written rarely
+static CONFIG: LazyLock<RwLock<Config>> = LazyLock::new(Default::default);
+
+/// The current configuration
+pub fn get_config() -> Config {
+    CONFIG.read().unwrap().clone()
+}
+
+/// Validate, save, and apply a whole configuration, returning it as stored, e.g. with the start
+/// page normalized. Nothing is saved unless the scheme, timeouts, and proxy are valid.
+pub fn set_config(config: Config) -> Result<Config, String> {
+    let default_scheme = config.default_scheme.trim().to_ascii_lowercase();
+    if registry::handler_for(&default_scheme).is_none() {
+        return Err(format!(
+            "Unsupported default scheme. Only {} are supported.",
+            registry::registered_schemes().join(", ")
+        ));
+    }
+    if config.connect_timeout_secs == 0 || config.read_timeout_secs == Some(0) {
+        return Err("Timeouts must be at least one second".to_string());
+    }
+    let proxy = config.proxy.validate()?;
+
+    start_page::set_start_page(Some(&config.start_page))?;
+    search::set_search_provider(Some(&config.search_provider))?;
+    if default_scheme == DEFAULT_SCHEME {
+        settings::remove(DEFAULT_SCHEME_KEY)?;
+    } else {
+        settings::set(DEFAULT_SCHEME_KEY, &default_scheme)?;
+    }
+    if config.guess_schemes {
+        settings::remove(GUESS_SCHEMES_KEY)?;
+    } else {
+        settings::set(GUESS_SCHEMES_KEY, "0")?;
+    }
+    settings::set(
+        CONNECT_TIMEOUT_KEY,
+        &config.connect_timeout_secs.to_string(),
+    )?;
+    match config.read_timeout_secs {
+        Some(secs) => settings::set(READ_TIMEOUT_KEY, &secs.to_string())?,
+        None => settings::remove(READ_TIMEOUT_KEY)?,
+    }
+    settings::set(TLS_POLICY_KEY, config.tls_policy.as_str())?;
+    proxy_store::save_settings(&proxy)?;
+    proxy::apply(proxy)?;
+
+    update(|current| {
+        current.default_scheme = default_scheme;
+        current.guess_schemes = config.guess_schemes;
+        current.connect_timeout_secs = config.connect_timeout_secs;
+        current.read_timeout_secs = config.read_timeout_secs;
+        current.tls_policy = config.tls_policy;
+    });
+    Ok(get_config())
+}
+
+/// Read the configuration of the profile just opened or closed; settings it never saved keep
+/// their defaults, as do unreadable ones
+pub(crate) fn load() {
+    let stored = |key| settings::get(key).ok().flatten();
+    let defaults = Config::default();
+    let config = Config {
+        default_scheme: stored(DEFAULT_SCHEME_KEY).unwrap_or(defaults.default_scheme),
+        guess_schemes: stored(GUESS_SCHEMES_KEY).is_none_or(|value| value != "0"),
+        start_page: start_page::stored_start_page(),
+        search_provider: search::stored_search_provider(),
+        connect_timeout_secs: stored(CONNECT_TIMEOUT_KEY)
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(defaults.connect_timeout_secs),
+        read_timeout_secs: stored(READ_TIMEOUT_KEY).and_then(|secs| secs.parse().ok()),
+        tls_policy: stored(TLS_POLICY_KEY)
+            .map_or(defaults.tls_policy, |policy| TlsPolicy::parse(&policy)),
+        proxy: proxy::settings(),
+    };
+    *CONFIG.write().unwrap() = config;
+}
+
+/// Read one value of the current configuration without copying the rest
+pub(crate) fn current<T>(f: impl FnOnce(&Config) -> T) -> T {
+    f(&CONFIG.read().unwrap())
+}
+
+/// Change the current configuration after its settings were saved, e.g. by `set_start_page`
+pub(crate) fn update(f: impl FnOnce(&mut Config)) {
+    f(&mut CONFIG.write().unwrap());
+}
@@ -0,0 +1,115 @@
+use url::Url;
+
+use crate::api::models::fetch_response::GOPHER_MENU_MIME;
+use crate::api::protocols::registry;
+use crate::api::storage::gemini_proxies;
+
+/// How the UI should present a response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Renderer {
+    /// A text/gemini document, parsed into lines
+    Gemtext,
+    /// A Gopher menu of typed items
+    GopherMenu,
+    /// Text shown as is, monospaced where the protocol expects it
+    PlainText,
+    Markdown,
+    /// An image shown inline
+    Image,
+    /// Content Holloway cannot show, offered for saving instead
+    Download,
+    /// Something for another application: a scheme no handler serves, a telnet session, or a
+    /// web link from a Gopher menu
+    External,
+}
+
+/// Decide how to present content of a MIME type fetched over a scheme; `item_type` is the
+/// Gopher item type of the URL, if any, which can override what the content says
+pub fn route(scheme: &str, mime: &str, item_type: Option<char>) -> Renderer {
+    if registry::handler_for(scheme).is_none() && !is_proxied(scheme) {
+        return Renderer::External;
+    }
+    if scheme.eq_ignore_ascii_case("gopher") && matches!(item_type, Some('8') | Some('T')) {
+        return Renderer::External;
+    }
+
+    match essence(mime).as_str() {
+        "text/gemini" => Renderer::Gemtext,
+        GOPHER_MENU_MIME => Renderer::GopherMenu,
+        "text/markdown" | "text/x-markdown" => Renderer::Markdown,
+        // Small-internet browsers show HTML as its source rather than rendering it
+        essence if essence.starts_with("text/") => Renderer::PlainText,
+        essence if essence.starts_with("image/") => Renderer::Image,
+        _ => Renderer::Download,
+    }
+}
+
+/// Decide how to present content of a MIME type fetched from a URL, taking the scheme and any
+/// Gopher item type from the URL; Gopher web links (`h` items with a `URL:` selector) leave
+/// the browser
+pub fn route_url(url: &str, mime: &str) -> Renderer {
+    let Ok(url) = Url::parse(url) else {
+        return Renderer::External;
+    };
+    let path = url.path().trim_start_matches('/');
+    let item_type = url
+        .scheme()
+        .eq_ignore_ascii_case("gopher")
+        .then(|| path.chars().next())
+        .flatten();
+    if item_type == Some('h') && path[1..].starts_with("URL:") {
+        return Renderer::External;
+    }
+    route(url.scheme(), mime, item_type)
+}
+
+/// Whether a MIME type names a gemtext document, whatever its parameters
+pub fn is_gemtext(mime: &str) -> bool {
+    essence(mime) == "text/gemini"
+}
+
+/// The charset parameter of a MIME type, lowercased; gemtext without one is UTF-8 by the
+/// Gemini specification
+pub fn charset(mime: &str) -> Option<String> {
+    let named = mime.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_ascii_lowercase())
+    });
+    named.or_else(|| is_gemtext(mime).then(|| "utf-8".to_string()))
+}
+
+/// A text MIME type with its charset parameter replaced, or added when it has none; other
+/// types are returned unchanged
+pub(crate) fn with_charset(mime: &str, charset: &str) -> String {
+    if !essence(mime).starts_with("text/") {
+        return mime.to_string();
+    }
+    let mut parts: Vec<&str> = mime
+        .split(';')
+        .map(str::trim)
+        .filter(|part| {
+            !part
+                .split_once('=')
+                .is_some_and(|(name, _)| name.trim().eq_ignore_ascii_case("charset"))
+        })
+        .collect();
+    let charset = format!("charset={}", charset);
+    parts.push(&charset);
+    parts.join("; ")
+}
+
+/// Whether URLs of a scheme are fetched through a Gemini proxy or Gopher gateway
+fn is_proxied(scheme: &str) -> bool {
+    gemini_proxies::proxy_for(scheme).ok().flatten().is_some()
+}
+
+/// A MIME type without parameters such as charset, lowercased
+fn essence(mime: &str) -> String {
+    mime.split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase()
+}
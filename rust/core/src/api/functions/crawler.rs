@@ -0,0 +1,151 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::links::{extract_links, LinkKind};
+use crate::api::functions::navigate_internal::{fetch_with_options, parse_input_url};
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::storage::page_index::{self, LocalSearchResult};
+
+/// Most pages one crawl may visit, whatever the caller asks for
+const MAX_CRAWL_PAGES: u32 = 1000;
+
+/// Bumped by every `cancel_capsule_indexing` call so running crawls stop before their next page
+static CRAWL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// What a crawl of a capsule did
+#[derive(Debug, Clone, Default)]
+pub struct CrawlResult {
+    /// The URL prefix the crawl kept to and `search_capsule` searches under
+    pub capsule: String,
+    /// Pages fetched and added to the local index, or found there already
+    pub indexed: u32,
+    /// Pages whose fetch failed or that answered with an error status
+    pub failed: u32,
+    /// Pages the capsule's robots.txt keeps crawlers out of
+    pub disallowed: u32,
+    /// Pages found but left unvisited once `max_pages` were visited
+    pub remaining: u32,
+    /// Set when `cancel_capsule_indexing` stopped the crawl
+    pub cancelled: bool,
+}
+
+/// Walk the pages of the capsule `url` belongs to, following the links of its gemtext pages and
+/// Gopher menus breadth first, so they are cached and added to the local full-text index for
+/// `search_capsule`. A capsule is the URL's host and port and the directory of its path, so
+/// crawling gemini://host/~user/ keeps to that user's pages; Gopher selectors start with an
+/// item type rather than a directory, so a Gopher crawl covers the whole host. At most `max_pages` pages (up to
+/// 1000) are visited, one at a time; the fetches are background requests, which keep to the
+/// host's robots.txt and rate limits and add no visits to history. Pages cached and not yet
+/// expired are taken from the cache.
+pub async fn index_capsule(url: &str, max_pages: u32) -> Result<CrawlResult, String> {
+    let generation = CRAWL_GENERATION.load(Ordering::SeqCst);
+    let start = parse_input_url(url).ok_or("Invalid URL format")?;
+    let root = capsule_root(&start)?;
+    let max_pages = max_pages.clamp(1, MAX_CRAWL_PAGES);
+    let options = FetchOptions {
+        use_cache: true,
+        background: true,
+        ..FetchOptions::default()
+    };
+
+    let mut result = CrawlResult {
+        capsule: root.to_string(),
+        ..CrawlResult::default()
+    };
+    let start = canonicalize(&start).to_string();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    let mut visited = 0;
+    while let Some(page_url) = queue.pop_front() {
+        if visited == max_pages {
+            result.remaining = queue.len() as u32 + 1;
+            break;
+        }
+        if CRAWL_GENERATION.load(Ordering::SeqCst) != generation {
+            result.cancelled = true;
+            result.remaining = queue.len() as u32 + 1;
+            break;
+        }
+        visited += 1;
+        let response = match fetch_with_options(page_url, &options).await {
+            Ok(response) if response.is_success() => response,
+            Ok(_) => {
+                result.failed += 1;
+                continue;
+            }
+            Err(NavigateError::Disallowed(_)) => {
+                result.disallowed += 1;
+                continue;
+            }
+            Err(_) => {
+                result.failed += 1;
+                continue;
+            }
+        };
+        result.indexed += 1;
+
+        // A redirect out of the capsule leaves its links unfollowed
+        let in_capsule = Url::parse(&response.url).is_ok_and(|url| within(&root, &url));
+        if !in_capsule {
+            continue;
+        }
+        for link in extract_links(&response.url, &response.mime, &response.body) {
+            if link.kind != LinkKind::Page {
+                continue;
+            }
+            let Ok(link_url) = Url::parse(&link.url) else {
+                continue;
+            };
+            let link_url = canonicalize(&link_url);
+            if within(&root, &link_url) && seen.insert(link_url.to_string()) {
+                queue.push_back(link_url.to_string());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Stop the running crawls before their next page
+pub fn cancel_capsule_indexing() {
+    CRAWL_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Search the indexed pages of the capsule `url` belongs to, as `index_capsule` defines it;
+/// see `search_local`
+pub fn search_capsule(
+    url: &str,
+    query: &str,
+    limit: u32,
+) -> Result<Vec<LocalSearchResult>, String> {
+    let url = parse_input_url(url).ok_or("Invalid URL format")?;
+    let root = capsule_root(&url)?;
+    page_index::search_within(query, Some(root.as_str()), limit)
+}
+
+/// The canonical URL of the directory a page is in, or of its host for Gopher, without query
+/// or fragment
+pub(crate) fn capsule_root(url: &Url) -> Result<Url, String> {
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err("Invalid host in URL".to_string());
+    }
+    let mut root = canonicalize(url);
+    root.set_query(None);
+    let directory = match root.path().rfind('/') {
+        Some(slash) if root.scheme() != "gopher" => root.path()[..=slash].to_string(),
+        _ => "/".to_string(),
+    };
+    root.set_path(&directory);
+    Ok(root)
+}
+
+/// Whether a canonical URL is a page of the capsule at `root`
+pub(crate) fn within(root: &Url, url: &Url) -> bool {
+    url.scheme() == root.scheme()
+        && url.host_str() == root.host_str()
+        && url.port() == root.port()
+        && url.path().starts_with(root.path())
+}
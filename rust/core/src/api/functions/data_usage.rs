@@ -0,0 +1,94 @@
+use std::sync::{LazyLock, RwLock};
+
+use crate::api::storage::data_usage::{self, NetworkType};
+use crate::api::storage::settings;
+
+/// Settings key of the soft cap on cellular traffic
+const DATA_CAP_KEY: &str = "cellular_data_cap_bytes";
+
+/// Settings key of lite mode
+const LITE_MODE_KEY: &str = "lite_mode";
+
+/// The cap counts cellular traffic over this many days, ending today
+pub const DATA_CAP_PERIOD_DAYS: u32 = 30;
+
+/// The network the device is on, as last reported by the app
+static NETWORK: LazyLock<RwLock<NetworkType>> = LazyLock::new(Default::default);
+
+/// How much of the cellular soft cap has been used
+#[derive(Debug, Clone)]
+pub struct DataCapStatus {
+    /// None when no cap is set
+    pub limit_bytes: Option<u64>,
+    /// Cellular bytes over the last DATA_CAP_PERIOD_DAYS days
+    pub used_bytes: u64,
+    pub exceeded: bool,
+}
+
+/// Record which network the device is on; the app calls this whenever connectivity changes
+pub fn set_network_type(network: NetworkType) {
+    *NETWORK.write().unwrap() = network;
+}
+
+pub fn network_type() -> NetworkType {
+    *NETWORK.read().unwrap()
+}
+
+/// Count traffic of a request against the current network type
+pub(crate) fn record(bytes_sent: u64, bytes_received: u64) {
+    if bytes_sent == 0 && bytes_received == 0 {
+        return;
+    }
+    // Accounting is best-effort and must not fail the request
+    let _ = data_usage::record_usage(network_type(), bytes_sent, bytes_received);
+}
+
+/// Set or remove (None) the soft cap on cellular traffic
+pub fn set_data_cap(limit_bytes: Option<u64>) -> Result<(), String> {
+    match limit_bytes {
+        Some(limit) => settings::set(DATA_CAP_KEY, &limit.to_string()),
+        None => settings::remove(DATA_CAP_KEY),
+    }
+}
+
+/// The cellular soft cap and how much of it is used
+pub fn data_cap_status() -> Result<DataCapStatus, String> {
+    let limit_bytes = settings::get(DATA_CAP_KEY)?.and_then(|limit| limit.parse().ok());
+    let used_bytes = data_usage::recent_total(NetworkType::Cellular, DATA_CAP_PERIOD_DAYS)?;
+    Ok(DataCapStatus {
+        limit_bytes,
+        used_bytes,
+        exceeded: limit_bytes.is_some_and(|limit| used_bytes >= limit),
+    })
+}
+
+/// Whether lite mode is on: no links are prefetched and no inline images fetched, so only pages
+/// the user opens use data
+pub fn lite_mode() -> bool {
+    settings::get(LITE_MODE_KEY)
+        .ok()
+        .flatten()
+        .is_some_and(|value| value == "1")
+}
+
+/// Turn lite mode on or off, e.g. for browsing over mobile data
+pub fn set_lite_mode(enabled: bool) -> Result<(), String> {
+    if enabled {
+        settings::set(LITE_MODE_KEY, "1")
+    } else {
+        settings::remove(LITE_MODE_KEY)
+    }
+}
+
+/// Whether speculative fetches (prefetching links, refreshing feeds in the background) should
+/// run: they pause in lite mode and on cellular once the soft cap is exceeded, while user
+/// navigation always works
+pub fn prefetch_allowed() -> bool {
+    if lite_mode() {
+        return false;
+    }
+    if network_type() != NetworkType::Cellular {
+        return true;
+    }
+    data_cap_status().map_or(true, |status| !status.exceeded)
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use crate::api::functions::anchors::{outline, OutlineEntry};
+use crate::api::functions::inline_images::{fetch_inline_images, InlineImages};
+use crate::api::functions::reading_stats::{reading_stats, ReadingStats};
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+
+/// A parsed document with what is computed once when it is opened
+struct Document {
+    lines: Vec<GemtextLine>,
+    stats: ReadingStats,
+}
+
+/// Parsed gemtext documents kept on the Rust side, so the UI fetches only the lines it shows
+static DOCUMENTS: LazyLock<Mutex<HashMap<u64, Arc<Document>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+fn document(handle: u64) -> Result<Arc<Document>, String> {
+    DOCUMENTS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown document {}", handle))
+}
+
+/// Parse a gemtext body and keep it for ranged access with `get_lines`, returning its handle
+pub fn open_document(body: &str) -> u64 {
+    let lines = parse_gemtext(body);
+    let stats = reading_stats(&lines);
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let document = Document { lines, stats };
+    DOCUMENTS.lock().unwrap().insert(handle, Arc::new(document));
+    handle
+}
+
+/// Number of lines in a retained document
+pub fn line_count(handle: u64) -> Result<u32, String> {
+    Ok(document(handle)?.lines.len() as u32)
+}
+
+/// Up to `count` lines of a retained document starting at `start`; empty past the end
+pub fn get_lines(handle: u64, start: u32, count: u32) -> Result<Vec<GemtextLine>, String> {
+    let document = document(handle)?;
+    let lines = &document.lines;
+    let start = (start as usize).min(lines.len());
+    let end = start.saturating_add(count as usize).min(lines.len());
+    Ok(lines[start..end].to_vec())
+}
+
+/// The heading hierarchy of a retained document, with the line index of each heading
+pub fn document_outline(handle: u64) -> Result<Vec<OutlineEntry>, String> {
+    Ok(outline(&document(handle)?.lines))
+}
+
+/// Word count, reading time and link count of a retained document, computed when it was opened
+pub fn document_stats(handle: u64) -> Result<ReadingStats, String> {
+    Ok(document(handle)?.stats.clone())
+}
+
+/// Fetch the images linked from a retained document fetched from `page_url`, for showing them
+/// inline; see `fetch_inline_images`
+pub async fn document_images(
+    handle: u64,
+    page_url: &str,
+    max_bytes: Option<u32>,
+) -> Result<InlineImages, String> {
+    let document = document(handle)?;
+    fetch_inline_images(page_url, &document.lines, max_bytes).await
+}
+
+/// Release a retained document, returning whether it existed
+pub fn close_document(handle: u64) -> bool {
+    DOCUMENTS.lock().unwrap().remove(&handle).is_some()
+}
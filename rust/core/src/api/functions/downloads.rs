@@ -0,0 +1,395 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use percent_encoding::percent_decode_str;
+#[cfg(not(target_arch = "wasm32"))]
+use sha2::{Digest, Sha256};
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::fs::File;
+use tokio::io::AsyncRead;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use unicode_normalization::UnicodeNormalization;
+use url::Url;
+
+use crate::api::functions::checksums;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::api::functions::digest::to_hex;
+use crate::api::functions::file_type::extension_for_mime;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::functions::navigation_events::NavigationTracker;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::api::functions::navigation_events::PROGRESS_STEP_BYTES;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::protocols::registry;
+use crate::api::storage::downloads::{
+    record_checksum, record_download, ChecksumStatus, DownloadStatus,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::api::transport::limits::BODY_LIMIT_ERROR_PREFIX;
+use crate::api::transport::timeouts::{self, Timeouts};
+use crate::api::transport::handshakes;
+
+/// Longest file name most file systems accept, in bytes
+const MAX_FILENAME_BYTES: usize = 255;
+
+/// Name used when nothing usable is left of the URL
+const FALLBACK_STEM: &str = "download";
+
+/// Suffix of the file a download is written to until it completes
+const PARTIAL_SUFFIX: &str = ".part";
+
+/// A file saved by `download`
+#[derive(Debug, Clone)]
+pub struct DownloadResult {
+    pub path: String,
+    /// Bytes written
+    pub size: u64,
+    pub mime: String,
+    /// SHA-256 of the file as lowercase hex
+    pub sha256: String,
+    /// How the file compared with the checksum it was downloaded with
+    pub checksum: ChecksumStatus,
+}
+
+/// Device names Windows refuses as file names, with or without an extension
+const RESERVED_WINDOWS_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Derive a safe file name for downloading a URL, adding an extension for the MIME type if it has none
+pub fn suggest_filename(url: &str, mime: &str) -> String {
+    let raw = Url::parse(url)
+        .ok()
+        .and_then(|url| last_segment(&url))
+        .unwrap_or_default();
+    let mut name = sanitize_filename(&raw);
+
+    if !name.contains('.') {
+        if let Some(extension) = extension_for_mime(mime) {
+            name = truncate_filename(&format!("{}.{}", name, extension));
+        }
+    }
+    name
+}
+
+/// The last non-empty path segment, percent-decoded; Gopher paths lose their item type first
+fn last_segment(url: &Url) -> Option<String> {
+    let mut path = url.path();
+    if url.scheme() == "gopher" {
+        let selector = path.trim_start_matches('/');
+        let mut chars = selector.chars();
+        chars.next();
+        path = chars.as_str();
+    }
+    let segment = path.rsplit('/').find(|segment| !segment.is_empty())?;
+    Some(percent_decode_str(segment).decode_utf8_lossy().to_string())
+}
+
+/// Make an untrusted name safe to create on any platform the app runs on
+pub fn sanitize_filename(raw: &str) -> String {
+    let cleaned: String = raw
+        .nfc()
+        .map(|c| match c {
+            '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    // Leading dots make hidden files (or "..") and Windows strips trailing dots and spaces
+    let trimmed = cleaned.trim_matches(|c: char| c == '.' || c.is_whitespace());
+    let mut name = if trimmed.is_empty() {
+        FALLBACK_STEM.to_string()
+    } else {
+        trimmed.to_string()
+    };
+
+    let stem = name.split('.').next().unwrap_or("").trim_end();
+    if RESERVED_WINDOWS_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+    {
+        name.insert(0, '_');
+    }
+    truncate_filename(&name)
+}
+
+/// Shorten a name to the file-system limit, keeping a short extension intact
+fn truncate_filename(name: &str) -> String {
+    if name.len() <= MAX_FILENAME_BYTES {
+        return name.to_string();
+    }
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if extension.len() <= 16 => (stem, format!(".{}", extension)),
+        _ => (name, String::new()),
+    };
+    let mut end = MAX_FILENAME_BYTES - extension.len();
+    while !stem.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &stem[..end], extension)
+}
+
+/// A path for the file name in the directory that does not exist yet,
+/// numbering duplicates like "file (1).png"
+pub fn unique_path(dir: &Path, filename: &str) -> PathBuf {
+    let candidate = dir.join(filename);
+    if !candidate.exists() {
+        return candidate;
+    }
+
+    let (stem, extension) = match filename.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{}", extension)),
+        _ => (filename, String::new()),
+    };
+    (1..)
+        .map(|n| dir.join(truncate_filename(&format!("{} ({}){}", stem, n, extension))))
+        .find(|candidate| !candidate.exists())
+        .expect("some numbered name is free")
+}
+
+/// Save a URL of any registered protocol to a file, streaming the body to disk so it is never
+/// held in memory; the file only appears at `dest_path` once it is complete. The request keeps to
+/// the options' timeouts, deadline, and body limit. Downloads are kept in the download history
+/// unless the request is private, which also pins no certificates and resumes no TLS sessions.
+pub async fn download(
+    url: &str,
+    dest_path: &str,
+    options: &FetchOptions,
+) -> Result<DownloadResult, String> {
+    download_verified(url, dest_path, None, options).await
+}
+
+/// `download`, then check the file against a checksum file published alongside it, such as the
+/// one `checksum_link` finds on the page linking the file. A file that does not match is kept,
+/// its download recorded as completed with the mismatch, for the user to decide what to do.
+pub async fn download_verified(
+    url: &str,
+    dest_path: &str,
+    checksum_url: Option<&str>,
+    options: &FetchOptions,
+) -> Result<DownloadResult, String> {
+    let tracker = NavigationTracker::start(url)
+        .with_max_body_bytes(options.max_body_bytes)
+        .with_private(options.private);
+    let timeouts = Timeouts::from_secs(
+        options.connect_timeout_secs,
+        options.read_timeout_secs,
+        options.deadline_secs,
+    );
+    let deadline = options
+        .deadline_secs
+        .and_then(|secs| tracker.arm_deadline(Duration::from_secs(secs as u64)));
+    // Kept on the heap like a navigation's dispatch, which is as large
+    let saved = Box::pin(save(url, dest_path, options, &tracker));
+    let saved = handshakes::scope(options.background, saved);
+    let result = tracker.scope(timeouts::scope(timeouts, saved)).await;
+    drop(deadline);
+    let result = match result {
+        Err(_) if tracker.deadline_exceeded() => Err(format!(
+            "Request timed out: it took longer than its deadline of {} seconds",
+            options.deadline_secs.unwrap_or_default()
+        )),
+        result => result,
+    };
+    let result = match (result, checksum_url) {
+        (Ok(mut saved), Some(checksum_url)) => {
+            saved.checksum = checksums::verify(checksum_url, url, &saved.sha256, options).await;
+            Ok(saved)
+        }
+        (result, _) => result,
+    };
+    match &result {
+        Ok(saved) => {
+            tracker.finished(None, saved.size);
+            if !options.private {
+                crate::api::functions::data_usage::record(url.len() as u64 + 2, saved.size);
+                // The file is saved; failing to note it in the history must not fail the download
+                let id = record_download(
+                    url,
+                    &saved.path,
+                    saved.size,
+                    &saved.mime,
+                    DownloadStatus::Completed,
+                    None,
+                );
+                if let (Ok(id), Some(checksum_url)) = (id, checksum_url) {
+                    let _ = record_checksum(id, checksum_url, saved.checksum);
+                }
+            }
+        }
+        Err(e) => {
+            tracker.failed(e, 0);
+            if !options.private {
+                let _ = record_download(url, dest_path, 0, "", DownloadStatus::Failed, Some(e));
+            }
+        }
+    }
+    result
+}
+
+async fn save(
+    url: &str,
+    dest_path: &str,
+    options: &FetchOptions,
+    tracker: &NavigationTracker,
+) -> Result<DownloadResult, String> {
+    let mut parsed_url = parse_input_url(url).ok_or("Invalid URL format")?;
+    parsed_url.set_fragment(None);
+    crate::api::storage::blocklist::check_url(&parsed_url)?;
+    crate::api::storage::scheme_toggles::check_url(&parsed_url)?;
+    let handler = registry::handler_for(parsed_url.scheme()).ok_or_else(|| {
+        format!(
+            "Unsupported URL scheme. Only {} are supported.",
+            registry::registered_schemes().join(", ")
+        )
+    })?;
+
+    let body = handler.open_body(&parsed_url).await?;
+    let partial = format!("{}{}", dest_path, PARTIAL_SUFFIX);
+    let written = write_body(body.reader, &partial, options.max_body_bytes, tracker)
+        .await
+        .and_then(|written| {
+            // A cancelled read may end like a complete body, which must not be saved as one
+            crate::api::functions::cancellation::check_cancelled()?;
+            std::fs::rename(&partial, dest_path)
+                .map_err(|e| format!("Failed to save {}: {}", dest_path, e))?;
+            Ok(written)
+        });
+    let (size, sha256) = match written {
+        Ok(written) => written,
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            return Err(e);
+        }
+    };
+    Ok(DownloadResult {
+        path: dest_path.to_string(),
+        size,
+        mime: body.mime,
+        sha256,
+        checksum: ChecksumStatus::Unchecked,
+    })
+}
+
+/// Copy a body into a new file chunk by chunk, up to `max_bytes`, returning its size and SHA-256
+#[cfg(not(target_arch = "wasm32"))]
+async fn write_body(
+    mut reader: impl AsyncRead + Unpin,
+    path: &str,
+    max_bytes: Option<u64>,
+    tracker: &NavigationTracker,
+) -> Result<(u64, String), String> {
+    let mut file = File::create(path)
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    let mut size = 0;
+    let mut reported = 0;
+    loop {
+        let read = match reader.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(format!("Failed to read response: {}", e)),
+        };
+        file.write_all(&buffer[..read])
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+        if let Some(max) = max_bytes.filter(|&max| size > max) {
+            return Err(format!(
+                "{}: more than the limit of {} bytes",
+                BODY_LIMIT_ERROR_PREFIX, max
+            ));
+        }
+        if size - reported >= PROGRESS_STEP_BYTES {
+            reported = size;
+            tracker.progress(size);
+        }
+    }
+    file.sync_all()
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    Ok((size, to_hex(&hasher.finalize())))
+}
+
+/// Browsers have no file system to save a download to
+#[cfg(target_arch = "wasm32")]
+async fn write_body(
+    _: impl AsyncRead + Unpin,
+    path: &str,
+    _: Option<u64>,
+    _: &NavigationTracker,
+) -> Result<(u64, String), String> {
+    Err(format!(
+        "Failed to create {}: web builds cannot save files",
+        path
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_from_urls() {
+        assert_eq!(
+            suggest_filename("gemini://example.org/files/report", "application/pdf"),
+            "report.pdf"
+        );
+        assert_eq!(
+            suggest_filename(
+                "gopher://example.org/9/pub/tool%20kit.zip",
+                "application/zip"
+            ),
+            "tool kit.zip"
+        );
+        assert_eq!(
+            suggest_filename("gemini://example.org/", "text/gemini"),
+            "download.gmi"
+        );
+    }
+
+    #[test]
+    fn path_traversal_and_separators() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), "_.._etc_passwd");
+        assert_eq!(sanitize_filename("a\\b:c*d?.txt"), "a_b_c_d_.txt");
+        assert_eq!(sanitize_filename("line\nbreak"), "line_break");
+        assert_eq!(sanitize_filename(" .. "), FALLBACK_STEM);
+    }
+
+    #[test]
+    fn reserved_windows_names() {
+        assert_eq!(sanitize_filename("CON"), "_CON");
+        assert_eq!(sanitize_filename("lpt1.txt"), "_lpt1.txt");
+        assert_eq!(sanitize_filename("console.txt"), "console.txt");
+    }
+
+    #[test]
+    fn unicode_is_normalized() {
+        assert_eq!(sanitize_filename("cafe\u{301}.txt"), "caf\u{e9}.txt");
+    }
+
+    #[test]
+    fn overlong_names_keep_their_extension() {
+        let name = sanitize_filename(&format!("{}.png", "\u{e9}".repeat(200)));
+        assert!(name.len() <= MAX_FILENAME_BYTES);
+        assert!(name.ends_with("\u{e9}.png"));
+    }
+
+    #[test]
+    fn collisions_are_numbered() {
+        let dir = std::env::temp_dir().join(format!("holloway-names-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("file.png"), b"").unwrap();
+        std::fs::write(dir.join("file (1).png"), b"").unwrap();
+        assert_eq!(unique_path(&dir, "file.png"), dir.join("file (2).png"));
+        assert_eq!(unique_path(&dir, "other.png"), dir.join("other.png"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use time::OffsetDateTime;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::digest::sha256_hex;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::server_failure::{ServerFailure, ServerFailureKind};
+use crate::api::storage::cache;
+
+/// Prefix of the about: page that shows the cached copy of the URL in its query
+pub const CACHED_COPY_PREFIX: &str = "about:cached?";
+
+/// The about: URL showing the cached copy of a URL
+pub fn cached_copy_url(url: &str) -> String {
+    format!(
+        "{}{}",
+        CACHED_COPY_PREFIX,
+        utf8_percent_encode(url, NON_ALPHANUMERIC)
+    )
+}
+
+/// Replace the body of a Gemini 4x or 5x response, which the specification leaves empty, with
+/// a gemtext page explaining the failure, so every client renders it the same way
+pub(crate) fn show_failure_page(response: &mut FetchResponse, failure: &ServerFailure) {
+    let cached_at = Url::parse(&response.url).ok().and_then(|url| {
+        cache::fetched_at(canonicalize(&url).as_str())
+            .ok()
+            .flatten()
+    });
+    let (title, page) = failure_page(&response.url, failure, cached_at);
+    response.body_hash = sha256_hex(page.as_bytes());
+    response.body = page;
+    response.title = Some(title);
+}
+
+/// The title and gemtext of the error page for a failed URL: what went wrong in plain words,
+/// the server's own message, a link to try again for temporary failures, and a link to the
+/// cached copy when there is one
+pub fn failure_page(
+    url: &str,
+    failure: &ServerFailure,
+    cached_at: Option<i64>,
+) -> (String, String) {
+    let (title, explanation) = match failure.kind {
+        ServerFailureKind::TemporaryFailure => (
+            "Temporary failure",
+            "The server could not serve this page right now.",
+        ),
+        ServerFailureKind::ServerUnavailable => (
+            "Server unavailable",
+            "The server is down for maintenance or too busy to answer.",
+        ),
+        ServerFailureKind::CgiError => (
+            "Server program failed",
+            "The program that generates this page failed on the server.",
+        ),
+        ServerFailureKind::ProxyError => (
+            "Proxy error",
+            "The server could not fetch this page from the host it proxies.",
+        ),
+        ServerFailureKind::SlowDown => (
+            "Slow down",
+            "The server limits how often it may be asked, and was asked too often.",
+        ),
+        ServerFailureKind::PermanentFailure => {
+            ("Permanent failure", "The server will not serve this page.")
+        }
+        ServerFailureKind::NotFound => (
+            "Page not found",
+            "There is no page at this address. It may have moved, or the link may be mistyped.",
+        ),
+        ServerFailureKind::Gone => ("Page gone", "This page was removed and will not come back."),
+        ServerFailureKind::ProxyRequestRefused => (
+            "Request refused",
+            "The server does not serve this host or protocol.",
+        ),
+        ServerFailureKind::BadRequest => (
+            "Bad request",
+            "The server could not understand the request for this address.",
+        ),
+    };
+
+    let mut page = format!("# {}\n\n{}\n\n", title, explanation);
+    // The meta line is the server's text, so it is quoted rather than read as gemtext
+    let message = failure.message.trim();
+    if !message.is_empty() && failure.retry_after_secs.is_none() {
+        page.push_str(&format!("> {}\n\n", message));
+    }
+    page.push_str(&format!("Status {} for {}\n\n", failure.status, url));
+    match failure.retry_after_secs {
+        Some(secs) => page.push_str(&format!("=> {} Try again in {} seconds\n", url, secs)),
+        None if failure.temporary => page.push_str(&format!("=> {} Try again\n", url)),
+        None => {}
+    }
+    if let Some(date) = cached_at
+        .and_then(|cached_at| OffsetDateTime::from_unix_timestamp(cached_at).ok())
+        .map(|cached_at| cached_at.date())
+    {
+        page.push_str(&format!(
+            "=> {} View the copy cached on {}\n",
+            cached_copy_url(url),
+            date
+        ));
+    }
+    (title.to_string(), page)
+}
@@ -0,0 +1,166 @@
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::content_router::Renderer;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::parsers::gophermap::parse_gophermap;
+use crate::api::protocols::registry;
+use crate::api::storage::{bookmarks, cache};
+
+/// Where the "explore the smolnet" button may take the user
+#[derive(Debug, Clone)]
+pub struct ExploreSource {
+    /// Directory or aggregator page whose links to other hosts are candidates; None picks among
+    /// the bookmarks
+    pub directory_url: Option<String>,
+    /// Relative chance of picking from this source; sources without candidates are skipped
+    pub weight: f64,
+}
+
+/// A randomly picked page
+#[derive(Debug, Clone)]
+pub struct RandomDestination {
+    pub url: String,
+    /// Link label or bookmark title
+    pub label: Option<String>,
+    /// Directory the page was listed in, or None when it is a bookmark
+    pub directory_url: Option<String>,
+}
+
+/// Pick a random page: first a source, weighted, then one of its candidates uniformly.
+/// Directories are served from the cache when fresh and from a stale cached copy when they
+/// cannot be fetched, so exploring works offline once each directory was seen
+pub async fn random_destination(sources: Vec<ExploreSource>) -> Result<RandomDestination, String> {
+    let mut pools = Vec::new();
+    for source in sources {
+        if source.weight.is_nan() || source.weight <= 0.0 {
+            continue;
+        }
+        let candidates = match &source.directory_url {
+            Some(url) => directory_links(url).await,
+            None => bookmark_candidates()?,
+        };
+        if !candidates.is_empty() {
+            pools.push((source.weight, candidates));
+        }
+    }
+
+    let total: f64 = pools.iter().map(|(weight, _)| weight).sum();
+    let mut point = random_fraction() * total;
+    let pool = pools
+        .iter()
+        .find(|(weight, _)| {
+            point -= weight;
+            point < 0.0
+        })
+        // Rounding can leave the point just past the last pool
+        .or(pools.last())
+        .map(|(_, candidates)| candidates)
+        .ok_or("No destinations to explore: the sources list no pages")?;
+    Ok(pool[random_below(pool.len())].clone())
+}
+
+/// Bookmarked pages of schemes that can still be opened
+fn bookmark_candidates() -> Result<Vec<RandomDestination>, String> {
+    Ok(bookmarks::list_bookmarks()?
+        .into_iter()
+        .filter(|bookmark| {
+            Url::parse(&bookmark.url).is_ok_and(|url| registry::handler_for(url.scheme()).is_some())
+        })
+        .map(|bookmark| RandomDestination {
+            url: bookmark.url,
+            label: bookmark.title,
+            directory_url: None,
+        })
+        .collect())
+}
+
+/// Links of a directory page that lead to other hosts, each listed once; a directory that
+/// cannot be fetched or read offers none
+async fn directory_links(directory_url: &str) -> Vec<RandomDestination> {
+    let Some(page) = directory_page(directory_url).await else {
+        return Vec::new();
+    };
+    let Ok(base) = Url::parse(&page.url) else {
+        return Vec::new();
+    };
+
+    let links: Vec<(Option<Url>, Option<String>)> = match page.renderer {
+        Renderer::Gemtext => parse_gemtext(&page.body)
+            .into_iter()
+            .filter_map(|line| match line {
+                GemtextLine::Link { url, label } => Some((base.join(&url).ok(), label)),
+                _ => None,
+            })
+            .collect(),
+        Renderer::GopherMenu => parse_gophermap(&page.body)
+            .into_iter()
+            .filter(|item| item.fetchable)
+            .map(|item| {
+                let url = item.to_url().and_then(|url| Url::parse(&url).ok());
+                (url, Some(item.display))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut candidates: Vec<RandomDestination> = Vec::new();
+    for (url, label) in links {
+        let Some(url) = url else {
+            continue;
+        };
+        // Links back into the directory itself are navigation, not destinations
+        if url.host_str().is_none()
+            || url.host_str() == base.host_str()
+            || registry::handler_for(url.scheme()).is_none()
+        {
+            continue;
+        }
+        let url = url.to_string();
+        if candidates.iter().all(|candidate| candidate.url != url) {
+            candidates.push(RandomDestination {
+                url,
+                label,
+                directory_url: Some(directory_url.to_string()),
+            });
+        }
+    }
+    candidates
+}
+
+/// The directory page, fetched without touching history, or its last cached copy
+async fn directory_page(directory_url: &str) -> Option<FetchResponse> {
+    let options = FetchOptions {
+        use_cache: true,
+        background: true,
+        ..FetchOptions::default()
+    };
+    match fetch_with_options(directory_url.to_string(), &options).await {
+        Ok(page) if page.is_success() => Some(page),
+        _ => {
+            let url = Url::parse(directory_url).ok()?;
+            cache::lookup_stale(canonicalize(&url).as_str())
+                .ok()
+                .flatten()
+        }
+    }
+}
+
+/// 62 random bits; a v4 UUID carries 122, its fixed version bits lying in the high 64 bits and
+/// its variant bits in the top two of the low 64
+fn random_bits() -> u64 {
+    uuid::Uuid::new_v4().as_u128() as u64 & ((1 << 62) - 1)
+}
+
+/// A uniformly distributed number in [0, 1)
+fn random_fraction() -> f64 {
+    (random_bits() >> 9) as f64 / (1u64 << 53) as f64
+}
+
+/// A uniformly distributed index below `n`
+fn random_below(n: usize) -> usize {
+    (random_bits() % n as u64) as usize
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use unicode_segmentation::UnicodeSegmentation;
+use url::Url;
+
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::favicons;
+use crate::api::storage::now;
+use crate::api::storage::ports::resolve_port;
+
+/// Longest favicon.txt body accepted; a single emoji with modifiers fits comfortably
+const MAX_FAVICON_BYTES: usize = 32;
+
+/// How long a fetched favicon, or a capsule's lack of one, is trusted before it is asked again
+const FAVICON_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Least time between two requests for one host's favicon, whatever came of the first, so a
+/// capsule that fails to answer is not asked again on every page
+const MIN_FETCH_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// When each host's favicon was last requested in this session
+static LAST_FETCH: LazyLock<Mutex<HashMap<String, Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Fetch a Gemini capsule's /favicon.txt and cache it for its host unless the request is private.
+/// Returns None when the capsule has no valid favicon; a host asked within the last ten minutes
+/// is not asked again, and its cached favicon is returned instead.
+pub async fn refresh_favicon(
+    page_url: &str,
+    options: &FetchOptions,
+) -> Result<Option<String>, String> {
+    let url = Url::parse(page_url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if url.scheme() != "gemini" || !crate::api::storage::scheme_toggles::is_enabled("gemini")? {
+        return Ok(None);
+    }
+    let host = url.host_str().ok_or("Invalid host in URL")?.to_string();
+    if !start_fetch(&host) {
+        return favicons::get_favicon(&host);
+    }
+    let port = resolve_port(&url, 1965);
+    let favicon_url = match url.port() {
+        Some(port) => format!("gemini://{}:{}/favicon.txt", host, port),
+        None => format!("gemini://{}/favicon.txt", host),
+    };
+
+    let response = crate::api::protocols::gemini::fetch_gemini(&host, port, &favicon_url).await?;
+    let icon = if (20..30).contains(&response.status) {
+        parse_favicon(&response.body)
+    } else {
+        None
+    };
+    if !options.private {
+        favicons::store_favicon(&host, icon.as_deref())?;
+    }
+    Ok(icon)
+}
+
+/// The cached favicon of the capsule a navigation landed on, for showing with the page. When
+/// it is older than a week or was never fetched, it is refreshed in the background for the
+/// next page; private navigations only read the cache.
+pub(crate) fn favicon_for_visit(url: &Url, options: &FetchOptions) -> Option<String> {
+    if url.scheme() != "gemini" {
+        return None;
+    }
+    let host = url.host_str()?;
+    let cached = favicons::cached_favicon(host).ok().flatten();
+    let fresh = cached
+        .as_ref()
+        .is_some_and(|(_, fetched_at)| now() - fetched_at < FAVICON_TTL_SECS);
+    if !fresh && !options.private {
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let (page_url, options) = (url.to_string(), options.clone());
+            runtime.spawn(async move {
+                // A capsule without a reachable favicon keeps showing none
+                let _ = refresh_favicon(&page_url, &options).await;
+            });
+        }
+    }
+    cached.and_then(|(icon, _)| icon)
+}
+
+/// Note a request for a host's favicon, returning false when one was made too recently
+fn start_fetch(host: &str) -> bool {
+    let mut last_fetch = LAST_FETCH.lock().unwrap();
+    let host = host.to_ascii_lowercase();
+    if last_fetch
+        .get(&host)
+        .is_some_and(|at| at.elapsed() < MIN_FETCH_INTERVAL)
+    {
+        return false;
+    }
+    last_fetch.insert(host, Instant::now());
+    true
+}
+
+/// A favicon.txt body is valid when it holds exactly one grapheme, such as an emoji
+fn parse_favicon(body: &str) -> Option<String> {
+    let icon = body.trim();
+    (icon.len() <= MAX_FAVICON_BYTES && icon.graphemes(true).count() == 1).then(|| icon.to_string())
+}
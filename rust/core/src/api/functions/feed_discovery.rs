@@ -0,0 +1,156 @@
+use url::Url;
+
+use crate::api::functions::content_router::{route_url, Renderer};
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::parsers::gophermap::parse_gophermap;
+
+/// The flavour of a discovered feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedKind {
+    Atom,
+    Rss,
+    /// A gemtext page following the Gemini subscription (gmisub) convention
+    Gemfeed,
+    /// An XML feed whose flavour is only known once it is fetched
+    Unknown,
+}
+
+/// A link on a page that looks like something the user could subscribe to
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedCandidate {
+    pub url: String,
+    pub title: Option<String>,
+    pub kind: FeedKind,
+}
+
+/// Scan a fetched page for likely feeds
+pub fn discover_feeds(page_url: &str, mime: &str, body: &str) -> Vec<FeedCandidate> {
+    let base = match Url::parse(page_url) {
+        Ok(url) => url,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut candidates = Vec::new();
+    match route_url(page_url, mime) {
+        Renderer::Gemtext => discover_in_gemtext(&base, body, &mut candidates),
+        Renderer::GopherMenu => discover_in_gophermap(body, &mut candidates),
+        _ => {}
+    }
+
+    // The same feed is often linked from both the header and the footer
+    let mut seen = Vec::new();
+    candidates.retain(|candidate| {
+        if seen.contains(&candidate.url) {
+            false
+        } else {
+            seen.push(candidate.url.clone());
+            true
+        }
+    });
+    candidates
+}
+
+fn discover_in_gemtext(base: &Url, body: &str, candidates: &mut Vec<FeedCandidate>) {
+    let lines = parse_gemtext(body);
+
+    let mut title = None;
+    let mut dated_links = 0;
+
+    for line in &lines {
+        match line {
+            GemtextLine::Heading { level: 1, text } if title.is_none() => {
+                title = Some(text.clone());
+            }
+            GemtextLine::Link { url, label } => {
+                if label.as_deref().is_some_and(starts_with_iso_date) {
+                    dated_links += 1;
+                }
+                if let Some(kind) = classify_link(url, label.as_deref()) {
+                    if let Ok(resolved) = base.join(url) {
+                        candidates.push(FeedCandidate {
+                            url: resolved.to_string(),
+                            title: label.clone(),
+                            kind,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // A titled page of dated links is itself subscribable as a gemfeed
+    if title.is_some() && dated_links > 0 {
+        candidates.insert(
+            0,
+            FeedCandidate {
+                url: base.to_string(),
+                title,
+                kind: FeedKind::Gemfeed,
+            },
+        );
+    }
+}
+
+fn discover_in_gophermap(body: &str, candidates: &mut Vec<FeedCandidate>) {
+    for item in parse_gophermap(body) {
+        if item.item_type != '0' && item.item_type != '1' {
+            continue;
+        }
+        let kind = match classify_link(&item.selector, Some(&item.display)) {
+            Some(kind) => kind,
+            None => continue,
+        };
+        if let Some(url) = item.to_url() {
+            candidates.push(FeedCandidate {
+                url,
+                title: Some(item.display.clone()),
+                kind,
+            });
+        }
+    }
+}
+
+/// Guess whether a link points at a feed from its target and label
+fn classify_link(url: &str, label: Option<&str>) -> Option<FeedKind> {
+    let path = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    if path.ends_with("atom.xml") || path.ends_with(".atom") {
+        return Some(FeedKind::Atom);
+    }
+    if path.ends_with("rss.xml") || path.ends_with(".rss") {
+        return Some(FeedKind::Rss);
+    }
+    if path.ends_with("feed.xml") || path.ends_with("index.xml") {
+        return Some(FeedKind::Unknown);
+    }
+
+    let label = label.unwrap_or("").to_lowercase();
+    let mentions_feed = label
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| matches!(word, "subscribe" | "feed" | "gemfeed" | "atom" | "rss"));
+    if !mentions_feed {
+        return None;
+    }
+
+    if path.ends_with(".xml") {
+        Some(FeedKind::Unknown)
+    } else {
+        Some(FeedKind::Gemfeed)
+    }
+}
+
+/// Check for a leading YYYY-MM-DD as used by gmisub entries
+fn starts_with_iso_date(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    bytes.len() >= 10
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[5..7].iter().all(u8::is_ascii_digit)
+        && bytes[7] == b'-'
+        && bytes[8..10].iter().all(u8::is_ascii_digit)
+}
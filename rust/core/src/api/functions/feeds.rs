@@ -0,0 +1,87 @@
+use url::Url;
+
+use crate::api::functions::content_router::{route_url, Renderer};
+use crate::api::functions::feed_discovery::FeedKind;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::parsers::feed::{parse_atom, parse_gemfeed, parse_rss, ParsedFeed};
+use crate::api::storage::feeds;
+
+/// Redirects followed when fetching a feed
+const MAX_FEED_REDIRECTS: usize = 5;
+
+/// Fetch a subscription and store its new entries, returning how many there were; a failure is
+/// also recorded on the feed for the Feeds tab to show
+pub async fn refresh_feed(id: i64) -> Result<u32, String> {
+    let feed = feeds::get_feed(id)?.ok_or_else(|| format!("Unknown feed {}", id))?;
+    match fetch_feed(&feed.url).await {
+        Ok((kind, parsed)) => {
+            feeds::store_entries(id, parsed.title.as_deref(), kind, &parsed.entries)
+        }
+        Err(e) => {
+            feeds::record_error(id, &e)?;
+            Err(e)
+        }
+    }
+}
+
+/// Refresh every subscription in turn, returning how many new entries they had; a feed that
+/// fails does not stop the others and keeps its error for the Feeds tab
+pub async fn refresh_all_feeds() -> Result<u32, String> {
+    let mut added = 0;
+    for feed in feeds::list_feeds()? {
+        if let Ok(count) = refresh_feed(feed.id).await {
+            added += count;
+        }
+    }
+    Ok(added)
+}
+
+/// Fetch a feed without touching history, following redirects, and parse it
+async fn fetch_feed(url: &str) -> Result<(FeedKind, ParsedFeed), String> {
+    let options = FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    };
+    let mut url = url.to_string();
+    for _ in 0..=MAX_FEED_REDIRECTS {
+        let response = fetch_with_options(url.clone(), &options).await?;
+        if response.is_success() {
+            return parse_feed(&response.url, &response.mime, &response.body);
+        }
+        let status = response.status.unwrap_or_default();
+        let meta = response.meta.unwrap_or_default();
+        if !(30..40).contains(&status) {
+            return Err(format!("Feed {} answered {} {}", url, status, meta));
+        }
+        url = Url::parse(&response.url)
+            .and_then(|base| base.join(&meta))
+            .map_err(|e| format!("Invalid feed URL {}: {}", meta, e))?
+            .to_string();
+    }
+    Err(format!("Too many redirects fetching feed {}", url))
+}
+
+/// Parse a fetched feed as Atom or RSS when it is XML, whatever its MIME type (Gopher serves
+/// feeds as plain text), or as a gemfeed when it is gemtext
+pub(crate) fn parse_feed(
+    url: &str,
+    mime: &str,
+    body: &str,
+) -> Result<(FeedKind, ParsedFeed), String> {
+    let base = Url::parse(url).map_err(|e| format!("Invalid feed URL: {}", e))?;
+    if body.trim_start().starts_with('<') || mime.contains("xml") {
+        if let Some(feed) = parse_atom(&base, body) {
+            return Ok((FeedKind::Atom, feed));
+        }
+        if let Some(feed) = parse_rss(&base, body) {
+            return Ok((FeedKind::Rss, feed));
+        }
+    } else if route_url(url, mime) == Renderer::Gemtext {
+        return Ok((FeedKind::Gemfeed, parse_gemfeed(&base, body)));
+    }
+    Err(format!(
+        "Not a feed: {} is neither Atom, RSS, nor a gemfeed",
+        url
+    ))
+}
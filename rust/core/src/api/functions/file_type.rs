@@ -0,0 +1,158 @@
+/// A file type recognized from its content or MIME type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileType {
+    pub mime: String,
+    /// Suggested file name extension, without the dot
+    pub extension: String,
+}
+
+/// Magic bytes at a fixed offset, with the type they identify
+struct Signature {
+    offset: usize,
+    magic: &'static [u8],
+    mime: &'static str,
+    extension: &'static str,
+}
+
+const fn signature(
+    offset: usize,
+    magic: &'static [u8],
+    mime: &'static str,
+    extension: &'static str,
+) -> Signature {
+    Signature {
+        offset,
+        magic,
+        mime,
+        extension,
+    }
+}
+
+/// Known signatures, most specific first
+const SIGNATURES: &[Signature] = &[
+    signature(0, b"\x89PNG\r\n\x1a\n", "image/png", "png"),
+    signature(0, b"\xff\xd8\xff", "image/jpeg", "jpg"),
+    signature(0, b"GIF87a", "image/gif", "gif"),
+    signature(0, b"GIF89a", "image/gif", "gif"),
+    signature(8, b"WEBP", "image/webp", "webp"),
+    signature(8, b"WAVE", "audio/wav", "wav"),
+    signature(0, b"%PDF-", "application/pdf", "pdf"),
+    signature(0, b"PK\x03\x04", "application/zip", "zip"),
+    signature(0, b"PK\x05\x06", "application/zip", "zip"),
+    signature(0, b"\x1f\x8b", "application/gzip", "gz"),
+    signature(0, b"BZh", "application/x-bzip2", "bz2"),
+    signature(0, b"\xfd7zXZ\x00", "application/x-xz", "xz"),
+    signature(0, b"\x28\xb5\x2f\xfd", "application/zstd", "zst"),
+    signature(
+        0,
+        b"7z\xbc\xaf\x27\x1c",
+        "application/x-7z-compressed",
+        "7z",
+    ),
+    signature(0, b"Rar!\x1a\x07", "application/vnd.rar", "rar"),
+    signature(257, b"ustar", "application/x-tar", "tar"),
+    signature(0, b"OggS", "audio/ogg", "ogg"),
+    signature(0, b"fLaC", "audio/flac", "flac"),
+    signature(0, b"ID3", "audio/mpeg", "mp3"),
+    signature(4, b"ftyp", "video/mp4", "mp4"),
+    signature(0, b"\x7fELF", "application/x-elf", "elf"),
+];
+
+/// Infer the type of binary content from its leading bytes
+pub fn sniff(bytes: &[u8]) -> Option<FileType> {
+    // WEBP and WAVE share the RIFF container, so check the container too
+    SIGNATURES
+        .iter()
+        .find(|sig| {
+            bytes.get(sig.offset..sig.offset + sig.magic.len()) == Some(sig.magic)
+                && (sig.offset != 8 || bytes.starts_with(b"RIFF"))
+        })
+        .map(|sig| FileType {
+            mime: sig.mime.to_string(),
+            extension: sig.extension.to_string(),
+        })
+}
+
+/// Bytes checked for binary content by `sniff_text`
+const TEXT_CHECK_BYTES: usize = 8192;
+
+/// Infer the type of content a server labelled as text, e.g. an image served as a Gopher text
+/// item. Text may well start like a signature ("ID3", "%PDF-"), so a match only counts when
+/// bytes that text does not contain follow.
+pub fn sniff_text(bytes: &[u8]) -> Option<FileType> {
+    sniff(bytes).filter(|_| looks_binary(bytes))
+}
+
+/// The type of content whose declared MIME type says nothing or is wrong: generic types are
+/// sniffed outright, while text types only give way to binary content with a signature
+pub fn sniff_mislabelled(declared: &str, bytes: &[u8]) -> Option<FileType> {
+    let essence = declared.split(';').next().unwrap_or("").trim();
+    if is_generic_mime(essence) {
+        sniff(bytes)
+    } else if essence
+        .get(..5)
+        .is_some_and(|kind| kind.eq_ignore_ascii_case("text/"))
+    {
+        sniff_text(bytes)
+    } else {
+        None
+    }
+}
+
+/// Whether the start of some content has a NUL or another control character that text, even
+/// with ANSI escapes, form feeds or backspace overstrikes, does not contain
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(TEXT_CHECK_BYTES)]
+        .iter()
+        .any(|&b| b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x08 | 0x0c | 0x1b))
+}
+
+/// Suggested extension for a MIME type, ignoring any parameters such as charset
+pub fn extension_for_mime(mime: &str) -> Option<String> {
+    let essence = mime
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    let extension = match essence.as_str() {
+        "text/gemini" => "gmi",
+        "text/plain" => "txt",
+        "text/html" => "html",
+        "text/markdown" => "md",
+        "application/gopher-menu" => "gophermap",
+        _ => {
+            return SIGNATURES
+                .iter()
+                .find(|sig| sig.mime == essence)
+                .map(|sig| sig.extension.to_string())
+        }
+    };
+    Some(extension.to_string())
+}
+
+/// MIME type of a file name extension (without the dot), for files read without a server
+pub fn mime_for_extension(extension: &str) -> Option<String> {
+    let extension = extension.to_ascii_lowercase();
+    let mime = match extension.as_str() {
+        "gmi" | "gemini" => "text/gemini",
+        "txt" | "text" => "text/plain",
+        "html" | "htm" => "text/html",
+        "md" | "markdown" => "text/markdown",
+        "gophermap" => "application/gopher-menu",
+        "jpeg" => "image/jpeg",
+        _ => {
+            return SIGNATURES
+                .iter()
+                .find(|sig| sig.extension == extension)
+                .map(|sig| sig.mime.to_string())
+        }
+    };
+    Some(mime.to_string())
+}
+
+/// Whether a declared MIME type says nothing about the content and sniffing should decide
+pub fn is_generic_mime(mime: &str) -> bool {
+    let essence = mime.split(';').next().unwrap_or("").trim();
+    essence.eq_ignore_ascii_case("application/octet-stream") || essence.is_empty()
+}
@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use percent_encoding::percent_decode_str;
+use tokio::io::AsyncReadExt;
+use url::Url;
+
+use crate::api::functions::file_type::{mime_for_extension, sniff};
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::protocols::registry;
+
+/// Largest .gpub archive downloaded
+const MAX_ARCHIVE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Most bytes an archive may unpack to, so a small archive cannot fill memory
+const MAX_UNPACKED_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Index file used when metadata.txt names none
+const DEFAULT_INDEX: &str = "index.gmi";
+
+/// The descriptive fields of a Gempub's metadata.txt
+#[derive(Debug, Clone, Default)]
+pub struct GempubMetadata {
+    /// The `title` field, else the first heading of the index, else "Untitled"
+    pub title: String,
+    pub author: Option<String>,
+    pub language: Option<String>,
+    pub description: Option<String>,
+    /// Publication date as written, e.g. "2021-03-01"
+    pub published: Option<String>,
+    pub version: Option<String>,
+    /// Path of the cover image inside the archive
+    pub cover: Option<String>,
+}
+
+/// A chapter in reading order
+#[derive(Debug, Clone)]
+pub struct GempubChapter {
+    /// The index link's label, else the chapter's first heading, else its path
+    pub title: String,
+    /// Path of the chapter's gemtext file inside the archive
+    pub path: String,
+}
+
+/// An unpacked Gempub kept open for reading
+#[derive(Debug, Clone)]
+pub struct GempubBook {
+    pub handle: u64,
+    pub metadata: GempubMetadata,
+    /// Path of the index page inside the archive, if the book has one
+    pub index_path: Option<String>,
+    pub chapters: Vec<GempubChapter>,
+}
+
+/// A file of an open book with its place among the chapters
+#[derive(Debug, Clone)]
+pub struct GempubPage {
+    /// Path inside the archive, to resolve the page's links against
+    pub path: String,
+    pub mime: String,
+    /// Text of text files (chapters, the index); empty for other files
+    pub body: String,
+    /// Bytes of other files, such as images; empty for text files
+    pub data: Vec<u8>,
+    /// Position of the page among the chapters, if it is one
+    pub chapter: Option<u32>,
+    pub previous_chapter: Option<u32>,
+    pub next_chapter: Option<u32>,
+}
+
+/// The files of an open book by path, with its chapters
+struct Book {
+    files: HashMap<String, Vec<u8>>,
+    chapters: Vec<GempubChapter>,
+}
+
+/// Books opened with `open_gempub`, until they are closed
+static BOOKS: LazyLock<Mutex<HashMap<u64, Arc<Book>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+fn book(handle: u64) -> Result<Arc<Book>, String> {
+    BOOKS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown gempub {}", handle))
+}
+
+/// Download a .gpub archive (up to 64 MiB) and open it; see `open_gempub_data`
+pub async fn open_gempub(url: &str) -> Result<GempubBook, String> {
+    let mut parsed_url = parse_input_url(url).ok_or("Invalid URL format")?;
+    parsed_url.set_fragment(None);
+    crate::api::storage::blocklist::check_url(&parsed_url)?;
+    crate::api::storage::scheme_toggles::check_url(&parsed_url)?;
+    let handler = registry::handler_for(parsed_url.scheme())
+        .ok_or_else(|| format!("Unsupported URL scheme {}", parsed_url.scheme()))?;
+
+    let body = handler.open_body(&parsed_url).await?;
+    let mut data = Vec::new();
+    // One byte more than the limit tells whether the archive is over it
+    body.reader
+        .take(MAX_ARCHIVE_BYTES + 1)
+        .read_to_end(&mut data)
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    if data.len() as u64 > MAX_ARCHIVE_BYTES {
+        return Err(format!("Gempub is larger than {} bytes", MAX_ARCHIVE_BYTES));
+    }
+    crate::api::functions::data_usage::record(
+        parsed_url.as_str().len() as u64 + 2,
+        data.len() as u64,
+    );
+    open_gempub_data(&data)
+}
+
+/// Unpack a .gpub archive and keep it open for `gempub_chapter` and `gempub_file`. The chapters
+/// are the index's links to gemtext files of the archive, in order; a book without an index
+/// reads its gemtext files in path order.
+pub fn open_gempub_data(data: &[u8]) -> Result<GempubBook, String> {
+    let files = unpack(data)?;
+    let fields = files
+        .get("metadata.txt")
+        .map(|metadata| parse_metadata(&String::from_utf8_lossy(metadata)))
+        .unwrap_or_default();
+
+    let index_path = match fields.get("index") {
+        Some(index) => {
+            Some(resolve("", index).ok_or_else(|| format!("Invalid index path {}", index))?)
+        }
+        None => Some(DEFAULT_INDEX.to_string()),
+    }
+    .filter(|path| files.contains_key(path));
+    let index = index_path
+        .as_ref()
+        .map(|path| parse_gemtext(&String::from_utf8_lossy(&files[path])));
+    let chapters = match (&index_path, &index) {
+        (Some(index_path), Some(index)) => index_chapters(index_path, index, &files),
+        _ => listed_chapters(&files),
+    };
+    if chapters.is_empty() {
+        return Err("Gempub has no chapters".to_string());
+    }
+
+    let field = |name: &str| fields.get(name).cloned();
+    let metadata = GempubMetadata {
+        title: field("title")
+            .or_else(|| index.as_deref().and_then(first_heading))
+            .unwrap_or_else(|| "Untitled".to_string()),
+        author: field("author"),
+        language: field("language"),
+        description: field("description"),
+        published: field("published").or_else(|| field("publishdate")),
+        version: field("version"),
+        cover: field("cover").and_then(|cover| resolve("", &cover)),
+    };
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let book = GempubBook {
+        handle,
+        metadata,
+        index_path,
+        chapters: chapters.clone(),
+    };
+    BOOKS
+        .lock()
+        .unwrap()
+        .insert(handle, Arc::new(Book { files, chapters }));
+    Ok(book)
+}
+
+/// A chapter of an open book by its position, with the chapters before and after it
+pub fn gempub_chapter(handle: u64, chapter: u32) -> Result<GempubPage, String> {
+    let book = book(handle)?;
+    let path = &book
+        .chapters
+        .get(chapter as usize)
+        .ok_or_else(|| format!("Unknown chapter {}", chapter))?
+        .path;
+    Ok(page(&book, path))
+}
+
+/// The file of an open book a link leads to, resolved against the path of the page it is on
+/// (the archive's root when None). Fails for links out of the book, which the UI follows as
+/// ordinary URLs.
+pub fn gempub_file(handle: u64, from_path: Option<&str>, link: &str) -> Result<GempubPage, String> {
+    let book = book(handle)?;
+    let path = resolve(from_path.unwrap_or_default(), link)
+        .filter(|path| book.files.contains_key(path))
+        .ok_or_else(|| format!("Unknown gempub file {}", link))?;
+    Ok(page(&book, &path))
+}
+
+/// Release an open book, returning whether it was open
+pub fn close_gempub(handle: u64) -> bool {
+    BOOKS.lock().unwrap().remove(&handle).is_some()
+}
+
+fn page(book: &Book, path: &str) -> GempubPage {
+    let data = &book.files[path];
+    let mime = extension(path)
+        .and_then(mime_for_extension)
+        .or_else(|| sniff(data).map(|file_type| file_type.mime))
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+    let (body, data) = if mime.starts_with("text/") {
+        (String::from_utf8_lossy(data).to_string(), Vec::new())
+    } else {
+        (String::new(), data.clone())
+    };
+    let chapter = book
+        .chapters
+        .iter()
+        .position(|chapter| chapter.path == path)
+        .map(|index| index as u32);
+    GempubPage {
+        path: path.to_string(),
+        mime,
+        body,
+        data,
+        chapter,
+        previous_chapter: chapter.and_then(|index| index.checked_sub(1)),
+        next_chapter: chapter
+            .map(|index| index + 1)
+            .filter(|next| (*next as usize) < book.chapters.len()),
+    }
+}
+
+/// The files of a zip archive by path. An archive whose files all sit in one folder, as made
+/// by zipping the folder, is read as if they were at its root.
+fn unpack(data: &[u8]) -> Result<HashMap<String, Vec<u8>>, String> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data))
+        .map_err(|e| format!("Invalid gempub archive: {}", e))?;
+    let mut files = HashMap::new();
+    let mut unpacked = 0;
+    for index in 0..archive.len() {
+        let file = archive
+            .by_index(index)
+            .map_err(|e| format!("Invalid gempub archive: {}", e))?;
+        if file.is_dir() {
+            continue;
+        }
+        let Some(path) = file.name().ok().and_then(|name| resolve("", &name)) else {
+            continue;
+        };
+        let mut contents = Vec::new();
+        file.take(MAX_UNPACKED_BYTES - unpacked + 1)
+            .read_to_end(&mut contents)
+            .map_err(|e| format!("Invalid gempub archive: {}", e))?;
+        unpacked += contents.len() as u64;
+        if unpacked > MAX_UNPACKED_BYTES {
+            return Err(format!(
+                "Gempub unpacks to more than {} bytes",
+                MAX_UNPACKED_BYTES
+            ));
+        }
+        files.insert(path, contents);
+    }
+
+    if !files.contains_key("metadata.txt") {
+        let folders: Vec<&str> = files
+            .keys()
+            .filter_map(|path| path.strip_suffix("/metadata.txt"))
+            .filter(|folder| !folder.contains('/'))
+            .collect();
+        if let [folder] = folders.as_slice() {
+            let prefix = format!("{}/", folder);
+            files = files
+                .into_iter()
+                .filter_map(|(path, contents)| {
+                    Some((path.strip_prefix(&prefix)?.to_string(), contents))
+                })
+                .collect();
+        }
+    }
+    Ok(files)
+}
+
+/// The `key: value` fields of metadata.txt with lowercase keys
+fn parse_metadata(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+        .collect()
+}
+
+/// The index's links to gemtext files of the archive, each taken once
+fn index_chapters(
+    index_path: &str,
+    index: &[GemtextLine],
+    files: &HashMap<String, Vec<u8>>,
+) -> Vec<GempubChapter> {
+    let mut chapters: Vec<GempubChapter> = Vec::new();
+    for line in index {
+        let GemtextLine::Link { url, label } = line else {
+            continue;
+        };
+        let Some(path) = resolve(index_path, url) else {
+            continue;
+        };
+        if path == index_path
+            || !is_gemtext(&path)
+            || !files.contains_key(&path)
+            || chapters.iter().any(|chapter| chapter.path == path)
+        {
+            continue;
+        }
+        let title = label
+            .clone()
+            .or_else(|| chapter_heading(&files[&path]))
+            .unwrap_or_else(|| path.clone());
+        chapters.push(GempubChapter { title, path });
+    }
+    chapters
+}
+
+/// Every gemtext file of an archive without an index, in path order
+fn listed_chapters(files: &HashMap<String, Vec<u8>>) -> Vec<GempubChapter> {
+    let mut paths: Vec<&String> = files.keys().filter(|path| is_gemtext(path)).collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| GempubChapter {
+            title: chapter_heading(&files[path]).unwrap_or_else(|| path.clone()),
+            path: path.clone(),
+        })
+        .collect()
+}
+
+fn chapter_heading(contents: &[u8]) -> Option<String> {
+    first_heading(&parse_gemtext(&String::from_utf8_lossy(contents)))
+}
+
+fn first_heading(document: &[GemtextLine]) -> Option<String> {
+    document.iter().find_map(|line| match line {
+        GemtextLine::Heading { text, .. } if !text.trim().is_empty() => {
+            Some(text.trim().to_string())
+        }
+        _ => None,
+    })
+}
+
+fn extension(path: &str) -> Option<&str> {
+    let name = path.rsplit('/').next()?;
+    name.rsplit_once('.').map(|(_, extension)| extension)
+}
+
+fn is_gemtext(path: &str) -> bool {
+    extension(path).is_some_and(|extension| {
+        extension.eq_ignore_ascii_case("gmi") || extension.eq_ignore_ascii_case("gemini")
+    })
+}
+
+/// The archive path a link on the page at `from_path` leads to, with `.` and `..` resolved and
+/// the query and fragment left out; None for links with a scheme, which leave the book
+fn resolve(from_path: &str, link: &str) -> Option<String> {
+    let root = Url::parse("gpub:///").ok()?;
+    let target = root.join(from_path).ok()?.join(link.trim()).ok()?;
+    if target.scheme() != root.scheme() || target.host().is_some() {
+        return None;
+    }
+    let path = percent_decode_str(target.path().trim_start_matches('/'))
+        .decode_utf8()
+        .ok()?;
+    (!path.is_empty() && !path.ends_with('/')).then(|| path.to_string())
+}
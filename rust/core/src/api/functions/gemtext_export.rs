@@ -0,0 +1,45 @@
+use crate::api::functions::links::menu_link;
+use crate::api::parsers::gophermap::parse_gophermap;
+
+/// Line starts that gemtext reads as something other than text
+const GEMTEXT_MARKERS: &[&str] = &["=>", "#", "* ", ">", "```"];
+
+/// Render a Gopher menu as gemtext, so one renderer (and the gemtext exports) serve both
+/// protocols: information and error lines become text lines, and every item that leads
+/// somewhere a link line with its gopher:// URL, or for web and telnet items the URL they point
+/// at. Items that lead nowhere keep their label as text.
+pub fn gophermap_to_gemtext(body: &str) -> String {
+    let mut gemtext = String::with_capacity(body.len());
+    for item in parse_gophermap(body) {
+        let page_link = match item.item_type {
+            'i' | '3' => None,
+            _ => menu_link(&item),
+        };
+        match page_link {
+            Some(page_link) if item.display.trim().is_empty() => {
+                gemtext.push_str(&format!("=> {}\n", page_link.url));
+            }
+            Some(page_link) => {
+                gemtext.push_str(&format!("=> {} {}\n", page_link.url, item.display.trim()));
+            }
+            None => {
+                gemtext.push_str(&text_line(&item.display));
+                gemtext.push('\n');
+            }
+        }
+    }
+    gemtext
+}
+
+/// A text line showing `text` as it is; a leading space keeps text that starts like a link,
+/// heading, list item, quote, or preformatting toggle from being read as one
+fn text_line(text: &str) -> String {
+    if GEMTEXT_MARKERS
+        .iter()
+        .any(|marker| text.starts_with(marker))
+    {
+        format!(" {}", text)
+    } else {
+        text.to_string()
+    }
+}
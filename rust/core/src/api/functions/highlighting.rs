@@ -0,0 +1,227 @@
+use std::sync::LazyLock;
+
+use syntect::easy::ScopeRegionIterator;
+use syntect::parsing::{ParseState, ScopeStack, SyntaxReference, SyntaxSet};
+
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// Grammars of the languages syntect ships with, loaded on the first highlight
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// What a span of code is, for the UI to color with its own light or dark palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Plain,
+    Comment,
+    String,
+    Number,
+    /// Language constants such as `true` and `None`
+    Constant,
+    Keyword,
+    Operator,
+    Type,
+    Function,
+    Variable,
+    /// Markup tag names, e.g. `div` in HTML
+    Tag,
+    Attribute,
+    Punctuation,
+}
+
+/// A run of a line's text of one kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenSpan {
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+/// The token spans of one line of code; joining them gives the line back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightedLine {
+    pub spans: Vec<TokenSpan>,
+}
+
+/// A preformatted block whose alt text names a recognized language, split into token spans
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighlightedBlock {
+    /// Index of the opening ``` line in the document
+    pub start_index: u32,
+    /// Name of the grammar used, e.g. "Rust"
+    pub language: String,
+    /// Each line of the block in order
+    pub lines: Vec<HighlightedLine>,
+}
+
+/// The names of the languages that can be highlighted
+pub fn highlight_languages() -> Vec<String> {
+    SYNTAXES
+        .syntaxes()
+        .iter()
+        .filter(|syntax| !is_plain_text(syntax))
+        .map(|syntax| syntax.name.clone())
+        .collect()
+}
+
+/// Highlight every preformatted block whose alt text names a language, e.g. "rust" or
+/// "python example"; blocks without one, such as ASCII art, are left out
+pub fn highlight_preformatted(document: &[GemtextLine]) -> Vec<HighlightedBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(u32, Option<&SyntaxReference>, Vec<&str>)> = None;
+    for (index, line) in document.iter().enumerate() {
+        match line {
+            GemtextLine::PreformatToggle { alt } => match current.take() {
+                Some(block) => blocks.extend(highlight_block(block)),
+                None => {
+                    let syntax = alt.as_deref().and_then(syntax_for_alt);
+                    current = Some((index as u32, syntax, Vec::new()));
+                }
+            },
+            GemtextLine::Preformatted(text) => {
+                if let Some((_, _, lines)) = current.as_mut() {
+                    lines.push(text);
+                }
+            }
+            _ => {}
+        }
+    }
+    // An unterminated block runs to the end of the document
+    blocks.extend(current.and_then(highlight_block));
+    blocks
+}
+
+/// Highlight code in a language named by its name or a file extension, e.g. "Rust" or "rs";
+/// None for a language without a grammar
+pub fn highlight_code(code: &str, language: &str) -> Option<Vec<HighlightedLine>> {
+    let syntax = find_syntax(language.trim())?;
+    Some(highlight_lines(syntax, code.lines()))
+}
+
+fn highlight_block(
+    (start_index, syntax, lines): (u32, Option<&SyntaxReference>, Vec<&str>),
+) -> Option<HighlightedBlock> {
+    let syntax = syntax?;
+    Some(HighlightedBlock {
+        start_index,
+        language: syntax.name.clone(),
+        lines: highlight_lines(syntax, lines),
+    })
+}
+
+/// The grammar an alt text names: its first word, as in "rust" or "sh session", or all of it,
+/// as in "Objective-C"
+fn syntax_for_alt(alt: &str) -> Option<&'static SyntaxReference> {
+    let first_word = alt.split_whitespace().next()?;
+    find_syntax(first_word).or_else(|| find_syntax(alt.trim()))
+}
+
+fn find_syntax(name: &str) -> Option<&'static SyntaxReference> {
+    if name.is_empty() {
+        return None;
+    }
+    SYNTAXES
+        .find_syntax_by_token(name)
+        .filter(|syntax| !is_plain_text(syntax))
+}
+
+fn is_plain_text(syntax: &SyntaxReference) -> bool {
+    syntax.name == "Plain Text"
+}
+
+fn highlight_lines<'a>(
+    syntax: &SyntaxReference,
+    lines: impl IntoIterator<Item = &'a str>,
+) -> Vec<HighlightedLine> {
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut highlighted = Vec::new();
+    for line in lines {
+        // The grammars are the newline variants, which expect each line to end with one
+        let line = format!("{}\n", line);
+        let Ok(ops) = state.parse_line(&line, &SYNTAXES) else {
+            // A grammar the parser cannot follow leaves the rest of the block plain
+            highlighted.push(HighlightedLine {
+                spans: vec![plain(line.trim_end_matches('\n'))],
+            });
+            state = ParseState::new(syntax);
+            stack = ScopeStack::new();
+            continue;
+        };
+        let mut spans: Vec<TokenSpan> = Vec::new();
+        for (text, op) in ScopeRegionIterator::new(&ops, &line) {
+            let _ = stack.apply(op);
+            let text = text.trim_end_matches('\n');
+            if text.is_empty() {
+                continue;
+            }
+            let kind = token_kind(&stack);
+            match spans.last_mut() {
+                Some(last) if last.kind == kind => last.text.push_str(text),
+                _ => spans.push(TokenSpan {
+                    text: text.to_string(),
+                    kind,
+                }),
+            }
+        }
+        highlighted.push(HighlightedLine { spans });
+    }
+    highlighted
+}
+
+fn plain(text: &str) -> TokenSpan {
+    TokenSpan {
+        text: text.to_string(),
+        kind: TokenKind::Plain,
+    }
+}
+
+/// The kind of text under a scope stack: inside a comment or string everything takes its
+/// kind, quotes and escapes included; elsewhere the innermost scope with a kind decides
+fn token_kind(stack: &ScopeStack) -> TokenKind {
+    let scopes: Vec<String> = stack
+        .as_slice()
+        .iter()
+        .map(|scope| scope.build_string())
+        .collect();
+    for scope in &scopes {
+        if scope.starts_with("comment") {
+            return TokenKind::Comment;
+        }
+        if scope.starts_with("string") {
+            return TokenKind::String;
+        }
+    }
+    scopes
+        .iter()
+        .rev()
+        .find_map(|scope| scope_kind(scope))
+        .unwrap_or(TokenKind::Plain)
+}
+
+/// Scope name prefixes of the TextMate naming conventions and their kinds, most specific first
+const SCOPE_KINDS: &[(&str, TokenKind)] = &[
+    ("constant.numeric", TokenKind::Number),
+    ("constant", TokenKind::Constant),
+    ("keyword.operator", TokenKind::Operator),
+    ("keyword", TokenKind::Keyword),
+    // Grammars scope declaration keywords such as `fn` and `def` as storage.type
+    ("storage", TokenKind::Keyword),
+    ("entity.name.function", TokenKind::Function),
+    ("support.function", TokenKind::Function),
+    ("variable.function", TokenKind::Function),
+    ("entity.name.tag", TokenKind::Tag),
+    ("entity.other.attribute-name", TokenKind::Attribute),
+    ("entity.name", TokenKind::Type),
+    ("support.type", TokenKind::Type),
+    ("support.class", TokenKind::Type),
+    ("variable", TokenKind::Variable),
+    ("punctuation", TokenKind::Punctuation),
+];
+
+fn scope_kind(scope: &str) -> Option<TokenKind> {
+    SCOPE_KINDS.iter().find_map(|(prefix, kind)| {
+        let matches = scope
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'));
+        matches.then_some(*kind)
+    })
+}
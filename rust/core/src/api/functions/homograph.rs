@@ -0,0 +1,208 @@
+use percent_encoding::percent_decode_str;
+use unicode_normalization::UnicodeNormalization;
+use url::{Position, Url};
+
+use crate::api::storage::known_hosts;
+
+/// How a host should be shown to the user
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisplayHost {
+    /// Unicode form of the host, or its punycode (xn--) form when it may be spoofed
+    pub host: String,
+    /// Set when the host mixes scripts or looks like a different host the user has visited
+    pub spoofing_warning: bool,
+}
+
+/// Writing systems told apart when checking a label; digits, hyphens, and combining marks
+/// belong to none of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Han,
+    Kana,
+    Hangul,
+    Other,
+}
+
+/// Non-Latin and Latin-extension letters that render like a plain Latin letter
+const CONFUSABLES: &[(char, char)] = &[
+    // Cyrillic
+    ('а', 'a'),
+    ('в', 'b'),
+    ('е', 'e'),
+    ('ё', 'e'),
+    ('һ', 'h'),
+    ('і', 'i'),
+    ('ї', 'i'),
+    ('ј', 'j'),
+    ('к', 'k'),
+    ('ӏ', 'l'),
+    ('м', 'm'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('ԛ', 'q'),
+    ('г', 'r'),
+    ('с', 'c'),
+    ('ѕ', 's'),
+    ('т', 't'),
+    ('у', 'y'),
+    ('ү', 'y'),
+    ('ԝ', 'w'),
+    ('х', 'x'),
+    ('ԁ', 'd'),
+    // Greek
+    ('α', 'a'),
+    ('β', 'b'),
+    ('ε', 'e'),
+    ('η', 'n'),
+    ('ι', 'i'),
+    ('κ', 'k'),
+    ('ν', 'v'),
+    ('ο', 'o'),
+    ('ρ', 'p'),
+    ('τ', 't'),
+    ('υ', 'u'),
+    ('χ', 'x'),
+    ('ω', 'w'),
+    // Latin letters without the dot or with a different shape
+    ('ı', 'i'),
+    ('ɡ', 'g'),
+    ('ȷ', 'j'),
+    ('ɑ', 'a'),
+    ('ɩ', 'i'),
+    ('ʟ', 'l'),
+    ('ø', 'o'),
+    ('đ', 'd'),
+    ('ħ', 'h'),
+    ('ł', 'l'),
+];
+
+/// Decide how to display a host, falling back to punycode when it could impersonate another:
+/// a label mixing scripts (other than Latin with CJK, as in Japanese and Korean names), a
+/// Cyrillic or Greek label spelled only with Latin look-alikes, or a host that looks the same
+/// as a different pinned server
+pub fn display_host(host: &str) -> DisplayHost {
+    // Non-special URL schemes keep IDN hosts percent-encoded
+    let decoded = percent_decode_str(host).decode_utf8_lossy().to_lowercase();
+    let (unicode, _) = idna::domain_to_unicode(&decoded);
+    if unicode.is_ascii() {
+        return DisplayHost {
+            host: unicode,
+            spoofing_warning: false,
+        };
+    }
+
+    let spoofing_warning = has_suspicious_label(&unicode) || resembles_known_host(&unicode);
+    let host = if spoofing_warning {
+        idna::domain_to_ascii(&unicode).unwrap_or(decoded)
+    } else {
+        unicode
+    };
+    DisplayHost {
+        host,
+        spoofing_warning,
+    }
+}
+
+/// A URL as the user should read it, with its host shown as `display_host` decides, so an
+/// internationalized host appears in Unicode unless it may be spoofed. The result is for
+/// display only; requests keep the punycode form.
+pub fn display_url(url: &Url) -> String {
+    match url.host_str() {
+        Some(host) => format!(
+            "{}{}{}",
+            &url[..Position::BeforeHost],
+            display_host(host).host,
+            &url[Position::AfterHost..]
+        ),
+        None => url.to_string(),
+    }
+}
+
+fn script(c: char) -> Option<Script> {
+    match c as u32 {
+        0x30..=0x39 | 0x2D | 0x5F | 0x300..=0x36F => None,
+        0x41..=0x5A | 0x61..=0x7A | 0xC0..=0x24F | 0x250..=0x2AF | 0x1E00..=0x1EFF => {
+            Some(Script::Latin)
+        }
+        0x370..=0x3FF | 0x1F00..=0x1FFF => Some(Script::Greek),
+        0x400..=0x52F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => Some(Script::Cyrillic),
+        0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Some(Script::Kana),
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Some(Script::Hangul),
+        0x2E80..=0x2FDF | 0x3005 | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => {
+            Some(Script::Han)
+        }
+        _ if c.is_alphabetic() => Some(Script::Other),
+        _ => None,
+    }
+}
+
+fn has_suspicious_label(host: &str) -> bool {
+    let labels: Vec<&str> = host.split('.').filter(|label| !label.is_empty()).collect();
+    let tld_scripts = labels.last().map(|tld| scripts(tld)).unwrap_or_default();
+    labels.iter().any(|label| {
+        let label_scripts = scripts(label);
+        let mixed = match label_scripts.as_slice() {
+            [] | [_] => false,
+            _ => {
+                !label_scripts
+                    .iter()
+                    .all(|script| matches!(script, Script::Latin | Script::Han | Script::Kana))
+                    && !label_scripts.iter().all(|script| {
+                        matches!(script, Script::Latin | Script::Han | Script::Hangul)
+                    })
+            }
+        };
+        // A look-alike label is expected under a TLD in the same script, e.g. .рф
+        let whole_script_confusable = matches!(
+            label_scripts.as_slice(),
+            [Script::Cyrillic] | [Script::Greek]
+        ) && tld_scripts != label_scripts
+            && label
+                .chars()
+                .filter(|&c| script(c).is_some())
+                .all(|c| confusable(c).is_some());
+        mixed || whole_script_confusable
+    })
+}
+
+/// The distinct scripts of a label in order of first appearance
+fn scripts(label: &str) -> Vec<Script> {
+    let mut found = Vec::new();
+    for script in label.chars().filter_map(script) {
+        if !found.contains(&script) {
+            found.push(script);
+        }
+    }
+    found
+}
+
+fn confusable(c: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|(from, _)| *from == c)
+        .map(|(_, to)| *to)
+}
+
+/// The host reduced to the Latin letters it looks like, without accents
+fn skeleton(host: &str) -> String {
+    host.nfd()
+        .filter(|&c| !(0x300..=0x36F).contains(&(c as u32)))
+        .map(|c| confusable(c).unwrap_or(c))
+        .collect()
+}
+
+fn resembles_known_host(unicode: &str) -> bool {
+    let target = skeleton(unicode);
+    // Without a readable store there is nothing to compare against
+    let Ok(known) = known_hosts::host_names() else {
+        return false;
+    };
+    known.iter().any(|known| {
+        let decoded = percent_decode_str(known).decode_utf8_lossy().to_lowercase();
+        let (known, _) = idna::domain_to_unicode(&decoded);
+        known != unicode && skeleton(&known) == target
+    })
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::{bookmarks, redirects};
+
+/// Gemini status of a permanent redirect
+const PERMANENT_REDIRECT: u8 = 31;
+
+/// Distinct pages that must have moved before a whole host is considered moved
+const MIN_MOVED_PAGES: u32 = 2;
+
+/// A bookmarked host whose pages permanently redirect to another host
+#[derive(Debug, Clone)]
+pub struct HostMigration {
+    pub scheme: String,
+    pub from_host: String,
+    pub to_host: String,
+    /// Distinct pages seen redirecting from `from_host` to `to_host`
+    pub moved_pages: u32,
+    /// Bookmarks that `migrate_host` would rewrite
+    pub bookmarks: u32,
+}
+
+/// What `migrate_host` rewrote
+#[derive(Debug, Clone, Default)]
+pub struct HostMigrationResult {
+    /// Bookmarks moved to the new host, including ones merged into an existing bookmark there
+    pub bookmarks: u32,
+}
+
+/// Remember a permanent redirect to another host, or forget one for a page that answers normally
+pub(crate) fn observe(response: &FetchResponse) -> Result<(), String> {
+    let Ok(from) = Url::parse(&response.url) else {
+        return Ok(());
+    };
+    let from = canonicalize(&from);
+    if response.is_success() {
+        return redirects::forget_url(from.as_str());
+    }
+    if response.status != Some(PERMANENT_REDIRECT) {
+        return Ok(());
+    }
+    let Some(to) = response
+        .meta
+        .as_deref()
+        .and_then(|meta| from.join(meta.trim()).ok())
+    else {
+        return Ok(());
+    };
+    let to = canonicalize(&to);
+    match (from.host_str(), to.host_str()) {
+        (Some(from_host), Some(to_host))
+            if from.scheme() == to.scheme() && !from_host.eq_ignore_ascii_case(to_host) =>
+        {
+            redirects::record(
+                from.as_str(),
+                from.scheme(),
+                from_host,
+                to.as_str(),
+                to_host,
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Bookmarked hosts that have permanently redirected several pages to the same new host
+pub fn suggested_migrations() -> Result<Vec<HostMigration>, String> {
+    let mut groups: BTreeMap<(String, String, String), u32> = BTreeMap::new();
+    for redirect in redirects::list()? {
+        *groups
+            .entry((redirect.scheme, redirect.from_host, redirect.to_host))
+            .or_default() += 1;
+    }
+    let mut migrations = Vec::new();
+    for ((scheme, from_host, to_host), moved_pages) in groups {
+        if moved_pages < MIN_MOVED_PAGES {
+            continue;
+        }
+        let bookmarks = bookmarks::on_host(&scheme, &from_host)?.len() as u32;
+        if bookmarks > 0 {
+            migrations.push(HostMigration {
+                scheme,
+                from_host,
+                to_host,
+                moved_pages,
+                bookmarks,
+            });
+        }
+    }
+    Ok(migrations)
+}
+
+/// Rewrite the bookmarks on a host to the host it moved to; pages seen redirecting keep their
+/// exact new URL, the rest keep their path on the new host
+pub fn migrate_host(
+    scheme: &str,
+    from_host: &str,
+    to_host: &str,
+) -> Result<HostMigrationResult, String> {
+    let moved: Vec<redirects::PermanentRedirect> = redirects::list()?
+        .into_iter()
+        .filter(|redirect| {
+            redirect.scheme == scheme
+                && redirect.from_host.eq_ignore_ascii_case(from_host)
+                && redirect.to_host.eq_ignore_ascii_case(to_host)
+        })
+        .collect();
+    // The new host's port, when it moved to a non-default one
+    let to_port = moved
+        .iter()
+        .find_map(|redirect| Url::parse(&redirect.to_url).ok()?.port());
+
+    let mut result = HostMigrationResult::default();
+    for bookmark in bookmarks::on_host(scheme, from_host)? {
+        let Ok(url) = Url::parse(&bookmark.url) else {
+            continue;
+        };
+        let canonical = canonicalize(&url);
+        let redirect = moved.iter().find(|r| r.from_url == canonical.as_str());
+        let target = match redirect.and_then(|redirect| Url::parse(&redirect.to_url).ok()) {
+            Some(target) => target,
+            None => {
+                let mut target = url;
+                target
+                    .set_host(Some(to_host))
+                    .map_err(|e| format!("Invalid host {}: {}", to_host, e))?;
+                let _ = target.set_port(to_port);
+                target
+            }
+        };
+        bookmarks::move_bookmark(bookmark.id, &target)?;
+        result.bookmarks += 1;
+    }
+    redirects::forget_host(scheme, from_host)?;
+    Ok(result)
+}
+
+/// Stop suggesting a migration away from a host, returning whether one was pending
+pub fn dismiss_migration(scheme: &str, from_host: &str) -> Result<bool, String> {
+    Ok(redirects::forget_host(scheme, from_host)? > 0)
+}
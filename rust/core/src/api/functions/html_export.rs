@@ -0,0 +1,146 @@
+use url::Url;
+
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+
+/// Minimal styling so the exported page reads like it does in the app
+const STYLE: &str = "body{max-width:42em;margin:2em auto;padding:0 1em;font-family:sans-serif;\
+line-height:1.5}pre{overflow-x:auto;padding:.5em;background:#f4f4f4}\
+blockquote{margin-left:0;padding-left:1em;border-left:3px solid #ccc;font-style:italic}\
+figure{margin:1em 0}figcaption{font-size:.9em;color:#555}";
+
+/// Link schemes that would run code in a browser, rendered as plain text instead
+const UNSAFE_SCHEMES: &[&str] = &["javascript", "vbscript", "data"];
+
+/// Block being built from consecutive lines of the same kind
+#[derive(PartialEq)]
+enum Block {
+    None,
+    List,
+    Quote,
+    Preformatted,
+}
+
+/// Render a gemtext page as a standalone HTML document for sharing or archiving: headings,
+/// paragraphs, lists, quotes, and preformatted blocks (captioned with their alt text) map to
+/// their HTML elements, and links are resolved against `base_url` so they keep working outside
+/// the capsule. The title is the page's first heading, or the URL.
+pub fn render_to_html(gemtext: &str, base_url: &str) -> String {
+    let base = Url::parse(base_url).ok();
+    let lines = parse_gemtext(gemtext);
+    let title = lines
+        .iter()
+        .find_map(|line| match line {
+            GemtextLine::Heading { text, .. } if !text.is_empty() => Some(text.as_str()),
+            _ => None,
+        })
+        .unwrap_or(base_url);
+
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+        escape(title),
+        STYLE
+    );
+    let mut block = Block::None;
+    for line in &lines {
+        match line {
+            GemtextLine::PreformatToggle { alt } => {
+                close(&mut html, &block);
+                // The parser alternates opening and closing toggles
+                if block == Block::Preformatted {
+                    block = Block::None;
+                    continue;
+                }
+                html.push_str("<figure>\n");
+                if let Some(alt) = alt {
+                    html.push_str(&format!("<figcaption>{}</figcaption>\n", escape(alt)));
+                }
+                let label = alt
+                    .as_deref()
+                    .map(|alt| format!(" aria-label=\"{}\"", escape(alt)))
+                    .unwrap_or_default();
+                html.push_str(&format!("<pre{}>", label));
+                block = Block::Preformatted;
+            }
+            GemtextLine::Preformatted(text) => {
+                html.push_str(&escape(text));
+                html.push('\n');
+            }
+            GemtextLine::ListItem(text) => {
+                if block != Block::List {
+                    close(&mut html, &block);
+                    html.push_str("<ul>\n");
+                    block = Block::List;
+                }
+                html.push_str(&format!("<li>{}</li>\n", escape(text)));
+            }
+            GemtextLine::Quote(text) => {
+                if block != Block::Quote {
+                    close(&mut html, &block);
+                    html.push_str("<blockquote>\n");
+                    block = Block::Quote;
+                }
+                html.push_str(&format!("<p>{}</p>\n", escape(text)));
+            }
+            _ => {
+                close(&mut html, &block);
+                block = Block::None;
+                match line {
+                    GemtextLine::Text(text) if !text.trim().is_empty() => {
+                        html.push_str(&format!("<p>{}</p>\n", escape(text)));
+                    }
+                    GemtextLine::Heading { level, text } => {
+                        html.push_str(&format!("<h{0}>{1}</h{0}>\n", level, escape(text)));
+                    }
+                    GemtextLine::Link { url, label } => {
+                        html.push_str(&link(base.as_ref(), url, label));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    close(&mut html, &block);
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn close(html: &mut String, block: &Block) {
+    match block {
+        Block::None => {}
+        Block::List => html.push_str("</ul>\n"),
+        Block::Quote => html.push_str("</blockquote>\n"),
+        Block::Preformatted => html.push_str("</pre>\n</figure>\n"),
+    }
+}
+
+/// A link line as a paragraph, its target resolved against the page URL
+fn link(base: Option<&Url>, url: &str, label: &Option<String>) -> String {
+    let target = match base.and_then(|base| base.join(url).ok()) {
+        Some(target) => target.to_string(),
+        None => url.to_string(),
+    };
+    let text = escape(label.as_deref().unwrap_or(&target));
+    let scheme = target.split(':').next().unwrap_or("").to_ascii_lowercase();
+    if UNSAFE_SCHEMES.contains(&scheme.trim()) {
+        return format!("<p>{}</p>\n", text);
+    }
+    format!("<p><a href=\"{}\">{}</a></p>\n", escape(&target), text)
+}
+
+/// Escape text for HTML element content and quoted attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use rcgen::{CertificateParams, DnType, KeyPair, SerialNumber};
+use time::OffsetDateTime;
+use tokio::sync::mpsc;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::digest::sha256_hex;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::functions::navigation_events;
+use crate::api::storage::identities::{self, Identity, IdentityCredentials};
+use crate::api::storage::identity_scopes::{self, IdentityScope};
+use crate::api::storage::{host_settings, now};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A freshly signed certificate with the metadata stored alongside it
+pub(crate) struct GeneratedCertificate {
+    pub credentials: IdentityCredentials,
+    pub fingerprint: String,
+    pub not_before: i64,
+    pub not_after: i64,
+}
+
+/// Sign a self-signed client certificate for a common name, reusing the given PEM key or generating a new one
+pub(crate) fn generate_certificate(
+    common_name: &str,
+    private_key_pem: Option<&str>,
+    valid_days: u32,
+) -> Result<GeneratedCertificate, String> {
+    let key_pair = match private_key_pem {
+        Some(pem) => KeyPair::from_pem(pem),
+        None => KeyPair::generate(),
+    }
+    .map_err(|e| format!("Failed to prepare identity key: {}", e))?;
+
+    let not_before = now();
+    let not_after = not_before + valid_days.max(1) as i64 * SECONDS_PER_DAY;
+    let mut params = CertificateParams::default();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, common_name);
+    params.not_before = timestamp(not_before)?;
+    params.not_after = timestamp(not_after)?;
+    // A renewed certificate keeping its key must still be distinguishable from the old one
+    params.serial_number = Some(SerialNumber::from_slice(uuid::Uuid::new_v4().as_bytes()));
+
+    let certificate = params
+        .self_signed(&key_pair)
+        .map_err(|e| format!("Failed to sign identity certificate: {}", e))?;
+
+    Ok(GeneratedCertificate {
+        fingerprint: sha256_hex(certificate.der()),
+        credentials: IdentityCredentials {
+            certificate_pem: certificate.pem(),
+            private_key_pem: key_pair.serialize_pem(),
+        },
+        not_before,
+        not_after,
+    })
+}
+
+fn timestamp(seconds: i64) -> Result<OffsetDateTime, String> {
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .map_err(|e| format!("Invalid certificate date: {}", e))
+}
+
+/// Generate a key pair and a self-signed certificate for a new identity named after its common
+/// name, valid from now for `valid_days`, and store it; returns the identity's id
+pub fn create_identity(common_name: &str, valid_days: u32) -> Result<String, String> {
+    let common_name = common_name.trim();
+    if common_name.is_empty() {
+        return Err("Common name cannot be empty".to_string());
+    }
+    if valid_days == 0 {
+        return Err("Validity must be at least one day".to_string());
+    }
+    let generated = generate_certificate(common_name, None, valid_days)?;
+    let identity = Identity {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: common_name.to_string(),
+        common_name: common_name.to_string(),
+        fingerprint: generated.fingerprint,
+        not_before: generated.not_before,
+        not_after: generated.not_after,
+        created_at: now(),
+    };
+    identities::insert(&identity, &generated.credentials)?;
+    Ok(identity.id)
+}
+
+/// Issue a new certificate for an identity, keeping its key unless `rotate_key` is set.
+/// The validity period defaults to that of the current certificate.
+pub fn renew_identity(
+    id: &str,
+    rotate_key: bool,
+    valid_days: Option<u32>,
+) -> Result<Identity, String> {
+    let mut identity =
+        identities::get_identity(id)?.ok_or_else(|| format!("Unknown identity {}", id))?;
+    let credentials =
+        identities::credentials(id)?.ok_or_else(|| format!("Unknown identity {}", id))?;
+
+    let valid_days = valid_days.unwrap_or_else(|| {
+        ((identity.not_after - identity.not_before) / SECONDS_PER_DAY).max(1) as u32
+    });
+    let key = (!rotate_key).then_some(credentials.private_key_pem.as_str());
+    let generated = generate_certificate(&identity.common_name, key, valid_days)?;
+
+    identity.fingerprint = generated.fingerprint;
+    identity.not_before = generated.not_before;
+    identity.not_after = generated.not_after;
+    identities::replace_certificate(&identity, &generated.credentials)?;
+    Ok(identity)
+}
+
+/// Activate an identity for a URL and everything under it, as the Gemini specification asks:
+/// requests to URLs starting with the URL (canonicalized, without its query) present the
+/// identity until it is deactivated. A longer prefix bound to another identity takes precedence.
+pub fn activate_identity(identity_id: &str, url: &str) -> Result<IdentityScope, String> {
+    if identities::get_identity(identity_id)?.is_none() {
+        return Err(format!("Unknown identity {}", identity_id));
+    }
+    identity_scopes::activate(&scope_prefix(url)?, identity_id)
+}
+
+/// Stop presenting the identity activated for exactly this URL prefix, returning whether one was
+pub fn deactivate_identity(url: &str) -> Result<bool, String> {
+    identity_scopes::deactivate(&scope_prefix(url)?)
+}
+
+/// The activation whose prefix a URL falls under, if any; the longest prefix wins
+pub fn active_identity(url: &str) -> Result<Option<IdentityScope>, String> {
+    let url = parse_input_url(url).ok_or_else(|| format!("Invalid URL: {}", url.trim()))?;
+    identity_scopes::scope_for(canonicalize(&url).as_str())
+}
+
+/// The identity a request to a URL presents when the caller names none: the one activated for
+/// a prefix of the URL, or else the one set for its host. A private navigation presents none.
+pub(crate) fn identity_for_url(url: &Url) -> Result<Option<String>, String> {
+    if navigation_events::is_private() {
+        return Ok(None);
+    }
+    if let Some(scope) = identity_scopes::scope_for(canonicalize(url).as_str())? {
+        return Ok(Some(scope.identity_id));
+    }
+    let Some(host) = url.host_str() else {
+        return Ok(None);
+    };
+    Ok(host_settings::get_host_settings(host)?.and_then(|settings| settings.identity_id))
+}
+
+/// The prefix an activation for a URL covers
+fn scope_prefix(url: &str) -> Result<String, String> {
+    let parsed = parse_input_url(url)
+        .filter(Url::has_host)
+        .ok_or_else(|| format!("Invalid URL: {}", url.trim()))?;
+    let mut prefix = canonicalize(&parsed);
+    prefix.set_query(None);
+    Ok(prefix.to_string())
+}
+
+/// An identity whose certificate expires soon or already has
+#[derive(Debug, Clone)]
+pub struct ExpiryReminder {
+    pub identity: Identity,
+    /// Whole days until expiry; negative once expired
+    pub days_left: i64,
+    pub expired: bool,
+}
+
+/// Identities expiring within the given number of days, soonest first
+pub fn expiring_identities(within_days: u32) -> Result<Vec<ExpiryReminder>, String> {
+    let now = now();
+    let mut reminders: Vec<ExpiryReminder> = identities::list_identities()?
+        .into_iter()
+        .filter(|identity| identity.not_after - now <= within_days as i64 * SECONDS_PER_DAY)
+        .map(|identity| ExpiryReminder {
+            days_left: (identity.not_after - now).div_euclid(SECONDS_PER_DAY),
+            expired: identity.not_after <= now,
+            identity,
+        })
+        .collect();
+    reminders.sort_by_key(|reminder| reminder.identity.not_after);
+    Ok(reminders)
+}
+
+/// Reminders waiting for `next_expiry_reminder`
+static REMINDERS: LazyLock<(
+    mpsc::UnboundedSender<ExpiryReminder>,
+    tokio::sync::Mutex<mpsc::UnboundedReceiver<ExpiryReminder>>,
+)> = LazyLock::new(|| {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    (sender, tokio::sync::Mutex::new(receiver))
+});
+
+/// Certificates (by identity and expiry) already reported, so each one is announced once
+static REPORTED: LazyLock<Mutex<HashSet<(String, i64)>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Bumped by every `start_expiry_checks` call so older check loops stop
+static CHECK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Queue a reminder for every newly expiring identity
+fn check_expiry(within_days: u32) {
+    let Ok(reminders) = expiring_identities(within_days) else {
+        return;
+    };
+    let mut reported = REPORTED.lock().unwrap();
+    for reminder in reminders {
+        let key = (reminder.identity.id.clone(), reminder.identity.not_after);
+        if reported.insert(key) {
+            let _ = REMINDERS.0.send(reminder);
+        }
+    }
+}
+
+/// Scan identities now and then every `interval_secs` (0 scans once), queueing a reminder
+/// for each certificate within `within_days` of expiry. Call after `open_profile` on startup.
+pub fn start_expiry_checks(within_days: u32, interval_secs: u32) {
+    let generation = CHECK_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    check_expiry(within_days);
+    if interval_secs == 0 {
+        return;
+    }
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(interval_secs as u64));
+        if CHECK_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        check_expiry(within_days);
+    });
+}
+
+/// Stop the periodic scans started by `start_expiry_checks`
+pub fn stop_expiry_checks() {
+    CHECK_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Wait for the next identity expiry reminder
+pub async fn next_expiry_reminder() -> ExpiryReminder {
+    let mut receiver = REMINDERS.1.lock().await;
+    receiver
+        .recv()
+        .await
+        .expect("reminder sender lives in a static")
+}
@@ -0,0 +1,186 @@
+use p12_keystore::{
+    Certificate, KeyStore, KeyStoreEntry, Pkcs12ImportPolicy, PrivateKey, PrivateKeyChain,
+};
+use pem::{EncodeConfig, LineEnding, Pem};
+use rcgen::{KeyPair, PublicKeyData};
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::api::functions::digest::sha256_hex;
+use crate::api::storage::identities::{self, Identity, IdentityCredentials};
+use crate::api::storage::now;
+
+const PEM_CONFIG: EncodeConfig = EncodeConfig::new().set_line_ending(LineEnding::LF);
+
+/// Import an identity from PEM text; the key may be passed separately or follow the certificate
+pub fn import_pem(
+    name: Option<String>,
+    certificate_pem: &str,
+    private_key_pem: Option<&str>,
+) -> Result<Identity, String> {
+    let mut blocks = pem::parse_many(certificate_pem).map_err(|e| format!("Invalid PEM: {}", e))?;
+    if let Some(key) = private_key_pem {
+        blocks.extend(pem::parse_many(key).map_err(|e| format!("Invalid PEM key: {}", e))?);
+    }
+
+    let certificate = blocks
+        .iter()
+        .find(|block| block.tag() == "CERTIFICATE")
+        .ok_or("No certificate found in PEM")?;
+    let key = blocks
+        .iter()
+        .find(|block| block.tag().ends_with("PRIVATE KEY"))
+        .ok_or("No private key found in PEM")?;
+    if key.tag() != "PRIVATE KEY" {
+        // Traditional RSA/EC keys have to be converted first
+        return Err(format!(
+            "Unsupported key format \"{}\"; convert it to PKCS#8 with `openssl pkcs8 -topk8 -nocrypt`",
+            key.tag()
+        ));
+    }
+
+    import_der(name, certificate.contents(), key.contents())
+}
+
+/// Import an identity from a password-protected PKCS#12 (.p12/.pfx) archive
+pub fn import_pkcs12(
+    name: Option<String>,
+    data: &[u8],
+    password: &str,
+) -> Result<Identity, String> {
+    let store = KeyStore::from_pkcs12(data, password, Pkcs12ImportPolicy::Strict)
+        .map_err(|e| format!("Failed to read PKCS#12 archive: {}", e))?;
+    let (alias, chain) = store
+        .private_key_chain()
+        .ok_or("PKCS#12 archive contains no private key with a certificate")?;
+    let certificate = chain
+        .certs()
+        .first()
+        .ok_or("PKCS#12 archive contains no certificate")?;
+
+    let name = name.or_else(|| (!alias.is_empty()).then(|| alias.to_string()));
+    import_der(name, certificate.as_der(), chain.key().as_der())
+}
+
+/// How many identities an import added and how many it passed over
+#[derive(Debug, Clone, Default)]
+pub struct IdentitiesImport {
+    pub imported: u32,
+    /// Identities already imported, and certificates without a readable key
+    pub skipped: u32,
+}
+
+/// Import the identities of Lagrange's `idents` directory, where each identity is a
+/// `<fingerprint>.crt` certificate next to a `<fingerprint>.key` PKCS#8 key. Lagrange keeps the
+/// notes of its identities in a binary file, so they are named after their common names.
+pub fn import_lagrange_identities(dir: &str) -> Result<IdentitiesImport, String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir, e))?;
+    let mut certificates: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "crt"))
+        .collect();
+    certificates.sort();
+    let mut result = IdentitiesImport::default();
+    for certificate in certificates {
+        let read = |path: &std::path::Path| std::fs::read_to_string(path).ok();
+        let imported = match (read(&certificate), read(&certificate.with_extension("key"))) {
+            (Some(certificate), Some(key)) => import_pem(None, &certificate, Some(&key)).is_ok(),
+            _ => false,
+        };
+        if imported {
+            result.imported += 1;
+        } else {
+            result.skipped += 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Validate a DER certificate and PKCS#8 key pair and store them as a new identity
+fn import_der(
+    name: Option<String>,
+    certificate_der: &[u8],
+    key_der: &[u8],
+) -> Result<Identity, String> {
+    let (_, certificate) = X509Certificate::from_der(certificate_der)
+        .map_err(|e| format!("Invalid certificate: {}", e))?;
+
+    let private_key_pem = pem::encode_config(&Pem::new("PRIVATE KEY", key_der), PEM_CONFIG);
+    let key_pair = KeyPair::from_pem(&private_key_pem)
+        .map_err(|e| format!("Unsupported private key: {}", e))?;
+    if key_pair.subject_public_key_info() != certificate.public_key().raw {
+        return Err("Private key does not belong to the certificate".to_string());
+    }
+
+    let fingerprint = sha256_hex(certificate_der);
+    if let Some(existing) = identities::list_identities()?
+        .into_iter()
+        .find(|identity| identity.fingerprint == fingerprint)
+    {
+        return Err(format!(
+            "Identity already imported as \"{}\"",
+            existing.name
+        ));
+    }
+
+    let common_name = certificate
+        .subject()
+        .iter_common_name()
+        .next()
+        .and_then(|cn| cn.as_str().ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| certificate.subject().to_string());
+    let identity = Identity {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: name.unwrap_or_else(|| common_name.clone()),
+        common_name,
+        fingerprint,
+        not_before: certificate.validity().not_before.timestamp(),
+        not_after: certificate.validity().not_after.timestamp(),
+        created_at: now(),
+    };
+    let credentials = IdentityCredentials {
+        certificate_pem: pem::encode_config(&Pem::new("CERTIFICATE", certificate_der), PEM_CONFIG),
+        private_key_pem,
+    };
+    identities::insert(&identity, &credentials)?;
+    Ok(identity)
+}
+
+fn stored_credentials(id: &str) -> Result<IdentityCredentials, String> {
+    identities::credentials(id)?.ok_or_else(|| format!("Unknown identity {}", id))
+}
+
+/// Export an identity as PEM text: the certificate followed by its unencrypted PKCS#8 key
+pub fn export_pem(id: &str) -> Result<String, String> {
+    let credentials = stored_credentials(id)?;
+    Ok(format!(
+        "{}{}",
+        credentials.certificate_pem, credentials.private_key_pem
+    ))
+}
+
+/// Export an identity as a PKCS#12 archive protected by the given password
+pub fn export_pkcs12(id: &str, password: &str) -> Result<Vec<u8>, String> {
+    let identity =
+        identities::get_identity(id)?.ok_or_else(|| format!("Unknown identity {}", id))?;
+    let credentials = stored_credentials(id)?;
+
+    let certificate = pem::parse(&credentials.certificate_pem)
+        .map_err(|e| format!("Stored certificate is corrupt: {}", e))?;
+    let key = pem::parse(&credentials.private_key_pem)
+        .map_err(|e| format!("Stored key is corrupt: {}", e))?;
+    let chain = PrivateKeyChain::new(
+        identity.fingerprint.as_str(),
+        PrivateKey::from_der(key.contents())
+            .map_err(|e| format!("Stored key is corrupt: {}", e))?,
+        [Certificate::from_der(certificate.contents())
+            .map_err(|e| format!("Stored certificate is corrupt: {}", e))?],
+    );
+
+    let mut store = KeyStore::new();
+    store.add_entry(&identity.name, KeyStoreEntry::PrivateKeyChain(chain));
+    store
+        .writer(password)
+        .write()
+        .map_err(|e| format!("Failed to write PKCS#12 archive: {}", e))
+}
@@ -0,0 +1,191 @@
+use tokio::io::AsyncReadExt;
+use tokio::task::JoinSet;
+use url::Url;
+
+use crate::api::functions::data_usage::lite_mode;
+use crate::api::functions::file_type::{is_generic_mime, mime_for_extension, sniff};
+use crate::api::parsers::gemtext::GemtextLine;
+use crate::api::protocols::registry;
+
+/// Largest image fetched when the caller sets no limit
+const DEFAULT_MAX_BYTES: u32 = 1024 * 1024;
+
+/// Most images fetched for one document; links past these are left as links
+const MAX_IMAGES: usize = 32;
+
+/// Images fetched at once
+const CONCURRENT_FETCHES: usize = 4;
+
+/// An image link of a document with the image it leads to
+#[derive(Debug, Clone)]
+pub struct InlineImage {
+    /// Position of the link among the document's links, counting from 0
+    pub link_index: u32,
+    /// Index of the link's line in the document
+    pub line_index: u32,
+    pub url: String,
+    pub mime: String,
+    pub data: Vec<u8>,
+}
+
+/// An image link that was not fetched and why, e.g. the image was over the size limit
+#[derive(Debug, Clone)]
+pub struct InlineImageFailure {
+    pub link_index: u32,
+    pub line_index: u32,
+    pub url: String,
+    pub error: String,
+}
+
+/// The images of a document's image links, in document order
+#[derive(Debug, Clone, Default)]
+pub struct InlineImages {
+    pub images: Vec<InlineImage>,
+    pub failed: Vec<InlineImageFailure>,
+}
+
+/// A link of the document that names an image
+struct ImageLink {
+    link_index: u32,
+    line_index: u32,
+    url: Url,
+}
+
+/// Fetch the images a document links to for showing them in place of their links: only links on
+/// the page's own host over Gemini or Gopher that name an image by extension or Gopher item
+/// type, at most 32, each up to `max_bytes` (1 MiB by default). Images are not cached or added
+/// to history. In lite mode nothing is fetched and the links stay links.
+pub async fn fetch_inline_images(
+    page_url: &str,
+    document: &[GemtextLine],
+    max_bytes: Option<u32>,
+) -> Result<InlineImages, String> {
+    let base = Url::parse(page_url).map_err(|e| format!("Invalid URL: {}", e))?;
+    let max_bytes = max_bytes.unwrap_or(DEFAULT_MAX_BYTES);
+    if max_bytes == 0 {
+        return Err("Image size limit must be at least 1 byte".to_string());
+    }
+    if lite_mode() {
+        return Ok(InlineImages::default());
+    }
+
+    let mut links = image_links(&base, document).into_iter();
+    let mut fetches = JoinSet::new();
+    let mut result = InlineImages::default();
+    loop {
+        while fetches.len() < CONCURRENT_FETCHES {
+            let Some(link) = links.next() else {
+                break;
+            };
+            fetches.spawn(async move {
+                let fetched = fetch_image(link.url.clone(), max_bytes).await;
+                (link, fetched)
+            });
+        }
+        let Some(joined) = fetches.join_next().await else {
+            break;
+        };
+        let Ok((link, fetched)) = joined else {
+            continue;
+        };
+        match fetched {
+            Ok((mime, data)) => {
+                crate::api::functions::data_usage::record(
+                    link.url.as_str().len() as u64 + 2,
+                    data.len() as u64,
+                );
+                result.images.push(InlineImage {
+                    link_index: link.link_index,
+                    line_index: link.line_index,
+                    url: link.url.to_string(),
+                    mime,
+                    data,
+                });
+            }
+            Err(error) => result.failed.push(InlineImageFailure {
+                link_index: link.link_index,
+                line_index: link.line_index,
+                url: link.url.to_string(),
+                error,
+            }),
+        }
+    }
+    result.images.sort_by_key(|image| image.link_index);
+    result.failed.sort_by_key(|failure| failure.link_index);
+    Ok(result)
+}
+
+/// The links of a document that name an image on the page's host
+fn image_links(base: &Url, document: &[GemtextLine]) -> Vec<ImageLink> {
+    let mut links = Vec::new();
+    let mut link_index = 0;
+    for (line_index, line) in document.iter().enumerate() {
+        let GemtextLine::Link { url, .. } = line else {
+            continue;
+        };
+        let index = link_index;
+        link_index += 1;
+        let Ok(url) = base.join(url) else {
+            continue;
+        };
+        if url.host_str().is_none() || url.host_str() != base.host_str() || !names_image(&url) {
+            continue;
+        }
+        links.push(ImageLink {
+            link_index: index,
+            line_index: line_index as u32,
+            url,
+        });
+        if links.len() == MAX_IMAGES {
+            break;
+        }
+    }
+    links
+}
+
+/// Whether a Gemini or Gopher URL names an image, by its file extension or Gopher item type
+fn names_image(url: &Url) -> bool {
+    let path = url.path();
+    match url.scheme() {
+        "gopher" => {
+            let item_type = path.trim_start_matches('/').chars().next();
+            if matches!(item_type, Some('I' | 'g' | 'p')) {
+                return true;
+            }
+        }
+        "gemini" => {}
+        _ => return false,
+    }
+    path.rsplit_once('.')
+        .and_then(|(_, extension)| mime_for_extension(extension))
+        .is_some_and(|mime| mime.starts_with("image/"))
+}
+
+/// Fetch an image, returning its MIME type and bytes
+async fn fetch_image(url: Url, max_bytes: u32) -> Result<(String, Vec<u8>), String> {
+    crate::api::storage::blocklist::check_url(&url)?;
+    crate::api::storage::scheme_toggles::check_url(&url)?;
+    let handler = registry::handler_for(url.scheme())
+        .ok_or_else(|| format!("Unsupported URL scheme {}", url.scheme()))?;
+    let body = handler.open_body(&url).await?;
+    let mut data = Vec::new();
+    // One byte more than the limit tells whether the image is over it
+    body.reader
+        .take(max_bytes as u64 + 1)
+        .read_to_end(&mut data)
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    if data.len() as u64 > max_bytes as u64 {
+        return Err(format!("Image is larger than {} bytes", max_bytes));
+    }
+    // Gopher servers answer image items without a type, and capsules often mislabel them
+    let mime = if is_generic_mime(&body.mime) || !body.mime.starts_with("image/") {
+        sniff(&data).map(|file_type| file_type.mime)
+    } else {
+        Some(body.mime)
+    };
+    match mime {
+        Some(mime) if mime.starts_with("image/") => Ok((mime, data)),
+        _ => Err("Not an image".to_string()),
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::input_answers::{self, SavedAnswer};
+use crate::api::storage::settings;
+
+/// Settings key of whether answers to input prompts are saved
+const REMEMBER_INPUT_KEY: &str = "remember_input_answers";
+
+/// Most prompts remembered while waiting for their answer; the map is emptied when full
+const MAX_PENDING_PROMPTS: usize = 64;
+
+/// The last prompt each URL asked, by its canonical URL without a query: the prompt and
+/// whether the input was sensitive (status 11)
+static PROMPTS: LazyLock<Mutex<HashMap<String, (String, bool)>>> = LazyLock::new(Default::default);
+
+/// Whether answers to input prompts are saved to be offered again; off unless turned on
+pub fn remember_input_answers() -> bool {
+    settings::get(REMEMBER_INPUT_KEY)
+        .ok()
+        .flatten()
+        .is_some_and(|value| value == "1")
+}
+
+/// Save answers to input prompts, or stop saving them and forget those saved. Answers to
+/// sensitive prompts (status 11), such as passwords, are never saved.
+pub fn set_remember_input_answers(enabled: bool) -> Result<(), String> {
+    if enabled {
+        settings::set(REMEMBER_INPUT_KEY, "1")
+    } else {
+        input_answers::clear_since(i64::MIN)?;
+        settings::remove(REMEMBER_INPUT_KEY)
+    }
+}
+
+/// The answers given before to a prompt of a URL, most recently given first, for the UI to
+/// offer when the URL asks the same question again
+pub fn saved_answers(url: &str, prompt: &str) -> Result<Vec<SavedAnswer>, String> {
+    let url = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+    input_answers::answers_for(&prompt_url(&url), prompt)
+}
+
+/// Follow a navigation for input prompts and their answers: remember the prompt a response
+/// asks, and save the query of a request answering a non-sensitive one when answers are saved
+pub(crate) fn observe(requested: Option<&Url>, response: &FetchResponse) {
+    let mut prompts = PROMPTS.lock().unwrap();
+    if let Some(requested) = requested.filter(|url| url.query().is_some()) {
+        let asked = prompt_url(requested);
+        if let Some((prompt, false)) = prompts.remove(&asked) {
+            if remember_input_answers() {
+                let answer =
+                    percent_decode_str(requested.query().unwrap_or_default()).decode_utf8_lossy();
+                // Saving is best-effort and must not fail the navigation
+                let _ = input_answers::record_answer(&asked, &prompt, &answer);
+            }
+        }
+    }
+    let Some(status @ 10..=19) = response.status else {
+        return;
+    };
+    let Ok(url) = Url::parse(&response.url) else {
+        return;
+    };
+    let url = prompt_url(&url);
+    let sensitive = status == 11;
+    if sensitive {
+        // A URL that now asks for a secret may have been given one as an ordinary answer
+        let _ = input_answers::forget_url(&url);
+    }
+    if prompts.len() >= MAX_PENDING_PROMPTS {
+        prompts.clear();
+    }
+    let prompt = response.meta.clone().unwrap_or_default().trim().to_string();
+    prompts.insert(url, (prompt, sensitive));
+}
+
+/// The canonical form of the URL that asks a prompt, without the query an answer adds
+fn prompt_url(url: &Url) -> String {
+    let mut url = canonicalize(url);
+    url.set_query(None);
+    url.to_string()
+}
@@ -0,0 +1,268 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::api::storage::known_hosts::{self, KnownHost};
+use crate::api::storage::now;
+
+/// Port Gemini hosts are written without in the Amfora and Lagrange formats
+const GEMINI_PORT: u16 = 1965;
+
+/// known_hosts file formats; all of them pin the SHA-256 of the public key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnownHostsFormat {
+    /// Holloway's own file, which keeps every detail of a pin: one
+    /// `host port hex expiry first-seen last-seen` line per host, times in Unix seconds and `-`
+    /// for a certificate without an expiry
+    Holloway,
+    /// Amfora's tofu.toml: `"host" = "HEX"` and `"host/expiry" = <RFC 3339 time>`, with the dots
+    /// of the host written as slashes and non-default ports appended as `:port`
+    Amfora,
+    /// Lagrange's trusted.2.txt: one `host;port expiry-unix-seconds hex` line per host
+    Lagrange,
+}
+
+/// How many pins an import added and how many it passed over
+#[derive(Debug, Clone, Default)]
+pub struct KnownHostsImport {
+    pub imported: u32,
+    /// Hosts already pinned here (whose existing trust decision wins) and unreadable entries
+    pub skipped: u32,
+}
+
+/// One pin read from another client's file
+struct ImportedPin {
+    host: String,
+    port: u16,
+    fingerprint: String,
+    expires_at: Option<i64>,
+    /// When the pin was first made, for files that record it
+    first_seen: Option<i64>,
+}
+
+/// Write every pin in a known_hosts format
+pub fn export_known_hosts(format: KnownHostsFormat) -> Result<String, String> {
+    let hosts = known_hosts::list_known_hosts()?;
+    Ok(match format {
+        KnownHostsFormat::Holloway => export_holloway(&hosts),
+        KnownHostsFormat::Amfora => export_amfora(&hosts),
+        KnownHostsFormat::Lagrange => export_lagrange(&hosts),
+    })
+}
+
+/// Add the pins of a known_hosts file, keeping pins that already exist
+pub fn import_known_hosts(
+    format: KnownHostsFormat,
+    data: &str,
+) -> Result<KnownHostsImport, String> {
+    let (pins, unreadable) = match format {
+        KnownHostsFormat::Holloway => parse_holloway(data),
+        KnownHostsFormat::Amfora => parse_amfora(data),
+        KnownHostsFormat::Lagrange => parse_lagrange(data),
+    };
+    let mut result = KnownHostsImport {
+        imported: 0,
+        skipped: unreadable,
+    };
+    for pin in pins {
+        if known_hosts::get_known_host(&pin.host, pin.port)?.is_some() {
+            result.skipped += 1;
+            continue;
+        }
+        known_hosts::pin(
+            &pin.host,
+            pin.port,
+            &pin.fingerprint,
+            pin.expires_at,
+            pin.first_seen.unwrap_or_else(now),
+        )?;
+        result.imported += 1;
+    }
+    Ok(result)
+}
+
+fn export_holloway(hosts: &[KnownHost]) -> String {
+    let mut out = String::from("# host port fingerprint expires-at first-seen last-seen\n");
+    for host in hosts {
+        let expiry = host
+            .expires_at
+            .map_or_else(|| "-".to_string(), |expiry| expiry.to_string());
+        out.push_str(&format!(
+            "{} {} {} {} {} {}\n",
+            host.host, host.port, host.fingerprint, expiry, host.first_seen, host.last_seen
+        ));
+    }
+    out
+}
+
+fn parse_holloway(data: &str) -> (Vec<ImportedPin>, u32) {
+    let mut pins = Vec::new();
+    let mut unreadable = 0;
+    for line in data.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // The last-seen time is not imported; the pin is seen from here on
+        let pin = match fields.as_slice() {
+            [host, port, fingerprint, expiry, first_seen, _] => {
+                let fingerprint = fingerprint.to_ascii_lowercase();
+                let expiry = match *expiry {
+                    "-" => Ok(None),
+                    expiry => expiry.parse::<i64>().map(Some),
+                };
+                match (port.parse(), expiry, first_seen.parse::<i64>()) {
+                    (Ok(port), Ok(expires_at), Ok(first_seen)) if is_fingerprint(&fingerprint) => {
+                        Some(ImportedPin {
+                            host: host.to_ascii_lowercase(),
+                            port,
+                            fingerprint,
+                            expires_at,
+                            first_seen: Some(first_seen),
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match pin {
+            Some(pin) => pins.push(pin),
+            None => unreadable += 1,
+        }
+    }
+    (pins, unreadable)
+}
+
+fn amfora_key(host: &str, port: u16) -> String {
+    let key = host.replace('.', "/");
+    if port == GEMINI_PORT {
+        key
+    } else {
+        format!("{}:{}", key, port)
+    }
+}
+
+fn export_amfora(hosts: &[KnownHost]) -> String {
+    let mut out = String::new();
+    for host in hosts {
+        let key = amfora_key(&host.host, host.port);
+        out.push_str(&format!(
+            "\"{}\" = \"{}\"\n",
+            key,
+            host.fingerprint.to_ascii_uppercase()
+        ));
+        let expiry = host
+            .expires_at
+            .and_then(|expiry| OffsetDateTime::from_unix_timestamp(expiry).ok())
+            .and_then(|expiry| expiry.format(&Rfc3339).ok());
+        if let Some(expiry) = expiry {
+            out.push_str(&format!("\"{}/expiry\" = {}\n", key, expiry));
+        }
+    }
+    out
+}
+
+fn parse_amfora(data: &str) -> (Vec<ImportedPin>, u32) {
+    let mut fingerprints = Vec::new();
+    let mut expiries = std::collections::HashMap::new();
+    let mut unreadable = 0;
+
+    for line in data.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            unreadable += 1;
+            continue;
+        };
+        let key = key.trim().trim_matches('"').to_ascii_lowercase();
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        match key.strip_suffix("/expiry") {
+            Some(host_key) => {
+                if let Ok(expiry) = OffsetDateTime::parse(value, &Rfc3339) {
+                    expiries.insert(host_key.to_string(), expiry.unix_timestamp());
+                }
+            }
+            None => fingerprints.push((key, value.to_ascii_lowercase())),
+        }
+    }
+
+    let mut pins = Vec::new();
+    for (key, fingerprint) in fingerprints {
+        let (host, port) = match key.rsplit_once(':') {
+            Some((host, port)) => match port.parse() {
+                Ok(port) => (host, port),
+                Err(_) => {
+                    unreadable += 1;
+                    continue;
+                }
+            },
+            None => (key.as_str(), GEMINI_PORT),
+        };
+        if !is_fingerprint(&fingerprint) {
+            unreadable += 1;
+            continue;
+        }
+        pins.push(ImportedPin {
+            host: host.replace('/', "."),
+            port,
+            expires_at: expiries.get(&key).copied(),
+            first_seen: None,
+            fingerprint,
+        });
+    }
+    (pins, unreadable)
+}
+
+fn export_lagrange(hosts: &[KnownHost]) -> String {
+    hosts
+        .iter()
+        .map(|host| {
+            format!(
+                "{};{} {} {}\n",
+                host.host,
+                host.port,
+                host.expires_at.unwrap_or(0),
+                host.fingerprint
+            )
+        })
+        .collect()
+}
+
+fn parse_lagrange(data: &str) -> (Vec<ImportedPin>, u32) {
+    let mut pins = Vec::new();
+    let mut unreadable = 0;
+    for line in data.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let pin = match fields.as_slice() {
+            [key, expiry, fingerprint] => {
+                let (host, port) = match key.rsplit_once(';') {
+                    Some((host, port)) => (host, port.parse().ok()),
+                    None => (*key, Some(GEMINI_PORT)),
+                };
+                let fingerprint = fingerprint.to_ascii_lowercase();
+                match (port, expiry.parse::<i64>()) {
+                    (Some(port), Ok(expiry)) if is_fingerprint(&fingerprint) => Some(ImportedPin {
+                        host: host.to_ascii_lowercase(),
+                        port,
+                        fingerprint,
+                        expires_at: (expiry > 0).then_some(expiry),
+                        first_seen: None,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match pin {
+            Some(pin) => pins.push(pin),
+            None => unreadable += 1,
+        }
+    }
+    (pins, unreadable)
+}
+
+/// A SHA-256 digest as hex
+fn is_fingerprint(value: &str) -> bool {
+    value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
@@ -0,0 +1,175 @@
+use chardetng::EncodingDetector;
+use codepage_437::{FromCp437, CP437_CONTROL};
+use encoding_rs::{Encoding, REPLACEMENT, UTF_8};
+
+use crate::api::storage::host_settings::get_host_settings;
+
+/// Labels of ASCII, which the Encoding Standard decodes as Windows-1252
+const ASCII_LABELS: &[&str] = &[
+    "ascii",
+    "us-ascii",
+    "ansi_x3.4-1968",
+    "iso646-us",
+    "iso-ir-6",
+    "csascii",
+    "cp367",
+    "ibm367",
+];
+
+/// Labels of IBM PC code page 437, the DOS character set of much ANSI art and BBS-era text
+const IBM437_LABELS: &[&str] = &["ibm437", "cp437", "437", "dos", "cspc8codepage437"];
+
+/// A character set legacy text can be decoded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Charset {
+    /// An encoding of the Encoding Standard, e.g. UTF-8, KOI8-R, or Windows-1252
+    Encoding(&'static Encoding),
+    /// ASCII, whose bytes past 0x7F are invalid rather than Windows-1252 characters
+    Ascii,
+    /// CP437, which the Encoding Standard lacks
+    Ibm437,
+}
+
+impl Charset {
+    fn name(self) -> String {
+        match self {
+            Charset::Encoding(encoding) => encoding.name().to_ascii_lowercase(),
+            Charset::Ascii => "us-ascii".to_string(),
+            Charset::Ibm437 => "ibm437".to_string(),
+        }
+    }
+
+    /// The character set a label names, ignoring case and surrounding whitespace
+    fn from_label(label: &str) -> Option<Charset> {
+        let label = label.trim().to_ascii_lowercase();
+        if ASCII_LABELS.contains(&label.as_str()) {
+            return Some(Charset::Ascii);
+        }
+        if IBM437_LABELS.contains(&label.as_str()) {
+            return Some(Charset::Ibm437);
+        }
+        // The replacement encoding only stands in for ones unsafe to decode, e.g. ISO-2022-KR
+        Encoding::for_label(label.as_bytes())
+            .filter(|&encoding| encoding != REPLACEMENT)
+            .map(Charset::Encoding)
+    }
+
+    fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            Charset::Encoding(encoding) => encoding.decode_with_bom_removal(bytes).0.into_owned(),
+            Charset::Ascii => bytes
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii() {
+                        byte as char
+                    } else {
+                        char::REPLACEMENT_CHARACTER
+                    }
+                })
+                .collect(),
+            Charset::Ibm437 => String::from_cp437(bytes.to_vec(), &CP437_CONTROL),
+        }
+    }
+}
+
+/// Whether a charset label names a character set text can be decoded from, for checking the
+/// charset setting of a host when it is saved
+pub(crate) fn is_known_label(label: &str) -> bool {
+    Charset::from_label(label).is_some()
+}
+
+/// Guess the character set of text that declares none: UTF-8 when the bytes are valid UTF-8,
+/// CP437 for DOS box drawing and ANSI art, and otherwise what chardetng makes of the bytes
+/// and the host's top-level domain
+fn detect(host: &str, bytes: &[u8]) -> Charset {
+    // A response cut off midway may end inside a character
+    if std::str::from_utf8(bytes).map_or_else(|e| e.error_len().is_none(), |_| true) {
+        return Charset::Encoding(UTF_8);
+    }
+    // chardetng knows no DOS code page, so box drawing is looked for first: the CP437 lines
+    // and corners at 0xB0-0xBF, which text in the encodings it knows rarely uses, and lines
+    // repeating a drawing character, which words rarely do
+    let high = bytes.iter().filter(|&&byte| byte >= 0x80).count();
+    let mut drawing = bytes
+        .iter()
+        .filter(|&&byte| (0xB0..0xC0).contains(&byte))
+        .count();
+    drawing += bytes
+        .windows(2)
+        .filter(|pair| pair[0] == pair[1] && (0xB0..0xE0).contains(&pair[0]))
+        .count();
+    if drawing * 4 >= high {
+        return Charset::Ibm437;
+    }
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    let tld = host
+        .rsplit('.')
+        .next()
+        .filter(|tld| tld.bytes().all(|byte| byte.is_ascii_alphabetic()))
+        .map(str::to_ascii_lowercase);
+    Charset::Encoding(detector.guess(tld.as_deref().map(str::as_bytes), false))
+}
+
+/// Decode the text of a response that declares no character set, with the host's configured
+/// charset when it has one and a guess otherwise, returning the text and the name of the
+/// charset used
+pub(crate) fn decode_for_host(host: &str, bytes: &[u8]) -> (String, String) {
+    let configured = get_host_settings(host)
+        .ok()
+        .flatten()
+        .and_then(|settings| settings.charset)
+        .and_then(|label| Charset::from_label(&label));
+    let charset = configured.unwrap_or_else(|| detect(host, bytes));
+    (charset.decode(bytes), charset.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels() {
+        assert_eq!(Charset::from_label(" US-ASCII "), Some(Charset::Ascii));
+        assert_eq!(Charset::from_label("CP437"), Some(Charset::Ibm437));
+        assert_eq!(
+            Charset::from_label("latin1"),
+            Some(Charset::Encoding(encoding_rs::WINDOWS_1252))
+        );
+        assert_eq!(
+            Charset::from_label("koi8-r"),
+            Some(Charset::Encoding(encoding_rs::KOI8_R))
+        );
+        assert!(!is_known_label("klingon"));
+        assert!(!is_known_label("iso-2022-kr"));
+    }
+
+    #[test]
+    fn ascii_keeps_high_bytes_invalid() {
+        assert_eq!(
+            Charset::Ascii.decode(b"caf\xe9 \x93ok\x94"),
+            "caf\u{fffd} \u{fffd}ok\u{fffd}"
+        );
+    }
+
+    #[test]
+    fn detected_charsets() {
+        let russian = "Добро пожаловать на наш сервер. Здесь вы найдёте новости и статьи.";
+        let (koi8, _, _) = encoding_rs::KOI8_R.encode(russian);
+        let charset = detect("gopher.example.ru", &koi8);
+        assert_eq!(charset.decode(&koi8), russian);
+
+        let box_drawing =
+            b"\xc9\xcd\xcd\xcd\xcd\xbb\r\n\xba BBS \xba\r\n\xc8\xcd\xcd\xcd\xcd\xbc\r\n";
+        assert_eq!(detect("example.org", box_drawing), Charset::Ibm437);
+        assert_eq!(
+            Charset::Ibm437.decode(box_drawing),
+            "╔════╗\r\n║ BBS ║\r\n╚════╝\r\n"
+        );
+
+        assert_eq!(
+            detect("example.org", "déjà vu".as_bytes()),
+            Charset::Encoding(UTF_8)
+        );
+    }
+}
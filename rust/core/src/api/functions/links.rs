@@ -0,0 +1,138 @@
+use url::Url;
+
+use crate::api::functions::content_router::{route_url, Renderer};
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::parsers::gophermap::{parse_gophermap, GopherItem};
+use crate::api::protocols::registry;
+use crate::api::storage::gemini_proxies;
+
+/// What following a link leads to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// A page or menu the browser opens itself
+    Page,
+    /// A Gopher search or phone book, which needs a query before it can be fetched
+    Search,
+    /// An image, sound, video, or other file that is downloaded rather than shown as a page
+    Download,
+    /// A link handed to another app, e.g. a web page or a telnet session
+    External,
+}
+
+/// A link on a page, resolved against the page URL
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageLink {
+    pub url: String,
+    pub label: Option<String>,
+    pub kind: LinkKind,
+}
+
+/// Every link of a gemtext page or Gopher menu in document order, e.g. for prefetching the
+/// `Page` links, opening all of them, or link hints; other content has none. Links that cannot
+/// be resolved, informational menu lines, and errors are left out.
+pub fn extract_links(page_url: &str, mime: &str, body: &str) -> Vec<PageLink> {
+    let Ok(base) = Url::parse(page_url) else {
+        return Vec::new();
+    };
+    match route_url(page_url, mime) {
+        Renderer::Gemtext => parse_gemtext(body)
+            .into_iter()
+            .filter_map(|line| match line {
+                GemtextLine::Link { url, label } => {
+                    let url = base.join(&url).ok()?;
+                    Some(PageLink {
+                        kind: scheme_kind(url.scheme()),
+                        url: url.to_string(),
+                        label,
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+        Renderer::GopherMenu => parse_gophermap(body).iter().filter_map(menu_link).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Resolve a link target against the URL of the page it is on (RFC 3986), e.g. `./post.gmi`
+/// on a gemlog. Relative links on Gopher pages are resolved against the selector rather than
+/// the whole path, whose first segment is the item type of the page, and get the item type
+/// their file name suggests: `notes.txt` on `gopher://host/1/dir/` is
+/// `gopher://host/0/dir/notes.txt`.
+pub fn resolve_link(base_url: &str, href: &str) -> Result<String, String> {
+    let base = Url::parse(base_url).map_err(|e| format!("Invalid URL {}: {}", base_url, e))?;
+    let href = href.trim();
+    let relative_path = !href.starts_with(['/', '?', '#']) && Url::parse(href).is_err();
+    if base.scheme() != "gopher" || !relative_path {
+        return base
+            .join(href)
+            .map(|url| url.to_string())
+            .map_err(|e| format!("Invalid URL {}: {}", href, e));
+    }
+    let path = base.path().trim_start_matches('/');
+    let selector = path.get(1..).unwrap_or_default();
+    let mut selector_base = base.clone();
+    selector_base.set_path(selector);
+    selector_base.set_query(None);
+    let mut target = selector_base
+        .join(href)
+        .map_err(|e| format!("Invalid URL {}: {}", href, e))?;
+    let item_type = item_type_for(target.path());
+    let path = format!("/{}{}", item_type, target.path());
+    target.set_path(&path);
+    Ok(target.to_string())
+}
+
+/// The Gopher item type of a selector by its file name: a directory for a trailing slash,
+/// else by extension, with plain text for files without one
+fn item_type_for(selector: &str) -> char {
+    if selector.ends_with('/') {
+        return '1';
+    }
+    let name = selector.rsplit('/').next().unwrap_or_default();
+    let extension = match name.rsplit_once('.') {
+        Some((_, extension)) => extension.to_ascii_lowercase(),
+        None => return '0',
+    };
+    match extension.as_str() {
+        "txt" | "text" | "gmi" | "gemini" | "md" | "asc" | "nfo" => '0',
+        "html" | "htm" | "xhtml" => 'h',
+        "gif" => 'g',
+        "png" | "jpg" | "jpeg" | "webp" | "bmp" => 'I',
+        "wav" | "mp3" | "ogg" | "flac" => 's',
+        "mp4" | "mkv" | "webm" | "avi" => ';',
+        "pdf" | "ps" | "doc" | "odt" => 'd',
+        _ => '9',
+    }
+}
+
+/// Whether the browser opens URLs of a scheme itself
+fn scheme_kind(scheme: &str) -> LinkKind {
+    let proxied = gemini_proxies::proxy_for(scheme).ok().flatten().is_some();
+    if registry::handler_for(scheme).is_some() || proxied {
+        LinkKind::Page
+    } else {
+        LinkKind::External
+    }
+}
+
+/// The link of a menu item; web links point at the web page rather than at the gopher server,
+/// and telnet items at the telnet session
+pub(crate) fn menu_link(item: &GopherItem) -> Option<PageLink> {
+    let label = Some(item.display.clone());
+    let (url, kind) = match item.item_type {
+        'h' if item.selector.starts_with("URL:") => {
+            let url = Url::parse(&item.selector["URL:".len()..]).ok()?;
+            (url.to_string(), LinkKind::External)
+        }
+        '8' | 'T' if !item.host.is_empty() => (
+            format!("telnet://{}:{}", item.host, item.port),
+            LinkKind::External,
+        ),
+        '0' | '1' | '+' | 'h' => (item.to_url()?, LinkKind::Page),
+        '2' | '7' => (item.to_url()?, LinkKind::Search),
+        _ if item.fetchable => (item.to_url()?, LinkKind::Download),
+        _ => return None,
+    };
+    Some(PageLink { url, label, kind })
+}
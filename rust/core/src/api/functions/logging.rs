@@ -0,0 +1,298 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use tokio::sync::broadcast;
+
+use crate::api::functions::navigation_events::current_request;
+
+/// Records kept in memory for a console opened after they were logged
+const MAX_RECENT: usize = 1000;
+
+/// Records kept for a slow subscriber before the oldest are dropped
+const BUS_CAPACITY: usize = 1024;
+
+/// Size at which the log file is rotated
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Rotated log files kept besides the current one
+const ROTATED_FILES: usize = 3;
+
+/// Name of the current log file in the log directory; rotated files get ".1", ".2", ...
+const FILE_NAME: &str = "holloway.log";
+
+/// How severe a log record is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Each step of a request, as recorded in its trace
+    Debug,
+    /// Requests finishing, settings changing
+    Info,
+    /// Failures of a request or of a server, e.g. a refused connection or a changed certificate
+    Warn,
+    /// Failures of the browser itself, e.g. of the profile database
+    Error,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => LogLevel::Debug,
+            1 => LogLevel::Info,
+            2 => LogLevel::Warn,
+            _ => LogLevel::Error,
+        }
+    }
+}
+
+/// One entry of the log
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: i64,
+    pub level: LogLevel,
+    /// Part of the browser that logged it, e.g. "gemini", "tls", or "navigation"
+    pub target: String,
+    pub message: String,
+    /// Request id of the navigation it was logged during, for matching it with its trace
+    pub request_id: Option<u64>,
+}
+
+/// The open log file and how much has been written to it
+struct LogFile {
+    dir: PathBuf,
+    file: File,
+    size: u64,
+}
+
+static MIN_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+
+static RECENT: LazyLock<Mutex<VecDeque<LogRecord>>> = LazyLock::new(|| Mutex::new(VecDeque::new()));
+
+static BUS: LazyLock<broadcast::Sender<LogRecord>> =
+    LazyLock::new(|| broadcast::channel(BUS_CAPACITY).0);
+
+/// A subscriber's end of the bus, locked while it waits for a record
+type Subscription = Arc<tokio::sync::Mutex<broadcast::Receiver<LogRecord>>>;
+
+static SUBSCRIPTIONS: LazyLock<Mutex<HashMap<u64, Subscription>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_SUBSCRIPTION: AtomicU64 = AtomicU64::new(1);
+
+static FILE: Mutex<Option<LogFile>> = Mutex::new(None);
+
+/// Log a record at a level, building the message only when the level is logged; records are
+/// kept in memory, published to subscribers, and appended to the log file if there is one
+pub(crate) fn log(level: LogLevel, target: &str, message: impl FnOnce() -> String) {
+    let request_id = current_request().map(|(request_id, _)| request_id);
+    log_for(request_id, level, target, message);
+}
+
+/// Log a record of a request by its id, for code running outside the request's task
+pub(crate) fn log_for(
+    request_id: Option<u64>,
+    level: LogLevel,
+    target: &str,
+    message: impl FnOnce() -> String,
+) {
+    if level < log_level() {
+        return;
+    }
+    let record = LogRecord {
+        timestamp_ms: (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000) as i64,
+        level,
+        target: target.to_string(),
+        message: message(),
+        request_id,
+    };
+    write_to_file(&record);
+    {
+        let mut recent = RECENT.lock().unwrap();
+        if recent.len() >= MAX_RECENT {
+            recent.pop_front();
+        }
+        recent.push_back(record.clone());
+    }
+    // Sending only fails when nobody is subscribed
+    let _ = BUS.send(record);
+}
+
+/// The least severe level that is logged
+pub fn log_level() -> LogLevel {
+    LogLevel::from_u8(MIN_LEVEL.load(Ordering::Relaxed))
+}
+
+/// Log records of this level and more severe ones from now on; Info by default
+pub fn set_log_level(level: LogLevel) {
+    MIN_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The most recent records of at least `min_level` still kept in memory, oldest first, for a
+/// console opened after they were logged
+pub fn recent_logs(min_level: LogLevel, limit: u32) -> Vec<LogRecord> {
+    let recent = RECENT.lock().unwrap();
+    let mut records: Vec<LogRecord> = recent
+        .iter()
+        .rev()
+        .filter(|record| record.level >= min_level)
+        .take(limit as usize)
+        .cloned()
+        .collect();
+    records.reverse();
+    records
+}
+
+/// Start receiving log records, returning a subscription for `next_record`
+pub fn subscribe() -> u64 {
+    let id = NEXT_SUBSCRIPTION.fetch_add(1, Ordering::Relaxed);
+    let receiver = Arc::new(tokio::sync::Mutex::new(BUS.subscribe()));
+    SUBSCRIPTIONS.lock().unwrap().insert(id, receiver);
+    id
+}
+
+/// Wait for the next record logged after the subscription started; records a slow subscriber
+/// fell behind on are skipped
+pub async fn next_record(subscription: u64) -> Result<LogRecord, String> {
+    let receiver = SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .get(&subscription)
+        .cloned()
+        .ok_or_else(|| format!("Unknown log subscription {}", subscription))?;
+    let mut receiver = receiver.lock().await;
+    loop {
+        match receiver.recv().await {
+            Ok(record) => return Ok(record),
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => {
+                unreachable!("log bus sender lives in a static")
+            }
+        }
+    }
+}
+
+/// Stop a subscription, returning whether it existed
+pub fn unsubscribe(subscription: u64) -> bool {
+    SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .remove(&subscription)
+        .is_some()
+}
+
+/// Append records to a log file in a directory, rotated at 1 MiB with the three previous files
+/// kept, or stop writing one with None. The files contain the URLs of visited pages, so
+/// writing them is left to the user.
+pub fn set_log_directory(dir: Option<&str>) -> Result<(), String> {
+    let mut file = FILE.lock().unwrap();
+    *file = None;
+    let Some(dir) = dir else {
+        return Ok(());
+    };
+    let dir = PathBuf::from(dir);
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create log directory {}: {}", dir.display(), e))?;
+    *file = Some(open_file(&dir)?);
+    Ok(())
+}
+
+/// Paths of the log files, oldest first, e.g. for attaching them to a bug report
+pub fn log_files() -> Vec<String> {
+    let file = FILE.lock().unwrap();
+    let Some(log) = file.as_ref() else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = (1..=ROTATED_FILES)
+        .rev()
+        .map(|index| rotated_path(&log.dir, index))
+        .filter(|path| path.exists())
+        .collect();
+    paths.push(log.dir.join(FILE_NAME));
+    paths
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect()
+}
+
+fn open_file(dir: &Path) -> Result<LogFile, String> {
+    let path = dir.join(FILE_NAME);
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open log file {}: {}", path.display(), e))?;
+    let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+    Ok(LogFile {
+        dir: dir.to_path_buf(),
+        file,
+        size,
+    })
+}
+
+fn rotated_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("{}.{}", FILE_NAME, index))
+}
+
+/// Append a record to the log file, rotating it first when the record would overflow it;
+/// logging must never fail what is being logged, so write errors close the file instead
+fn write_to_file(record: &LogRecord) {
+    let mut file = FILE.lock().unwrap();
+    let Some(log) = file.as_mut() else {
+        return;
+    };
+    let line = format_line(record);
+    if log.size > 0 && log.size + line.len() as u64 > MAX_FILE_BYTES {
+        let dir = log.dir.clone();
+        for index in (1..ROTATED_FILES).rev() {
+            let _ = fs::rename(rotated_path(&dir, index), rotated_path(&dir, index + 1));
+        }
+        let _ = fs::rename(dir.join(FILE_NAME), rotated_path(&dir, 1));
+        match open_file(&dir) {
+            Ok(reopened) => *log = reopened,
+            Err(_) => {
+                *file = None;
+                return;
+            }
+        }
+    }
+    match log.file.write_all(line.as_bytes()) {
+        Ok(()) => log.size += line.len() as u64,
+        Err(_) => *file = None,
+    }
+}
+
+/// A record as one line of the log file, its message's line breaks escaped
+fn format_line(record: &LogRecord) -> String {
+    let timestamp =
+        OffsetDateTime::from_unix_timestamp_nanos(record.timestamp_ms as i128 * 1_000_000)
+            .ok()
+            .and_then(|time| time.format(&Rfc3339).ok())
+            .unwrap_or_else(|| record.timestamp_ms.to_string());
+    let request = record
+        .request_id
+        .map(|request_id| format!(" [request {}]", request_id))
+        .unwrap_or_default();
+    format!(
+        "{} {:<5} {}{}: {}\n",
+        timestamp,
+        record.level.as_str(),
+        record.target,
+        request,
+        record.message.replace('\n', "\\n")
+    )
+}
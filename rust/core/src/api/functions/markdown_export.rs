@@ -0,0 +1,168 @@
+use url::Url;
+
+use crate::api::functions::content_router::{route_url, Renderer};
+use crate::api::functions::links::menu_link;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::parsers::gophermap::parse_gophermap;
+
+/// Render a page as CommonMark for Markdown-based note tools: a Gopher menu (by its URL's item
+/// type or MIME type) becomes its info text as code blocks and its items as a list of links,
+/// and anything else is read as gemtext. Links are resolved against `base_url`, and text is
+/// escaped so it is not mistaken for Markdown syntax.
+pub fn render_to_markdown(body: &str, base_url: &str, mime: &str) -> String {
+    let blocks = match route_url(base_url, mime) {
+        Renderer::GopherMenu => menu_blocks(body),
+        _ => gemtext_blocks(body, Url::parse(base_url).ok().as_ref()),
+    };
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Markdown blocks of a gemtext page; every gemtext line is a paragraph of its own, except that
+/// consecutive list items and quote lines stay together
+fn gemtext_blocks(body: &str, base: Option<&Url>) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut preformatted: Option<(Option<String>, Vec<String>)> = None;
+    let mut previous_list = false;
+    let mut previous_quote = false;
+    for line in parse_gemtext(body) {
+        let (list, quote) = (
+            matches!(line, GemtextLine::ListItem(_)),
+            matches!(line, GemtextLine::Quote(_)),
+        );
+        match line {
+            GemtextLine::PreformatToggle { alt } => match preformatted.take() {
+                Some((alt, lines)) => blocks.push(fenced(alt.as_deref(), &lines)),
+                None => preformatted = Some((alt, Vec::new())),
+            },
+            GemtextLine::Preformatted(text) => {
+                if let Some((_, lines)) = preformatted.as_mut() {
+                    lines.push(text);
+                }
+            }
+            GemtextLine::Text(text) if text.trim().is_empty() => {}
+            GemtextLine::Text(text) => blocks.push(escape(&text)),
+            GemtextLine::Heading { level, text } => {
+                blocks.push(format!("{} {}", "#".repeat(level as usize), escape(&text)));
+            }
+            GemtextLine::Link { url, label } => {
+                let target = base
+                    .and_then(|base| base.join(&url).ok())
+                    .map_or(url, |target| target.to_string());
+                blocks.push(link(label.as_deref().unwrap_or(&target), &target));
+            }
+            GemtextLine::ListItem(text) => {
+                let item = format!("- {}", escape(&text));
+                push_joined(&mut blocks, item, previous_list, "\n");
+            }
+            GemtextLine::Quote(text) => {
+                let quote = format!("> {}", escape(&text));
+                // A bare ">" keeps each quote line a paragraph of its own within one quote
+                push_joined(&mut blocks, quote, previous_quote, "\n>\n");
+            }
+        }
+        previous_list = list;
+        previous_quote = quote;
+    }
+    // A block left open at the end of the page
+    if let Some((alt, lines)) = preformatted {
+        blocks.push(fenced(alt.as_deref(), &lines));
+    }
+    blocks
+}
+
+/// Markdown blocks of a Gopher menu: runs of information lines as code blocks, keeping their
+/// layout, and runs of items as lists of links
+fn menu_blocks(body: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut info: Vec<String> = Vec::new();
+    let mut previous_item = false;
+    for item in parse_gophermap(body) {
+        if item.item_type == 'i' || item.item_type == '3' {
+            info.push(item.display);
+            previous_item = false;
+            continue;
+        }
+        if !info.is_empty() {
+            blocks.push(fenced(None, &std::mem::take(&mut info)));
+        }
+        let Some(page_link) = menu_link(&item) else {
+            continue;
+        };
+        let label = page_link.label.as_deref().unwrap_or(&page_link.url);
+        let entry = format!("- {}", link(label, &page_link.url));
+        push_joined(&mut blocks, entry, previous_item, "\n");
+        previous_item = true;
+    }
+    if !info.is_empty() {
+        blocks.push(fenced(None, &info));
+    }
+    blocks
+}
+
+/// Append to the last block when it is of the same kind, else start a new block
+fn push_joined(blocks: &mut Vec<String>, text: String, same_kind: bool, separator: &str) {
+    match blocks.last_mut() {
+        Some(last) if same_kind => {
+            last.push_str(separator);
+            last.push_str(&text);
+        }
+        _ => blocks.push(text),
+    }
+}
+
+/// A fenced code block, its fence longer than any run of fence characters inside; the alt text
+/// is kept as the info string
+fn fenced(alt: Option<&str>, lines: &[String]) -> String {
+    let fence_char = if alt.is_some_and(|alt| alt.contains('`')) {
+        '~'
+    } else {
+        '`'
+    };
+    let longest_run = lines
+        .iter()
+        .flat_map(|line| line.split(|c| c != fence_char))
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = fence_char.to_string().repeat(longest_run.max(2) + 1);
+    format!(
+        "{}{}\n{}{}{}",
+        fence,
+        alt.unwrap_or(""),
+        lines.join("\n"),
+        if lines.is_empty() { "" } else { "\n" },
+        fence
+    )
+}
+
+/// An inline link; the destination is wrapped in angle brackets so spaces and parentheses in
+/// it need no escaping
+fn link(label: &str, url: &str) -> String {
+    let destination = url.replace('<', "%3C").replace('>', "%3E");
+    format!("[{}](<{}>)", escape(label), destination)
+}
+
+/// Backslash-escape the characters that would start Markdown inline syntax anywhere in the
+/// text, and the block markers that would turn a line into a heading, list, or code block;
+/// leading whitespace is dropped since four spaces would make the line code
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.trim_start().chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '&') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    // "1." and "1)" start ordered list items
+    let marker_at = escaped
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(escaped.len());
+    if marker_at > 0 && escaped[marker_at..].starts_with(['.', ')']) {
+        escaped.insert(marker_at, '\\');
+    } else if escaped.starts_with(['#', '+', '-', '=', '~']) {
+        escaped.insert(0, '\\');
+    }
+    escaped
+}
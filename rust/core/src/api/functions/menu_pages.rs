@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use crate::api::parsers::gophermap::{count_items, parse_item, GopherItem};
+
+/// A window of a retained Gopher menu
+#[derive(Debug, Clone)]
+pub struct MenuPage {
+    pub offset: u32,
+    pub items: Vec<GopherItem>,
+    /// Items in the whole menu
+    pub total: u32,
+}
+
+/// A menu body kept on the Rust side, parsed only as far as pages have been requested
+struct RetainedMenu {
+    body: String,
+    /// Byte offset of the first line not parsed yet
+    cursor: usize,
+    parsed: Vec<GopherItem>,
+    total: usize,
+}
+
+impl RetainedMenu {
+    /// Parse lines until `count` items are available or the menu ends
+    fn parse_until(&mut self, count: usize) {
+        while self.parsed.len() < count && self.cursor < self.body.len() {
+            let rest = &self.body[self.cursor..];
+            let line_end = rest.find('\n').map_or(rest.len(), |end| end + 1);
+            let line = rest[..line_end].trim_end_matches(['\n', '\r']);
+            self.cursor += line_end;
+            if line == "." {
+                self.cursor = self.body.len();
+            } else if let Some(item) = parse_item(line) {
+                self.parsed.push(item);
+            }
+        }
+    }
+}
+
+static MENUS: LazyLock<Mutex<HashMap<u64, Arc<Mutex<RetainedMenu>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Keep a menu body for paged access with `menu_page`, returning its handle; nothing is parsed yet
+pub fn open_menu(body: String) -> u64 {
+    let total = count_items(&body);
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let menu = RetainedMenu {
+        body,
+        cursor: 0,
+        parsed: Vec::new(),
+        total,
+    };
+    MENUS
+        .lock()
+        .unwrap()
+        .insert(handle, Arc::new(Mutex::new(menu)));
+    handle
+}
+
+/// Up to `count` items of a retained menu starting at `offset`, parsing the menu up to there
+pub fn menu_page(handle: u64, offset: u32, count: u32) -> Result<MenuPage, String> {
+    let menu = MENUS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown menu {}", handle))?;
+    let mut menu = menu.lock().unwrap();
+    let start = (offset as usize).min(menu.total);
+    let end = start.saturating_add(count as usize).min(menu.total);
+    menu.parse_until(end);
+    Ok(MenuPage {
+        offset: start as u32,
+        items: menu.parsed[start..end.min(menu.parsed.len())].to_vec(),
+        total: menu.total as u32,
+    })
+}
+
+/// Release a retained menu, returning whether it existed
+pub fn close_menu(handle: u64) -> bool {
+    MENUS.lock().unwrap().remove(&handle).is_some()
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use tokio::task::JoinSet;
+use url::Url;
+
+use crate::api::functions::canonical_url::equivalence_key;
+use crate::api::functions::content_router::is_gemtext;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::functions::search::{query_url, search_providers, SearchProvider};
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+
+/// Seconds each engine has to answer before its results are left out
+const PROVIDER_DEADLINE_SECS: u32 = 15;
+
+/// Constant of reciprocal rank fusion: how much a hit near the top of one engine's list
+/// outweighs one further down
+const RANK_CONSTANT: f64 = 60.0;
+
+/// One result of a metasearch, merged across the engines that returned it
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub url: String,
+    /// The link label the first engine gave it, or its URL
+    pub title: String,
+    /// First line of text an engine printed under the link, e.g. an excerpt or the page size
+    pub snippet: Option<String>,
+    /// Names of the engines that returned it, in the order of the provider list
+    pub providers: Vec<String>,
+    /// Sum over those engines of 1 / (60 + rank); higher is better
+    pub score: f64,
+}
+
+/// Merged hits of every engine that answered, best first
+#[derive(Debug, Clone)]
+pub struct MetasearchResult {
+    pub hits: Vec<SearchHit>,
+    /// Engines that failed or answered with something other than a result page
+    pub failed: Vec<ProviderFailure>,
+}
+
+/// An engine left out of a metasearch and why
+#[derive(Debug, Clone)]
+pub struct ProviderFailure {
+    pub name: String,
+    pub error: String,
+}
+
+/// A hit as one engine listed it
+struct ProviderHit {
+    url: Url,
+    title: String,
+    snippet: Option<String>,
+}
+
+/// Ask every offered search engine at once, without adding their result pages to history,
+/// and merge their hits: the same page found by several engines, even spelled differently,
+/// is one hit ranked by reciprocal rank fusion. Fails only when no engine answers.
+pub async fn search_all(query: &str) -> Result<MetasearchResult, String> {
+    if query.trim().is_empty() {
+        return Err("Search query cannot be empty".to_string());
+    }
+    let providers = search_providers();
+    let mut searches = JoinSet::new();
+    for (index, provider) in providers.iter().cloned().enumerate() {
+        let url = query_url(&provider.url_template, query);
+        searches.spawn(async move { (index, search_provider(&url).await) });
+    }
+    let mut answers = Vec::new();
+    let mut failed = Vec::new();
+    while let Some(joined) = searches.join_next().await {
+        let Ok((index, answer)) = joined else {
+            continue;
+        };
+        match answer {
+            Ok(hits) => answers.push((index, hits)),
+            Err(e) => failed.push((index, e)),
+        }
+    }
+    failed.sort_by_key(|(index, _)| *index);
+    let failed: Vec<ProviderFailure> = failed
+        .into_iter()
+        .map(|(index, error)| ProviderFailure {
+            name: providers[index].name.clone(),
+            error,
+        })
+        .collect();
+    if answers.is_empty() {
+        let reasons: Vec<String> = failed
+            .iter()
+            .map(|failure| format!("{}: {}", failure.name, failure.error))
+            .collect();
+        return Err(format!(
+            "No search engine answered ({})",
+            reasons.join("; ")
+        ));
+    }
+    answers.sort_by_key(|(index, _)| *index);
+    Ok(MetasearchResult {
+        hits: merge(&providers, answers),
+        failed,
+    })
+}
+
+/// Fetch one engine's result page and read its hits
+async fn search_provider(url: &str) -> Result<Vec<ProviderHit>, String> {
+    let options = FetchOptions {
+        private: true,
+        deadline_secs: Some(PROVIDER_DEADLINE_SECS),
+        ..FetchOptions::default()
+    };
+    let response = fetch_with_options(url.to_string(), &options).await?;
+    if !response.is_success() || !is_gemtext(&response.mime) {
+        return Err(format!(
+            "answered {} {}",
+            response.status.unwrap_or_default(),
+            response.meta.as_deref().unwrap_or_default()
+        ));
+    }
+    let base = Url::parse(&response.url).map_err(|e| format!("Invalid URL: {}", e))?;
+    Ok(parse_results(&base, &response.body))
+}
+
+/// The hits of a gemtext result page: every link leaving the engine's host, with the first line
+/// of text below it as its snippet. Links back to the engine are its own navigation and paging.
+fn parse_results(base: &Url, body: &str) -> Vec<ProviderHit> {
+    let mut hits: Vec<ProviderHit> = Vec::new();
+    let mut open = false;
+    for line in parse_gemtext(body) {
+        match line {
+            GemtextLine::Link { url, label } => {
+                open = false;
+                let Ok(url) = base.join(&url) else {
+                    continue;
+                };
+                if url.host_str().is_none() || url.host_str() == base.host_str() {
+                    continue;
+                }
+                let title = label
+                    .map(|label| label.trim().to_string())
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or_else(|| url.to_string());
+                hits.push(ProviderHit {
+                    url,
+                    title,
+                    snippet: None,
+                });
+                open = true;
+            }
+            GemtextLine::Text(text) | GemtextLine::ListItem(text) | GemtextLine::Quote(text)
+                if open && !text.trim().is_empty() =>
+            {
+                if let Some(hit) = hits.last_mut() {
+                    hit.snippet = Some(text.trim().to_string());
+                }
+                open = false;
+            }
+            GemtextLine::Heading { .. } => open = false,
+            _ => {}
+        }
+    }
+    hits
+}
+
+/// Merge the hits of each engine, given in provider order, by reciprocal rank fusion
+fn merge(providers: &[SearchProvider], answers: Vec<(usize, Vec<ProviderHit>)>) -> Vec<SearchHit> {
+    let mut merged: Vec<SearchHit> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for (index, hits) in answers {
+        let name = &providers[index].name;
+        for (rank, hit) in hits.into_iter().enumerate() {
+            let score = 1.0 / (RANK_CONSTANT + rank as f64 + 1.0);
+            let key = equivalence_key(&hit.url);
+            match positions.get(&key) {
+                Some(&position) => {
+                    let existing = &mut merged[position];
+                    // An engine listing a page twice counts once
+                    if existing.providers.contains(name) {
+                        continue;
+                    }
+                    existing.providers.push(name.clone());
+                    existing.score += score;
+                    if existing.snippet.is_none() {
+                        existing.snippet = hit.snippet;
+                    }
+                }
+                None => {
+                    positions.insert(key, merged.len());
+                    merged.push(SearchHit {
+                        url: hit.url.to_string(),
+                        title: hit.title,
+                        snippet: hit.snippet,
+                        providers: vec![name.clone()],
+                        score,
+                    });
+                }
+            }
+        }
+    }
+    // Stable, so ties keep the order the engines listed them in
+    merged.sort_by(|a, b| b.score.total_cmp(&a.score));
+    merged
+}
@@ -0,0 +1,481 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use tokio::io::AsyncReadExt;
+use tokio::sync::mpsc;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::crawler::{capsule_root, within};
+use crate::api::functions::downloads::sanitize_filename;
+use crate::api::functions::file_type::{extension_for_mime, mime_for_extension};
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::protocols::registry;
+use crate::api::transport::handshakes;
+
+/// File in the destination directory recording a mirror's progress, so a stopped mirror resumes
+/// where it left off. Each line is `queue <depth> <url>` for a URL found, or
+/// `done <url> <path> <mime>` for one saved, fields separated by tabs.
+const LOG_FILE: &str = ".holloway-mirror";
+
+/// Deepest link depth a mirror may follow from its start page
+const MAX_DEPTH: u32 = 16;
+
+/// Largest file saved; bigger ones are reported as failed
+const MAX_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Characters escaped in rewritten link paths
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'%').add(b'#').add(b'?');
+
+/// What happened to one URL of a mirror
+#[derive(Debug, Clone)]
+pub struct MirrorProgress {
+    pub url: String,
+    /// Where it was saved, when it was
+    pub path: Option<String>,
+    /// Why it was not saved
+    pub error: Option<String>,
+    /// Files saved so far, counting those of the run that was resumed
+    pub saved: u32,
+    pub failed: u32,
+    /// URLs found and not yet fetched
+    pub remaining: u32,
+}
+
+/// The caller's end of a running mirror
+struct ActiveMirror {
+    cancelled: Arc<AtomicBool>,
+    progress: tokio::sync::Mutex<mpsc::UnboundedReceiver<MirrorProgress>>,
+}
+
+static MIRRORS: LazyLock<Mutex<HashMap<u64, Arc<ActiveMirror>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// A URL waiting to be fetched and how many links away from the start page it is
+struct Queued {
+    url: Url,
+    depth: u32,
+}
+
+/// A saved file, by its path relative to the destination directory
+struct Saved {
+    path: String,
+    mime: String,
+}
+
+/// A mirror's state: what was found and saved so far, replayed from its log on resume
+struct State {
+    root: Url,
+    dest: PathBuf,
+    max_depth: u32,
+    seen: HashSet<String>,
+    queue: VecDeque<Queued>,
+    saved: HashMap<String, Saved>,
+    failed: u32,
+    log: File,
+}
+
+/// Start saving the Gemini capsule `url` belongs to (its host, under the URL's directory) to
+/// `dest_dir` for offline reading, returning a handle for `next_mirror_progress`. Pages up to
+/// `depth` links from `url` are saved with the files they link to on the same host, such as
+/// images. Once every file is saved, the links between them are rewritten to relative paths,
+/// so the copy reads without the network, and relative links to files not saved to absolute
+/// URLs.
+/// Files are fetched one at a time as background requests, within the host's robots.txt and
+/// rate limits. Starting again with the same `dest_dir` resumes a stopped mirror, fetching
+/// only what it had not saved.
+pub fn mirror_capsule(url: &str, dest_dir: &str, depth: u32) -> Result<u64, String> {
+    let start = parse_input_url(url).ok_or("Invalid URL format")?;
+    if start.scheme() != "gemini" {
+        return Err(format!(
+            "Unsupported URL scheme {} for mirroring",
+            start.scheme()
+        ));
+    }
+    if depth > MAX_DEPTH {
+        return Err(format!("Depth must be at most {}", MAX_DEPTH));
+    }
+    let dest = PathBuf::from(dest_dir);
+    fs::create_dir_all(&dest).map_err(|e| format!("Failed to create {}: {}", dest_dir, e))?;
+    let state = State::resume(&start, dest, depth)?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start mirror: {}", e))?;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let (sender, progress) = mpsc::unbounded_channel();
+    let worker_cancelled = cancelled.clone();
+    std::thread::Builder::new()
+        .name("capsule mirror".to_string())
+        .spawn(move || runtime.block_on(run(state, worker_cancelled, sender)))
+        .map_err(|e| format!("Failed to start mirror: {}", e))?;
+
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let mirror = ActiveMirror {
+        cancelled,
+        progress: tokio::sync::Mutex::new(progress),
+    };
+    MIRRORS.lock().unwrap().insert(handle, Arc::new(mirror));
+    Ok(handle)
+}
+
+/// Wait for the next URL of a mirror to be saved or fail; None once the mirror finished or was
+/// stopped, which releases the handle
+pub async fn next_progress(handle: u64) -> Result<Option<MirrorProgress>, String> {
+    let mirror = MIRRORS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown mirror {}", handle))?;
+    let progress = mirror.progress.lock().await.recv().await;
+    if progress.is_none() {
+        MIRRORS.lock().unwrap().remove(&handle);
+    }
+    Ok(progress)
+}
+
+/// Stop a mirror after the file it is fetching, returning whether it was running; starting it
+/// again with the same destination resumes it
+pub fn cancel_mirror(handle: u64) -> bool {
+    match MIRRORS.lock().unwrap().remove(&handle) {
+        Some(mirror) => {
+            mirror.cancelled.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Stop every running mirror after the file it is fetching, e.g. at shutdown, returning how many
+/// were running
+pub(crate) fn cancel_all_mirrors() -> usize {
+    let mirrors: Vec<_> = MIRRORS.lock().unwrap().drain().collect();
+    for (_, mirror) in &mirrors {
+        mirror.cancelled.store(true, Ordering::SeqCst);
+    }
+    mirrors.len()
+}
+
+async fn run(
+    mut state: State,
+    cancelled: Arc<AtomicBool>,
+    progress: mpsc::UnboundedSender<MirrorProgress>,
+) {
+    while let Some(queued) = state.queue.pop_front() {
+        if cancelled.load(Ordering::SeqCst) {
+            return;
+        }
+        let key = queued.url.to_string();
+        let (path, error) = match state.fetch(&queued).await {
+            Ok(path) => (Some(path), None),
+            Err(e) => {
+                state.failed += 1;
+                (None, Some(e))
+            }
+        };
+        // Sending only fails once the mirror was cancelled
+        let _ = progress.send(MirrorProgress {
+            url: key,
+            path: path.map(|path| state.dest.join(path).to_string_lossy().to_string()),
+            error,
+            saved: state.saved.len() as u32,
+            failed: state.failed,
+            remaining: state.queue.len() as u32,
+        });
+    }
+    if !cancelled.load(Ordering::SeqCst) {
+        state.rewrite_links();
+    }
+}
+
+impl State {
+    /// The state of a new mirror, or of the one whose log is in `dest`
+    fn resume(start: &Url, dest: PathBuf, max_depth: u32) -> Result<Self, String> {
+        let log_path = dest.join(LOG_FILE);
+        let mut entries = Vec::new();
+        if let Ok(log) = File::open(&log_path) {
+            for line in BufReader::new(log).lines() {
+                let line = line.map_err(|e| format!("Failed to read mirror log: {}", e))?;
+                entries.push(line);
+            }
+        }
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)
+            .map_err(|e| format!("Failed to open mirror log: {}", e))?;
+        let mut state = State {
+            root: capsule_root(start)?,
+            dest,
+            max_depth,
+            seen: HashSet::new(),
+            queue: VecDeque::new(),
+            saved: HashMap::new(),
+            failed: 0,
+            log,
+        };
+
+        let mut depths = Vec::new();
+        for entry in &entries {
+            match entry.split('\t').collect::<Vec<_>>().as_slice() {
+                ["queue", depth, url] => {
+                    if let (Ok(depth), Ok(url)) = (depth.parse(), Url::parse(url)) {
+                        if state.seen.insert(url.to_string()) {
+                            depths.push((url, depth));
+                        }
+                    }
+                }
+                ["done", url, path, mime] => {
+                    state.saved.insert(
+                        url.to_string(),
+                        Saved {
+                            path: path.to_string(),
+                            mime: mime.to_string(),
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+        for (url, depth) in depths {
+            if !state.saved.contains_key(url.as_str()) {
+                state.queue.push_back(Queued { url, depth });
+            }
+        }
+        state.enqueue(canonicalize(start), 0);
+        Ok(state)
+    }
+
+    /// Queue a URL not seen before, noting it in the log
+    fn enqueue(&mut self, url: Url, depth: u32) {
+        if !self.seen.insert(url.to_string()) {
+            return;
+        }
+        self.append(&format!("queue\t{}\t{}", depth, url));
+        self.queue.push_back(Queued { url, depth });
+    }
+
+    fn append(&mut self, line: &str) {
+        // A lost line only means the file is fetched again on resume
+        let _ = writeln!(self.log, "{}", line);
+    }
+
+    /// Fetch and save a URL, queueing the links of a gemtext page; returns the path it was
+    /// saved to, relative to the destination
+    async fn fetch(&mut self, queued: &Queued) -> Result<String, String> {
+        let url = queued.url.clone();
+        crate::api::storage::blocklist::check_url(&url)?;
+        crate::api::functions::robots::check_url(&url).await?;
+        let handler = registry::handler_for(url.scheme())
+            .ok_or_else(|| format!("Unsupported URL scheme {}", url.scheme()))?;
+
+        let fetch_url = url.clone();
+        let (mime, data) = handshakes::scope(true, async move {
+            let body = handler.open_body(&fetch_url).await?;
+            let mut data = Vec::new();
+            body.reader
+                .take(MAX_FILE_BYTES + 1)
+                .read_to_end(&mut data)
+                .await
+                .map_err(|e| format!("Failed to read response: {}", e))?;
+            if data.len() as u64 > MAX_FILE_BYTES {
+                return Err(format!("File is larger than {} bytes", MAX_FILE_BYTES));
+            }
+            crate::api::functions::data_usage::record(
+                fetch_url.as_str().len() as u64 + 2,
+                data.len() as u64,
+            );
+            Ok::<_, String>((body.mime, data))
+        })
+        .await?;
+
+        let path = local_path(&url, &mime);
+        let target = self.dest.join(&path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let partial = PathBuf::from(format!("{}.part", target.display()));
+        fs::write(&partial, &data)
+            .and_then(|_| fs::rename(&partial, &target))
+            .map_err(|e| {
+                let _ = fs::remove_file(&partial);
+                format!("Failed to save {}: {}", target.display(), e)
+            })?;
+        let mime = mime
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        self.append(&format!("done\t{}\t{}\t{}", url, path, mime));
+
+        if mime == "text/gemini" {
+            for link in page_links(&url, &String::from_utf8_lossy(&data)) {
+                let is_page = !is_resource(&link);
+                if link.host_str() != url.host_str() || link.port() != url.port() {
+                    continue;
+                }
+                if is_page && (queued.depth >= self.max_depth || !within(&self.root, &link)) {
+                    continue;
+                }
+                self.enqueue(link, queued.depth + 1);
+            }
+        }
+        self.saved.insert(
+            url.to_string(),
+            Saved {
+                path: path.clone(),
+                mime,
+            },
+        );
+        Ok(path)
+    }
+
+    /// Point the links of every saved gemtext page at the saved copies of their targets
+    fn rewrite_links(&self) {
+        for (url, saved) in &self.saved {
+            if saved.mime != "text/gemini" {
+                continue;
+            }
+            let Ok(base) = Url::parse(url) else {
+                continue;
+            };
+            let file = self.dest.join(&saved.path);
+            let Ok(text) = fs::read_to_string(&file) else {
+                continue;
+            };
+            let rewritten = self.rewrite_page(&base, &saved.path, &text);
+            if rewritten != text {
+                // A page left unrewritten still reads, with its links to the capsule
+                let _ = fs::write(&file, rewritten);
+            }
+        }
+    }
+
+    fn rewrite_page(&self, base: &Url, path: &str, text: &str) -> String {
+        let mut preformatted = false;
+        let mut out = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            if content.starts_with("```") {
+                preformatted = !preformatted;
+            }
+            let target = (!preformatted)
+                .then(|| content.strip_prefix("=>"))
+                .flatten()
+                .and_then(|rest| {
+                    let rest = rest.trim_start();
+                    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                    let (link, label) = rest.split_at(end);
+                    let target = base.join(link).ok()?;
+                    let Some(saved) = self.saved.get(canonicalize(&target).as_str()) else {
+                        // A relative link to a file not saved would lead nowhere in the copy
+                        let relative = Url::parse(link).is_err();
+                        return relative.then(|| format!("=> {}{}", target, label));
+                    };
+                    let mut relative = relative_path(path, &saved.path);
+                    if let Some(fragment) = target.fragment() {
+                        relative = format!("{}#{}", relative, fragment);
+                    }
+                    Some(format!("=> {}{}", relative, label))
+                });
+            match target {
+                Some(rewritten) => {
+                    out.push_str(&rewritten);
+                    out.push_str(&line[content.len()..]);
+                }
+                None => out.push_str(line),
+            }
+        }
+        out
+    }
+}
+
+/// The links of a gemtext page as canonical URLs, without those that carry a query
+fn page_links(base: &Url, body: &str) -> Vec<Url> {
+    parse_gemtext(body)
+        .into_iter()
+        .filter_map(|line| match line {
+            GemtextLine::Link { url, .. } => base.join(&url).ok(),
+            _ => None,
+        })
+        .filter(|url| url.scheme() == base.scheme() && url.query().is_none())
+        .map(|url| canonicalize(&url))
+        .collect()
+}
+
+/// Whether a link names a file other than a page, e.g. an image, by its extension
+fn is_resource(url: &Url) -> bool {
+    url.path()
+        .rsplit_once('.')
+        .filter(|(_, extension)| !extension.contains('/'))
+        .and_then(|(_, extension)| mime_for_extension(extension))
+        .is_some_and(|mime| !mime.starts_with("text/"))
+}
+
+/// Where a URL is saved under the destination: its host (and port), then its path, with an
+/// extension for its MIME type added to names without one and "index" standing for a
+/// directory
+fn local_path(url: &Url, mime: &str) -> String {
+    // The colons of an IPv6 host cannot appear in file names on every platform
+    let host = sanitize_filename(url.host_str().unwrap_or_default());
+    let mut segments = vec![match url.port() {
+        Some(port) => format!("{}_{}", host, port),
+        None => host,
+    }];
+    let path = url.path().trim_start_matches('/');
+    let mut names: Vec<String> = path
+        .split('/')
+        .map(|segment| percent_decode_str(segment).decode_utf8_lossy().to_string())
+        .collect();
+    let last = names.pop().unwrap_or_default();
+    segments.extend(
+        names
+            .iter()
+            .filter(|name| !name.is_empty())
+            .map(|name| sanitize_filename(name)),
+    );
+    let mime = mime.split(';').next().unwrap_or_default().trim();
+    let mut name = if last.is_empty() {
+        "index".to_string()
+    } else {
+        sanitize_filename(&last)
+    };
+    if !name.contains('.') {
+        if let Some(extension) = extension_for_mime(mime) {
+            name = format!("{}.{}", name, extension);
+        }
+    }
+    segments.push(name);
+    segments.join("/")
+}
+
+/// A link from the file at `from` to the file at `to`, both relative to the same directory
+fn relative_path(from: &str, to: &str) -> String {
+    let from: Vec<&str> = from.split('/').collect();
+    let to: Vec<&str> = to.split('/').collect();
+    let from_dirs = &from[..from.len() - 1];
+    let common = from_dirs
+        .iter()
+        .zip(&to[..to.len() - 1])
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts: Vec<String> = vec!["..".to_string(); from_dirs.len() - common];
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|part| utf8_percent_encode(part, PATH_ENCODE_SET).to_string()),
+    );
+    parts.join("/")
+}
@@ -0,0 +1,70 @@
+pub mod anchors;
+pub mod ansi;
+pub mod archive;
+pub mod batch;
+pub mod bidi;
+pub mod bookmark_export;
+pub mod bookmark_import;
+pub mod cache_refresh;
+pub mod cancellation;
+pub mod canonical_url;
+pub mod checksums;
+pub mod clock_skew;
+pub mod config;
+pub mod content_router;
+pub mod crawler;
+pub mod data_usage;
+pub mod digest;
+pub mod documents;
+pub mod downloads;
+pub mod error_pages;
+pub mod explore;
+pub mod favicons;
+pub mod feed_discovery;
+pub mod feeds;
+pub mod file_type;
+pub mod gempub;
+pub mod gemtext_export;
+pub mod highlighting;
+pub mod homograph;
+pub mod host_migration;
+pub mod html_export;
+pub mod identities;
+pub mod identity_transfer;
+pub mod inline_images;
+pub mod input_answers;
+pub mod known_hosts_transfer;
+pub mod legacy_charset;
+pub mod links;
+pub mod logging;
+pub mod markdown_export;
+pub mod menu_pages;
+pub mod metasearch;
+pub mod mirror;
+pub mod navigate_internal;
+pub mod navigation_events;
+pub mod offline;
+pub mod page_diff;
+pub mod page_summary;
+pub mod peek;
+pub mod pending_fetch;
+pub mod plan_watches;
+pub mod prefetch;
+pub mod read_later;
+pub mod reading_stats;
+pub mod reflow;
+pub mod request_trace;
+pub mod robots;
+pub mod scheduler;
+pub mod search;
+pub mod shutdown;
+pub mod site_map;
+pub mod start_page;
+pub mod streaming;
+pub mod suggestions;
+pub mod text_width;
+pub mod tls_failures;
+pub mod tofu;
+pub mod top_sites;
+pub mod trust_store;
+pub mod watches;
@@ -0,0 +1,771 @@
+use std::net::Ipv6Addr;
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use crate::api::functions::cancellation;
+use crate::api::functions::canonical_url::{canonicalize, to_ascii_host};
+use crate::api::functions::config;
+use crate::api::functions::content_router::with_charset;
+use crate::api::functions::error_pages;
+use crate::api::functions::favicons;
+use crate::api::functions::homograph::display_host;
+use crate::api::functions::host_migration;
+use crate::api::functions::input_answers;
+use crate::api::functions::logging::{log_for, LogLevel};
+use crate::api::functions::navigation_events::NavigationTracker;
+use crate::api::functions::offline::{self, OFFLINE_ERROR_PREFIX};
+use crate::api::functions::request_trace::{trace, TraceStage};
+use crate::api::functions::robots;
+use crate::api::functions::search;
+use crate::api::models::certificate_error::CertificateError;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_result::{NavigationResult, RequestTimings};
+use crate::api::models::server_failure::ServerFailure;
+use crate::api::protocols::{gemini, gopher, registry};
+use crate::api::storage::gemini_proxies::ProxyProtocol;
+use crate::api::storage::host_settings::{self, HostSettings, RedirectPolicy};
+use crate::api::storage::identity_scopes::{self, IdentityScope};
+use crate::api::storage::{cache, gemini_proxies, history, host_stats, scheme_toggles, watches};
+use crate::api::transport::handshakes;
+use crate::api::transport::timeouts::{self, Timeouts};
+
+/// Most redirects one navigation follows, as the Gemini specification recommends
+const MAX_REDIRECTS: usize = 5;
+
+/// Navigate to a URL and return the content with its final URL, protocol, status, MIME type,
+/// charset, duration, and server certificate
+pub async fn navigate_internal(url: String) -> Result<NavigationResult, NavigateError> {
+    fetch_internal(url).await.map(NavigationResult::from)
+}
+
+/// `navigate_internal` with per-request options such as connect and read timeouts and a
+/// deadline for the whole request
+pub async fn navigate_with_options(
+    url: String,
+    options: &FetchOptions,
+) -> Result<NavigationResult, NavigateError> {
+    fetch_with_options(url, options)
+        .await
+        .map(NavigationResult::from)
+}
+
+/// `navigate_internal` under a request id from `reserve_request_id`, so the navigation can be
+/// stopped with `cancel_request` while the caller waits
+pub async fn navigate_cancellable(
+    url: String,
+    request_id: u64,
+) -> Result<NavigationResult, NavigateError> {
+    if !cancellation::is_reserved(request_id) {
+        return Err(NavigateError::NotFound(format!(
+            "Unknown request {}",
+            request_id
+        )));
+    }
+    let tracker = NavigationTracker::start_with_id(&url, request_id);
+    fetch_tracked(url, &FetchOptions::default(), tracker)
+        .await
+        .map(NavigationResult::from)
+}
+
+/// Navigate to a Gemini, Gopher, or Finger URL and return the content with its metadata
+pub async fn fetch_internal(url: String) -> Result<FetchResponse, NavigateError> {
+    fetch_with_options(url, &FetchOptions::default()).await
+}
+
+/// Navigate with per-request options, recording the visit in history and caching the response
+/// unless the request is private; progress is published on the navigation event bus
+pub async fn fetch_with_options(
+    url: String,
+    options: &FetchOptions,
+) -> Result<FetchResponse, NavigateError> {
+    let tracker = NavigationTracker::start(&url);
+    fetch_tracked(url, options, tracker).await
+}
+
+/// `fetch_with_options` for a navigation whose tracker the caller already started
+pub(crate) async fn fetch_tracked(
+    url: String,
+    options: &FetchOptions,
+    tracker: NavigationTracker,
+) -> Result<FetchResponse, NavigateError> {
+    let input_url = parse_input_url(&url);
+    let input_host = input_url
+        .as_ref()
+        .and_then(|url| url.host_str().map(str::to_string));
+    // Overrides of the host fill in what the navigation left unset; a failed lookup keeps the
+    // defaults rather than fail the navigation
+    let settings = input_host
+        .as_deref()
+        .and_then(|host| host_settings::get_host_settings(host).ok().flatten());
+    // An identity activated for a prefix of the URL comes before the one set for its host
+    let scope = input_url
+        .as_ref()
+        .filter(|_| !options.private)
+        .and_then(|url| {
+            identity_scopes::scope_for(canonicalize(url).as_str())
+                .ok()
+                .flatten()
+        });
+    let options = &with_host_settings(options, settings.as_ref(), scope);
+    let tracker = tracker
+        .with_max_body_bytes(options.max_body_bytes)
+        .with_private(options.private);
+    let started = Instant::now();
+    let timeouts = Timeouts::from_secs(
+        options.connect_timeout_secs,
+        options.read_timeout_secs,
+        options.deadline_secs,
+    );
+    let deadline = options
+        .deadline_secs
+        .and_then(|secs| tracker.arm_deadline(Duration::from_secs(secs as u64)));
+    // Boxed, as the protocol handlers' futures would otherwise make every caller's future large
+    let dispatched = Box::pin(dispatch_following(url, options, &tracker));
+    let dispatched = handshakes::scope(options.background, dispatched);
+    let result = tracker.scope(timeouts::scope(timeouts, dispatched)).await;
+    drop(deadline);
+    // A cancelled read may end like a closed connection, so a cancelled request fails whatever
+    // its handler returned
+    let result = match result {
+        _ if tracker.deadline_exceeded() => Err(NavigateError::ReadTimeout(format!(
+            "Request timed out: it took longer than its deadline of {} seconds",
+            options.deadline_secs.unwrap_or_default()
+        ))),
+        _ if tracker.is_cancelled() => Err(cancellation::cancelled_error()),
+        // An unreachable network or host is better answered with the newest cached copy
+        Err(e) if offline::is_unreachable(&e) && options.identity_id.is_none() => {
+            match input_url
+                .as_ref()
+                .filter(|url| offline::needs_network(url))
+                .and_then(|url| offline::cached_copy(url).ok())
+            {
+                Some(response) => Ok(response),
+                None => Err(e),
+            }
+        }
+        result => result,
+    };
+    let mut response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            // The request id lets a bug report be matched with its trace
+            let e = e.map_message(|e| format!("{} (request {})", e, tracker.request_id));
+            tracker.failed(&e.to_string(), 0);
+            log_for(
+                Some(tracker.request_id),
+                LogLevel::Warn,
+                "navigation",
+                || e.to_string(),
+            );
+            // Only failures talking to the host count against it: not refusals made locally,
+            // nor failures of a proxy or gateway, which also fetches schemes routed through it
+            let proxied = input_url.as_ref().is_some_and(|url| {
+                gemini_proxies::proxy_for(url.scheme()).is_ok_and(|proxy| proxy.is_some())
+            });
+            if let Some(host) = input_host.filter(|_| is_host_failure(&e) && !proxied) {
+                record_stats(options, &host, 0, 0, started, true);
+            }
+            return Err(e);
+        }
+    };
+    if let Some(charset) = settings
+        .as_ref()
+        .and_then(|settings| settings.charset.as_deref())
+    {
+        response.mime = with_charset(&response.mime, charset);
+    }
+    response.request_id = tracker.request_id;
+    response.clock_skew_warning = tracker.clock_skew().is_some();
+    response.certificate = tracker.certificate();
+    if let Some(host) = Url::parse(&response.url)
+        .ok()
+        .and_then(|url| url.host_str().map(display_host))
+    {
+        response.display_host = host.host;
+        response.spoofing_warning = host.spoofing_warning;
+    }
+    if response.cached_at.is_none() {
+        let received = response.body.len() + response.meta.as_ref().map_or(0, |m| m.len() + 5);
+        let sent = response.url.len() + 2;
+        response.timings = RequestTimings {
+            bytes_sent: sent as u64,
+            bytes_received: received as u64,
+            ..tracker.timings()
+        };
+        if let Some(host) = Url::parse(&response.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+        {
+            record_stats(options, &host, sent as u64, received as u64, started, false);
+        }
+    }
+    trace(TraceStage::Parser, || {
+        format!(
+            "{} as {:?}, title {:?}, {} feeds",
+            response.mime,
+            response.renderer,
+            response.title,
+            response.feeds.len()
+        )
+    });
+    if let (Some(status), Some(meta)) = (response.status, &response.meta) {
+        tracker.header(status, meta);
+        response.certificate_error =
+            CertificateError::from_status(status, meta, options.identity_id.clone());
+        response.server_failure = ServerFailure::from_status(status, meta);
+    }
+    let bytes = response.body.len() as u64;
+    tracker.progress(bytes);
+    tracker.finished(response.status, bytes);
+    response.duration_ms = tracker.elapsed_ms();
+    let status = response
+        .status
+        .map_or("-".to_string(), |status| status.to_string());
+    log_for(
+        Some(tracker.request_id),
+        LogLevel::Info,
+        "navigation",
+        || {
+            format!(
+                "{} {} ({} bytes in {} ms)",
+                status, response.url, bytes, response.duration_ms
+            )
+        },
+    );
+    if !options.private && response.cached_at.is_none() {
+        // Noticing moved hosts is best-effort and must not fail the navigation
+        let _ = host_migration::observe(&response);
+    }
+    if !options.private && !options.background {
+        input_answers::observe(input_url.as_ref(), &response);
+    }
+
+    if let Some(failure) = response.server_failure.clone() {
+        error_pages::show_failure_page(&mut response, &failure);
+    }
+
+    if !response.is_success() {
+        return Ok(response);
+    }
+    let Ok(parsed) = Url::parse(&response.url) else {
+        return Ok(response);
+    };
+    let canonical = canonicalize(&parsed).to_string();
+    response.favicon = favicons::favicon_for_visit(&parsed, options);
+
+    let unchanged = options.revalidate
+        && response.cached_at.is_none()
+        && cache::cached_hash(&canonical).ok().flatten().as_ref() == Some(&response.body_hash);
+
+    if !options.private {
+        // Failed history or cache writes must not fail the navigation itself
+        if !options.background {
+            let _ = history::record_visit(&canonical, response.title.as_deref());
+            let _ = watches::mark_seen(&canonical, &response.body_hash);
+        }
+        // A truncated body would be served from the cache as if it were the whole page
+        if response.cached_at.is_none() && !response.truncated {
+            let policy = cache::policy_for(parsed.scheme())
+                .unwrap_or_else(|_| cache::CachePolicy::default_for(parsed.scheme()));
+            if policy.allows(&parsed) && options.identity_id.is_none() {
+                let host = parsed.host_str().unwrap_or_default();
+                let stored = cache::store(&canonical, host, &response, policy.ttl_secs as i64);
+                trace(TraceStage::Cache, || match stored {
+                    Ok(()) => format!("Stored for {} seconds", policy.ttl_secs),
+                    Err(e) => format!("Not stored: {}", e),
+                });
+            }
+        }
+    }
+
+    if unchanged {
+        response.unchanged = true;
+        response.body.clear();
+    }
+    Ok(response)
+}
+
+/// Dispatch a request and follow the redirects the redirecting hosts' policies allow, recording
+/// the redirecting URLs on the final response. A redirect that is not followed (to another
+/// scheme, back to a URL already visited, past MAX_REDIRECTS, or to another host while an
+/// identity is presented) is returned for the UI.
+async fn dispatch_following(
+    url: String,
+    options: &FetchOptions,
+    tracker: &NavigationTracker,
+) -> Result<FetchResponse, NavigateError> {
+    let fragment = Url::parse(&url)
+        .ok()
+        .and_then(|url| url.fragment().map(str::to_string));
+    let mut redirects: Vec<String> = Vec::new();
+    let mut response = dispatch(url, options).await?;
+    while let Some(mut target) = redirect_target(&response, &redirects, options) {
+        if !options.private {
+            // Noticing moved hosts is best-effort and must not fail the navigation
+            let _ = host_migration::observe(&response);
+        }
+        // The requested fragment carries over unless the redirect names its own
+        if target.fragment().is_none() {
+            target.set_fragment(fragment.as_deref());
+        }
+        redirects.push(response.url);
+        tracker.redirected(target.as_str());
+        response = dispatch(target.to_string(), options).await?;
+    }
+    response.redirects = redirects;
+    Ok(response)
+}
+
+/// Where a redirect response leads, if navigation should follow it on its own
+fn redirect_target(
+    response: &FetchResponse,
+    redirects: &[String],
+    options: &FetchOptions,
+) -> Option<Url> {
+    let status = response.status?;
+    if !(30..40).contains(&status) || redirects.len() >= MAX_REDIRECTS {
+        return None;
+    }
+    let from = Url::parse(&response.url).ok()?;
+    let target = from.join(response.meta.as_deref()?.trim()).ok()?;
+    let mut bare_target = target.clone();
+    bare_target.set_fragment(None);
+    if target.scheme() != from.scheme()
+        || bare_target == from
+        || redirects.iter().any(|url| *url == bare_target.as_str())
+    {
+        return None;
+    }
+    let same_host = target.host_str() == from.host_str() && target.port() == from.port();
+    // An identity is only ever presented to the host it was meant for
+    if options.identity_id.is_some() && !same_host {
+        return None;
+    }
+    let follow = match host_settings::redirect_policy(from.host_str().unwrap_or_default()) {
+        RedirectPolicy::Follow => true,
+        RedirectPolicy::AskCrossHost => same_host,
+        RedirectPolicy::Ask => false,
+    };
+    follow.then_some(target)
+}
+
+/// Options with the fields a navigation left unset taken from its host's overrides, then the
+/// timeouts from the configuration, and the
+/// identity from the activation covering the URL if there is one. A private navigation only
+/// presents an identity it names itself.
+fn with_host_settings(
+    options: &FetchOptions,
+    settings: Option<&HostSettings>,
+    scope: Option<IdentityScope>,
+) -> FetchOptions {
+    let mut options = options.clone();
+    if let Some(scope) = scope {
+        options.identity_id = options.identity_id.or(Some(scope.identity_id));
+    }
+    if let Some(settings) = settings {
+        if !options.private {
+            options.identity_id = options.identity_id.or(settings.identity_id.clone());
+        }
+        options.connect_timeout_secs = options
+            .connect_timeout_secs
+            .or(settings.connect_timeout_secs);
+        options.read_timeout_secs = options.read_timeout_secs.or(settings.read_timeout_secs);
+        options.max_body_bytes = options.max_body_bytes.or(settings.max_body_bytes);
+    }
+    config::current(|config| {
+        options.connect_timeout_secs = options
+            .connect_timeout_secs
+            .or(Some(config.connect_timeout_secs));
+        options.read_timeout_secs = options.read_timeout_secs.or(config.read_timeout_secs);
+    });
+    options
+}
+
+/// Add a network request to the host's usage statistics and the daily data usage unless the
+/// request is private
+fn record_stats(
+    options: &FetchOptions,
+    host: &str,
+    bytes_sent: u64,
+    bytes_received: u64,
+    started: Instant,
+    failed: bool,
+) {
+    if options.private {
+        return;
+    }
+    let latency_ms = started.elapsed().as_millis() as u64;
+    // Statistics are best-effort and must not fail the navigation
+    let _ = host_stats::record_request(host, bytes_sent, bytes_received, latency_ms, failed);
+    crate::api::functions::data_usage::record(bytes_sent, bytes_received);
+}
+
+/// Resolve the input to a URL and fetch it with the handler registered for its scheme
+async fn dispatch(url: String, options: &FetchOptions) -> Result<FetchResponse, NavigateError> {
+    let mut parsed_url = match parse_input_url(&url) {
+        Some(url) => url,
+        None if offline::is_offline_mode() => {
+            return Err(NavigateError::Offline(format!(
+                "{}: searching needs the network",
+                OFFLINE_ERROR_PREFIX
+            )))
+        }
+        None => {
+            // If the input is not a URL at all, search for it
+            return search_fallback(&url)
+                .await
+                .map_err(|e| e.context(format_args!("Failed to search for {}", url)));
+        }
+    };
+
+    // Fragments are resolved locally and must not be part of the request
+    let fragment = parsed_url.fragment().map(|f| f.to_string());
+    parsed_url.set_fragment(None);
+
+    // Refuse blocked hosts and disabled protocols before any connection is made
+    crate::api::storage::blocklist::check_url(&parsed_url)?;
+    scheme_toggles::check_url(&parsed_url)?;
+
+    // Pages seen with a client certificate may be personal, so they bypass the cache
+    if options.use_cache && options.identity_id.is_none() {
+        let cached = cache::lookup(canonicalize(&parsed_url).as_str())
+            .map_err(NavigateError::StorageError)?;
+        if let Some(response) = cached {
+            trace(TraceStage::Cache, || {
+                format!(
+                    "Served from the cache, fetched at {}",
+                    response.cached_at.unwrap_or(0)
+                )
+            });
+            return Ok(response.with_fragment(fragment));
+        }
+    }
+
+    // Offline, the newest cached copy stands in for the network
+    if offline::is_offline_mode() && offline::needs_network(&parsed_url) {
+        let response = offline::cached_copy(&parsed_url)?;
+        trace(TraceStage::Cache, || {
+            format!(
+                "Offline, serving the copy fetched at {}",
+                response.cached_at.unwrap_or(0)
+            )
+        });
+        return Ok(response.with_fragment(fragment));
+    }
+
+    // A scheme sent to a proxy or gateway is fetched by it, whether or not it has a handler
+    let proxy =
+        gemini_proxies::proxy_for(parsed_url.scheme()).map_err(NavigateError::StorageError)?;
+    if let Some(proxy) = proxy {
+        let kind = match proxy.protocol {
+            ProxyProtocol::Gemini => "Gemini proxy",
+            ProxyProtocol::Gopher => "Gopher gateway",
+        };
+        trace(TraceStage::Navigation, || {
+            format!(
+                "Fetching {} through the {} {}:{}",
+                parsed_url, kind, proxy.host, proxy.port
+            )
+        });
+        let fetched = match proxy.protocol {
+            ProxyProtocol::Gemini => gemini::fetch_through_proxy(&proxy, &parsed_url).await,
+            ProxyProtocol::Gopher => gopher::fetch_through_gateway(&proxy, &parsed_url).await,
+        };
+        return match fetched {
+            Ok(response) => Ok(response.with_fragment(fragment)),
+            Err(e) => Err(e.context(format_args!(
+                "Failed to fetch {} through {} {}:{}",
+                url, kind, proxy.host, proxy.port
+            ))),
+        };
+    }
+
+    // Fetches the user did not ask for keep out of what the host's robots.txt disallows
+    if options.background {
+        robots::check_url(&parsed_url).await?;
+    }
+
+    // Dispatch to the handler registered for the scheme
+    let handler = match registry::handler_for(parsed_url.scheme()) {
+        Some(handler) => handler,
+        None => {
+            return Err(NavigateError::UnsupportedScheme(format!(
+                "Unsupported URL scheme. Only {} are supported.",
+                registry::registered_schemes().join(", ")
+            )))
+        }
+    };
+
+    trace(TraceStage::Navigation, || {
+        format!(
+            "Fetching {} with the {} handler",
+            parsed_url,
+            handler.scheme()
+        )
+    });
+    let fetched = match options.identity_id.as_deref() {
+        Some(identity_id) => handler.fetch_with_identity(&parsed_url, identity_id).await,
+        None => handler.fetch(&parsed_url).await,
+    };
+    match fetched {
+        Ok(response) => Ok(response.with_fragment(fragment)),
+        Err(e)
+            if handler.scheme() == "gemini"
+                && options.probe_protocols
+                && is_bare_host(&url)
+                && is_connect_failure(&e) =>
+        {
+            trace(TraceStage::Navigation, || {
+                format!("Probing other protocols: {}", e)
+            });
+            match probe_protocols(&parsed_url).await {
+                Some(response) => Ok(response),
+                None if search::search_on_failure() => match search_fallback(&url).await {
+                    Ok(response) => Ok(response),
+                    Err(_) => Err(e.context(format_args!("Failed to fetch {}", parsed_url))),
+                },
+                None => Err(e.context(format_args!("Failed to fetch {}", parsed_url))),
+            }
+        }
+        // A changed or outdated certificate must reach the user rather than be hidden behind
+        // search results
+        Err(e)
+            if handler.scheme() == "gemini"
+                && search::search_on_failure()
+                && !matches!(
+                    e,
+                    NavigateError::FingerprintMismatch(_) | NavigateError::CertificateDate(_)
+                ) =>
+        {
+            // The user asked for failed Gemini requests to be searched for instead
+            trace(TraceStage::Navigation, || {
+                format!("Searching instead: {}", e)
+            });
+            match search_fallback(&url).await {
+                Ok(response) => Ok(response),
+                Err(_) => Err(e.context(format_args!("Failed to fetch {}", parsed_url))),
+            }
+        }
+        Err(e) => Err(e.context(format_args!("Failed to fetch {}", url))),
+    }
+}
+
+/// Schemes tried, in order, on a bare host that refuses Gemini connections
+const PROBE_SCHEMES: &[&str] = &["gopher", "finger"];
+
+/// Whether the input is just a host name (optionally with a port), typed without a scheme or path
+fn is_bare_host(input: &str) -> bool {
+    let input = input.trim().trim_end_matches('/');
+    !input.is_empty() && !input.contains("://") && !input.contains(['/', '?', '#', ' '])
+}
+
+/// Whether a navigation failed talking to the host itself, counting against it in its statistics
+fn is_host_failure(error: &NavigateError) -> bool {
+    matches!(
+        error,
+        NavigateError::DnsFailure(_)
+            | NavigateError::ConnectTimeout(_)
+            | NavigateError::ConnectionRefused(_)
+            | NavigateError::ConnectionFailed(_)
+            | NavigateError::ConnectionLost(_)
+            | NavigateError::ReadTimeout(_)
+            | NavigateError::GeminiTimeout(_)
+            | NavigateError::TlsError(_)
+            | NavigateError::CertificateDate(_)
+            | NavigateError::CertificateRejected(_)
+            | NavigateError::BadResponse(_)
+    )
+}
+
+/// Whether the host could not be reached on the Gemini port at all, as opposed to failing later
+fn is_connect_failure(error: &NavigateError) -> bool {
+    matches!(
+        error,
+        NavigateError::ConnectionRefused(_)
+            | NavigateError::ConnectTimeout(_)
+            | NavigateError::ConnectionFailed(_)
+    )
+}
+
+/// Fetch the root of a Gemini URL's host over the other plain-text protocols, returning the
+/// first answer; each probe uses that protocol's default port
+async fn probe_protocols(gemini_url: &Url) -> Option<FetchResponse> {
+    let host = gemini_url.host_str()?;
+    for &scheme in PROBE_SCHEMES {
+        let Some(handler) = registry::handler_for(scheme) else {
+            continue;
+        };
+        let Ok(url) = Url::parse(&format!("{}://{}/", scheme, host)) else {
+            continue;
+        };
+        if crate::api::storage::blocklist::check_url(&url).is_err()
+            || scheme_toggles::check_url(&url).is_err()
+        {
+            continue;
+        }
+        if let Ok(mut response) = handler.fetch(&url).await {
+            response.probed_scheme = Some(scheme.to_string());
+            return Some(response);
+        }
+    }
+    None
+}
+
+/// Parse user input as a URL, guessing the scheme of input typed without one; input containing
+/// whitespace is a search query rather than an address and yields None. An internationalized
+/// host is converted to punycode, so it is sent to DNS and servers in ASCII.
+pub fn parse_input_url(input: &str) -> Option<Url> {
+    let mut url = parse_address(input)?;
+    to_ascii_host(&mut url);
+    Some(url)
+}
+
+fn parse_address(input: &str) -> Option<Url> {
+    let input = input.trim();
+    // Inline content may contain anything, including "://" and spaces
+    if input
+        .get(..5)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+    {
+        return Url::parse(input).ok();
+    }
+    if input.contains("://") {
+        return Url::parse(input).ok();
+    }
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+    // An IPv6 address typed alone needs the brackets that set it apart from a port in a URL
+    if let Ok(ip) = input.parse::<Ipv6Addr>() {
+        return parse_address(&format!("[{}]", ip));
+    }
+    // "host:port" also parses as a URL whose scheme is the host
+    if let Ok(url) = Url::parse(input) {
+        if host_and_port(input).1.is_none() {
+            return Some(url);
+        }
+    }
+    let scheme = guess_scheme(input);
+    if scheme == "gopher" {
+        return Url::parse(&format!("gopher://{}", with_gopher_item_type(input))).ok();
+    }
+    Url::parse(&format!("{}://{}", scheme, input)).ok()
+}
+
+/// A Gopher address typed with a bare selector, such as `sdf.org/users/alice/`, with the item
+/// type a gopher:// URL needs before it: text for a .txt file, otherwise a menu
+fn with_gopher_item_type(input: &str) -> String {
+    let Some(slash) = input.find('/') else {
+        return input.to_string();
+    };
+    let (authority, path) = input.split_at(slash);
+    if path == "/" || has_gopher_item_type(path) {
+        return input.to_string();
+    }
+    let item_type = if path.to_ascii_lowercase().ends_with(".txt") {
+        '0'
+    } else {
+        '1'
+    };
+    format!("{}/{}{}", authority, item_type, path)
+}
+
+/// Pick the scheme for a scheme-less address, unless guessing is turned off and the configured
+/// default scheme applies to all: finger for `user@host`; by port, gopher for 70, finger for 79,
+/// nntp for 119, text for 1961, and gemini for 1965; gopher for a path that starts with an item
+/// type, such as `/1/`, and gemini for a path to a `.gmi` file; otherwise the scheme the host was
+/// last visited with; then gopher for paths that only Gopher holes tend to have, such as SDF's
+/// `/users/`, and for a `gopher.` host, and gemini for a `gemini.` host; falling back to the
+/// configured default scheme
+fn guess_scheme(input: &str) -> String {
+    let (default_scheme, guess) =
+        config::current(|config| (config.default_scheme.clone(), config.guess_schemes));
+    if !guess {
+        return default_scheme;
+    }
+    let (host, port) = host_and_port(input);
+    let path = &input[input.find('/').unwrap_or(input.len())..];
+    if host.contains('@') {
+        return "finger".to_string();
+    }
+    match port {
+        Some(70) => return "gopher".to_string(),
+        Some(79) => return "finger".to_string(),
+        Some(119) => return "nntp".to_string(),
+        Some(1961) => return "text".to_string(),
+        Some(1965) => return "gemini".to_string(),
+        Some(_) => return default_scheme,
+        None => {}
+    }
+    if has_gopher_item_type(path) {
+        return "gopher".to_string();
+    }
+    if GEMTEXT_EXTENSIONS
+        .iter()
+        .any(|extension| path.to_ascii_lowercase().ends_with(extension))
+    {
+        return "gemini".to_string();
+    }
+    let visited = history::last_scheme_for_host(host).ok().flatten();
+    if let Some(scheme) = visited.filter(|scheme| registry::handler_for(scheme).is_some()) {
+        return scheme;
+    }
+    let host = host.to_ascii_lowercase();
+    if host.starts_with("gopher.") || GOPHER_PATH_HINTS.iter().any(|hint| path.contains(hint)) {
+        return "gopher".to_string();
+    }
+    if host.starts_with("gemini.") {
+        return "gemini".to_string();
+    }
+    default_scheme
+}
+
+/// Path endings of gemtext files, which only Gemini serves
+const GEMTEXT_EXTENSIONS: &[&str] = &[".gmi", ".gemini"];
+
+/// Path parts common in Gopher holes and rare in Gemini capsules, which use `/~user/`
+const GOPHER_PATH_HINTS: &[&str] = &["/users/", "/phlog", "gophermap"];
+
+/// Gopher item types that start the path of a gopher:// URL, as in `/1/users/`
+const GOPHER_ITEM_TYPES: &[char] = &[
+    '0', '1', '2', '4', '5', '6', '7', '9', 'g', 'h', 'I', 'p', 's', 'd', ';',
+];
+
+/// Whether a path starts with a Gopher item type followed by a selector, e.g. `/0/about.txt`
+fn has_gopher_item_type(path: &str) -> bool {
+    let mut chars = path.chars();
+    chars.next() == Some('/')
+        && chars
+            .next()
+            .is_some_and(|item_type| GOPHER_ITEM_TYPES.contains(&item_type))
+        && chars.next() == Some('/')
+}
+
+/// Split the authority at the start of an address into its host and numeric port, if any
+fn host_and_port(input: &str) -> (&str, Option<u16>) {
+    let authority = input.split(['/', '?', '#']).next().unwrap_or(input);
+    match authority.rsplit_once(':') {
+        Some((host, port)) if !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) => {
+            (host, port.parse().ok())
+        }
+        _ => (authority, None),
+    }
+}
+
+/// Run the input as a query against the configured search provider
+async fn search_fallback(query: &str) -> Result<FetchResponse, NavigateError> {
+    let search_url = Url::parse(&search::search_url(query))
+        .map_err(|e| NavigateError::InvalidUrl(format!("Invalid search URL: {}", e)))?;
+    crate::api::storage::blocklist::check_url(&search_url)?;
+    scheme_toggles::check_url(&search_url)?;
+    let handler = registry::handler_for(search_url.scheme()).ok_or_else(|| {
+        NavigateError::UnsupportedScheme(format!("Unsupported URL scheme {}", search_url.scheme()))
+    })?;
+    trace(TraceStage::Navigation, || {
+        format!("Searching with {}", search_url)
+    });
+    handler.fetch(&search_url).await
+}
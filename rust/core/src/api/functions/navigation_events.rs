@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::sync::broadcast;
+
+use crate::api::functions::cancellation::{self, CancelToken, DeadlineGuard};
+use crate::api::functions::request_trace::{self, TraceStage};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_event::{NavigationEvent, NavigationEventKind};
+use crate::api::models::navigation_result::{CertificateSummary, RequestTimings};
+use crate::api::transport::limits::BODY_LIMIT_ERROR_PREFIX;
+
+/// Events kept for a slow subscriber before the oldest are dropped
+const BUS_CAPACITY: usize = 1024;
+
+/// Bytes between BodyProgress events, which would otherwise be published for every read
+pub(crate) const PROGRESS_STEP_BYTES: u64 = 16 * 1024;
+
+static BUS: LazyLock<broadcast::Sender<NavigationEvent>> =
+    LazyLock::new(|| broadcast::channel(BUS_CAPACITY).0);
+
+/// A subscriber's end of the bus, locked while it waits for an event, and the one request it
+/// follows, if it follows only one
+type Subscription = (
+    Arc<tokio::sync::Mutex<broadcast::Receiver<NavigationEvent>>>,
+    Option<u64>,
+);
+
+static SUBSCRIPTIONS: LazyLock<Mutex<HashMap<u64, Subscription>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_SUBSCRIPTION: AtomicU64 = AtomicU64::new(1);
+
+static NEXT_REQUEST: AtomicU64 = AtomicU64::new(1);
+
+tokio::task_local! {
+    /// The navigation whose protocol handler is running on this task
+    static CURRENT: NavigationTracker;
+}
+
+/// Start receiving navigation events, returning a subscription for `next_event`
+pub fn subscribe() -> u64 {
+    subscribe_to(None)
+}
+
+/// Start receiving the events of one request only, e.g. of a request id reserved for
+/// `navigate_cancellable` before the navigation starts, for showing its progress. The
+/// subscription ends by itself after the request's NavigationFinished or NavigationFailed.
+pub fn subscribe_request(request_id: u64) -> u64 {
+    subscribe_to(Some(request_id))
+}
+
+fn subscribe_to(request_id: Option<u64>) -> u64 {
+    let id = NEXT_SUBSCRIPTION.fetch_add(1, Ordering::Relaxed);
+    let receiver = Arc::new(tokio::sync::Mutex::new(BUS.subscribe()));
+    SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .insert(id, (receiver, request_id));
+    id
+}
+
+/// A receiver of every event published from now on, for consumers inside the crate
+pub(crate) fn receiver() -> broadcast::Receiver<NavigationEvent> {
+    BUS.subscribe()
+}
+
+/// Stop a subscription, returning whether it existed
+pub fn unsubscribe(subscription: u64) -> bool {
+    SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .remove(&subscription)
+        .is_some()
+}
+
+/// Wait for the next event of a subscription; events a slow subscriber missed are skipped
+pub async fn next_event(subscription: u64) -> Result<NavigationEvent, String> {
+    let (receiver, request_id) = SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .get(&subscription)
+        .cloned()
+        .ok_or_else(|| format!("Unknown subscription {}", subscription))?;
+    let mut receiver = receiver.lock().await;
+    loop {
+        match receiver.recv().await {
+            Ok(event) if request_id.is_none() => return Ok(event),
+            Ok(event) if Some(event.request_id) == request_id => {
+                if matches!(
+                    event.kind,
+                    NavigationEventKind::NavigationFinished | NavigationEventKind::NavigationFailed
+                ) {
+                    SUBSCRIPTIONS.lock().unwrap().remove(&subscription);
+                }
+                return Ok(event);
+            }
+            Ok(_) => continue,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => {
+                unreachable!("event bus sender lives in a static")
+            }
+        }
+    }
+}
+
+/// Report the response header of the navigation running on this task as soon as it is read
+pub(crate) fn report_header(status: u8, meta: &str) {
+    let _ = CURRENT.try_with(|tracker| tracker.header(status, meta));
+}
+
+/// Request id and start time of the navigation running on this task, if any
+pub(crate) fn current_request() -> Option<(u64, Instant)> {
+    CURRENT
+        .try_with(|tracker| (tracker.request_id, tracker.started))
+        .ok()
+}
+
+/// Whether the navigation running on this task is private
+pub(crate) fn is_private() -> bool {
+    CURRENT.try_with(|tracker| tracker.private).unwrap_or(false)
+}
+
+/// Allocate a fresh request id
+pub(crate) fn next_request_id() -> u64 {
+    NEXT_REQUEST.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Cancellation token of the navigation running on this task, if any
+pub(crate) fn current_cancel_token() -> Option<Arc<CancelToken>> {
+    CURRENT.try_with(|tracker| tracker.cancel.clone()).ok()
+}
+
+/// Note that the navigation running on this task accepted a certificate only by allowing for a
+/// wrong device clock
+pub(crate) fn report_clock_skew(skew_secs: i64) {
+    let _ = CURRENT.try_with(|tracker| *tracker.clock_skew.lock().unwrap() = Some(skew_secs));
+}
+
+/// Note the server certificate the navigation running on this task was made over
+pub(crate) fn report_certificate(certificate: CertificateSummary) {
+    let _ = CURRENT.try_with(|tracker| *tracker.certificate.lock().unwrap() = Some(certificate));
+}
+
+/// Note the TLS version and cipher suite of the connection the certificate of the navigation
+/// running on this task was presented over
+pub(crate) fn report_tls_parameters(version: String, cipher_suite: String) {
+    let _ = CURRENT.try_with(|tracker| {
+        if let Some(certificate) = tracker.certificate.lock().unwrap().as_mut() {
+            certificate.tls_version = Some(version);
+            certificate.cipher_suite = Some(cipher_suite);
+        }
+    });
+}
+
+/// Publish that the navigation running on this task entered a phase of opening its connection
+pub(crate) fn report_phase(kind: NavigationEventKind, message: Option<String>) {
+    let _ = CURRENT.try_with(|tracker| tracker.emit(kind, |event| event.message = message));
+}
+
+/// Note that the navigation running on this task is opening a new connection, whose timings
+/// replace those of any earlier one
+pub(crate) fn report_dialing() {
+    let _ = CURRENT.try_with(|tracker| {
+        *tracker.timings.lock().unwrap() = ConnectionTimings::default();
+    });
+}
+
+/// Note how long resolving the host of the connection being opened took
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn report_resolved(elapsed: Duration) {
+    let _ = CURRENT.try_with(|tracker| tracker.timings.lock().unwrap().dns = Some(elapsed));
+}
+
+/// Note how long opening the connection took, after any resolving; the first report of a
+/// connection counts, so a dialer can report the connection itself without the resolving
+pub(crate) fn report_connected(elapsed: Duration) {
+    let _ = CURRENT.try_with(|tracker| {
+        let mut timings = tracker.timings.lock().unwrap();
+        timings.connect.get_or_insert(elapsed);
+        timings.ready = Some(Instant::now());
+    });
+}
+
+/// Note how long the TLS handshake of the connection took
+pub(crate) fn report_handshake(elapsed: Duration) {
+    let _ = CURRENT.try_with(|tracker| {
+        let mut timings = tracker.timings.lock().unwrap();
+        timings.tls = Some(elapsed);
+        timings.ready = Some(Instant::now());
+    });
+}
+
+/// Read a response to the end, publishing body progress of the navigation running on this task
+/// as it arrives; `on_read` sees everything read so far after each read
+pub(crate) async fn read_to_end_tracked(
+    reader: &mut (impl AsyncRead + Unpin),
+    on_read: impl FnMut(&[u8]),
+) -> std::io::Result<Vec<u8>> {
+    let mut response = Vec::new();
+    read_tracked(reader, &mut response, on_read, None).await?;
+    Ok(response)
+}
+
+/// Read a response like `read_to_end_tracked` from a server that may never close the
+/// connection: once data has arrived, a read timing out or `max_duration` passing ends the
+/// response, which is then flagged as possibly truncated
+pub(crate) async fn read_until_quiet(
+    reader: &mut (impl AsyncRead + Unpin),
+    max_duration: Duration,
+) -> std::io::Result<(Vec<u8>, bool)> {
+    let mut response = Vec::new();
+    let deadline = Instant::now() + max_duration;
+    match read_tracked(reader, &mut response, |_| {}, Some(deadline)).await {
+        Ok(complete) => Ok((response, !complete)),
+        Err(e) if !response.is_empty() && e.kind() == std::io::ErrorKind::TimedOut => {
+            Ok((response, true))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Read into `response` until the end of the stream, returning true, or until `deadline`
+/// passes, returning false
+async fn read_tracked(
+    reader: &mut (impl AsyncRead + Unpin),
+    response: &mut Vec<u8>,
+    mut on_read: impl FnMut(&[u8]),
+    deadline: Option<Instant>,
+) -> std::io::Result<bool> {
+    let mut buffer = [0u8; 8 * 1024];
+    let mut reported = 0;
+    loop {
+        let read = match deadline {
+            Some(deadline) => {
+                match tokio::time::timeout_at(deadline.into(), reader.read(&mut buffer)).await {
+                    Ok(read) => read,
+                    Err(_) => return Ok(false),
+                }
+            }
+            None => reader.read(&mut buffer).await,
+        };
+        let read = match read {
+            Ok(0) => return Ok(true),
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if response.is_empty() {
+            let _ = CURRENT.try_with(|tracker| {
+                let mut timings = tracker.timings.lock().unwrap();
+                timings.first_byte.get_or_insert_with(Instant::now);
+            });
+        }
+        response.extend_from_slice(&buffer[..read]);
+        if let Some(max) = CURRENT
+            .try_with(|tracker| tracker.max_body_bytes)
+            .ok()
+            .flatten()
+        {
+            if response.len() as u64 > max {
+                return Err(std::io::Error::other(NavigateError::TooLarge(format!(
+                    "{}: more than the limit of {} bytes",
+                    BODY_LIMIT_ERROR_PREFIX, max
+                ))));
+            }
+        }
+        on_read(response);
+        if (response.len() - reported) as u64 >= PROGRESS_STEP_BYTES {
+            reported = response.len();
+            let _ = CURRENT.try_with(|tracker| tracker.progress(reported as u64));
+        }
+    }
+}
+
+/// When the phases of the connection a navigation was last made over ended
+#[derive(Debug, Default)]
+struct ConnectionTimings {
+    dns: Option<Duration>,
+    connect: Option<Duration>,
+    tls: Option<Duration>,
+    /// When the connection was ready to send the request, after any TLS handshake
+    ready: Option<Instant>,
+    first_byte: Option<Instant>,
+}
+
+/// Publishes the events of one request under a fresh request id
+#[derive(Clone)]
+pub(crate) struct NavigationTracker {
+    pub(crate) request_id: u64,
+    url: String,
+    started: Instant,
+    /// Whether HeaderReceived was published, possibly while the body was still arriving
+    header_sent: Arc<AtomicBool>,
+    /// Seconds a server certificate was outside its validity period, if it was
+    clock_skew: Arc<Mutex<Option<i64>>>,
+    /// The last server certificate accepted during the navigation
+    certificate: Arc<Mutex<Option<CertificateSummary>>>,
+    cancel: Arc<CancelToken>,
+    /// Bytes of body the navigation may read before it fails
+    max_body_bytes: Option<u64>,
+    /// Whether the navigation is private, leaving nothing behind in the profile
+    private: bool,
+    timings: Arc<Mutex<ConnectionTimings>>,
+}
+
+impl NavigationTracker {
+    /// Allocate a request id and announce the navigation
+    pub(crate) fn start(url: &str) -> Self {
+        Self::start_with_id(url, next_request_id())
+    }
+
+    /// Announce a navigation under a request id reserved by the caller
+    pub(crate) fn start_with_id(url: &str, request_id: u64) -> Self {
+        let tracker = NavigationTracker {
+            request_id,
+            url: url.to_string(),
+            started: Instant::now(),
+            header_sent: Arc::new(AtomicBool::new(false)),
+            clock_skew: Arc::new(Mutex::new(None)),
+            certificate: Arc::new(Mutex::new(None)),
+            cancel: cancellation::token(request_id),
+            max_body_bytes: None,
+            private: false,
+            timings: Arc::new(Mutex::new(ConnectionTimings::default())),
+        };
+        tracker.emit(NavigationEventKind::NavigationStarted, |_| {});
+        tracker.trace(TraceStage::Navigation, format!("Started {}", url));
+        tracker
+    }
+
+    /// Fail reads of the navigation's body once they exceed `max_body_bytes`
+    pub(crate) fn with_max_body_bytes(mut self, max_body_bytes: Option<u64>) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    /// Mark the navigation private, so its protocol handlers write nothing to the profile
+    pub(crate) fn with_private(mut self, private: bool) -> Self {
+        self.private = private;
+        self
+    }
+
+    /// Run a future with this tracker as the current navigation of its protocol handlers
+    pub(crate) async fn scope<F: Future>(&self, future: F) -> F::Output {
+        CURRENT.scope(self.clone(), future).await
+    }
+
+    /// Publish the header, once per request however many times it is reported
+    pub(crate) fn header(&self, status: u8, meta: &str) {
+        if self.header_sent.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        self.trace(TraceStage::Response, format!("Header {} {}", status, meta));
+        self.emit(NavigationEventKind::HeaderReceived, |event| {
+            event.status = Some(status);
+            event.meta = Some(meta.to_string());
+        });
+    }
+
+    /// Publish a redirect followed automatically, letting the next hop publish its own header
+    pub(crate) fn redirected(&self, target: &str) {
+        self.header_sent.store(false, Ordering::Relaxed);
+        self.trace(TraceStage::Navigation, format!("Redirected to {}", target));
+        self.emit(NavigationEventKind::RedirectFollowed, |event| {
+            event.url = target.to_string()
+        });
+    }
+
+    /// Whether a certificate was accepted only by allowing for a wrong device clock
+    pub(crate) fn clock_skew(&self) -> Option<i64> {
+        *self.clock_skew.lock().unwrap()
+    }
+
+    /// The last server certificate accepted during the navigation
+    pub(crate) fn certificate(&self) -> Option<CertificateSummary> {
+        self.certificate.lock().unwrap().clone()
+    }
+
+    /// How long the phases of the connection the navigation was last made over took, up to now;
+    /// the byte counts are left for the caller
+    pub(crate) fn timings(&self) -> RequestTimings {
+        let timings = self.timings.lock().unwrap();
+        let millis = |duration: Duration| duration.as_millis() as u64;
+        RequestTimings {
+            dns_ms: timings.dns.map(millis),
+            connect_ms: timings.connect.map(millis),
+            tls_ms: timings.tls.map(millis),
+            first_byte_ms: timings
+                .ready
+                .zip(timings.first_byte)
+                .map(|(ready, first_byte)| millis(first_byte.saturating_duration_since(ready))),
+            transfer_ms: timings
+                .first_byte
+                .map(|first_byte| millis(first_byte.elapsed())),
+            bytes_sent: 0,
+            bytes_received: 0,
+        }
+    }
+
+    /// Whether the navigation was cancelled by `cancel_request`
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Whether the navigation was cancelled because its deadline passed
+    pub(crate) fn deadline_exceeded(&self) -> bool {
+        self.cancel.deadline_exceeded()
+    }
+
+    /// Cancel the navigation if it is still running after `after`, until the guard is dropped
+    pub(crate) fn arm_deadline(&self, after: Duration) -> Option<DeadlineGuard> {
+        cancellation::arm_deadline(self.cancel.clone(), after)
+    }
+
+    /// Time since the navigation started
+    pub(crate) fn elapsed_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    pub(crate) fn progress(&self, bytes: u64) {
+        self.emit(NavigationEventKind::BodyProgress, |event| {
+            event.bytes = bytes
+        });
+    }
+
+    pub(crate) fn finished(&self, status: Option<u8>, bytes: u64) {
+        cancellation::release(self.request_id);
+        self.trace(
+            TraceStage::Navigation,
+            format!("Finished with {} bytes", bytes),
+        );
+        self.emit(NavigationEventKind::NavigationFinished, |event| {
+            event.status = status;
+            event.bytes = bytes;
+        });
+    }
+
+    pub(crate) fn failed(&self, message: &str, bytes: u64) {
+        cancellation::release(self.request_id);
+        self.trace(TraceStage::Navigation, format!("Failed: {}", message));
+        self.emit(NavigationEventKind::NavigationFailed, |event| {
+            event.message = Some(message.to_string());
+            event.bytes = bytes;
+        });
+    }
+
+    fn trace(&self, stage: TraceStage, message: String) {
+        request_trace::record(self.request_id, stage, message, self.elapsed_ms());
+    }
+
+    fn emit(&self, kind: NavigationEventKind, fill: impl FnOnce(&mut NavigationEvent)) {
+        // Nobody listening is not an error; sending only fails when there are no subscribers
+        if BUS.receiver_count() == 0 {
+            return;
+        }
+        let mut event = NavigationEvent::new(self.request_id, kind, self.url.clone());
+        fill(&mut event);
+        let _ = BUS.send(event);
+    }
+}
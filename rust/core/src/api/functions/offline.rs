@@ -0,0 +1,66 @@
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::storage::{cache, settings};
+
+/// Settings key of the offline browsing switch
+const OFFLINE_MODE_KEY: &str = "offline_mode";
+
+/// Prefix of the error returned in offline mode for a page that was never cached
+pub const OFFLINE_ERROR_PREFIX: &str = "Offline";
+
+/// Schemes whose content is already on the device, which stay available offline
+const LOCAL_SCHEMES: &[&str] = &["about", "data", "file"];
+
+/// Turn offline browsing on or off; while on, navigation serves cached copies only
+pub fn set_offline_mode(enabled: bool) -> Result<(), String> {
+    if enabled {
+        settings::set(OFFLINE_MODE_KEY, "1")
+    } else {
+        settings::remove(OFFLINE_MODE_KEY)
+    }
+}
+
+/// Whether offline browsing is on; a failed lookup counts as online
+pub fn is_offline_mode() -> bool {
+    settings::get(OFFLINE_MODE_KEY)
+        .ok()
+        .flatten()
+        .is_some_and(|value| value == "1")
+}
+
+/// Whether a URL has to be fetched over the network
+pub(crate) fn needs_network(url: &Url) -> bool {
+    !LOCAL_SCHEMES.contains(&url.scheme())
+}
+
+/// The newest cached copy of a URL, fresh or stale, served in offline mode
+pub(crate) fn cached_copy(url: &Url) -> Result<FetchResponse, NavigateError> {
+    cache::lookup_stale(canonicalize(url).as_str())
+        .map_err(NavigateError::StorageError)?
+        .ok_or_else(|| {
+            NavigateError::Offline(format!(
+                "{}: {} has no cached copy",
+                OFFLINE_ERROR_PREFIX,
+                canonicalize(url)
+            ))
+        })
+}
+
+/// Whether a failure means the network or the host could not be reached at all, so a cached
+/// copy is better than an error
+pub(crate) fn is_unreachable(error: &NavigateError) -> bool {
+    matches!(
+        error,
+        NavigateError::DnsFailure(_)
+            | NavigateError::ConnectTimeout(_)
+            | NavigateError::ReadTimeout(_)
+            | NavigateError::GeminiTimeout(_)
+            | NavigateError::ConnectionRefused(_)
+            | NavigateError::ConnectionFailed(_)
+            | NavigateError::ProxyUnreachable(_)
+            | NavigateError::GatewayError(_)
+    )
+}
@@ -0,0 +1,156 @@
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::gemtext_export::gophermap_to_gemtext;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::{FetchResponse, GOPHER_MENU_MIME};
+use crate::api::storage::cache;
+
+/// Most line pairs compared to match up the changed part of two versions; past it the whole
+/// changed part counts as removed and added, rather than taking seconds on huge pages
+const MAX_COMPARED_PAIRS: usize = 4_000_000;
+
+/// A line that was added to or removed from a page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// 1-based line number, in the fresh version for added lines and in the cached version for
+    /// removed ones
+    pub line: u32,
+    pub text: String,
+}
+
+/// How a page changed between its cached copy and a fresh fetch
+#[derive(Debug, Clone)]
+pub struct PageDiff {
+    pub url: String,
+    /// When the cached copy compared against was fetched, in seconds since the Unix epoch
+    pub cached_at: i64,
+    /// Lines of the fresh version not in the cached one, in page order
+    pub added: Vec<DiffLine>,
+    /// Lines of the cached version not in the fresh one, in page order
+    pub removed: Vec<DiffLine>,
+}
+
+/// Refetch a page and compare it line by line with its cached copy, stale or not, to show what
+/// changed on it, e.g. for a watched page or a feed entry. Gopher menus are compared as gemtext.
+/// The fresh version replaces the cached copy, so the next call shows changes since this one;
+/// the fetch is not added to history.
+pub async fn diff_with_cache(url: &str) -> Result<PageDiff, String> {
+    let parsed = Url::parse(url.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+    let canonical = canonicalize(&parsed).to_string();
+    let cached = cache::lookup_stale(&canonical)?
+        .ok_or_else(|| format!("{} has no cached copy to compare with", canonical))?;
+    let options = FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    };
+    let fresh = fetch_with_options(canonical.clone(), &options).await?;
+    if fresh.cached_at.is_some() {
+        return Err(format!(
+            "Cannot compare {}: the host is unreachable",
+            canonical
+        ));
+    }
+    if !fresh.is_success() {
+        return Err(format!(
+            "Cannot compare {}: the server answered {} {}",
+            canonical,
+            fresh.status.unwrap_or_default(),
+            fresh.meta.as_deref().unwrap_or_default()
+        ));
+    }
+    let old_text = diff_text(&cached);
+    let new_text = diff_text(&fresh);
+    let (removed, added) = diff_lines(
+        &old_text.lines().collect::<Vec<_>>(),
+        &new_text.lines().collect::<Vec<_>>(),
+    );
+    Ok(PageDiff {
+        url: canonical,
+        cached_at: cached.cached_at.unwrap_or_default(),
+        added,
+        removed,
+    })
+}
+
+/// The text of a response as it is compared, with Gopher menus rendered as gemtext
+fn diff_text(response: &FetchResponse) -> String {
+    if response.mime == GOPHER_MENU_MIME {
+        gophermap_to_gemtext(&response.body)
+    } else {
+        response.body.clone()
+    }
+}
+
+/// The lines removed from `old` and added in `new`: everything but a longest common
+/// subsequence of the two, found after setting aside the lines both start and end with
+fn diff_lines(old: &[&str], new: &[&str]) -> (Vec<DiffLine>, Vec<DiffLine>) {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let (kept_old, kept_new) = if old_middle.len() * new_middle.len() <= MAX_COMPARED_PAIRS {
+        common_lines(old_middle, new_middle)
+    } else {
+        (vec![false; old_middle.len()], vec![false; new_middle.len()])
+    };
+    let changed = |middle: &[&str], kept: &[bool]| {
+        middle
+            .iter()
+            .zip(kept)
+            .enumerate()
+            .filter(|(_, (_, kept))| !**kept)
+            .map(|(index, (text, _))| DiffLine {
+                line: (prefix + index + 1) as u32,
+                text: text.to_string(),
+            })
+            .collect()
+    };
+    (
+        changed(old_middle, &kept_old),
+        changed(new_middle, &kept_new),
+    )
+}
+
+/// Which lines of each side belong to a longest common subsequence of the two
+fn common_lines(old: &[&str], new: &[&str]) -> (Vec<bool>, Vec<bool>) {
+    let width = new.len() + 1;
+    // lengths[i * width + j]: longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let mut kept_old = vec![false; old.len()];
+    let mut kept_new = vec![false; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            kept_old[i] = true;
+            kept_new[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    (kept_old, kept_new)
+}
@@ -0,0 +1,126 @@
+use url::Url;
+
+use crate::api::functions::content_router::is_gemtext;
+use crate::api::functions::favicons::refresh_favicon;
+use crate::api::functions::gemtext_export::gophermap_to_gemtext;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::GOPHER_MENU_MIME;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+use crate::api::storage::favicons;
+
+/// Longest description kept, in characters; longer first paragraphs are cut at a word
+const MAX_DESCRIPTION_CHARS: usize = 200;
+
+/// What a page is about in a few words, for a share sheet or a bookmark preview
+#[derive(Debug, Clone)]
+pub struct PageSummary {
+    /// The URL the page was found at, after redirects
+    pub url: String,
+    /// The first heading, or None for a page without one
+    pub title: Option<String>,
+    /// The first paragraph of text, cut to about 200 characters
+    pub description: Option<String>,
+    /// The capsule's /favicon.txt emoji, when it has one
+    pub favicon: Option<String>,
+    /// When the summarized copy was fetched, if it came from the cache
+    pub cached_at: Option<i64>,
+}
+
+/// Summarize a page from its fresh cached copy, or fetch it in the background when it has
+/// none. The fetch is not added to history; Gopher menus are read as gemtext. The favicon of a
+/// capsule never asked for one is fetched first, so the summary has it.
+pub async fn page_summary(url: &str) -> Result<PageSummary, String> {
+    let options = FetchOptions {
+        use_cache: true,
+        background: true,
+        ..FetchOptions::default()
+    };
+    let host = Url::parse(url.trim())
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string));
+    if host
+        .as_deref()
+        .is_some_and(|host| favicons::cached_favicon(host).ok().flatten().is_none())
+    {
+        // A capsule without a reachable favicon is summarized without one
+        let _ = refresh_favicon(url.trim(), &options).await;
+    }
+    let response = fetch_with_options(url.trim().to_string(), &options).await?;
+    if !response.is_success() {
+        return Err(format!(
+            "Cannot summarize {}: the server answered {} {}",
+            response.url,
+            response.status.unwrap_or_default(),
+            response.meta.as_deref().unwrap_or_default()
+        ));
+    }
+    let document = if response.mime == GOPHER_MENU_MIME {
+        parse_gemtext(&gophermap_to_gemtext(&response.body))
+    } else if is_gemtext(&response.mime) {
+        parse_gemtext(&response.body)
+    } else if response.mime.starts_with("text/") {
+        response
+            .body
+            .lines()
+            .map(|line| GemtextLine::Text(line.to_string()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let favicon = match response.favicon {
+        Some(favicon) => Some(favicon),
+        None => Url::parse(&response.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .and_then(|host| favicons::get_favicon(&host).ok().flatten()),
+    };
+    Ok(PageSummary {
+        title: response.title.or_else(|| first_heading(&document)),
+        description: first_paragraph(&document),
+        favicon,
+        cached_at: response.cached_at,
+        url: response.url,
+    })
+}
+
+fn first_heading(document: &[GemtextLine]) -> Option<String> {
+    document.iter().find_map(|line| match line {
+        GemtextLine::Heading { text, .. } if !text.is_empty() => Some(text.clone()),
+        _ => None,
+    })
+}
+
+/// The text lines of the first paragraph, joined with spaces: a paragraph ends at a blank line
+/// or anything that is not text, such as a link, a heading, or a preformatted block
+fn first_paragraph(document: &[GemtextLine]) -> Option<String> {
+    let paragraph: Vec<&str> = document
+        .iter()
+        .skip_while(|line| !is_text(line))
+        .map_while(|line| match line {
+            GemtextLine::Text(text) if !text.trim().is_empty() => Some(text.trim()),
+            _ => None,
+        })
+        .collect();
+    if paragraph.is_empty() {
+        return None;
+    }
+    Some(shorten(&paragraph.join(" ")))
+}
+
+fn is_text(line: &GemtextLine) -> bool {
+    matches!(line, GemtextLine::Text(text) if !text.trim().is_empty())
+}
+
+/// Cut a description at the last space within the limit and mark the cut with an ellipsis
+fn shorten(text: &str) -> String {
+    if text.chars().count() <= MAX_DESCRIPTION_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(MAX_DESCRIPTION_CHARS).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space > 0 => &cut[..space],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end_matches([',', ';', ':', '.', ' ']))
+}
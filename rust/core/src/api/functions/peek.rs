@@ -0,0 +1,121 @@
+use tokio::io::{AsyncRead, AsyncReadExt};
+use url::Url;
+
+use crate::api::functions::content_router::{route_url, Renderer};
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::functions::navigation_events::NavigationTracker;
+use crate::api::protocols::{gemini, registry};
+
+/// Bytes of body a peek reads, for protocols without a header, to tell the type of the content
+/// and, for small bodies, their size
+const PREFIX_BYTES: u64 = 4 * 1024;
+
+/// What a URL answers, learned without downloading its body
+#[derive(Debug, Clone)]
+pub struct PeekResult {
+    pub url: String,
+    /// Status code of the header, for protocols that have one (Gemini)
+    pub status: Option<u8>,
+    pub meta: Option<String>,
+    /// MIME type of the body, from the Gemini header or guessed by the protocol handler; None
+    /// when the answer has no body, e.g. a redirect or an error
+    pub mime: Option<String>,
+    /// How the body would be presented, e.g. Download for a file to save
+    pub renderer: Option<Renderer>,
+    /// Body size in bytes, known when the whole body of a protocol without a header fit in the
+    /// first 4 KiB; never known for Gemini, whose header does not give it
+    pub size: Option<u64>,
+}
+
+/// Request a URL and close the connection after the header of a Gemini answer, or after the
+/// first 4 KiB of the body of protocols without a header, e.g. for a link preview or to decide
+/// between opening and saving. Nothing is added to history or the cache.
+pub async fn peek(url: &str) -> Result<PeekResult, String> {
+    let tracker = NavigationTracker::start(url);
+    let result = tracker.scope(peek_url(url)).await;
+    match &result {
+        Ok((peeked, read)) => {
+            tracker.finished(peeked.status, *read);
+            crate::api::functions::data_usage::record(url.len() as u64 + 2, *read);
+        }
+        Err(e) => tracker.failed(e, 0),
+    }
+    result.map(|(peeked, _)| peeked)
+}
+
+/// Peek at a URL, returning what was learned and how many body bytes were read
+async fn peek_url(url: &str) -> Result<(PeekResult, u64), String> {
+    let mut parsed_url = parse_input_url(url).ok_or("Invalid URL format")?;
+    parsed_url.set_fragment(None);
+    crate::api::storage::blocklist::check_url(&parsed_url)?;
+    crate::api::storage::scheme_toggles::check_url(&parsed_url)?;
+
+    // Gemini answers with a header, which tells all a peek needs; the connection closes as the
+    // unread body is dropped
+    if parsed_url.scheme() == "gemini" {
+        let (status, meta, _body) = gemini::open_response(&parsed_url).await?;
+        if !(20..30).contains(&status) {
+            let peeked = PeekResult {
+                url: parsed_url.to_string(),
+                status: Some(status),
+                meta: Some(meta),
+                mime: None,
+                renderer: None,
+                size: None,
+            };
+            return Ok((peeked, 0));
+        }
+        let mime = if meta.is_empty() {
+            "text/gemini".to_string()
+        } else {
+            meta.clone()
+        };
+        let peeked = with_body(&parsed_url, Some(status), Some(meta), mime, None);
+        return Ok((peeked, 0));
+    }
+
+    let handler = registry::handler_for(parsed_url.scheme()).ok_or_else(|| {
+        format!(
+            "Unsupported URL scheme. Only {} are supported.",
+            registry::registered_schemes().join(", ")
+        )
+    })?;
+    let body = handler.open_body(&parsed_url).await?;
+    let (prefix, size) = read_prefix(body.reader).await?;
+    let peeked = with_body(&parsed_url, None, None, body.mime, size);
+    Ok((peeked, prefix.len() as u64))
+}
+
+fn with_body(
+    url: &Url,
+    status: Option<u8>,
+    meta: Option<String>,
+    mime: String,
+    size: Option<u64>,
+) -> PeekResult {
+    PeekResult {
+        url: url.to_string(),
+        status,
+        meta,
+        renderer: Some(route_url(url.as_str(), &mime)),
+        mime: Some(mime),
+        size,
+    }
+}
+
+/// Read the first bytes of a body, returning them with the body's size if it ended within them
+async fn read_prefix(reader: impl AsyncRead + Unpin) -> Result<(Vec<u8>, Option<u64>), String> {
+    let mut prefix = Vec::new();
+    // One byte more than the prefix tells whether the body goes on
+    reader
+        .take(PREFIX_BYTES + 1)
+        .read_to_end(&mut prefix)
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    if prefix.len() as u64 > PREFIX_BYTES {
+        prefix.truncate(PREFIX_BYTES as usize);
+        return Ok((prefix, None));
+    }
+    let size = prefix.len() as u64;
+    Ok((prefix, Some(size)))
+}
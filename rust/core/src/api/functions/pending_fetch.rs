@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::api::functions::navigate_internal::fetch_tracked;
+use crate::api::functions::navigation_events::{self, NavigationTracker};
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_event::{NavigationEvent, NavigationEventKind};
+
+/// A navigation running in the background, so the header can be shown before the body is complete
+struct PendingFetch {
+    events: tokio::sync::Mutex<broadcast::Receiver<NavigationEvent>>,
+    /// Set once NavigationFinished or NavigationFailed was delivered
+    done: AtomicBool,
+    /// Taken by `finish`
+    task: Mutex<Option<JoinHandle<Result<FetchResponse, NavigateError>>>>,
+}
+
+static PENDING: LazyLock<Mutex<HashMap<u64, Arc<PendingFetch>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Start a navigation without waiting for it, returning its request id for `next_update` and
+/// `finish`; must be called on the Tokio runtime
+pub fn begin_fetch(url: String, options: FetchOptions) -> u64 {
+    // Subscribe before the navigation starts so none of its events are missed
+    let events = navigation_events::receiver();
+    let tracker = NavigationTracker::start(&url);
+    let request_id = tracker.request_id;
+    let task = tokio::spawn(async move { fetch_tracked(url, &options, tracker).await });
+    let pending = PendingFetch {
+        events: tokio::sync::Mutex::new(events),
+        done: AtomicBool::new(false),
+        task: Mutex::new(Some(task)),
+    };
+    PENDING
+        .lock()
+        .unwrap()
+        .insert(request_id, Arc::new(pending));
+    request_id
+}
+
+/// Wait for the next event of a pending navigation: HeaderReceived as soon as the header line is
+/// read, BodyProgress while the body arrives, then NavigationFinished or NavigationFailed, after
+/// which None is returned
+pub async fn next_update(request_id: u64) -> Result<Option<NavigationEvent>, String> {
+    let pending = pending(request_id)?;
+    if pending.done.load(Ordering::Relaxed) {
+        return Ok(None);
+    }
+    let mut events = pending.events.lock().await;
+    loop {
+        let event = match events.recv().await {
+            Ok(event) if event.request_id == request_id => event,
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => {
+                unreachable!("event bus sender lives in a static")
+            }
+        };
+        if matches!(
+            event.kind,
+            NavigationEventKind::NavigationFinished | NavigationEventKind::NavigationFailed
+        ) {
+            pending.done.store(true, Ordering::Relaxed);
+        }
+        return Ok(Some(event));
+    }
+}
+
+/// Wait for a pending navigation to complete and release it
+pub async fn finish(request_id: u64) -> Result<FetchResponse, String> {
+    let pending = PENDING
+        .lock()
+        .unwrap()
+        .remove(&request_id)
+        .ok_or_else(|| format!("Unknown pending fetch {}", request_id))?;
+    let task = pending.task.lock().unwrap().take();
+    match task {
+        Some(task) => Ok(task
+            .await
+            .map_err(|e| format!("Fetch task failed: {}", e))??),
+        None => Err(format!("Pending fetch {} was already finished", request_id)),
+    }
+}
+
+/// Abort every pending navigation and release it, returning how many were aborted
+pub(crate) fn abort_all() -> usize {
+    let pending: Vec<_> = PENDING.lock().unwrap().drain().collect();
+    for (_, fetch) in &pending {
+        if let Some(task) = fetch.task.lock().unwrap().take() {
+            task.abort();
+        }
+    }
+    pending.len()
+}
+
+fn pending(request_id: u64) -> Result<Arc<PendingFetch>, String> {
+    PENDING
+        .lock()
+        .unwrap()
+        .get(&request_id)
+        .cloned()
+        .ok_or_else(|| format!("Unknown pending fetch {}", request_id))
+}
@@ -0,0 +1,47 @@
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::now;
+use crate::api::storage::plan_watches::{self, WatchedPlan};
+
+/// Least time between two checks of one finger address, however often updates are asked for
+const MIN_CHECK_INTERVAL_SECS: i64 = 15 * 60;
+
+/// Finger the watched addresses not checked in the last 15 minutes, one at a time and within
+/// the per-host limits of background requests, keep each answer, and return every address whose
+/// .plan or .project changed since it was last read, newest update first. A host that fails
+/// does not stop the others and keeps the last answer.
+pub async fn check_plans() -> Result<Vec<WatchedPlan>, String> {
+    let options = FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    };
+    for watch in plan_watches::list_watched_plans()? {
+        if watch
+            .checked_at
+            .is_some_and(|checked_at| now() - checked_at < MIN_CHECK_INTERVAL_SECS)
+        {
+            continue;
+        }
+        match fetch_with_options(watch.url.clone(), &options).await {
+            Ok(response) if response.cached_at.is_some() => {
+                plan_watches::record_error(watch.id, "Host unreachable; served from the cache")?
+            }
+            Ok(response) if response.is_success() => {
+                plan_watches::record_plan(watch.id, &response.body, &response.body_hash)?
+            }
+            Ok(response) => plan_watches::record_error(
+                watch.id,
+                &format!(
+                    "Server answered {} {}",
+                    response.status.unwrap_or_default(),
+                    response.meta.unwrap_or_default()
+                ),
+            )?,
+            Err(e) => plan_watches::record_error(watch.id, &e.to_string())?,
+        }
+    }
+    Ok(plan_watches::list_watched_plans()?
+        .into_iter()
+        .filter(|watch| watch.updated_at.is_some())
+        .collect())
+}
@@ -0,0 +1,101 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::data_usage::prefetch_allowed;
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::cache;
+
+/// Prefetches running at once across all hosts
+const MAX_CONCURRENT_PREFETCHES: usize = 4;
+
+/// Prefetches running at once against a single host, so a long index does not hammer its capsule
+const MAX_PREFETCHES_PER_HOST: usize = 2;
+
+/// Links prefetched per call; the first links of a page are the likeliest to be followed
+const MAX_PREFETCH_URLS: usize = 32;
+
+/// Bumped by every `prefetch` and `cancel_prefetch` call so the queued fetches of the previous
+/// page are dropped
+static PREFETCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Fetch and cache the links of the current page in the background so following one is served
+/// from the cache, returning how many were fetched. Links already cached, not cacheable under
+/// their scheme's policy, or past the first 32 are skipped; nothing is fetched while
+/// `prefetch_allowed` is false. The fetches run as background requests: they wait for TLS
+/// handshake slots behind the user's navigations and add no visits to history. A later call
+/// supersedes this one, whose fetches not yet started are dropped.
+pub async fn prefetch(urls: Vec<String>) -> Result<u32, String> {
+    let generation = PREFETCH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    if !prefetch_allowed() {
+        return Ok(0);
+    }
+
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
+    for url in urls {
+        let Ok(url) = Url::parse(&url) else {
+            continue;
+        };
+        let canonical = canonicalize(&url);
+        if !seen.insert(canonical.to_string()) {
+            continue;
+        }
+        if !cache::policy_for(url.scheme())?.allows(&url)
+            || cache::lookup(canonical.as_str())?.is_some()
+        {
+            continue;
+        }
+        candidates.push(url);
+        if candidates.len() == MAX_PREFETCH_URLS {
+            break;
+        }
+    }
+
+    let options = Arc::new(FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    });
+    let overall = Arc::new(Semaphore::new(MAX_CONCURRENT_PREFETCHES));
+    let mut per_host: HashMap<String, Arc<Semaphore>> = HashMap::new();
+    let mut fetches = JoinSet::new();
+    for url in candidates {
+        let host = per_host
+            .entry(url.host_str().unwrap_or_default().to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(MAX_PREFETCHES_PER_HOST)))
+            .clone();
+        let overall = overall.clone();
+        let options = options.clone();
+        fetches.spawn(async move {
+            let Ok(_host_permit) = host.acquire_owned().await else {
+                return false;
+            };
+            let Ok(_permit) = overall.acquire_owned().await else {
+                return false;
+            };
+            if PREFETCH_GENERATION.load(Ordering::SeqCst) != generation {
+                return false;
+            }
+            fetch_with_options(url.to_string(), &options).await.is_ok()
+        });
+    }
+
+    let mut fetched = 0;
+    while let Some(result) = fetches.join_next().await {
+        if result.unwrap_or(false) {
+            fetched += 1;
+        }
+    }
+    Ok(fetched)
+}
+
+/// Drop the prefetches not yet started, e.g. when the user leaves the page they were for
+pub fn cancel_prefetch() {
+    PREFETCH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
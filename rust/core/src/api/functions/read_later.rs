@@ -0,0 +1,80 @@
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::navigate_internal::{fetch_with_options, parse_input_url};
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::now;
+use crate::api::storage::read_later::{self, ReadLaterItem};
+
+/// Save a page to read later and fetch a copy of it right away, so it can be read offline; a
+/// fresh cached copy is taken as it is. The fetch adds no visit to history. When it fails, e.g.
+/// while offline, the page is still queued with the error, and `cache_read_later` fetches it
+/// later.
+pub async fn save_for_later(url: &str, title: Option<&str>) -> Result<ReadLaterItem, String> {
+    let parsed = parse_input_url(url).ok_or("Invalid URL format")?;
+    let canonical = canonicalize(&parsed).to_string();
+    let title = title.map(str::trim).filter(|title| !title.is_empty());
+    let item = read_later::add(&canonical, title)?;
+    let options = FetchOptions {
+        private: true,
+        use_cache: true,
+        ..FetchOptions::default()
+    };
+    fetch_copy(item.id, &canonical, &options).await?;
+    read_later::get_read_later(item.id)?
+        .ok_or_else(|| format!("Unknown read-later item {}", item.id))
+}
+
+/// Fetch the queued pages that have no copy yet, e.g. once back on Wi-Fi, returning how many
+/// were saved. These are background requests, within the hosts' robots.txt and rate limits.
+pub async fn cache_read_later() -> Result<u32, String> {
+    let options = FetchOptions {
+        use_cache: true,
+        background: true,
+        ..FetchOptions::default()
+    };
+    let mut saved = 0;
+    for (id, url) in read_later::uncached()? {
+        if fetch_copy(id, &url, &options).await? {
+            saved += 1;
+        }
+    }
+    Ok(saved)
+}
+
+/// The saved copy of a queued page as a response served from the cache, with `cached_at` set to
+/// when it was fetched
+pub fn open_read_later(id: i64) -> Result<FetchResponse, String> {
+    if let Some(response) = read_later::load(id)? {
+        return Ok(response);
+    }
+    match read_later::get_read_later(id)? {
+        Some(item) => Err(format!("{} has no cached copy yet", item.url)),
+        None => Err(format!("Unknown read-later item {}", id)),
+    }
+}
+
+/// Fetch an item's page and keep it as its copy, or record why that failed; returns whether a
+/// copy was saved. Only database errors fail.
+async fn fetch_copy(id: i64, url: &str, options: &FetchOptions) -> Result<bool, String> {
+    match fetch_with_options(url.to_string(), options).await {
+        Ok(response) if response.is_success() => {
+            read_later::store_copy(id, &response, response.cached_at.unwrap_or_else(now))?;
+            Ok(true)
+        }
+        Ok(response) => {
+            read_later::record_error(
+                id,
+                &format!(
+                    "Server answered {} {}",
+                    response.status.unwrap_or_default(),
+                    response.meta.unwrap_or_default()
+                ),
+            )?;
+            Ok(false)
+        }
+        Err(e) => {
+            read_later::record_error(id, &e.to_string())?;
+            Ok(false)
+        }
+    }
+}
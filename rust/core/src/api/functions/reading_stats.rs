@@ -0,0 +1,48 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// Words a typical reader gets through in a minute of prose
+const WORDS_PER_MINUTE: u32 = 200;
+
+/// Size of a document as a reader sees it, for showing next to its title
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadingStats {
+    /// Words of text, headings, list items, quotes and link labels; preformatted blocks are
+    /// counted apart, since code and art are not read like prose
+    pub words: u32,
+    pub preformatted_words: u32,
+    /// Estimated minutes to read `words`, rounded up; 0 only for a document without words
+    pub reading_minutes: u32,
+    pub links: u32,
+    pub headings: u32,
+}
+
+/// Count the words, links and headings of a parsed document
+pub fn reading_stats(document: &[GemtextLine]) -> ReadingStats {
+    let mut stats = ReadingStats::default();
+    for line in document {
+        match line {
+            GemtextLine::Text(text) | GemtextLine::ListItem(text) | GemtextLine::Quote(text) => {
+                stats.words += count_words(text);
+            }
+            GemtextLine::Heading { text, .. } => {
+                stats.headings += 1;
+                stats.words += count_words(text);
+            }
+            GemtextLine::Link { label, .. } => {
+                stats.links += 1;
+                stats.words += label.as_deref().map_or(0, count_words);
+            }
+            GemtextLine::Preformatted(text) => stats.preformatted_words += count_words(text),
+            GemtextLine::PreformatToggle { .. } => {}
+        }
+    }
+    stats.reading_minutes = stats.words.div_ceil(WORDS_PER_MINUTE);
+    stats
+}
+
+/// Words by Unicode word boundaries, so text without spaces, e.g. Chinese, counts per character
+fn count_words(text: &str) -> u32 {
+    text.unicode_words().count() as u32
+}
@@ -0,0 +1,228 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::functions::text_width::display_width;
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// Columns reserved for the bullet or quote bar the UI draws before list and quote lines
+const MARKER_COLUMNS: usize = 2;
+
+/// One display line produced by reflowing a gemtext document
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReflowedLine {
+    /// Index of the document line this display line came from
+    pub source_index: u32,
+    pub line: GemtextLine,
+    /// True for the second and later display lines of a wrapped source line
+    pub continuation: bool,
+}
+
+/// Wrap text, quote, and list lines to at most `max_cols` columns, leaving everything else untouched
+pub fn reflow(document: &[GemtextLine], max_cols: usize) -> Vec<ReflowedLine> {
+    let max_cols = max_cols.max(MARKER_COLUMNS + 1);
+    let mut output = Vec::with_capacity(document.len());
+
+    for (index, line) in document.iter().enumerate() {
+        let source_index = index as u32;
+        let (text, width, rebuild): (&str, usize, fn(String) -> GemtextLine) = match line {
+            GemtextLine::Text(text) => (text, max_cols, GemtextLine::Text),
+            GemtextLine::Quote(text) => (text, max_cols - MARKER_COLUMNS, GemtextLine::Quote),
+            GemtextLine::ListItem(text) => (text, max_cols - MARKER_COLUMNS, GemtextLine::ListItem),
+            // Links, headings, and preformatted blocks keep their original layout
+            other => {
+                output.push(ReflowedLine {
+                    source_index,
+                    line: other.clone(),
+                    continuation: false,
+                });
+                continue;
+            }
+        };
+
+        for (n, wrapped) in wrap_text(text, width).into_iter().enumerate() {
+            output.push(ReflowedLine {
+                source_index,
+                line: rebuild(wrapped),
+                continuation: n > 0,
+            });
+        }
+    }
+
+    output
+}
+
+/// Greedy word wrap by display width, splitting words longer than a line
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let word_len = display_width(word);
+
+        if current_len > 0 && current_len + 1 + word_len <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_len += 1 + word_len;
+            continue;
+        }
+
+        if current_len > 0 {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        if word_len <= width {
+            current.push_str(word);
+            current_len = word_len;
+            continue;
+        }
+
+        // Break overlong words at grapheme boundaries so combining marks stay attached
+        for grapheme in word.graphemes(true) {
+            let grapheme_len = display_width(grapheme);
+            if current_len > 0 && current_len + grapheme_len > width {
+                lines.push(std::mem::take(&mut current));
+                current_len = 0;
+            }
+            current.push_str(grapheme);
+            current_len += grapheme_len;
+        }
+    }
+
+    // Blank lines are meaningful paragraph breaks in gemtext
+    if current_len > 0 || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Share of the widest line a line must fill to run on into the next one; shorter lines, such
+/// as the last line of a paragraph, a verse, or an address, end where they are
+const RUN_ON_FRACTION: f32 = 0.6;
+
+/// ASCII symbols pictures, boxes, and tables are drawn with
+const DRAWING_SYMBOLS: &str = "|/\\_-=+*#~^<>[]{}()@$%&`',.:;";
+
+/// Rewrap plain text laid out for a fixed-width terminal, such as a Gopher text file or finger
+/// output, to at most `max_cols` columns. Paragraphs (lines separated by blank lines) run
+/// together and are wrapped again, keeping their indents; list items start their own paragraph
+/// and wrap under their text. Lines that look drawn rather than written (ASCII art, tables,
+/// rules, aligned columns) are kept as they are, as is every line of a block made up mostly of
+/// them.
+pub fn reflow_plain_text(text: &str, max_cols: usize) -> String {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let source_width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let run_on_width = (source_width as f32 * RUN_ON_FRACTION) as usize;
+    let mut output = Vec::with_capacity(lines.len());
+
+    for block in lines.split(|line| line.is_empty()) {
+        let drawn = block.iter().filter(|line| looks_drawn(line)).count();
+        if drawn > 0 && drawn * 3 >= block.len() {
+            output.extend(block.iter().map(|line| line.to_string()));
+        } else {
+            let mut paragraph: Vec<&str> = Vec::new();
+            for line in block {
+                if looks_drawn(line) {
+                    flush_paragraph(&mut paragraph, max_cols, &mut output);
+                    output.push(line.to_string());
+                    continue;
+                }
+                if list_marker_width(line).is_some() {
+                    flush_paragraph(&mut paragraph, max_cols, &mut output);
+                }
+                paragraph.push(line);
+                if display_width(line) < run_on_width {
+                    flush_paragraph(&mut paragraph, max_cols, &mut output);
+                }
+            }
+            flush_paragraph(&mut paragraph, max_cols, &mut output);
+        }
+        output.push(String::new());
+    }
+
+    // Splitting on blank lines leaves one separator too many at the end
+    output.pop();
+    output.join("\n")
+}
+
+/// Wrap the lines of a paragraph as one. The first line keeps its indent and the others take
+/// that of the paragraph's second line, so first-line indents stay on the first line; a list
+/// item's later lines line up with the text after its marker.
+fn flush_paragraph(paragraph: &mut Vec<&str>, max_cols: usize, output: &mut Vec<String>) {
+    let Some(first) = paragraph.first() else {
+        return;
+    };
+    let indent = &first[..first.len() - first.trim_start().len()];
+    let marker_end = list_marker_width(first).map(|width| display_width(indent) + width);
+    let hanging = paragraph
+        .get(1)
+        .map(|second| display_width(&second[..second.len() - second.trim_start().len()]))
+        .unwrap_or(0)
+        .max(marker_end.unwrap_or(0));
+    let joined = paragraph
+        .iter()
+        .map(|line| line.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    paragraph.clear();
+
+    let width = max_cols
+        .saturating_sub(hanging.max(display_width(indent)))
+        .max(1);
+    for (n, wrapped) in wrap_text(&joined, width).into_iter().enumerate() {
+        if n == 0 {
+            output.push(format!("{}{}", indent, wrapped));
+        } else {
+            output.push(format!("{}{}", " ".repeat(hanging), wrapped));
+        }
+    }
+}
+
+/// Width of the bullet or number a list item starts with, including the space after it
+fn list_marker_width(line: &str) -> Option<usize> {
+    let item = line.trim_start();
+    let (marker, _) = item.split_once(' ')?;
+    let bullet = matches!(marker, "-" | "*" | "+" | "•");
+    let numbered = (2..=4).contains(&marker.len())
+        && marker.ends_with(['.', ')'])
+        && marker[..marker.len() - 1]
+            .chars()
+            .all(|c| c.is_ascii_digit());
+    (bullet || numbered).then(|| display_width(marker) + 1)
+}
+
+/// Whether a line is laid out by position rather than by words: runs of spaces or a tab
+/// inside it, a run of four or more of one symbol (rules and underlines), or mostly line
+/// drawing symbols and box-drawing characters
+fn looks_drawn(line: &str) -> bool {
+    let content = line.trim();
+    if content.contains("   ") || content.contains('\t') {
+        return true;
+    }
+
+    let mut run = 0;
+    let mut previous = None;
+    let mut symbols = 0;
+    let mut visible = 0;
+    for c in content.chars().filter(|c| !c.is_whitespace()) {
+        visible += 1;
+        if is_drawing_char(c) {
+            symbols += 1;
+        }
+        run = if previous == Some(c) { run + 1 } else { 1 };
+        previous = Some(c);
+        if run >= 4 && !c.is_alphanumeric() {
+            return true;
+        }
+    }
+    visible >= 3 && symbols * 5 >= visible * 2
+}
+
+fn is_drawing_char(c: char) -> bool {
+    DRAWING_SYMBOLS.contains(c) || ('\u{2500}'..='\u{259f}').contains(&c)
+}
@@ -0,0 +1,106 @@
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+
+use crate::api::functions::logging::{log_for, LogLevel};
+use crate::api::functions::navigation_events::current_request;
+
+/// Requests whose traces are kept; older ones are dropped first
+const MAX_TRACES: usize = 64;
+
+/// Events kept per request, so an endless stream cannot grow its trace without bound
+const MAX_EVENTS: usize = 256;
+
+/// Part of the pipeline an event comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceStage {
+    /// Resolving the input, checking blocklists and toggles, and choosing a handler
+    Navigation,
+    Cache,
+    /// Opening the connection, directly or through a proxy or gateway
+    Transport,
+    Tls,
+    /// The server's answer arriving
+    Response,
+    /// Analysis of the body: title, feeds, fragment
+    Parser,
+}
+
+impl TraceStage {
+    /// The log target of the stage's events
+    fn target(self) -> &'static str {
+        match self {
+            TraceStage::Navigation => "navigation",
+            TraceStage::Cache => "cache",
+            TraceStage::Transport => "transport",
+            TraceStage::Tls => "tls",
+            TraceStage::Response => "response",
+            TraceStage::Parser => "parser",
+        }
+    }
+}
+
+/// One step of a request, for bug reports
+#[derive(Debug, Clone)]
+pub struct TraceEvent {
+    pub stage: TraceStage,
+    pub message: String,
+    /// Milliseconds since the navigation started
+    pub elapsed_ms: u64,
+}
+
+/// The events recorded for one request id
+type Trace = (u64, Vec<TraceEvent>);
+
+/// Traces of the most recent requests, oldest first
+static TRACES: LazyLock<Mutex<VecDeque<Trace>>> = LazyLock::new(|| Mutex::new(VecDeque::new()));
+
+/// Record a step of the navigation running on this task; the message is only built when a
+/// navigation is running
+pub(crate) fn trace(stage: TraceStage, message: impl FnOnce() -> String) {
+    let Some((request_id, started)) = current_request() else {
+        return;
+    };
+    record(
+        request_id,
+        stage,
+        message(),
+        started.elapsed().as_millis() as u64,
+    );
+}
+
+/// Record a step of a request by its id, for code running outside the request's task; the
+/// step is also logged at debug level
+pub(crate) fn record(request_id: u64, stage: TraceStage, message: String, elapsed_ms: u64) {
+    log_for(Some(request_id), LogLevel::Debug, stage.target(), || {
+        message.clone()
+    });
+    let mut traces = TRACES.lock().unwrap();
+    let index = match traces.iter().position(|(id, _)| *id == request_id) {
+        Some(index) => index,
+        None => {
+            if traces.len() >= MAX_TRACES {
+                traces.pop_front();
+            }
+            traces.push_back((request_id, Vec::new()));
+            traces.len() - 1
+        }
+    };
+    let events = &mut traces[index].1;
+    if events.len() < MAX_EVENTS {
+        events.push(TraceEvent {
+            stage,
+            message,
+            elapsed_ms,
+        });
+    }
+}
+
+/// The recorded steps of a recent request in order, or None once it was dropped from memory
+pub fn get_trace(request_id: u64) -> Option<Vec<TraceEvent>> {
+    TRACES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(id, _)| *id == request_id)
+        .map(|(_, events)| events.clone())
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use crate::api::functions::request_trace::{trace, TraceStage};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::gemini;
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::handshakes;
+
+/// Prefix of the error returned when a host's robots.txt disallows an automated request
+pub const ROBOTS_ERROR_PREFIX: &str = "Disallowed by robots.txt";
+
+/// How long a fetched robots.txt is trusted before it is fetched again
+const ROBOTS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Hosts whose robots.txt is kept in memory; the oldest is dropped first
+const MAX_CACHED_HOSTS: usize = 256;
+
+/// The Allow and Disallow rules of a robots.txt that apply to this browser
+#[derive(Debug, Clone, Default)]
+struct Rules {
+    /// Path prefixes and whether they are allowed
+    rules: Vec<(String, bool)>,
+}
+
+impl Rules {
+    /// Parse the groups of a robots.txt for every user agent (`*`). The Gemini robots.txt
+    /// companion specification names virtual agents for archivers, indexers, researchers, and
+    /// web proxies; a browser fetching ahead of its user is none of them.
+    fn parse(text: &str) -> Self {
+        let mut rules = Vec::new();
+        let mut agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match field.trim().to_ascii_lowercase().as_str() {
+                "user-agent" => {
+                    // A user agent after rules starts the next group
+                    if in_rules {
+                        agents.clear();
+                        in_rules = false;
+                    }
+                    agents.push(value.to_string());
+                }
+                field @ ("allow" | "disallow") => {
+                    in_rules = true;
+                    // An empty Disallow allows everything and adds no rule
+                    if value.is_empty() || !agents.iter().any(|agent| agent == "*") {
+                        continue;
+                    }
+                    rules.push((value.to_string(), field == "allow"));
+                }
+                _ => {}
+            }
+        }
+        Rules { rules }
+    }
+
+    /// Whether a path may be fetched: the longest matching rule decides, Allow winning a tie,
+    /// and paths no rule matches are allowed
+    fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, allowed)| (prefix.len(), *allowed))
+            .is_none_or(|(_, allowed)| *allowed)
+    }
+}
+
+/// Rules of recently asked hosts by "host:port", with when they were fetched
+static ROBOTS: LazyLock<Mutex<HashMap<String, (Instant, Rules)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Fail if the host's robots.txt disallows fetching a URL without the user asking for it, as
+/// prefetches, feed refreshes, and bookmark refreshes do. Only Gemini has a robots.txt
+/// convention; a host without one, or that cannot be asked, allows everything.
+pub(crate) async fn check_url(url: &Url) -> Result<(), NavigateError> {
+    if url.scheme() != "gemini" || url.path() == "/robots.txt" {
+        return Ok(());
+    }
+    let Some(host) = url.host_str() else {
+        return Ok(());
+    };
+    let port = resolve_port(url, 1965);
+    let rules = rules_for(host, port).await;
+    let path = if url.path().is_empty() {
+        "/"
+    } else {
+        url.path()
+    };
+    if rules.allows(path) {
+        return Ok(());
+    }
+    Err(NavigateError::Disallowed(format!(
+        "{}: {}",
+        ROBOTS_ERROR_PREFIX, url
+    )))
+}
+
+/// The cached rules of a host, fetching its robots.txt when they are missing or too old
+async fn rules_for(host: &str, port: u16) -> Rules {
+    let key = format!("{}:{}", host.to_ascii_lowercase(), port);
+    if let Some((fetched, rules)) = ROBOTS.lock().unwrap().get(&key) {
+        if fetched.elapsed() < ROBOTS_TTL {
+            return rules.clone();
+        }
+    }
+    let rules = fetch_rules(host, port).await;
+    let mut robots = ROBOTS.lock().unwrap();
+    if robots.len() >= MAX_CACHED_HOSTS && !robots.contains_key(&key) {
+        let oldest = robots
+            .iter()
+            .min_by_key(|(_, (fetched, _))| *fetched)
+            .map(|(key, _)| key.clone());
+        if let Some(oldest) = oldest {
+            robots.remove(&oldest);
+        }
+    }
+    robots.insert(key, (Instant::now(), rules.clone()));
+    rules
+}
+
+/// Fetch and parse a host's robots.txt as a background request of its own, outside the
+/// navigation that needs it so its header is not taken for the navigation's
+async fn fetch_rules(host: &str, port: u16) -> Rules {
+    let url = format!("gemini://{}:{}/robots.txt", host, port);
+    trace(TraceStage::Navigation, || format!("Fetching {}", url));
+    let host = host.to_string();
+    // A task of its own starts without the navigation's tracker, time limits, and cancellation
+    let fetched = tokio::spawn(handshakes::scope(true, async move {
+        gemini::fetch_gemini(&host, port, &url).await
+    }))
+    .await;
+    match fetched {
+        Ok(Ok(response)) if (20..30).contains(&response.status) => Rules::parse(&response.body),
+        _ => Rules::default(),
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+
+use crate::api::functions::data_usage::network_type;
+use crate::api::functions::offline::is_offline_mode;
+use crate::api::functions::tofu::EXPIRY_WARNING_SECS;
+use crate::api::functions::{feeds, plan_watches, watches};
+use crate::api::storage::data_usage::NetworkType;
+use crate::api::storage::{known_hosts, now};
+
+/// Events kept for a slow subscriber before the oldest are dropped
+const BUS_CAPACITY: usize = 256;
+
+/// Shortest interval a task may be scheduled at
+const MIN_INTERVAL_SECS: u32 = 60;
+
+/// Background work the scheduler runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScheduledTask {
+    /// Refresh every feed subscription
+    FeedRefresh,
+    /// Check the watched pages for changes
+    PageWatches,
+    /// Finger the watched addresses for .plan updates
+    PlanWatches,
+    /// Find pinned certificates that expired or expire within 30 days
+    PinExpiry,
+}
+
+impl ScheduledTask {
+    const ALL: [ScheduledTask; 4] = [
+        ScheduledTask::FeedRefresh,
+        ScheduledTask::PageWatches,
+        ScheduledTask::PlanWatches,
+        ScheduledTask::PinExpiry,
+    ];
+
+    /// Whether the task connects to servers, and so waits while the browser is offline
+    fn uses_network(self) -> bool {
+        self != ScheduledTask::PinExpiry
+    }
+}
+
+/// When and under which conditions a task runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskSchedule {
+    pub task: ScheduledTask,
+    pub enabled: bool,
+    /// Seconds between two runs, at least 60
+    pub interval_secs: u32,
+    /// Up to this many seconds are added at random to each interval, so many devices, or many
+    /// tasks, do not all contact the same servers at once
+    pub jitter_secs: u32,
+    /// Run only on Wi-Fi or Ethernet
+    pub unmetered_only: bool,
+    /// Run only while the device is charging
+    pub charging_only: bool,
+}
+
+impl TaskSchedule {
+    fn default_for(task: ScheduledTask) -> Self {
+        let (interval_secs, jitter_secs) = match task {
+            ScheduledTask::FeedRefresh => (60 * 60, 5 * 60),
+            ScheduledTask::PageWatches | ScheduledTask::PlanWatches => (60 * 60, 10 * 60),
+            ScheduledTask::PinExpiry => (24 * 60 * 60, 0),
+        };
+        TaskSchedule {
+            task,
+            enabled: true,
+            interval_secs,
+            jitter_secs,
+            unmetered_only: false,
+            charging_only: false,
+        }
+    }
+}
+
+/// The device's power source, as last reported by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PowerState {
+    Charging,
+    Battery,
+    /// On battery and low on it: no background work runs
+    LowBattery,
+    #[default]
+    Unknown,
+}
+
+/// What happened to a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerEventKind {
+    Started,
+    Completed,
+    /// The task was due, but a constraint held it back; it runs once the constraint is met.
+    /// Published once until the reason changes or the task runs.
+    Skipped,
+    Failed,
+}
+
+/// One step of a scheduled task, published on the scheduler's event stream
+#[derive(Debug, Clone)]
+pub struct SchedulerEvent {
+    pub task: ScheduledTask,
+    pub kind: SchedulerEventKind,
+    /// Seconds since the Unix epoch
+    pub timestamp: i64,
+    /// What a completed task found: new feed entries, changed pages, updated plans, or expiring
+    /// pins
+    pub count: u32,
+    /// The URLs of the changed pages or updated plans, or the host:port of the expiring pins
+    pub items: Vec<String>,
+    /// Why the task was skipped or failed
+    pub message: Option<String>,
+    /// When the task is due next, for Completed and Failed events
+    pub next_run_at: Option<i64>,
+}
+
+/// Where each task stands
+#[derive(Debug, Clone, Default)]
+struct TaskState {
+    /// None until the first run, so every task is due on the first tick
+    next_run_at: Option<i64>,
+    /// Why the task was last skipped, until it runs
+    skipped: Option<String>,
+}
+
+static SCHEDULES: LazyLock<RwLock<Vec<TaskSchedule>>> = LazyLock::new(|| {
+    RwLock::new(
+        ScheduledTask::ALL
+            .into_iter()
+            .map(TaskSchedule::default_for)
+            .collect(),
+    )
+});
+
+static STATES: LazyLock<Mutex<HashMap<ScheduledTask, TaskState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static POWER: RwLock<PowerState> = RwLock::new(PowerState::Unknown);
+
+/// Held for the length of a tick, so ticks never overlap
+static TICKING: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Bumped by every `start_scheduler` and `stop_scheduler` call so older timer loops stop
+static TIMER_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+static BUS: LazyLock<broadcast::Sender<SchedulerEvent>> =
+    LazyLock::new(|| broadcast::channel(BUS_CAPACITY).0);
+
+/// A subscriber's end of the bus, locked while it waits for an event
+type Subscription = Arc<tokio::sync::Mutex<broadcast::Receiver<SchedulerEvent>>>;
+
+static SUBSCRIPTIONS: LazyLock<Mutex<HashMap<u64, Subscription>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_SUBSCRIPTION: AtomicU64 = AtomicU64::new(1);
+
+/// The schedule of every task
+pub fn schedules() -> Vec<TaskSchedule> {
+    SCHEDULES.read().unwrap().clone()
+}
+
+/// Change when and under which conditions a task runs; a task whose interval changed is due
+/// at its new interval after its last run
+pub fn set_schedule(schedule: TaskSchedule) -> Result<(), String> {
+    if schedule.interval_secs < MIN_INTERVAL_SECS {
+        return Err(format!(
+            "Interval must be at least {} seconds",
+            MIN_INTERVAL_SECS
+        ));
+    }
+    let mut schedules = SCHEDULES.write().unwrap();
+    if let Some(current) = schedules
+        .iter_mut()
+        .find(|current| current.task == schedule.task)
+    {
+        if let Some(next_run_at) = STATES
+            .lock()
+            .unwrap()
+            .get_mut(&schedule.task)
+            .and_then(|state| state.next_run_at.as_mut())
+        {
+            *next_run_at += schedule.interval_secs as i64 - current.interval_secs as i64;
+        }
+        *current = schedule;
+    }
+    Ok(())
+}
+
+/// Record the device's power source; the app calls this whenever it changes
+pub fn set_power_state(state: PowerState) {
+    *POWER.write().unwrap() = state;
+}
+
+pub fn power_state() -> PowerState {
+    *POWER.read().unwrap()
+}
+
+/// Run every enabled task that is due and whose constraints are met, one after the other,
+/// publishing each step on the event stream, and return the events of the tasks that ran or
+/// were held back. A tick while another one is running returns nothing.
+pub async fn tick() -> Vec<SchedulerEvent> {
+    let Ok(_ticking) = TICKING.try_lock() else {
+        return Vec::new();
+    };
+    let mut events = Vec::new();
+    for schedule in schedules() {
+        let due = STATES
+            .lock()
+            .unwrap()
+            .get(&schedule.task)
+            .and_then(|state| state.next_run_at)
+            .is_none_or(|next_run_at| next_run_at <= now());
+        if !schedule.enabled || !due {
+            continue;
+        }
+        if let Some(reason) = held_back(&schedule) {
+            let mut states = STATES.lock().unwrap();
+            let state = states.entry(schedule.task).or_default();
+            if state.skipped.as_deref() != Some(reason) {
+                state.skipped = Some(reason.to_string());
+                let mut event = event(schedule.task, SchedulerEventKind::Skipped);
+                event.message = Some(reason.to_string());
+                publish(&event);
+                events.push(event);
+            }
+            continue;
+        }
+        events.push(run(&schedule).await);
+    }
+    events
+}
+
+/// Tick every `tick_secs` on a thread of its own, so tasks run even when the app does not
+/// tick; off until called. Call after `open_profile`.
+pub fn start_scheduler(tick_secs: u32) -> Result<(), String> {
+    let generation = TIMER_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("Failed to start scheduler: {}", e))?;
+
+    std::thread::Builder::new()
+        .name("scheduler".to_string())
+        .spawn(move || loop {
+            if TIMER_GENERATION.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            runtime.block_on(tick());
+            std::thread::sleep(Duration::from_secs(tick_secs.max(1) as u64));
+        })
+        .map_err(|e| format!("Failed to start scheduler: {}", e))?;
+    Ok(())
+}
+
+/// Stop the timer started by `start_scheduler`; ticks of the app still run tasks
+pub fn stop_scheduler() {
+    TIMER_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Start receiving scheduler events, returning a subscription for `next_event`
+pub fn subscribe() -> u64 {
+    let id = NEXT_SUBSCRIPTION.fetch_add(1, Ordering::Relaxed);
+    let receiver = Arc::new(tokio::sync::Mutex::new(BUS.subscribe()));
+    SUBSCRIPTIONS.lock().unwrap().insert(id, receiver);
+    id
+}
+
+/// Wait for the next event published after the subscription started; events a slow
+/// subscriber fell behind on are skipped
+pub async fn next_event(subscription: u64) -> Result<SchedulerEvent, String> {
+    let receiver = SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .get(&subscription)
+        .cloned()
+        .ok_or_else(|| format!("Unknown scheduler subscription {}", subscription))?;
+    let mut receiver = receiver.lock().await;
+    loop {
+        match receiver.recv().await {
+            Ok(event) => return Ok(event),
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => {
+                unreachable!("scheduler bus sender lives in a static")
+            }
+        }
+    }
+}
+
+/// Stop a subscription, returning whether it existed
+pub fn unsubscribe(subscription: u64) -> bool {
+    SUBSCRIPTIONS
+        .lock()
+        .unwrap()
+        .remove(&subscription)
+        .is_some()
+}
+
+/// Why a due task may not run now, if it may not
+fn held_back(schedule: &TaskSchedule) -> Option<&'static str> {
+    let power = power_state();
+    if power == PowerState::LowBattery {
+        return Some("Battery low");
+    }
+    if schedule.charging_only && power != PowerState::Charging {
+        return Some("Not charging");
+    }
+    if !schedule.task.uses_network() {
+        return None;
+    }
+    if is_offline_mode() {
+        return Some("Offline mode");
+    }
+    if schedule.unmetered_only
+        && !matches!(network_type(), NetworkType::Wifi | NetworkType::Ethernet)
+    {
+        return Some("Not on Wi-Fi or Ethernet");
+    }
+    None
+}
+
+/// Run a task, schedule its next run, and return its Completed or Failed event
+async fn run(schedule: &TaskSchedule) -> SchedulerEvent {
+    STATES
+        .lock()
+        .unwrap()
+        .entry(schedule.task)
+        .or_default()
+        .skipped = None;
+    publish(&event(schedule.task, SchedulerEventKind::Started));
+
+    let result = match schedule.task {
+        ScheduledTask::FeedRefresh => feeds::refresh_all_feeds()
+            .await
+            .map(|added| (added, Vec::new())),
+        ScheduledTask::PageWatches => watches::check_for_updates()
+            .await
+            .map(|changed| counted(changed.into_iter().map(|watch| watch.url).collect())),
+        ScheduledTask::PlanWatches => plan_watches::check_plans()
+            .await
+            .map(|updated| counted(updated.into_iter().map(|watch| watch.url).collect())),
+        ScheduledTask::PinExpiry => expiring_pins().map(counted),
+    };
+
+    let jitter = match schedule.jitter_secs {
+        0 => 0,
+        jitter => uuid::Uuid::new_v4().as_u128() as u64 % (jitter as u64 + 1),
+    };
+    let next_run_at = now() + schedule.interval_secs as i64 + jitter as i64;
+    STATES
+        .lock()
+        .unwrap()
+        .entry(schedule.task)
+        .or_default()
+        .next_run_at = Some(next_run_at);
+
+    let mut event = match result {
+        Ok((count, items)) => {
+            let mut event = event(schedule.task, SchedulerEventKind::Completed);
+            event.count = count;
+            event.items = items;
+            event
+        }
+        Err(e) => {
+            let mut event = event(schedule.task, SchedulerEventKind::Failed);
+            event.message = Some(e);
+            event
+        }
+    };
+    event.next_run_at = Some(next_run_at);
+    publish(&event);
+    event
+}
+
+/// The host:port of every pin that expired or expires within 30 days, soonest first
+fn expiring_pins() -> Result<Vec<String>, String> {
+    let mut pins: Vec<_> = known_hosts::list_known_hosts()?
+        .into_iter()
+        .filter_map(|known| {
+            let expires_at = known.expires_at?;
+            (expires_at - now() < EXPIRY_WARNING_SECS).then_some((expires_at, known))
+        })
+        .collect();
+    pins.sort_by_key(|(expires_at, _)| *expires_at);
+    Ok(pins
+        .into_iter()
+        .map(|(_, known)| format!("{}:{}", known.host, known.port))
+        .collect())
+}
+
+fn counted(items: Vec<String>) -> (u32, Vec<String>) {
+    (items.len() as u32, items)
+}
+
+fn event(task: ScheduledTask, kind: SchedulerEventKind) -> SchedulerEvent {
+    SchedulerEvent {
+        task,
+        kind,
+        timestamp: now(),
+        count: 0,
+        items: Vec::new(),
+        message: None,
+        next_run_at: None,
+    }
+}
+
+fn publish(event: &SchedulerEvent) {
+    // Sending only fails when nobody is subscribed
+    let _ = BUS.send(event.clone());
+}
@@ -0,0 +1,132 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::api::functions::config;
+use crate::api::functions::navigate_internal::{navigate_internal, parse_input_url};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_result::NavigationResult;
+use crate::api::protocols::registry;
+use crate::api::storage::settings;
+
+/// Settings key of the search URL template
+const SEARCH_URL_KEY: &str = "search_url";
+
+/// Settings key of whether a Gemini address that fails to load is searched for
+const SEARCH_ON_FAILURE_KEY: &str = "search_on_failure";
+
+/// Replaced by the percent-encoded query in a search URL template
+pub const QUERY_PLACEHOLDER: &str = "%s";
+
+/// Everything but the unreserved characters is encoded, so a query cannot end the query string
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Search engines offered to pick from; the first is used until the user picks one
+const PROVIDERS: &[(&str, &str)] = &[
+    ("Kennedy", "gemini://kennedy.gemi.dev/search?%s"),
+    ("TLGS", "gemini://tlgs.one/search?%s"),
+    ("geminispace.info", "gemini://geminispace.info/search?%s"),
+];
+
+/// Search URL template used until the user picks one
+pub const DEFAULT_SEARCH_PROVIDER: &str = PROVIDERS[0].1;
+
+/// A search engine and how to ask it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchProvider {
+    pub name: String,
+    /// URL with `%s` where the query goes
+    pub url_template: String,
+}
+
+/// The search engines offered to pick from, the default first
+pub fn search_providers() -> Vec<SearchProvider> {
+    PROVIDERS
+        .iter()
+        .map(|(name, url_template)| SearchProvider {
+            name: name.to_string(),
+            url_template: url_template.to_string(),
+        })
+        .collect()
+}
+
+/// The configured search URL template, or the default
+pub fn search_provider() -> String {
+    config::current(|config| config.search_provider.clone())
+}
+
+/// The search URL template saved in the profile, or the default; an unreadable profile falls
+/// back to the default
+pub(crate) fn stored_search_provider() -> String {
+    settings::get(SEARCH_URL_KEY)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| DEFAULT_SEARCH_PROVIDER.to_string())
+}
+
+/// Search with a URL template of a registered scheme containing `%s`, one of the offered
+/// providers or any other, or with the default with None; returns the stored template
+pub fn set_search_provider(url_template: Option<&str>) -> Result<String, String> {
+    let Some(url_template) = url_template
+        .map(str::trim)
+        .filter(|url_template| !url_template.is_empty())
+    else {
+        settings::remove(SEARCH_URL_KEY)?;
+        config::update(|config| config.search_provider = DEFAULT_SEARCH_PROVIDER.to_string());
+        return Ok(DEFAULT_SEARCH_PROVIDER.to_string());
+    };
+    if !url_template.contains(QUERY_PLACEHOLDER) {
+        return Err(format!(
+            "Search URL must contain {} where the query goes",
+            QUERY_PLACEHOLDER
+        ));
+    }
+    let parsed = parse_input_url(&url_template.replace(QUERY_PLACEHOLDER, "query"))
+        .ok_or("Invalid search URL")?;
+    if registry::handler_for(parsed.scheme()).is_none() {
+        return Err(format!(
+            "Unsupported search URL scheme. Only {} are supported.",
+            registry::registered_schemes().join(", ")
+        ));
+    }
+    settings::set(SEARCH_URL_KEY, url_template)?;
+    config::update(|config| config.search_provider = url_template.to_string());
+    Ok(url_template.to_string())
+}
+
+/// Whether a Gemini address that fails to load is searched for instead of failing; off unless
+/// turned on, since search results hide why the page could not be loaded
+pub fn search_on_failure() -> bool {
+    settings::get(SEARCH_ON_FAILURE_KEY)
+        .ok()
+        .flatten()
+        .is_some_and(|value| value == "1")
+}
+
+/// Search for Gemini addresses that fail to load, e.g. for typos of host names, rather than
+/// return the error. Input that is not an address is searched for either way.
+pub fn set_search_on_failure(enabled: bool) -> Result<(), String> {
+    if enabled {
+        settings::set(SEARCH_ON_FAILURE_KEY, "1")
+    } else {
+        settings::remove(SEARCH_ON_FAILURE_KEY)
+    }
+}
+
+/// The URL asking the configured search provider for a query
+pub fn search_url(query: &str) -> String {
+    query_url(&search_provider(), query)
+}
+
+/// A search URL template with the query filled in
+pub(crate) fn query_url(url_template: &str, query: &str) -> String {
+    let query = utf8_percent_encode(query.trim(), QUERY_ENCODE_SET).to_string();
+    url_template.replace(QUERY_PLACEHOLDER, &query)
+}
+
+/// Navigate to the results of a query at the configured search provider
+pub async fn search(query: &str) -> Result<NavigationResult, NavigateError> {
+    navigate_internal(search_url(query)).await
+}
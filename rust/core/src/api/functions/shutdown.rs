@@ -0,0 +1,38 @@
+use crate::api::functions::{
+    batch, cache_refresh, cancellation, crawler, identities, mirror, pending_fetch, prefetch,
+    scheduler, streaming,
+};
+
+/// What `shutdown` stopped
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownSummary {
+    pub streams_cancelled: u32,
+    pub fetches_aborted: u32,
+    /// Navigations whose sockets were shut down, including ones awaited by the caller
+    pub requests_cancelled: u32,
+    /// Capsule mirrors stopped after their current file; mirroring again resumes them
+    pub mirrors_cancelled: u32,
+}
+
+/// Stop background work and close the profile database cleanly, for the app's lifecycle hooks
+/// to call before the process may be killed. History and cache writes are made as each
+/// navigation completes, so closing the database is what makes them durable; navigations still
+/// in flight are abandoned and record nothing.
+pub fn shutdown() -> Result<ShutdownSummary, String> {
+    cache_refresh::stop_bookmark_refresh();
+    identities::stop_expiry_checks();
+    scheduler::stop_scheduler();
+    prefetch::cancel_prefetch();
+    crawler::cancel_capsule_indexing();
+    batch::cancel_all();
+    // Mirrors go first, so none starts another request once the running ones are cancelled
+    let mirrors_cancelled = mirror::cancel_all_mirrors() as u32;
+    let summary = ShutdownSummary {
+        streams_cancelled: streaming::cancel_all() as u32,
+        fetches_aborted: pending_fetch::abort_all() as u32,
+        requests_cancelled: cancellation::cancel_all() as u32,
+        mirrors_cancelled,
+    };
+    crate::api::storage::close_profile()?;
+    Ok(summary)
+}
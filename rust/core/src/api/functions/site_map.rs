@@ -0,0 +1,181 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::crawler::{capsule_root, within};
+use crate::api::functions::links::{menu_link, LinkKind};
+use crate::api::functions::navigate_internal::{fetch_with_options, parse_input_url};
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::models::fetch_response::GOPHER_MENU_MIME;
+use crate::api::parsers::gophermap::parse_gophermap;
+
+/// Deepest level of menus a site map descends to, whatever the caller asks for
+const MAX_SITE_MAP_DEPTH: u32 = 8;
+
+/// Most menus one site map fetches; large holes such as sdf.org have thousands
+const MAX_SITE_MAP_MENUS: usize = 300;
+
+/// An item of a site map
+#[derive(Debug, Clone)]
+pub struct SiteNode {
+    pub url: String,
+    /// The item's text in the menu that lists it, or the URL for the start menu
+    pub title: String,
+    pub kind: LinkKind,
+    /// Icon the UI shows next to the item, e.g. "text", "folder", or "search"
+    pub icon: String,
+    /// Whether the item is a menu, whose items may follow it
+    pub menu: bool,
+    /// Menus from the start menu to this item; 0 for the start menu
+    pub depth: u32,
+    /// Index in `SiteMap::nodes` of the menu listing the item; None for the start menu
+    pub parent: Option<u32>,
+    /// Whether the items of a menu are listed after it; not for menus past the depth, off the
+    /// hole, listed under another menu already, left over at the menu limit, or failing
+    pub expanded: bool,
+    /// Why fetching a menu failed
+    pub error: Option<String>,
+}
+
+/// The menus and documents of a gopher hole, as a tree flattened in menu order: each node is
+/// followed by the items of its menu, so a sidebar shows them indented by depth
+#[derive(Debug, Clone)]
+pub struct SiteMap {
+    /// The hole's root, e.g. gopher://sdf.org/
+    pub root: String,
+    pub nodes: Vec<SiteNode>,
+    /// Set when menus were left unfetched once 300 were
+    pub truncated: bool,
+}
+
+/// The items a fetched menu lists
+struct MenuItem {
+    url: String,
+    title: String,
+    kind: LinkKind,
+    icon: String,
+    menu: bool,
+}
+
+/// Walk a gopher hole's menus from `url`, breadth first and `depth` levels deep (at most 8),
+/// and return the tree of its menus and documents for a site map. Menus of other hosts are
+/// listed but not walked, and each menu is walked once however many menus link to it. Fetches
+/// are background requests, which keep to the host's rate limits and add no visits to history;
+/// menus cached and not yet expired are taken from the cache.
+pub async fn map_site(url: &str, depth: u32) -> Result<SiteMap, String> {
+    let start = parse_input_url(url).ok_or("Invalid URL format")?;
+    if start.scheme() != "gopher" {
+        return Err("Site map URL must be a gopher:// URL".to_string());
+    }
+    let root = capsule_root(&start)?;
+    let depth = depth.min(MAX_SITE_MAP_DEPTH);
+    let options = FetchOptions {
+        use_cache: true,
+        background: true,
+        ..FetchOptions::default()
+    };
+
+    let start = canonicalize(&start).to_string();
+    let mut listed: HashMap<String, Result<Vec<MenuItem>, String>> = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+    let mut truncated = false;
+    while let Some((menu_url, level)) = queue.pop_front() {
+        if listed.len() == MAX_SITE_MAP_MENUS {
+            truncated = true;
+            break;
+        }
+        let items = fetch_menu(&menu_url, &options).await;
+        if let Ok(items) = &items {
+            for item in items.iter().filter(|item| item.menu && level < depth) {
+                let in_hole = Url::parse(&item.url).is_ok_and(|url| within(&root, &url));
+                if in_hole && seen.insert(item.url.clone()) {
+                    queue.push_back((item.url.clone(), level + 1));
+                }
+            }
+        }
+        listed.insert(menu_url, items);
+    }
+
+    let mut nodes = vec![SiteNode {
+        url: start.clone(),
+        title: start.clone(),
+        kind: LinkKind::Page,
+        icon: "folder".to_string(),
+        menu: true,
+        depth: 0,
+        parent: None,
+        expanded: false,
+        error: None,
+    }];
+    let mut expanded = HashSet::new();
+    add_items(&mut nodes, 0, &listed, &mut expanded);
+    Ok(SiteMap {
+        root: root.to_string(),
+        nodes,
+        truncated,
+    })
+}
+
+/// Fetch a menu and list its items with their canonical URLs; informational lines are left out
+async fn fetch_menu(url: &str, options: &FetchOptions) -> Result<Vec<MenuItem>, String> {
+    let response = fetch_with_options(url.to_string(), options).await?;
+    if !response.mime.starts_with(GOPHER_MENU_MIME) {
+        return Err(format!("{} is not a menu", url));
+    }
+    Ok(parse_gophermap(&response.body)
+        .into_iter()
+        .filter_map(|item| {
+            let link = menu_link(&item)?;
+            let url = match Url::parse(&link.url) {
+                Ok(url) if link.kind != LinkKind::External => canonicalize(&url).to_string(),
+                _ => link.url,
+            };
+            Some(MenuItem {
+                url,
+                title: item.display,
+                kind: link.kind,
+                icon: item.icon,
+                menu: item.item_type == '1',
+            })
+        })
+        .collect())
+}
+
+/// Append the items of the menu at `index` after it, each followed by its own items when it is
+/// a menu walked and not yet expanded elsewhere in the map
+fn add_items(
+    nodes: &mut Vec<SiteNode>,
+    index: usize,
+    listed: &HashMap<String, Result<Vec<MenuItem>, String>>,
+    expanded: &mut HashSet<String>,
+) {
+    let url = nodes[index].url.clone();
+    let items = match listed.get(&url) {
+        Some(Ok(items)) if expanded.insert(url) => items,
+        Some(Err(e)) => {
+            nodes[index].error = Some(e.clone());
+            return;
+        }
+        _ => return,
+    };
+    nodes[index].expanded = true;
+    let depth = nodes[index].depth + 1;
+    for item in items {
+        nodes.push(SiteNode {
+            url: item.url.clone(),
+            title: item.title.clone(),
+            kind: item.kind,
+            icon: item.icon.clone(),
+            menu: item.menu,
+            depth,
+            parent: Some(index as u32),
+            expanded: false,
+            error: None,
+        });
+        if item.menu {
+            add_items(nodes, nodes.len() - 1, listed, expanded);
+        }
+    }
+}
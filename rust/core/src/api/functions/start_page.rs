@@ -0,0 +1,45 @@
+use crate::api::functions::config;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::protocols::registry;
+use crate::api::storage::settings;
+
+/// Settings key of the start page
+const START_PAGE_KEY: &str = "start_page";
+
+/// Start page used until the user picks one
+pub const DEFAULT_START_PAGE: &str = "gemini://kennedy.gemi.dev/";
+
+/// The configured start page, or the default
+pub fn start_page() -> String {
+    config::current(|config| config.start_page.clone())
+}
+
+/// The start page saved in the profile, or the default; an unreadable profile falls back to
+/// the default
+pub(crate) fn stored_start_page() -> String {
+    settings::get(START_PAGE_KEY)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| DEFAULT_START_PAGE.to_string())
+}
+
+/// Set the start page to a URL of a registered scheme or about:home, or back to the default
+/// with None, returning the stored value
+pub fn set_start_page(url: Option<&str>) -> Result<String, String> {
+    let Some(url) = url.map(str::trim).filter(|url| !url.is_empty()) else {
+        settings::remove(START_PAGE_KEY)?;
+        config::update(|config| config.start_page = DEFAULT_START_PAGE.to_string());
+        return Ok(DEFAULT_START_PAGE.to_string());
+    };
+    let parsed = parse_input_url(url).ok_or("Invalid start page URL")?;
+    if registry::handler_for(parsed.scheme()).is_none() {
+        return Err(format!(
+            "Unsupported start page scheme. Only {} are supported.",
+            registry::registered_schemes().join(", ")
+        ));
+    }
+    let url = parsed.to_string();
+    settings::set(START_PAGE_KEY, &url)?;
+    config::update(|config| config.start_page = url.clone());
+    Ok(url)
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::sync::mpsc;
+
+use crate::api::functions::content_router::is_gemtext;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::functions::navigation_events::{NavigationTracker, PROGRESS_STEP_BYTES};
+use crate::api::models::stream_event::{StreamEvent, StreamEventKind};
+use crate::api::parsers::gemtext::GemtextParser;
+use crate::api::protocols::gemini;
+
+/// Events buffered between the reader task and the caller before the reader waits
+const EVENT_BUFFER: usize = 256;
+
+/// Longest body line delivered as one event; longer lines are split so a server cannot exhaust memory
+const MAX_LINE_BYTES: u64 = 64 * 1024;
+
+/// How a stream behaves while the server is quiet
+#[derive(Debug, Clone)]
+pub struct StreamOptions {
+    /// Seconds without data before an Idle event is delivered
+    pub idle_interval_secs: u32,
+    /// Seconds without data before giving up, or None to keep reading until cancelled
+    pub max_idle_secs: Option<u32>,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        StreamOptions {
+            idle_interval_secs: 30,
+            max_idle_secs: None,
+        }
+    }
+}
+
+/// The caller's end of a running stream
+struct ActiveStream {
+    options: StreamOptions,
+    cancelled: Arc<AtomicBool>,
+    receiver: tokio::sync::Mutex<StreamReceiver>,
+}
+
+struct StreamReceiver {
+    events: mpsc::Receiver<StreamEvent>,
+    last_activity: Instant,
+    bytes_received: u64,
+}
+
+static STREAMS: LazyLock<Mutex<HashMap<u64, Arc<ActiveStream>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Start streaming a Gemini URL line by line, returning a handle for `next_event`; the lines of
+/// a gemtext body also come parsed
+pub async fn open_stream(url: &str, options: StreamOptions) -> Result<u64, String> {
+    let mut parsed_url = parse_input_url(url).ok_or("Invalid URL format")?;
+    parsed_url.set_fragment(None);
+    crate::api::storage::blocklist::check_url(&parsed_url)?;
+    crate::api::storage::scheme_toggles::check_url(&parsed_url)?;
+    if parsed_url.scheme() != "gemini" {
+        return Err("Streaming is only supported for gemini:// URLs".to_string());
+    }
+
+    let host = parsed_url
+        .host_str()
+        .ok_or("Invalid host in URL")?
+        .to_string();
+    let port = crate::api::storage::ports::resolve_port(&parsed_url, 1965);
+    let request_url = gemini::request_url(&parsed_url).to_string();
+
+    let tracker = NavigationTracker::start(&request_url);
+    let (sender, events) = mpsc::channel(EVENT_BUFFER);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let reader_cancelled = cancelled.clone();
+    tokio::spawn(async move {
+        let mut bytes_received = 0;
+        let last = match read_stream(
+            &host,
+            port,
+            &request_url,
+            &sender,
+            &reader_cancelled,
+            &tracker,
+            &mut bytes_received,
+        )
+        .await
+        {
+            Ok(status) => {
+                tracker.finished(status, bytes_received);
+                crate::api::functions::data_usage::record(
+                    request_url.len() as u64 + 2,
+                    bytes_received,
+                );
+                StreamEvent::new(StreamEventKind::Finished, bytes_received)
+            }
+            Err(e) => {
+                tracker.failed(&e, bytes_received);
+                crate::api::functions::data_usage::record(0, bytes_received);
+                StreamEvent::with_text(StreamEventKind::Failed, e, bytes_received)
+            }
+        };
+        let _ = sender.send(last).await;
+    });
+
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let stream = ActiveStream {
+        options,
+        cancelled,
+        receiver: tokio::sync::Mutex::new(StreamReceiver {
+            events,
+            last_activity: Instant::now(),
+            bytes_received: 0,
+        }),
+    };
+    STREAMS.lock().unwrap().insert(handle, Arc::new(stream));
+    Ok(handle)
+}
+
+/// Wait for the next event of a stream; the handle is released after a Finished or Failed event
+pub async fn next_event(handle: u64) -> Result<StreamEvent, String> {
+    let stream = STREAMS
+        .lock()
+        .unwrap()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| format!("Unknown stream {}", handle))?;
+    let mut receiver = stream.receiver.lock().await;
+
+    let idle_interval = Duration::from_secs(stream.options.idle_interval_secs.max(1) as u64);
+    let event = match tokio::time::timeout(idle_interval, receiver.events.recv()).await {
+        Ok(Some(event)) => {
+            receiver.last_activity = Instant::now();
+            receiver.bytes_received = event.bytes_received;
+            event
+        }
+        // The reader is gone without a final event, which only happens after cancellation
+        Ok(None) => StreamEvent::new(StreamEventKind::Finished, receiver.bytes_received),
+        Err(_) => {
+            let idle_for = receiver.last_activity.elapsed();
+            match stream.options.max_idle_secs {
+                Some(max) if idle_for.as_secs() >= max as u64 => {
+                    stream.cancelled.store(true, Ordering::Relaxed);
+                    StreamEvent::with_text(
+                        StreamEventKind::Failed,
+                        format!("Stream idle for {} seconds", idle_for.as_secs()),
+                        receiver.bytes_received,
+                    )
+                }
+                _ => StreamEvent::new(StreamEventKind::Idle, receiver.bytes_received),
+            }
+        }
+    };
+
+    if event.is_terminal() {
+        STREAMS.lock().unwrap().remove(&handle);
+    }
+    Ok(event)
+}
+
+/// Stop a stream, returning whether it was running; its reader exits at the next data it receives
+pub fn cancel_stream(handle: u64) -> bool {
+    match STREAMS.lock().unwrap().remove(&handle) {
+        Some(stream) => {
+            stream.cancelled.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Cancel every open stream, returning how many were cancelled
+pub(crate) fn cancel_all() -> usize {
+    let streams: Vec<_> = STREAMS.lock().unwrap().drain().collect();
+    for (_, stream) in &streams {
+        stream.cancelled.store(true, Ordering::Relaxed);
+    }
+    streams.len()
+}
+
+/// Read the header and then body lines as they arrive, until the server closes or the caller
+/// goes away, returning the status once the header was read
+async fn read_stream(
+    host: &str,
+    port: u16,
+    url: &str,
+    events: &mpsc::Sender<StreamEvent>,
+    cancelled: &AtomicBool,
+    tracker: &NavigationTracker,
+    bytes_received: &mut u64,
+) -> Result<Option<u8>, String> {
+    let connection = gemini::open_request(host, port, url).await?;
+    let mut reader = BufReader::new(connection);
+    let mut line = Vec::new();
+
+    let read = read_line(&mut reader, &mut line).await?;
+    *bytes_received += read as u64;
+    let header = String::from_utf8_lossy(&line);
+    let (status, meta) = gemini::parse_header(header.trim_end_matches(['\r', '\n']));
+    tracker.header(status, &meta);
+    // A gemtext body is parsed line by line as it arrives
+    let mut parser = (meta.is_empty() || is_gemtext(&meta)).then(GemtextParser::default);
+    if events
+        .send(StreamEvent::header(status, meta, *bytes_received))
+        .await
+        .is_err()
+    {
+        return Ok(Some(status));
+    }
+
+    // Only success responses have a body
+    if !(20..30).contains(&status) {
+        return Ok(Some(status));
+    }
+
+    let mut line_start = true;
+    let mut reported = *bytes_received;
+    loop {
+        line.clear();
+        let read = read_line(&mut reader, &mut line).await?;
+        if read == 0 || cancelled.load(Ordering::Relaxed) {
+            return Ok(Some(status));
+        }
+        *bytes_received += read as u64;
+        if *bytes_received - reported >= PROGRESS_STEP_BYTES {
+            reported = *bytes_received;
+            tracker.progress(reported);
+        }
+
+        let text = String::from_utf8_lossy(&line);
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        let element = parser.as_mut().map(|parser| {
+            if line_start {
+                parser.parse_line(text)
+            } else {
+                parser.parse_continuation(text)
+            }
+        });
+        // A line longer than MAX_LINE_BYTES continues in the next event
+        line_start = line.ends_with(b"\n");
+        let event = StreamEvent {
+            element,
+            ..StreamEvent::with_text(StreamEventKind::Line, text.to_string(), *bytes_received)
+        };
+        if events.send(event).await.is_err() {
+            return Ok(Some(status));
+        }
+    }
+}
+
+async fn read_line(
+    reader: &mut (impl AsyncBufRead + Unpin),
+    line: &mut Vec<u8>,
+) -> Result<usize, String> {
+    reader
+        .take(MAX_LINE_BYTES)
+        .read_until(b'\n', line)
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::api::functions::canonical_url::canonicalize_str;
+use crate::api::storage::history::{self, HistoryMatch, HistoryRange};
+use crate::api::storage::{bookmarks, frecency};
+
+/// Candidates read from each store per suggestion asked for, before ranking
+const CANDIDATES_PER_SUGGESTION: u32 = 5;
+
+/// How well a suggestion matches what was typed, best last
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SuggestionMatch {
+    /// The typed text appears somewhere in the URL or title
+    Substring,
+    /// A word of the title or a label of the host starts with the typed text
+    WordPrefix,
+    /// The URL without its scheme starts with the typed text, or the whole URL does when the
+    /// typed text includes a scheme
+    UrlPrefix,
+}
+
+/// An address bar completion from history or bookmarks
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub url: String,
+    pub title: Option<String>,
+    pub bookmarked: bool,
+    /// Visits in history, 0 for a bookmark never visited
+    pub visit_count: u32,
+    pub last_visited: Option<i64>,
+    pub matched: SuggestionMatch,
+    /// For a URL prefix match, the rest of the URL after the typed text, to fill in inline
+    pub completion: Option<String>,
+}
+
+/// Suggestions for the address bar as the user types: history and bookmarks whose URL or title
+/// contains `prefix`, case-insensitively, best first. URLs starting with the typed text come
+/// before title and host word matches, which come before matches anywhere else; among equal
+/// matches, pages come by frecency, as top sites do, a bookmark counting as one visit made now.
+pub fn suggest(prefix: &str, limit: u32) -> Result<Vec<Suggestion>, String> {
+    let typed = prefix.trim().to_lowercase();
+    if typed.is_empty() || limit == 0 {
+        return Ok(Vec::new());
+    }
+    let candidates = limit.saturating_mul(CANDIDATES_PER_SUGGESTION);
+    let mut suggestions: HashMap<String, (Suggestion, f64)> = HashMap::new();
+
+    // Prefix matches are read first so that many recent substring matches cannot crowd them out
+    let range = HistoryRange::default();
+    let visited = history::search_history(&typed, HistoryMatch::Prefix, &range, candidates)?
+        .into_iter()
+        .chain(history::search_history(
+            &typed,
+            HistoryMatch::Substring,
+            &range,
+            candidates,
+        )?);
+    let visited: Vec<_> = visited.collect();
+    let urls: Vec<&str> = visited.iter().map(|result| result.url.as_str()).collect();
+    let scores = frecency::scores(&urls)?;
+    for result in visited {
+        let key = canonicalize_str(&result.url);
+        if suggestions.contains_key(&key) {
+            continue;
+        }
+        let Some(matched) = match_kind(&typed, &result.url, result.title.as_deref()) else {
+            continue;
+        };
+        let frecency = scores.get(&result.url).copied().unwrap_or_default();
+        suggestions.insert(
+            key,
+            (
+                Suggestion {
+                    completion: completion(&typed, &result.url),
+                    url: result.url,
+                    title: result.title,
+                    bookmarked: false,
+                    visit_count: result.visit_count,
+                    last_visited: Some(result.last_visited),
+                    matched,
+                },
+                frecency,
+            ),
+        );
+    }
+
+    for bookmark in bookmarks::search(&typed, candidates)? {
+        let Some(matched) = match_kind(&typed, &bookmark.url, bookmark.title.as_deref()) else {
+            continue;
+        };
+        let (suggestion, weight) = suggestions
+            .entry(canonicalize_str(&bookmark.url))
+            .or_insert_with(|| {
+                (
+                    Suggestion {
+                        completion: completion(&typed, &bookmark.url),
+                        url: bookmark.url.clone(),
+                        title: None,
+                        bookmarked: true,
+                        visit_count: 0,
+                        last_visited: None,
+                        matched,
+                    },
+                    0.0,
+                )
+            });
+        // The title the user gave a bookmark says more than the one the page was seen with
+        if bookmark.title.is_some() {
+            suggestion.title = bookmark.title;
+        }
+        suggestion.matched = suggestion.matched.max(matched);
+        suggestion.bookmarked = true;
+        *weight += 1.0;
+    }
+
+    let mut ranked: Vec<(Suggestion, f64)> = suggestions.into_values().collect();
+    ranked.sort_by(|(a, a_weight), (b, b_weight)| {
+        b.matched
+            .cmp(&a.matched)
+            .then(b_weight.total_cmp(a_weight))
+            .then(a.url.len().cmp(&b.url.len()))
+    });
+    ranked.truncate(limit as usize);
+    Ok(ranked
+        .into_iter()
+        .map(|(suggestion, _)| suggestion)
+        .collect())
+}
+
+/// How a URL and title match lowercased typed text, if they do
+fn match_kind(typed: &str, url: &str, title: Option<&str>) -> Option<SuggestionMatch> {
+    let url = url.to_lowercase();
+    let title = title.unwrap_or_default().to_lowercase();
+    let address = without_scheme(&url);
+    if address.starts_with(typed) || (typed.contains("://") && url.starts_with(typed)) {
+        return Some(SuggestionMatch::UrlPrefix);
+    }
+    let host = address.split(['/', ':', '?']).next().unwrap_or_default();
+    let word_prefix = title
+        .split(|c: char| !c.is_alphanumeric())
+        .chain(host.split('.'))
+        .any(|word| word.starts_with(typed));
+    if word_prefix {
+        return Some(SuggestionMatch::WordPrefix);
+    }
+    (url.contains(typed) || title.contains(typed)).then_some(SuggestionMatch::Substring)
+}
+
+/// The rest of a URL after typed text it starts with, the scheme counting only when typed
+fn completion(typed: &str, url: &str) -> Option<String> {
+    let lowercase = url.to_lowercase();
+    let start = if typed.contains("://") {
+        0
+    } else {
+        lowercase.len() - without_scheme(&lowercase).len()
+    };
+    if !lowercase[start..].starts_with(typed) {
+        return None;
+    }
+    // Lowercasing keeps the length of ASCII URLs; others get no inline completion
+    url.get(start + typed.len()..)
+        .filter(|rest| url.is_ascii() && !rest.is_empty())
+        .map(str::to_string)
+}
+
+fn without_scheme(url: &str) -> &str {
+    url.split_once("://").map_or(url, |(_, rest)| rest)
+}
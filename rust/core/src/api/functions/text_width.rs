@@ -0,0 +1,73 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// Tab stops used when measuring preformatted text, matching a typical terminal
+const TAB_STOP: usize = 8;
+
+/// Measurements of one preformatted block in a gemtext document
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreformattedBlock {
+    /// Index of the opening ``` line in the document
+    pub start_index: u32,
+    pub line_count: u32,
+    pub alt: Option<String>,
+    /// Widest line in terminal columns
+    pub max_width: u32,
+    /// Whether any line contains wide (East Asian or emoji) characters
+    pub has_wide_chars: bool,
+}
+
+/// Terminal column width of a string: wide characters count as two, combining marks as zero
+pub fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    for grapheme in text.graphemes(true) {
+        if grapheme == "\t" {
+            width += TAB_STOP - width % TAB_STOP;
+        } else {
+            width += grapheme.width();
+        }
+    }
+    width
+}
+
+/// Measure every preformatted block so the renderer can choose between scrolling and shrinking
+pub fn preformatted_blocks(document: &[GemtextLine]) -> Vec<PreformattedBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<PreformattedBlock> = None;
+
+    for (index, line) in document.iter().enumerate() {
+        match line {
+            GemtextLine::PreformatToggle { alt } => match current.take() {
+                Some(block) => blocks.push(block),
+                None => {
+                    current = Some(PreformattedBlock {
+                        start_index: index as u32,
+                        line_count: 0,
+                        alt: alt.clone(),
+                        max_width: 0,
+                        has_wide_chars: false,
+                    })
+                }
+            },
+            GemtextLine::Preformatted(text) => {
+                if let Some(block) = current.as_mut() {
+                    let width = display_width(text);
+                    block.line_count += 1;
+                    block.max_width = block.max_width.max(width as u32);
+                    block.has_wide_chars |= text
+                        .chars()
+                        .any(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0) > 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // An unterminated block runs to the end of the document
+    if let Some(block) = current {
+        blocks.push(block);
+    }
+    blocks
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{LazyLock, Mutex};
+
+use rustls::pki_types::CertificateDer;
+use rustls::CertificateError;
+use x509_parser::prelude::{FromDer, GeneralName, X509Certificate};
+
+use crate::api::models::tls_failure::{TlsFailure, TlsFailureKind};
+
+/// Prefix of the error returned when strict validation refuses a server certificate
+pub const CERTIFICATE_REJECTED_ERROR_PREFIX: &str = "Certificate rejected by the trust store";
+
+/// The last certificate refused by strict validation for each host, until one is accepted
+static REJECTED: LazyLock<Mutex<HashMap<String, TlsFailure>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Why strict validation last refused a host's certificate, for the security warning of a
+/// failed navigation; None when its last certificate passed or none was checked
+pub fn certificate_failure(host: &str) -> Option<TlsFailure> {
+    REJECTED
+        .lock()
+        .unwrap()
+        .get(&host.trim().to_ascii_lowercase())
+        .cloned()
+}
+
+/// Keep why a host's certificate was refused
+pub(crate) fn rejected(failure: TlsFailure) {
+    REJECTED
+        .lock()
+        .unwrap()
+        .insert(failure.host.to_ascii_lowercase(), failure);
+}
+
+/// Forget a host's refused certificate once one passes
+pub(crate) fn accepted(host: &str) {
+    REJECTED.lock().unwrap().remove(&host.to_ascii_lowercase());
+}
+
+/// Explain a verifier error from what the certificate and the chain the server sent say:
+/// rustls reports a certificate chaining to no trusted CA alike whether it signed itself, the
+/// server left out an intermediate, or its root is simply not trusted
+pub(crate) fn explain(
+    host: &str,
+    error: &CertificateError,
+    end_entity: &[u8],
+    intermediates: &[CertificateDer<'_>],
+) -> TlsFailure {
+    let mut failure = TlsFailure {
+        kind: TlsFailureKind::Other,
+        host: host.to_string(),
+        message: String::new(),
+        names: Vec::new(),
+        subject: String::new(),
+        issuer: String::new(),
+        not_before: 0,
+        not_after: 0,
+        intermediates: intermediates.len() as u32,
+    };
+    let Ok((_, certificate)) = X509Certificate::from_der(end_entity) else {
+        failure.message = format!("the certificate of {} is unreadable", host);
+        return failure;
+    };
+    failure.names = names(&certificate);
+    failure.subject = certificate.subject().to_string();
+    failure.issuer = certificate.issuer().to_string();
+    failure.not_before = certificate.validity().not_before.timestamp();
+    failure.not_after = certificate.validity().not_after.timestamp();
+
+    let (kind, message) = match error {
+        CertificateError::Expired | CertificateError::ExpiredContext { .. } => (
+            TlsFailureKind::Expired,
+            format!(
+                "the certificate of {} expired on {}",
+                host,
+                certificate.validity().not_after.to_datetime().date()
+            ),
+        ),
+        CertificateError::NotValidYet | CertificateError::NotValidYetContext { .. } => (
+            TlsFailureKind::NotYetValid,
+            format!(
+                "the certificate of {} is not valid before {}",
+                host,
+                certificate.validity().not_before.to_datetime().date()
+            ),
+        ),
+        CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. } => {
+            let names = if failure.names.is_empty() {
+                "no host name".to_string()
+            } else {
+                failure.names.join(", ")
+            };
+            (
+                TlsFailureKind::NameMismatch,
+                format!("the certificate is for {}, not {}", names, host),
+            )
+        }
+        CertificateError::UnknownIssuer => {
+            let last = intermediates
+                .last()
+                .and_then(|der| X509Certificate::from_der(der).ok())
+                .map(|(_, last)| last);
+            let last = last.as_ref().unwrap_or(&certificate);
+            if intermediates.is_empty() && self_signed(&certificate) {
+                (
+                    TlsFailureKind::SelfSigned,
+                    format!("the certificate of {} is self-signed", host),
+                )
+            } else if self_signed(last) {
+                (
+                    TlsFailureKind::UntrustedIssuer,
+                    format!(
+                        "the certificate of {} was issued by {}, which is not a trusted CA",
+                        host,
+                        last.subject()
+                    ),
+                )
+            } else {
+                (
+                    TlsFailureKind::IncompleteChain,
+                    format!(
+                        "the chain of {} ends at {}, a certificate issued by {}, which the \
+                         server did not send and is not a trusted CA",
+                        host,
+                        last.subject(),
+                        last.issuer()
+                    ),
+                )
+            }
+        }
+        CertificateError::Revoked => (
+            TlsFailureKind::Revoked,
+            format!("the certificate of {} was revoked", host),
+        ),
+        error => (
+            TlsFailureKind::Other,
+            format!("the certificate of {} is invalid: {:?}", host, error),
+        ),
+    };
+    failure.kind = kind;
+    failure.message = message;
+    failure
+}
+
+/// The DNS names and IP addresses a certificate is for, or its common names when it has no
+/// subject alternative names, as old certificates do
+fn names(certificate: &X509Certificate<'_>) -> Vec<String> {
+    let alternative: Vec<String> = certificate
+        .subject_alternative_name()
+        .ok()
+        .flatten()
+        .map(|extension| {
+            extension
+                .value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(name) => Some(name.to_string()),
+                    GeneralName::IPAddress(bytes) => ip_address(bytes).map(|ip| ip.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if !alternative.is_empty() {
+        return alternative;
+    }
+    certificate
+        .subject()
+        .iter_common_name()
+        .filter_map(|name| name.as_str().ok())
+        .map(str::to_string)
+        .collect()
+}
+
+fn ip_address(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+        _ => None,
+    }
+}
+
+fn self_signed(certificate: &X509Certificate<'_>) -> bool {
+    certificate.subject().as_raw() == certificate.issuer().as_raw()
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::api::functions::clock_skew;
+use crate::api::functions::digest::sha256_hex;
+use crate::api::functions::logging::{log, LogLevel};
+use crate::api::functions::navigation_events::{is_private, report_certificate, report_clock_skew};
+use crate::api::functions::request_trace::{trace, TraceStage};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_result::{CertificateSummary, PinExpiry, PinStatus};
+use crate::api::storage::host_settings::{self, TlsPolicy};
+use crate::api::storage::known_hosts::{self, KnownHost};
+use crate::api::storage::now;
+use crate::api::transport::tls;
+
+/// Prefix of the error returned when a server presents a different certificate than the pinned one
+pub const FINGERPRINT_MISMATCH_ERROR_PREFIX: &str = "Certificate changed";
+
+/// How long before a certificate's expiry it is reported as expiring soon
+pub(crate) const EXPIRY_WARNING_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// A certificate refused for differing from the pin of its host, kept for
+/// `accept_new_certificate`
+struct RefusedCertificate {
+    fingerprint: String,
+    expires_at: i64,
+}
+
+/// The last certificate refused for each host and port
+static REFUSED: LazyLock<Mutex<HashMap<(String, u16), RefusedCertificate>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Certificates trusted on first use by private navigations, for each host and port: checked
+/// and filled in like the stored pins, which private navigations leave untouched, and forgotten
+/// when the private session ends
+static PRIVATE_PINS: LazyLock<Mutex<HashMap<(String, u16), KnownHost>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Fingerprint, names, and validity of a DER certificate; `first_seen` is left at now and
+/// `pin_status` at unverified until the certificate is checked against the pin
+pub(crate) fn certificate_summary(der: &[u8]) -> Result<CertificateSummary, NavigateError> {
+    let (_, certificate) = X509Certificate::from_der(der).map_err(|e| {
+        NavigateError::TlsError(format!(
+            "TLS connection failed: unreadable server certificate: {}",
+            e
+        ))
+    })?;
+    Ok(CertificateSummary {
+        fingerprint: sha256_hex(certificate.public_key().raw),
+        subject: certificate.subject().to_string(),
+        issuer: certificate.issuer().to_string(),
+        not_before: certificate.validity().not_before.timestamp(),
+        not_after: certificate.validity().not_after.timestamp(),
+        first_seen: now(),
+        pin_status: PinStatus::Unverified,
+        expiry: pin_expiry(certificate.validity().not_after.timestamp()),
+        tls_version: None,
+        cipher_suite: None,
+    })
+}
+
+/// How near a certificate expiring at `not_after` is to its expiry
+fn pin_expiry(not_after: i64) -> PinExpiry {
+    let remaining = not_after - now();
+    if remaining < 0 {
+        PinExpiry::Expired
+    } else if remaining < EXPIRY_WARNING_SECS {
+        PinExpiry::ExpiringSoon
+    } else {
+        PinExpiry::Valid
+    }
+}
+
+/// Trust a server's certificate on first use and refuse a different one until the pinned
+/// certificate expires; a certificate outside its validity period is refused unless a wrong
+/// device clock may explain it, which is reported to the navigation as a clock skew warning.
+/// A private navigation is checked against the pins without adding or updating any; it pins
+/// hosts it meets first in memory instead, until `end_private_session`. The
+/// certificate of a strict host was verified against the user trust store during the handshake
+/// and is not pinned.
+pub(crate) fn verify_certificate(host: &str, port: u16, der: &[u8]) -> Result<(), NavigateError> {
+    let policy = host_settings::tls_policy(host);
+    if policy == TlsPolicy::Unverified {
+        trace(TraceStage::Tls, || {
+            "Certificate not verified, as the host's settings ask".to_string()
+        });
+        report_certificate(certificate_summary(der)?);
+        return Ok(());
+    }
+    if policy == TlsPolicy::Strict {
+        trace(TraceStage::Tls, || {
+            "Certificate verified against the user trust store".to_string()
+        });
+        let mut summary = certificate_summary(der)?;
+        summary.pin_status = PinStatus::CaVerified;
+        report_certificate(summary);
+        return Ok(());
+    }
+    if let Some(skew) = clock_skew::check_validity(host, port, der)? {
+        report_clock_skew(skew);
+    }
+    let mut summary = certificate_summary(der)?;
+    let fingerprint = summary.fingerprint.clone();
+    let expires_at = summary.not_after;
+    trace(TraceStage::Tls, || {
+        format!("Certificate fingerprint {}", fingerprint)
+    });
+    // A private navigation checks the pins but leaves them as they were, pinning in memory
+    let read_only = is_private();
+    let known = match read_only.then(|| private_pin(host, port)).flatten() {
+        Some(known) => Some(known),
+        None => known_hosts::get_known_host(host, port).map_err(NavigateError::StorageError)?,
+    };
+    match known {
+        None if read_only => {
+            pin_privately(host, port, &fingerprint, expires_at, summary.first_seen);
+            summary.pin_status = PinStatus::FirstUse;
+        }
+        None => {
+            known_hosts::pin(
+                host,
+                port,
+                &fingerprint,
+                Some(expires_at),
+                summary.first_seen,
+            )
+            .map_err(NavigateError::StorageError)?;
+            summary.pin_status = PinStatus::FirstUse;
+        }
+        Some(known) if known.fingerprint == fingerprint => {
+            if !read_only {
+                known_hosts::touch(host, port, Some(expires_at))
+                    .map_err(NavigateError::StorageError)?;
+            }
+            summary.first_seen = known.first_seen;
+            summary.pin_status = PinStatus::Matched;
+        }
+        // An expired pin no longer vouches for anything, so the new certificate replaces it
+        Some(known)
+            if known.expires_at.is_some_and(|expiry| expiry < now())
+                || policy == TlsPolicy::AcceptChanged =>
+        {
+            if read_only {
+                pin_privately(host, port, &fingerprint, expires_at, known.first_seen);
+            } else {
+                known_hosts::pin(host, port, &fingerprint, Some(expires_at), known.first_seen)
+                    .map_err(NavigateError::StorageError)?;
+                tls::forget_sessions(host);
+                log(LogLevel::Warn, "tls", || {
+                    format!(
+                        "Pin of {}:{} replaced: {} by {}",
+                        host, port, known.fingerprint, fingerprint
+                    )
+                });
+            }
+            summary.first_seen = known.first_seen;
+            summary.pin_status = PinStatus::Replaced;
+        }
+        Some(known) => {
+            let refused = RefusedCertificate {
+                fingerprint: fingerprint.clone(),
+                expires_at,
+            };
+            REFUSED
+                .lock()
+                .unwrap()
+                .insert((host.to_ascii_lowercase(), port), refused);
+            // A pin about to expire makes a renewed certificate the likely explanation
+            let renewal = known
+                .expires_at
+                .filter(|expiry| *expiry - now() < EXPIRY_WARNING_SECS)
+                .map(|expiry| {
+                    format!(
+                        "; the pinned certificate expires in {} days, so this may be a renewal",
+                        (expiry - now()) / (24 * 60 * 60)
+                    )
+                })
+                .unwrap_or_default();
+            return Err(NavigateError::FingerprintMismatch(format!(
+                "{}: {}:{} presented a certificate with fingerprint {}, but {} is pinned{}",
+                FINGERPRINT_MISMATCH_ERROR_PREFIX,
+                host,
+                port,
+                fingerprint,
+                known.fingerprint,
+                renewal
+            )));
+        }
+    }
+    report_certificate(summary);
+    Ok(())
+}
+
+/// The in-memory pin of a host met by a private navigation
+fn private_pin(host: &str, port: u16) -> Option<KnownHost> {
+    PRIVATE_PINS
+        .lock()
+        .unwrap()
+        .get(&(host.to_ascii_lowercase(), port))
+        .cloned()
+}
+
+/// Pin a certificate in memory for the rest of the private session
+fn pin_privately(host: &str, port: u16, fingerprint: &str, expires_at: i64, first_seen: i64) {
+    let host = host.to_ascii_lowercase();
+    let pin = KnownHost {
+        host: host.clone(),
+        port,
+        fingerprint: fingerprint.to_string(),
+        expires_at: Some(expires_at),
+        first_seen,
+        last_seen: now(),
+    };
+    PRIVATE_PINS.lock().unwrap().insert((host, port), pin);
+}
+
+/// Forget the certificates pinned in memory by private navigations, once the last private tab
+/// closes; returns how many were pinned
+pub fn end_private_session() -> u32 {
+    let mut pins = PRIVATE_PINS.lock().unwrap();
+    let count = pins.len() as u32;
+    pins.clear();
+    count
+}
+
+/// Pin the certificate a host last presented in place of its pinned one, after the user chose
+/// to trust it, e.g. a renewal shortly before the old pin's expiry; the host keeps its first
+/// seen time. Returns how many of the host's ports were re-pinned.
+pub fn accept_new_certificate(host: &str) -> Result<u32, String> {
+    let host = host.trim().to_ascii_lowercase();
+    let accepted: Vec<_> = REFUSED
+        .lock()
+        .unwrap()
+        .extract_if(|(refused_host, _), _| *refused_host == host)
+        .collect();
+    if accepted.is_empty() {
+        return Err(format!(
+            "{} has no changed certificate waiting to be accepted",
+            host
+        ));
+    }
+    for ((_, port), refused) in &accepted {
+        let fingerprint = &refused.fingerprint;
+        // A certificate refused for differing from an in-memory pin replaces only that pin,
+        // keeping the host out of the stored ones
+        if let Some(private) = private_pin(&host, *port) {
+            pin_privately(
+                &host,
+                *port,
+                fingerprint,
+                refused.expires_at,
+                private.first_seen,
+            );
+            continue;
+        }
+        let first_seen =
+            known_hosts::get_known_host(&host, *port)?.map_or_else(now, |known| known.first_seen);
+        known_hosts::pin(
+            &host,
+            *port,
+            fingerprint,
+            Some(refused.expires_at),
+            first_seen,
+        )?;
+        tls::forget_sessions(&host);
+        log(LogLevel::Warn, "tls", || {
+            format!(
+                "Pin of {}:{} replaced by the user: {}",
+                host, port, fingerprint
+            )
+        });
+    }
+    Ok(accepted.len() as u32)
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use url::Url;
+
+use crate::api::storage::frecency::{self, Frecency};
+use crate::api::storage::{favicons, history};
+
+/// A frequently and recently visited page or host for the new-tab speed dial
+#[derive(Debug, Clone)]
+pub struct TopSite {
+    pub url: String,
+    pub host: String,
+    pub title: Option<String>,
+    pub favicon: Option<String>,
+    pub visit_count: u32,
+    pub last_visited: i64,
+    /// Frecency: each visit weighs 1, halving every two weeks
+    pub score: f64,
+}
+
+/// The highest-frecency pages
+pub fn top_pages(limit: u32) -> Result<Vec<TopSite>, String> {
+    let mut sites = Vec::new();
+    for page in frecency::top(limit)? {
+        let Some(host) = Url::parse(&page.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+        else {
+            continue;
+        };
+        sites.push(site(page, host)?);
+    }
+    Ok(sites)
+}
+
+/// The highest-frecency hosts, each linked to its root page; a host's frecency is that of all
+/// its pages together
+pub fn top_hosts(limit: u32) -> Result<Vec<TopSite>, String> {
+    let mut hosts: HashMap<String, (Frecency, String)> = HashMap::new();
+    for page in frecency::top(u32::MAX)? {
+        let Some(mut root) = Url::parse(&page.url).ok().filter(Url::has_host) else {
+            continue;
+        };
+        root.set_path("/");
+        root.set_query(None);
+        let host = root.host_str().unwrap_or_default().to_string();
+        let (total, _) = hosts.entry(root.to_string()).or_insert_with_key(|root| {
+            let total = Frecency {
+                url: root.clone(),
+                visit_count: 0,
+                last_visited: page.last_visited,
+                score: 0.0,
+            };
+            (total, host)
+        });
+        total.visit_count += page.visit_count;
+        total.last_visited = total.last_visited.max(page.last_visited);
+        total.score += page.score;
+    }
+
+    let mut hosts: Vec<(Frecency, String)> = hosts.into_values().collect();
+    hosts.sort_by(|(a, _), (b, _)| b.score.total_cmp(&a.score));
+    hosts.truncate(limit as usize);
+    hosts
+        .into_iter()
+        .map(|(total, host)| site(total, host))
+        .collect()
+}
+
+/// A top site for a page or host root, titled like the page was when last visited; host
+/// entries thereby take the title of the root page only
+fn site(frecency: Frecency, host: String) -> Result<TopSite, String> {
+    Ok(TopSite {
+        title: history::latest_title(&frecency.url)?,
+        favicon: favicons::get_favicon(&host)?,
+        url: frecency.url,
+        host,
+        visit_count: frecency.visit_count,
+        last_visited: frecency.last_visited,
+        score: frecency.score,
+    })
+}
@@ -0,0 +1,40 @@
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::api::functions::digest::sha256_hex;
+use crate::api::storage::now;
+use crate::api::storage::trusted_cas::{self, TrustedCa};
+
+/// Add the CA certificates of a PEM file, e.g. a community's private CA, to the user trust
+/// store, which strict hosts' certificates must chain to. Every certificate in the file must
+/// be a CA certificate, or none is imported; importing one again keeps a single copy.
+pub fn import_ca_certificates(pem: &str) -> Result<Vec<TrustedCa>, String> {
+    let blocks = pem::parse_many(pem).map_err(|e| format!("Invalid PEM: {}", e))?;
+    let mut imported: Vec<(TrustedCa, &[u8])> = Vec::new();
+    for block in blocks.iter().filter(|block| block.tag() == "CERTIFICATE") {
+        let der = block.contents();
+        let (_, certificate) =
+            X509Certificate::from_der(der).map_err(|e| format!("Invalid certificate: {}", e))?;
+        let subject = certificate.subject().to_string();
+        if !certificate.is_ca() {
+            return Err(format!("Not a CA certificate: {}", subject));
+        }
+        let fingerprint = sha256_hex(der);
+        if imported.iter().any(|(ca, _)| ca.fingerprint == fingerprint) {
+            continue;
+        }
+        let ca = TrustedCa {
+            fingerprint,
+            subject,
+            not_after: certificate.validity().not_after.timestamp(),
+            added_at: now(),
+        };
+        imported.push((ca, der));
+    }
+    if imported.is_empty() {
+        return Err("No certificates found in PEM".to_string());
+    }
+    for (ca, der) in &imported {
+        trusted_cas::add(ca, der)?;
+    }
+    Ok(imported.into_iter().map(|(ca, _)| ca).collect())
+}
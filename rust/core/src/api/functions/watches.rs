@@ -0,0 +1,48 @@
+use crate::api::functions::navigate_internal::fetch_with_options;
+use crate::api::models::fetch_options::FetchOptions;
+use crate::api::storage::now;
+use crate::api::storage::watches::{self, WatchedPage};
+
+/// Least time between two checks of one watched page, however often updates are asked for
+const MIN_CHECK_INTERVAL_SECS: i64 = 15 * 60;
+
+/// Refetch the watched pages not checked in the last 15 minutes, one at a time and within the
+/// per-host limits of background requests, and return every watched page that changed since
+/// it was last visited, newest change first. The fetches are not added to history; a page that
+/// fails does not stop the others and keeps its error.
+pub async fn check_for_updates() -> Result<Vec<WatchedPage>, String> {
+    let options = FetchOptions {
+        background: true,
+        ..FetchOptions::default()
+    };
+    for watch in watches::list_watched_pages()? {
+        if watch
+            .checked_at
+            .is_some_and(|checked_at| now() - checked_at < MIN_CHECK_INTERVAL_SECS)
+        {
+            continue;
+        }
+        match fetch_with_options(watch.url.clone(), &options).await {
+            // The cached copy served while the host is unreachable says nothing about changes
+            Ok(response) if response.cached_at.is_some() => {
+                watches::record_error(watch.id, "Host unreachable; served from the cache")?
+            }
+            Ok(response) if response.is_success() => {
+                watches::record_check(watch.id, &response.body_hash)?
+            }
+            Ok(response) => watches::record_error(
+                watch.id,
+                &format!(
+                    "Server answered {} {}",
+                    response.status.unwrap_or_default(),
+                    response.meta.unwrap_or_default()
+                ),
+            )?,
+            Err(e) => watches::record_error(watch.id, &e.to_string())?,
+        }
+    }
+    Ok(watches::list_watched_pages()?
+        .into_iter()
+        .filter(|watch| watch.changed_at.is_some())
+        .collect())
+}
@@ -0,0 +1,6 @@
+pub mod functions;
+pub mod models;
+pub mod parsers;
+pub mod protocols;
+pub mod storage;
+pub mod transport;
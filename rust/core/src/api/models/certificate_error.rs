@@ -0,0 +1,47 @@
+use crate::api::models::error_info::RecoveryAction;
+
+/// Which client certificate problem a Gemini 6x response reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificateErrorKind {
+    /// 60 and unassigned 6x codes: the page needs a client certificate
+    Required,
+    /// 61: the certificate is valid but not allowed to see the page
+    NotAuthorized,
+    /// 62: the certificate itself was rejected, e.g. because it expired
+    NotValid,
+}
+
+/// A Gemini 6x response together with the identity that was presented, so the UI can offer
+/// switching identities for 61 and renewing the presented one for 62
+#[derive(Debug, Clone)]
+pub struct CertificateError {
+    pub kind: CertificateErrorKind,
+    /// Identity presented with the request, or None when the request carried no certificate
+    pub identity_id: Option<String>,
+    /// The server's explanation from the meta line
+    pub message: String,
+    pub suggested_action: RecoveryAction,
+}
+
+impl CertificateError {
+    /// The certificate error a response status reports, if it is in the 6x range
+    pub fn from_status(status: u8, meta: &str, identity_id: Option<String>) -> Option<Self> {
+        let kind = match status {
+            61 => CertificateErrorKind::NotAuthorized,
+            62 => CertificateErrorKind::NotValid,
+            60..=69 => CertificateErrorKind::Required,
+            _ => return None,
+        };
+        // Without a presented identity there is nothing to renew, only one to choose
+        let suggested_action = match (kind, &identity_id) {
+            (CertificateErrorKind::NotValid, Some(_)) => RecoveryAction::RenewIdentity,
+            _ => RecoveryAction::ChooseIdentity,
+        };
+        Some(CertificateError {
+            kind,
+            identity_id,
+            message: meta.to_string(),
+            suggested_action,
+        })
+    }
+}
@@ -0,0 +1,260 @@
+/// Stable identifier of a failure, independent of the English error text; each `NavigateError`
+/// variant has its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    UrlInvalid,
+    UrlUnsupportedScheme,
+    UrlSchemeDisabled,
+    UrlBlocked,
+    /// The host's robots.txt disallows a fetch the user did not ask for
+    UrlDisallowed,
+    NetDnsFailed,
+    /// Connecting took longer than the connect timeout
+    NetTimeout,
+    NetRefused,
+    NetConnectFailed,
+    NetConnectionLost,
+    /// Offline mode is on and the page has no cached copy
+    NetOffline,
+    /// The server stopped sending for longer than the read timeout, or the request outlasted
+    /// its deadline
+    NetReadTimeout,
+    ProxyUnreachable,
+    ProxyAuthFailed,
+    ProxyFailed,
+    GatewayFailed,
+    /// A response outgrew the body limit or the decompression limits
+    ContentTooLarge,
+    TlsHandshakeFailed,
+    TlsFingerprintMismatch,
+    /// The server certificate is expired or not yet valid by more than the clock skew tolerance
+    TlsCertificateDate,
+    /// Strict validation refused the server certificate, e.g. self-signed or for another host
+    TlsCertificateInvalid,
+    StoreFailed,
+    /// The identity to present could not be used, e.g. while the key store is locked
+    IdentityFailed,
+    NotFound,
+    InputInvalid,
+    /// The caller cancelled the request
+    Cancelled,
+    /// The server answered something that is not valid for its protocol
+    ResponseInvalid,
+    /// Gemini 1x
+    GemInputRequired,
+    /// Gemini 3x
+    GemRedirect,
+    /// Gemini 4x and 5x
+    GemServerError,
+    /// Gemini 6x
+    GemCertificateRequired,
+    /// A Gemini server stopped sending for longer than the read timeout
+    GemTimeout,
+    /// The Gopher item type names no document to fetch, e.g. a telnet session or an info line
+    GopherBadType,
+    /// The Gopher selector would span lines
+    GopherBadSelector,
+    Unknown,
+}
+
+impl ErrorCode {
+    /// Name of the code, e.g. "NET_TIMEOUT"; never changes once released
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::UrlInvalid => "URL_INVALID",
+            ErrorCode::UrlUnsupportedScheme => "URL_UNSUPPORTED_SCHEME",
+            ErrorCode::UrlSchemeDisabled => "URL_SCHEME_DISABLED",
+            ErrorCode::UrlBlocked => "URL_BLOCKED",
+            ErrorCode::UrlDisallowed => "URL_DISALLOWED",
+            ErrorCode::NetDnsFailed => "NET_DNS_FAILED",
+            ErrorCode::NetTimeout => "NET_TIMEOUT",
+            ErrorCode::NetRefused => "NET_REFUSED",
+            ErrorCode::NetConnectFailed => "NET_CONNECT_FAILED",
+            ErrorCode::NetConnectionLost => "NET_CONNECTION_LOST",
+            ErrorCode::NetOffline => "NET_OFFLINE",
+            ErrorCode::NetReadTimeout => "NET_READ_TIMEOUT",
+            ErrorCode::ProxyUnreachable => "PROXY_UNREACHABLE",
+            ErrorCode::ProxyAuthFailed => "PROXY_AUTH_FAILED",
+            ErrorCode::ProxyFailed => "PROXY_FAILED",
+            ErrorCode::GatewayFailed => "GATEWAY_FAILED",
+            ErrorCode::ContentTooLarge => "CONTENT_TOO_LARGE",
+            ErrorCode::TlsHandshakeFailed => "TLS_HANDSHAKE_FAILED",
+            ErrorCode::TlsFingerprintMismatch => "TLS_FPR_MISMATCH",
+            ErrorCode::TlsCertificateDate => "TLS_CERT_DATE",
+            ErrorCode::TlsCertificateInvalid => "TLS_CERT_INVALID",
+            ErrorCode::StoreFailed => "STORE_FAILED",
+            ErrorCode::IdentityFailed => "IDENTITY_FAILED",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::InputInvalid => "INPUT_INVALID",
+            ErrorCode::Cancelled => "REQUEST_CANCELLED",
+            ErrorCode::ResponseInvalid => "RESPONSE_INVALID",
+            ErrorCode::GemInputRequired => "GEM_INPUT_REQUIRED",
+            ErrorCode::GemRedirect => "GEM_REDIRECT",
+            ErrorCode::GemServerError => "GEM_SERVER_ERROR",
+            ErrorCode::GemCertificateRequired => "GEM_CERT_REQUIRED",
+            ErrorCode::GemTimeout => "GEM_TIMEOUT",
+            ErrorCode::GopherBadType => "GOPHER_BAD_TYPE",
+            ErrorCode::GopherBadSelector => "GOPHER_BAD_SELECTOR",
+            ErrorCode::Unknown => "UNKNOWN",
+        }
+    }
+
+    /// Numeric form of the code, grouped by hundreds: 1xx URL, 2xx network, 3xx TLS,
+    /// 4xx storage, 5xx identities, 6xx other requests, 7xx Gemini, 8xx Gopher, 999 unknown
+    pub fn number(self) -> u32 {
+        match self {
+            ErrorCode::UrlInvalid => 100,
+            ErrorCode::UrlUnsupportedScheme => 101,
+            ErrorCode::UrlSchemeDisabled => 102,
+            ErrorCode::UrlBlocked => 103,
+            ErrorCode::UrlDisallowed => 104,
+            ErrorCode::NetDnsFailed => 200,
+            ErrorCode::NetTimeout => 201,
+            ErrorCode::NetRefused => 202,
+            ErrorCode::NetConnectFailed => 203,
+            ErrorCode::NetConnectionLost => 204,
+            ErrorCode::NetOffline => 205,
+            ErrorCode::NetReadTimeout => 206,
+            ErrorCode::ProxyUnreachable => 210,
+            ErrorCode::ProxyAuthFailed => 211,
+            ErrorCode::ProxyFailed => 212,
+            ErrorCode::GatewayFailed => 220,
+            ErrorCode::ContentTooLarge => 230,
+            ErrorCode::TlsHandshakeFailed => 300,
+            ErrorCode::TlsFingerprintMismatch => 301,
+            ErrorCode::TlsCertificateDate => 302,
+            ErrorCode::TlsCertificateInvalid => 303,
+            ErrorCode::StoreFailed => 400,
+            ErrorCode::IdentityFailed => 502,
+            ErrorCode::NotFound => 600,
+            ErrorCode::InputInvalid => 601,
+            ErrorCode::Cancelled => 602,
+            ErrorCode::ResponseInvalid => 603,
+            ErrorCode::GemInputRequired => 700,
+            ErrorCode::GemRedirect => 701,
+            ErrorCode::GemServerError => 702,
+            ErrorCode::GemCertificateRequired => 703,
+            ErrorCode::GemTimeout => 704,
+            ErrorCode::GopherBadType => 800,
+            ErrorCode::GopherBadSelector => 801,
+            ErrorCode::Unknown => 999,
+        }
+    }
+
+    /// Whether the same request may succeed if simply repeated
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::NetDnsFailed
+                | ErrorCode::NetTimeout
+                | ErrorCode::NetRefused
+                | ErrorCode::NetConnectFailed
+                | ErrorCode::NetConnectionLost
+                | ErrorCode::NetReadTimeout
+                | ErrorCode::ProxyUnreachable
+                | ErrorCode::GatewayFailed
+                | ErrorCode::TlsHandshakeFailed
+                | ErrorCode::StoreFailed
+                | ErrorCode::GemTimeout
+        )
+    }
+
+    /// Whether the user can fix the cause, e.g. by correcting input or changing a setting
+    pub fn is_user_actionable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::UrlInvalid
+                | ErrorCode::UrlUnsupportedScheme
+                | ErrorCode::UrlSchemeDisabled
+                | ErrorCode::UrlBlocked
+                | ErrorCode::NetOffline
+                | ErrorCode::TlsFingerprintMismatch
+                | ErrorCode::TlsCertificateDate
+                | ErrorCode::TlsCertificateInvalid
+                | ErrorCode::ProxyUnreachable
+                | ErrorCode::ProxyAuthFailed
+                | ErrorCode::ProxyFailed
+                | ErrorCode::InputInvalid
+                | ErrorCode::GemInputRequired
+                | ErrorCode::GemCertificateRequired
+                | ErrorCode::GopherBadSelector
+        )
+    }
+
+    /// The one action worth a button on the error page, if any
+    pub fn suggested_action(self) -> Option<RecoveryAction> {
+        match self {
+            ErrorCode::UrlBlocked | ErrorCode::TlsFingerprintMismatch => {
+                Some(RecoveryAction::AddException)
+            }
+            ErrorCode::UrlSchemeDisabled => Some(RecoveryAction::EnableProtocol),
+            ErrorCode::ProxyUnreachable | ErrorCode::ProxyAuthFailed | ErrorCode::ProxyFailed => {
+                Some(RecoveryAction::CheckProxy)
+            }
+            ErrorCode::GemInputRequired => Some(RecoveryAction::ProvideInput),
+            ErrorCode::GemCertificateRequired => Some(RecoveryAction::ChooseIdentity),
+            code if code.is_retryable() => Some(RecoveryAction::Retry),
+            _ => None,
+        }
+    }
+}
+
+/// What the UI can offer the user after a failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryAction {
+    /// Try the same request again
+    Retry,
+    /// Make an exception for what refused the URL: a blocklist rule or a changed certificate
+    AddException,
+    /// Turn the disabled protocol back on
+    EnableProtocol,
+    /// Ask the user for the input a Gemini 1x response requested
+    ProvideInput,
+    /// Let the user present a client certificate for a Gemini 6x response, or switch to another
+    ChooseIdentity,
+    /// Issue a new certificate for the identity the server found invalid, e.g. because it expired
+    RenewIdentity,
+    /// Open the proxy settings
+    CheckProxy,
+}
+
+/// The action a Gemini response status asks of the user: input for 1x, a client certificate
+/// for 6x (a renewed one for 62, certificate not valid), and a retry for temporary 4x failures
+pub fn suggested_action_for_status(status: u8) -> Option<RecoveryAction> {
+    match status {
+        10..=19 => Some(RecoveryAction::ProvideInput),
+        40..=49 => Some(RecoveryAction::Retry),
+        62 => Some(RecoveryAction::RenewIdentity),
+        60..=69 => Some(RecoveryAction::ChooseIdentity),
+        _ => None,
+    }
+}
+
+/// An error message with its stable code, for localized messages and recovery actions
+#[derive(Debug, Clone)]
+pub struct ErrorInfo {
+    pub code: ErrorCode,
+    /// `code` as its stable string, e.g. "NET_TIMEOUT"
+    pub name: String,
+    /// `code` as its stable number, e.g. 201
+    pub number: u32,
+    /// The original English message
+    pub message: String,
+    pub retryable: bool,
+    pub user_actionable: bool,
+    pub suggested_action: Option<RecoveryAction>,
+}
+
+impl ErrorInfo {
+    pub fn new(code: ErrorCode, message: String) -> Self {
+        ErrorInfo {
+            code,
+            name: code.as_str().to_string(),
+            number: code.number(),
+            message,
+            retryable: code.is_retryable(),
+            user_actionable: code.is_user_actionable(),
+            suggested_action: code.suggested_action(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+/// Per-request switches for the navigation pipeline
+#[derive(Debug, Clone, Default)]
+pub struct FetchOptions {
+    /// Private (incognito) request: nothing about it is written to the profile database, so
+    /// certificates are checked against the TOFU pins without pinning new ones, and no
+    /// identity is presented but the one named in `identity_id`.
+    /// Tabs opened in private mode pass this on every navigation.
+    pub private: bool,
+    /// Serve a fresh cached copy when there is one, e.g. for back and forward navigation
+    pub use_cache: bool,
+    /// The caller already shows this page (pull-to-refresh): when the refetched body matches
+    /// the cached copy, return it with `unchanged` set instead of sending the body again
+    pub revalidate: bool,
+    /// When a bare host typed without a scheme refuses Gemini connections, try Gopher and
+    /// then Finger on it before falling back to search
+    pub probe_protocols: bool,
+    /// Requested by a background task rather than the user, e.g. a cache refresh: the response
+    /// is cached but no visit is added to history
+    pub background: bool,
+    /// Identity (client certificate) to present, for protocols that support them
+    pub identity_id: Option<String>,
+    /// Seconds to wait for a connection, or None for the default of 10
+    pub connect_timeout_secs: Option<u32>,
+    /// Seconds to wait for data once connected, or None to wait as long as the server keeps
+    /// the connection open
+    pub read_timeout_secs: Option<u32>,
+    /// Seconds the whole request may take, redirects and protocol probes included
+    pub deadline_secs: Option<u32>,
+    /// Bytes of body the response may have before the request fails, or None for no limit
+    pub max_body_bytes: Option<u64>,
+}
@@ -0,0 +1,150 @@
+use crate::api::functions::content_router::{is_gemtext, route_url, Renderer};
+use crate::api::functions::feed_discovery::FeedCandidate;
+use crate::api::models::certificate_error::CertificateError;
+use crate::api::models::navigation_result::{CertificateSummary, RequestTimings};
+use crate::api::models::server_failure::ServerFailure;
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// MIME type used internally for Gopher menus, which have no registered type
+pub const GOPHER_MENU_MIME: &str = "application/gopher-menu";
+
+/// A fetched page together with the metadata the UI needs to render it
+#[derive(Debug, Clone)]
+pub struct FetchResponse {
+    pub url: String,
+    /// URLs that answered with a redirect on the way to `url`, in the order they were
+    /// followed and starting with the requested one; empty when no redirect was followed
+    pub redirects: Vec<String>,
+    /// Protocol status code, for protocols that have one (Gemini)
+    pub status: Option<u8>,
+    /// Raw meta/header text accompanying the status
+    pub meta: Option<String>,
+    pub mime: String,
+    /// How the UI should present the body, decided from the scheme, MIME type, and item type
+    pub renderer: Renderer,
+    pub body: String,
+    /// SHA-256 of the body as lowercase hex, for detecting unchanged pages
+    pub body_hash: String,
+    /// Set on revalidation when the body matched the cached copy; `body` is then left empty
+    pub unchanged: bool,
+    /// Set when the server kept the connection open and the body is what arrived before it
+    /// went quiet or the read deadline passed, so it may be incomplete
+    pub truncated: bool,
+    /// Served from the cache after its expiry, e.g. while offline; `cached_at` tells how old
+    pub stale: bool,
+    /// Page title taken from the first gemtext heading
+    pub title: Option<String>,
+    pub feeds: Vec<FeedCandidate>,
+    /// Cached /favicon.txt emoji of the capsule, for its tab and bookmarks
+    pub favicon: Option<String>,
+    /// The #fragment of the requested URL, which is never sent to the server
+    pub fragment: Option<String>,
+    /// Document line the fragment resolved to, for the UI to scroll to
+    pub fragment_line: Option<u32>,
+    /// When the response was fetched, if it was served from the cache
+    pub cached_at: Option<i64>,
+    /// Request id of the navigation events published for this fetch
+    pub request_id: u64,
+    /// Host to show in the address bar, in punycode when it may impersonate another host
+    pub display_host: String,
+    /// Set when the host mixes scripts or looks like a host the user has visited before
+    pub spoofing_warning: bool,
+    /// Set when the server certificate appeared expired or not yet valid and was accepted only
+    /// because a wrong device clock may explain it
+    pub clock_skew_warning: bool,
+    /// Scheme that answered when a bare host was probed after Gemini could not connect
+    pub probed_scheme: Option<String>,
+    /// Set for Gemini 6x responses: which certificate problem it was and what was presented
+    pub certificate_error: Option<CertificateError>,
+    /// Set for Gemini 4x and 5x responses, whose body is then a generated error page
+    pub server_failure: Option<ServerFailure>,
+    /// The server certificate the response came over, when it was fetched over TLS
+    pub certificate: Option<CertificateSummary>,
+    /// Milliseconds from the start of the navigation until the response was complete
+    pub duration_ms: u64,
+    /// How `duration_ms` divides into resolving, connecting, waiting, and transferring
+    pub timings: RequestTimings,
+}
+
+impl FetchResponse {
+    /// Build a response, running the per-page analysis passes over the body
+    pub fn new(url: String, mime: String, body: String) -> Self {
+        let feeds = crate::api::functions::feed_discovery::discover_feeds(&url, &mime, &body);
+        let title = page_title(&mime, &body);
+        let body_hash = crate::api::functions::digest::sha256_hex(body.as_bytes());
+        let renderer = route_url(&url, &mime);
+        FetchResponse {
+            url,
+            redirects: Vec::new(),
+            status: None,
+            meta: None,
+            mime,
+            renderer,
+            body,
+            body_hash,
+            unchanged: false,
+            truncated: false,
+            stale: false,
+            title,
+            feeds,
+            favicon: None,
+            fragment: None,
+            fragment_line: None,
+            cached_at: None,
+            request_id: 0,
+            display_host: String::new(),
+            spoofing_warning: false,
+            clock_skew_warning: false,
+            probed_scheme: None,
+            certificate_error: None,
+            server_failure: None,
+            certificate: None,
+            duration_ms: 0,
+            timings: RequestTimings::default(),
+        }
+    }
+
+    /// Whether the request succeeded with a page, as opposed to a prompt, redirect, or error status
+    pub fn is_success(&self) -> bool {
+        self.status.is_none_or(|status| (20..30).contains(&status))
+    }
+
+    /// Record the status line the server answered with
+    pub fn with_header(mut self, status: u8, meta: String) -> Self {
+        self.status = Some(status);
+        self.meta = Some(meta);
+        self
+    }
+
+    /// Mark the response as served from the cache
+    pub fn with_cached_at(mut self, fetched_at: i64) -> Self {
+        self.cached_at = Some(fetched_at);
+        self
+    }
+
+    /// Attach the fragment of the requested URL and resolve it against the page headings
+    pub fn with_fragment(mut self, fragment: Option<String>) -> Self {
+        if let Some(fragment) = fragment.as_deref() {
+            if self.renderer == Renderer::Gemtext {
+                let document = crate::api::parsers::gemtext::parse_gemtext(&self.body);
+                self.fragment_line =
+                    crate::api::functions::anchors::resolve_fragment(&document, fragment);
+            }
+        }
+        self.fragment = fragment;
+        self
+    }
+}
+
+/// The first heading of a gemtext page, which Gemini clients use as its title
+fn page_title(mime: &str, body: &str) -> Option<String> {
+    if !is_gemtext(mime) {
+        return None;
+    }
+    crate::api::parsers::gemtext::parse_gemtext(body)
+        .into_iter()
+        .find_map(|line| match line {
+            GemtextLine::Heading { text, .. } if !text.is_empty() => Some(text),
+            _ => None,
+        })
+}
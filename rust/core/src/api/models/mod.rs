@@ -0,0 +1,10 @@
+pub mod certificate_error;
+pub mod error_info;
+pub mod fetch_options;
+pub mod fetch_response;
+pub mod navigate_error;
+pub mod navigation_result;
+pub mod navigation_event;
+pub mod server_failure;
+pub mod stream_event;
+pub mod tls_failure;
@@ -0,0 +1,312 @@
+use std::fmt;
+use std::io;
+
+use crate::api::models::certificate_error::CertificateError;
+use crate::api::models::error_info::{ErrorCode, ErrorInfo};
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::server_failure::ServerFailure;
+
+/// Why a navigation did not produce a page, for tailored error screens; each variant keeps
+/// the English message (or the server's meta line) for display
+#[derive(Debug, Clone)]
+pub enum NavigateError {
+    /// The input is not a usable URL
+    InvalidUrl(String),
+    /// No protocol handler serves the scheme
+    UnsupportedScheme(String),
+    /// Refused locally: the user turned the protocol off
+    SchemeDisabled(String),
+    /// Refused locally: the URL matches a blocklist rule
+    Blocked(String),
+    /// Refused locally: the host's robots.txt disallows a fetch the user did not ask for
+    Disallowed(String),
+    /// Offline mode is on and the page has no cached copy
+    Offline(String),
+    DnsFailure(String),
+    ConnectTimeout(String),
+    /// The host refused the connection
+    ConnectionRefused(String),
+    /// The host could not be reached
+    ConnectionFailed(String),
+    /// The connection broke while sending the request or reading the response
+    ConnectionLost(String),
+    /// The server stopped sending for longer than the read timeout, or the request outlasted
+    /// its deadline
+    ReadTimeout(String),
+    /// A Gemini server stopped sending for longer than the read timeout
+    GeminiTimeout(String),
+    /// The SOCKS5 proxy could not be reached
+    ProxyUnreachable(String),
+    /// The SOCKS5 proxy refused the credentials, or needs some
+    ProxyAuthFailed(String),
+    /// The SOCKS5 proxy failed to connect to the host, or does not speak SOCKS5
+    ProxyError(String),
+    /// The WebSocket gateway failed
+    GatewayError(String),
+    /// The TLS handshake failed
+    TlsError(String),
+    /// The server's certificate changed from the pinned one
+    FingerprintMismatch(String),
+    /// The server's certificate is expired or not yet valid, past the clock skew tolerance
+    CertificateDate(String),
+    /// Strict validation refused the server's certificate; `tls_failures::certificate_failure`
+    /// tells why
+    CertificateRejected(String),
+    /// The server answered something that is not valid for its protocol
+    BadResponse(String),
+    /// The response outgrew the body limit or the decompression limits
+    TooLarge(String),
+    /// Something the request named does not exist, e.g. a local file or a newsgroup
+    NotFound(String),
+    /// The request cannot be sent as it is, e.g. a Finger query spanning lines
+    InvalidInput(String),
+    /// The Gopher item type names no document to fetch, e.g. a telnet session or an info line
+    GopherBadType(String),
+    /// The Gopher selector spans lines, which would send the server more than one request
+    GopherBadSelector(String),
+    /// The profile database failed
+    StorageError(String),
+    /// The identity to present could not be used, e.g. while the key store is locked
+    IdentityError(String),
+    /// The caller cancelled the navigation
+    Cancelled(String),
+    /// Gemini 3x: the page moved
+    Redirected {
+        target: String,
+        permanent: bool,
+    },
+    /// Gemini 1x: the server asks for input; sensitive input (11) should be masked
+    InputRequired {
+        prompt: String,
+        sensitive: bool,
+    },
+    /// Gemini 6x: a client certificate is needed or was refused
+    CertificateRequired(CertificateError),
+    /// Gemini 4x and 5x: the server could not serve the page, for now or for good
+    ServerError(ServerFailure),
+    Other(String),
+}
+
+impl NavigateError {
+    /// What a response that is not a page asks for, or None for a successful response
+    pub fn from_response(response: &FetchResponse) -> Option<Self> {
+        if response.is_success() {
+            return None;
+        }
+        match (&response.certificate_error, &response.server_failure) {
+            (Some(error), _) => Some(NavigateError::CertificateRequired(error.clone())),
+            (_, Some(failure)) => Some(NavigateError::ServerError(failure.clone())),
+            _ => Self::from_status(
+                response.status?,
+                response.meta.as_deref().unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// What a Gemini status line asks for, or None for a success (2x)
+    pub(crate) fn from_status(status: u8, meta: &str) -> Option<Self> {
+        Some(match status {
+            20..=29 => return None,
+            10..=19 => NavigateError::InputRequired {
+                prompt: meta.to_string(),
+                sensitive: status == 11,
+            },
+            30..=39 => NavigateError::Redirected {
+                target: meta.to_string(),
+                permanent: status == 31,
+            },
+            60..=69 => NavigateError::CertificateRequired(CertificateError::from_status(
+                status, meta, None,
+            )?),
+            40..=59 => NavigateError::ServerError(ServerFailure::from_status(status, meta)?),
+            _ => NavigateError::BadResponse(format!("Unknown status {} {}", status, meta)),
+        })
+    }
+
+    /// The stable code of the error, for localized messages and recovery actions
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            NavigateError::InvalidUrl(_) => ErrorCode::UrlInvalid,
+            NavigateError::UnsupportedScheme(_) => ErrorCode::UrlUnsupportedScheme,
+            NavigateError::SchemeDisabled(_) => ErrorCode::UrlSchemeDisabled,
+            NavigateError::Blocked(_) => ErrorCode::UrlBlocked,
+            NavigateError::Disallowed(_) => ErrorCode::UrlDisallowed,
+            NavigateError::Offline(_) => ErrorCode::NetOffline,
+            NavigateError::DnsFailure(_) => ErrorCode::NetDnsFailed,
+            NavigateError::ConnectTimeout(_) => ErrorCode::NetTimeout,
+            NavigateError::ConnectionRefused(_) => ErrorCode::NetRefused,
+            NavigateError::ConnectionFailed(_) => ErrorCode::NetConnectFailed,
+            NavigateError::ConnectionLost(_) => ErrorCode::NetConnectionLost,
+            NavigateError::ReadTimeout(_) => ErrorCode::NetReadTimeout,
+            NavigateError::GeminiTimeout(_) => ErrorCode::GemTimeout,
+            NavigateError::ProxyUnreachable(_) => ErrorCode::ProxyUnreachable,
+            NavigateError::ProxyAuthFailed(_) => ErrorCode::ProxyAuthFailed,
+            NavigateError::ProxyError(_) => ErrorCode::ProxyFailed,
+            NavigateError::GatewayError(_) => ErrorCode::GatewayFailed,
+            NavigateError::TlsError(_) => ErrorCode::TlsHandshakeFailed,
+            NavigateError::FingerprintMismatch(_) => ErrorCode::TlsFingerprintMismatch,
+            NavigateError::CertificateDate(_) => ErrorCode::TlsCertificateDate,
+            NavigateError::CertificateRejected(_) => ErrorCode::TlsCertificateInvalid,
+            NavigateError::BadResponse(_) => ErrorCode::ResponseInvalid,
+            NavigateError::TooLarge(_) => ErrorCode::ContentTooLarge,
+            NavigateError::NotFound(_) => ErrorCode::NotFound,
+            NavigateError::InvalidInput(_) => ErrorCode::InputInvalid,
+            NavigateError::GopherBadType(_) => ErrorCode::GopherBadType,
+            NavigateError::GopherBadSelector(_) => ErrorCode::GopherBadSelector,
+            NavigateError::StorageError(_) => ErrorCode::StoreFailed,
+            NavigateError::IdentityError(_) => ErrorCode::IdentityFailed,
+            NavigateError::Cancelled(_) => ErrorCode::Cancelled,
+            NavigateError::Redirected { .. } => ErrorCode::GemRedirect,
+            NavigateError::InputRequired { .. } => ErrorCode::GemInputRequired,
+            NavigateError::CertificateRequired(_) => ErrorCode::GemCertificateRequired,
+            NavigateError::ServerError(_) => ErrorCode::GemServerError,
+            NavigateError::Other(_) => ErrorCode::Unknown,
+        }
+    }
+
+    /// The error's code with its message; a server failure or certificate error keeps the
+    /// recovery action its status asks for
+    pub fn info(&self) -> ErrorInfo {
+        let mut info = ErrorInfo::new(self.code(), self.to_string());
+        match self {
+            NavigateError::CertificateRequired(error) => {
+                info.suggested_action = Some(error.suggested_action);
+            }
+            NavigateError::ServerError(failure) => {
+                info.retryable = failure.temporary;
+                info.suggested_action = failure.suggested_action;
+            }
+            _ => {}
+        }
+        info
+    }
+
+    /// A failed read or write on a connection: the error a layer below put in the I/O error,
+    /// such as a cancellation or an exceeded limit, or else what the I/O error kind tells
+    pub(crate) fn from_io(context: &str, error: io::Error) -> Self {
+        if let Some(inner) = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<NavigateError>())
+        {
+            return inner.clone();
+        }
+        let message = format!("{}: {}", context, error);
+        match error.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => {
+                NavigateError::ReadTimeout(message)
+            }
+            io::ErrorKind::ConnectionRefused => NavigateError::ConnectionRefused(message),
+            io::ErrorKind::InvalidData => NavigateError::BadResponse(message),
+            _ => NavigateError::ConnectionLost(message),
+        }
+    }
+
+    /// The same error with its message rewritten, e.g. to add where it happened; the responses
+    /// of Gemini 1x, 3x, 4x, 5x, and 6x keep the server's words
+    pub(crate) fn map_message(mut self, f: impl FnOnce(String) -> String) -> Self {
+        if let Some(message) = self.message_mut() {
+            *message = f(std::mem::take(message));
+        }
+        self
+    }
+
+    /// `map_message` putting `context` in front of the message
+    pub(crate) fn context(self, context: impl fmt::Display) -> Self {
+        self.map_message(|message| format!("{}: {}", context, message))
+    }
+
+    fn message_mut(&mut self) -> Option<&mut String> {
+        match self {
+            NavigateError::InvalidUrl(message)
+            | NavigateError::UnsupportedScheme(message)
+            | NavigateError::SchemeDisabled(message)
+            | NavigateError::Blocked(message)
+            | NavigateError::Disallowed(message)
+            | NavigateError::Offline(message)
+            | NavigateError::DnsFailure(message)
+            | NavigateError::ConnectTimeout(message)
+            | NavigateError::ConnectionRefused(message)
+            | NavigateError::ConnectionFailed(message)
+            | NavigateError::ConnectionLost(message)
+            | NavigateError::ReadTimeout(message)
+            | NavigateError::GeminiTimeout(message)
+            | NavigateError::ProxyUnreachable(message)
+            | NavigateError::ProxyAuthFailed(message)
+            | NavigateError::ProxyError(message)
+            | NavigateError::GatewayError(message)
+            | NavigateError::TlsError(message)
+            | NavigateError::FingerprintMismatch(message)
+            | NavigateError::CertificateDate(message)
+            | NavigateError::CertificateRejected(message)
+            | NavigateError::BadResponse(message)
+            | NavigateError::TooLarge(message)
+            | NavigateError::NotFound(message)
+            | NavigateError::InvalidInput(message)
+            | NavigateError::GopherBadType(message)
+            | NavigateError::GopherBadSelector(message)
+            | NavigateError::StorageError(message)
+            | NavigateError::IdentityError(message)
+            | NavigateError::Cancelled(message)
+            | NavigateError::Other(message) => Some(message),
+            NavigateError::Redirected { .. }
+            | NavigateError::InputRequired { .. }
+            | NavigateError::CertificateRequired(_)
+            | NavigateError::ServerError(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for NavigateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavigateError::InvalidUrl(message)
+            | NavigateError::UnsupportedScheme(message)
+            | NavigateError::SchemeDisabled(message)
+            | NavigateError::Blocked(message)
+            | NavigateError::Disallowed(message)
+            | NavigateError::Offline(message)
+            | NavigateError::DnsFailure(message)
+            | NavigateError::ConnectTimeout(message)
+            | NavigateError::ConnectionRefused(message)
+            | NavigateError::ConnectionFailed(message)
+            | NavigateError::ConnectionLost(message)
+            | NavigateError::ReadTimeout(message)
+            | NavigateError::GeminiTimeout(message)
+            | NavigateError::ProxyUnreachable(message)
+            | NavigateError::ProxyAuthFailed(message)
+            | NavigateError::ProxyError(message)
+            | NavigateError::GatewayError(message)
+            | NavigateError::TlsError(message)
+            | NavigateError::FingerprintMismatch(message)
+            | NavigateError::CertificateDate(message)
+            | NavigateError::CertificateRejected(message)
+            | NavigateError::BadResponse(message)
+            | NavigateError::TooLarge(message)
+            | NavigateError::NotFound(message)
+            | NavigateError::InvalidInput(message)
+            | NavigateError::GopherBadType(message)
+            | NavigateError::GopherBadSelector(message)
+            | NavigateError::StorageError(message)
+            | NavigateError::IdentityError(message)
+            | NavigateError::Cancelled(message)
+            | NavigateError::Other(message) => f.write_str(message),
+            NavigateError::Redirected { target, .. } => write!(f, "Redirected to {}", target),
+            NavigateError::InputRequired { prompt, .. } => write!(f, "Input required: {}", prompt),
+            NavigateError::CertificateRequired(error) => {
+                write!(f, "Client certificate required: {}", error.message)
+            }
+            NavigateError::ServerError(failure) => {
+                write!(f, "Server answered {}: {}", failure.status, failure.message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NavigateError {}
+
+/// The API functions built on navigation that keep plain messages fail with the error's text
+impl From<NavigateError> for String {
+    fn from(error: NavigateError) -> Self {
+        error.to_string()
+    }
+}
@@ -0,0 +1,50 @@
+/// Stage of a navigation reported on the event bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationEventKind {
+    /// The request was accepted and is about to be resolved
+    NavigationStarted,
+    /// The host name of a new connection is being looked up
+    Resolving,
+    /// A connection is being opened; `message` is the address when the host was looked up
+    Connecting,
+    /// The connection is open and its TLS handshake started
+    TlsHandshake,
+    /// A redirect was followed automatically; `url` is the new target
+    RedirectFollowed,
+    /// The response header arrived; `status` and `meta` are set
+    HeaderReceived,
+    /// More of the body arrived; `bytes` is the running total
+    BodyProgress,
+    /// The response is complete
+    NavigationFinished,
+    /// The request stopped with an error; `message` is set
+    NavigationFailed,
+}
+
+/// One event published on the navigation event bus
+#[derive(Debug, Clone)]
+pub struct NavigationEvent {
+    /// Identifies the request across all of its events
+    pub request_id: u64,
+    pub kind: NavigationEventKind,
+    pub url: String,
+    pub status: Option<u8>,
+    pub meta: Option<String>,
+    /// Body bytes received so far
+    pub bytes: u64,
+    pub message: Option<String>,
+}
+
+impl NavigationEvent {
+    pub fn new(request_id: u64, kind: NavigationEventKind, url: String) -> Self {
+        NavigationEvent {
+            request_id,
+            kind,
+            url,
+            status: None,
+            meta: None,
+            bytes: 0,
+            message: None,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::api::functions::content_router::{charset, Renderer};
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::server_failure::ServerFailure;
+
+/// How a server certificate compared with the one pinned for its host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinStatus {
+    /// No certificate was pinned for the host, and this one now is
+    FirstUse,
+    /// The certificate is the pinned one
+    Matched,
+    /// The certificate differs from the pinned one and replaced it, because the pin had expired
+    /// or the host's settings accept changed certificates
+    Replaced,
+    /// The host's settings skip verification, so the certificate was not checked against a pin
+    Unverified,
+    /// The host's settings are strict, and a certificate authority in the user trust store
+    /// issued the certificate, which is not pinned
+    CaVerified,
+}
+
+/// How near a server certificate is to the end of its validity. A capsule usually replaces its
+/// certificate around then, and the replacement is refused while the pin is still valid until
+/// the user accepts it with `accept_new_certificate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinExpiry {
+    Valid,
+    /// Expires within 30 days
+    ExpiringSoon,
+    /// Past its expiry, accepted because a wrong device clock may explain it or the host's
+    /// settings skip verification
+    Expired,
+}
+
+/// The server certificate a navigation was made over, as it was checked against the pin
+#[derive(Debug, Clone)]
+pub struct CertificateSummary {
+    /// SHA-256 of the certificate's public key as lowercase hex, as pinned in known_hosts
+    pub fingerprint: String,
+    pub subject: String,
+    pub issuer: String,
+    pub not_before: i64,
+    pub not_after: i64,
+    /// When a certificate was first pinned for the host
+    pub first_seen: i64,
+    pub pin_status: PinStatus,
+    pub expiry: PinExpiry,
+    /// TLS version the connection negotiated, e.g. "TLS 1.3"
+    pub tls_version: Option<String>,
+    /// Cipher suite the connection negotiated, by its IANA name
+    pub cipher_suite: Option<String>,
+}
+
+/// Where the time of a navigation went, measured on the connection that answered; phases the
+/// connection did not go through, e.g. resolving a host a proxy resolved, are None, and a
+/// response from the cache has none at all
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestTimings {
+    /// Resolving the host name to an address
+    pub dns_ms: Option<u64>,
+    /// Opening the TCP connection, or the connection through the proxy or gateway
+    pub connect_ms: Option<u64>,
+    pub tls_ms: Option<u64>,
+    /// From the connection being ready until the first byte of the response arrived
+    pub first_byte_ms: Option<u64>,
+    /// From the first byte until the response was complete
+    pub transfer_ms: Option<u64>,
+    /// Bytes of the request line sent to the server
+    pub bytes_sent: u64,
+    /// Bytes of the header and body received from the server
+    pub bytes_received: u64,
+}
+
+/// A finished navigation with the metadata history, the address bar, and the renderer share
+#[derive(Debug, Clone)]
+pub struct NavigationResult {
+    /// The URL that answered, after any redirects; the base for relative links
+    pub url: String,
+    /// URLs that answered with a redirect on the way to `url`, in the order they were
+    /// followed and starting with the requested one; empty when no redirect was followed
+    pub redirects: Vec<String>,
+    /// Scheme of the final URL
+    pub protocol: String,
+    /// Protocol status code, for protocols that have one (Gemini)
+    pub status: Option<u8>,
+    pub mime: String,
+    /// Character set from the MIME type, or the protocol's default when it names none
+    pub charset: Option<String>,
+    pub renderer: Renderer,
+    pub title: Option<String>,
+    /// Emoji from the capsule's /favicon.txt, for the tab; None until it has been fetched
+    pub favicon: Option<String>,
+    pub body: String,
+    /// Set when the server kept the connection open and the body may be incomplete
+    pub truncated: bool,
+    /// When the body was fetched, if it was served from the cache
+    pub cached_at: Option<i64>,
+    /// Served from the cache after its expiry, e.g. while offline or the host was unreachable
+    pub stale: bool,
+    /// Milliseconds from the start of the navigation until the response was complete
+    pub duration_ms: u64,
+    /// How `duration_ms` divides into resolving, connecting, waiting, and transferring
+    pub timings: RequestTimings,
+    /// Set when the response came over TLS from the network rather than from the cache
+    pub certificate: Option<CertificateSummary>,
+    /// Set for Gemini 4x and 5x responses, whose body is then a generated error page with retry
+    /// and cached copy links
+    pub server_failure: Option<ServerFailure>,
+    /// Request id of the navigation events and trace of this navigation
+    pub request_id: u64,
+}
+
+impl From<FetchResponse> for NavigationResult {
+    fn from(response: FetchResponse) -> Self {
+        let protocol = response
+            .url
+            .split_once(':')
+            .map_or("", |(scheme, _)| scheme)
+            .to_ascii_lowercase();
+        NavigationResult {
+            charset: charset(&response.mime),
+            url: response.url,
+            redirects: response.redirects,
+            protocol,
+            status: response.status,
+            mime: response.mime,
+            renderer: response.renderer,
+            title: response.title,
+            favicon: response.favicon,
+            body: response.body,
+            truncated: response.truncated,
+            cached_at: response.cached_at,
+            stale: response.stale,
+            duration_ms: response.duration_ms,
+            timings: response.timings,
+            certificate: response.certificate,
+            server_failure: response.server_failure,
+            request_id: response.request_id,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::api::models::error_info::RecoveryAction;
+
+/// Which failure a Gemini 4x or 5x response reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerFailureKind {
+    /// 40 and unassigned 4x codes
+    TemporaryFailure,
+    /// 41: the server is down for maintenance or overloaded
+    ServerUnavailable,
+    /// 42: the program generating the page failed
+    CgiError,
+    /// 43: the server could not complete a request it proxies
+    ProxyError,
+    /// 44: the client asks too often and should wait the seconds in the meta line
+    SlowDown,
+    /// 50 and unassigned 5x codes
+    PermanentFailure,
+    /// 51
+    NotFound,
+    /// 52: the page was removed for good
+    Gone,
+    /// 53: the server does not serve the requested host or protocol
+    ProxyRequestRefused,
+    /// 59: the server could not parse the request
+    BadRequest,
+}
+
+/// A Gemini 4x or 5x response, so the UI can tell a page that may come back from one that
+/// will not, and when it is worth trying again
+#[derive(Debug, Clone)]
+pub struct ServerFailure {
+    pub status: u8,
+    pub kind: ServerFailureKind,
+    /// The server's explanation from the meta line
+    pub message: String,
+    /// 4x: the same request may succeed later
+    pub temporary: bool,
+    /// Seconds the server asked to wait before trying again (44, slow down)
+    pub retry_after_secs: Option<u32>,
+    pub suggested_action: Option<RecoveryAction>,
+}
+
+impl ServerFailure {
+    /// The failure a response status reports, if it is in the 4x or 5x range
+    pub fn from_status(status: u8, meta: &str) -> Option<Self> {
+        let kind = match status {
+            41 => ServerFailureKind::ServerUnavailable,
+            42 => ServerFailureKind::CgiError,
+            43 => ServerFailureKind::ProxyError,
+            44 => ServerFailureKind::SlowDown,
+            40..=49 => ServerFailureKind::TemporaryFailure,
+            51 => ServerFailureKind::NotFound,
+            52 => ServerFailureKind::Gone,
+            53 => ServerFailureKind::ProxyRequestRefused,
+            59 => ServerFailureKind::BadRequest,
+            50..=59 => ServerFailureKind::PermanentFailure,
+            _ => return None,
+        };
+        let temporary = status < 50;
+        let retry_after_secs = (kind == ServerFailureKind::SlowDown)
+            .then(|| meta.trim().parse().ok())
+            .flatten();
+        Some(ServerFailure {
+            status,
+            kind,
+            message: meta.to_string(),
+            temporary,
+            retry_after_secs,
+            suggested_action: temporary.then_some(RecoveryAction::Retry),
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::api::parsers::gemtext::GemtextLine;
+
+/// What a stream event carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamEventKind {
+    /// The response header arrived; `status` and `meta` are set
+    Header,
+    /// A complete body line arrived; `text` is set, and `element` for a text/gemini body
+    Line,
+    /// Nothing arrived for the idle interval but the connection is still open
+    Idle,
+    /// The server closed the connection or the stream was cancelled
+    Finished,
+    /// The stream stopped because of an error; `text` is set
+    Failed,
+}
+
+/// One event delivered by a streaming fetch
+#[derive(Debug, Clone)]
+pub struct StreamEvent {
+    pub kind: StreamEventKind,
+    pub status: Option<u8>,
+    pub meta: Option<String>,
+    pub text: Option<String>,
+    /// The line parsed as gemtext, so a long document renders while it is still downloading
+    pub element: Option<GemtextLine>,
+    /// Total bytes read from the server so far
+    pub bytes_received: u64,
+}
+
+impl StreamEvent {
+    pub fn new(kind: StreamEventKind, bytes_received: u64) -> Self {
+        StreamEvent {
+            kind,
+            status: None,
+            meta: None,
+            text: None,
+            element: None,
+            bytes_received,
+        }
+    }
+
+    pub fn header(status: u8, meta: String, bytes_received: u64) -> Self {
+        StreamEvent {
+            status: Some(status),
+            meta: Some(meta),
+            ..StreamEvent::new(StreamEventKind::Header, bytes_received)
+        }
+    }
+
+    pub fn with_text(kind: StreamEventKind, text: String, bytes_received: u64) -> Self {
+        StreamEvent {
+            text: Some(text),
+            ..StreamEvent::new(kind, bytes_received)
+        }
+    }
+
+    /// Whether no further events follow this one
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.kind,
+            StreamEventKind::Finished | StreamEventKind::Failed
+        )
+    }
+}
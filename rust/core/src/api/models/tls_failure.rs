@@ -0,0 +1,41 @@
+/// Why a server certificate failed validation against the user trust store
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsFailureKind {
+    /// The certificate's validity period has ended
+    Expired,
+    /// The certificate's validity period has not started
+    NotYetValid,
+    /// The certificate signed itself and is not a trusted CA
+    SelfSigned,
+    /// None of the certificate's names (its subject alternative names) is the host's
+    NameMismatch,
+    /// The server sent no chain up to a self-signed root, so the certificate cannot be traced to
+    /// a trusted CA; usually an intermediate certificate missing from the server's configuration
+    IncompleteChain,
+    /// The chain ends at a root CA that is not in the user trust store
+    UntrustedIssuer,
+    /// The issuer revoked the certificate
+    Revoked,
+    /// A bad signature, an unsupported algorithm, an unreadable certificate, and the like
+    Other,
+}
+
+/// A server certificate refused by strict validation, with what the certificate says, so the
+/// security warning can explain the problem rather than report a failed connection
+#[derive(Debug, Clone)]
+pub struct TlsFailure {
+    pub kind: TlsFailureKind,
+    pub host: String,
+    /// The problem in plain words, e.g. "the certificate is for example.org, not example.com"
+    pub message: String,
+    /// DNS names and IP addresses the certificate is valid for
+    pub names: Vec<String>,
+    pub subject: String,
+    pub issuer: String,
+    /// Start of the validity period, in seconds since the Unix epoch
+    pub not_before: i64,
+    /// End of the validity period, in seconds since the Unix epoch
+    pub not_after: i64,
+    /// Certificates the server sent after its own
+    pub intermediates: u32,
+}
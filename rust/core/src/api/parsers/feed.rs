@@ -0,0 +1,251 @@
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::{Date, Month, OffsetDateTime};
+use url::Url;
+
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+
+/// A feed document reduced to what the aggregator keeps
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedFeed {
+    pub title: Option<String>,
+    pub entries: Vec<ParsedEntry>,
+}
+
+/// One entry of a feed, its link resolved against the feed URL
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedEntry {
+    pub url: String,
+    pub title: String,
+    /// Seconds since the Unix epoch, when the feed dates its entries
+    pub published: Option<i64>,
+}
+
+/// Parse an Atom feed, or None when the document is not one
+pub fn parse_atom(base: &Url, body: &str) -> Option<ParsedFeed> {
+    let (_, feed) = elements(body, "feed").into_iter().next()?;
+    let title = head_title(feed, "entry");
+    let entries = elements(feed, "entry")
+        .into_iter()
+        .filter_map(|(_, entry)| {
+            let link = elements(entry, "link")
+                .into_iter()
+                .map(|(tag, _)| tag)
+                .find(|tag| attribute(tag, "rel").is_none_or(|rel| rel == "alternate"))
+                .and_then(|tag| attribute(tag, "href"))
+                .or_else(|| child_text(entry, "id"))?;
+            let published = child_text(entry, "published")
+                .or_else(|| child_text(entry, "updated"))
+                .and_then(|date| OffsetDateTime::parse(&date, &Rfc3339).ok());
+            entry_from(base, &link, child_text(entry, "title"), published)
+        })
+        .collect();
+    Some(ParsedFeed { title, entries })
+}
+
+/// Parse an RSS 2.0 or RSS 1.0 (RDF) feed, or None when the document is not one
+pub fn parse_rss(base: &Url, body: &str) -> Option<ParsedFeed> {
+    let (_, channel) = elements(body, "channel").into_iter().next()?;
+    let title = head_title(channel, "item");
+    // RSS 1.0 lists its items after the channel rather than inside it
+    let items_in = if channel.contains("<item") {
+        channel
+    } else {
+        body
+    };
+    let entries = elements(items_in, "item")
+        .into_iter()
+        .filter_map(|(_, item)| {
+            let link = child_text(item, "link").or_else(|| child_text(item, "guid"))?;
+            let published = child_text(item, "pubDate")
+                .and_then(|date| OffsetDateTime::parse(&date, &Rfc2822).ok())
+                .or_else(|| {
+                    child_text(item, "dc:date")
+                        .and_then(|date| OffsetDateTime::parse(&date, &Rfc3339).ok())
+                });
+            entry_from(base, &link, child_text(item, "title"), published)
+        })
+        .collect();
+    Some(ParsedFeed { title, entries })
+}
+
+/// Parse a gemtext page following the Gemini subscription convention: the first level-one
+/// heading titles the feed and every link labelled with a leading YYYY-MM-DD date is an entry
+pub fn parse_gemfeed(base: &Url, body: &str) -> ParsedFeed {
+    let mut title = None;
+    let mut entries = Vec::new();
+    for line in parse_gemtext(body) {
+        match line {
+            GemtextLine::Heading { level: 1, text } if title.is_none() => title = Some(text),
+            GemtextLine::Link {
+                url,
+                label: Some(label),
+            } => {
+                let Some(published) = iso_date(&label) else {
+                    continue;
+                };
+                let rest = label[10..].trim_start_matches([' ', '-', '–', '—', ':', '\t']);
+                let entry_title = if rest.is_empty() { &label } else { rest };
+                if let Some(entry) =
+                    entry_from(base, &url, Some(entry_title.to_string()), Some(published))
+                {
+                    entries.push(entry);
+                }
+            }
+            _ => {}
+        }
+    }
+    ParsedFeed { title, entries }
+}
+
+fn entry_from(
+    base: &Url,
+    link: &str,
+    title: Option<String>,
+    published: Option<OffsetDateTime>,
+) -> Option<ParsedEntry> {
+    let url = base.join(link.trim()).ok()?.to_string();
+    let title = title
+        .filter(|title| !title.is_empty())
+        .unwrap_or(url.clone());
+    Some(ParsedEntry {
+        url,
+        title,
+        published: published.map(OffsetDateTime::unix_timestamp),
+    })
+}
+
+/// The feed's own title, which comes before its first entry
+fn head_title(xml: &str, entry_name: &str) -> Option<String> {
+    let head = match xml.find(&format!("<{}", entry_name)) {
+        Some(end) => &xml[..end],
+        None => xml,
+    };
+    child_text(head, "title").filter(|title| !title.is_empty())
+}
+
+/// The start-tag attributes and inner XML of each `<name>` element, in document order; elements
+/// of the same name are assumed not to nest, which holds for the feed and XBEL elements read
+pub(crate) fn elements<'a>(xml: &'a str, name: &str) -> Vec<(&'a str, &'a str)> {
+    let open = format!("<{}", name);
+    let close = format!("</{}>", name);
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+        // Skip longer names sharing the prefix, e.g. <itemDescription> when looking for <item>
+        if !after_name.starts_with(|c: char| c == '>' || c == '/' || c.is_whitespace()) {
+            rest = after_name;
+            continue;
+        }
+        let Some(tag_end) = after_name.find('>') else {
+            break;
+        };
+        let tag = &after_name[..tag_end];
+        let after_tag = &after_name[tag_end + 1..];
+        if let Some(tag) = tag.strip_suffix('/') {
+            found.push((tag, ""));
+            rest = after_tag;
+            continue;
+        }
+        let Some(inner_end) = after_tag.find(&close) else {
+            break;
+        };
+        found.push((tag, &after_tag[..inner_end]));
+        rest = &after_tag[inner_end + close.len()..];
+    }
+    found
+}
+
+/// Text of the first `<name>` child, unescaped with whitespace collapsed
+pub(crate) fn child_text(xml: &str, name: &str) -> Option<String> {
+    let (_, inner) = elements(xml, name).into_iter().next()?;
+    let mut text = String::new();
+    let mut rest = inner;
+    while let Some(start) = rest.find("<![CDATA[") {
+        text.push_str(&unescape(&rest[..start]));
+        let cdata = &rest[start + "<![CDATA[".len()..];
+        let end = cdata.find("]]>").unwrap_or(cdata.len());
+        text.push_str(&cdata[..end]);
+        rest = cdata.get(end + "]]>".len()..).unwrap_or("");
+    }
+    text.push_str(&unescape(rest));
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Value of an attribute in a start tag, unescaped
+pub(crate) fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(position) = rest.find(name) {
+        let preceded = rest[..position].ends_with(char::is_whitespace);
+        let after = rest[position + name.len()..].trim_start();
+        rest = &rest[position + name.len()..];
+        let Some(value) = after.strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        if !preceded {
+            continue;
+        }
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        let end = value.find(quote)?;
+        return Some(unescape(&value[..end]));
+    }
+    None
+}
+
+/// Replace the predefined XML entities and numeric character references
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        let entity = &rest[start + 1..];
+        let Some(end) = entity.find(';').filter(|end| *end <= 10) else {
+            unescaped.push('&');
+            rest = entity;
+            continue;
+        };
+        let name = &entity[..end];
+        let decoded = match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => name
+                .strip_prefix("#x")
+                .or_else(|| name.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| name.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                unescaped.push(c);
+                rest = &entity[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = entity;
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// A leading YYYY-MM-DD as used by gmisub entries, as midnight UTC
+fn iso_date(label: &str) -> Option<OffsetDateTime> {
+    let date = label.get(..10)?;
+    let well_formed = date.bytes().enumerate().all(|(i, byte)| match i {
+        4 | 7 => byte == b'-',
+        _ => byte.is_ascii_digit(),
+    });
+    if !well_formed {
+        return None;
+    }
+    let month = Month::try_from(date[5..7].parse::<u8>().ok()?).ok()?;
+    let date =
+        Date::from_calendar_date(date[..4].parse().ok()?, month, date[8..].parse().ok()?).ok()?;
+    Some(date.midnight().assume_utc())
+}
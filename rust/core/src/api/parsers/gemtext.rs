@@ -0,0 +1,106 @@
+/// A single parsed line of a text/gemini document
+#[derive(Debug, Clone, PartialEq)]
+pub enum GemtextLine {
+    Text(String),
+    Link { url: String, label: Option<String> },
+    Heading { level: u8, text: String },
+    ListItem(String),
+    Quote(String),
+    PreformatToggle { alt: Option<String> },
+    Preformatted(String),
+}
+
+/// Parse a text/gemini body into lines according to the Gemini specification
+pub fn parse_gemtext(body: &str) -> Vec<GemtextLine> {
+    let mut parser = GemtextParser::default();
+    body.lines()
+        .map(|raw| parser.parse_line(raw.trim_end_matches('\r')))
+        .collect()
+}
+
+/// Parses a text/gemini document a line at a time as it arrives, e.g. from a stream, carrying
+/// whether a preformatted block is open from one line to the next
+#[derive(Debug, Clone, Default)]
+pub struct GemtextParser {
+    preformatted: bool,
+}
+
+impl GemtextParser {
+    /// Parse the next line of the document, given without its line break
+    pub fn parse_line(&mut self, line: &str) -> GemtextLine {
+        // Toggle lines are the only special lines while in preformatted mode
+        if let Some(alt) = line.strip_prefix("```") {
+            self.preformatted = !self.preformatted;
+            let alt = alt.trim();
+            return GemtextLine::PreformatToggle {
+                alt: if alt.is_empty() {
+                    None
+                } else {
+                    Some(alt.to_string())
+                },
+            };
+        }
+
+        if self.preformatted {
+            return GemtextLine::Preformatted(line.to_string());
+        }
+
+        parse_line(line)
+    }
+
+    /// Parse the rest of a line too long to arrive in one piece, which is never a line type
+    /// prefix: text, or preformatted text inside a preformatted block
+    pub fn parse_continuation(&self, text: &str) -> GemtextLine {
+        if self.preformatted {
+            GemtextLine::Preformatted(text.to_string())
+        } else {
+            GemtextLine::Text(text.to_string())
+        }
+    }
+}
+
+/// Parse a single non-preformatted line in isolation
+fn parse_line(line: &str) -> GemtextLine {
+    if let Some(rest) = line.strip_prefix("=>") {
+        let rest = rest.trim();
+        let (url, label) = match rest.find(char::is_whitespace) {
+            Some(pos) => {
+                let label = rest[pos..].trim();
+                (
+                    &rest[..pos],
+                    if label.is_empty() {
+                        None
+                    } else {
+                        Some(label.to_string())
+                    },
+                )
+            }
+            None => (rest, None),
+        };
+        if url.is_empty() {
+            return GemtextLine::Text(line.to_string());
+        }
+        return GemtextLine::Link {
+            url: url.to_string(),
+            label,
+        };
+    }
+
+    if line.starts_with('#') {
+        let level = line.chars().take_while(|c| *c == '#').count().min(3);
+        return GemtextLine::Heading {
+            level: level as u8,
+            text: line[level..].trim().to_string(),
+        };
+    }
+
+    if let Some(rest) = line.strip_prefix("* ") {
+        return GemtextLine::ListItem(rest.to_string());
+    }
+
+    if let Some(rest) = line.strip_prefix('>') {
+        return GemtextLine::Quote(rest.trim_start().to_string());
+    }
+
+    GemtextLine::Text(line.to_string())
+}
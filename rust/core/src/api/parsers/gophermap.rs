@@ -0,0 +1,114 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// Selector bytes that cannot appear literally in the path of a gopher:// URL
+const SELECTOR_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// A single item of a Gopher menu (RFC 1436)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GopherItem {
+    pub item_type: char,
+    pub display: String,
+    pub selector: String,
+    pub host: String,
+    pub port: u16,
+    /// Human-readable item kind, e.g. "Text file" or "Directory"
+    pub kind: String,
+    /// Icon the UI shows next to the item, e.g. "text", "folder", or "search"
+    pub icon: String,
+    /// Whether Holloway can open or download the item itself; informational lines, errors,
+    /// telnet sessions, and web links are not. Phone books, like searches, need a query
+    pub fetchable: bool,
+}
+
+impl GopherItem {
+    /// Build the gopher:// URL this item points to, if it points anywhere, percent-encoding the
+    /// selector so spaces and other bytes survive the round trip
+    pub fn to_url(&self) -> Option<String> {
+        if self.item_type == 'i' || self.item_type == '3' || self.host.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "gopher://{}:{}/{}{}",
+            self.host,
+            self.port,
+            self.item_type,
+            utf8_percent_encode(&self.selector, SELECTOR_ENCODE_SET)
+        ))
+    }
+}
+
+/// Parse a Gopher menu body into items, skipping the terminating "." line
+pub fn parse_gophermap(body: &str) -> Vec<GopherItem> {
+    menu_lines(body).filter_map(parse_item).collect()
+}
+
+/// Number of items in a menu body, counted without parsing them
+pub(crate) fn count_items(body: &str) -> usize {
+    menu_lines(body).filter(|line| !line.is_empty()).count()
+}
+
+/// The lines of a menu body up to the terminating "." line
+fn menu_lines(body: &str) -> impl Iterator<Item = &str> {
+    body.lines()
+        .map(|raw| raw.trim_end_matches('\r'))
+        .take_while(|line| *line != ".")
+}
+
+/// Parse one menu line into an item; empty lines are not items
+pub(crate) fn parse_item(line: &str) -> Option<GopherItem> {
+    let mut chars = line.chars();
+    let item_type = chars.next()?;
+
+    let mut fields = chars.as_str().split('\t');
+    let display = fields.next().unwrap_or("").to_string();
+    let selector = fields.next().unwrap_or("").to_string();
+    let host = fields.next().unwrap_or("").trim().to_string();
+    let port = fields
+        .next()
+        .and_then(|p| p.trim().parse().ok())
+        .unwrap_or(70);
+
+    let (kind, icon, fetchable) = describe(item_type, &selector);
+    Some(GopherItem {
+        item_type,
+        display,
+        selector,
+        host,
+        port,
+        kind: kind.to_string(),
+        icon: icon.to_string(),
+        fetchable,
+    })
+}
+
+/// Kind, icon id, and fetchability of an item type; `h` items with a `URL:` selector link to the web
+fn describe(item_type: char, selector: &str) -> (&'static str, &'static str, bool) {
+    match item_type {
+        '0' => ("Text file", "text", true),
+        '1' => ("Directory", "folder", true),
+        '2' => ("Phone book", "phonebook", true),
+        '3' => ("Error", "error", false),
+        '4' | '5' | '6' | '9' => ("Binary", "binary", true),
+        '7' => ("Search", "search", true),
+        '8' | 'T' => ("Telnet session", "terminal", false),
+        '+' => ("Mirror", "folder", true),
+        'g' | 'I' | 'p' => ("Image", "image", true),
+        'h' if selector.starts_with("URL:") => ("Web link", "web", false),
+        'h' => ("HTML page", "html", true),
+        'i' => ("Information", "info", false),
+        's' => ("Sound", "audio", true),
+        ';' => ("Video", "video", true),
+        'd' => ("Document", "document", true),
+        _ => ("Unknown", "unknown", false),
+    }
+}
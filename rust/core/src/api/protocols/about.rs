@@ -0,0 +1,83 @@
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::top_sites::top_pages;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{HandlerFuture, ProtocolCapabilities, ProtocolHandler};
+use crate::api::storage::cache;
+
+/// How many top sites about:home links to
+const HOME_TOP_SITES: u32 = 12;
+
+/// Registry handler for built-in about: pages, generated locally without a connection
+pub struct AboutHandler;
+
+impl ProtocolHandler for AboutHandler {
+    fn scheme(&self) -> &'static str {
+        "about"
+    }
+
+    /// about: URLs never name a host, so there is nothing to connect to
+    fn default_port(&self) -> u16 {
+        0
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities::default()
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let body = match url.path() {
+                "home" => home_page()?,
+                "cached" => return cached_page(url),
+                page => {
+                    return Err(NavigateError::NotFound(format!(
+                        "Unknown page about:{}",
+                        page
+                    )))
+                }
+            };
+            Ok(
+                FetchResponse::new(url.to_string(), "text/gemini".to_string(), body)
+                    .with_header(20, "text/gemini".to_string()),
+            )
+        })
+    }
+}
+
+/// about:cached?<URL>: the newest cached copy of a URL, fresh or stale, e.g. for the link on
+/// the error page of a server failure. The copy keeps its own URL, so its links resolve as on
+/// the page itself.
+fn cached_page(url: &Url) -> Result<FetchResponse, NavigateError> {
+    let target = percent_decode_str(url.query().unwrap_or_default()).decode_utf8_lossy();
+    let target = Url::parse(&target)
+        .map_err(|e| NavigateError::InvalidUrl(format!("Invalid URL: {}", e)))?;
+    let canonical = canonicalize(&target);
+    cache::lookup_stale(canonical.as_str())
+        .map_err(NavigateError::StorageError)?
+        .ok_or_else(|| NavigateError::NotFound(format!("{} has no cached copy", canonical)))
+}
+
+/// The about:home page: a gemtext list of the user's most visited pages
+fn home_page() -> Result<String, NavigateError> {
+    let mut page = String::from("# Holloway\n\n## Top sites\n\n");
+    let sites = top_pages(HOME_TOP_SITES).map_err(NavigateError::StorageError)?;
+    if sites.is_empty() {
+        page.push_str("Pages you visit often will appear here.\n");
+    }
+    for site in sites {
+        let label = site.title.unwrap_or_else(|| site.url.clone());
+        match site.favicon {
+            Some(icon) => page.push_str(&format!("=> {} {} {}\n", site.url, icon, label)),
+            None => page.push_str(&format!("=> {} {}\n", site.url, label)),
+        }
+    }
+    Ok(page)
+}
@@ -0,0 +1,252 @@
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use url::Url;
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::url_host;
+use crate::api::transport::Connection;
+
+/// Port of CSO phone book (qi) servers when a URL does not name one
+pub const DEFAULT_CSO_PORT: u16 = 105;
+
+/// Reply code meaning the query matched no entries
+const NO_MATCHES: u16 = 501;
+
+/// One entry a CSO phone book returned
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsoRecord {
+    /// Position of the entry among the matches, starting at 1
+    pub index: u32,
+    pub fields: Vec<CsoField>,
+}
+
+/// A field of a phone book entry, e.g. "name" or "email"
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsoField {
+    pub name: String,
+    /// The value, with continuation lines joined by newlines
+    pub value: String,
+}
+
+/// A field of a phone book's entries, as its `fields` command describes it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsoFieldInfo {
+    pub name: String,
+    pub description: String,
+    /// Whether queries may search the field; the others are only shown
+    pub searchable: bool,
+}
+
+/// Look up entries in the phone book a gopher type-2 item URL points to
+pub async fn query_item(url: &str, query: &str) -> Result<Vec<CsoRecord>, NavigateError> {
+    let url =
+        Url::parse(url).map_err(|e| NavigateError::InvalidUrl(format!("Invalid URL: {}", e)))?;
+    let host = url_host(&url)?;
+    query_cso(host, url.port().unwrap_or(DEFAULT_CSO_PORT), query).await
+}
+
+/// Look up entries in the CSO phone book (the qi protocol, RFC 2378) behind a gopher type-2
+/// item, e.g. "name=smith" or just "smith"; a query without matches yields no records
+pub async fn query_cso(
+    host: &str,
+    port: u16,
+    query: &str,
+) -> Result<Vec<CsoRecord>, NavigateError> {
+    // A line break in the query would start a second command
+    let query = query.replace(['\r', '\n'], " ");
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(NavigateError::InvalidInput(
+            "Phone book query cannot be empty".to_string(),
+        ));
+    }
+
+    let (code, text, lines) = run_command(host, port, &format!("query {}", query)).await?;
+    match code {
+        200..=299 => Ok(parse_records(&lines)),
+        NO_MATCHES => Ok(Vec::new()),
+        _ => Err(NavigateError::BadResponse(format!(
+            "Phone book answered {}: {}",
+            code, text
+        ))),
+    }
+}
+
+/// The fields of a CSO phone book's entries, from its `fields` command, in the server's order
+pub async fn list_fields(host: &str, port: u16) -> Result<Vec<CsoFieldInfo>, NavigateError> {
+    let (code, text, lines) = run_command(host, port, "fields").await?;
+    if !(200..=299).contains(&code) {
+        return Err(NavigateError::BadResponse(format!(
+            "Phone book answered {}: {}",
+            code, text
+        )));
+    }
+    // Each field comes as two lines under its number: its attributes, then its description
+    Ok(parse_records(&lines)
+        .into_iter()
+        .filter_map(|record| {
+            let mut lines = record.fields.into_iter();
+            let attributes = lines.next()?;
+            Some(CsoFieldInfo {
+                description: lines.next().map(|line| line.value).unwrap_or_default(),
+                searchable: attributes
+                    .value
+                    .split_whitespace()
+                    .any(|attribute| attribute.eq_ignore_ascii_case("Indexed")),
+                name: attributes.name,
+            })
+        })
+        .collect())
+}
+
+/// A page for a phone book opened without a query: how to search it and by which fields
+pub fn format_fields(fields: &[CsoFieldInfo]) -> String {
+    let mut text = "This is a CSO phone book. Search it with a query such as \"name=smith\", or \
+                    just \"smith\".\n"
+        .to_string();
+    // Servers that flag no field as indexed still answer queries by their default fields
+    let searchable: Vec<&CsoFieldInfo> = if fields.iter().any(|field| field.searchable) {
+        fields.iter().filter(|field| field.searchable).collect()
+    } else {
+        fields.iter().collect()
+    };
+    if searchable.is_empty() {
+        return text;
+    }
+    let width = searchable
+        .iter()
+        .map(|field| field.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    text.push_str("\nSearchable fields:\n");
+    for field in searchable {
+        text.push_str(&format!("{:>width$}: {}\n", field.name, field.description));
+    }
+    text
+}
+
+/// Render phone book entries as plain text, one block of aligned fields per entry
+pub fn format_records(records: &[CsoRecord]) -> String {
+    if records.is_empty() {
+        return "No matches.\n".to_string();
+    }
+    let width = records
+        .iter()
+        .flat_map(|record| &record.fields)
+        .map(|field| field.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut text = String::new();
+    for record in records {
+        for field in &record.fields {
+            let mut lines = field.value.lines();
+            let first = lines.next().unwrap_or_default();
+            text.push_str(&format!("{:>width$}: {}\n", field.name, first));
+            for line in lines {
+                text.push_str(&format!("{:>width$}  {}\n", "", line));
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Connect, send one command, and read its reply, then say goodbye
+async fn run_command(
+    host: &str,
+    port: u16,
+    command: &str,
+) -> Result<(u16, String, Vec<String>), NavigateError> {
+    let mut reader = BufReader::new(crate::api::transport::dial(host, port).await?);
+    send(&mut reader, command).await?;
+    let reply = read_reply(&mut reader).await?;
+    // The answer is complete, so a server that hangs up before the goodbye is no error
+    let _ = send(&mut reader, "quit").await;
+    Ok(reply)
+}
+
+async fn send(
+    reader: &mut BufReader<Box<dyn Connection>>,
+    command: &str,
+) -> Result<(), NavigateError> {
+    reader
+        .get_mut()
+        .write_all(format!("{}\r\n", command).as_bytes())
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to send request", e))
+}
+
+/// Read reply lines up to the final one, which is the first without a leading "-"; returns
+/// the final code and text and the continued lines before it
+async fn read_reply(
+    reader: &mut BufReader<Box<dyn Connection>>,
+) -> Result<(u16, String, Vec<String>), NavigateError> {
+    let mut lines = Vec::new();
+    loop {
+        let mut line = Vec::new();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+        if read == 0 {
+            return Err(NavigateError::ConnectionLost(
+                "Failed to read response: connection closed mid-reply".to_string(),
+            ));
+        }
+        let line = String::from_utf8_lossy(&line)
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        if let Some(continued) = line.strip_prefix('-') {
+            lines.push(continued.to_string());
+            continue;
+        }
+        let (code, text) = line.split_once(':').unwrap_or((&line, ""));
+        // 1xx lines such as "102:There were 2 matches" announce what follows
+        match code.trim().parse::<u16>() {
+            Ok(code) if code < 200 => continue,
+            Ok(code) => return Ok((code, text.trim().to_string(), lines)),
+            Err(_) => {
+                return Err(NavigateError::BadResponse(format!(
+                    "Failed to read response: invalid phone book reply {}",
+                    line
+                )))
+            }
+        }
+    }
+}
+
+/// Group "<code>:<index>:<field>:<value>" lines into entries; a line with a blank field name
+/// continues the previous field
+fn parse_records(lines: &[String]) -> Vec<CsoRecord> {
+    let mut records: Vec<CsoRecord> = Vec::new();
+    for line in lines {
+        let mut parts = line.splitn(4, ':');
+        let (Some(_code), Some(index), Some(name), Some(value)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let Ok(index) = index.trim().parse::<u32>() else {
+            continue;
+        };
+        let (name, value) = (name.trim(), value.trim());
+
+        if records.last().is_none_or(|record| record.index != index) {
+            records.push(CsoRecord {
+                index,
+                fields: Vec::new(),
+            });
+        }
+        let record = records.last_mut().expect("record was just pushed");
+        match record.fields.last_mut() {
+            Some(field) if name.is_empty() => {
+                field.value.push('\n');
+                field.value.push_str(value);
+            }
+            _ => record.fields.push(CsoField {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+    records
+}
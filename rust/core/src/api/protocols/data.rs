@@ -0,0 +1,100 @@
+use base64::Engine;
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{
+    BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+
+/// Type of a data: URL that names none (RFC 2397)
+const DEFAULT_MIME: &str = "text/plain;charset=US-ASCII";
+
+/// Registry handler for data: URLs (RFC 2397), whose content is embedded in the URL itself
+pub struct DataHandler;
+
+impl ProtocolHandler for DataHandler {
+    fn scheme(&self) -> &'static str {
+        "data"
+    }
+
+    /// data: URLs never name a host, so there is nothing to connect to
+    fn default_port(&self) -> u16 {
+        0
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities::default()
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let (mime, payload) = decode(url)?;
+            Ok(FetchResponse::new(
+                url.to_string(),
+                mime,
+                String::from_utf8_lossy(&payload).into_owned(),
+            ))
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let (mime, payload) = decode(url)?;
+            Ok(ResponseBody {
+                mime,
+                reader: Box::new(std::io::Cursor::new(payload)),
+            })
+        })
+    }
+}
+
+/// Split a data: URL into its MIME type and decoded payload, from either base64 or
+/// percent-encoded form
+fn decode(url: &Url) -> Result<(String, Vec<u8>), NavigateError> {
+    // Everything after the scheme is content, including what the URL parser takes for a query
+    let content = url.as_str().split_once(':').map_or("", |(_, rest)| rest);
+    let (header, payload) = content.split_once(',').ok_or_else(|| {
+        NavigateError::InvalidUrl(
+            "Invalid URL: data URL has no comma before its content".to_string(),
+        )
+    })?;
+
+    let (media_type, base64) = match header.strip_suffix(";base64") {
+        Some(media_type) => (media_type, true),
+        None => (header, false),
+    };
+    let media_type = percent_decode_str(media_type).decode_utf8_lossy();
+    let mime = match media_type.trim() {
+        "" => DEFAULT_MIME.to_string(),
+        // ";charset=utf-8" alone keeps the default type
+        parameters if parameters.starts_with(';') => format!("text/plain{}", parameters),
+        media_type => media_type.to_string(),
+    };
+
+    let decoded: Vec<u8> = percent_decode_str(payload).collect();
+    let payload = if base64 {
+        // Base64 in URLs is often wrapped or padded loosely, so whitespace is dropped first
+        let compact: Vec<u8> = decoded
+            .into_iter()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        base64::engine::general_purpose::STANDARD
+            .decode(&compact)
+            .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(&compact))
+            .map_err(|e| {
+                NavigateError::InvalidUrl(format!(
+                    "Invalid URL: data URL content is not valid base64: {}",
+                    e
+                ))
+            })?
+    } else {
+        decoded
+    };
+    Ok((mime, payload))
+}
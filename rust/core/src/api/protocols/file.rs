@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use url::Url;
+
+use crate::api::functions::file_type::{mime_for_extension, sniff};
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{
+    BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+
+/// Bytes of a file without a known extension inspected to tell text from binary
+const SNIFF_BYTES: usize = 512;
+
+/// Registry handler for file:// URLs: local files, and directories listed as gemtext links
+pub struct FileHandler;
+
+impl ProtocolHandler for FileHandler {
+    fn scheme(&self) -> &'static str {
+        "file"
+    }
+
+    /// Local files are not reached over the network
+    fn default_port(&self) -> u16 {
+        0
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities::default()
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let path = local_path(url)?;
+            if path.is_dir() {
+                let listing = directory_listing(&path)?;
+                return Ok(FetchResponse::new(
+                    directory_url(&path)?.to_string(),
+                    "text/gemini".to_string(),
+                    listing,
+                ));
+            }
+            let content = fs::read(&path).map_err(|e| read_error(&path, e))?;
+            Ok(FetchResponse::new(
+                url.to_string(),
+                file_mime(&path, &content),
+                String::from_utf8_lossy(&content).to_string(),
+            ))
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let path = local_path(url)?;
+            if path.is_dir() {
+                return Ok(ResponseBody {
+                    mime: "text/gemini".to_string(),
+                    reader: Box::new(std::io::Cursor::new(directory_listing(&path)?.into_bytes())),
+                });
+            }
+            let mut file = File::open(&path).await.map_err(|e| read_error(&path, e))?;
+            let mut start = Vec::with_capacity(SNIFF_BYTES);
+            (&mut file)
+                .take(SNIFF_BYTES as u64)
+                .read_to_end(&mut start)
+                .await
+                .map_err(|e| read_error(&path, e))?;
+            Ok(ResponseBody {
+                mime: file_mime(&path, &start),
+                reader: Box::new(std::io::Cursor::new(start).chain(file)),
+            })
+        })
+    }
+}
+
+/// The local path a file:// URL names
+fn local_path(url: &Url) -> Result<PathBuf, NavigateError> {
+    url.to_file_path()
+        .map_err(|_| NavigateError::InvalidUrl(format!("Not a local file path: {}", url)))
+}
+
+/// Error for a file that could not be read, telling a missing file from other failures
+fn read_error(path: &Path, error: std::io::Error) -> NavigateError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => {
+            NavigateError::NotFound(format!("File not found: {}", path.display()))
+        }
+        _ => NavigateError::Other(format!("Failed to read {}: {}", path.display(), error)),
+    }
+}
+
+/// URL of a directory, with the trailing slash relative links need
+fn directory_url(path: &Path) -> Result<Url, NavigateError> {
+    Url::from_directory_path(path).map_err(|_| {
+        NavigateError::InvalidUrl(format!("Not a local file path: {}", path.display()))
+    })
+}
+
+/// Type of a local file from its extension, falling back to its content
+fn file_mime(path: &Path, content: &[u8]) -> String {
+    if let Some(mime) = path
+        .extension()
+        .and_then(|extension| mime_for_extension(&extension.to_string_lossy()))
+    {
+        return mime;
+    }
+    match sniff(content) {
+        Some(file_type) => file_type.mime,
+        None if std::str::from_utf8(&content[..content.len().min(SNIFF_BYTES)]).is_ok() => {
+            "text/plain".to_string()
+        }
+        None => "application/octet-stream".to_string(),
+    }
+}
+
+/// A gemtext page linking to the parent directory and every entry, subdirectories first
+fn directory_listing(path: &Path) -> Result<String, NavigateError> {
+    let mut entries: Vec<(bool, String, PathBuf)> = fs::read_dir(path)
+        .map_err(|e| NavigateError::Other(format!("Failed to list {}: {}", path.display(), e)))?
+        .filter_map(Result::ok)
+        .map(|entry| {
+            let entry_path = entry.path();
+            (
+                !entry_path.is_dir(),
+                entry.file_name().to_string_lossy().into_owned(),
+                entry_path,
+            )
+        })
+        .collect();
+    entries.sort_by_key(|(is_file, name, _)| (*is_file, name.to_lowercase()));
+
+    let mut listing = format!("# {}\n\n", path.display());
+    if let Some(parent) = path.parent() {
+        listing.push_str(&format!("=> {} ..\n", directory_url(parent)?));
+    }
+    for (is_file, name, entry_path) in entries {
+        let (url, label) = if is_file {
+            (Url::from_file_path(&entry_path), name)
+        } else {
+            (Url::from_directory_path(&entry_path), format!("{}/", name))
+        };
+        if let Ok(url) = url {
+            listing.push_str(&format!("=> {} {}\n", url, label));
+        }
+    }
+    Ok(listing)
+}
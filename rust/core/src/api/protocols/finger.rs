@@ -0,0 +1,121 @@
+use percent_encoding::percent_decode_str;
+use tokio::io::AsyncWriteExt;
+use url::Url;
+
+use crate::api::functions::legacy_charset::decode_for_host;
+use crate::api::functions::navigation_events::read_until_quiet;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{
+    url_host, BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::timeouts::{with_idle_timeout, IDLE_TIMEOUT, READ_DEADLINE};
+use crate::api::transport::Connection;
+
+/// Registry handler for finger:// URLs
+pub struct FingerHandler;
+
+impl ProtocolHandler for FingerHandler {
+    fn scheme(&self) -> &'static str {
+        "finger"
+    }
+
+    fn default_port(&self) -> u16 {
+        79
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities::default()
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            let (content, truncated) = fetch_finger(host, port, &username(url)).await?;
+            let (body, charset) = decode_for_host(host, &content);
+            let mime = format!("text/plain; charset={}", charset);
+            let mut response = FetchResponse::new(url.to_string(), mime, body);
+            response.truncated = truncated;
+            Ok(response)
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            Ok(ResponseBody {
+                mime: "text/plain".to_string(),
+                reader: Box::new(send_query(host, port, &username(url)).await?),
+            })
+        })
+    }
+}
+
+/// The query to send, from `finger://user@host` or `finger://host/user`; a forwarded query
+/// (`finger://user@host1@host2`, RFC 1288 section 2.5.1) keeps everything before the last `@`,
+/// which the URL parser percent-encodes into the user name, so that host2 forwards it to host1
+fn username(url: &Url) -> String {
+    let query = if url.username().is_empty() {
+        url.path().trim_start_matches('/')
+    } else {
+        url.username()
+    };
+    percent_decode_str(query).decode_utf8_lossy().into_owned()
+}
+
+/// Connect to Finger server and fetch content
+pub async fn connect_and_fetch_finger(
+    host: &str,
+    port: u16,
+    username: &str,
+) -> Result<String, NavigateError> {
+    let (response, _) = fetch_finger(host, port, username).await?;
+
+    Ok(decode_for_host(host, &response).0)
+}
+
+/// Send the query for a user and read the response; the flag is set when the server kept the
+/// connection open and the response was cut off after it went quiet
+async fn fetch_finger(
+    host: &str,
+    port: u16,
+    username: &str,
+) -> Result<(Vec<u8>, bool), NavigateError> {
+    let mut stream = send_query(host, port, username).await?;
+    read_until_quiet(&mut stream, READ_DEADLINE)
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to read response", e))
+}
+
+/// Connect and send the query for a user, leaving the response unread
+async fn send_query(
+    host: &str,
+    port: u16,
+    username: &str,
+) -> Result<Box<dyn Connection>, NavigateError> {
+    // A decoded %0D%0A would end the query early and send what follows as more lines
+    if username.contains(['\r', '\n']) {
+        return Err(NavigateError::InvalidInput(
+            "Finger query must be a single line".to_string(),
+        ));
+    }
+    // Some servers never close the connection, so a quiet one ends the response
+    let mut stream =
+        with_idle_timeout(IDLE_TIMEOUT, crate::api::transport::dial(host, port)).await?;
+
+    // Send finger request: username + CRLF
+    let request = format!("{}\r\n", username);
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to send request", e))?;
+
+    Ok(stream)
+}
@@ -0,0 +1,383 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use url::Url;
+
+use crate::api::functions::file_type::sniff_mislabelled;
+use crate::api::functions::navigation_events::{read_to_end_tracked, report_header};
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{
+    url_host, BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+use crate::api::storage::gemini_proxies::SchemeProxy;
+use crate::api::storage::identities::{self, IdentityCredentials};
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::preconnect;
+use crate::api::transport::tls::{self, TlsConnection};
+
+/// Longest header a server may send: a two-digit status, a space, 1024 bytes of meta, and CRLF
+const MAX_HEADER_BYTES: u64 = 1029;
+
+/// Characters that may not appear in a URI: spaces, controls, and those RFC 3986 leaves out;
+/// non-ASCII characters are always escaped. `%` is kept, as it starts an existing escape.
+const URI_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'\\')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Parsed Gemini response: status code, meta line, and body
+#[derive(Debug, Clone)]
+pub struct GeminiResponse {
+    pub status: u8,
+    pub meta: String,
+    pub body: String,
+    /// Type recognized from the body's magic bytes when the server only said
+    /// application/octet-stream or labelled binary content as text
+    pub sniffed_mime: Option<String>,
+}
+
+impl GeminiResponse {
+    /// MIME type of the body; only 2x responses carry one and an empty meta defaults to gemtext
+    pub fn mime(&self) -> String {
+        if let Some(sniffed) = &self.sniffed_mime {
+            sniffed.clone()
+        } else if (20..30).contains(&self.status) && !self.meta.is_empty() {
+            self.meta.clone()
+        } else {
+            "text/gemini".to_string()
+        }
+    }
+}
+
+/// Registry handler for gemini:// URLs
+pub struct GeminiHandler;
+
+impl ProtocolHandler for GeminiHandler {
+    fn scheme(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn default_port(&self) -> u16 {
+        1965
+    }
+
+    fn requires_tls(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities {
+            user_input: true,
+            redirects: true,
+            client_certificates: true,
+            menus: false,
+        }
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+
+            let request_url = request_url(url);
+
+            let response = fetch_gemini(host, port, request_url.as_str()).await?;
+            Ok(
+                FetchResponse::new(request_url.to_string(), response.mime(), response.body)
+                    .with_header(response.status, response.meta),
+            )
+        })
+    }
+
+    fn fetch_with_identity<'a>(&'a self, url: &'a Url, identity_id: &'a str) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            let identity = credentials(identity_id)?;
+
+            let request_url = request_url(url);
+
+            let response =
+                fetch_gemini_as(host, port, request_url.as_str(), Some(&identity)).await?;
+            Ok(
+                FetchResponse::new(request_url.to_string(), response.mime(), response.body)
+                    .with_header(response.status, response.meta),
+            )
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let (status, meta, mut reader) = open_response(url).await?;
+            if let Some(error) = NavigateError::from_status(status, &meta) {
+                return Err(error);
+            }
+
+            let declared = if meta.is_empty() {
+                "text/gemini".to_string()
+            } else {
+                meta
+            };
+            let start = reader.fill_buf().await.map_err(read_error)?;
+            let mime =
+                sniff_mislabelled(&declared, start).map_or(declared, |file_type| file_type.mime);
+            Ok(ResponseBody {
+                mime,
+                reader: Box::new(reader),
+            })
+        })
+    }
+}
+
+/// A connection whose response header was read, positioned at the start of the body
+pub(crate) type BodyReader = BufReader<TlsConnection>;
+
+/// Send the request for a Gemini URL and read only the response header, returning its status
+/// and meta with the connection positioned at the start of the body
+pub(crate) async fn open_response(url: &Url) -> Result<(u8, String, BodyReader), NavigateError> {
+    let host = url_host(url)?;
+    let port = resolve_port(url, GeminiHandler.default_port());
+    let request = request_url(url);
+    let connection = open_request(host, port, request.as_str()).await?;
+    let warmed = connection.warmed();
+    let (mut reader, mut header) = (BufReader::new(connection), Vec::new());
+    let mut read = read_header(&mut reader, &mut header).await;
+    if warmed && !matches!(read, Ok(n) if n > 0) {
+        // The server closed the warmed-up connection while it waited, so ask again
+        reader = BufReader::new(open_request(host, port, request.as_str()).await?);
+        header.clear();
+        read = read_header(&mut reader, &mut header).await;
+    }
+    read.map_err(read_error)?;
+    let header = String::from_utf8_lossy(&header);
+    let (status, meta) = parse_header(header.trim_end_matches(['\r', '\n']));
+    report_header(status, &meta);
+    Ok((status, meta, reader))
+}
+
+/// Read the response header line, up to its limit
+async fn read_header(reader: &mut BodyReader, header: &mut Vec<u8>) -> std::io::Result<usize> {
+    reader
+        .take(MAX_HEADER_BYTES)
+        .read_until(b'\n', header)
+        .await
+}
+
+/// Connect to Gemini server and fetch content
+pub async fn connect_and_fetch_gemini(
+    host: &str,
+    port: u16,
+    url: &str,
+) -> Result<String, NavigateError> {
+    Ok(fetch_gemini(host, port, url).await?.body)
+}
+
+/// Connect to Gemini server and fetch the full response including the header
+pub async fn fetch_gemini(
+    host: &str,
+    port: u16,
+    url: &str,
+) -> Result<GeminiResponse, NavigateError> {
+    fetch_gemini_as(host, port, url, None).await
+}
+
+/// `fetch_gemini` presenting a client certificate, if one is given
+pub(crate) async fn fetch_gemini_as(
+    host: &str,
+    port: u16,
+    url: &str,
+    identity: Option<&IdentityCredentials>,
+) -> Result<GeminiResponse, NavigateError> {
+    Ok(parse_response(
+        &read_response(host, port, url, identity).await?,
+    ))
+}
+
+/// The response to a Gemini URL exactly as the server sent it, header included, presenting the
+/// stored identity `identity_id` if one is given
+pub(crate) async fn fetch_raw(
+    url: &Url,
+    identity_id: Option<&str>,
+) -> Result<Vec<u8>, NavigateError> {
+    let host = url_host(url)?;
+    let port = resolve_port(url, GeminiHandler.default_port());
+    let identity = identity_id.map(credentials).transpose()?;
+    read_response(host, port, request_url(url).as_str(), identity.as_ref()).await
+}
+
+/// Send a request and read the whole response, header and body, as raw bytes
+async fn read_response(
+    host: &str,
+    port: u16,
+    url: &str,
+    identity: Option<&IdentityCredentials>,
+) -> Result<Vec<u8>, NavigateError> {
+    let tls_stream = open_request_as(host, port, url, identity).await?;
+    if tls_stream.warmed() {
+        match read_all(tls_stream).await {
+            Ok(response) if !response.is_empty() => return Ok(response),
+            // The server closed the warmed-up connection while it waited, so ask again
+            _ => return read_all(open_request_as(host, port, url, identity).await?).await,
+        }
+    }
+    read_all(tls_stream).await
+}
+
+/// Read a whole response, reporting the header as soon as its line is complete
+async fn read_all(mut tls_stream: TlsConnection) -> Result<Vec<u8>, NavigateError> {
+    let mut header_seen = false;
+    let response = read_to_end_tracked(&mut tls_stream, |response| {
+        if header_seen {
+            return;
+        }
+        if let Some(end) = response.windows(2).position(|w| w == b"\r\n") {
+            header_seen = true;
+            let (status, meta) = parse_header(&String::from_utf8_lossy(&response[..end]));
+            report_header(status, &meta);
+        }
+    })
+    .await
+    .map_err(read_error)?;
+
+    Ok(response)
+}
+
+/// Fetch a URL of another scheme through a Gemini proxy, which is sent the full URL; the
+/// proxy's certificate is the one checked on first use
+pub(crate) async fn fetch_through_proxy(
+    proxy: &SchemeProxy,
+    url: &Url,
+) -> Result<FetchResponse, NavigateError> {
+    let response = fetch_gemini(&proxy.host, proxy.port, url.as_str()).await?;
+    Ok(
+        FetchResponse::new(url.to_string(), response.mime(), response.body)
+            .with_header(response.status, response.meta),
+    )
+}
+
+/// Open a TLS connection to a Gemini server and send the request line, leaving the response unread
+pub(crate) async fn open_request(
+    host: &str,
+    port: u16,
+    url: &str,
+) -> Result<TlsConnection, NavigateError> {
+    open_request_as(host, port, url, None).await
+}
+
+/// `open_request` presenting a client certificate, if one is given
+pub(crate) async fn open_request_as(
+    host: &str,
+    port: u16,
+    url: &str,
+    identity: Option<&IdentityCredentials>,
+) -> Result<TlsConnection, NavigateError> {
+    // A connection warmed up by preconnect presents no identity, so it only serves requests
+    // without one
+    let warm = match identity {
+        None => preconnect::take(host, port),
+        Some(_) => None,
+    };
+    let mut tls_stream = match warm {
+        Some(connection) => {
+            connection.verify(host, port)?;
+            connection
+        }
+        None => {
+            // Connect through the active transport (direct TCP or a gateway tunnel)
+            let connection = crate::api::transport::dial(host, port).await?;
+            tls::connect(host, port, connection, identity).await?
+        }
+    };
+
+    // Send Gemini request
+    let request = format!("{}\r\n", iri_to_uri(url));
+    if let Err(e) = tls_stream.write_all(request.as_bytes()).await {
+        return Err(NavigateError::from_io("Failed to send request", e));
+    }
+
+    Ok(tls_stream)
+}
+
+/// A failed read of a response, telling a stalled Gemini server from other read timeouts
+fn read_error(error: std::io::Error) -> NavigateError {
+    match NavigateError::from_io("Failed to read response", error) {
+        NavigateError::ReadTimeout(message) => NavigateError::GeminiTimeout(message),
+        error => error,
+    }
+}
+
+/// Key material of a stored identity
+pub(crate) fn credentials(identity_id: &str) -> Result<IdentityCredentials, NavigateError> {
+    identities::credentials(identity_id)
+        .map_err(NavigateError::IdentityError)?
+        .ok_or_else(|| NavigateError::NotFound(format!("Unknown identity {}", identity_id)))
+}
+
+/// Gemini requests need an explicit path, so "gemini://host" becomes "gemini://host/"
+pub(crate) fn request_url(url: &Url) -> Url {
+    let mut request_url = url.clone();
+    if request_url.path().is_empty() {
+        request_url.set_path("/");
+    }
+    request_url
+}
+
+/// Percent-encode the characters of an IRI that are not allowed in a URI, such as spaces and
+/// non-ASCII letters typed in a path or query, as UTF-8; a valid URI comes back unchanged.
+/// Requests must be valid URIs, and URLs that did not go through `Url` may still be IRIs.
+pub(crate) fn iri_to_uri(iri: &str) -> String {
+    utf8_percent_encode(iri, URI_ENCODE_SET).to_string()
+}
+
+/// Parse a raw Gemini response into its header fields and body
+pub fn parse_response(response: &[u8]) -> GeminiResponse {
+    // Split the "<STATUS> <META>\r\n" header from the body
+    let (header, body_start) = match response.windows(2).position(|w| w == b"\r\n") {
+        Some(pos) => (
+            String::from_utf8_lossy(&response[..pos]).to_string(),
+            pos + 2,
+        ),
+        None => (String::new(), 0), // No header found, assume entire response is body
+    };
+
+    let (status, meta) = parse_header(&header);
+    let body = &response[body_start..];
+
+    // An empty meta means gemtext, which binary content can be mislabelled as like any text
+    let declared = if meta.is_empty() {
+        "text/gemini"
+    } else {
+        &meta
+    };
+    let sniffed_mime = (20..30)
+        .contains(&status)
+        .then(|| sniff_mislabelled(declared, body).map(|file_type| file_type.mime))
+        .flatten();
+
+    GeminiResponse {
+        status,
+        meta,
+        body: String::from_utf8_lossy(body).to_string(),
+        sniffed_mime,
+    }
+}
+
+/// Parse a Gemini header line into its status code and meta string
+pub(crate) fn parse_header(header: &str) -> (u8, String) {
+    let (code, meta) = match header.split_once(' ') {
+        Some((code, meta)) => (code, meta.trim()),
+        None => (header.trim(), ""),
+    };
+    // Treat a missing or malformed header as a successful gemtext response
+    match code.parse::<u8>() {
+        Ok(status) => (status, meta.to_string()),
+        Err(_) => (20, "text/gemini".to_string()),
+    }
+}
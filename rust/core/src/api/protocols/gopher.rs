@@ -0,0 +1,256 @@
+use std::io::Cursor;
+
+use percent_encoding::percent_decode_str;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use url::Url;
+
+use crate::api::functions::content_router::with_charset;
+use crate::api::functions::file_type::{sniff, sniff_text};
+use crate::api::functions::legacy_charset::decode_for_host;
+use crate::api::functions::navigation_events::read_until_quiet;
+use crate::api::models::fetch_response::{FetchResponse, GOPHER_MENU_MIME};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::cso::{format_fields, format_records, list_fields, query_cso};
+use crate::api::protocols::registry::{
+    url_host, BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+use crate::api::storage::gemini_proxies::SchemeProxy;
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::timeouts::{with_idle_timeout, IDLE_TIMEOUT, READ_DEADLINE};
+use crate::api::transport::Connection;
+
+/// Item types that name no document to fetch: 3 an error, 8 and T telnet sessions, and i an
+/// informational line
+const NON_DOCUMENT_TYPES: &[char] = &['3', '8', 'T', 'i'];
+
+/// Registry handler for gopher:// URLs
+pub struct GopherHandler;
+
+impl ProtocolHandler for GopherHandler {
+    fn scheme(&self) -> &'static str {
+        "gopher"
+    }
+
+    fn default_port(&self) -> u16 {
+        70
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities {
+            user_input: true,
+            redirects: false,
+            client_certificates: false,
+            menus: true,
+        }
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            if item_type(url) == Some('2') {
+                return phone_book_lookup(url, host, port).await;
+            }
+            fetch_item(url.to_string(), host, port, url.path(), &selector(url)?).await
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            // A phone book speaks its own protocol, and its answers are short
+            if item_type(url) == Some('2') {
+                let response = phone_book_lookup(url, host, port).await?;
+                return Ok(ResponseBody {
+                    mime: response.mime,
+                    reader: Box::new(Cursor::new(response.body.into_bytes())),
+                });
+            }
+            let mut reader = BufReader::new(send_selector(host, port, &selector(url)?).await?);
+            let start = reader
+                .fill_buf()
+                .await
+                .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+            let mime = gopher_mime(url.path(), start);
+            Ok(ResponseBody {
+                mime,
+                reader: Box::new(reader),
+            })
+        })
+    }
+}
+
+/// Request a selector and build the response for the item type at the start of `path`,
+/// reported under `url`
+async fn fetch_item(
+    url: String,
+    host: &str,
+    port: u16,
+    path: &str,
+    selector: &[u8],
+) -> Result<FetchResponse, NavigateError> {
+    let (content, truncated) = fetch_gopher_bytes(host, port, selector).await?;
+    let mime = gopher_mime(path, &content);
+    // Gopher text carries no charset, so legacy encodings are guessed or configured
+    let (mime, body) = if mime.starts_with("text/") || mime == GOPHER_MENU_MIME {
+        let (body, charset) = decode_for_host(host, &content);
+        (with_charset(&mime, &charset), body)
+    } else {
+        (mime, String::from_utf8_lossy(&content).to_string())
+    };
+    let mut response = FetchResponse::new(url, mime, body);
+    response.truncated = truncated;
+    Ok(response)
+}
+
+/// Fetch a URL of another scheme through a Gopher gateway, asking for the gateway's selector
+/// with the URL in it; the response is reported under the URL itself
+pub(crate) async fn fetch_through_gateway(
+    gateway: &SchemeProxy,
+    url: &Url,
+) -> Result<FetchResponse, NavigateError> {
+    let path = if gateway.selector.contains("{url}") {
+        gateway.selector.replace("{url}", url.as_str())
+    } else {
+        format!("{}{}", gateway.selector, url)
+    };
+    let mut chars = path.chars();
+    chars.next();
+    fetch_item(
+        url.to_string(),
+        &gateway.host,
+        gateway.port,
+        &path,
+        chars.as_str().as_bytes(),
+    )
+    .await
+}
+
+/// The item type at the start of a gopher:// URL's path, if it names one
+fn item_type(url: &Url) -> Option<char> {
+    url.path().trim_start_matches('/').chars().next()
+}
+
+/// Answer a type-2 (CSO phone book) URL by running its query string against the phone book;
+/// without a query, as when the item is opened rather than searched, the page tells how to
+/// search it and lists the fields it can be searched by
+async fn phone_book_lookup(
+    url: &Url,
+    host: &str,
+    port: u16,
+) -> Result<FetchResponse, NavigateError> {
+    let query = url
+        .query()
+        .map(|query| percent_decode_str(query).decode_utf8_lossy().into_owned())
+        .unwrap_or_default();
+    let body = if query.trim().is_empty() {
+        format_fields(&list_fields(host, port).await?)
+    } else {
+        format_records(&query_cso(host, port, &query).await?)
+    };
+    Ok(FetchResponse::new(
+        url.to_string(),
+        "text/plain".to_string(),
+        body,
+    ))
+}
+
+/// The selector a gopher:// URL asks for (RFC 4266): the percent-decoded path after the item
+/// type, with a search query appended after a tab. Fails for an item type that names no
+/// document, and for a selector spanning lines, whose second line the server would take for
+/// another request.
+pub(crate) fn selector(url: &Url) -> Result<Vec<u8>, NavigateError> {
+    if let Some(item_type) =
+        item_type(url).filter(|item_type| NON_DOCUMENT_TYPES.contains(item_type))
+    {
+        return Err(NavigateError::GopherBadType(format!(
+            "Gopher item type {} is not a document",
+            item_type
+        )));
+    }
+    let path = url.path().trim_start_matches('/');
+    let mut chars = path.chars();
+    chars.next();
+    let mut selector: Vec<u8> = percent_decode_str(chars.as_str()).collect();
+    if let Some(query) = url.query() {
+        selector.push(b'\t');
+        selector.extend(percent_decode_str(query));
+    }
+    if selector.contains(&b'\r') || selector.contains(&b'\n') {
+        return Err(NavigateError::GopherBadSelector(
+            "Gopher selector must be a single line".to_string(),
+        ));
+    }
+    Ok(selector)
+}
+
+/// Derive the body MIME type from the item type at the start of a Gopher path, sniffing the
+/// content of binary and unknown item types, which carry no MIME type of their own. Servers
+/// also mislabel files as text or menus, so those give way to a signature of binary content.
+fn gopher_mime(path: &str, content: &[u8]) -> String {
+    let (fallback, declared_text) = match path.trim_start_matches('/').chars().next() {
+        None | Some('1') | Some('7') => (GOPHER_MENU_MIME, true),
+        Some('0') => ("text/plain", true),
+        Some('h') => ("text/html", true),
+        Some('g') => ("image/gif", false),
+        Some('p') => ("image/png", false),
+        Some('4') | Some('5') | Some('6') | Some('9') | Some('I') | Some('s') | Some(';')
+        | Some('d') => ("application/octet-stream", false),
+        _ => ("text/plain", false),
+    };
+    let sniffed = if declared_text {
+        sniff_text(content)
+    } else {
+        sniff(content)
+    };
+    match sniffed {
+        Some(file_type) => file_type.mime,
+        None => fallback.to_string(),
+    }
+}
+
+/// Connect to Gopher server and fetch content
+pub async fn connect_and_fetch_gopher(
+    host: &str,
+    port: u16,
+    path: &str,
+) -> Result<String, NavigateError> {
+    let (response, _) = fetch_gopher_bytes(host, port, path.as_bytes()).await?;
+    Ok(decode_for_host(host, &response).0)
+}
+
+/// Send a selector and read the raw response, which may be binary; the flag is set when the
+/// server kept the connection open and the response was cut off after it went quiet
+pub(crate) async fn fetch_gopher_bytes(
+    host: &str,
+    port: u16,
+    selector: &[u8],
+) -> Result<(Vec<u8>, bool), NavigateError> {
+    let mut stream = send_selector(host, port, selector).await?;
+    read_until_quiet(&mut stream, READ_DEADLINE)
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to read response", e))
+}
+
+/// Connect and send a selector, leaving the response unread
+async fn send_selector(
+    host: &str,
+    port: u16,
+    selector: &[u8],
+) -> Result<Box<dyn Connection>, NavigateError> {
+    // Some servers never close the connection, so a quiet one ends the response
+    let mut stream =
+        with_idle_timeout(IDLE_TIMEOUT, crate::api::transport::dial(host, port)).await?;
+
+    stream
+        .write_all(&[selector, b"\r\n"].concat())
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to send request", e))?;
+
+    Ok(stream)
+}
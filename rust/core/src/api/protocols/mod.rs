@@ -0,0 +1,13 @@
+pub mod gemini;
+pub mod titan;
+pub mod gopher;
+pub mod cso;
+pub mod finger;
+pub mod text;
+pub mod nntp;
+pub mod about;
+pub mod data;
+pub mod view_source;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file;
+pub mod registry;
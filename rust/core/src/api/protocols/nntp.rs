@@ -0,0 +1,275 @@
+use percent_encoding::percent_decode_str;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use url::Url;
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry::{
+    url_host, HandlerFuture, ProtocolCapabilities, ProtocolHandler,
+};
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::Connection;
+
+/// Most recent articles listed on a group page
+const OVERVIEW_ARTICLES: u64 = 100;
+
+/// Registry handler for read-only nntp:// URLs: `nntp://server/` lists the newsgroups,
+/// `nntp://server/group` the latest articles of a group, and `nntp://server/group/number`
+/// shows one article
+pub struct NntpHandler;
+
+impl ProtocolHandler for NntpHandler {
+    fn scheme(&self) -> &'static str {
+        "nntp"
+    }
+
+    fn default_port(&self) -> u16 {
+        119
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities::default()
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            let segments: Vec<String> = url
+                .path_segments()
+                .into_iter()
+                .flatten()
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned())
+                .collect();
+
+            let mut session = NntpSession::connect(host, port).await?;
+            let (mime, body) = match segments.as_slice() {
+                [] => ("text/gemini", group_list(&mut session, url).await?),
+                [group] => (
+                    "text/gemini",
+                    group_overview(&mut session, url, group).await?,
+                ),
+                [group, number] => ("text/plain", article(&mut session, group, number).await?),
+                _ => {
+                    return Err(NavigateError::InvalidUrl(
+                        "Invalid URL: expected nntp://server/group/article".to_string(),
+                    ))
+                }
+            };
+            session.quit().await;
+            Ok(FetchResponse::new(url.to_string(), mime.to_string(), body))
+        })
+    }
+}
+
+/// A connection to a news server that has sent its greeting
+struct NntpSession {
+    reader: BufReader<Box<dyn Connection>>,
+}
+
+impl NntpSession {
+    /// Connect and read the greeting; 200 allows posting and 201 does not, which makes no
+    /// difference to a reader
+    async fn connect(host: &str, port: u16) -> Result<Self, NavigateError> {
+        let mut session = NntpSession {
+            reader: BufReader::new(crate::api::transport::dial(host, port).await?),
+        };
+        let (code, text) = session.status().await?;
+        if code != 200 && code != 201 {
+            return Err(NavigateError::ConnectionRefused(format!(
+                "NNTP server refused the connection: {} {}",
+                code, text
+            )));
+        }
+        Ok(session)
+    }
+
+    /// Send a command and read its status line
+    async fn command(&mut self, command: &str) -> Result<(u16, String), NavigateError> {
+        self.reader
+            .get_mut()
+            .write_all(format!("{}\r\n", command).as_bytes())
+            .await
+            .map_err(|e| NavigateError::from_io("Failed to send request", e))?;
+        self.status().await
+    }
+
+    /// Read a "<code> <text>" status line
+    async fn status(&mut self) -> Result<(u16, String), NavigateError> {
+        let line = self.line().await?.ok_or_else(|| {
+            NavigateError::ConnectionLost("Failed to read response: connection closed".to_string())
+        })?;
+        let (code, text) = line.split_once(' ').unwrap_or((&line, ""));
+        let code = code.parse().map_err(|_| {
+            NavigateError::BadResponse(format!(
+                "Failed to read response: invalid NNTP status line {}",
+                line
+            ))
+        })?;
+        Ok((code, text.to_string()))
+    }
+
+    /// Read the lines of a multi-line response up to its terminating ".", undoing dot-stuffing
+    async fn block(&mut self) -> Result<Vec<String>, NavigateError> {
+        let mut lines = Vec::new();
+        while let Some(line) = self.line().await? {
+            if line == "." {
+                return Ok(lines);
+            }
+            lines.push(line.strip_prefix('.').unwrap_or(&line).to_string());
+        }
+        Err(NavigateError::ConnectionLost(
+            "Failed to read response: connection closed mid-response".to_string(),
+        ))
+    }
+
+    /// Read one line without its line ending, or None at the end of the stream
+    async fn line(&mut self) -> Result<Option<String>, NavigateError> {
+        let mut line = Vec::new();
+        let read = self
+            .reader
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let line = String::from_utf8_lossy(&line);
+        Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+    }
+
+    /// Say goodbye; the page is already read, so a server that hangs up first is no error
+    async fn quit(mut self) {
+        let _ = self.command("QUIT").await;
+    }
+
+    /// Select a group, returning its lowest and highest article numbers
+    async fn select_group(&mut self, group: &str) -> Result<(u64, u64), NavigateError> {
+        match self.command(&format!("GROUP {}", group)).await? {
+            (211, text) => {
+                // "211 <count> <low> <high> <group>"
+                let mut fields = text.split_whitespace().skip(1);
+                let low = fields.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                let high = fields.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                Ok((low, high))
+            }
+            (411, _) => Err(NavigateError::NotFound(format!(
+                "Unknown newsgroup {}",
+                group
+            ))),
+            (code, text) => Err(server_answered(code, &text)),
+        }
+    }
+}
+
+/// Every group the server carries, as a gemtext page of links
+async fn group_list(session: &mut NntpSession, url: &Url) -> Result<String, NavigateError> {
+    let groups = match session.command("LIST ACTIVE").await? {
+        (215, _) => session.block().await?,
+        (code, text) => return Err(server_answered(code, &text)),
+    };
+    let mut page = format!("# Newsgroups on {}\n\n", url.host_str().unwrap_or_default());
+    // "<group> <high> <low> <status>"
+    let mut names: Vec<&str> = groups
+        .iter()
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+    names.sort_unstable();
+    for name in names {
+        page.push_str(&format!("=> {} {}\n", page_url(url, &[name]), name));
+    }
+    Ok(page)
+}
+
+/// The latest articles of a group, newest first, as a gemtext page of links
+async fn group_overview(
+    session: &mut NntpSession,
+    url: &Url,
+    group: &str,
+) -> Result<String, NavigateError> {
+    let (low, high) = session.select_group(group).await?;
+    let mut page = format!("# {}\n\n", group);
+    if high == 0 || high < low {
+        page.push_str("This group has no articles.\n");
+        return Ok(page);
+    }
+    let first = low.max(high.saturating_sub(OVERVIEW_ARTICLES - 1));
+    let range = format!("{}-{}", first, high);
+    // OVER is RFC 3977; older servers only know the XOVER extension it was based on
+    let overview = match session.command(&format!("OVER {}", range)).await? {
+        (224, _) => session.block().await?,
+        (500, _) => match session.command(&format!("XOVER {}", range)).await? {
+            (224, _) => session.block().await?,
+            (code, text) => return Err(server_answered(code, &text)),
+        },
+        (423, _) => Vec::new(),
+        (code, text) => return Err(server_answered(code, &text)),
+    };
+    if overview.is_empty() {
+        page.push_str("This group has no articles.\n");
+    }
+    // "<number>\t<subject>\t<from>\t<date>\t<message-id>\t<references>\t<bytes>\t<lines>"
+    for line in overview.iter().rev() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [number, subject, from, date, ..] = fields.as_slice() else {
+            continue;
+        };
+        let subject = if subject.is_empty() {
+            "(no subject)"
+        } else {
+            subject
+        };
+        page.push_str(&format!(
+            "=> {} {} — {} ({})\n",
+            page_url(url, &[group, number]),
+            subject,
+            from,
+            date
+        ));
+    }
+    Ok(page)
+}
+
+/// One article of a group with its headers, as plain text
+async fn article(
+    session: &mut NntpSession,
+    group: &str,
+    number: &str,
+) -> Result<String, NavigateError> {
+    session.select_group(group).await?;
+    let number: u64 = number.parse().map_err(|_| {
+        NavigateError::InvalidUrl(format!(
+            "Invalid URL: article number {} is not a number",
+            number
+        ))
+    })?;
+    match session.command(&format!("ARTICLE {}", number)).await? {
+        (220, _) => Ok(session.block().await?.join("\n")),
+        (423, _) => Err(NavigateError::NotFound(format!(
+            "Unknown article {} in {}",
+            number, group
+        ))),
+        (code, text) => Err(server_answered(code, &text)),
+    }
+}
+
+/// URL of a page on the same server, keeping any explicit port
+fn page_url(url: &Url, segments: &[&str]) -> Url {
+    let mut page = url.clone();
+    page.set_query(None);
+    page.set_path("/");
+    if let Ok(mut path) = page.path_segments_mut() {
+        path.clear().extend(segments);
+    }
+    page
+}
+
+/// A status the command does not expect
+fn server_answered(code: u16, text: &str) -> NavigateError {
+    NavigateError::BadResponse(format!("NNTP server answered {} {}", code, text))
+}
@@ -0,0 +1,180 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use tokio::io::AsyncRead;
+use url::Url;
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+
+/// Future returned by a protocol handler's fetch
+pub type HandlerFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<FetchResponse, NavigateError>> + Send + 'a>>;
+
+/// Future returned by a protocol handler's open_body
+pub type BodyFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<ResponseBody, NavigateError>> + Send + 'a>>;
+
+/// A successful response whose body is still to be read, for downloads too large for memory
+pub struct ResponseBody {
+    pub mime: String,
+    pub reader: Box<dyn AsyncRead + Send + Unpin>,
+}
+
+/// Optional features a protocol supports, so the UI can adapt its controls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProtocolCapabilities {
+    /// The server can ask the user for input (Gemini status 1x, Gopher search items)
+    pub user_input: bool,
+    /// The server can redirect to another URL
+    pub redirects: bool,
+    /// Identities (client certificates) can be presented
+    pub client_certificates: bool,
+    /// Responses are menus of typed items rather than documents
+    pub menus: bool,
+}
+
+/// A pluggable implementation of one URL scheme
+pub trait ProtocolHandler: Send + Sync {
+    /// The URL scheme this handler serves, e.g. "gemini"
+    fn scheme(&self) -> &'static str;
+
+    /// Port used when the URL does not specify one
+    fn default_port(&self) -> u16;
+
+    /// Whether connections for this scheme are always wrapped in TLS
+    fn requires_tls(&self) -> bool;
+
+    fn capabilities(&self) -> ProtocolCapabilities;
+
+    /// Fetch the resource at an already parsed URL of this handler's scheme
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a>;
+
+    /// Fetch while presenting a stored identity (client certificate); handlers without
+    /// `client_certificates` keep the default, which ignores it
+    fn fetch_with_identity<'a>(&'a self, url: &'a Url, identity_id: &'a str) -> HandlerFuture<'a> {
+        let _ = identity_id;
+        self.fetch(url)
+    }
+
+    /// Request the resource and return its body unread; handlers that build responses in memory
+    /// keep the default, which fetches the whole body first
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let response = self.fetch(url).await?;
+            if let Some(error) = NavigateError::from_response(&response) {
+                return Err(error);
+            }
+            Ok(ResponseBody {
+                mime: response.mime,
+                reader: Box::new(std::io::Cursor::new(response.body.into_bytes())),
+            })
+        })
+    }
+}
+
+/// Summary of a registered protocol exposed to the UI
+#[derive(Debug, Clone)]
+pub struct ProtocolInfo {
+    pub scheme: String,
+    pub default_port: u16,
+    pub requires_tls: bool,
+    pub capabilities: ProtocolCapabilities,
+    /// False when the user has turned the protocol off
+    pub enabled: bool,
+}
+
+/// Protocol handlers keyed by scheme
+#[derive(Default)]
+pub struct ProtocolRegistry {
+    handlers: Vec<Arc<dyn ProtocolHandler>>,
+}
+
+impl ProtocolRegistry {
+    /// Registry preloaded with the built-in protocols
+    pub fn with_builtin_handlers() -> Self {
+        let mut registry = ProtocolRegistry::default();
+        registry.register(Arc::new(crate::api::protocols::gemini::GeminiHandler));
+        registry.register(Arc::new(crate::api::protocols::gopher::GopherHandler));
+        registry.register(Arc::new(crate::api::protocols::finger::FingerHandler));
+        registry.register(Arc::new(crate::api::protocols::text::TextHandler));
+        registry.register(Arc::new(crate::api::protocols::nntp::NntpHandler));
+        registry.register(Arc::new(crate::api::protocols::about::AboutHandler));
+        registry.register(Arc::new(crate::api::protocols::data::DataHandler));
+        registry.register(Arc::new(
+            crate::api::protocols::view_source::ViewSourceHandler,
+        ));
+        // Web builds have no file system to read
+        #[cfg(not(target_arch = "wasm32"))]
+        registry.register(Arc::new(crate::api::protocols::file::FileHandler));
+        registry
+    }
+
+    /// Add a handler, replacing any existing handler for the same scheme
+    pub fn register(&mut self, handler: Arc<dyn ProtocolHandler>) {
+        self.handlers.retain(|h| h.scheme() != handler.scheme());
+        self.handlers.push(handler);
+    }
+
+    pub fn get(&self, scheme: &str) -> Option<Arc<dyn ProtocolHandler>> {
+        self.handlers
+            .iter()
+            .find(|h| h.scheme().eq_ignore_ascii_case(scheme))
+            .cloned()
+    }
+
+    pub fn schemes(&self) -> Vec<&'static str> {
+        self.handlers.iter().map(|h| h.scheme()).collect()
+    }
+
+    pub fn protocols(&self) -> Vec<ProtocolInfo> {
+        self.handlers
+            .iter()
+            .map(|h| ProtocolInfo {
+                scheme: h.scheme().to_string(),
+                default_port: h.default_port(),
+                requires_tls: h.requires_tls(),
+                capabilities: h.capabilities(),
+                enabled: true,
+            })
+            .collect()
+    }
+}
+
+static REGISTRY: LazyLock<RwLock<ProtocolRegistry>> =
+    LazyLock::new(|| RwLock::new(ProtocolRegistry::with_builtin_handlers()));
+
+/// Look up the handler for a scheme in the global registry
+pub fn handler_for(scheme: &str) -> Option<Arc<dyn ProtocolHandler>> {
+    REGISTRY.read().unwrap().get(scheme)
+}
+
+/// Register an additional protocol handler in the global registry
+pub fn register_handler(handler: Arc<dyn ProtocolHandler>) {
+    REGISTRY.write().unwrap().register(handler);
+}
+
+/// Schemes currently served by the global registry
+pub fn registered_schemes() -> Vec<&'static str> {
+    REGISTRY.read().unwrap().schemes()
+}
+
+/// Describe all protocols in the global registry, with configured default ports and toggles applied
+pub fn registered_protocols() -> Vec<ProtocolInfo> {
+    let mut protocols = REGISTRY.read().unwrap().protocols();
+    for protocol in &mut protocols {
+        if let Some(port) = crate::api::storage::ports::scheme_port(&protocol.scheme) {
+            protocol.default_port = port;
+        }
+        protocol.enabled =
+            crate::api::storage::scheme_toggles::is_enabled(&protocol.scheme).unwrap_or(true);
+    }
+    protocols
+}
+
+/// The host of a URL given to a handler whose scheme needs one
+pub(crate) fn url_host(url: &Url) -> Result<&str, NavigateError> {
+    url.host_str()
+        .ok_or_else(|| NavigateError::InvalidUrl("Invalid host in URL".to_string()))
+}
@@ -0,0 +1,147 @@
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use url::Url;
+
+use crate::api::functions::navigation_events::{read_to_end_tracked, report_header};
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::gemini::{iri_to_uri, parse_header, request_url};
+use crate::api::protocols::registry::{
+    url_host, BodyFuture, HandlerFuture, ProtocolCapabilities, ProtocolHandler, ResponseBody,
+};
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::Connection;
+
+/// Longest header accepted: a two-digit status, a space, 1024 bytes of meta, and CRLF
+const MAX_HEADER_BYTES: u64 = 1029;
+
+/// Type of a successful response that names none
+const DEFAULT_MIME: &str = "text/plain";
+
+/// Registry handler for text:// URLs (textprotocol.org): Gemini's request and status-line
+/// framing over plain TCP, without TLS or client certificates
+pub struct TextHandler;
+
+impl ProtocolHandler for TextHandler {
+    fn scheme(&self) -> &'static str {
+        "text"
+    }
+
+    fn default_port(&self) -> u16 {
+        1961
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities {
+            user_input: false,
+            redirects: true,
+            client_certificates: false,
+            menus: false,
+        }
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            let request_url = request_url(url);
+
+            let mut stream = send_request(host, port, request_url.as_str()).await?;
+            let mut header_seen = false;
+            let response = read_to_end_tracked(&mut stream, |response| {
+                if header_seen {
+                    return;
+                }
+                if let Some(end) = response.windows(2).position(|w| w == b"\r\n") {
+                    header_seen = true;
+                    let (status, meta) = parse_header(&String::from_utf8_lossy(&response[..end]));
+                    report_header(status, &meta);
+                }
+            })
+            .await
+            .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+
+            let (status, meta, body) = parse_response(&response);
+            Ok(
+                FetchResponse::new(request_url.to_string(), mime(status, &meta), body)
+                    .with_header(status, meta),
+            )
+        })
+    }
+
+    fn open_body<'a>(&'a self, url: &'a Url) -> BodyFuture<'a> {
+        Box::pin(async move {
+            let host = url_host(url)?;
+            let port = resolve_port(url, self.default_port());
+            let connection = send_request(host, port, request_url(url).as_str()).await?;
+            let mut reader = BufReader::new(connection);
+
+            let mut header = Vec::new();
+            (&mut reader)
+                .take(MAX_HEADER_BYTES)
+                .read_until(b'\n', &mut header)
+                .await
+                .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+            let header = String::from_utf8_lossy(&header);
+            let (status, meta) = parse_header(header.trim_end_matches(['\r', '\n']));
+            report_header(status, &meta);
+            if let Some(error) = NavigateError::from_status(status, &meta) {
+                return Err(error);
+            }
+            Ok(ResponseBody {
+                mime: mime(status, &meta),
+                reader: Box::new(reader),
+            })
+        })
+    }
+}
+
+/// MIME type of a response; only 2x responses carry one, defaulting to plain text
+fn mime(status: u8, meta: &str) -> String {
+    if (20..30).contains(&status) && !meta.is_empty() {
+        meta.to_string()
+    } else {
+        DEFAULT_MIME.to_string()
+    }
+}
+
+/// Split a raw response into its status, meta, and body; a response without a valid status
+/// line is all body
+fn parse_response(response: &[u8]) -> (u8, String, String) {
+    let Some(end) = response.windows(2).position(|w| w == b"\r\n") else {
+        return (
+            20,
+            String::new(),
+            String::from_utf8_lossy(response).to_string(),
+        );
+    };
+    let header = String::from_utf8_lossy(&response[..end]);
+    let code = header.split(' ').next().unwrap_or_default();
+    if code.parse::<u8>().is_err() {
+        return (
+            20,
+            String::new(),
+            String::from_utf8_lossy(response).to_string(),
+        );
+    }
+    let (status, meta) = parse_header(&header);
+    let body = String::from_utf8_lossy(&response[end + 2..]).to_string();
+    (status, meta, body)
+}
+
+/// Connect and send the request line, leaving the response unread
+async fn send_request(
+    host: &str,
+    port: u16,
+    url: &str,
+) -> Result<Box<dyn Connection>, NavigateError> {
+    let mut stream = crate::api::transport::dial(host, port).await?;
+    stream
+        .write_all(format!("{}\r\n", iri_to_uri(url)).as_bytes())
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to send request", e))?;
+    Ok(stream)
+}
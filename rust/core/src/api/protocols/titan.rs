@@ -0,0 +1,141 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use url::Url;
+
+use crate::api::functions::identities::identity_for_url;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::gemini::{credentials, open_request_as, parse_response};
+use crate::api::protocols::registry::url_host;
+use crate::api::storage::ports::resolve_port;
+use crate::api::storage::titan_tokens;
+
+/// Titan shares Gemini's port and TLS, and uploads are answered like Gemini requests
+const TITAN_PORT: u16 = 1965;
+
+/// Characters escaped in Titan parameter values: those that separate parameters or end the URL
+const PARAMETER_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'%')
+    .add(b';')
+    .add(b'=')
+    .add(b'?')
+    .add(b'#');
+
+/// Upload a resource to a Titan URL, such as a wiki page to replace. `url` may be the page's
+/// gemini:// address, which is uploaded to over titan:// at the same place. The `mime`
+/// parameter is left out when None, which servers take as text/gemini; the `token` parameter
+/// carries `token` or else the host's stored token. The identity activated for the page, or
+/// else the one set for the host, is presented unless another one is given. The server's answer, typically a redirect to the updated page,
+/// is returned with the parameters left out of its URL so the token is not shown.
+pub async fn upload(
+    url: &str,
+    data: Vec<u8>,
+    mime: Option<String>,
+    token: Option<String>,
+    identity_id: Option<String>,
+) -> Result<FetchResponse, NavigateError> {
+    let url = titan_url(url)?;
+    crate::api::storage::blocklist::check_url(&url)?;
+    let host = url_host(&url)?.to_string();
+    let port = resolve_port(&url, TITAN_PORT);
+
+    let mime = mime.map(|mime| mime.trim().to_string());
+    if mime.as_deref().is_some_and(|mime| {
+        mime.split_once('/')
+            .is_none_or(|(kind, sub)| kind.is_empty() || sub.is_empty())
+    }) {
+        return Err(NavigateError::InvalidInput(
+            "MIME type must be of the form type/subtype".to_string(),
+        ));
+    }
+    let token = match token.filter(|token| !token.is_empty()) {
+        Some(token) => Some(token),
+        None => titan_tokens::get_titan_token(&host).map_err(NavigateError::StorageError)?,
+    };
+    let identity_id = match identity_id {
+        Some(identity_id) => Some(identity_id),
+        None => {
+            // Identities are activated for the gemini:// pages uploaded to
+            let mut page = url.clone();
+            let _ = page.set_scheme("gemini");
+            identity_for_url(&page).map_err(NavigateError::StorageError)?
+        }
+    };
+    let identity = match identity_id {
+        Some(identity_id) => Some(credentials(&identity_id)?),
+        None => None,
+    };
+
+    let request = request_line(&url, data.len(), mime.as_deref(), token.as_deref());
+    let mut stream = open_request_as(&host, port, &request, identity.as_ref()).await?;
+    let sent = match stream.write_all(&data).await {
+        Ok(()) => stream.flush().await,
+        Err(e) => Err(e),
+    };
+    sent.map_err(|e| NavigateError::from_io("Failed to send upload", e))?;
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .await
+        .map_err(|e| NavigateError::from_io("Failed to read response", e))?;
+    crate::api::functions::data_usage::record(
+        (request.len() + 2 + data.len()) as u64,
+        response.len() as u64,
+    );
+
+    let response = parse_response(&response);
+    Ok(
+        FetchResponse::new(url.to_string(), response.mime(), response.body)
+            .with_header(response.status, response.meta),
+    )
+}
+
+/// The titan:// URL to upload to, without any parameters, query, or fragment it was given with
+fn titan_url(url: &str) -> Result<Url, NavigateError> {
+    let mut url = Url::parse(url.trim())
+        .map_err(|e| NavigateError::InvalidUrl(format!("Invalid URL: {}", e)))?;
+    match url.scheme() {
+        "titan" => {}
+        "gemini" => {
+            url.set_scheme("titan").map_err(|_| {
+                NavigateError::InvalidUrl("Invalid URL: cannot upload over Titan".to_string())
+            })?;
+        }
+        scheme => {
+            return Err(NavigateError::UnsupportedScheme(format!(
+                "Unsupported URL scheme {} for uploads",
+                scheme
+            )))
+        }
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(NavigateError::InvalidUrl("Invalid host in URL".to_string()));
+    }
+    // Parameters follow the last path segment, as in /page;size=10
+    let path = url.path();
+    let segment_start = path.rfind('/').map_or(0, |slash| slash + 1);
+    let path = match path[segment_start..].find(';') {
+        Some(parameters) => path[..segment_start + parameters].to_string(),
+        None => path.to_string(),
+    };
+    url.set_path(if path.is_empty() { "/" } else { &path });
+    url.set_query(None);
+    url.set_fragment(None);
+    Ok(url)
+}
+
+/// The request line of an upload: the URL with its `size`, `mime`, and `token` parameters
+fn request_line(url: &Url, size: usize, mime: Option<&str>, token: Option<&str>) -> String {
+    let mut request = format!("{};size={}", url, size);
+    for (name, value) in [("mime", mime), ("token", token)] {
+        if let Some(value) = value {
+            request.push_str(&format!(
+                ";{}={}",
+                name,
+                utf8_percent_encode(value, PARAMETER_ENCODE_SET)
+            ));
+        }
+    }
+    request
+}
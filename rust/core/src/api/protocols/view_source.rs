@@ -0,0 +1,95 @@
+use url::Url;
+
+use crate::api::functions::identities::identity_for_url;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::functions::navigation_events::read_to_end_tracked;
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::gemini;
+use crate::api::protocols::registry::{self, HandlerFuture, ProtocolCapabilities, ProtocolHandler};
+use crate::api::storage::{blocklist, scheme_toggles};
+
+/// What a view-source: URL starts with before the URL whose source it shows
+const PREFIX: &str = "view-source:";
+
+/// Registry handler for view-source: URLs such as view-source:gemini://host/page, which show
+/// the response to the wrapped URL unparsed, as text/plain: for Gemini the header line and body
+/// exactly as the server sent them, for other protocols the body. No charset is applied beyond
+/// reading the bytes as UTF-8, and redirects and prompts are shown rather than followed.
+pub struct ViewSourceHandler;
+
+impl ProtocolHandler for ViewSourceHandler {
+    fn scheme(&self) -> &'static str {
+        "view-source"
+    }
+
+    /// The connection goes wherever the wrapped URL points
+    fn default_port(&self) -> u16 {
+        0
+    }
+
+    fn requires_tls(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> ProtocolCapabilities {
+        ProtocolCapabilities {
+            client_certificates: true,
+            ..ProtocolCapabilities::default()
+        }
+    }
+
+    fn fetch<'a>(&'a self, url: &'a Url) -> HandlerFuture<'a> {
+        Box::pin(source(url, None))
+    }
+
+    fn fetch_with_identity<'a>(&'a self, url: &'a Url, identity_id: &'a str) -> HandlerFuture<'a> {
+        Box::pin(source(url, Some(identity_id)))
+    }
+}
+
+/// Fetch the wrapped URL without parsing the response. A Gemini request presents the given
+/// identity, or else the one activated for the URL or set for its host, as navigating to the
+/// page would.
+async fn source(url: &Url, identity_id: Option<&str>) -> Result<FetchResponse, NavigateError> {
+    let target = target_url(url)?;
+    blocklist::check_url(&target)?;
+    scheme_toggles::check_url(&target)?;
+
+    let raw = if target.scheme() == "gemini" {
+        let identity_id = match identity_id {
+            Some(identity_id) => Some(identity_id.to_string()),
+            None => identity_for_url(&target).map_err(NavigateError::StorageError)?,
+        };
+        gemini::fetch_raw(&target, identity_id.as_deref()).await?
+    } else {
+        let handler = registry::handler_for(target.scheme()).ok_or_else(|| {
+            NavigateError::UnsupportedScheme(format!(
+                "Unsupported URL scheme. Only {} are supported.",
+                registry::registered_schemes().join(", ")
+            ))
+        })?;
+        let mut body = handler.open_body(&target).await?;
+        read_to_end_tracked(&mut body.reader, |_| {})
+            .await
+            .map_err(|e| NavigateError::from_io("Failed to read response", e))?
+    };
+    Ok(FetchResponse::new(
+        url.to_string(),
+        "text/plain".to_string(),
+        String::from_utf8_lossy(&raw).into_owned(),
+    ))
+}
+
+/// The URL a view-source: URL wraps
+fn target_url(url: &Url) -> Result<Url, NavigateError> {
+    let wrapped = url.as_str().get(PREFIX.len()..).unwrap_or_default();
+    let target = parse_input_url(wrapped)
+        .ok_or_else(|| NavigateError::InvalidUrl(format!("Invalid URL: {}", wrapped)))?;
+    if target.scheme() == url.scheme() {
+        return Err(NavigateError::InvalidUrl(
+            "Invalid URL: view-source: URLs cannot be nested".to_string(),
+        ));
+    }
+    Ok(target)
+}
@@ -0,0 +1,134 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::{now, with_db};
+
+/// Snapshots of pages the user chose to keep; unlike the cache they never expire and are left
+/// alone when browsing data is cleared
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS archive (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    status INTEGER,
+    meta TEXT,
+    mime TEXT NOT NULL,
+    title TEXT,
+    body TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    body_hash TEXT NOT NULL,
+    certificate_fingerprint TEXT,
+    fetched_at INTEGER NOT NULL,
+    archived_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS archive_url ON archive (url);";
+
+/// Summary of an archived snapshot, without its body
+#[derive(Debug, Clone)]
+pub struct ArchivedPage {
+    pub id: i64,
+    pub url: String,
+    /// Protocol status code the page was served with, for protocols that have one (Gemini)
+    pub status: Option<u8>,
+    pub mime: String,
+    pub title: Option<String>,
+    /// Body size in bytes
+    pub size: u64,
+    /// SHA-256 of the body as lowercase hex, e.g. to tell whether two snapshots differ
+    pub body_hash: String,
+    /// Fingerprint of the server certificate the page came over, when it was fetched over TLS
+    pub certificate_fingerprint: Option<String>,
+    /// When the page was fetched from the server, seconds since the Unix epoch
+    pub fetched_at: i64,
+    pub archived_at: i64,
+}
+
+const PAGE_COLUMNS: &str =
+    "id, url, status, mime, title, size, body_hash, certificate_fingerprint, fetched_at, archived_at";
+
+fn page_from_row(row: &Row) -> rusqlite::Result<ArchivedPage> {
+    let size: i64 = row.get(5)?;
+    Ok(ArchivedPage {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        status: row.get(2)?,
+        mime: row.get(3)?,
+        title: row.get(4)?,
+        size: size as u64,
+        body_hash: row.get(6)?,
+        certificate_fingerprint: row.get(7)?,
+        fetched_at: row.get(8)?,
+        archived_at: row.get(9)?,
+    })
+}
+
+/// Keep a snapshot of a response fetched at the given time, returning its summary
+pub(crate) fn store(response: &FetchResponse, fetched_at: i64) -> Result<ArchivedPage, String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO archive (url, status, meta, mime, title, body, size, body_hash,
+                                  certificate_fingerprint, fetched_at, archived_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                response.url,
+                response.status,
+                response.meta,
+                response.mime,
+                response.title,
+                response.body,
+                response.body.len() as i64,
+                response.body_hash,
+                response
+                    .certificate
+                    .as_ref()
+                    .map(|certificate| &certificate.fingerprint),
+                fetched_at,
+                now()
+            ],
+        )?;
+        db.query_row(
+            &format!("SELECT {} FROM archive WHERE id = ?1", PAGE_COLUMNS),
+            [db.last_insert_rowid()],
+            page_from_row,
+        )
+    })
+}
+
+/// Archived snapshots, newest first, optionally only those of one URL
+pub fn list_archived(url: Option<&str>, limit: u32) -> Result<Vec<ArchivedPage>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM archive WHERE ?1 IS NULL OR url = ?1
+             ORDER BY archived_at DESC, id DESC LIMIT ?2",
+            PAGE_COLUMNS
+        ))?;
+        let rows = statement.query_map(params![url, limit], page_from_row)?;
+        rows.collect()
+    })
+}
+
+/// An archived snapshot as a response, analysed again like a cached one; `cached_at` is when
+/// it was fetched
+pub(crate) fn load(id: i64) -> Result<Option<FetchResponse>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT url, status, meta, mime, body, fetched_at FROM archive WHERE id = ?1",
+            [id],
+            |row| {
+                let status: Option<u8> = row.get(1)?;
+                let meta: Option<String> = row.get(2)?;
+                let response = FetchResponse::new(row.get(0)?, row.get(3)?, row.get(4)?);
+                let response = match status {
+                    Some(status) => response.with_header(status, meta.unwrap_or_default()),
+                    None => response,
+                };
+                Ok(response.with_cached_at(row.get(5)?))
+            },
+        )
+        .optional()
+    })
+}
+
+/// Delete an archived snapshot, returning whether it existed
+pub fn delete_archived(id: i64) -> Result<bool, String> {
+    with_db(|db| db.execute("DELETE FROM archive WHERE id = ?1", [id])).map(|n| n > 0)
+}
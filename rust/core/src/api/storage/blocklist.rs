@@ -0,0 +1,144 @@
+use rusqlite::params;
+use url::Url;
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocklist (
+    pattern TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);";
+
+/// Prefix of the error returned when a URL is refused by the blocklist
+pub const BLOCKED_ERROR_PREFIX: &str = "Blocked";
+
+/// How a blocklist pattern is matched against a URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockRuleKind {
+    /// Matches one host exactly, e.g. "spam.example"
+    ExactHost,
+    /// Matches a domain and all of its subdomains, written "*.example.org"
+    Subdomains,
+    /// Matches every URL starting with the pattern, e.g. "gemini://host/path/"
+    UrlPrefix,
+}
+
+/// A single user-defined blocklist entry
+#[derive(Debug, Clone)]
+pub struct BlockRule {
+    pub pattern: String,
+    pub kind: BlockRuleKind,
+    pub created_at: i64,
+}
+
+impl BlockRuleKind {
+    fn from_pattern(pattern: &str) -> Self {
+        if pattern.contains("://") {
+            BlockRuleKind::UrlPrefix
+        } else if pattern.starts_with("*.") {
+            BlockRuleKind::Subdomains
+        } else {
+            BlockRuleKind::ExactHost
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            BlockRuleKind::ExactHost => "host",
+            BlockRuleKind::Subdomains => "subdomains",
+            BlockRuleKind::UrlPrefix => "prefix",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "subdomains" => BlockRuleKind::Subdomains,
+            "prefix" => BlockRuleKind::UrlPrefix,
+            _ => BlockRuleKind::ExactHost,
+        }
+    }
+}
+
+impl BlockRule {
+    /// Check whether this rule matches a parsed URL
+    pub fn matches(&self, url: &Url) -> bool {
+        let host = url.host_str().unwrap_or("").to_ascii_lowercase();
+        match self.kind {
+            BlockRuleKind::ExactHost => host == self.pattern,
+            BlockRuleKind::Subdomains => {
+                let domain = &self.pattern[2..];
+                host == domain || host.ends_with(&format!(".{}", domain))
+            }
+            BlockRuleKind::UrlPrefix => url.as_str().starts_with(&self.pattern),
+        }
+    }
+}
+
+/// Normalize user input into the stored form of a pattern
+fn normalize_pattern(pattern: &str) -> Result<String, String> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() || pattern == "*." {
+        return Err("Blocklist pattern cannot be empty".to_string());
+    }
+    if pattern.contains("://") {
+        // Parse prefixes so they compare equal to the serialized request URL
+        let url = Url::parse(pattern).map_err(|e| format!("Invalid URL prefix: {}", e))?;
+        Ok(url.to_string())
+    } else {
+        Ok(pattern.to_ascii_lowercase())
+    }
+}
+
+/// Add a pattern to the blocklist, returning the stored rule
+pub fn add_rule(pattern: &str) -> Result<BlockRule, String> {
+    let pattern = normalize_pattern(pattern)?;
+    let rule = BlockRule {
+        kind: BlockRuleKind::from_pattern(&pattern),
+        pattern,
+        created_at: now(),
+    };
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO blocklist (pattern, kind, created_at) VALUES (?1, ?2, ?3)",
+            params![rule.pattern, rule.kind.as_str(), rule.created_at],
+        )
+    })?;
+    Ok(rule)
+}
+
+/// Remove a pattern from the blocklist, returning whether it existed
+pub fn remove_rule(pattern: &str) -> Result<bool, String> {
+    let pattern = normalize_pattern(pattern)?;
+    let removed = with_db(|db| db.execute("DELETE FROM blocklist WHERE pattern = ?1", [pattern]))?;
+    Ok(removed > 0)
+}
+
+/// All blocklist rules, oldest first
+pub fn list_rules() -> Result<Vec<BlockRule>, String> {
+    with_db(|db| {
+        let mut statement =
+            db.prepare("SELECT pattern, kind, created_at FROM blocklist ORDER BY created_at")?;
+        let rows = statement.query_map([], |row| {
+            Ok(BlockRule {
+                pattern: row.get(0)?,
+                kind: BlockRuleKind::parse(&row.get::<_, String>(1)?),
+                created_at: row.get(2)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Refuse a URL matched by any blocklist rule before it is fetched
+pub fn check_url(url: &Url) -> Result<(), NavigateError> {
+    let rules = list_rules().map_err(NavigateError::StorageError)?;
+    match rules.into_iter().find(|rule| rule.matches(url)) {
+        Some(rule) => Err(NavigateError::Blocked(format!(
+            "{}: {} matches blocklist rule {}",
+            BLOCKED_ERROR_PREFIX, url, rule.pattern
+        ))),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,203 @@
+use rusqlite::{params, OptionalExtension, Row};
+use url::Url;
+
+use crate::api::functions::canonical_url::equivalence_key;
+use crate::api::storage::history::like_literal;
+use crate::api::storage::{favicons, now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS bookmarks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL UNIQUE,
+    title TEXT,
+    created_at INTEGER NOT NULL
+);";
+
+/// A page the user bookmarked
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    pub id: i64,
+    pub url: String,
+    pub title: Option<String>,
+    /// Seconds since the Unix epoch
+    pub created_at: i64,
+    /// Cached /favicon.txt emoji of the bookmarked capsule
+    pub favicon: Option<String>,
+}
+
+fn bookmark_from_row(row: &Row) -> rusqlite::Result<Bookmark> {
+    Ok(Bookmark {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        created_at: row.get(3)?,
+        favicon: None,
+    })
+}
+
+/// Fill in the favicon of a bookmark read from the database
+fn with_favicon(mut bookmark: Bookmark) -> Bookmark {
+    bookmark.favicon = Url::parse(&bookmark.url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .and_then(|host| favicons::get_favicon(&host).ok().flatten());
+    bookmark
+}
+
+/// Result of `add_bookmark`
+#[derive(Debug, Clone)]
+pub struct AddedBookmark {
+    pub bookmark: Bookmark,
+    /// Set when the URL was already bookmarked, possibly spelled differently; `bookmark` is then
+    /// the existing entry, left unchanged for the UI to merge into
+    pub duplicate: bool,
+}
+
+/// Bookmark a URL unless an equivalent URL is already bookmarked
+pub fn add_bookmark(url: &str, title: Option<&str>) -> Result<AddedBookmark, String> {
+    let url = Url::parse(url.trim()).map_err(|e| format!("Invalid bookmark URL: {}", e))?;
+    if let Some(bookmark) = find_duplicate(&url, None)? {
+        return Ok(AddedBookmark {
+            bookmark,
+            duplicate: true,
+        });
+    }
+    Ok(AddedBookmark {
+        bookmark: insert(&url, title, now())?,
+        duplicate: false,
+    })
+}
+
+/// Bookmark a URL read from another browser with the time it was bookmarked there, unless an
+/// equivalent URL is already bookmarked; returns whether it was added
+pub(crate) fn import(url: &Url, title: Option<&str>, created_at: i64) -> Result<bool, String> {
+    if find_duplicate(url, None)?.is_some() {
+        return Ok(false);
+    }
+    insert(url, title, created_at)?;
+    Ok(true)
+}
+
+fn insert(url: &Url, title: Option<&str>, created_at: i64) -> Result<Bookmark, String> {
+    let bookmark = with_db(|db| {
+        db.execute(
+            "INSERT INTO bookmarks (url, title, created_at) VALUES (?1, ?2, ?3)",
+            params![url.as_str(), title, created_at],
+        )?;
+        db.query_row(
+            "SELECT id, url, title, created_at FROM bookmarks WHERE id = ?1",
+            [db.last_insert_rowid()],
+            bookmark_from_row,
+        )
+    })?;
+    Ok(with_favicon(bookmark))
+}
+
+/// The bookmark of a URL that differs at most in trailing slash, default port, or
+/// percent-encoding
+pub fn find_duplicate_bookmark(url: &str) -> Result<Option<Bookmark>, String> {
+    match Url::parse(url.trim()) {
+        Ok(url) => find_duplicate(&url, None),
+        Err(_) => Ok(None),
+    }
+}
+
+/// First bookmark equivalent to a URL, other than the one with id `except`
+fn find_duplicate(url: &Url, except: Option<i64>) -> Result<Option<Bookmark>, String> {
+    let key = equivalence_key(url);
+    let duplicate = all_bookmarks()?.into_iter().find(|bookmark| {
+        Some(bookmark.id) != except
+            && Url::parse(&bookmark.url).is_ok_and(|url| equivalence_key(&url) == key)
+    });
+    Ok(duplicate.map(with_favicon))
+}
+
+/// Change the title of a bookmark, returning whether it exists
+pub fn set_bookmark_title(id: i64, title: Option<&str>) -> Result<bool, String> {
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE bookmarks SET title = ?2 WHERE id = ?1",
+            params![id, title],
+        )
+    })?;
+    Ok(updated > 0)
+}
+
+/// One bookmark by id
+pub fn get_bookmark(id: i64) -> Result<Option<Bookmark>, String> {
+    let bookmark = with_db(|db| {
+        db.query_row(
+            "SELECT id, url, title, created_at FROM bookmarks WHERE id = ?1",
+            [id],
+            bookmark_from_row,
+        )
+        .optional()
+    })?;
+    Ok(bookmark.map(with_favicon))
+}
+
+/// Every bookmark, oldest first
+pub fn list_bookmarks() -> Result<Vec<Bookmark>, String> {
+    Ok(all_bookmarks()?.into_iter().map(with_favicon).collect())
+}
+
+/// Every bookmark, oldest first, without favicons
+fn all_bookmarks() -> Result<Vec<Bookmark>, String> {
+    with_db(|db| {
+        let mut statement =
+            db.prepare("SELECT id, url, title, created_at FROM bookmarks ORDER BY created_at, id")?;
+        let rows = statement.query_map([], bookmark_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Bookmarks whose URL or title contains a query, case-insensitively, newest first
+pub(crate) fn search(query: &str, limit: u32) -> Result<Vec<Bookmark>, String> {
+    let pattern = format!("%{}%", like_literal(query.trim()));
+    let bookmarks = with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT id, url, title, created_at FROM bookmarks
+             WHERE url LIKE ?1 ESCAPE '\\' OR title LIKE ?1 ESCAPE '\\'
+             ORDER BY created_at DESC, id DESC
+             LIMIT ?2",
+        )?;
+        let rows = statement.query_map(params![pattern, limit], bookmark_from_row)?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+    })?;
+    Ok(bookmarks.into_iter().map(with_favicon).collect())
+}
+
+/// Delete a bookmark, returning whether it existed
+pub fn remove_bookmark(id: i64) -> Result<bool, String> {
+    let removed = with_db(|db| db.execute("DELETE FROM bookmarks WHERE id = ?1", [id]))?;
+    Ok(removed > 0)
+}
+
+/// Bookmarks on one host of a scheme
+pub(crate) fn on_host(scheme: &str, host: &str) -> Result<Vec<Bookmark>, String> {
+    Ok(all_bookmarks()?
+        .into_iter()
+        .filter(|bookmark| {
+            Url::parse(&bookmark.url).is_ok_and(|url| {
+                url.scheme() == scheme
+                    && url.host_str().is_some_and(|h| h.eq_ignore_ascii_case(host))
+            })
+        })
+        .collect())
+}
+
+/// Point a bookmark at a new URL; when that URL is already bookmarked the two are merged,
+/// keeping the one already there
+pub(crate) fn move_bookmark(id: i64, url: &Url) -> Result<(), String> {
+    if find_duplicate(url, Some(id))?.is_some() {
+        remove_bookmark(id)?;
+        return Ok(());
+    }
+    with_db(|db| {
+        db.execute(
+            "UPDATE bookmarks SET url = ?2 WHERE id = ?1",
+            params![id, url.as_str()],
+        )
+    })?;
+    Ok(())
+}
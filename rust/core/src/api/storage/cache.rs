@@ -0,0 +1,369 @@
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::{now, page_index, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cache (
+    url TEXT PRIMARY KEY,
+    host TEXT NOT NULL,
+    status INTEGER,
+    meta TEXT,
+    mime TEXT NOT NULL,
+    body TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    body_hash TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS cache_host ON cache (host);";
+
+/// How long a cached response is served before it counts as stale, unless its scheme's policy says otherwise
+pub const DEFAULT_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// Body bytes of the recently stored responses also kept in memory, so back and forward
+/// navigation to them does not wait on the database
+const MEMORY_BUDGET_BYTES: usize = 8 * 1024 * 1024;
+
+/// A cached response held in memory, ready to serve
+struct MemoryEntry {
+    url: String,
+    response: FetchResponse,
+    expires_at: i64,
+}
+
+/// Recently stored or served responses, least recently used first
+static MEMORY: LazyLock<Mutex<VecDeque<MemoryEntry>>> = LazyLock::new(Default::default);
+
+/// Summary of one cached response, without its body
+#[derive(Debug, Clone)]
+pub struct CacheEntry {
+    pub url: String,
+    pub host: String,
+    pub mime: String,
+    /// Body size in bytes
+    pub size: u64,
+    /// Seconds since the Unix epoch
+    pub fetched_at: i64,
+    pub expires_at: i64,
+    /// Past its expiry, so navigation fetches the page again instead of serving it
+    pub stale: bool,
+}
+
+/// Cache a response under its canonical URL
+pub(crate) fn store(
+    url: &str,
+    host: &str,
+    response: &FetchResponse,
+    ttl_secs: i64,
+) -> Result<(), String> {
+    let fetched_at = now();
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO cache (url, host, status, meta, mime, body, size, body_hash, fetched_at, expires_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                url,
+                host.to_ascii_lowercase(),
+                response.status,
+                response.meta,
+                response.mime,
+                response.body,
+                response.body.len() as i64,
+                response.body_hash,
+                fetched_at,
+                fetched_at + ttl_secs
+            ],
+        )
+    })?;
+    page_index::index_page(
+        url,
+        response.title.as_deref(),
+        &response.mime,
+        &response.body,
+    )?;
+    let response = cached_response(
+        url,
+        response.status,
+        response.meta.clone(),
+        response.mime.clone(),
+        response.body.clone(),
+        fetched_at,
+    );
+    remember(url, response, fetched_at + ttl_secs);
+    Ok(())
+}
+
+/// A response as served from the cache, analysed again so nothing of the request it came
+/// from carries over
+fn cached_response(
+    url: &str,
+    status: Option<u8>,
+    meta: Option<String>,
+    mime: String,
+    body: String,
+    fetched_at: i64,
+) -> FetchResponse {
+    let response = FetchResponse::new(url.to_string(), mime, body);
+    let response = match status {
+        Some(status) => response.with_header(status, meta.unwrap_or_default()),
+        None => response,
+    };
+    response.with_cached_at(fetched_at)
+}
+
+/// Keep a response in memory, dropping the least recently used ones over the budget
+fn remember(url: &str, response: FetchResponse, expires_at: i64) {
+    let mut memory = MEMORY.lock().unwrap();
+    memory.retain(|entry| entry.url != url);
+    if response.body.len() > MEMORY_BUDGET_BYTES {
+        return;
+    }
+    memory.push_back(MemoryEntry {
+        url: url.to_string(),
+        response,
+        expires_at,
+    });
+    let mut used: usize = memory.iter().map(|entry| entry.response.body.len()).sum();
+    while used > MEMORY_BUDGET_BYTES {
+        let Some(oldest) = memory.pop_front() else {
+            break;
+        };
+        used -= oldest.response.body.len();
+    }
+}
+
+/// The in-memory copy of a canonical URL that expires after the given time, marked as most
+/// recently used
+fn recall(url: &str, after: i64) -> Option<FetchResponse> {
+    let mut memory = MEMORY.lock().unwrap();
+    let position = memory
+        .iter()
+        .position(|entry| entry.url == url && entry.expires_at > after)?;
+    let entry = memory.remove(position)?;
+    let mut response = entry.response.clone();
+    response.stale = entry.expires_at <= now();
+    memory.push_back(entry);
+    Some(response)
+}
+
+/// Drop every in-memory copy, e.g. when the profile database is replaced
+pub(crate) fn forget_memory() {
+    MEMORY.lock().unwrap().clear();
+}
+
+/// A fresh cached response for a canonical URL, if there is one
+pub(crate) fn lookup(url: &str) -> Result<Option<FetchResponse>, String> {
+    lookup_expiring_after(url, now())
+}
+
+/// The cached response for a canonical URL, fresh or stale, for use while offline
+pub(crate) fn lookup_stale(url: &str) -> Result<Option<FetchResponse>, String> {
+    lookup_expiring_after(url, i64::MIN)
+}
+
+/// A cached response for a canonical URL that expires after the given time
+fn lookup_expiring_after(url: &str, after: i64) -> Result<Option<FetchResponse>, String> {
+    if let Some(response) = recall(url, after) {
+        return Ok(Some(response));
+    }
+    let stored = with_db(|db| {
+        db.query_row(
+            "SELECT status, meta, mime, body, fetched_at, expires_at FROM cache WHERE url = ?1 AND expires_at > ?2",
+            params![url, after],
+            |row| {
+                let response = cached_response(
+                    url,
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                );
+                Ok((response, row.get::<_, i64>(5)?))
+            },
+        )
+        .optional()
+    })?;
+    Ok(stored.map(|(mut response, expires_at)| {
+        remember(url, response.clone(), expires_at);
+        response.stale = expires_at <= now();
+        response
+    }))
+}
+
+/// Hash of the cached body of a canonical URL, fresh or stale
+pub(crate) fn cached_hash(url: &str) -> Result<Option<String>, String> {
+    with_db(|db| {
+        db.query_row("SELECT body_hash FROM cache WHERE url = ?1", [url], |row| {
+            row.get(0)
+        })
+        .optional()
+    })
+}
+
+/// When a canonical URL was last cached, fresh or stale
+pub(crate) fn fetched_at(url: &str) -> Result<Option<i64>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT fetched_at FROM cache WHERE url = ?1",
+            [url],
+            |row| row.get(0),
+        )
+        .optional()
+    })
+}
+
+/// Cached responses, optionally only those of one host, newest first
+pub fn list_entries(host: Option<&str>) -> Result<Vec<CacheEntry>, String> {
+    let now = now();
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT url, host, mime, size, fetched_at, expires_at FROM cache
+             WHERE ?1 IS NULL OR host = ?1
+             ORDER BY fetched_at DESC",
+        )?;
+        let rows = statement.query_map([host.map(str::to_ascii_lowercase)], |row| {
+            let expires_at: i64 = row.get(5)?;
+            Ok(CacheEntry {
+                url: row.get(0)?,
+                host: row.get(1)?,
+                mime: row.get(2)?,
+                size: row.get::<_, i64>(3)? as u64,
+                fetched_at: row.get(4)?,
+                expires_at,
+                stale: expires_at <= now,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Remove one cached response, returning whether it was cached
+pub fn evict(url: &str) -> Result<bool, String> {
+    let url = crate::api::functions::canonical_url::canonicalize_str(url);
+    MEMORY.lock().unwrap().retain(|entry| entry.url != url);
+    let removed = with_db(|db| db.execute("DELETE FROM cache WHERE url = ?1", [url]))?;
+    Ok(removed > 0)
+}
+
+/// Delete responses cached at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    MEMORY.lock().unwrap().retain(|entry| {
+        entry
+            .response
+            .cached_at
+            .is_some_and(|fetched_at| fetched_at < since)
+    });
+    with_db(|db| db.execute("DELETE FROM cache WHERE fetched_at >= ?1", [since]))
+}
+
+/// Delete responses to URLs with a query cached at or after the given time, returning how many
+/// were removed
+pub(crate) fn clear_queries_since(since: i64) -> Result<usize, String> {
+    MEMORY.lock().unwrap().retain(|entry| {
+        !entry.url.contains('?')
+            || entry
+                .response
+                .cached_at
+                .is_some_and(|fetched_at| fetched_at < since)
+    });
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM cache WHERE fetched_at >= ?1 AND instr(url, '?') > 0",
+            [since],
+        )
+    })
+}
+
+pub(crate) const POLICY_SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cache_policies (
+    scheme TEXT PRIMARY KEY,
+    cacheable INTEGER NOT NULL,
+    ttl_secs INTEGER NOT NULL,
+    cache_queries INTEGER NOT NULL
+);";
+
+/// Whether and for how long responses of one scheme are cached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    pub scheme: String,
+    pub cacheable: bool,
+    pub ttl_secs: u32,
+    /// Also cache URLs with a query string, which usually name search results or input replies
+    pub cache_queries: bool,
+}
+
+impl CachePolicy {
+    /// Built-in policy of a scheme: finger answers change constantly, about: pages, data: URLs,
+    /// and local files are already on the device, view-source: shows what the server sends now,
+    /// gopher menus and newsgroups are kept for an hour, and everything else for a day
+    pub fn default_for(scheme: &str) -> Self {
+        let (cacheable, ttl_secs) = match scheme {
+            "finger" | "about" | "data" | "file" | "view-source" => (false, 0),
+            "gopher" | "nntp" => (true, 60 * 60),
+            _ => (true, DEFAULT_TTL_SECS as u32),
+        };
+        CachePolicy {
+            scheme: scheme.to_string(),
+            cacheable,
+            ttl_secs,
+            cache_queries: false,
+        }
+    }
+
+    /// Whether a response for this URL may be cached
+    pub fn allows(&self, url: &url::Url) -> bool {
+        self.cacheable && self.ttl_secs > 0 && (self.cache_queries || url.query().is_none())
+    }
+}
+
+/// The configured policy of a scheme, or its built-in default
+pub fn policy_for(scheme: &str) -> Result<CachePolicy, String> {
+    let stored = with_db(|db| {
+        db.query_row(
+            "SELECT cacheable, ttl_secs, cache_queries FROM cache_policies WHERE scheme = ?1",
+            [scheme],
+            |row| {
+                Ok(CachePolicy {
+                    scheme: scheme.to_string(),
+                    cacheable: row.get(0)?,
+                    ttl_secs: row.get(1)?,
+                    cache_queries: row.get(2)?,
+                })
+            },
+        )
+        .optional()
+    })?;
+    Ok(stored.unwrap_or_else(|| CachePolicy::default_for(scheme)))
+}
+
+/// Override the policy of a scheme
+pub fn set_policy(policy: &CachePolicy) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO cache_policies (scheme, cacheable, ttl_secs, cache_queries) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                policy.scheme.to_ascii_lowercase(),
+                policy.cacheable,
+                policy.ttl_secs,
+                policy.cache_queries
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Drop the override of a scheme so its built-in default applies again
+pub fn reset_policy(scheme: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM cache_policies WHERE scheme = ?1",
+            [scheme.to_ascii_lowercase()],
+        )
+    })?;
+    Ok(())
+}
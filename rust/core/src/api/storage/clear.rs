@@ -0,0 +1,90 @@
+use crate::api::storage::{
+    cache, data_usage, downloads, favicons, history, host_stats, identities, identity_scopes,
+    input_answers, known_hosts, redirects, sessions,
+};
+
+/// A kind of locally stored browsing data that can be cleared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataCategory {
+    History,
+    /// Cached page responses
+    Cache,
+    /// Daily traffic totals per network type
+    DataUsage,
+    /// The download history; downloaded files stay on disk
+    Downloads,
+    /// Cached /favicon.txt emoji of visited capsules
+    Favicons,
+    /// Per-host request counts, traffic, and latency
+    HostStats,
+    /// Answers given to input prompts, such as search terms, which live on in the query of the
+    /// answered URL: the visits to and cached copies of URLs with a query, and saved answers
+    InputAnswers,
+    /// Identities activated for URL prefixes, the Gemini counterpart of cookies; the identities
+    /// themselves are kept
+    IdentityActivations,
+    /// Client certificates; losing them can lock the user out of capsule accounts
+    Identities,
+    /// Trusted server certificates; without them the next certificate of every server is trusted blindly
+    KnownHosts,
+}
+
+/// Categories cleared when the user does not pick any; identities and known hosts are
+/// deliberately left out, while identity activations go like cookies would
+pub fn default_categories() -> Vec<DataCategory> {
+    vec![
+        DataCategory::History,
+        DataCategory::Cache,
+        DataCategory::Downloads,
+        DataCategory::Favicons,
+        DataCategory::HostStats,
+        DataCategory::IdentityActivations,
+    ]
+}
+
+/// How much was removed from one category
+#[derive(Debug, Clone)]
+pub struct ClearedData {
+    pub category: DataCategory,
+    pub removed: u32,
+}
+
+/// Delete the data of each category recorded at or after `since` (seconds since the Unix epoch),
+/// or all of it when `since` is None
+pub fn clear_data(
+    categories: &[DataCategory],
+    since: Option<i64>,
+) -> Result<Vec<ClearedData>, String> {
+    let since = since.unwrap_or(i64::MIN);
+    categories
+        .iter()
+        .map(|&category| {
+            let removed = match category {
+                // Redirects seen while browsing and the tabs of saved sessions are part of the
+                // history
+                DataCategory::History => {
+                    history::clear_since(since)?
+                        + redirects::clear_since(since)?
+                        + sessions::clear_since(since)?
+                }
+                DataCategory::Cache => cache::clear_since(since)?,
+                DataCategory::DataUsage => data_usage::clear_since(since)?,
+                DataCategory::Downloads => downloads::clear_since(since)?,
+                DataCategory::Favicons => favicons::clear_since(since)?,
+                DataCategory::HostStats => host_stats::clear_since(since)?,
+                DataCategory::InputAnswers => {
+                    history::clear_queries_since(since)?
+                        + cache::clear_queries_since(since)?
+                        + input_answers::clear_since(since)?
+                }
+                DataCategory::IdentityActivations => identity_scopes::clear_since(since)?,
+                DataCategory::Identities => identities::clear_since(since)?,
+                DataCategory::KnownHosts => known_hosts::clear_since(since)?,
+            };
+            Ok(ClearedData {
+                category,
+                removed: removed as u32,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+use rusqlite::params;
+
+use crate::api::storage::{now, with_db};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Traffic totals per UTC day (as days since the Unix epoch) and network type
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS data_usage (
+    day INTEGER NOT NULL,
+    network TEXT NOT NULL,
+    bytes_sent INTEGER NOT NULL,
+    bytes_received INTEGER NOT NULL,
+    PRIMARY KEY (day, network)
+);";
+
+/// Kind of connection the device is on, as reported by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkType {
+    Wifi,
+    /// Mobile data, usually metered
+    Cellular,
+    Ethernet,
+    #[default]
+    Unknown,
+}
+
+impl NetworkType {
+    fn as_str(self) -> &'static str {
+        match self {
+            NetworkType::Wifi => "wifi",
+            NetworkType::Cellular => "cellular",
+            NetworkType::Ethernet => "ethernet",
+            NetworkType::Unknown => "unknown",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "wifi" => NetworkType::Wifi,
+            "cellular" => NetworkType::Cellular,
+            "ethernet" => NetworkType::Ethernet,
+            _ => NetworkType::Unknown,
+        }
+    }
+}
+
+/// Traffic of one day on one network type
+#[derive(Debug, Clone)]
+pub struct DailyUsage {
+    /// Start of the UTC day, in seconds since the Unix epoch
+    pub day_start: i64,
+    pub network: NetworkType,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+}
+
+/// Add traffic to today's totals for a network type
+pub(crate) fn record_usage(
+    network: NetworkType,
+    bytes_sent: u64,
+    bytes_received: u64,
+) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO data_usage (day, network, bytes_sent, bytes_received)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (day, network) DO UPDATE SET
+                bytes_sent = bytes_sent + excluded.bytes_sent,
+                bytes_received = bytes_received + excluded.bytes_received",
+            params![
+                now().div_euclid(SECONDS_PER_DAY),
+                network.as_str(),
+                bytes_sent as i64,
+                bytes_received as i64
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Daily totals for the days touching the range (seconds since the Unix epoch), oldest first
+pub fn usage_between(since: i64, until: i64) -> Result<Vec<DailyUsage>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT day, network, bytes_sent, bytes_received FROM data_usage
+             WHERE day BETWEEN ?1 AND ?2 ORDER BY day, network",
+        )?;
+        let rows = statement.query_map(
+            params![
+                since.div_euclid(SECONDS_PER_DAY),
+                until.div_euclid(SECONDS_PER_DAY)
+            ],
+            |row| {
+                Ok(DailyUsage {
+                    day_start: row.get::<_, i64>(0)? * SECONDS_PER_DAY,
+                    network: NetworkType::parse(&row.get::<_, String>(1)?),
+                    bytes_sent: row.get::<_, i64>(2)? as u64,
+                    bytes_received: row.get::<_, i64>(3)? as u64,
+                })
+            },
+        )?;
+        rows.collect()
+    })
+}
+
+/// Total bytes sent and received on a network type over the last `days` days including today
+pub(crate) fn recent_total(network: NetworkType, days: u32) -> Result<u64, String> {
+    let today = now().div_euclid(SECONDS_PER_DAY);
+    with_db(|db| {
+        db.query_row(
+            "SELECT COALESCE(SUM(bytes_sent + bytes_received), 0) FROM data_usage
+             WHERE network = ?1 AND day > ?2",
+            params![network.as_str(), today - days as i64],
+            |row| row.get::<_, i64>(0),
+        )
+    })
+    .map(|total| total as u64)
+}
+
+/// Delete the totals of days at or after the day containing the given time, returning how many
+/// were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM data_usage WHERE day >= ?1",
+            [since.div_euclid(SECONDS_PER_DAY)],
+        )
+    })
+}
@@ -0,0 +1,177 @@
+use rusqlite::{params, Row};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS downloads (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    path TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    mime TEXT NOT NULL,
+    status TEXT NOT NULL,
+    error TEXT,
+    finished_at INTEGER NOT NULL,
+    checksum_url TEXT,
+    checksum_status TEXT NOT NULL DEFAULT 'unchecked'
+);
+CREATE INDEX IF NOT EXISTS downloads_finished_at ON downloads (finished_at);";
+
+/// How a download ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadStatus {
+    Completed,
+    Failed,
+}
+
+impl DownloadStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            DownloadStatus::Completed => "completed",
+            DownloadStatus::Failed => "failed",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "completed" => DownloadStatus::Completed,
+            _ => DownloadStatus::Failed,
+        }
+    }
+}
+
+/// Whether a download matched the checksum published alongside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// No checksum was given to check against
+    Unchecked,
+    /// The file's SHA-256 is the one the checksum file gives
+    Verified,
+    /// The file's SHA-256 differs: it was corrupted or tampered with
+    Mismatch,
+    /// The checksum file could not be fetched or gave no hash for the file
+    Unavailable,
+}
+
+impl ChecksumStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChecksumStatus::Unchecked => "unchecked",
+            ChecksumStatus::Verified => "verified",
+            ChecksumStatus::Mismatch => "mismatch",
+            ChecksumStatus::Unavailable => "unavailable",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "verified" => ChecksumStatus::Verified,
+            "mismatch" => ChecksumStatus::Mismatch,
+            "unavailable" => ChecksumStatus::Unavailable,
+            _ => ChecksumStatus::Unchecked,
+        }
+    }
+}
+
+/// A finished download, kept so the downloads screen survives restarts
+#[derive(Debug, Clone)]
+pub struct DownloadRecord {
+    pub id: i64,
+    pub url: String,
+    /// Where the file was (or would have been) written
+    pub path: String,
+    /// Bytes written to disk
+    pub size: u64,
+    pub mime: String,
+    pub status: DownloadStatus,
+    /// Why a failed download stopped
+    pub error: Option<String>,
+    /// Seconds since the Unix epoch
+    pub finished_at: i64,
+    /// The checksum file the download was checked against
+    pub checksum_url: Option<String>,
+    pub checksum: ChecksumStatus,
+}
+
+fn record_from_row(row: &Row) -> rusqlite::Result<DownloadRecord> {
+    let size: i64 = row.get(3)?;
+    let status: String = row.get(5)?;
+    let checksum: String = row.get(9)?;
+    Ok(DownloadRecord {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        path: row.get(2)?,
+        size: size as u64,
+        mime: row.get(4)?,
+        status: DownloadStatus::parse(&status),
+        error: row.get(6)?,
+        finished_at: row.get(7)?,
+        checksum_url: row.get(8)?,
+        checksum: ChecksumStatus::parse(&checksum),
+    })
+}
+
+/// Record a completed or failed download, returning its id
+pub fn record_download(
+    url: &str,
+    path: &str,
+    size: u64,
+    mime: &str,
+    status: DownloadStatus,
+    error: Option<&str>,
+) -> Result<i64, String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO downloads (url, path, size, mime, status, error, finished_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![url, path, size as i64, mime, status.as_str(), error, now()],
+        )?;
+        Ok(db.last_insert_rowid())
+    })
+}
+
+/// Record how a download compared with the checksum file published alongside it
+pub(crate) fn record_checksum(
+    id: i64,
+    checksum_url: &str,
+    checksum: ChecksumStatus,
+) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE downloads SET checksum_url = ?2, checksum_status = ?3 WHERE id = ?1",
+            params![id, checksum_url, checksum.as_str()],
+        )
+    })?;
+    Ok(())
+}
+
+/// Recorded downloads, newest first, optionally only those with the given status
+pub fn list_downloads(
+    status: Option<DownloadStatus>,
+    limit: u32,
+) -> Result<Vec<DownloadRecord>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT id, url, path, size, mime, status, error, finished_at, checksum_url,
+                    checksum_status
+             FROM downloads
+             WHERE ?1 IS NULL OR status = ?1
+             ORDER BY finished_at DESC, id DESC LIMIT ?2",
+        )?;
+        let rows = statement.query_map(
+            params![status.map(DownloadStatus::as_str), limit],
+            record_from_row,
+        )?;
+        rows.collect()
+    })
+}
+
+/// Forget one download (the file itself is left alone), returning whether it was recorded
+pub fn delete_download(id: i64) -> Result<bool, String> {
+    with_db(|db| db.execute("DELETE FROM downloads WHERE id = ?1", [id])).map(|n| n > 0)
+}
+
+/// Delete downloads finished at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM downloads WHERE finished_at >= ?1", [since]))
+}
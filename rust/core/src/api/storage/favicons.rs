@@ -0,0 +1,47 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS favicons (
+    host TEXT PRIMARY KEY,
+    icon TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL
+);";
+
+/// Remember the favicon (an emoji from /favicon.txt) of a host, or with None that it has none,
+/// so it is not asked again until that is checked again
+pub(crate) fn store_favicon(host: &str, icon: Option<&str>) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO favicons (host, icon, fetched_at) VALUES (?1, ?2, ?3)",
+            params![host.to_ascii_lowercase(), icon.unwrap_or_default(), now()],
+        )
+    })?;
+    Ok(())
+}
+
+/// The cached favicon of a host, if one was fetched
+pub fn get_favicon(host: &str) -> Result<Option<String>, String> {
+    Ok(cached_favicon(host)?.and_then(|(icon, _)| icon))
+}
+
+/// What was last learned of a host's favicon and when: the icon, or None if it had none
+pub(crate) fn cached_favicon(host: &str) -> Result<Option<(Option<String>, i64)>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT icon, fetched_at FROM favicons WHERE host = ?1",
+            [host.to_ascii_lowercase()],
+            |row| {
+                let icon: String = row.get(0)?;
+                Ok(((!icon.is_empty()).then_some(icon), row.get(1)?))
+            },
+        )
+        .optional()
+    })
+}
+
+/// Delete favicons fetched at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM favicons WHERE fetched_at >= ?1", [since]))
+}
@@ -0,0 +1,261 @@
+use rusqlite::{params, OptionalExtension, Row};
+use url::Url;
+
+use crate::api::functions::feed_discovery::FeedKind;
+use crate::api::parsers::feed::ParsedEntry;
+use crate::api::storage::{now, with_db, with_transaction};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS feeds (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL UNIQUE,
+    title TEXT,
+    kind TEXT NOT NULL,
+    subscribed_at INTEGER NOT NULL,
+    refreshed_at INTEGER,
+    last_error TEXT
+);
+CREATE TABLE IF NOT EXISTS feed_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    feed_id INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    title TEXT NOT NULL,
+    published_at INTEGER,
+    seen_at INTEGER NOT NULL,
+    read INTEGER NOT NULL DEFAULT 0,
+    UNIQUE (feed_id, url)
+);
+CREATE INDEX IF NOT EXISTS feed_entries_url ON feed_entries (url);";
+
+fn kind_as_str(kind: FeedKind) -> &'static str {
+    match kind {
+        FeedKind::Atom => "atom",
+        FeedKind::Rss => "rss",
+        FeedKind::Gemfeed => "gemfeed",
+        FeedKind::Unknown => "unknown",
+    }
+}
+
+fn parse_kind(value: &str) -> FeedKind {
+    match value {
+        "atom" => FeedKind::Atom,
+        "rss" => FeedKind::Rss,
+        "gemfeed" => FeedKind::Gemfeed,
+        _ => FeedKind::Unknown,
+    }
+}
+
+/// A feed the user subscribed to
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub id: i64,
+    pub url: String,
+    /// Title the user gave or, once fetched, the feed's own
+    pub title: Option<String>,
+    /// Flavour of the feed, Unknown until it is first fetched
+    pub kind: FeedKind,
+    /// Seconds since the Unix epoch
+    pub subscribed_at: i64,
+    /// When the feed was last fetched, successfully or not
+    pub refreshed_at: Option<i64>,
+    /// Why the last fetch failed, cleared by a successful one
+    pub last_error: Option<String>,
+    pub unread: u32,
+}
+
+const FEED_COLUMNS: &str = "id, url, title, kind, subscribed_at, refreshed_at, last_error, \
+                            (SELECT COUNT(*) FROM feed_entries WHERE feed_id = feeds.id AND read = 0)";
+
+fn feed_from_row(row: &Row) -> rusqlite::Result<Feed> {
+    let kind: String = row.get(3)?;
+    Ok(Feed {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        kind: parse_kind(&kind),
+        subscribed_at: row.get(4)?,
+        refreshed_at: row.get(5)?,
+        last_error: row.get(6)?,
+        unread: row.get(7)?,
+    })
+}
+
+/// An entry of the merged timeline of every subscription
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    pub id: i64,
+    pub feed_id: i64,
+    pub feed_title: Option<String>,
+    pub url: String,
+    pub title: String,
+    /// When the feed dates the entry, in seconds since the Unix epoch
+    pub published_at: Option<i64>,
+    /// When the entry first appeared in a refresh
+    pub seen_at: i64,
+    pub read: bool,
+}
+
+/// Subscribe to a feed, or return the existing subscription of the same URL
+pub fn subscribe(url: &str, title: Option<&str>, kind: FeedKind) -> Result<Feed, String> {
+    let url = Url::parse(url.trim()).map_err(|e| format!("Invalid feed URL: {}", e))?;
+    let title = title.map(str::trim).filter(|title| !title.is_empty());
+    with_db(|db| {
+        db.execute(
+            "INSERT OR IGNORE INTO feeds (url, title, kind, subscribed_at) VALUES (?1, ?2, ?3, ?4)",
+            params![url.as_str(), title, kind_as_str(kind), now()],
+        )?;
+        db.query_row(
+            &format!("SELECT {} FROM feeds WHERE url = ?1", FEED_COLUMNS),
+            [url.as_str()],
+            feed_from_row,
+        )
+    })
+}
+
+/// Drop a subscription and its entries, returning whether it existed
+pub fn unsubscribe(id: i64) -> Result<bool, String> {
+    with_transaction(|db| {
+        db.execute("DELETE FROM feed_entries WHERE feed_id = ?1", [id])?;
+        Ok(db.execute("DELETE FROM feeds WHERE id = ?1", [id])? > 0)
+    })
+}
+
+/// A subscription by id
+pub fn get_feed(id: i64) -> Result<Option<Feed>, String> {
+    with_db(|db| {
+        db.query_row(
+            &format!("SELECT {} FROM feeds WHERE id = ?1", FEED_COLUMNS),
+            [id],
+            feed_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Every subscription with its unread count, by title
+pub fn list_feeds() -> Result<Vec<Feed>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM feeds ORDER BY COALESCE(title, url) COLLATE NOCASE",
+            FEED_COLUMNS
+        ))?;
+        let rows = statement.query_map([], feed_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Store the entries of a successful fetch, returning how many were new; entries already seen
+/// keep their read state, and the feed's title replaces the stored one only when it had none
+pub(crate) fn store_entries(
+    id: i64,
+    title: Option<&str>,
+    kind: FeedKind,
+    entries: &[ParsedEntry],
+) -> Result<u32, String> {
+    let fetched_at = now();
+    with_transaction(|db| {
+        db.execute(
+            "UPDATE feeds SET title = COALESCE(title, ?2), kind = ?3, refreshed_at = ?4,
+                 last_error = NULL
+             WHERE id = ?1",
+            params![id, title, kind_as_str(kind), fetched_at],
+        )?;
+        let mut insert = db.prepare(
+            "INSERT OR IGNORE INTO feed_entries (feed_id, url, title, published_at, seen_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        let mut added = 0;
+        for entry in entries {
+            added += insert.execute(params![
+                id,
+                entry.url,
+                entry.title,
+                entry.published,
+                fetched_at
+            ])? as u32;
+        }
+        Ok(added)
+    })
+}
+
+/// Record why fetching a feed failed
+pub(crate) fn record_error(id: i64, error: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE feeds SET refreshed_at = ?2, last_error = ?3 WHERE id = ?1",
+            params![id, now(), error],
+        )
+    })?;
+    Ok(())
+}
+
+/// Entries of every subscription, or of one, newest first. An article found in several feeds
+/// (e.g. a capsule's Atom feed and its gemfeed) is listed once, under the feed that found it
+/// first.
+pub fn list_entries(
+    feed_id: Option<i64>,
+    unread_only: bool,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<FeedEntry>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT e.id, e.feed_id, f.title, e.url, e.title, e.published_at, e.seen_at, e.read
+             FROM feed_entries e JOIN feeds f ON f.id = e.feed_id
+             WHERE e.id = (SELECT MIN(id) FROM feed_entries WHERE url = e.url)
+                 AND (?1 IS NULL OR e.feed_id = ?1)
+                 AND (?2 = 0 OR e.read = 0)
+             ORDER BY COALESCE(e.published_at, e.seen_at) DESC, e.id DESC
+             LIMIT ?3 OFFSET ?4",
+        )?;
+        let rows = statement.query_map(params![feed_id, unread_only, limit, offset], |row| {
+            Ok(FeedEntry {
+                id: row.get(0)?,
+                feed_id: row.get(1)?,
+                feed_title: row.get(2)?,
+                url: row.get(3)?,
+                title: row.get(4)?,
+                published_at: row.get(5)?,
+                seen_at: row.get(6)?,
+                read: row.get(7)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Mark an entry read or unread, in every feed that lists it; returns whether it exists
+pub fn set_entry_read(id: i64, read: bool) -> Result<bool, String> {
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE feed_entries SET read = ?2
+             WHERE url = (SELECT url FROM feed_entries WHERE id = ?1)",
+            params![id, read],
+        )
+    })?;
+    Ok(updated > 0)
+}
+
+/// Mark every entry of a feed, or of all feeds, read; returns how many were unread
+pub fn mark_all_read(feed_id: Option<i64>) -> Result<u32, String> {
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE feed_entries SET read = 1
+             WHERE read = 0 AND (?1 IS NULL
+                 OR url IN (SELECT url FROM feed_entries WHERE feed_id = ?1))",
+            [feed_id],
+        )
+    })?;
+    Ok(updated as u32)
+}
+
+/// Number of unread entries across every subscription, counting an article once
+pub fn unread_count() -> Result<u32, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT COUNT(DISTINCT url) FROM feed_entries WHERE read = 0",
+            [],
+            |row| row.get(0),
+        )
+    })
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS frecency (
+    url TEXT PRIMARY KEY,
+    visit_count INTEGER NOT NULL,
+    last_visited INTEGER NOT NULL,
+    rank REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS frecency_rank ON frecency (rank);";
+
+/// Age at which a visit counts half as much as one made now
+const HALF_LIFE_SECS: f64 = 14.0 * 24.0 * 60.0 * 60.0;
+
+// Frecency sums a weight of 1 for every visit to a URL, halving every HALF_LIFE_SECS. Each row
+// stores it as a rank, log2 of the sum plus the time in half-lives, which no later decay
+// changes, so ordering by rank orders by frecency at any moment and a visit updates one row.
+
+/// Visit statistics of one URL
+#[derive(Debug, Clone)]
+pub(crate) struct Frecency {
+    pub url: String,
+    pub visit_count: u32,
+    pub last_visited: i64,
+    /// Frecency now
+    pub score: f64,
+}
+
+fn rank_at(score: f64, at: i64) -> f64 {
+    score.log2() + at as f64 / HALF_LIFE_SECS
+}
+
+fn score_at(rank: f64, at: i64) -> f64 {
+    (rank - at as f64 / HALF_LIFE_SECS).exp2()
+}
+
+/// Add a visit to a URL made at `at` to its frecency
+pub(crate) fn record(db: &Connection, url: &str, at: i64) -> rusqlite::Result<()> {
+    let previous: Option<(u32, i64, f64)> = db
+        .query_row(
+            "SELECT visit_count, last_visited, rank FROM frecency WHERE url = ?1",
+            [url],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let (visit_count, last_visited, score) = match previous {
+        Some((count, last, rank)) if at >= last => (count + 1, at, score_at(rank, at) + 1.0),
+        // A visit made before the latest one has already lost some of its weight
+        Some((count, last, rank)) => (
+            count + 1,
+            last,
+            score_at(rank, last) + score_at(rank_at(1.0, at), last),
+        ),
+        None => (1, at, 1.0),
+    };
+    db.execute(
+        "INSERT OR REPLACE INTO frecency (url, visit_count, last_visited, rank)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![url, visit_count, last_visited, rank_at(score, last_visited)],
+    )?;
+    Ok(())
+}
+
+/// Recompute the frecency of a URL from its visits in history, e.g. after some were deleted
+pub(crate) fn rebuild(db: &Connection, url: &str) -> rusqlite::Result<()> {
+    db.execute("DELETE FROM frecency WHERE url = ?1", [url])?;
+    let mut statement =
+        db.prepare("SELECT visited_at FROM history WHERE url = ?1 ORDER BY visited_at")?;
+    let visits = statement.query_map([url], |row| row.get::<_, i64>(0))?;
+    for visited_at in visits {
+        record(db, url, visited_at?)?;
+    }
+    Ok(())
+}
+
+/// Recompute the frecency of every URL in history
+pub(crate) fn rebuild_all(db: &Connection) -> rusqlite::Result<()> {
+    db.execute("DELETE FROM frecency", [])?;
+    let mut statement =
+        db.prepare("SELECT url, visited_at FROM history ORDER BY visited_at, id")?;
+    let visits = statement.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get(1)?)))?;
+    for visit in visits {
+        let (url, visited_at) = visit?;
+        record(db, &url, visited_at)?;
+    }
+    Ok(())
+}
+
+/// Fill in the frecency of history recorded before it was tracked
+pub(crate) fn rebuild_if_missing(db: &Connection) -> rusqlite::Result<()> {
+    let tracked = db.prepare("SELECT 1 FROM frecency LIMIT 1")?.exists([])?;
+    let visited = db.prepare("SELECT 1 FROM history LIMIT 1")?.exists([])?;
+    if visited && !tracked {
+        rebuild_all(db)?;
+    }
+    Ok(())
+}
+
+/// The URLs with the highest frecency, highest first
+pub(crate) fn top(limit: u32) -> Result<Vec<Frecency>, String> {
+    let now = now();
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT url, visit_count, last_visited, rank FROM frecency
+             ORDER BY rank DESC
+             LIMIT ?1",
+        )?;
+        let rows = statement.query_map([limit], |row| {
+            Ok(Frecency {
+                url: row.get(0)?,
+                visit_count: row.get(1)?,
+                last_visited: row.get(2)?,
+                score: score_at(row.get(3)?, now),
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// The current frecency of each of the given URLs that was ever visited
+pub(crate) fn scores(urls: &[&str]) -> Result<HashMap<String, f64>, String> {
+    let now = now();
+    with_db(|db| {
+        let mut statement = db.prepare("SELECT rank FROM frecency WHERE url = ?1")?;
+        let mut scores = HashMap::new();
+        for url in urls {
+            if let Some(rank) = statement
+                .query_row([url], |row| row.get::<_, f64>(0))
+                .optional()?
+            {
+                scores.insert(url.to_string(), score_at(rank, now));
+            }
+        }
+        Ok(scores)
+    })
+}
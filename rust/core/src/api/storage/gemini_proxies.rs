@@ -0,0 +1,151 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::storage::with_db;
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS gemini_proxies (
+    scheme TEXT PRIMARY KEY,
+    host TEXT NOT NULL,
+    port INTEGER NOT NULL
+);";
+
+/// Protocol spoken to the upstream server of a proxied scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProxyProtocol {
+    /// A Gemini proxy, sent the full URL as an ordinary Gemini request
+    #[default]
+    Gemini,
+    /// A Gopher gateway, sent a selector containing the URL
+    Gopher,
+}
+
+impl ProxyProtocol {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProxyProtocol::Gemini => "gemini",
+            ProxyProtocol::Gopher => "gopher",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "gopher" => ProxyProtocol::Gopher,
+            _ => ProxyProtocol::Gemini,
+        }
+    }
+}
+
+/// A server that fetches URLs of another scheme (e.g. http) on the browser's behalf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemeProxy {
+    pub scheme: String,
+    pub protocol: ProxyProtocol,
+    pub host: String,
+    pub port: u16,
+    /// For a Gopher gateway, the item type and selector requested, as in the path of a gopher://
+    /// URL: `{url}` is replaced by the URL, which is appended when there is no placeholder.
+    /// Empty for a Gemini proxy.
+    pub selector: String,
+}
+
+const PROXY_COLUMNS: &str = "scheme, protocol, host, port, selector";
+
+fn proxy_from_row(row: &Row) -> rusqlite::Result<SchemeProxy> {
+    let protocol: String = row.get(1)?;
+    Ok(SchemeProxy {
+        scheme: row.get(0)?,
+        protocol: ProxyProtocol::parse(&protocol),
+        host: row.get(2)?,
+        port: row.get(3)?,
+        selector: row.get(4)?,
+    })
+}
+
+/// Send every URL of a scheme to a Gemini proxy, replacing the scheme's handler if it has one
+pub fn set_proxy(scheme: &str, host: &str, port: u16) -> Result<(), String> {
+    store(scheme, ProxyProtocol::Gemini, host, port, "")
+}
+
+/// Send every URL of a scheme to a Gopher gateway, which is asked for `selector` (an item type
+/// followed by the selector, e.g. "0/fetch?{url}") with the URL in place of `{url}` or appended
+pub fn set_gopher_gateway(
+    scheme: &str,
+    host: &str,
+    port: u16,
+    selector: &str,
+) -> Result<(), String> {
+    let selector = selector.trim_start_matches('/');
+    if selector.is_empty() {
+        return Err("Gateway selector cannot be empty".to_string());
+    }
+    if selector.contains(['\t', '\r', '\n']) {
+        return Err("Gateway selector must not contain tabs or line breaks".to_string());
+    }
+    store(scheme, ProxyProtocol::Gopher, host, port, selector)
+}
+
+fn store(
+    scheme: &str,
+    protocol: ProxyProtocol,
+    host: &str,
+    port: u16,
+    selector: &str,
+) -> Result<(), String> {
+    let scheme = scheme.trim().to_ascii_lowercase();
+    let host = host.trim().to_ascii_lowercase();
+    if scheme.is_empty() {
+        return Err("Scheme cannot be empty".to_string());
+    }
+    if host.is_empty() {
+        return Err("Proxy host cannot be empty".to_string());
+    }
+    if port == 0 {
+        return Err("Port must be between 1 and 65535".to_string());
+    }
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO gemini_proxies (scheme, protocol, host, port, selector)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![scheme, protocol.as_str(), host, port, selector],
+        )
+    })?;
+    Ok(())
+}
+
+/// Stop proxying a scheme, returning whether it was proxied
+pub fn remove_proxy(scheme: &str) -> Result<bool, String> {
+    let removed = with_db(|db| {
+        db.execute(
+            "DELETE FROM gemini_proxies WHERE scheme = ?1",
+            [scheme.trim().to_ascii_lowercase()],
+        )
+    })?;
+    Ok(removed > 0)
+}
+
+/// Every configured proxy and gateway, by scheme
+pub fn list_proxies() -> Result<Vec<SchemeProxy>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM gemini_proxies ORDER BY scheme",
+            PROXY_COLUMNS
+        ))?;
+        let rows = statement.query_map([], proxy_from_row)?;
+        rows.collect()
+    })
+}
+
+/// The proxy or gateway URLs of a scheme are sent to, if any
+pub fn proxy_for(scheme: &str) -> Result<Option<SchemeProxy>, String> {
+    with_db(|db| {
+        db.query_row(
+            &format!(
+                "SELECT {} FROM gemini_proxies WHERE scheme = ?1",
+                PROXY_COLUMNS
+            ),
+            [scheme.to_ascii_lowercase()],
+            proxy_from_row,
+        )
+        .optional()
+    })
+}
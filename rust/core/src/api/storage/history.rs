@@ -0,0 +1,250 @@
+use rusqlite::{params, OptionalExtension, Row};
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::storage::{frecency, now, with_db, with_transaction};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    title TEXT,
+    visited_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS history_url ON history (url);
+CREATE INDEX IF NOT EXISTS history_visited_at ON history (visited_at);";
+
+/// One visit to a page
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub url: String,
+    pub title: Option<String>,
+    /// Seconds since the Unix epoch
+    pub visited_at: i64,
+}
+
+fn entry_from_row(row: &Row) -> rusqlite::Result<HistoryEntry> {
+    Ok(HistoryEntry {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        visited_at: row.get(3)?,
+    })
+}
+
+/// Record a visit to a page
+pub(crate) fn record_visit(url: &str, title: Option<&str>) -> Result<(), String> {
+    let visited_at = now();
+    with_transaction(|db| {
+        db.execute(
+            "INSERT INTO history (url, title, visited_at) VALUES (?1, ?2, ?3)",
+            params![url, title, visited_at],
+        )?;
+        frecency::record(db, url, visited_at)
+    })
+}
+
+/// Record a visit made outside the navigation pipeline, e.g. a page the UI loaded itself
+pub fn add_visit(url: &str, title: Option<&str>) -> Result<(), String> {
+    let url = Url::parse(url.trim()).map_err(|_| "Invalid URL format".to_string())?;
+    let title = title.map(str::trim).filter(|title| !title.is_empty());
+    record_visit(canonicalize(&url).as_str(), title)
+}
+
+/// Delete one visit, returning whether it existed
+pub fn delete_visit(id: i64) -> Result<bool, String> {
+    with_transaction(|db| {
+        let url: Option<String> = db
+            .query_row("SELECT url FROM history WHERE id = ?1", [id], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        let Some(url) = url else {
+            return Ok(false);
+        };
+        db.execute("DELETE FROM history WHERE id = ?1", [id])?;
+        frecency::rebuild(db, &url)?;
+        Ok(true)
+    })
+}
+
+/// Delete every visit to a page, returning how many were removed
+pub fn delete_url(url: &str) -> Result<u32, String> {
+    let url = match Url::parse(url.trim()) {
+        Ok(url) => canonicalize(&url).to_string(),
+        Err(_) => url.trim().to_string(),
+    };
+    let removed = with_transaction(|db| {
+        db.execute("DELETE FROM frecency WHERE url = ?1", [&url])?;
+        db.execute("DELETE FROM history WHERE url = ?1", [&url])
+    })?;
+    Ok(removed as u32)
+}
+
+/// The most recent visits, newest first
+pub fn recent_history(limit: u32) -> Result<Vec<HistoryEntry>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT id, url, title, visited_at FROM history ORDER BY visited_at DESC, id DESC LIMIT ?1",
+        )?;
+        let rows = statement.query_map([limit], entry_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Delete visits made at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_transaction(|db| {
+        let removed = db.execute("DELETE FROM history WHERE visited_at >= ?1", [since])?;
+        frecency::rebuild_all(db)?;
+        Ok(removed)
+    })
+}
+
+/// Delete visits made at or after the given time to URLs with a query, which holds the answer
+/// to an input prompt, returning how many were removed
+pub(crate) fn clear_queries_since(since: i64) -> Result<usize, String> {
+    with_transaction(|db| {
+        let removed = db.execute(
+            "DELETE FROM history WHERE visited_at >= ?1 AND instr(url, '?') > 0",
+            [since],
+        )?;
+        frecency::rebuild_all(db)?;
+        Ok(removed)
+    })
+}
+
+/// Scheme of the most recent visit to a host, for completing addresses typed without one
+pub(crate) fn last_scheme_for_host(host: &str) -> Result<Option<String>, String> {
+    let host = host.to_ascii_lowercase();
+    let urls: Vec<String> = with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT url FROM history WHERE url LIKE '%://' || ?1 || '%'
+             ORDER BY visited_at DESC, id DESC LIMIT 50",
+        )?;
+        let rows = statement.query_map([&host], |row| row.get(0))?;
+        rows.collect()
+    })?;
+    // LIKE also matches longer hosts and paths, so compare the parsed host exactly
+    Ok(urls
+        .iter()
+        .filter_map(|url| url::Url::parse(url).ok())
+        .find(|url| url.host_str() == Some(host.as_str()))
+        .map(|url| url.scheme().to_string()))
+}
+
+/// The title a page was most recently seen with, if it was ever visited with one
+pub(crate) fn latest_title(url: &str) -> Result<Option<String>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT title FROM history WHERE url = ?1 AND title IS NOT NULL
+             ORDER BY visited_at DESC, id DESC LIMIT 1",
+            [url],
+            |row| row.get(0),
+        )
+        .optional()
+    })
+}
+
+/// How a history search query is matched against titles and URLs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryMatch {
+    /// The query appears anywhere in the title or URL
+    Substring,
+    /// The title, the URL, or the URL without its scheme starts with the query
+    Prefix,
+}
+
+/// Visit time window of a history search, in seconds since the Unix epoch; open ends are None
+#[derive(Debug, Clone, Default)]
+pub struct HistoryRange {
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+/// All visits to one URL, aggregated
+#[derive(Debug, Clone)]
+pub struct HistoryResult {
+    pub url: String,
+    /// Most recent title the page was seen with
+    pub title: Option<String>,
+    pub visit_count: u32,
+    pub last_visited: i64,
+}
+
+fn result_from_row(row: &Row) -> rusqlite::Result<HistoryResult> {
+    Ok(HistoryResult {
+        url: row.get(0)?,
+        title: row.get(1)?,
+        visit_count: row.get(2)?,
+        last_visited: row.get(3)?,
+    })
+}
+
+/// Most recent non-empty title of the grouped URL, for queries grouping history by url
+const LATEST_TITLE: &str = "(SELECT title FROM history latest
+     WHERE latest.url = history.url AND latest.title IS NOT NULL
+     ORDER BY latest.visited_at DESC, latest.id DESC LIMIT 1)";
+
+/// One page of visited pages, one result per URL, most recently visited first; `offset`
+/// results are skipped
+pub fn history_page(offset: u32, limit: u32) -> Result<Vec<HistoryResult>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT url, {}, COUNT(*), MAX(visited_at)
+             FROM history
+             GROUP BY url
+             ORDER BY MAX(visited_at) DESC, MAX(id) DESC
+             LIMIT ?1 OFFSET ?2",
+            LATEST_TITLE
+        ))?;
+        let rows = statement.query_map([limit, offset], result_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Escape LIKE wildcards so the query is matched literally
+pub(crate) fn like_literal(query: &str) -> String {
+    query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Search history case-insensitively, one result per URL, most recently visited first
+pub fn search_history(
+    query: &str,
+    mode: HistoryMatch,
+    range: &HistoryRange,
+    limit: u32,
+) -> Result<Vec<HistoryResult>, String> {
+    let pattern = match mode {
+        HistoryMatch::Substring => format!("%{}%", like_literal(query.trim())),
+        HistoryMatch::Prefix => format!("{}%", like_literal(query.trim())),
+    };
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT url, {}, COUNT(*), MAX(visited_at)
+             FROM history
+             WHERE visited_at BETWEEN ?1 AND ?2
+             GROUP BY url
+             HAVING url LIKE ?3 ESCAPE '\\'
+                 OR substr(url, instr(url, '://') + 3) LIKE ?3 ESCAPE '\\'
+                 OR MAX(title LIKE ?3 ESCAPE '\\')
+             ORDER BY MAX(visited_at) DESC
+             LIMIT ?4",
+            LATEST_TITLE
+        ))?;
+        let rows = statement.query_map(
+            params![
+                range.since.unwrap_or(i64::MIN),
+                range.until.unwrap_or(i64::MAX),
+                pattern,
+                limit
+            ],
+            result_from_row,
+        )?;
+        rows.collect()
+    })
+}
@@ -0,0 +1,242 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::functions::{config, legacy_charset};
+use crate::api::storage::with_db;
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS host_settings (
+    host TEXT PRIMARY KEY,
+    tls_policy TEXT,
+    identity_id TEXT,
+    connect_timeout_secs INTEGER,
+    read_timeout_secs INTEGER,
+    redirect_policy TEXT,
+    max_body_bytes INTEGER,
+    charset TEXT
+);";
+
+/// How a host's server certificates are checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsPolicy {
+    /// Pin the first certificate and refuse a different one until the pin expires
+    TrustOnFirstUse,
+    /// Pin the first certificate and silently replace the pin when the host presents another,
+    /// for hosts that renew their self-signed certificates often
+    AcceptChanged,
+    /// Neither pin certificates nor check their validity dates
+    Unverified,
+    /// Require a certificate for the host issued by a certificate authority in the user trust
+    /// store, instead of pinning the first one
+    Strict,
+}
+
+impl TlsPolicy {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            TlsPolicy::TrustOnFirstUse => "tofu",
+            TlsPolicy::AcceptChanged => "accept_changed",
+            TlsPolicy::Unverified => "unverified",
+            TlsPolicy::Strict => "strict",
+        }
+    }
+
+    pub(crate) fn parse(value: &str) -> Self {
+        match value {
+            "accept_changed" => TlsPolicy::AcceptChanged,
+            "unverified" => TlsPolicy::Unverified,
+            "strict" => TlsPolicy::Strict,
+            _ => TlsPolicy::TrustOnFirstUse,
+        }
+    }
+}
+
+/// What to do when a host answers with a redirect; navigation follows the redirects it allows
+/// and returns the others for the UI to ask about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedirectPolicy {
+    Follow,
+    /// Ask before following a redirect to another host
+    AskCrossHost,
+    /// Always ask before following
+    Ask,
+}
+
+impl RedirectPolicy {
+    fn as_str(self) -> &'static str {
+        match self {
+            RedirectPolicy::Follow => "follow",
+            RedirectPolicy::AskCrossHost => "ask_cross_host",
+            RedirectPolicy::Ask => "ask",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "ask_cross_host" => RedirectPolicy::AskCrossHost,
+            "ask" => RedirectPolicy::Ask,
+            _ => RedirectPolicy::Follow,
+        }
+    }
+}
+
+/// Overrides of navigation behaviour for one host; a None field keeps the default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostSettings {
+    pub host: String,
+    pub tls_policy: Option<TlsPolicy>,
+    /// Identity (client certificate) presented to the host when the navigation names none
+    pub identity_id: Option<String>,
+    pub connect_timeout_secs: Option<u32>,
+    pub read_timeout_secs: Option<u32>,
+    pub redirect_policy: Option<RedirectPolicy>,
+    /// Largest body accepted from the host before the navigation fails
+    pub max_body_bytes: Option<u64>,
+    /// Character set reported for the host's text responses instead of the one they declare;
+    /// Gopher and Finger text, which declares none, is also decoded with it rather than guessed.
+    /// Any label of the Encoding Standard, ASCII, or IBM437 (CP437); others are refused.
+    pub charset: Option<String>,
+}
+
+impl HostSettings {
+    fn has_overrides(&self) -> bool {
+        self.tls_policy.is_some()
+            || self.identity_id.is_some()
+            || self.connect_timeout_secs.is_some()
+            || self.read_timeout_secs.is_some()
+            || self.redirect_policy.is_some()
+            || self.max_body_bytes.is_some()
+            || self.charset.is_some()
+    }
+}
+
+fn settings_from_row(row: &Row) -> rusqlite::Result<HostSettings> {
+    let tls_policy: Option<String> = row.get(1)?;
+    let redirect_policy: Option<String> = row.get(5)?;
+    let max_body_bytes: Option<i64> = row.get(6)?;
+    Ok(HostSettings {
+        host: row.get(0)?,
+        tls_policy: tls_policy.as_deref().map(TlsPolicy::parse),
+        identity_id: row.get(2)?,
+        connect_timeout_secs: row.get(3)?,
+        read_timeout_secs: row.get(4)?,
+        redirect_policy: redirect_policy.as_deref().map(RedirectPolicy::parse),
+        max_body_bytes: max_body_bytes.map(|bytes| bytes as u64),
+        charset: row.get(7)?,
+    })
+}
+
+const COLUMNS: &str = "host, tls_policy, identity_id, connect_timeout_secs, read_timeout_secs, \
+                       redirect_policy, max_body_bytes, charset";
+
+/// Store the overrides of a host, replacing any it had; settings without any override are
+/// removed instead
+pub fn set_host_settings(settings: HostSettings) -> Result<(), String> {
+    let host = settings
+        .host
+        .trim()
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    if host.is_empty() {
+        return Err("Host cannot be empty".to_string());
+    }
+    if settings.connect_timeout_secs == Some(0) || settings.read_timeout_secs == Some(0) {
+        return Err("Timeouts must be at least one second".to_string());
+    }
+    if settings.max_body_bytes == Some(0) {
+        return Err("Maximum body size must be greater than zero".to_string());
+    }
+    let charset = settings
+        .charset
+        .map(|charset| charset.trim().to_ascii_lowercase())
+        .filter(|charset| !charset.is_empty());
+    if let Some(charset) = charset.as_deref() {
+        if !legacy_charset::is_known_label(charset) {
+            return Err(format!("Unknown charset {}", charset));
+        }
+    }
+    if let Some(identity_id) = &settings.identity_id {
+        if crate::api::storage::identities::get_identity(identity_id)?.is_none() {
+            return Err(format!("Unknown identity {}", identity_id));
+        }
+    }
+    let settings = HostSettings {
+        host,
+        charset,
+        ..settings
+    };
+    if !settings.has_overrides() {
+        remove_host_settings(&settings.host)?;
+        return Ok(());
+    }
+
+    with_db(|db| {
+        db.execute(
+            &format!(
+                "INSERT OR REPLACE INTO host_settings ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                COLUMNS
+            ),
+            params![
+                settings.host,
+                settings.tls_policy.map(TlsPolicy::as_str),
+                settings.identity_id,
+                settings.connect_timeout_secs,
+                settings.read_timeout_secs,
+                settings.redirect_policy.map(RedirectPolicy::as_str),
+                settings.max_body_bytes.map(|bytes| bytes as i64),
+                settings.charset,
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// The overrides of a host, if it has any
+pub fn get_host_settings(host: &str) -> Result<Option<HostSettings>, String> {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    with_db(|db| {
+        db.query_row(
+            &format!("SELECT {} FROM host_settings WHERE host = ?1", COLUMNS),
+            [host],
+            settings_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Drop the overrides of a host, returning whether it had any
+pub fn remove_host_settings(host: &str) -> Result<bool, String> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+    let removed = with_db(|db| db.execute("DELETE FROM host_settings WHERE host = ?1", [host]))?;
+    Ok(removed > 0)
+}
+
+/// Every host with overrides, by host name
+pub fn list_host_settings() -> Result<Vec<HostSettings>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM host_settings ORDER BY host",
+            COLUMNS
+        ))?;
+        let rows = statement.query_map([], settings_from_row)?;
+        rows.collect()
+    })
+}
+
+/// The certificate policy of a host, or the configured one when it has none; database errors
+/// keep the configured policy rather than fail the connection
+pub(crate) fn tls_policy(host: &str) -> TlsPolicy {
+    get_host_settings(host)
+        .ok()
+        .flatten()
+        .and_then(|settings| settings.tls_policy)
+        .unwrap_or_else(|| config::current(|config| config.tls_policy))
+}
+
+/// The redirect policy of a host; database errors keep the default of following redirects
+pub(crate) fn redirect_policy(host: &str) -> RedirectPolicy {
+    get_host_settings(host)
+        .ok()
+        .flatten()
+        .and_then(|settings| settings.redirect_policy)
+        .unwrap_or(RedirectPolicy::Follow)
+}
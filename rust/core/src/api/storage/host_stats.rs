@@ -0,0 +1,127 @@
+use rusqlite::params;
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS host_stats (
+    host TEXT PRIMARY KEY,
+    requests INTEGER NOT NULL,
+    errors INTEGER NOT NULL,
+    bytes_sent INTEGER NOT NULL,
+    bytes_received INTEGER NOT NULL,
+    total_latency_ms INTEGER NOT NULL,
+    first_request_at INTEGER NOT NULL,
+    last_request_at INTEGER NOT NULL
+);";
+
+/// Totals of all network requests made to one host
+#[derive(Debug, Clone)]
+pub struct HostStats {
+    pub host: String,
+    pub requests: u32,
+    /// Requests that failed to produce a response
+    pub errors: u32,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub average_latency_ms: u32,
+    /// Share of requests that failed, from 0 to 1
+    pub error_rate: f64,
+    pub first_request_at: i64,
+    pub last_request_at: i64,
+}
+
+/// Which statistic ranks hosts first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostStatsOrder {
+    Requests,
+    BytesReceived,
+    AverageLatency,
+    ErrorRate,
+    LastRequest,
+}
+
+impl HostStatsOrder {
+    fn sql(self) -> &'static str {
+        match self {
+            HostStatsOrder::Requests => "requests DESC",
+            HostStatsOrder::BytesReceived => "bytes_received DESC",
+            HostStatsOrder::AverageLatency => "total_latency_ms * 1.0 / requests DESC",
+            HostStatsOrder::ErrorRate => "errors * 1.0 / requests DESC",
+            HostStatsOrder::LastRequest => "last_request_at DESC",
+        }
+    }
+}
+
+/// Add one request to the totals of a host
+pub(crate) fn record_request(
+    host: &str,
+    bytes_sent: u64,
+    bytes_received: u64,
+    latency_ms: u64,
+    failed: bool,
+) -> Result<(), String> {
+    let now = now();
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO host_stats
+                (host, requests, errors, bytes_sent, bytes_received, total_latency_ms,
+                 first_request_at, last_request_at)
+             VALUES (?1, 1, ?2, ?3, ?4, ?5, ?6, ?6)
+             ON CONFLICT (host) DO UPDATE SET
+                requests = requests + 1,
+                errors = errors + excluded.errors,
+                bytes_sent = bytes_sent + excluded.bytes_sent,
+                bytes_received = bytes_received + excluded.bytes_received,
+                total_latency_ms = total_latency_ms + excluded.total_latency_ms,
+                last_request_at = excluded.last_request_at",
+            params![
+                host.to_ascii_lowercase(),
+                failed as i64,
+                bytes_sent as i64,
+                bytes_received as i64,
+                latency_ms as i64,
+                now
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Hosts ranked by the chosen statistic
+pub fn list_host_stats(order: HostStatsOrder, limit: u32) -> Result<Vec<HostStats>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT host, requests, errors, bytes_sent, bytes_received, total_latency_ms,
+                    first_request_at, last_request_at
+             FROM host_stats ORDER BY {}, host LIMIT ?1",
+            order.sql()
+        ))?;
+        let rows = statement.query_map([limit], |row| {
+            let requests: u32 = row.get(1)?;
+            let errors: u32 = row.get(2)?;
+            let total_latency_ms: i64 = row.get(5)?;
+            Ok(HostStats {
+                host: row.get(0)?,
+                requests,
+                errors,
+                bytes_sent: row.get::<_, i64>(3)? as u64,
+                bytes_received: row.get::<_, i64>(4)? as u64,
+                average_latency_ms: (total_latency_ms / requests.max(1) as i64) as u32,
+                error_rate: errors as f64 / requests.max(1) as f64,
+                first_request_at: row.get(6)?,
+                last_request_at: row.get(7)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Delete the totals of hosts requested at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM host_stats WHERE last_request_at >= ?1",
+            [since],
+        )
+    })
+}
@@ -0,0 +1,152 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::storage::{key_storage, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS identities (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    common_name TEXT NOT NULL,
+    fingerprint TEXT NOT NULL,
+    not_before INTEGER NOT NULL,
+    not_after INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    certificate_pem TEXT NOT NULL,
+    private_key_pem TEXT NOT NULL
+);";
+
+/// A client certificate the user can present to capsules, without its key material
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub id: String,
+    pub name: String,
+    pub common_name: String,
+    /// SHA-256 fingerprint of the DER certificate, as lowercase hex
+    pub fingerprint: String,
+    /// Validity window as seconds since the Unix epoch
+    pub not_before: i64,
+    pub not_after: i64,
+    pub created_at: i64,
+}
+
+/// The PEM certificate and private key of an identity; never leaves the crate
+#[derive(Clone)]
+pub(crate) struct IdentityCredentials {
+    pub certificate_pem: String,
+    pub private_key_pem: String,
+}
+
+const IDENTITY_COLUMNS: &str =
+    "id, name, common_name, fingerprint, not_before, not_after, created_at";
+
+fn identity_from_row(row: &Row) -> rusqlite::Result<Identity> {
+    Ok(Identity {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        common_name: row.get(2)?,
+        fingerprint: row.get(3)?,
+        not_before: row.get(4)?,
+        not_after: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+/// Store a new identity with its credentials, the key sealed when key storage is unlocked
+pub(crate) fn insert(identity: &Identity, credentials: &IdentityCredentials) -> Result<(), String> {
+    let private_key = key_storage::seal(&identity.id, &credentials.private_key_pem)?;
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO identities (id, name, common_name, fingerprint, not_before, not_after, created_at, certificate_pem, private_key_pem)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                identity.id,
+                identity.name,
+                identity.common_name,
+                identity.fingerprint,
+                identity.not_before,
+                identity.not_after,
+                identity.created_at,
+                credentials.certificate_pem,
+                private_key
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Replace the certificate (and possibly the key) of an identity, returning whether it exists
+pub(crate) fn replace_certificate(
+    identity: &Identity,
+    credentials: &IdentityCredentials,
+) -> Result<bool, String> {
+    let private_key = key_storage::seal(&identity.id, &credentials.private_key_pem)?;
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE identities SET fingerprint = ?2, not_before = ?3, not_after = ?4, certificate_pem = ?5, private_key_pem = ?6
+             WHERE id = ?1",
+            params![
+                identity.id,
+                identity.fingerprint,
+                identity.not_before,
+                identity.not_after,
+                credentials.certificate_pem,
+                private_key
+            ],
+        )
+    })?;
+    Ok(updated > 0)
+}
+
+/// Look up an identity by ID
+pub fn get_identity(id: &str) -> Result<Option<Identity>, String> {
+    with_db(|db| {
+        db.query_row(
+            &format!("SELECT {} FROM identities WHERE id = ?1", IDENTITY_COLUMNS),
+            [id],
+            identity_from_row,
+        )
+        .optional()
+    })
+}
+
+/// All identities, oldest first
+pub fn list_identities() -> Result<Vec<Identity>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM identities ORDER BY created_at",
+            IDENTITY_COLUMNS
+        ))?;
+        let rows = statement.query_map([], identity_from_row)?;
+        rows.collect()
+    })
+}
+
+/// The certificate and private key of an identity; a sealed key needs key storage unlocked
+pub(crate) fn credentials(id: &str) -> Result<Option<IdentityCredentials>, String> {
+    let stored = with_db(|db| {
+        db.query_row(
+            "SELECT certificate_pem, private_key_pem FROM identities WHERE id = ?1",
+            [id],
+            |row| {
+                Ok(IdentityCredentials {
+                    certificate_pem: row.get(0)?,
+                    private_key_pem: row.get(1)?,
+                })
+            },
+        )
+        .optional()
+    })?;
+    stored
+        .map(|credentials| {
+            Ok(IdentityCredentials {
+                private_key_pem: key_storage::open(id, &credentials.private_key_pem)?,
+                ..credentials
+            })
+        })
+        .transpose()
+}
+
+/// Delete identities created at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM identities WHERE created_at >= ?1", [since]))
+}
@@ -0,0 +1,96 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS identity_scopes (
+    prefix TEXT PRIMARY KEY,
+    identity_id TEXT NOT NULL,
+    activated_at INTEGER NOT NULL
+);";
+
+/// An identity activated for every URL under a prefix, such as gemini://host/app/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityScope {
+    pub prefix: String,
+    pub identity_id: String,
+    pub activated_at: i64,
+}
+
+const SCOPE_COLUMNS: &str =
+    "identity_scopes.prefix, identity_scopes.identity_id, identity_scopes.activated_at";
+
+fn scope_from_row(row: &Row) -> rusqlite::Result<IdentityScope> {
+    Ok(IdentityScope {
+        prefix: row.get(0)?,
+        identity_id: row.get(1)?,
+        activated_at: row.get(2)?,
+    })
+}
+
+/// Bind an identity to a URL prefix, replacing the identity bound to it before
+pub(crate) fn activate(prefix: &str, identity_id: &str) -> Result<IdentityScope, String> {
+    let scope = IdentityScope {
+        prefix: prefix.to_string(),
+        identity_id: identity_id.to_string(),
+        activated_at: now(),
+    };
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO identity_scopes (prefix, identity_id, activated_at)
+             VALUES (?1, ?2, ?3)",
+            params![scope.prefix, scope.identity_id, scope.activated_at],
+        )
+    })?;
+    Ok(scope)
+}
+
+/// Remove the binding of a URL prefix, returning whether there was one
+pub(crate) fn deactivate(prefix: &str) -> Result<bool, String> {
+    let removed =
+        with_db(|db| db.execute("DELETE FROM identity_scopes WHERE prefix = ?1", [prefix]))?;
+    Ok(removed > 0)
+}
+
+/// Every binding of an identity that still exists, by prefix
+pub fn list_identity_scopes() -> Result<Vec<IdentityScope>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM identity_scopes
+             JOIN identities ON identities.id = identity_scopes.identity_id
+             ORDER BY identity_scopes.prefix",
+            SCOPE_COLUMNS
+        ))?;
+        let rows = statement.query_map([], scope_from_row)?;
+        rows.collect()
+    })
+}
+
+/// The binding with the longest prefix of a canonical URL, if any
+pub(crate) fn scope_for(url: &str) -> Result<Option<IdentityScope>, String> {
+    with_db(|db| {
+        db.query_row(
+            &format!(
+                "SELECT {} FROM identity_scopes
+                 JOIN identities ON identities.id = identity_scopes.identity_id
+                 WHERE substr(?1, 1, length(identity_scopes.prefix)) = identity_scopes.prefix
+                 ORDER BY length(identity_scopes.prefix) DESC
+                 LIMIT 1",
+                SCOPE_COLUMNS
+            ),
+            [url],
+            scope_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Delete activations made at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM identity_scopes WHERE activated_at >= ?1",
+            [since],
+        )
+    })
+}
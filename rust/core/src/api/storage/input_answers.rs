@@ -0,0 +1,104 @@
+use rusqlite::{params, Row};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS input_answers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    prompt TEXT NOT NULL,
+    answer TEXT NOT NULL,
+    answered_at INTEGER NOT NULL,
+    uses INTEGER NOT NULL DEFAULT 1,
+    UNIQUE (url, prompt, answer)
+);
+CREATE INDEX IF NOT EXISTS idx_input_answers_prompt ON input_answers(url, prompt);";
+
+/// Most answers offered for one prompt
+const MAX_ANSWERS_PER_PROMPT: u32 = 20;
+
+/// An answer given to a Gemini input prompt (status 10), kept to be offered again
+#[derive(Debug, Clone)]
+pub struct SavedAnswer {
+    pub id: i64,
+    /// The canonical URL that asked, without a query
+    pub url: String,
+    /// The prompt the server sent with status 10
+    pub prompt: String,
+    pub answer: String,
+    /// When the answer was last given, in seconds since the Unix epoch
+    pub answered_at: i64,
+    /// How many times the answer was given
+    pub uses: u32,
+}
+
+const ANSWER_COLUMNS: &str = "id, url, prompt, answer, answered_at, uses";
+
+fn answer_from_row(row: &Row) -> rusqlite::Result<SavedAnswer> {
+    Ok(SavedAnswer {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        prompt: row.get(2)?,
+        answer: row.get(3)?,
+        answered_at: row.get(4)?,
+        uses: row.get(5)?,
+    })
+}
+
+/// The answers given before to a prompt of a URL, canonical and without a query, most recently
+/// given first
+pub(crate) fn answers_for(url: &str, prompt: &str) -> Result<Vec<SavedAnswer>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM input_answers WHERE url = ?1 AND prompt = ?2
+             ORDER BY answered_at DESC, uses DESC LIMIT ?3",
+            ANSWER_COLUMNS
+        ))?;
+        let rows = statement.query_map(
+            params![url, prompt, MAX_ANSWERS_PER_PROMPT],
+            answer_from_row,
+        )?;
+        rows.collect()
+    })
+}
+
+/// Every saved answer, by URL and prompt, most recently given first
+pub fn list_saved_answers() -> Result<Vec<SavedAnswer>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM input_answers ORDER BY url, prompt, answered_at DESC",
+            ANSWER_COLUMNS
+        ))?;
+        let rows = statement.query_map([], answer_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Forget a saved answer, returning whether it was saved
+pub fn delete_saved_answer(id: i64) -> Result<bool, String> {
+    let removed = with_db(|db| db.execute("DELETE FROM input_answers WHERE id = ?1", [id]))?;
+    Ok(removed > 0)
+}
+
+/// Save an answer to a prompt, or count it again when it was given before
+pub(crate) fn record_answer(url: &str, prompt: &str, answer: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO input_answers (url, prompt, answer, answered_at) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (url, prompt, answer)
+             DO UPDATE SET answered_at = excluded.answered_at, uses = uses + 1",
+            params![url, prompt, answer, now()],
+        )
+    })?;
+    Ok(())
+}
+
+/// Forget every answer given to a URL, e.g. once it asks for sensitive input
+pub(crate) fn forget_url(url: &str) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM input_answers WHERE url = ?1", [url]))
+}
+
+/// Delete answers last given at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM input_answers WHERE answered_at >= ?1", [since]))
+}
@@ -0,0 +1,184 @@
+use std::sync::Mutex;
+
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use rusqlite::OptionalExtension;
+
+use crate::api::storage::{with_db, with_transaction};
+
+/// Keyring service the storage keys of profiles are kept under, one entry per profile
+#[cfg(feature = "keyring")]
+const KEYRING_SERVICE: &str = "holloway";
+
+/// Prefix of a private key sealed with the storage key, followed by the base64 nonce and
+/// ciphertext; keys stored before the profile had a storage key are plain PEM
+const SEALED_PREFIX: &str = "sealed:";
+
+/// Bytes of a storage key (AES-256)
+const STORAGE_KEY_LEN: usize = 32;
+
+/// Prefix of the error returned when a sealed key is needed while no storage key is set
+pub const LOCKED_ERROR_PREFIX: &str = "Key storage is locked";
+
+/// The storage key of the open profile, set by `unlock`
+static STORAGE_KEY: Mutex<Option<LessSafeKey>> = Mutex::new(None);
+
+/// A new random storage key, for the app to keep in the platform's secure storage (Android
+/// Keystore, iOS Keychain, or the Secret Service) and pass to `unlock` on every start
+pub fn generate_storage_key() -> Result<Vec<u8>, String> {
+    let mut key = vec![0; STORAGE_KEY_LEN];
+    SystemRandom::new()
+        .fill(&mut key)
+        .map_err(|_| "Failed to generate storage key".to_string())?;
+    Ok(key)
+}
+
+/// Seal the open profile's identity keys with a storage key from the platform's secure
+/// storage: keys stored in plain PEM are sealed now, and keys stored later are sealed as they
+/// are written. Fails when the profile's keys were sealed with another storage key. Returns
+/// how many keys were sealed now.
+pub fn unlock(storage_key: &[u8]) -> Result<u32, String> {
+    let unbound = UnboundKey::new(&AES_256_GCM, storage_key)
+        .map_err(|_| format!("Storage key must be {} bytes", STORAGE_KEY_LEN))?;
+    let key = LessSafeKey::new(unbound);
+
+    let sealed_sample: Option<(String, String)> = with_db(|db| {
+        db.query_row(
+            "SELECT id, private_key_pem FROM identities WHERE private_key_pem LIKE 'sealed:%'
+             LIMIT 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+    })?;
+    if let Some((id, sealed)) = sealed_sample {
+        open_with(&key, &id, &sealed).map_err(|_| {
+            "Storage key does not open the identity keys of this profile".to_string()
+        })?;
+    }
+
+    let sealed = with_transaction(|db| {
+        let plain: Vec<(String, String)> = db
+            .prepare(
+                "SELECT id, private_key_pem FROM identities
+                 WHERE private_key_pem NOT LIKE 'sealed:%'",
+            )?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        for (id, pem) in &plain {
+            let sealed = seal_with(&key, id, pem)
+                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(e.into()))?;
+            db.execute(
+                "UPDATE identities SET private_key_pem = ?2 WHERE id = ?1",
+                [id, &sealed],
+            )?;
+        }
+        Ok(plain.len() as u32)
+    })?;
+    if sealed > 0 {
+        // The plain keys overwritten above may still sit in the write-ahead log and in free
+        // pages; secure_delete zeroes freed pages, and a vacuum rewrites the rest
+        with_db(|db| db.execute_batch("PRAGMA wal_checkpoint(TRUNCATE); VACUUM;"))?;
+    }
+    *STORAGE_KEY.lock().unwrap() = Some(key);
+    Ok(sealed)
+}
+
+/// `unlock` with the profile's storage key from the platform keyring (the macOS or iOS
+/// Keychain, the Windows Credential Manager, or the Secret Service), generating and saving one
+/// the first time; `profile` names the profile's entry, e.g. its directory
+#[cfg(feature = "keyring")]
+pub fn unlock_from_keyring(profile: &str) -> Result<u32, String> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, profile)
+        .map_err(|e| format!("Keyring unavailable: {}", e))?;
+    let storage_key = match entry.get_secret() {
+        Err(keyring::Error::NoEntry) => {
+            let storage_key = generate_storage_key()?;
+            entry
+                .set_secret(&storage_key)
+                .map_err(|e| format!("Failed to save storage key to the keyring: {}", e))?;
+            storage_key
+        }
+        result => {
+            result.map_err(|e| format!("Failed to read storage key from the keyring: {}", e))?
+        }
+    };
+    unlock(&storage_key)
+}
+
+/// Forget the storage key, e.g. when the profile is closed; sealed keys cannot be used until
+/// `unlock` is called again
+pub fn lock() {
+    *STORAGE_KEY.lock().unwrap() = None;
+}
+
+/// Whether a storage key is set for the open profile
+pub fn is_unlocked() -> bool {
+    STORAGE_KEY.lock().unwrap().is_some()
+}
+
+/// The sealed form an identity's private key is stored in; no key is stored in plain PEM, so
+/// creating or importing one fails while key storage is locked
+pub(crate) fn seal(id: &str, private_key_pem: &str) -> Result<String, String> {
+    match STORAGE_KEY.lock().unwrap().as_ref() {
+        Some(key) => seal_with(key, id, private_key_pem),
+        None => Err(format!(
+            "{}: unlock it to store the key of identity {}",
+            LOCKED_ERROR_PREFIX, id
+        )),
+    }
+}
+
+/// The PEM private key of an identity from its stored form
+pub(crate) fn open(id: &str, stored: &str) -> Result<String, String> {
+    if !stored.starts_with(SEALED_PREFIX) {
+        return Ok(stored.to_string());
+    }
+    match STORAGE_KEY.lock().unwrap().as_ref() {
+        Some(key) => open_with(key, id, stored),
+        None => Err(format!(
+            "{}: unlock it to use identity {}",
+            LOCKED_ERROR_PREFIX, id
+        )),
+    }
+}
+
+/// Seal a key with AES-256-GCM under a fresh nonce; the identity id is authenticated with it so
+/// a sealed key cannot be moved to another identity
+fn seal_with(key: &LessSafeKey, id: &str, private_key_pem: &str) -> Result<String, String> {
+    let mut nonce = [0; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| "Failed to seal identity key".to_string())?;
+    let mut sealed = private_key_pem.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(id.as_bytes()),
+        &mut sealed,
+    )
+    .map_err(|_| "Failed to seal identity key".to_string())?;
+    let mut data = nonce.to_vec();
+    data.extend(sealed);
+    Ok(format!(
+        "{}{}",
+        SEALED_PREFIX,
+        base64::engine::general_purpose::STANDARD.encode(data)
+    ))
+}
+
+fn open_with(key: &LessSafeKey, id: &str, stored: &str) -> Result<String, String> {
+    let failed = || format!("Failed to open identity key of {}", id);
+    let mut data = base64::engine::general_purpose::STANDARD
+        .decode(&stored[SEALED_PREFIX.len()..])
+        .map_err(|_| failed())?;
+    if data.len() < NONCE_LEN {
+        return Err(failed());
+    }
+    let mut sealed = data.split_off(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(&data).map_err(|_| failed())?;
+    let plain = key
+        .open_in_place(nonce, Aad::from(id.as_bytes()), &mut sealed)
+        .map_err(|_| failed())?;
+    String::from_utf8(plain.to_vec()).map_err(|_| failed())
+}
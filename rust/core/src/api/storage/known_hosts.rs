@@ -0,0 +1,127 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS known_hosts (
+    host TEXT NOT NULL,
+    port INTEGER NOT NULL,
+    fingerprint TEXT NOT NULL,
+    expires_at INTEGER,
+    first_seen INTEGER NOT NULL,
+    last_seen INTEGER NOT NULL,
+    PRIMARY KEY (host, port)
+);";
+
+/// A server whose certificate was trusted on first use
+#[derive(Debug, Clone)]
+pub struct KnownHost {
+    pub host: String,
+    pub port: u16,
+    /// SHA-256 of the certificate's public key as lowercase hex
+    pub fingerprint: String,
+    /// When the pinned certificate expires; after that a new certificate replaces the pin
+    pub expires_at: Option<i64>,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+fn host_from_row(row: &Row) -> rusqlite::Result<KnownHost> {
+    Ok(KnownHost {
+        host: row.get(0)?,
+        port: row.get(1)?,
+        fingerprint: row.get(2)?,
+        expires_at: row.get(3)?,
+        first_seen: row.get(4)?,
+        last_seen: row.get(5)?,
+    })
+}
+
+/// The pin of a server, if it was seen before
+pub fn get_known_host(host: &str, port: u16) -> Result<Option<KnownHost>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT host, port, fingerprint, expires_at, first_seen, last_seen
+             FROM known_hosts WHERE host = ?1 AND port = ?2",
+            params![host.to_ascii_lowercase(), port],
+            host_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Pin a certificate for a server, replacing any earlier pin
+pub(crate) fn pin(
+    host: &str,
+    port: u16,
+    fingerprint: &str,
+    expires_at: Option<i64>,
+    first_seen: i64,
+) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO known_hosts
+                (host, port, fingerprint, expires_at, first_seen, last_seen)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                host.to_ascii_lowercase(),
+                port,
+                fingerprint,
+                expires_at,
+                first_seen,
+                now()
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Note that a server presented its pinned key again, possibly in a renewed certificate
+pub(crate) fn touch(host: &str, port: u16, expires_at: Option<i64>) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE known_hosts SET last_seen = ?3, expires_at = COALESCE(?4, expires_at)
+             WHERE host = ?1 AND port = ?2",
+            params![host.to_ascii_lowercase(), port, now(), expires_at],
+        )
+    })?;
+    Ok(())
+}
+
+/// Every pinned server, alphabetically
+pub fn list_known_hosts() -> Result<Vec<KnownHost>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT host, port, fingerprint, expires_at, first_seen, last_seen
+             FROM known_hosts ORDER BY host, port",
+        )?;
+        let rows = statement.query_map([], host_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Names of every pinned server, each listed once
+pub(crate) fn host_names() -> Result<Vec<String>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare("SELECT DISTINCT host FROM known_hosts")?;
+        let rows = statement.query_map([], |row| row.get(0))?;
+        rows.collect()
+    })
+}
+
+/// Forget the pin of a server so its next certificate is trusted on first use again,
+/// returning whether it was pinned
+pub fn forget(host: &str, port: u16) -> Result<bool, String> {
+    let removed = with_db(|db| {
+        db.execute(
+            "DELETE FROM known_hosts WHERE host = ?1 AND port = ?2",
+            params![host.to_ascii_lowercase(), port],
+        )
+    })?;
+    Ok(removed > 0)
+}
+
+/// Delete pins first made at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| db.execute("DELETE FROM known_hosts WHERE first_seen >= ?1", [since]))
+}
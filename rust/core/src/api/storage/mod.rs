@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+
+use rusqlite::Connection;
+
+pub mod archive;
+pub mod blocklist;
+pub mod bookmarks;
+pub mod cache;
+pub mod clear;
+pub mod data_usage;
+pub mod downloads;
+pub mod favicons;
+pub mod feeds;
+pub mod frecency;
+pub mod gemini_proxies;
+pub mod history;
+pub mod host_settings;
+pub mod host_stats;
+pub mod identities;
+pub mod identity_scopes;
+pub mod input_answers;
+pub mod key_storage;
+pub mod known_hosts;
+pub mod page_index;
+pub mod plan_watches;
+pub mod ports;
+pub mod proxy;
+pub mod read_later;
+pub mod redirects;
+pub mod scheme_toggles;
+pub mod sessions;
+pub mod settings;
+pub mod titan_tokens;
+pub mod trusted_cas;
+pub mod watches;
+
+/// File name of the profile database inside the profile directory
+const DATABASE_FILE: &str = "holloway.db";
+
+/// The open profile database; falls back to an in-memory database until a profile is opened
+static DATABASE: LazyLock<Mutex<Connection>> = LazyLock::new(|| {
+    let connection = Connection::open_in_memory().expect("in-memory database");
+    create_schema(&connection).expect("profile schema");
+    Mutex::new(connection)
+});
+
+/// Open (creating if needed) the profile database in the given directory
+pub fn open_profile(dir: String) -> Result<(), String> {
+    let path = PathBuf::from(dir).join(DATABASE_FILE);
+    let connection = Connection::open(&path)
+        .map_err(|e| format!("Failed to open profile database {}: {}", path.display(), e))?;
+    // Write-ahead logging keeps the database intact when a mobile OS kills the process mid-write,
+    // and lets a commit survive an app crash without waiting for a full sync
+    connection
+        .pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))
+        .and_then(|_| connection.pragma_update(None, "synchronous", "NORMAL"))
+        // Deleted rows, such as identity keys and private history, are zeroed rather than left
+        // in free pages
+        .and_then(|_| connection.pragma_update(None, "secure_delete", "ON"))
+        .map_err(|e| format!("Failed to open profile database {}: {}", path.display(), e))?;
+    create_schema(&connection).map_err(|e| format!("Failed to create profile schema: {}", e))?;
+    *DATABASE.lock().unwrap() = connection;
+    cache::forget_memory();
+    // Another profile's identity keys are sealed with its own storage key
+    key_storage::lock();
+    // Connections made from now on use the proxy saved in this profile
+    crate::api::transport::proxy::apply(proxy::load_settings()?)?;
+    crate::api::functions::config::load();
+    Ok(())
+}
+
+/// Flush and close the profile database; until a profile is opened again, stores use a fresh
+/// in-memory database
+pub fn close_profile() -> Result<(), String> {
+    let memory = Connection::open_in_memory()
+        .map_err(|e| format!("Failed to open in-memory database: {}", e))?;
+    create_schema(&memory).map_err(|e| format!("Failed to create profile schema: {}", e))?;
+    let connection = std::mem::replace(&mut *DATABASE.lock().unwrap(), memory);
+    cache::forget_memory();
+    key_storage::lock();
+    crate::api::functions::config::load();
+    // Let SQLite refresh its query planner statistics before the file is closed
+    connection
+        .execute_batch("PRAGMA optimize;")
+        .map_err(|e| format!("Database error: {}", e))?;
+    connection
+        .close()
+        .map_err(|(_, e)| format!("Failed to close profile database: {}", e))
+}
+
+/// Copy the write-ahead log into the database file and truncate it, e.g. when the app moves to
+/// the background, so the file alone holds every committed change
+pub fn checkpoint_profile() -> Result<(), String> {
+    with_db(|db| db.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);"))
+}
+
+/// Run a closure against the profile database, converting errors to strings
+pub(crate) fn with_db<T>(f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> Result<T, String> {
+    let connection = DATABASE.lock().unwrap();
+    f(&connection).map_err(|e| format!("Database error: {}", e))
+}
+
+/// Run a batch of writes in one transaction, so that it lands completely or not at all even if
+/// the process dies midway
+pub(crate) fn with_transaction<T>(
+    f: impl FnOnce(&Connection) -> rusqlite::Result<T>,
+) -> Result<T, String> {
+    with_db(|db| {
+        let transaction = db.unchecked_transaction()?;
+        let result = f(&transaction)?;
+        transaction.commit()?;
+        Ok(result)
+    })
+}
+
+/// Create the tables of every store
+fn create_schema(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(archive::SCHEMA)?;
+    connection.execute_batch(blocklist::SCHEMA)?;
+    connection.execute_batch(bookmarks::SCHEMA)?;
+    connection.execute_batch(cache::SCHEMA)?;
+    connection.execute_batch(cache::POLICY_SCHEMA)?;
+    add_column_if_missing(connection, "cache", "body_hash", "TEXT NOT NULL DEFAULT ''")?;
+    connection.execute_batch(data_usage::SCHEMA)?;
+    connection.execute_batch(downloads::SCHEMA)?;
+    add_column_if_missing(connection, "downloads", "checksum_url", "TEXT")?;
+    add_column_if_missing(
+        connection,
+        "downloads",
+        "checksum_status",
+        "TEXT NOT NULL DEFAULT 'unchecked'",
+    )?;
+    connection.execute_batch(favicons::SCHEMA)?;
+    connection.execute_batch(feeds::SCHEMA)?;
+    connection.execute_batch(gemini_proxies::SCHEMA)?;
+    add_column_if_missing(
+        connection,
+        "gemini_proxies",
+        "protocol",
+        "TEXT NOT NULL DEFAULT 'gemini'",
+    )?;
+    add_column_if_missing(
+        connection,
+        "gemini_proxies",
+        "selector",
+        "TEXT NOT NULL DEFAULT ''",
+    )?;
+    connection.execute_batch(history::SCHEMA)?;
+    connection.execute_batch(frecency::SCHEMA)?;
+    frecency::rebuild_if_missing(connection)?;
+    connection.execute_batch(host_settings::SCHEMA)?;
+    connection.execute_batch(host_stats::SCHEMA)?;
+    connection.execute_batch(identities::SCHEMA)?;
+    connection.execute_batch(identity_scopes::SCHEMA)?;
+    connection.execute_batch(input_answers::SCHEMA)?;
+    connection.execute_batch(known_hosts::SCHEMA)?;
+    connection.execute_batch(page_index::SCHEMA)?;
+    connection.execute_batch(plan_watches::SCHEMA)?;
+    connection.execute_batch(ports::SCHEMA)?;
+    connection.execute_batch(proxy::SCHEMA)?;
+    connection.execute_batch(read_later::SCHEMA)?;
+    connection.execute_batch(redirects::SCHEMA)?;
+    connection.execute_batch(scheme_toggles::SCHEMA)?;
+    connection.execute_batch(sessions::SCHEMA)?;
+    connection.execute_batch(settings::SCHEMA)?;
+    connection.execute_batch(titan_tokens::SCHEMA)?;
+    connection.execute_batch(trusted_cas::SCHEMA)?;
+    connection.execute_batch(watches::SCHEMA)
+}
+
+/// Add a column to a table created by an earlier version of its schema
+fn add_column_if_missing(
+    connection: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> rusqlite::Result<()> {
+    let exists = connection
+        .prepare(&format!(
+            "SELECT 1 FROM pragma_table_info('{}') WHERE name = ?1",
+            table
+        ))?
+        .exists([column])?;
+    if !exists {
+        connection.execute_batch(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, definition
+        ))?;
+    }
+    Ok(())
+}
+
+/// Current time as seconds since the Unix epoch
+pub(crate) fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
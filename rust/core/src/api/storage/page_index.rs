@@ -0,0 +1,146 @@
+use rusqlite::params;
+
+use crate::api::models::fetch_response::GOPHER_MENU_MIME;
+use crate::api::storage::with_db;
+
+/// Full-text index of the cached text pages. Rows follow the cache: `cache::store` indexes a
+/// page and the trigger drops it with its cached copy, whether evicted or cleared.
+pub(crate) const SCHEMA: &str = "
+CREATE VIRTUAL TABLE IF NOT EXISTS page_index USING fts5 (
+    url UNINDEXED,
+    title,
+    body,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+CREATE TRIGGER IF NOT EXISTS cache_unindex AFTER DELETE ON cache BEGIN
+    DELETE FROM page_index WHERE url = old.url;
+END;";
+
+/// Markers FTS5 puts around matched terms in snippets, split out again before returning them
+const MATCH_START: char = '\u{2}';
+const MATCH_END: char = '\u{3}';
+
+/// Tokens of context around the matches in a snippet
+const SNIPPET_TOKENS: u32 = 16;
+
+/// A piece of a search result snippet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnippetPart {
+    pub text: String,
+    /// Whether the text matched the query, for the UI to highlight
+    pub matched: bool,
+}
+
+/// A cached page matching a local search
+#[derive(Debug, Clone)]
+pub struct LocalSearchResult {
+    pub url: String,
+    pub title: Option<String>,
+    /// The best-matching passage of the page, elided with "…" where it was cut
+    pub snippet: Vec<SnippetPart>,
+    /// When the cached copy was fetched, in seconds since the Unix epoch
+    pub fetched_at: i64,
+    /// Latest visit to the page, if it is in the history
+    pub last_visited_at: Option<i64>,
+}
+
+/// Index the body and title of a page just cached under its canonical URL, replacing what was
+/// indexed for it before; only text and Gopher menus are indexed
+pub(crate) fn index_page(
+    url: &str,
+    title: Option<&str>,
+    mime: &str,
+    body: &str,
+) -> Result<(), String> {
+    let indexable = mime.starts_with("text/") || mime == GOPHER_MENU_MIME;
+    with_db(|db| {
+        db.execute("DELETE FROM page_index WHERE url = ?1", [url])?;
+        if indexable && !body.is_empty() {
+            db.execute(
+                "INSERT INTO page_index (url, title, body) VALUES (?1, ?2, ?3)",
+                params![url, title, body],
+            )?;
+        }
+        Ok(())
+    })
+}
+
+/// Search the text of cached pages, best matches first; every word of the query must appear,
+/// the last one possibly as a prefix so results update while typing. Title matches weigh more
+/// than body matches.
+pub fn search_local(query: &str, limit: u32) -> Result<Vec<LocalSearchResult>, String> {
+    search_within(query, None, limit)
+}
+
+/// `search_local` over the pages whose URL starts with `url_prefix`, when one is given
+pub(crate) fn search_within(
+    query: &str,
+    url_prefix: Option<&str>,
+    limit: u32,
+) -> Result<Vec<LocalSearchResult>, String> {
+    let Some(query) = match_expression(query) else {
+        return Ok(Vec::new());
+    };
+    // LIKE wildcards in the prefix are matched literally
+    let url_pattern = url_prefix.map(|prefix| {
+        format!(
+            "{}%",
+            prefix
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        )
+    });
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT page_index.url, page_index.title,
+                 snippet(page_index, 2, '{}', '{}', '…', {}),
+                 cache.fetched_at,
+                 (SELECT MAX(visited_at) FROM history WHERE history.url = page_index.url)
+             FROM page_index JOIN cache ON cache.url = page_index.url
+             WHERE page_index MATCH ?1 AND (?3 IS NULL OR page_index.url LIKE ?3 ESCAPE '\\')
+             ORDER BY bm25(page_index, 0.0, 10.0, 1.0), cache.fetched_at DESC
+             LIMIT ?2",
+            MATCH_START, MATCH_END, SNIPPET_TOKENS
+        ))?;
+        let rows = statement.query_map(params![query, limit, url_pattern], |row| {
+            let snippet: String = row.get(2)?;
+            Ok(LocalSearchResult {
+                url: row.get(0)?,
+                title: row.get(1)?,
+                snippet: snippet_parts(&snippet),
+                fetched_at: row.get(3)?,
+                last_visited_at: row.get(4)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// An FTS5 query requiring every word of the user's query, quoted so punctuation and FTS5
+/// operators in it are taken literally; None when it has no words
+fn match_expression(query: &str) -> Option<String> {
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", words.join(" ")))
+}
+
+/// Split a snippet at the match markers, dropping them and newlines
+fn snippet_parts(snippet: &str) -> Vec<SnippetPart> {
+    let snippet = snippet.replace(['\r', '\n', '\t'], " ");
+    // Markers come in pairs, so the text alternates between unmatched and matched
+    snippet
+        .split([MATCH_START, MATCH_END])
+        .enumerate()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(i, text)| SnippetPart {
+            text: text.to_string(),
+            matched: i % 2 == 1,
+        })
+        .collect()
+}
@@ -0,0 +1,136 @@
+use rusqlite::{params, Row};
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::functions::navigate_internal::parse_input_url;
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS plan_watches (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL UNIQUE,
+    added_at INTEGER NOT NULL,
+    plan TEXT,
+    plan_hash TEXT,
+    checked_at INTEGER,
+    updated_at INTEGER,
+    last_error TEXT
+);";
+
+/// A finger address watched for changes to its .plan and .project, a lightweight status feed
+#[derive(Debug, Clone)]
+pub struct WatchedPlan {
+    pub id: i64,
+    /// The canonical finger:// URL, e.g. finger://alice@example.org
+    pub url: String,
+    /// Seconds since the Unix epoch
+    pub added_at: i64,
+    /// The finger answer of the last successful check, with the user's .plan and .project;
+    /// None until the first check
+    pub plan: Option<String>,
+    /// When the last check was made, successfully or not
+    pub checked_at: Option<i64>,
+    /// When a check first found the answer different from the one read; None once it is read
+    pub updated_at: Option<i64>,
+    /// Why the last check failed, cleared by a successful one
+    pub last_error: Option<String>,
+}
+
+const PLAN_COLUMNS: &str = "id, url, added_at, plan, checked_at, updated_at, last_error";
+
+fn plan_from_row(row: &Row) -> rusqlite::Result<WatchedPlan> {
+    Ok(WatchedPlan {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        added_at: row.get(2)?,
+        plan: row.get(3)?,
+        checked_at: row.get(4)?,
+        updated_at: row.get(5)?,
+        last_error: row.get(6)?,
+    })
+}
+
+/// Watch a finger address, typed as `user@host` or a finger:// URL, or return the existing watch
+/// of the same address
+pub fn watch_plan(address: &str) -> Result<WatchedPlan, String> {
+    let address = address.trim();
+    // A bare user@host would otherwise get the default scheme when schemes are not guessed
+    let typed = if address.contains("://") {
+        address.to_string()
+    } else {
+        format!("finger://{}", address)
+    };
+    let url = parse_input_url(&typed)
+        .filter(|url| url.scheme() == "finger")
+        .ok_or_else(|| format!("Not a finger address: {}", address))?;
+    let url = canonicalize(&url).to_string();
+    with_db(|db| {
+        db.execute(
+            "INSERT OR IGNORE INTO plan_watches (url, added_at) VALUES (?1, ?2)",
+            params![url, now()],
+        )?;
+        db.query_row(
+            &format!("SELECT {} FROM plan_watches WHERE url = ?1", PLAN_COLUMNS),
+            [&url],
+            plan_from_row,
+        )
+    })
+}
+
+/// Stop watching a finger address, returning whether it was watched
+pub fn unwatch_plan(id: i64) -> Result<bool, String> {
+    let removed = with_db(|db| db.execute("DELETE FROM plan_watches WHERE id = ?1", [id]))?;
+    Ok(removed > 0)
+}
+
+/// Every watched finger address, updated ones first, newest update first, then by address
+pub fn list_watched_plans() -> Result<Vec<WatchedPlan>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM plan_watches
+             ORDER BY updated_at IS NULL, updated_at DESC, url COLLATE NOCASE",
+            PLAN_COLUMNS
+        ))?;
+        let rows = statement.query_map([], plan_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Mark the update of a watched address as read
+pub fn mark_plan_read(id: i64) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE plan_watches SET updated_at = NULL WHERE id = ?1",
+            [id],
+        )
+    })?;
+    Ok(())
+}
+
+/// Keep the answer a check found. The first check takes it as read; later ones mark the address
+/// updated when the answer differs from the one kept.
+pub(crate) fn record_plan(id: i64, plan: &str, hash: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE plan_watches SET
+                 updated_at = CASE
+                     WHEN COALESCE(plan_hash, ?3) = ?3 THEN updated_at
+                     ELSE ?4
+                 END,
+                 plan = ?2, plan_hash = ?3, checked_at = ?4, last_error = NULL
+             WHERE id = ?1",
+            params![id, plan, hash, now()],
+        )
+    })?;
+    Ok(())
+}
+
+/// Record why checking an address failed, keeping the last answer
+pub(crate) fn record_error(id: i64, error: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE plan_watches SET checked_at = ?2, last_error = ?3 WHERE id = ?1",
+            params![id, now(), error],
+        )
+    })?;
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use rusqlite::{params, OptionalExtension};
+use url::Url;
+
+use crate::api::storage::with_db;
+
+/// Scheme-wide overrides are stored with an empty host
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS port_overrides (
+    scheme TEXT NOT NULL,
+    host TEXT NOT NULL DEFAULT '',
+    port INTEGER NOT NULL,
+    PRIMARY KEY (scheme, host)
+);";
+
+/// A port used instead of the protocol default when a URL omits one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortOverride {
+    pub scheme: String,
+    /// Only URLs of this host use the port; None applies it to the whole scheme
+    pub host: Option<String>,
+    pub port: u16,
+}
+
+fn normalize(scheme: &str, host: Option<&str>) -> (String, String) {
+    (
+        scheme.trim().to_ascii_lowercase(),
+        host.unwrap_or_default().trim().to_ascii_lowercase(),
+    )
+}
+
+/// Use a port for URLs of a scheme (or of one host) that do not name one
+pub fn set_override(scheme: &str, host: Option<&str>, port: u16) -> Result<(), String> {
+    let (scheme, host) = normalize(scheme, host);
+    if scheme.is_empty() {
+        return Err("Scheme cannot be empty".to_string());
+    }
+    if port == 0 {
+        return Err("Port must be between 1 and 65535".to_string());
+    }
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO port_overrides (scheme, host, port) VALUES (?1, ?2, ?3)",
+            params![scheme, host, port],
+        )
+    })?;
+    Ok(())
+}
+
+/// Drop an override, returning whether it existed
+pub fn remove_override(scheme: &str, host: Option<&str>) -> Result<bool, String> {
+    let (scheme, host) = normalize(scheme, host);
+    let removed = with_db(|db| {
+        db.execute(
+            "DELETE FROM port_overrides WHERE scheme = ?1 AND host = ?2",
+            params![scheme, host],
+        )
+    })?;
+    Ok(removed > 0)
+}
+
+/// Every override, scheme-wide ones before the host ones of each scheme
+pub fn list_overrides() -> Result<Vec<PortOverride>, String> {
+    with_db(|db| {
+        let mut statement =
+            db.prepare("SELECT scheme, host, port FROM port_overrides ORDER BY scheme, host")?;
+        let rows = statement.query_map([], |row| {
+            let host: String = row.get(1)?;
+            Ok(PortOverride {
+                scheme: row.get(0)?,
+                host: (!host.is_empty()).then_some(host),
+                port: row.get(2)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// The configured default port of a scheme, if overridden
+pub fn scheme_port(scheme: &str) -> Option<u16> {
+    lookup(&scheme.to_ascii_lowercase(), "")
+}
+
+/// The port to connect to: the URL's own, then the host's override, then the scheme's,
+/// then the protocol default
+pub fn resolve_port(url: &Url, default: u16) -> u16 {
+    if let Some(port) = url.port() {
+        return port;
+    }
+    let scheme = url.scheme().to_ascii_lowercase();
+    url.host_str()
+        .and_then(|host| lookup(&scheme, &host.to_ascii_lowercase()))
+        .or_else(|| lookup(&scheme, ""))
+        .unwrap_or(default)
+}
+
+/// A stored port; database errors fall back to the defaults rather than fail the connection
+fn lookup(scheme: &str, host: &str) -> Option<u16> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT port FROM port_overrides WHERE scheme = ?1 AND host = ?2",
+            params![scheme, host],
+            |row| row.get(0),
+        )
+        .optional()
+    })
+    .ok()
+    .flatten()
+}
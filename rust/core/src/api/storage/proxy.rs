@@ -0,0 +1,88 @@
+use rusqlite::{params, Row};
+
+use crate::api::storage::{with_db, with_transaction};
+use crate::api::transport::proxy::{ProxyRule, ProxySettings, Socks5Proxy};
+
+/// Pattern of the row holding the proxy used when no rule matches
+const FALLBACK_PATTERN: &str = "*";
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS proxy_rules (
+    position INTEGER PRIMARY KEY,
+    pattern TEXT NOT NULL,
+    host TEXT,
+    port INTEGER,
+    username TEXT,
+    password TEXT
+);";
+
+fn rule_from_row(row: &Row) -> rusqlite::Result<ProxyRule> {
+    let host: Option<String> = row.get(1)?;
+    let proxy = match host {
+        Some(host) => Some(Socks5Proxy {
+            host,
+            port: row.get(2)?,
+            username: row.get(3)?,
+            password: row.get(4)?,
+        }),
+        None => None,
+    };
+    Ok(ProxyRule {
+        pattern: row.get(0)?,
+        proxy,
+    })
+}
+
+/// The saved proxy settings; a profile without any is direct
+pub(crate) fn load_settings() -> Result<ProxySettings, String> {
+    let mut rules = with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT pattern, host, port, username, password FROM proxy_rules ORDER BY position",
+        )?;
+        let rows = statement.query_map([], rule_from_row)?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+    })?;
+
+    let fallback = match rules.last() {
+        Some(rule) if rule.pattern == FALLBACK_PATTERN => rules.pop().and_then(|rule| rule.proxy),
+        _ => None,
+    };
+    Ok(match (rules.is_empty(), fallback) {
+        (true, None) => ProxySettings::Direct,
+        (true, Some(proxy)) => ProxySettings::Socks5 { proxy },
+        (false, fallback) => ProxySettings::PerRule { rules, fallback },
+    })
+}
+
+/// Replace the saved proxy settings, stored as rules with a trailing catch-all row
+pub fn save_settings(settings: &ProxySettings) -> Result<(), String> {
+    let (rules, fallback) = match settings {
+        ProxySettings::Direct => (&[][..], None),
+        ProxySettings::Socks5 { proxy } => (&[][..], Some(proxy)),
+        ProxySettings::PerRule { rules, fallback } => (&rules[..], fallback.as_ref()),
+    };
+    let fallback_rule = fallback.map(|proxy| ProxyRule {
+        pattern: FALLBACK_PATTERN.to_string(),
+        proxy: Some(proxy.clone()),
+    });
+
+    with_transaction(|db| {
+        db.execute("DELETE FROM proxy_rules", [])?;
+        for (position, rule) in rules.iter().chain(fallback_rule.as_ref()).enumerate() {
+            let proxy = rule.proxy.as_ref();
+            db.execute(
+                "INSERT INTO proxy_rules (position, pattern, host, port, username, password)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    position as i64,
+                    rule.pattern,
+                    proxy.map(|p| &p.host),
+                    proxy.map(|p| p.port),
+                    proxy.and_then(|p| p.username.as_ref()),
+                    proxy.and_then(|p| p.password.as_ref()),
+                ],
+            )?;
+        }
+        Ok(())
+    })
+}
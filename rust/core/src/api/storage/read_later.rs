@@ -0,0 +1,185 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::storage::{now, with_db};
+
+/// Pages saved to read later, each with its own copy of the page so it stays readable offline
+/// after the cache evicts it; the copy is missing until a fetch succeeds
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS read_later (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL UNIQUE,
+    title TEXT,
+    added_at INTEGER NOT NULL,
+    read_at INTEGER,
+    status INTEGER,
+    meta TEXT,
+    mime TEXT,
+    body TEXT,
+    fetched_at INTEGER,
+    last_error TEXT
+);";
+
+/// A page in the read-later queue, without its body
+#[derive(Debug, Clone)]
+pub struct ReadLaterItem {
+    pub id: i64,
+    /// The page's canonical URL
+    pub url: String,
+    /// Title of the saved copy, or the one given when the page was saved
+    pub title: Option<String>,
+    /// Seconds since the Unix epoch
+    pub added_at: i64,
+    /// When the item was marked read; None while it is unread
+    pub read_at: Option<i64>,
+    /// Whether a copy of the page is saved for reading offline
+    pub cached: bool,
+    /// When the saved copy was fetched
+    pub fetched_at: Option<i64>,
+    /// Body size of the saved copy in bytes
+    pub size: u64,
+    /// Why the last fetch failed, cleared by a successful one
+    pub last_error: Option<String>,
+}
+
+const ITEM_COLUMNS: &str =
+    "id, url, title, added_at, read_at, body IS NOT NULL, fetched_at, LENGTH(body), last_error";
+
+fn item_from_row(row: &Row) -> rusqlite::Result<ReadLaterItem> {
+    let size: Option<i64> = row.get(7)?;
+    Ok(ReadLaterItem {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        added_at: row.get(3)?,
+        read_at: row.get(4)?,
+        cached: row.get(5)?,
+        fetched_at: row.get(6)?,
+        size: size.unwrap_or_default() as u64,
+        last_error: row.get(8)?,
+    })
+}
+
+/// Add a page to the queue, or return the item already queued for it, marked unread again
+pub(crate) fn add(canonical_url: &str, title: Option<&str>) -> Result<ReadLaterItem, String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT INTO read_later (url, title, added_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT (url) DO UPDATE SET read_at = NULL, title = COALESCE(title, ?2)",
+            params![canonical_url, title, now()],
+        )?;
+        db.query_row(
+            &format!("SELECT {} FROM read_later WHERE url = ?1", ITEM_COLUMNS),
+            [canonical_url],
+            item_from_row,
+        )
+    })
+}
+
+/// The item with an id, if it is queued
+pub fn get_read_later(id: i64) -> Result<Option<ReadLaterItem>, String> {
+    with_db(|db| {
+        db.query_row(
+            &format!("SELECT {} FROM read_later WHERE id = ?1", ITEM_COLUMNS),
+            [id],
+            item_from_row,
+        )
+        .optional()
+    })
+}
+
+/// Queued items, unread ones first and newest first within each; read ones only when asked for
+pub fn list_read_later(include_read: bool) -> Result<Vec<ReadLaterItem>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM read_later WHERE ?1 OR read_at IS NULL
+             ORDER BY read_at IS NOT NULL, added_at DESC, id DESC",
+            ITEM_COLUMNS
+        ))?;
+        let rows = statement.query_map([include_read], item_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Ids and URLs of the items without a saved copy
+pub(crate) fn uncached() -> Result<Vec<(i64, String)>, String> {
+    with_db(|db| {
+        let mut statement =
+            db.prepare("SELECT id, url FROM read_later WHERE body IS NULL ORDER BY added_at")?;
+        let rows = statement.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    })
+}
+
+/// Keep a fetched response as an item's copy, replacing any earlier one
+pub(crate) fn store_copy(id: i64, response: &FetchResponse, fetched_at: i64) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE read_later SET title = COALESCE(?2, title), status = ?3, meta = ?4,
+                 mime = ?5, body = ?6, fetched_at = ?7, last_error = NULL
+             WHERE id = ?1",
+            params![
+                id,
+                response.title,
+                response.status,
+                response.meta,
+                response.mime,
+                response.body,
+                fetched_at
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Record why fetching an item failed, keeping any copy it already has
+pub(crate) fn record_error(id: i64, error: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE read_later SET last_error = ?2 WHERE id = ?1",
+            params![id, error],
+        )
+    })?;
+    Ok(())
+}
+
+/// An item's saved copy as a response, analysed again like a cached one; `cached_at` is when it
+/// was fetched. None when the item is unknown or has no copy.
+pub(crate) fn load(id: i64) -> Result<Option<FetchResponse>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT url, status, meta, mime, body, fetched_at FROM read_later
+             WHERE id = ?1 AND body IS NOT NULL",
+            [id],
+            |row| {
+                let status: Option<u8> = row.get(1)?;
+                let meta: Option<String> = row.get(2)?;
+                let response = FetchResponse::new(row.get(0)?, row.get(3)?, row.get(4)?);
+                let response = match status {
+                    Some(status) => response.with_header(status, meta.unwrap_or_default()),
+                    None => response,
+                };
+                Ok(response.with_cached_at(row.get(5)?))
+            },
+        )
+        .optional()
+    })
+}
+
+/// Mark an item read or unread again, returning whether it is queued
+pub fn mark_read_later(id: i64, read: bool) -> Result<bool, String> {
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE read_later SET read_at = CASE WHEN ?2 THEN COALESCE(read_at, ?3) END
+             WHERE id = ?1",
+            params![id, read, now()],
+        )
+    })?;
+    Ok(updated > 0)
+}
+
+/// Remove an item and its copy from the queue, returning whether it was queued
+pub fn remove_read_later(id: i64) -> Result<bool, String> {
+    let removed = with_db(|db| db.execute("DELETE FROM read_later WHERE id = ?1", [id]))?;
+    Ok(removed > 0)
+}
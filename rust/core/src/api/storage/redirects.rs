@@ -0,0 +1,101 @@
+use rusqlite::params;
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS permanent_redirects (
+    from_url TEXT PRIMARY KEY,
+    scheme TEXT NOT NULL,
+    from_host TEXT NOT NULL,
+    to_url TEXT NOT NULL,
+    to_host TEXT NOT NULL,
+    seen_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS permanent_redirects_from_host ON permanent_redirects (from_host);";
+
+/// A page that answered with a permanent redirect to another host
+#[derive(Debug, Clone)]
+pub(crate) struct PermanentRedirect {
+    pub from_url: String,
+    pub scheme: String,
+    pub from_host: String,
+    pub to_url: String,
+    pub to_host: String,
+}
+
+/// Remember that a page permanently moved to another host, replacing what it said before
+pub(crate) fn record(
+    from_url: &str,
+    scheme: &str,
+    from_host: &str,
+    to_url: &str,
+    to_host: &str,
+) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO permanent_redirects
+                (from_url, scheme, from_host, to_url, to_host, seen_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                from_url,
+                scheme,
+                from_host.to_ascii_lowercase(),
+                to_url,
+                to_host.to_ascii_lowercase(),
+                now()
+            ],
+        )
+    })?;
+    Ok(())
+}
+
+/// Forget a page's redirect, e.g. once it answers normally again
+pub(crate) fn forget_url(from_url: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM permanent_redirects WHERE from_url = ?1",
+            [from_url],
+        )
+    })?;
+    Ok(())
+}
+
+/// Every recorded redirect, grouped by the host that moved
+pub(crate) fn list() -> Result<Vec<PermanentRedirect>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT from_url, scheme, from_host, to_url, to_host
+             FROM permanent_redirects ORDER BY scheme, from_host, to_host, from_url",
+        )?;
+        let rows = statement.query_map([], |row| {
+            Ok(PermanentRedirect {
+                from_url: row.get(0)?,
+                scheme: row.get(1)?,
+                from_host: row.get(2)?,
+                to_url: row.get(3)?,
+                to_host: row.get(4)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Forget every redirect away from a host on a scheme, returning how many were removed
+pub(crate) fn forget_host(scheme: &str, from_host: &str) -> Result<usize, String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM permanent_redirects WHERE scheme = ?1 AND from_host = ?2",
+            params![scheme, from_host.to_ascii_lowercase()],
+        )
+    })
+}
+
+/// Delete redirects seen at or after the given time, returning how many were removed
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_db(|db| {
+        db.execute(
+            "DELETE FROM permanent_redirects WHERE seen_at >= ?1",
+            [since],
+        )
+    })
+}
@@ -0,0 +1,56 @@
+use rusqlite::params;
+use url::Url;
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::protocols::registry;
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS disabled_schemes (
+    scheme TEXT PRIMARY KEY,
+    disabled_at INTEGER NOT NULL
+);";
+
+/// Prefix of the error returned when a URL uses a scheme the user turned off
+pub const SCHEME_DISABLED_ERROR_PREFIX: &str = "SchemeDisabled";
+
+/// Turn a registered protocol on or off
+pub fn set_enabled(scheme: &str, enabled: bool) -> Result<(), String> {
+    let scheme = scheme.trim().to_ascii_lowercase();
+    if registry::handler_for(&scheme).is_none() {
+        return Err(format!("Unknown scheme {}", scheme));
+    }
+    with_db(|db| {
+        if enabled {
+            db.execute("DELETE FROM disabled_schemes WHERE scheme = ?1", [&scheme])
+        } else {
+            db.execute(
+                "INSERT OR IGNORE INTO disabled_schemes (scheme, disabled_at) VALUES (?1, ?2)",
+                params![scheme, now()],
+            )
+        }
+    })?;
+    Ok(())
+}
+
+/// Whether URLs of a scheme may be fetched; protocols are on unless turned off
+pub fn is_enabled(scheme: &str) -> Result<bool, String> {
+    let disabled = with_db(|db| {
+        db.prepare("SELECT 1 FROM disabled_schemes WHERE scheme = ?1")?
+            .exists([scheme.to_ascii_lowercase()])
+    })?;
+    Ok(!disabled)
+}
+
+/// Refuse a URL whose scheme is turned off before anything is fetched
+pub fn check_url(url: &Url) -> Result<(), NavigateError> {
+    if is_enabled(url.scheme()).map_err(NavigateError::StorageError)? {
+        Ok(())
+    } else {
+        Err(NavigateError::SchemeDisabled(format!(
+            "{}: {} URLs are turned off",
+            SCHEME_DISABLED_ERROR_PREFIX,
+            url.scheme()
+        )))
+    }
+}
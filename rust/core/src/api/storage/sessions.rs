@@ -0,0 +1,226 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::api::storage::{now, with_db, with_transaction};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS sessions (
+    name TEXT PRIMARY KEY,
+    active_tab INTEGER NOT NULL,
+    saved_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS session_tabs (
+    session TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    current_entry INTEGER NOT NULL,
+    PRIMARY KEY (session, position)
+);
+CREATE TABLE IF NOT EXISTS session_entries (
+    session TEXT NOT NULL,
+    tab INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    title TEXT,
+    scroll_offset REAL NOT NULL,
+    PRIMARY KEY (session, tab, position)
+);";
+
+/// Name under which the app keeps the tabs it had open, saved as they change and restored on
+/// the next start
+pub const CURRENT_SESSION: &str = "current";
+
+/// One page of a tab's navigation stack
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionEntry {
+    pub url: String,
+    pub title: Option<String>,
+    /// How far the page was scrolled, in logical pixels
+    pub scroll_offset: f64,
+}
+
+/// An open tab and its back/forward stack
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionTab {
+    /// Pages from the oldest back entry to the newest forward entry
+    pub entries: Vec<SessionEntry>,
+    /// Index of the page the tab shows in `entries`
+    pub current_entry: u32,
+    /// Private (incognito) tab: never written to the profile database, so it is not restored
+    pub private: bool,
+}
+
+/// The open tabs of a window
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub name: String,
+    pub tabs: Vec<SessionTab>,
+    /// Index of the selected tab in `tabs`
+    pub active_tab: u32,
+    /// Seconds since the Unix epoch; set when the session is saved
+    pub saved_at: i64,
+}
+
+/// A saved session without its tabs
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    pub name: String,
+    pub tab_count: u32,
+    pub saved_at: i64,
+}
+
+/// Save a session, replacing the one of the same name in a single transaction so a crash midway
+/// leaves the previous save intact. Private tabs and tabs without pages are left out, and the
+/// indexes are clamped to what remains.
+pub fn save_session(session: &Session) -> Result<(), String> {
+    let name = session.name.trim();
+    if name.is_empty() {
+        return Err("Session name cannot be empty".to_string());
+    }
+    let active = session.tabs.get(session.active_tab as usize);
+    let tabs: Vec<&SessionTab> = session
+        .tabs
+        .iter()
+        .filter(|tab| !tab.private && !tab.entries.is_empty())
+        .collect();
+    let active_tab = active
+        .and_then(|active| tabs.iter().position(|tab| std::ptr::eq(*tab, active)))
+        .unwrap_or(0);
+
+    with_transaction(|db| {
+        delete(db, name)?;
+        db.execute(
+            "INSERT INTO sessions (name, active_tab, saved_at) VALUES (?1, ?2, ?3)",
+            params![name, active_tab as u32, now()],
+        )?;
+        let mut insert_tab = db.prepare(
+            "INSERT INTO session_tabs (session, position, current_entry) VALUES (?1, ?2, ?3)",
+        )?;
+        let mut insert_entry = db.prepare(
+            "INSERT INTO session_entries (session, tab, position, url, title, scroll_offset)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for (position, tab) in tabs.iter().enumerate() {
+            let current_entry = tab.current_entry.min(tab.entries.len() as u32 - 1);
+            insert_tab.execute(params![name, position as u32, current_entry])?;
+            for (index, entry) in tab.entries.iter().enumerate() {
+                insert_entry.execute(params![
+                    name,
+                    position as u32,
+                    index as u32,
+                    entry.url,
+                    entry.title,
+                    entry.scroll_offset
+                ])?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// A saved session with its tabs in order, if there is one of that name
+pub fn load_session(name: &str) -> Result<Option<Session>, String> {
+    with_db(|db| {
+        let Some((active_tab, saved_at)) = db
+            .query_row(
+                "SELECT active_tab, saved_at FROM sessions WHERE name = ?1",
+                [name],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+        else {
+            return Ok(None);
+        };
+        let mut tabs: Vec<SessionTab> = db
+            .prepare("SELECT current_entry FROM session_tabs WHERE session = ?1 ORDER BY position")?
+            .query_map([name], |row| {
+                Ok(SessionTab {
+                    entries: Vec::new(),
+                    current_entry: row.get(0)?,
+                    private: false,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        let mut statement = db.prepare(
+            "SELECT tab, url, title, scroll_offset FROM session_entries
+             WHERE session = ?1 ORDER BY tab, position",
+        )?;
+        let entries = statement.query_map([name], |row| {
+            let entry = SessionEntry {
+                url: row.get(1)?,
+                title: row.get(2)?,
+                scroll_offset: row.get(3)?,
+            };
+            Ok((row.get::<_, u32>(0)?, entry))
+        })?;
+        for entry in entries {
+            let (tab, entry) = entry?;
+            if let Some(tab) = tabs.get_mut(tab as usize) {
+                tab.entries.push(entry);
+            }
+        }
+        Ok(Some(Session {
+            name: name.to_string(),
+            tabs,
+            active_tab,
+            saved_at,
+        }))
+    })
+}
+
+/// Record how far the current page of a saved tab is scrolled, without saving the whole session;
+/// returns whether the tab exists
+pub fn set_scroll_offset(name: &str, tab: u32, scroll_offset: f64) -> Result<bool, String> {
+    let updated = with_db(|db| {
+        db.execute(
+            "UPDATE session_entries SET scroll_offset = ?3
+             WHERE session = ?1 AND tab = ?2 AND position =
+                 (SELECT current_entry FROM session_tabs WHERE session = ?1 AND position = ?2)",
+            params![name, tab, scroll_offset],
+        )
+    })?;
+    Ok(updated > 0)
+}
+
+/// Every saved session, most recently saved first
+pub fn list_sessions() -> Result<Vec<SessionSummary>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(
+            "SELECT name, saved_at,
+                 (SELECT COUNT(*) FROM session_tabs WHERE session = sessions.name)
+             FROM sessions ORDER BY saved_at DESC",
+        )?;
+        let rows = statement.query_map([], |row| {
+            Ok(SessionSummary {
+                name: row.get(0)?,
+                saved_at: row.get(1)?,
+                tab_count: row.get(2)?,
+            })
+        })?;
+        rows.collect()
+    })
+}
+
+/// Delete a saved session, returning whether it existed
+pub fn delete_session(name: &str) -> Result<bool, String> {
+    with_transaction(|db| delete(db, name))
+}
+
+fn delete(db: &rusqlite::Connection, name: &str) -> rusqlite::Result<bool> {
+    db.execute("DELETE FROM session_entries WHERE session = ?1", [name])?;
+    db.execute("DELETE FROM session_tabs WHERE session = ?1", [name])?;
+    Ok(db.execute("DELETE FROM sessions WHERE name = ?1", [name])? > 0)
+}
+
+/// Delete sessions saved at or after the given time, returning how many were removed; the pages
+/// of their tabs are browsing history
+pub(crate) fn clear_since(since: i64) -> Result<usize, String> {
+    with_transaction(|db| {
+        let names: Vec<String> = db
+            .prepare("SELECT name FROM sessions WHERE saved_at >= ?1")?
+            .query_map([since], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        for name in &names {
+            delete(db, name)?;
+        }
+        Ok(names.len())
+    })
+}
@@ -0,0 +1,37 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::api::storage::with_db;
+
+/// Single values that do not need a table of their own, such as the start page
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS settings (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);";
+
+/// The stored value of a setting, if it was ever set
+pub(crate) fn get(key: &str) -> Result<Option<String>, String> {
+    with_db(|db| {
+        db.query_row("SELECT value FROM settings WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()
+    })
+}
+
+/// Store a setting, replacing any previous value
+pub(crate) fn set(key: &str, value: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)",
+            params![key, value],
+        )
+    })?;
+    Ok(())
+}
+
+/// Forget a setting so its default applies again
+pub(crate) fn remove(key: &str) -> Result<(), String> {
+    with_db(|db| db.execute("DELETE FROM settings WHERE key = ?1", [key]))?;
+    Ok(())
+}
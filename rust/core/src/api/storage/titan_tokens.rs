@@ -0,0 +1,60 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS titan_tokens (
+    host TEXT PRIMARY KEY,
+    token TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);";
+
+/// Remember the Titan token of a host, sent with every upload to it that names no other token
+pub fn set_titan_token(host: &str, token: &str) -> Result<(), String> {
+    let host = host.trim().to_ascii_lowercase();
+    if host.is_empty() {
+        return Err("Host cannot be empty".to_string());
+    }
+    if token.is_empty() {
+        return Err("Token cannot be empty".to_string());
+    }
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO titan_tokens (host, token, updated_at) VALUES (?1, ?2, ?3)",
+            params![host, token, now()],
+        )
+    })?;
+    Ok(())
+}
+
+/// The stored Titan token of a host, if any
+pub(crate) fn get_titan_token(host: &str) -> Result<Option<String>, String> {
+    with_db(|db| {
+        db.query_row(
+            "SELECT token FROM titan_tokens WHERE host = ?1",
+            [host.to_ascii_lowercase()],
+            |row| row.get(0),
+        )
+        .optional()
+    })
+}
+
+/// Forget the Titan token of a host, returning whether one was stored
+pub fn remove_titan_token(host: &str) -> Result<bool, String> {
+    let removed = with_db(|db| {
+        db.execute(
+            "DELETE FROM titan_tokens WHERE host = ?1",
+            [host.trim().to_ascii_lowercase()],
+        )
+    })?;
+    Ok(removed > 0)
+}
+
+/// Hosts with a stored Titan token, alphabetically; the tokens themselves are not returned
+pub fn titan_token_hosts() -> Result<Vec<String>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare("SELECT host FROM titan_tokens ORDER BY host")?;
+        let rows = statement.query_map([], |row| row.get(0))?;
+        rows.collect()
+    })
+}
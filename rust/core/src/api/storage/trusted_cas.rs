@@ -0,0 +1,79 @@
+use rusqlite::{params, Row};
+
+use crate::api::storage::with_db;
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS trusted_cas (
+    fingerprint TEXT PRIMARY KEY,
+    subject TEXT NOT NULL,
+    not_after INTEGER NOT NULL,
+    certificate BLOB NOT NULL,
+    added_at INTEGER NOT NULL
+);";
+
+/// A certificate authority in the user trust store, which vouches for the certificates of
+/// hosts whose TLS policy is strict
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedCa {
+    /// SHA-256 of the DER certificate as lowercase hex
+    pub fingerprint: String,
+    pub subject: String,
+    pub not_after: i64,
+    pub added_at: i64,
+}
+
+const CA_COLUMNS: &str = "fingerprint, subject, not_after, added_at";
+
+fn ca_from_row(row: &Row) -> rusqlite::Result<TrustedCa> {
+    Ok(TrustedCa {
+        fingerprint: row.get(0)?,
+        subject: row.get(1)?,
+        not_after: row.get(2)?,
+        added_at: row.get(3)?,
+    })
+}
+
+/// Store a CA certificate, replacing the same certificate imported before
+pub(crate) fn add(ca: &TrustedCa, der: &[u8]) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "INSERT OR REPLACE INTO trusted_cas
+                (fingerprint, subject, not_after, certificate, added_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![ca.fingerprint, ca.subject, ca.not_after, der, ca.added_at],
+        )
+    })?;
+    Ok(())
+}
+
+/// Remove a CA from the trust store, returning whether it was there
+pub fn remove_trusted_ca(fingerprint: &str) -> Result<bool, String> {
+    let removed = with_db(|db| {
+        db.execute(
+            "DELETE FROM trusted_cas WHERE fingerprint = ?1",
+            [fingerprint.to_ascii_lowercase()],
+        )
+    })?;
+    Ok(removed > 0)
+}
+
+/// Every CA in the trust store, by subject
+pub fn list_trusted_cas() -> Result<Vec<TrustedCa>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM trusted_cas ORDER BY subject, fingerprint",
+            CA_COLUMNS
+        ))?;
+        let rows = statement.query_map([], ca_from_row)?;
+        rows.collect()
+    })
+}
+
+/// The DER certificates of every CA in the trust store
+pub(crate) fn certificates() -> Result<Vec<Vec<u8>>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare("SELECT certificate FROM trusted_cas")?;
+        let rows = statement.query_map([], |row| row.get(0))?;
+        rows.collect()
+    })
+}
@@ -0,0 +1,134 @@
+use rusqlite::{params, Row};
+use url::Url;
+
+use crate::api::functions::canonical_url::canonicalize;
+use crate::api::storage::{now, with_db};
+
+pub(crate) const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS watches (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL UNIQUE,
+    title TEXT,
+    added_at INTEGER NOT NULL,
+    seen_hash TEXT,
+    seen_at INTEGER,
+    checked_at INTEGER,
+    changed_at INTEGER,
+    last_error TEXT
+);";
+
+/// A page watched for changes, for capsules that publish no feed
+#[derive(Debug, Clone)]
+pub struct WatchedPage {
+    pub id: i64,
+    /// The page's canonical URL
+    pub url: String,
+    pub title: Option<String>,
+    /// Seconds since the Unix epoch
+    pub added_at: i64,
+    /// When the user last visited the page, or when it was first checked if never
+    pub seen_at: Option<i64>,
+    /// When the page was last fetched to check it, successfully or not
+    pub checked_at: Option<i64>,
+    /// When a check first found the page different from the visited version; None while it is
+    /// unchanged
+    pub changed_at: Option<i64>,
+    /// Why the last check failed, cleared by a successful one
+    pub last_error: Option<String>,
+}
+
+const WATCH_COLUMNS: &str = "id, url, title, added_at, seen_at, checked_at, changed_at, last_error";
+
+fn watch_from_row(row: &Row) -> rusqlite::Result<WatchedPage> {
+    Ok(WatchedPage {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        added_at: row.get(3)?,
+        seen_at: row.get(4)?,
+        checked_at: row.get(5)?,
+        changed_at: row.get(6)?,
+        last_error: row.get(7)?,
+    })
+}
+
+/// Watch a page for changes, or return the existing watch of the same page
+pub fn watch_page(url: &str, title: Option<&str>) -> Result<WatchedPage, String> {
+    let url = Url::parse(url.trim()).map_err(|e| format!("Invalid URL: {}", e))?;
+    let url = canonicalize(&url).to_string();
+    let title = title.map(str::trim).filter(|title| !title.is_empty());
+    with_db(|db| {
+        db.execute(
+            "INSERT OR IGNORE INTO watches (url, title, added_at) VALUES (?1, ?2, ?3)",
+            params![url, title, now()],
+        )?;
+        db.query_row(
+            &format!("SELECT {} FROM watches WHERE url = ?1", WATCH_COLUMNS),
+            [&url],
+            watch_from_row,
+        )
+    })
+}
+
+/// Stop watching a page, returning whether it was watched
+pub fn unwatch_page(id: i64) -> Result<bool, String> {
+    let removed = with_db(|db| db.execute("DELETE FROM watches WHERE id = ?1", [id]))?;
+    Ok(removed > 0)
+}
+
+/// Every watched page, changed ones first, then by title
+pub fn list_watched_pages() -> Result<Vec<WatchedPage>, String> {
+    with_db(|db| {
+        let mut statement = db.prepare(&format!(
+            "SELECT {} FROM watches
+             ORDER BY changed_at IS NULL, changed_at DESC, COALESCE(title, url) COLLATE NOCASE",
+            WATCH_COLUMNS
+        ))?;
+        let rows = statement.query_map([], watch_from_row)?;
+        rows.collect()
+    })
+}
+
+/// Record the content hash a check found. The first check of a page never visited since it was
+/// watched takes it as the version seen; later ones mark the page changed when the hash differs
+/// from the version seen.
+pub(crate) fn record_check(id: i64, hash: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE watches SET
+                 seen_at = CASE WHEN seen_hash IS NULL THEN ?3 ELSE seen_at END,
+                 seen_hash = COALESCE(seen_hash, ?2),
+                 changed_at = CASE
+                     WHEN COALESCE(seen_hash, ?2) = ?2 THEN NULL
+                     ELSE COALESCE(changed_at, ?3)
+                 END,
+                 checked_at = ?3, last_error = NULL
+             WHERE id = ?1",
+            params![id, hash, now()],
+        )
+    })?;
+    Ok(())
+}
+
+/// Record why checking a page failed
+pub(crate) fn record_error(id: i64, error: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE watches SET checked_at = ?2, last_error = ?3 WHERE id = ?1",
+            params![id, now(), error],
+        )
+    })?;
+    Ok(())
+}
+
+/// Note that the user saw a page with the given content hash, clearing its change; a page that
+/// is not watched is left alone
+pub(crate) fn mark_seen(canonical_url: &str, hash: &str) -> Result<(), String> {
+    with_db(|db| {
+        db.execute(
+            "UPDATE watches SET seen_hash = ?2, seen_at = ?3, changed_at = NULL WHERE url = ?1",
+            params![canonical_url, hash, now()],
+        )
+    })?;
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use futures_util::future::{self, Either};
+use gloo_timers::future::TimeoutFuture;
+use js_sys::{ArrayBuffer, Uint8Array};
+use send_wrapper::SendWrapper;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::transport::gateway::GatewayDialer;
+use crate::api::transport::limits::{decompression_limits, DECOMPRESSION_LIMIT_ERROR_PREFIX};
+use crate::api::transport::{Connection, DialFuture, Dialer};
+
+impl Dialer for GatewayDialer {
+    fn dial<'a>(&'a self, host: &'a str, port: u16, timeout: Duration) -> DialFuture<'a> {
+        let url = self.url_for(host, port);
+        // The browser's socket belongs to the thread that opened it, the only one a web build
+        // runs its futures on
+        Box::pin(SendWrapper::new(async move {
+            let socket = BrowserSocket::open(&url, timeout).await?;
+            let connection: Box<dyn Connection> = Box::new(socket);
+            Ok(connection)
+        }))
+    }
+}
+
+/// What the socket's event handlers have seen, for the reads polling it
+#[derive(Default)]
+struct State {
+    open: bool,
+    closed: bool,
+    /// Why the socket failed, reported by the next read
+    error: Option<NavigateError>,
+    /// Messages not yet read; the first may be read in part, up to `offset`
+    received: VecDeque<Vec<u8>>,
+    offset: usize,
+    /// The read or the dial waiting for an event
+    waker: Option<Waker>,
+}
+
+/// Update the state from an event handler and wake whoever waits for it
+fn notify(state: &RefCell<State>, update: impl FnOnce(&mut State)) {
+    let waker = {
+        let mut state = state.borrow_mut();
+        update(&mut state);
+        state.waker.take()
+    };
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+/// A browser WebSocket to the gateway with the handlers feeding its state
+struct Socket {
+    socket: WebSocket,
+    state: Rc<RefCell<State>>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onerror(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// A tunnelled connection exposing the binary messages of a browser WebSocket as a byte stream
+struct BrowserSocket(SendWrapper<Socket>);
+
+impl BrowserSocket {
+    /// Open a WebSocket to the gateway and wait until it is open, at most `timeout`
+    async fn open(url: &str, timeout: Duration) -> Result<BrowserSocket, NavigateError> {
+        let socket = WebSocket::new(url)
+            .map_err(|e| NavigateError::GatewayError(format!("Invalid gateway URL: {:?}", e)))?;
+        socket.set_binary_type(BinaryType::Arraybuffer);
+        let state = Rc::new(RefCell::new(State::default()));
+
+        let on_open = {
+            let state = state.clone();
+            Closure::<dyn FnMut(Event)>::new(move |_| notify(&state, |state| state.open = true))
+        };
+        // A gateway could send one enormous message; bound it like any other decoded content
+        let max_message = usize::try_from(decompression_limits().max_bytes).unwrap_or(usize::MAX);
+        let on_message = {
+            let state = state.clone();
+            let socket = socket.clone();
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let data = match event.data().dyn_into::<ArrayBuffer>() {
+                    Ok(buffer) => Uint8Array::new(&buffer),
+                    // Text frames carry bytes too, as the native tunnel reads them
+                    Err(data) => match data.as_string() {
+                        Some(text) => Uint8Array::from(text.as_bytes()),
+                        None => return,
+                    },
+                };
+                if data.length() as usize > max_message {
+                    notify(&state, |state| {
+                        state.error = Some(NavigateError::TooLarge(format!(
+                            "{}: gateway message of {} bytes",
+                            DECOMPRESSION_LIMIT_ERROR_PREFIX,
+                            data.length()
+                        )))
+                    });
+                    let _ = socket.close();
+                } else if data.length() > 0 {
+                    notify(&state, |state| state.received.push_back(data.to_vec()));
+                }
+            })
+        };
+        // Browsers tell nothing about the failure; the close event that follows has its code
+        let on_error = {
+            let state = state.clone();
+            Closure::<dyn FnMut(Event)>::new(move |_| {
+                notify(&state, |state| {
+                    if state.open && state.error.is_none() {
+                        state.error = Some(NavigateError::GatewayError(
+                            "Gateway connection failed".to_string(),
+                        ));
+                    }
+                })
+            })
+        };
+        let on_close = {
+            let state = state.clone();
+            Closure::<dyn FnMut(CloseEvent)>::new(move |event: CloseEvent| {
+                notify(&state, |state| {
+                    state.closed = true;
+                    if !state.open && state.error.is_none() {
+                        state.error = Some(NavigateError::GatewayError(format!(
+                            "Could not reach gateway: closed with code {} {}",
+                            event.code(),
+                            event.reason()
+                        )));
+                    }
+                })
+            })
+        };
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        let socket = Socket {
+            socket,
+            state,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_error: on_error,
+            _on_close: on_close,
+        };
+
+        // A gateway that accepts the connection but never answers must not hang the caller
+        let opened = pin!(future::poll_fn(|cx| poll_open(&socket.state, cx)));
+        let timer = TimeoutFuture::new(timeout.as_millis().min(u32::MAX as u128) as u32);
+        let opened = match future::select(opened, timer).await {
+            Either::Left((opened, _)) => opened,
+            Either::Right(_) => Err(NavigateError::GatewayError(
+                "Gateway handshake failed: timed out".to_string(),
+            )),
+        };
+        opened.map(|()| BrowserSocket(SendWrapper::new(socket)))
+    }
+}
+
+/// Whether the socket opened, or failed to
+fn poll_open(state: &RefCell<State>, cx: &mut Context<'_>) -> Poll<Result<(), NavigateError>> {
+    let mut state = state.borrow_mut();
+    if state.open {
+        return Poll::Ready(Ok(()));
+    }
+    if let Some(e) = state.error.take() {
+        return Poll::Ready(Err(e));
+    }
+    if state.closed {
+        return Poll::Ready(Err(NavigateError::GatewayError(
+            "Could not reach gateway".to_string(),
+        )));
+    }
+    state.waker = Some(cx.waker().clone());
+    Poll::Pending
+}
+
+impl fmt::Debug for BrowserSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrowserSocket")
+            .field("url", &self.0.socket.url())
+            .finish()
+    }
+}
+
+impl AsyncRead for BrowserSocket {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut state = self.0.state.borrow_mut();
+        if let Some(message) = state.received.front() {
+            let available = &message[state.offset..];
+            let n = available.len().min(buf.remaining());
+            buf.put_slice(&available[..n]);
+            if n == available.len() {
+                state.received.pop_front();
+                state.offset = 0;
+            } else {
+                state.offset += n;
+            }
+            return Poll::Ready(Ok(()));
+        }
+        if let Some(e) = state.error.take() {
+            return Poll::Ready(Err(io::Error::other(e)));
+        }
+        if state.closed {
+            return Poll::Ready(Ok(()));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl AsyncWrite for BrowserSocket {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.0.state.borrow().closed {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+        // The browser queues the message and sends it on its own
+        self.0
+            .socket
+            .send_with_u8_array(buf)
+            .map_err(|e| io::Error::other(format!("Gateway send failed: {:?}", e)))?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let _ = self.0.socket.close();
+        Poll::Ready(Ok(()))
+    }
+}
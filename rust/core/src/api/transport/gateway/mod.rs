@@ -0,0 +1,63 @@
+#[cfg(target_arch = "wasm32")]
+use std::time::Duration;
+
+use url::Url;
+
+#[cfg(target_arch = "wasm32")]
+use crate::api::models::navigate_error::NavigateError;
+#[cfg(target_arch = "wasm32")]
+use crate::api::transport::{DialFuture, Dialer};
+
+#[cfg(target_arch = "wasm32")]
+mod browser;
+#[cfg(not(target_arch = "wasm32"))]
+mod tunnel;
+
+/// Tunnels each connection as binary frames over a WebSocket to a gateway
+///
+/// The gateway (for example websockify) opens the real TCP connection, so TLS to
+/// Gemini servers is still negotiated end-to-end by the protocol modules. The URL
+/// template must contain `{host}` and `{port}` placeholders, e.g.
+/// `wss://gateway.example/tunnel?host={host}&port={port}`. Native builds speak the
+/// WebSocket protocol over their own socket; web builds open the browser's WebSocket.
+pub struct GatewayDialer {
+    template: String,
+}
+
+impl GatewayDialer {
+    pub fn new(template: String) -> Result<Self, String> {
+        if !template.contains("{host}") || !template.contains("{port}") {
+            return Err("Gateway URL must contain {host} and {port} placeholders".to_string());
+        }
+        let probe = template.replace("{host}", "example").replace("{port}", "1");
+        match Url::parse(&probe) {
+            Ok(url) if url.scheme() == "ws" || url.scheme() == "wss" => {
+                Ok(GatewayDialer { template })
+            }
+            Ok(_) => Err("Gateway URL must use ws:// or wss://".to_string()),
+            Err(e) => Err(format!("Invalid gateway URL: {}", e)),
+        }
+    }
+
+    /// The gateway URL that tunnels a connection to host:port
+    fn url_for(&self, host: &str, port: u16) -> String {
+        self.template
+            .replace("{host}", host)
+            .replace("{port}", &port.to_string())
+    }
+}
+
+/// Dialer of web builds until a gateway is configured, as browsers cannot open raw sockets
+#[cfg(target_arch = "wasm32")]
+pub struct MissingGateway;
+
+#[cfg(target_arch = "wasm32")]
+impl Dialer for MissingGateway {
+    fn dial<'a>(&'a self, _: &'a str, _: u16, _: Duration) -> DialFuture<'a> {
+        Box::pin(async {
+            Err(NavigateError::GatewayError(
+                "No gateway configured; web builds need set_gateway before navigating".to_string(),
+            ))
+        })
+    }
+}
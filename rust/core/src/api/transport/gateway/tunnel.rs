@@ -0,0 +1,167 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::WebSocketStream;
+use url::Url;
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::transport::gateway::GatewayDialer;
+use crate::api::transport::limits::{decompression_limits, DECOMPRESSION_LIMIT_ERROR_PREFIX};
+use crate::api::transport::{ip_literal, tcp::TcpDialer, Connection, DialFuture, Dialer};
+
+impl Dialer for GatewayDialer {
+    fn dial<'a>(&'a self, host: &'a str, port: u16, timeout: Duration) -> DialFuture<'a> {
+        Box::pin(async move {
+            let url = self.url_for(host, port);
+            let parsed = Url::parse(&url)
+                .map_err(|e| NavigateError::GatewayError(format!("Invalid gateway URL: {}", e)))?;
+            let gateway_host = parsed
+                .host_str()
+                .ok_or_else(|| NavigateError::GatewayError("Invalid gateway host".to_string()))?;
+            let secure = parsed.scheme() == "wss";
+            let gateway_port = parsed.port().unwrap_or(if secure { 443 } else { 80 });
+
+            let stream = TcpDialer
+                .dial(gateway_host, gateway_port, timeout)
+                .await
+                .map_err(|e| {
+                    NavigateError::GatewayError(format!("Could not reach gateway: {}", e))
+                })?;
+            // A gateway that accepts the connection but never answers must not hang the caller
+            let socket = tokio::time::timeout(timeout, open(&url, gateway_host, secure, stream))
+                .await
+                .map_err(|_| {
+                    NavigateError::GatewayError("Gateway handshake failed: timed out".to_string())
+                })??;
+            let connection: Box<dyn Connection> = Box::new(GatewayConnection {
+                socket,
+                pending: Vec::new(),
+                offset: 0,
+            });
+            Ok(connection)
+        })
+    }
+}
+
+/// Negotiate TLS with a wss:// gateway, then the WebSocket
+async fn open(
+    url: &str,
+    gateway_host: &str,
+    secure: bool,
+    stream: Box<dyn Connection>,
+) -> Result<WebSocketStream<Box<dyn Connection>>, NavigateError> {
+    let stream: Box<dyn Connection> = if secure {
+        // The gateway is an ordinary web server, so its certificate is verified normally
+        let connector = native_tls::TlsConnector::new().map_err(|e| {
+            NavigateError::GatewayError(format!("TLS connector creation failed: {}", e))
+        })?;
+        // The TLS library takes an IPv6 literal without the brackets of the URL
+        let domain = match ip_literal(gateway_host) {
+            Some(ip) => ip.to_string(),
+            None => gateway_host.to_string(),
+        };
+        match tokio_native_tls::TlsConnector::from(connector)
+            .connect(&domain, stream)
+            .await
+        {
+            Ok(tls) => Box::new(tls),
+            Err(e) => {
+                return Err(NavigateError::GatewayError(format!(
+                    "TLS connection to gateway failed: {}",
+                    e
+                )))
+            }
+        }
+    } else {
+        stream
+    };
+
+    // A gateway could send one enormous message; bound it like any other decoded content
+    let max_message = usize::try_from(decompression_limits().max_bytes).unwrap_or(usize::MAX);
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(max_message))
+        .max_frame_size(Some(max_message));
+    let (socket, _) = tokio_tungstenite::client_async_with_config(url, stream, Some(config))
+        .await
+        .map_err(|e| NavigateError::GatewayError(format!("Gateway handshake failed: {}", e)))?;
+    Ok(socket)
+}
+
+/// A tunnelled connection exposing the WebSocket's binary frames as a byte stream
+#[derive(Debug)]
+struct GatewayConnection {
+    socket: WebSocketStream<Box<dyn Connection>>,
+    pending: Vec<u8>,
+    offset: usize,
+}
+
+impl AsyncRead for GatewayConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        while this.offset >= this.pending.len() {
+            match ready!(this.socket.poll_next_unpin(cx)) {
+                Some(Ok(Message::Binary(data))) => this.pending = data.to_vec(),
+                Some(Ok(Message::Text(text))) => this.pending = text.as_bytes().to_vec(),
+                Some(Ok(Message::Close(_))) | None => return Poll::Ready(Ok(())),
+                // Ping, pong, and raw frames carry no payload for the tunnel
+                Some(Ok(_)) => continue,
+                Some(Err(tungstenite::Error::ConnectionClosed))
+                | Some(Err(tungstenite::Error::AlreadyClosed)) => return Poll::Ready(Ok(())),
+                Some(Err(tungstenite::Error::Io(e))) => return Poll::Ready(Err(e)),
+                Some(Err(tungstenite::Error::Capacity(e))) => {
+                    return Poll::Ready(Err(io::Error::other(NavigateError::TooLarge(format!(
+                        "{}: gateway message {}",
+                        DECOMPRESSION_LIMIT_ERROR_PREFIX, e
+                    )))))
+                }
+                Some(Err(e)) => return Poll::Ready(Err(io::Error::other(e))),
+            }
+            this.offset = 0;
+        }
+
+        let available = &this.pending[this.offset..];
+        let n = available.len().min(buf.remaining());
+        buf.put_slice(&available[..n]);
+        this.offset += n;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for GatewayConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        ready!(self.socket.poll_ready_unpin(cx)).map_err(socket_error)?;
+        self.socket
+            .start_send_unpin(Message::binary(buf.to_vec()))
+            .map_err(socket_error)?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.socket.poll_flush_unpin(cx).map_err(socket_error)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.socket.poll_close_unpin(cx).map_err(socket_error)
+    }
+}
+
+fn socket_error(e: tungstenite::Error) -> io::Error {
+    match e {
+        tungstenite::Error::Io(e) => e,
+        e => io::Error::other(e),
+    }
+}
@@ -0,0 +1,92 @@
+use std::future::Future;
+use std::sync::{LazyLock, Mutex};
+
+use tokio::sync::Notify;
+
+/// Old phones spend most of a handshake on the CPU, so they get fewer at a time
+#[cfg(any(target_os = "android", target_os = "ios"))]
+const DEFAULT_MAX_HANDSHAKES: u32 = 2;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DEFAULT_MAX_HANDSHAKES: u32 = 4;
+
+/// Background TLS handshakes in progress and the most allowed at once
+struct Slots {
+    active: u32,
+    max: u32,
+}
+
+static SLOTS: Mutex<Slots> = Mutex::new(Slots {
+    active: 0,
+    max: DEFAULT_MAX_HANDSHAKES,
+});
+
+static SLOT_FREED: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+tokio::task_local! {
+    /// Whether the request running on this task was made by a background task, e.g. a prefetch
+    static BACKGROUND: bool;
+}
+
+/// How many TLS handshakes background requests may run at once
+pub fn max_concurrent_handshakes() -> u32 {
+    SLOTS.lock().unwrap().max
+}
+
+/// Change how many TLS handshakes background requests may run at once
+pub fn set_max_concurrent_handshakes(max: u32) -> Result<(), String> {
+    if max == 0 {
+        return Err("Concurrent handshakes must be at least 1".to_string());
+    }
+    SLOTS.lock().unwrap().max = max;
+    // A raised cap lets waiting handshakes start now
+    SLOT_FREED.notify_waiters();
+    Ok(())
+}
+
+/// Run a request, marking whether it was made in the background
+pub(crate) async fn scope<F: Future>(background: bool, future: F) -> F::Output {
+    BACKGROUND.scope(background, future).await
+}
+
+/// Whether the request running on this task was made in the background
+pub(crate) fn is_background() -> bool {
+    BACKGROUND
+        .try_with(|background| *background)
+        .unwrap_or(false)
+}
+
+/// Held for the duration of a TLS handshake; releases its slot when dropped
+pub(crate) struct HandshakePermit {
+    counted: bool,
+}
+
+/// Wait for a handshake slot if the request on this task runs in the background; requests the
+/// user is waiting for never queue behind prefetches and are not counted against the cap
+pub(crate) async fn acquire() -> HandshakePermit {
+    if !is_background() {
+        return HandshakePermit { counted: false };
+    }
+    loop {
+        // Registered before the slots are looked at, so a slot freed meanwhile still wakes it
+        let freed = SLOT_FREED.notified();
+        tokio::pin!(freed);
+        freed.as_mut().enable();
+        {
+            let mut slots = SLOTS.lock().unwrap();
+            if slots.active < slots.max {
+                slots.active += 1;
+                return HandshakePermit { counted: true };
+            }
+        }
+        freed.await;
+    }
+}
+
+impl Drop for HandshakePermit {
+    fn drop(&mut self) {
+        if self.counted {
+            SLOTS.lock().unwrap().active -= 1;
+            SLOT_FREED.notify_waiters();
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::io::{self, Read};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, RwLock};
+
+use crate::api::models::navigate_error::NavigateError;
+
+/// Error prefix when decoded content outgrows the decompression limits
+pub const DECOMPRESSION_LIMIT_ERROR_PREFIX: &str = "Decompressed content too large";
+
+/// Error prefix when a response body outgrows the limit of its request
+pub const BODY_LIMIT_ERROR_PREFIX: &str = "Response too large";
+
+/// Output allowed before the ratio is enforced, so tiny but highly compressible bodies pass
+const RATIO_GRACE_BYTES: u64 = 1024 * 1024;
+
+/// How far decoded content may outgrow what arrived over the wire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecompressionLimits {
+    /// Decoded bytes allowed per received byte
+    pub max_ratio: u32,
+    /// Decoded bytes allowed in total, however well the input compresses
+    pub max_bytes: u64,
+}
+
+impl Default for DecompressionLimits {
+    fn default() -> Self {
+        DecompressionLimits {
+            max_ratio: 100,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+/// Phones are killed long before desktops run out of memory
+#[cfg(any(target_os = "android", target_os = "ios"))]
+const DEFAULT_MAX_BYTES: u64 = 32 * 1024 * 1024;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DEFAULT_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+static LIMITS: LazyLock<RwLock<DecompressionLimits>> =
+    LazyLock::new(|| RwLock::new(DecompressionLimits::default()));
+
+/// The limits decoders and the gateway currently enforce
+pub fn decompression_limits() -> DecompressionLimits {
+    *LIMITS.read().unwrap()
+}
+
+/// Replace the decompression limits
+pub fn set_decompression_limits(limits: DecompressionLimits) -> Result<(), String> {
+    if limits.max_ratio == 0 || limits.max_bytes == 0 {
+        return Err("Decompression limits must be greater than zero".to_string());
+    }
+    *LIMITS.write().unwrap() = limits;
+    Ok(())
+}
+
+/// Counts the bytes read through it into a shared counter
+pub struct CountingReader<R> {
+    inner: R,
+    count: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+/// A decoder whose output is checked against the decompression limits as it is read, failing
+/// before a decompression bomb can exhaust memory
+pub struct BoundedReader<D> {
+    decoder: D,
+    compressed: Arc<AtomicU64>,
+    produced: u64,
+    limits: DecompressionLimits,
+}
+
+/// Wrap compressed input in a decoder, e.g. `bounded(body, GzDecoder::new)`, bounding its output
+pub fn bounded<R: Read, D: Read>(
+    compressed: R,
+    decoder: impl FnOnce(CountingReader<R>) -> D,
+) -> BoundedReader<D> {
+    let count = Arc::new(AtomicU64::new(0));
+    BoundedReader {
+        decoder: decoder(CountingReader {
+            inner: compressed,
+            count: count.clone(),
+        }),
+        compressed: count,
+        produced: 0,
+        limits: decompression_limits(),
+    }
+}
+
+impl<D: Read> Read for BoundedReader<D> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.decoder.read(buf)?;
+        self.produced += n as u64;
+        let compressed = self.compressed.load(Ordering::Relaxed).max(1);
+        if self.produced > self.limits.max_bytes {
+            return Err(io::Error::other(NavigateError::TooLarge(format!(
+                "{}: more than {} bytes",
+                DECOMPRESSION_LIMIT_ERROR_PREFIX, self.limits.max_bytes
+            ))));
+        }
+        if self.produced > RATIO_GRACE_BYTES
+            && self.produced / compressed > self.limits.max_ratio as u64
+        {
+            return Err(io::Error::other(NavigateError::TooLarge(format!(
+                "{}: {} bytes from {} exceeds a ratio of {}",
+                DECOMPRESSION_LIMIT_ERROR_PREFIX, self.produced, compressed, self.limits.max_ratio
+            ))));
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Read a body of `input` bytes that a decoder turns into `input` plus `expanded` bytes
+    fn read_expanded(input: usize, expanded: u64, limits: DecompressionLimits) -> io::Result<u64> {
+        let mut reader = bounded(io::repeat(1).take(input as u64), |compressed| {
+            compressed.chain(io::repeat(0).take(expanded))
+        });
+        reader.limits = limits;
+        io::copy(&mut reader, &mut io::sink())
+    }
+
+    #[test]
+    fn small_bodies_pass_whatever_their_ratio() {
+        let limits = DecompressionLimits {
+            max_ratio: 10,
+            max_bytes: u64::MAX,
+        };
+        assert_eq!(read_expanded(1, 64 * 1024, limits).unwrap(), 64 * 1024 + 1);
+    }
+
+    #[test]
+    fn absolute_cap() {
+        let limits = DecompressionLimits {
+            max_ratio: u32::MAX,
+            max_bytes: 100_000,
+        };
+        let error = read_expanded(1024, 200_000, limits).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(DECOMPRESSION_LIMIT_ERROR_PREFIX));
+    }
+
+    #[test]
+    fn ratio_past_the_grace() {
+        let limits = DecompressionLimits {
+            max_ratio: 100,
+            max_bytes: u64::MAX,
+        };
+        let error = read_expanded(1024, 4 * RATIO_GRACE_BYTES, limits).unwrap_err();
+        assert!(error.to_string().contains("exceeds a ratio of 100"));
+        // The same output from input that compresses less stays within the ratio
+        assert!(read_expanded(64 * 1024, 4 * RATIO_GRACE_BYTES, limits).is_ok());
+    }
+
+    #[test]
+    fn limits_must_be_positive() {
+        let limits = DecompressionLimits {
+            max_ratio: 0,
+            max_bytes: 1,
+        };
+        assert!(set_decompression_limits(limits).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use crate::api::functions::navigation_events::{report_connected, report_dialing};
+use crate::api::functions::request_trace::{trace, TraceStage};
+use crate::api::models::navigate_error::NavigateError;
+
+pub mod gateway;
+pub mod handshakes;
+pub mod limits;
+pub mod preconnect;
+pub mod proxy;
+pub mod rate_limit;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod socks5;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tcp;
+pub mod throttle;
+pub(crate) mod timeouts;
+pub(crate) mod tls;
+
+/// Connect timeout of requests that set none
+pub const CONNECT_TIMEOUT: Duration = Duration::new(10, 0);
+
+/// A bidirectional byte stream to a remote host, before any TLS is layered on top
+pub trait Connection: AsyncRead + AsyncWrite + Send + Unpin + Debug {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin + Debug> Connection for T {}
+
+/// The future of a dial in progress
+pub type DialFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Box<dyn Connection>, NavigateError>> + Send + 'a>>;
+
+/// Something that can open a byte stream to host:port
+pub trait Dialer: Send + Sync {
+    fn dial<'a>(&'a self, host: &'a str, port: u16, timeout: Duration) -> DialFuture<'a>;
+}
+
+/// The dialer every protocol module connects through
+static DIALER: LazyLock<RwLock<Arc<dyn Dialer>>> = LazyLock::new(|| RwLock::new(default_dialer()));
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_dialer() -> Arc<dyn Dialer> {
+    proxy::socket_dialer()
+}
+
+/// Browsers cannot open raw sockets, so web builds must be given a gateway first
+#[cfg(target_arch = "wasm32")]
+fn default_dialer() -> Arc<dyn Dialer> {
+    Arc::new(gateway::MissingGateway)
+}
+
+/// Open a stream to host:port through the active dialer. Reads on it fail once they wait
+/// longer than the request's read timeout, and the stream closes when the navigation is
+/// cancelled.
+pub async fn dial(host: &str, port: u16) -> Result<Box<dyn Connection>, NavigateError> {
+    // A cancelled navigation must not open new connections, e.g. when probing other protocols
+    crate::api::functions::cancellation::check_cancelled()?;
+    let dialer = DIALER.read().unwrap().clone();
+    let permit = rate_limit::acquire(host).await?;
+    trace(TraceStage::Transport, || {
+        format!("Connecting to {}", authority(host, port))
+    });
+    report_dialing();
+    let dialing = Instant::now();
+    let connection = dialer
+        .dial(host, port, timeouts::connect_timeout())
+        .await
+        .map(|connection| {
+            let limited = Box::new(rate_limit::LimitedConnection::new(connection, permit));
+            let timed = timeouts::timed(throttle::throttled(limited));
+            crate::api::functions::cancellation::watched(timed)
+        });
+    // Dialers that report no connect time of their own, e.g. through a proxy, took all of it
+    if connection.is_ok() {
+        report_connected(dialing.elapsed());
+    }
+    trace(TraceStage::Transport, || match &connection {
+        Ok(_) => "Connected".to_string(),
+        Err(e) => format!("Connection failed: {}", e),
+    });
+    connection
+}
+
+/// The IP address a host is a literal of, if it is one: an IPv6 address with or without the
+/// brackets URLs put around it, or an IPv4 address
+pub(crate) fn ip_literal(host: &str) -> Option<IpAddr> {
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+        .parse()
+        .ok()
+}
+
+/// host:port with an IPv6 literal in brackets, as in a URL
+pub(crate) fn authority(host: &str, port: u16) -> String {
+    match ip_literal(host) {
+        Some(IpAddr::V6(ip)) => format!("[{}]:{}", ip, port),
+        _ => format!("{}:{}", host, port),
+    }
+}
+
+/// Replace the active dialer
+pub fn set_dialer(dialer: Arc<dyn Dialer>) {
+    *DIALER.write().unwrap() = dialer;
+}
+
+/// Route all connections through a WebSocket gateway, or back to sockets (and any proxy) with None
+pub fn set_gateway(url_template: Option<String>) -> Result<(), String> {
+    match url_template {
+        Some(template) => set_dialer(Arc::new(gateway::GatewayDialer::new(template)?)),
+        None => set_dialer(default_dialer()),
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use crate::api::functions::navigation_events::is_private;
+use crate::api::storage::ports::resolve_port;
+use crate::api::transport::tls::{self, TlsConnection};
+use crate::api::transport::{dial, handshakes};
+
+/// How long a warmed-up connection waits for its navigation before it is closed; servers drop
+/// connections that send no request for long
+const WARM_TTL: Duration = Duration::from_secs(10);
+
+/// Most warmed-up connections kept at once; the oldest is closed to make room
+const MAX_WARM: usize = 8;
+
+/// A connection whose TLS handshake is complete, waiting for a request
+#[derive(Debug)]
+struct WarmConnection {
+    host: String,
+    port: u16,
+    connection: TlsConnection,
+    opened: Instant,
+}
+
+static WARM: LazyLock<Mutex<Vec<WarmConnection>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Resolve, connect to, and complete the TLS handshake with the server of a Gemini URL ahead of
+/// a navigation, e.g. when a link is hovered or long-pressed, so following it within ten
+/// seconds skips all three. The handshake runs as a background request, behind the user's
+/// navigations; the certificate is checked against the host's pin only when a navigation takes
+/// the connection. URLs of other schemes are ignored.
+pub async fn preconnect(url: &str) -> Result<(), String> {
+    let url = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if url.scheme() != "gemini" {
+        return Ok(());
+    }
+    let host = url.host_str().ok_or("Invalid host in URL")?.to_string();
+    let port = resolve_port(&url, 1965);
+    if is_warm(&host, port) {
+        return Ok(());
+    }
+    let warm = handshakes::scope(true, async move {
+        let connection = tls::handshake(&host, dial(&host, port).await?, None).await?;
+        Ok::<_, String>(WarmConnection {
+            host,
+            port,
+            connection,
+            opened: Instant::now(),
+        })
+    })
+    .await?;
+
+    {
+        let mut connections = WARM.lock().unwrap();
+        connections.retain(|warm| warm.opened.elapsed() < WARM_TTL);
+        if connections.len() >= MAX_WARM {
+            connections.remove(0);
+        }
+        connections.push(warm);
+    }
+    // Close the connection once it can no longer be taken, rather than at the next call
+    tokio::spawn(async {
+        tokio::time::sleep(WARM_TTL).await;
+        WARM.lock()
+            .unwrap()
+            .retain(|warm| warm.opened.elapsed() < WARM_TTL);
+    });
+    Ok(())
+}
+
+/// Whether a connection to host:port is already waiting
+fn is_warm(host: &str, port: u16) -> bool {
+    WARM.lock().unwrap().iter().any(|warm| {
+        warm.port == port
+            && warm.host.eq_ignore_ascii_case(host)
+            && warm.opened.elapsed() < WARM_TTL
+    })
+}
+
+/// Take the warmed-up connection to host:port, if one is waiting. Private navigations never
+/// take one, as it may have resumed a session of an earlier connection.
+pub(crate) fn take(host: &str, port: u16) -> Option<TlsConnection> {
+    if is_private() {
+        return None;
+    }
+    let mut connections = WARM.lock().unwrap();
+    connections.retain(|warm| warm.opened.elapsed() < WARM_TTL);
+    let index = connections
+        .iter()
+        .position(|warm| warm.port == port && warm.host.eq_ignore_ascii_case(host))?;
+    let mut connection = connections.remove(index).connection;
+    connection.set_warmed();
+    Some(connection)
+}
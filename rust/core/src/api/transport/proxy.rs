@@ -0,0 +1,219 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+use std::sync::{LazyLock, RwLock};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+use crate::api::functions::config;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::api::transport::{
+    socks5, tcp::TcpDialer, Connection, DialFuture, Dialer, CONNECT_TIMEOUT,
+};
+
+/// Where test_proxy asks the proxy to connect once the handshake succeeds
+#[cfg(not(target_arch = "wasm32"))]
+const PROBE_HOST: &str = "geminiprotocol.net";
+#[cfg(not(target_arch = "wasm32"))]
+const PROBE_PORT: u16 = 1965;
+
+/// A SOCKS5 proxy server, e.g. Tor at 127.0.0.1:9050
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Socks5Proxy {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+/// Route hosts matching a pattern ("host.example" or "*.onion") through a proxy, or directly when
+/// proxy is None
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyRule {
+    pub pattern: String,
+    pub proxy: Option<Socks5Proxy>,
+}
+
+/// How connections reach remote hosts
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProxySettings {
+    /// Connect directly
+    #[default]
+    Direct,
+    /// Send every connection through one proxy
+    Socks5 { proxy: Socks5Proxy },
+    /// Use the first matching rule, then the fallback (direct when None)
+    PerRule {
+        rules: Vec<ProxyRule>,
+        fallback: Option<Socks5Proxy>,
+    },
+}
+
+/// Timings of a successful test_proxy probe
+#[derive(Debug, Clone)]
+pub struct ProxyProbe {
+    /// Time to connect to the proxy and authenticate
+    pub handshake_ms: u32,
+    /// Time to open a connection to the probe host through the proxy
+    pub connect_ms: u32,
+}
+
+/// The settings the active dialer was built from
+static SETTINGS: LazyLock<RwLock<ProxySettings>> = LazyLock::new(Default::default);
+
+impl Socks5Proxy {
+    fn validate(&self) -> Result<(), String> {
+        if self.host.trim().is_empty() {
+            return Err("Proxy host cannot be empty".to_string());
+        }
+        if self.port == 0 {
+            return Err("Proxy port must be between 1 and 65535".to_string());
+        }
+        match (&self.username, &self.password) {
+            (None, Some(_)) => Err("A proxy password needs a username".to_string()),
+            (Some(username), _) if username.is_empty() || username.len() > 255 => {
+                Err("Proxy username must be 1 to 255 bytes".to_string())
+            }
+            (_, Some(password)) if password.len() > 255 => {
+                Err("Proxy password must be at most 255 bytes".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ProxyRule {
+    fn matches(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        match self.pattern.strip_prefix("*.") {
+            Some(domain) => host == domain || host.ends_with(&format!(".{}", domain)),
+            None => host == self.pattern,
+        }
+    }
+}
+
+impl ProxySettings {
+    /// Check every proxy and rule, normalizing rule patterns to lowercase
+    pub fn validate(mut self) -> Result<Self, String> {
+        match &mut self {
+            ProxySettings::Direct => {}
+            ProxySettings::Socks5 { proxy } => proxy.validate()?,
+            ProxySettings::PerRule { rules, fallback } => {
+                for rule in rules.iter_mut() {
+                    rule.pattern = rule.pattern.trim().to_ascii_lowercase();
+                    if rule.pattern.is_empty() || rule.pattern == "*." {
+                        return Err("Proxy rule pattern cannot be empty".to_string());
+                    }
+                    if rule.pattern.contains('/')
+                        || rule
+                            .pattern
+                            .strip_prefix("*.")
+                            .unwrap_or(&rule.pattern)
+                            .contains('*')
+                    {
+                        return Err(format!(
+                            "Proxy rule pattern {} must be a host or *.domain",
+                            rule.pattern
+                        ));
+                    }
+                    if let Some(proxy) = &rule.proxy {
+                        proxy.validate()?;
+                    }
+                }
+                if let Some(proxy) = fallback {
+                    proxy.validate()?;
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// The proxy a connection to host should use, or None to connect directly
+    pub fn route(&self, host: &str) -> Option<&Socks5Proxy> {
+        match self {
+            ProxySettings::Direct => None,
+            ProxySettings::Socks5 { proxy } => Some(proxy),
+            ProxySettings::PerRule { rules, fallback } => {
+                match rules.iter().find(|rule| rule.matches(host)) {
+                    Some(rule) => rule.proxy.as_ref(),
+                    None => fallback.as_ref(),
+                }
+            }
+        }
+    }
+}
+
+/// Dials each host directly or through the proxy its settings route it to
+#[cfg(not(target_arch = "wasm32"))]
+pub struct ProxyDialer {
+    settings: ProxySettings,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Dialer for ProxyDialer {
+    fn dial<'a>(&'a self, host: &'a str, port: u16, timeout: Duration) -> DialFuture<'a> {
+        match self.settings.route(host) {
+            Some(proxy) => Box::pin(async move {
+                let stream: Box<dyn Connection> =
+                    Box::new(socks5::connect(proxy, host, port, timeout).await?);
+                Ok(stream)
+            }),
+            None => TcpDialer.dial(host, port, timeout),
+        }
+    }
+}
+
+/// The active proxy settings
+pub fn settings() -> ProxySettings {
+    SETTINGS.read().unwrap().clone()
+}
+
+/// Validate and activate proxy settings, replacing any gateway
+pub fn apply(settings: ProxySettings) -> Result<(), String> {
+    let settings = settings.validate()?;
+    config::update(|config| config.proxy = settings.clone());
+    *SETTINGS.write().unwrap() = settings;
+    // Web builds reach every host through their gateway, which keeps its place
+    #[cfg(not(target_arch = "wasm32"))]
+    crate::api::transport::set_dialer(socket_dialer());
+    Ok(())
+}
+
+/// The dialer for raw sockets under the active proxy settings
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn socket_dialer() -> Arc<dyn Dialer> {
+    let settings = settings();
+    if settings == ProxySettings::Direct {
+        Arc::new(TcpDialer)
+    } else {
+        Arc::new(ProxyDialer { settings })
+    }
+}
+
+/// Check that a proxy is reachable, accepts the credentials, and can open a connection
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn test_proxy(proxy: &Socks5Proxy) -> Result<ProxyProbe, String> {
+    proxy.validate()?;
+    let started = Instant::now();
+    drop(socks5::handshake(proxy, CONNECT_TIMEOUT).await?);
+    let handshake_ms = started.elapsed().as_millis() as u32;
+
+    let started = Instant::now();
+    drop(socks5::connect(
+        proxy,
+        PROBE_HOST,
+        PROBE_PORT,
+        CONNECT_TIMEOUT,
+    )
+    .await?);
+    Ok(ProxyProbe {
+        handshake_ms,
+        connect_ms: started.elapsed().as_millis() as u32,
+    })
+}
+
+/// Browsers cannot open the socket a SOCKS5 proxy is reached over
+#[cfg(target_arch = "wasm32")]
+pub async fn test_proxy(proxy: &Socks5Proxy) -> Result<ProxyProbe, String> {
+    proxy.validate()?;
+    Err("Web builds cannot reach a SOCKS5 proxy".to_string())
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::io;
+use std::pin::Pin;
+use std::sync::{LazyLock, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::Notify;
+
+use crate::api::functions::cancellation;
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::storage::settings;
+use crate::api::transport::handshakes::is_background;
+use crate::api::transport::Connection;
+
+/// Settings key of the most connections open to one host at once
+const MAX_CONNECTIONS_KEY: &str = "host_max_connections";
+
+/// Settings key of the least time between connections to one host
+const MIN_INTERVAL_KEY: &str = "host_min_interval_ms";
+
+const DEFAULT_MAX_CONNECTIONS: u32 = 2;
+
+const DEFAULT_MIN_INTERVAL_MS: u64 = 500;
+
+/// Longest minimum interval, so a typo cannot stall every background request for hours
+const MAX_MIN_INTERVAL_MS: u64 = 60_000;
+
+/// Hosts tracked before idle ones are forgotten
+const MAX_TRACKED_HOSTS: usize = 256;
+
+/// How hard background requests may hit one host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+    /// Connections open to one host at once, counting those of requests the user is waiting for
+    pub max_connections: u32,
+    /// Milliseconds between the start of one connection to a host and the next
+    pub min_interval_ms: u64,
+}
+
+/// Connections of one host
+#[derive(Debug, Default)]
+struct HostSlots {
+    active: u32,
+    last_start: Option<Instant>,
+}
+
+static HOSTS: LazyLock<Mutex<HashMap<String, HostSlots>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static SLOT_FREED: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+/// The limits background requests keep to; a failed lookup keeps the defaults
+pub fn rate_limits() -> RateLimits {
+    let stored = |key: &str| settings::get(key).ok().flatten();
+    RateLimits {
+        max_connections: stored(MAX_CONNECTIONS_KEY)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_MAX_CONNECTIONS),
+        min_interval_ms: stored(MIN_INTERVAL_KEY)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_MIN_INTERVAL_MS),
+    }
+}
+
+/// Change the limits background requests keep to
+pub fn set_rate_limits(limits: RateLimits) -> Result<(), String> {
+    if limits.max_connections == 0 {
+        return Err("Connections per host must be at least 1".to_string());
+    }
+    if limits.min_interval_ms > MAX_MIN_INTERVAL_MS {
+        return Err(format!(
+            "Minimum interval must be at most {} milliseconds",
+            MAX_MIN_INTERVAL_MS
+        ));
+    }
+    settings::set(MAX_CONNECTIONS_KEY, &limits.max_connections.to_string())?;
+    settings::set(MIN_INTERVAL_KEY, &limits.min_interval_ms.to_string())?;
+    // Relaxed limits let waiting connections start now
+    SLOT_FREED.notify_waiters();
+    Ok(())
+}
+
+/// Held while a connection to a host is open; frees its slot when dropped
+#[derive(Debug)]
+pub(crate) struct HostPermit {
+    host: String,
+}
+
+/// Take a connection slot of a host. Background requests wait until the host has a free slot
+/// and its minimum interval has passed; requests the user is waiting for never wait, but count
+/// against the host so prefetches hold back while they run.
+pub(crate) async fn acquire(host: &str) -> Result<HostPermit, NavigateError> {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    let limits = is_background().then(rate_limits);
+    loop {
+        // Registered before the slots are looked at, so a slot freed meanwhile still wakes it
+        let freed = SLOT_FREED.notified();
+        tokio::pin!(freed);
+        freed.as_mut().enable();
+        let wait = {
+            let mut hosts = HOSTS.lock().unwrap();
+            match limits.and_then(|limits| hold_back(&mut hosts, &host, limits)) {
+                Some(wait) => wait,
+                None => return Ok(take_slot(&mut hosts, host)),
+            }
+        };
+        tokio::select! {
+            _ = freed => {}
+            _ = tokio::time::sleep(wait), if !wait.is_zero() => {}
+            _ = cancellation::cancelled() => return Err(cancellation::cancelled_error()),
+        }
+    }
+}
+
+/// How long a background connection to a host must wait for its minimum interval, or zero to
+/// wait for a slot; None when it may start now
+fn hold_back(
+    hosts: &mut HashMap<String, HostSlots>,
+    host: &str,
+    limits: RateLimits,
+) -> Option<Duration> {
+    let slots = hosts.entry(host.to_string()).or_default();
+    let wait = slots
+        .last_start
+        .map(|start| Duration::from_millis(limits.min_interval_ms).saturating_sub(start.elapsed()))
+        .unwrap_or_default();
+    (slots.active >= limits.max_connections || !wait.is_zero()).then_some(wait)
+}
+
+fn take_slot(hosts: &mut HashMap<String, HostSlots>, host: String) -> HostPermit {
+    if hosts.len() >= MAX_TRACKED_HOSTS {
+        let idle = Duration::from_millis(MAX_MIN_INTERVAL_MS);
+        hosts.retain(|_, slots| {
+            slots.active > 0 || slots.last_start.is_some_and(|start| start.elapsed() < idle)
+        });
+    }
+    let slots = hosts.entry(host.clone()).or_default();
+    slots.active += 1;
+    slots.last_start = Some(Instant::now());
+    HostPermit { host }
+}
+
+impl Drop for HostPermit {
+    fn drop(&mut self) {
+        if let Some(slots) = HOSTS.lock().unwrap().get_mut(&self.host) {
+            slots.active -= 1;
+        }
+        SLOT_FREED.notify_waiters();
+    }
+}
+
+/// A connection that frees its host's slot when closed
+#[derive(Debug)]
+pub(crate) struct LimitedConnection {
+    inner: Box<dyn Connection>,
+    _permit: HostPermit,
+}
+
+impl LimitedConnection {
+    pub(crate) fn new(inner: Box<dyn Connection>, permit: HostPermit) -> Self {
+        LimitedConnection {
+            inner,
+            _permit: permit,
+        }
+    }
+}
+
+impl AsyncRead for LimitedConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for LimitedConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,211 @@
+use std::future::Future;
+use std::io;
+use std::net::IpAddr;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::transport::proxy::Socks5Proxy;
+use crate::api::transport::{authority, ip_literal, tcp};
+
+const VERSION: u8 = 5;
+const NO_AUTH: u8 = 0;
+const USERNAME_PASSWORD: u8 = 2;
+const NO_ACCEPTABLE_METHOD: u8 = 0xff;
+const CONNECT: u8 = 1;
+const ADDRESS_IPV4: u8 = 1;
+const ADDRESS_DOMAIN: u8 = 3;
+const ADDRESS_IPV6: u8 = 4;
+
+/// Connect to the proxy and negotiate authentication, leaving the stream ready for a request
+pub(crate) async fn handshake(
+    proxy: &Socks5Proxy,
+    timeout: Duration,
+) -> Result<TcpStream, NavigateError> {
+    let mut stream = tcp::connect(&proxy.host, proxy.port, timeout)
+        .await
+        .map_err(|e| {
+            NavigateError::ProxyUnreachable(format!(
+                "Could not reach SOCKS5 proxy {}:{}: {}",
+                proxy.host, proxy.port, e
+            ))
+        })?;
+    answered(timeout, negotiate(&mut stream, proxy)).await?;
+    Ok(stream)
+}
+
+/// Run an exchange with the proxy; a proxy that accepts the connection but never answers must
+/// not hang the caller
+async fn answered(
+    timeout: Duration,
+    exchange: impl Future<Output = Result<(), NavigateError>>,
+) -> Result<(), NavigateError> {
+    tokio::time::timeout(timeout, exchange)
+        .await
+        .unwrap_or_else(|_| Err(proxy_io_error(io::ErrorKind::TimedOut.into())))
+}
+
+/// Offer the authentication methods the proxy's settings allow and run the one it picks
+async fn negotiate(stream: &mut TcpStream, proxy: &Socks5Proxy) -> Result<(), NavigateError> {
+    let greeting: &[u8] = if proxy.username.is_some() {
+        &[VERSION, 2, NO_AUTH, USERNAME_PASSWORD]
+    } else {
+        &[VERSION, 1, NO_AUTH]
+    };
+    stream.write_all(greeting).await.map_err(proxy_io_error)?;
+
+    let mut choice = [0u8; 2];
+    stream
+        .read_exact(&mut choice)
+        .await
+        .map_err(proxy_io_error)?;
+    if choice[0] != VERSION {
+        return Err(NavigateError::ProxyError(
+            "Proxy is not a SOCKS5 server".to_string(),
+        ));
+    }
+    match choice[1] {
+        NO_AUTH => {}
+        USERNAME_PASSWORD => authenticate(stream, proxy).await?,
+        NO_ACCEPTABLE_METHOD if proxy.username.is_none() => {
+            return Err(NavigateError::ProxyAuthFailed(
+                "SOCKS5 proxy requires a username and password".to_string(),
+            ))
+        }
+        _ => {
+            return Err(NavigateError::ProxyAuthFailed(
+                "SOCKS5 proxy accepted none of the offered authentication methods".to_string(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Open a tunnelled connection to host:port through the proxy
+pub(crate) async fn connect(
+    proxy: &Socks5Proxy,
+    host: &str,
+    port: u16,
+    timeout: Duration,
+) -> Result<TcpStream, NavigateError> {
+    let mut stream = handshake(proxy, timeout).await?;
+    answered(timeout, request_connect(&mut stream, host, port)).await?;
+    Ok(stream)
+}
+
+/// Ask the proxy to connect to host:port, and wait for its reply
+async fn request_connect(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+) -> Result<(), NavigateError> {
+    // Hostnames are sent unresolved so the proxy does the lookup (required for .onion addresses)
+    let mut request = vec![VERSION, CONNECT, 0];
+    match ip_literal(host) {
+        Some(IpAddr::V4(ip)) => {
+            request.push(ADDRESS_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Some(IpAddr::V6(ip)) => {
+            request.push(ADDRESS_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        None => {
+            let name = host.as_bytes();
+            if name.len() > 255 {
+                return Err(NavigateError::ProxyError(
+                    "Host name is too long for SOCKS5".to_string(),
+                ));
+            }
+            request.push(ADDRESS_DOMAIN);
+            request.push(name.len() as u8);
+            request.extend_from_slice(name);
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await.map_err(proxy_io_error)?;
+
+    let mut reply = [0u8; 4];
+    stream
+        .read_exact(&mut reply)
+        .await
+        .map_err(proxy_io_error)?;
+    if reply[1] != 0 {
+        let message = format!(
+            "SOCKS5 proxy could not connect to {}: {}",
+            authority(host, port),
+            reply_message(reply[1])
+        );
+        // The proxy's answer tells whether the host or the proxy failed
+        return Err(match reply[1] {
+            3 | 4 | 6 => NavigateError::ConnectionFailed(message),
+            5 => NavigateError::ConnectionRefused(message),
+            _ => NavigateError::ProxyError(message),
+        });
+    }
+    // Skip the bound address, which clients do not need
+    let address_len = match reply[3] {
+        ADDRESS_IPV4 => 4,
+        ADDRESS_IPV6 => 16,
+        ADDRESS_DOMAIN => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len).await.map_err(proxy_io_error)?;
+            len[0] as usize
+        }
+        _ => {
+            return Err(NavigateError::ProxyError(
+                "SOCKS5 proxy sent a malformed reply".to_string(),
+            ))
+        }
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    stream
+        .read_exact(&mut bound)
+        .await
+        .map_err(proxy_io_error)?;
+    Ok(())
+}
+
+/// Username/password sub-negotiation (RFC 1929)
+async fn authenticate(stream: &mut TcpStream, proxy: &Socks5Proxy) -> Result<(), NavigateError> {
+    let username = proxy.username.as_deref().unwrap_or_default().as_bytes();
+    let password = proxy.password.as_deref().unwrap_or_default().as_bytes();
+    let mut request = vec![1, username.len() as u8];
+    request.extend_from_slice(username);
+    request.push(password.len() as u8);
+    request.extend_from_slice(password);
+    stream.write_all(&request).await.map_err(proxy_io_error)?;
+
+    let mut status = [0u8; 2];
+    stream
+        .read_exact(&mut status)
+        .await
+        .map_err(proxy_io_error)?;
+    if status[1] != 0 {
+        return Err(NavigateError::ProxyAuthFailed(
+            "SOCKS5 proxy rejected the username or password".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn proxy_io_error(e: io::Error) -> NavigateError {
+    NavigateError::ProxyError(format!("SOCKS5 proxy connection failed: {}", e))
+}
+
+/// Human-readable meaning of a SOCKS5 reply code
+fn reply_message(code: u8) -> &'static str {
+    match code {
+        1 => "general failure",
+        2 => "connection not allowed by ruleset",
+        3 => "network unreachable",
+        4 => "host unreachable",
+        5 => "connection refused",
+        6 => "TTL expired",
+        7 => "command not supported",
+        8 => "address type not supported",
+        _ => "unknown error",
+    }
+}
@@ -0,0 +1,98 @@
+use std::io;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use tokio::net::TcpStream;
+
+use crate::api::functions::navigation_events::{report_connected, report_phase, report_resolved};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_event::NavigationEventKind;
+use crate::api::transport::{ip_literal, Connection, DialFuture, Dialer};
+
+/// Direct TCP connections using the operating system's resolver
+pub struct TcpDialer;
+
+impl Dialer for TcpDialer {
+    fn dial<'a>(&'a self, host: &'a str, port: u16, timeout: Duration) -> DialFuture<'a> {
+        Box::pin(async move {
+            // Resolving an onion address would leak it to the system resolver, and can never
+            // succeed
+            if is_onion(host) {
+                return Err(NavigateError::ConnectionFailed(format!(
+                    "Onion address {} can only be reached through a SOCKS5 proxy such as Tor",
+                    host
+                )));
+            }
+            let stream: Box<dyn Connection> = Box::new(connect(host, port, timeout).await?);
+            Ok(stream)
+        })
+    }
+}
+
+/// Whether host is a Tor onion service
+fn is_onion(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    host == "onion" || host.ends_with(".onion")
+}
+
+/// Resolve host:port and open a plain TCP stream to the first address; an IP literal, IPv6
+/// ones with or without brackets, is connected to without resolving
+pub(crate) async fn connect(
+    host: &str,
+    port: u16,
+    timeout: Duration,
+) -> Result<TcpStream, NavigateError> {
+    let addr = match ip_literal(host) {
+        Some(ip) => SocketAddr::new(ip, port),
+        None => resolve(host, port).await?,
+    };
+    report_phase(NavigationEventKind::Connecting, Some(addr.to_string()));
+
+    let connecting = Instant::now();
+    let stream = match tokio::time::timeout(timeout, TcpStream::connect(addr)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) if e.kind() == io::ErrorKind::ConnectionRefused => {
+            return Err(NavigateError::ConnectionRefused(format!(
+                "TCP connection failed: {}",
+                e
+            )))
+        }
+        Ok(Err(e)) => {
+            return Err(NavigateError::ConnectionFailed(format!(
+                "TCP connection failed: {}",
+                e
+            )))
+        }
+        Err(_) => {
+            return Err(NavigateError::ConnectTimeout(
+                "TCP connection failed: connection timed out".to_string(),
+            ))
+        }
+    };
+    report_connected(connecting.elapsed());
+    Ok(stream)
+}
+
+/// The first address a host name resolves to
+async fn resolve(host: &str, port: u16) -> Result<SocketAddr, NavigateError> {
+    report_phase(NavigationEventKind::Resolving, None);
+    let resolving = Instant::now();
+    let addr = match tokio::net::lookup_host((host, port)).await {
+        Ok(mut addrs_iter) => match addrs_iter.next() {
+            Some(addr) => addr,
+            None => {
+                return Err(NavigateError::DnsFailure(
+                    "No socket addresses found".to_string(),
+                ))
+            }
+        },
+        Err(e) => {
+            return Err(NavigateError::DnsFailure(format!(
+                "Failed to resolve socket address: {}",
+                e
+            )))
+        }
+    };
+    report_resolved(resolving.elapsed());
+    Ok(addr)
+}
@@ -0,0 +1,141 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{LazyLock, Mutex};
+use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::Sleep;
+
+use crate::api::storage::settings;
+use crate::api::transport::Connection;
+
+/// Settings key of the bandwidth limit
+const BANDWIDTH_LIMIT_KEY: &str = "bandwidth_limit_bytes_per_sec";
+
+/// Lowest limit accepted, so a typo cannot make every page take minutes
+pub const MIN_BANDWIDTH_LIMIT: u64 = 1024;
+
+/// Bytes all connections together may still receive before readers wait; negative when reads
+/// have run ahead of the limit
+#[derive(Debug)]
+struct Bucket {
+    allowance: f64,
+    refilled: Instant,
+}
+
+/// Starts full: the first charge caps it at a second's worth
+static BUCKET: LazyLock<Mutex<Bucket>> = LazyLock::new(|| {
+    Mutex::new(Bucket {
+        allowance: f64::MAX,
+        refilled: Instant::now(),
+    })
+});
+
+/// The limit on bytes received per second over all connections, None when unlimited
+pub fn bandwidth_limit() -> Option<u64> {
+    settings::get(BANDWIDTH_LIMIT_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+}
+
+/// Limit (or with None stop limiting) how fast all connections together receive, e.g. on a
+/// metered connection. Connections opened before the change keep the previous limit.
+pub fn set_bandwidth_limit(bytes_per_sec: Option<u64>) -> Result<(), String> {
+    match bytes_per_sec {
+        Some(limit) if limit < MIN_BANDWIDTH_LIMIT => Err(format!(
+            "Bandwidth limit must be at least {} bytes per second",
+            MIN_BANDWIDTH_LIMIT
+        )),
+        Some(limit) => settings::set(BANDWIDTH_LIMIT_KEY, &limit.to_string()),
+        None => settings::remove(BANDWIDTH_LIMIT_KEY),
+    }
+}
+
+/// Wrap a connection in the bandwidth limit, if one is set
+pub(crate) fn throttled(connection: Box<dyn Connection>) -> Box<dyn Connection> {
+    match bandwidth_limit() {
+        Some(bytes_per_sec) => Box::new(ThrottledConnection {
+            inner: connection,
+            bytes_per_sec,
+            pause: None,
+            chunk: Vec::new(),
+        }),
+        None => connection,
+    }
+}
+
+/// Take `bytes` from the shared allowance, returning how long the reader must wait to stay
+/// within the limit. Up to a second's worth of bytes may arrive at once after an idle spell.
+fn charge(bytes: usize, bytes_per_sec: u64) -> Duration {
+    let rate = bytes_per_sec as f64;
+    let mut bucket = BUCKET.lock().unwrap();
+    let now = Instant::now();
+    let refill = now.duration_since(bucket.refilled).as_secs_f64() * rate;
+    bucket.allowance = (bucket.allowance + refill).min(rate) - bytes as f64;
+    bucket.refilled = now;
+    if bucket.allowance >= 0.0 {
+        Duration::ZERO
+    } else {
+        Duration::from_secs_f64(-bucket.allowance / rate)
+    }
+}
+
+/// A connection whose reads, shared with every other throttled connection, stay within the
+/// bandwidth limit
+#[derive(Debug)]
+struct ThrottledConnection {
+    inner: Box<dyn Connection>,
+    bytes_per_sec: u64,
+    /// The wait the last read ran up, which the next read sits out first
+    pause: Option<Pin<Box<Sleep>>>,
+    /// Bytes read ahead of being handed to the caller, at most a second's worth
+    chunk: Vec<u8>,
+}
+
+impl AsyncRead for ThrottledConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        if let Some(pause) = &mut this.pause {
+            ready!(pause.as_mut().poll(cx));
+            this.pause = None;
+        }
+        // A read of at most a second's worth keeps one large buffer from a long stall
+        let max = usize::try_from(this.bytes_per_sec).unwrap_or(usize::MAX);
+        let len = buf.remaining().min(max);
+        this.chunk.resize(len, 0);
+        let mut chunk = ReadBuf::new(&mut this.chunk);
+        ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk))?;
+        let n = chunk.filled().len();
+        buf.put_slice(&this.chunk[..n]);
+        let wait = charge(n, this.bytes_per_sec);
+        if !wait.is_zero() {
+            this.pause = Some(Box::pin(tokio::time::sleep(wait)));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for ThrottledConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
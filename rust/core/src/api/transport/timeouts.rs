@@ -0,0 +1,167 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::Sleep;
+
+use crate::api::transport::{Connection, CONNECT_TIMEOUT};
+
+/// Shortest timeout of a read, so one past its deadline still gets a chance to see data
+const MIN_TIMEOUT: Duration = Duration::from_millis(1);
+
+/// Quiet time after which a Gopher or Finger response is taken as complete, for servers that
+/// keep the connection open after answering
+pub(crate) const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest a Gopher or Finger response is read before what arrived is returned as truncated
+pub(crate) const READ_DEADLINE: Duration = Duration::from_secs(120);
+
+/// Time limits of the request running on a task
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Timeouts {
+    connect: Duration,
+    /// Longest wait for data on a socket, or None to wait as long as the server keeps it open
+    read: Option<Duration>,
+    /// When the whole request must be complete
+    deadline: Option<Instant>,
+}
+
+impl Timeouts {
+    /// Limits from request options in seconds; a missing connect timeout keeps the default
+    pub(crate) fn from_secs(
+        connect_secs: Option<u32>,
+        read_secs: Option<u32>,
+        deadline_secs: Option<u32>,
+    ) -> Self {
+        let secs = |secs: u32| Duration::from_secs(secs as u64);
+        Timeouts {
+            connect: connect_secs.map_or(CONNECT_TIMEOUT, secs),
+            read: read_secs.map(secs),
+            deadline: deadline_secs.map(|deadline| Instant::now() + secs(deadline)),
+        }
+    }
+}
+
+tokio::task_local! {
+    static TIMEOUTS: Timeouts;
+}
+
+/// Run a request under its time limits
+pub(crate) async fn scope<F: Future>(timeouts: Timeouts, future: F) -> F::Output {
+    TIMEOUTS.scope(timeouts, future).await
+}
+
+/// Run `future`, typically a dial, with the read timeout of the connections it opens capped at
+/// `idle`
+pub(crate) async fn with_idle_timeout<F: Future>(idle: Duration, future: F) -> F::Output {
+    let timeouts = TIMEOUTS
+        .try_with(|timeouts| *timeouts)
+        .unwrap_or_else(|_| Timeouts::from_secs(None, None, None));
+    let capped = Timeouts {
+        read: Some(timeouts.read.map_or(idle, |read| read.min(idle))),
+        ..timeouts
+    };
+    TIMEOUTS.scope(capped, future).await
+}
+
+/// How long the request on this task may wait for a connection, never past its deadline
+pub(crate) fn connect_timeout() -> Duration {
+    match TIMEOUTS.try_with(|timeouts| *timeouts) {
+        Ok(timeouts) => until_deadline(timeouts.connect, timeouts.deadline),
+        Err(_) => CONNECT_TIMEOUT,
+    }
+}
+
+/// How long the request on this task may wait for data on a socket, never past its deadline
+pub(crate) fn read_timeout() -> Option<Duration> {
+    let timeouts = TIMEOUTS.try_with(|timeouts| *timeouts).ok()?;
+    match (timeouts.read, timeouts.deadline) {
+        (Some(read), deadline) => Some(until_deadline(read, deadline)),
+        (None, Some(deadline)) => Some(remaining(deadline)),
+        (None, None) => None,
+    }
+}
+
+fn until_deadline(timeout: Duration, deadline: Option<Instant>) -> Duration {
+    deadline.map_or(timeout, |deadline| timeout.min(remaining(deadline)))
+}
+
+fn remaining(deadline: Instant) -> Duration {
+    deadline
+        .saturating_duration_since(Instant::now())
+        .max(MIN_TIMEOUT)
+}
+
+/// Fail reads on a connection that wait longer than the read timeout of the request on this
+/// task, if it has one
+pub(crate) fn timed(connection: Box<dyn Connection>) -> Box<dyn Connection> {
+    match read_timeout() {
+        Some(timeout) => Box::new(TimedConnection {
+            inner: connection,
+            timeout,
+            timer: Box::pin(tokio::time::sleep(timeout)),
+            waiting: false,
+        }),
+        None => connection,
+    }
+}
+
+/// A connection whose reads fail with `TimedOut` once no data arrived for its timeout
+#[derive(Debug)]
+struct TimedConnection {
+    inner: Box<dyn Connection>,
+    timeout: Duration,
+    timer: Pin<Box<Sleep>>,
+    /// Whether the timer runs for a read still waiting for data
+    waiting: bool,
+}
+
+impl AsyncRead for TimedConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        if let Poll::Ready(result) = Pin::new(&mut this.inner).poll_read(cx, buf) {
+            this.waiting = false;
+            return Poll::Ready(result);
+        }
+        if !this.waiting {
+            this.waiting = true;
+            let deadline = tokio::time::Instant::now() + this.timeout;
+            this.timer.as_mut().reset(deadline);
+        }
+        match this.timer.as_mut().poll(cx) {
+            Poll::Ready(()) => {
+                this.waiting = false;
+                Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "read timed out",
+                )))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl AsyncWrite for TimedConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
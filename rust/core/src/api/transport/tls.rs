@@ -0,0 +1,412 @@
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{
+    ClientSessionMemoryCache, ClientSessionStore, Resumption, WebPkiServerVerifier,
+};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{
+    ClientConfig, DigitallySignedStruct, HandshakeKind, ProtocolVersion, RootCertStore,
+    SignatureScheme,
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::TlsConnector;
+
+use crate::api::functions::navigation_events::{
+    is_private, report_handshake, report_phase, report_tls_parameters,
+};
+use crate::api::functions::request_trace::{trace, TraceStage};
+use crate::api::functions::tls_failures::{self, CERTIFICATE_REJECTED_ERROR_PREFIX};
+use crate::api::models::navigate_error::NavigateError;
+use crate::api::models::navigation_event::NavigationEventKind;
+use crate::api::storage::host_settings::{self, TlsPolicy};
+use crate::api::storage::identities::IdentityCredentials;
+use crate::api::storage::trusted_cas;
+use crate::api::transport::{ip_literal, Connection};
+
+/// Cryptography of every TLS connection, the same on all platforms
+static PROVIDER: LazyLock<Arc<CryptoProvider>> =
+    LazyLock::new(|| Arc::new(rustls::crypto::ring::default_provider()));
+
+/// Servers whose sessions are kept for resumption
+const SESSION_CACHE_SIZE: usize = 256;
+
+/// Sessions of earlier connections, so the next connection to the same server can resume one
+/// with an abbreviated handshake
+static SESSIONS: LazyLock<Arc<ClientSessionMemoryCache>> =
+    LazyLock::new(|| Arc::new(ClientSessionMemoryCache::new(SESSION_CACHE_SIZE)));
+
+/// The configuration of every connection that may resume a session. rustls only resumes a
+/// session with the very verifier and client credentials that established it, so they are
+/// shared rather than created per connection.
+static RESUMING_CONFIG: LazyLock<Result<Arc<ClientConfig>, NavigateError>> = LazyLock::new(|| {
+    let mut config = client_config(Arc::new(TofuVerifier), None)?;
+    config.resumption = Resumption::store(SESSIONS.clone());
+    Ok(Arc::new(config))
+});
+
+/// A TLS connection over a transport connection. A server closing the connection without a
+/// TLS close_notify, as many Gemini servers do, ends the response rather than failing it.
+#[derive(Debug)]
+pub(crate) struct TlsConnection {
+    stream: TlsStream<Box<dyn Connection>>,
+    /// Set when the connection was opened ahead of its request by `preconnect`
+    warmed: bool,
+}
+
+impl TlsConnection {
+    /// Check the server's certificate against the host's pin (trust on first use) and report
+    /// the negotiated parameters to the navigation
+    pub(crate) fn verify(&self, host: &str, port: u16) -> Result<(), NavigateError> {
+        let (_, client) = self.stream.get_ref();
+        let der = client
+            .peer_certificates()
+            .and_then(|certificates| certificates.first())
+            .ok_or_else(|| {
+                NavigateError::TlsError(
+                    "TLS connection failed: server sent no certificate".to_string(),
+                )
+            })?;
+        crate::api::functions::tofu::verify_certificate(host, port, der)?;
+        if let (Some(version), Some(suite)) =
+            (client.protocol_version(), client.negotiated_cipher_suite())
+        {
+            report_tls_parameters(version_name(version), cipher_suite_name(suite.suite()));
+        }
+        Ok(())
+    }
+
+    /// Whether the connection was opened ahead of its request, so the server may have closed it
+    /// while it waited
+    pub(crate) fn warmed(&self) -> bool {
+        self.warmed
+    }
+
+    pub(crate) fn set_warmed(&mut self) {
+        self.warmed = true;
+    }
+}
+
+impl AsyncRead for TlsConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match Pin::new(&mut self.stream).poll_read(cx, buf) {
+            Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof => Poll::Ready(Ok(())),
+            result => result,
+        }
+    }
+}
+
+impl AsyncWrite for TlsConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+/// Accepts any certificate chain, as self-signed certificates are the norm in Gemini, while
+/// still checking the handshake signatures, so the server proves it holds the certificate's
+/// key before the certificate is checked against the host's pin
+#[derive(Debug)]
+struct TofuVerifier;
+
+impl ServerCertVerifier for TofuVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &PROVIDER.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &PROVIDER.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        PROVIDER
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// Verifies a strict host's certificate against the user trust store, keeping why it was
+/// refused for the security warning
+#[derive(Debug)]
+struct StrictVerifier {
+    host: String,
+    inner: Arc<dyn ServerCertVerifier>,
+}
+
+impl ServerCertVerifier for StrictVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            Err(rustls::Error::InvalidCertificate(error)) => {
+                let failure = tls_failures::explain(&self.host, &error, end_entity, intermediates);
+                tls_failures::rejected(failure);
+                Err(rustls::Error::InvalidCertificate(error))
+            }
+            result => {
+                if result.is_ok() {
+                    tls_failures::accepted(&self.host);
+                }
+                result
+            }
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// Negotiate TLS 1.2 or 1.3 with host:port over an open connection, presenting a client
+/// certificate if one is given, and check the server's certificate against the host's pin
+/// (trust on first use) once the handshake is complete. The certificate of a host with the
+/// strict policy must instead chain to a CA in the user trust store, checked in the handshake.
+pub(crate) async fn connect(
+    host: &str,
+    port: u16,
+    connection: Box<dyn Connection>,
+    identity: Option<&IdentityCredentials>,
+) -> Result<TlsConnection, NavigateError> {
+    let connection = handshake(host, connection, identity).await?;
+    connection.verify(host, port)?;
+    Ok(connection)
+}
+
+/// `connect` without checking the certificate against the host's pin, which is left to
+/// `TlsConnection::verify`. A session of an earlier connection to the host is resumed when
+/// there is one, except by private navigations and connections presenting an identity, which
+/// resuming would tie to earlier connections, and to strict hosts, whose certificate a resumed
+/// session does not present to the trust store again.
+pub(crate) async fn handshake(
+    host: &str,
+    connection: Box<dyn Connection>,
+    identity: Option<&IdentityCredentials>,
+) -> Result<TlsConnection, NavigateError> {
+    let strict = host_settings::tls_policy(host) == TlsPolicy::Strict;
+    let config = if strict || identity.is_some() || is_private() {
+        let verifier: Arc<dyn ServerCertVerifier> = if strict {
+            Arc::new(StrictVerifier {
+                host: host.to_string(),
+                inner: trust_store_verifier()?,
+            })
+        } else {
+            Arc::new(TofuVerifier)
+        };
+        let mut config = client_config(verifier, identity)?;
+        config.resumption = Resumption::disabled();
+        Arc::new(config)
+    } else {
+        RESUMING_CONFIG.clone()?
+    };
+    let name = server_name(host)?;
+
+    // Wait for a handshake slot if this is a background request
+    let permit = crate::api::transport::handshakes::acquire().await;
+    trace(TraceStage::Tls, || format!("Handshake with {}", host));
+    report_phase(NavigationEventKind::TlsHandshake, None);
+    let handshake = Instant::now();
+    let stream = TlsConnector::from(config)
+        .connect(name, connection)
+        .await
+        .map_err(|e| handshake_error(host, strict, e))?;
+    report_handshake(handshake.elapsed());
+    drop(permit);
+    trace(TraceStage::Tls, || {
+        match stream.get_ref().1.handshake_kind() {
+            Some(HandshakeKind::Resumed) => "Handshake complete, session resumed".to_string(),
+            _ => "Handshake complete".to_string(),
+        }
+    });
+    Ok(TlsConnection {
+        stream,
+        warmed: false,
+    })
+}
+
+/// The message of a failed handshake; a certificate refused by strict validation is explained
+/// rather than named by its rustls error
+fn handshake_error(host: &str, strict: bool, error: io::Error) -> NavigateError {
+    let refused = error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<rustls::Error>())
+        .is_some_and(|inner| matches!(inner, rustls::Error::InvalidCertificate(_)));
+    match tls_failures::certificate_failure(host).filter(|_| strict && refused) {
+        Some(failure) => NavigateError::CertificateRejected(format!(
+            "TLS connection failed: {}: {}",
+            CERTIFICATE_REJECTED_ERROR_PREFIX, failure.message
+        )),
+        // rustls reports a failed negotiation as invalid data; other failures are the
+        // connection's, such as a timeout or a cancellation
+        None if error.kind() == io::ErrorKind::InvalidData => {
+            NavigateError::TlsError(format!("TLS connection failed: {}", error))
+        }
+        None => NavigateError::from_io("TLS connection failed", error),
+    }
+}
+
+/// Drop the sessions kept for resuming connections to a host, e.g. once its pin was replaced:
+/// a resumed session presents the certificate of the connection that made it
+pub(crate) fn forget_sessions(host: &str) {
+    let Ok(name) = server_name(host) else {
+        return;
+    };
+    SESSIONS.remove_tls12_session(&name);
+    while SESSIONS.take_tls13_ticket(&name).is_some() {}
+}
+
+/// The name TLS is negotiated for; rustls sends no SNI for an IP address, as TLS allows only
+/// host names there
+fn server_name(host: &str) -> Result<ServerName<'static>, NavigateError> {
+    match ip_literal(host) {
+        Some(ip) => Ok(ServerName::IpAddress(ip.into())),
+        None => ServerName::try_from(host.to_string())
+            .map_err(|_| NavigateError::InvalidUrl("Invalid host in URL".to_string())),
+    }
+}
+
+/// Verifies certificates as the web does, but with the CAs of the user trust store as its only
+/// roots
+fn trust_store_verifier() -> Result<Arc<dyn ServerCertVerifier>, NavigateError> {
+    let mut roots = RootCertStore::empty();
+    for der in trusted_cas::certificates().map_err(NavigateError::StorageError)? {
+        roots.add(CertificateDer::from(der)).map_err(|e| {
+            NavigateError::TlsError(format!(
+                "TLS connector creation failed: unusable trusted CA: {}",
+                e
+            ))
+        })?;
+    }
+    if roots.is_empty() {
+        return Err(NavigateError::CertificateRejected(
+            "TLS connection failed: strict TLS policy, but no CA is trusted".to_string(),
+        ));
+    }
+    let verifier = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), PROVIDER.clone())
+        .build()
+        .map_err(|e| NavigateError::TlsError(format!("TLS connector creation failed: {}", e)))?;
+    Ok(verifier)
+}
+
+fn client_config(
+    verifier: Arc<dyn ServerCertVerifier>,
+    identity: Option<&IdentityCredentials>,
+) -> Result<ClientConfig, NavigateError> {
+    let builder = ClientConfig::builder_with_provider(PROVIDER.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| NavigateError::TlsError(format!("TLS connector creation failed: {}", e)))?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier);
+    let Some(identity) = identity else {
+        return Ok(builder.with_no_client_auth());
+    };
+    let identity_error = |e: &dyn std::fmt::Display| {
+        NavigateError::IdentityError(format!("Failed to prepare identity key: {}", e))
+    };
+    let chain = CertificateDer::pem_slice_iter(identity.certificate_pem.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| identity_error(&e))?;
+    let key = PrivateKeyDer::from_pem_slice(identity.private_key_pem.as_bytes())
+        .map_err(|e| identity_error(&e))?;
+    builder
+        .with_client_auth_cert(chain, key)
+        .map_err(|e| identity_error(&e))
+}
+
+fn version_name(version: ProtocolVersion) -> String {
+    match version {
+        ProtocolVersion::TLSv1_3 => "TLS 1.3".to_string(),
+        ProtocolVersion::TLSv1_2 => "TLS 1.2".to_string(),
+        version => format!("{:?}", version),
+    }
+}
+
+/// The IANA name of a cipher suite; rustls marks the TLS 1.3 ones with a TLS13_ prefix
+fn cipher_suite_name(suite: rustls::CipherSuite) -> String {
+    let name = format!("{:?}", suite);
+    match name.strip_prefix("TLS13_") {
+        Some(rest) => format!("TLS_{}", rest),
+        None => name,
+    }
+}
@@ -0,0 +1,7 @@
+//! The Holloway browser engine: protocol clients, document parsers, the navigation pipeline and
+//! the profile stores, independent of any front-end. The Flutter bindings, the C ABI and the
+//! command-line client are built on it.
+
+pub mod api;
+#[cfg(any(test, feature = "test-servers"))]
+pub mod test_servers;
@@ -0,0 +1,284 @@
+//! Minimal Gemini, Gopher and Finger servers on localhost for integration tests
+//!
+//! Each server listens on an unused port of 127.0.0.1 and answers every connection on its own
+//! thread with the reply a handler picks for the request line, so tests can exercise redirects,
+//! large bodies, slow servers, bad certificates and truncated responses without live hosts.
+//! A server stops accepting connections when it is dropped.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use native_tls::{Identity, TlsAcceptor};
+use rcgen::{CertificateParams, KeyPair};
+use time::OffsetDateTime;
+
+/// Longest request line read before the connection is answered anyway
+const MAX_REQUEST_BYTES: u64 = 4096;
+
+/// How a server answers one request
+#[derive(Debug, Clone)]
+pub enum Reply {
+    /// Send the bytes and close the connection cleanly
+    Send(Vec<u8>),
+    /// Send the chunks with a pause before each, then close cleanly
+    Slow {
+        chunks: Vec<Vec<u8>>,
+        delay: Duration,
+    },
+    /// Send the bytes, then drop the connection without a TLS close_notify
+    Truncated(Vec<u8>),
+    /// Send the bytes and keep the connection open without sending more until the server stops
+    Hold(Vec<u8>),
+}
+
+impl Reply {
+    /// A Gemini response: the header line, then the body
+    pub fn gemini(status: u8, meta: &str, body: &str) -> Self {
+        Reply::Send(format!("{} {}\r\n{}", status, meta, body).into_bytes())
+    }
+
+    /// A plain-text response, e.g. a Gopher menu or a Finger answer
+    pub fn text(body: &str) -> Self {
+        Reply::Send(body.as_bytes().to_vec())
+    }
+}
+
+type Handler = dyn Fn(&str) -> Reply + Send + Sync;
+
+/// A self-signed certificate and its key, both PEM
+#[derive(Debug, Clone)]
+pub struct ServerCertificate {
+    pub certificate_pem: String,
+    pub key_pem: String,
+}
+
+impl ServerCertificate {
+    /// A fresh certificate for 127.0.0.1 and localhost, valid from a day ago for a year
+    pub fn new() -> Self {
+        let now = OffsetDateTime::now_utc();
+        Self::valid_between(
+            now - time::Duration::days(1),
+            now + time::Duration::days(365),
+        )
+    }
+
+    /// A certificate for 127.0.0.1 and localhost that expired a year ago
+    pub fn expired() -> Self {
+        let now = OffsetDateTime::now_utc();
+        Self::valid_between(
+            now - time::Duration::days(730),
+            now - time::Duration::days(365),
+        )
+    }
+
+    fn valid_between(not_before: OffsetDateTime, not_after: OffsetDateTime) -> Self {
+        let mut params =
+            CertificateParams::new(vec!["127.0.0.1".to_string(), "localhost".to_string()])
+                .expect("certificate names");
+        params.not_before = not_before;
+        params.not_after = not_after;
+        let key = KeyPair::generate().expect("certificate key");
+        let certificate = params.self_signed(&key).expect("self-signed certificate");
+        ServerCertificate {
+            certificate_pem: certificate.pem(),
+            key_pem: key.serialize_pem(),
+        }
+    }
+}
+
+impl Default for ServerCertificate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A server answering on 127.0.0.1 until it is dropped
+pub struct TestServer {
+    scheme: &'static str,
+    port: u16,
+    stopped: Arc<AtomicBool>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl TestServer {
+    /// A Gemini server with a fresh self-signed certificate
+    pub fn gemini(handler: impl Fn(&str) -> Reply + Send + Sync + 'static) -> Self {
+        Self::gemini_with(ServerCertificate::new(), 0, handler)
+    }
+
+    /// A Gemini server presenting `certificate` on `port`, or an unused port for 0, e.g. to
+    /// replace a server with one whose certificate differs from the pinned one
+    pub fn gemini_with(
+        certificate: ServerCertificate,
+        port: u16,
+        handler: impl Fn(&str) -> Reply + Send + Sync + 'static,
+    ) -> Self {
+        let identity = Identity::from_pkcs8(
+            certificate.certificate_pem.as_bytes(),
+            certificate.key_pem.as_bytes(),
+        )
+        .expect("server identity");
+        let acceptor = Arc::new(TlsAcceptor::new(identity).expect("TLS acceptor"));
+        Self::start("gemini", port, Arc::new(handler), move |stream| {
+            acceptor.accept(stream).ok().map(|tls| Box::new(tls) as _)
+        })
+    }
+
+    /// A Gopher server; the handler gets the selector
+    pub fn gopher(handler: impl Fn(&str) -> Reply + Send + Sync + 'static) -> Self {
+        Self::start("gopher", 0, Arc::new(handler), plain)
+    }
+
+    /// A Finger server; the handler gets the query, e.g. the user name
+    pub fn finger(handler: impl Fn(&str) -> Reply + Send + Sync + 'static) -> Self {
+        Self::start("finger", 0, Arc::new(handler), plain)
+    }
+
+    /// A CSO phone book server; the handler gets the command, e.g. "query smith", and the
+    /// connection closes after the reply, before the client's "quit"
+    pub fn cso(handler: impl Fn(&str) -> Reply + Send + Sync + 'static) -> Self {
+        Self::start("cso", 0, Arc::new(handler), plain)
+    }
+
+    fn start(
+        scheme: &'static str,
+        port: u16,
+        handler: Arc<Handler>,
+        wrap: impl Fn(TcpStream) -> Option<Box<dyn ServerStream>> + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind(("127.0.0.1", port)).expect("test server port");
+        let port = listener.local_addr().expect("test server address").port();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let server = TestServer {
+            scheme,
+            port,
+            stopped: stopped.clone(),
+            requests: requests.clone(),
+        };
+        let wrap = Arc::new(wrap);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                let (handler, wrap, stopped, requests) = (
+                    handler.clone(),
+                    wrap.clone(),
+                    stopped.clone(),
+                    requests.clone(),
+                );
+                thread::spawn(move || {
+                    if let Some(stream) = wrap(stream) {
+                        answer(stream, handler.as_ref(), &stopped, &requests);
+                    }
+                });
+            }
+        });
+        server
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The URL of a path on this server, e.g. "/page.gmi"
+    pub fn url(&self, path: &str) -> String {
+        format!("{}://127.0.0.1:{}{}", self.scheme, self.port, path)
+    }
+
+    /// Every request line received so far, without its line ending
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the flag
+        let _ = TcpStream::connect(("127.0.0.1", self.port));
+    }
+}
+
+/// A connection to a client, plain or TLS
+trait ServerStream: Read + Write + Send {
+    /// Close the connection cleanly, with a TLS close_notify where there is TLS
+    fn close(&mut self);
+}
+
+impl ServerStream for TcpStream {
+    fn close(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl ServerStream for native_tls::TlsStream<TcpStream> {
+    fn close(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+fn plain(stream: TcpStream) -> Option<Box<dyn ServerStream>> {
+    Some(Box::new(stream))
+}
+
+/// Read the request line of a connection and send the handler's reply
+fn answer(
+    stream: Box<dyn ServerStream>,
+    handler: &Handler,
+    stopped: &AtomicBool,
+    requests: &Mutex<Vec<String>>,
+) {
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    if (&mut reader)
+        .take(MAX_REQUEST_BYTES)
+        .read_until(b'\n', &mut line)
+        .is_err()
+    {
+        return;
+    }
+    let request = String::from_utf8_lossy(&line)
+        .trim_end_matches(['\r', '\n'])
+        .to_string();
+    requests.lock().unwrap().push(request.clone());
+    let mut stream = reader.into_inner();
+    match handler(&request) {
+        Reply::Send(bytes) => {
+            let _ = stream.write_all(&bytes);
+            stream.close();
+        }
+        Reply::Slow { chunks, delay } => {
+            for chunk in chunks {
+                thread::sleep(delay);
+                if stream
+                    .write_all(&chunk)
+                    .and_then(|_| stream.flush())
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            stream.close();
+        }
+        Reply::Truncated(bytes) => {
+            let _ = stream.write_all(&bytes);
+            let _ = stream.flush();
+        }
+        Reply::Hold(bytes) => {
+            let _ = stream.write_all(&bytes);
+            let _ = stream.flush();
+            while !stopped.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_millis(50));
+            }
+        }
+    }
+}
@@ -0,0 +1,608 @@
+//! Navigation against the in-process servers of `test_servers`, without live hosts.
+//!
+//! Run with `cargo test -p holloway-core --features test-servers`. The tests share the in-memory profile
+//! database, so each uses its own server and port.
+
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use holloway_core::api::functions::checksums::checksum_link;
+use holloway_core::api::functions::digest::sha256_hex;
+use holloway_core::api::functions::downloads::{download, download_verified};
+use holloway_core::api::functions::input_answers::{saved_answers, set_remember_input_answers};
+use holloway_core::api::functions::navigate_internal::navigate_with_options;
+use holloway_core::api::functions::peek::peek;
+use holloway_core::api::functions::plan_watches::check_plans;
+use holloway_core::api::functions::site_map::map_site;
+use holloway_core::api::functions::tls_failures::certificate_failure;
+use holloway_core::api::functions::tofu::end_private_session;
+use holloway_core::api::functions::trust_store::import_ca_certificates;
+use holloway_core::api::models::error_info::ErrorCode;
+use holloway_core::api::models::fetch_options::FetchOptions;
+use holloway_core::api::models::navigate_error::NavigateError;
+use holloway_core::api::models::navigation_result::{NavigationResult, PinStatus};
+use holloway_core::api::models::server_failure::ServerFailureKind;
+use holloway_core::api::models::tls_failure::TlsFailureKind;
+use holloway_core::api::storage::downloads::{list_downloads, ChecksumStatus};
+use holloway_core::api::storage::host_settings::{set_host_settings, HostSettings, TlsPolicy};
+use holloway_core::api::storage::known_hosts::get_known_host;
+use holloway_core::api::storage::plan_watches;
+use holloway_core::test_servers::{Reply, ServerCertificate, TestServer};
+
+fn fresh() -> FetchOptions {
+    FetchOptions {
+        private: true,
+        ..FetchOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn gemini_page() {
+    let server = TestServer::gemini(|_| Reply::gemini(20, "text/gemini", "# Hello\n"));
+    let result = navigate_with_options(server.url("/"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(result.status, Some(20));
+    assert_eq!(result.body, "# Hello\n");
+    assert_eq!(result.title.as_deref(), Some("Hello"));
+    assert_eq!(server.requests(), vec![server.url("/")]);
+}
+
+#[tokio::test]
+async fn gemini_redirects_are_followed() {
+    let server = TestServer::gemini(|request| {
+        if request.ends_with("/old") {
+            Reply::gemini(31, "/new", "")
+        } else {
+            Reply::gemini(20, "text/gemini", "moved here\n")
+        }
+    });
+    let result = navigate_with_options(server.url("/old"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(result.url, server.url("/new"));
+    assert_eq!(result.redirects, vec![server.url("/old")]);
+    assert_eq!(result.body, "moved here\n");
+}
+
+#[tokio::test]
+async fn gemini_redirect_loop_is_returned() {
+    let server = TestServer::gemini(|request| {
+        if request.ends_with("/a") {
+            Reply::gemini(30, "/b", "")
+        } else {
+            Reply::gemini(30, "/a", "")
+        }
+    });
+    let result = navigate_with_options(server.url("/a"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(result.status, Some(30));
+    assert_eq!(result.url, server.url("/b"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn gemini_large_body() {
+    let body = "0123456789abcdef\n".repeat(256 * 1024);
+    let reply = Reply::gemini(20, "text/plain", &body);
+    let server = TestServer::gemini(move |_| reply.clone());
+    let result = navigate_with_options(server.url("/large.txt"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(result.body.len(), body.len());
+    assert!(!result.truncated);
+}
+
+#[tokio::test]
+async fn gemini_body_limit() {
+    let body = "x".repeat(64 * 1024);
+    let reply = Reply::gemini(20, "text/plain", &body);
+    let server = TestServer::gemini(move |_| reply.clone());
+    let options = FetchOptions {
+        max_body_bytes: Some(1024),
+        ..fresh()
+    };
+    let error = navigate_with_options(server.url("/large.txt"), &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, NavigateError::TooLarge(_)), "{}", error);
+}
+
+#[tokio::test]
+async fn gemini_slow_server() {
+    let server = TestServer::gemini(|_| Reply::Slow {
+        chunks: vec![
+            b"20 text/gemini\r\n".to_vec(),
+            b"first\n".to_vec(),
+            b"second\n".to_vec(),
+        ],
+        delay: Duration::from_millis(300),
+    });
+    let options = FetchOptions {
+        read_timeout_secs: Some(5),
+        ..fresh()
+    };
+    let result = navigate_with_options(server.url("/"), &options)
+        .await
+        .unwrap();
+    assert_eq!(result.body, "first\nsecond\n");
+}
+
+#[tokio::test]
+async fn gemini_read_timeout() {
+    let server = TestServer::gemini(|_| Reply::Hold(b"20 text/gemini\r\npartial\n".to_vec()));
+    let options = FetchOptions {
+        read_timeout_secs: Some(1),
+        ..fresh()
+    };
+    let error = navigate_with_options(server.url("/"), &options)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, NavigateError::GeminiTimeout(_)),
+        "{}",
+        error
+    );
+    assert_eq!(error.code(), ErrorCode::GemTimeout);
+}
+
+#[tokio::test]
+async fn gemini_truncated_response() {
+    let server =
+        TestServer::gemini(|_| Reply::Truncated(b"20 text/gemini\r\n# Cut off mid-".to_vec()));
+    let result = navigate_with_options(server.url("/"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(result.body, "# Cut off mid-");
+}
+
+#[tokio::test]
+async fn gemini_changed_certificate() {
+    let page = |_: &str| Reply::gemini(20, "text/gemini", "pinned\n");
+    let server = TestServer::gemini(page);
+    let port = server.port();
+    let url = server.url("/");
+    let first = navigate_with_options(url.clone(), &FetchOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        first.certificate.map(|certificate| certificate.pin_status),
+        Some(PinStatus::FirstUse)
+    );
+    drop(server);
+
+    let _server = TestServer::gemini_with(ServerCertificate::new(), port, page);
+    let error = navigate_with_options(url, &FetchOptions::default())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, NavigateError::FingerprintMismatch(_)),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn private_certificate_pins_last_the_session() {
+    let page = |_: &str| Reply::gemini(20, "text/gemini", "private\n");
+    let server = TestServer::gemini(page);
+    let port = server.port();
+    let url = server.url("/");
+    let pin_status = |result: NavigationResult| result.certificate.map(|c| c.pin_status);
+    let first = navigate_with_options(url.clone(), &fresh()).await.unwrap();
+    assert_eq!(pin_status(first), Some(PinStatus::FirstUse));
+    let again = navigate_with_options(url.clone(), &fresh()).await.unwrap();
+    assert_eq!(pin_status(again), Some(PinStatus::Matched));
+    assert!(get_known_host("127.0.0.1", port).unwrap().is_none());
+    drop(server);
+
+    let _server = TestServer::gemini_with(ServerCertificate::new(), port, page);
+    let error = navigate_with_options(url.clone(), &fresh())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, NavigateError::FingerprintMismatch(_)),
+        "{}",
+        error
+    );
+    end_private_session();
+    let after = navigate_with_options(url, &fresh()).await.unwrap();
+    assert_eq!(pin_status(after), Some(PinStatus::FirstUse));
+}
+
+#[tokio::test]
+async fn gemini_expired_certificate() {
+    let server = TestServer::gemini_with(ServerCertificate::expired(), 0, |_| {
+        Reply::gemini(20, "text/gemini", "stale\n")
+    });
+    let error = navigate_with_options(server.url("/"), &fresh())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(error, NavigateError::CertificateDate(_)),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn gemini_failure_page() {
+    let visits = AtomicUsize::new(0);
+    let server = TestServer::gemini(move |request| {
+        if !request.ends_with("/page") {
+            return Reply::gemini(51, "Not here", "");
+        }
+        match visits.fetch_add(1, Ordering::SeqCst) {
+            0 => Reply::gemini(20, "text/gemini", "# Cached page\n"),
+            _ => Reply::gemini(41, "Down for maintenance", ""),
+        }
+    });
+    let missing = navigate_with_options(server.url("/missing"), &fresh())
+        .await
+        .unwrap();
+    let failure = missing.server_failure.unwrap();
+    assert_eq!(failure.kind, ServerFailureKind::NotFound);
+    assert!(!failure.temporary);
+    assert_eq!(missing.title.as_deref(), Some("Page not found"));
+    assert!(missing.body.contains("> Not here"));
+
+    navigate_with_options(server.url("/page"), &FetchOptions::default())
+        .await
+        .unwrap();
+    let down = navigate_with_options(server.url("/page"), &FetchOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        down.server_failure.map(|failure| failure.kind),
+        Some(ServerFailureKind::ServerUnavailable)
+    );
+    assert!(down
+        .body
+        .contains(&format!("=> {} Try again\n", server.url("/page"))));
+    let cached_link = down
+        .body
+        .lines()
+        .find_map(|line| line.strip_prefix("=> about:cached?"))
+        .unwrap()
+        .split(' ')
+        .next()
+        .unwrap();
+    let cached = navigate_with_options(format!("about:cached?{}", cached_link), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(cached.body, "# Cached page\n");
+    assert!(cached.cached_at.is_some());
+}
+
+#[tokio::test]
+async fn strict_host_self_signed_certificate() {
+    let mut params = rcgen::CertificateParams::new(Vec::<String>::new()).unwrap();
+    params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+    params
+        .distinguished_name
+        .push(rcgen::DnType::CommonName, "Test community CA");
+    let ca = params
+        .self_signed(&rcgen::KeyPair::generate().unwrap())
+        .unwrap();
+    import_ca_certificates(&ca.pem()).unwrap();
+    // The other tests connect to 127.0.0.1, so only localhost is strict
+    set_host_settings(HostSettings {
+        host: "localhost".to_string(),
+        tls_policy: Some(TlsPolicy::Strict),
+        ..HostSettings::default()
+    })
+    .unwrap();
+    let server = TestServer::gemini(|_| Reply::gemini(20, "text/gemini", "untrusted\n"));
+    let url = format!("gemini://localhost:{}/", server.port());
+    let error = navigate_with_options(url, &fresh()).await.unwrap_err();
+    assert!(
+        matches!(error, NavigateError::CertificateRejected(_)),
+        "{}",
+        error
+    );
+    assert!(error.to_string().contains("self-signed"));
+    let failure = certificate_failure("localhost").unwrap();
+    assert_eq!(failure.kind, TlsFailureKind::SelfSigned);
+    assert!(failure.names.contains(&"localhost".to_string()));
+}
+
+#[tokio::test]
+async fn gopher_menu() {
+    let server = TestServer::gopher(|selector| {
+        Reply::text(&format!(
+            "iSelector was [{}]\t\terror.host\t1\r\n0About\t/about.txt\tlocalhost\t70\r\n.\r\n",
+            selector
+        ))
+    });
+    let result = navigate_with_options(server.url("/1/menu"), &fresh())
+        .await
+        .unwrap();
+    assert!(result.body.contains("Selector was [/menu]"));
+    assert_eq!(server.requests(), vec!["/menu".to_string()]);
+}
+
+#[tokio::test]
+async fn gopher_server_holding_connection() {
+    let server = TestServer::gopher(|_| Reply::Hold(b"Text that never ends\r\n".to_vec()));
+    let options = FetchOptions {
+        read_timeout_secs: Some(1),
+        ..fresh()
+    };
+    let result = navigate_with_options(server.url("/0/file.txt"), &options)
+        .await
+        .unwrap();
+    assert!(result.body.starts_with("Text that never ends"));
+}
+
+#[tokio::test]
+async fn gopher_mislabelled_image() {
+    let server = TestServer::gopher(|selector| match selector {
+        "/logo" => Reply::Send(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec()),
+        _ => Reply::text("ID3 tags explained\r\n"),
+    });
+    let image = navigate_with_options(server.url("/0/logo"), &fresh())
+        .await
+        .unwrap();
+    assert_eq!(image.mime, "image/png");
+    // Text that merely starts like a signature stays text
+    let text = navigate_with_options(server.url("/0/id3.txt"), &fresh())
+        .await
+        .unwrap();
+    assert!(text.mime.starts_with("text/plain"));
+}
+
+#[tokio::test]
+async fn gopher_request_that_is_no_document() {
+    let server = TestServer::gopher(|_| Reply::text("Should not be asked\r\n"));
+    let telnet = navigate_with_options(server.url("/8/bbs"), &fresh())
+        .await
+        .unwrap_err();
+    assert_eq!(telnet.code(), ErrorCode::GopherBadType);
+    let two_lines = navigate_with_options(server.url("/0/readme%0D%0A/0/other"), &fresh())
+        .await
+        .unwrap_err();
+    assert_eq!(two_lines.code(), ErrorCode::GopherBadSelector);
+    assert_eq!(two_lines.info().number, 801);
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn finger_query() {
+    let server = TestServer::finger(|query| Reply::text(&format!("Login: {}\r\n", query)));
+    let url = format!("finger://alice@127.0.0.1:{}/", server.port());
+    let result = navigate_with_options(url, &fresh()).await.unwrap();
+    assert!(result.body.contains("Login: alice"));
+    assert_eq!(server.requests(), vec!["alice".to_string()]);
+}
+
+#[tokio::test]
+async fn finger_query_with_line_break() {
+    let server = TestServer::finger(|query| Reply::text(&format!("Login: {}\r\n", query)));
+    let url = format!("finger://127.0.0.1:{}/alice%0D%0A/W%20root", server.port());
+    let error = navigate_with_options(url, &fresh()).await.unwrap_err();
+    assert!(matches!(error, NavigateError::InvalidInput(_)), "{}", error);
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn finger_plan_watch() {
+    let server =
+        TestServer::finger(|query| Reply::text(&format!("Plan: hacking on {}\r\n", query)));
+    let watch = plan_watches::watch_plan(&format!("bob@127.0.0.1:{}", server.port())).unwrap();
+    assert_eq!(
+        watch.url,
+        format!("finger://bob@127.0.0.1:{}/", server.port())
+    );
+    // The first answer is taken as read
+    assert!(check_plans().await.unwrap().is_empty());
+    let watch = plan_watches::list_watched_plans()
+        .unwrap()
+        .into_iter()
+        .find(|plan| plan.id == watch.id)
+        .unwrap();
+    assert!(watch.plan.unwrap().contains("Plan: hacking on bob"));
+    assert!(watch.checked_at.is_some());
+    assert_eq!(server.requests(), vec!["bob".to_string()]);
+}
+
+#[tokio::test]
+async fn gopher_phone_book() {
+    let server = TestServer::cso(|command| match command {
+        "fields" => Reply::text(
+            "-200:1:name:max 64 Indexed Lookup Public Default\r\n\
+             -200:1:name:Full name\r\n\
+             -200:2:email:max 64 Lookup Public\r\n\
+             -200:2:email:Electronic mail address\r\n\
+             200:Ok.\r\n",
+        ),
+        "query smith" => Reply::text(
+            "102:There was 1 match to your request.\r\n\
+             -200:1:name:Smith, Jane\r\n\
+             -200:1:email:jane@example.org\r\n\
+             200:Ok.\r\n",
+        ),
+        _ => Reply::text("501:No matches to your query.\r\n"),
+    });
+    let url = format!("gopher://127.0.0.1:{}/2", server.port());
+    let opened = navigate_with_options(url.clone(), &fresh()).await.unwrap();
+    assert!(opened.body.contains("name: Full name"));
+    assert!(!opened.body.contains("email"));
+
+    let found = navigate_with_options(format!("{}?smith", url), &fresh())
+        .await
+        .unwrap();
+    assert!(found.body.contains(" name: Smith, Jane"));
+    assert!(found.body.contains("email: jane@example.org"));
+    assert_eq!(
+        server.requests(),
+        vec!["fields".to_string(), "query smith".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn gopher_site_map() {
+    let port = Arc::new(AtomicU16::new(0));
+    let server_port = port.clone();
+    let server = TestServer::gopher(move |selector| {
+        let port = server_port.load(Ordering::SeqCst);
+        let menu = match selector {
+            "/" => format!(
+                "iWelcome\t\terror.host\t1\r\n\
+                 1Phlog\t/phlog\t127.0.0.1\t{port}\r\n\
+                 0About\t/about.txt\t127.0.0.1\t{port}\r\n\
+                 7Search\t/search\t127.0.0.1\t{port}\r\n\
+                 1Friends\t/\tgopher.example.org\t70\r\n"
+            ),
+            "/phlog" => format!(
+                "0First post\t/phlog/first.txt\t127.0.0.1\t{port}\r\n\
+                 1Home\t/\t127.0.0.1\t{port}\r\n\
+                 1Archive\t/phlog/archive\t127.0.0.1\t{port}\r\n"
+            ),
+            _ => "0Old post\t/phlog/old.txt\t127.0.0.1\t70\r\n".to_string(),
+        };
+        Reply::text(&format!("{}.\r\n", menu))
+    });
+    port.store(server.port(), Ordering::SeqCst);
+
+    let map = map_site(&server.url("/1/"), 1).await.unwrap();
+    let titles: Vec<(&str, u32)> = map
+        .nodes
+        .iter()
+        .map(|node| (node.title.as_str(), node.depth))
+        .collect();
+    assert_eq!(
+        titles[1..],
+        [
+            ("Phlog", 1),
+            ("First post", 2),
+            ("Home", 2),
+            ("Archive", 2),
+            ("About", 1),
+            ("Search", 1),
+            ("Friends", 1),
+        ]
+    );
+    assert!(map.nodes[0].expanded && map.nodes[1].expanded);
+    // The start menu is not listed twice, and the archive is past the depth
+    assert!(!map.nodes[3].expanded && !map.nodes[4].expanded);
+    assert_eq!(map.nodes[4].parent, Some(1));
+    assert!(!map.nodes[7].expanded);
+    assert!(!map.truncated);
+    assert_eq!(
+        server.requests(),
+        vec!["/".to_string(), "/phlog".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn gemini_saved_input_answers() {
+    let server = TestServer::gemini(|request| match request.split_once('?') {
+        Some((_, answer)) => Reply::gemini(20, "text/gemini", &format!("# Hello {}\n", answer)),
+        None if request.ends_with("/login") => Reply::gemini(11, "Password", ""),
+        None => Reply::gemini(10, "Your name", ""),
+    });
+    set_remember_input_answers(true).unwrap();
+    let options = FetchOptions::default();
+    let asked = navigate_with_options(server.url("/ask"), &options)
+        .await
+        .unwrap();
+    assert_eq!(asked.status, Some(10));
+    navigate_with_options(server.url("/ask?Jane%20Doe"), &options)
+        .await
+        .unwrap();
+    let answers = saved_answers(&server.url("/ask"), "Your name").unwrap();
+    assert_eq!(answers.len(), 1);
+    assert_eq!(answers[0].answer, "Jane Doe");
+
+    // Sensitive input is never saved
+    navigate_with_options(server.url("/login"), &options)
+        .await
+        .unwrap();
+    navigate_with_options(server.url("/login?hunter2"), &options)
+        .await
+        .unwrap();
+    assert!(saved_answers(&server.url("/login"), "Password")
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn gemini_verified_download() {
+    const ARCHIVE: &str = "not really a tarball";
+    let server = TestServer::gemini(|request| {
+        if request.ends_with("/SHA256SUMS") {
+            let sums = format!(
+                "{}  other.tar.gz\n{}  tool.tar.gz\n",
+                sha256_hex(b"other"),
+                sha256_hex(ARCHIVE.as_bytes())
+            );
+            Reply::gemini(20, "text/plain", &sums)
+        } else if request.ends_with("/bad.tar.gz.sha256") {
+            Reply::gemini(20, "text/plain", &sha256_hex(b"something else"))
+        } else {
+            Reply::gemini(20, "application/gzip", ARCHIVE)
+        }
+    });
+    let page = "=> tool.tar.gz Tool\n=> bad.tar.gz Bad\n=> bad.tar.gz.sha256\n=> SHA256SUMS\n";
+    let page_url = server.url("/releases/");
+    let file_url = server.url("/releases/tool.tar.gz");
+    let checksum_url = checksum_link(&page_url, "text/gemini", page, &file_url).unwrap();
+    assert_eq!(checksum_url, server.url("/releases/SHA256SUMS"));
+    let bad_url = server.url("/releases/bad.tar.gz");
+    let bad_checksum_url = checksum_link(&page_url, "text/gemini", page, &bad_url).unwrap();
+    assert_eq!(bad_checksum_url, server.url("/releases/bad.tar.gz.sha256"));
+
+    let dir = std::env::temp_dir().join(format!("holloway-download-{}", server.port()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let options = FetchOptions::default();
+    let dest = dir.join("tool.tar.gz").to_string_lossy().to_string();
+    let saved = download_verified(&file_url, &dest, Some(&checksum_url), &options)
+        .await
+        .unwrap();
+    assert_eq!(saved.checksum, ChecksumStatus::Verified);
+    let dest = dir.join("bad.tar.gz").to_string_lossy().to_string();
+    let saved = download_verified(&bad_url, &dest, Some(&bad_checksum_url), &options)
+        .await
+        .unwrap();
+    assert_eq!(saved.checksum, ChecksumStatus::Mismatch);
+    let record = list_downloads(None, 100)
+        .unwrap()
+        .into_iter()
+        .find(|record| record.url == bad_url)
+        .unwrap();
+    assert_eq!(record.checksum, ChecksumStatus::Mismatch);
+    assert_eq!(record.checksum_url, Some(bad_checksum_url));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn gemini_download_body_limit() {
+    let server =
+        TestServer::gemini(|_| Reply::gemini(20, "application/zip", "more than ten bytes"));
+    let dir = std::env::temp_dir().join(format!("holloway-limit-{}", server.port()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let dest = dir.join("big.zip").to_string_lossy().to_string();
+    let options = FetchOptions {
+        max_body_bytes: Some(10),
+        ..fresh()
+    };
+    let error = download(&server.url("/big.zip"), &dest, &options)
+        .await
+        .unwrap_err();
+    assert!(error.starts_with("Response too large"));
+    assert!(!std::path::Path::new(&dest).exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn gemini_peek_stops_after_header() {
+    let server = TestServer::gemini(|_| {
+        Reply::Hold(b"20 application/zip\r\nPK\x03\x04 more than a header".to_vec())
+    });
+    let peeked = peek(&server.url("/archive.zip")).await.unwrap();
+    assert_eq!(peeked.status, Some(20));
+    assert_eq!(peeked.mime.as_deref(), Some("application/zip"));
+    assert_eq!(peeked.size, None);
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use holloway_core::api::protocols::gemini::parse_response;
+
+fuzz_target!(|data: &[u8]| {
+    let response = parse_response(data);
+    let _ = response.mime();
+});
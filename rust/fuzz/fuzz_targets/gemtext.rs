@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use holloway_core::api::functions::{anchors, bidi, feed_discovery, reflow, text_width};
+use holloway_core::api::parsers::gemtext::parse_gemtext;
+
+fuzz_target!(|data: &[u8]| {
+    let body = String::from_utf8_lossy(data);
+    let document = parse_gemtext(&body);
+
+    // Everything downstream of the parser also sees the untrusted document
+    let _ = reflow::reflow(&document, 40);
+    let _ = text_width::preformatted_blocks(&document);
+    let _ = bidi::analyze_directions(&document);
+    let _ = anchors::heading_anchors(&document);
+    let _ = anchors::resolve_fragment(&document, &body);
+    let _ = feed_discovery::discover_feeds("gemini://fuzz.example/", "text/gemini", &body);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use holloway_core::api::parsers::gophermap::parse_gophermap;
+
+fuzz_target!(|data: &[u8]| {
+    let body = String::from_utf8_lossy(data);
+    for item in parse_gophermap(&body) {
+        let _ = item.to_url();
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use holloway_core::api::functions::navigate_internal::parse_input_url;
+use holloway_core::api::protocols::registry;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Some(url) = parse_input_url(input) {
+            // URLs the browser would actually request must survive a round trip unchanged
+            if registry::handler_for(url.scheme()).is_some() {
+                let reparsed = parse_input_url(url.as_str()).expect("canonical URL reparses");
+                assert_eq!(url, reparsed);
+            }
+        }
+    }
+});
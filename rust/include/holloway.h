@@ -0,0 +1,62 @@
+/*
+ * C ABI for the Holloway browser engine. Build the crate with `--features capi`
+ * and link against the produced cdylib or staticlib.
+ *
+ * All functions block until done. Strings passed in must be NUL-terminated UTF-8.
+ * Strings returned by accessors belong to their object and stay valid until it is
+ * freed; strings returned through `error_out` must be released with
+ * holloway_string_free.
+ */
+#ifndef HOLLOWAY_H
+#define HOLLOWAY_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct HollowayResponse HollowayResponse;
+typedef struct HollowayDocument HollowayDocument;
+
+enum {
+    HOLLOWAY_LINE_TEXT = 0,
+    HOLLOWAY_LINE_LINK = 1,
+    HOLLOWAY_LINE_HEADING_1 = 2,
+    HOLLOWAY_LINE_HEADING_2 = 3,
+    HOLLOWAY_LINE_HEADING_3 = 4,
+    HOLLOWAY_LINE_LIST_ITEM = 5,
+    HOLLOWAY_LINE_QUOTE = 6,
+    HOLLOWAY_LINE_PREFORMAT_TOGGLE = 7,
+    HOLLOWAY_LINE_PREFORMATTED = 8,
+};
+
+/* Fetching */
+HollowayResponse *holloway_fetch(const char *url, char **error_out);
+const char *holloway_response_url(const HollowayResponse *response);
+const char *holloway_response_mime(const HollowayResponse *response);
+int holloway_response_status(const HollowayResponse *response);
+const char *holloway_response_meta(const HollowayResponse *response);
+const char *holloway_response_body(const HollowayResponse *response, size_t *len_out);
+void holloway_response_free(HollowayResponse *response);
+
+/* Gemtext parsing */
+HollowayDocument *holloway_gemtext_parse(const char *body);
+size_t holloway_document_len(const HollowayDocument *document);
+int holloway_document_line_kind(const HollowayDocument *document, size_t index);
+const char *holloway_document_line_text(const HollowayDocument *document, size_t index);
+const char *holloway_document_line_url(const HollowayDocument *document, size_t index);
+void holloway_document_free(HollowayDocument *document);
+
+/* Storage */
+int holloway_open_profile(const char *dir, char **error_out);
+int holloway_block(const char *pattern, char **error_out);
+int holloway_unblock(const char *pattern, char **error_out);
+
+void holloway_string_free(char *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HOLLOWAY_H */
@@ -1,23 +1,1930 @@
-#[flutter_rust_bridge::frb(sync)] // Synchronous mode for simplicity of the demo
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))] // Synchronous mode for simplicity of the demo
 pub fn greet(name: String) -> String {
     format!("Hello, {name}!")
 }
 
-#[flutter_rust_bridge::frb(init)]
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(init))]
 pub fn init_app() {
     // Default utilities - feel free to customize
+    #[cfg(feature = "flutter")]
     flutter_rust_bridge::setup_default_user_utils();
 }
 
-/// Navigate to a Gemini, Gopher, or Finger URL and return the plaintext content
-#[flutter_rust_bridge::frb]
-pub async fn navigate(url: String) -> Result<String, String> {
-    crate::api::functions::navigate_internal::navigate_internal(url).await
+/// Navigate to a URL and return the content with its final URL, protocol, status, MIME type, charset, duration, and certificate summary;
+/// a fresh cached copy is served unless `force_refresh` is set, e.g. by the reload button
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn navigate(
+    url: String,
+    force_refresh: bool,
+) -> Result<
+    crate::api::models::navigation_result::NavigationResult,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    let options = crate::api::models::fetch_options::FetchOptions {
+        use_cache: !force_refresh,
+        ..Default::default()
+    };
+    crate::api::functions::navigate_internal::navigate_with_options(url, &options).await
 }
 
-/// Get the default start page URL
-#[flutter_rust_bridge::frb(sync)]
+/// Navigate like `navigate` with per-request options, e.g. connect and read timeouts and a deadline for the whole request
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn navigate_with_options(
+    url: String,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> Result<
+    crate::api::models::navigation_result::NavigationResult,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::functions::navigate_internal::navigate_with_options(url, &options).await
+}
+
+/// Reserve a request id for `navigate_cancellable`, so the navigation can be stopped while it runs
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn reserve_request_id() -> u64 {
+    crate::api::functions::cancellation::reserve_request_id()
+}
+
+/// Navigate like `navigate` under a request id from `reserve_request_id`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn navigate_cancellable(
+    url: String,
+    request_id: u64,
+) -> Result<
+    crate::api::models::navigation_result::NavigationResult,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::functions::navigate_internal::navigate_cancellable(url, request_id).await
+}
+
+/// Stop a navigation or download by its request id (reserved, or from `begin_fetch`), closing its connection at once; returns whether it was still running
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_request(request_id: u64) -> bool {
+    crate::api::functions::cancellation::cancel_request(request_id)
+}
+
+/// Navigate to a URL and return the content along with page metadata such as discovered feeds
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn fetch(
+    url: String,
+) -> Result<
+    crate::api::models::fetch_response::FetchResponse,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::functions::navigate_internal::fetch_internal(url).await
+}
+
+/// Learn a URL's status, MIME type, and (for small bodies) size without downloading it, e.g. for link previews or choosing between opening and saving
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn peek(url: String) -> Result<crate::api::functions::peek::PeekResult, String> {
+    crate::api::functions::peek::peek(&url).await
+}
+
+/// Summarize a page for a share sheet or bookmark preview: its first heading, first paragraph, and the capsule's favicon, from the cache when fresh
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn page_summary(
+    url: String,
+) -> Result<crate::api::functions::page_summary::PageSummary, String> {
+    crate::api::functions::page_summary::page_summary(&url).await
+}
+
+/// Start streaming a Gemini URL line by line (for endless endpoints such as chat logs, or long gemtext documents, whose lines arrive parsed so the first screenful renders early) and return its handle
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn open_stream(
+    url: String,
+    options: crate::api::functions::streaming::StreamOptions,
+) -> Result<u64, String> {
+    crate::api::functions::streaming::open_stream(&url, options).await
+}
+
+/// Wait for the next header, line, idle, or end event of a stream
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_stream_event(
+    handle: u64,
+) -> Result<crate::api::models::stream_event::StreamEvent, String> {
+    crate::api::functions::streaming::next_event(handle).await
+}
+
+/// Stop a stream, returning whether it was still running
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_stream(handle: u64) -> bool {
+    crate::api::functions::streaming::cancel_stream(handle)
+}
+
+/// Start navigating a list of URLs with at most max_concurrent at a time, e.g. to check which bookmarks still answer, and return its handle
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn navigate_many(
+    urls: Vec<String>,
+    options: crate::api::models::fetch_options::FetchOptions,
+    max_concurrent: u32,
+) -> Result<u64, String> {
+    crate::api::functions::batch::navigate_many(urls, options, max_concurrent)
+}
+
+/// Wait for the next URL of a batch to finish; None once all of them have been reported
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_batch_result(
+    handle: u64,
+) -> Result<Option<crate::api::functions::batch::BatchItem>, String> {
+    crate::api::functions::batch::next_result(handle).await
+}
+
+/// Stop a batch, cancelling its running navigations; returns whether it was still running
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_batch(handle: u64) -> bool {
+    crate::api::functions::batch::cancel_batch(handle)
+}
+
+/// Navigate with per-request options, e.g. `private` for incognito tabs that must leave no history
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn fetch_with_options(
+    url: String,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> Result<
+    crate::api::models::fetch_response::FetchResponse,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::functions::navigate_internal::fetch_with_options(url, &options).await
+}
+
+/// Start a navigation in the background, returning its request id; use `next_fetch_update`
+/// to show the header and progress before the body is complete, then `finish_fetch`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn begin_fetch(
+    url: String,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> u64 {
+    crate::api::functions::pending_fetch::begin_fetch(url, options)
+}
+
+/// Wait for the next header, progress, or completion event of a background navigation; None after completion
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_fetch_update(
+    request_id: u64,
+) -> Result<Option<crate::api::models::navigation_event::NavigationEvent>, String> {
+    crate::api::functions::pending_fetch::next_update(request_id).await
+}
+
+/// Wait for a background navigation to complete and return its response
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn finish_fetch(
+    request_id: u64,
+) -> Result<crate::api::models::fetch_response::FetchResponse, String> {
+    crate::api::functions::pending_fetch::finish(request_id).await
+}
+
+/// Subscribe to navigation events (started, resolving, connecting, TLS handshake, header, progress, finished, failed) of every fetch and stream
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn subscribe_events() -> u64 {
+    crate::api::functions::navigation_events::subscribe()
+}
+
+/// Subscribe to the navigation events of one request, e.g. one reserved with `reserve_request_id`, for a progress indicator; ends after it finishes or fails
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn subscribe_request_events(request_id: u64) -> u64 {
+    crate::api::functions::navigation_events::subscribe_request(request_id)
+}
+
+/// Wait for the next navigation event of a subscription
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_navigation_event(
+    subscription: u64,
+) -> Result<crate::api::models::navigation_event::NavigationEvent, String> {
+    crate::api::functions::navigation_events::next_event(subscription).await
+}
+
+/// The ordered steps (cache, connection, TLS, response, parsing) of a recent request by its request id, for bug reports; None once it is no longer kept
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_trace(request_id: u64) -> Option<Vec<crate::api::functions::request_trace::TraceEvent>> {
+    crate::api::functions::request_trace::get_trace(request_id)
+}
+
+/// End a navigation event subscription, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unsubscribe_events(subscription: u64) -> bool {
+    crate::api::functions::navigation_events::unsubscribe(subscription)
+}
+
+/// Subscribe to log records (requests, TLS, redirects, failures) for a debug console
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn subscribe_logs() -> u64 {
+    crate::api::functions::logging::subscribe()
+}
+
+/// Wait for the next log record of a subscription
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_log_record(
+    subscription: u64,
+) -> Result<crate::api::functions::logging::LogRecord, String> {
+    crate::api::functions::logging::next_record(subscription).await
+}
+
+/// End a log subscription, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unsubscribe_logs(subscription: u64) -> bool {
+    crate::api::functions::logging::unsubscribe(subscription)
+}
+
+/// The most recent log records of at least a level still kept in memory, oldest first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn recent_logs(
+    min_level: crate::api::functions::logging::LogLevel,
+    limit: u32,
+) -> Vec<crate::api::functions::logging::LogRecord> {
+    crate::api::functions::logging::recent_logs(min_level, limit)
+}
+
+/// The least severe level that is logged; Info by default, Debug adds every step of every request
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_log_level() -> crate::api::functions::logging::LogLevel {
+    crate::api::functions::logging::log_level()
+}
+
+/// Set the least severe level that is logged
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_log_level(level: crate::api::functions::logging::LogLevel) {
+    crate::api::functions::logging::set_log_level(level)
+}
+
+/// Also write log records to rotating files in a directory, or stop with None
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_log_directory(dir: Option<String>) -> Result<(), String> {
+    crate::api::functions::logging::set_log_directory(dir.as_deref())
+}
+
+/// Paths of the log files, oldest first, for attaching to a bug report
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn log_files() -> Vec<String> {
+    crate::api::functions::logging::log_files()
+}
+
+/// The stable code (e.g. GEM_TIMEOUT / 704) and recovery hints of an error a navigation failed with
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn navigate_error_info(
+    error: crate::api::models::navigate_error::NavigateError,
+) -> crate::api::models::error_info::ErrorInfo {
+    error.info()
+}
+
+/// The recovery action a Gemini status asks for: input (1x), retry (4x), or an identity (6x)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn suggested_action_for_status(
+    status: u8,
+) -> Option<crate::api::models::error_info::RecoveryAction> {
+    crate::api::models::error_info::suggested_action_for_status(status)
+}
+
+/// List the supported protocols with their default ports and capabilities
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_protocols() -> Vec<crate::api::protocols::registry::ProtocolInfo> {
+    crate::api::protocols::registry::registered_protocols()
+}
+
+/// Parse a gemtext body and wrap its text, quote, and list lines for reader mode
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn reflow_gemtext(
+    body: String,
+    max_cols: u32,
+) -> Vec<crate::api::functions::reflow::ReflowedLine> {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::reflow::reflow(&document, max_cols as usize)
+}
+
+/// Rewrap plain text laid out for an 80-column terminal (Gopher text, finger output) to max_cols, keeping ASCII art, tables, and short lines as they are
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn reflow_text(text: String, max_cols: u32) -> String {
+    crate::api::functions::reflow::reflow_plain_text(&text, max_cols as usize)
+}
+
+/// How to present content of a MIME type from a URL (the same decision as `FetchResponse.renderer`), e.g. before following a link
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn route_content(url: String, mime: String) -> crate::api::functions::content_router::Renderer {
+    crate::api::functions::content_router::route_url(&url, &mime)
+}
+
+/// Parse a Gopher menu into items with their kind, icon, and whether they can be opened
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn parse_gopher_menu(body: String) -> Vec<crate::api::parsers::gophermap::GopherItem> {
+    crate::api::parsers::gophermap::parse_gophermap(&body)
+}
+
+/// Every link of a gemtext page or Gopher menu, resolved against its URL and classified, for prefetching, "open all links", and link hints
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn extract_links(
+    url: String,
+    body: String,
+    mime: String,
+) -> Vec<crate::api::functions::links::PageLink> {
+    crate::api::functions::links::extract_links(&url, &mime, &body)
+}
+
+/// Resolve a link target against the URL of its page (RFC 3986), keeping the item type layout of Gopher URLs
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn resolve_link(base_url: String, href: String) -> Result<String, String> {
+    crate::api::functions::links::resolve_link(&base_url, &href)
+}
+
+/// Look up entries in the CSO phone book of a gopher type-2 item URL, returning them as field records
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn cso_query(
+    url: String,
+    query: String,
+) -> Result<
+    Vec<crate::api::protocols::cso::CsoRecord>,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::protocols::cso::query_item(&url, &query).await
+}
+
+/// Render a gemtext page as standalone HTML with its links made absolute, for sharing or archiving it outside the app
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn render_to_html(gemtext: String, base_url: String) -> String {
+    crate::api::functions::html_export::render_to_html(&gemtext, &base_url)
+}
+
+/// Render a gemtext page, or a Gopher menu when the URL or MIME type says so, as CommonMark with its links made absolute, for Markdown-based note tools
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn render_to_markdown(body: String, base_url: String, mime: String) -> String {
+    crate::api::functions::markdown_export::render_to_markdown(&body, &base_url, &mime)
+}
+
+/// Render a Gopher menu as gemtext: information lines as text and items as links to their gopher:// URLs, for showing or exporting menus like gemtext pages
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn gophermap_to_gemtext(body: String) -> String {
+    crate::api::functions::gemtext_export::gophermap_to_gemtext(&body)
+}
+
+/// Keep a Gopher menu on the Rust side for paged access, returning a handle for `get_menu_page`;
+/// use this instead of `parse_gopher_menu` for menus with thousands of items
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_menu(body: String) -> u64 {
+    crate::api::functions::menu_pages::open_menu(body)
+}
+
+/// Up to `count` items of a retained Gopher menu starting at `offset`, with the menu's total item count
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_menu_page(
+    handle: u64,
+    offset: u32,
+    count: u32,
+) -> Result<crate::api::functions::menu_pages::MenuPage, String> {
+    crate::api::functions::menu_pages::menu_page(handle, offset, count)
+}
+
+/// Release a menu retained by `open_menu`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn close_menu(handle: u64) -> bool {
+    crate::api::functions::menu_pages::close_menu(handle)
+}
+
+/// Parse a gemtext body and keep it on the Rust side, returning a handle for `get_lines`;
+/// lets a virtualized list view fetch only visible lines of very large documents
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_document(body: String) -> u64 {
+    crate::api::functions::documents::open_document(&body)
+}
+
+/// Number of lines in a document retained by `open_document`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_line_count(handle: u64) -> Result<u32, String> {
+    crate::api::functions::documents::line_count(handle)
+}
+
+/// Up to `count` lines of a retained document starting at `start`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_lines(
+    handle: u64,
+    start: u32,
+    count: u32,
+) -> Result<Vec<crate::api::parsers::gemtext::GemtextLine>, String> {
+    crate::api::functions::documents::get_lines(handle, start, count)
+}
+
+/// Release a document retained by `open_document`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn close_document(handle: u64) -> bool {
+    crate::api::functions::documents::close_document(handle)
+}
+
+/// The headings of a document retained by `open_document` as an outline (level, text, line index, nesting) for a jump-to-section sidebar
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn document_outline(
+    handle: u64,
+) -> Result<Vec<crate::api::functions::anchors::OutlineEntry>, String> {
+    crate::api::functions::documents::document_outline(handle)
+}
+
+/// The headings of a gemtext body as an outline, like `document_outline`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn gemtext_outline(body: String) -> Vec<crate::api::functions::anchors::OutlineEntry> {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::anchors::outline(&document)
+}
+
+/// Word count, estimated reading time, and link and heading counts of a document retained by `open_document`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn document_stats(
+    handle: u64,
+) -> Result<crate::api::functions::reading_stats::ReadingStats, String> {
+    crate::api::functions::documents::document_stats(handle)
+}
+
+/// Reading statistics of a gemtext body, like `document_stats`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn gemtext_stats(body: String) -> crate::api::functions::reading_stats::ReadingStats {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::reading_stats::reading_stats(&document)
+}
+
+/// Fetch the same-host images a document retained by `open_document` links to, keyed by link index, for an inline-images mode; each is capped at `max_bytes` (1 MiB by default)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn fetch_document_images(
+    handle: u64,
+    page_url: String,
+    max_bytes: Option<u32>,
+) -> Result<crate::api::functions::inline_images::InlineImages, String> {
+    crate::api::functions::documents::document_images(handle, &page_url, max_bytes).await
+}
+
+/// Download a Gempub e-book (.gpub) and open it, returning its handle, metadata and chapters in reading order
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn open_gempub(url: String) -> Result<crate::api::functions::gempub::GempubBook, String> {
+    crate::api::functions::gempub::open_gempub(&url).await
+}
+
+/// Open a Gempub e-book from the bytes of a .gpub file, e.g. one already downloaded
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_gempub_data(
+    data: Vec<u8>,
+) -> Result<crate::api::functions::gempub::GempubBook, String> {
+    crate::api::functions::gempub::open_gempub_data(&data)
+}
+
+/// A chapter of a book opened by `open_gempub`, with the indexes of the previous and next chapters
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn gempub_chapter(
+    handle: u64,
+    chapter: u32,
+) -> Result<crate::api::functions::gempub::GempubPage, String> {
+    crate::api::functions::gempub::gempub_chapter(handle, chapter)
+}
+
+/// The file of a book opened by `open_gempub` that a link on the page at `from_path` leads to, such as another chapter or an image
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn gempub_file(
+    handle: u64,
+    from_path: Option<String>,
+    link: String,
+) -> Result<crate::api::functions::gempub::GempubPage, String> {
+    crate::api::functions::gempub::gempub_file(handle, from_path.as_deref(), &link)
+}
+
+/// Release a book opened by `open_gempub`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn close_gempub(handle: u64) -> bool {
+    crate::api::functions::gempub::close_gempub(handle)
+}
+
+/// Measure the display width of each preformatted block in a gemtext body
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn measure_preformatted(
+    body: String,
+) -> Vec<crate::api::functions::text_width::PreformattedBlock> {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::text_width::preformatted_blocks(&document)
+}
+
+/// Token spans of each preformatted block of a gemtext body whose alt text names a known language
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn highlight_preformatted(
+    body: String,
+) -> Vec<crate::api::functions::highlighting::HighlightedBlock> {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::highlighting::highlight_preformatted(&document)
+}
+
+/// Token spans of each line of code in a language given by name or extension; None if it has no grammar
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn highlight_code(
+    code: String,
+    language: String,
+) -> Option<Vec<crate::api::functions::highlighting::HighlightedLine>> {
+    crate::api::functions::highlighting::highlight_code(&code, &language)
+}
+
+/// Names of the languages preformatted blocks can be highlighted in
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn highlight_languages() -> Vec<String> {
+    crate::api::functions::highlighting::highlight_languages()
+}
+
+/// Remove ANSI escape sequences (terminal colors and cursor commands) from a Gopher or Finger body
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn strip_ansi_escapes(body: String) -> String {
+    crate::api::functions::ansi::strip_ansi(&body)
+}
+
+/// Remove ANSI escape sequences from a body, returning the colors and attributes they set as styled spans
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn style_ansi_text(body: String) -> crate::api::functions::ansi::StyledText {
+    crate::api::functions::ansi::parse_ansi(&body)
+}
+
+/// Compute paragraph direction and bidi level runs for the right-to-left lines of a gemtext body
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn analyze_text_direction(body: String) -> Vec<crate::api::functions::bidi::LineDirection> {
+    let document = crate::api::parsers::gemtext::parse_gemtext(&body);
+    crate::api::functions::bidi::analyze_directions(&document)
+}
+
+/// Recognize a file type (MIME and extension) from the leading bytes of its content
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn detect_file_type(content: Vec<u8>) -> Option<crate::api::functions::file_type::FileType> {
+    crate::api::functions::file_type::sniff(&content)
+}
+
+/// How to show the host of a URL in the address bar, flagging likely spoofed (homograph) hosts
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn display_host(url: String) -> crate::api::functions::homograph::DisplayHost {
+    let parsed = crate::api::functions::navigate_internal::parse_input_url(&url);
+    match parsed.as_ref().and_then(|url| url.host_str()) {
+        Some(host) => crate::api::functions::homograph::display_host(host),
+        None => crate::api::functions::homograph::DisplayHost::default(),
+    }
+}
+
+/// A URL with its host in Unicode for display, or in punycode when the host may be spoofed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn display_url(url: String) -> String {
+    match crate::api::functions::navigate_internal::parse_input_url(&url) {
+        Some(parsed) => crate::api::functions::homograph::display_url(&parsed),
+        None => url,
+    }
+}
+
+/// Suggest a safe download file name for a URL and MIME type, numbered if it already exists in dest_dir
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn suggest_download_path(url: String, mime: String, dest_dir: String) -> String {
+    let filename = crate::api::functions::downloads::suggest_filename(&url, &mime);
+    crate::api::functions::downloads::unique_path(std::path::Path::new(&dest_dir), &filename)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// The decompression ratio and size caps protecting against decompression bombs
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_decompression_limits() -> crate::api::transport::limits::DecompressionLimits {
+    crate::api::transport::limits::decompression_limits()
+}
+
+/// Change the decompression ratio and size caps, e.g. lower them on devices short of memory
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_decompression_limits(
+    limits: crate::api::transport::limits::DecompressionLimits,
+) -> Result<(), String> {
+    crate::api::transport::limits::set_decompression_limits(limits)
+}
+
+/// How many TLS handshakes background fetches such as prefetches may run at once
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_max_concurrent_handshakes() -> u32 {
+    crate::api::transport::handshakes::max_concurrent_handshakes()
+}
+
+/// Cap the TLS handshakes of background fetches, e.g. lower it on slow phones; fetches the user asked for are never held back
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_max_concurrent_handshakes(max: u32) -> Result<(), String> {
+    crate::api::transport::handshakes::set_max_concurrent_handshakes(max)
+}
+
+/// How many connections background fetches may keep open to one host and how long they wait between them
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_rate_limits() -> crate::api::transport::rate_limit::RateLimits {
+    crate::api::transport::rate_limit::rate_limits()
+}
+
+/// Limit how hard prefetches and feed refreshes hit one host; fetches the user asked for are never held back but count against the host
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_rate_limits(
+    limits: crate::api::transport::rate_limit::RateLimits,
+) -> Result<(), String> {
+    crate::api::transport::rate_limit::set_rate_limits(limits)
+}
+
+/// Tunnel all connections through a WebSocket gateway (required on the web), or pass None for direct sockets
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_gateway(url_template: Option<String>) -> Result<(), String> {
+    crate::api::transport::set_gateway(url_template)
+}
+
+/// The active proxy settings
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_proxy_settings() -> crate::api::transport::proxy::ProxySettings {
+    crate::api::transport::proxy::settings()
+}
+
+/// Validate, save to the profile, and activate proxy settings (direct, one SOCKS5 proxy, or per-host rules)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_proxy_settings(
+    settings: crate::api::transport::proxy::ProxySettings,
+) -> Result<(), String> {
+    let settings = settings.validate()?;
+    crate::api::storage::proxy::save_settings(&settings)?;
+    crate::api::transport::proxy::apply(settings)
+}
+
+/// Probe a SOCKS5 proxy: connect, authenticate, and open a connection through it
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn test_proxy(
+    proxy: crate::api::transport::proxy::Socks5Proxy,
+) -> Result<crate::api::transport::proxy::ProxyProbe, String> {
+    crate::api::transport::proxy::test_proxy(&proxy).await
+}
+
+/// Open the profile database stored in the given app data directory
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_profile(dir: String) -> Result<(), String> {
+    crate::api::storage::open_profile(dir)
+}
+
+/// Fold the profile database's write-ahead log into the database file, e.g. when the app moves to the background
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn checkpoint_profile() -> Result<(), String> {
+    crate::api::storage::checkpoint_profile()
+}
+
+/// Stop background work (streams, pending fetches, refresh and expiry loops) and close the profile database; call from the app's paused/detached lifecycle hooks and `open_profile` again on resume
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn shutdown() -> Result<crate::api::functions::shutdown::ShutdownSummary, String> {
+    crate::api::functions::shutdown::shutdown()
+}
+
+/// Block a host ("spam.example"), a domain with its subdomains ("*.example.org"), or a URL prefix
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn add_block_rule(
+    pattern: String,
+) -> Result<crate::api::storage::blocklist::BlockRule, String> {
+    crate::api::storage::blocklist::add_rule(&pattern)
+}
+
+/// Remove a blocklist pattern, returning whether it was present
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_block_rule(pattern: String) -> Result<bool, String> {
+    crate::api::storage::blocklist::remove_rule(&pattern)
+}
+
+/// List all blocklist rules
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_block_rules() -> Result<Vec<crate::api::storage::blocklist::BlockRule>, String> {
+    crate::api::storage::blocklist::list_rules()
+}
+
+/// The most recent history entries, newest first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_recent_history(
+    limit: u32,
+) -> Result<Vec<crate::api::storage::history::HistoryEntry>, String> {
+    crate::api::storage::history::recent_history(limit)
+}
+
+/// Visited pages with their visit counts, most recently visited first, `limit` at a time
+/// starting after `offset` pages
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_history_page(
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::history::HistoryResult>, String> {
+    crate::api::storage::history::history_page(offset, limit)
+}
+
+/// Record a visit to a page the UI loaded without `navigate`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn add_history_visit(url: String, title: Option<String>) -> Result<(), String> {
+    crate::api::storage::history::add_visit(&url, title.as_deref())
+}
+
+/// Delete one history entry, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_history_entry(id: i64) -> Result<bool, String> {
+    crate::api::storage::history::delete_visit(id)
+}
+
+/// Delete every visit to a page from history, returning how many were removed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_history_url(url: String) -> Result<u32, String> {
+    crate::api::storage::history::delete_url(&url)
+}
+
+/// Search history by title or URL within a time range, with one aggregated result per page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn search_history(
+    query: String,
+    mode: crate::api::storage::history::HistoryMatch,
+    range: crate::api::storage::history::HistoryRange,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::history::HistoryResult>, String> {
+    crate::api::storage::history::search_history(&query, mode, &range, limit)
+}
+
+/// Address bar completions for typed text from history and bookmarks, ranked with titles and an inline completion for URL prefix matches
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn suggest(
+    prefix: String,
+    limit: u32,
+) -> Result<Vec<crate::api::functions::suggestions::Suggestion>, String> {
+    crate::api::functions::suggestions::suggest(&prefix, limit)
+}
+
+/// Search the text of cached pages, best matches first, with a snippet around the matches of each
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn search_local(
+    query: String,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::page_index::LocalSearchResult>, String> {
+    crate::api::storage::page_index::search_local(&query, limit)
+}
+
+/// Crawl the capsule a URL belongs to (same host, under the URL's directory) through its internal links, up to `max_pages` pages, adding them to the local search index within robots.txt and the host rate limits
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn index_capsule(
+    url: String,
+    max_pages: u32,
+) -> Result<crate::api::functions::crawler::CrawlResult, String> {
+    crate::api::functions::crawler::index_capsule(&url, max_pages).await
+}
+
+/// Stop running capsule crawls before their next page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_capsule_indexing() {
+    crate::api::functions::crawler::cancel_capsule_indexing()
+}
+
+/// Walk a gopher hole's menus from a URL up to `depth` levels (at most 8) within the host rate limits, returning its menus and documents as a flattened tree for a site map sidebar
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn map_site(
+    url: String,
+    depth: u32,
+) -> Result<crate::api::functions::site_map::SiteMap, String> {
+    crate::api::functions::site_map::map_site(&url, depth).await
+}
+
+/// Search the locally indexed pages of the capsule a URL belongs to, like `search_local`
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn search_capsule(
+    url: String,
+    query: String,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::page_index::LocalSearchResult>, String> {
+    crate::api::functions::crawler::search_capsule(&url, &query, limit)
+}
+
+/// Start saving a Gemini capsule's pages up to `depth` links deep, with the files they link to, under dest_dir with links rewritten to relative paths; resumes a stopped mirror of the same dest_dir and returns a handle
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn mirror_capsule(url: String, dest_dir: String, depth: u32) -> Result<u64, String> {
+    crate::api::functions::mirror::mirror_capsule(&url, &dest_dir, depth)
+}
+
+/// Wait for the next file of a mirror to be saved or fail; None once the mirror is finished
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_mirror_progress(
+    handle: u64,
+) -> Result<Option<crate::api::functions::mirror::MirrorProgress>, String> {
+    crate::api::functions::mirror::next_progress(handle).await
+}
+
+/// Stop a mirror after its current file; mirroring to the same dest_dir again resumes it
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_mirror(handle: u64) -> bool {
+    crate::api::functions::mirror::cancel_mirror(handle)
+}
+
+/// The most frequently and recently visited pages, for the new-tab speed dial
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_top_sites(limit: u32) -> Result<Vec<crate::api::functions::top_sites::TopSite>, String> {
+    crate::api::functions::top_sites::top_pages(limit)
+}
+
+/// The most frequently and recently visited hosts, each linked to its root page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_top_hosts(limit: u32) -> Result<Vec<crate::api::functions::top_sites::TopSite>, String> {
+    crate::api::functions::top_sites::top_hosts(limit)
+}
+
+/// Fetch and cache the /favicon.txt emoji of the capsule serving a page (not cached for private requests)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn refresh_favicon(
+    page_url: String,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> Result<Option<String>, String> {
+    crate::api::functions::favicons::refresh_favicon(&page_url, &options).await
+}
+
+/// List cached responses with their size, type, and staleness, optionally for one host only
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_cache_entries(
+    host: Option<String>,
+) -> Result<Vec<crate::api::storage::cache::CacheEntry>, String> {
+    crate::api::storage::cache::list_entries(host.as_deref())
+}
+
+/// Remove one page from the cache, returning whether it was cached
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn evict_cache_entry(url: String) -> Result<bool, String> {
+    crate::api::storage::cache::evict(&url)
+}
+
+/// The cache policy (cacheability, TTL, query handling) of each registered scheme
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_cache_policies() -> Result<Vec<crate::api::storage::cache::CachePolicy>, String> {
+    crate::api::protocols::registry::registered_schemes()
+        .into_iter()
+        .map(crate::api::storage::cache::policy_for)
+        .collect()
+}
+
+/// Override the cache policy of one scheme
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_cache_policy(policy: crate::api::storage::cache::CachePolicy) -> Result<(), String> {
+    crate::api::storage::cache::set_policy(&policy)
+}
+
+/// Restore the built-in cache policy of a scheme
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn reset_cache_policy(scheme: String) -> Result<(), String> {
+    crate::api::storage::cache::reset_policy(&scheme)
+}
+
+/// Turn a protocol on or off; fetching a URL of a disabled scheme fails with a SchemeDisabled error
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_protocol_enabled(scheme: String, enabled: bool) -> Result<(), String> {
+    crate::api::storage::scheme_toggles::set_enabled(&scheme, enabled)
+}
+
+/// Use a port for URLs of a scheme that omit one, or only for one host when host is given
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_port_override(scheme: String, host: Option<String>, port: u16) -> Result<(), String> {
+    crate::api::storage::ports::set_override(&scheme, host.as_deref(), port)
+}
+
+/// Remove a scheme-wide (host None) or per-host port override, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_port_override(scheme: String, host: Option<String>) -> Result<bool, String> {
+    crate::api::storage::ports::remove_override(&scheme, host.as_deref())
+}
+
+/// List the configured port overrides
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_port_overrides() -> Result<Vec<crate::api::storage::ports::PortOverride>, String> {
+    crate::api::storage::ports::list_overrides()
+}
+
+/// Send every URL of a scheme (e.g. http) as a full URL to a Gemini proxy, replacing the scheme's
+/// handler if it has one
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_gemini_proxy(scheme: String, host: String, port: u16) -> Result<(), String> {
+    crate::api::storage::gemini_proxies::set_proxy(&scheme, &host, port)
+}
+
+/// Stop sending a scheme to its Gemini proxy or Gopher gateway, returning whether it had one
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_gemini_proxy(scheme: String) -> Result<bool, String> {
+    crate::api::storage::gemini_proxies::remove_proxy(&scheme)
+}
+
+/// Send every URL of a scheme (e.g. https) to a Gopher gateway, asking for `selector` (item type and selector, e.g. "0/fetch?{url}") with the URL in place of {url} or appended
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_gopher_gateway(
+    scheme: String,
+    host: String,
+    port: u16,
+    selector: String,
+) -> Result<(), String> {
+    crate::api::storage::gemini_proxies::set_gopher_gateway(&scheme, &host, port, &selector)
+}
+
+/// List the configured Gemini proxies and Gopher gateways
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_gemini_proxies() -> Result<Vec<crate::api::storage::gemini_proxies::SchemeProxy>, String>
+{
+    crate::api::storage::gemini_proxies::list_proxies()
+}
+
+/// Overrides of a host (certificate policy, identity, timeouts, redirect policy, maximum body
+/// size, charset), if it has any
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_host_settings(
+    host: String,
+) -> Result<Option<crate::api::storage::host_settings::HostSettings>, String> {
+    crate::api::storage::host_settings::get_host_settings(&host)
+}
+
+/// Store the overrides of a host, replacing any it had
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_host_settings(
+    settings: crate::api::storage::host_settings::HostSettings,
+) -> Result<(), String> {
+    crate::api::storage::host_settings::set_host_settings(settings)
+}
+
+/// Drop the overrides of a host, returning whether it had any
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_host_settings(host: String) -> Result<bool, String> {
+    crate::api::storage::host_settings::remove_host_settings(&host)
+}
+
+/// List every host with overrides
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_host_settings() -> Result<Vec<crate::api::storage::host_settings::HostSettings>, String>
+{
+    crate::api::storage::host_settings::list_host_settings()
+}
+
+/// Turn offline browsing on or off; while on, navigation serves the newest cached copy of a page,
+/// marked stale when past its expiry, and fails for pages never cached
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_offline_mode(enabled: bool) -> Result<(), String> {
+    crate::api::functions::offline::set_offline_mode(enabled)
+}
+
+/// Whether offline browsing is on
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn is_offline_mode() -> bool {
+    crate::api::functions::offline::is_offline_mode()
+}
+
+/// Clear browsing data recorded since the given Unix time (None for everything);
+/// an empty category list clears the defaults, which exclude identities
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn clear_data(
+    categories: Vec<crate::api::storage::clear::DataCategory>,
+    since: Option<i64>,
+) -> Result<Vec<crate::api::storage::clear::ClearedData>, String> {
+    let categories = if categories.is_empty() {
+        crate::api::storage::clear::default_categories()
+    } else {
+        categories
+    };
+    crate::api::storage::clear::clear_data(&categories, since)
+}
+
+/// Save a URL of any protocol to dest_path, streaming it to disk instead of memory, and return its size, MIME type, and SHA-256
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn download(
+    url: String,
+    dest_path: String,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> Result<crate::api::functions::downloads::DownloadResult, String> {
+    crate::api::functions::downloads::download(&url, &dest_path, &options).await
+}
+
+/// The checksum file (file.sha256 or a SHA256SUMS list) a page links alongside a file it links, to pass to download_verified
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn checksum_link(
+    page_url: String,
+    mime: String,
+    body: String,
+    file_url: String,
+) -> Option<String> {
+    crate::api::functions::checksums::checksum_link(&page_url, &mime, &body, &file_url)
+}
+
+/// Download a URL like download, then check the file against a checksum file, reporting whether it was verified in the result and the download history
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn download_verified(
+    url: String,
+    dest_path: String,
+    checksum_url: Option<String>,
+    options: crate::api::models::fetch_options::FetchOptions,
+) -> Result<crate::api::functions::downloads::DownloadResult, String> {
+    crate::api::functions::downloads::download_verified(
+        &url,
+        &dest_path,
+        checksum_url.as_deref(),
+        &options,
+    )
+    .await
+}
+
+/// Record a finished download in the profile's download history, returning its id
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn record_download(
+    url: String,
+    path: String,
+    size: u64,
+    mime: String,
+    status: crate::api::storage::downloads::DownloadStatus,
+    error: Option<String>,
+) -> Result<i64, String> {
+    crate::api::storage::downloads::record_download(
+        &url,
+        &path,
+        size,
+        &mime,
+        status,
+        error.as_deref(),
+    )
+}
+
+/// Per-host request counts, traffic, average latency, and error rate, ranked by the chosen statistic
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_host_stats(
+    order: crate::api::storage::host_stats::HostStatsOrder,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::host_stats::HostStats>, String> {
+    crate::api::storage::host_stats::list_host_stats(order, limit)
+}
+
+/// Tell the core which network the device is on (Wi-Fi, cellular, ...) so traffic is attributed to it
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_network_type(network: crate::api::storage::data_usage::NetworkType) {
+    crate::api::functions::data_usage::set_network_type(network)
+}
+
+/// Bytes sent and received per day and network type for the days in the given Unix time range
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_data_usage(
+    since: i64,
+    until: i64,
+) -> Result<Vec<crate::api::storage::data_usage::DailyUsage>, String> {
+    crate::api::storage::data_usage::usage_between(since, until)
+}
+
+/// Set (or remove with None) a soft cap on cellular traffic over the last 30 days; prefetching stops once it is exceeded
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_data_cap(limit_bytes: Option<u64>) -> Result<(), String> {
+    crate::api::functions::data_usage::set_data_cap(limit_bytes)
+}
+
+/// The cellular soft cap and how much of it has been used
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_data_cap_status() -> Result<crate::api::functions::data_usage::DataCapStatus, String> {
+    crate::api::functions::data_usage::data_cap_status()
+}
+
+/// Whether background and speculative fetches should run right now
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn prefetch_allowed() -> bool {
+    crate::api::functions::data_usage::prefetch_allowed()
+}
+
+/// Whether lite mode is on
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_lite_mode() -> bool {
+    crate::api::functions::data_usage::lite_mode()
+}
+
+/// Turn lite mode on or off; in lite mode links are not prefetched and inline images are not fetched
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_lite_mode(enabled: bool) -> Result<(), String> {
+    crate::api::functions::data_usage::set_lite_mode(enabled)
+}
+
+/// The limit on bytes received per second over all connections, None when unlimited
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_bandwidth_limit() -> Option<u64> {
+    crate::api::transport::throttle::bandwidth_limit()
+}
+
+/// Limit how fast all connections together receive (at least 1024 bytes per second), or pass None for no limit; applies to connections opened afterwards
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_bandwidth_limit(bytes_per_sec: Option<u64>) -> Result<(), String> {
+    crate::api::transport::throttle::set_bandwidth_limit(bytes_per_sec)
+}
+
+/// Fetch and cache the links of the current page at low priority so following one is instant, returning how many were fetched; a later call drops this one's queued fetches
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn prefetch(urls: Vec<String>) -> Result<u32, String> {
+    crate::api::functions::prefetch::prefetch(urls).await
+}
+
+/// Connect to a Gemini link's server and complete the TLS handshake ahead of time, e.g. on hover or long-press, so following the link within ten seconds skips DNS, TCP, and TLS; other schemes are ignored
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn preconnect(url: String) -> Result<(), String> {
+    crate::api::transport::preconnect::preconnect(&url).await
+}
+
+/// Drop the link prefetches not yet started, e.g. when the user leaves the page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn cancel_prefetch() {
+    crate::api::functions::prefetch::cancel_prefetch()
+}
+
+/// Every server whose certificate is pinned, with its fingerprint and when it was first and last seen
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_known_hosts() -> Result<Vec<crate::api::storage::known_hosts::KnownHost>, String> {
+    crate::api::storage::known_hosts::list_known_hosts()
+}
+
+/// Export the trusted server certificates as Holloway's known_hosts file, Amfora's tofu.toml or Lagrange's trusted.2.txt
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn export_known_hosts(
+    format: crate::api::functions::known_hosts_transfer::KnownHostsFormat,
+) -> Result<String, String> {
+    crate::api::functions::known_hosts_transfer::export_known_hosts(format)
+}
+
+/// Import trusted server certificates from a known_hosts file, keeping hosts that are already pinned
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_known_hosts(
+    format: crate::api::functions::known_hosts_transfer::KnownHostsFormat,
+    data: String,
+) -> Result<crate::api::functions::known_hosts_transfer::KnownHostsImport, String> {
+    crate::api::functions::known_hosts_transfer::import_known_hosts(format, &data)
+}
+
+/// Forget the pinned certificate of a server so its next certificate is trusted (the exception for TLS_FPR_MISMATCH)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn forget_known_host(host: String, port: u16) -> Result<bool, String> {
+    crate::api::storage::known_hosts::forget(&host, port)
+}
+
+/// Trust the certificate a host presented instead of its pinned one (a "Certificate changed" error), e.g. a renewal; returns how many of its ports were re-pinned
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn accept_new_certificate(host: String) -> Result<u32, String> {
+    crate::api::functions::tofu::accept_new_certificate(&host)
+}
+
+/// End the private session once the last private tab closes, forgetting the certificates its navigations pinned in memory; returns how many were pinned
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn end_private_session() -> u32 {
+    crate::api::functions::tofu::end_private_session()
+}
+
+/// Why strict validation refused a host's certificate (a "Certificate rejected by the trust store" error): expired, self-signed, for another host, or an incomplete chain
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_certificate_failure(
+    host: String,
+) -> Option<crate::api::models::tls_failure::TlsFailure> {
+    crate::api::functions::tls_failures::certificate_failure(&host)
+}
+
+/// Add the CA certificates of a PEM file to the user trust store that hosts with the strict TLS policy are verified against
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_ca_certificates(
+    pem: String,
+) -> Result<Vec<crate::api::storage::trusted_cas::TrustedCa>, String> {
+    crate::api::functions::trust_store::import_ca_certificates(&pem)
+}
+
+/// Every certificate authority in the user trust store
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_trusted_cas() -> Result<Vec<crate::api::storage::trusted_cas::TrustedCa>, String> {
+    crate::api::storage::trusted_cas::list_trusted_cas()
+}
+
+/// Remove a certificate authority from the user trust store by its fingerprint, returning whether it was there
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_trusted_ca(fingerprint: String) -> Result<bool, String> {
+    crate::api::storage::trusted_cas::remove_trusted_ca(&fingerprint)
+}
+
+/// How many seconds outside its validity period a server certificate may appear before it is refused
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_clock_skew_tolerance() -> u64 {
+    crate::api::functions::clock_skew::clock_skew_tolerance()
+}
+
+/// Change the clock skew tolerance for devices with unreliable clocks; 0 refuses every outdated certificate
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_clock_skew_tolerance(secs: u64) {
+    crate::api::functions::clock_skew::set_clock_skew_tolerance(secs)
+}
+
+/// How far the device clock appears to be off, inferred from the certificates of the servers visited
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_clock_offset() -> crate::api::functions::clock_skew::ClockOffset {
+    crate::api::functions::clock_skew::clock_offset()
+}
+
+/// Pick a random page for "I'm feeling lucky" from weighted sources (directory pages and bookmarks), using cached directories when offline
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn random_destination(
+    sources: Vec<crate::api::functions::explore::ExploreSource>,
+) -> Result<crate::api::functions::explore::RandomDestination, String> {
+    crate::api::functions::explore::random_destination(sources).await
+}
+
+/// Bookmark a URL; if an equivalent URL is already bookmarked, that bookmark is returned marked as a duplicate instead
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn add_bookmark(
+    url: String,
+    title: Option<String>,
+) -> Result<crate::api::storage::bookmarks::AddedBookmark, String> {
+    crate::api::storage::bookmarks::add_bookmark(&url, title.as_deref())
+}
+
+/// The existing bookmark of a URL differing only in trailing slash, default port, or percent-encoding
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn find_duplicate_bookmark(
+    url: String,
+) -> Result<Option<crate::api::storage::bookmarks::Bookmark>, String> {
+    crate::api::storage::bookmarks::find_duplicate_bookmark(&url)
+}
+
+/// Change the title of a bookmark, e.g. when merging a duplicate into it
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_bookmark_title(id: i64, title: Option<String>) -> Result<bool, String> {
+    crate::api::storage::bookmarks::set_bookmark_title(id, title.as_deref())
+}
+
+/// Canonical form of an address as typed (scheme guessed, host lowercased, default port and fragment dropped, dot segments resolved), as history and the cache key pages
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn normalize_url(input: String) -> Result<String, String> {
+    crate::api::functions::canonical_url::normalize_url(&input)
+}
+
+/// Index of the open tab already showing the same page as url, so the UI can switch to it instead of opening another
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn find_duplicate_tab(url: String, open_tabs: Vec<String>) -> Option<u32> {
+    crate::api::functions::canonical_url::duplicate_tab(&url, &open_tabs)
+}
+
+/// Save the open tabs with their back/forward stacks and scroll positions under a name, replacing an earlier save; private tabs are left out. Save under "current" as tabs change to resume after a restart or crash
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn save_session(session: crate::api::storage::sessions::Session) -> Result<(), String> {
+    crate::api::storage::sessions::save_session(&session)
+}
+
+/// A saved session, e.g. "current" on startup to reopen the tabs the app had
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn load_session(
+    name: String,
+) -> Result<Option<crate::api::storage::sessions::Session>, String> {
+    crate::api::storage::sessions::load_session(&name)
+}
+
+/// Record the scroll position of a saved tab's current page without saving the whole session, returning whether the tab exists
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_session_scroll_offset(
+    name: String,
+    tab: u32,
+    scroll_offset: f64,
+) -> Result<bool, String> {
+    crate::api::storage::sessions::set_scroll_offset(&name, tab, scroll_offset)
+}
+
+/// Every saved session with its tab count, most recently saved first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_sessions() -> Result<Vec<crate::api::storage::sessions::SessionSummary>, String> {
+    crate::api::storage::sessions::list_sessions()
+}
+
+/// Delete a saved session, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_session(name: String) -> Result<bool, String> {
+    crate::api::storage::sessions::delete_session(&name)
+}
+
+/// Every bookmark, oldest first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_bookmarks() -> Result<Vec<crate::api::storage::bookmarks::Bookmark>, String> {
+    crate::api::storage::bookmarks::list_bookmarks()
+}
+
+/// Delete a bookmark, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_bookmark(id: i64) -> Result<bool, String> {
+    crate::api::storage::bookmarks::remove_bookmark(id)
+}
+
+/// Serialize every bookmark as XBEL, a gemtext page, or a Netscape HTML bookmarks file
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn export_bookmarks(
+    format: crate::api::functions::bookmark_export::BookmarkFormat,
+) -> Result<String, String> {
+    crate::api::functions::bookmark_export::export_bookmarks(format)
+}
+
+/// Write every bookmark to a file in one of the export formats, returning how many were written
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn export_bookmarks_to_file(
+    format: crate::api::functions::bookmark_export::BookmarkFormat,
+    path: String,
+) -> Result<u32, String> {
+    crate::api::functions::bookmark_export::export_bookmarks_to_file(format, &path)
+}
+
+/// Import bookmarks from Amfora's bookmarks.xml or Lagrange's bookmarks.ini, keeping URLs that are already bookmarked
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_bookmarks(
+    source: crate::api::functions::bookmark_import::BookmarkSource,
+    data: String,
+) -> Result<crate::api::functions::bookmark_import::BookmarksImport, String> {
+    crate::api::functions::bookmark_import::import_bookmarks(source, &data)
+}
+
+/// Subscribe to an Atom, RSS, or gemfeed URL, e.g. one of a page's discovered feeds; an existing subscription of the URL is returned as is
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn subscribe_feed(
+    url: String,
+    title: Option<String>,
+    kind: crate::api::functions::feed_discovery::FeedKind,
+) -> Result<crate::api::storage::feeds::Feed, String> {
+    crate::api::storage::feeds::subscribe(&url, title.as_deref(), kind)
+}
+
+/// Drop a feed subscription and its entries, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unsubscribe_feed(id: i64) -> Result<bool, String> {
+    crate::api::storage::feeds::unsubscribe(id)
+}
+
+/// Every feed subscription with its unread count, by title
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_feeds() -> Result<Vec<crate::api::storage::feeds::Feed>, String> {
+    crate::api::storage::feeds::list_feeds()
+}
+
+/// Fetch one feed and store its new entries, returning how many there were
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn refresh_feed(id: i64) -> Result<u32, String> {
+    crate::api::functions::feeds::refresh_feed(id).await
+}
+
+/// Fetch every feed, returning how many new entries they had; failures are kept on each feed as last_error
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn refresh_all_feeds() -> Result<u32, String> {
+    crate::api::functions::feeds::refresh_all_feeds().await
+}
+
+/// A page of the merged timeline of every feed, or of one, newest first, for the Feeds tab
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_feed_entries(
+    feed_id: Option<i64>,
+    unread_only: bool,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::feeds::FeedEntry>, String> {
+    crate::api::storage::feeds::list_entries(feed_id, unread_only, offset, limit)
+}
+
+/// Mark a feed entry read or unread, returning whether it exists
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_feed_entry_read(id: i64, read: bool) -> Result<bool, String> {
+    crate::api::storage::feeds::set_entry_read(id, read)
+}
+
+/// Mark every entry of a feed, or of all feeds when feed_id is None, read
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn mark_all_feed_entries_read(feed_id: Option<i64>) -> Result<u32, String> {
+    crate::api::storage::feeds::mark_all_read(feed_id)
+}
+
+/// Number of unread feed entries, for the Feeds tab badge
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unread_feed_count() -> Result<u32, String> {
+    crate::api::storage::feeds::unread_count()
+}
+
+/// Watch a page for changes, e.g. a capsule without a feed; returns the existing watch of the same page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn watch_page(
+    url: String,
+    title: Option<String>,
+) -> Result<crate::api::storage::watches::WatchedPage, String> {
+    crate::api::storage::watches::watch_page(&url, title.as_deref())
+}
+
+/// Stop watching a page, returning whether it was watched
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unwatch_page(id: i64) -> Result<bool, String> {
+    crate::api::storage::watches::unwatch_page(id)
+}
+
+/// Every watched page, changed ones first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_watched_pages() -> Result<Vec<crate::api::storage::watches::WatchedPage>, String> {
+    crate::api::storage::watches::list_watched_pages()
+}
+
+/// Refetch watched pages not checked in the last 15 minutes and return those changed since they were last visited
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn check_for_updates() -> Result<Vec<crate::api::storage::watches::WatchedPage>, String> {
+    crate::api::functions::watches::check_for_updates().await
+}
+
+/// Watch a finger address, `user@host` or a finger:// URL, for changes to its .plan and .project; returns the existing watch of the same address
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn watch_plan(
+    address: String,
+) -> Result<crate::api::storage::plan_watches::WatchedPlan, String> {
+    crate::api::storage::plan_watches::watch_plan(&address)
+}
+
+/// Stop watching a finger address, returning whether it was watched
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unwatch_plan(id: i64) -> Result<bool, String> {
+    crate::api::storage::plan_watches::unwatch_plan(id)
+}
+
+/// Every watched finger address with its last answer, updated ones first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_watched_plans() -> Result<Vec<crate::api::storage::plan_watches::WatchedPlan>, String> {
+    crate::api::storage::plan_watches::list_watched_plans()
+}
+
+/// Mark the .plan update of a watched finger address as read
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn mark_plan_read(id: i64) -> Result<(), String> {
+    crate::api::storage::plan_watches::mark_plan_read(id)
+}
+
+/// Finger watched addresses not checked in the last 15 minutes and return those whose plans changed since they were last read
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn check_plans() -> Result<Vec<crate::api::storage::plan_watches::WatchedPlan>, String> {
+    crate::api::functions::plan_watches::check_plans().await
+}
+
+/// Refetch a page and return the lines added and removed since its cached copy
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn diff_with_cache(
+    url: String,
+) -> Result<crate::api::functions::page_diff::PageDiff, String> {
+    crate::api::functions::page_diff::diff_with_cache(&url).await
+}
+
+/// Bookmarked hosts whose pages permanently redirect (31) to another host, for offering a migration
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn suggested_host_migrations(
+) -> Result<Vec<crate::api::functions::host_migration::HostMigration>, String> {
+    crate::api::functions::host_migration::suggested_migrations()
+}
+
+/// Rewrite the bookmarks on a host that moved to its new host
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn migrate_host(
+    scheme: String,
+    from_host: String,
+    to_host: String,
+) -> Result<crate::api::functions::host_migration::HostMigrationResult, String> {
+    crate::api::functions::host_migration::migrate_host(&scheme, &from_host, &to_host)
+}
+
+/// Stop suggesting a migration away from a host
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn dismiss_host_migration(scheme: String, from_host: String) -> Result<bool, String> {
+    crate::api::functions::host_migration::dismiss_migration(&scheme, &from_host)
+}
+
+/// Refresh the cached copies of bookmarked pages older than max_age_secs now, returning how many were refetched
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn refresh_bookmarks(max_age_secs: u32) -> Result<u32, String> {
+    crate::api::functions::cache_refresh::refresh_bookmarks(max_age_secs).await
+}
+
+/// Refresh bookmarked pages in the background every interval_secs while on Wi-Fi or Ethernet, for offline reading
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn start_bookmark_refresh(interval_secs: u32, max_age_secs: u32) -> Result<(), String> {
+    crate::api::functions::cache_refresh::start_bookmark_refresh(interval_secs, max_age_secs)
+}
+
+/// Stop the background bookmark refresh
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn stop_bookmark_refresh() {
+    crate::api::functions::cache_refresh::stop_bookmark_refresh()
+}
+
+/// The schedule of every background task (feed refreshes, page and plan watches, pinned certificate expiry scans)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_schedules() -> Vec<crate::api::functions::scheduler::TaskSchedule> {
+    crate::api::functions::scheduler::schedules()
+}
+
+/// Change a background task's interval, jitter, and network and battery constraints
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_schedule(
+    schedule: crate::api::functions::scheduler::TaskSchedule,
+) -> Result<(), String> {
+    crate::api::functions::scheduler::set_schedule(schedule)
+}
+
+/// Tell the core whether the device is charging, on battery, or low on battery, for the scheduler's constraints
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_power_state(state: crate::api::functions::scheduler::PowerState) {
+    crate::api::functions::scheduler::set_power_state(state)
+}
+
+/// Run the background tasks that are due and allowed now, e.g. from the platform's background fetch, and return what happened to them
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn scheduler_tick() -> Vec<crate::api::functions::scheduler::SchedulerEvent> {
+    crate::api::functions::scheduler::tick().await
+}
+
+/// Tick the scheduler every tick_secs on a background thread while the app runs
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn start_scheduler(tick_secs: u32) -> Result<(), String> {
+    crate::api::functions::scheduler::start_scheduler(tick_secs)
+}
+
+/// Stop the scheduler's background thread
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn stop_scheduler() {
+    crate::api::functions::scheduler::stop_scheduler()
+}
+
+/// Subscribe to the events of background tasks: started, completed with what they found, skipped, or failed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn subscribe_scheduler_events() -> u64 {
+    crate::api::functions::scheduler::subscribe()
+}
+
+/// Wait for the next background task event of a subscription
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_scheduler_event(
+    subscription: u64,
+) -> Result<crate::api::functions::scheduler::SchedulerEvent, String> {
+    crate::api::functions::scheduler::next_event(subscription).await
+}
+
+/// End a background task event subscription, returning whether it existed
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unsubscribe_scheduler_events(subscription: u64) -> bool {
+    crate::api::functions::scheduler::unsubscribe(subscription)
+}
+
+/// Recorded downloads, newest first; pass a status to show only completed or failed ones
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_downloads(
+    status: Option<crate::api::storage::downloads::DownloadStatus>,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::downloads::DownloadRecord>, String> {
+    crate::api::storage::downloads::list_downloads(status, limit)
+}
+
+/// Remove one entry from the download history without deleting the file
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_download(id: i64) -> Result<bool, String> {
+    crate::api::storage::downloads::delete_download(id)
+}
+
+/// Keep a permanent copy of a page (the on-screen copy when it is cached) with its metadata and fetch time
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn archive_page(
+    url: String,
+) -> Result<crate::api::storage::archive::ArchivedPage, String> {
+    crate::api::functions::archive::archive_page(&url).await
+}
+
+/// Archived page snapshots, newest first; pass a URL to list only the snapshots of that page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_archived(
+    url: Option<String>,
+    limit: u32,
+) -> Result<Vec<crate::api::storage::archive::ArchivedPage>, String> {
+    crate::api::storage::archive::list_archived(url.as_deref(), limit)
+}
+
+/// Reopen an archived snapshot as a response served from the cache
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_archived(id: i64) -> Result<crate::api::models::fetch_response::FetchResponse, String> {
+    crate::api::functions::archive::open_archived(id)
+}
+
+/// Delete an archived snapshot
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_archived(id: i64) -> Result<bool, String> {
+    crate::api::storage::archive::delete_archived(id)
+}
+
+/// Save a page to read later and fetch a copy of it right away for reading offline; a failed fetch leaves it queued with the error
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn save_for_later(
+    url: String,
+    title: Option<String>,
+) -> Result<crate::api::storage::read_later::ReadLaterItem, String> {
+    crate::api::functions::read_later::save_for_later(&url, title.as_deref()).await
+}
+
+/// Fetch the read-later pages that have no copy yet as background requests; returns how many were saved
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn cache_read_later() -> Result<u32, String> {
+    crate::api::functions::read_later::cache_read_later().await
+}
+
+/// The read-later queue with titles and whether each page is cached, unread first and newest first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_read_later(
+    include_read: bool,
+) -> Result<Vec<crate::api::storage::read_later::ReadLaterItem>, String> {
+    crate::api::storage::read_later::list_read_later(include_read)
+}
+
+/// Open the saved copy of a read-later page as a response served from the cache
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn open_read_later(
+    id: i64,
+) -> Result<crate::api::models::fetch_response::FetchResponse, String> {
+    crate::api::functions::read_later::open_read_later(id)
+}
+
+/// Mark a read-later page read, or unread again
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn mark_read_later(id: i64, read: bool) -> Result<bool, String> {
+    crate::api::storage::read_later::mark_read_later(id, read)
+}
+
+/// Remove a page and its saved copy from the read-later queue
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_read_later(id: i64) -> Result<bool, String> {
+    crate::api::storage::read_later::remove_read_later(id)
+}
+
+/// List the stored client certificate identities
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_identities() -> Result<Vec<crate::api::storage::identities::Identity>, String> {
+    crate::api::storage::identities::list_identities()
+}
+
+/// Create an identity with a new key pair and a self-signed certificate for common_name, valid for valid_days; returns its id
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn create_identity(common_name: String, valid_days: u32) -> Result<String, String> {
+    crate::api::functions::identities::create_identity(&common_name, valid_days)
+}
+
+/// Issue a fresh certificate for an identity, keeping its key unless rotate_key is set
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn renew_identity(
+    id: String,
+    rotate_key: bool,
+    valid_days: Option<u32>,
+) -> Result<crate::api::storage::identities::Identity, String> {
+    crate::api::functions::identities::renew_identity(&id, rotate_key, valid_days)
+}
+
+/// Import a client certificate and PKCS#8 key from PEM; the key may also follow the certificate in certificate_pem
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_identity_pem(
+    name: Option<String>,
+    certificate_pem: String,
+    private_key_pem: Option<String>,
+) -> Result<crate::api::storage::identities::Identity, String> {
+    crate::api::functions::identity_transfer::import_pem(
+        name,
+        &certificate_pem,
+        private_key_pem.as_deref(),
+    )
+}
+
+/// Import a client certificate from a password-protected .p12/.pfx archive
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_identity_pkcs12(
+    name: Option<String>,
+    data: Vec<u8>,
+    password: String,
+) -> Result<crate::api::storage::identities::Identity, String> {
+    crate::api::functions::identity_transfer::import_pkcs12(name, &data, &password)
+}
+
+/// Import every identity of Lagrange's idents directory, skipping those already imported
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn import_lagrange_identities(
+    dir: String,
+) -> Result<crate::api::functions::identity_transfer::IdentitiesImport, String> {
+    crate::api::functions::identity_transfer::import_lagrange_identities(&dir)
+}
+
+/// Export an identity as PEM (certificate followed by its unencrypted key)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn export_identity_pem(id: String) -> Result<String, String> {
+    crate::api::functions::identity_transfer::export_pem(&id)
+}
+
+/// Export an identity as a password-protected PKCS#12 archive
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn export_identity_pkcs12(id: String, password: String) -> Result<Vec<u8>, String> {
+    crate::api::functions::identity_transfer::export_pkcs12(&id, &password)
+}
+
+/// Present an identity on every request to url and the URLs under it until it is deactivated, as when a page asks for a certificate
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn activate_identity(
+    identity_id: String,
+    url: String,
+) -> Result<crate::api::storage::identity_scopes::IdentityScope, String> {
+    crate::api::functions::identities::activate_identity(&identity_id, &url)
+}
+
+/// Stop presenting the identity activated for exactly this URL; returns whether one was
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn deactivate_identity(url: String) -> Result<bool, String> {
+    crate::api::functions::identities::deactivate_identity(&url)
+}
+
+/// Every URL prefix an identity is activated for
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_identity_scopes(
+) -> Result<Vec<crate::api::storage::identity_scopes::IdentityScope>, String> {
+    crate::api::storage::identity_scopes::list_identity_scopes()
+}
+
+/// The activation a URL falls under, e.g. to show which identity the current page is seen with
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn active_identity(
+    url: String,
+) -> Result<Option<crate::api::storage::identity_scopes::IdentityScope>, String> {
+    crate::api::functions::identities::active_identity(&url)
+}
+
+/// Identities whose certificates expire within the given number of days
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn expiring_identities(
+    within_days: u32,
+) -> Result<Vec<crate::api::functions::identities::ExpiryReminder>, String> {
+    crate::api::functions::identities::expiring_identities(within_days)
+}
+
+/// Scan identities for upcoming expiry now and then periodically (0 scans once); call after open_profile
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn start_expiry_checks(within_days: u32, interval_secs: u32) {
+    crate::api::functions::identities::start_expiry_checks(within_days, interval_secs)
+}
+
+/// Stop the periodic identity expiry scans
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn stop_expiry_checks() {
+    crate::api::functions::identities::stop_expiry_checks()
+}
+
+/// Wait for the next identity expiry reminder from the periodic check
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn next_expiry_reminder() -> crate::api::functions::identities::ExpiryReminder {
+    crate::api::functions::identities::next_expiry_reminder().await
+}
+
+/// A new random storage key for sealing identity private keys; keep it in the platform's secure storage (Android Keystore, iOS Keychain, Secret Service)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn generate_storage_key() -> Result<Vec<u8>, String> {
+    crate::api::storage::key_storage::generate_storage_key()
+}
+
+/// Seal the profile's identity private keys at rest with the storage key from secure storage; call after open_profile. Returns how many plain keys were sealed now
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unlock_key_storage(storage_key: Vec<u8>) -> Result<u32, String> {
+    crate::api::storage::key_storage::unlock(&storage_key)
+}
+
+/// Unlock key storage with the profile's storage key from the platform keyring (Keychain, Windows Credential Manager, or the Secret Service), generating and saving one the first time; needs the `keyring` feature. Returns how many plain keys were sealed now
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn unlock_key_storage_from_keyring(profile: String) -> Result<u32, String> {
+    #[cfg(feature = "keyring")]
+    return crate::api::storage::key_storage::unlock_from_keyring(&profile);
+    #[cfg(not(feature = "keyring"))]
+    Err(format!(
+        "No keyring support in this build to unlock {}; pass the storage key instead",
+        profile
+    ))
+}
+
+/// Forget the storage key; sealed identities cannot be used until key storage is unlocked again
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn lock_key_storage() {
+    crate::api::storage::key_storage::lock()
+}
+
+/// Whether a storage key is set for the open profile
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn is_key_storage_unlocked() -> bool {
+    crate::api::storage::key_storage::is_unlocked()
+}
+
+/// Upload data to a titan:// (or gemini://) URL with its mime and token parameters, using the host's stored token and identity unless others are given
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn titan_upload(
+    url: String,
+    data: Vec<u8>,
+    mime: Option<String>,
+    token: Option<String>,
+    identity_id: Option<String>,
+) -> Result<
+    crate::api::models::fetch_response::FetchResponse,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::protocols::titan::upload(&url, data, mime, token, identity_id).await
+}
+
+/// Store the Titan token of a host so uploads to it need not ask for it again
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_titan_token(host: String, token: String) -> Result<(), String> {
+    crate::api::storage::titan_tokens::set_titan_token(&host, &token)
+}
+
+/// Forget the stored Titan token of a host
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn remove_titan_token(host: String) -> Result<bool, String> {
+    crate::api::storage::titan_tokens::remove_titan_token(&host)
+}
+
+/// List the hosts with a stored Titan token, without the tokens
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn titan_token_hosts() -> Result<Vec<String>, String> {
+    crate::api::storage::titan_tokens::titan_token_hosts()
+}
+
+/// Get the browser-wide configuration: default scheme, start page, search provider, timeouts, TLS policy and proxy
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_config() -> crate::api::functions::config::Config {
+    crate::api::functions::config::get_config()
+}
+
+/// Validate, save and apply a whole configuration; returns it as stored
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_config(
+    config: crate::api::functions::config::Config,
+) -> Result<crate::api::functions::config::Config, String> {
+    crate::api::functions::config::set_config(config)
+}
+
+/// Get the start page URL: the user's choice (possibly about:home) or the default
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
 pub fn get_start_page() -> String {
-    "gemini://kennedy.gemi.dev/".to_string()
+    crate::api::functions::start_page::start_page()
+}
+
+/// Change the start page (a URL or about:home), or restore the default with None; returns the new start page
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_start_page(url: Option<String>) -> Result<String, String> {
+    crate::api::functions::start_page::set_start_page(url.as_deref())
+}
+
+/// Navigate to the results of a query at the configured search provider
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn search(
+    query: String,
+) -> Result<
+    crate::api::models::navigation_result::NavigationResult,
+    crate::api::models::navigate_error::NavigateError,
+> {
+    crate::api::functions::search::search(&query).await
+}
+
+/// Query every offered search engine at once and return their hits merged, deduplicated by URL and ranked
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb)]
+pub async fn search_all(
+    query: String,
+) -> Result<crate::api::functions::metasearch::MetasearchResult, String> {
+    crate::api::functions::metasearch::search_all(&query).await
+}
+
+/// The search engines offered in settings, the default first
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn search_providers() -> Vec<crate::api::functions::search::SearchProvider> {
+    crate::api::functions::search::search_providers()
+}
+
+/// Get the search URL template, with %s where the query goes
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_search_provider() -> String {
+    crate::api::functions::search::search_provider()
+}
+
+/// Change the search URL template (it must contain %s), or restore the default with None; returns the new template
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_search_provider(url_template: Option<String>) -> Result<String, String> {
+    crate::api::functions::search::set_search_provider(url_template.as_deref())
+}
+
+/// Whether a Gemini address that fails to load is searched for instead of returning its error (off by default)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_search_on_failure() -> bool {
+    crate::api::functions::search::search_on_failure()
+}
+
+/// Turn searching for Gemini addresses that fail to load on or off; input that is not an address is always searched for
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_search_on_failure(enabled: bool) -> Result<(), String> {
+    crate::api::functions::search::set_search_on_failure(enabled)
+}
+
+/// Whether answers to Gemini input prompts are saved to be offered again (off by default)
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_remember_input_answers() -> bool {
+    crate::api::functions::input_answers::remember_input_answers()
+}
+
+/// Turn saving answers to input prompts on, or off forgetting those saved; answers to sensitive (status 11) prompts are never saved
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn set_remember_input_answers(enabled: bool) -> Result<(), String> {
+    crate::api::functions::input_answers::set_remember_input_answers(enabled)
+}
+
+/// Answers given before to the prompt a URL asks, most recently given first, to offer as autofill
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn get_saved_answers(
+    url: String,
+    prompt: String,
+) -> Result<Vec<crate::api::storage::input_answers::SavedAnswer>, String> {
+    crate::api::functions::input_answers::saved_answers(&url, &prompt)
+}
+
+/// Every saved answer to an input prompt, by URL and prompt
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn list_saved_answers() -> Result<Vec<crate::api::storage::input_answers::SavedAnswer>, String>
+{
+    crate::api::storage::input_answers::list_saved_answers()
 }
 
+/// Forget one saved answer, returning whether it was saved
+#[cfg_attr(feature = "flutter", flutter_rust_bridge::frb(sync))]
+pub fn delete_saved_answer(id: i64) -> Result<bool, String> {
+    crate::api::storage::input_answers::delete_saved_answer(id)
+}
@@ -1,2 +0,0 @@
-pub mod navigate_internal;
-
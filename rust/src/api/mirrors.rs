@@ -0,0 +1,127 @@
+//! Definitions of holloway-core enums for the binding generator, which cannot see the fields of
+//! another crate's enum variants; each must match the enum it mirrors variant for variant
+
+use flutter_rust_bridge::frb;
+
+use crate::api::models::certificate_error::CertificateError;
+use crate::api::models::server_failure::ServerFailure;
+use crate::api::transport::proxy::{ProxyRule, Socks5Proxy};
+
+// The generated code names each mirrored enum by the module of its mirror
+pub use crate::api::functions::ansi::AnsiColor;
+pub use crate::api::models::navigate_error::NavigateError;
+pub use crate::api::parsers::gemtext::GemtextLine;
+pub use crate::api::transport::proxy::ProxySettings;
+
+/// Why a navigation did not produce a page, for tailored error screens; each variant keeps
+/// the English message (or the server's meta line) for display
+#[frb(mirror(NavigateError))]
+pub enum _NavigateError {
+    /// The input is not a usable URL
+    InvalidUrl(String),
+    /// No protocol handler serves the scheme
+    UnsupportedScheme(String),
+    /// Refused locally: the user turned the protocol off
+    SchemeDisabled(String),
+    /// Refused locally: the URL matches a blocklist rule
+    Blocked(String),
+    /// Refused locally: the host's robots.txt disallows a fetch the user did not ask for
+    Disallowed(String),
+    /// Offline mode is on and the page has no cached copy
+    Offline(String),
+    DnsFailure(String),
+    ConnectTimeout(String),
+    /// The host refused the connection
+    ConnectionRefused(String),
+    /// The host could not be reached
+    ConnectionFailed(String),
+    /// The connection broke while sending the request or reading the response
+    ConnectionLost(String),
+    /// The server stopped sending for longer than the read timeout, or the request outlasted
+    /// its deadline
+    ReadTimeout(String),
+    /// A Gemini server stopped sending for longer than the read timeout
+    GeminiTimeout(String),
+    /// The SOCKS5 proxy could not be reached
+    ProxyUnreachable(String),
+    /// The SOCKS5 proxy refused the credentials, or needs some
+    ProxyAuthFailed(String),
+    /// The SOCKS5 proxy failed to connect to the host, or does not speak SOCKS5
+    ProxyError(String),
+    /// The WebSocket gateway failed
+    GatewayError(String),
+    /// The TLS handshake failed
+    TlsError(String),
+    /// The server's certificate changed from the pinned one
+    FingerprintMismatch(String),
+    /// The server's certificate is expired or not yet valid, past the clock skew tolerance
+    CertificateDate(String),
+    /// Strict validation refused the server's certificate; `certificate_failure` tells why
+    CertificateRejected(String),
+    /// The server answered something that is not valid for its protocol
+    BadResponse(String),
+    /// The response outgrew the body limit or the decompression limits
+    TooLarge(String),
+    /// Something the request named does not exist, e.g. a local file or a newsgroup
+    NotFound(String),
+    /// The request cannot be sent as it is, e.g. a Finger query spanning lines
+    InvalidInput(String),
+    /// The Gopher item type names no document to fetch, e.g. a telnet session or an info line
+    GopherBadType(String),
+    /// The Gopher selector spans lines, which would send the server more than one request
+    GopherBadSelector(String),
+    /// The profile database failed
+    StorageError(String),
+    /// The identity to present could not be used, e.g. while the key store is locked
+    IdentityError(String),
+    /// The caller cancelled the navigation
+    Cancelled(String),
+    /// Gemini 3x: the page moved
+    Redirected {
+        target: String,
+        permanent: bool,
+    },
+    /// Gemini 1x: the server asks for input; sensitive input (11) should be masked
+    InputRequired {
+        prompt: String,
+        sensitive: bool,
+    },
+    /// Gemini 6x: a client certificate is needed or was refused
+    CertificateRequired(CertificateError),
+    /// Gemini 4x and 5x: the server could not serve the page, for now or for good
+    ServerError(ServerFailure),
+    Other(String),
+}
+
+/// A single parsed line of a text/gemini document
+#[frb(mirror(GemtextLine))]
+pub enum _GemtextLine {
+    Text(String),
+    Link { url: String, label: Option<String> },
+    Heading { level: u8, text: String },
+    ListItem(String),
+    Quote(String),
+    PreformatToggle { alt: Option<String> },
+    Preformatted(String),
+}
+
+/// A terminal color: an entry of the 256-color palette (0-7 standard, 8-15 bright) or 24-bit RGB
+#[frb(mirror(AnsiColor))]
+pub enum _AnsiColor {
+    Indexed(u8),
+    Rgb { r: u8, g: u8, b: u8 },
+}
+
+/// How connections reach remote hosts
+#[frb(mirror(ProxySettings))]
+pub enum _ProxySettings {
+    /// Connect directly
+    Direct,
+    /// Send every connection through one proxy
+    Socks5 { proxy: Socks5Proxy },
+    /// Use the first matching rule, then the fallback (direct when None)
+    PerRule {
+        rules: Vec<ProxyRule>,
+        fallback: Option<Socks5Proxy>,
+    },
+}
@@ -1,3 +1,6 @@
 pub mod exposed_functions;
-pub mod functions;
-pub mod protocols;
+#[cfg(feature = "flutter")]
+pub mod mirrors;
+
+// The engine lives in holloway-core; its modules keep their paths here for the bindings
+pub use holloway_core::api::{functions, models, parsers, protocols, storage, transport};
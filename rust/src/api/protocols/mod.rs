@@ -1,4 +0,0 @@
-pub mod gemini;
-pub mod gopher;
-pub mod finger;
-
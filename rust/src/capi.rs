@@ -0,0 +1,353 @@
+//! Stable C ABI over the browser engine for non-Flutter front-ends (GTK, TUI, ...)
+//!
+//! Every function is blocking and safe to call from any thread. Strings passed in must be
+//! NUL-terminated UTF-8; strings handed out are owned by the object they came from unless
+//! documented otherwise, and must be released with the matching `*_free` function.
+//! The matching declarations live in `include/holloway.h`.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use std::sync::LazyLock;
+
+use crate::api::models::fetch_response::FetchResponse;
+use crate::api::parsers::gemtext::{parse_gemtext, GemtextLine};
+
+/// Runtime driving the async engine for blocking C callers
+static RUNTIME: LazyLock<tokio::runtime::Runtime> =
+    LazyLock::new(|| tokio::runtime::Runtime::new().expect("tokio runtime"));
+
+/// A fetched page with its strings kept alive for the caller
+pub struct HollowayResponse {
+    url: CString,
+    mime: CString,
+    meta: Option<CString>,
+    status: Option<u8>,
+    body: CString,
+    body_len: usize,
+}
+
+/// A parsed gemtext document
+pub struct HollowayDocument {
+    lines: Vec<(c_int, CString, Option<CString>)>,
+}
+
+/// Line kinds returned by `holloway_document_line_kind`
+pub const HOLLOWAY_LINE_TEXT: c_int = 0;
+pub const HOLLOWAY_LINE_LINK: c_int = 1;
+pub const HOLLOWAY_LINE_HEADING_1: c_int = 2;
+pub const HOLLOWAY_LINE_HEADING_2: c_int = 3;
+pub const HOLLOWAY_LINE_HEADING_3: c_int = 4;
+pub const HOLLOWAY_LINE_LIST_ITEM: c_int = 5;
+pub const HOLLOWAY_LINE_QUOTE: c_int = 6;
+pub const HOLLOWAY_LINE_PREFORMAT_TOGGLE: c_int = 7;
+pub const HOLLOWAY_LINE_PREFORMATTED: c_int = 8;
+
+/// Convert text to a C string, dropping interior NULs rather than failing
+fn to_c_string(text: String) -> CString {
+    CString::new(text).unwrap_or_else(|e| {
+        let mut bytes = e.into_vec();
+        bytes.retain(|b| *b != 0);
+        CString::new(bytes).unwrap_or_default()
+    })
+}
+
+/// Read a caller-provided string, reporting NULL and invalid UTF-8 as errors
+unsafe fn from_c_str<'a>(value: *const c_char) -> Result<&'a str, String> {
+    if value.is_null() {
+        return Err("NULL string argument".to_string());
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| "String argument is not valid UTF-8".to_string())
+}
+
+/// Store an error message in `error_out` when the caller asked for one
+unsafe fn set_error(error_out: *mut *mut c_char, message: String) {
+    if !error_out.is_null() {
+        *error_out = to_c_string(message).into_raw();
+    }
+}
+
+/// Fetch a URL. Returns NULL on failure and stores a message in `error_out` (if non-NULL),
+/// which must be released with `holloway_string_free`.
+///
+/// # Safety
+/// `url` must be a valid NUL-terminated string and `error_out` NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_fetch(
+    url: *const c_char,
+    error_out: *mut *mut c_char,
+) -> *mut HollowayResponse {
+    let url = match from_c_str(url) {
+        Ok(url) => url.to_string(),
+        Err(e) => {
+            set_error(error_out, e);
+            return ptr::null_mut();
+        }
+    };
+
+    let result: Result<FetchResponse, String> = RUNTIME.block_on(
+        crate::api::functions::navigate_internal::fetch_internal(url),
+    );
+    match result {
+        Ok(response) => {
+            let body_len = response.body.len();
+            Box::into_raw(Box::new(HollowayResponse {
+                url: to_c_string(response.url),
+                mime: to_c_string(response.mime),
+                meta: response.meta.map(to_c_string),
+                status: response.status,
+                body: to_c_string(response.body),
+                body_len,
+            }))
+        }
+        Err(e) => {
+            set_error(error_out, e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Final URL of the response.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_url(response: *const HollowayResponse) -> *const c_char {
+    (*response).url.as_ptr()
+}
+
+/// MIME type of the body.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_mime(
+    response: *const HollowayResponse,
+) -> *const c_char {
+    (*response).mime.as_ptr()
+}
+
+/// Status code, or -1 for protocols without one.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_status(response: *const HollowayResponse) -> c_int {
+    (*response).status.map(c_int::from).unwrap_or(-1)
+}
+
+/// Meta line accompanying the status, or NULL.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_meta(
+    response: *const HollowayResponse,
+) -> *const c_char {
+    match &(*response).meta {
+        Some(meta) => meta.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Body text; its length in bytes is stored in `len_out` if non-NULL.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` and not yet be freed; `len_out` NULL or valid.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_body(
+    response: *const HollowayResponse,
+    len_out: *mut usize,
+) -> *const c_char {
+    if !len_out.is_null() {
+        *len_out = (*response).body_len;
+    }
+    (*response).body.as_ptr()
+}
+
+/// Release a response.
+///
+/// # Safety
+/// `response` must come from `holloway_fetch` (or be NULL) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_response_free(response: *mut HollowayResponse) {
+    if !response.is_null() {
+        drop(Box::from_raw(response));
+    }
+}
+
+/// Parse a gemtext body into a document.
+///
+/// # Safety
+/// `body` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_gemtext_parse(body: *const c_char) -> *mut HollowayDocument {
+    let body = match from_c_str(body) {
+        Ok(body) => body,
+        Err(_) => return ptr::null_mut(),
+    };
+
+    let lines = parse_gemtext(body)
+        .into_iter()
+        .map(|line| match line {
+            GemtextLine::Text(text) => (HOLLOWAY_LINE_TEXT, to_c_string(text), None),
+            GemtextLine::Link { url, label } => (
+                HOLLOWAY_LINE_LINK,
+                to_c_string(label.unwrap_or_else(|| url.clone())),
+                Some(to_c_string(url)),
+            ),
+            GemtextLine::Heading { level, text } => (
+                HOLLOWAY_LINE_HEADING_1 + c_int::from(level.clamp(1, 3)) - 1,
+                to_c_string(text),
+                None,
+            ),
+            GemtextLine::ListItem(text) => (HOLLOWAY_LINE_LIST_ITEM, to_c_string(text), None),
+            GemtextLine::Quote(text) => (HOLLOWAY_LINE_QUOTE, to_c_string(text), None),
+            GemtextLine::PreformatToggle { alt } => (
+                HOLLOWAY_LINE_PREFORMAT_TOGGLE,
+                to_c_string(alt.unwrap_or_default()),
+                None,
+            ),
+            GemtextLine::Preformatted(text) => {
+                (HOLLOWAY_LINE_PREFORMATTED, to_c_string(text), None)
+            }
+        })
+        .collect();
+
+    Box::into_raw(Box::new(HollowayDocument { lines }))
+}
+
+/// Number of lines in a document.
+///
+/// # Safety
+/// `document` must come from `holloway_gemtext_parse` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_document_len(document: *const HollowayDocument) -> usize {
+    (*document).lines.len()
+}
+
+/// Kind of a line (one of the HOLLOWAY_LINE_* constants), or -1 when out of range.
+///
+/// # Safety
+/// `document` must come from `holloway_gemtext_parse` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_document_line_kind(
+    document: *const HollowayDocument,
+    index: usize,
+) -> c_int {
+    let document = &*document;
+    document.lines.get(index).map(|line| line.0).unwrap_or(-1)
+}
+
+/// Text of a line (the label for links, alt text for toggles), or NULL when out of range.
+///
+/// # Safety
+/// `document` must come from `holloway_gemtext_parse` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_document_line_text(
+    document: *const HollowayDocument,
+    index: usize,
+) -> *const c_char {
+    let document = &*document;
+    match document.lines.get(index) {
+        Some(line) => line.1.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Target URL of a link line, or NULL for other lines.
+///
+/// # Safety
+/// `document` must come from `holloway_gemtext_parse` and not yet be freed.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_document_line_url(
+    document: *const HollowayDocument,
+    index: usize,
+) -> *const c_char {
+    let document = &*document;
+    match document.lines.get(index).and_then(|line| line.2.as_ref()) {
+        Some(url) => url.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Release a document.
+///
+/// # Safety
+/// `document` must come from `holloway_gemtext_parse` (or be NULL) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_document_free(document: *mut HollowayDocument) {
+    if !document.is_null() {
+        drop(Box::from_raw(document));
+    }
+}
+
+/// Open the profile database in a directory. Returns 0 on success, -1 on failure.
+///
+/// # Safety
+/// `dir` must be a valid NUL-terminated string and `error_out` NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_open_profile(
+    dir: *const c_char,
+    error_out: *mut *mut c_char,
+) -> c_int {
+    let result = from_c_str(dir).and_then(|dir| crate::api::storage::open_profile(dir.to_string()));
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            set_error(error_out, e);
+            -1
+        }
+    }
+}
+
+/// Add a blocklist pattern. Returns 0 on success, -1 on failure.
+///
+/// # Safety
+/// `pattern` must be a valid NUL-terminated string and `error_out` NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_block(
+    pattern: *const c_char,
+    error_out: *mut *mut c_char,
+) -> c_int {
+    let result = from_c_str(pattern)
+        .and_then(crate::api::storage::blocklist::add_rule)
+        .map(|_| ());
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            set_error(error_out, e);
+            -1
+        }
+    }
+}
+
+/// Remove a blocklist pattern. Returns 1 if it existed, 0 if not, -1 on failure.
+///
+/// # Safety
+/// `pattern` must be a valid NUL-terminated string and `error_out` NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_unblock(
+    pattern: *const c_char,
+    error_out: *mut *mut c_char,
+) -> c_int {
+    match from_c_str(pattern).and_then(crate::api::storage::blocklist::remove_rule) {
+        Ok(removed) => c_int::from(removed),
+        Err(e) => {
+            set_error(error_out, e);
+            -1
+        }
+    }
+}
+
+/// Release a string handed out through an `error_out` parameter.
+///
+/// # Safety
+/// `value` must come from this library (or be NULL) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn holloway_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
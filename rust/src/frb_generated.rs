@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 568361207;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1059131202;
 
 // Section: executor
 
@@ -45,17 +45,16 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__protocols__finger__connect_and_fetch_finger_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__exposed_functions__accept_new_certificate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect_and_fetch_finger",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "accept_new_certificate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -68,37 +67,24 @@ fn wire__crate__api__protocols__finger__connect_and_fetch_finger_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_host = <String>::sse_decode(&mut deserializer);
-            let api_port = <u16>::sse_decode(&mut deserializer);
-            let api_username = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::protocols::finger::connect_and_fetch_finger(
-                            &api_host,
-                            api_port,
-                            &api_username,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::accept_new_certificate(api_host)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__protocols__gemini__connect_and_fetch_gemini_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__exposed_functions__activate_identity_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect_and_fetch_gemini",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "activate_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -110,36 +96,57 @@ fn wire__crate__api__protocols__gemini__connect_and_fetch_gemini_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_host = <String>::sse_decode(&mut deserializer);
-            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_identity_id = <String>::sse_decode(&mut deserializer);
             let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::protocols::gemini::connect_and_fetch_gemini(
-                            &api_host, api_port, &api_url,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::activate_identity(api_identity_id, api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__active_identity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "active_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::active_identity(api_url)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__protocols__gopher__connect_and_fetch_gopher_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__exposed_functions__add_block_rule_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect_and_fetch_gopher",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "add_block_rule",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -151,33 +158,54 @@ fn wire__crate__api__protocols__gopher__connect_and_fetch_gopher_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_host = <String>::sse_decode(&mut deserializer);
-            let api_port = <u16>::sse_decode(&mut deserializer);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_pattern = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::protocols::gopher::connect_and_fetch_gopher(
-                            &api_host, api_port, &api_path,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::add_block_rule(api_pattern)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__add_bookmark_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "add_bookmark",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::add_bookmark(api_url, api_title)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__exposed_functions__get_start_page_impl(
+fn wire__crate__api__exposed_functions__add_history_visit_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_start_page",
+            debug_name: "add_history_visit",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -191,23 +219,25 @@ fn wire__crate__api__exposed_functions__get_start_page_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, String>((move || {
                 let output_ok =
-                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_start_page())?;
+                    crate::api::exposed_functions::add_history_visit(api_url, api_title)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__exposed_functions__greet_impl(
+fn wire__crate__api__exposed_functions__analyze_text_direction_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "analyze_text_direction",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -221,25 +251,26 @@ fn wire__crate__api__exposed_functions__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::exposed_functions::greet(api_name))?;
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::analyze_text_direction(api_body),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__exposed_functions__init_app_impl(
+fn wire__crate__api__exposed_functions__archive_page_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "archive_page",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -253,19 +284,22 @@ fn wire__crate__api__exposed_functions__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::exposed_functions::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::archive_page(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__exposed_functions__navigate_impl(
+fn wire__crate__api__exposed_functions__begin_fetch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -273,7 +307,7 @@ fn wire__crate__api__exposed_functions__navigate_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "navigate",
+            debug_name: "begin_fetch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -288,11 +322,16 @@ fn wire__crate__api__exposed_functions__navigate_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_url = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::exposed_functions::navigate(api_url).await?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::exposed_functions::begin_fetch(api_url, api_options).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -301,7 +340,7 @@ fn wire__crate__api__exposed_functions__navigate_impl(
         },
     )
 }
-fn wire__crate__api__functions__navigate_internal__navigate_internal_impl(
+fn wire__crate__api__exposed_functions__cache_read_later_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -309,7 +348,7 @@ fn wire__crate__api__functions__navigate_internal__navigate_internal_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "navigate_internal",
+            debug_name: "cache_read_later",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -323,14 +362,11 @@ fn wire__crate__api__functions__navigate_internal__navigate_internal_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::functions::navigate_internal::navigate_internal(api_url)
-                                .await?;
+                        let output_ok = crate::api::exposed_functions::cache_read_later().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -339,131 +375,22061 @@ fn wire__crate__api__functions__navigate_internal__navigate_internal_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for u16 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
-}
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+fn wire__crate__api__exposed_functions__cancel_batch_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_batch",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::cancel_batch(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__exposed_functions__cancel_capsule_indexing_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_capsule_indexing",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::cancel_capsule_indexing();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+fn wire__crate__api__exposed_functions__cancel_mirror_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_mirror",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::cancel_mirror(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__cancel_prefetch_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_prefetch",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::cancel_prefetch();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__cancel_request_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_request",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::cancel_request(api_request_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__cancel_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cancel_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::cancel_stream(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__check_for_updates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_for_updates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::check_for_updates().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__check_plans_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_plans",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::check_plans().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__checkpoint_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "checkpoint_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::checkpoint_profile()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__checksum_link_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "checksum_link",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page_url = <String>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_file_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::checksum_link(
+                    api_page_url,
+                    api_mime,
+                    api_body,
+                    api_file_url,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__clear_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "clear_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_categories =
+                <Vec<holloway_core::api::storage::clear::DataCategory>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_since = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::clear_data(api_categories, api_since)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__close_document_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_document",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::close_document(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__close_gempub_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_gempub",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::close_gempub(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__close_menu_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_menu",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::close_menu(api_handle))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__create_identity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_common_name = <String>::sse_decode(&mut deserializer);
+            let api_valid_days = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::create_identity(
+                    api_common_name,
+                    api_valid_days,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__cso_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "cso_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::cso_query(api_url, api_query).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__deactivate_identity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "deactivate_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::deactivate_identity(api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_archived_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_archived",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_archived(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_download_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_download",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_download(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_history_entry_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_history_entry",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_history_entry(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_history_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_history_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_history_url(api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_saved_answer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_saved_answer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_saved_answer(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__delete_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::delete_session(api_name)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__detect_file_type_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_file_type",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::detect_file_type(api_content),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__diff_with_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diff_with_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::diff_with_cache(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__dismiss_host_migration_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dismiss_host_migration",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_from_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::dismiss_host_migration(
+                    api_scheme,
+                    api_from_host,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__display_host_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "display_host",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::display_host(api_url))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__display_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "display_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::display_url(api_url))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__document_outline_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "document_outline",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::document_outline(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__document_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "document_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::document_stats(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__download_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "download",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_dest_path = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::download(
+                            api_url,
+                            api_dest_path,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__download_verified_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "download_verified",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_dest_path = <String>::sse_decode(&mut deserializer);
+            let api_checksum_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::download_verified(
+                            api_url,
+                            api_dest_path,
+                            api_checksum_url,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__end_private_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "end_private_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::end_private_session())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__evict_cache_entry_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "evict_cache_entry",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::evict_cache_entry(api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__expiring_identities_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "expiring_identities",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_within_days = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::expiring_identities(api_within_days)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__export_bookmarks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_bookmarks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format =
+                <holloway_core::api::functions::bookmark_export::BookmarkFormat>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::export_bookmarks(api_format)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__export_bookmarks_to_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_bookmarks_to_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format =
+                <holloway_core::api::functions::bookmark_export::BookmarkFormat>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::export_bookmarks_to_file(api_format, api_path)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__export_identity_pem_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_identity_pem",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::export_identity_pem(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__export_identity_pkcs12_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_identity_pkcs12",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::export_identity_pkcs12(api_id, api_password)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__export_known_hosts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_known_hosts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format =
+                <holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::export_known_hosts(api_format)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__extract_links_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_links",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::extract_links(
+                    api_url, api_body, api_mime,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__fetch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::fetch(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__fetch_document_images_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch_document_images",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_page_url = <String>::sse_decode(&mut deserializer);
+            let api_max_bytes = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::fetch_document_images(
+                            api_handle,
+                            api_page_url,
+                            api_max_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__fetch_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::fetch_with_options(api_url, api_options)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__find_duplicate_bookmark_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicate_bookmark",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::find_duplicate_bookmark(api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__find_duplicate_tab_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicate_tab",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_open_tabs = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::find_duplicate_tab(api_url, api_open_tabs),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__finish_fetch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "finish_fetch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::finish_fetch(api_request_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__forget_known_host_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "forget_known_host",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::forget_known_host(api_host, api_port)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__gempub_chapter_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gempub_chapter",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_chapter = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::gempub_chapter(api_handle, api_chapter)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__gempub_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gempub_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_from_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_link = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::gempub_file(
+                    api_handle,
+                    api_from_path,
+                    api_link,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__gemtext_outline_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gemtext_outline",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::gemtext_outline(api_body))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__gemtext_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gemtext_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::gemtext_stats(api_body))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__generate_storage_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_storage_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::generate_storage_key()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_bandwidth_limit_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_bandwidth_limit",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_bandwidth_limit())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_cache_policies_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_cache_policies",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_cache_policies()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_certificate_failure_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_certificate_failure",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::get_certificate_failure(api_host),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_clock_offset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_clock_offset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_clock_offset())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_clock_skew_tolerance_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_clock_skew_tolerance",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_clock_skew_tolerance())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::get_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_data_cap_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_data_cap_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_data_cap_status()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_data_usage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_data_usage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_since = <i64>::sse_decode(&mut deserializer);
+            let api_until = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::get_data_usage(api_since, api_until)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_decompression_limits_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_decompression_limits",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_decompression_limits())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_history_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_history_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::get_history_page(api_offset, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_host_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_host_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_host_settings(api_host)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_host_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_host_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_order = <holloway_core::api::storage::host_stats::HostStatsOrder>::sse_decode(
+                &mut deserializer,
+            );
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::get_host_stats(api_order, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_line_count_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_line_count",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_line_count(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_lines_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_lines",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_start = <u32>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::get_lines(api_handle, api_start, api_count)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_lite_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_lite_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_lite_mode())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_log_level())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_max_concurrent_handshakes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_max_concurrent_handshakes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::get_max_concurrent_handshakes(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_menu_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_menu_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_menu_page(
+                    api_handle, api_offset, api_count,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_protocols_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_protocols",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_protocols())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_proxy_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_proxy_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_proxy_settings())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_rate_limits_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_rate_limits",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_rate_limits())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_recent_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recent_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_recent_history(api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_remember_input_answers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_remember_input_answers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::get_remember_input_answers(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_saved_answers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_saved_answers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_prompt = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::get_saved_answers(api_url, api_prompt)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_schedules_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_schedules",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_schedules())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_search_on_failure_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_search_on_failure",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_search_on_failure())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_search_provider_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_search_provider",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_search_provider())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_start_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_start_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_start_page())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_top_hosts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_top_hosts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_top_hosts(api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_top_sites_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_top_sites",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::get_top_sites(api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__get_trace_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_trace",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::get_trace(api_request_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__gophermap_to_gemtext_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "gophermap_to_gemtext",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::gophermap_to_gemtext(api_body),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__highlight_code_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "highlight_code",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_code = <String>::sse_decode(&mut deserializer);
+            let api_language = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::highlight_code(api_code, api_language),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__highlight_languages_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "highlight_languages",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::highlight_languages())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__highlight_preformatted_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "highlight_preformatted",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::highlight_preformatted(api_body),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_bookmarks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_bookmarks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source =
+                <holloway_core::api::functions::bookmark_import::BookmarkSource>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_data = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::import_bookmarks(api_source, api_data)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_ca_certificates_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_ca_certificates",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pem = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::import_ca_certificates(api_pem)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_identity_pem_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_identity_pem",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_certificate_pem = <String>::sse_decode(&mut deserializer);
+            let api_private_key_pem = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::import_identity_pem(
+                    api_name,
+                    api_certificate_pem,
+                    api_private_key_pem,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_identity_pkcs12_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_identity_pkcs12",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::import_identity_pkcs12(
+                    api_name,
+                    api_data,
+                    api_password,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_known_hosts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_known_hosts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format =
+                <holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_data = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::import_known_hosts(api_format, api_data)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__import_lagrange_identities_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_lagrange_identities",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::import_lagrange_identities(api_dir)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__index_capsule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_capsule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_max_pages = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::index_capsule(api_url, api_max_pages)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::exposed_functions::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__is_key_storage_unlocked_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_key_storage_unlocked",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::is_key_storage_unlocked())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__is_offline_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_offline_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::is_offline_mode())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_archived_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_archived",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_archived(api_url, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_block_rules_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_block_rules",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_block_rules()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_bookmarks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_bookmarks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_bookmarks()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_cache_entries_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_cache_entries",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_cache_entries(api_host)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_downloads_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_downloads",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_status =
+                <Option<holloway_core::api::storage::downloads::DownloadStatus>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::list_downloads(api_status, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_feed_entries_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_feed_entries",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_feed_id = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_unread_only = <bool>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_feed_entries(
+                    api_feed_id,
+                    api_unread_only,
+                    api_offset,
+                    api_limit,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_feeds_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_feeds",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_feeds()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_gemini_proxies_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_gemini_proxies",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_gemini_proxies()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_host_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_host_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_host_settings()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_identities_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_identities",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_identities()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_identity_scopes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_identity_scopes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_identity_scopes()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_known_hosts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_known_hosts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_known_hosts()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_port_overrides_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_port_overrides",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_port_overrides()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_read_later_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_read_later",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_include_read = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_read_later(api_include_read)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_saved_answers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_saved_answers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_saved_answers()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_sessions_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_sessions",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_sessions()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_trusted_cas_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_trusted_cas",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_trusted_cas()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_watched_pages_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_watched_pages",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_watched_pages()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__list_watched_plans_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_watched_plans",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::list_watched_plans()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__load_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::load_session(api_name)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__lock_key_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_key_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::lock_key_storage();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__log_files_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_files",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::log_files())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__map_site_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "map_site",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::map_site(api_url, api_depth).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__mark_all_feed_entries_read_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_all_feed_entries_read",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_feed_id = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::mark_all_feed_entries_read(api_feed_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__mark_plan_read_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_plan_read",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::mark_plan_read(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__mark_read_later_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_read_later",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_read = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::mark_read_later(api_id, api_read)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__measure_preformatted_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "measure_preformatted",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::measure_preformatted(api_body),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__migrate_host_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "migrate_host",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_from_host = <String>::sse_decode(&mut deserializer);
+            let api_to_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::migrate_host(
+                    api_scheme,
+                    api_from_host,
+                    api_to_host,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__mirror_capsule_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mirror_capsule",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_dest_dir = <String>::sse_decode(&mut deserializer);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::mirror_capsule(
+                    api_url,
+                    api_dest_dir,
+                    api_depth,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__navigate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "navigate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_force_refresh = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::navigate(api_url, api_force_refresh)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__navigate_cancellable_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "navigate_cancellable",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::navigate_cancellable(
+                            api_url,
+                            api_request_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__navigate_error_info_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "navigate_error_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_error = <crate::api::mirrors::NavigateError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::navigate_error_info(api_error),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__navigate_many_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "navigate_many",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            let api_max_concurrent = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::navigate_many(
+                    api_urls,
+                    api_options,
+                    api_max_concurrent,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__navigate_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "navigate_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::navigate_with_options(
+                            api_url,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_batch_result_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_batch_result",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_batch_result(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_expiry_reminder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_expiry_reminder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::exposed_functions::next_expiry_reminder().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_fetch_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_fetch_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_fetch_update(api_request_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_log_record_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_log_record",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_log_record(api_subscription)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_mirror_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_mirror_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_mirror_progress(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_navigation_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_navigation_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_navigation_event(api_subscription)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_scheduler_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_scheduler_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_scheduler_event(api_subscription)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__next_stream_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "next_stream_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::next_stream_event(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__normalize_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "normalize_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_input = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::normalize_url(api_input)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_archived_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_archived",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::open_archived(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_document_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_document",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::open_document(api_body))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_gempub_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_gempub",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::open_gempub(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_gempub_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_gempub_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::open_gempub_data(api_data)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_menu_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_menu",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::open_menu(api_body))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::open_profile(api_dir)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_read_later_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_read_later",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::open_read_later(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__open_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::functions::streaming::StreamOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::open_stream(api_url, api_options)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__page_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "page_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::page_summary(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__parse_gopher_menu_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_gopher_menu",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::parse_gopher_menu(api_body),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__peek_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "peek",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::peek(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__preconnect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preconnect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::preconnect(api_url).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__prefetch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prefetch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_urls = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::prefetch(api_urls).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__prefetch_allowed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prefetch_allowed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::prefetch_allowed())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__random_destination_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "random_destination",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sources =
+                <Vec<holloway_core::api::functions::explore::ExploreSource>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::random_destination(api_sources).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__recent_logs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recent_logs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_min_level =
+                <holloway_core::api::functions::logging::LogLevel>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::recent_logs(
+                    api_min_level,
+                    api_limit,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__record_download_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "record_download",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_size = <u64>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            let api_status = <holloway_core::api::storage::downloads::DownloadStatus>::sse_decode(
+                &mut deserializer,
+            );
+            let api_error = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::record_download(
+                    api_url, api_path, api_size, api_mime, api_status, api_error,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__reflow_gemtext_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reflow_gemtext",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_max_cols = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::reflow_gemtext(api_body, api_max_cols),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__reflow_text_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reflow_text",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_max_cols = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::reflow_text(
+                    api_text,
+                    api_max_cols,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__refresh_all_feeds_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_all_feeds",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::refresh_all_feeds().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__refresh_bookmarks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_bookmarks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_age_secs = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::refresh_bookmarks(api_max_age_secs)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__refresh_favicon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_favicon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page_url = <String>::sse_decode(&mut deserializer);
+            let api_options = <holloway_core::api::models::fetch_options::FetchOptions>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::refresh_favicon(
+                            api_page_url,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__refresh_feed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_feed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::refresh_feed(api_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_block_rule_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_block_rule",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pattern = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_block_rule(api_pattern)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_bookmark_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_bookmark",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_bookmark(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_gemini_proxy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_gemini_proxy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_gemini_proxy(api_scheme)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_host_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_host_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_host_settings(api_host)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_port_override_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_port_override",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::remove_port_override(api_scheme, api_host)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_read_later_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_read_later",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_read_later(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_titan_token_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_titan_token",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_titan_token(api_host)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__remove_trusted_ca_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_trusted_ca",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_fingerprint = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::remove_trusted_ca(api_fingerprint)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__render_to_html_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_to_html",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_gemtext = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::render_to_html(api_gemtext, api_base_url),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__render_to_markdown_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "render_to_markdown",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::render_to_markdown(
+                        api_body,
+                        api_base_url,
+                        api_mime,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__renew_identity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "renew_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_rotate_key = <bool>::sse_decode(&mut deserializer);
+            let api_valid_days = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::renew_identity(
+                    api_id,
+                    api_rotate_key,
+                    api_valid_days,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__holloway_core__api__models__navigation_result__request_timings_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "request_timings_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        holloway_core::api::models::navigation_result::RequestTimings::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__reserve_request_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reserve_request_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::reserve_request_id())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__reset_cache_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_cache_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::reset_cache_policy(api_scheme)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__resolve_link_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_link",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_href = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::resolve_link(api_base_url, api_href)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__route_content_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "route_content",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::exposed_functions::route_content(
+                    api_url, api_mime,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__save_for_later_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_for_later",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::save_for_later(api_url, api_title)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__save_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session =
+                <holloway_core::api::storage::sessions::Session>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::save_session(api_session)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__scheduler_tick_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scheduler_tick",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::exposed_functions::scheduler_tick().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::search(api_query).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_all_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_all",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::search_all(api_query).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_capsule_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_capsule",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::search_capsule(api_url, api_query, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_mode =
+                <holloway_core::api::storage::history::HistoryMatch>::sse_decode(&mut deserializer);
+            let api_range =
+                <holloway_core::api::storage::history::HistoryRange>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::search_history(
+                    api_query, api_mode, api_range, api_limit,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_local_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_local",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::search_local(api_query, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__search_providers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_providers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::search_providers())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_bandwidth_limit_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bandwidth_limit",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bytes_per_sec = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_bandwidth_limit(api_bytes_per_sec)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_bookmark_title_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_bookmark_title",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_bookmark_title(api_id, api_title)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_cache_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cache_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy =
+                <holloway_core::api::storage::cache::CachePolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_cache_policy(api_policy)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_clock_skew_tolerance_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_clock_skew_tolerance",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::set_clock_skew_tolerance(api_secs);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_config_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <holloway_core::api::functions::config::Config>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_config(api_config)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_data_cap_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_data_cap",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit_bytes = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_data_cap(api_limit_bytes)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_decompression_limits_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_decompression_limits",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limits =
+                <holloway_core::api::transport::limits::DecompressionLimits>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_decompression_limits(api_limits)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_feed_entry_read_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_feed_entry_read",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            let api_read = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_feed_entry_read(api_id, api_read)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_gateway_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_gateway",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url_template = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_gateway(api_url_template)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_gemini_proxy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_gemini_proxy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_gemini_proxy(
+                    api_scheme, api_host, api_port,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_gopher_gateway_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_gopher_gateway",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_selector = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_gopher_gateway(
+                    api_scheme,
+                    api_host,
+                    api_port,
+                    api_selector,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_host_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_host_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <holloway_core::api::storage::host_settings::HostSettings>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_host_settings(api_settings)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_lite_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_lite_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_lite_mode(api_enabled)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_log_directory_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_log_directory",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_log_directory(api_dir)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level =
+                <holloway_core::api::functions::logging::LogLevel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::set_log_level(api_level);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_max_concurrent_handshakes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_max_concurrent_handshakes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_max_concurrent_handshakes(api_max)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_network_type_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_network_type",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_network = <holloway_core::api::storage::data_usage::NetworkType>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::set_network_type(api_network);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_offline_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_offline_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_offline_mode(api_enabled)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_port_override_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_port_override",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_host = <Option<String>>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_port_override(
+                    api_scheme, api_host, api_port,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_power_state_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_power_state",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_state = <holloway_core::api::functions::scheduler::PowerState>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::set_power_state(api_state);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_protocol_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_protocol_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheme = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_protocol_enabled(api_scheme, api_enabled)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_proxy_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_proxy_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::api::mirrors::ProxySettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_proxy_settings(api_settings)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_rate_limits_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_rate_limits",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limits = <holloway_core::api::transport::rate_limit::RateLimits>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_rate_limits(api_limits)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_remember_input_answers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_remember_input_answers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_remember_input_answers(api_enabled)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_schedule_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_schedule",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_schedule = <holloway_core::api::functions::scheduler::TaskSchedule>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_schedule(api_schedule)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_search_on_failure_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_search_on_failure",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_search_on_failure(api_enabled)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_search_provider_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_search_provider",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url_template = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_search_provider(api_url_template)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_session_scroll_offset_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_session_scroll_offset",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_tab = <u32>::sse_decode(&mut deserializer);
+            let api_scroll_offset = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_session_scroll_offset(
+                    api_name,
+                    api_tab,
+                    api_scroll_offset,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_start_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_start_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::set_start_page(api_url)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__set_titan_token_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_titan_token",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_host = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::set_titan_token(api_host, api_token)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__shutdown_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "shutdown",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::shutdown()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__start_bookmark_refresh_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_bookmark_refresh",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_interval_secs = <u32>::sse_decode(&mut deserializer);
+            let api_max_age_secs = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::start_bookmark_refresh(
+                    api_interval_secs,
+                    api_max_age_secs,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__start_expiry_checks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_expiry_checks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_within_days = <u32>::sse_decode(&mut deserializer);
+            let api_interval_secs = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::start_expiry_checks(
+                        api_within_days,
+                        api_interval_secs,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__start_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tick_secs = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::start_scheduler(api_tick_secs)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__stop_bookmark_refresh_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_bookmark_refresh",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::stop_bookmark_refresh();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__stop_expiry_checks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_expiry_checks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::stop_expiry_checks();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__stop_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::exposed_functions::stop_scheduler();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__strip_ansi_escapes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "strip_ansi_escapes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::strip_ansi_escapes(api_body),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__style_ansi_text_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "style_ansi_text",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::style_ansi_text(api_body))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__subscribe_events_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::subscribe_events())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__subscribe_feed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_feed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            let api_kind = <holloway_core::api::functions::feed_discovery::FeedKind>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::subscribe_feed(api_url, api_title, api_kind)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__subscribe_logs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_logs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::subscribe_logs())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__subscribe_request_events_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_request_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_request_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::subscribe_request_events(api_request_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__subscribe_scheduler_events_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_scheduler_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::subscribe_scheduler_events(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__suggest_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suggest",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_prefix = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::suggest(api_prefix, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__suggest_download_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suggest_download_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_mime = <String>::sse_decode(&mut deserializer);
+            let api_dest_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::exposed_functions::suggest_download_path(
+                        api_url,
+                        api_mime,
+                        api_dest_dir,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__suggested_action_for_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suggested_action_for_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_status = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::suggested_action_for_status(api_status),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__suggested_host_migrations_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "suggested_host_migrations",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::suggested_host_migrations()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__test_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy =
+                <holloway_core::api::transport::proxy::Socks5Proxy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::exposed_functions::test_proxy(api_proxy).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__titan_token_hosts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "titan_token_hosts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::titan_token_hosts()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__titan_upload_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "titan_upload",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mime = <Option<String>>::sse_decode(&mut deserializer);
+            let api_token = <Option<String>>::sse_decode(&mut deserializer);
+            let api_identity_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::mirrors::NavigateError>(
+                    (move || async move {
+                        let output_ok = crate::api::exposed_functions::titan_upload(
+                            api_url,
+                            api_data,
+                            api_mime,
+                            api_token,
+                            api_identity_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unlock_key_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_key_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::unlock_key_storage(api_storage_key)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unlock_key_storage_from_keyring_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_key_storage_from_keyring",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::exposed_functions::unlock_key_storage_from_keyring(api_profile)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unread_feed_count_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unread_feed_count",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::unread_feed_count()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unsubscribe_events_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::unsubscribe_events(api_subscription),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unsubscribe_feed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_feed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::unsubscribe_feed(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unsubscribe_logs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_logs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::unsubscribe_logs(api_subscription),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unsubscribe_scheduler_events_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unsubscribe_scheduler_events",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscription = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::exposed_functions::unsubscribe_scheduler_events(api_subscription),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unwatch_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unwatch_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::unwatch_page(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__unwatch_plan_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unwatch_plan",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::unwatch_plan(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__watch_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::watch_page(api_url, api_title)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__exposed_functions__watch_plan_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_plan",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::exposed_functions::watch_plan(api_address)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: static_checks
+
+#[allow(clippy::unnecessary_literal_unwrap)]
+const _: fn() = || {
+    {
+        let AddedBookmark = None::<holloway_core::api::storage::bookmarks::AddedBookmark>.unwrap();
+        let _: holloway_core::api::storage::bookmarks::Bookmark = AddedBookmark.bookmark;
+        let _: bool = AddedBookmark.duplicate;
+    }
+    match None::<crate::api::mirrors::AnsiColor>.unwrap() {
+        crate::api::mirrors::AnsiColor::Indexed(field0) => {
+            let _: u8 = field0;
+        }
+        crate::api::mirrors::AnsiColor::Rgb { r, g, b } => {
+            let _: u8 = r;
+            let _: u8 = g;
+            let _: u8 = b;
+        }
+    }
+    {
+        let AnsiStyle = None::<holloway_core::api::functions::ansi::AnsiStyle>.unwrap();
+        let _: Option<crate::api::mirrors::AnsiColor> = AnsiStyle.foreground;
+        let _: Option<crate::api::mirrors::AnsiColor> = AnsiStyle.background;
+        let _: bool = AnsiStyle.bold;
+        let _: bool = AnsiStyle.dim;
+        let _: bool = AnsiStyle.italic;
+        let _: bool = AnsiStyle.underline;
+        let _: bool = AnsiStyle.inverse;
+    }
+    {
+        let ArchivedPage = None::<holloway_core::api::storage::archive::ArchivedPage>.unwrap();
+        let _: i64 = ArchivedPage.id;
+        let _: String = ArchivedPage.url;
+        let _: Option<u8> = ArchivedPage.status;
+        let _: String = ArchivedPage.mime;
+        let _: Option<String> = ArchivedPage.title;
+        let _: u64 = ArchivedPage.size;
+        let _: String = ArchivedPage.body_hash;
+        let _: Option<String> = ArchivedPage.certificate_fingerprint;
+        let _: i64 = ArchivedPage.fetched_at;
+        let _: i64 = ArchivedPage.archived_at;
+    }
+    {
+        let BatchItem = None::<holloway_core::api::functions::batch::BatchItem>.unwrap();
+        let _: u32 = BatchItem.index;
+        let _: String = BatchItem.url;
+        let _: u64 = BatchItem.request_id;
+        let _: Option<holloway_core::api::models::navigation_result::NavigationResult> =
+            BatchItem.result;
+        let _: Option<holloway_core::api::models::error_info::ErrorInfo> = BatchItem.error;
+    }
+    {
+        let BlockRule = None::<holloway_core::api::storage::blocklist::BlockRule>.unwrap();
+        let _: String = BlockRule.pattern;
+        let _: holloway_core::api::storage::blocklist::BlockRuleKind = BlockRule.kind;
+        let _: i64 = BlockRule.created_at;
+    }
+    {
+        let Bookmark = None::<holloway_core::api::storage::bookmarks::Bookmark>.unwrap();
+        let _: i64 = Bookmark.id;
+        let _: String = Bookmark.url;
+        let _: Option<String> = Bookmark.title;
+        let _: i64 = Bookmark.created_at;
+        let _: Option<String> = Bookmark.favicon;
+    }
+    {
+        let BookmarksImport =
+            None::<holloway_core::api::functions::bookmark_import::BookmarksImport>.unwrap();
+        let _: u32 = BookmarksImport.imported;
+        let _: u32 = BookmarksImport.skipped;
+    }
+    {
+        let CacheEntry = None::<holloway_core::api::storage::cache::CacheEntry>.unwrap();
+        let _: String = CacheEntry.url;
+        let _: String = CacheEntry.host;
+        let _: String = CacheEntry.mime;
+        let _: u64 = CacheEntry.size;
+        let _: i64 = CacheEntry.fetched_at;
+        let _: i64 = CacheEntry.expires_at;
+        let _: bool = CacheEntry.stale;
+    }
+    {
+        let CachePolicy = None::<holloway_core::api::storage::cache::CachePolicy>.unwrap();
+        let _: String = CachePolicy.scheme;
+        let _: bool = CachePolicy.cacheable;
+        let _: u32 = CachePolicy.ttl_secs;
+        let _: bool = CachePolicy.cache_queries;
+    }
+    {
+        let CertificateError =
+            None::<holloway_core::api::models::certificate_error::CertificateError>.unwrap();
+        let _: holloway_core::api::models::certificate_error::CertificateErrorKind =
+            CertificateError.kind;
+        let _: Option<String> = CertificateError.identity_id;
+        let _: String = CertificateError.message;
+        let _: holloway_core::api::models::error_info::RecoveryAction =
+            CertificateError.suggested_action;
+    }
+    {
+        let CertificateSummary =
+            None::<holloway_core::api::models::navigation_result::CertificateSummary>.unwrap();
+        let _: String = CertificateSummary.fingerprint;
+        let _: String = CertificateSummary.subject;
+        let _: String = CertificateSummary.issuer;
+        let _: i64 = CertificateSummary.not_before;
+        let _: i64 = CertificateSummary.not_after;
+        let _: i64 = CertificateSummary.first_seen;
+        let _: holloway_core::api::models::navigation_result::PinStatus =
+            CertificateSummary.pin_status;
+        let _: holloway_core::api::models::navigation_result::PinExpiry = CertificateSummary.expiry;
+        let _: Option<String> = CertificateSummary.tls_version;
+        let _: Option<String> = CertificateSummary.cipher_suite;
+    }
+    {
+        let ClearedData = None::<holloway_core::api::storage::clear::ClearedData>.unwrap();
+        let _: holloway_core::api::storage::clear::DataCategory = ClearedData.category;
+        let _: u32 = ClearedData.removed;
+    }
+    {
+        let ClockOffset = None::<holloway_core::api::functions::clock_skew::ClockOffset>.unwrap();
+        let _: Option<i64> = ClockOffset.offset_secs;
+        let _: u32 = ClockOffset.hosts;
+        let _: bool = ClockOffset.consistent;
+    }
+    {
+        let Config = None::<holloway_core::api::functions::config::Config>.unwrap();
+        let _: String = Config.default_scheme;
+        let _: bool = Config.guess_schemes;
+        let _: String = Config.start_page;
+        let _: String = Config.search_provider;
+        let _: u32 = Config.connect_timeout_secs;
+        let _: Option<u32> = Config.read_timeout_secs;
+        let _: holloway_core::api::storage::host_settings::TlsPolicy = Config.tls_policy;
+        let _: crate::api::mirrors::ProxySettings = Config.proxy;
+    }
+    {
+        let CrawlResult = None::<holloway_core::api::functions::crawler::CrawlResult>.unwrap();
+        let _: String = CrawlResult.capsule;
+        let _: u32 = CrawlResult.indexed;
+        let _: u32 = CrawlResult.failed;
+        let _: u32 = CrawlResult.disallowed;
+        let _: u32 = CrawlResult.remaining;
+        let _: bool = CrawlResult.cancelled;
+    }
+    {
+        let CsoField = None::<holloway_core::api::protocols::cso::CsoField>.unwrap();
+        let _: String = CsoField.name;
+        let _: String = CsoField.value;
+    }
+    {
+        let CsoRecord = None::<holloway_core::api::protocols::cso::CsoRecord>.unwrap();
+        let _: u32 = CsoRecord.index;
+        let _: Vec<holloway_core::api::protocols::cso::CsoField> = CsoRecord.fields;
+    }
+    {
+        let DailyUsage = None::<holloway_core::api::storage::data_usage::DailyUsage>.unwrap();
+        let _: i64 = DailyUsage.day_start;
+        let _: holloway_core::api::storage::data_usage::NetworkType = DailyUsage.network;
+        let _: u64 = DailyUsage.bytes_sent;
+        let _: u64 = DailyUsage.bytes_received;
+    }
+    {
+        let DataCapStatus =
+            None::<holloway_core::api::functions::data_usage::DataCapStatus>.unwrap();
+        let _: Option<u64> = DataCapStatus.limit_bytes;
+        let _: u64 = DataCapStatus.used_bytes;
+        let _: bool = DataCapStatus.exceeded;
+    }
+    {
+        let DecompressionLimits =
+            None::<holloway_core::api::transport::limits::DecompressionLimits>.unwrap();
+        let _: u32 = DecompressionLimits.max_ratio;
+        let _: u64 = DecompressionLimits.max_bytes;
+    }
+    {
+        let DiffLine = None::<holloway_core::api::functions::page_diff::DiffLine>.unwrap();
+        let _: u32 = DiffLine.line;
+        let _: String = DiffLine.text;
+    }
+    {
+        let DisplayHost = None::<holloway_core::api::functions::homograph::DisplayHost>.unwrap();
+        let _: String = DisplayHost.host;
+        let _: bool = DisplayHost.spoofing_warning;
+    }
+    {
+        let DownloadRecord =
+            None::<holloway_core::api::storage::downloads::DownloadRecord>.unwrap();
+        let _: i64 = DownloadRecord.id;
+        let _: String = DownloadRecord.url;
+        let _: String = DownloadRecord.path;
+        let _: u64 = DownloadRecord.size;
+        let _: String = DownloadRecord.mime;
+        let _: holloway_core::api::storage::downloads::DownloadStatus = DownloadRecord.status;
+        let _: Option<String> = DownloadRecord.error;
+        let _: i64 = DownloadRecord.finished_at;
+        let _: Option<String> = DownloadRecord.checksum_url;
+        let _: holloway_core::api::storage::downloads::ChecksumStatus = DownloadRecord.checksum;
+    }
+    {
+        let DownloadResult =
+            None::<holloway_core::api::functions::downloads::DownloadResult>.unwrap();
+        let _: String = DownloadResult.path;
+        let _: u64 = DownloadResult.size;
+        let _: String = DownloadResult.mime;
+        let _: String = DownloadResult.sha256;
+        let _: holloway_core::api::storage::downloads::ChecksumStatus = DownloadResult.checksum;
+    }
+    {
+        let ErrorInfo = None::<holloway_core::api::models::error_info::ErrorInfo>.unwrap();
+        let _: holloway_core::api::models::error_info::ErrorCode = ErrorInfo.code;
+        let _: String = ErrorInfo.name;
+        let _: u32 = ErrorInfo.number;
+        let _: String = ErrorInfo.message;
+        let _: bool = ErrorInfo.retryable;
+        let _: bool = ErrorInfo.user_actionable;
+        let _: Option<holloway_core::api::models::error_info::RecoveryAction> =
+            ErrorInfo.suggested_action;
+    }
+    {
+        let ExpiryReminder =
+            None::<holloway_core::api::functions::identities::ExpiryReminder>.unwrap();
+        let _: holloway_core::api::storage::identities::Identity = ExpiryReminder.identity;
+        let _: i64 = ExpiryReminder.days_left;
+        let _: bool = ExpiryReminder.expired;
+    }
+    {
+        let ExploreSource = None::<holloway_core::api::functions::explore::ExploreSource>.unwrap();
+        let _: Option<String> = ExploreSource.directory_url;
+        let _: f64 = ExploreSource.weight;
+    }
+    {
+        let Feed = None::<holloway_core::api::storage::feeds::Feed>.unwrap();
+        let _: i64 = Feed.id;
+        let _: String = Feed.url;
+        let _: Option<String> = Feed.title;
+        let _: holloway_core::api::functions::feed_discovery::FeedKind = Feed.kind;
+        let _: i64 = Feed.subscribed_at;
+        let _: Option<i64> = Feed.refreshed_at;
+        let _: Option<String> = Feed.last_error;
+        let _: u32 = Feed.unread;
+    }
+    {
+        let FeedCandidate =
+            None::<holloway_core::api::functions::feed_discovery::FeedCandidate>.unwrap();
+        let _: String = FeedCandidate.url;
+        let _: Option<String> = FeedCandidate.title;
+        let _: holloway_core::api::functions::feed_discovery::FeedKind = FeedCandidate.kind;
+    }
+    {
+        let FeedEntry = None::<holloway_core::api::storage::feeds::FeedEntry>.unwrap();
+        let _: i64 = FeedEntry.id;
+        let _: i64 = FeedEntry.feed_id;
+        let _: Option<String> = FeedEntry.feed_title;
+        let _: String = FeedEntry.url;
+        let _: String = FeedEntry.title;
+        let _: Option<i64> = FeedEntry.published_at;
+        let _: i64 = FeedEntry.seen_at;
+        let _: bool = FeedEntry.read;
+    }
+    {
+        let FetchOptions = None::<holloway_core::api::models::fetch_options::FetchOptions>.unwrap();
+        let _: bool = FetchOptions.private;
+        let _: bool = FetchOptions.use_cache;
+        let _: bool = FetchOptions.revalidate;
+        let _: bool = FetchOptions.probe_protocols;
+        let _: bool = FetchOptions.background;
+        let _: Option<String> = FetchOptions.identity_id;
+        let _: Option<u32> = FetchOptions.connect_timeout_secs;
+        let _: Option<u32> = FetchOptions.read_timeout_secs;
+        let _: Option<u32> = FetchOptions.deadline_secs;
+        let _: Option<u64> = FetchOptions.max_body_bytes;
+    }
+    {
+        let FetchResponse =
+            None::<holloway_core::api::models::fetch_response::FetchResponse>.unwrap();
+        let _: String = FetchResponse.url;
+        let _: Vec<String> = FetchResponse.redirects;
+        let _: Option<u8> = FetchResponse.status;
+        let _: Option<String> = FetchResponse.meta;
+        let _: String = FetchResponse.mime;
+        let _: holloway_core::api::functions::content_router::Renderer = FetchResponse.renderer;
+        let _: String = FetchResponse.body;
+        let _: String = FetchResponse.body_hash;
+        let _: bool = FetchResponse.unchanged;
+        let _: bool = FetchResponse.truncated;
+        let _: bool = FetchResponse.stale;
+        let _: Option<String> = FetchResponse.title;
+        let _: Vec<holloway_core::api::functions::feed_discovery::FeedCandidate> =
+            FetchResponse.feeds;
+        let _: Option<String> = FetchResponse.favicon;
+        let _: Option<String> = FetchResponse.fragment;
+        let _: Option<u32> = FetchResponse.fragment_line;
+        let _: Option<i64> = FetchResponse.cached_at;
+        let _: u64 = FetchResponse.request_id;
+        let _: String = FetchResponse.display_host;
+        let _: bool = FetchResponse.spoofing_warning;
+        let _: bool = FetchResponse.clock_skew_warning;
+        let _: Option<String> = FetchResponse.probed_scheme;
+        let _: Option<holloway_core::api::models::certificate_error::CertificateError> =
+            FetchResponse.certificate_error;
+        let _: Option<holloway_core::api::models::server_failure::ServerFailure> =
+            FetchResponse.server_failure;
+        let _: Option<holloway_core::api::models::navigation_result::CertificateSummary> =
+            FetchResponse.certificate;
+        let _: u64 = FetchResponse.duration_ms;
+        let _: holloway_core::api::models::navigation_result::RequestTimings =
+            FetchResponse.timings;
+    }
+    {
+        let FileType = None::<holloway_core::api::functions::file_type::FileType>.unwrap();
+        let _: String = FileType.mime;
+        let _: String = FileType.extension;
+    }
+    {
+        let GempubBook = None::<holloway_core::api::functions::gempub::GempubBook>.unwrap();
+        let _: u64 = GempubBook.handle;
+        let _: holloway_core::api::functions::gempub::GempubMetadata = GempubBook.metadata;
+        let _: Option<String> = GempubBook.index_path;
+        let _: Vec<holloway_core::api::functions::gempub::GempubChapter> = GempubBook.chapters;
+    }
+    {
+        let GempubChapter = None::<holloway_core::api::functions::gempub::GempubChapter>.unwrap();
+        let _: String = GempubChapter.title;
+        let _: String = GempubChapter.path;
+    }
+    {
+        let GempubMetadata = None::<holloway_core::api::functions::gempub::GempubMetadata>.unwrap();
+        let _: String = GempubMetadata.title;
+        let _: Option<String> = GempubMetadata.author;
+        let _: Option<String> = GempubMetadata.language;
+        let _: Option<String> = GempubMetadata.description;
+        let _: Option<String> = GempubMetadata.published;
+        let _: Option<String> = GempubMetadata.version;
+        let _: Option<String> = GempubMetadata.cover;
+    }
+    {
+        let GempubPage = None::<holloway_core::api::functions::gempub::GempubPage>.unwrap();
+        let _: String = GempubPage.path;
+        let _: String = GempubPage.mime;
+        let _: String = GempubPage.body;
+        let _: Vec<u8> = GempubPage.data;
+        let _: Option<u32> = GempubPage.chapter;
+        let _: Option<u32> = GempubPage.previous_chapter;
+        let _: Option<u32> = GempubPage.next_chapter;
+    }
+    match None::<crate::api::mirrors::GemtextLine>.unwrap() {
+        crate::api::mirrors::GemtextLine::Text(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::GemtextLine::Link { url, label } => {
+            let _: String = url;
+            let _: Option<String> = label;
+        }
+        crate::api::mirrors::GemtextLine::Heading { level, text } => {
+            let _: u8 = level;
+            let _: String = text;
+        }
+        crate::api::mirrors::GemtextLine::ListItem(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::GemtextLine::Quote(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::GemtextLine::PreformatToggle { alt } => {
+            let _: Option<String> = alt;
+        }
+        crate::api::mirrors::GemtextLine::Preformatted(field0) => {
+            let _: String = field0;
+        }
+    }
+    {
+        let GopherItem = None::<holloway_core::api::parsers::gophermap::GopherItem>.unwrap();
+        let _: char = GopherItem.item_type;
+        let _: String = GopherItem.display;
+        let _: String = GopherItem.selector;
+        let _: String = GopherItem.host;
+        let _: u16 = GopherItem.port;
+        let _: String = GopherItem.kind;
+        let _: String = GopherItem.icon;
+        let _: bool = GopherItem.fetchable;
+    }
+    {
+        let HeadingAnchor = None::<holloway_core::api::functions::anchors::HeadingAnchor>.unwrap();
+        let _: String = HeadingAnchor.slug;
+        let _: String = HeadingAnchor.text;
+        let _: u8 = HeadingAnchor.level;
+        let _: u32 = HeadingAnchor.line_index;
+    }
+    {
+        let HighlightedBlock =
+            None::<holloway_core::api::functions::highlighting::HighlightedBlock>.unwrap();
+        let _: u32 = HighlightedBlock.start_index;
+        let _: String = HighlightedBlock.language;
+        let _: Vec<holloway_core::api::functions::highlighting::HighlightedLine> =
+            HighlightedBlock.lines;
+    }
+    {
+        let HighlightedLine =
+            None::<holloway_core::api::functions::highlighting::HighlightedLine>.unwrap();
+        let _: Vec<holloway_core::api::functions::highlighting::TokenSpan> = HighlightedLine.spans;
+    }
+    {
+        let HistoryEntry = None::<holloway_core::api::storage::history::HistoryEntry>.unwrap();
+        let _: i64 = HistoryEntry.id;
+        let _: String = HistoryEntry.url;
+        let _: Option<String> = HistoryEntry.title;
+        let _: i64 = HistoryEntry.visited_at;
+    }
+    {
+        let HistoryRange = None::<holloway_core::api::storage::history::HistoryRange>.unwrap();
+        let _: Option<i64> = HistoryRange.since;
+        let _: Option<i64> = HistoryRange.until;
+    }
+    {
+        let HistoryResult = None::<holloway_core::api::storage::history::HistoryResult>.unwrap();
+        let _: String = HistoryResult.url;
+        let _: Option<String> = HistoryResult.title;
+        let _: u32 = HistoryResult.visit_count;
+        let _: i64 = HistoryResult.last_visited;
+    }
+    {
+        let HostMigration =
+            None::<holloway_core::api::functions::host_migration::HostMigration>.unwrap();
+        let _: String = HostMigration.scheme;
+        let _: String = HostMigration.from_host;
+        let _: String = HostMigration.to_host;
+        let _: u32 = HostMigration.moved_pages;
+        let _: u32 = HostMigration.bookmarks;
+    }
+    {
+        let HostMigrationResult =
+            None::<holloway_core::api::functions::host_migration::HostMigrationResult>.unwrap();
+        let _: u32 = HostMigrationResult.bookmarks;
+    }
+    {
+        let HostSettings =
+            None::<holloway_core::api::storage::host_settings::HostSettings>.unwrap();
+        let _: String = HostSettings.host;
+        let _: Option<holloway_core::api::storage::host_settings::TlsPolicy> =
+            HostSettings.tls_policy;
+        let _: Option<String> = HostSettings.identity_id;
+        let _: Option<u32> = HostSettings.connect_timeout_secs;
+        let _: Option<u32> = HostSettings.read_timeout_secs;
+        let _: Option<holloway_core::api::storage::host_settings::RedirectPolicy> =
+            HostSettings.redirect_policy;
+        let _: Option<u64> = HostSettings.max_body_bytes;
+        let _: Option<String> = HostSettings.charset;
+    }
+    {
+        let HostStats = None::<holloway_core::api::storage::host_stats::HostStats>.unwrap();
+        let _: String = HostStats.host;
+        let _: u32 = HostStats.requests;
+        let _: u32 = HostStats.errors;
+        let _: u64 = HostStats.bytes_sent;
+        let _: u64 = HostStats.bytes_received;
+        let _: u32 = HostStats.average_latency_ms;
+        let _: f64 = HostStats.error_rate;
+        let _: i64 = HostStats.first_request_at;
+        let _: i64 = HostStats.last_request_at;
+    }
+    {
+        let IdentitiesImport =
+            None::<holloway_core::api::functions::identity_transfer::IdentitiesImport>.unwrap();
+        let _: u32 = IdentitiesImport.imported;
+        let _: u32 = IdentitiesImport.skipped;
+    }
+    {
+        let Identity = None::<holloway_core::api::storage::identities::Identity>.unwrap();
+        let _: String = Identity.id;
+        let _: String = Identity.name;
+        let _: String = Identity.common_name;
+        let _: String = Identity.fingerprint;
+        let _: i64 = Identity.not_before;
+        let _: i64 = Identity.not_after;
+        let _: i64 = Identity.created_at;
+    }
+    {
+        let IdentityScope =
+            None::<holloway_core::api::storage::identity_scopes::IdentityScope>.unwrap();
+        let _: String = IdentityScope.prefix;
+        let _: String = IdentityScope.identity_id;
+        let _: i64 = IdentityScope.activated_at;
+    }
+    {
+        let InlineImage =
+            None::<holloway_core::api::functions::inline_images::InlineImage>.unwrap();
+        let _: u32 = InlineImage.link_index;
+        let _: u32 = InlineImage.line_index;
+        let _: String = InlineImage.url;
+        let _: String = InlineImage.mime;
+        let _: Vec<u8> = InlineImage.data;
+    }
+    {
+        let InlineImageFailure =
+            None::<holloway_core::api::functions::inline_images::InlineImageFailure>.unwrap();
+        let _: u32 = InlineImageFailure.link_index;
+        let _: u32 = InlineImageFailure.line_index;
+        let _: String = InlineImageFailure.url;
+        let _: String = InlineImageFailure.error;
+    }
+    {
+        let InlineImages =
+            None::<holloway_core::api::functions::inline_images::InlineImages>.unwrap();
+        let _: Vec<holloway_core::api::functions::inline_images::InlineImage> = InlineImages.images;
+        let _: Vec<holloway_core::api::functions::inline_images::InlineImageFailure> =
+            InlineImages.failed;
+    }
+    {
+        let KnownHost = None::<holloway_core::api::storage::known_hosts::KnownHost>.unwrap();
+        let _: String = KnownHost.host;
+        let _: u16 = KnownHost.port;
+        let _: String = KnownHost.fingerprint;
+        let _: Option<i64> = KnownHost.expires_at;
+        let _: i64 = KnownHost.first_seen;
+        let _: i64 = KnownHost.last_seen;
+    }
+    {
+        let KnownHostsImport =
+            None::<holloway_core::api::functions::known_hosts_transfer::KnownHostsImport>.unwrap();
+        let _: u32 = KnownHostsImport.imported;
+        let _: u32 = KnownHostsImport.skipped;
+    }
+    {
+        let LevelRun = None::<holloway_core::api::functions::bidi::LevelRun>.unwrap();
+        let _: u32 = LevelRun.start;
+        let _: u32 = LevelRun.end;
+        let _: u8 = LevelRun.level;
+        let _: holloway_core::api::functions::bidi::TextDirection = LevelRun.direction;
+    }
+    {
+        let LineDirection = None::<holloway_core::api::functions::bidi::LineDirection>.unwrap();
+        let _: u32 = LineDirection.line_index;
+        let _: holloway_core::api::functions::bidi::TextDirection = LineDirection.direction;
+        let _: Vec<holloway_core::api::functions::bidi::LevelRun> = LineDirection.runs;
+    }
+    {
+        let LocalSearchResult =
+            None::<holloway_core::api::storage::page_index::LocalSearchResult>.unwrap();
+        let _: String = LocalSearchResult.url;
+        let _: Option<String> = LocalSearchResult.title;
+        let _: Vec<holloway_core::api::storage::page_index::SnippetPart> =
+            LocalSearchResult.snippet;
+        let _: i64 = LocalSearchResult.fetched_at;
+        let _: Option<i64> = LocalSearchResult.last_visited_at;
+    }
+    {
+        let LogRecord = None::<holloway_core::api::functions::logging::LogRecord>.unwrap();
+        let _: i64 = LogRecord.timestamp_ms;
+        let _: holloway_core::api::functions::logging::LogLevel = LogRecord.level;
+        let _: String = LogRecord.target;
+        let _: String = LogRecord.message;
+        let _: Option<u64> = LogRecord.request_id;
+    }
+    {
+        let MenuPage = None::<holloway_core::api::functions::menu_pages::MenuPage>.unwrap();
+        let _: u32 = MenuPage.offset;
+        let _: Vec<holloway_core::api::parsers::gophermap::GopherItem> = MenuPage.items;
+        let _: u32 = MenuPage.total;
+    }
+    {
+        let MetasearchResult =
+            None::<holloway_core::api::functions::metasearch::MetasearchResult>.unwrap();
+        let _: Vec<holloway_core::api::functions::metasearch::SearchHit> = MetasearchResult.hits;
+        let _: Vec<holloway_core::api::functions::metasearch::ProviderFailure> =
+            MetasearchResult.failed;
+    }
+    {
+        let MirrorProgress = None::<holloway_core::api::functions::mirror::MirrorProgress>.unwrap();
+        let _: String = MirrorProgress.url;
+        let _: Option<String> = MirrorProgress.path;
+        let _: Option<String> = MirrorProgress.error;
+        let _: u32 = MirrorProgress.saved;
+        let _: u32 = MirrorProgress.failed;
+        let _: u32 = MirrorProgress.remaining;
+    }
+    match None::<crate::api::mirrors::NavigateError>.unwrap() {
+        crate::api::mirrors::NavigateError::InvalidUrl(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::UnsupportedScheme(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::SchemeDisabled(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::Blocked(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::Disallowed(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::Offline(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::DnsFailure(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ConnectTimeout(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ConnectionRefused(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ConnectionFailed(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ConnectionLost(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ReadTimeout(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::GeminiTimeout(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ProxyUnreachable(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ProxyAuthFailed(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::ProxyError(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::GatewayError(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::TlsError(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::FingerprintMismatch(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::CertificateDate(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::CertificateRejected(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::BadResponse(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::TooLarge(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::NotFound(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::InvalidInput(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::GopherBadType(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::GopherBadSelector(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::StorageError(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::IdentityError(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::Cancelled(field0) => {
+            let _: String = field0;
+        }
+        crate::api::mirrors::NavigateError::Redirected { target, permanent } => {
+            let _: String = target;
+            let _: bool = permanent;
+        }
+        crate::api::mirrors::NavigateError::InputRequired { prompt, sensitive } => {
+            let _: String = prompt;
+            let _: bool = sensitive;
+        }
+        crate::api::mirrors::NavigateError::CertificateRequired(field0) => {
+            let _: holloway_core::api::models::certificate_error::CertificateError = field0;
+        }
+        crate::api::mirrors::NavigateError::ServerError(field0) => {
+            let _: holloway_core::api::models::server_failure::ServerFailure = field0;
+        }
+        crate::api::mirrors::NavigateError::Other(field0) => {
+            let _: String = field0;
+        }
+    }
+    {
+        let NavigationEvent =
+            None::<holloway_core::api::models::navigation_event::NavigationEvent>.unwrap();
+        let _: u64 = NavigationEvent.request_id;
+        let _: holloway_core::api::models::navigation_event::NavigationEventKind =
+            NavigationEvent.kind;
+        let _: String = NavigationEvent.url;
+        let _: Option<u8> = NavigationEvent.status;
+        let _: Option<String> = NavigationEvent.meta;
+        let _: u64 = NavigationEvent.bytes;
+        let _: Option<String> = NavigationEvent.message;
+    }
+    {
+        let NavigationResult =
+            None::<holloway_core::api::models::navigation_result::NavigationResult>.unwrap();
+        let _: String = NavigationResult.url;
+        let _: Vec<String> = NavigationResult.redirects;
+        let _: String = NavigationResult.protocol;
+        let _: Option<u8> = NavigationResult.status;
+        let _: String = NavigationResult.mime;
+        let _: Option<String> = NavigationResult.charset;
+        let _: holloway_core::api::functions::content_router::Renderer = NavigationResult.renderer;
+        let _: Option<String> = NavigationResult.title;
+        let _: Option<String> = NavigationResult.favicon;
+        let _: String = NavigationResult.body;
+        let _: bool = NavigationResult.truncated;
+        let _: Option<i64> = NavigationResult.cached_at;
+        let _: bool = NavigationResult.stale;
+        let _: u64 = NavigationResult.duration_ms;
+        let _: holloway_core::api::models::navigation_result::RequestTimings =
+            NavigationResult.timings;
+        let _: Option<holloway_core::api::models::navigation_result::CertificateSummary> =
+            NavigationResult.certificate;
+        let _: Option<holloway_core::api::models::server_failure::ServerFailure> =
+            NavigationResult.server_failure;
+        let _: u64 = NavigationResult.request_id;
+    }
+    {
+        let OutlineEntry = None::<holloway_core::api::functions::anchors::OutlineEntry>.unwrap();
+        let _: holloway_core::api::functions::anchors::HeadingAnchor = OutlineEntry.anchor;
+        let _: u8 = OutlineEntry.depth;
+        let _: Option<u32> = OutlineEntry.parent;
+    }
+    {
+        let PageDiff = None::<holloway_core::api::functions::page_diff::PageDiff>.unwrap();
+        let _: String = PageDiff.url;
+        let _: i64 = PageDiff.cached_at;
+        let _: Vec<holloway_core::api::functions::page_diff::DiffLine> = PageDiff.added;
+        let _: Vec<holloway_core::api::functions::page_diff::DiffLine> = PageDiff.removed;
+    }
+    {
+        let PageLink = None::<holloway_core::api::functions::links::PageLink>.unwrap();
+        let _: String = PageLink.url;
+        let _: Option<String> = PageLink.label;
+        let _: holloway_core::api::functions::links::LinkKind = PageLink.kind;
+    }
+    {
+        let PageSummary = None::<holloway_core::api::functions::page_summary::PageSummary>.unwrap();
+        let _: String = PageSummary.url;
+        let _: Option<String> = PageSummary.title;
+        let _: Option<String> = PageSummary.description;
+        let _: Option<String> = PageSummary.favicon;
+        let _: Option<i64> = PageSummary.cached_at;
+    }
+    {
+        let PeekResult = None::<holloway_core::api::functions::peek::PeekResult>.unwrap();
+        let _: String = PeekResult.url;
+        let _: Option<u8> = PeekResult.status;
+        let _: Option<String> = PeekResult.meta;
+        let _: Option<String> = PeekResult.mime;
+        let _: Option<holloway_core::api::functions::content_router::Renderer> =
+            PeekResult.renderer;
+        let _: Option<u64> = PeekResult.size;
+    }
+    {
+        let PortOverride = None::<holloway_core::api::storage::ports::PortOverride>.unwrap();
+        let _: String = PortOverride.scheme;
+        let _: Option<String> = PortOverride.host;
+        let _: u16 = PortOverride.port;
+    }
+    {
+        let PreformattedBlock =
+            None::<holloway_core::api::functions::text_width::PreformattedBlock>.unwrap();
+        let _: u32 = PreformattedBlock.start_index;
+        let _: u32 = PreformattedBlock.line_count;
+        let _: Option<String> = PreformattedBlock.alt;
+        let _: u32 = PreformattedBlock.max_width;
+        let _: bool = PreformattedBlock.has_wide_chars;
+    }
+    {
+        let ProtocolCapabilities =
+            None::<holloway_core::api::protocols::registry::ProtocolCapabilities>.unwrap();
+        let _: bool = ProtocolCapabilities.user_input;
+        let _: bool = ProtocolCapabilities.redirects;
+        let _: bool = ProtocolCapabilities.client_certificates;
+        let _: bool = ProtocolCapabilities.menus;
+    }
+    {
+        let ProtocolInfo = None::<holloway_core::api::protocols::registry::ProtocolInfo>.unwrap();
+        let _: String = ProtocolInfo.scheme;
+        let _: u16 = ProtocolInfo.default_port;
+        let _: bool = ProtocolInfo.requires_tls;
+        let _: holloway_core::api::protocols::registry::ProtocolCapabilities =
+            ProtocolInfo.capabilities;
+        let _: bool = ProtocolInfo.enabled;
+    }
+    {
+        let ProviderFailure =
+            None::<holloway_core::api::functions::metasearch::ProviderFailure>.unwrap();
+        let _: String = ProviderFailure.name;
+        let _: String = ProviderFailure.error;
+    }
+    {
+        let ProxyProbe = None::<holloway_core::api::transport::proxy::ProxyProbe>.unwrap();
+        let _: u32 = ProxyProbe.handshake_ms;
+        let _: u32 = ProxyProbe.connect_ms;
+    }
+    {
+        let ProxyRule = None::<holloway_core::api::transport::proxy::ProxyRule>.unwrap();
+        let _: String = ProxyRule.pattern;
+        let _: Option<holloway_core::api::transport::proxy::Socks5Proxy> = ProxyRule.proxy;
+    }
+    match None::<crate::api::mirrors::ProxySettings>.unwrap() {
+        crate::api::mirrors::ProxySettings::Direct => {}
+        crate::api::mirrors::ProxySettings::Socks5 { proxy } => {
+            let _: holloway_core::api::transport::proxy::Socks5Proxy = proxy;
+        }
+        crate::api::mirrors::ProxySettings::PerRule { rules, fallback } => {
+            let _: Vec<holloway_core::api::transport::proxy::ProxyRule> = rules;
+            let _: Option<holloway_core::api::transport::proxy::Socks5Proxy> = fallback;
+        }
+    }
+    {
+        let RandomDestination =
+            None::<holloway_core::api::functions::explore::RandomDestination>.unwrap();
+        let _: String = RandomDestination.url;
+        let _: Option<String> = RandomDestination.label;
+        let _: Option<String> = RandomDestination.directory_url;
+    }
+    {
+        let RateLimits = None::<holloway_core::api::transport::rate_limit::RateLimits>.unwrap();
+        let _: u32 = RateLimits.max_connections;
+        let _: u64 = RateLimits.min_interval_ms;
+    }
+    {
+        let ReadLaterItem = None::<holloway_core::api::storage::read_later::ReadLaterItem>.unwrap();
+        let _: i64 = ReadLaterItem.id;
+        let _: String = ReadLaterItem.url;
+        let _: Option<String> = ReadLaterItem.title;
+        let _: i64 = ReadLaterItem.added_at;
+        let _: Option<i64> = ReadLaterItem.read_at;
+        let _: bool = ReadLaterItem.cached;
+        let _: Option<i64> = ReadLaterItem.fetched_at;
+        let _: u64 = ReadLaterItem.size;
+        let _: Option<String> = ReadLaterItem.last_error;
+    }
+    {
+        let ReadingStats =
+            None::<holloway_core::api::functions::reading_stats::ReadingStats>.unwrap();
+        let _: u32 = ReadingStats.words;
+        let _: u32 = ReadingStats.preformatted_words;
+        let _: u32 = ReadingStats.reading_minutes;
+        let _: u32 = ReadingStats.links;
+        let _: u32 = ReadingStats.headings;
+    }
+    {
+        let ReflowedLine = None::<holloway_core::api::functions::reflow::ReflowedLine>.unwrap();
+        let _: u32 = ReflowedLine.source_index;
+        let _: crate::api::mirrors::GemtextLine = ReflowedLine.line;
+        let _: bool = ReflowedLine.continuation;
+    }
+    {
+        let RequestTimings =
+            None::<holloway_core::api::models::navigation_result::RequestTimings>.unwrap();
+        let _: Option<u64> = RequestTimings.dns_ms;
+        let _: Option<u64> = RequestTimings.connect_ms;
+        let _: Option<u64> = RequestTimings.tls_ms;
+        let _: Option<u64> = RequestTimings.first_byte_ms;
+        let _: Option<u64> = RequestTimings.transfer_ms;
+        let _: u64 = RequestTimings.bytes_sent;
+        let _: u64 = RequestTimings.bytes_received;
+    }
+    {
+        let SavedAnswer = None::<holloway_core::api::storage::input_answers::SavedAnswer>.unwrap();
+        let _: i64 = SavedAnswer.id;
+        let _: String = SavedAnswer.url;
+        let _: String = SavedAnswer.prompt;
+        let _: String = SavedAnswer.answer;
+        let _: i64 = SavedAnswer.answered_at;
+        let _: u32 = SavedAnswer.uses;
+    }
+    {
+        let SchedulerEvent =
+            None::<holloway_core::api::functions::scheduler::SchedulerEvent>.unwrap();
+        let _: holloway_core::api::functions::scheduler::ScheduledTask = SchedulerEvent.task;
+        let _: holloway_core::api::functions::scheduler::SchedulerEventKind = SchedulerEvent.kind;
+        let _: i64 = SchedulerEvent.timestamp;
+        let _: u32 = SchedulerEvent.count;
+        let _: Vec<String> = SchedulerEvent.items;
+        let _: Option<String> = SchedulerEvent.message;
+        let _: Option<i64> = SchedulerEvent.next_run_at;
+    }
+    {
+        let SchemeProxy = None::<holloway_core::api::storage::gemini_proxies::SchemeProxy>.unwrap();
+        let _: String = SchemeProxy.scheme;
+        let _: holloway_core::api::storage::gemini_proxies::ProxyProtocol = SchemeProxy.protocol;
+        let _: String = SchemeProxy.host;
+        let _: u16 = SchemeProxy.port;
+        let _: String = SchemeProxy.selector;
+    }
+    {
+        let SearchHit = None::<holloway_core::api::functions::metasearch::SearchHit>.unwrap();
+        let _: String = SearchHit.url;
+        let _: String = SearchHit.title;
+        let _: Option<String> = SearchHit.snippet;
+        let _: Vec<String> = SearchHit.providers;
+        let _: f64 = SearchHit.score;
+    }
+    {
+        let SearchProvider = None::<holloway_core::api::functions::search::SearchProvider>.unwrap();
+        let _: String = SearchProvider.name;
+        let _: String = SearchProvider.url_template;
+    }
+    {
+        let ServerFailure =
+            None::<holloway_core::api::models::server_failure::ServerFailure>.unwrap();
+        let _: u8 = ServerFailure.status;
+        let _: holloway_core::api::models::server_failure::ServerFailureKind = ServerFailure.kind;
+        let _: String = ServerFailure.message;
+        let _: bool = ServerFailure.temporary;
+        let _: Option<u32> = ServerFailure.retry_after_secs;
+        let _: Option<holloway_core::api::models::error_info::RecoveryAction> =
+            ServerFailure.suggested_action;
+    }
+    {
+        let Session = None::<holloway_core::api::storage::sessions::Session>.unwrap();
+        let _: String = Session.name;
+        let _: Vec<holloway_core::api::storage::sessions::SessionTab> = Session.tabs;
+        let _: u32 = Session.active_tab;
+        let _: i64 = Session.saved_at;
+    }
+    {
+        let SessionEntry = None::<holloway_core::api::storage::sessions::SessionEntry>.unwrap();
+        let _: String = SessionEntry.url;
+        let _: Option<String> = SessionEntry.title;
+        let _: f64 = SessionEntry.scroll_offset;
+    }
+    {
+        let SessionSummary = None::<holloway_core::api::storage::sessions::SessionSummary>.unwrap();
+        let _: String = SessionSummary.name;
+        let _: u32 = SessionSummary.tab_count;
+        let _: i64 = SessionSummary.saved_at;
+    }
+    {
+        let SessionTab = None::<holloway_core::api::storage::sessions::SessionTab>.unwrap();
+        let _: Vec<holloway_core::api::storage::sessions::SessionEntry> = SessionTab.entries;
+        let _: u32 = SessionTab.current_entry;
+        let _: bool = SessionTab.private;
+    }
+    {
+        let ShutdownSummary =
+            None::<holloway_core::api::functions::shutdown::ShutdownSummary>.unwrap();
+        let _: u32 = ShutdownSummary.streams_cancelled;
+        let _: u32 = ShutdownSummary.fetches_aborted;
+        let _: u32 = ShutdownSummary.requests_cancelled;
+        let _: u32 = ShutdownSummary.mirrors_cancelled;
+    }
+    {
+        let SiteMap = None::<holloway_core::api::functions::site_map::SiteMap>.unwrap();
+        let _: String = SiteMap.root;
+        let _: Vec<holloway_core::api::functions::site_map::SiteNode> = SiteMap.nodes;
+        let _: bool = SiteMap.truncated;
+    }
+    {
+        let SiteNode = None::<holloway_core::api::functions::site_map::SiteNode>.unwrap();
+        let _: String = SiteNode.url;
+        let _: String = SiteNode.title;
+        let _: holloway_core::api::functions::links::LinkKind = SiteNode.kind;
+        let _: String = SiteNode.icon;
+        let _: bool = SiteNode.menu;
+        let _: u32 = SiteNode.depth;
+        let _: Option<u32> = SiteNode.parent;
+        let _: bool = SiteNode.expanded;
+        let _: Option<String> = SiteNode.error;
+    }
+    {
+        let SnippetPart = None::<holloway_core::api::storage::page_index::SnippetPart>.unwrap();
+        let _: String = SnippetPart.text;
+        let _: bool = SnippetPart.matched;
+    }
+    {
+        let Socks5Proxy = None::<holloway_core::api::transport::proxy::Socks5Proxy>.unwrap();
+        let _: String = Socks5Proxy.host;
+        let _: u16 = Socks5Proxy.port;
+        let _: Option<String> = Socks5Proxy.username;
+        let _: Option<String> = Socks5Proxy.password;
+    }
+    {
+        let StreamEvent = None::<holloway_core::api::models::stream_event::StreamEvent>.unwrap();
+        let _: holloway_core::api::models::stream_event::StreamEventKind = StreamEvent.kind;
+        let _: Option<u8> = StreamEvent.status;
+        let _: Option<String> = StreamEvent.meta;
+        let _: Option<String> = StreamEvent.text;
+        let _: Option<crate::api::mirrors::GemtextLine> = StreamEvent.element;
+        let _: u64 = StreamEvent.bytes_received;
+    }
+    {
+        let StreamOptions =
+            None::<holloway_core::api::functions::streaming::StreamOptions>.unwrap();
+        let _: u32 = StreamOptions.idle_interval_secs;
+        let _: Option<u32> = StreamOptions.max_idle_secs;
+    }
+    {
+        let StyledSpan = None::<holloway_core::api::functions::ansi::StyledSpan>.unwrap();
+        let _: u32 = StyledSpan.start;
+        let _: u32 = StyledSpan.end;
+        let _: holloway_core::api::functions::ansi::AnsiStyle = StyledSpan.style;
+    }
+    {
+        let StyledText = None::<holloway_core::api::functions::ansi::StyledText>.unwrap();
+        let _: String = StyledText.text;
+        let _: Vec<holloway_core::api::functions::ansi::StyledSpan> = StyledText.spans;
+    }
+    {
+        let Suggestion = None::<holloway_core::api::functions::suggestions::Suggestion>.unwrap();
+        let _: String = Suggestion.url;
+        let _: Option<String> = Suggestion.title;
+        let _: bool = Suggestion.bookmarked;
+        let _: u32 = Suggestion.visit_count;
+        let _: Option<i64> = Suggestion.last_visited;
+        let _: holloway_core::api::functions::suggestions::SuggestionMatch = Suggestion.matched;
+        let _: Option<String> = Suggestion.completion;
+    }
+    {
+        let TaskSchedule = None::<holloway_core::api::functions::scheduler::TaskSchedule>.unwrap();
+        let _: holloway_core::api::functions::scheduler::ScheduledTask = TaskSchedule.task;
+        let _: bool = TaskSchedule.enabled;
+        let _: u32 = TaskSchedule.interval_secs;
+        let _: u32 = TaskSchedule.jitter_secs;
+        let _: bool = TaskSchedule.unmetered_only;
+        let _: bool = TaskSchedule.charging_only;
+    }
+    {
+        let TlsFailure = None::<holloway_core::api::models::tls_failure::TlsFailure>.unwrap();
+        let _: holloway_core::api::models::tls_failure::TlsFailureKind = TlsFailure.kind;
+        let _: String = TlsFailure.host;
+        let _: String = TlsFailure.message;
+        let _: Vec<String> = TlsFailure.names;
+        let _: String = TlsFailure.subject;
+        let _: String = TlsFailure.issuer;
+        let _: i64 = TlsFailure.not_before;
+        let _: i64 = TlsFailure.not_after;
+        let _: u32 = TlsFailure.intermediates;
+    }
+    {
+        let TokenSpan = None::<holloway_core::api::functions::highlighting::TokenSpan>.unwrap();
+        let _: String = TokenSpan.text;
+        let _: holloway_core::api::functions::highlighting::TokenKind = TokenSpan.kind;
+    }
+    {
+        let TopSite = None::<holloway_core::api::functions::top_sites::TopSite>.unwrap();
+        let _: String = TopSite.url;
+        let _: String = TopSite.host;
+        let _: Option<String> = TopSite.title;
+        let _: Option<String> = TopSite.favicon;
+        let _: u32 = TopSite.visit_count;
+        let _: i64 = TopSite.last_visited;
+        let _: f64 = TopSite.score;
+    }
+    {
+        let TraceEvent = None::<holloway_core::api::functions::request_trace::TraceEvent>.unwrap();
+        let _: holloway_core::api::functions::request_trace::TraceStage = TraceEvent.stage;
+        let _: String = TraceEvent.message;
+        let _: u64 = TraceEvent.elapsed_ms;
+    }
+    {
+        let TrustedCa = None::<holloway_core::api::storage::trusted_cas::TrustedCa>.unwrap();
+        let _: String = TrustedCa.fingerprint;
+        let _: String = TrustedCa.subject;
+        let _: i64 = TrustedCa.not_after;
+        let _: i64 = TrustedCa.added_at;
+    }
+    {
+        let WatchedPage = None::<holloway_core::api::storage::watches::WatchedPage>.unwrap();
+        let _: i64 = WatchedPage.id;
+        let _: String = WatchedPage.url;
+        let _: Option<String> = WatchedPage.title;
+        let _: i64 = WatchedPage.added_at;
+        let _: Option<i64> = WatchedPage.seen_at;
+        let _: Option<i64> = WatchedPage.checked_at;
+        let _: Option<i64> = WatchedPage.changed_at;
+        let _: Option<String> = WatchedPage.last_error;
+    }
+    {
+        let WatchedPlan = None::<holloway_core::api::storage::plan_watches::WatchedPlan>.unwrap();
+        let _: i64 = WatchedPlan.id;
+        let _: String = WatchedPlan.url;
+        let _: i64 = WatchedPlan.added_at;
+        let _: Option<String> = WatchedPlan.plan;
+        let _: Option<i64> = WatchedPlan.checked_at;
+        let _: Option<i64> = WatchedPlan.updated_at;
+        let _: Option<String> = WatchedPlan.last_error;
+    }
+};
+
+// Section: dart2rust
+
+impl SseDecode for char {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return inner.chars().next().unwrap();
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::bookmarks::AddedBookmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bookmark =
+            <holloway_core::api::storage::bookmarks::Bookmark>::sse_decode(deserializer);
+        let mut var_duplicate = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::bookmarks::AddedBookmark {
+            bookmark: var_bookmark,
+            duplicate: var_duplicate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mirrors::AnsiColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <u8>::sse_decode(deserializer);
+                return crate::api::mirrors::AnsiColor::Indexed(var_field0);
+            }
+            1 => {
+                let mut var_r = <u8>::sse_decode(deserializer);
+                let mut var_g = <u8>::sse_decode(deserializer);
+                let mut var_b = <u8>::sse_decode(deserializer);
+                return crate::api::mirrors::AnsiColor::Rgb {
+                    r: var_r,
+                    g: var_g,
+                    b: var_b,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::ansi::AnsiStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_foreground = <Option<crate::api::mirrors::AnsiColor>>::sse_decode(deserializer);
+        let mut var_background = <Option<crate::api::mirrors::AnsiColor>>::sse_decode(deserializer);
+        let mut var_bold = <bool>::sse_decode(deserializer);
+        let mut var_dim = <bool>::sse_decode(deserializer);
+        let mut var_italic = <bool>::sse_decode(deserializer);
+        let mut var_underline = <bool>::sse_decode(deserializer);
+        let mut var_inverse = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::ansi::AnsiStyle {
+            foreground: var_foreground,
+            background: var_background,
+            bold: var_bold,
+            dim: var_dim,
+            italic: var_italic,
+            underline: var_underline,
+            inverse: var_inverse,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::archive::ArchivedPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_bodyHash = <String>::sse_decode(deserializer);
+        let mut var_certificateFingerprint = <Option<String>>::sse_decode(deserializer);
+        let mut var_fetchedAt = <i64>::sse_decode(deserializer);
+        let mut var_archivedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::archive::ArchivedPage {
+            id: var_id,
+            url: var_url,
+            status: var_status,
+            mime: var_mime,
+            title: var_title,
+            size: var_size,
+            body_hash: var_bodyHash,
+            certificate_fingerprint: var_certificateFingerprint,
+            fetched_at: var_fetchedAt,
+            archived_at: var_archivedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::batch::BatchItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_result = <Option<
+            holloway_core::api::models::navigation_result::NavigationResult,
+        >>::sse_decode(deserializer);
+        let mut var_error =
+            <Option<holloway_core::api::models::error_info::ErrorInfo>>::sse_decode(deserializer);
+        return holloway_core::api::functions::batch::BatchItem {
+            index: var_index,
+            url: var_url,
+            request_id: var_requestId,
+            result: var_result,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::blocklist::BlockRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pattern = <String>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::storage::blocklist::BlockRuleKind>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::blocklist::BlockRule {
+            pattern: var_pattern,
+            kind: var_kind,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::blocklist::BlockRuleKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::blocklist::BlockRuleKind::ExactHost,
+            1 => holloway_core::api::storage::blocklist::BlockRuleKind::Subdomains,
+            2 => holloway_core::api::storage::blocklist::BlockRuleKind::UrlPrefix,
+            _ => unreachable!("Invalid variant for BlockRuleKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::bookmarks::Bookmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_favicon = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::storage::bookmarks::Bookmark {
+            id: var_id,
+            url: var_url,
+            title: var_title,
+            created_at: var_createdAt,
+            favicon: var_favicon,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bookmark_export::BookmarkFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::bookmark_export::BookmarkFormat::Xbel,
+            1 => holloway_core::api::functions::bookmark_export::BookmarkFormat::Gemtext,
+            2 => holloway_core::api::functions::bookmark_export::BookmarkFormat::Html,
+            _ => unreachable!("Invalid variant for BookmarkFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bookmark_import::BookmarkSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::bookmark_import::BookmarkSource::Amfora,
+            1 => holloway_core::api::functions::bookmark_import::BookmarkSource::Lagrange,
+            _ => unreachable!("Invalid variant for BookmarkSource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bookmark_import::BookmarksImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::bookmark_import::BookmarksImport {
+            imported: var_imported,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::cache::CacheEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_fetchedAt = <i64>::sse_decode(deserializer);
+        let mut var_expiresAt = <i64>::sse_decode(deserializer);
+        let mut var_stale = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::cache::CacheEntry {
+            url: var_url,
+            host: var_host,
+            mime: var_mime,
+            size: var_size,
+            fetched_at: var_fetchedAt,
+            expires_at: var_expiresAt,
+            stale: var_stale,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::cache::CachePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_cacheable = <bool>::sse_decode(deserializer);
+        let mut var_ttlSecs = <u32>::sse_decode(deserializer);
+        let mut var_cacheQueries = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::cache::CachePolicy {
+            scheme: var_scheme,
+            cacheable: var_cacheable,
+            ttl_secs: var_ttlSecs,
+            cache_queries: var_cacheQueries,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::certificate_error::CertificateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <holloway_core::api::models::certificate_error::CertificateErrorKind>::sse_decode(
+                deserializer,
+            );
+        let mut var_identityId = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_suggestedAction =
+            <holloway_core::api::models::error_info::RecoveryAction>::sse_decode(deserializer);
+        return holloway_core::api::models::certificate_error::CertificateError {
+            kind: var_kind,
+            identity_id: var_identityId,
+            message: var_message,
+            suggested_action: var_suggestedAction,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::certificate_error::CertificateErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::certificate_error::CertificateErrorKind::Required,
+            1 => holloway_core::api::models::certificate_error::CertificateErrorKind::NotAuthorized,
+            2 => holloway_core::api::models::certificate_error::CertificateErrorKind::NotValid,
+            _ => unreachable!("Invalid variant for CertificateErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_result::CertificateSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_issuer = <String>::sse_decode(deserializer);
+        let mut var_notBefore = <i64>::sse_decode(deserializer);
+        let mut var_notAfter = <i64>::sse_decode(deserializer);
+        let mut var_firstSeen = <i64>::sse_decode(deserializer);
+        let mut var_pinStatus =
+            <holloway_core::api::models::navigation_result::PinStatus>::sse_decode(deserializer);
+        let mut var_expiry =
+            <holloway_core::api::models::navigation_result::PinExpiry>::sse_decode(deserializer);
+        let mut var_tlsVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_cipherSuite = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::models::navigation_result::CertificateSummary {
+            fingerprint: var_fingerprint,
+            subject: var_subject,
+            issuer: var_issuer,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            first_seen: var_firstSeen,
+            pin_status: var_pinStatus,
+            expiry: var_expiry,
+            tls_version: var_tlsVersion,
+            cipher_suite: var_cipherSuite,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::downloads::ChecksumStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::downloads::ChecksumStatus::Unchecked,
+            1 => holloway_core::api::storage::downloads::ChecksumStatus::Verified,
+            2 => holloway_core::api::storage::downloads::ChecksumStatus::Mismatch,
+            3 => holloway_core::api::storage::downloads::ChecksumStatus::Unavailable,
+            _ => unreachable!("Invalid variant for ChecksumStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::clear::ClearedData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category =
+            <holloway_core::api::storage::clear::DataCategory>::sse_decode(deserializer);
+        let mut var_removed = <u32>::sse_decode(deserializer);
+        return holloway_core::api::storage::clear::ClearedData {
+            category: var_category,
+            removed: var_removed,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::clock_skew::ClockOffset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offsetSecs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_hosts = <u32>::sse_decode(deserializer);
+        let mut var_consistent = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::clock_skew::ClockOffset {
+            offset_secs: var_offsetSecs,
+            hosts: var_hosts,
+            consistent: var_consistent,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::config::Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_defaultScheme = <String>::sse_decode(deserializer);
+        let mut var_guessSchemes = <bool>::sse_decode(deserializer);
+        let mut var_startPage = <String>::sse_decode(deserializer);
+        let mut var_searchProvider = <String>::sse_decode(deserializer);
+        let mut var_connectTimeoutSecs = <u32>::sse_decode(deserializer);
+        let mut var_readTimeoutSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_tlsPolicy =
+            <holloway_core::api::storage::host_settings::TlsPolicy>::sse_decode(deserializer);
+        let mut var_proxy = <crate::api::mirrors::ProxySettings>::sse_decode(deserializer);
+        return holloway_core::api::functions::config::Config {
+            default_scheme: var_defaultScheme,
+            guess_schemes: var_guessSchemes,
+            start_page: var_startPage,
+            search_provider: var_searchProvider,
+            connect_timeout_secs: var_connectTimeoutSecs,
+            read_timeout_secs: var_readTimeoutSecs,
+            tls_policy: var_tlsPolicy,
+            proxy: var_proxy,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::crawler::CrawlResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_capsule = <String>::sse_decode(deserializer);
+        let mut var_indexed = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_disallowed = <u32>::sse_decode(deserializer);
+        let mut var_remaining = <u32>::sse_decode(deserializer);
+        let mut var_cancelled = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::crawler::CrawlResult {
+            capsule: var_capsule,
+            indexed: var_indexed,
+            failed: var_failed,
+            disallowed: var_disallowed,
+            remaining: var_remaining,
+            cancelled: var_cancelled,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::protocols::cso::CsoField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return holloway_core::api::protocols::cso::CsoField {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::protocols::cso::CsoRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_fields =
+            <Vec<holloway_core::api::protocols::cso::CsoField>>::sse_decode(deserializer);
+        return holloway_core::api::protocols::cso::CsoRecord {
+            index: var_index,
+            fields: var_fields,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::data_usage::DailyUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dayStart = <i64>::sse_decode(deserializer);
+        let mut var_network =
+            <holloway_core::api::storage::data_usage::NetworkType>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        return holloway_core::api::storage::data_usage::DailyUsage {
+            day_start: var_dayStart,
+            network: var_network,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::data_usage::DataCapStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_limitBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_usedBytes = <u64>::sse_decode(deserializer);
+        let mut var_exceeded = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::data_usage::DataCapStatus {
+            limit_bytes: var_limitBytes,
+            used_bytes: var_usedBytes,
+            exceeded: var_exceeded,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::clear::DataCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::clear::DataCategory::History,
+            1 => holloway_core::api::storage::clear::DataCategory::Cache,
+            2 => holloway_core::api::storage::clear::DataCategory::DataUsage,
+            3 => holloway_core::api::storage::clear::DataCategory::Downloads,
+            4 => holloway_core::api::storage::clear::DataCategory::Favicons,
+            5 => holloway_core::api::storage::clear::DataCategory::HostStats,
+            6 => holloway_core::api::storage::clear::DataCategory::InputAnswers,
+            7 => holloway_core::api::storage::clear::DataCategory::IdentityActivations,
+            8 => holloway_core::api::storage::clear::DataCategory::Identities,
+            9 => holloway_core::api::storage::clear::DataCategory::KnownHosts,
+            _ => unreachable!("Invalid variant for DataCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::transport::limits::DecompressionLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxRatio = <u32>::sse_decode(deserializer);
+        let mut var_maxBytes = <u64>::sse_decode(deserializer);
+        return holloway_core::api::transport::limits::DecompressionLimits {
+            max_ratio: var_maxRatio,
+            max_bytes: var_maxBytes,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::page_diff::DiffLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_line = <u32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::page_diff::DiffLine {
+            line: var_line,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::homograph::DisplayHost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_spoofingWarning = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::homograph::DisplayHost {
+            host: var_host,
+            spoofing_warning: var_spoofingWarning,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::downloads::DownloadRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_status =
+            <holloway_core::api::storage::downloads::DownloadStatus>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_finishedAt = <i64>::sse_decode(deserializer);
+        let mut var_checksumUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_checksum =
+            <holloway_core::api::storage::downloads::ChecksumStatus>::sse_decode(deserializer);
+        return holloway_core::api::storage::downloads::DownloadRecord {
+            id: var_id,
+            url: var_url,
+            path: var_path,
+            size: var_size,
+            mime: var_mime,
+            status: var_status,
+            error: var_error,
+            finished_at: var_finishedAt,
+            checksum_url: var_checksumUrl,
+            checksum: var_checksum,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::downloads::DownloadResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        let mut var_checksum =
+            <holloway_core::api::storage::downloads::ChecksumStatus>::sse_decode(deserializer);
+        return holloway_core::api::functions::downloads::DownloadResult {
+            path: var_path,
+            size: var_size,
+            mime: var_mime,
+            sha256: var_sha256,
+            checksum: var_checksum,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::downloads::DownloadStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::downloads::DownloadStatus::Completed,
+            1 => holloway_core::api::storage::downloads::DownloadStatus::Failed,
+            _ => unreachable!("Invalid variant for DownloadStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::error_info::ErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::error_info::ErrorCode::UrlInvalid,
+            1 => holloway_core::api::models::error_info::ErrorCode::UrlUnsupportedScheme,
+            2 => holloway_core::api::models::error_info::ErrorCode::UrlSchemeDisabled,
+            3 => holloway_core::api::models::error_info::ErrorCode::UrlBlocked,
+            4 => holloway_core::api::models::error_info::ErrorCode::UrlDisallowed,
+            5 => holloway_core::api::models::error_info::ErrorCode::NetDnsFailed,
+            6 => holloway_core::api::models::error_info::ErrorCode::NetTimeout,
+            7 => holloway_core::api::models::error_info::ErrorCode::NetRefused,
+            8 => holloway_core::api::models::error_info::ErrorCode::NetConnectFailed,
+            9 => holloway_core::api::models::error_info::ErrorCode::NetConnectionLost,
+            10 => holloway_core::api::models::error_info::ErrorCode::NetOffline,
+            11 => holloway_core::api::models::error_info::ErrorCode::NetReadTimeout,
+            12 => holloway_core::api::models::error_info::ErrorCode::ProxyUnreachable,
+            13 => holloway_core::api::models::error_info::ErrorCode::ProxyAuthFailed,
+            14 => holloway_core::api::models::error_info::ErrorCode::ProxyFailed,
+            15 => holloway_core::api::models::error_info::ErrorCode::GatewayFailed,
+            16 => holloway_core::api::models::error_info::ErrorCode::ContentTooLarge,
+            17 => holloway_core::api::models::error_info::ErrorCode::TlsHandshakeFailed,
+            18 => holloway_core::api::models::error_info::ErrorCode::TlsFingerprintMismatch,
+            19 => holloway_core::api::models::error_info::ErrorCode::TlsCertificateDate,
+            20 => holloway_core::api::models::error_info::ErrorCode::TlsCertificateInvalid,
+            21 => holloway_core::api::models::error_info::ErrorCode::StoreFailed,
+            22 => holloway_core::api::models::error_info::ErrorCode::IdentityFailed,
+            23 => holloway_core::api::models::error_info::ErrorCode::NotFound,
+            24 => holloway_core::api::models::error_info::ErrorCode::InputInvalid,
+            25 => holloway_core::api::models::error_info::ErrorCode::Cancelled,
+            26 => holloway_core::api::models::error_info::ErrorCode::ResponseInvalid,
+            27 => holloway_core::api::models::error_info::ErrorCode::GemInputRequired,
+            28 => holloway_core::api::models::error_info::ErrorCode::GemRedirect,
+            29 => holloway_core::api::models::error_info::ErrorCode::GemServerError,
+            30 => holloway_core::api::models::error_info::ErrorCode::GemCertificateRequired,
+            31 => holloway_core::api::models::error_info::ErrorCode::GemTimeout,
+            32 => holloway_core::api::models::error_info::ErrorCode::GopherBadType,
+            33 => holloway_core::api::models::error_info::ErrorCode::GopherBadSelector,
+            34 => holloway_core::api::models::error_info::ErrorCode::Unknown,
+            _ => unreachable!("Invalid variant for ErrorCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::error_info::ErrorInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code =
+            <holloway_core::api::models::error_info::ErrorCode>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_number = <u32>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_retryable = <bool>::sse_decode(deserializer);
+        let mut var_userActionable = <bool>::sse_decode(deserializer);
+        let mut var_suggestedAction = <Option<
+            holloway_core::api::models::error_info::RecoveryAction,
+        >>::sse_decode(deserializer);
+        return holloway_core::api::models::error_info::ErrorInfo {
+            code: var_code,
+            name: var_name,
+            number: var_number,
+            message: var_message,
+            retryable: var_retryable,
+            user_actionable: var_userActionable,
+            suggested_action: var_suggestedAction,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::identities::ExpiryReminder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_identity =
+            <holloway_core::api::storage::identities::Identity>::sse_decode(deserializer);
+        let mut var_daysLeft = <i64>::sse_decode(deserializer);
+        let mut var_expired = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::identities::ExpiryReminder {
+            identity: var_identity,
+            days_left: var_daysLeft,
+            expired: var_expired,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::explore::ExploreSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_directoryUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_weight = <f64>::sse_decode(deserializer);
+        return holloway_core::api::functions::explore::ExploreSource {
+            directory_url: var_directoryUrl,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::feeds::Feed {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::feed_discovery::FeedKind>::sse_decode(deserializer);
+        let mut var_subscribedAt = <i64>::sse_decode(deserializer);
+        let mut var_refreshedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_unread = <u32>::sse_decode(deserializer);
+        return holloway_core::api::storage::feeds::Feed {
+            id: var_id,
+            url: var_url,
+            title: var_title,
+            kind: var_kind,
+            subscribed_at: var_subscribedAt,
+            refreshed_at: var_refreshedAt,
+            last_error: var_lastError,
+            unread: var_unread,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::feed_discovery::FeedCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::feed_discovery::FeedKind>::sse_decode(deserializer);
+        return holloway_core::api::functions::feed_discovery::FeedCandidate {
+            url: var_url,
+            title: var_title,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::feeds::FeedEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_feedId = <i64>::sse_decode(deserializer);
+        let mut var_feedTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_publishedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_seenAt = <i64>::sse_decode(deserializer);
+        let mut var_read = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::feeds::FeedEntry {
+            id: var_id,
+            feed_id: var_feedId,
+            feed_title: var_feedTitle,
+            url: var_url,
+            title: var_title,
+            published_at: var_publishedAt,
+            seen_at: var_seenAt,
+            read: var_read,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::feed_discovery::FeedKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::feed_discovery::FeedKind::Atom,
+            1 => holloway_core::api::functions::feed_discovery::FeedKind::Rss,
+            2 => holloway_core::api::functions::feed_discovery::FeedKind::Gemfeed,
+            3 => holloway_core::api::functions::feed_discovery::FeedKind::Unknown,
+            _ => unreachable!("Invalid variant for FeedKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::fetch_options::FetchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_private = <bool>::sse_decode(deserializer);
+        let mut var_useCache = <bool>::sse_decode(deserializer);
+        let mut var_revalidate = <bool>::sse_decode(deserializer);
+        let mut var_probeProtocols = <bool>::sse_decode(deserializer);
+        let mut var_background = <bool>::sse_decode(deserializer);
+        let mut var_identityId = <Option<String>>::sse_decode(deserializer);
+        let mut var_connectTimeoutSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_readTimeoutSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_deadlineSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_maxBodyBytes = <Option<u64>>::sse_decode(deserializer);
+        return holloway_core::api::models::fetch_options::FetchOptions {
+            private: var_private,
+            use_cache: var_useCache,
+            revalidate: var_revalidate,
+            probe_protocols: var_probeProtocols,
+            background: var_background,
+            identity_id: var_identityId,
+            connect_timeout_secs: var_connectTimeoutSecs,
+            read_timeout_secs: var_readTimeoutSecs,
+            deadline_secs: var_deadlineSecs,
+            max_body_bytes: var_maxBodyBytes,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::fetch_response::FetchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_redirects = <Vec<String>>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_meta = <Option<String>>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_renderer =
+            <holloway_core::api::functions::content_router::Renderer>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_bodyHash = <String>::sse_decode(deserializer);
+        let mut var_unchanged = <bool>::sse_decode(deserializer);
+        let mut var_truncated = <bool>::sse_decode(deserializer);
+        let mut var_stale = <bool>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_feeds =
+            <Vec<holloway_core::api::functions::feed_discovery::FeedCandidate>>::sse_decode(
+                deserializer,
+            );
+        let mut var_favicon = <Option<String>>::sse_decode(deserializer);
+        let mut var_fragment = <Option<String>>::sse_decode(deserializer);
+        let mut var_fragmentLine = <Option<u32>>::sse_decode(deserializer);
+        let mut var_cachedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_displayHost = <String>::sse_decode(deserializer);
+        let mut var_spoofingWarning = <bool>::sse_decode(deserializer);
+        let mut var_clockSkewWarning = <bool>::sse_decode(deserializer);
+        let mut var_probedScheme = <Option<String>>::sse_decode(deserializer);
+        let mut var_certificateError = <Option<
+            holloway_core::api::models::certificate_error::CertificateError,
+        >>::sse_decode(deserializer);
+        let mut var_serverFailure = <Option<
+            holloway_core::api::models::server_failure::ServerFailure,
+        >>::sse_decode(deserializer);
+        let mut var_certificate = <Option<
+            holloway_core::api::models::navigation_result::CertificateSummary,
+        >>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_timings =
+            <holloway_core::api::models::navigation_result::RequestTimings>::sse_decode(
+                deserializer,
+            );
+        return holloway_core::api::models::fetch_response::FetchResponse {
+            url: var_url,
+            redirects: var_redirects,
+            status: var_status,
+            meta: var_meta,
+            mime: var_mime,
+            renderer: var_renderer,
+            body: var_body,
+            body_hash: var_bodyHash,
+            unchanged: var_unchanged,
+            truncated: var_truncated,
+            stale: var_stale,
+            title: var_title,
+            feeds: var_feeds,
+            favicon: var_favicon,
+            fragment: var_fragment,
+            fragment_line: var_fragmentLine,
+            cached_at: var_cachedAt,
+            request_id: var_requestId,
+            display_host: var_displayHost,
+            spoofing_warning: var_spoofingWarning,
+            clock_skew_warning: var_clockSkewWarning,
+            probed_scheme: var_probedScheme,
+            certificate_error: var_certificateError,
+            server_failure: var_serverFailure,
+            certificate: var_certificate,
+            duration_ms: var_durationMs,
+            timings: var_timings,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::file_type::FileType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_extension_ = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::file_type::FileType {
+            mime: var_mime,
+            extension: var_extension_,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::gempub::GempubBook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_metadata =
+            <holloway_core::api::functions::gempub::GempubMetadata>::sse_decode(deserializer);
+        let mut var_indexPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_chapters =
+            <Vec<holloway_core::api::functions::gempub::GempubChapter>>::sse_decode(deserializer);
+        return holloway_core::api::functions::gempub::GempubBook {
+            handle: var_handle,
+            metadata: var_metadata,
+            index_path: var_indexPath,
+            chapters: var_chapters,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::gempub::GempubChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::gempub::GempubChapter {
+            title: var_title,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::gempub::GempubMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_author = <Option<String>>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_published = <Option<String>>::sse_decode(deserializer);
+        let mut var_version = <Option<String>>::sse_decode(deserializer);
+        let mut var_cover = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::functions::gempub::GempubMetadata {
+            title: var_title,
+            author: var_author,
+            language: var_language,
+            description: var_description,
+            published: var_published,
+            version: var_version,
+            cover: var_cover,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::gempub::GempubPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_chapter = <Option<u32>>::sse_decode(deserializer);
+        let mut var_previousChapter = <Option<u32>>::sse_decode(deserializer);
+        let mut var_nextChapter = <Option<u32>>::sse_decode(deserializer);
+        return holloway_core::api::functions::gempub::GempubPage {
+            path: var_path,
+            mime: var_mime,
+            body: var_body,
+            data: var_data,
+            chapter: var_chapter,
+            previous_chapter: var_previousChapter,
+            next_chapter: var_nextChapter,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mirrors::GemtextLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::Text(var_field0);
+            }
+            1 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                let mut var_label = <Option<String>>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::Link {
+                    url: var_url,
+                    label: var_label,
+                };
+            }
+            2 => {
+                let mut var_level = <u8>::sse_decode(deserializer);
+                let mut var_text = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::Heading {
+                    level: var_level,
+                    text: var_text,
+                };
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::ListItem(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::Quote(var_field0);
+            }
+            5 => {
+                let mut var_alt = <Option<String>>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::PreformatToggle { alt: var_alt };
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::GemtextLine::Preformatted(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for holloway_core::api::parsers::gophermap::GopherItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_itemType = <char>::sse_decode(deserializer);
+        let mut var_display = <String>::sse_decode(deserializer);
+        let mut var_selector = <String>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_icon = <String>::sse_decode(deserializer);
+        let mut var_fetchable = <bool>::sse_decode(deserializer);
+        return holloway_core::api::parsers::gophermap::GopherItem {
+            item_type: var_itemType,
+            display: var_display,
+            selector: var_selector,
+            host: var_host,
+            port: var_port,
+            kind: var_kind,
+            icon: var_icon,
+            fetchable: var_fetchable,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::anchors::HeadingAnchor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_slug = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_level = <u8>::sse_decode(deserializer);
+        let mut var_lineIndex = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::anchors::HeadingAnchor {
+            slug: var_slug,
+            text: var_text,
+            level: var_level,
+            line_index: var_lineIndex,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::highlighting::HighlightedBlock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startIndex = <u32>::sse_decode(deserializer);
+        let mut var_language = <String>::sse_decode(deserializer);
+        let mut var_lines =
+            <Vec<holloway_core::api::functions::highlighting::HighlightedLine>>::sse_decode(
+                deserializer,
+            );
+        return holloway_core::api::functions::highlighting::HighlightedBlock {
+            start_index: var_startIndex,
+            language: var_language,
+            lines: var_lines,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::highlighting::HighlightedLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_spans =
+            <Vec<holloway_core::api::functions::highlighting::TokenSpan>>::sse_decode(deserializer);
+        return holloway_core::api::functions::highlighting::HighlightedLine { spans: var_spans };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::history::HistoryEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_visitedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::history::HistoryEntry {
+            id: var_id,
+            url: var_url,
+            title: var_title,
+            visited_at: var_visitedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::history::HistoryMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::history::HistoryMatch::Substring,
+            1 => holloway_core::api::storage::history::HistoryMatch::Prefix,
+            _ => unreachable!("Invalid variant for HistoryMatch: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::history::HistoryRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_since = <Option<i64>>::sse_decode(deserializer);
+        let mut var_until = <Option<i64>>::sse_decode(deserializer);
+        return holloway_core::api::storage::history::HistoryRange {
+            since: var_since,
+            until: var_until,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::history::HistoryResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_visitCount = <u32>::sse_decode(deserializer);
+        let mut var_lastVisited = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::history::HistoryResult {
+            url: var_url,
+            title: var_title,
+            visit_count: var_visitCount,
+            last_visited: var_lastVisited,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::host_migration::HostMigration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_fromHost = <String>::sse_decode(deserializer);
+        let mut var_toHost = <String>::sse_decode(deserializer);
+        let mut var_movedPages = <u32>::sse_decode(deserializer);
+        let mut var_bookmarks = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::host_migration::HostMigration {
+            scheme: var_scheme,
+            from_host: var_fromHost,
+            to_host: var_toHost,
+            moved_pages: var_movedPages,
+            bookmarks: var_bookmarks,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::host_migration::HostMigrationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bookmarks = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::host_migration::HostMigrationResult {
+            bookmarks: var_bookmarks,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::host_settings::HostSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_tlsPolicy =
+            <Option<holloway_core::api::storage::host_settings::TlsPolicy>>::sse_decode(
+                deserializer,
+            );
+        let mut var_identityId = <Option<String>>::sse_decode(deserializer);
+        let mut var_connectTimeoutSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_readTimeoutSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_redirectPolicy = <Option<
+            holloway_core::api::storage::host_settings::RedirectPolicy,
+        >>::sse_decode(deserializer);
+        let mut var_maxBodyBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_charset = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::storage::host_settings::HostSettings {
+            host: var_host,
+            tls_policy: var_tlsPolicy,
+            identity_id: var_identityId,
+            connect_timeout_secs: var_connectTimeoutSecs,
+            read_timeout_secs: var_readTimeoutSecs,
+            redirect_policy: var_redirectPolicy,
+            max_body_bytes: var_maxBodyBytes,
+            charset: var_charset,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::host_stats::HostStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_requests = <u32>::sse_decode(deserializer);
+        let mut var_errors = <u32>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_averageLatencyMs = <u32>::sse_decode(deserializer);
+        let mut var_errorRate = <f64>::sse_decode(deserializer);
+        let mut var_firstRequestAt = <i64>::sse_decode(deserializer);
+        let mut var_lastRequestAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::host_stats::HostStats {
+            host: var_host,
+            requests: var_requests,
+            errors: var_errors,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            average_latency_ms: var_averageLatencyMs,
+            error_rate: var_errorRate,
+            first_request_at: var_firstRequestAt,
+            last_request_at: var_lastRequestAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::host_stats::HostStatsOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::host_stats::HostStatsOrder::Requests,
+            1 => holloway_core::api::storage::host_stats::HostStatsOrder::BytesReceived,
+            2 => holloway_core::api::storage::host_stats::HostStatsOrder::AverageLatency,
+            3 => holloway_core::api::storage::host_stats::HostStatsOrder::ErrorRate,
+            4 => holloway_core::api::storage::host_stats::HostStatsOrder::LastRequest,
+            _ => unreachable!("Invalid variant for HostStatsOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::identity_transfer::IdentitiesImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::identity_transfer::IdentitiesImport {
+            imported: var_imported,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::identities::Identity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_commonName = <String>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_notBefore = <i64>::sse_decode(deserializer);
+        let mut var_notAfter = <i64>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::identities::Identity {
+            id: var_id,
+            name: var_name,
+            common_name: var_commonName,
+            fingerprint: var_fingerprint,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::identity_scopes::IdentityScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_prefix = <String>::sse_decode(deserializer);
+        let mut var_identityId = <String>::sse_decode(deserializer);
+        let mut var_activatedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::identity_scopes::IdentityScope {
+            prefix: var_prefix,
+            identity_id: var_identityId,
+            activated_at: var_activatedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::inline_images::InlineImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_linkIndex = <u32>::sse_decode(deserializer);
+        let mut var_lineIndex = <u32>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        return holloway_core::api::functions::inline_images::InlineImage {
+            link_index: var_linkIndex,
+            line_index: var_lineIndex,
+            url: var_url,
+            mime: var_mime,
+            data: var_data,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::inline_images::InlineImageFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_linkIndex = <u32>::sse_decode(deserializer);
+        let mut var_lineIndex = <u32>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::inline_images::InlineImageFailure {
+            link_index: var_linkIndex,
+            line_index: var_lineIndex,
+            url: var_url,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::inline_images::InlineImages {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_images =
+            <Vec<holloway_core::api::functions::inline_images::InlineImage>>::sse_decode(
+                deserializer,
+            );
+        let mut var_failed =
+            <Vec<holloway_core::api::functions::inline_images::InlineImageFailure>>::sse_decode(
+                deserializer,
+            );
+        return holloway_core::api::functions::inline_images::InlineImages {
+            images: var_images,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::known_hosts::KnownHost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_expiresAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_firstSeen = <i64>::sse_decode(deserializer);
+        let mut var_lastSeen = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::known_hosts::KnownHost {
+            host: var_host,
+            port: var_port,
+            fingerprint: var_fingerprint,
+            expires_at: var_expiresAt,
+            first_seen: var_firstSeen,
+            last_seen: var_lastSeen,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Holloway,
+            1 => holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Amfora,
+            2 => holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Lagrange,
+            _ => unreachable!("Invalid variant for KnownHostsFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::known_hosts_transfer::KnownHostsImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::known_hosts_transfer::KnownHostsImport {
+            imported: var_imported,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bidi::LevelRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        let mut var_level = <u8>::sse_decode(deserializer);
+        let mut var_direction =
+            <holloway_core::api::functions::bidi::TextDirection>::sse_decode(deserializer);
+        return holloway_core::api::functions::bidi::LevelRun {
+            start: var_start,
+            end: var_end,
+            level: var_level,
+            direction: var_direction,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bidi::LineDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lineIndex = <u32>::sse_decode(deserializer);
+        let mut var_direction =
+            <holloway_core::api::functions::bidi::TextDirection>::sse_decode(deserializer);
+        let mut var_runs =
+            <Vec<holloway_core::api::functions::bidi::LevelRun>>::sse_decode(deserializer);
+        return holloway_core::api::functions::bidi::LineDirection {
+            line_index: var_lineIndex,
+            direction: var_direction,
+            runs: var_runs,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::links::LinkKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::links::LinkKind::Page,
+            1 => holloway_core::api::functions::links::LinkKind::Search,
+            2 => holloway_core::api::functions::links::LinkKind::Download,
+            3 => holloway_core::api::functions::links::LinkKind::External,
+            _ => unreachable!("Invalid variant for LinkKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::archive::ArchivedPage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::archive::ArchivedPage>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::blocklist::BlockRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::blocklist::BlockRule>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::bookmarks::Bookmark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::bookmarks::Bookmark>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::cache::CacheEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::cache::CacheEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::cache::CachePolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::cache::CachePolicy>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::clear::ClearedData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::clear::ClearedData>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::protocols::cso::CsoField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::protocols::cso::CsoField>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::protocols::cso::CsoRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::protocols::cso::CsoRecord>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::data_usage::DailyUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::data_usage::DailyUsage>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::clear::DataCategory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::clear::DataCategory>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::page_diff::DiffLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::page_diff::DiffLine>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::downloads::DownloadRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::downloads::DownloadRecord>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::identities::ExpiryReminder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::identities::ExpiryReminder>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::explore::ExploreSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::explore::ExploreSource>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::feeds::Feed> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::feeds::Feed>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::feed_discovery::FeedCandidate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::feed_discovery::FeedCandidate>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::feeds::FeedEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::feeds::FeedEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::gempub::GempubChapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::gempub::GempubChapter>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::mirrors::GemtextLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::mirrors::GemtextLine>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::parsers::gophermap::GopherItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::parsers::gophermap::GopherItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::highlighting::HighlightedBlock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::highlighting::HighlightedBlock>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::highlighting::HighlightedLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::highlighting::HighlightedLine>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::history::HistoryEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::history::HistoryEntry>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::history::HistoryResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::history::HistoryResult>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::host_migration::HostMigration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::host_migration::HostMigration>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::host_settings::HostSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::host_settings::HostSettings>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::host_stats::HostStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::host_stats::HostStats>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::identities::Identity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::identities::Identity>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::identity_scopes::IdentityScope> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::identity_scopes::IdentityScope>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::inline_images::InlineImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::inline_images::InlineImage>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::inline_images::InlineImageFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::inline_images::InlineImageFailure>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::known_hosts::KnownHost> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::known_hosts::KnownHost>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::bidi::LevelRun> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::functions::bidi::LevelRun>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::bidi::LineDirection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::bidi::LineDirection>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::page_index::LocalSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::page_index::LocalSearchResult>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::logging::LogRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::logging::LogRecord>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::anchors::OutlineEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::anchors::OutlineEntry>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::links::PageLink> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::functions::links::PageLink>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::ports::PortOverride> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::storage::ports::PortOverride>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::text_width::PreformattedBlock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::text_width::PreformattedBlock>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::protocols::registry::ProtocolInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::protocols::registry::ProtocolInfo>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::metasearch::ProviderFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::metasearch::ProviderFailure>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::transport::proxy::ProxyRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::transport::proxy::ProxyRule>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::read_later::ReadLaterItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::read_later::ReadLaterItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::reflow::ReflowedLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::reflow::ReflowedLine>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::input_answers::SavedAnswer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::input_answers::SavedAnswer>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::scheduler::SchedulerEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::scheduler::SchedulerEvent>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::gemini_proxies::SchemeProxy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::gemini_proxies::SchemeProxy>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::metasearch::SearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::metasearch::SearchHit>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::search::SearchProvider> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::search::SearchProvider>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::sessions::SessionEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::sessions::SessionEntry>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::sessions::SessionSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::sessions::SessionSummary>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::sessions::SessionTab> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::sessions::SessionTab>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::site_map::SiteNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::site_map::SiteNode>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::page_index::SnippetPart> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::page_index::SnippetPart>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::ansi::StyledSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<holloway_core::api::functions::ansi::StyledSpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::suggestions::Suggestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::suggestions::Suggestion>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::scheduler::TaskSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::scheduler::TaskSchedule>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::highlighting::TokenSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::highlighting::TokenSpan>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::top_sites::TopSite> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::top_sites::TopSite>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::functions::request_trace::TraceEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::functions::request_trace::TraceEvent>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::trusted_cas::TrustedCa> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::trusted_cas::TrustedCa>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::watches::WatchedPage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::watches::WatchedPage>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<holloway_core::api::storage::plan_watches::WatchedPlan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <holloway_core::api::storage::plan_watches::WatchedPlan>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::page_index::LocalSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_snippet =
+            <Vec<holloway_core::api::storage::page_index::SnippetPart>>::sse_decode(deserializer);
+        let mut var_fetchedAt = <i64>::sse_decode(deserializer);
+        let mut var_lastVisitedAt = <Option<i64>>::sse_decode(deserializer);
+        return holloway_core::api::storage::page_index::LocalSearchResult {
+            url: var_url,
+            title: var_title,
+            snippet: var_snippet,
+            fetched_at: var_fetchedAt,
+            last_visited_at: var_lastVisitedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::logging::LogLevel::Debug,
+            1 => holloway_core::api::functions::logging::LogLevel::Info,
+            2 => holloway_core::api::functions::logging::LogLevel::Warn,
+            3 => holloway_core::api::functions::logging::LogLevel::Error,
+            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::logging::LogRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestampMs = <i64>::sse_decode(deserializer);
+        let mut var_level =
+            <holloway_core::api::functions::logging::LogLevel>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_requestId = <Option<u64>>::sse_decode(deserializer);
+        return holloway_core::api::functions::logging::LogRecord {
+            timestamp_ms: var_timestampMs,
+            level: var_level,
+            target: var_target,
+            message: var_message,
+            request_id: var_requestId,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::menu_pages::MenuPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        let mut var_items =
+            <Vec<holloway_core::api::parsers::gophermap::GopherItem>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::menu_pages::MenuPage {
+            offset: var_offset,
+            items: var_items,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::metasearch::MetasearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hits =
+            <Vec<holloway_core::api::functions::metasearch::SearchHit>>::sse_decode(deserializer);
+        let mut var_failed =
+            <Vec<holloway_core::api::functions::metasearch::ProviderFailure>>::sse_decode(
+                deserializer,
+            );
+        return holloway_core::api::functions::metasearch::MetasearchResult {
+            hits: var_hits,
+            failed: var_failed,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::mirror::MirrorProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_path = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        let mut var_saved = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_remaining = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::mirror::MirrorProgress {
+            url: var_url,
+            path: var_path,
+            error: var_error,
+            saved: var_saved,
+            failed: var_failed,
+            remaining: var_remaining,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mirrors::NavigateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::InvalidUrl(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::UnsupportedScheme(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::SchemeDisabled(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Blocked(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Disallowed(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Offline(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::DnsFailure(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ConnectTimeout(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ConnectionRefused(var_field0);
+            }
+            9 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ConnectionFailed(var_field0);
+            }
+            10 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ConnectionLost(var_field0);
+            }
+            11 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ReadTimeout(var_field0);
+            }
+            12 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::GeminiTimeout(var_field0);
+            }
+            13 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ProxyUnreachable(var_field0);
+            }
+            14 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ProxyAuthFailed(var_field0);
+            }
+            15 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::ProxyError(var_field0);
+            }
+            16 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::GatewayError(var_field0);
+            }
+            17 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::TlsError(var_field0);
+            }
+            18 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::FingerprintMismatch(var_field0);
+            }
+            19 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::CertificateDate(var_field0);
+            }
+            20 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::CertificateRejected(var_field0);
+            }
+            21 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::BadResponse(var_field0);
+            }
+            22 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::TooLarge(var_field0);
+            }
+            23 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::NotFound(var_field0);
+            }
+            24 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::InvalidInput(var_field0);
+            }
+            25 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::GopherBadType(var_field0);
+            }
+            26 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::GopherBadSelector(var_field0);
+            }
+            27 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::StorageError(var_field0);
+            }
+            28 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::IdentityError(var_field0);
+            }
+            29 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Cancelled(var_field0);
+            }
+            30 => {
+                let mut var_target = <String>::sse_decode(deserializer);
+                let mut var_permanent = <bool>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Redirected {
+                    target: var_target,
+                    permanent: var_permanent,
+                };
+            }
+            31 => {
+                let mut var_prompt = <String>::sse_decode(deserializer);
+                let mut var_sensitive = <bool>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::InputRequired {
+                    prompt: var_prompt,
+                    sensitive: var_sensitive,
+                };
+            }
+            32 => {
+                let mut var_field0 =
+                    <holloway_core::api::models::certificate_error::CertificateError>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::mirrors::NavigateError::CertificateRequired(var_field0);
+            }
+            33 => {
+                let mut var_field0 =
+                    <holloway_core::api::models::server_failure::ServerFailure>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::mirrors::NavigateError::ServerError(var_field0);
+            }
+            34 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::mirrors::NavigateError::Other(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_event::NavigationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::models::navigation_event::NavigationEventKind>::sse_decode(
+                deserializer,
+            );
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_meta = <Option<String>>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::models::navigation_event::NavigationEvent {
+            request_id: var_requestId,
+            kind: var_kind,
+            url: var_url,
+            status: var_status,
+            meta: var_meta,
+            bytes: var_bytes,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_event::NavigationEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::navigation_event::NavigationEventKind::NavigationStarted,
+1 => holloway_core::api::models::navigation_event::NavigationEventKind::Resolving,
+2 => holloway_core::api::models::navigation_event::NavigationEventKind::Connecting,
+3 => holloway_core::api::models::navigation_event::NavigationEventKind::TlsHandshake,
+4 => holloway_core::api::models::navigation_event::NavigationEventKind::RedirectFollowed,
+5 => holloway_core::api::models::navigation_event::NavigationEventKind::HeaderReceived,
+6 => holloway_core::api::models::navigation_event::NavigationEventKind::BodyProgress,
+7 => holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFinished,
+8 => holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFailed,
+            _ => unreachable!("Invalid variant for NavigationEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_result::NavigationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_redirects = <Vec<String>>::sse_decode(deserializer);
+        let mut var_protocol = <String>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_mime = <String>::sse_decode(deserializer);
+        let mut var_charset = <Option<String>>::sse_decode(deserializer);
+        let mut var_renderer =
+            <holloway_core::api::functions::content_router::Renderer>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_favicon = <Option<String>>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_truncated = <bool>::sse_decode(deserializer);
+        let mut var_cachedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_stale = <bool>::sse_decode(deserializer);
+        let mut var_durationMs = <u64>::sse_decode(deserializer);
+        let mut var_timings =
+            <holloway_core::api::models::navigation_result::RequestTimings>::sse_decode(
+                deserializer,
+            );
+        let mut var_certificate = <Option<
+            holloway_core::api::models::navigation_result::CertificateSummary,
+        >>::sse_decode(deserializer);
+        let mut var_serverFailure = <Option<
+            holloway_core::api::models::server_failure::ServerFailure,
+        >>::sse_decode(deserializer);
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        return holloway_core::api::models::navigation_result::NavigationResult {
+            url: var_url,
+            redirects: var_redirects,
+            protocol: var_protocol,
+            status: var_status,
+            mime: var_mime,
+            charset: var_charset,
+            renderer: var_renderer,
+            title: var_title,
+            favicon: var_favicon,
+            body: var_body,
+            truncated: var_truncated,
+            cached_at: var_cachedAt,
+            stale: var_stale,
+            duration_ms: var_durationMs,
+            timings: var_timings,
+            certificate: var_certificate,
+            server_failure: var_serverFailure,
+            request_id: var_requestId,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::data_usage::NetworkType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::data_usage::NetworkType::Wifi,
+            1 => holloway_core::api::storage::data_usage::NetworkType::Cellular,
+            2 => holloway_core::api::storage::data_usage::NetworkType::Ethernet,
+            3 => holloway_core::api::storage::data_usage::NetworkType::Unknown,
+            _ => unreachable!("Invalid variant for NetworkType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::mirrors::AnsiColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::mirrors::AnsiColor>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::functions::batch::BatchItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::functions::batch::BatchItem>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::bookmarks::Bookmark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::bookmarks::Bookmark>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::certificate_error::CertificateError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::certificate_error::CertificateError>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::navigation_result::CertificateSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::navigation_result::CertificateSummary>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::downloads::DownloadStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::downloads::DownloadStatus>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::error_info::ErrorInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::error_info::ErrorInfo>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::functions::file_type::FileType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::functions::file_type::FileType>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::mirrors::GemtextLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::mirrors::GemtextLine>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::host_settings::HostSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::host_settings::HostSettings>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::identity_scopes::IdentityScope> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::identity_scopes::IdentityScope>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::functions::mirror::MirrorProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::functions::mirror::MirrorProgress>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::navigation_event::NavigationEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::navigation_event::NavigationEvent>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::navigation_result::NavigationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::navigation_result::NavigationResult>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::error_info::RecoveryAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::error_info::RecoveryAction>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::host_settings::RedirectPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::host_settings::RedirectPolicy>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::functions::content_router::Renderer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::functions::content_router::Renderer>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::server_failure::ServerFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::server_failure::ServerFailure>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::sessions::Session> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::sessions::Session>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::transport::proxy::Socks5Proxy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::transport::proxy::Socks5Proxy>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::models::tls_failure::TlsFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::models::tls_failure::TlsFailure>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<holloway_core::api::storage::host_settings::TlsPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <holloway_core::api::storage::host_settings::TlsPolicy>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u8>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<holloway_core::api::functions::highlighting::HighlightedLine>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<
+                holloway_core::api::functions::highlighting::HighlightedLine,
+            >>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<holloway_core::api::functions::request_trace::TraceEvent>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<
+                holloway_core::api::functions::request_trace::TraceEvent,
+            >>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::anchors::OutlineEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_anchor =
+            <holloway_core::api::functions::anchors::HeadingAnchor>::sse_decode(deserializer);
+        let mut var_depth = <u8>::sse_decode(deserializer);
+        let mut var_parent = <Option<u32>>::sse_decode(deserializer);
+        return holloway_core::api::functions::anchors::OutlineEntry {
+            anchor: var_anchor,
+            depth: var_depth,
+            parent: var_parent,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::page_diff::PageDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_cachedAt = <i64>::sse_decode(deserializer);
+        let mut var_added =
+            <Vec<holloway_core::api::functions::page_diff::DiffLine>>::sse_decode(deserializer);
+        let mut var_removed =
+            <Vec<holloway_core::api::functions::page_diff::DiffLine>>::sse_decode(deserializer);
+        return holloway_core::api::functions::page_diff::PageDiff {
+            url: var_url,
+            cached_at: var_cachedAt,
+            added: var_added,
+            removed: var_removed,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::links::PageLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::links::LinkKind>::sse_decode(deserializer);
+        return holloway_core::api::functions::links::PageLink {
+            url: var_url,
+            label: var_label,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::page_summary::PageSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_favicon = <Option<String>>::sse_decode(deserializer);
+        let mut var_cachedAt = <Option<i64>>::sse_decode(deserializer);
+        return holloway_core::api::functions::page_summary::PageSummary {
+            url: var_url,
+            title: var_title,
+            description: var_description,
+            favicon: var_favicon,
+            cached_at: var_cachedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::peek::PeekResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_meta = <Option<String>>::sse_decode(deserializer);
+        let mut var_mime = <Option<String>>::sse_decode(deserializer);
+        let mut var_renderer =
+            <Option<holloway_core::api::functions::content_router::Renderer>>::sse_decode(
+                deserializer,
+            );
+        let mut var_size = <Option<u64>>::sse_decode(deserializer);
+        return holloway_core::api::functions::peek::PeekResult {
+            url: var_url,
+            status: var_status,
+            meta: var_meta,
+            mime: var_mime,
+            renderer: var_renderer,
+            size: var_size,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_result::PinExpiry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::navigation_result::PinExpiry::Valid,
+            1 => holloway_core::api::models::navigation_result::PinExpiry::ExpiringSoon,
+            2 => holloway_core::api::models::navigation_result::PinExpiry::Expired,
+            _ => unreachable!("Invalid variant for PinExpiry: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_result::PinStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::navigation_result::PinStatus::FirstUse,
+            1 => holloway_core::api::models::navigation_result::PinStatus::Matched,
+            2 => holloway_core::api::models::navigation_result::PinStatus::Replaced,
+            3 => holloway_core::api::models::navigation_result::PinStatus::Unverified,
+            4 => holloway_core::api::models::navigation_result::PinStatus::CaVerified,
+            _ => unreachable!("Invalid variant for PinStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::ports::PortOverride {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_host = <Option<String>>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        return holloway_core::api::storage::ports::PortOverride {
+            scheme: var_scheme,
+            host: var_host,
+            port: var_port,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::scheduler::PowerState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::scheduler::PowerState::Charging,
+            1 => holloway_core::api::functions::scheduler::PowerState::Battery,
+            2 => holloway_core::api::functions::scheduler::PowerState::LowBattery,
+            3 => holloway_core::api::functions::scheduler::PowerState::Unknown,
+            _ => unreachable!("Invalid variant for PowerState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::text_width::PreformattedBlock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startIndex = <u32>::sse_decode(deserializer);
+        let mut var_lineCount = <u32>::sse_decode(deserializer);
+        let mut var_alt = <Option<String>>::sse_decode(deserializer);
+        let mut var_maxWidth = <u32>::sse_decode(deserializer);
+        let mut var_hasWideChars = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::text_width::PreformattedBlock {
+            start_index: var_startIndex,
+            line_count: var_lineCount,
+            alt: var_alt,
+            max_width: var_maxWidth,
+            has_wide_chars: var_hasWideChars,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::protocols::registry::ProtocolCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userInput = <bool>::sse_decode(deserializer);
+        let mut var_redirects = <bool>::sse_decode(deserializer);
+        let mut var_clientCertificates = <bool>::sse_decode(deserializer);
+        let mut var_menus = <bool>::sse_decode(deserializer);
+        return holloway_core::api::protocols::registry::ProtocolCapabilities {
+            user_input: var_userInput,
+            redirects: var_redirects,
+            client_certificates: var_clientCertificates,
+            menus: var_menus,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::protocols::registry::ProtocolInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_defaultPort = <u16>::sse_decode(deserializer);
+        let mut var_requiresTls = <bool>::sse_decode(deserializer);
+        let mut var_capabilities =
+            <holloway_core::api::protocols::registry::ProtocolCapabilities>::sse_decode(
+                deserializer,
+            );
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        return holloway_core::api::protocols::registry::ProtocolInfo {
+            scheme: var_scheme,
+            default_port: var_defaultPort,
+            requires_tls: var_requiresTls,
+            capabilities: var_capabilities,
+            enabled: var_enabled,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::metasearch::ProviderFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::metasearch::ProviderFailure {
+            name: var_name,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::transport::proxy::ProxyProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handshakeMs = <u32>::sse_decode(deserializer);
+        let mut var_connectMs = <u32>::sse_decode(deserializer);
+        return holloway_core::api::transport::proxy::ProxyProbe {
+            handshake_ms: var_handshakeMs,
+            connect_ms: var_connectMs,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::gemini_proxies::ProxyProtocol {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gemini,
+            1 => holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gopher,
+            _ => unreachable!("Invalid variant for ProxyProtocol: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::transport::proxy::ProxyRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pattern = <String>::sse_decode(deserializer);
+        let mut var_proxy =
+            <Option<holloway_core::api::transport::proxy::Socks5Proxy>>::sse_decode(deserializer);
+        return holloway_core::api::transport::proxy::ProxyRule {
+            pattern: var_pattern,
+            proxy: var_proxy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mirrors::ProxySettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::mirrors::ProxySettings::Direct;
+            }
+            1 => {
+                let mut var_proxy =
+                    <holloway_core::api::transport::proxy::Socks5Proxy>::sse_decode(deserializer);
+                return crate::api::mirrors::ProxySettings::Socks5 { proxy: var_proxy };
+            }
+            2 => {
+                let mut var_rules =
+                    <Vec<holloway_core::api::transport::proxy::ProxyRule>>::sse_decode(
+                        deserializer,
+                    );
+                let mut var_fallback =
+                    <Option<holloway_core::api::transport::proxy::Socks5Proxy>>::sse_decode(
+                        deserializer,
+                    );
+                return crate::api::mirrors::ProxySettings::PerRule {
+                    rules: var_rules,
+                    fallback: var_fallback,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::explore::RandomDestination {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_directoryUrl = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::functions::explore::RandomDestination {
+            url: var_url,
+            label: var_label,
+            directory_url: var_directoryUrl,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::transport::rate_limit::RateLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxConnections = <u32>::sse_decode(deserializer);
+        let mut var_minIntervalMs = <u64>::sse_decode(deserializer);
+        return holloway_core::api::transport::rate_limit::RateLimits {
+            max_connections: var_maxConnections,
+            min_interval_ms: var_minIntervalMs,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::read_later::ReadLaterItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_addedAt = <i64>::sse_decode(deserializer);
+        let mut var_readAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_cached = <bool>::sse_decode(deserializer);
+        let mut var_fetchedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::storage::read_later::ReadLaterItem {
+            id: var_id,
+            url: var_url,
+            title: var_title,
+            added_at: var_addedAt,
+            read_at: var_readAt,
+            cached: var_cached,
+            fetched_at: var_fetchedAt,
+            size: var_size,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::reading_stats::ReadingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_words = <u32>::sse_decode(deserializer);
+        let mut var_preformattedWords = <u32>::sse_decode(deserializer);
+        let mut var_readingMinutes = <u32>::sse_decode(deserializer);
+        let mut var_links = <u32>::sse_decode(deserializer);
+        let mut var_headings = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::reading_stats::ReadingStats {
+            words: var_words,
+            preformatted_words: var_preformattedWords,
+            reading_minutes: var_readingMinutes,
+            links: var_links,
+            headings: var_headings,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::error_info::RecoveryAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::error_info::RecoveryAction::Retry,
+            1 => holloway_core::api::models::error_info::RecoveryAction::AddException,
+            2 => holloway_core::api::models::error_info::RecoveryAction::EnableProtocol,
+            3 => holloway_core::api::models::error_info::RecoveryAction::ProvideInput,
+            4 => holloway_core::api::models::error_info::RecoveryAction::ChooseIdentity,
+            5 => holloway_core::api::models::error_info::RecoveryAction::RenewIdentity,
+            6 => holloway_core::api::models::error_info::RecoveryAction::CheckProxy,
+            _ => unreachable!("Invalid variant for RecoveryAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::host_settings::RedirectPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::host_settings::RedirectPolicy::Follow,
+            1 => holloway_core::api::storage::host_settings::RedirectPolicy::AskCrossHost,
+            2 => holloway_core::api::storage::host_settings::RedirectPolicy::Ask,
+            _ => unreachable!("Invalid variant for RedirectPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::reflow::ReflowedLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceIndex = <u32>::sse_decode(deserializer);
+        let mut var_line = <crate::api::mirrors::GemtextLine>::sse_decode(deserializer);
+        let mut var_continuation = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::reflow::ReflowedLine {
+            source_index: var_sourceIndex,
+            line: var_line,
+            continuation: var_continuation,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::content_router::Renderer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::content_router::Renderer::Gemtext,
+            1 => holloway_core::api::functions::content_router::Renderer::GopherMenu,
+            2 => holloway_core::api::functions::content_router::Renderer::PlainText,
+            3 => holloway_core::api::functions::content_router::Renderer::Markdown,
+            4 => holloway_core::api::functions::content_router::Renderer::Image,
+            5 => holloway_core::api::functions::content_router::Renderer::Download,
+            6 => holloway_core::api::functions::content_router::Renderer::External,
+            _ => unreachable!("Invalid variant for Renderer: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::navigation_result::RequestTimings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dnsMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_connectMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_tlsMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_firstByteMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_transferMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        return holloway_core::api::models::navigation_result::RequestTimings {
+            dns_ms: var_dnsMs,
+            connect_ms: var_connectMs,
+            tls_ms: var_tlsMs,
+            first_byte_ms: var_firstByteMs,
+            transfer_ms: var_transferMs,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::input_answers::SavedAnswer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_prompt = <String>::sse_decode(deserializer);
+        let mut var_answer = <String>::sse_decode(deserializer);
+        let mut var_answeredAt = <i64>::sse_decode(deserializer);
+        let mut var_uses = <u32>::sse_decode(deserializer);
+        return holloway_core::api::storage::input_answers::SavedAnswer {
+            id: var_id,
+            url: var_url,
+            prompt: var_prompt,
+            answer: var_answer,
+            answered_at: var_answeredAt,
+            uses: var_uses,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::scheduler::ScheduledTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::scheduler::ScheduledTask::FeedRefresh,
+            1 => holloway_core::api::functions::scheduler::ScheduledTask::PageWatches,
+            2 => holloway_core::api::functions::scheduler::ScheduledTask::PlanWatches,
+            3 => holloway_core::api::functions::scheduler::ScheduledTask::PinExpiry,
+            _ => unreachable!("Invalid variant for ScheduledTask: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::scheduler::SchedulerEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_task =
+            <holloway_core::api::functions::scheduler::ScheduledTask>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::scheduler::SchedulerEventKind>::sse_decode(
+                deserializer,
+            );
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        let mut var_items = <Vec<String>>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        let mut var_nextRunAt = <Option<i64>>::sse_decode(deserializer);
+        return holloway_core::api::functions::scheduler::SchedulerEvent {
+            task: var_task,
+            kind: var_kind,
+            timestamp: var_timestamp,
+            count: var_count,
+            items: var_items,
+            message: var_message,
+            next_run_at: var_nextRunAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::scheduler::SchedulerEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::scheduler::SchedulerEventKind::Started,
+            1 => holloway_core::api::functions::scheduler::SchedulerEventKind::Completed,
+            2 => holloway_core::api::functions::scheduler::SchedulerEventKind::Skipped,
+            3 => holloway_core::api::functions::scheduler::SchedulerEventKind::Failed,
+            _ => unreachable!("Invalid variant for SchedulerEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::gemini_proxies::SchemeProxy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_protocol =
+            <holloway_core::api::storage::gemini_proxies::ProxyProtocol>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_selector = <String>::sse_decode(deserializer);
+        return holloway_core::api::storage::gemini_proxies::SchemeProxy {
+            scheme: var_scheme,
+            protocol: var_protocol,
+            host: var_host,
+            port: var_port,
+            selector: var_selector,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::metasearch::SearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_snippet = <Option<String>>::sse_decode(deserializer);
+        let mut var_providers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return holloway_core::api::functions::metasearch::SearchHit {
+            url: var_url,
+            title: var_title,
+            snippet: var_snippet,
+            providers: var_providers,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::search::SearchProvider {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_urlTemplate = <String>::sse_decode(deserializer);
+        return holloway_core::api::functions::search::SearchProvider {
+            name: var_name,
+            url_template: var_urlTemplate,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::server_failure::ServerFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_status = <u8>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::models::server_failure::ServerFailureKind>::sse_decode(
+                deserializer,
+            );
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_temporary = <bool>::sse_decode(deserializer);
+        let mut var_retryAfterSecs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_suggestedAction = <Option<
+            holloway_core::api::models::error_info::RecoveryAction,
+        >>::sse_decode(deserializer);
+        return holloway_core::api::models::server_failure::ServerFailure {
+            status: var_status,
+            kind: var_kind,
+            message: var_message,
+            temporary: var_temporary,
+            retry_after_secs: var_retryAfterSecs,
+            suggested_action: var_suggestedAction,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::server_failure::ServerFailureKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::server_failure::ServerFailureKind::TemporaryFailure,
+            1 => holloway_core::api::models::server_failure::ServerFailureKind::ServerUnavailable,
+            2 => holloway_core::api::models::server_failure::ServerFailureKind::CgiError,
+            3 => holloway_core::api::models::server_failure::ServerFailureKind::ProxyError,
+            4 => holloway_core::api::models::server_failure::ServerFailureKind::SlowDown,
+            5 => holloway_core::api::models::server_failure::ServerFailureKind::PermanentFailure,
+            6 => holloway_core::api::models::server_failure::ServerFailureKind::NotFound,
+            7 => holloway_core::api::models::server_failure::ServerFailureKind::Gone,
+            8 => holloway_core::api::models::server_failure::ServerFailureKind::ProxyRequestRefused,
+            9 => holloway_core::api::models::server_failure::ServerFailureKind::BadRequest,
+            _ => unreachable!("Invalid variant for ServerFailureKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::sessions::Session {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_tabs =
+            <Vec<holloway_core::api::storage::sessions::SessionTab>>::sse_decode(deserializer);
+        let mut var_activeTab = <u32>::sse_decode(deserializer);
+        let mut var_savedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::sessions::Session {
+            name: var_name,
+            tabs: var_tabs,
+            active_tab: var_activeTab,
+            saved_at: var_savedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::sessions::SessionEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_scrollOffset = <f64>::sse_decode(deserializer);
+        return holloway_core::api::storage::sessions::SessionEntry {
+            url: var_url,
+            title: var_title,
+            scroll_offset: var_scrollOffset,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::sessions::SessionSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_tabCount = <u32>::sse_decode(deserializer);
+        let mut var_savedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::sessions::SessionSummary {
+            name: var_name,
+            tab_count: var_tabCount,
+            saved_at: var_savedAt,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::sessions::SessionTab {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entries =
+            <Vec<holloway_core::api::storage::sessions::SessionEntry>>::sse_decode(deserializer);
+        let mut var_currentEntry = <u32>::sse_decode(deserializer);
+        let mut var_private = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::sessions::SessionTab {
+            entries: var_entries,
+            current_entry: var_currentEntry,
+            private: var_private,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::shutdown::ShutdownSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_streamsCancelled = <u32>::sse_decode(deserializer);
+        let mut var_fetchesAborted = <u32>::sse_decode(deserializer);
+        let mut var_requestsCancelled = <u32>::sse_decode(deserializer);
+        let mut var_mirrorsCancelled = <u32>::sse_decode(deserializer);
+        return holloway_core::api::functions::shutdown::ShutdownSummary {
+            streams_cancelled: var_streamsCancelled,
+            fetches_aborted: var_fetchesAborted,
+            requests_cancelled: var_requestsCancelled,
+            mirrors_cancelled: var_mirrorsCancelled,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::site_map::SiteMap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_root = <String>::sse_decode(deserializer);
+        let mut var_nodes =
+            <Vec<holloway_core::api::functions::site_map::SiteNode>>::sse_decode(deserializer);
+        let mut var_truncated = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::site_map::SiteMap {
+            root: var_root,
+            nodes: var_nodes,
+            truncated: var_truncated,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::site_map::SiteNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::links::LinkKind>::sse_decode(deserializer);
+        let mut var_icon = <String>::sse_decode(deserializer);
+        let mut var_menu = <bool>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        let mut var_parent = <Option<u32>>::sse_decode(deserializer);
+        let mut var_expanded = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::functions::site_map::SiteNode {
+            url: var_url,
+            title: var_title,
+            kind: var_kind,
+            icon: var_icon,
+            menu: var_menu,
+            depth: var_depth,
+            parent: var_parent,
+            expanded: var_expanded,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::page_index::SnippetPart {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_matched = <bool>::sse_decode(deserializer);
+        return holloway_core::api::storage::page_index::SnippetPart {
+            text: var_text,
+            matched: var_matched,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::transport::proxy::Socks5Proxy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_port = <u16>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::transport::proxy::Socks5Proxy {
+            host: var_host,
+            port: var_port,
+            username: var_username,
+            password: var_password,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::stream_event::StreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <holloway_core::api::models::stream_event::StreamEventKind>::sse_decode(deserializer);
+        let mut var_status = <Option<u8>>::sse_decode(deserializer);
+        let mut var_meta = <Option<String>>::sse_decode(deserializer);
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_element = <Option<crate::api::mirrors::GemtextLine>>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        return holloway_core::api::models::stream_event::StreamEvent {
+            kind: var_kind,
+            status: var_status,
+            meta: var_meta,
+            text: var_text,
+            element: var_element,
+            bytes_received: var_bytesReceived,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::stream_event::StreamEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::stream_event::StreamEventKind::Header,
+            1 => holloway_core::api::models::stream_event::StreamEventKind::Line,
+            2 => holloway_core::api::models::stream_event::StreamEventKind::Idle,
+            3 => holloway_core::api::models::stream_event::StreamEventKind::Finished,
+            4 => holloway_core::api::models::stream_event::StreamEventKind::Failed,
+            _ => unreachable!("Invalid variant for StreamEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::streaming::StreamOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_idleIntervalSecs = <u32>::sse_decode(deserializer);
+        let mut var_maxIdleSecs = <Option<u32>>::sse_decode(deserializer);
+        return holloway_core::api::functions::streaming::StreamOptions {
+            idle_interval_secs: var_idleIntervalSecs,
+            max_idle_secs: var_maxIdleSecs,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::ansi::StyledSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        let mut var_style =
+            <holloway_core::api::functions::ansi::AnsiStyle>::sse_decode(deserializer);
+        return holloway_core::api::functions::ansi::StyledSpan {
+            start: var_start,
+            end: var_end,
+            style: var_style,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::ansi::StyledText {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_spans =
+            <Vec<holloway_core::api::functions::ansi::StyledSpan>>::sse_decode(deserializer);
+        return holloway_core::api::functions::ansi::StyledText {
+            text: var_text,
+            spans: var_spans,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::suggestions::Suggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_bookmarked = <bool>::sse_decode(deserializer);
+        let mut var_visitCount = <u32>::sse_decode(deserializer);
+        let mut var_lastVisited = <Option<i64>>::sse_decode(deserializer);
+        let mut var_matched =
+            <holloway_core::api::functions::suggestions::SuggestionMatch>::sse_decode(deserializer);
+        let mut var_completion = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::functions::suggestions::Suggestion {
+            url: var_url,
+            title: var_title,
+            bookmarked: var_bookmarked,
+            visit_count: var_visitCount,
+            last_visited: var_lastVisited,
+            matched: var_matched,
+            completion: var_completion,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::suggestions::SuggestionMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::suggestions::SuggestionMatch::Substring,
+            1 => holloway_core::api::functions::suggestions::SuggestionMatch::WordPrefix,
+            2 => holloway_core::api::functions::suggestions::SuggestionMatch::UrlPrefix,
+            _ => unreachable!("Invalid variant for SuggestionMatch: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::scheduler::TaskSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_task =
+            <holloway_core::api::functions::scheduler::ScheduledTask>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_intervalSecs = <u32>::sse_decode(deserializer);
+        let mut var_jitterSecs = <u32>::sse_decode(deserializer);
+        let mut var_unmeteredOnly = <bool>::sse_decode(deserializer);
+        let mut var_chargingOnly = <bool>::sse_decode(deserializer);
+        return holloway_core::api::functions::scheduler::TaskSchedule {
+            task: var_task,
+            enabled: var_enabled,
+            interval_secs: var_intervalSecs,
+            jitter_secs: var_jitterSecs,
+            unmetered_only: var_unmeteredOnly,
+            charging_only: var_chargingOnly,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::bidi::TextDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::bidi::TextDirection::Ltr,
+            1 => holloway_core::api::functions::bidi::TextDirection::Rtl,
+            _ => unreachable!("Invalid variant for TextDirection: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::tls_failure::TlsFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <holloway_core::api::models::tls_failure::TlsFailureKind>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_names = <Vec<String>>::sse_decode(deserializer);
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_issuer = <String>::sse_decode(deserializer);
+        let mut var_notBefore = <i64>::sse_decode(deserializer);
+        let mut var_notAfter = <i64>::sse_decode(deserializer);
+        let mut var_intermediates = <u32>::sse_decode(deserializer);
+        return holloway_core::api::models::tls_failure::TlsFailure {
+            kind: var_kind,
+            host: var_host,
+            message: var_message,
+            names: var_names,
+            subject: var_subject,
+            issuer: var_issuer,
+            not_before: var_notBefore,
+            not_after: var_notAfter,
+            intermediates: var_intermediates,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::models::tls_failure::TlsFailureKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::models::tls_failure::TlsFailureKind::Expired,
+            1 => holloway_core::api::models::tls_failure::TlsFailureKind::NotYetValid,
+            2 => holloway_core::api::models::tls_failure::TlsFailureKind::SelfSigned,
+            3 => holloway_core::api::models::tls_failure::TlsFailureKind::NameMismatch,
+            4 => holloway_core::api::models::tls_failure::TlsFailureKind::IncompleteChain,
+            5 => holloway_core::api::models::tls_failure::TlsFailureKind::UntrustedIssuer,
+            6 => holloway_core::api::models::tls_failure::TlsFailureKind::Revoked,
+            7 => holloway_core::api::models::tls_failure::TlsFailureKind::Other,
+            _ => unreachable!("Invalid variant for TlsFailureKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::host_settings::TlsPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::storage::host_settings::TlsPolicy::TrustOnFirstUse,
+            1 => holloway_core::api::storage::host_settings::TlsPolicy::AcceptChanged,
+            2 => holloway_core::api::storage::host_settings::TlsPolicy::Unverified,
+            3 => holloway_core::api::storage::host_settings::TlsPolicy::Strict,
+            _ => unreachable!("Invalid variant for TlsPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::highlighting::TokenKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::highlighting::TokenKind::Plain,
+            1 => holloway_core::api::functions::highlighting::TokenKind::Comment,
+            2 => holloway_core::api::functions::highlighting::TokenKind::String,
+            3 => holloway_core::api::functions::highlighting::TokenKind::Number,
+            4 => holloway_core::api::functions::highlighting::TokenKind::Constant,
+            5 => holloway_core::api::functions::highlighting::TokenKind::Keyword,
+            6 => holloway_core::api::functions::highlighting::TokenKind::Operator,
+            7 => holloway_core::api::functions::highlighting::TokenKind::Type,
+            8 => holloway_core::api::functions::highlighting::TokenKind::Function,
+            9 => holloway_core::api::functions::highlighting::TokenKind::Variable,
+            10 => holloway_core::api::functions::highlighting::TokenKind::Tag,
+            11 => holloway_core::api::functions::highlighting::TokenKind::Attribute,
+            12 => holloway_core::api::functions::highlighting::TokenKind::Punctuation,
+            _ => unreachable!("Invalid variant for TokenKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::highlighting::TokenSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_kind =
+            <holloway_core::api::functions::highlighting::TokenKind>::sse_decode(deserializer);
+        return holloway_core::api::functions::highlighting::TokenSpan {
+            text: var_text,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::top_sites::TopSite {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_host = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_favicon = <Option<String>>::sse_decode(deserializer);
+        let mut var_visitCount = <u32>::sse_decode(deserializer);
+        let mut var_lastVisited = <i64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return holloway_core::api::functions::top_sites::TopSite {
+            url: var_url,
+            host: var_host,
+            title: var_title,
+            favicon: var_favicon,
+            visit_count: var_visitCount,
+            last_visited: var_lastVisited,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::request_trace::TraceEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage =
+            <holloway_core::api::functions::request_trace::TraceStage>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_elapsedMs = <u64>::sse_decode(deserializer);
+        return holloway_core::api::functions::request_trace::TraceEvent {
+            stage: var_stage,
+            message: var_message,
+            elapsed_ms: var_elapsedMs,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::functions::request_trace::TraceStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => holloway_core::api::functions::request_trace::TraceStage::Navigation,
+            1 => holloway_core::api::functions::request_trace::TraceStage::Cache,
+            2 => holloway_core::api::functions::request_trace::TraceStage::Transport,
+            3 => holloway_core::api::functions::request_trace::TraceStage::Tls,
+            4 => holloway_core::api::functions::request_trace::TraceStage::Response,
+            5 => holloway_core::api::functions::request_trace::TraceStage::Parser,
+            _ => unreachable!("Invalid variant for TraceStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::trusted_cas::TrustedCa {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fingerprint = <String>::sse_decode(deserializer);
+        let mut var_subject = <String>::sse_decode(deserializer);
+        let mut var_notAfter = <i64>::sse_decode(deserializer);
+        let mut var_addedAt = <i64>::sse_decode(deserializer);
+        return holloway_core::api::storage::trusted_cas::TrustedCa {
+            fingerprint: var_fingerprint,
+            subject: var_subject,
+            not_after: var_notAfter,
+            added_at: var_addedAt,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for holloway_core::api::storage::watches::WatchedPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_addedAt = <i64>::sse_decode(deserializer);
+        let mut var_seenAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_checkedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_changedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::storage::watches::WatchedPage {
+            id: var_id,
+            url: var_url,
+            title: var_title,
+            added_at: var_addedAt,
+            seen_at: var_seenAt,
+            checked_at: var_checkedAt,
+            changed_at: var_changedAt,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for holloway_core::api::storage::plan_watches::WatchedPlan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_addedAt = <i64>::sse_decode(deserializer);
+        let mut var_plan = <Option<String>>::sse_decode(deserializer);
+        let mut var_checkedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return holloway_core::api::storage::plan_watches::WatchedPlan {
+            id: var_id,
+            url: var_url,
+            added_at: var_addedAt,
+            plan: var_plan,
+            checked_at: var_checkedAt,
+            updated_at: var_updatedAt,
+            last_error: var_lastError,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        8 => wire__crate__api__exposed_functions__archive_page_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => {
+            wire__crate__api__exposed_functions__begin_fetch_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => wire__crate__api__exposed_functions__cache_read_later_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__exposed_functions__check_for_updates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => {
+            wire__crate__api__exposed_functions__check_plans_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => {
+            wire__crate__api__exposed_functions__cso_query_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__exposed_functions__diff_with_cache_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__exposed_functions__download_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__exposed_functions__download_verified_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__exposed_functions__fetch_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__exposed_functions__fetch_document_images_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__exposed_functions__fetch_with_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__exposed_functions__finish_fetch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__api__exposed_functions__index_capsule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => {
+            wire__crate__api__exposed_functions__init_app_impl(port, ptr, rust_vec_len, data_len)
+        }
+        132 => {
+            wire__crate__api__exposed_functions__map_site_impl(port, ptr, rust_vec_len, data_len)
+        }
+        139 => {
+            wire__crate__api__exposed_functions__navigate_impl(port, ptr, rust_vec_len, data_len)
+        }
+        140 => wire__crate__api__exposed_functions__navigate_cancellable_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        143 => wire__crate__api__exposed_functions__navigate_with_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        144 => wire__crate__api__exposed_functions__next_batch_result_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        145 => wire__crate__api__exposed_functions__next_expiry_reminder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        146 => wire__crate__api__exposed_functions__next_fetch_update_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        147 => wire__crate__api__exposed_functions__next_log_record_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        148 => wire__crate__api__exposed_functions__next_mirror_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        149 => wire__crate__api__exposed_functions__next_navigation_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        150 => wire__crate__api__exposed_functions__next_scheduler_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        151 => wire__crate__api__exposed_functions__next_stream_event_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        155 => {
+            wire__crate__api__exposed_functions__open_gempub_impl(port, ptr, rust_vec_len, data_len)
+        }
+        160 => {
+            wire__crate__api__exposed_functions__open_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        161 => wire__crate__api__exposed_functions__page_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => wire__crate__api__exposed_functions__peek_impl(port, ptr, rust_vec_len, data_len),
+        164 => {
+            wire__crate__api__exposed_functions__preconnect_impl(port, ptr, rust_vec_len, data_len)
+        }
+        165 => {
+            wire__crate__api__exposed_functions__prefetch_impl(port, ptr, rust_vec_len, data_len)
+        }
+        167 => wire__crate__api__exposed_functions__random_destination_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        172 => wire__crate__api__exposed_functions__refresh_all_feeds_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        173 => wire__crate__api__exposed_functions__refresh_bookmarks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        174 => wire__crate__api__exposed_functions__refresh_favicon_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        175 => wire__crate__api__exposed_functions__refresh_feed_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        187 => wire__holloway_core__api__models__navigation_result__request_timings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        192 => wire__crate__api__exposed_functions__save_for_later_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        194 => wire__crate__api__exposed_functions__scheduler_tick_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        195 => wire__crate__api__exposed_functions__search_impl(port, ptr, rust_vec_len, data_len),
+        196 => {
+            wire__crate__api__exposed_functions__search_all_impl(port, ptr, rust_vec_len, data_len)
+        }
+        249 => {
+            wire__crate__api__exposed_functions__test_proxy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        251 => wire__crate__api__exposed_functions__titan_upload_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__exposed_functions__accept_new_certificate_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => {
+            wire__crate__api__exposed_functions__activate_identity_impl(ptr, rust_vec_len, data_len)
+        }
+        3 => wire__crate__api__exposed_functions__active_identity_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__exposed_functions__add_block_rule_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__exposed_functions__add_bookmark_impl(ptr, rust_vec_len, data_len),
+        6 => {
+            wire__crate__api__exposed_functions__add_history_visit_impl(ptr, rust_vec_len, data_len)
+        }
+        7 => wire__crate__api__exposed_functions__analyze_text_direction_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__exposed_functions__cancel_batch_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__exposed_functions__cancel_capsule_indexing_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__exposed_functions__cancel_mirror_impl(ptr, rust_vec_len, data_len),
+        14 => {
+            wire__crate__api__exposed_functions__cancel_prefetch_impl(ptr, rust_vec_len, data_len)
+        }
+        15 => wire__crate__api__exposed_functions__cancel_request_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__exposed_functions__cancel_stream_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__exposed_functions__checkpoint_profile_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__exposed_functions__checksum_link_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__exposed_functions__clear_data_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__exposed_functions__close_document_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__exposed_functions__close_gempub_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__exposed_functions__close_menu_impl(ptr, rust_vec_len, data_len),
+        25 => {
+            wire__crate__api__exposed_functions__create_identity_impl(ptr, rust_vec_len, data_len)
+        }
+        27 => wire__crate__api__exposed_functions__deactivate_identity_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => {
+            wire__crate__api__exposed_functions__delete_archived_impl(ptr, rust_vec_len, data_len)
+        }
+        29 => {
+            wire__crate__api__exposed_functions__delete_download_impl(ptr, rust_vec_len, data_len)
+        }
+        30 => wire__crate__api__exposed_functions__delete_history_entry_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__exposed_functions__delete_history_url_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__exposed_functions__delete_saved_answer_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__exposed_functions__delete_session_impl(ptr, rust_vec_len, data_len),
+        34 => {
+            wire__crate__api__exposed_functions__detect_file_type_impl(ptr, rust_vec_len, data_len)
+        }
+        36 => wire__crate__api__exposed_functions__dismiss_host_migration_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__exposed_functions__display_host_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__exposed_functions__display_url_impl(ptr, rust_vec_len, data_len),
+        39 => {
+            wire__crate__api__exposed_functions__document_outline_impl(ptr, rust_vec_len, data_len)
+        }
+        40 => wire__crate__api__exposed_functions__document_stats_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__exposed_functions__end_private_session_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => {
+            wire__crate__api__exposed_functions__evict_cache_entry_impl(ptr, rust_vec_len, data_len)
+        }
+        45 => wire__crate__api__exposed_functions__expiring_identities_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => {
+            wire__crate__api__exposed_functions__export_bookmarks_impl(ptr, rust_vec_len, data_len)
+        }
+        47 => wire__crate__api__exposed_functions__export_bookmarks_to_file_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__exposed_functions__export_identity_pem_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => wire__crate__api__exposed_functions__export_identity_pkcs12_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__exposed_functions__export_known_hosts_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__exposed_functions__extract_links_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__exposed_functions__find_duplicate_bookmark_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__exposed_functions__find_duplicate_tab_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => {
+            wire__crate__api__exposed_functions__forget_known_host_impl(ptr, rust_vec_len, data_len)
+        }
+        59 => wire__crate__api__exposed_functions__gempub_chapter_impl(ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__exposed_functions__gempub_file_impl(ptr, rust_vec_len, data_len),
+        61 => {
+            wire__crate__api__exposed_functions__gemtext_outline_impl(ptr, rust_vec_len, data_len)
+        }
+        62 => wire__crate__api__exposed_functions__gemtext_stats_impl(ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__exposed_functions__generate_storage_key_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__exposed_functions__get_bandwidth_limit_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__exposed_functions__get_cache_policies_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__exposed_functions__get_certificate_failure_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => {
+            wire__crate__api__exposed_functions__get_clock_offset_impl(ptr, rust_vec_len, data_len)
+        }
+        68 => wire__crate__api__exposed_functions__get_clock_skew_tolerance_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__exposed_functions__get_config_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__exposed_functions__get_data_cap_status_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__exposed_functions__get_data_usage_impl(ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__exposed_functions__get_decompression_limits_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => {
+            wire__crate__api__exposed_functions__get_history_page_impl(ptr, rust_vec_len, data_len)
+        }
+        74 => {
+            wire__crate__api__exposed_functions__get_host_settings_impl(ptr, rust_vec_len, data_len)
+        }
+        75 => wire__crate__api__exposed_functions__get_host_stats_impl(ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__exposed_functions__get_line_count_impl(ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__exposed_functions__get_lines_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__exposed_functions__get_lite_mode_impl(ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__exposed_functions__get_log_level_impl(ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__exposed_functions__get_max_concurrent_handshakes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__exposed_functions__get_menu_page_impl(ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__exposed_functions__get_protocols_impl(ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__exposed_functions__get_proxy_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        84 => {
+            wire__crate__api__exposed_functions__get_rate_limits_impl(ptr, rust_vec_len, data_len)
+        }
+        85 => wire__crate__api__exposed_functions__get_recent_history_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__exposed_functions__get_remember_input_answers_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => {
+            wire__crate__api__exposed_functions__get_saved_answers_impl(ptr, rust_vec_len, data_len)
+        }
+        88 => wire__crate__api__exposed_functions__get_schedules_impl(ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__exposed_functions__get_search_on_failure_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__exposed_functions__get_search_provider_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__exposed_functions__get_start_page_impl(ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__exposed_functions__get_top_hosts_impl(ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__exposed_functions__get_top_sites_impl(ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__exposed_functions__get_trace_impl(ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__exposed_functions__gophermap_to_gemtext_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        96 => wire__crate__api__exposed_functions__greet_impl(ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__exposed_functions__highlight_code_impl(ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__exposed_functions__highlight_languages_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__exposed_functions__highlight_preformatted_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => {
+            wire__crate__api__exposed_functions__import_bookmarks_impl(ptr, rust_vec_len, data_len)
+        }
+        101 => wire__crate__api__exposed_functions__import_ca_certificates_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        102 => wire__crate__api__exposed_functions__import_identity_pem_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        103 => wire__crate__api__exposed_functions__import_identity_pkcs12_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__exposed_functions__import_known_hosts_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__exposed_functions__import_lagrange_identities_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        108 => wire__crate__api__exposed_functions__is_key_storage_unlocked_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        109 => {
+            wire__crate__api__exposed_functions__is_offline_mode_impl(ptr, rust_vec_len, data_len)
+        }
+        110 => wire__crate__api__exposed_functions__list_archived_impl(ptr, rust_vec_len, data_len),
+        111 => {
+            wire__crate__api__exposed_functions__list_block_rules_impl(ptr, rust_vec_len, data_len)
+        }
+        112 => {
+            wire__crate__api__exposed_functions__list_bookmarks_impl(ptr, rust_vec_len, data_len)
+        }
+        113 => wire__crate__api__exposed_functions__list_cache_entries_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => {
+            wire__crate__api__exposed_functions__list_downloads_impl(ptr, rust_vec_len, data_len)
+        }
+        115 => {
+            wire__crate__api__exposed_functions__list_feed_entries_impl(ptr, rust_vec_len, data_len)
+        }
+        116 => wire__crate__api__exposed_functions__list_feeds_impl(ptr, rust_vec_len, data_len),
+        117 => wire__crate__api__exposed_functions__list_gemini_proxies_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => wire__crate__api__exposed_functions__list_host_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => {
+            wire__crate__api__exposed_functions__list_identities_impl(ptr, rust_vec_len, data_len)
+        }
+        120 => wire__crate__api__exposed_functions__list_identity_scopes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => {
+            wire__crate__api__exposed_functions__list_known_hosts_impl(ptr, rust_vec_len, data_len)
+        }
+        122 => wire__crate__api__exposed_functions__list_port_overrides_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => {
+            wire__crate__api__exposed_functions__list_read_later_impl(ptr, rust_vec_len, data_len)
+        }
+        124 => wire__crate__api__exposed_functions__list_saved_answers_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        125 => wire__crate__api__exposed_functions__list_sessions_impl(ptr, rust_vec_len, data_len),
+        126 => {
+            wire__crate__api__exposed_functions__list_trusted_cas_impl(ptr, rust_vec_len, data_len)
+        }
+        127 => wire__crate__api__exposed_functions__list_watched_pages_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        128 => wire__crate__api__exposed_functions__list_watched_plans_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        129 => wire__crate__api__exposed_functions__load_session_impl(ptr, rust_vec_len, data_len),
+        130 => {
+            wire__crate__api__exposed_functions__lock_key_storage_impl(ptr, rust_vec_len, data_len)
+        }
+        131 => wire__crate__api__exposed_functions__log_files_impl(ptr, rust_vec_len, data_len),
+        133 => wire__crate__api__exposed_functions__mark_all_feed_entries_read_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        134 => {
+            wire__crate__api__exposed_functions__mark_plan_read_impl(ptr, rust_vec_len, data_len)
+        }
+        135 => {
+            wire__crate__api__exposed_functions__mark_read_later_impl(ptr, rust_vec_len, data_len)
+        }
+        136 => wire__crate__api__exposed_functions__measure_preformatted_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        137 => wire__crate__api__exposed_functions__migrate_host_impl(ptr, rust_vec_len, data_len),
+        138 => {
+            wire__crate__api__exposed_functions__mirror_capsule_impl(ptr, rust_vec_len, data_len)
+        }
+        141 => wire__crate__api__exposed_functions__navigate_error_info_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        142 => wire__crate__api__exposed_functions__navigate_many_impl(ptr, rust_vec_len, data_len),
+        152 => wire__crate__api__exposed_functions__normalize_url_impl(ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__exposed_functions__open_archived_impl(ptr, rust_vec_len, data_len),
+        154 => wire__crate__api__exposed_functions__open_document_impl(ptr, rust_vec_len, data_len),
+        156 => {
+            wire__crate__api__exposed_functions__open_gempub_data_impl(ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__exposed_functions__open_menu_impl(ptr, rust_vec_len, data_len),
+        158 => wire__crate__api__exposed_functions__open_profile_impl(ptr, rust_vec_len, data_len),
+        159 => {
+            wire__crate__api__exposed_functions__open_read_later_impl(ptr, rust_vec_len, data_len)
+        }
+        162 => {
+            wire__crate__api__exposed_functions__parse_gopher_menu_impl(ptr, rust_vec_len, data_len)
+        }
+        166 => {
+            wire__crate__api__exposed_functions__prefetch_allowed_impl(ptr, rust_vec_len, data_len)
+        }
+        168 => wire__crate__api__exposed_functions__recent_logs_impl(ptr, rust_vec_len, data_len),
+        169 => {
+            wire__crate__api__exposed_functions__record_download_impl(ptr, rust_vec_len, data_len)
+        }
+        170 => {
+            wire__crate__api__exposed_functions__reflow_gemtext_impl(ptr, rust_vec_len, data_len)
+        }
+        171 => wire__crate__api__exposed_functions__reflow_text_impl(ptr, rust_vec_len, data_len),
+        176 => {
+            wire__crate__api__exposed_functions__remove_block_rule_impl(ptr, rust_vec_len, data_len)
+        }
+        177 => {
+            wire__crate__api__exposed_functions__remove_bookmark_impl(ptr, rust_vec_len, data_len)
+        }
+        178 => wire__crate__api__exposed_functions__remove_gemini_proxy_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        179 => wire__crate__api__exposed_functions__remove_host_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        180 => wire__crate__api__exposed_functions__remove_port_override_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        181 => {
+            wire__crate__api__exposed_functions__remove_read_later_impl(ptr, rust_vec_len, data_len)
+        }
+        182 => wire__crate__api__exposed_functions__remove_titan_token_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        183 => {
+            wire__crate__api__exposed_functions__remove_trusted_ca_impl(ptr, rust_vec_len, data_len)
+        }
+        184 => {
+            wire__crate__api__exposed_functions__render_to_html_impl(ptr, rust_vec_len, data_len)
+        }
+        185 => wire__crate__api__exposed_functions__render_to_markdown_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        186 => {
+            wire__crate__api__exposed_functions__renew_identity_impl(ptr, rust_vec_len, data_len)
+        }
+        188 => wire__crate__api__exposed_functions__reserve_request_id_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        189 => wire__crate__api__exposed_functions__reset_cache_policy_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        190 => wire__crate__api__exposed_functions__resolve_link_impl(ptr, rust_vec_len, data_len),
+        191 => wire__crate__api__exposed_functions__route_content_impl(ptr, rust_vec_len, data_len),
+        193 => wire__crate__api__exposed_functions__save_session_impl(ptr, rust_vec_len, data_len),
+        197 => {
+            wire__crate__api__exposed_functions__search_capsule_impl(ptr, rust_vec_len, data_len)
+        }
+        198 => {
+            wire__crate__api__exposed_functions__search_history_impl(ptr, rust_vec_len, data_len)
+        }
+        199 => wire__crate__api__exposed_functions__search_local_impl(ptr, rust_vec_len, data_len),
+        200 => {
+            wire__crate__api__exposed_functions__search_providers_impl(ptr, rust_vec_len, data_len)
+        }
+        201 => wire__crate__api__exposed_functions__set_bandwidth_limit_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        202 => wire__crate__api__exposed_functions__set_bookmark_title_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        203 => {
+            wire__crate__api__exposed_functions__set_cache_policy_impl(ptr, rust_vec_len, data_len)
+        }
+        204 => wire__crate__api__exposed_functions__set_clock_skew_tolerance_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        205 => wire__crate__api__exposed_functions__set_config_impl(ptr, rust_vec_len, data_len),
+        206 => wire__crate__api__exposed_functions__set_data_cap_impl(ptr, rust_vec_len, data_len),
+        207 => wire__crate__api__exposed_functions__set_decompression_limits_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        208 => wire__crate__api__exposed_functions__set_feed_entry_read_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        209 => wire__crate__api__exposed_functions__set_gateway_impl(ptr, rust_vec_len, data_len),
+        210 => {
+            wire__crate__api__exposed_functions__set_gemini_proxy_impl(ptr, rust_vec_len, data_len)
+        }
+        211 => wire__crate__api__exposed_functions__set_gopher_gateway_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        212 => {
+            wire__crate__api__exposed_functions__set_host_settings_impl(ptr, rust_vec_len, data_len)
+        }
+        213 => wire__crate__api__exposed_functions__set_lite_mode_impl(ptr, rust_vec_len, data_len),
+        214 => {
+            wire__crate__api__exposed_functions__set_log_directory_impl(ptr, rust_vec_len, data_len)
+        }
+        215 => wire__crate__api__exposed_functions__set_log_level_impl(ptr, rust_vec_len, data_len),
+        216 => wire__crate__api__exposed_functions__set_max_concurrent_handshakes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        217 => {
+            wire__crate__api__exposed_functions__set_network_type_impl(ptr, rust_vec_len, data_len)
+        }
+        218 => {
+            wire__crate__api__exposed_functions__set_offline_mode_impl(ptr, rust_vec_len, data_len)
+        }
+        219 => {
+            wire__crate__api__exposed_functions__set_port_override_impl(ptr, rust_vec_len, data_len)
+        }
+        220 => {
+            wire__crate__api__exposed_functions__set_power_state_impl(ptr, rust_vec_len, data_len)
+        }
+        221 => wire__crate__api__exposed_functions__set_protocol_enabled_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        222 => wire__crate__api__exposed_functions__set_proxy_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        223 => {
+            wire__crate__api__exposed_functions__set_rate_limits_impl(ptr, rust_vec_len, data_len)
+        }
+        224 => wire__crate__api__exposed_functions__set_remember_input_answers_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        225 => wire__crate__api__exposed_functions__set_schedule_impl(ptr, rust_vec_len, data_len),
+        226 => wire__crate__api__exposed_functions__set_search_on_failure_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        227 => wire__crate__api__exposed_functions__set_search_provider_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        228 => wire__crate__api__exposed_functions__set_session_scroll_offset_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        229 => {
+            wire__crate__api__exposed_functions__set_start_page_impl(ptr, rust_vec_len, data_len)
+        }
+        230 => {
+            wire__crate__api__exposed_functions__set_titan_token_impl(ptr, rust_vec_len, data_len)
+        }
+        231 => wire__crate__api__exposed_functions__shutdown_impl(ptr, rust_vec_len, data_len),
+        232 => wire__crate__api__exposed_functions__start_bookmark_refresh_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        233 => wire__crate__api__exposed_functions__start_expiry_checks_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        234 => {
+            wire__crate__api__exposed_functions__start_scheduler_impl(ptr, rust_vec_len, data_len)
+        }
+        235 => wire__crate__api__exposed_functions__stop_bookmark_refresh_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        236 => wire__crate__api__exposed_functions__stop_expiry_checks_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        237 => {
+            wire__crate__api__exposed_functions__stop_scheduler_impl(ptr, rust_vec_len, data_len)
+        }
+        238 => wire__crate__api__exposed_functions__strip_ansi_escapes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        239 => {
+            wire__crate__api__exposed_functions__style_ansi_text_impl(ptr, rust_vec_len, data_len)
+        }
+        240 => {
+            wire__crate__api__exposed_functions__subscribe_events_impl(ptr, rust_vec_len, data_len)
+        }
+        241 => {
+            wire__crate__api__exposed_functions__subscribe_feed_impl(ptr, rust_vec_len, data_len)
+        }
+        242 => {
+            wire__crate__api__exposed_functions__subscribe_logs_impl(ptr, rust_vec_len, data_len)
+        }
+        243 => wire__crate__api__exposed_functions__subscribe_request_events_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        244 => wire__crate__api__exposed_functions__subscribe_scheduler_events_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        245 => wire__crate__api__exposed_functions__suggest_impl(ptr, rust_vec_len, data_len),
+        246 => wire__crate__api__exposed_functions__suggest_download_path_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        247 => wire__crate__api__exposed_functions__suggested_action_for_status_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        248 => wire__crate__api__exposed_functions__suggested_host_migrations_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        250 => {
+            wire__crate__api__exposed_functions__titan_token_hosts_impl(ptr, rust_vec_len, data_len)
+        }
+        252 => wire__crate__api__exposed_functions__unlock_key_storage_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        253 => wire__crate__api__exposed_functions__unlock_key_storage_from_keyring_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        254 => {
+            wire__crate__api__exposed_functions__unread_feed_count_impl(ptr, rust_vec_len, data_len)
+        }
+        255 => wire__crate__api__exposed_functions__unsubscribe_events_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        256 => {
+            wire__crate__api__exposed_functions__unsubscribe_feed_impl(ptr, rust_vec_len, data_len)
+        }
+        257 => {
+            wire__crate__api__exposed_functions__unsubscribe_logs_impl(ptr, rust_vec_len, data_len)
+        }
+        258 => wire__crate__api__exposed_functions__unsubscribe_scheduler_events_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        259 => wire__crate__api__exposed_functions__unwatch_page_impl(ptr, rust_vec_len, data_len),
+        260 => wire__crate__api__exposed_functions__unwatch_plan_impl(ptr, rust_vec_len, data_len),
+        261 => wire__crate__api__exposed_functions__watch_page_impl(ptr, rust_vec_len, data_len),
+        262 => wire__crate__api__exposed_functions__watch_plan_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::bookmarks::AddedBookmark>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.bookmark.into_into_dart().into_dart(),
+            self.0.duplicate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::bookmarks::AddedBookmark>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::bookmarks::AddedBookmark>,
+    > for holloway_core::api::storage::bookmarks::AddedBookmark
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::bookmarks::AddedBookmark> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::mirrors::AnsiColor> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::mirrors::AnsiColor::Indexed(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::AnsiColor::Rgb { r, g, b } => [
+                1.into_dart(),
+                r.into_into_dart().into_dart(),
+                g.into_into_dart().into_dart(),
+                b.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::mirrors::AnsiColor>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::mirrors::AnsiColor>>
+    for crate::api::mirrors::AnsiColor
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::mirrors::AnsiColor> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::ansi::AnsiStyle> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.foreground.into_into_dart().into_dart(),
+            self.0.background.into_into_dart().into_dart(),
+            self.0.bold.into_into_dart().into_dart(),
+            self.0.dim.into_into_dart().into_dart(),
+            self.0.italic.into_into_dart().into_dart(),
+            self.0.underline.into_into_dart().into_dart(),
+            self.0.inverse.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::ansi::AnsiStyle>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::ansi::AnsiStyle>>
+    for holloway_core::api::functions::ansi::AnsiStyle
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::ansi::AnsiStyle> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::archive::ArchivedPage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+            self.0.body_hash.into_into_dart().into_dart(),
+            self.0.certificate_fingerprint.into_into_dart().into_dart(),
+            self.0.fetched_at.into_into_dart().into_dart(),
+            self.0.archived_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::archive::ArchivedPage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::archive::ArchivedPage>,
+    > for holloway_core::api::storage::archive::ArchivedPage
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::archive::ArchivedPage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::batch::BatchItem> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.index.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.request_id.into_into_dart().into_dart(),
+            self.0.result.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::batch::BatchItem>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::batch::BatchItem>>
+    for holloway_core::api::functions::batch::BatchItem
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::batch::BatchItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::blocklist::BlockRule>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.pattern.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::blocklist::BlockRule>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::blocklist::BlockRule>>
+    for holloway_core::api::storage::blocklist::BlockRule
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::blocklist::BlockRule> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::blocklist::BlockRuleKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::blocklist::BlockRuleKind::ExactHost => 0.into_dart(),
+            holloway_core::api::storage::blocklist::BlockRuleKind::Subdomains => 1.into_dart(),
+            holloway_core::api::storage::blocklist::BlockRuleKind::UrlPrefix => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::blocklist::BlockRuleKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::blocklist::BlockRuleKind>,
+    > for holloway_core::api::storage::blocklist::BlockRuleKind
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::blocklist::BlockRuleKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::bookmarks::Bookmark>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.created_at.into_into_dart().into_dart(),
+            self.0.favicon.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::bookmarks::Bookmark>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::bookmarks::Bookmark>>
+    for holloway_core::api::storage::bookmarks::Bookmark
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::bookmarks::Bookmark> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::bookmark_export::BookmarkFormat>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::bookmark_export::BookmarkFormat::Xbel => 0.into_dart(),
+            holloway_core::api::functions::bookmark_export::BookmarkFormat::Gemtext => {
+                1.into_dart()
+            }
+            holloway_core::api::functions::bookmark_export::BookmarkFormat::Html => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bookmark_export::BookmarkFormat>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::bookmark_export::BookmarkFormat>,
+    > for holloway_core::api::functions::bookmark_export::BookmarkFormat
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::bookmark_export::BookmarkFormat> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarkSource>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::bookmark_import::BookmarkSource::Amfora => 0.into_dart(),
+            holloway_core::api::functions::bookmark_import::BookmarkSource::Lagrange => {
+                1.into_dart()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarkSource>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarkSource>,
+    > for holloway_core::api::functions::bookmark_import::BookmarkSource
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarkSource> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarksImport>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.imported.into_into_dart().into_dart(),
+            self.0.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarksImport>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarksImport>,
+    > for holloway_core::api::functions::bookmark_import::BookmarksImport
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::bookmark_import::BookmarksImport> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::cache::CacheEntry> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+            self.0.fetched_at.into_into_dart().into_dart(),
+            self.0.expires_at.into_into_dart().into_dart(),
+            self.0.stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::cache::CacheEntry>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::cache::CacheEntry>>
+    for holloway_core::api::storage::cache::CacheEntry
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::cache::CacheEntry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::cache::CachePolicy> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.scheme.into_into_dart().into_dart(),
+            self.0.cacheable.into_into_dart().into_dart(),
+            self.0.ttl_secs.into_into_dart().into_dart(),
+            self.0.cache_queries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::cache::CachePolicy>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::cache::CachePolicy>>
+    for holloway_core::api::storage::cache::CachePolicy
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::cache::CachePolicy> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::certificate_error::CertificateError>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.identity_id.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.suggested_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::certificate_error::CertificateError>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::certificate_error::CertificateError>,
+    > for holloway_core::api::models::certificate_error::CertificateError
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::certificate_error::CertificateError> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::certificate_error::CertificateErrorKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::certificate_error::CertificateErrorKind::Required => {
+                0.into_dart()
+            }
+            holloway_core::api::models::certificate_error::CertificateErrorKind::NotAuthorized => {
+                1.into_dart()
+            }
+            holloway_core::api::models::certificate_error::CertificateErrorKind::NotValid => {
+                2.into_dart()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::certificate_error::CertificateErrorKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::certificate_error::CertificateErrorKind>,
+    > for holloway_core::api::models::certificate_error::CertificateErrorKind
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::certificate_error::CertificateErrorKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_result::CertificateSummary>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.fingerprint.into_into_dart().into_dart(),
+            self.0.subject.into_into_dart().into_dart(),
+            self.0.issuer.into_into_dart().into_dart(),
+            self.0.not_before.into_into_dart().into_dart(),
+            self.0.not_after.into_into_dart().into_dart(),
+            self.0.first_seen.into_into_dart().into_dart(),
+            self.0.pin_status.into_into_dart().into_dart(),
+            self.0.expiry.into_into_dart().into_dart(),
+            self.0.tls_version.into_into_dart().into_dart(),
+            self.0.cipher_suite.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_result::CertificateSummary>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_result::CertificateSummary>,
+    > for holloway_core::api::models::navigation_result::CertificateSummary
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_result::CertificateSummary> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::downloads::ChecksumStatus>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::downloads::ChecksumStatus::Unchecked => 0.into_dart(),
+            holloway_core::api::storage::downloads::ChecksumStatus::Verified => 1.into_dart(),
+            holloway_core::api::storage::downloads::ChecksumStatus::Mismatch => 2.into_dart(),
+            holloway_core::api::storage::downloads::ChecksumStatus::Unavailable => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::downloads::ChecksumStatus>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::downloads::ChecksumStatus>,
+    > for holloway_core::api::storage::downloads::ChecksumStatus
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::downloads::ChecksumStatus> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::clear::ClearedData> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.category.into_into_dart().into_dart(),
+            self.0.removed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::clear::ClearedData>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::clear::ClearedData>>
+    for holloway_core::api::storage::clear::ClearedData
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::clear::ClearedData> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::clock_skew::ClockOffset>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.offset_secs.into_into_dart().into_dart(),
+            self.0.hosts.into_into_dart().into_dart(),
+            self.0.consistent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::clock_skew::ClockOffset>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::clock_skew::ClockOffset>,
+    > for holloway_core::api::functions::clock_skew::ClockOffset
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::clock_skew::ClockOffset> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::config::Config> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.default_scheme.into_into_dart().into_dart(),
+            self.0.guess_schemes.into_into_dart().into_dart(),
+            self.0.start_page.into_into_dart().into_dart(),
+            self.0.search_provider.into_into_dart().into_dart(),
+            self.0.connect_timeout_secs.into_into_dart().into_dart(),
+            self.0.read_timeout_secs.into_into_dart().into_dart(),
+            self.0.tls_policy.into_into_dart().into_dart(),
+            self.0.proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::config::Config>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::config::Config>>
+    for holloway_core::api::functions::config::Config
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::config::Config> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::crawler::CrawlResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.capsule.into_into_dart().into_dart(),
+            self.0.indexed.into_into_dart().into_dart(),
+            self.0.failed.into_into_dart().into_dart(),
+            self.0.disallowed.into_into_dart().into_dart(),
+            self.0.remaining.into_into_dart().into_dart(),
+            self.0.cancelled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::crawler::CrawlResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::crawler::CrawlResult>,
+    > for holloway_core::api::functions::crawler::CrawlResult
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::crawler::CrawlResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::protocols::cso::CsoField> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.name.into_into_dart().into_dart(),
+            self.0.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::protocols::cso::CsoField>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::protocols::cso::CsoField>>
+    for holloway_core::api::protocols::cso::CsoField
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::protocols::cso::CsoField> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::protocols::cso::CsoRecord> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.index.into_into_dart().into_dart(),
+            self.0.fields.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::protocols::cso::CsoRecord>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::protocols::cso::CsoRecord>>
+    for holloway_core::api::protocols::cso::CsoRecord
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::protocols::cso::CsoRecord> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::data_usage::DailyUsage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.day_start.into_into_dart().into_dart(),
+            self.0.network.into_into_dart().into_dart(),
+            self.0.bytes_sent.into_into_dart().into_dart(),
+            self.0.bytes_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::data_usage::DailyUsage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::data_usage::DailyUsage>,
+    > for holloway_core::api::storage::data_usage::DailyUsage
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::data_usage::DailyUsage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::data_usage::DataCapStatus>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.limit_bytes.into_into_dart().into_dart(),
+            self.0.used_bytes.into_into_dart().into_dart(),
+            self.0.exceeded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::data_usage::DataCapStatus>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::data_usage::DataCapStatus>,
+    > for holloway_core::api::functions::data_usage::DataCapStatus
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::data_usage::DataCapStatus> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::clear::DataCategory>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::clear::DataCategory::History => 0.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::Cache => 1.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::DataUsage => 2.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::Downloads => 3.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::Favicons => 4.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::HostStats => 5.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::InputAnswers => 6.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::IdentityActivations => 7.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::Identities => 8.into_dart(),
+            holloway_core::api::storage::clear::DataCategory::KnownHosts => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::clear::DataCategory>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::clear::DataCategory>>
+    for holloway_core::api::storage::clear::DataCategory
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::clear::DataCategory> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::transport::limits::DecompressionLimits>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.max_ratio.into_into_dart().into_dart(),
+            self.0.max_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::transport::limits::DecompressionLimits>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::transport::limits::DecompressionLimits>,
+    > for holloway_core::api::transport::limits::DecompressionLimits
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::transport::limits::DecompressionLimits> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::page_diff::DiffLine>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.line.into_into_dart().into_dart(),
+            self.0.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::page_diff::DiffLine>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::page_diff::DiffLine>,
+    > for holloway_core::api::functions::page_diff::DiffLine
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::page_diff::DiffLine> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::homograph::DisplayHost>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.host.into_into_dart().into_dart(),
+            self.0.spoofing_warning.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::homograph::DisplayHost>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::homograph::DisplayHost>,
+    > for holloway_core::api::functions::homograph::DisplayHost
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::homograph::DisplayHost> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::downloads::DownloadRecord>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.path.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+            self.0.finished_at.into_into_dart().into_dart(),
+            self.0.checksum_url.into_into_dart().into_dart(),
+            self.0.checksum.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::downloads::DownloadRecord>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::downloads::DownloadRecord>,
+    > for holloway_core::api::storage::downloads::DownloadRecord
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::downloads::DownloadRecord> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::downloads::DownloadResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.path.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.sha256.into_into_dart().into_dart(),
+            self.0.checksum.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::downloads::DownloadResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::downloads::DownloadResult>,
+    > for holloway_core::api::functions::downloads::DownloadResult
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::downloads::DownloadResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::downloads::DownloadStatus>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::downloads::DownloadStatus::Completed => 0.into_dart(),
+            holloway_core::api::storage::downloads::DownloadStatus::Failed => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::downloads::DownloadStatus>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::downloads::DownloadStatus>,
+    > for holloway_core::api::storage::downloads::DownloadStatus
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::downloads::DownloadStatus> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::error_info::ErrorCode>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::error_info::ErrorCode::UrlInvalid => 0.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::UrlUnsupportedScheme => {
+                1.into_dart()
+            }
+            holloway_core::api::models::error_info::ErrorCode::UrlSchemeDisabled => 2.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::UrlBlocked => 3.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::UrlDisallowed => 4.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetDnsFailed => 5.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetTimeout => 6.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetRefused => 7.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetConnectFailed => 8.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetConnectionLost => 9.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetOffline => 10.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NetReadTimeout => 11.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::ProxyUnreachable => 12.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::ProxyAuthFailed => 13.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::ProxyFailed => 14.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GatewayFailed => 15.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::ContentTooLarge => 16.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::TlsHandshakeFailed => 17.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::TlsFingerprintMismatch => {
+                18.into_dart()
+            }
+            holloway_core::api::models::error_info::ErrorCode::TlsCertificateDate => 19.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::TlsCertificateInvalid => {
+                20.into_dart()
+            }
+            holloway_core::api::models::error_info::ErrorCode::StoreFailed => 21.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::IdentityFailed => 22.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::NotFound => 23.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::InputInvalid => 24.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::Cancelled => 25.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::ResponseInvalid => 26.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GemInputRequired => 27.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GemRedirect => 28.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GemServerError => 29.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GemCertificateRequired => {
+                30.into_dart()
+            }
+            holloway_core::api::models::error_info::ErrorCode::GemTimeout => 31.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GopherBadType => 32.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::GopherBadSelector => 33.into_dart(),
+            holloway_core::api::models::error_info::ErrorCode::Unknown => 34.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::error_info::ErrorCode>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::models::error_info::ErrorCode>>
+    for holloway_core::api::models::error_info::ErrorCode
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::error_info::ErrorCode> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::error_info::ErrorInfo>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.code.into_into_dart().into_dart(),
+            self.0.name.into_into_dart().into_dart(),
+            self.0.number.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.retryable.into_into_dart().into_dart(),
+            self.0.user_actionable.into_into_dart().into_dart(),
+            self.0.suggested_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::error_info::ErrorInfo>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::models::error_info::ErrorInfo>>
+    for holloway_core::api::models::error_info::ErrorInfo
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::error_info::ErrorInfo> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::identities::ExpiryReminder>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.identity.into_into_dart().into_dart(),
+            self.0.days_left.into_into_dart().into_dart(),
+            self.0.expired.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::identities::ExpiryReminder>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::identities::ExpiryReminder>,
+    > for holloway_core::api::functions::identities::ExpiryReminder
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::identities::ExpiryReminder> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::explore::ExploreSource>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.directory_url.into_into_dart().into_dart(),
+            self.0.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::explore::ExploreSource>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::explore::ExploreSource>,
+    > for holloway_core::api::functions::explore::ExploreSource
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::explore::ExploreSource> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::feeds::Feed> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.subscribed_at.into_into_dart().into_dart(),
+            self.0.refreshed_at.into_into_dart().into_dart(),
+            self.0.last_error.into_into_dart().into_dart(),
+            self.0.unread.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::feeds::Feed>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::feeds::Feed>>
+    for holloway_core::api::storage::feeds::Feed
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::feeds::Feed> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::feed_discovery::FeedCandidate>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::feed_discovery::FeedCandidate>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::feed_discovery::FeedCandidate>,
+    > for holloway_core::api::functions::feed_discovery::FeedCandidate
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::feed_discovery::FeedCandidate> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::feeds::FeedEntry> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.feed_id.into_into_dart().into_dart(),
+            self.0.feed_title.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.published_at.into_into_dart().into_dart(),
+            self.0.seen_at.into_into_dart().into_dart(),
+            self.0.read.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::feeds::FeedEntry>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::feeds::FeedEntry>>
+    for holloway_core::api::storage::feeds::FeedEntry
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::feeds::FeedEntry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::feed_discovery::FeedKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::feed_discovery::FeedKind::Atom => 0.into_dart(),
+            holloway_core::api::functions::feed_discovery::FeedKind::Rss => 1.into_dart(),
+            holloway_core::api::functions::feed_discovery::FeedKind::Gemfeed => 2.into_dart(),
+            holloway_core::api::functions::feed_discovery::FeedKind::Unknown => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::feed_discovery::FeedKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::feed_discovery::FeedKind>,
+    > for holloway_core::api::functions::feed_discovery::FeedKind
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::feed_discovery::FeedKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::fetch_options::FetchOptions>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.private.into_into_dart().into_dart(),
+            self.0.use_cache.into_into_dart().into_dart(),
+            self.0.revalidate.into_into_dart().into_dart(),
+            self.0.probe_protocols.into_into_dart().into_dart(),
+            self.0.background.into_into_dart().into_dart(),
+            self.0.identity_id.into_into_dart().into_dart(),
+            self.0.connect_timeout_secs.into_into_dart().into_dart(),
+            self.0.read_timeout_secs.into_into_dart().into_dart(),
+            self.0.deadline_secs.into_into_dart().into_dart(),
+            self.0.max_body_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::fetch_options::FetchOptions>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::fetch_options::FetchOptions>,
+    > for holloway_core::api::models::fetch_options::FetchOptions
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::fetch_options::FetchOptions> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::fetch_response::FetchResponse>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.redirects.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.meta.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.renderer.into_into_dart().into_dart(),
+            self.0.body.into_into_dart().into_dart(),
+            self.0.body_hash.into_into_dart().into_dart(),
+            self.0.unchanged.into_into_dart().into_dart(),
+            self.0.truncated.into_into_dart().into_dart(),
+            self.0.stale.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.feeds.into_into_dart().into_dart(),
+            self.0.favicon.into_into_dart().into_dart(),
+            self.0.fragment.into_into_dart().into_dart(),
+            self.0.fragment_line.into_into_dart().into_dart(),
+            self.0.cached_at.into_into_dart().into_dart(),
+            self.0.request_id.into_into_dart().into_dart(),
+            self.0.display_host.into_into_dart().into_dart(),
+            self.0.spoofing_warning.into_into_dart().into_dart(),
+            self.0.clock_skew_warning.into_into_dart().into_dart(),
+            self.0.probed_scheme.into_into_dart().into_dart(),
+            self.0.certificate_error.into_into_dart().into_dart(),
+            self.0.server_failure.into_into_dart().into_dart(),
+            self.0.certificate.into_into_dart().into_dart(),
+            self.0.duration_ms.into_into_dart().into_dart(),
+            self.0.timings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::fetch_response::FetchResponse>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::fetch_response::FetchResponse>,
+    > for holloway_core::api::models::fetch_response::FetchResponse
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::fetch_response::FetchResponse> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::file_type::FileType>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.extension.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::file_type::FileType>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::file_type::FileType>,
+    > for holloway_core::api::functions::file_type::FileType
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::file_type::FileType> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubBook>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.handle.into_into_dart().into_dart(),
+            self.0.metadata.into_into_dart().into_dart(),
+            self.0.index_path.into_into_dart().into_dart(),
+            self.0.chapters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubBook>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::gempub::GempubBook>>
+    for holloway_core::api::functions::gempub::GempubBook
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::gempub::GempubBook> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubChapter>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.title.into_into_dart().into_dart(),
+            self.0.path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubChapter>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::gempub::GempubChapter>,
+    > for holloway_core::api::functions::gempub::GempubChapter
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::gempub::GempubChapter> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubMetadata>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.title.into_into_dart().into_dart(),
+            self.0.author.into_into_dart().into_dart(),
+            self.0.language.into_into_dart().into_dart(),
+            self.0.description.into_into_dart().into_dart(),
+            self.0.published.into_into_dart().into_dart(),
+            self.0.version.into_into_dart().into_dart(),
+            self.0.cover.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubMetadata>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::gempub::GempubMetadata>,
+    > for holloway_core::api::functions::gempub::GempubMetadata
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::gempub::GempubMetadata> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubPage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.path.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.body.into_into_dart().into_dart(),
+            self.0.data.into_into_dart().into_dart(),
+            self.0.chapter.into_into_dart().into_dart(),
+            self.0.previous_chapter.into_into_dart().into_dart(),
+            self.0.next_chapter.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::gempub::GempubPage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::gempub::GempubPage>>
+    for holloway_core::api::functions::gempub::GempubPage
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::gempub::GempubPage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::mirrors::GemtextLine> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::mirrors::GemtextLine::Text(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::GemtextLine::Link { url, label } => [
+                1.into_dart(),
+                url.into_into_dart().into_dart(),
+                label.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::mirrors::GemtextLine::Heading { level, text } => [
+                2.into_dart(),
+                level.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::mirrors::GemtextLine::ListItem(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::GemtextLine::Quote(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::GemtextLine::PreformatToggle { alt } => {
+                [5.into_dart(), alt.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::GemtextLine::Preformatted(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::mirrors::GemtextLine>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::mirrors::GemtextLine>>
+    for crate::api::mirrors::GemtextLine
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::mirrors::GemtextLine> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::parsers::gophermap::GopherItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.item_type.into_into_dart().into_dart(),
+            self.0.display.into_into_dart().into_dart(),
+            self.0.selector.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.port.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.icon.into_into_dart().into_dart(),
+            self.0.fetchable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::parsers::gophermap::GopherItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::parsers::gophermap::GopherItem>,
+    > for holloway_core::api::parsers::gophermap::GopherItem
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::parsers::gophermap::GopherItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::anchors::HeadingAnchor>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.slug.into_into_dart().into_dart(),
+            self.0.text.into_into_dart().into_dart(),
+            self.0.level.into_into_dart().into_dart(),
+            self.0.line_index.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::anchors::HeadingAnchor>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::anchors::HeadingAnchor>,
+    > for holloway_core::api::functions::anchors::HeadingAnchor
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::anchors::HeadingAnchor> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::highlighting::HighlightedBlock>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start_index.into_into_dart().into_dart(),
+            self.0.language.into_into_dart().into_dart(),
+            self.0.lines.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::highlighting::HighlightedBlock>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::highlighting::HighlightedBlock>,
+    > for holloway_core::api::functions::highlighting::HighlightedBlock
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::highlighting::HighlightedBlock> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::highlighting::HighlightedLine>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.0.spans.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::highlighting::HighlightedLine>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::highlighting::HighlightedLine>,
+    > for holloway_core::api::functions::highlighting::HighlightedLine
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::highlighting::HighlightedLine> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::history::HistoryEntry>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.visited_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::history::HistoryEntry>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::history::HistoryEntry>,
+    > for holloway_core::api::storage::history::HistoryEntry
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::history::HistoryEntry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::history::HistoryMatch>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::history::HistoryMatch::Substring => 0.into_dart(),
+            holloway_core::api::storage::history::HistoryMatch::Prefix => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::history::HistoryMatch>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::history::HistoryMatch>,
+    > for holloway_core::api::storage::history::HistoryMatch
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::history::HistoryMatch> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::history::HistoryRange>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.since.into_into_dart().into_dart(),
+            self.0.until.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::history::HistoryRange>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::history::HistoryRange>,
+    > for holloway_core::api::storage::history::HistoryRange
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::history::HistoryRange> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::history::HistoryResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.visit_count.into_into_dart().into_dart(),
+            self.0.last_visited.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::history::HistoryResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::history::HistoryResult>,
+    > for holloway_core::api::storage::history::HistoryResult
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::history::HistoryResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::host_migration::HostMigration>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.scheme.into_into_dart().into_dart(),
+            self.0.from_host.into_into_dart().into_dart(),
+            self.0.to_host.into_into_dart().into_dart(),
+            self.0.moved_pages.into_into_dart().into_dart(),
+            self.0.bookmarks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::host_migration::HostMigration>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::host_migration::HostMigration>,
+    > for holloway_core::api::functions::host_migration::HostMigration
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::host_migration::HostMigration> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::host_migration::HostMigrationResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.0.bookmarks.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::host_migration::HostMigrationResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::host_migration::HostMigrationResult>,
+    > for holloway_core::api::functions::host_migration::HostMigrationResult
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::host_migration::HostMigrationResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::host_settings::HostSettings>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.host.into_into_dart().into_dart(),
+            self.0.tls_policy.into_into_dart().into_dart(),
+            self.0.identity_id.into_into_dart().into_dart(),
+            self.0.connect_timeout_secs.into_into_dart().into_dart(),
+            self.0.read_timeout_secs.into_into_dart().into_dart(),
+            self.0.redirect_policy.into_into_dart().into_dart(),
+            self.0.max_body_bytes.into_into_dart().into_dart(),
+            self.0.charset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::host_settings::HostSettings>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::host_settings::HostSettings>,
+    > for holloway_core::api::storage::host_settings::HostSettings
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::host_settings::HostSettings> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::host_stats::HostStats>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.host.into_into_dart().into_dart(),
+            self.0.requests.into_into_dart().into_dart(),
+            self.0.errors.into_into_dart().into_dart(),
+            self.0.bytes_sent.into_into_dart().into_dart(),
+            self.0.bytes_received.into_into_dart().into_dart(),
+            self.0.average_latency_ms.into_into_dart().into_dart(),
+            self.0.error_rate.into_into_dart().into_dart(),
+            self.0.first_request_at.into_into_dart().into_dart(),
+            self.0.last_request_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::host_stats::HostStats>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::host_stats::HostStats>,
+    > for holloway_core::api::storage::host_stats::HostStats
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::host_stats::HostStats> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::host_stats::HostStatsOrder>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::host_stats::HostStatsOrder::Requests => 0.into_dart(),
+            holloway_core::api::storage::host_stats::HostStatsOrder::BytesReceived => 1.into_dart(),
+            holloway_core::api::storage::host_stats::HostStatsOrder::AverageLatency => {
+                2.into_dart()
+            }
+            holloway_core::api::storage::host_stats::HostStatsOrder::ErrorRate => 3.into_dart(),
+            holloway_core::api::storage::host_stats::HostStatsOrder::LastRequest => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::host_stats::HostStatsOrder>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::host_stats::HostStatsOrder>,
+    > for holloway_core::api::storage::host_stats::HostStatsOrder
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::host_stats::HostStatsOrder> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::identity_transfer::IdentitiesImport>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.imported.into_into_dart().into_dart(),
+            self.0.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::identity_transfer::IdentitiesImport>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::identity_transfer::IdentitiesImport>,
+    > for holloway_core::api::functions::identity_transfer::IdentitiesImport
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::identity_transfer::IdentitiesImport> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::identities::Identity>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.name.into_into_dart().into_dart(),
+            self.0.common_name.into_into_dart().into_dart(),
+            self.0.fingerprint.into_into_dart().into_dart(),
+            self.0.not_before.into_into_dart().into_dart(),
+            self.0.not_after.into_into_dart().into_dart(),
+            self.0.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::identities::Identity>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::identities::Identity>>
+    for holloway_core::api::storage::identities::Identity
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::identities::Identity> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::identity_scopes::IdentityScope>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.prefix.into_into_dart().into_dart(),
+            self.0.identity_id.into_into_dart().into_dart(),
+            self.0.activated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::identity_scopes::IdentityScope>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::identity_scopes::IdentityScope>,
+    > for holloway_core::api::storage::identity_scopes::IdentityScope
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::identity_scopes::IdentityScope> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.link_index.into_into_dart().into_dart(),
+            self.0.line_index.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::inline_images::InlineImage>,
+    > for holloway_core::api::functions::inline_images::InlineImage
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::inline_images::InlineImage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImageFailure>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.link_index.into_into_dart().into_dart(),
+            self.0.line_index.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImageFailure>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::inline_images::InlineImageFailure>,
+    > for holloway_core::api::functions::inline_images::InlineImageFailure
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::inline_images::InlineImageFailure> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImages>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.images.into_into_dart().into_dart(),
+            self.0.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::inline_images::InlineImages>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::inline_images::InlineImages>,
+    > for holloway_core::api::functions::inline_images::InlineImages
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::inline_images::InlineImages> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::known_hosts::KnownHost>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.host.into_into_dart().into_dart(),
+            self.0.port.into_into_dart().into_dart(),
+            self.0.fingerprint.into_into_dart().into_dart(),
+            self.0.expires_at.into_into_dart().into_dart(),
+            self.0.first_seen.into_into_dart().into_dart(),
+            self.0.last_seen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::known_hosts::KnownHost>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::known_hosts::KnownHost>,
+    > for holloway_core::api::storage::known_hosts::KnownHost
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::known_hosts::KnownHost> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Holloway => {
+                0.into_dart()
+            }
+            holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Amfora => {
+                1.into_dart()
+            }
+            holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Lagrange => {
+                2.into_dart()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat>,
+    > for holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsImport>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.imported.into_into_dart().into_dart(),
+            self.0.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsImport>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsImport>,
+    > for holloway_core::api::functions::known_hosts_transfer::KnownHostsImport
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::known_hosts_transfer::KnownHostsImport> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::bidi::LevelRun> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start.into_into_dart().into_dart(),
+            self.0.end.into_into_dart().into_dart(),
+            self.0.level.into_into_dart().into_dart(),
+            self.0.direction.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bidi::LevelRun>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::bidi::LevelRun>>
+    for holloway_core::api::functions::bidi::LevelRun
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::bidi::LevelRun> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::bidi::LineDirection>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.line_index.into_into_dart().into_dart(),
+            self.0.direction.into_into_dart().into_dart(),
+            self.0.runs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bidi::LineDirection>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::bidi::LineDirection>,
+    > for holloway_core::api::functions::bidi::LineDirection
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::bidi::LineDirection> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::links::LinkKind> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::links::LinkKind::Page => 0.into_dart(),
+            holloway_core::api::functions::links::LinkKind::Search => 1.into_dart(),
+            holloway_core::api::functions::links::LinkKind::Download => 2.into_dart(),
+            holloway_core::api::functions::links::LinkKind::External => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::links::LinkKind>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::links::LinkKind>>
+    for holloway_core::api::functions::links::LinkKind
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::links::LinkKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::page_index::LocalSearchResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.snippet.into_into_dart().into_dart(),
+            self.0.fetched_at.into_into_dart().into_dart(),
+            self.0.last_visited_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::page_index::LocalSearchResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::page_index::LocalSearchResult>,
+    > for holloway_core::api::storage::page_index::LocalSearchResult
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::page_index::LocalSearchResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::logging::LogLevel>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::logging::LogLevel::Debug => 0.into_dart(),
+            holloway_core::api::functions::logging::LogLevel::Info => 1.into_dart(),
+            holloway_core::api::functions::logging::LogLevel::Warn => 2.into_dart(),
+            holloway_core::api::functions::logging::LogLevel::Error => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::logging::LogLevel>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::logging::LogLevel>>
+    for holloway_core::api::functions::logging::LogLevel
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::logging::LogLevel> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::logging::LogRecord>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.timestamp_ms.into_into_dart().into_dart(),
+            self.0.level.into_into_dart().into_dart(),
+            self.0.target.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.request_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::logging::LogRecord>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::logging::LogRecord>>
+    for holloway_core::api::functions::logging::LogRecord
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::logging::LogRecord> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::menu_pages::MenuPage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.offset.into_into_dart().into_dart(),
+            self.0.items.into_into_dart().into_dart(),
+            self.0.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::menu_pages::MenuPage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::menu_pages::MenuPage>,
+    > for holloway_core::api::functions::menu_pages::MenuPage
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::menu_pages::MenuPage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::metasearch::MetasearchResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.hits.into_into_dart().into_dart(),
+            self.0.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::metasearch::MetasearchResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::metasearch::MetasearchResult>,
+    > for holloway_core::api::functions::metasearch::MetasearchResult
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::metasearch::MetasearchResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::mirror::MirrorProgress>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.path.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+            self.0.saved.into_into_dart().into_dart(),
+            self.0.failed.into_into_dart().into_dart(),
+            self.0.remaining.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::mirror::MirrorProgress>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::mirror::MirrorProgress>,
+    > for holloway_core::api::functions::mirror::MirrorProgress
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::mirror::MirrorProgress> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::mirrors::NavigateError> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::mirrors::NavigateError::InvalidUrl(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::UnsupportedScheme(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::SchemeDisabled(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Blocked(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Disallowed(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Offline(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::DnsFailure(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ConnectTimeout(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ConnectionRefused(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ConnectionFailed(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ConnectionLost(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ReadTimeout(field0) => {
+                [11.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::GeminiTimeout(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ProxyUnreachable(field0) => {
+                [13.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ProxyAuthFailed(field0) => {
+                [14.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ProxyError(field0) => {
+                [15.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::GatewayError(field0) => {
+                [16.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::TlsError(field0) => {
+                [17.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::FingerprintMismatch(field0) => {
+                [18.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::CertificateDate(field0) => {
+                [19.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::CertificateRejected(field0) => {
+                [20.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::BadResponse(field0) => {
+                [21.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::TooLarge(field0) => {
+                [22.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::NotFound(field0) => {
+                [23.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::InvalidInput(field0) => {
+                [24.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::GopherBadType(field0) => {
+                [25.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::GopherBadSelector(field0) => {
+                [26.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::StorageError(field0) => {
+                [27.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::IdentityError(field0) => {
+                [28.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Cancelled(field0) => {
+                [29.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Redirected { target, permanent } => [
+                30.into_dart(),
+                target.into_into_dart().into_dart(),
+                permanent.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::mirrors::NavigateError::InputRequired { prompt, sensitive } => [
+                31.into_dart(),
+                prompt.into_into_dart().into_dart(),
+                sensitive.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::mirrors::NavigateError::CertificateRequired(field0) => {
+                [32.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::ServerError(field0) => {
+                [33.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::NavigateError::Other(field0) => {
+                [34.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::mirrors::NavigateError>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::mirrors::NavigateError>>
+    for crate::api::mirrors::NavigateError
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::mirrors::NavigateError> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_event::NavigationEvent>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.request_id.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.meta.into_into_dart().into_dart(),
+            self.0.bytes.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_event::NavigationEvent>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_event::NavigationEvent>,
+    > for holloway_core::api::models::navigation_event::NavigationEvent
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_event::NavigationEvent> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_event::NavigationEventKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+                    holloway_core::api::models::navigation_event::NavigationEventKind::NavigationStarted => 0.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::Resolving => 1.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::Connecting => 2.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::TlsHandshake => 3.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::RedirectFollowed => 4.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::HeaderReceived => 5.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::BodyProgress => 6.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFinished => 7.into_dart(),
+holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFailed => 8.into_dart(),
+                    _ => unreachable!(),
+                }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_event::NavigationEventKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_event::NavigationEventKind>,
+    > for holloway_core::api::models::navigation_event::NavigationEventKind
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_event::NavigationEventKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_result::NavigationResult>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.redirects.into_into_dart().into_dart(),
+            self.0.protocol.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.charset.into_into_dart().into_dart(),
+            self.0.renderer.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.favicon.into_into_dart().into_dart(),
+            self.0.body.into_into_dart().into_dart(),
+            self.0.truncated.into_into_dart().into_dart(),
+            self.0.cached_at.into_into_dart().into_dart(),
+            self.0.stale.into_into_dart().into_dart(),
+            self.0.duration_ms.into_into_dart().into_dart(),
+            self.0.timings.into_into_dart().into_dart(),
+            self.0.certificate.into_into_dart().into_dart(),
+            self.0.server_failure.into_into_dart().into_dart(),
+            self.0.request_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_result::NavigationResult>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_result::NavigationResult>,
+    > for holloway_core::api::models::navigation_result::NavigationResult
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_result::NavigationResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::data_usage::NetworkType>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::data_usage::NetworkType::Wifi => 0.into_dart(),
+            holloway_core::api::storage::data_usage::NetworkType::Cellular => 1.into_dart(),
+            holloway_core::api::storage::data_usage::NetworkType::Ethernet => 2.into_dart(),
+            holloway_core::api::storage::data_usage::NetworkType::Unknown => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::data_usage::NetworkType>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::data_usage::NetworkType>,
+    > for holloway_core::api::storage::data_usage::NetworkType
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::data_usage::NetworkType> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::anchors::OutlineEntry>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.anchor.into_into_dart().into_dart(),
+            self.0.depth.into_into_dart().into_dart(),
+            self.0.parent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::anchors::OutlineEntry>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::anchors::OutlineEntry>,
+    > for holloway_core::api::functions::anchors::OutlineEntry
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::anchors::OutlineEntry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::page_diff::PageDiff>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.cached_at.into_into_dart().into_dart(),
+            self.0.added.into_into_dart().into_dart(),
+            self.0.removed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::page_diff::PageDiff>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::page_diff::PageDiff>,
+    > for holloway_core::api::functions::page_diff::PageDiff
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::page_diff::PageDiff> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::links::PageLink> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.label.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::links::PageLink>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::links::PageLink>>
+    for holloway_core::api::functions::links::PageLink
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::links::PageLink> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::page_summary::PageSummary>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.description.into_into_dart().into_dart(),
+            self.0.favicon.into_into_dart().into_dart(),
+            self.0.cached_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::page_summary::PageSummary>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::page_summary::PageSummary>,
+    > for holloway_core::api::functions::page_summary::PageSummary
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::page_summary::PageSummary> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::peek::PeekResult> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.meta.into_into_dart().into_dart(),
+            self.0.mime.into_into_dart().into_dart(),
+            self.0.renderer.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::peek::PeekResult>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::peek::PeekResult>>
+    for holloway_core::api::functions::peek::PeekResult
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::peek::PeekResult> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_result::PinExpiry>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::navigation_result::PinExpiry::Valid => 0.into_dart(),
+            holloway_core::api::models::navigation_result::PinExpiry::ExpiringSoon => 1.into_dart(),
+            holloway_core::api::models::navigation_result::PinExpiry::Expired => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_result::PinExpiry>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_result::PinExpiry>,
+    > for holloway_core::api::models::navigation_result::PinExpiry
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_result::PinExpiry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_result::PinStatus>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::navigation_result::PinStatus::FirstUse => 0.into_dart(),
+            holloway_core::api::models::navigation_result::PinStatus::Matched => 1.into_dart(),
+            holloway_core::api::models::navigation_result::PinStatus::Replaced => 2.into_dart(),
+            holloway_core::api::models::navigation_result::PinStatus::Unverified => 3.into_dart(),
+            holloway_core::api::models::navigation_result::PinStatus::CaVerified => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_result::PinStatus>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_result::PinStatus>,
+    > for holloway_core::api::models::navigation_result::PinStatus
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_result::PinStatus> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::ports::PortOverride>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.scheme.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.port.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::ports::PortOverride>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::ports::PortOverride>>
+    for holloway_core::api::storage::ports::PortOverride
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::ports::PortOverride> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::scheduler::PowerState>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::scheduler::PowerState::Charging => 0.into_dart(),
+            holloway_core::api::functions::scheduler::PowerState::Battery => 1.into_dart(),
+            holloway_core::api::functions::scheduler::PowerState::LowBattery => 2.into_dart(),
+            holloway_core::api::functions::scheduler::PowerState::Unknown => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::scheduler::PowerState>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::scheduler::PowerState>,
+    > for holloway_core::api::functions::scheduler::PowerState
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::scheduler::PowerState> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::text_width::PreformattedBlock>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start_index.into_into_dart().into_dart(),
+            self.0.line_count.into_into_dart().into_dart(),
+            self.0.alt.into_into_dart().into_dart(),
+            self.0.max_width.into_into_dart().into_dart(),
+            self.0.has_wide_chars.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::text_width::PreformattedBlock>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::text_width::PreformattedBlock>,
+    > for holloway_core::api::functions::text_width::PreformattedBlock
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::text_width::PreformattedBlock> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::protocols::registry::ProtocolCapabilities>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.user_input.into_into_dart().into_dart(),
+            self.0.redirects.into_into_dart().into_dart(),
+            self.0.client_certificates.into_into_dart().into_dart(),
+            self.0.menus.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::protocols::registry::ProtocolCapabilities>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::protocols::registry::ProtocolCapabilities>,
+    > for holloway_core::api::protocols::registry::ProtocolCapabilities
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::protocols::registry::ProtocolCapabilities> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::protocols::registry::ProtocolInfo>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.scheme.into_into_dart().into_dart(),
+            self.0.default_port.into_into_dart().into_dart(),
+            self.0.requires_tls.into_into_dart().into_dart(),
+            self.0.capabilities.into_into_dart().into_dart(),
+            self.0.enabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::protocols::registry::ProtocolInfo>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::protocols::registry::ProtocolInfo>,
+    > for holloway_core::api::protocols::registry::ProtocolInfo
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::protocols::registry::ProtocolInfo> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::metasearch::ProviderFailure>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.name.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::metasearch::ProviderFailure>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::metasearch::ProviderFailure>,
+    > for holloway_core::api::functions::metasearch::ProviderFailure
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::metasearch::ProviderFailure> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::transport::proxy::ProxyProbe>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.handshake_ms.into_into_dart().into_dart(),
+            self.0.connect_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::transport::proxy::ProxyProbe>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::transport::proxy::ProxyProbe>>
+    for holloway_core::api::transport::proxy::ProxyProbe
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::transport::proxy::ProxyProbe> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::gemini_proxies::ProxyProtocol>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gemini => 0.into_dart(),
+            holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gopher => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::gemini_proxies::ProxyProtocol>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::gemini_proxies::ProxyProtocol>,
+    > for holloway_core::api::storage::gemini_proxies::ProxyProtocol
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::gemini_proxies::ProxyProtocol> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::transport::proxy::ProxyRule> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.pattern.into_into_dart().into_dart(),
+            self.0.proxy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::transport::proxy::ProxyRule>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::transport::proxy::ProxyRule>>
+    for holloway_core::api::transport::proxy::ProxyRule
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::transport::proxy::ProxyRule> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::api::mirrors::ProxySettings> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            crate::api::mirrors::ProxySettings::Direct => [0.into_dart()].into_dart(),
+            crate::api::mirrors::ProxySettings::Socks5 { proxy } => {
+                [1.into_dart(), proxy.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::mirrors::ProxySettings::PerRule { rules, fallback } => [
+                2.into_dart(),
+                rules.into_into_dart().into_dart(),
+                fallback.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::api::mirrors::ProxySettings>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::api::mirrors::ProxySettings>>
+    for crate::api::mirrors::ProxySettings
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::api::mirrors::ProxySettings> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::explore::RandomDestination>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.label.into_into_dart().into_dart(),
+            self.0.directory_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::explore::RandomDestination>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::explore::RandomDestination>,
+    > for holloway_core::api::functions::explore::RandomDestination
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::explore::RandomDestination> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::transport::rate_limit::RateLimits>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.max_connections.into_into_dart().into_dart(),
+            self.0.min_interval_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::transport::rate_limit::RateLimits>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::transport::rate_limit::RateLimits>,
+    > for holloway_core::api::transport::rate_limit::RateLimits
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::transport::rate_limit::RateLimits> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::read_later::ReadLaterItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.added_at.into_into_dart().into_dart(),
+            self.0.read_at.into_into_dart().into_dart(),
+            self.0.cached.into_into_dart().into_dart(),
+            self.0.fetched_at.into_into_dart().into_dart(),
+            self.0.size.into_into_dart().into_dart(),
+            self.0.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::read_later::ReadLaterItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::read_later::ReadLaterItem>,
+    > for holloway_core::api::storage::read_later::ReadLaterItem
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::read_later::ReadLaterItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::reading_stats::ReadingStats>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.words.into_into_dart().into_dart(),
+            self.0.preformatted_words.into_into_dart().into_dart(),
+            self.0.reading_minutes.into_into_dart().into_dart(),
+            self.0.links.into_into_dart().into_dart(),
+            self.0.headings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::reading_stats::ReadingStats>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::reading_stats::ReadingStats>,
+    > for holloway_core::api::functions::reading_stats::ReadingStats
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::reading_stats::ReadingStats> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::error_info::RecoveryAction>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::error_info::RecoveryAction::Retry => 0.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::AddException => 1.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::EnableProtocol => 2.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::ProvideInput => 3.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::ChooseIdentity => 4.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::RenewIdentity => 5.into_dart(),
+            holloway_core::api::models::error_info::RecoveryAction::CheckProxy => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::error_info::RecoveryAction>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::error_info::RecoveryAction>,
+    > for holloway_core::api::models::error_info::RecoveryAction
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::error_info::RecoveryAction> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::host_settings::RedirectPolicy>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::host_settings::RedirectPolicy::Follow => 0.into_dart(),
+            holloway_core::api::storage::host_settings::RedirectPolicy::AskCrossHost => {
+                1.into_dart()
+            }
+            holloway_core::api::storage::host_settings::RedirectPolicy::Ask => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::host_settings::RedirectPolicy>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::host_settings::RedirectPolicy>,
+    > for holloway_core::api::storage::host_settings::RedirectPolicy
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::host_settings::RedirectPolicy> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::reflow::ReflowedLine>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.source_index.into_into_dart().into_dart(),
+            self.0.line.into_into_dart().into_dart(),
+            self.0.continuation.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::reflow::ReflowedLine>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::reflow::ReflowedLine>,
+    > for holloway_core::api::functions::reflow::ReflowedLine
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::reflow::ReflowedLine> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::content_router::Renderer>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::content_router::Renderer::Gemtext => 0.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::GopherMenu => 1.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::PlainText => 2.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::Markdown => 3.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::Image => 4.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::Download => 5.into_dart(),
+            holloway_core::api::functions::content_router::Renderer::External => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::content_router::Renderer>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::content_router::Renderer>,
+    > for holloway_core::api::functions::content_router::Renderer
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::content_router::Renderer> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::navigation_result::RequestTimings>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.dns_ms.into_into_dart().into_dart(),
+            self.0.connect_ms.into_into_dart().into_dart(),
+            self.0.tls_ms.into_into_dart().into_dart(),
+            self.0.first_byte_ms.into_into_dart().into_dart(),
+            self.0.transfer_ms.into_into_dart().into_dart(),
+            self.0.bytes_sent.into_into_dart().into_dart(),
+            self.0.bytes_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::navigation_result::RequestTimings>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::navigation_result::RequestTimings>,
+    > for holloway_core::api::models::navigation_result::RequestTimings
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::navigation_result::RequestTimings> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::input_answers::SavedAnswer>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.prompt.into_into_dart().into_dart(),
+            self.0.answer.into_into_dart().into_dart(),
+            self.0.answered_at.into_into_dart().into_dart(),
+            self.0.uses.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::input_answers::SavedAnswer>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::input_answers::SavedAnswer>,
+    > for holloway_core::api::storage::input_answers::SavedAnswer
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::input_answers::SavedAnswer> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::scheduler::ScheduledTask>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::scheduler::ScheduledTask::FeedRefresh => 0.into_dart(),
+            holloway_core::api::functions::scheduler::ScheduledTask::PageWatches => 1.into_dart(),
+            holloway_core::api::functions::scheduler::ScheduledTask::PlanWatches => 2.into_dart(),
+            holloway_core::api::functions::scheduler::ScheduledTask::PinExpiry => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::scheduler::ScheduledTask>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::scheduler::ScheduledTask>,
+    > for holloway_core::api::functions::scheduler::ScheduledTask
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::scheduler::ScheduledTask> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEvent>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.task.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.timestamp.into_into_dart().into_dart(),
+            self.0.count.into_into_dart().into_dart(),
+            self.0.items.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.next_run_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEvent>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEvent>,
+    > for holloway_core::api::functions::scheduler::SchedulerEvent
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEvent> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEventKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::scheduler::SchedulerEventKind::Started => 0.into_dart(),
+            holloway_core::api::functions::scheduler::SchedulerEventKind::Completed => {
+                1.into_dart()
+            }
+            holloway_core::api::functions::scheduler::SchedulerEventKind::Skipped => 2.into_dart(),
+            holloway_core::api::functions::scheduler::SchedulerEventKind::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEventKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEventKind>,
+    > for holloway_core::api::functions::scheduler::SchedulerEventKind
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::scheduler::SchedulerEventKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::gemini_proxies::SchemeProxy>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.scheme.into_into_dart().into_dart(),
+            self.0.protocol.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.port.into_into_dart().into_dart(),
+            self.0.selector.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::gemini_proxies::SchemeProxy>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::gemini_proxies::SchemeProxy>,
+    > for holloway_core::api::storage::gemini_proxies::SchemeProxy
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::storage::gemini_proxies::SchemeProxy> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::metasearch::SearchHit>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.snippet.into_into_dart().into_dart(),
+            self.0.providers.into_into_dart().into_dart(),
+            self.0.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::metasearch::SearchHit>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::metasearch::SearchHit>,
+    > for holloway_core::api::functions::metasearch::SearchHit
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::metasearch::SearchHit> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::search::SearchProvider>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.name.into_into_dart().into_dart(),
+            self.0.url_template.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::search::SearchProvider>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::search::SearchProvider>,
+    > for holloway_core::api::functions::search::SearchProvider
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::search::SearchProvider> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::server_failure::ServerFailure>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.status.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.temporary.into_into_dart().into_dart(),
+            self.0.retry_after_secs.into_into_dart().into_dart(),
+            self.0.suggested_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::server_failure::ServerFailure>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::server_failure::ServerFailure>,
+    > for holloway_core::api::models::server_failure::ServerFailure
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::server_failure::ServerFailure> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::server_failure::ServerFailureKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::server_failure::ServerFailureKind::TemporaryFailure => {
+                0.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::ServerUnavailable => {
+                1.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::CgiError => {
+                2.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::ProxyError => {
+                3.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::SlowDown => {
+                4.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::PermanentFailure => {
+                5.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::NotFound => {
+                6.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::Gone => 7.into_dart(),
+            holloway_core::api::models::server_failure::ServerFailureKind::ProxyRequestRefused => {
+                8.into_dart()
+            }
+            holloway_core::api::models::server_failure::ServerFailureKind::BadRequest => {
+                9.into_dart()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::server_failure::ServerFailureKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::server_failure::ServerFailureKind>,
+    > for holloway_core::api::models::server_failure::ServerFailureKind
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::server_failure::ServerFailureKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::storage::sessions::Session> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.name.into_into_dart().into_dart(),
+            self.0.tabs.into_into_dart().into_dart(),
+            self.0.active_tab.into_into_dart().into_dart(),
+            self.0.saved_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::sessions::Session>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::sessions::Session>>
+    for holloway_core::api::storage::sessions::Session
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::sessions::Session> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionEntry>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.scroll_offset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionEntry>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::sessions::SessionEntry>,
+    > for holloway_core::api::storage::sessions::SessionEntry
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::sessions::SessionEntry> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionSummary>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.name.into_into_dart().into_dart(),
+            self.0.tab_count.into_into_dart().into_dart(),
+            self.0.saved_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionSummary>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::sessions::SessionSummary>,
+    > for holloway_core::api::storage::sessions::SessionSummary
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::sessions::SessionSummary> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionTab>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.entries.into_into_dart().into_dart(),
+            self.0.current_entry.into_into_dart().into_dart(),
+            self.0.private.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::sessions::SessionTab>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::sessions::SessionTab>>
+    for holloway_core::api::storage::sessions::SessionTab
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::sessions::SessionTab> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::shutdown::ShutdownSummary>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.streams_cancelled.into_into_dart().into_dart(),
+            self.0.fetches_aborted.into_into_dart().into_dart(),
+            self.0.requests_cancelled.into_into_dart().into_dart(),
+            self.0.mirrors_cancelled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::shutdown::ShutdownSummary>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::shutdown::ShutdownSummary>,
+    > for holloway_core::api::functions::shutdown::ShutdownSummary
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::shutdown::ShutdownSummary> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::site_map::SiteMap>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.root.into_into_dart().into_dart(),
+            self.0.nodes.into_into_dart().into_dart(),
+            self.0.truncated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::site_map::SiteMap>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::site_map::SiteMap>>
+    for holloway_core::api::functions::site_map::SiteMap
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::site_map::SiteMap> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::site_map::SiteNode>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.icon.into_into_dart().into_dart(),
+            self.0.menu.into_into_dart().into_dart(),
+            self.0.depth.into_into_dart().into_dart(),
+            self.0.parent.into_into_dart().into_dart(),
+            self.0.expanded.into_into_dart().into_dart(),
+            self.0.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::site_map::SiteNode>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::site_map::SiteNode>>
+    for holloway_core::api::functions::site_map::SiteNode
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::site_map::SiteNode> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::page_index::SnippetPart>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.text.into_into_dart().into_dart(),
+            self.0.matched.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::page_index::SnippetPart>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::page_index::SnippetPart>,
+    > for holloway_core::api::storage::page_index::SnippetPart
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::page_index::SnippetPart> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::transport::proxy::Socks5Proxy>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.host.into_into_dart().into_dart(),
+            self.0.port.into_into_dart().into_dart(),
+            self.0.username.into_into_dart().into_dart(),
+            self.0.password.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::transport::proxy::Socks5Proxy>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::transport::proxy::Socks5Proxy>>
+    for holloway_core::api::transport::proxy::Socks5Proxy
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::transport::proxy::Socks5Proxy> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::stream_event::StreamEvent>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
+            self.0.meta.into_into_dart().into_dart(),
+            self.0.text.into_into_dart().into_dart(),
+            self.0.element.into_into_dart().into_dart(),
+            self.0.bytes_received.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::stream_event::StreamEvent>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::stream_event::StreamEvent>,
+    > for holloway_core::api::models::stream_event::StreamEvent
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::stream_event::StreamEvent> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::stream_event::StreamEventKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::stream_event::StreamEventKind::Header => 0.into_dart(),
+            holloway_core::api::models::stream_event::StreamEventKind::Line => 1.into_dart(),
+            holloway_core::api::models::stream_event::StreamEventKind::Idle => 2.into_dart(),
+            holloway_core::api::models::stream_event::StreamEventKind::Finished => 3.into_dart(),
+            holloway_core::api::models::stream_event::StreamEventKind::Failed => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::stream_event::StreamEventKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::stream_event::StreamEventKind>,
+    > for holloway_core::api::models::stream_event::StreamEventKind
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::models::stream_event::StreamEventKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::streaming::StreamOptions>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.idle_interval_secs.into_into_dart().into_dart(),
+            self.0.max_idle_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::streaming::StreamOptions>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::streaming::StreamOptions>,
+    > for holloway_core::api::functions::streaming::StreamOptions
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::streaming::StreamOptions> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::ansi::StyledSpan> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start.into_into_dart().into_dart(),
+            self.0.end.into_into_dart().into_dart(),
+            self.0.style.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::ansi::StyledSpan>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::ansi::StyledSpan>>
+    for holloway_core::api::functions::ansi::StyledSpan
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::ansi::StyledSpan> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<holloway_core::api::functions::ansi::StyledText> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.text.into_into_dart().into_dart(),
+            self.0.spans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::ansi::StyledText>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::ansi::StyledText>>
+    for holloway_core::api::functions::ansi::StyledText
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::ansi::StyledText> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::suggestions::Suggestion>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.bookmarked.into_into_dart().into_dart(),
+            self.0.visit_count.into_into_dart().into_dart(),
+            self.0.last_visited.into_into_dart().into_dart(),
+            self.0.matched.into_into_dart().into_dart(),
+            self.0.completion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::suggestions::Suggestion>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::suggestions::Suggestion>,
+    > for holloway_core::api::functions::suggestions::Suggestion
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::suggestions::Suggestion> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::suggestions::SuggestionMatch>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::suggestions::SuggestionMatch::Substring => 0.into_dart(),
+            holloway_core::api::functions::suggestions::SuggestionMatch::WordPrefix => {
+                1.into_dart()
+            }
+            holloway_core::api::functions::suggestions::SuggestionMatch::UrlPrefix => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::suggestions::SuggestionMatch>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::suggestions::SuggestionMatch>,
+    > for holloway_core::api::functions::suggestions::SuggestionMatch
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::suggestions::SuggestionMatch> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::scheduler::TaskSchedule>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.task.into_into_dart().into_dart(),
+            self.0.enabled.into_into_dart().into_dart(),
+            self.0.interval_secs.into_into_dart().into_dart(),
+            self.0.jitter_secs.into_into_dart().into_dart(),
+            self.0.unmetered_only.into_into_dart().into_dart(),
+            self.0.charging_only.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::scheduler::TaskSchedule>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::scheduler::TaskSchedule>,
+    > for holloway_core::api::functions::scheduler::TaskSchedule
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::scheduler::TaskSchedule> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::bidi::TextDirection>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::bidi::TextDirection::Ltr => 0.into_dart(),
+            holloway_core::api::functions::bidi::TextDirection::Rtl => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::bidi::TextDirection>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::bidi::TextDirection>,
+    > for holloway_core::api::functions::bidi::TextDirection
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::bidi::TextDirection> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::tls_failure::TlsFailure>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.kind.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.names.into_into_dart().into_dart(),
+            self.0.subject.into_into_dart().into_dart(),
+            self.0.issuer.into_into_dart().into_dart(),
+            self.0.not_before.into_into_dart().into_dart(),
+            self.0.not_after.into_into_dart().into_dart(),
+            self.0.intermediates.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::tls_failure::TlsFailure>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::tls_failure::TlsFailure>,
+    > for holloway_core::api::models::tls_failure::TlsFailure
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::tls_failure::TlsFailure> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::models::tls_failure::TlsFailureKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::models::tls_failure::TlsFailureKind::Expired => 0.into_dart(),
+            holloway_core::api::models::tls_failure::TlsFailureKind::NotYetValid => 1.into_dart(),
+            holloway_core::api::models::tls_failure::TlsFailureKind::SelfSigned => 2.into_dart(),
+            holloway_core::api::models::tls_failure::TlsFailureKind::NameMismatch => 3.into_dart(),
+            holloway_core::api::models::tls_failure::TlsFailureKind::IncompleteChain => {
+                4.into_dart()
+            }
+            holloway_core::api::models::tls_failure::TlsFailureKind::UntrustedIssuer => {
+                5.into_dart()
+            }
+            holloway_core::api::models::tls_failure::TlsFailureKind::Revoked => 6.into_dart(),
+            holloway_core::api::models::tls_failure::TlsFailureKind::Other => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::models::tls_failure::TlsFailureKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::models::tls_failure::TlsFailureKind>,
+    > for holloway_core::api::models::tls_failure::TlsFailureKind
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::models::tls_failure::TlsFailureKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::host_settings::TlsPolicy>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::storage::host_settings::TlsPolicy::TrustOnFirstUse => 0.into_dart(),
+            holloway_core::api::storage::host_settings::TlsPolicy::AcceptChanged => 1.into_dart(),
+            holloway_core::api::storage::host_settings::TlsPolicy::Unverified => 2.into_dart(),
+            holloway_core::api::storage::host_settings::TlsPolicy::Strict => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::host_settings::TlsPolicy>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::host_settings::TlsPolicy>,
+    > for holloway_core::api::storage::host_settings::TlsPolicy
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::host_settings::TlsPolicy> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::highlighting::TokenKind>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::highlighting::TokenKind::Plain => 0.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Comment => 1.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::String => 2.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Number => 3.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Constant => 4.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Keyword => 5.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Operator => 6.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Type => 7.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Function => 8.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Variable => 9.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Tag => 10.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Attribute => 11.into_dart(),
+            holloway_core::api::functions::highlighting::TokenKind::Punctuation => 12.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::highlighting::TokenKind>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::highlighting::TokenKind>,
+    > for holloway_core::api::functions::highlighting::TokenKind
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::highlighting::TokenKind> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::highlighting::TokenSpan>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.text.into_into_dart().into_dart(),
+            self.0.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::highlighting::TokenSpan>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::highlighting::TokenSpan>,
+    > for holloway_core::api::functions::highlighting::TokenSpan
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::highlighting::TokenSpan> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::top_sites::TopSite>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.host.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.favicon.into_into_dart().into_dart(),
+            self.0.visit_count.into_into_dart().into_dart(),
+            self.0.last_visited.into_into_dart().into_dart(),
+            self.0.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::top_sites::TopSite>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::functions::top_sites::TopSite>>
+    for holloway_core::api::functions::top_sites::TopSite
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::functions::top_sites::TopSite> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::request_trace::TraceEvent>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.stage.into_into_dart().into_dart(),
+            self.0.message.into_into_dart().into_dart(),
+            self.0.elapsed_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::request_trace::TraceEvent>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::request_trace::TraceEvent>,
+    > for holloway_core::api::functions::request_trace::TraceEvent
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::request_trace::TraceEvent> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::functions::request_trace::TraceStage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self.0 {
+            holloway_core::api::functions::request_trace::TraceStage::Navigation => 0.into_dart(),
+            holloway_core::api::functions::request_trace::TraceStage::Cache => 1.into_dart(),
+            holloway_core::api::functions::request_trace::TraceStage::Transport => 2.into_dart(),
+            holloway_core::api::functions::request_trace::TraceStage::Tls => 3.into_dart(),
+            holloway_core::api::functions::request_trace::TraceStage::Response => 4.into_dart(),
+            holloway_core::api::functions::request_trace::TraceStage::Parser => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::functions::request_trace::TraceStage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::functions::request_trace::TraceStage>,
+    > for holloway_core::api::functions::request_trace::TraceStage
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<holloway_core::api::functions::request_trace::TraceStage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::trusted_cas::TrustedCa>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.fingerprint.into_into_dart().into_dart(),
+            self.0.subject.into_into_dart().into_dart(),
+            self.0.not_after.into_into_dart().into_dart(),
+            self.0.added_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::trusted_cas::TrustedCa>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::trusted_cas::TrustedCa>,
+    > for holloway_core::api::storage::trusted_cas::TrustedCa
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::trusted_cas::TrustedCa> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::watches::WatchedPage>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.added_at.into_into_dart().into_dart(),
+            self.0.seen_at.into_into_dart().into_dart(),
+            self.0.checked_at.into_into_dart().into_dart(),
+            self.0.changed_at.into_into_dart().into_dart(),
+            self.0.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::watches::WatchedPage>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<holloway_core::api::storage::watches::WatchedPage>>
+    for holloway_core::api::storage::watches::WatchedPage
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::watches::WatchedPage> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<holloway_core::api::storage::plan_watches::WatchedPlan>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.id.into_into_dart().into_dart(),
+            self.0.url.into_into_dart().into_dart(),
+            self.0.added_at.into_into_dart().into_dart(),
+            self.0.plan.into_into_dart().into_dart(),
+            self.0.checked_at.into_into_dart().into_dart(),
+            self.0.updated_at.into_into_dart().into_dart(),
+            self.0.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<holloway_core::api::storage::plan_watches::WatchedPlan>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<holloway_core::api::storage::plan_watches::WatchedPlan>,
+    > for holloway_core::api::storage::plan_watches::WatchedPlan
+{
+    fn into_into_dart(self) -> FrbWrapper<holloway_core::api::storage::plan_watches::WatchedPlan> {
+        self.into()
+    }
+}
+
+impl SseEncode for char {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.to_string(), serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::bookmarks::AddedBookmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::storage::bookmarks::Bookmark>::sse_encode(self.bookmark, serializer);
+        <bool>::sse_encode(self.duplicate, serializer);
+    }
+}
+
+impl SseEncode for crate::api::mirrors::AnsiColor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::mirrors::AnsiColor::Indexed(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <u8>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::AnsiColor::Rgb { r, g, b } => {
+                <i32>::sse_encode(1, serializer);
+                <u8>::sse_encode(r, serializer);
+                <u8>::sse_encode(g, serializer);
+                <u8>::sse_encode(b, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::ansi::AnsiStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::mirrors::AnsiColor>>::sse_encode(self.foreground, serializer);
+        <Option<crate::api::mirrors::AnsiColor>>::sse_encode(self.background, serializer);
+        <bool>::sse_encode(self.bold, serializer);
+        <bool>::sse_encode(self.dim, serializer);
+        <bool>::sse_encode(self.italic, serializer);
+        <bool>::sse_encode(self.underline, serializer);
+        <bool>::sse_encode(self.inverse, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::archive::ArchivedPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.body_hash, serializer);
+        <Option<String>>::sse_encode(self.certificate_fingerprint, serializer);
+        <i64>::sse_encode(self.fetched_at, serializer);
+        <i64>::sse_encode(self.archived_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::batch::BatchItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <u64>::sse_encode(self.request_id, serializer);
+        <Option<holloway_core::api::models::navigation_result::NavigationResult>>::sse_encode(
+            self.result,
+            serializer,
+        );
+        <Option<holloway_core::api::models::error_info::ErrorInfo>>::sse_encode(
+            self.error, serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::blocklist::BlockRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.pattern, serializer);
+        <holloway_core::api::storage::blocklist::BlockRuleKind>::sse_encode(self.kind, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::blocklist::BlockRuleKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::blocklist::BlockRuleKind::ExactHost => 0,
+                holloway_core::api::storage::blocklist::BlockRuleKind::Subdomains => 1,
+                holloway_core::api::storage::blocklist::BlockRuleKind::UrlPrefix => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::bookmarks::Bookmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Option<String>>::sse_encode(self.favicon, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bookmark_export::BookmarkFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::bookmark_export::BookmarkFormat::Xbel => 0,
+                holloway_core::api::functions::bookmark_export::BookmarkFormat::Gemtext => 1,
+                holloway_core::api::functions::bookmark_export::BookmarkFormat::Html => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bookmark_import::BookmarkSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::bookmark_import::BookmarkSource::Amfora => 0,
+                holloway_core::api::functions::bookmark_import::BookmarkSource::Lagrange => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bookmark_import::BookmarksImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.imported, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::cache::CacheEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <i64>::sse_encode(self.fetched_at, serializer);
+        <i64>::sse_encode(self.expires_at, serializer);
+        <bool>::sse_encode(self.stale, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::cache::CachePolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.scheme, serializer);
+        <bool>::sse_encode(self.cacheable, serializer);
+        <u32>::sse_encode(self.ttl_secs, serializer);
+        <bool>::sse_encode(self.cache_queries, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::certificate_error::CertificateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::models::certificate_error::CertificateErrorKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <Option<String>>::sse_encode(self.identity_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <holloway_core::api::models::error_info::RecoveryAction>::sse_encode(
+            self.suggested_action,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::certificate_error::CertificateErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(match self {holloway_core::api::models::certificate_error::CertificateErrorKind::Required => { 0 }
+holloway_core::api::models::certificate_error::CertificateErrorKind::NotAuthorized => { 1 }
+holloway_core::api::models::certificate_error::CertificateErrorKind::NotValid => { 2 }
+ _ => { unimplemented!(""); }}, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_result::CertificateSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.fingerprint, serializer);
+        <String>::sse_encode(self.subject, serializer);
+        <String>::sse_encode(self.issuer, serializer);
+        <i64>::sse_encode(self.not_before, serializer);
+        <i64>::sse_encode(self.not_after, serializer);
+        <i64>::sse_encode(self.first_seen, serializer);
+        <holloway_core::api::models::navigation_result::PinStatus>::sse_encode(
+            self.pin_status,
+            serializer,
+        );
+        <holloway_core::api::models::navigation_result::PinExpiry>::sse_encode(
+            self.expiry,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.tls_version, serializer);
+        <Option<String>>::sse_encode(self.cipher_suite, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::downloads::ChecksumStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::downloads::ChecksumStatus::Unchecked => 0,
+                holloway_core::api::storage::downloads::ChecksumStatus::Verified => 1,
+                holloway_core::api::storage::downloads::ChecksumStatus::Mismatch => 2,
+                holloway_core::api::storage::downloads::ChecksumStatus::Unavailable => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::clear::ClearedData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::storage::clear::DataCategory>::sse_encode(self.category, serializer);
+        <u32>::sse_encode(self.removed, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::clock_skew::ClockOffset {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<i64>>::sse_encode(self.offset_secs, serializer);
+        <u32>::sse_encode(self.hosts, serializer);
+        <bool>::sse_encode(self.consistent, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::config::Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.default_scheme, serializer);
+        <bool>::sse_encode(self.guess_schemes, serializer);
+        <String>::sse_encode(self.start_page, serializer);
+        <String>::sse_encode(self.search_provider, serializer);
+        <u32>::sse_encode(self.connect_timeout_secs, serializer);
+        <Option<u32>>::sse_encode(self.read_timeout_secs, serializer);
+        <holloway_core::api::storage::host_settings::TlsPolicy>::sse_encode(
+            self.tls_policy,
+            serializer,
+        );
+        <crate::api::mirrors::ProxySettings>::sse_encode(self.proxy, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::crawler::CrawlResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.capsule, serializer);
+        <u32>::sse_encode(self.indexed, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <u32>::sse_encode(self.disallowed, serializer);
+        <u32>::sse_encode(self.remaining, serializer);
+        <bool>::sse_encode(self.cancelled, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::protocols::cso::CsoField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::protocols::cso::CsoRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <Vec<holloway_core::api::protocols::cso::CsoField>>::sse_encode(self.fields, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::data_usage::DailyUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.day_start, serializer);
+        <holloway_core::api::storage::data_usage::NetworkType>::sse_encode(
+            self.network,
+            serializer,
+        );
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::data_usage::DataCapStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.limit_bytes, serializer);
+        <u64>::sse_encode(self.used_bytes, serializer);
+        <bool>::sse_encode(self.exceeded, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::clear::DataCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::clear::DataCategory::History => 0,
+                holloway_core::api::storage::clear::DataCategory::Cache => 1,
+                holloway_core::api::storage::clear::DataCategory::DataUsage => 2,
+                holloway_core::api::storage::clear::DataCategory::Downloads => 3,
+                holloway_core::api::storage::clear::DataCategory::Favicons => 4,
+                holloway_core::api::storage::clear::DataCategory::HostStats => 5,
+                holloway_core::api::storage::clear::DataCategory::InputAnswers => 6,
+                holloway_core::api::storage::clear::DataCategory::IdentityActivations => 7,
+                holloway_core::api::storage::clear::DataCategory::Identities => 8,
+                holloway_core::api::storage::clear::DataCategory::KnownHosts => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::transport::limits::DecompressionLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_ratio, serializer);
+        <u64>::sse_encode(self.max_bytes, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::page_diff::DiffLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.line, serializer);
+        <String>::sse_encode(self.text, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::homograph::DisplayHost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <bool>::sse_encode(self.spoofing_warning, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::downloads::DownloadRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <holloway_core::api::storage::downloads::DownloadStatus>::sse_encode(
+            self.status,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.error, serializer);
+        <i64>::sse_encode(self.finished_at, serializer);
+        <Option<String>>::sse_encode(self.checksum_url, serializer);
+        <holloway_core::api::storage::downloads::ChecksumStatus>::sse_encode(
+            self.checksum,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::downloads::DownloadResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+        <holloway_core::api::storage::downloads::ChecksumStatus>::sse_encode(
+            self.checksum,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::downloads::DownloadStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::downloads::DownloadStatus::Completed => 0,
+                holloway_core::api::storage::downloads::DownloadStatus::Failed => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::error_info::ErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::error_info::ErrorCode::UrlInvalid => 0,
+                holloway_core::api::models::error_info::ErrorCode::UrlUnsupportedScheme => 1,
+                holloway_core::api::models::error_info::ErrorCode::UrlSchemeDisabled => 2,
+                holloway_core::api::models::error_info::ErrorCode::UrlBlocked => 3,
+                holloway_core::api::models::error_info::ErrorCode::UrlDisallowed => 4,
+                holloway_core::api::models::error_info::ErrorCode::NetDnsFailed => 5,
+                holloway_core::api::models::error_info::ErrorCode::NetTimeout => 6,
+                holloway_core::api::models::error_info::ErrorCode::NetRefused => 7,
+                holloway_core::api::models::error_info::ErrorCode::NetConnectFailed => 8,
+                holloway_core::api::models::error_info::ErrorCode::NetConnectionLost => 9,
+                holloway_core::api::models::error_info::ErrorCode::NetOffline => 10,
+                holloway_core::api::models::error_info::ErrorCode::NetReadTimeout => 11,
+                holloway_core::api::models::error_info::ErrorCode::ProxyUnreachable => 12,
+                holloway_core::api::models::error_info::ErrorCode::ProxyAuthFailed => 13,
+                holloway_core::api::models::error_info::ErrorCode::ProxyFailed => 14,
+                holloway_core::api::models::error_info::ErrorCode::GatewayFailed => 15,
+                holloway_core::api::models::error_info::ErrorCode::ContentTooLarge => 16,
+                holloway_core::api::models::error_info::ErrorCode::TlsHandshakeFailed => 17,
+                holloway_core::api::models::error_info::ErrorCode::TlsFingerprintMismatch => 18,
+                holloway_core::api::models::error_info::ErrorCode::TlsCertificateDate => 19,
+                holloway_core::api::models::error_info::ErrorCode::TlsCertificateInvalid => 20,
+                holloway_core::api::models::error_info::ErrorCode::StoreFailed => 21,
+                holloway_core::api::models::error_info::ErrorCode::IdentityFailed => 22,
+                holloway_core::api::models::error_info::ErrorCode::NotFound => 23,
+                holloway_core::api::models::error_info::ErrorCode::InputInvalid => 24,
+                holloway_core::api::models::error_info::ErrorCode::Cancelled => 25,
+                holloway_core::api::models::error_info::ErrorCode::ResponseInvalid => 26,
+                holloway_core::api::models::error_info::ErrorCode::GemInputRequired => 27,
+                holloway_core::api::models::error_info::ErrorCode::GemRedirect => 28,
+                holloway_core::api::models::error_info::ErrorCode::GemServerError => 29,
+                holloway_core::api::models::error_info::ErrorCode::GemCertificateRequired => 30,
+                holloway_core::api::models::error_info::ErrorCode::GemTimeout => 31,
+                holloway_core::api::models::error_info::ErrorCode::GopherBadType => 32,
+                holloway_core::api::models::error_info::ErrorCode::GopherBadSelector => 33,
+                holloway_core::api::models::error_info::ErrorCode::Unknown => 34,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::error_info::ErrorInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::models::error_info::ErrorCode>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.number, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <bool>::sse_encode(self.retryable, serializer);
+        <bool>::sse_encode(self.user_actionable, serializer);
+        <Option<holloway_core::api::models::error_info::RecoveryAction>>::sse_encode(
+            self.suggested_action,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::identities::ExpiryReminder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::storage::identities::Identity>::sse_encode(self.identity, serializer);
+        <i64>::sse_encode(self.days_left, serializer);
+        <bool>::sse_encode(self.expired, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::explore::ExploreSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.directory_url, serializer);
+        <f64>::sse_encode(self.weight, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::feeds::Feed {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <holloway_core::api::functions::feed_discovery::FeedKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <i64>::sse_encode(self.subscribed_at, serializer);
+        <Option<i64>>::sse_encode(self.refreshed_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <u32>::sse_encode(self.unread, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::feed_discovery::FeedCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <holloway_core::api::functions::feed_discovery::FeedKind>::sse_encode(
+            self.kind, serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::feeds::FeedEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <i64>::sse_encode(self.feed_id, serializer);
+        <Option<String>>::sse_encode(self.feed_title, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<i64>>::sse_encode(self.published_at, serializer);
+        <i64>::sse_encode(self.seen_at, serializer);
+        <bool>::sse_encode(self.read, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::feed_discovery::FeedKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::feed_discovery::FeedKind::Atom => 0,
+                holloway_core::api::functions::feed_discovery::FeedKind::Rss => 1,
+                holloway_core::api::functions::feed_discovery::FeedKind::Gemfeed => 2,
+                holloway_core::api::functions::feed_discovery::FeedKind::Unknown => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::fetch_options::FetchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.private, serializer);
+        <bool>::sse_encode(self.use_cache, serializer);
+        <bool>::sse_encode(self.revalidate, serializer);
+        <bool>::sse_encode(self.probe_protocols, serializer);
+        <bool>::sse_encode(self.background, serializer);
+        <Option<String>>::sse_encode(self.identity_id, serializer);
+        <Option<u32>>::sse_encode(self.connect_timeout_secs, serializer);
+        <Option<u32>>::sse_encode(self.read_timeout_secs, serializer);
+        <Option<u32>>::sse_encode(self.deadline_secs, serializer);
+        <Option<u64>>::sse_encode(self.max_body_bytes, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::fetch_response::FetchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Vec<String>>::sse_encode(self.redirects, serializer);
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.meta, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <holloway_core::api::functions::content_router::Renderer>::sse_encode(
+            self.renderer,
+            serializer,
+        );
+        <String>::sse_encode(self.body, serializer);
+        <String>::sse_encode(self.body_hash, serializer);
+        <bool>::sse_encode(self.unchanged, serializer);
+        <bool>::sse_encode(self.truncated, serializer);
+        <bool>::sse_encode(self.stale, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Vec<holloway_core::api::functions::feed_discovery::FeedCandidate>>::sse_encode(
+            self.feeds, serializer,
+        );
+        <Option<String>>::sse_encode(self.favicon, serializer);
+        <Option<String>>::sse_encode(self.fragment, serializer);
+        <Option<u32>>::sse_encode(self.fragment_line, serializer);
+        <Option<i64>>::sse_encode(self.cached_at, serializer);
+        <u64>::sse_encode(self.request_id, serializer);
+        <String>::sse_encode(self.display_host, serializer);
+        <bool>::sse_encode(self.spoofing_warning, serializer);
+        <bool>::sse_encode(self.clock_skew_warning, serializer);
+        <Option<String>>::sse_encode(self.probed_scheme, serializer);
+        <Option<holloway_core::api::models::certificate_error::CertificateError>>::sse_encode(
+            self.certificate_error,
+            serializer,
+        );
+        <Option<holloway_core::api::models::server_failure::ServerFailure>>::sse_encode(
+            self.server_failure,
+            serializer,
+        );
+        <Option<holloway_core::api::models::navigation_result::CertificateSummary>>::sse_encode(
+            self.certificate,
+            serializer,
+        );
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <holloway_core::api::models::navigation_result::RequestTimings>::sse_encode(
+            self.timings,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::file_type::FileType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mime, serializer);
+        <String>::sse_encode(self.extension, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::gempub::GempubBook {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <holloway_core::api::functions::gempub::GempubMetadata>::sse_encode(
+            self.metadata,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.index_path, serializer);
+        <Vec<holloway_core::api::functions::gempub::GempubChapter>>::sse_encode(
+            self.chapters,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::gempub::GempubChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.path, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::gempub::GempubMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.author, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.published, serializer);
+        <Option<String>>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.cover, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::gempub::GempubPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <String>::sse_encode(self.body, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <Option<u32>>::sse_encode(self.chapter, serializer);
+        <Option<u32>>::sse_encode(self.previous_chapter, serializer);
+        <Option<u32>>::sse_encode(self.next_chapter, serializer);
+    }
+}
+
+impl SseEncode for crate::api::mirrors::GemtextLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::mirrors::GemtextLine::Text(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::GemtextLine::Link { url, label } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(url, serializer);
+                <Option<String>>::sse_encode(label, serializer);
+            }
+            crate::api::mirrors::GemtextLine::Heading { level, text } => {
+                <i32>::sse_encode(2, serializer);
+                <u8>::sse_encode(level, serializer);
+                <String>::sse_encode(text, serializer);
+            }
+            crate::api::mirrors::GemtextLine::ListItem(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::GemtextLine::Quote(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::GemtextLine::PreformatToggle { alt } => {
+                <i32>::sse_encode(5, serializer);
+                <Option<String>>::sse_encode(alt, serializer);
+            }
+            crate::api::mirrors::GemtextLine::Preformatted(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::parsers::gophermap::GopherItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <char>::sse_encode(self.item_type, serializer);
+        <String>::sse_encode(self.display, serializer);
+        <String>::sse_encode(self.selector, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.icon, serializer);
+        <bool>::sse_encode(self.fetchable, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::anchors::HeadingAnchor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.slug, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u8>::sse_encode(self.level, serializer);
+        <u32>::sse_encode(self.line_index, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::highlighting::HighlightedBlock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start_index, serializer);
+        <String>::sse_encode(self.language, serializer);
+        <Vec<holloway_core::api::functions::highlighting::HighlightedLine>>::sse_encode(
+            self.lines, serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::highlighting::HighlightedLine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<holloway_core::api::functions::highlighting::TokenSpan>>::sse_encode(
+            self.spans, serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::history::HistoryEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.visited_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::history::HistoryMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::history::HistoryMatch::Substring => 0,
+                holloway_core::api::storage::history::HistoryMatch::Prefix => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::history::HistoryRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<i64>>::sse_encode(self.since, serializer);
+        <Option<i64>>::sse_encode(self.until, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::history::HistoryResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <u32>::sse_encode(self.visit_count, serializer);
+        <i64>::sse_encode(self.last_visited, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::host_migration::HostMigration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.scheme, serializer);
+        <String>::sse_encode(self.from_host, serializer);
+        <String>::sse_encode(self.to_host, serializer);
+        <u32>::sse_encode(self.moved_pages, serializer);
+        <u32>::sse_encode(self.bookmarks, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::host_migration::HostMigrationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.bookmarks, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::host_settings::HostSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <Option<holloway_core::api::storage::host_settings::TlsPolicy>>::sse_encode(
+            self.tls_policy,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.identity_id, serializer);
+        <Option<u32>>::sse_encode(self.connect_timeout_secs, serializer);
+        <Option<u32>>::sse_encode(self.read_timeout_secs, serializer);
+        <Option<holloway_core::api::storage::host_settings::RedirectPolicy>>::sse_encode(
+            self.redirect_policy,
+            serializer,
+        );
+        <Option<u64>>::sse_encode(self.max_body_bytes, serializer);
+        <Option<String>>::sse_encode(self.charset, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::host_stats::HostStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <u32>::sse_encode(self.requests, serializer);
+        <u32>::sse_encode(self.errors, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <u32>::sse_encode(self.average_latency_ms, serializer);
+        <f64>::sse_encode(self.error_rate, serializer);
+        <i64>::sse_encode(self.first_request_at, serializer);
+        <i64>::sse_encode(self.last_request_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::host_stats::HostStatsOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::host_stats::HostStatsOrder::Requests => 0,
+                holloway_core::api::storage::host_stats::HostStatsOrder::BytesReceived => 1,
+                holloway_core::api::storage::host_stats::HostStatsOrder::AverageLatency => 2,
+                holloway_core::api::storage::host_stats::HostStatsOrder::ErrorRate => 3,
+                holloway_core::api::storage::host_stats::HostStatsOrder::LastRequest => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::identity_transfer::IdentitiesImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.imported, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::identities::Identity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.common_name, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+        <i64>::sse_encode(self.not_before, serializer);
+        <i64>::sse_encode(self.not_after, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::identity_scopes::IdentityScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.prefix, serializer);
+        <String>::sse_encode(self.identity_id, serializer);
+        <i64>::sse_encode(self.activated_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::inline_images::InlineImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.link_index, serializer);
+        <u32>::sse_encode(self.line_index, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <Vec<u8>>::sse_encode(self.data, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::inline_images::InlineImageFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.link_index, serializer);
+        <u32>::sse_encode(self.line_index, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::inline_images::InlineImages {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<holloway_core::api::functions::inline_images::InlineImage>>::sse_encode(
+            self.images,
+            serializer,
+        );
+        <Vec<holloway_core::api::functions::inline_images::InlineImageFailure>>::sse_encode(
+            self.failed,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::known_hosts::KnownHost {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <String>::sse_encode(self.fingerprint, serializer);
+        <Option<i64>>::sse_encode(self.expires_at, serializer);
+        <i64>::sse_encode(self.first_seen, serializer);
+        <i64>::sse_encode(self.last_seen, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Holloway => {
+                    0
+                }
+                holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Amfora => 1,
+                holloway_core::api::functions::known_hosts_transfer::KnownHostsFormat::Lagrange => {
+                    2
+                }
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::known_hosts_transfer::KnownHostsImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.imported, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bidi::LevelRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+        <u8>::sse_encode(self.level, serializer);
+        <holloway_core::api::functions::bidi::TextDirection>::sse_encode(
+            self.direction,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bidi::LineDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.line_index, serializer);
+        <holloway_core::api::functions::bidi::TextDirection>::sse_encode(
+            self.direction,
+            serializer,
+        );
+        <Vec<holloway_core::api::functions::bidi::LevelRun>>::sse_encode(self.runs, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::links::LinkKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::links::LinkKind::Page => 0,
+                holloway_core::api::functions::links::LinkKind::Search => 1,
+                holloway_core::api::functions::links::LinkKind::Download => 2,
+                holloway_core::api::functions::links::LinkKind::External => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::archive::ArchivedPage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::archive::ArchivedPage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::blocklist::BlockRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::blocklist::BlockRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::bookmarks::Bookmark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::bookmarks::Bookmark>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::cache::CacheEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::cache::CacheEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::cache::CachePolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::cache::CachePolicy>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::clear::ClearedData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::clear::ClearedData>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::protocols::cso::CsoField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::protocols::cso::CsoField>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::protocols::cso::CsoRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::protocols::cso::CsoRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::data_usage::DailyUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::data_usage::DailyUsage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::clear::DataCategory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::clear::DataCategory>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::page_diff::DiffLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::page_diff::DiffLine>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::downloads::DownloadRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::downloads::DownloadRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::identities::ExpiryReminder> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::identities::ExpiryReminder>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::explore::ExploreSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::explore::ExploreSource>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::feeds::Feed> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::feeds::Feed>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::feed_discovery::FeedCandidate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::feed_discovery::FeedCandidate>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::feeds::FeedEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::feeds::FeedEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::gempub::GempubChapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::gempub::GempubChapter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::mirrors::GemtextLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::mirrors::GemtextLine>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::parsers::gophermap::GopherItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::parsers::gophermap::GopherItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::highlighting::HighlightedBlock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::highlighting::HighlightedBlock>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::highlighting::HighlightedLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::highlighting::HighlightedLine>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::history::HistoryEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::history::HistoryEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::history::HistoryResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::history::HistoryResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::host_migration::HostMigration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::host_migration::HostMigration>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::host_settings::HostSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::host_settings::HostSettings>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::host_stats::HostStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::host_stats::HostStats>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::identities::Identity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::identities::Identity>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::identity_scopes::IdentityScope> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::identity_scopes::IdentityScope>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::inline_images::InlineImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::inline_images::InlineImage>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::inline_images::InlineImageFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::inline_images::InlineImageFailure>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::known_hosts::KnownHost> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::known_hosts::KnownHost>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::bidi::LevelRun> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::bidi::LevelRun>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::bidi::LineDirection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::bidi::LineDirection>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::page_index::LocalSearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::page_index::LocalSearchResult>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::logging::LogRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::logging::LogRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::anchors::OutlineEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::anchors::OutlineEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::links::PageLink> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::links::PageLink>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::ports::PortOverride> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::ports::PortOverride>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::text_width::PreformattedBlock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::text_width::PreformattedBlock>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::protocols::registry::ProtocolInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::protocols::registry::ProtocolInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::metasearch::ProviderFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::metasearch::ProviderFailure>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::transport::proxy::ProxyRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::transport::proxy::ProxyRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::read_later::ReadLaterItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::read_later::ReadLaterItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::reflow::ReflowedLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::reflow::ReflowedLine>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::input_answers::SavedAnswer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::input_answers::SavedAnswer>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::scheduler::SchedulerEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::scheduler::SchedulerEvent>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::gemini_proxies::SchemeProxy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::gemini_proxies::SchemeProxy>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::metasearch::SearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::metasearch::SearchHit>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::search::SearchProvider> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::search::SearchProvider>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::sessions::SessionEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::sessions::SessionEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::sessions::SessionSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::sessions::SessionSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::sessions::SessionTab> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::sessions::SessionTab>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::site_map::SiteNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::site_map::SiteNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::page_index::SnippetPart> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::page_index::SnippetPart>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::ansi::StyledSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::ansi::StyledSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::suggestions::Suggestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::suggestions::Suggestion>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::scheduler::TaskSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::scheduler::TaskSchedule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::highlighting::TokenSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::highlighting::TokenSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::top_sites::TopSite> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::top_sites::TopSite>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::functions::request_trace::TraceEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::functions::request_trace::TraceEvent>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::trusted_cas::TrustedCa> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::trusted_cas::TrustedCa>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::watches::WatchedPage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::watches::WatchedPage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<holloway_core::api::storage::plan_watches::WatchedPlan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <holloway_core::api::storage::plan_watches::WatchedPlan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::page_index::LocalSearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Vec<holloway_core::api::storage::page_index::SnippetPart>>::sse_encode(
+            self.snippet,
+            serializer,
+        );
+        <i64>::sse_encode(self.fetched_at, serializer);
+        <Option<i64>>::sse_encode(self.last_visited_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::logging::LogLevel::Debug => 0,
+                holloway_core::api::functions::logging::LogLevel::Info => 1,
+                holloway_core::api::functions::logging::LogLevel::Warn => 2,
+                holloway_core::api::functions::logging::LogLevel::Error => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::logging::LogRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp_ms, serializer);
+        <holloway_core::api::functions::logging::LogLevel>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<u64>>::sse_encode(self.request_id, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::menu_pages::MenuPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.offset, serializer);
+        <Vec<holloway_core::api::parsers::gophermap::GopherItem>>::sse_encode(
+            self.items, serializer,
+        );
+        <u32>::sse_encode(self.total, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::metasearch::MetasearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<holloway_core::api::functions::metasearch::SearchHit>>::sse_encode(
+            self.hits, serializer,
+        );
+        <Vec<holloway_core::api::functions::metasearch::ProviderFailure>>::sse_encode(
+            self.failed,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::mirror::MirrorProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.path, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+        <u32>::sse_encode(self.saved, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <u32>::sse_encode(self.remaining, serializer);
+    }
+}
+
+impl SseEncode for crate::api::mirrors::NavigateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::mirrors::NavigateError::InvalidUrl(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::UnsupportedScheme(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::SchemeDisabled(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::Blocked(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::Disallowed(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::Offline(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::DnsFailure(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ConnectTimeout(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ConnectionRefused(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ConnectionFailed(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ConnectionLost(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ReadTimeout(field0) => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::GeminiTimeout(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ProxyUnreachable(field0) => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ProxyAuthFailed(field0) => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::ProxyError(field0) => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::GatewayError(field0) => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::TlsError(field0) => {
+                <i32>::sse_encode(17, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::FingerprintMismatch(field0) => {
+                <i32>::sse_encode(18, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::CertificateDate(field0) => {
+                <i32>::sse_encode(19, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::CertificateRejected(field0) => {
+                <i32>::sse_encode(20, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::BadResponse(field0) => {
+                <i32>::sse_encode(21, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::TooLarge(field0) => {
+                <i32>::sse_encode(22, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::NotFound(field0) => {
+                <i32>::sse_encode(23, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::InvalidInput(field0) => {
+                <i32>::sse_encode(24, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::GopherBadType(field0) => {
+                <i32>::sse_encode(25, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::GopherBadSelector(field0) => {
+                <i32>::sse_encode(26, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::StorageError(field0) => {
+                <i32>::sse_encode(27, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::IdentityError(field0) => {
+                <i32>::sse_encode(28, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::Cancelled(field0) => {
+                <i32>::sse_encode(29, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::mirrors::NavigateError::Redirected { target, permanent } => {
+                <i32>::sse_encode(30, serializer);
+                <String>::sse_encode(target, serializer);
+                <bool>::sse_encode(permanent, serializer);
+            }
+            crate::api::mirrors::NavigateError::InputRequired { prompt, sensitive } => {
+                <i32>::sse_encode(31, serializer);
+                <String>::sse_encode(prompt, serializer);
+                <bool>::sse_encode(sensitive, serializer);
+            }
+            crate::api::mirrors::NavigateError::CertificateRequired(field0) => {
+                <i32>::sse_encode(32, serializer);
+                <holloway_core::api::models::certificate_error::CertificateError>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            crate::api::mirrors::NavigateError::ServerError(field0) => {
+                <i32>::sse_encode(33, serializer);
+                <holloway_core::api::models::server_failure::ServerFailure>::sse_encode(
+                    field0, serializer,
+                );
+            }
+            crate::api::mirrors::NavigateError::Other(field0) => {
+                <i32>::sse_encode(34, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_event::NavigationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.request_id, serializer);
+        <holloway_core::api::models::navigation_event::NavigationEventKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <String>::sse_encode(self.url, serializer);
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.meta, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_event::NavigationEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(match self {holloway_core::api::models::navigation_event::NavigationEventKind::NavigationStarted => { 0 }
+holloway_core::api::models::navigation_event::NavigationEventKind::Resolving => { 1 }
+holloway_core::api::models::navigation_event::NavigationEventKind::Connecting => { 2 }
+holloway_core::api::models::navigation_event::NavigationEventKind::TlsHandshake => { 3 }
+holloway_core::api::models::navigation_event::NavigationEventKind::RedirectFollowed => { 4 }
+holloway_core::api::models::navigation_event::NavigationEventKind::HeaderReceived => { 5 }
+holloway_core::api::models::navigation_event::NavigationEventKind::BodyProgress => { 6 }
+holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFinished => { 7 }
+holloway_core::api::models::navigation_event::NavigationEventKind::NavigationFailed => { 8 }
+ _ => { unimplemented!(""); }}, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_result::NavigationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Vec<String>>::sse_encode(self.redirects, serializer);
+        <String>::sse_encode(self.protocol, serializer);
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.mime, serializer);
+        <Option<String>>::sse_encode(self.charset, serializer);
+        <holloway_core::api::functions::content_router::Renderer>::sse_encode(
+            self.renderer,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.favicon, serializer);
+        <String>::sse_encode(self.body, serializer);
+        <bool>::sse_encode(self.truncated, serializer);
+        <Option<i64>>::sse_encode(self.cached_at, serializer);
+        <bool>::sse_encode(self.stale, serializer);
+        <u64>::sse_encode(self.duration_ms, serializer);
+        <holloway_core::api::models::navigation_result::RequestTimings>::sse_encode(
+            self.timings,
+            serializer,
+        );
+        <Option<holloway_core::api::models::navigation_result::CertificateSummary>>::sse_encode(
+            self.certificate,
+            serializer,
+        );
+        <Option<holloway_core::api::models::server_failure::ServerFailure>>::sse_encode(
+            self.server_failure,
+            serializer,
+        );
+        <u64>::sse_encode(self.request_id, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::data_usage::NetworkType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::data_usage::NetworkType::Wifi => 0,
+                holloway_core::api::storage::data_usage::NetworkType::Cellular => 1,
+                holloway_core::api::storage::data_usage::NetworkType::Ethernet => 2,
+                holloway_core::api::storage::data_usage::NetworkType::Unknown => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::mirrors::AnsiColor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::mirrors::AnsiColor>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::functions::batch::BatchItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::functions::batch::BatchItem>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::bookmarks::Bookmark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::bookmarks::Bookmark>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::certificate_error::CertificateError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::certificate_error::CertificateError>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::navigation_result::CertificateSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::navigation_result::CertificateSummary>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::downloads::DownloadStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::downloads::DownloadStatus>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::error_info::ErrorInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::error_info::ErrorInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::functions::file_type::FileType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::functions::file_type::FileType>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::mirrors::GemtextLine> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::mirrors::GemtextLine>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::host_settings::HostSettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::host_settings::HostSettings>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::identity_scopes::IdentityScope> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::identity_scopes::IdentityScope>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::functions::mirror::MirrorProgress> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::functions::mirror::MirrorProgress>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::navigation_event::NavigationEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::navigation_event::NavigationEvent>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::navigation_result::NavigationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::navigation_result::NavigationResult>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::error_info::RecoveryAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::error_info::RecoveryAction>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::host_settings::RedirectPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::host_settings::RedirectPolicy>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::functions::content_router::Renderer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::functions::content_router::Renderer>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::server_failure::ServerFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::server_failure::ServerFailure>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::sessions::Session> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::sessions::Session>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::transport::proxy::Socks5Proxy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::transport::proxy::Socks5Proxy>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::models::tls_failure::TlsFailure> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::models::tls_failure::TlsFailure>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<holloway_core::api::storage::host_settings::TlsPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <holloway_core::api::storage::host_settings::TlsPolicy>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u8>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<holloway_core::api::functions::highlighting::HighlightedLine>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<holloway_core::api::functions::highlighting::HighlightedLine>>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<holloway_core::api::functions::request_trace::TraceEvent>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<holloway_core::api::functions::request_trace::TraceEvent>>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::anchors::OutlineEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::functions::anchors::HeadingAnchor>::sse_encode(
+            self.anchor,
+            serializer,
+        );
+        <u8>::sse_encode(self.depth, serializer);
+        <Option<u32>>::sse_encode(self.parent, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::page_diff::PageDiff {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <i64>::sse_encode(self.cached_at, serializer);
+        <Vec<holloway_core::api::functions::page_diff::DiffLine>>::sse_encode(
+            self.added, serializer,
+        );
+        <Vec<holloway_core::api::functions::page_diff::DiffLine>>::sse_encode(
+            self.removed,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::links::PageLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <holloway_core::api::functions::links::LinkKind>::sse_encode(self.kind, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::page_summary::PageSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.favicon, serializer);
+        <Option<i64>>::sse_encode(self.cached_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::peek::PeekResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.meta, serializer);
+        <Option<String>>::sse_encode(self.mime, serializer);
+        <Option<holloway_core::api::functions::content_router::Renderer>>::sse_encode(
+            self.renderer,
+            serializer,
+        );
+        <Option<u64>>::sse_encode(self.size, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_result::PinExpiry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::navigation_result::PinExpiry::Valid => 0,
+                holloway_core::api::models::navigation_result::PinExpiry::ExpiringSoon => 1,
+                holloway_core::api::models::navigation_result::PinExpiry::Expired => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_result::PinStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::navigation_result::PinStatus::FirstUse => 0,
+                holloway_core::api::models::navigation_result::PinStatus::Matched => 1,
+                holloway_core::api::models::navigation_result::PinStatus::Replaced => 2,
+                holloway_core::api::models::navigation_result::PinStatus::Unverified => 3,
+                holloway_core::api::models::navigation_result::PinStatus::CaVerified => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::ports::PortOverride {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.scheme, serializer);
+        <Option<String>>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::scheduler::PowerState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::scheduler::PowerState::Charging => 0,
+                holloway_core::api::functions::scheduler::PowerState::Battery => 1,
+                holloway_core::api::functions::scheduler::PowerState::LowBattery => 2,
+                holloway_core::api::functions::scheduler::PowerState::Unknown => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::text_width::PreformattedBlock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start_index, serializer);
+        <u32>::sse_encode(self.line_count, serializer);
+        <Option<String>>::sse_encode(self.alt, serializer);
+        <u32>::sse_encode(self.max_width, serializer);
+        <bool>::sse_encode(self.has_wide_chars, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::protocols::registry::ProtocolCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.user_input, serializer);
+        <bool>::sse_encode(self.redirects, serializer);
+        <bool>::sse_encode(self.client_certificates, serializer);
+        <bool>::sse_encode(self.menus, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::protocols::registry::ProtocolInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.scheme, serializer);
+        <u16>::sse_encode(self.default_port, serializer);
+        <bool>::sse_encode(self.requires_tls, serializer);
+        <holloway_core::api::protocols::registry::ProtocolCapabilities>::sse_encode(
+            self.capabilities,
+            serializer,
+        );
+        <bool>::sse_encode(self.enabled, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::metasearch::ProviderFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::transport::proxy::ProxyProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.handshake_ms, serializer);
+        <u32>::sse_encode(self.connect_ms, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::gemini_proxies::ProxyProtocol {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gemini => 0,
+                holloway_core::api::storage::gemini_proxies::ProxyProtocol::Gopher => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::transport::proxy::ProxyRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.pattern, serializer);
+        <Option<holloway_core::api::transport::proxy::Socks5Proxy>>::sse_encode(
+            self.proxy, serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::mirrors::ProxySettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::mirrors::ProxySettings::Direct => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::mirrors::ProxySettings::Socks5 { proxy } => {
+                <i32>::sse_encode(1, serializer);
+                <holloway_core::api::transport::proxy::Socks5Proxy>::sse_encode(proxy, serializer);
+            }
+            crate::api::mirrors::ProxySettings::PerRule { rules, fallback } => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<holloway_core::api::transport::proxy::ProxyRule>>::sse_encode(
+                    rules, serializer,
+                );
+                <Option<holloway_core::api::transport::proxy::Socks5Proxy>>::sse_encode(
+                    fallback, serializer,
+                );
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::explore::RandomDestination {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.directory_url, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::transport::rate_limit::RateLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_connections, serializer);
+        <u64>::sse_encode(self.min_interval_ms, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::read_later::ReadLaterItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.added_at, serializer);
+        <Option<i64>>::sse_encode(self.read_at, serializer);
+        <bool>::sse_encode(self.cached, serializer);
+        <Option<i64>>::sse_encode(self.fetched_at, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__protocols__finger__connect_and_fetch_finger_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__protocols__gemini__connect_and_fetch_gemini_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__protocols__gopher__connect_and_fetch_gopher_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__exposed_functions__init_app_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__exposed_functions__navigate_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__functions__navigate_internal__navigate_internal_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for holloway_core::api::functions::reading_stats::ReadingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.words, serializer);
+        <u32>::sse_encode(self.preformatted_words, serializer);
+        <u32>::sse_encode(self.reading_minutes, serializer);
+        <u32>::sse_encode(self.links, serializer);
+        <u32>::sse_encode(self.headings, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__exposed_functions__get_start_page_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__exposed_functions__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for holloway_core::api::models::error_info::RecoveryAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::error_info::RecoveryAction::Retry => 0,
+                holloway_core::api::models::error_info::RecoveryAction::AddException => 1,
+                holloway_core::api::models::error_info::RecoveryAction::EnableProtocol => 2,
+                holloway_core::api::models::error_info::RecoveryAction::ProvideInput => 3,
+                holloway_core::api::models::error_info::RecoveryAction::ChooseIdentity => 4,
+                holloway_core::api::models::error_info::RecoveryAction::RenewIdentity => 5,
+                holloway_core::api::models::error_info::RecoveryAction::CheckProxy => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-// Section: rust2dart
+impl SseEncode for holloway_core::api::storage::host_settings::RedirectPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::host_settings::RedirectPolicy::Follow => 0,
+                holloway_core::api::storage::host_settings::RedirectPolicy::AskCrossHost => 1,
+                holloway_core::api::storage::host_settings::RedirectPolicy::Ask => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
 
-impl SseEncode for String {
+impl SseEncode for holloway_core::api::functions::reflow::ReflowedLine {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <u32>::sse_encode(self.source_index, serializer);
+        <crate::api::mirrors::GemtextLine>::sse_encode(self.line, serializer);
+        <bool>::sse_encode(self.continuation, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for holloway_core::api::functions::content_router::Renderer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::content_router::Renderer::Gemtext => 0,
+                holloway_core::api::functions::content_router::Renderer::GopherMenu => 1,
+                holloway_core::api::functions::content_router::Renderer::PlainText => 2,
+                holloway_core::api::functions::content_router::Renderer::Markdown => 3,
+                holloway_core::api::functions::content_router::Renderer::Image => 4,
+                holloway_core::api::functions::content_router::Renderer::Download => 5,
+                holloway_core::api::functions::content_router::Renderer::External => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::navigation_result::RequestTimings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.dns_ms, serializer);
+        <Option<u64>>::sse_encode(self.connect_ms, serializer);
+        <Option<u64>>::sse_encode(self.tls_ms, serializer);
+        <Option<u64>>::sse_encode(self.first_byte_ms, serializer);
+        <Option<u64>>::sse_encode(self.transfer_ms, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::input_answers::SavedAnswer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.prompt, serializer);
+        <String>::sse_encode(self.answer, serializer);
+        <i64>::sse_encode(self.answered_at, serializer);
+        <u32>::sse_encode(self.uses, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::scheduler::ScheduledTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::scheduler::ScheduledTask::FeedRefresh => 0,
+                holloway_core::api::functions::scheduler::ScheduledTask::PageWatches => 1,
+                holloway_core::api::functions::scheduler::ScheduledTask::PlanWatches => 2,
+                holloway_core::api::functions::scheduler::ScheduledTask::PinExpiry => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::scheduler::SchedulerEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::functions::scheduler::ScheduledTask>::sse_encode(
+            self.task, serializer,
+        );
+        <holloway_core::api::functions::scheduler::SchedulerEventKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <i64>::sse_encode(self.timestamp, serializer);
+        <u32>::sse_encode(self.count, serializer);
+        <Vec<String>>::sse_encode(self.items, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+        <Option<i64>>::sse_encode(self.next_run_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::scheduler::SchedulerEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::scheduler::SchedulerEventKind::Started => 0,
+                holloway_core::api::functions::scheduler::SchedulerEventKind::Completed => 1,
+                holloway_core::api::functions::scheduler::SchedulerEventKind::Skipped => 2,
+                holloway_core::api::functions::scheduler::SchedulerEventKind::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::gemini_proxies::SchemeProxy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.scheme, serializer);
+        <holloway_core::api::storage::gemini_proxies::ProxyProtocol>::sse_encode(
+            self.protocol,
+            serializer,
+        );
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <String>::sse_encode(self.selector, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::metasearch::SearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.snippet, serializer);
+        <Vec<String>>::sse_encode(self.providers, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::search::SearchProvider {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.url_template, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::server_failure::ServerFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.status, serializer);
+        <holloway_core::api::models::server_failure::ServerFailureKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <String>::sse_encode(self.message, serializer);
+        <bool>::sse_encode(self.temporary, serializer);
+        <Option<u32>>::sse_encode(self.retry_after_secs, serializer);
+        <Option<holloway_core::api::models::error_info::RecoveryAction>>::sse_encode(
+            self.suggested_action,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::server_failure::ServerFailureKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(match self {holloway_core::api::models::server_failure::ServerFailureKind::TemporaryFailure => { 0 }
+holloway_core::api::models::server_failure::ServerFailureKind::ServerUnavailable => { 1 }
+holloway_core::api::models::server_failure::ServerFailureKind::CgiError => { 2 }
+holloway_core::api::models::server_failure::ServerFailureKind::ProxyError => { 3 }
+holloway_core::api::models::server_failure::ServerFailureKind::SlowDown => { 4 }
+holloway_core::api::models::server_failure::ServerFailureKind::PermanentFailure => { 5 }
+holloway_core::api::models::server_failure::ServerFailureKind::NotFound => { 6 }
+holloway_core::api::models::server_failure::ServerFailureKind::Gone => { 7 }
+holloway_core::api::models::server_failure::ServerFailureKind::ProxyRequestRefused => { 8 }
+holloway_core::api::models::server_failure::ServerFailureKind::BadRequest => { 9 }
+ _ => { unimplemented!(""); }}, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::sessions::Session {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Vec<holloway_core::api::storage::sessions::SessionTab>>::sse_encode(self.tabs, serializer);
+        <u32>::sse_encode(self.active_tab, serializer);
+        <i64>::sse_encode(self.saved_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::sessions::SessionEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <f64>::sse_encode(self.scroll_offset, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::sessions::SessionSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.tab_count, serializer);
+        <i64>::sse_encode(self.saved_at, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::sessions::SessionTab {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<holloway_core::api::storage::sessions::SessionEntry>>::sse_encode(
+            self.entries,
+            serializer,
+        );
+        <u32>::sse_encode(self.current_entry, serializer);
+        <bool>::sse_encode(self.private, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::shutdown::ShutdownSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.streams_cancelled, serializer);
+        <u32>::sse_encode(self.fetches_aborted, serializer);
+        <u32>::sse_encode(self.requests_cancelled, serializer);
+        <u32>::sse_encode(self.mirrors_cancelled, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::site_map::SiteMap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.root, serializer);
+        <Vec<holloway_core::api::functions::site_map::SiteNode>>::sse_encode(
+            self.nodes, serializer,
+        );
+        <bool>::sse_encode(self.truncated, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::site_map::SiteNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <holloway_core::api::functions::links::LinkKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.icon, serializer);
+        <bool>::sse_encode(self.menu, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+        <Option<u32>>::sse_encode(self.parent, serializer);
+        <bool>::sse_encode(self.expanded, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::page_index::SnippetPart {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <bool>::sse_encode(self.matched, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::transport::proxy::Socks5Proxy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.host, serializer);
+        <u16>::sse_encode(self.port, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::stream_event::StreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::models::stream_event::StreamEventKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <Option<u8>>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.meta, serializer);
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<crate::api::mirrors::GemtextLine>>::sse_encode(self.element, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::stream_event::StreamEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::stream_event::StreamEventKind::Header => 0,
+                holloway_core::api::models::stream_event::StreamEventKind::Line => 1,
+                holloway_core::api::models::stream_event::StreamEventKind::Idle => 2,
+                holloway_core::api::models::stream_event::StreamEventKind::Finished => 3,
+                holloway_core::api::models::stream_event::StreamEventKind::Failed => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::streaming::StreamOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.idle_interval_secs, serializer);
+        <Option<u32>>::sse_encode(self.max_idle_secs, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::ansi::StyledSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+        <holloway_core::api::functions::ansi::AnsiStyle>::sse_encode(self.style, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::ansi::StyledText {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <Vec<holloway_core::api::functions::ansi::StyledSpan>>::sse_encode(self.spans, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::suggestions::Suggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <bool>::sse_encode(self.bookmarked, serializer);
+        <u32>::sse_encode(self.visit_count, serializer);
+        <Option<i64>>::sse_encode(self.last_visited, serializer);
+        <holloway_core::api::functions::suggestions::SuggestionMatch>::sse_encode(
+            self.matched,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.completion, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::suggestions::SuggestionMatch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::suggestions::SuggestionMatch::Substring => 0,
+                holloway_core::api::functions::suggestions::SuggestionMatch::WordPrefix => 1,
+                holloway_core::api::functions::suggestions::SuggestionMatch::UrlPrefix => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::scheduler::TaskSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::functions::scheduler::ScheduledTask>::sse_encode(
+            self.task, serializer,
+        );
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.interval_secs, serializer);
+        <u32>::sse_encode(self.jitter_secs, serializer);
+        <bool>::sse_encode(self.unmetered_only, serializer);
+        <bool>::sse_encode(self.charging_only, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::bidi::TextDirection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::bidi::TextDirection::Ltr => 0,
+                holloway_core::api::functions::bidi::TextDirection::Rtl => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::models::tls_failure::TlsFailure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::models::tls_failure::TlsFailureKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <String>::sse_encode(self.host, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<String>>::sse_encode(self.names, serializer);
+        <String>::sse_encode(self.subject, serializer);
+        <String>::sse_encode(self.issuer, serializer);
+        <i64>::sse_encode(self.not_before, serializer);
+        <i64>::sse_encode(self.not_after, serializer);
+        <u32>::sse_encode(self.intermediates, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::models::tls_failure::TlsFailureKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::models::tls_failure::TlsFailureKind::Expired => 0,
+                holloway_core::api::models::tls_failure::TlsFailureKind::NotYetValid => 1,
+                holloway_core::api::models::tls_failure::TlsFailureKind::SelfSigned => 2,
+                holloway_core::api::models::tls_failure::TlsFailureKind::NameMismatch => 3,
+                holloway_core::api::models::tls_failure::TlsFailureKind::IncompleteChain => 4,
+                holloway_core::api::models::tls_failure::TlsFailureKind::UntrustedIssuer => 5,
+                holloway_core::api::models::tls_failure::TlsFailureKind::Revoked => 6,
+                holloway_core::api::models::tls_failure::TlsFailureKind::Other => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::host_settings::TlsPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::storage::host_settings::TlsPolicy::TrustOnFirstUse => 0,
+                holloway_core::api::storage::host_settings::TlsPolicy::AcceptChanged => 1,
+                holloway_core::api::storage::host_settings::TlsPolicy::Unverified => 2,
+                holloway_core::api::storage::host_settings::TlsPolicy::Strict => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::highlighting::TokenKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::highlighting::TokenKind::Plain => 0,
+                holloway_core::api::functions::highlighting::TokenKind::Comment => 1,
+                holloway_core::api::functions::highlighting::TokenKind::String => 2,
+                holloway_core::api::functions::highlighting::TokenKind::Number => 3,
+                holloway_core::api::functions::highlighting::TokenKind::Constant => 4,
+                holloway_core::api::functions::highlighting::TokenKind::Keyword => 5,
+                holloway_core::api::functions::highlighting::TokenKind::Operator => 6,
+                holloway_core::api::functions::highlighting::TokenKind::Type => 7,
+                holloway_core::api::functions::highlighting::TokenKind::Function => 8,
+                holloway_core::api::functions::highlighting::TokenKind::Variable => 9,
+                holloway_core::api::functions::highlighting::TokenKind::Tag => 10,
+                holloway_core::api::functions::highlighting::TokenKind::Attribute => 11,
+                holloway_core::api::functions::highlighting::TokenKind::Punctuation => 12,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::highlighting::TokenSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <holloway_core::api::functions::highlighting::TokenKind>::sse_encode(self.kind, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::top_sites::TopSite {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.host, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.favicon, serializer);
+        <u32>::sse_encode(self.visit_count, serializer);
+        <i64>::sse_encode(self.last_visited, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::request_trace::TraceEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <holloway_core::api::functions::request_trace::TraceStage>::sse_encode(
+            self.stage, serializer,
+        );
+        <String>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.elapsed_ms, serializer);
+    }
+}
+
+impl SseEncode for holloway_core::api::functions::request_trace::TraceStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                holloway_core::api::functions::request_trace::TraceStage::Navigation => 0,
+                holloway_core::api::functions::request_trace::TraceStage::Cache => 1,
+                holloway_core::api::functions::request_trace::TraceStage::Transport => 2,
+                holloway_core::api::functions::request_trace::TraceStage::Tls => 3,
+                holloway_core::api::functions::request_trace::TraceStage::Response => 4,
+                holloway_core::api::functions::request_trace::TraceStage::Parser => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for holloway_core::api::storage::trusted_cas::TrustedCa {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.fingerprint, serializer);
+        <String>::sse_encode(self.subject, serializer);
+        <i64>::sse_encode(self.not_after, serializer);
+        <i64>::sse_encode(self.added_at, serializer);
     }
 }
 
@@ -474,6 +22440,20 @@ impl SseEncode for u16 {
     }
 }
 
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -486,17 +22466,30 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
+impl SseEncode for holloway_core::api::storage::watches::WatchedPage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <i64>::sse_encode(self.added_at, serializer);
+        <Option<i64>>::sse_encode(self.seen_at, serializer);
+        <Option<i64>>::sse_encode(self.checked_at, serializer);
+        <Option<i64>>::sse_encode(self.changed_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for holloway_core::api::storage::plan_watches::WatchedPlan {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <i64>::sse_encode(self.added_at, serializer);
+        <Option<String>>::sse_encode(self.plan, serializer);
+        <Option<i64>>::sse_encode(self.checked_at, serializer);
+        <Option<i64>>::sse_encode(self.updated_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
@@ -1,2 +1,5 @@
 pub mod api;
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "flutter")]
 mod frb_generated;